@@ -0,0 +1,506 @@
+//! Side-by-side comparison of agents that worked the same task in parallel.
+//!
+//! After a fan-out — one task handed to several agents, each in its own
+//! worktree — [`build_comparison`] collects per branch the commits and
+//! diffstat against the shared base, the test outcome of a worktree test
+//! matrix run, the latest agent's closing message, and the tokens its
+//! sessions consumed. [`render_comparison_table`] and
+//! [`render_comparison_markdown`] turn the report into the terminal and
+//! Markdown views, so the winner can be picked on evidence.
+
+use std::{
+    fmt::Write as _,
+    path::{Path, PathBuf},
+};
+
+use gwt_core::{
+    session_search::{last_assistant_message, SessionSource},
+    usage::{claude, codex},
+};
+use gwt_git::worktree_matrix::{MatrixOutcome, MatrixReport, MatrixTarget};
+use serde::{Deserialize, Serialize};
+
+use crate::{session::Session, types::AgentId};
+
+/// Characters of the closing message kept as the entry summary.
+const SUMMARY_MAX_CHARS: usize = 280;
+
+/// `git diff --shortstat` numbers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffStat {
+    pub files_changed: u64,
+    pub insertions: u64,
+    pub deletions: u64,
+}
+
+/// Tokens consumed by the sessions on one branch.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenCost {
+    pub input: u64,
+    pub output: u64,
+    pub total: u64,
+}
+
+/// What the transcript of one agent session tells about it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SessionInsight {
+    pub tokens: TokenCost,
+    pub closing_message: Option<String>,
+}
+
+/// One candidate branch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComparisonEntry {
+    pub branch: String,
+    pub worktree_path: Option<PathBuf>,
+    /// Display names of the agents that ran sessions on the branch.
+    pub agents: Vec<String>,
+    pub commits: Option<u64>,
+    pub diffstat: Option<DiffStat>,
+    /// `None` when no test run was included.
+    pub tests: Option<MatrixOutcome>,
+    /// Closing message of the latest session that has one.
+    pub summary: Option<String>,
+    /// `None` when no session transcript could be read.
+    pub tokens: Option<TokenCost>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComparisonReport {
+    pub base: String,
+    pub entries: Vec<ComparisonEntry>,
+}
+
+/// Compare `targets` against `base` in `repo`. `sessions` are matched to a
+/// target by branch or worktree path; `insight` reads one agent session's
+/// transcript (see [`read_session_insight`]).
+pub fn build_comparison(
+    repo: &Path,
+    base: &str,
+    targets: &[MatrixTarget],
+    sessions: &[Session],
+    tests: Option<&MatrixReport>,
+    insight: impl Fn(&AgentId, &str) -> Option<SessionInsight>,
+) -> ComparisonReport {
+    let entries = targets
+        .iter()
+        .map(|target| {
+            let mut matched: Vec<&Session> = sessions
+                .iter()
+                .filter(|session| {
+                    session.branch == target.branch
+                        || target.path.as_deref() == Some(session.worktree_path.as_path())
+                })
+                .collect();
+            matched.sort_by_key(|session| session.last_activity_at);
+
+            let mut agents: Vec<String> = Vec::new();
+            let mut tokens: Option<TokenCost> = None;
+            let mut summary = None;
+            for session in &matched {
+                if !agents.contains(&session.display_name) {
+                    agents.push(session.display_name.clone());
+                }
+                for agent_session_id in agent_session_ids(session) {
+                    let Some(found) = insight(&session.agent_id, agent_session_id) else {
+                        continue;
+                    };
+                    let sum = tokens.get_or_insert_with(TokenCost::default);
+                    sum.input += found.tokens.input;
+                    sum.output += found.tokens.output;
+                    sum.total += found.tokens.total;
+                    if let Some(message) = found.closing_message {
+                        summary = Some(truncate_summary(&message));
+                    }
+                }
+            }
+
+            ComparisonEntry {
+                branch: target.branch.clone(),
+                worktree_path: target.path.clone(),
+                agents,
+                commits: commit_count(repo, base, &target.branch),
+                diffstat: diffstat(repo, base, &target.branch),
+                tests: tests.and_then(|report| {
+                    report
+                        .cells
+                        .iter()
+                        .find(|cell| cell.branch == target.branch)
+                        .map(|cell| cell.outcome.clone())
+                }),
+                summary,
+                tokens,
+            }
+        })
+        .collect();
+    ComparisonReport {
+        base: base.to_string(),
+        entries,
+    }
+}
+
+/// Every agent-side session id of `session`, oldest first.
+fn agent_session_ids(session: &Session) -> Vec<&str> {
+    let mut ids: Vec<&str> = session
+        .session_history
+        .iter()
+        .map(|entry| entry.agent_session_id.as_str())
+        .collect();
+    if let Some(current) = session.agent_session_id.as_deref() {
+        if !ids.contains(&current) {
+            ids.push(current);
+        }
+    }
+    ids
+}
+
+/// Read token usage and the closing message from the Claude Code or Codex
+/// transcript store. Other agents keep no readable transcript.
+pub fn read_session_insight(agent_id: &AgentId, agent_session_id: &str) -> Option<SessionInsight> {
+    let (source, content, usage) = match agent_id {
+        AgentId::ClaudeCode => {
+            let home = claude::claude_home()?;
+            let path = claude::transcript_for_session(&home, agent_session_id)?;
+            let content = std::fs::read_to_string(path).ok()?;
+            let usage = claude::parse_claude_transcript(agent_session_id, &content);
+            (SessionSource::Claude, content, usage)
+        }
+        AgentId::Codex => {
+            let home = codex::codex_home()?;
+            let path = codex::rollout_for_session(&home, agent_session_id)?;
+            let content = std::fs::read_to_string(path).ok()?;
+            let usage = codex::parse_codex_session(agent_session_id, &content);
+            (SessionSource::Codex, content, usage)
+        }
+        _ => return None,
+    };
+    Some(SessionInsight {
+        tokens: TokenCost {
+            input: usage.input_tokens,
+            output: usage.output_tokens,
+            total: usage.total_tokens,
+        },
+        closing_message: last_assistant_message(source, &content),
+    })
+}
+
+fn truncate_summary(message: &str) -> String {
+    let line = message.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() <= SUMMARY_MAX_CHARS {
+        return line;
+    }
+    let mut out: String = line.chars().take(SUMMARY_MAX_CHARS).collect();
+    out.push('…');
+    out
+}
+
+fn git_stdout(repo: &Path, args: &[&str]) -> Option<String> {
+    let output = gwt_core::process::run_git_logged(args, Some(repo)).ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn commit_count(repo: &Path, base: &str, branch: &str) -> Option<u64> {
+    git_stdout(repo, &["rev-list", "--count", &format!("{base}..{branch}")])?
+        .parse()
+        .ok()
+}
+
+fn diffstat(repo: &Path, base: &str, branch: &str) -> Option<DiffStat> {
+    git_stdout(
+        repo,
+        &["diff", "--shortstat", &format!("{base}...{branch}")],
+    )
+    .map(|stat| parse_shortstat(&stat))
+}
+
+/// Parse `3 files changed, 10 insertions(+), 2 deletions(-)`; an empty
+/// string (no changes) is all zeros.
+fn parse_shortstat(stat: &str) -> DiffStat {
+    let mut parsed = DiffStat::default();
+    for part in stat.split(',') {
+        let mut words = part.split_whitespace();
+        let Some(count) = words.next().and_then(|n| n.parse::<u64>().ok()) else {
+            continue;
+        };
+        match words.next() {
+            Some(word) if word.starts_with("file") => parsed.files_changed = count,
+            Some(word) if word.starts_with("insertion") => parsed.insertions = count,
+            Some(word) if word.starts_with("deletion") => parsed.deletions = count,
+            _ => {}
+        }
+    }
+    parsed
+}
+
+fn tests_label(outcome: Option<&MatrixOutcome>) -> String {
+    match outcome {
+        None => "-".to_string(),
+        Some(MatrixOutcome::Failed { code: Some(code) }) => format!("FAIL ({code})"),
+        Some(outcome) => outcome.label().to_string(),
+    }
+}
+
+fn diff_label(diffstat: Option<&DiffStat>) -> String {
+    diffstat.map_or_else(
+        || "-".to_string(),
+        |stat| {
+            format!(
+                "{} files +{} -{}",
+                stat.files_changed, stat.insertions, stat.deletions
+            )
+        },
+    )
+}
+
+fn tokens_label(tokens: Option<&TokenCost>) -> String {
+    tokens.map_or_else(|| "-".to_string(), |tokens| compact_count(tokens.total))
+}
+
+fn compact_count(count: u64) -> String {
+    match count {
+        0..=999 => count.to_string(),
+        1_000..=999_999 => format!("{:.1}k", count as f64 / 1_000.0),
+        _ => format!("{:.1}M", count as f64 / 1_000_000.0),
+    }
+}
+
+fn agents_label(entry: &ComparisonEntry) -> String {
+    if entry.agents.is_empty() {
+        "-".to_string()
+    } else {
+        entry.agents.join(", ")
+    }
+}
+
+/// Aligned terminal table followed by each branch's summary.
+pub fn render_comparison_table(report: &ComparisonReport) -> String {
+    let rows: Vec<[String; 6]> = report
+        .entries
+        .iter()
+        .map(|entry| {
+            [
+                entry.branch.clone(),
+                agents_label(entry),
+                entry
+                    .commits
+                    .map_or_else(|| "-".to_string(), |n| n.to_string()),
+                diff_label(entry.diffstat.as_ref()),
+                tests_label(entry.tests.as_ref()),
+                tokens_label(entry.tokens.as_ref()),
+            ]
+        })
+        .collect();
+    let header = ["branch", "agent", "commits", "diff", "tests", "tokens"];
+    let widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            rows.iter()
+                .map(|row| row[column].chars().count())
+                .chain([header[column].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let line = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut out = format!(
+        "# agent comparison against {} ({} branches)\n",
+        report.base,
+        report.entries.len()
+    );
+    let _ = writeln!(out, "{}", line(&header.map(str::to_string)));
+    for row in &rows {
+        let _ = writeln!(out, "{}", line(row));
+    }
+    for entry in &report.entries {
+        if let Some(summary) = &entry.summary {
+            let _ = write!(out, "\n{}: {summary}\n", entry.branch);
+        }
+    }
+    out
+}
+
+/// Markdown export: a summary table plus one section per branch.
+pub fn render_comparison_markdown(report: &ComparisonReport) -> String {
+    let cell = |text: &str| text.replace('|', "\\|");
+    let mut out = format!("# Agent comparison against `{}`\n\n", report.base);
+    out.push_str("| Branch | Agent | Commits | Diff | Tests | Tokens |\n");
+    out.push_str("| --- | --- | ---: | --- | --- | ---: |\n");
+    for entry in &report.entries {
+        let _ = writeln!(
+            out,
+            "| `{}` | {} | {} | {} | {} | {} |",
+            entry.branch,
+            cell(&agents_label(entry)),
+            entry
+                .commits
+                .map_or_else(|| "-".to_string(), |n| n.to_string()),
+            diff_label(entry.diffstat.as_ref()),
+            tests_label(entry.tests.as_ref()),
+            tokens_label(entry.tokens.as_ref()),
+        );
+    }
+    for entry in &report.entries {
+        let _ = write!(out, "\n## `{}`\n\n", entry.branch);
+        let mut details = String::new();
+        if let Some(path) = &entry.worktree_path {
+            let _ = writeln!(details, "- Worktree: `{}`", path.display());
+        }
+        if let Some(tokens) = &entry.tokens {
+            let _ = writeln!(
+                details,
+                "- Tokens: {} in / {} out / {} total",
+                tokens.input, tokens.output, tokens.total
+            );
+        }
+        if !details.is_empty() {
+            out.push_str(&details);
+            out.push('\n');
+        }
+        let _ = writeln!(
+            out,
+            "{}",
+            entry
+                .summary
+                .as_deref()
+                .unwrap_or("_No agent summary recorded._")
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(repo: &Path, args: &[&str]) {
+        let output = gwt_core::process::run_git_logged(args, Some(repo)).expect("git");
+        assert!(
+            output.status.success(),
+            "git {args:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn parse_shortstat_reads_each_part() {
+        assert_eq!(
+            parse_shortstat(" 3 files changed, 10 insertions(+), 2 deletions(-)"),
+            DiffStat {
+                files_changed: 3,
+                insertions: 10,
+                deletions: 2
+            }
+        );
+        assert_eq!(
+            parse_shortstat("1 file changed, 1 deletion(-)").deletions,
+            1
+        );
+        assert_eq!(parse_shortstat(""), DiffStat::default());
+    }
+
+    #[test]
+    fn build_comparison_collects_git_tests_sessions_and_tokens() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path();
+        git(repo, &["init", "-q", "-b", "main"]);
+        git(repo, &["config", "user.name", "T"]);
+        git(repo, &["config", "user.email", "t@example.com"]);
+        git(repo, &["commit", "-q", "--allow-empty", "-m", "init"]);
+        git(repo, &["checkout", "-q", "-b", "try/codex"]);
+        std::fs::write(repo.join("a.txt"), "one\ntwo\n").unwrap();
+        git(repo, &["add", "a.txt"]);
+        git(repo, &["commit", "-q", "-m", "codex attempt"]);
+        git(repo, &["checkout", "-q", "main"]);
+        git(repo, &["branch", "try/claude"]);
+
+        let mut codex_session = Session::new(repo, "try/codex", AgentId::Codex);
+        codex_session.agent_session_id = Some("rollout-1".to_string());
+        let targets = vec![
+            MatrixTarget {
+                branch: "try/codex".into(),
+                path: None,
+            },
+            MatrixTarget {
+                branch: "try/claude".into(),
+                path: None,
+            },
+        ];
+        let tests = MatrixReport {
+            command: vec!["cargo".into(), "test".into()],
+            cells: vec![gwt_git::worktree_matrix::MatrixCell {
+                branch: "try/codex".into(),
+                path: None,
+                outcome: MatrixOutcome::Passed,
+                duration_ms: 10,
+                output_tail: Vec::new(),
+            }],
+        };
+
+        let report = build_comparison(
+            repo,
+            "main",
+            &targets,
+            &[codex_session],
+            Some(&tests),
+            |agent, id| {
+                (agent == &AgentId::Codex && id == "rollout-1").then(|| SessionInsight {
+                    tokens: TokenCost {
+                        input: 1_000,
+                        output: 500,
+                        total: 1_500,
+                    },
+                    closing_message: Some("Implemented   retry\nwith backoff".into()),
+                })
+            },
+        );
+
+        let codex = &report.entries[0];
+        assert_eq!(codex.agents, vec!["Codex".to_string()]);
+        assert_eq!(codex.commits, Some(1));
+        assert_eq!(
+            codex.diffstat,
+            Some(DiffStat {
+                files_changed: 1,
+                insertions: 2,
+                deletions: 0
+            })
+        );
+        assert_eq!(codex.tests, Some(MatrixOutcome::Passed));
+        assert_eq!(codex.tokens.map(|t| t.total), Some(1_500));
+        assert_eq!(
+            codex.summary.as_deref(),
+            Some("Implemented retry with backoff")
+        );
+
+        let claude = &report.entries[1];
+        assert_eq!(claude.commits, Some(0));
+        assert_eq!(claude.tests, None);
+        assert_eq!(claude.tokens, None);
+
+        let table = render_comparison_table(&report);
+        assert!(
+            table.contains("try/codex  | Codex | 1       | 1 files +2 -0 | PASS  | 1.5k"),
+            "{table}"
+        );
+        assert!(table.contains("try/codex: Implemented retry with backoff"));
+
+        let markdown = render_comparison_markdown(&report);
+        assert!(
+            markdown.contains("| `try/codex` | Codex | 1 | 1 files +2 -0 | PASS | 1.5k |"),
+            "{markdown}"
+        );
+        assert!(markdown.contains("## `try/claude`\n\n_No agent summary recorded._"));
+    }
+}
@@ -0,0 +1,166 @@
+//! Per-worktree host port remapping for Docker launches.
+//!
+//! Worktrees of one project share a compose file, so their services publish
+//! the same host ports and only one of them can run at a time. Before each
+//! Docker launch the worktree gets its own host port for every published
+//! port, persisted under `[docker] port_assignments` in
+//! `~/.gwt/config.toml`, and ports that had to move are written to a compose
+//! override that the launch includes.
+
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use gwt_config::Settings;
+use gwt_docker::{ComposeService, PortAllocator};
+
+/// Generated override (in the worktree) that republishes remapped ports.
+pub const DOCKER_PORT_OVERRIDE_FILE_NAME: &str = "docker-compose.gwt.ports.yml";
+
+const REMAP_RANGE_START: u16 = 1024;
+const REMAP_RANGE_END: u16 = 65535;
+
+/// Assign host ports for `worktree`'s compose project and write the port
+/// override. Returns the override path to append to the compose files, or
+/// `None` when every port keeps its original number.
+pub fn ensure_docker_port_override(
+    worktree: &Path,
+    compose_files: &[PathBuf],
+) -> Result<Option<PathBuf>, String> {
+    let config_path = Settings::global_config_path()
+        .ok_or_else(|| "Failed to resolve ~/.gwt/config.toml for Docker ports".to_string())?;
+    ensure_docker_port_override_in(&config_path, worktree, compose_files)
+}
+
+fn ensure_docker_port_override_in(
+    config_path: &Path,
+    worktree: &Path,
+    compose_files: &[PathBuf],
+) -> Result<Option<PathBuf>, String> {
+    let services = merged_compose_services(compose_files)?;
+    let mut settings = if config_path.exists() {
+        Settings::load_from_path(config_path).map_err(|err| err.to_string())?
+    } else {
+        Settings::default()
+    };
+
+    let key = worktree.display().to_string();
+    let all = &mut settings.docker.port_assignments;
+    let before = all.clone();
+    all.retain(|path, _| *path == key || Path::new(path).is_dir());
+    let reserved = all
+        .iter()
+        .filter(|(path, _)| **path != key)
+        .flat_map(|(_, ports)| ports.values().copied())
+        .collect::<BTreeSet<_>>();
+    let previous = all.get(&key).cloned().unwrap_or_default();
+    let assigned = PortAllocator::with_range(REMAP_RANGE_START, REMAP_RANGE_END)
+        .assign_compose_ports(&services, &previous, &reserved);
+    if assigned.is_empty() {
+        all.remove(&key);
+    } else {
+        all.insert(key, assigned.clone());
+    }
+    if *all != before {
+        settings
+            .save(config_path)
+            .map_err(|err| format!("Failed to save Docker port assignments: {err}"))?;
+    }
+
+    let override_path = worktree.join(DOCKER_PORT_OVERRIDE_FILE_NAME);
+    let Some(content) = gwt_docker::port_override_content(&services, &assigned) else {
+        if override_path.is_file() {
+            std::fs::remove_file(&override_path)
+                .map_err(|err| format!("Failed to remove {}: {err}", override_path.display()))?;
+        }
+        return Ok(None);
+    };
+    if std::fs::read_to_string(&override_path).ok().as_deref() != Some(content.as_str()) {
+        std::fs::write(&override_path, content).map_err(|err| {
+            format!(
+                "Failed to write Docker port override {}: {err}",
+                override_path.display()
+            )
+        })?;
+    }
+    Ok(Some(override_path))
+}
+
+/// Services across all compose files, with the `ports` of a service that
+/// appears in several files concatenated the way compose merges them.
+fn merged_compose_services(compose_files: &[PathBuf]) -> Result<Vec<ComposeService>, String> {
+    let mut merged: Vec<ComposeService> = Vec::new();
+    for file in compose_files {
+        let services = gwt_docker::parse_compose_file(file).map_err(|err| err.to_string())?;
+        for service in services {
+            match merged.iter_mut().find(|known| known.name == service.name) {
+                Some(known) => {
+                    for port in service.ports {
+                        if !known.ports.contains(&port) {
+                            known.ports.push(port);
+                        }
+                    }
+                }
+                None => merged.push(service),
+            }
+        }
+    }
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+
+    use super::*;
+
+    #[test]
+    fn second_worktree_gets_remapped_ports_that_persist() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let config_path = dir.path().join("config.toml");
+        let busy = TcpListener::bind("127.0.0.1:0").expect("bind");
+        let port = busy.local_addr().expect("addr").port();
+        let compose =
+            format!("services:\n  web:\n    image: nginx\n    ports:\n      - \"{port}:80\"\n");
+        let worktrees = ["a", "b"].map(|name| {
+            let worktree = dir.path().join(name);
+            std::fs::create_dir_all(&worktree).expect("worktree");
+            std::fs::write(worktree.join("compose.yml"), &compose).expect("compose");
+            worktree
+        });
+        let files = |worktree: &Path| vec![worktree.join("compose.yml")];
+
+        // Worktree `a` already holds the port from an earlier assignment.
+        let mut settings = Settings::default();
+        settings.docker.port_assignments.insert(
+            worktrees[0].display().to_string(),
+            [(gwt_docker::port_assignment_key("web", port), port)].into(),
+        );
+        settings.save(&config_path).expect("save");
+
+        let a = ensure_docker_port_override_in(&config_path, &worktrees[0], &files(&worktrees[0]))
+            .expect("worktree a");
+        assert!(a.is_none());
+
+        let b = ensure_docker_port_override_in(&config_path, &worktrees[1], &files(&worktrees[1]))
+            .expect("worktree b")
+            .expect("override for worktree b");
+        let content = std::fs::read_to_string(&b).expect("override");
+        assert!(content.contains("ports: !override"));
+        assert!(!content.contains(&format!("\"{port}:80\"")));
+
+        let saved = Settings::load_from_path(&config_path).expect("load");
+        let assigned_b = saved.docker.port_assignments[&worktrees[1].display().to_string()].clone();
+        let again =
+            ensure_docker_port_override_in(&config_path, &worktrees[1], &files(&worktrees[1]))
+                .expect("worktree b again");
+        assert_eq!(again, Some(b));
+        let saved = Settings::load_from_path(&config_path).expect("reload");
+        assert_eq!(
+            saved.docker.port_assignments[&worktrees[1].display().to_string()],
+            assigned_b
+        );
+        drop(busy);
+    }
+}
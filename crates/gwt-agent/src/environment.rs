@@ -174,13 +174,24 @@ impl LaunchEnvironment {
     pub fn from_active_profile(
         config_path: &Path,
         runtime_target: LaunchRuntimeTarget,
+    ) -> Result<Self, String> {
+        Self::from_project_profile(config_path, None, runtime_target)
+    }
+
+    /// Like [`Self::from_active_profile`], with the shared profiles of the
+    /// repository at `project_root` merged under the user's.
+    pub fn from_project_profile(
+        config_path: &Path,
+        project_root: Option<&Path>,
+        runtime_target: LaunchRuntimeTarget,
     ) -> Result<Self, String> {
         match runtime_target {
             LaunchRuntimeTarget::Host => {
-                Self::from_active_profile_with_base(config_path, host_process_env())
+                Self::from_project_profile_with_base(config_path, project_root, host_process_env())
             }
-            LaunchRuntimeTarget::Docker => Self::from_active_profile_with_base(
+            LaunchRuntimeTarget::Docker => Self::from_project_profile_with_base(
                 config_path,
+                project_root,
                 std::iter::empty::<(String, String)>(),
             ),
         }
@@ -188,6 +199,19 @@ impl LaunchEnvironment {
 
     /// Build from the active profile using an explicit host base environment.
     pub fn from_active_profile_with_base<I>(config_path: &Path, base_env: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        Self::from_project_profile_with_base(config_path, None, base_env)
+    }
+
+    /// Build from the active profile, including the repository's shared
+    /// profiles, using an explicit host base environment.
+    pub fn from_project_profile_with_base<I>(
+        config_path: &Path,
+        project_root: Option<&Path>,
+        base_env: I,
+    ) -> Result<Self, String>
     where
         I: IntoIterator<Item = (String, String)>,
     {
@@ -196,10 +220,26 @@ impl LaunchEnvironment {
         } else {
             gwt_config::Settings::default()
         };
+        if let Some(project_root) = project_root {
+            let shared = gwt_config::load_repo_profiles(project_root);
+            settings.profiles.merge_repo_profiles(&shared.profiles);
+        }
         let active_name = settings.profiles.normalize_active_profile().name;
-        let Some(profile) = settings.profiles.get(&active_name) else {
+        let Some(mut profile) = settings.profiles.get(&active_name).cloned() else {
             return Err(format!("active profile not found: {active_name}"));
         };
+        if !profile.secret_env.is_empty() {
+            let missing = profile
+                .load_secrets(&gwt_config::KeyringSecretStore)
+                .map_err(|error| error.to_string())?;
+            if !missing.is_empty() {
+                tracing::warn!(
+                    profile = %profile.name,
+                    keys = ?missing,
+                    "secret profile variables have no value in the keychain"
+                );
+            }
+        }
 
         let inherited_remove_env = inherited_terminal_color_suppressor_remove_env();
         let profile_remove_env = normalized_remove_env(&profile.disabled_env);
@@ -509,6 +549,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn active_repo_profile_applies_only_with_its_project() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_path = dir.path().join("config.toml");
+        let mut settings = Settings::default();
+        settings.profiles.active = Some("team".to_string());
+        settings.save(&config_path).unwrap();
+        let repo = dir.path().join("repo");
+        let shared_dir = repo.join(gwt_config::PROJECT_PROFILES_DIR);
+        std::fs::create_dir_all(&shared_dir).unwrap();
+        std::fs::write(
+            shared_dir.join("team.toml"),
+            "[env_vars]\nTEAM_ONLY = \"1\"\n",
+        )
+        .unwrap();
+
+        let (env, _) = LaunchEnvironment::from_project_profile_with_base(
+            &config_path,
+            Some(&repo),
+            Vec::new(),
+        )
+        .unwrap()
+        .into_parts();
+        assert_eq!(env.get("TEAM_ONLY").map(String::as_str), Some("1"));
+
+        let (env, _) = LaunchEnvironment::from_active_profile_with_base(&config_path, Vec::new())
+            .unwrap()
+            .into_parts();
+        assert!(!env.contains_key("TEAM_ONLY"));
+    }
+
     #[test]
     fn uses_empty_base_for_docker_runtime() {
         let (_dir, config_path) = write_profile_config(dev_profile());
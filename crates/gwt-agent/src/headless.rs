@@ -0,0 +1,163 @@
+//! Non-interactive agent runs for queued tasks.
+//!
+//! Interactive launches go through [`crate::prepare_agent_launch`] and a
+//! PTY. A queued task instead runs the agent's one-shot mode — `claude
+//! --print`, `codex exec` — with the prompt as an argument, stdin closed and
+//! all output appended to a log file, and reports the exit code.
+//!
+//! [`run_task`] drives one claimed [`gwt_core::task_queue`] record end to
+//! end; the embedded server's queue worker and `gwt run` both use it, under
+//! the [`gwt_core::jobs`] limits from [`job_policy`].
+
+use std::{collections::HashMap, fs::OpenOptions, path::Path, process::Stdio};
+
+use gwt_core::{jobs::JobPolicy, task_queue::TaskClaim};
+
+use crate::types::AgentId;
+
+/// Agents with a one-shot mode, by command name.
+pub const HEADLESS_AGENTS: &[&str] = &["claude", "codex"];
+
+/// Argument vector (command first) that runs `agent` once on `prompt`,
+/// allowed to edit files in its working directory. `None` for agents
+/// without a one-shot mode.
+pub fn headless_command(agent: &AgentId, prompt: &str) -> Option<Vec<String>> {
+    let args: &[&str] = match agent {
+        AgentId::ClaudeCode => &["--print", "--permission-mode", "acceptEdits"],
+        AgentId::Codex => &["exec", "--full-auto"],
+        _ => return None,
+    };
+    let mut command = vec![agent.command().to_string()];
+    command.extend(args.iter().map(|arg| arg.to_string()));
+    command.push(prompt.to_string());
+    Some(command)
+}
+
+/// The agent whose command name is `name`, if it has a one-shot mode.
+pub fn headless_agent(name: &str) -> Option<AgentId> {
+    [AgentId::ClaudeCode, AgentId::Codex]
+        .into_iter()
+        .find(|agent| agent.command() == name)
+}
+
+/// Run `command` in `worktree`, appending stdout and stderr to `log`.
+/// Returns the exit code, or `None` when the process was killed by a
+/// signal.
+pub fn run_headless(
+    worktree: &Path,
+    command: &[String],
+    log: &Path,
+) -> Result<Option<i32>, String> {
+    let (program, args) = command
+        .split_first()
+        .ok_or_else(|| "empty headless command".to_string())?;
+    let stdout = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log)
+        .map_err(|error| format!("open {}: {error}", log.display()))?;
+    let stderr = stdout
+        .try_clone()
+        .map_err(|error| format!("open {}: {error}", log.display()))?;
+    let status = gwt_core::process::hidden_command(program)
+        .args(args)
+        .current_dir(worktree)
+        .stdin(Stdio::null())
+        .stdout(stdout)
+        .stderr(stderr)
+        .status()
+        .map_err(|error| format!("run {program}: {error}"))?;
+    Ok(status.code())
+}
+
+/// Concurrency and retry limits from the `[jobs]` settings.
+pub fn job_policy() -> JobPolicy {
+    let config = gwt_config::Settings::load()
+        .map(|settings| settings.jobs)
+        .unwrap_or_default();
+    JobPolicy {
+        max_parallel: config.max_parallel_agents(),
+        max_attempts: config.max_attempts(),
+        retry_delay: chrono::Duration::from_std(std::time::Duration::from_secs(
+            config.retry_delay_secs(),
+        ))
+        .unwrap_or(chrono::Duration::MAX),
+    }
+}
+
+/// Run a claimed task: reuse the branch worktree or create it the way an
+/// interactive launch would, run the agent's one-shot mode there with
+/// output going to the task log, and record the outcome.
+pub fn run_task(mut claim: TaskClaim) {
+    let request = claim.record().request.clone();
+    let Some(command) =
+        headless_agent(&request.agent).and_then(|agent| headless_command(&agent, &request.prompt))
+    else {
+        claim.finish(
+            None,
+            Some(format!("agent {} has no headless mode", request.agent)),
+        );
+        return;
+    };
+    let mut working_dir = None;
+    if let Err(error) = crate::prepare::resolve_launch_worktree_request(
+        &request.repo_path,
+        Some(&request.branch),
+        None,
+        &mut working_dir,
+        &mut HashMap::new(),
+    ) {
+        claim.finish(None, Some(error));
+        return;
+    }
+    let Some(worktree) = working_dir else {
+        claim.finish(
+            None,
+            Some(format!("no worktree for branch {}", request.branch)),
+        );
+        return;
+    };
+    claim.set_worktree(&worktree);
+    tracing::info!(
+        task = %claim.record().id,
+        branch = %request.branch,
+        agent = %request.agent,
+        "running headless agent task"
+    );
+    match run_headless(&worktree, &command, &claim.log_path()) {
+        Ok(exit_code) => claim.finish(exit_code, None),
+        Err(error) => claim.finish(None, Some(error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn headless_command_uses_one_shot_modes() {
+        assert_eq!(
+            headless_command(&AgentId::Codex, "fix it").unwrap(),
+            vec!["codex", "exec", "--full-auto", "fix it"]
+        );
+        assert_eq!(
+            headless_command(&AgentId::ClaudeCode, "fix it").unwrap()[..2],
+            ["claude", "--print"]
+        );
+        assert!(headless_command(&AgentId::Gemini, "fix it").is_none());
+        assert_eq!(headless_agent("codex"), Some(AgentId::Codex));
+        assert_eq!(headless_agent("gemini"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn run_headless_appends_output_and_reports_exit_code() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let log = dir.path().join("task.log");
+        let command = ["sh", "-c", "echo out; echo err >&2; exit 3"].map(String::from);
+        let code = run_headless(dir.path(), &command, &log).expect("run");
+        assert_eq!(code, Some(3));
+        let output = std::fs::read_to_string(&log).expect("log");
+        assert!(output.contains("out") && output.contains("err"));
+    }
+}
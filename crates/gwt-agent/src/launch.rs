@@ -402,6 +402,11 @@ pub struct LaunchConfig {
     pub codex_fast_mode: bool,
     pub runtime_target: LaunchRuntimeTarget,
     pub docker_service: Option<String>,
+    /// Compose profile passed as `--profile` to `docker compose`.
+    pub docker_profile: Option<String>,
+    /// `[[launch_rules]]` entry (its label) that chose `runtime_target`,
+    /// when the launch kept the rule's choice.
+    pub runtime_rule: Option<String>,
     pub docker_lifecycle_intent: DockerLifecycleIntent,
     pub linked_issue_number: Option<u64>,
     pub windows_shell: Option<crate::WindowsShellKind>,
@@ -473,6 +478,8 @@ pub struct AgentLaunchBuilder {
     extra_args: Vec<String>,
     runtime_target: LaunchRuntimeTarget,
     docker_service: Option<String>,
+    docker_profile: Option<String>,
+    runtime_rule: Option<String>,
     docker_lifecycle_intent: DockerLifecycleIntent,
     linked_issue_number: Option<u64>,
     windows_shell: Option<crate::WindowsShellKind>,
@@ -509,6 +516,8 @@ impl AgentLaunchBuilder {
             extra_args: Vec::new(),
             runtime_target: LaunchRuntimeTarget::Host,
             docker_service: None,
+            docker_profile: None,
+            runtime_rule: None,
             docker_lifecycle_intent: DockerLifecycleIntent::Connect,
             linked_issue_number: None,
             windows_shell: None,
@@ -674,6 +683,16 @@ impl AgentLaunchBuilder {
         self
     }
 
+    pub fn docker_profile(mut self, profile: impl Into<String>) -> Self {
+        self.docker_profile = Some(profile.into());
+        self
+    }
+
+    pub fn runtime_rule(mut self, label: impl Into<String>) -> Self {
+        self.runtime_rule = Some(label.into());
+        self
+    }
+
     pub fn docker_lifecycle_intent(mut self, intent: DockerLifecycleIntent) -> Self {
         self.docker_lifecycle_intent = intent;
         self
@@ -824,6 +843,8 @@ impl AgentLaunchBuilder {
             codex_fast_mode,
             runtime_target: self.runtime_target,
             docker_service: self.docker_service,
+            docker_profile: self.docker_profile,
+            runtime_rule: self.runtime_rule,
             docker_lifecycle_intent: self.docker_lifecycle_intent,
             linked_issue_number: self.linked_issue_number,
             windows_shell: self.windows_shell,
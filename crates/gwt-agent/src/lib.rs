@@ -8,17 +8,25 @@ pub mod audit;
 pub mod backend;
 pub mod backend_store;
 pub mod claude_capabilities;
+pub mod comparison;
 pub mod custom;
 pub mod detect;
+pub mod docker_ports;
 pub mod environment;
+pub mod headless;
 pub mod launch;
 pub mod migration;
 pub mod prepare;
 pub mod presets;
+pub mod project_mcp;
 pub mod session;
+pub mod session_bundle;
+pub mod session_diff;
+pub mod session_export;
 pub mod store;
 pub mod types;
 pub mod version_cache;
+pub mod watchdog;
 
 #[cfg(test)]
 pub(crate) mod test_capture;
@@ -37,6 +45,7 @@ pub use claude_capabilities::{
 };
 pub use custom::CustomCodingAgent;
 pub use detect::{AgentDetector, DetectedAgent};
+pub use docker_ports::{ensure_docker_port_override, DOCKER_PORT_OVERRIDE_FILE_NAME};
 pub use environment::LaunchEnvironment;
 pub use launch::{
     canonical_launch_args, normalize_launch_args, resolve_host_npx_fallback_executable,
@@ -45,8 +54,9 @@ pub use launch::{
 pub use migration::{migrate_legacy_backend_rows, resolve_legacy_backend_remap, MigrationReport};
 pub use prepare::{
     apply_host_package_runner_fallback, apply_host_package_runner_fallback_with_probe,
-    branch_worktree_path, install_launch_gwt_bin_env, install_launch_gwt_bin_env_with_lookup,
-    prepare_agent_launch, register_codex_managed_hook_trust_in_docker, resolve_launch_worktree,
+    branch_worktree_path, copy_configured_worktree_files, install_launch_gwt_bin_env,
+    install_launch_gwt_bin_env_with_lookup, prepare_agent_launch,
+    register_codex_managed_hook_trust_in_docker, resolve_launch_worktree,
     resolve_launch_worktree_request, resolve_public_gwt_bin_with_lookup, HookForwardEnv,
     PreparedAgentLaunch, PreparedProcessLaunch,
 };
@@ -54,12 +64,13 @@ pub use presets::{
     claude_code_openai_compat_preset, list_presets, seed_agent, ClaudeCodeOpenaiCompatInput,
     PresetDefinition, PresetError, PresetId,
 };
+pub use project_mcp::{load_project_mcp_config, ProjectMcpConfig, PROJECT_MCP_CONFIG_RELATIVE};
 pub use session::{
     persist_agent_session_id, persist_session_completed_stop, persist_session_hook_event,
     persist_session_restore_window_on_startup, persist_session_status, reset_runtime_state_dir,
     reset_runtime_state_dir_for_pid, runtime_state_dir_for_pid, runtime_state_path,
     runtime_state_path_for_pid, sessions_dir_from_runtime_path, update_session,
-    AgentSessionHistoryEntry, PendingDiscussionResume, Session, SessionRuntimeState,
+    AgentSessionHistoryEntry, ConvertedFrom, PendingDiscussionResume, Session, SessionRuntimeState,
     GWT_BIN_PATH_ENV, GWT_HOOK_FORWARD_TOKEN_ENV, GWT_HOOK_FORWARD_URL_ENV, GWT_SESSION_ID_ENV,
     GWT_SESSION_RUNTIME_PATH_ENV,
 };
@@ -74,3 +85,4 @@ pub use types::{
     LaunchRuntimeTarget, SessionMode, WindowsShellKind, WorkflowBypass,
 };
 pub use version_cache::{build_version_options, VersionCache, VersionOption};
+pub use watchdog::{StallReason, WatchdogPolicy};
@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     path::{Path, PathBuf},
     process::Stdio,
 };
@@ -60,6 +60,10 @@ struct DockerLaunchPlan {
     service: String,
     container_cwd: String,
     target_arch: String,
+    profiles: Vec<String>,
+    remote_env: BTreeMap<String, String>,
+    post_create_command: Option<Vec<String>>,
+    features: Vec<String>,
 }
 
 impl DockerLaunchPlan {
@@ -93,6 +97,9 @@ struct DevContainerLaunchDefaults {
     service: Option<String>,
     workspace_folder: Option<String>,
     compose_file: Option<PathBuf>,
+    remote_env: BTreeMap<String, String>,
+    post_create_command: Option<Vec<String>>,
+    features: Vec<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -231,6 +238,15 @@ where
             .env_vars
             .insert(GWT_HOOK_FORWARD_TOKEN_ENV.to_string(), target.token);
     }
+    if let Ok(settings) = gwt_config::Settings::load() {
+        apply_commit_trailers(&settings.commit_trailers, &worktree_path, &mut config);
+        if settings.agent.inject_project_mcp {
+            apply_project_mcp(&worktree_path, &mut config);
+        }
+    }
+    if gwt_git::worktree_review::is_review_worktree(&worktree_path) {
+        apply_review_worktree_sandbox(&mut config);
+    }
     config
         .env_vars
         .entry("COLORTERM".to_string())
@@ -260,6 +276,140 @@ where
     })
 }
 
+/// Export the configured commit trailers and make sure the managed
+/// `prepare-commit-msg` block that consumes them is installed. A hook that
+/// cannot be installed only costs the trailers, never the launch.
+fn apply_commit_trailers(
+    trailers: &gwt_config::CommitTrailersConfig,
+    worktree_path: &Path,
+    config: &mut LaunchConfig,
+) {
+    if !trailers.enabled() {
+        return;
+    }
+    let signoff = trailers
+        .signed_off_by
+        .then(|| gwt_git::signoff_trailer(worktree_path))
+        .flatten();
+    let lines = commit_trailer_lines(trailers, &config.agent_id, &config.display_name, signoff);
+    if lines.is_empty() {
+        return;
+    }
+    if let Err(error) = gwt_git::install_commit_trailer_hook(worktree_path) {
+        tracing::warn!(
+            worktree = %worktree_path.display(),
+            error = %error,
+            "commit trailer hook not installed"
+        );
+        return;
+    }
+    config.env_vars.insert(
+        gwt_git::GWT_COMMIT_TRAILERS_ENV.to_string(),
+        lines.join("\n"),
+    );
+}
+
+/// Pass the worktree's `.gwt/mcp.toml` servers to the agent for this
+/// session. A broken config only costs the servers, never the launch.
+fn apply_project_mcp(worktree_path: &Path, config: &mut LaunchConfig) {
+    if !matches!(config.agent_id, AgentId::ClaudeCode | AgentId::Codex) {
+        return;
+    }
+    let project_mcp = match crate::project_mcp::load_project_mcp_config(worktree_path) {
+        Ok(Some(project_mcp)) => project_mcp,
+        Ok(None) => return,
+        Err(error) => {
+            tracing::warn!(error = %error, "project MCP config ignored");
+            return;
+        }
+    };
+    config
+        .args
+        .extend(project_mcp_launch_args(&project_mcp, config, |json| {
+            crate::project_mcp::materialize_claude_mcp_config(
+                &gwt_core::paths::gwt_home().join("tmp"),
+                json,
+            )
+        }));
+}
+
+/// Review worktrees are already read-only on disk; also have the agent's
+/// own sandbox deny writes so it reports the restriction instead of
+/// retrying edits against permission errors.
+fn apply_review_worktree_sandbox(config: &mut LaunchConfig) {
+    match config.agent_id {
+        AgentId::ClaudeCode => config
+            .args
+            .push("--disallowedTools=Edit,MultiEdit,Write,NotebookEdit".to_string()),
+        AgentId::Codex => {
+            // `--yolo` bypasses the sandbox altogether.
+            config.args.retain(|arg| arg != "--yolo");
+            match config.args.iter().position(|arg| arg == "--sandbox") {
+                Some(index) if index + 1 < config.args.len() => {
+                    config.args[index + 1] = "read-only".to_string();
+                }
+                _ => config
+                    .args
+                    .extend(["--sandbox".to_string(), "read-only".to_string()]),
+            }
+        }
+        _ => {}
+    }
+}
+
+fn project_mcp_launch_args(
+    project_mcp: &crate::project_mcp::ProjectMcpConfig,
+    config: &LaunchConfig,
+    materialize: impl FnOnce(&str) -> std::io::Result<PathBuf>,
+) -> Vec<String> {
+    match config.agent_id {
+        AgentId::ClaudeCode => {
+            let json = crate::project_mcp::claude_mcp_config_json(project_mcp);
+            // Same split as `--settings`: host launches get a file path,
+            // Docker launches the inline JSON (the host file is not
+            // mounted in the container). The `=` form keeps the variadic
+            // flag from swallowing anything that follows.
+            let value = if config.runtime_target == LaunchRuntimeTarget::Host {
+                match materialize(&json) {
+                    Ok(path) => path.display().to_string(),
+                    Err(error) => {
+                        tracing::warn!(
+                            error = %error,
+                            "failed to materialize project MCP config; passing inline JSON"
+                        );
+                        json
+                    }
+                }
+            } else {
+                json
+            };
+            vec![format!("--mcp-config={value}")]
+        }
+        AgentId::Codex => crate::project_mcp::codex_mcp_config_overrides(project_mcp),
+        _ => Vec::new(),
+    }
+}
+
+fn commit_trailer_lines(
+    trailers: &gwt_config::CommitTrailersConfig,
+    agent_id: &AgentId,
+    display_name: &str,
+    signoff: Option<String>,
+) -> Vec<String> {
+    let mut lines = Vec::new();
+    if trailers.co_authored_by {
+        let command = agent_id.command();
+        let identity = trailers
+            .co_authors
+            .get(command)
+            .cloned()
+            .unwrap_or_else(|| format!("{display_name} <{command}@agents.gwt.invalid>"));
+        lines.push(format!("{}: {identity}", gwt_git::CO_AUTHORED_BY));
+    }
+    lines.extend(signoff);
+    lines
+}
+
 fn normalize_child_process_path(path: &Path) -> PathBuf {
     gwt_core::paths::normalize_windows_child_process_path(path)
 }
@@ -412,8 +562,14 @@ pub fn resolve_launch_worktree_request(
             .map_err(|err| format!("failed to refresh origin refs after push: {err}"))?;
     }
 
-    let preferred_worktree_path =
-        gwt_git::worktree::sibling_worktree_path(&main_repo_path, &branch_name);
+    let max_path_len = gwt_config::Settings::load()
+        .ok()
+        .and_then(|settings| settings.worktree_max_path_len);
+    let preferred_worktree_path = gwt_git::worktree::sibling_worktree_path_with_max_len(
+        &main_repo_path,
+        &branch_name,
+        max_path_len,
+    );
     let worktree_path = first_available_worktree_path(&preferred_worktree_path, &worktrees)
         .ok_or_else(|| {
             format!("failed to resolve available worktree path for branch {branch_name}")
@@ -427,11 +583,56 @@ pub fn resolve_launch_worktree_request(
             .create_from_remote(&remote_branch_ref, &branch_name, &worktree_path)
             .map_err(|err| err.to_string())?;
     }
+    let copied_files = copy_configured_worktree_files(&main_repo_path, &worktree_path);
+    gwt_core::activity_bus::publish(gwt_core::activity_bus::ActivityEvent::WorktreeCreated {
+        repo_path: Some(main_repo_path.display().to_string()),
+        branch: Some(branch_name.clone()),
+        worktree_path: worktree_path.display().to_string(),
+        copied_files,
+    });
 
     set_worktree_launch_path(working_dir, env_vars, &worktree_path);
     Ok(())
 }
 
+/// Copy the `[worktree_files] copy_on_create` files into `worktree_path`,
+/// just created for the repository at `main_repo_path`. Problems are logged
+/// and never fail the worktree creation. Returns the copied files.
+pub fn copy_configured_worktree_files(main_repo_path: &Path, worktree_path: &Path) -> Vec<String> {
+    let config = gwt_config::Settings::load()
+        .unwrap_or_default()
+        .worktree_files;
+    if config.copy_on_create.is_empty() {
+        return Vec::new();
+    }
+    let source = config.source_dir(main_repo_path);
+    match gwt_git::copy_on_create(&source, worktree_path, &config.copy_on_create) {
+        Ok(outcome) => {
+            if !outcome.invalid_patterns.is_empty() {
+                tracing::warn!(
+                    patterns = ?outcome.invalid_patterns,
+                    "ignored [worktree_files] copy_on_create patterns"
+                );
+            }
+            tracing::info!(
+                worktree = %worktree_path.display(),
+                "{}",
+                outcome.summary()
+            );
+            outcome.copied
+        }
+        Err(error) => {
+            tracing::warn!(
+                worktree = %worktree_path.display(),
+                source = %source.display(),
+                error = %error,
+                "failed to copy [worktree_files] into new worktree"
+            );
+            Vec::new()
+        }
+    }
+}
+
 pub fn apply_host_package_runner_fallback(config: &mut LaunchConfig) -> bool {
     apply_host_package_runner_fallback_with_probe(
         config,
@@ -633,14 +834,30 @@ fn apply_docker_runtime_to_launch_config(
             .clone()
             .unwrap_or_else(|| repo_path.to_path_buf()),
     );
-    let launch = resolve_docker_launch_plan(&worktree, config.docker_service.as_deref())?;
+    let launch = resolve_docker_launch_plan(
+        &worktree,
+        config.docker_service.as_deref(),
+        config.docker_profile.as_deref(),
+    )?;
     ensure_docker_launch_runtime_ready()?;
     let mut launch = launch;
     let compose_override_file =
         ensure_docker_gwt_binary_setup(&worktree, &launch.service, &launch.target_arch)?;
     launch.include_compose_override(compose_override_file);
+    if let Some(port_override_file) =
+        crate::docker_ports::ensure_docker_port_override(&worktree, &launch.compose_files)?
+    {
+        launch.include_compose_override(port_override_file);
+    }
+    warn_unapplied_devcontainer_features(&launch);
     ensure_docker_launch_service_ready(&launch, config.docker_lifecycle_intent)?;
     maybe_inject_docker_sandbox_env(&launch, config)?;
+    for (key, value) in &launch.remote_env {
+        config
+            .env_vars
+            .entry(key.clone())
+            .or_insert_with(|| value.clone());
+    }
     install_launch_gwt_bin_env(&mut config.env_vars, LaunchRuntimeTarget::Docker)?;
     let runtime_program = resolve_docker_exec_program(&launch, config)?;
     config.command = runtime_program.executable;
@@ -658,7 +875,9 @@ pub fn register_codex_managed_hook_trust_in_docker(
     codex_hook_discovery_mode: gwt_skills::CodexHookDiscoveryMode,
 ) -> Result<(), String> {
     let worktree = normalize_child_process_path(worktree);
-    let launch = resolve_docker_launch_plan(&worktree, docker_service)?;
+    // The service's own profiles are always activated, which is all a
+    // one-off exec into it needs.
+    let launch = resolve_docker_launch_plan(&worktree, docker_service, None)?;
     let current_exe = std::env::current_exe().map_err(|err| format!("current_exe: {err}"))?;
     let host_gwt_bin = resolve_generated_hook_gwt_bin_with_lookup(&current_exe, |command| {
         which::which(command).ok()
@@ -673,6 +892,7 @@ pub fn register_codex_managed_hook_trust_in_docker(
     );
     let output = gwt_docker::compose_service_exec_capture_with_files(
         &launch.compose_files,
+        &launch.profiles,
         &launch.service,
         Some(&launch.container_cwd),
         &args,
@@ -734,7 +954,11 @@ fn finalize_docker_agent_launch_config(
             .clone()
             .unwrap_or_else(|| repo_path.to_path_buf()),
     );
-    let launch = resolve_docker_launch_plan(&worktree, config.docker_service.as_deref())?;
+    let launch = resolve_docker_launch_plan(
+        &worktree,
+        config.docker_service.as_deref(),
+        config.docker_profile.as_deref(),
+    )?;
     let runtime_program = PackageRunnerProgram {
         executable: config.command.clone(),
         args: config.args.clone(),
@@ -1071,14 +1295,17 @@ fn maybe_inject_docker_sandbox_env(
         return Ok(());
     }
 
-    let is_root =
-        gwt_docker::compose_service_user_is_root_with_files(&launch.compose_files, &launch.service)
-            .map_err(|err| {
-                format!(
-                    "Failed to determine Docker user for service '{}': {err}",
-                    launch.service
-                )
-            })?;
+    let is_root = gwt_docker::compose_service_user_is_root_with_files(
+        &launch.compose_files,
+        &launch.profiles,
+        &launch.service,
+    )
+    .map_err(|err| {
+        format!(
+            "Failed to determine Docker user for service '{}': {err}",
+            launch.service
+        )
+    })?;
     if is_root {
         config
             .env_vars
@@ -1168,6 +1395,7 @@ fn resolve_docker_package_runner(
     for candidate in candidates {
         let output = gwt_docker::compose_service_exec_capture_with_files(
             &launch.compose_files,
+            &launch.profiles,
             &launch.service,
             Some(&launch.container_cwd),
             &candidate.probe_args(),
@@ -1202,6 +1430,7 @@ fn ensure_docker_launch_command_ready(
 ) -> Result<(), String> {
     let available = gwt_docker::compose_service_has_command_with_files(
         &launch.compose_files,
+        &launch.profiles,
         &launch.service,
         command,
     )
@@ -1238,25 +1467,87 @@ fn ensure_docker_launch_service_ready(
     launch: &DockerLaunchPlan,
     intent: DockerLifecycleIntent,
 ) -> Result<(), String> {
-    let status =
-        gwt_docker::compose_service_status_with_files(&launch.compose_files, &launch.service)
-            .map_err(|err| err.to_string())?;
-    match normalize_docker_launch_action(intent, status) {
-        DockerLaunchServiceAction::Connect => Ok(()),
+    let status = gwt_docker::compose_service_status_with_files(
+        &launch.compose_files,
+        &launch.profiles,
+        &launch.service,
+    )
+    .map_err(|err| err.to_string())?;
+    let action = normalize_docker_launch_action(intent, status);
+    match action {
+        DockerLaunchServiceAction::Connect => return Ok(()),
         DockerLaunchServiceAction::Start => {
-            gwt_docker::compose_up_with_files(&launch.compose_files, &launch.service)
-                .map_err(|err| err.to_string())?;
-            Ok(())
+            gwt_docker::compose_up_with_files(
+                &launch.compose_files,
+                &launch.profiles,
+                &launch.service,
+            )
+            .map_err(|err| err.to_string())?;
         }
         DockerLaunchServiceAction::Restart => {
-            gwt_docker::compose_restart_with_files(&launch.compose_files, &launch.service)
-                .map_err(|err| err.to_string())
+            gwt_docker::compose_restart_with_files(
+                &launch.compose_files,
+                &launch.profiles,
+                &launch.service,
+            )
+            .map_err(|err| err.to_string())?;
         }
         DockerLaunchServiceAction::Recreate => {
-            gwt_docker::compose_up_force_recreate_with_files(&launch.compose_files, &launch.service)
-                .map_err(|err| err.to_string())
+            gwt_docker::compose_up_force_recreate_with_files(
+                &launch.compose_files,
+                &launch.profiles,
+                &launch.service,
+            )
+            .map_err(|err| err.to_string())?;
         }
     }
+    if docker_launch_creates_container(action, status) {
+        run_devcontainer_post_create(launch)?;
+    }
+    Ok(())
+}
+
+/// Whether `action` leaves a freshly created container, which is when the
+/// devcontainer `postCreateCommand` runs.
+fn docker_launch_creates_container(
+    action: DockerLaunchServiceAction,
+    status: gwt_docker::ComposeServiceStatus,
+) -> bool {
+    match action {
+        DockerLaunchServiceAction::Recreate => true,
+        DockerLaunchServiceAction::Start => status == gwt_docker::ComposeServiceStatus::NotFound,
+        DockerLaunchServiceAction::Connect | DockerLaunchServiceAction::Restart => false,
+    }
+}
+
+fn run_devcontainer_post_create(launch: &DockerLaunchPlan) -> Result<(), String> {
+    let Some(args) = launch.post_create_command.as_deref() else {
+        return Ok(());
+    };
+    eprintln!(
+        "Running devcontainer postCreateCommand in service '{}'",
+        launch.service
+    );
+    gwt_docker::compose_service_run_lifecycle_command_with_files(
+        &launch.compose_files,
+        &launch.profiles,
+        &launch.service,
+        Some(&launch.container_cwd),
+        args,
+    )
+    .map_err(|err| format!("devcontainer postCreateCommand failed: {err}"))
+}
+
+fn warn_unapplied_devcontainer_features(launch: &DockerLaunchPlan) {
+    if launch.features.is_empty() {
+        return;
+    }
+    tracing::warn!(
+        category = "docker",
+        service = %launch.service,
+        features = %launch.features.join(", "),
+        "devcontainer features are not installed by gwt; prebuild the image with `devcontainer build`"
+    );
 }
 
 fn normalize_docker_launch_action(
@@ -1286,6 +1577,7 @@ fn normalize_docker_launch_action(
 fn resolve_docker_launch_plan(
     worktree: &Path,
     selected_service: Option<&str>,
+    selected_profile: Option<&str>,
 ) -> Result<DockerLaunchPlan, String> {
     let files = gwt_docker::detect_docker_files(worktree);
     let compose_file = docker_compose_file_for_launch(worktree, &files)?.ok_or_else(|| {
@@ -1335,14 +1627,47 @@ fn resolve_docker_launch_plan(
             )
         })?;
 
+    let profiles = docker_launch_profiles(&services, service, selected_profile)?;
+    let devcontainer_defaults = devcontainer_defaults.unwrap_or_default();
     Ok(DockerLaunchPlan {
         compose_files: docker_launch_compose_files(worktree, &compose_file),
         service: service.name.clone(),
         container_cwd,
         target_arch: docker_bundle_target_arch(service)?,
+        profiles,
+        remote_env: devcontainer_defaults.remote_env,
+        post_create_command: devcontainer_defaults.post_create_command,
+        features: devcontainer_defaults.features,
     })
 }
 
+/// Compose profiles to activate: the selected one plus any the service itself
+/// belongs to, so `ps`/`exec` see the service even without a selection.
+fn docker_launch_profiles(
+    services: &[gwt_docker::ComposeService],
+    service: &gwt_docker::ComposeService,
+    selected_profile: Option<&str>,
+) -> Result<Vec<String>, String> {
+    let mut profiles = Vec::new();
+    if let Some(profile) = selected_profile.filter(|profile| !profile.is_empty()) {
+        if !gwt_docker::compose_profiles(services)
+            .iter()
+            .any(|known| known == profile)
+        {
+            return Err(format!(
+                "Selected Docker profile was not found in compose file: {profile}"
+            ));
+        }
+        profiles.push(profile.to_string());
+    }
+    for profile in &service.profiles {
+        if !profiles.contains(profile) {
+            profiles.push(profile.clone());
+        }
+    }
+    Ok(profiles)
+}
+
 fn docker_binary_for_launch() -> String {
     std::env::var("GWT_DOCKER_BIN").unwrap_or_else(|_| "docker".to_string())
 }
@@ -1403,10 +1728,10 @@ fn docker_launch_compose_files(worktree: &Path, compose_file: &Path) -> Vec<Path
 
 fn docker_compose_command_prefix(launch: &DockerLaunchPlan) -> Vec<String> {
     let mut args = vec!["compose".to_string()];
-    for compose_file in &launch.compose_files {
-        args.push("-f".to_string());
-        args.push(compose_file.display().to_string());
-    }
+    args.extend(gwt_docker::compose_project_args(
+        &launch.compose_files,
+        &launch.profiles,
+    ));
     args
 }
 
@@ -1430,16 +1755,23 @@ fn docker_devcontainer_defaults(
     }
 
     let config = gwt_docker::DevContainerConfig::load(&path).ok()?;
+    let generated_compose = config
+        .write_generated_compose(devcontainer_dir, project_root)
+        .unwrap_or_else(|err| {
+            tracing::warn!(category = "docker", error = %err, "devcontainer compose generation failed");
+            None
+        });
+    let service = config.service.clone().or_else(|| {
+        generated_compose
+            .as_ref()
+            .map(|_| gwt_docker::DEVCONTAINER_SERVICE_NAME.to_string())
+    });
+    let workspace_folder = config.container_workspace_folder(project_root);
     let compose_file = config
-        .docker_compose_file
-        .as_ref()
-        .and_then(|value| {
-            value
-                .to_vec()
-                .into_iter()
-                .map(|candidate| devcontainer_dir.join(candidate))
-                .find(|path| path.is_file())
-        })
+        .compose_files(devcontainer_dir)
+        .into_iter()
+        .next()
+        .or(generated_compose)
         .or_else(|| files.compose_file.clone())
         .or_else(|| {
             let fallback = project_root.join("docker-compose.yml");
@@ -1447,8 +1779,11 @@ fn docker_devcontainer_defaults(
         });
 
     Some(DevContainerLaunchDefaults {
-        service: config.service,
-        workspace_folder: config.workspace_folder,
+        service,
+        remote_env: config.remote_env_vars(project_root, workspace_folder.as_deref()),
+        post_create_command: config.post_create_args(),
+        features: config.feature_ids(),
+        workspace_folder,
         compose_file,
     })
 }
@@ -2149,6 +2484,103 @@ mod tests {
         assert!(config.args.contains(&"--no-alt-screen".to_string()));
     }
 
+    #[test]
+    fn docker_launch_plan_honors_image_based_devcontainer() {
+        let temp = tempdir().expect("tempdir");
+        let project = temp.path().join("project");
+        let devcontainer_dir = project.join(".devcontainer");
+        fs::create_dir_all(&devcontainer_dir).expect("create devcontainer dir");
+        fs::write(
+            project.join("docker-compose.yml"),
+            "services:\n  db:\n    image: postgres:16\n",
+        )
+        .expect("write unrelated compose file");
+        fs::write(
+            devcontainer_dir.join("devcontainer.json"),
+            r#"{
+  "image": "node:20",
+  "features": { "ghcr.io/devcontainers/features/github-cli:1": {} },
+  "postCreateCommand": "npm ci",
+  "remoteEnv": { "APP_ROOT": "${containerWorkspaceFolder}" }
+}"#,
+        )
+        .expect("write devcontainer");
+
+        let plan = resolve_docker_launch_plan(&project, None, None).expect("launch plan");
+        assert_eq!(plan.service, gwt_docker::DEVCONTAINER_SERVICE_NAME);
+        assert_eq!(plan.container_cwd, "/workspaces/project");
+        assert_eq!(
+            plan.compose_files[0],
+            project.join(gwt_docker::DEVCONTAINER_GENERATED_COMPOSE_FILE_NAME)
+        );
+        assert_eq!(
+            plan.remote_env.get("APP_ROOT").map(String::as_str),
+            Some("/workspaces/project")
+        );
+        assert_eq!(
+            plan.post_create_command,
+            Some(vec![
+                "/bin/sh".to_string(),
+                "-c".to_string(),
+                "npm ci".to_string()
+            ])
+        );
+        assert_eq!(
+            plan.features,
+            vec!["ghcr.io/devcontainers/features/github-cli:1".to_string()]
+        );
+    }
+
+    #[test]
+    fn docker_launch_plan_passes_compose_profiles() {
+        let temp = tempdir().expect("tempdir");
+        let project = temp.path().join("project");
+        fs::create_dir_all(&project).expect("create project");
+        fs::write(
+            project.join("docker-compose.yml"),
+            "services:\n  app:\n    image: alpine:3.19\n    working_dir: /workspace\n  tools:\n    image: alpine:3.19\n    working_dir: /workspace\n    profiles: [\"tools\"]\n  db:\n    image: postgres:16\n    profiles: [\"with-db\"]\n",
+        )
+        .expect("write compose file");
+
+        let plan = resolve_docker_launch_plan(&project, Some("app"), Some("with-db"))
+            .expect("plan with profile");
+        assert_eq!(plan.profiles, vec!["with-db".to_string()]);
+        let prefix = docker_compose_command_prefix(&plan);
+        assert!(prefix
+            .windows(2)
+            .any(|pair| pair[0] == "--profile" && pair[1] == "with-db"));
+
+        let plan =
+            resolve_docker_launch_plan(&project, Some("tools"), None).expect("profiled service");
+        assert_eq!(plan.profiles, vec!["tools".to_string()]);
+
+        let err = resolve_docker_launch_plan(&project, Some("app"), Some("missing"))
+            .expect_err("unknown profile");
+        assert!(err.contains("missing"));
+    }
+
+    #[test]
+    fn docker_post_create_runs_only_for_new_containers() {
+        use gwt_docker::ComposeServiceStatus;
+
+        assert!(docker_launch_creates_container(
+            DockerLaunchServiceAction::Start,
+            ComposeServiceStatus::NotFound
+        ));
+        assert!(docker_launch_creates_container(
+            DockerLaunchServiceAction::Recreate,
+            ComposeServiceStatus::Running
+        ));
+        assert!(!docker_launch_creates_container(
+            DockerLaunchServiceAction::Start,
+            ComposeServiceStatus::Stopped
+        ));
+        assert!(!docker_launch_creates_container(
+            DockerLaunchServiceAction::Connect,
+            ComposeServiceStatus::Running
+        ));
+    }
+
     #[test]
     fn docker_codex_hook_trust_registration_uses_container_home_and_host_fallback() {
         let args = docker_codex_hook_trust_registration_args(
@@ -2653,4 +3085,88 @@ mod tests {
         assert!(entries.contains(&PathBuf::from("/usr/bin")));
         assert!(entries.contains(&PathBuf::from("/bin")));
     }
+
+    #[test]
+    fn commit_trailer_lines_use_overrides_and_signoff() {
+        let mut trailers = gwt_config::CommitTrailersConfig {
+            co_authored_by: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            commit_trailer_lines(&trailers, &AgentId::Codex, "Codex", None),
+            vec!["Co-authored-by: Codex <codex@agents.gwt.invalid>".to_string()]
+        );
+
+        trailers
+            .co_authors
+            .insert("codex".to_string(), "Bot <bot@example.com>".to_string());
+        trailers.signed_off_by = true;
+        assert_eq!(
+            commit_trailer_lines(
+                &trailers,
+                &AgentId::Codex,
+                "Codex",
+                Some("Signed-off-by: Dev <dev@example.com>".to_string()),
+            ),
+            vec![
+                "Co-authored-by: Bot <bot@example.com>".to_string(),
+                "Signed-off-by: Dev <dev@example.com>".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn project_mcp_launch_args_follow_agent_and_runtime() {
+        let project_mcp = crate::project_mcp::parse_project_mcp_config(
+            "[servers.docs]\nurl = \"https://docs.example.com/mcp\"\n",
+        )
+        .unwrap();
+        let file = |_: &str| Ok(PathBuf::from("/tmp/claude-mcp.json"));
+
+        let mut claude = AgentLaunchBuilder::new(AgentId::ClaudeCode).build();
+        assert_eq!(
+            project_mcp_launch_args(&project_mcp, &claude, file),
+            vec!["--mcp-config=/tmp/claude-mcp.json".to_string()]
+        );
+        claude.runtime_target = LaunchRuntimeTarget::Docker;
+        let docker = project_mcp_launch_args(&project_mcp, &claude, file);
+        assert!(docker[0].starts_with("--mcp-config={\"mcpServers\""));
+
+        let codex = AgentLaunchBuilder::new(AgentId::Codex).build();
+        assert_eq!(
+            project_mcp_launch_args(&project_mcp, &codex, file),
+            vec![
+                "-c".to_string(),
+                r#"mcp_servers.docs.url="https://docs.example.com/mcp""#.to_string(),
+            ]
+        );
+        let gemini = AgentLaunchBuilder::new(AgentId::Gemini).build();
+        assert!(project_mcp_launch_args(&project_mcp, &gemini, file).is_empty());
+    }
+
+    #[test]
+    fn review_worktree_sandbox_denies_agent_writes() {
+        let mut codex = AgentLaunchBuilder::new(AgentId::Codex)
+            .skip_permissions(true)
+            .build();
+        apply_review_worktree_sandbox(&mut codex);
+        assert!(!codex.args.contains(&"--yolo".to_string()));
+        let sandbox = codex
+            .args
+            .iter()
+            .position(|arg| arg == "--sandbox")
+            .unwrap();
+        assert_eq!(codex.args[sandbox + 1], "read-only");
+        assert_eq!(
+            codex.args.iter().filter(|arg| *arg == "--sandbox").count(),
+            1
+        );
+
+        let mut claude = AgentLaunchBuilder::new(AgentId::ClaudeCode).build();
+        apply_review_worktree_sandbox(&mut claude);
+        assert_eq!(
+            claude.args.last().map(String::as_str),
+            Some("--disallowedTools=Edit,MultiEdit,Write,NotebookEdit")
+        );
+    }
 }
@@ -0,0 +1,266 @@
+//! Repo-scoped MCP servers from `.gwt/mcp.toml`.
+//!
+//! A project can declare the MCP servers its agents should have:
+//!
+//! ```toml
+//! [servers.playwright]
+//! command = "npx"
+//! args = ["@playwright/mcp@latest"]
+//! env = { DEBUG = "pw:mcp" }
+//!
+//! [servers.docs]
+//! url = "https://docs.example.com/mcp"
+//! ```
+//!
+//! With `[agent] inject_project_mcp = true`, launches hand these servers to
+//! the agent for that session only: Claude Code through `--mcp-config`,
+//! Codex through `-c mcp_servers.<name>.*` overrides. Neither agent's own
+//! config files are touched, so there is nothing to restore afterwards and
+//! concurrent sessions cannot clobber each other's edits.
+
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap},
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+/// Location of the project MCP config, relative to the worktree root.
+pub const PROJECT_MCP_CONFIG_RELATIVE: &str = ".gwt/mcp.toml";
+
+/// Parsed `.gwt/mcp.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectMcpConfig {
+    /// Servers keyed by the name the agent shows them under.
+    pub servers: BTreeMap<String, McpServer>,
+}
+
+/// One MCP server: either a local `command` (stdio) or a remote `url`
+/// (streamable HTTP).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct McpServer {
+    pub command: Option<String>,
+    pub args: Vec<String>,
+    pub env: BTreeMap<String, String>,
+    pub url: Option<String>,
+    /// HTTP headers sent to a `url` server.
+    pub headers: BTreeMap<String, String>,
+}
+
+/// Load `.gwt/mcp.toml` from `worktree`. `Ok(None)` when the file does not
+/// exist or declares no servers.
+pub fn load_project_mcp_config(worktree: &Path) -> Result<Option<ProjectMcpConfig>, String> {
+    let path = worktree.join(PROJECT_MCP_CONFIG_RELATIVE);
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(format!("{}: {error}", path.display())),
+    };
+    let config = parse_project_mcp_config(&text).map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok((!config.servers.is_empty()).then_some(config))
+}
+
+/// Parse and validate the contents of `.gwt/mcp.toml`.
+pub fn parse_project_mcp_config(text: &str) -> Result<ProjectMcpConfig, String> {
+    let config: ProjectMcpConfig = toml::from_str(text).map_err(|e| e.to_string())?;
+    for (name, server) in &config.servers {
+        // Names become Codex config keys, so keep them to bare-key characters.
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(format!(
+                "server name {name:?} may only contain letters, digits, '-' and '_'"
+            ));
+        }
+        match (&server.command, &server.url) {
+            (Some(_), None) | (None, Some(_)) => {}
+            _ => {
+                return Err(format!(
+                    "server {name} needs exactly one of `command` or `url`"
+                ))
+            }
+        }
+    }
+    Ok(config)
+}
+
+/// `{"mcpServers": {...}}` in the shape Claude Code's `--mcp-config` reads.
+pub fn claude_mcp_config_json(config: &ProjectMcpConfig) -> String {
+    let mut servers = Map::new();
+    for (name, server) in &config.servers {
+        let entry = match (&server.command, &server.url) {
+            (Some(command), _) => json!({
+                "command": command,
+                "args": server.args,
+                "env": server.env,
+            }),
+            (None, Some(url)) => json!({
+                "type": "http",
+                "url": url,
+                "headers": server.headers,
+            }),
+            (None, None) => continue,
+        };
+        servers.insert(name.clone(), entry);
+    }
+    json!({ "mcpServers": Value::Object(servers) }).to_string()
+}
+
+/// Codex `-c key=value` pairs (flag included) declaring each server under
+/// `mcp_servers.<name>`. Values are TOML literals, as `-c` expects.
+pub fn codex_mcp_config_overrides(config: &ProjectMcpConfig) -> Vec<String> {
+    let mut overrides = Vec::new();
+    let mut push = |key: String, value: toml::Value| {
+        overrides.push("-c".to_string());
+        overrides.push(format!("{key}={value}"));
+    };
+    for (name, server) in &config.servers {
+        let prefix = format!("mcp_servers.{name}");
+        if let Some(command) = &server.command {
+            push(
+                format!("{prefix}.command"),
+                toml::Value::String(command.clone()),
+            );
+            if !server.args.is_empty() {
+                push(
+                    format!("{prefix}.args"),
+                    toml::Value::Array(
+                        server
+                            .args
+                            .iter()
+                            .cloned()
+                            .map(toml::Value::String)
+                            .collect(),
+                    ),
+                );
+            }
+            for (key, value) in &server.env {
+                push(
+                    format!("{prefix}.env.{}", toml_key(key)),
+                    toml::Value::String(value.clone()),
+                );
+            }
+        } else if let Some(url) = &server.url {
+            push(format!("{prefix}.url"), toml::Value::String(url.clone()));
+            for (key, value) in &server.headers {
+                push(
+                    format!("{prefix}.http_headers.{}", toml_key(key)),
+                    toml::Value::String(value.clone()),
+                );
+            }
+        }
+    }
+    overrides
+}
+
+/// Quote a key segment unless it is a TOML bare key.
+fn toml_key(key: &str) -> String {
+    if !key.is_empty()
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        key.to_string()
+    } else {
+        toml::Value::String(key.to_string()).to_string()
+    }
+}
+
+/// Write the Claude MCP config JSON under `dir` and return its path. The
+/// file name is keyed by the content, so launches of the same project share
+/// one file and the directory does not grow per launch.
+pub fn materialize_claude_mcp_config(dir: &Path, json: &str) -> std::io::Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let mut hasher = DefaultHasher::new();
+    json.hash(&mut hasher);
+    let path = dir.join(format!("claude-mcp-{:016x}.json", hasher.finish()));
+    std::fs::write(&path, json)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+[servers.playwright]
+command = "npx"
+args = ["@playwright/mcp@latest"]
+env = { DEBUG = "pw:mcp" }
+
+[servers.docs]
+url = "https://docs.example.com/mcp"
+headers = { "X-Api-Key" = "k" }
+"#;
+
+    #[test]
+    fn renders_claude_json_and_codex_overrides() {
+        let config = parse_project_mcp_config(SAMPLE).unwrap();
+
+        let claude: Value = serde_json::from_str(&claude_mcp_config_json(&config)).unwrap();
+        assert_eq!(
+            claude["mcpServers"]["playwright"],
+            json!({
+                "command": "npx",
+                "args": ["@playwright/mcp@latest"],
+                "env": { "DEBUG": "pw:mcp" },
+            })
+        );
+        assert_eq!(claude["mcpServers"]["docs"]["type"], "http");
+
+        assert_eq!(
+            codex_mcp_config_overrides(&config),
+            vec![
+                "-c",
+                r#"mcp_servers.docs.url="https://docs.example.com/mcp""#,
+                "-c",
+                r#"mcp_servers.docs.http_headers.X-Api-Key="k""#,
+                "-c",
+                r#"mcp_servers.playwright.command="npx""#,
+                "-c",
+                r#"mcp_servers.playwright.args=["@playwright/mcp@latest"]"#,
+                "-c",
+                r#"mcp_servers.playwright.env.DEBUG="pw:mcp""#,
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_ambiguous_servers_and_bad_names() {
+        let both = "[servers.x]\ncommand = \"a\"\nurl = \"http://b\"\n";
+        assert!(parse_project_mcp_config(both)
+            .unwrap_err()
+            .contains("exactly one"));
+        assert!(parse_project_mcp_config("[servers.x]\n").is_err());
+        let bad_name = "[servers.\"a.b\"]\ncommand = \"a\"\n";
+        assert!(parse_project_mcp_config(bad_name)
+            .unwrap_err()
+            .contains("may only contain"));
+    }
+
+    #[test]
+    fn load_skips_missing_or_empty_config() {
+        let temp = tempfile::tempdir().unwrap();
+        assert_eq!(load_project_mcp_config(temp.path()).unwrap(), None);
+        std::fs::create_dir_all(temp.path().join(".gwt")).unwrap();
+        std::fs::write(temp.path().join(PROJECT_MCP_CONFIG_RELATIVE), "").unwrap();
+        assert_eq!(load_project_mcp_config(temp.path()).unwrap(), None);
+        std::fs::write(temp.path().join(PROJECT_MCP_CONFIG_RELATIVE), SAMPLE).unwrap();
+        let config = load_project_mcp_config(temp.path()).unwrap().unwrap();
+        assert_eq!(config.servers.len(), 2);
+
+        let json = claude_mcp_config_json(&config);
+        let path = materialize_claude_mcp_config(temp.path(), &json).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), json);
+        assert_eq!(
+            materialize_claude_mcp_config(temp.path(), &json).unwrap(),
+            path
+        );
+    }
+}
@@ -93,6 +93,11 @@ pub struct Session {
     pub runtime_target: LaunchRuntimeTarget,
     #[serde(default)]
     pub docker_service: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub docker_profile: Option<String>,
+    /// `[[launch_rules]]` entry that chose `runtime_target`, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime_rule: Option<String>,
     #[serde(default)]
     pub docker_lifecycle_intent: DockerLifecycleIntent,
     #[serde(default)]
@@ -133,9 +138,29 @@ pub struct Session {
     pub last_hook_event_at: Option<DateTime<Utc>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub last_completed_stop_at: Option<DateTime<Utc>>,
+    /// Process id of an agent started outside gwt and adopted afterwards.
+    /// gwt has no PTY for such a session: it is never resumed on startup and
+    /// is marked stopped once that process exits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adopted_pid: Option<u32>,
+    /// Set when this session was started from a conversation converted from
+    /// another agent's session.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub converted_from: Option<ConvertedFrom>,
     pub display_name: String,
 }
 
+/// Provenance of a session seeded with a converted conversation.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConvertedFrom {
+    pub agent_id: AgentId,
+    pub agent_session_id: String,
+    /// Converted history handed to the new agent.
+    pub history_path: PathBuf,
+    /// What the conversion dropped, as shown before launching.
+    pub loss: String,
+}
+
 /// Lightweight runtime state updated by hook events while the PTY is alive.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct PendingDiscussionResume {
@@ -193,6 +218,8 @@ impl Session {
             codex_fast_mode: false,
             runtime_target: LaunchRuntimeTarget::Host,
             docker_service: None,
+            docker_profile: None,
+            runtime_rule: None,
             docker_lifecycle_intent: DockerLifecycleIntent::Connect,
             linked_issue_number: None,
             workflow_bypass: None,
@@ -209,6 +236,8 @@ impl Session {
             last_hook_event: None,
             last_hook_event_at: None,
             last_completed_stop_at: None,
+            adopted_pid: None,
+            converted_from: None,
             display_name,
         }
     }
@@ -234,6 +263,8 @@ impl Session {
         session.codex_fast_mode = config.codex_fast_mode;
         session.runtime_target = config.runtime_target;
         session.docker_service = config.docker_service.clone();
+        session.docker_profile = config.docker_profile.clone();
+        session.runtime_rule = config.runtime_rule.clone();
         session.docker_lifecycle_intent = config.docker_lifecycle_intent;
         session.linked_issue_number = config.linked_issue_number;
         session.launch_command = config.command.clone();
@@ -306,6 +337,7 @@ impl Session {
                 | AgentStatus::Idle
                 | AgentStatus::WaitingInput
                 | AgentStatus::Interrupted
+                | AgentStatus::Stalled
         ) && self.has_lifecycle_recovery_evidence()
             && self.worktree_path.exists()
             && self.has_exact_resume_session_id()
@@ -963,6 +995,8 @@ display_name = "Claude Code"
         session.codex_fast_mode = true;
         session.runtime_target = LaunchRuntimeTarget::Docker;
         session.docker_service = Some("web".into());
+        session.docker_profile = Some("debug".into());
+        session.runtime_rule = Some("changed services/ → docker".into());
         session.docker_lifecycle_intent = DockerLifecycleIntent::Restart;
         session.workflow_bypass = Some(WorkflowBypass::Release);
         session.launch_command = "codex".into();
@@ -990,6 +1024,11 @@ display_name = "Claude Code"
         assert!(loaded.codex_fast_mode);
         assert_eq!(loaded.runtime_target, LaunchRuntimeTarget::Docker);
         assert_eq!(loaded.docker_service, Some("web".into()));
+        assert_eq!(loaded.docker_profile, Some("debug".into()));
+        assert_eq!(
+            loaded.runtime_rule.as_deref(),
+            Some("changed services/ → docker")
+        );
         assert_eq!(
             loaded.docker_lifecycle_intent,
             DockerLifecycleIntent::Restart
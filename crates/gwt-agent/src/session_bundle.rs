@@ -0,0 +1,431 @@
+//! Portable session bundles for bug reports.
+//!
+//! A bundle packs what a maintainer needs to reproduce an agent launch on
+//! one branch: the transcripts ([`SessionExport`]), how each agent was
+//! launched, the gwt version, platform, agent-related environment and git
+//! metadata. It is written as gzip-compressed JSON so it can be attached to
+//! an issue as a single file.
+//!
+//! Bundles are anonymized before they are written: the repository root and
+//! home directory are replaced with placeholders and every value that looks
+//! like a secret goes through the [`SecretRedactor`].
+
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{self, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use chrono::{DateTime, Utc};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use gwt_core::logging::redact::SecretRedactor;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    session::Session,
+    session_export::SessionExport,
+    types::{AgentId, LaunchRuntimeTarget, SessionMode},
+};
+
+/// Bumped when the bundle layout changes incompatibly.
+pub const SESSION_BUNDLE_SCHEMA_VERSION: u32 = 1;
+
+/// Placeholder for the repository root in anonymized bundles.
+pub const REPO_PLACEHOLDER: &str = "<repo>";
+
+/// Placeholder for the home directory in anonymized bundles.
+pub const HOME_PLACEHOLDER: &str = "~";
+
+/// Environment variables copied into a bundle. Entries ending in `_` are
+/// prefixes.
+const BUNDLED_ENV_VARS: &[&str] = &[
+    "SHELL",
+    "TERM",
+    "TERM_PROGRAM",
+    "LANG",
+    "GWT_",
+    "CLAUDE_",
+    "ANTHROPIC_",
+    "CODEX_",
+    "OPENAI_",
+    "GEMINI_",
+    "GOOGLE_",
+];
+
+/// Git state of the bundled branch.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleGitInfo {
+    pub branch: String,
+    /// Commit the branch pointed at when the bundle was created.
+    pub head: Option<String>,
+    /// Output of `git --version`.
+    pub git_version: Option<String>,
+    /// Uncommitted paths in the branch worktree.
+    pub dirty_paths: Vec<String>,
+}
+
+/// How one gwt session launched its agent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundledLaunch {
+    pub agent_id: AgentId,
+    pub agent: String,
+    pub tool_version: Option<String>,
+    pub model: Option<String>,
+    pub reasoning_level: Option<String>,
+    pub session_mode: SessionMode,
+    pub skip_permissions: bool,
+    pub fast_mode: bool,
+    pub runtime_target: LaunchRuntimeTarget,
+    pub docker_service: Option<String>,
+    pub launch_command: String,
+    pub launch_args: Vec<String>,
+    pub started_at: DateTime<Utc>,
+}
+
+impl BundledLaunch {
+    pub fn from_session(session: &Session) -> Self {
+        Self {
+            agent_id: session.agent_id.clone(),
+            agent: session.display_name.clone(),
+            tool_version: session.tool_version.clone(),
+            model: session.model.clone(),
+            reasoning_level: session.reasoning_level.clone(),
+            session_mode: session.session_mode,
+            skip_permissions: session.skip_permissions,
+            fast_mode: session.fast_mode,
+            runtime_target: session.runtime_target,
+            docker_service: session.docker_service.clone(),
+            launch_command: session.launch_command.clone(),
+            launch_args: session.launch_args.clone(),
+            started_at: session.created_at,
+        }
+    }
+
+    /// The launch as one shell command line.
+    pub fn command_line(&self) -> String {
+        std::iter::once(self.launch_command.as_str())
+            .chain(self.launch_args.iter().map(String::as_str))
+            .filter(|word| !word.is_empty())
+            .map(shell_word)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Everything `gwt session bundle` writes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionBundle {
+    pub schema_version: u32,
+    pub gwt_version: String,
+    /// `<os>-<arch>` of the machine that created the bundle.
+    pub platform: String,
+    pub created_at: DateTime<Utc>,
+    pub git: BundleGitInfo,
+    pub environment: BTreeMap<String, String>,
+    /// Launches on the branch, oldest first.
+    pub launches: Vec<BundledLaunch>,
+    pub export: SessionExport,
+}
+
+impl SessionBundle {
+    /// Assemble a bundle for `export.branch` from the gwt sessions that ran
+    /// on it. Not anonymized yet; see [`Self::anonymize`].
+    pub fn new(
+        gwt_version: &str,
+        git: BundleGitInfo,
+        environment: BTreeMap<String, String>,
+        sessions: &[Session],
+        export: SessionExport,
+    ) -> Self {
+        let mut launches: Vec<BundledLaunch> = sessions
+            .iter()
+            .filter(|session| session.branch == export.branch)
+            .map(BundledLaunch::from_session)
+            .collect();
+        launches.sort_by_key(|launch| launch.started_at);
+        Self {
+            schema_version: SESSION_BUNDLE_SCHEMA_VERSION,
+            gwt_version: gwt_version.to_string(),
+            platform: format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH),
+            created_at: Utc::now(),
+            git,
+            environment,
+            launches,
+            export,
+        }
+    }
+
+    /// Replace `repo_root` and `home` in every string with placeholders and
+    /// mask secrets with `redactor`.
+    pub fn anonymize(
+        self,
+        repo_root: &Path,
+        home: Option<&Path>,
+        redactor: &SecretRedactor,
+    ) -> io::Result<Self> {
+        let mut replacements = vec![(repo_root.to_string_lossy().into_owned(), REPO_PLACEHOLDER)];
+        if let Some(home) = home {
+            replacements.push((home.to_string_lossy().into_owned(), HOME_PLACEHOLDER));
+        }
+        // The longest path first so a repository inside the home directory
+        // becomes `<repo>` rather than `~/src/repo`.
+        replacements.retain(|(path, _)| path.len() > 1);
+        replacements.sort_by_key(|(path, _)| std::cmp::Reverse(path.len()));
+
+        let mut value = serde_json::to_value(&self).map_err(io::Error::other)?;
+        replace_paths(&mut value, &replacements);
+        redactor.redact_value(&mut value);
+        serde_json::from_value(value).map_err(io::Error::other)
+    }
+}
+
+/// The agent-related subset of `vars`, e.g. `std::env::vars()`.
+pub fn bundle_environment(
+    vars: impl IntoIterator<Item = (String, String)>,
+) -> BTreeMap<String, String> {
+    vars.into_iter()
+        .filter(|(key, _)| {
+            BUNDLED_ENV_VARS
+                .iter()
+                .any(|wanted| match wanted.strip_suffix('_') {
+                    Some(_) => key.starts_with(wanted),
+                    None => key == wanted,
+                })
+        })
+        .collect()
+}
+
+/// Write `bundle` to `path` as gzip-compressed JSON.
+pub fn write_bundle(path: &Path, bundle: &SessionBundle) -> io::Result<()> {
+    let mut encoder = GzEncoder::new(BufWriter::new(File::create(path)?), Compression::default());
+    serde_json::to_writer_pretty(&mut encoder, bundle).map_err(io::Error::other)?;
+    encoder.finish()?.flush()
+}
+
+/// Read a bundle written by [`write_bundle`].
+pub fn read_bundle(path: &Path) -> io::Result<SessionBundle> {
+    let decoder = GzDecoder::new(BufReader::new(File::open(path)?));
+    let bundle: SessionBundle = serde_json::from_reader(decoder)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    if bundle.schema_version > SESSION_BUNDLE_SCHEMA_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "bundle schema {} is newer than this gwt supports ({SESSION_BUNDLE_SCHEMA_VERSION})",
+                bundle.schema_version
+            ),
+        ));
+    }
+    Ok(bundle)
+}
+
+/// Markdown steps that reproduce the bundled launches locally.
+/// `local_version` is the running gwt version, compared with the bundle's.
+pub fn render_replay_plan(bundle: &SessionBundle, local_version: &str) -> String {
+    let mut out = format!("# Replay plan for {}\n\n", bundle.git.branch);
+    out.push_str(&format!(
+        "Bundled by gwt {} on {} at {}.\n",
+        bundle.gwt_version,
+        bundle.platform,
+        bundle.created_at.format("%Y-%m-%d %H:%M UTC")
+    ));
+    if bundle.gwt_version != local_version {
+        out.push_str(&format!(
+            "This is gwt {local_version}; install gwt {} for an exact replay.\n",
+            bundle.gwt_version
+        ));
+    }
+
+    out.push_str("\n## Repository\n\n");
+    match bundle.git.head.as_deref() {
+        Some(head) => out.push_str(&format!(
+            "    git switch -c {} {head}\n",
+            shell_word(&bundle.git.branch)
+        )),
+        None => out.push_str("The bundle does not record the branch commit.\n"),
+    }
+    if !bundle.git.dirty_paths.is_empty() {
+        out.push_str(&format!(
+            "\nThe worktree had {} uncommitted path(s), not included in the bundle:\n\n",
+            bundle.git.dirty_paths.len()
+        ));
+        for path in &bundle.git.dirty_paths {
+            out.push_str(&format!("- {path}\n"));
+        }
+    }
+
+    if !bundle.environment.is_empty() {
+        out.push_str("\n## Environment\n\n");
+        for (key, value) in &bundle.environment {
+            out.push_str(&format!("    export {key}={}\n", shell_word(value)));
+        }
+    }
+
+    if bundle.launches.is_empty() {
+        out.push_str("\nThe bundle records no agent launches.\n");
+    }
+    for (index, launch) in bundle.launches.iter().enumerate() {
+        out.push_str(&format!(
+            "\n## Launch {}: {} ({})\n\n",
+            index + 1,
+            launch.agent,
+            launch.started_at.format("%Y-%m-%d %H:%M UTC")
+        ));
+        let optional = [
+            ("Version", launch.tool_version.as_deref()),
+            ("Model", launch.model.as_deref()),
+            ("Reasoning", launch.reasoning_level.as_deref()),
+        ];
+        for (label, value) in optional {
+            if let Some(value) = value {
+                out.push_str(&format!("- {label}: {value}\n"));
+            }
+        }
+        out.push_str(&format!("- Mode: {:?}\n", launch.session_mode));
+        let runtime = match (launch.runtime_target, launch.docker_service.as_deref()) {
+            (LaunchRuntimeTarget::Docker, Some(service)) => format!("Docker ({service})"),
+            (LaunchRuntimeTarget::Docker, None) => "Docker".to_string(),
+            (LaunchRuntimeTarget::Host, _) => "Host".to_string(),
+        };
+        out.push_str(&format!("- Runtime: {runtime}\n"));
+        if launch.skip_permissions {
+            out.push_str("- Permissions skipped\n");
+        }
+        if launch.fast_mode {
+            out.push_str("- Fast mode\n");
+        }
+        let command = launch.command_line();
+        if !command.is_empty() {
+            out.push_str(&format!("\nFrom the worktree root:\n\n    {command}\n"));
+        }
+    }
+    out
+}
+
+/// Replace each `(path, placeholder)` in every string of `value`.
+fn replace_paths(value: &mut Value, replacements: &[(String, &str)]) {
+    match value {
+        Value::String(text) => {
+            for (path, placeholder) in replacements {
+                if text.contains(path.as_str()) {
+                    *text = text.replace(path.as_str(), placeholder);
+                }
+            }
+        }
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| replace_paths(item, replacements)),
+        Value::Object(map) => map
+            .values_mut()
+            .for_each(|item| replace_paths(item, replacements)),
+        _ => {}
+    }
+}
+
+/// `word` quoted for a POSIX shell when it needs quoting.
+fn shell_word(word: &str) -> String {
+    let plain = !word.is_empty()
+        && word
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:=@,+<>~".contains(c));
+    if plain {
+        word.to_string()
+    } else {
+        format!("'{}'", word.replace('\'', r"'\''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::session_export::ExportedSession;
+
+    fn sample_bundle() -> SessionBundle {
+        let mut session = Session::new(
+            "/home/ada/src/app/wt/login",
+            "feature/login",
+            AgentId::Codex,
+        );
+        session.display_name = "Codex".to_string();
+        session.model = Some("gpt-5.5".to_string());
+        session.launch_command = "codex".to_string();
+        session.launch_args = vec![
+            "--cd".to_string(),
+            "/home/ada/src/app/wt/login".to_string(),
+            "-c".to_string(),
+            "model instructions".to_string(),
+        ];
+        let other = Session::new("/home/ada/src/app", "main", AgentId::ClaudeCode);
+        let export = SessionExport {
+            branch: "feature/login".to_string(),
+            sessions: vec![ExportedSession {
+                agent: "Codex".to_string(),
+                agent_session_id: "0191".to_string(),
+                model: None,
+                worktree_path: PathBuf::from("/home/ada/src/app/wt/login"),
+                started_at: session.created_at,
+                entries: Vec::new(),
+            }],
+        };
+        let environment = bundle_environment([
+            ("OPENAI_API_KEY".to_string(), "sk-live".to_string()),
+            ("CODEX_HOME".to_string(), "/home/ada/.codex".to_string()),
+            ("PATH".to_string(), "/usr/bin".to_string()),
+        ]);
+        let git = BundleGitInfo {
+            branch: "feature/login".to_string(),
+            head: Some("abc1234".to_string()),
+            git_version: None,
+            dirty_paths: vec!["src/login.rs".to_string()],
+        };
+        SessionBundle::new("9.1.0", git, environment, &[session, other], export)
+    }
+
+    #[test]
+    fn anonymize_replaces_paths_and_masks_secrets() {
+        let bundle = sample_bundle()
+            .anonymize(
+                Path::new("/home/ada/src/app"),
+                Some(Path::new("/home/ada")),
+                &SecretRedactor::default(),
+            )
+            .unwrap();
+
+        assert_eq!(bundle.launches.len(), 1);
+        assert_eq!(bundle.launches[0].launch_args[1], "<repo>/wt/login");
+        assert_eq!(
+            bundle.export.sessions[0].worktree_path,
+            PathBuf::from("<repo>/wt/login")
+        );
+        assert_eq!(bundle.environment["CODEX_HOME"], "~/.codex");
+        assert_ne!(bundle.environment["OPENAI_API_KEY"], "sk-live");
+        assert!(!bundle.environment.contains_key("PATH"));
+    }
+
+    #[test]
+    fn bundle_round_trips_through_gzip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bundle.json.gz");
+        let bundle = sample_bundle();
+        write_bundle(&path, &bundle).unwrap();
+        assert_eq!(read_bundle(&path).unwrap(), bundle);
+
+        std::fs::write(&path, "not gzip").unwrap();
+        assert!(read_bundle(&path).is_err());
+    }
+
+    #[test]
+    fn replay_plan_lists_checkout_and_quoted_commands() {
+        let plan = render_replay_plan(&sample_bundle(), "9.2.0");
+        assert!(plan.contains("install gwt 9.1.0"));
+        assert!(plan.contains("git switch -c feature/login abc1234"));
+        assert!(plan.contains("- src/login.rs"));
+        assert!(plan.contains("- Model: gpt-5.5"));
+        assert!(plan.contains("codex --cd /home/ada/src/app/wt/login -c 'model instructions'"));
+        assert!(!plan.contains("## Launch 2"));
+    }
+}
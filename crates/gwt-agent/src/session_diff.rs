@@ -0,0 +1,534 @@
+//! Comparison of two agent runs on the same branch.
+//!
+//! Works on the transcripts gathered by [`crate::session_export`]: two
+//! [`ExportedSession`]s (different runs, possibly different agents) are
+//! reduced to message counts, tool usage and written files, and compared.
+//! The first message where the conversations part ways is reported as the
+//! divergence point, and every assistant message that wrote a file the
+//! other run never wrote is listed as a divergent decision.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write as _,
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::session_export::{ExportedSession, SessionExport};
+
+/// Longest message excerpt kept in a diff.
+const EXCERPT_CHARS: usize = 160;
+
+/// Which of the two compared runs something belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffSide {
+    Left,
+    Right,
+}
+
+/// Summary of one run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunSummary {
+    pub agent: String,
+    pub agent_session_id: String,
+    pub model: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub messages: usize,
+    pub user_messages: usize,
+    pub assistant_messages: usize,
+    /// Calls per tool name.
+    pub tools: BTreeMap<String, usize>,
+    /// Files the run wrote.
+    pub files: BTreeSet<String>,
+}
+
+impl RunSummary {
+    fn of(session: &ExportedSession) -> Self {
+        let mut tools = BTreeMap::new();
+        let mut files = BTreeSet::new();
+        for entry in &session.entries {
+            for tool in &entry.tools {
+                *tools.entry(tool.clone()).or_insert(0) += 1;
+            }
+            files.extend(entry.files.iter().cloned());
+        }
+        let count = |role: &str| {
+            session
+                .entries
+                .iter()
+                .filter(|entry| entry.role == role)
+                .count()
+        };
+        Self {
+            agent: session.agent.clone(),
+            agent_session_id: session.agent_session_id.clone(),
+            model: session.model.clone(),
+            started_at: session.started_at,
+            messages: session.entries.len(),
+            user_messages: count("user"),
+            assistant_messages: count("assistant"),
+            tools,
+            files,
+        }
+    }
+
+    pub fn tool_calls(&self) -> usize {
+        self.tools.values().sum()
+    }
+}
+
+/// A message at the divergence point, or `None` on the side that ended.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DivergentMessage {
+    pub role: String,
+    pub excerpt: String,
+}
+
+/// First conversation message (user / assistant) where the runs differ.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Divergence {
+    /// Index among the conversation messages of either run.
+    pub index: usize,
+    pub left: Option<DivergentMessage>,
+    pub right: Option<DivergentMessage>,
+}
+
+/// An assistant message that wrote files the other run never wrote.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DivergentDecision {
+    pub side: DiffSide,
+    pub files: Vec<String>,
+    pub excerpt: String,
+    pub timestamp: Option<String>,
+}
+
+/// Result of [`diff_sessions`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionDiff {
+    pub branch: String,
+    pub left: RunSummary,
+    pub right: RunSummary,
+    pub tools_only_left: Vec<String>,
+    pub tools_only_right: Vec<String>,
+    pub files_common: Vec<String>,
+    pub files_only_left: Vec<String>,
+    pub files_only_right: Vec<String>,
+    pub divergence: Option<Divergence>,
+    pub decisions: Vec<DivergentDecision>,
+}
+
+/// Pick the two runs of `export` to compare. `left` / `right` select a run
+/// by agent session id or a unique prefix of it; without them the two most
+/// recent runs are compared, older on the left.
+pub fn select_runs<'a>(
+    export: &'a SessionExport,
+    left: Option<&str>,
+    right: Option<&str>,
+) -> Result<(&'a ExportedSession, &'a ExportedSession), String> {
+    let find = |id: &str| -> Result<&'a ExportedSession, String> {
+        let mut matches = export
+            .sessions
+            .iter()
+            .filter(|session| session.agent_session_id.starts_with(id));
+        match (matches.next(), matches.next()) {
+            (Some(session), None) => Ok(session),
+            (None, _) => Err(format!("no session {id} on {}", export.branch)),
+            (Some(_), Some(_)) => Err(format!("session id {id} is ambiguous")),
+        }
+    };
+    let runs = &export.sessions;
+    match (left, right) {
+        (Some(left), Some(right)) => Ok((find(left)?, find(right)?)),
+        (Some(_), None) | (None, Some(_)) => Err("give both session ids or neither".to_string()),
+        (None, None) if runs.len() >= 2 => Ok((&runs[runs.len() - 2], &runs[runs.len() - 1])),
+        (None, None) => Err(format!(
+            "{} has {} recorded session(s); two are needed",
+            export.branch,
+            runs.len()
+        )),
+    }
+}
+
+/// Compare two runs of `branch`.
+pub fn diff_sessions(branch: &str, left: &ExportedSession, right: &ExportedSession) -> SessionDiff {
+    let left_summary = RunSummary::of(left);
+    let right_summary = RunSummary::of(right);
+    let only = |a: &RunSummary, b: &RunSummary| -> Vec<String> {
+        a.tools
+            .keys()
+            .filter(|tool| !b.tools.contains_key(*tool))
+            .cloned()
+            .collect()
+    };
+    let mut decisions = divergent_decisions(DiffSide::Left, left, &right_summary.files);
+    decisions.extend(divergent_decisions(
+        DiffSide::Right,
+        right,
+        &left_summary.files,
+    ));
+    SessionDiff {
+        branch: branch.to_string(),
+        tools_only_left: only(&left_summary, &right_summary),
+        tools_only_right: only(&right_summary, &left_summary),
+        files_common: left_summary
+            .files
+            .intersection(&right_summary.files)
+            .cloned()
+            .collect(),
+        files_only_left: left_summary
+            .files
+            .difference(&right_summary.files)
+            .cloned()
+            .collect(),
+        files_only_right: right_summary
+            .files
+            .difference(&left_summary.files)
+            .cloned()
+            .collect(),
+        divergence: divergence(left, right),
+        decisions,
+        left: left_summary,
+        right: right_summary,
+    }
+}
+
+fn conversation(session: &ExportedSession) -> impl Iterator<Item = (&str, &str)> {
+    session
+        .entries
+        .iter()
+        .filter(|entry| matches!(entry.role.as_str(), "user" | "assistant"))
+        .filter(|entry| !entry.text.trim().is_empty())
+        .map(|entry| (entry.role.as_str(), entry.text.as_str()))
+}
+
+fn divergence(left: &ExportedSession, right: &ExportedSession) -> Option<Divergence> {
+    let mut left_messages = conversation(left);
+    let mut right_messages = conversation(right);
+    let message = |(role, text): (&str, &str)| DivergentMessage {
+        role: role.to_string(),
+        excerpt: excerpt(text),
+    };
+    let mut index = 0;
+    loop {
+        match (left_messages.next(), right_messages.next()) {
+            (None, None) => return None,
+            (Some(l), Some(r)) if l.0 == r.0 && normalized(l.1) == normalized(r.1) => index += 1,
+            (l, r) => {
+                return Some(Divergence {
+                    index,
+                    left: l.map(message),
+                    right: r.map(message),
+                })
+            }
+        }
+    }
+}
+
+fn divergent_decisions(
+    side: DiffSide,
+    session: &ExportedSession,
+    other_files: &BTreeSet<String>,
+) -> Vec<DivergentDecision> {
+    session
+        .entries
+        .iter()
+        .filter_map(|entry| {
+            let mut files: Vec<String> = entry
+                .files
+                .iter()
+                .filter(|file| !other_files.contains(*file))
+                .cloned()
+                .collect();
+            files.dedup();
+            if files.is_empty() {
+                return None;
+            }
+            Some(DivergentDecision {
+                side,
+                files,
+                excerpt: excerpt(&entry.text),
+                timestamp: entry.timestamp.clone(),
+            })
+        })
+        .collect()
+}
+
+fn normalized(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn excerpt(text: &str) -> String {
+    let line = text
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or_default();
+    if line.chars().count() <= EXCERPT_CHARS {
+        return line.to_string();
+    }
+    let mut excerpt: String = line.chars().take(EXCERPT_CHARS).collect();
+    excerpt.push('…');
+    excerpt
+}
+
+/// Markdown report of a [`SessionDiff`].
+pub fn render_diff_markdown(diff: &SessionDiff) -> String {
+    let mut out = format!("# Session diff: {}\n\n", diff.branch);
+    let _ = writeln!(out, "| | left | right |\n|---|---|---|");
+    let row = |out: &mut String, label: &str, left: String, right: String| {
+        let _ = writeln!(out, "| {label} | {left} | {right} |");
+    };
+    let run = |summary: &RunSummary| {
+        format!(
+            "{} `{}`",
+            summary.agent,
+            short_id(&summary.agent_session_id)
+        )
+    };
+    row(&mut out, "run", run(&diff.left), run(&diff.right));
+    row(
+        &mut out,
+        "started",
+        diff.left.started_at.to_rfc3339(),
+        diff.right.started_at.to_rfc3339(),
+    );
+    row(
+        &mut out,
+        "messages",
+        counts(&diff.left),
+        counts(&diff.right),
+    );
+    row(
+        &mut out,
+        "tool calls",
+        diff.left.tool_calls().to_string(),
+        diff.right.tool_calls().to_string(),
+    );
+    row(
+        &mut out,
+        "files written",
+        diff.left.files.len().to_string(),
+        diff.right.files.len().to_string(),
+    );
+
+    out.push_str("\n## Tools\n\n");
+    let tools: BTreeSet<&String> = diff
+        .left
+        .tools
+        .keys()
+        .chain(diff.right.tools.keys())
+        .collect();
+    if tools.is_empty() {
+        out.push_str("No tool calls.\n");
+    }
+    for tool in tools {
+        let calls = |summary: &RunSummary| summary.tools.get(tool).copied().unwrap_or(0);
+        let _ = writeln!(
+            out,
+            "- `{tool}`: {} / {}",
+            calls(&diff.left),
+            calls(&diff.right)
+        );
+    }
+
+    out.push_str("\n## Files\n\n");
+    list(&mut out, "Both", &diff.files_common);
+    list(&mut out, "Left only", &diff.files_only_left);
+    list(&mut out, "Right only", &diff.files_only_right);
+
+    out.push_str("\n## Divergence\n\n");
+    match &diff.divergence {
+        None => out.push_str("The conversations are identical.\n"),
+        Some(divergence) => {
+            let _ = writeln!(out, "Message {}:\n", divergence.index + 1);
+            for (label, message) in [("left", &divergence.left), ("right", &divergence.right)] {
+                match message {
+                    Some(message) => {
+                        let _ = writeln!(out, "- {label} ({}): {}", message.role, message.excerpt);
+                    }
+                    None => {
+                        let _ = writeln!(out, "- {label}: (conversation ended)");
+                    }
+                }
+            }
+        }
+    }
+
+    if !diff.decisions.is_empty() {
+        out.push_str("\n## Divergent decisions\n\n");
+        for decision in &diff.decisions {
+            let side = match decision.side {
+                DiffSide::Left => "left",
+                DiffSide::Right => "right",
+            };
+            let _ = writeln!(
+                out,
+                "- {side}: {} — {}",
+                decision
+                    .files
+                    .iter()
+                    .map(|file| format!("`{file}`"))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                if decision.excerpt.is_empty() {
+                    "(no message text)"
+                } else {
+                    &decision.excerpt
+                }
+            );
+        }
+    }
+    out
+}
+
+fn counts(summary: &RunSummary) -> String {
+    format!(
+        "{} ({} user, {} assistant)",
+        summary.messages, summary.user_messages, summary.assistant_messages
+    )
+}
+
+fn list(out: &mut String, label: &str, files: &[String]) {
+    if files.is_empty() {
+        let _ = writeln!(out, "- {label}: none");
+        return;
+    }
+    let _ = writeln!(out, "- {label}:");
+    for file in files {
+        let _ = writeln!(out, "  - `{file}`");
+    }
+}
+
+fn short_id(id: &str) -> &str {
+    id.get(..8).unwrap_or(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use chrono::TimeZone;
+    use gwt_core::session_search::TranscriptEntry;
+
+    use super::*;
+
+    fn entry(role: &str, text: &str, tools: &[&str], files: &[&str]) -> TranscriptEntry {
+        TranscriptEntry {
+            role: role.to_string(),
+            text: text.to_string(),
+            timestamp: None,
+            tools: tools.iter().map(|tool| tool.to_string()).collect(),
+            files: files.iter().map(|file| file.to_string()).collect(),
+        }
+    }
+
+    fn run(id: &str, minute: u32, entries: Vec<TranscriptEntry>) -> ExportedSession {
+        ExportedSession {
+            agent: "Claude Code".to_string(),
+            agent_session_id: id.to_string(),
+            model: None,
+            worktree_path: PathBuf::from("/repo"),
+            started_at: Utc.with_ymd_and_hms(2026, 10, 1, 12, minute, 0).unwrap(),
+            entries,
+        }
+    }
+
+    #[test]
+    fn diff_reports_counts_tools_files_and_divergence() {
+        let left = run(
+            "aaaa1111",
+            0,
+            vec![
+                entry("user", "Fix the login redirect", &[], &[]),
+                entry(
+                    "assistant",
+                    "Patching the middleware",
+                    &["Edit"],
+                    &["src/mw.rs"],
+                ),
+                entry(
+                    "assistant",
+                    "Updating the test",
+                    &["Edit"],
+                    &["tests/login.rs"],
+                ),
+            ],
+        );
+        let right = run(
+            "bbbb2222",
+            5,
+            vec![
+                entry("user", "Fix  the login redirect", &[], &[]),
+                entry(
+                    "assistant",
+                    "Rewriting the router instead",
+                    &["Write"],
+                    &["src/router.rs"],
+                ),
+                entry("tool", "", &["shell"], &[]),
+                entry(
+                    "assistant",
+                    "Updating the test",
+                    &["Edit"],
+                    &["tests/login.rs"],
+                ),
+            ],
+        );
+        let diff = diff_sessions("feature/login", &left, &right);
+
+        assert_eq!(diff.left.messages, 3);
+        assert_eq!(diff.right.assistant_messages, 2);
+        assert_eq!(diff.right.tool_calls(), 3);
+        assert!(diff.tools_only_left.is_empty());
+        assert_eq!(diff.tools_only_right, ["Write", "shell"]);
+        assert_eq!(diff.files_common, ["tests/login.rs"]);
+        assert_eq!(diff.files_only_left, ["src/mw.rs"]);
+        assert_eq!(diff.files_only_right, ["src/router.rs"]);
+        // Whitespace differences do not count as divergence.
+        let divergence = diff.divergence.as_ref().unwrap();
+        assert_eq!(divergence.index, 1);
+        assert_eq!(
+            divergence.right.as_ref().unwrap().excerpt,
+            "Rewriting the router instead"
+        );
+        assert_eq!(diff.decisions.len(), 2);
+        assert_eq!(diff.decisions[0].side, DiffSide::Left);
+        assert_eq!(diff.decisions[0].files, ["src/mw.rs"]);
+        assert_eq!(diff.decisions[1].side, DiffSide::Right);
+
+        let markdown = render_diff_markdown(&diff);
+        assert!(markdown.contains("# Session diff: feature/login"));
+        assert!(markdown.contains("| run | Claude Code `aaaa1111` | Claude Code `bbbb2222` |"));
+        assert!(markdown.contains("- `Edit`: 2 / 1"));
+        assert!(markdown.contains("Message 2:"));
+        assert!(markdown.contains("- right: `src/router.rs` — Rewriting the router instead"));
+    }
+
+    #[test]
+    fn select_runs_defaults_to_the_latest_two_and_matches_prefixes() {
+        let export = SessionExport {
+            branch: "main".to_string(),
+            sessions: vec![
+                run("aaaa1111", 0, Vec::new()),
+                run("bbbb2222", 1, Vec::new()),
+                run("bbbb3333", 2, Vec::new()),
+            ],
+        };
+        let (left, right) = select_runs(&export, None, None).unwrap();
+        assert_eq!(left.agent_session_id, "bbbb2222");
+        assert_eq!(right.agent_session_id, "bbbb3333");
+        let (left, right) = select_runs(&export, Some("bbbb3"), Some("aaaa")).unwrap();
+        assert_eq!(left.agent_session_id, "bbbb3333");
+        assert_eq!(right.agent_session_id, "aaaa1111");
+        assert!(select_runs(&export, Some("bbbb"), Some("aaaa")).is_err());
+        assert!(select_runs(&export, Some("cccc"), Some("aaaa")).is_err());
+        assert!(select_runs(&export, Some("aaaa"), None).is_err());
+        let single = SessionExport {
+            branch: "main".to_string(),
+            sessions: vec![run("aaaa1111", 0, Vec::new())],
+        };
+        assert!(select_runs(&single, None, None).is_err());
+    }
+}
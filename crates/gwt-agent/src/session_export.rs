@@ -0,0 +1,543 @@
+//! Readable transcripts of the agent sessions that worked on a branch.
+//!
+//! [`build_session_export`] gathers, per branch, every agent-side session
+//! recorded by gwt (the current one and its history) and reads its
+//! transcript with [`gwt_core::session_stream`], which decodes it line by
+//! line instead of loading the whole file. [`render_export`] turns the result into
+//! Markdown (for pasting into a PR), a standalone HTML page, or JSON.
+//! Tool calls are annotated on the message that made them, and every
+//! message keeps its timestamp when the transcript records one.
+//! [`recent_sessions`] lists the same sessions newest first with a short
+//! preview, for picking the conversation to resume.
+
+use std::{fmt::Write as _, path::PathBuf, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use gwt_core::{
+    session_search::{SessionSource, TranscriptEntry},
+    session_stream::TranscriptStream,
+    usage::{claude, codex},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{session::Session, types::AgentId};
+
+/// Output format of [`render_export`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    #[default]
+    Markdown,
+    Html,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Html => "html",
+            Self::Json => "json",
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Markdown => "text/markdown; charset=utf-8",
+            Self::Html => "text/html; charset=utf-8",
+            Self::Json => "application/json",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "md" | "markdown" => Ok(Self::Markdown),
+            "html" => Ok(Self::Html),
+            "json" => Ok(Self::Json),
+            other => Err(format!(
+                "unknown export format {other}; use md, html or json"
+            )),
+        }
+    }
+}
+
+/// One agent-side session and its transcript.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedSession {
+    /// Agent display name.
+    pub agent: String,
+    pub agent_session_id: String,
+    pub model: Option<String>,
+    pub worktree_path: PathBuf,
+    pub started_at: DateTime<Utc>,
+    /// Empty when the agent keeps no readable transcript.
+    pub entries: Vec<TranscriptEntry>,
+}
+
+/// Every exported session of one branch, oldest first.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionExport {
+    pub branch: String,
+    pub sessions: Vec<ExportedSession>,
+}
+
+/// Collect the sessions of `sessions` that ran on `branch`. `read` returns
+/// the transcript of one agent session (see [`read_session_transcript`]).
+pub fn build_session_export(
+    branch: &str,
+    sessions: &[Session],
+    read: impl Fn(&AgentId, &str) -> Option<Vec<TranscriptEntry>>,
+) -> SessionExport {
+    let mut exported = Vec::new();
+    for session in sessions.iter().filter(|session| session.branch == branch) {
+        for (agent_session_id, started_at) in agent_session_ids(session) {
+            exported.push(ExportedSession {
+                agent: session.display_name.clone(),
+                agent_session_id: agent_session_id.to_string(),
+                model: session.model.clone(),
+                worktree_path: session.worktree_path.clone(),
+                started_at,
+                entries: read(&session.agent_id, agent_session_id).unwrap_or_default(),
+            });
+        }
+    }
+    exported.sort_by_key(|session| session.started_at);
+    SessionExport {
+        branch: branch.to_string(),
+        sessions: exported,
+    }
+}
+
+/// Agent-side sessions of one gwt session (its history and the current
+/// one) with their start times.
+fn agent_session_ids(session: &Session) -> Vec<(&str, DateTime<Utc>)> {
+    let mut ids: Vec<(&str, DateTime<Utc>)> = session
+        .session_history
+        .iter()
+        .map(|entry| (entry.agent_session_id.as_str(), entry.started_at))
+        .collect();
+    if let Some(current) = session.agent_session_id.as_deref() {
+        if !ids.iter().any(|(id, _)| *id == current) {
+            ids.push((current, session.created_at));
+        }
+    }
+    ids
+}
+
+/// Characters of the first user message kept in [`RecentSession::preview`].
+const PREVIEW_CHARS: usize = 120;
+
+/// Picker row of one resumable agent session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentSession {
+    /// Agent command id (`claude`, `codex`, ...).
+    pub agent_id: String,
+    /// Agent display name.
+    pub agent: String,
+    pub agent_session_id: String,
+    pub model: Option<String>,
+    pub started_at: DateTime<Utc>,
+    /// First user message on one line, shortened.
+    pub preview: Option<String>,
+    /// User messages in the transcript; 0 when it could not be read.
+    pub turns: usize,
+}
+
+/// What [`recent_sessions`] needs from one transcript.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TranscriptSummary {
+    pub preview: Option<String>,
+    pub turns: usize,
+}
+
+/// The agent sessions of `sessions`, newest first, keeping at most
+/// `per_agent` of each agent. `summarize` reads one transcript (see
+/// [`summarize_session_transcript`]); sessions it returns `None` for are
+/// still listed, without a preview.
+pub fn recent_sessions(
+    sessions: &[Session],
+    per_agent: usize,
+    summarize: impl Fn(&AgentId, &str) -> Option<TranscriptSummary>,
+) -> Vec<RecentSession> {
+    let mut candidates: Vec<(&Session, &str, DateTime<Utc>)> = sessions
+        .iter()
+        .flat_map(|session| {
+            agent_session_ids(session)
+                .into_iter()
+                .map(move |(id, started_at)| (session, id, started_at))
+        })
+        .collect();
+    candidates.sort_by_key(|(_, _, started_at)| std::cmp::Reverse(*started_at));
+
+    let mut recent: Vec<RecentSession> = Vec::new();
+    for (session, agent_session_id, started_at) in candidates {
+        let agent_id = session.agent_id.command();
+        let listed = recent
+            .iter()
+            .filter(|entry| entry.agent_id == agent_id)
+            .count();
+        if listed >= per_agent
+            || recent
+                .iter()
+                .any(|entry| entry.agent_session_id == agent_session_id)
+        {
+            continue;
+        }
+        let summary = summarize(&session.agent_id, agent_session_id).unwrap_or_default();
+        recent.push(RecentSession {
+            agent_id: agent_id.to_string(),
+            agent: session.display_name.clone(),
+            agent_session_id: agent_session_id.to_string(),
+            model: session.model.clone(),
+            started_at,
+            preview: summary.preview,
+            turns: summary.turns,
+        });
+    }
+    recent
+}
+
+/// Count the user messages of one Claude Code or Codex transcript and keep
+/// the first as a preview. The transcript is streamed, never held whole.
+pub fn summarize_session_transcript(
+    agent_id: &AgentId,
+    agent_session_id: &str,
+) -> Option<TranscriptSummary> {
+    let (source, path) = session_transcript_path(agent_id, agent_session_id)?;
+    let mut summary = TranscriptSummary::default();
+    for entry in TranscriptStream::open(source, path, 0).into_entries() {
+        let entry = entry.ok()?.entry;
+        summarize_entry(&mut summary, &entry);
+    }
+    Some(summary)
+}
+
+fn summarize_entry(summary: &mut TranscriptSummary, entry: &TranscriptEntry) {
+    if entry.role != "user" || entry.text.trim().is_empty() {
+        return;
+    }
+    summary.turns += 1;
+    if summary.preview.is_none() {
+        summary.preview = Some(preview_text(&entry.text));
+    }
+}
+
+fn preview_text(text: &str) -> String {
+    let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() <= PREVIEW_CHARS {
+        return line;
+    }
+    let mut preview: String = line.chars().take(PREVIEW_CHARS - 1).collect();
+    preview.push('…');
+    preview
+}
+
+/// Read the Claude Code or Codex transcript of one agent session. Other
+/// agents keep no readable transcript.
+pub fn read_session_transcript(
+    agent_id: &AgentId,
+    agent_session_id: &str,
+) -> Option<Vec<TranscriptEntry>> {
+    let (source, path) = session_transcript_path(agent_id, agent_session_id)?;
+    TranscriptStream::open(source, path, 0)
+        .into_entries()
+        .map(|entry| entry.map(|entry| entry.entry))
+        .collect::<std::io::Result<_>>()
+        .ok()
+}
+
+/// Transcript file of one Claude Code or Codex agent session.
+pub fn session_transcript_path(
+    agent_id: &AgentId,
+    agent_session_id: &str,
+) -> Option<(SessionSource, PathBuf)> {
+    match agent_id {
+        AgentId::ClaudeCode => Some((
+            SessionSource::Claude,
+            claude::transcript_for_session(&claude::claude_home()?, agent_session_id)?,
+        )),
+        AgentId::Codex => Some((
+            SessionSource::Codex,
+            codex::rollout_for_session(&codex::codex_home()?, agent_session_id)?,
+        )),
+        _ => None,
+    }
+}
+
+/// `export` in `format`.
+pub fn render_export(export: &SessionExport, format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Markdown => render_export_markdown(export),
+        ExportFormat::Html => render_export_html(export),
+        ExportFormat::Json => {
+            let mut json = serde_json::to_string_pretty(export).unwrap_or_default();
+            json.push('\n');
+            json
+        }
+    }
+}
+
+fn session_heading(session: &ExportedSession) -> String {
+    let model = session
+        .model
+        .as_deref()
+        .map(|model| format!(" ({model})"))
+        .unwrap_or_default();
+    format!(
+        "{}{model} · {} · {}",
+        session.agent,
+        session.started_at.format("%Y-%m-%d %H:%M UTC"),
+        session.agent_session_id
+    )
+}
+
+fn role_label(entry: &TranscriptEntry) -> &str {
+    match entry.role.as_str() {
+        "user" => "User",
+        "assistant" => "Assistant",
+        "tool" => "Tool",
+        other => other,
+    }
+}
+
+/// Markdown transcript. Tool calls and results are folded into
+/// `<details>` blocks so the conversation stays readable on GitHub.
+pub fn render_export_markdown(export: &SessionExport) -> String {
+    let mut out = format!("# Agent sessions on `{}`\n", export.branch);
+    if export.sessions.is_empty() {
+        out.push_str("\nNo agent sessions recorded for this branch.\n");
+        return out;
+    }
+    for session in &export.sessions {
+        let _ = write!(out, "\n## {}\n\n", session_heading(session));
+        if session.entries.is_empty() {
+            out.push_str("_No readable transcript._\n");
+            continue;
+        }
+        for entry in &session.entries {
+            let time = entry
+                .timestamp
+                .as_deref()
+                .map(|time| format!(" · {time}"))
+                .unwrap_or_default();
+            let tools = if entry.tools.is_empty() {
+                String::new()
+            } else {
+                format!(" · tools: {}", entry.tools.join(", "))
+            };
+            if entry.role == "tool" {
+                let _ = write!(
+                    out,
+                    "<details><summary>{}{tools}{time}</summary>\n\n{}\n\n</details>\n\n",
+                    role_label(entry),
+                    fenced(&entry.text)
+                );
+            } else {
+                let _ = write!(
+                    out,
+                    "**{}**{tools}{time}\n\n{}\n\n",
+                    role_label(entry),
+                    entry.text
+                );
+            }
+        }
+    }
+    out
+}
+
+/// Fence `text` with more backticks than any run inside it.
+fn fenced(text: &str) -> String {
+    let longest = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest.max(2) + 1);
+    format!("{fence}\n{text}\n{fence}")
+}
+
+/// Standalone HTML page.
+pub fn render_export_html(export: &SessionExport) -> String {
+    let title = format!("Agent sessions on {}", escape_html(&export.branch));
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{title}</title>\n<style>\n\
+         body {{ font-family: system-ui, sans-serif; max-width: 960px; margin: 2rem auto; padding: 0 1rem; }}\n\
+         .entry {{ border-left: 3px solid #ccc; margin: 1rem 0; padding: 0.25rem 0.75rem; }}\n\
+         .entry.user {{ border-color: #2f81f7; }}\n\
+         .entry.assistant {{ border-color: #3fb950; }}\n\
+         .meta {{ color: #666; font-size: 0.85rem; }}\n\
+         pre {{ white-space: pre-wrap; word-break: break-word; }}\n\
+         </style>\n</head>\n<body>\n<h1>{title}</h1>\n"
+    );
+    if export.sessions.is_empty() {
+        out.push_str("<p>No agent sessions recorded for this branch.</p>\n");
+    }
+    for session in &export.sessions {
+        let _ = writeln!(
+            out,
+            "<section>\n<h2>{}</h2>",
+            escape_html(&session_heading(session))
+        );
+        if session.entries.is_empty() {
+            out.push_str("<p><em>No readable transcript.</em></p>\n");
+        }
+        for entry in &session.entries {
+            let mut meta = escape_html(role_label(entry));
+            if !entry.tools.is_empty() {
+                let _ = write!(meta, " · tools: {}", escape_html(&entry.tools.join(", ")));
+            }
+            if let Some(time) = &entry.timestamp {
+                let _ = write!(meta, " · <time>{}</time>", escape_html(time));
+            }
+            let body = format!("<pre>{}</pre>", escape_html(&entry.text));
+            if entry.role == "tool" {
+                let _ = writeln!(
+                    out,
+                    "<details class=\"entry tool\"><summary class=\"meta\">{meta}</summary>{body}</details>"
+                );
+            } else {
+                let _ = writeln!(
+                    out,
+                    "<div class=\"entry {}\"><div class=\"meta\">{meta}</div>{body}</div>",
+                    escape_html(&entry.role)
+                );
+            }
+        }
+        out.push_str("</section>\n");
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::session::AgentSessionHistoryEntry;
+
+    fn entry(role: &str, text: &str, tools: &[&str]) -> TranscriptEntry {
+        TranscriptEntry {
+            role: role.to_string(),
+            text: text.to_string(),
+            timestamp: Some("2026-10-01T12:00:00Z".to_string()),
+            tools: tools.iter().map(|tool| tool.to_string()).collect(),
+            files: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn export_collects_history_and_renders_each_format() {
+        let mut session = Session::new("/repo/wt", "feature/a", AgentId::ClaudeCode);
+        session.created_at = Utc.with_ymd_and_hms(2026, 10, 2, 9, 0, 0).unwrap();
+        session.session_history = vec![AgentSessionHistoryEntry {
+            agent_session_id: "old".to_string(),
+            started_at: Utc.with_ymd_and_hms(2026, 10, 1, 9, 0, 0).unwrap(),
+        }];
+        session.agent_session_id = Some("new".to_string());
+        let other = Session::new("/repo/other", "feature/b", AgentId::Codex);
+
+        let export = build_session_export("feature/a", &[session, other], |_, id| {
+            (id == "new").then(|| {
+                vec![
+                    entry("user", "Fix <the> bug", &[]),
+                    entry("tool", "{\"file_path\":\"a.rs\"}", &["Edit"]),
+                ]
+            })
+        });
+
+        let ids: Vec<&str> = export
+            .sessions
+            .iter()
+            .map(|session| session.agent_session_id.as_str())
+            .collect();
+        assert_eq!(ids, ["old", "new"]);
+        assert!(export.sessions[0].entries.is_empty());
+
+        let markdown = render_export(&export, ExportFormat::Markdown);
+        assert!(markdown.starts_with("# Agent sessions on `feature/a`\n"));
+        assert!(markdown.contains("_No readable transcript._"));
+        assert!(markdown.contains("**User** · 2026-10-01T12:00:00Z\n\nFix <the> bug"));
+        assert!(markdown
+            .contains("<details><summary>Tool · tools: Edit · 2026-10-01T12:00:00Z</summary>"));
+
+        let html = render_export(&export, ExportFormat::Html);
+        assert!(html.contains("<pre>Fix &lt;the&gt; bug</pre>"));
+        assert!(html.contains("tools: Edit"));
+
+        let json: SessionExport =
+            serde_json::from_str(&render_export(&export, ExportFormat::Json)).unwrap();
+        assert_eq!(json, export);
+    }
+
+    #[test]
+    fn format_parses_short_and_long_names() {
+        assert_eq!("md".parse(), Ok(ExportFormat::Markdown));
+        assert_eq!("markdown".parse(), Ok(ExportFormat::Markdown));
+        assert_eq!("html".parse(), Ok(ExportFormat::Html));
+        assert_eq!("json".parse(), Ok(ExportFormat::Json));
+        assert!("pdf".parse::<ExportFormat>().is_err());
+    }
+
+    #[test]
+    fn recent_sessions_are_newest_first_capped_per_agent_with_previews() {
+        let mut claude = Session::new("/repo/wt", "feature/a", AgentId::ClaudeCode);
+        claude.created_at = Utc.with_ymd_and_hms(2026, 10, 3, 9, 0, 0).unwrap();
+        claude.session_history = vec![
+            AgentSessionHistoryEntry {
+                agent_session_id: "c1".to_string(),
+                started_at: Utc.with_ymd_and_hms(2026, 10, 1, 9, 0, 0).unwrap(),
+            },
+            AgentSessionHistoryEntry {
+                agent_session_id: "c2".to_string(),
+                started_at: Utc.with_ymd_and_hms(2026, 10, 2, 9, 0, 0).unwrap(),
+            },
+        ];
+        claude.agent_session_id = Some("c3".to_string());
+        let mut codex = Session::new("/repo/wt", "feature/a", AgentId::Codex);
+        codex.created_at = Utc.with_ymd_and_hms(2026, 10, 2, 12, 0, 0).unwrap();
+        codex.agent_session_id = Some("x1".to_string());
+
+        let recent = recent_sessions(&[claude, codex], 2, |_, id| {
+            (id != "x1").then(|| {
+                let mut summary = TranscriptSummary::default();
+                summarize_entry(&mut summary, &entry("assistant", "hello", &[]));
+                summarize_entry(&mut summary, &entry("user", &format!("Fix\n  {id}"), &[]));
+                summarize_entry(&mut summary, &entry("user", "  ", &[]));
+                summarize_entry(&mut summary, &entry("user", "and test", &[]));
+                summary
+            })
+        });
+
+        let ids: Vec<&str> = recent
+            .iter()
+            .map(|session| session.agent_session_id.as_str())
+            .collect();
+        assert_eq!(ids, ["c3", "x1", "c2"]);
+        assert_eq!(recent[0].agent_id, "claude");
+        assert_eq!(recent[0].preview.as_deref(), Some("Fix c3"));
+        assert_eq!(recent[0].turns, 2);
+        assert_eq!(recent[1].preview, None);
+        assert_eq!(recent[1].turns, 0);
+
+        let long = preview_text(&"word ".repeat(60));
+        assert_eq!(long.chars().count(), PREVIEW_CHARS);
+        assert!(long.ends_with('…'));
+    }
+}
@@ -337,6 +337,10 @@ pub enum AgentStatus {
     Stopped,
     #[serde(alias = "interrupted", alias = "Interrupted")]
     Interrupted,
+    /// The agent watchdog found no session activity, or the wall-clock
+    /// budget ran out, while the process was still alive.
+    #[serde(alias = "stalled", alias = "Stalled")]
+    Stalled,
 }
 
 /// Session start mode.
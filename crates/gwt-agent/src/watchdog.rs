@@ -0,0 +1,168 @@
+//! Agent watchdog: decides when a live agent session has stalled.
+//!
+//! Limits come from the active profile's `watchdog` table. A session that is
+//! mid-turn (`Running`) and has recorded no session activity for
+//! `idle_minutes` is stalled, as is any live session launched more than
+//! `max_runtime_minutes` ago. Sessions waiting on the user (`Idle`,
+//! `Waiting`) are never stalled for inactivity.
+
+use std::fmt;
+
+use chrono::{DateTime, Duration, Utc};
+use gwt_config::{Settings, WatchdogAction, WatchdogConfig};
+
+use crate::{AgentStatus, Session};
+
+/// Resolved watchdog limits for one launch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchdogPolicy {
+    pub idle: Option<Duration>,
+    pub max_runtime: Option<Duration>,
+    pub action: WatchdogAction,
+}
+
+/// Why [`WatchdogPolicy::check`] flagged a session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StallReason {
+    /// Minutes since the last session activity.
+    Idle { minutes: i64 },
+    /// Minutes since launch.
+    MaxRuntime { minutes: i64 },
+}
+
+impl StallReason {
+    /// Wire name used in lifecycle hook payloads.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Idle { .. } => "idle",
+            Self::MaxRuntime { .. } => "max_runtime",
+        }
+    }
+}
+
+impl fmt::Display for StallReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Idle { minutes } => write!(f, "no session activity for {minutes} min"),
+            Self::MaxRuntime { minutes } => {
+                write!(f, "running for {minutes} min, over the runtime budget")
+            }
+        }
+    }
+}
+
+impl WatchdogPolicy {
+    /// `None` when the config sets no limit. A limit of `0` is unset.
+    pub fn from_config(config: &WatchdogConfig) -> Option<Self> {
+        let minutes = |limit: Option<u64>| {
+            limit.filter(|minutes| *minutes > 0).map(|minutes| {
+                i64::try_from(minutes)
+                    .ok()
+                    .and_then(Duration::try_minutes)
+                    .unwrap_or(Duration::MAX)
+            })
+        };
+        let policy = Self {
+            idle: minutes(config.idle_minutes),
+            max_runtime: minutes(config.max_runtime_minutes),
+            action: config.action,
+        };
+        (policy.idle.is_some() || policy.max_runtime.is_some()).then_some(policy)
+    }
+
+    /// Policy of the active profile, falling back to `default` like launches
+    /// do.
+    pub fn for_active_profile(settings: &Settings) -> Option<Self> {
+        settings
+            .profiles
+            .active_profile()
+            .or_else(|| settings.profiles.get("default"))?
+            .watchdog
+            .as_ref()
+            .and_then(Self::from_config)
+    }
+
+    /// Whether `session` has stalled at `now`. Sessions already marked
+    /// stalled, or no longer live, are not reported again.
+    pub fn check(&self, session: &Session, now: DateTime<Utc>) -> Option<StallReason> {
+        if !matches!(
+            session.status,
+            AgentStatus::Running | AgentStatus::Idle | AgentStatus::WaitingInput
+        ) {
+            return None;
+        }
+        if let Some(budget) = self.max_runtime {
+            let elapsed = now.signed_duration_since(session.created_at);
+            if elapsed >= budget {
+                return Some(StallReason::MaxRuntime {
+                    minutes: elapsed.num_minutes(),
+                });
+            }
+        }
+        if let Some(limit) = self.idle {
+            let quiet = now.signed_duration_since(session.last_activity_at);
+            if session.status == AgentStatus::Running && quiet >= limit {
+                return Some(StallReason::Idle {
+                    minutes: quiet.num_minutes(),
+                });
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AgentId;
+
+    fn policy(idle: Option<u64>, max_runtime: Option<u64>) -> WatchdogPolicy {
+        WatchdogPolicy::from_config(&WatchdogConfig {
+            idle_minutes: idle,
+            max_runtime_minutes: max_runtime,
+            action: WatchdogAction::Notify,
+        })
+        .expect("policy")
+    }
+
+    #[test]
+    fn running_session_without_activity_is_stalled() {
+        let mut session = Session::new("/tmp/wt", "feature/x", AgentId::ClaudeCode);
+        session.update_status(AgentStatus::Running);
+        let now = session.last_activity_at + Duration::minutes(25);
+
+        assert_eq!(
+            policy(Some(20), None).check(&session, now),
+            Some(StallReason::Idle { minutes: 25 })
+        );
+        assert_eq!(policy(Some(30), None).check(&session, now), None);
+
+        session.update_status(AgentStatus::Idle);
+        assert_eq!(
+            policy(Some(20), None).check(&session, now + Duration::minutes(25)),
+            None
+        );
+    }
+
+    #[test]
+    fn runtime_budget_applies_to_any_live_session() {
+        let mut session = Session::new("/tmp/wt", "feature/x", AgentId::Codex);
+        session.update_status(AgentStatus::WaitingInput);
+        let now = session.created_at + Duration::minutes(90);
+
+        let found = policy(Some(10), Some(60)).check(&session, now);
+        assert_eq!(found, Some(StallReason::MaxRuntime { minutes: 90 }));
+        assert_eq!(
+            found.unwrap().to_string(),
+            "running for 90 min, over the runtime budget"
+        );
+
+        session.update_status(AgentStatus::Stalled);
+        assert_eq!(policy(None, Some(60)).check(&session, now), None);
+        assert!(WatchdogPolicy::from_config(&WatchdogConfig {
+            idle_minutes: Some(0),
+            ..WatchdogConfig::default()
+        })
+        .is_none());
+    }
+}
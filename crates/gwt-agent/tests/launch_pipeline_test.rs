@@ -224,6 +224,8 @@ fn prepare_agent_launch_composes_session_env_without_spawning() {
         codex_fast_mode: false,
         runtime_target: LaunchRuntimeTarget::Host,
         docker_service: None,
+        docker_profile: None,
+        runtime_rule: None,
         docker_lifecycle_intent: DockerLifecycleIntent::Connect,
         linked_issue_number: None,
         windows_shell: None,
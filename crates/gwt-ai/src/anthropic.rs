@@ -0,0 +1,243 @@
+//! Native Anthropic Messages API client.
+
+use reqwest::{
+    blocking::{Client, RequestBuilder},
+    header::{HeaderMap, HeaderValue},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        build_http_client, read_sse_data, send_with_retry, ChatMessage, MAX_OUTPUT_TOKENS,
+        TEMPERATURE,
+    },
+    error::AIError,
+    models_probe::{parse_models_response, ModelInfo},
+    provider::{split_system, AIProvider, AIProviderKind},
+};
+
+/// Default Anthropic API base URL.
+pub const DEFAULT_ANTHROPIC_ENDPOINT: &str = "https://api.anthropic.com/v1";
+/// Value sent in the required `anthropic-version` header.
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+// ── Request / Response wire types ──────────────────────────────────────
+
+#[derive(Debug, Serialize)]
+struct MessagesRequest<'a> {
+    model: &'a str,
+    max_tokens: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system: Option<String>,
+    messages: Vec<MessageParam<'a>>,
+    temperature: f32,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    stream: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct MessageParam<'a> {
+    role: &'a str,
+    content: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct MessagesResponse {
+    content: Vec<ContentBlock>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContentBlock {
+    #[serde(rename = "type")]
+    block_type: String,
+    text: Option<String>,
+}
+
+// ── AnthropicClient ────────────────────────────────────────────────────
+
+/// Blocking client for the Anthropic Messages API.
+#[derive(Debug)]
+pub struct AnthropicClient {
+    endpoint: String,
+    api_key: String,
+    model: String,
+    client: Client,
+}
+
+impl AnthropicClient {
+    /// Create a new client. Anthropic always requires an API key.
+    ///
+    /// # Errors
+    /// Returns [`AIError::ConfigError`] if `endpoint`, `api_key`, or `model`
+    /// is empty.
+    pub fn new(endpoint: &str, api_key: &str, model: &str) -> Result<Self, AIError> {
+        let endpoint = endpoint.trim().to_string();
+        let model = model.trim().to_string();
+        if endpoint.is_empty() {
+            return Err(AIError::ConfigError("API endpoint is empty".into()));
+        }
+        if api_key.trim().is_empty() {
+            return Err(AIError::ConfigError("Anthropic API key is empty".into()));
+        }
+        if model.is_empty() {
+            return Err(AIError::ConfigError("Model is empty".into()));
+        }
+        Ok(Self {
+            endpoint,
+            api_key: api_key.trim().to_string(),
+            model,
+            client: build_http_client()?,
+        })
+    }
+
+    fn messages_request(
+        &self,
+        messages: &[ChatMessage],
+        stream: bool,
+    ) -> Result<impl Fn() -> RequestBuilder + '_, AIError> {
+        if messages.is_empty() {
+            return Err(AIError::ConfigError("No input messages".into()));
+        }
+        let (system, turns) = split_system(messages);
+        let body = MessagesRequest {
+            model: &self.model,
+            max_tokens: MAX_OUTPUT_TOKENS,
+            system,
+            messages: turns
+                .iter()
+                .map(|msg| MessageParam {
+                    role: &msg.role,
+                    content: &msg.content,
+                })
+                .collect(),
+            temperature: TEMPERATURE,
+            stream,
+        };
+        let body = serde_json::to_value(body).map_err(|e| AIError::ParseError(e.to_string()))?;
+        let url = format!("{}/messages", self.endpoint.trim_end_matches('/'));
+        Ok(move || self.client.post(&url).headers(self.headers()).json(&body))
+    }
+
+    fn headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if let Ok(val) = HeaderValue::from_str(&self.api_key) {
+            headers.insert("x-api-key", val);
+        }
+        headers.insert(
+            "anthropic-version",
+            HeaderValue::from_static(ANTHROPIC_VERSION),
+        );
+        headers
+    }
+}
+
+impl AIProvider for AnthropicClient {
+    fn kind(&self) -> AIProviderKind {
+        AIProviderKind::Anthropic
+    }
+
+    fn create_response(&self, messages: Vec<ChatMessage>) -> Result<String, AIError> {
+        let raw = send_with_retry(self.messages_request(&messages, false)?)?
+            .text()
+            .map_err(|e| AIError::NetworkError(e.to_string()))?;
+        parse_text(&raw)
+    }
+
+    fn create_response_streaming(
+        &self,
+        messages: Vec<ChatMessage>,
+        on_delta: &mut dyn FnMut(&str),
+    ) -> Result<String, AIError> {
+        let resp = send_with_retry(self.messages_request(&messages, true)?)?;
+        let mut text = String::new();
+        read_sse_data(std::io::BufReader::new(resp), |data| {
+            if let Some(delta) = parse_stream_delta(data)? {
+                on_delta(&delta);
+                text.push_str(&delta);
+            }
+            Ok(())
+        })?;
+        Ok(text)
+    }
+
+    fn list_models(&self) -> Result<Vec<ModelInfo>, AIError> {
+        let url = format!("{}/models", self.endpoint.trim_end_matches('/'));
+        let raw = send_with_retry(|| self.client.get(&url).headers(self.headers()))?
+            .text()
+            .map_err(|e| AIError::NetworkError(e.to_string()))?;
+        // Anthropic uses the same `{"data":[{"id":...}]}` shape as OpenAI.
+        parse_models_response(&raw).map_err(|e| AIError::ParseError(e.to_string()))
+    }
+}
+
+// ── Free helpers ───────────────────────────────────────────────────────
+
+fn parse_text(raw: &str) -> Result<String, AIError> {
+    let resp: MessagesResponse = serde_json::from_str(raw)
+        .map_err(|e| AIError::ParseError(format!("Invalid JSON response: {e}")))?;
+    let text: String = resp
+        .content
+        .iter()
+        .filter(|block| block.block_type == "text")
+        .filter_map(|block| block.text.as_deref())
+        .collect();
+    if text.is_empty() {
+        return Err(AIError::ParseError(
+            "No text content found in response".into(),
+        ));
+    }
+    Ok(text)
+}
+
+/// Extract the text of a `content_block_delta` stream event; surfaces
+/// `error` events as [`AIError::ServerError`].
+fn parse_stream_delta(data: &str) -> Result<Option<String>, AIError> {
+    let event: serde_json::Value = serde_json::from_str(data)
+        .map_err(|e| AIError::ParseError(format!("Invalid stream event: {e}")))?;
+    match event["type"].as_str() {
+        Some("content_block_delta") => Ok(event["delta"]["text"].as_str().map(str::to_string)),
+        Some("error") => Err(AIError::ServerError(event["error"].to_string())),
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_requires_api_key() {
+        let err = AnthropicClient::new(DEFAULT_ANTHROPIC_ENDPOINT, " ", "claude").unwrap_err();
+        assert!(matches!(err, AIError::ConfigError(_)));
+    }
+
+    #[test]
+    fn parse_text_joins_text_blocks() {
+        let raw = r#"{
+            "content": [
+                {"type": "text", "text": "Hello"},
+                {"type": "tool_use", "id": "x"},
+                {"type": "text", "text": " world"}
+            ]
+        }"#;
+        assert_eq!(parse_text(raw).unwrap(), "Hello world");
+        assert!(parse_text(r#"{"content": []}"#).is_err());
+    }
+
+    #[test]
+    fn parse_stream_delta_reads_text_deltas_and_errors() {
+        assert_eq!(
+            parse_stream_delta(
+                r#"{"type":"content_block_delta","delta":{"type":"text_delta","text":"Hi"}}"#
+            )
+            .unwrap()
+            .as_deref(),
+            Some("Hi")
+        );
+        assert_eq!(parse_stream_delta(r#"{"type":"ping"}"#).unwrap(), None);
+        assert!(matches!(
+            parse_stream_delta(r#"{"type":"error","error":{"type":"overloaded_error"}}"#),
+            Err(AIError::ServerError(_))
+        ));
+    }
+}
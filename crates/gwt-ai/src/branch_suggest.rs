@@ -5,10 +5,7 @@
 
 use serde::Deserialize;
 
-use crate::{
-    client::{AIClient, ChatMessage},
-    error::AIError,
-};
+use crate::{client::ChatMessage, error::AIError, provider::AIProvider};
 
 const SYSTEM_PROMPT: &str = "\
 You are a git branch naming assistant. Generate 3 to 5 branch name suggestions \
@@ -112,16 +109,57 @@ pub fn parse_suggestions(response: &str) -> Result<Vec<String>, AIError> {
     Ok(valid)
 }
 
-/// Ask the AI client to suggest branch names for the given context.
+/// Validated names from the complete string literals of a possibly
+/// truncated `{"suggestions": [...]}` response.
 ///
-/// Returns 3-5 validated, git-safe branch name candidates.
-pub fn suggest_branch_name(client: &AIClient, context: &str) -> Result<Vec<String>, AIError> {
+/// Used while streaming: a name is only reported once its closing quote has
+/// arrived, so earlier results never change as more text streams in.
+pub fn partial_suggestions(response: &str) -> Vec<String> {
+    let Some(key) = response.find("\"suggestions\"") else {
+        return Vec::new();
+    };
+    let Some(open) = response[key..].find('[').map(|offset| key + offset) else {
+        return Vec::new();
+    };
+
+    let mut names: Vec<String> = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut escaped = false;
+    for (index, c) in response[open + 1..].char_indices() {
+        let index = open + 1 + index;
+        match start {
+            Some(begin) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == '"' {
+                    start = None;
+                    let name = serde_json::from_str::<String>(&response[begin..=index])
+                        .ok()
+                        .and_then(|raw| validate_branch_name(&raw));
+                    if let Some(name) = name {
+                        if !names.contains(&name) {
+                            names.push(name);
+                        }
+                    }
+                }
+            }
+            None if c == '"' => start = Some(index),
+            None if c == ']' => break,
+            None => {}
+        }
+    }
+    names.truncate(5);
+    names
+}
+
+fn suggestion_messages(context: &str) -> Result<Vec<ChatMessage>, AIError> {
     let context = context.trim();
     if context.is_empty() {
         return Err(AIError::ConfigError("Context is empty".into()));
     }
-
-    let messages = vec![
+    Ok(vec![
         ChatMessage {
             role: "system".into(),
             content: SYSTEM_PROMPT.into(),
@@ -130,12 +168,41 @@ pub fn suggest_branch_name(client: &AIClient, context: &str) -> Result<Vec<Strin
             role: "user".into(),
             content: context.into(),
         },
-    ];
+    ])
+}
 
+/// Ask the AI client to suggest branch names for the given context.
+///
+/// Returns 3-5 validated, git-safe branch name candidates.
+pub fn suggest_branch_name(client: &dyn AIProvider, context: &str) -> Result<Vec<String>, AIError> {
+    let messages = suggestion_messages(context)?;
     let response = client.create_response(messages)?;
     parse_suggestions(&response)
 }
 
+/// Streaming variant of [`suggest_branch_name`].
+///
+/// `on_partial` receives the validated names seen so far each time another
+/// one completes. The returned list is the final, fully validated result.
+pub fn suggest_branch_name_streaming(
+    client: &dyn AIProvider,
+    context: &str,
+    on_partial: &mut dyn FnMut(&[String]),
+) -> Result<Vec<String>, AIError> {
+    let messages = suggestion_messages(context)?;
+    let mut text = String::new();
+    let mut reported = 0;
+    let response = client.create_response_streaming(messages, &mut |delta| {
+        text.push_str(delta);
+        let partial = partial_suggestions(&text);
+        if partial.len() > reported {
+            reported = partial.len();
+            on_partial(&partial);
+        }
+    })?;
+    parse_suggestions(&response)
+}
+
 #[cfg(test)]
 mod tests {
     use std::process::Command;
@@ -273,12 +340,33 @@ mod tests {
         assert_eq!(result, vec!["release/v2", "release/v2-1", "release/v2-2"]);
     }
 
+    // ── partial_suggestions ────────────────────────────────────────────
+
+    #[test]
+    fn partial_suggestions_reports_only_completed_names() {
+        assert!(partial_suggestions(r#"{"sugg"#).is_empty());
+        assert_eq!(
+            partial_suggestions(r#"{"suggestions": ["feature/add-auth", "bugfix/fix-cr"#),
+            vec!["feature/add-auth"]
+        );
+        assert_eq!(
+            partial_suggestions(
+                r#"{"suggestions": ["feature/add-auth", "bad/name", "bugfix/fix-crash"]} "x""#
+            ),
+            vec!["feature/add-auth", "bugfix/fix-crash"]
+        );
+        assert_eq!(
+            partial_suggestions(r#"{"suggestions": ["feature/a\"b", "feature/a\"b"]}"#),
+            vec!["feature/ab"]
+        );
+    }
+
     // ── suggest_branch_name ────────────────────────────────────────────
 
     #[test]
     fn rejects_empty_context() {
         // We cannot call the real API, but we can test validation.
-        let client = AIClient::new("https://api.example.com", "k", "m").unwrap();
+        let client = crate::client::AIClient::new("https://api.example.com", "k", "m").unwrap();
         let err = suggest_branch_name(&client, "").unwrap_err();
         assert!(matches!(err, AIError::ConfigError(_)));
     }
@@ -1,20 +1,24 @@
 //! OpenAI Responses API client with retry and rate-limit handling.
 
-use std::time::Duration;
+use std::{io::BufRead, time::Duration};
 
 use reqwest::{
-    blocking::Client,
+    blocking::{Client, RequestBuilder, Response},
     header::{HeaderMap, HeaderValue, AUTHORIZATION},
     StatusCode,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::error::AIError;
+use crate::{
+    error::AIError,
+    models_probe::{parse_models_response, ModelInfo},
+    provider::{AIProvider, AIProviderKind},
+};
 
 const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
 const REQUEST_TIMEOUT: Duration = Duration::from_secs(120);
-const MAX_OUTPUT_TOKENS: u32 = 1024;
-const TEMPERATURE: f32 = 0.3;
+pub(crate) const MAX_OUTPUT_TOKENS: u32 = 1024;
+pub(crate) const TEMPERATURE: f32 = 0.3;
 
 const MAX_RETRIES: usize = 3;
 const BACKOFF_BASE_MS: u64 = 500;
@@ -85,11 +89,7 @@ impl AIClient {
             return Err(AIError::ConfigError("Model is empty".into()));
         }
 
-        let client = Client::builder()
-            .connect_timeout(CONNECT_TIMEOUT)
-            .timeout(REQUEST_TIMEOUT)
-            .build()
-            .map_err(|e| AIError::NetworkError(e.to_string()))?;
+        let client = build_http_client()?;
 
         Ok(Self {
             endpoint,
@@ -122,77 +122,205 @@ impl AIClient {
         parse_text(&raw)
     }
 
+    /// Send chat messages with `"stream": true` and forward each text delta
+    /// to `on_delta`. Returns the full concatenated text.
+    pub fn create_response_streaming(
+        &self,
+        messages: Vec<ChatMessage>,
+        on_delta: &mut dyn FnMut(&str),
+    ) -> Result<String, AIError> {
+        if messages.is_empty() {
+            return Err(AIError::ConfigError("No input messages".into()));
+        }
+
+        let url = build_responses_url(&self.endpoint)?;
+        let (instructions, input) = build_input(&messages);
+        let mut body = serde_json::to_value(ResponsesRequest {
+            model: &self.model,
+            input,
+            instructions,
+            max_output_tokens: MAX_OUTPUT_TOKENS,
+            temperature: TEMPERATURE,
+        })
+        .map_err(|e| AIError::ParseError(e.to_string()))?;
+        body["stream"] = serde_json::Value::Bool(true);
+
+        let resp = send_with_retry(|| {
+            self.client
+                .post(&url)
+                .headers(self.auth_headers())
+                .json(&body)
+        })?;
+        let mut text = String::new();
+        read_sse_data(std::io::BufReader::new(resp), |data| {
+            let event: serde_json::Value = serde_json::from_str(data)
+                .map_err(|e| AIError::ParseError(format!("Invalid stream event: {e}")))?;
+            if event["type"] == "response.output_text.delta" {
+                if let Some(delta) = event["delta"].as_str() {
+                    on_delta(delta);
+                    text.push_str(delta);
+                }
+            }
+            Ok(())
+        })?;
+        Ok(text)
+    }
+
+    /// List model ids via `GET {endpoint}/models`.
+    pub fn list_models(&self) -> Result<Vec<ModelInfo>, AIError> {
+        let url = format!("{}/models", self.endpoint.trim_end_matches('/'));
+        let raw = send_with_retry(|| self.client.get(&url).headers(self.auth_headers()))?
+            .text()
+            .map_err(|e| AIError::NetworkError(e.to_string()))?;
+        parse_models_response(&raw).map_err(|e| AIError::ParseError(e.to_string()))
+    }
+
     // ── internal helpers ───────────────────────────────────────────────
 
     fn send_with_retry(&self, url: &str, body: &impl Serialize) -> Result<String, AIError> {
-        let mut retries = 0usize;
+        send_with_retry(|| {
+            self.client
+                .post(url)
+                .headers(self.auth_headers())
+                .json(body)
+        })?
+        .text()
+        .map_err(|e| AIError::NetworkError(e.to_string()))
+    }
 
-        loop {
-            let headers = self.auth_headers();
-            let result = self.client.post(url).headers(headers).json(body).send();
+    fn auth_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if !self.api_key.is_empty() {
+            if let Ok(val) = HeaderValue::from_str(&format!("Bearer {}", self.api_key)) {
+                headers.insert(AUTHORIZATION, val);
+            }
+        }
+        headers
+    }
+}
 
-            match result {
-                Ok(resp) => {
-                    let status = resp.status();
-                    let resp_headers = resp.headers().clone();
-                    let text = resp.text().unwrap_or_default();
+impl AIProvider for AIClient {
+    fn kind(&self) -> AIProviderKind {
+        AIProviderKind::OpenAI
+    }
 
-                    if status == StatusCode::OK {
-                        return Ok(text);
-                    }
+    fn create_response(&self, messages: Vec<ChatMessage>) -> Result<String, AIError> {
+        AIClient::create_response(self, messages)
+    }
 
-                    if status == StatusCode::TOO_MANY_REQUESTS {
-                        let retry_after = resp_headers
-                            .get("retry-after")
-                            .and_then(|v| v.to_str().ok())
-                            .and_then(|v| v.parse::<u64>().ok());
+    fn create_response_streaming(
+        &self,
+        messages: Vec<ChatMessage>,
+        on_delta: &mut dyn FnMut(&str),
+    ) -> Result<String, AIError> {
+        AIClient::create_response_streaming(self, messages, on_delta)
+    }
 
-                        if retries >= MAX_RETRIES {
-                            return Err(AIError::RateLimited {
-                                retry_after_secs: retry_after,
-                            });
-                        }
-                        let wait = retry_after.unwrap_or(backoff_ms(retries) / 1000 + 1);
-                        std::thread::sleep(Duration::from_secs(wait));
-                        retries += 1;
-                        continue;
-                    }
+    fn list_models(&self) -> Result<Vec<ModelInfo>, AIError> {
+        AIClient::list_models(self)
+    }
+}
 
-                    if status.is_server_error() {
-                        if retries >= MAX_RETRIES {
-                            return Err(AIError::ServerError(format!("{status}: {text}")));
-                        }
-                        std::thread::sleep(Duration::from_millis(backoff_ms(retries)));
-                        retries += 1;
-                        continue;
-                    }
+/// Shared blocking HTTP client with the crate's connect/request timeouts.
+pub(crate) fn build_http_client() -> Result<Client, AIError> {
+    Client::builder()
+        .connect_timeout(CONNECT_TIMEOUT)
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| AIError::NetworkError(e.to_string()))
+}
 
-                    // Non-retryable client error
-                    return Err(AIError::ServerError(format!("{status}: {text}")));
+/// Send the request built by `build` and return the `200 OK` response.
+///
+/// Retries transient failures (429, 5xx, connection errors) with exponential
+/// back-off up to `MAX_RETRIES` times; 429 honours `retry-after`. `build` is
+/// called once per attempt. Shared by every provider.
+pub(crate) fn send_with_retry(build: impl Fn() -> RequestBuilder) -> Result<Response, AIError> {
+    let mut retries = 0usize;
+
+    loop {
+        match build().send() {
+            Ok(resp) => {
+                let status = resp.status();
+                if status == StatusCode::OK {
+                    return Ok(resp);
                 }
-                Err(e) => {
-                    if e.is_timeout() {
-                        return Err(AIError::Timeout(e.to_string()));
+                let resp_headers = resp.headers().clone();
+                let text = resp.text().unwrap_or_default();
+
+                if status == StatusCode::TOO_MANY_REQUESTS {
+                    let retry_after = resp_headers
+                        .get("retry-after")
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(|v| v.parse::<u64>().ok());
+
+                    if retries >= MAX_RETRIES {
+                        return Err(AIError::RateLimited {
+                            retry_after_secs: retry_after,
+                        });
                     }
+                    let wait = retry_after.unwrap_or(backoff_ms(retries) / 1000 + 1);
+                    std::thread::sleep(Duration::from_secs(wait));
+                    retries += 1;
+                    continue;
+                }
+
+                if status.is_server_error() {
                     if retries >= MAX_RETRIES {
-                        return Err(AIError::NetworkError(e.to_string()));
+                        return Err(AIError::ServerError(format!("{status}: {text}")));
                     }
                     std::thread::sleep(Duration::from_millis(backoff_ms(retries)));
                     retries += 1;
+                    continue;
+                }
+
+                // Non-retryable client error
+                return Err(AIError::ServerError(format!("{status}: {text}")));
+            }
+            Err(e) => {
+                if e.is_timeout() {
+                    return Err(AIError::Timeout(e.to_string()));
+                }
+                if retries >= MAX_RETRIES {
+                    return Err(AIError::NetworkError(e.to_string()));
                 }
+                std::thread::sleep(Duration::from_millis(backoff_ms(retries)));
+                retries += 1;
             }
         }
     }
+}
 
-    fn auth_headers(&self) -> HeaderMap {
-        let mut headers = HeaderMap::new();
-        if !self.api_key.is_empty() {
-            if let Ok(val) = HeaderValue::from_str(&format!("Bearer {}", self.api_key)) {
-                headers.insert(AUTHORIZATION, val);
+/// Feed the `data:` payload of each server-sent event to `on_data`.
+/// Multi-line data fields are joined with `\n`; `[DONE]` ends the stream.
+pub(crate) fn read_sse_data(
+    reader: impl BufRead,
+    mut on_data: impl FnMut(&str) -> Result<(), AIError>,
+) -> Result<(), AIError> {
+    let mut data = String::new();
+    for line in reader.lines() {
+        let line = line.map_err(|e| AIError::NetworkError(e.to_string()))?;
+        if line.is_empty() {
+            if !data.is_empty() {
+                if data == "[DONE]" {
+                    return Ok(());
+                }
+                on_data(&data)?;
+                data.clear();
             }
+            continue;
         }
-        headers
+        if let Some(value) = line.strip_prefix("data:") {
+            if !data.is_empty() {
+                data.push('\n');
+            }
+            data.push_str(value.strip_prefix(' ').unwrap_or(value));
+        }
+    }
+    if !data.is_empty() && data != "[DONE]" {
+        on_data(&data)?;
     }
+    Ok(())
 }
 
 // ── Free helpers ───────────────────────────────────────────────────────
@@ -355,6 +483,18 @@ mod tests {
 
     // ── backoff_ms ─────────────────────────────────────────────────────
 
+    #[test]
+    fn read_sse_data_joins_events_and_stops_at_done() {
+        let stream = "event: a\ndata: {\"x\":1}\n\ndata: line1\ndata: line2\n\ndata: [DONE]\n\ndata: ignored\n\n";
+        let mut seen = Vec::new();
+        read_sse_data(stream.as_bytes(), |data| {
+            seen.push(data.to_string());
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(seen, vec!["{\"x\":1}", "line1\nline2"]);
+    }
+
     #[test]
     fn backoff_ms_grows_exponentially() {
         assert_eq!(backoff_ms(0), 500);
@@ -0,0 +1,143 @@
+//! AI-suggested commit messages for the staged changes.
+//!
+//! The staged diff and a few recent subjects (so the suggestion follows the
+//! repository's existing style) are sent; the reply is used as-is as the
+//! starting text of the commit message, which the user still edits and
+//! confirms.
+
+use crate::{client::ChatMessage, error::AIError, provider::AIProvider};
+
+const SYSTEM_PROMPT: &str = "\
+You write git commit messages for staged changes. You are given the staged \
+diff and, when available, recent commit subjects from the same repository.\n\n\
+Rules:\n\
+- First line: an imperative summary of at most 72 characters. Follow the \
+style of the recent subjects (for example a `feat:` / `fix:` prefix) when \
+they share one.\n\
+- If the change needs explaining, add a blank line and a short body that \
+says what changed and why, wrapped at 72 characters.\n\
+- Only describe what the diff shows.\n\
+- Reply with the commit message only: no code fences, no quotes, no \
+commentary.";
+
+/// Diffs longer than this are cut before sending; the AI is told so.
+const MAX_DIFF_CHARS: usize = 24_000;
+
+/// Recent subjects included as style examples.
+pub const STYLE_SUBJECT_COUNT: usize = 10;
+
+fn build_user_payload(staged_diff: &str, recent_subjects: &[String]) -> String {
+    let mut payload = String::new();
+    if !recent_subjects.is_empty() {
+        payload.push_str("Recent commit subjects:\n");
+        for subject in recent_subjects.iter().take(STYLE_SUBJECT_COUNT) {
+            payload.push_str(&format!("- {subject}\n"));
+        }
+        payload.push('\n');
+    }
+    let diff = staged_diff.trim_end();
+    match diff.char_indices().nth(MAX_DIFF_CHARS) {
+        Some((cut, _)) => payload.push_str(&format!(
+            "Staged diff:\n{}\n[diff truncated after {MAX_DIFF_CHARS} characters]",
+            &diff[..cut]
+        )),
+        None => payload.push_str(&format!("Staged diff:\n{diff}")),
+    }
+    payload
+}
+
+/// Drop a surrounding code fence the model added despite the instructions.
+fn strip_fence(reply: &str) -> &str {
+    let reply = reply.trim();
+    let Some(inner) = reply.strip_prefix("```") else {
+        return reply;
+    };
+    let Some(inner) = inner.strip_suffix("```") else {
+        return reply;
+    };
+    // Skip an info string such as ```text on the opening line.
+    match inner.split_once('\n') {
+        Some((_, body)) => body.trim(),
+        None => inner.trim(),
+    }
+}
+
+/// Ask the AI for a commit message describing `staged_diff`.
+pub fn suggest_commit_message(
+    client: &dyn AIProvider,
+    staged_diff: &str,
+    recent_subjects: &[String],
+) -> Result<String, AIError> {
+    if staged_diff.trim().is_empty() {
+        return Err(AIError::ConfigError("nothing is staged".to_string()));
+    }
+    let messages = vec![
+        ChatMessage {
+            role: "system".into(),
+            content: SYSTEM_PROMPT.into(),
+        },
+        ChatMessage {
+            role: "user".into(),
+            content: build_user_payload(staged_diff, recent_subjects),
+        },
+    ];
+    let reply = client.create_response(messages)?;
+    let message = strip_fence(&reply);
+    if message.is_empty() {
+        return Err(AIError::ParseError("empty commit message".to_string()));
+    }
+    Ok(message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{models_probe::ModelInfo, provider::AIProviderKind};
+
+    struct FencedProvider;
+
+    impl AIProvider for FencedProvider {
+        fn kind(&self) -> AIProviderKind {
+            AIProviderKind::OpenAI
+        }
+
+        fn create_response(&self, messages: Vec<ChatMessage>) -> Result<String, AIError> {
+            assert!(messages[1]
+                .content
+                .starts_with("Recent commit subjects:\n- feat: earlier\n"));
+            Ok("```text\nfix: guard empty input\n\nReturn early.\n```\n".to_string())
+        }
+
+        fn create_response_streaming(
+            &self,
+            messages: Vec<ChatMessage>,
+            _on_delta: &mut dyn FnMut(&str),
+        ) -> Result<String, AIError> {
+            self.create_response(messages)
+        }
+
+        fn list_models(&self) -> Result<Vec<ModelInfo>, AIError> {
+            Ok(Vec::new())
+        }
+    }
+
+    #[test]
+    fn suggestion_strips_code_fence() {
+        let subjects = vec!["feat: earlier".to_string()];
+        let message =
+            suggest_commit_message(&FencedProvider, "+if x.is_empty() { return; }\n", &subjects)
+                .unwrap();
+        assert_eq!(message, "fix: guard empty input\n\nReturn early.");
+        assert!(suggest_commit_message(&FencedProvider, "\n", &subjects).is_err());
+        assert_eq!(strip_fence("plain subject"), "plain subject");
+    }
+
+    #[test]
+    fn payload_truncates_oversized_diffs() {
+        let diff = "+x\n".repeat(MAX_DIFF_CHARS);
+        let payload = build_user_payload(&diff, &[]);
+        assert!(payload.starts_with("Staged diff:\n"));
+        assert!(payload.ends_with("characters]"));
+        assert!(payload.len() < diff.len() + 100);
+    }
+}
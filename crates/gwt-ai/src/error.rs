@@ -31,4 +31,8 @@ pub enum AIError {
     /// The request timed out.
     #[error("timeout: {0}")]
     Timeout(String),
+
+    /// The job was cancelled from the AI job queue.
+    #[error("cancelled")]
+    Cancelled,
 }
@@ -0,0 +1,279 @@
+//! Native Gemini `generateContent` API client.
+
+use reqwest::{
+    blocking::{Client, RequestBuilder},
+    header::{HeaderMap, HeaderValue},
+};
+use serde::Deserialize;
+
+use crate::{
+    client::{
+        build_http_client, read_sse_data, send_with_retry, ChatMessage, MAX_OUTPUT_TOKENS,
+        TEMPERATURE,
+    },
+    error::AIError,
+    models_probe::ModelInfo,
+    provider::{split_system, AIProvider, AIProviderKind},
+};
+
+/// Default Gemini API base URL.
+pub const DEFAULT_GEMINI_ENDPOINT: &str = "https://generativelanguage.googleapis.com/v1beta";
+
+// ── Response wire types ────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct GenerateContentResponse {
+    #[serde(default)]
+    candidates: Vec<Candidate>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Candidate {
+    content: Option<Content>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Content {
+    #[serde(default)]
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Part {
+    text: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ModelsResponse {
+    #[serde(default)]
+    models: Vec<ModelEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ModelEntry {
+    name: String,
+}
+
+// ── GeminiClient ───────────────────────────────────────────────────────
+
+/// Blocking client for the Gemini `generateContent` API.
+#[derive(Debug)]
+pub struct GeminiClient {
+    endpoint: String,
+    api_key: String,
+    model: String,
+    client: Client,
+}
+
+impl GeminiClient {
+    /// Create a new client. Gemini always requires an API key.
+    ///
+    /// # Errors
+    /// Returns [`AIError::ConfigError`] if `endpoint`, `api_key`, or `model`
+    /// is empty.
+    pub fn new(endpoint: &str, api_key: &str, model: &str) -> Result<Self, AIError> {
+        let endpoint = endpoint.trim().to_string();
+        // Accept both `gemini-2.0-flash` and the `models/...` resource name.
+        let model = model.trim().trim_start_matches("models/").to_string();
+        if endpoint.is_empty() {
+            return Err(AIError::ConfigError("API endpoint is empty".into()));
+        }
+        if api_key.trim().is_empty() {
+            return Err(AIError::ConfigError("Gemini API key is empty".into()));
+        }
+        if model.is_empty() {
+            return Err(AIError::ConfigError("Model is empty".into()));
+        }
+        Ok(Self {
+            endpoint,
+            api_key: api_key.trim().to_string(),
+            model,
+            client: build_http_client()?,
+        })
+    }
+
+    fn generate_request(
+        &self,
+        messages: &[ChatMessage],
+        stream: bool,
+    ) -> Result<impl Fn() -> RequestBuilder + '_, AIError> {
+        if messages.is_empty() {
+            return Err(AIError::ConfigError("No input messages".into()));
+        }
+        let body = build_body(messages);
+        let base = self.endpoint.trim_end_matches('/');
+        let url = if stream {
+            format!("{base}/models/{}:streamGenerateContent?alt=sse", self.model)
+        } else {
+            format!("{base}/models/{}:generateContent", self.model)
+        };
+        Ok(move || self.client.post(&url).headers(self.headers()).json(&body))
+    }
+
+    fn headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if let Ok(val) = HeaderValue::from_str(&self.api_key) {
+            headers.insert("x-goog-api-key", val);
+        }
+        headers
+    }
+}
+
+impl AIProvider for GeminiClient {
+    fn kind(&self) -> AIProviderKind {
+        AIProviderKind::Gemini
+    }
+
+    fn create_response(&self, messages: Vec<ChatMessage>) -> Result<String, AIError> {
+        let raw = send_with_retry(self.generate_request(&messages, false)?)?
+            .text()
+            .map_err(|e| AIError::NetworkError(e.to_string()))?;
+        let text = parse_text(&raw)?;
+        if text.is_empty() {
+            return Err(AIError::ParseError(
+                "No text content found in response".into(),
+            ));
+        }
+        Ok(text)
+    }
+
+    fn create_response_streaming(
+        &self,
+        messages: Vec<ChatMessage>,
+        on_delta: &mut dyn FnMut(&str),
+    ) -> Result<String, AIError> {
+        let resp = send_with_retry(self.generate_request(&messages, true)?)?;
+        let mut text = String::new();
+        // Each SSE event is a complete `GenerateContentResponse` chunk.
+        read_sse_data(std::io::BufReader::new(resp), |data| {
+            let delta = parse_text(data)?;
+            if !delta.is_empty() {
+                on_delta(&delta);
+                text.push_str(&delta);
+            }
+            Ok(())
+        })?;
+        Ok(text)
+    }
+
+    fn list_models(&self) -> Result<Vec<ModelInfo>, AIError> {
+        let url = format!("{}/models", self.endpoint.trim_end_matches('/'));
+        let raw = send_with_retry(|| self.client.get(&url).headers(self.headers()))?
+            .text()
+            .map_err(|e| AIError::NetworkError(e.to_string()))?;
+        parse_models(&raw)
+    }
+}
+
+// ── Free helpers ───────────────────────────────────────────────────────
+
+/// Map chat messages to a `generateContent` body. Gemini names the
+/// assistant role `model`.
+fn build_body(messages: &[ChatMessage]) -> serde_json::Value {
+    let (system, turns) = split_system(messages);
+    let contents: Vec<serde_json::Value> = turns
+        .iter()
+        .map(|msg| {
+            let role = if msg.role == "assistant" {
+                "model"
+            } else {
+                "user"
+            };
+            serde_json::json!({ "role": role, "parts": [{ "text": msg.content }] })
+        })
+        .collect();
+    let mut body = serde_json::json!({
+        "contents": contents,
+        "generationConfig": {
+            "maxOutputTokens": MAX_OUTPUT_TOKENS,
+            "temperature": TEMPERATURE,
+        },
+    });
+    if let Some(system) = system {
+        body["systemInstruction"] = serde_json::json!({ "parts": [{ "text": system }] });
+    }
+    body
+}
+
+/// Concatenate the text parts of the first candidate. An empty string means
+/// the chunk carried no text (e.g. a final usage-only stream event).
+fn parse_text(raw: &str) -> Result<String, AIError> {
+    let resp: GenerateContentResponse = serde_json::from_str(raw)
+        .map_err(|e| AIError::ParseError(format!("Invalid JSON response: {e}")))?;
+    Ok(resp
+        .candidates
+        .first()
+        .and_then(|candidate| candidate.content.as_ref())
+        .map(|content| {
+            content
+                .parts
+                .iter()
+                .filter_map(|part| part.text.as_deref())
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+fn parse_models(raw: &str) -> Result<Vec<ModelInfo>, AIError> {
+    let resp: ModelsResponse = serde_json::from_str(raw)
+        .map_err(|e| AIError::ParseError(format!("Invalid JSON response: {e}")))?;
+    Ok(resp
+        .models
+        .into_iter()
+        .map(|model| ModelInfo {
+            id: model.name.trim_start_matches("models/").to_string(),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_body_maps_roles_and_system_instruction() {
+        let body = build_body(&[
+            ChatMessage {
+                role: "system".into(),
+                content: "Be brief.".into(),
+            },
+            ChatMessage {
+                role: "user".into(),
+                content: "Hi".into(),
+            },
+            ChatMessage {
+                role: "assistant".into(),
+                content: "Hello".into(),
+            },
+        ]);
+        assert_eq!(body["systemInstruction"]["parts"][0]["text"], "Be brief.");
+        assert_eq!(body["contents"][0]["role"], "user");
+        assert_eq!(body["contents"][1]["role"], "model");
+        assert_eq!(body["contents"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn parse_text_joins_first_candidate_parts() {
+        let raw = r#"{"candidates":[{"content":{"role":"model","parts":[{"text":"Hel"},{"text":"lo"}]}}]}"#;
+        assert_eq!(parse_text(raw).unwrap(), "Hello");
+        assert_eq!(parse_text(r#"{"usageMetadata":{}}"#).unwrap(), "");
+        assert!(parse_text("nope").is_err());
+    }
+
+    #[test]
+    fn parse_models_strips_resource_prefix() {
+        let raw = r#"{"models":[{"name":"models/gemini-2.0-flash"},{"name":"models/gemini-pro"}]}"#;
+        let ids: Vec<String> = parse_models(raw)
+            .unwrap()
+            .into_iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(ids, vec!["gemini-2.0-flash", "gemini-pro"]);
+    }
+
+    #[test]
+    fn new_normalizes_model_resource_name() {
+        let client = GeminiClient::new(DEFAULT_GEMINI_ENDPOINT, "k", "models/gemini-pro").unwrap();
+        assert_eq!(client.model, "gemini-pro");
+    }
+}
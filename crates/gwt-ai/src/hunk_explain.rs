@@ -0,0 +1,207 @@
+//! "Explain this change" for a single diff hunk.
+//!
+//! Only the file path and the hunk itself (its `@@` header plus the context
+//! lines git already includes) are sent. Explanations are cached by
+//! [`hunk_hash`], so re-opening the same change does not call the AI again;
+//! any edit to the hunk changes the hash and yields a fresh explanation.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, VecDeque},
+    hash::{Hash, Hasher},
+    sync::Mutex,
+};
+
+use crate::{client::ChatMessage, error::AIError, provider::AIProvider};
+
+const SYSTEM_PROMPT: &str = "\
+You explain code changes to a developer reviewing what a coding agent changed \
+in unfamiliar code. You are given a file path and one unified diff hunk.\n\n\
+Rules:\n\
+- Say what the change does and why it plausibly matters, in 2 to 5 sentences.\n\
+- Point out anything that looks risky or incomplete (behaviour changes, \
+removed checks, unhandled errors). Say nothing about style.\n\
+- Only describe what the hunk shows; do not guess at code you cannot see.\n\
+- Plain text, no headings, no restating the diff line by line.";
+
+/// Hunks longer than this are cut before sending; the AI is told so.
+const MAX_HUNK_CHARS: usize = 12_000;
+
+/// Explanations kept by [`HunkExplanationCache::default`].
+pub const DEFAULT_CACHE_CAPACITY: usize = 256;
+
+/// Cache key for a hunk: identical path and hunk text (ignoring trailing
+/// whitespace) give the same key within one process.
+pub fn hunk_hash(path: &str, hunk: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    path.hash(&mut hasher);
+    hunk.trim_end().hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+fn build_user_payload(path: &str, hunk: &str) -> String {
+    let hunk = hunk.trim_end();
+    match hunk.char_indices().nth(MAX_HUNK_CHARS) {
+        Some((cut, _)) => format!(
+            "File: {path}\n\n{}\n[hunk truncated after {MAX_HUNK_CHARS} characters]",
+            &hunk[..cut]
+        ),
+        None => format!("File: {path}\n\n{hunk}"),
+    }
+}
+
+/// Ask the AI to explain one hunk of `path`.
+pub fn explain_hunk(client: &dyn AIProvider, path: &str, hunk: &str) -> Result<String, AIError> {
+    let messages = vec![
+        ChatMessage {
+            role: "system".into(),
+            content: SYSTEM_PROMPT.into(),
+        },
+        ChatMessage {
+            role: "user".into(),
+            content: build_user_payload(path, hunk),
+        },
+    ];
+    let explanation = client.create_response(messages)?.trim().to_string();
+    if explanation.is_empty() {
+        return Err(AIError::ParseError("empty hunk explanation".to_string()));
+    }
+    Ok(explanation)
+}
+
+/// Bounded, process-wide store of explanations keyed by [`hunk_hash`].
+/// The oldest entry is evicted once `capacity` is reached.
+#[derive(Debug)]
+pub struct HunkExplanationCache {
+    capacity: usize,
+    inner: Mutex<(HashMap<String, String>, VecDeque<String>)>,
+}
+
+impl Default for HunkExplanationCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_CAPACITY)
+    }
+}
+
+impl HunkExplanationCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            inner: Mutex::new((HashMap::new(), VecDeque::new())),
+        }
+    }
+
+    pub fn get(&self, hash: &str) -> Option<String> {
+        let guard = self
+            .inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        guard.0.get(hash).cloned()
+    }
+
+    pub fn insert(&self, hash: String, explanation: String) {
+        let mut guard = self
+            .inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let (entries, order) = &mut *guard;
+        if entries.insert(hash.clone(), explanation).is_none() {
+            order.push_back(hash);
+        }
+        while order.len() > self.capacity {
+            if let Some(oldest) = order.pop_front() {
+                entries.remove(&oldest);
+            }
+        }
+    }
+
+    /// Cached explanation for the hunk, or a fresh one from `client` which
+    /// is then cached. The flag is `true` when the answer came from cache.
+    pub fn explain(
+        &self,
+        client: &dyn AIProvider,
+        path: &str,
+        hunk: &str,
+    ) -> Result<(String, bool), AIError> {
+        let hash = hunk_hash(path, hunk);
+        if let Some(explanation) = self.get(&hash) {
+            return Ok((explanation, true));
+        }
+        let explanation = explain_hunk(client, path, hunk)?;
+        self.insert(hash, explanation.clone());
+        Ok((explanation, false))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::{models_probe::ModelInfo, provider::AIProviderKind};
+
+    struct CountingProvider {
+        calls: AtomicUsize,
+    }
+
+    impl AIProvider for CountingProvider {
+        fn kind(&self) -> AIProviderKind {
+            AIProviderKind::OpenAI
+        }
+
+        fn create_response(&self, messages: Vec<ChatMessage>) -> Result<String, AIError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            assert!(messages[1].content.starts_with("File: src/lib.rs"));
+            Ok("  Adds a bounds check.\n".to_string())
+        }
+
+        fn create_response_streaming(
+            &self,
+            messages: Vec<ChatMessage>,
+            _on_delta: &mut dyn FnMut(&str),
+        ) -> Result<String, AIError> {
+            self.create_response(messages)
+        }
+
+        fn list_models(&self) -> Result<Vec<ModelInfo>, AIError> {
+            Ok(Vec::new())
+        }
+    }
+
+    const HUNK: &str = "@@ -1,2 +1,3 @@ fn get\n let v = xs[i];\n+assert!(i < xs.len());\n";
+
+    #[test]
+    fn cache_reuses_explanation_for_identical_hunk() {
+        let provider = CountingProvider {
+            calls: AtomicUsize::new(0),
+        };
+        let cache = HunkExplanationCache::default();
+        let (first, cached) = cache.explain(&provider, "src/lib.rs", HUNK).unwrap();
+        assert_eq!((first.as_str(), cached), ("Adds a bounds check.", false));
+        let (_, cached) = cache.explain(&provider, "src/lib.rs", HUNK).unwrap();
+        assert!(cached);
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 1);
+
+        let edited = HUNK.replace("xs.len()", "xs.len() - 1");
+        cache.explain(&provider, "src/lib.rs", &edited).unwrap();
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn cache_evicts_oldest_entry_past_capacity() {
+        let cache = HunkExplanationCache::new(2);
+        cache.insert("a".into(), "1".into());
+        cache.insert("b".into(), "2".into());
+        cache.insert("c".into(), "3".into());
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.get("c").as_deref(), Some("3"));
+    }
+
+    #[test]
+    fn payload_truncates_oversized_hunks() {
+        let hunk = "+x\n".repeat(MAX_HUNK_CHARS);
+        let payload = build_user_payload("a.rs", &hunk);
+        assert!(payload.ends_with("characters]"));
+        assert!(payload.len() < hunk.len() + 100);
+        assert_eq!(hunk_hash("a.rs", "+x\n"), hunk_hash("a.rs", "+x"));
+    }
+}
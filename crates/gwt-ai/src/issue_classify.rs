@@ -3,10 +3,7 @@
 //! Determines the branch-type category prefix for a GitHub issue
 //! (feature, bugfix, hotfix, or release) based on title and body.
 
-use crate::{
-    client::{AIClient, ChatMessage},
-    error::AIError,
-};
+use crate::{client::ChatMessage, error::AIError, provider::AIProvider};
 
 const SYSTEM_PROMPT: &str = "\
 You are a branch prefix classifier for GitHub issues. Based on the issue title \
@@ -53,7 +50,7 @@ pub fn parse_classify_response(response: &str) -> Result<String, AIError> {
 /// Classify a GitHub issue into a branch prefix using AI.
 ///
 /// Returns one of: `"feature"`, `"bugfix"`, `"hotfix"`, `"release"`.
-pub fn classify_issue(client: &AIClient, title: &str, body: &str) -> Result<String, AIError> {
+pub fn classify_issue(client: &dyn AIProvider, title: &str, body: &str) -> Result<String, AIError> {
     let title = title.trim();
     if title.is_empty() {
         return Err(AIError::ConfigError("Issue title is empty".into()));
@@ -152,7 +149,7 @@ mod tests {
 
     #[test]
     fn classify_rejects_empty_title() {
-        let client = AIClient::new("https://api.example.com", "k", "m").unwrap();
+        let client = crate::client::AIClient::new("https://api.example.com", "k", "m").unwrap();
         let err = classify_issue(&client, "", "body").unwrap_err();
         assert!(matches!(err, AIError::ConfigError(_)));
     }
@@ -0,0 +1,345 @@
+//! Process-wide queue for AI requests.
+//!
+//! Summaries, suggestions and explanations all call the configured provider.
+//! Routing them through [`run_ai_job`] caps how many run at once, starts
+//! interactive requests ahead of queued background batches, lets a job be
+//! cancelled, and reports every change to a listener (the GUI status strip).
+//!
+//! Requests are blocking HTTP calls, so cancelling a running job cannot stop
+//! the request: its result is discarded and the caller gets
+//! [`AIError::Cancelled`]. A queued job is dropped right away.
+
+use std::sync::{Arc, Condvar, Mutex, OnceLock, PoisonError};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AIError;
+
+/// Jobs the global queue runs at the same time.
+pub const MAX_RUNNING_AI_JOBS: usize = 2;
+
+/// Scheduling class of a job. Interactive jobs start before any queued
+/// background job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AiJobPriority {
+    /// Someone is waiting for the answer (a suggestion, an explanation).
+    Interactive,
+    /// Batch work nobody is waiting on (work summaries).
+    Background,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AiJobState {
+    Queued,
+    Running,
+}
+
+/// One job as shown in the status strip.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AiJobView {
+    pub id: u64,
+    pub label: String,
+    pub priority: AiJobPriority,
+    pub state: AiJobState,
+    /// Cancelled while running; the result will be discarded.
+    pub cancelled: bool,
+}
+
+/// Queue snapshot handed to the listener.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AiQueueStatus {
+    pub running: usize,
+    pub queued: usize,
+    /// Running jobs first, then queued jobs in the order they will start.
+    pub jobs: Vec<AiJobView>,
+}
+
+type Listener = Arc<dyn Fn(AiQueueStatus) + Send + Sync>;
+
+#[derive(Debug)]
+struct Job {
+    id: u64,
+    label: String,
+    priority: AiJobPriority,
+    state: AiJobState,
+    cancelled: bool,
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    next_id: u64,
+    jobs: Vec<Job>,
+}
+
+impl QueueState {
+    fn running(&self) -> usize {
+        self.jobs
+            .iter()
+            .filter(|job| job.state == AiJobState::Running)
+            .count()
+    }
+
+    /// The queued job that starts next.
+    fn next_queued(&self) -> Option<u64> {
+        self.jobs
+            .iter()
+            .filter(|job| job.state == AiJobState::Queued)
+            .min_by_key(|job| (job.priority, job.id))
+            .map(|job| job.id)
+    }
+
+    fn status(&self) -> AiQueueStatus {
+        let mut jobs: Vec<&Job> = self.jobs.iter().collect();
+        jobs.sort_by_key(|job| (job.state == AiJobState::Queued, job.priority, job.id));
+        AiQueueStatus {
+            running: self.running(),
+            queued: self.jobs.len() - self.running(),
+            jobs: jobs
+                .into_iter()
+                .map(|job| AiJobView {
+                    id: job.id,
+                    label: job.label.clone(),
+                    priority: job.priority,
+                    state: job.state,
+                    cancelled: job.cancelled,
+                })
+                .collect(),
+        }
+    }
+
+    fn remove(&mut self, id: u64) -> Option<Job> {
+        let index = self.jobs.iter().position(|job| job.id == id)?;
+        Some(self.jobs.remove(index))
+    }
+}
+
+/// A bounded, prioritized queue of AI jobs.
+pub struct AiJobQueue {
+    max_running: usize,
+    state: Mutex<QueueState>,
+    changed: Condvar,
+    listener: Mutex<Option<Listener>>,
+}
+
+impl AiJobQueue {
+    pub fn new(max_running: usize) -> Self {
+        Self {
+            max_running: max_running.max(1),
+            state: Mutex::new(QueueState::default()),
+            changed: Condvar::new(),
+            listener: Mutex::new(None),
+        }
+    }
+
+    /// Call `listener` with a fresh snapshot after every change.
+    pub fn set_listener(&self, listener: impl Fn(AiQueueStatus) + Send + Sync + 'static) {
+        *self.listener.lock().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(listener));
+    }
+
+    pub fn status(&self) -> AiQueueStatus {
+        self.lock().status()
+    }
+
+    /// Wait for a slot, run `job` on the calling thread and release the
+    /// slot. Returns [`AIError::Cancelled`] when the job was cancelled before
+    /// or while it ran.
+    pub fn run<T>(
+        &self,
+        label: impl Into<String>,
+        priority: AiJobPriority,
+        job: impl FnOnce() -> Result<T, AIError>,
+    ) -> Result<T, AIError> {
+        let id = {
+            let mut state = self.lock();
+            state.next_id += 1;
+            let id = state.next_id;
+            state.jobs.push(Job {
+                id,
+                label: label.into(),
+                priority,
+                state: AiJobState::Queued,
+                cancelled: false,
+            });
+            id
+        };
+        self.notify();
+
+        {
+            let mut state = self.lock();
+            loop {
+                if !state.jobs.iter().any(|job| job.id == id) {
+                    // Cancelled while queued.
+                    return Err(AIError::Cancelled);
+                }
+                if state.running() < self.max_running && state.next_queued() == Some(id) {
+                    if let Some(current) = state.jobs.iter_mut().find(|job| job.id == id) {
+                        current.state = AiJobState::Running;
+                    }
+                    break;
+                }
+                state = self
+                    .changed
+                    .wait(state)
+                    .unwrap_or_else(PoisonError::into_inner);
+            }
+        }
+        self.notify();
+
+        let result = job();
+        let finished = self.lock().remove(id);
+        self.notify();
+        match finished {
+            Some(job) if !job.cancelled => result,
+            _ => Err(AIError::Cancelled),
+        }
+    }
+
+    /// Cancel job `id`. A queued job is dropped; a running job finishes but
+    /// its result is discarded. Returns `false` for an unknown id.
+    pub fn cancel(&self, id: u64) -> bool {
+        let found = {
+            let mut state = self.lock();
+            match state.jobs.iter().position(|job| job.id == id) {
+                Some(index) if state.jobs[index].state == AiJobState::Queued => {
+                    state.jobs.remove(index);
+                    true
+                }
+                Some(index) => {
+                    state.jobs[index].cancelled = true;
+                    true
+                }
+                None => false,
+            }
+        };
+        if found {
+            self.notify();
+        }
+        found
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, QueueState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Wake waiting jobs and tell the listener, outside the state lock.
+    fn notify(&self) {
+        self.changed.notify_all();
+        let listener = self
+            .listener
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        if let Some(listener) = listener {
+            listener(self.status());
+        }
+    }
+}
+
+/// The queue shared by every AI request of this process.
+pub fn global_queue() -> &'static AiJobQueue {
+    static QUEUE: OnceLock<AiJobQueue> = OnceLock::new();
+    QUEUE.get_or_init(|| AiJobQueue::new(MAX_RUNNING_AI_JOBS))
+}
+
+/// Run `job` through the [`global_queue`].
+pub fn run_ai_job<T>(
+    label: impl Into<String>,
+    priority: AiJobPriority,
+    job: impl FnOnce() -> Result<T, AIError>,
+) -> Result<T, AIError> {
+    global_queue().run(label, priority, job)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::mpsc, thread, time::Duration};
+
+    use super::*;
+
+    fn wait_for(queue: &AiJobQueue, check: impl Fn(&AiQueueStatus) -> bool) {
+        for _ in 0..200 {
+            if check(&queue.status()) {
+                return;
+            }
+            thread::sleep(Duration::from_millis(5));
+        }
+        panic!(
+            "queue never reached the expected state: {:?}",
+            queue.status()
+        );
+    }
+
+    #[test]
+    fn interactive_jobs_start_before_queued_background_jobs() {
+        let queue = Arc::new(AiJobQueue::new(1));
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let (order_tx, order_rx) = mpsc::channel::<&'static str>();
+
+        let blocker = {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                queue.run("blocker", AiJobPriority::Background, || {
+                    release_rx.recv().unwrap();
+                    Ok(())
+                })
+            })
+        };
+        wait_for(&queue, |status| status.running == 1);
+
+        let spawn_job = |label: &'static str, priority| {
+            let queue = queue.clone();
+            let order_tx = order_tx.clone();
+            thread::spawn(move || {
+                queue.run(label, priority, || {
+                    order_tx.send(label).unwrap();
+                    Ok(())
+                })
+            })
+        };
+        let background = spawn_job("summary", AiJobPriority::Background);
+        wait_for(&queue, |status| status.queued == 1);
+        let interactive = spawn_job("suggestion", AiJobPriority::Interactive);
+        wait_for(&queue, |status| status.queued == 2);
+        assert_eq!(queue.status().jobs[1].label, "suggestion");
+
+        release_tx.send(()).unwrap();
+        for handle in [blocker, background, interactive] {
+            handle.join().unwrap().unwrap();
+        }
+        let order: Vec<&str> = order_rx.try_iter().collect();
+        assert_eq!(order, ["suggestion", "summary"]);
+        assert_eq!(queue.status(), AiQueueStatus::default());
+    }
+
+    #[test]
+    fn cancelled_jobs_return_cancelled() {
+        let queue = Arc::new(AiJobQueue::new(1));
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let running = {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                queue.run("running", AiJobPriority::Interactive, || {
+                    release_rx.recv().unwrap();
+                    Ok(1)
+                })
+            })
+        };
+        wait_for(&queue, |status| status.running == 1);
+        let queued = {
+            let queue = queue.clone();
+            thread::spawn(move || queue.run("queued", AiJobPriority::Background, || Ok(2)))
+        };
+        wait_for(&queue, |status| status.queued == 1);
+
+        let ids: Vec<u64> = queue.status().jobs.iter().map(|job| job.id).collect();
+        assert!(queue.cancel(ids[1]));
+        assert!(matches!(queued.join().unwrap(), Err(AIError::Cancelled)));
+        assert!(queue.cancel(ids[0]));
+        assert!(queue.status().jobs[0].cancelled);
+        release_tx.send(()).unwrap();
+        assert!(matches!(running.join().unwrap(), Err(AIError::Cancelled)));
+        assert!(!queue.cancel(ids[0]));
+    }
+}
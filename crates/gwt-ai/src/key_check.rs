@@ -0,0 +1,205 @@
+//! Cheap validity checks of agent provider API keys.
+//!
+//! An invalid, expired or rate-limited key otherwise only shows up as an
+//! agent that exits seconds after launch. [`check_key_blocking`] lists the
+//! provider's models with the key, which costs no tokens, and classifies
+//! the HTTP status. Unreachable providers and server errors are
+//! [`KeyHealth::Unknown`] and produce no warning, so being offline does not
+//! nag.
+
+use std::time::Duration;
+
+use reqwest::blocking::{Client, RequestBuilder};
+use serde::Serialize;
+
+/// Connect + read timeout of one key check.
+pub const KEY_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Provider whose API key an agent launch depends on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyProvider {
+    Anthropic,
+    OpenAi,
+    Gemini,
+}
+
+impl KeyProvider {
+    pub const ALL: [KeyProvider; 3] = [Self::Anthropic, Self::OpenAi, Self::Gemini];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Anthropic => "Anthropic",
+            Self::OpenAi => "OpenAI",
+            Self::Gemini => "Gemini",
+        }
+    }
+
+    /// Environment variables the provider's agents read the key from, in
+    /// lookup order.
+    pub fn env_vars(self) -> &'static [&'static str] {
+        match self {
+            Self::Anthropic => &["ANTHROPIC_API_KEY"],
+            Self::OpenAi => &["OPENAI_API_KEY"],
+            Self::Gemini => &["GEMINI_API_KEY", "GOOGLE_API_KEY"],
+        }
+    }
+
+    /// First non-empty key among [`Self::env_vars`] according to `lookup`,
+    /// with the variable it came from.
+    pub fn find_key(
+        self,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Option<(&'static str, String)> {
+        self.env_vars().iter().find_map(|name| {
+            lookup(name)
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+                .map(|value| (*name, value))
+        })
+    }
+
+    fn models_request(self, client: &Client, key: &str) -> RequestBuilder {
+        match self {
+            Self::Anthropic => client
+                .get("https://api.anthropic.com/v1/models")
+                .header("x-api-key", key)
+                .header("anthropic-version", "2023-06-01"),
+            Self::OpenAi => client
+                .get("https://api.openai.com/v1/models")
+                .bearer_auth(key),
+            Self::Gemini => client
+                .get("https://generativelanguage.googleapis.com/v1beta/models")
+                .header("x-goog-api-key", key),
+        }
+    }
+}
+
+/// Outcome of a key check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum KeyHealth {
+    Valid,
+    /// Rejected as unknown, revoked or expired.
+    Invalid,
+    /// Accepted but not allowed to use the API.
+    Forbidden,
+    /// Rate-limited or out of quota.
+    RateLimited,
+    /// The check itself failed; nothing is known about the key.
+    Unknown {
+        detail: String,
+    },
+}
+
+impl KeyHealth {
+    /// Classify the response to the models request.
+    pub fn from_status(code: u16, body: &str) -> Self {
+        match code {
+            200..=299 => Self::Valid,
+            401 => Self::Invalid,
+            // Gemini answers a bad key with 400 and `API_KEY_INVALID`.
+            400 if body.contains("API_KEY_INVALID") || body.contains("API key expired") => {
+                Self::Invalid
+            }
+            403 => Self::Forbidden,
+            429 => Self::RateLimited,
+            _ => Self::Unknown {
+                detail: format!("HTTP {code}"),
+            },
+        }
+    }
+
+    /// Warning for the launch wizard, or `None` when the key is fine or its
+    /// state is unknown.
+    pub fn warning(&self, provider: KeyProvider, env_var: &str) -> Option<String> {
+        let problem = match self {
+            Self::Valid | Self::Unknown { .. } => return None,
+            Self::Invalid => "is invalid or expired",
+            Self::Forbidden => "is not permitted to use the API",
+            Self::RateLimited => "is rate-limited or out of quota",
+        };
+        Some(format!(
+            "{} key {env_var} {problem}; the agent may exit right after launch",
+            provider.label()
+        ))
+    }
+}
+
+/// Check `key` against `provider` with one models request.
+pub fn check_key_blocking(provider: KeyProvider, key: &str) -> KeyHealth {
+    let client = match Client::builder()
+        .timeout(KEY_CHECK_TIMEOUT)
+        .connect_timeout(KEY_CHECK_TIMEOUT)
+        .build()
+    {
+        Ok(client) => client,
+        Err(error) => {
+            return KeyHealth::Unknown {
+                detail: error.to_string(),
+            }
+        }
+    };
+    match provider.models_request(&client, key).send() {
+        Ok(response) => {
+            let code = response.status().as_u16();
+            let body = response.text().unwrap_or_default();
+            KeyHealth::from_status(code, &body)
+        }
+        Err(error) => KeyHealth::Unknown {
+            detail: error.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_codes_map_to_key_health() {
+        assert_eq!(KeyHealth::from_status(200, "{}"), KeyHealth::Valid);
+        assert_eq!(KeyHealth::from_status(401, ""), KeyHealth::Invalid);
+        assert_eq!(
+            KeyHealth::from_status(
+                400,
+                r#"{"error":{"details":[{"reason":"API_KEY_INVALID"}]}}"#
+            ),
+            KeyHealth::Invalid
+        );
+        assert_eq!(
+            KeyHealth::from_status(400, "bad request"),
+            KeyHealth::Unknown {
+                detail: "HTTP 400".to_string()
+            }
+        );
+        assert_eq!(KeyHealth::from_status(403, ""), KeyHealth::Forbidden);
+        assert_eq!(KeyHealth::from_status(429, ""), KeyHealth::RateLimited);
+        assert!(KeyHealth::from_status(529, "")
+            .warning(KeyProvider::Anthropic, "ANTHROPIC_API_KEY")
+            .is_none());
+        assert_eq!(
+            KeyHealth::RateLimited
+                .warning(KeyProvider::OpenAi, "OPENAI_API_KEY")
+                .as_deref(),
+            Some(
+                "OpenAI key OPENAI_API_KEY is rate-limited or out of quota; \
+                 the agent may exit right after launch"
+            )
+        );
+    }
+
+    #[test]
+    fn find_key_skips_blank_variables_in_order() {
+        let env = |name: &str| match name {
+            "GEMINI_API_KEY" => Some("  ".to_string()),
+            "GOOGLE_API_KEY" => Some("g-key".to_string()),
+            _ => None,
+        };
+        assert_eq!(
+            KeyProvider::Gemini.find_key(env),
+            Some(("GOOGLE_API_KEY", "g-key".to_string()))
+        );
+        assert_eq!(KeyProvider::Anthropic.find_key(env), None);
+    }
+}
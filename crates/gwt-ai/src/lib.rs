@@ -1,30 +1,61 @@
 //! AI client and utilities for Git Worktree Manager.
 //!
 //! This crate provides:
+//! - [`provider::AIProvider`] — provider trait plus [`provider::build_provider`]
 //! - [`client::AIClient`] — OpenAI Responses API client with retry logic
+//! - [`anthropic::AnthropicClient`] — native Anthropic Messages API client
+//! - [`gemini::GeminiClient`] — native Gemini generateContent client
+//! - [`ollama::OllamaClient`] — native Ollama client for local models
 //! - [`branch_suggest`] — AI-powered branch name suggestions
+//! - [`commit_message`] — AI-suggested commit messages for staged changes
+//! - [`hunk_explain`] — AI explanations of single diff hunks
 //! - [`issue_classify`] — AI-powered issue classification
+//! - [`job_queue`] — Process-wide queue and priorities for AI requests
+//! - [`key_check`] — Pre-launch validity checks of agent provider API keys
 //! - [`session_converter`] — Session format conversion between agents
 //! - [`error::AIError`] — Unified error type
 
+pub mod anthropic;
 pub mod branch_suggest;
 pub mod client;
+pub mod commit_message;
 pub mod error;
+pub mod gemini;
+pub mod hunk_explain;
 pub mod issue_classify;
+pub mod job_queue;
+pub mod key_check;
 pub mod models_probe;
+pub mod ollama;
+pub mod pr_description;
+pub mod provider;
 pub mod session_converter;
 pub mod work_summary;
 
-pub use branch_suggest::{parse_suggestions, suggest_branch_name};
+pub use anthropic::AnthropicClient;
+pub use branch_suggest::{
+    parse_suggestions, partial_suggestions, suggest_branch_name, suggest_branch_name_streaming,
+};
 pub use client::{AIClient, ChatMessage};
+pub use commit_message::suggest_commit_message;
 pub use error::AIError;
+pub use gemini::GeminiClient;
+pub use hunk_explain::{explain_hunk, hunk_hash, HunkExplanationCache};
 pub use issue_classify::{classify_issue, parse_classify_response};
+pub use job_queue::{
+    global_queue, run_ai_job, AiJobPriority, AiJobQueue, AiJobState, AiJobView, AiQueueStatus,
+};
 pub use models_probe::{
     is_valid_base_url, list_model_ids_blocking, list_models_blocking, parse_models_response,
     ModelInfo, ProbeError, PROBE_TIMEOUT,
 };
+pub use ollama::{OllamaClient, DEFAULT_OLLAMA_ENDPOINT};
+pub use pr_description::{draft_pull_request, PullRequestContext, PullRequestDraft};
+pub use provider::{
+    build_provider, format_error_for_display, AIProvider, AIProviderKind, ResolvedAISettings,
+};
 pub use session_converter::{
-    convert_session, get_encoder, ClaudeEncoder, CodexEncoder, GeminiEncoder, OpenCodeEncoder,
-    Role, SessionEncoder, SessionMessage,
+    convert_session, convert_session_with_loss, get_encoder, ClaudeEncoder, CodexEncoder,
+    GeminiEncoder, LossInfo, OpenCodeEncoder, Role, SessionEncoder, SessionMessage,
 };
 pub use work_summary::{parse_work_summaries, summarize_work_purposes, WorkSummaryInput};
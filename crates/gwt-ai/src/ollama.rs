@@ -0,0 +1,309 @@
+//! Native Ollama API client.
+//!
+//! Ollama serves an OpenAI-compatible API under `/v1`, but its native API is
+//! what every Ollama version supports: `POST /api/chat` for chat,
+//! `GET /api/tags` for the installed models, no authentication, and
+//! streaming as newline-delimited JSON objects instead of server-sent events.
+
+use std::io::BufRead;
+
+use reqwest::{
+    blocking::{Client, RequestBuilder},
+    header::{HeaderMap, HeaderValue, AUTHORIZATION},
+};
+use serde::Deserialize;
+
+use crate::{
+    client::{build_http_client, send_with_retry, ChatMessage, MAX_OUTPUT_TOKENS, TEMPERATURE},
+    error::AIError,
+    models_probe::ModelInfo,
+    provider::{AIProvider, AIProviderKind},
+};
+
+/// Default Ollama server address.
+pub const DEFAULT_OLLAMA_ENDPOINT: &str = "http://localhost:11434";
+
+// ── Response wire types ────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct ChatChunk {
+    message: Option<ChunkMessage>,
+    #[serde(default)]
+    done: bool,
+    error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChunkMessage {
+    #[serde(default)]
+    content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TagsResponse {
+    #[serde(default)]
+    models: Vec<TagEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TagEntry {
+    name: String,
+}
+
+// ── OllamaClient ───────────────────────────────────────────────────────
+
+/// Blocking client for a local (or proxied) Ollama server.
+#[derive(Debug)]
+pub struct OllamaClient {
+    endpoint: String,
+    api_key: String,
+    model: String,
+    client: Client,
+}
+
+impl OllamaClient {
+    /// Create a new client. The API key is optional and only sent when an
+    /// authenticating proxy sits in front of Ollama.
+    ///
+    /// # Errors
+    /// Returns [`AIError::ConfigError`] if `endpoint` or `model` is empty.
+    pub fn new(endpoint: &str, api_key: &str, model: &str) -> Result<Self, AIError> {
+        let endpoint = native_base(endpoint);
+        let model = model.trim().to_string();
+        if endpoint.is_empty() {
+            return Err(AIError::ConfigError("API endpoint is empty".into()));
+        }
+        if model.is_empty() {
+            return Err(AIError::ConfigError("Model is empty".into()));
+        }
+        Ok(Self {
+            endpoint,
+            api_key: api_key.trim().to_string(),
+            model,
+            client: build_http_client()?,
+        })
+    }
+
+    /// Models installed on the server at `endpoint`, without picking one.
+    pub fn installed_models(endpoint: &str) -> Result<Vec<ModelInfo>, AIError> {
+        let client = build_http_client()?;
+        let url = format!("{}/api/tags", native_base(endpoint));
+        let raw = send_with_retry(|| client.get(&url))?
+            .text()
+            .map_err(|e| AIError::NetworkError(e.to_string()))?;
+        parse_models(&raw)
+    }
+
+    fn chat_request(
+        &self,
+        messages: &[ChatMessage],
+        stream: bool,
+    ) -> Result<impl Fn() -> RequestBuilder + '_, AIError> {
+        if messages.is_empty() {
+            return Err(AIError::ConfigError("No input messages".into()));
+        }
+        let body = build_body(&self.model, messages, stream);
+        let url = format!("{}/api/chat", self.endpoint);
+        Ok(move || self.client.post(&url).headers(self.headers()).json(&body))
+    }
+
+    fn headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if !self.api_key.is_empty() {
+            if let Ok(val) = HeaderValue::from_str(&format!("Bearer {}", self.api_key)) {
+                headers.insert(AUTHORIZATION, val);
+            }
+        }
+        headers
+    }
+}
+
+impl AIProvider for OllamaClient {
+    fn kind(&self) -> AIProviderKind {
+        AIProviderKind::Ollama
+    }
+
+    fn create_response(&self, messages: Vec<ChatMessage>) -> Result<String, AIError> {
+        let raw = send_with_retry(self.chat_request(&messages, false)?)?
+            .text()
+            .map_err(|e| AIError::NetworkError(e.to_string()))?;
+        let text = parse_chunk(&raw)?.0;
+        if text.is_empty() {
+            return Err(AIError::ParseError(
+                "No text content found in response".into(),
+            ));
+        }
+        Ok(text)
+    }
+
+    fn create_response_streaming(
+        &self,
+        messages: Vec<ChatMessage>,
+        on_delta: &mut dyn FnMut(&str),
+    ) -> Result<String, AIError> {
+        let resp = send_with_retry(self.chat_request(&messages, true)?)?;
+        read_ndjson(std::io::BufReader::new(resp), on_delta)
+    }
+
+    fn list_models(&self) -> Result<Vec<ModelInfo>, AIError> {
+        Self::installed_models(&self.endpoint)
+    }
+}
+
+// ── Free helpers ───────────────────────────────────────────────────────
+
+/// Server root for the native API. Endpoints copied from OpenAI-compatible
+/// setups end in `/v1` (or `/api`); both are dropped.
+fn native_base(endpoint: &str) -> String {
+    let base = endpoint.trim().trim_end_matches('/');
+    let base = base
+        .strip_suffix("/v1")
+        .or_else(|| base.strip_suffix("/api"))
+        .unwrap_or(base);
+    base.to_string()
+}
+
+/// `/api/chat` body. Ollama takes the system prompt as an ordinary
+/// `system` message, so the messages pass through unchanged.
+fn build_body(model: &str, messages: &[ChatMessage], stream: bool) -> serde_json::Value {
+    serde_json::json!({
+        "model": model,
+        "messages": messages,
+        "stream": stream,
+        "options": {
+            "num_predict": MAX_OUTPUT_TOKENS,
+            "temperature": TEMPERATURE,
+        },
+    })
+}
+
+/// Text and `done` flag of one `/api/chat` object. Ollama reports failures
+/// mid-stream as `{"error": "..."}` objects with a `200 OK` status.
+fn parse_chunk(raw: &str) -> Result<(String, bool), AIError> {
+    let chunk: ChatChunk = serde_json::from_str(raw)
+        .map_err(|e| AIError::ParseError(format!("Invalid JSON response: {e}")))?;
+    if let Some(error) = chunk.error {
+        return Err(AIError::ServerError(error));
+    }
+    let text = chunk
+        .message
+        .map(|message| message.content)
+        .unwrap_or_default();
+    Ok((text, chunk.done))
+}
+
+/// Forward the text of each streamed object to `on_delta` until the `done`
+/// object. Returns the full text.
+fn read_ndjson(reader: impl BufRead, on_delta: &mut dyn FnMut(&str)) -> Result<String, AIError> {
+    let mut text = String::new();
+    for line in reader.lines() {
+        let line = line.map_err(|e| AIError::NetworkError(e.to_string()))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let (delta, done) = parse_chunk(&line)?;
+        if !delta.is_empty() {
+            on_delta(&delta);
+            text.push_str(&delta);
+        }
+        if done {
+            break;
+        }
+    }
+    Ok(text)
+}
+
+fn parse_models(raw: &str) -> Result<Vec<ModelInfo>, AIError> {
+    let resp: TagsResponse = serde_json::from_str(raw)
+        .map_err(|e| AIError::ParseError(format!("Invalid JSON response: {e}")))?;
+    Ok(resp
+        .models
+        .into_iter()
+        .map(|entry| ModelInfo { id: entry.name })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn native_base_drops_openai_compat_suffix() {
+        assert_eq!(
+            native_base("http://localhost:11434/v1/"),
+            DEFAULT_OLLAMA_ENDPOINT
+        );
+        assert_eq!(
+            native_base("http://localhost:11434/api"),
+            DEFAULT_OLLAMA_ENDPOINT
+        );
+        assert_eq!(
+            native_base(" http://gpu-box:11434 "),
+            "http://gpu-box:11434"
+        );
+    }
+
+    #[test]
+    fn build_body_keeps_system_message_and_caps_output() {
+        let messages = vec![
+            ChatMessage {
+                role: "system".into(),
+                content: "be brief".into(),
+            },
+            ChatMessage {
+                role: "user".into(),
+                content: "hi".into(),
+            },
+        ];
+        let body = build_body("llama3.2", &messages, true);
+        assert_eq!(body["model"], "llama3.2");
+        assert_eq!(body["messages"][0]["role"], "system");
+        assert_eq!(body["stream"], true);
+        assert_eq!(body["options"]["num_predict"], MAX_OUTPUT_TOKENS);
+    }
+
+    #[test]
+    fn read_ndjson_joins_deltas_until_done() {
+        let stream = concat!(
+            r#"{"message":{"role":"assistant","content":"feat/"},"done":false}"#,
+            "\n\n",
+            r#"{"message":{"role":"assistant","content":"login"},"done":false}"#,
+            "\n",
+            r#"{"message":{"role":"assistant","content":""},"done":true,"eval_count":2}"#,
+            "\n",
+            r#"{"message":{"role":"assistant","content":"ignored"},"done":false}"#,
+        );
+        let mut deltas = Vec::new();
+        let text = read_ndjson(stream.as_bytes(), &mut |delta| {
+            deltas.push(delta.to_string())
+        })
+        .unwrap();
+        assert_eq!(text, "feat/login");
+        assert_eq!(deltas, ["feat/", "login"]);
+    }
+
+    #[test]
+    fn in_stream_errors_are_server_errors() {
+        let err = read_ndjson(
+            r#"{"error":"model 'llama9' not found"}"#.as_bytes(),
+            &mut |_| {},
+        )
+        .unwrap_err();
+        assert!(matches!(err, AIError::ServerError(message) if message.contains("llama9")));
+    }
+
+    #[test]
+    fn parse_models_reads_tag_names() {
+        let models =
+            parse_models(r#"{"models":[{"name":"llama3.2:latest","size":1},{"name":"qwen2.5"}]}"#)
+                .unwrap();
+        let ids: Vec<&str> = models.iter().map(|model| model.id.as_str()).collect();
+        assert_eq!(ids, ["llama3.2:latest", "qwen2.5"]);
+    }
+
+    #[test]
+    fn new_accepts_missing_api_key() {
+        assert!(OllamaClient::new(DEFAULT_OLLAMA_ENDPOINT, "", "llama3.2").is_ok());
+        assert!(OllamaClient::new(DEFAULT_OLLAMA_ENDPOINT, "", " ").is_err());
+    }
+}
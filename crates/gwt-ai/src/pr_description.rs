@@ -0,0 +1,160 @@
+//! AI-drafted pull request title and body.
+//!
+//! The branch's commit subjects, its diffstat against the base and, when an
+//! agent worked on the branch, that agent's closing summary are sent; the
+//! reply's first line becomes the title and the rest the body.
+
+use crate::{client::ChatMessage, error::AIError, provider::AIProvider};
+
+const SYSTEM_PROMPT: &str = "\
+You write GitHub pull request descriptions. You are given the commits of a \
+branch, its diffstat against the base branch and, when available, the \
+closing summary of the coding agent that did the work.\n\n\
+Rules:\n\
+- First line: the pull request title, an imperative summary of at most 72 \
+characters, with no prefix such as \"Title:\".\n\
+- Then a blank line and a Markdown body: a short paragraph on what changed \
+and why, then a `## Changes` bullet list.\n\
+- Only describe what the commits, diffstat and summary show.\n\
+- Reply with the title and body only: no code fences, no commentary.";
+
+/// Summaries longer than this are cut before sending.
+const MAX_SUMMARY_CHARS: usize = 6_000;
+
+/// Inputs for [`draft_pull_request`].
+#[derive(Debug, Clone, Default)]
+pub struct PullRequestContext<'a> {
+    pub branch: &'a str,
+    pub base: &'a str,
+    /// Commit subjects on the branch, oldest first.
+    pub commit_subjects: &'a [String],
+    /// `git diff --stat` against the base.
+    pub diffstat: &'a str,
+    /// Closing message of the agent session on the branch.
+    pub session_summary: Option<&'a str>,
+}
+
+/// Title and Markdown body of a pull request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PullRequestDraft {
+    pub title: String,
+    pub body: String,
+}
+
+fn build_user_payload(context: &PullRequestContext<'_>) -> String {
+    let mut payload = format!(
+        "Branch: {}\nBase: {}\n\nCommits:\n",
+        context.branch, context.base
+    );
+    for subject in context.commit_subjects {
+        payload.push_str(&format!("- {subject}\n"));
+    }
+    let diffstat = context.diffstat.trim_end();
+    if !diffstat.is_empty() {
+        payload.push_str(&format!("\nDiffstat:\n{diffstat}\n"));
+    }
+    if let Some(summary) = context
+        .session_summary
+        .map(str::trim)
+        .filter(|summary| !summary.is_empty())
+    {
+        let summary = match summary.char_indices().nth(MAX_SUMMARY_CHARS) {
+            Some((cut, _)) => &summary[..cut],
+            None => summary,
+        };
+        payload.push_str(&format!("\nAgent session summary:\n{summary}\n"));
+    }
+    payload
+}
+
+/// Split a reply into title and body, dropping a code fence or a `Title:`
+/// label the model added despite the instructions.
+fn parse_draft(reply: &str) -> Option<PullRequestDraft> {
+    let mut reply = reply.trim();
+    if let Some(inner) = reply
+        .strip_prefix("```")
+        .and_then(|inner| inner.strip_suffix("```"))
+    {
+        reply = inner
+            .split_once('\n')
+            .map_or(inner, |(_, body)| body)
+            .trim();
+    }
+    let (title, body) = reply.split_once('\n').unwrap_or((reply, ""));
+    let title = title.trim().trim_start_matches('#').trim();
+    let title = title
+        .strip_prefix("Title:")
+        .unwrap_or(title)
+        .trim()
+        .to_string();
+    if title.is_empty() {
+        return None;
+    }
+    Some(PullRequestDraft {
+        title,
+        body: body.trim().to_string(),
+    })
+}
+
+/// Ask the AI for a pull request title and body.
+pub fn draft_pull_request(
+    client: &dyn AIProvider,
+    context: &PullRequestContext<'_>,
+) -> Result<PullRequestDraft, AIError> {
+    if context.commit_subjects.is_empty() {
+        return Err(AIError::ConfigError(format!(
+            "{} has no commits on top of {}",
+            context.branch, context.base
+        )));
+    }
+    let messages = vec![
+        ChatMessage {
+            role: "system".into(),
+            content: SYSTEM_PROMPT.into(),
+        },
+        ChatMessage {
+            role: "user".into(),
+            content: build_user_payload(context),
+        },
+    ];
+    let reply = client.create_response(messages)?;
+    parse_draft(&reply).ok_or_else(|| AIError::ParseError("empty pull request title".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draft_splits_title_and_body() {
+        let draft =
+            parse_draft("Title: Add retry\n\nRetries failed pushes.\n\n## Changes\n- x").unwrap();
+        assert_eq!(draft.title, "Add retry");
+        assert_eq!(draft.body, "Retries failed pushes.\n\n## Changes\n- x");
+
+        let fenced = parse_draft("```markdown\n# Fix crash\n\nBody\n```").unwrap();
+        assert_eq!(fenced.title, "Fix crash");
+        assert_eq!(fenced.body, "Body");
+        assert_eq!(parse_draft("  \n"), None);
+    }
+
+    #[test]
+    fn payload_includes_summary_only_when_present() {
+        let subjects = vec!["Add retry".to_string()];
+        let mut context = PullRequestContext {
+            branch: "feature/retry",
+            base: "main",
+            commit_subjects: &subjects,
+            diffstat: " src/push.rs | 4 ++--\n",
+            session_summary: None,
+        };
+        let payload = build_user_payload(&context);
+        assert!(payload.contains("Commits:\n- Add retry\n"));
+        assert!(payload.contains("Diffstat:\n src/push.rs"));
+        assert!(!payload.contains("Agent session summary"));
+
+        context.session_summary = Some("Added retries with backoff.");
+        assert!(build_user_payload(&context)
+            .ends_with("Agent session summary:\nAdded retries with backoff.\n"));
+    }
+}
@@ -0,0 +1,388 @@
+//! Provider abstraction over the supported AI HTTP APIs.
+//!
+//! Feature modules ([`crate::branch_suggest`], [`crate::work_summary`],
+//! [`crate::issue_classify`]) only need "send messages, get text", so they
+//! take `&dyn AIProvider`. Four native implementations exist:
+//!
+//! - [`crate::client::AIClient`] — OpenAI Responses API (and compatible
+//!   local servers), `Authorization: Bearer`.
+//! - [`crate::anthropic::AnthropicClient`] — Anthropic Messages API,
+//!   `x-api-key` + `anthropic-version`.
+//! - [`crate::gemini::GeminiClient`] — Gemini `generateContent`,
+//!   `x-goog-api-key`.
+//! - [`crate::ollama::OllamaClient`] — Ollama `/api/chat`, no auth, for
+//!   fully offline use.
+//!
+//! [`ResolvedAISettings::resolve`] picks the implementation from the
+//! configured provider name, or from the endpoint host when the name is
+//! `auto`; [`build_provider`] is the one-call shorthand.
+//! [`format_error_for_display`] turns an [`AIError`] into a message naming
+//! the provider and its own error text.
+
+use crate::{
+    anthropic::{AnthropicClient, DEFAULT_ANTHROPIC_ENDPOINT},
+    client::{AIClient, ChatMessage},
+    error::AIError,
+    gemini::{GeminiClient, DEFAULT_GEMINI_ENDPOINT},
+    models_probe::ModelInfo,
+    ollama::{OllamaClient, DEFAULT_OLLAMA_ENDPOINT},
+};
+
+/// Default OpenAI endpoint; matches the `AISettings` default.
+pub const DEFAULT_OPENAI_ENDPOINT: &str = "https://api.openai.com/v1";
+
+/// Wire protocol spoken by an [`AIProvider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AIProviderKind {
+    OpenAI,
+    Anthropic,
+    Gemini,
+    Ollama,
+}
+
+impl AIProviderKind {
+    /// Parse a configured provider name. `auto` (or empty) returns `None`
+    /// so the caller can fall back to [`Self::detect`].
+    pub fn from_name(name: &str) -> Result<Option<Self>, AIError> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "" | "auto" => Ok(None),
+            "openai" => Ok(Some(Self::OpenAI)),
+            "anthropic" | "claude" => Ok(Some(Self::Anthropic)),
+            "gemini" | "google" => Ok(Some(Self::Gemini)),
+            "ollama" => Ok(Some(Self::Ollama)),
+            other => Err(AIError::ConfigError(format!(
+                "unknown AI provider: {other}"
+            ))),
+        }
+    }
+
+    /// Infer the provider from the endpoint host. Ollama's default port
+    /// without the `/v1` compatibility path selects the native Ollama API.
+    /// Anything else that is not a known Anthropic or Gemini host is treated
+    /// as OpenAI-compatible.
+    pub fn detect(endpoint: &str) -> Self {
+        let endpoint = endpoint.to_ascii_lowercase();
+        if endpoint.contains("anthropic.com") {
+            Self::Anthropic
+        } else if endpoint.contains("generativelanguage.googleapis.com") {
+            Self::Gemini
+        } else if endpoint.contains(":11434") && !endpoint.trim_end_matches('/').ends_with("/v1") {
+            Self::Ollama
+        } else {
+            Self::OpenAI
+        }
+    }
+
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::OpenAI => "OpenAI-compatible API",
+            Self::Anthropic => "Anthropic",
+            Self::Gemini => "Gemini",
+            Self::Ollama => "Ollama",
+        }
+    }
+
+    pub fn default_endpoint(self) -> &'static str {
+        match self {
+            Self::OpenAI => DEFAULT_OPENAI_ENDPOINT,
+            Self::Anthropic => DEFAULT_ANTHROPIC_ENDPOINT,
+            Self::Gemini => DEFAULT_GEMINI_ENDPOINT,
+            Self::Ollama => DEFAULT_OLLAMA_ENDPOINT,
+        }
+    }
+}
+
+/// A chat-capable AI backend.
+pub trait AIProvider: Send + Sync {
+    fn kind(&self) -> AIProviderKind;
+
+    /// Send chat messages and return the assistant's text. A leading
+    /// `"system"` message becomes the provider's system instruction.
+    fn create_response(&self, messages: Vec<ChatMessage>) -> Result<String, AIError>;
+
+    /// Like [`Self::create_response`] but streams text deltas to `on_delta`
+    /// as they arrive. Returns the full text.
+    fn create_response_streaming(
+        &self,
+        messages: Vec<ChatMessage>,
+        on_delta: &mut dyn FnMut(&str),
+    ) -> Result<String, AIError>;
+
+    /// Models available to the configured credentials.
+    fn list_models(&self) -> Result<Vec<ModelInfo>, AIError>;
+}
+
+/// AI settings with the provider decided and the endpoint filled in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedAISettings {
+    pub provider: AIProviderKind,
+    pub endpoint: String,
+    pub api_key: String,
+    pub model: String,
+}
+
+impl ResolvedAISettings {
+    /// Resolve a configured `(provider, endpoint, api_key, model)`.
+    ///
+    /// When an explicit non-OpenAI provider is paired with the default OpenAI
+    /// endpoint (the `AISettings` default), the provider's own default
+    /// endpoint is used instead, so setting `provider = "anthropic"` plus an
+    /// API key is enough.
+    pub fn resolve(
+        provider: &str,
+        endpoint: &str,
+        api_key: &str,
+        model: &str,
+    ) -> Result<Self, AIError> {
+        let kind = AIProviderKind::from_name(provider)?
+            .unwrap_or_else(|| AIProviderKind::detect(endpoint));
+        let endpoint = endpoint.trim();
+        let endpoint = if endpoint.is_empty()
+            || (kind != AIProviderKind::OpenAI
+                && endpoint.trim_end_matches('/') == DEFAULT_OPENAI_ENDPOINT)
+        {
+            kind.default_endpoint()
+        } else {
+            endpoint
+        };
+        Ok(Self {
+            provider: kind,
+            endpoint: endpoint.to_string(),
+            api_key: api_key.to_string(),
+            model: model.to_string(),
+        })
+    }
+
+    /// Build the client for these settings.
+    pub fn build(&self) -> Result<Box<dyn AIProvider>, AIError> {
+        let (endpoint, api_key, model) = (&self.endpoint, &self.api_key, &self.model);
+        Ok(match self.provider {
+            AIProviderKind::OpenAI => Box::new(AIClient::new(endpoint, api_key, model)?),
+            AIProviderKind::Anthropic => Box::new(AnthropicClient::new(endpoint, api_key, model)?),
+            AIProviderKind::Gemini => Box::new(GeminiClient::new(endpoint, api_key, model)?),
+            AIProviderKind::Ollama => Box::new(OllamaClient::new(endpoint, api_key, model)?),
+        })
+    }
+
+    /// [`format_error_for_display`] for this provider.
+    pub fn format_error(&self, error: &AIError) -> String {
+        format_error_for_display(self.provider, error)
+    }
+}
+
+/// Build the provider for a configured `(provider, endpoint, api_key, model)`;
+/// see [`ResolvedAISettings::resolve`].
+pub fn build_provider(
+    provider: &str,
+    endpoint: &str,
+    api_key: &str,
+    model: &str,
+) -> Result<Box<dyn AIProvider>, AIError> {
+    ResolvedAISettings::resolve(provider, endpoint, api_key, model)?.build()
+}
+
+/// One-line, user-facing description of `error` from `provider`.
+///
+/// HTTP failures carry the raw status and body; the body's own message
+/// (`error.message` for OpenAI, Anthropic and Gemini, `error` for Ollama) is
+/// pulled out, and the status picks the wording.
+pub fn format_error_for_display(provider: AIProviderKind, error: &AIError) -> String {
+    let name = provider.display_name();
+    match error {
+        AIError::ConfigError(message) => format!("AI settings: {message}"),
+        AIError::RateLimited {
+            retry_after_secs: Some(secs),
+        } => format!("{name} rate limit reached; try again in {secs}s"),
+        AIError::RateLimited { .. } => format!("{name} rate limit reached; try again later"),
+        AIError::ServerError(raw) => {
+            let (status, body) = split_status(raw);
+            let message = body_message(body);
+            match status {
+                Some(401) | Some(403) => {
+                    format!("{name} rejected the API key ({message})")
+                }
+                Some(404) => format!("{name} does not know this model or endpoint ({message})"),
+                Some(status @ 400..=499) => {
+                    format!("{name} rejected the request ({status}: {message})")
+                }
+                Some(status) => format!("{name} is unavailable ({status}: {message})"),
+                None => format!("{name} error: {message}"),
+            }
+        }
+        AIError::ParseError(message) => format!("Unexpected {name} response: {message}"),
+        AIError::NetworkError(message) => format!("Could not reach {name}: {message}"),
+        AIError::Timeout(_) => format!("{name} did not answer in time"),
+        AIError::Cancelled => "Cancelled".to_string(),
+    }
+}
+
+/// `"401 Unauthorized: {body}"` → `(Some(401), "{body}")`.
+fn split_status(raw: &str) -> (Option<u16>, &str) {
+    let status = raw
+        .split_whitespace()
+        .next()
+        .and_then(|code| code.parse::<u16>().ok());
+    match (status, raw.split_once(": ")) {
+        (Some(status), Some((_, body))) => (Some(status), body),
+        _ => (None, raw),
+    }
+}
+
+/// The provider's own error message inside a JSON error body, or the body.
+fn body_message(body: &str) -> String {
+    let body = body.trim();
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(body) else {
+        return body.to_string();
+    };
+    let message = json["error"]["message"]
+        .as_str()
+        .or_else(|| json["error"].as_str())
+        .or_else(|| json["message"].as_str());
+    match message {
+        Some(message) => message.to_string(),
+        None => body.to_string(),
+    }
+}
+
+/// Split messages into the system instruction and the remaining turns, as
+/// both native APIs take the system prompt outside the message list.
+pub(crate) fn split_system(messages: &[ChatMessage]) -> (Option<String>, Vec<&ChatMessage>) {
+    let mut system: Option<String> = None;
+    let mut turns = Vec::new();
+    for msg in messages {
+        if msg.role == "system" {
+            system = Some(match system {
+                Some(existing) => format!("{existing}\n\n{}", msg.content),
+                None => msg.content.clone(),
+            });
+        } else {
+            turns.push(msg);
+        }
+    }
+    (system, turns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn provider_name_overrides_endpoint_detection() {
+        assert_eq!(AIProviderKind::from_name("auto").unwrap(), None);
+        assert_eq!(
+            AIProviderKind::from_name("Anthropic").unwrap(),
+            Some(AIProviderKind::Anthropic)
+        );
+        assert!(AIProviderKind::from_name("bard").is_err());
+        assert_eq!(
+            AIProviderKind::detect("https://api.anthropic.com/v1"),
+            AIProviderKind::Anthropic
+        );
+        assert_eq!(
+            AIProviderKind::detect("https://generativelanguage.googleapis.com/v1beta"),
+            AIProviderKind::Gemini
+        );
+        assert_eq!(
+            AIProviderKind::detect("http://localhost:11434/v1"),
+            AIProviderKind::OpenAI
+        );
+        assert_eq!(
+            AIProviderKind::detect("http://localhost:11434"),
+            AIProviderKind::Ollama
+        );
+        assert_eq!(
+            AIProviderKind::from_name("ollama").unwrap(),
+            Some(AIProviderKind::Ollama)
+        );
+    }
+
+    #[test]
+    fn build_provider_swaps_default_openai_endpoint_for_native_provider() {
+        let provider =
+            build_provider("anthropic", DEFAULT_OPENAI_ENDPOINT, "key", "claude-x").unwrap();
+        assert_eq!(provider.kind(), AIProviderKind::Anthropic);
+        let provider = build_provider(
+            "auto",
+            "https://generativelanguage.googleapis.com/v1beta",
+            "key",
+            "gemini-x",
+        )
+        .unwrap();
+        assert_eq!(provider.kind(), AIProviderKind::Gemini);
+        let provider = build_provider("", "http://localhost:8080/v1", "", "local").unwrap();
+        assert_eq!(provider.kind(), AIProviderKind::OpenAI);
+        let provider = build_provider("ollama", DEFAULT_OPENAI_ENDPOINT, "", "llama3.2").unwrap();
+        assert_eq!(provider.kind(), AIProviderKind::Ollama);
+    }
+
+    #[test]
+    fn resolve_fills_in_the_native_endpoint() {
+        let resolved =
+            ResolvedAISettings::resolve("gemini", DEFAULT_OPENAI_ENDPOINT, "key", "gemini-x")
+                .unwrap();
+        assert_eq!(resolved.provider, AIProviderKind::Gemini);
+        assert_eq!(resolved.endpoint, DEFAULT_GEMINI_ENDPOINT);
+        let resolved = ResolvedAISettings::resolve("auto", "", "", "gpt-x").unwrap();
+        assert_eq!(resolved.endpoint, DEFAULT_OPENAI_ENDPOINT);
+    }
+
+    #[test]
+    fn errors_show_the_provider_message() {
+        let anthropic = AIError::ServerError(
+            r#"401 Unauthorized: {"type":"error","error":{"type":"authentication_error","message":"invalid x-api-key"}}"#
+                .to_string(),
+        );
+        assert_eq!(
+            format_error_for_display(AIProviderKind::Anthropic, &anthropic),
+            "Anthropic rejected the API key (invalid x-api-key)"
+        );
+        let gemini = AIError::ServerError(
+            r#"404 Not Found: {"error":{"code":404,"message":"models/gemini-9 is not found","status":"NOT_FOUND"}}"#
+                .to_string(),
+        );
+        assert_eq!(
+            format_error_for_display(AIProviderKind::Gemini, &gemini),
+            "Gemini does not know this model or endpoint (models/gemini-9 is not found)"
+        );
+        let overloaded = AIError::ServerError("529 <unknown status code>: overloaded".to_string());
+        assert_eq!(
+            format_error_for_display(AIProviderKind::Anthropic, &overloaded),
+            "Anthropic is unavailable (529: overloaded)"
+        );
+        let streamed = AIError::ServerError(r#"{"type":"overloaded_error"}"#.to_string());
+        assert!(
+            format_error_for_display(AIProviderKind::Anthropic, &streamed)
+                .starts_with("Anthropic error: ")
+        );
+        assert_eq!(
+            format_error_for_display(
+                AIProviderKind::Ollama,
+                &AIError::RateLimited {
+                    retry_after_secs: Some(3)
+                }
+            ),
+            "Ollama rate limit reached; try again in 3s"
+        );
+    }
+
+    #[test]
+    fn split_system_merges_system_messages() {
+        let messages = vec![
+            ChatMessage {
+                role: "system".into(),
+                content: "a".into(),
+            },
+            ChatMessage {
+                role: "user".into(),
+                content: "hi".into(),
+            },
+            ChatMessage {
+                role: "system".into(),
+                content: "b".into(),
+            },
+        ];
+        let (system, turns) = split_system(&messages);
+        assert_eq!(system.as_deref(), Some("a\n\nb"));
+        assert_eq!(turns.len(), 1);
+    }
+}
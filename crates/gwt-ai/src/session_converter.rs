@@ -55,6 +55,50 @@ pub struct SessionMessage {
     pub content: String,
 }
 
+/// What a conversion leaves behind. The generic history only carries text
+/// turns, so tool activity is described here rather than converted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LossInfo {
+    /// Tool calls and tool results left out of the history.
+    pub dropped_tool_messages: usize,
+    /// Files those tool calls wrote; the target only sees them mentioned in
+    /// the conversation text.
+    pub files_touched: Vec<String>,
+    /// System messages the target format sends as user turns.
+    pub system_as_user: usize,
+}
+
+impl LossInfo {
+    pub fn is_lossless(&self) -> bool {
+        self.dropped_tool_messages == 0 && self.files_touched.is_empty() && self.system_as_user == 0
+    }
+
+    /// One line for a confirmation prompt, e.g. `12 tool message(s) dropped,
+    /// 3 file(s) touched`.
+    pub fn summary(&self) -> String {
+        if self.is_lossless() {
+            return "nothing lost".to_string();
+        }
+        let mut parts = Vec::new();
+        if self.dropped_tool_messages > 0 {
+            parts.push(format!(
+                "{} tool message(s) dropped",
+                self.dropped_tool_messages
+            ));
+        }
+        if !self.files_touched.is_empty() {
+            parts.push(format!("{} file(s) touched", self.files_touched.len()));
+        }
+        if self.system_as_user > 0 {
+            parts.push(format!(
+                "{} system message(s) sent as user turns",
+                self.system_as_user
+            ));
+        }
+        parts.join(", ")
+    }
+}
+
 /// Trait for encoding a session history into a specific agent format.
 pub trait SessionEncoder {
     /// Human-readable name of the target format (e.g. "Claude", "Codex").
@@ -215,6 +259,32 @@ pub fn convert_session(
     encoder.encode(history)
 }
 
+/// [`convert_session`], also reporting what the target format cannot
+/// represent. Tool activity the caller dropped while building `history` is
+/// not known here; add it to the returned [`LossInfo`].
+pub fn convert_session_with_loss(
+    from: &str,
+    to: &str,
+    history: &[SessionMessage],
+) -> Result<(String, LossInfo), AIError> {
+    let encoded = convert_session(from, to, history)?;
+    let system_as_user = if SessionFormat::parse(to) == Some(SessionFormat::Gemini) {
+        history
+            .iter()
+            .filter(|message| message.role == Role::System)
+            .count()
+    } else {
+        0
+    };
+    Ok((
+        encoded,
+        LossInfo {
+            system_as_user,
+            ..LossInfo::default()
+        },
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -357,6 +427,18 @@ mod tests {
         assert!(matches!(err, AIError::ConfigError(_)));
     }
 
+    #[test]
+    fn convert_session_with_loss_reports_system_messages_for_gemini() {
+        let history = sample_history();
+        let (_, loss) = convert_session_with_loss("claude", "gemini", &history).unwrap();
+        assert_eq!(loss.system_as_user, 1);
+        assert_eq!(loss.summary(), "1 system message(s) sent as user turns");
+
+        let (_, loss) = convert_session_with_loss("claude", "codex", &history).unwrap();
+        assert!(loss.is_lossless());
+        assert_eq!(loss.summary(), "nothing lost");
+    }
+
     #[test]
     fn supported_formats_are_stable_and_non_empty() {
         let formats = supported_formats();
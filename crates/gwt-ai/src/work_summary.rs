@@ -14,10 +14,7 @@ use std::collections::HashMap;
 
 use serde::Deserialize;
 
-use crate::{
-    client::{AIClient, ChatMessage},
-    error::AIError,
-};
+use crate::{client::ChatMessage, error::AIError, provider::AIProvider};
 
 /// Structured meta for one Workspace, fed to the AI summary generator. Holds no
 /// session transcript — only the branch, its owner, and raw textual signals.
@@ -104,7 +101,7 @@ fn build_user_payload(inputs: &[WorkSummaryInput]) -> String {
 /// Returns a `branch -> summary` map; branches the AI could not summarize are
 /// simply absent. Returns an empty map (no AI call) when `inputs` is empty.
 pub fn summarize_work_purposes(
-    client: &AIClient,
+    client: &dyn AIProvider,
     inputs: &[WorkSummaryInput],
 ) -> Result<HashMap<String, String>, AIError> {
     if inputs.is_empty() {
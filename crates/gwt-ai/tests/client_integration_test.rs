@@ -1,7 +1,8 @@
 //! SPEC-3014 FR-005: integration tests for gwt-ai response handling against
 //! a local HTTP mock (std `TcpListener`, no extra dependencies).
 //!
-//! Covered surfaces: `AIClient::create_response` (Responses API), issue
+//! Covered surfaces: `AIClient::create_response` (Responses API), the native
+//! Anthropic Messages / Gemini generateContent providers, issue
 //! classification, branch suggestion, and the `/v1/models` probe — each with
 //! a happy path plus malformed-JSON / empty-or-missing-field / invalid-token
 //! edge cases. `AIClient::new` already accepts an injectable endpoint, so no
@@ -12,8 +13,9 @@ use std::net::{TcpListener, TcpStream};
 use std::thread::JoinHandle;
 
 use gwt_ai::{
-    classify_issue, list_models_blocking, suggest_branch_name, AIClient, AIError, ChatMessage,
-    ProbeError,
+    build_provider, classify_issue, list_models_blocking, suggest_branch_name,
+    suggest_branch_name_streaming, AIClient, AIError, AIProvider, AnthropicClient, ChatMessage,
+    GeminiClient, ProbeError,
 };
 
 struct MockResponse {
@@ -290,3 +292,124 @@ fn models_probe_http_error_status_carries_code_and_body() {
         other => panic!("expected HttpStatus, got {other:?}"),
     }
 }
+
+// ── Native providers ────────────────────────────────────────────────────
+
+#[test]
+fn anthropic_create_response_uses_messages_api_headers_and_system_field() {
+    let body = r#"{"content":[{"type":"text","text":"Hi from Claude"}]}"#;
+    let (base, server) = serve(vec![MockResponse::ok(body)]);
+    let client = AnthropicClient::new(&base, "ant-key", "claude-test").expect("client");
+
+    let text = client
+        .create_response(vec![
+            ChatMessage {
+                role: "system".to_string(),
+                content: "Be terse.".to_string(),
+            },
+            ChatMessage {
+                role: "user".to_string(),
+                content: "Hello".to_string(),
+            },
+        ])
+        .expect("create_response");
+    assert_eq!(text, "Hi from Claude");
+
+    let captured = server.join().expect("server thread");
+    let request = captured[0].to_lowercase();
+    assert!(request.starts_with("post /messages http/1.1"), "{request}");
+    assert!(request.contains("x-api-key: ant-key"));
+    assert!(request.contains("anthropic-version: 2023-06-01"));
+    assert!(!request.contains("authorization:"));
+    assert!(captured[0].contains(r#""system":"Be terse.""#));
+}
+
+#[test]
+fn anthropic_provider_drives_branch_suggestions() {
+    let suggestions = r#"{\"suggestions\": [\"feature/a\", \"bugfix/b\", \"hotfix/c\"]}"#;
+    let body = format!(r#"{{"content":[{{"type":"text","text":"{suggestions}"}}]}}"#);
+    let (base, _server) = serve(vec![MockResponse::ok(body)]);
+    let provider = build_provider("anthropic", &base, "k", "claude-test").expect("provider");
+
+    let names = suggest_branch_name(provider.as_ref(), "login page").expect("suggest");
+    assert_eq!(names, vec!["feature/a", "bugfix/b", "hotfix/c"]);
+}
+
+#[test]
+fn gemini_streaming_concatenates_sse_chunks() {
+    let body = [
+        r#"data: {"candidates":[{"content":{"role":"model","parts":[{"text":"Hel"}]}}]}"#,
+        "",
+        r#"data: {"candidates":[{"content":{"role":"model","parts":[{"text":"lo"}]}}]}"#,
+        "",
+        "",
+    ]
+    .join("\r\n");
+    let (base, server) = serve(vec![MockResponse::ok(body)]);
+    let client = GeminiClient::new(&base, "g-key", "gemini-test").expect("client");
+
+    let mut deltas = Vec::new();
+    let text = client
+        .create_response_streaming(user_message("hi"), &mut |delta| {
+            deltas.push(delta.to_string())
+        })
+        .expect("stream");
+    assert_eq!(text, "Hello");
+    assert_eq!(deltas, vec!["Hel", "lo"]);
+
+    let captured = server.join().expect("server thread");
+    let request = captured[0].to_lowercase();
+    assert!(
+        request.starts_with("post /models/gemini-test:streamgeneratecontent?alt=sse http/1.1"),
+        "{request}"
+    );
+    assert!(request.contains("x-goog-api-key: g-key"));
+}
+
+#[test]
+fn streaming_branch_suggestions_report_partial_names() {
+    let chunk = |text: &str| {
+        format!(
+            r#"data: {{"candidates":[{{"content":{{"role":"model","parts":[{{"text":"{text}"}}]}}}}]}}"#
+        )
+    };
+    let body = [
+        chunk(r#"{\"suggestions\": [\"feature/a\", \"bug"#),
+        String::new(),
+        chunk(r#"fix/b\", \"hotfix/c\"]}"#),
+        String::new(),
+        String::new(),
+    ]
+    .join("\r\n");
+    let (base, _server) = serve(vec![MockResponse::ok(body)]);
+    let client = GeminiClient::new(&base, "g-key", "gemini-test").expect("client");
+
+    let mut partials: Vec<Vec<String>> = Vec::new();
+    let names = suggest_branch_name_streaming(&client, "login page", &mut |names| {
+        partials.push(names.to_vec())
+    })
+    .expect("suggest");
+    assert_eq!(names, vec!["feature/a", "bugfix/b", "hotfix/c"]);
+    assert_eq!(
+        partials,
+        vec![
+            vec!["feature/a".to_string()],
+            vec![
+                "feature/a".to_string(),
+                "bugfix/b".to_string(),
+                "hotfix/c".to_string()
+            ],
+        ]
+    );
+}
+
+#[test]
+fn gemini_list_models_reads_models_array() {
+    let (base, _server) = serve(vec![MockResponse::ok(
+        r#"{"models":[{"name":"models/gemini-test"}]}"#,
+    )]);
+    let client = GeminiClient::new(&base, "g-key", "gemini-test").expect("client");
+
+    let models = client.list_models().expect("list");
+    assert_eq!(models[0].id, "gemini-test");
+}
@@ -17,6 +17,57 @@ pub struct AgentConfig {
     /// Optional override for pre-registering trust of gwt-generated Codex hooks.
     /// `None` and `Some(true)` enable trust; `Some(false)` is the opt-out.
     pub codex_trust_managed_hooks: Option<bool>,
+    /// Publish a branch with `git push -u origin <branch>` when an agent stops
+    /// after creating its first commit. Opt-in; defaults off.
+    pub auto_push_first_commit: bool,
+    /// Hand the MCP servers declared in the worktree's `.gwt/mcp.toml` to
+    /// Claude Code and Codex launches for that session. Opt-in; defaults off.
+    pub inject_project_mcp: bool,
+    /// Validate the provider API key of Claude Code, Codex and Gemini with a
+    /// cheap request when the launch wizard opens, and warn in the Confirm
+    /// step when it is rejected or rate-limited. Opt-in; defaults off.
+    pub check_api_keys: bool,
+    /// Per-agent exit handling, keyed by agent id (e.g. "codex").
+    pub exit_policies: HashMap<String, AgentExitPolicy>,
+}
+
+impl AgentConfig {
+    /// Exit policy for `agent_id`, or the default when none is configured.
+    pub fn exit_policy(&self, agent_id: &str) -> AgentExitPolicy {
+        self.exit_policies
+            .get(agent_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+/// How an agent's process exit is classified.
+///
+/// By default only exit code 0 is a normal stop; any other code or a signal
+/// shows the failure screen. Some agents exit 1 when the user quits, so the
+/// accepted codes, signal handling and a fast-exit threshold are configurable
+/// under `[agent.exit_policies.<agent>]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AgentExitPolicy {
+    /// Exit codes treated as a normal stop.
+    pub success_codes: Vec<i32>,
+    /// Treat termination by a signal (SIGINT, SIGTERM, ...) as a normal stop.
+    pub signal_is_success: bool,
+    /// Only exits within this many seconds of launch count as failures; a
+    /// failing exit after a longer run is treated as a normal stop. `None`
+    /// classifies every failing exit as a failure.
+    pub fast_exit_secs: Option<u64>,
+}
+
+impl Default for AgentExitPolicy {
+    fn default() -> Self {
+        Self {
+            success_codes: vec![0],
+            signal_is_success: false,
+            fast_exit_secs: None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -30,6 +81,11 @@ mod tests {
         assert!(c.agent_paths.is_empty());
         assert!(!c.auto_install_deps);
         assert_eq!(c.codex_trust_managed_hooks, None);
+        assert!(!c.auto_push_first_commit);
+        assert!(!c.inject_project_mcp);
+        assert!(!c.check_api_keys);
+        assert!(c.exit_policies.is_empty());
+        assert_eq!(c.exit_policy("codex"), AgentExitPolicy::default());
     }
 
     #[test]
@@ -43,6 +99,10 @@ mod tests {
             agent_paths: paths,
             auto_install_deps: true,
             codex_trust_managed_hooks: Some(true),
+            auto_push_first_commit: true,
+            inject_project_mcp: true,
+            check_api_keys: true,
+            exit_policies: HashMap::new(),
         };
         let toml_str = toml::to_string_pretty(&c).unwrap();
         let loaded: AgentConfig = toml::from_str(&toml_str).unwrap();
@@ -50,6 +110,9 @@ mod tests {
         assert_eq!(loaded.agent_paths.len(), 2);
         assert!(loaded.auto_install_deps);
         assert_eq!(loaded.codex_trust_managed_hooks, Some(true));
+        assert!(loaded.auto_push_first_commit);
+        assert!(loaded.inject_project_mcp);
+        assert!(loaded.check_api_keys);
     }
 
     #[test]
@@ -63,4 +126,16 @@ mod tests {
         let enabled: AgentConfig = toml::from_str("codex_trust_managed_hooks = true").unwrap();
         assert_eq!(enabled.codex_trust_managed_hooks, Some(true));
     }
+
+    #[test]
+    fn exit_policies_parse_per_agent() {
+        let c: AgentConfig =
+            toml::from_str("[exit_policies.codex]\nsuccess_codes = [0, 1]\nfast_exit_secs = 5\n")
+                .unwrap();
+        let codex = c.exit_policy("codex");
+        assert_eq!(codex.success_codes, vec![0, 1]);
+        assert!(!codex.signal_is_success);
+        assert_eq!(codex.fast_exit_secs, Some(5));
+        assert_eq!(c.exit_policy("claude"), AgentExitPolicy::default());
+    }
 }
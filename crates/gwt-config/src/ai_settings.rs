@@ -16,10 +16,18 @@ fn default_summary_enabled() -> bool {
     true
 }
 
-/// AI provider configuration for OpenAI-compatible APIs.
+fn default_provider() -> String {
+    "auto".to_string()
+}
+
+/// AI provider configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct AISettings {
+    /// Wire protocol: "openai" | "anthropic" | "gemini" | "ollama", or "auto"
+    /// to infer it from the endpoint host.
+    #[serde(default = "default_provider")]
+    pub provider: String,
     /// API endpoint URL.
     #[serde(default = "default_endpoint")]
     pub endpoint: String,
@@ -40,6 +48,7 @@ pub struct AISettings {
 impl Default for AISettings {
     fn default() -> Self {
         Self {
+            provider: default_provider(),
             endpoint: default_endpoint(),
             api_key: None,
             model: String::new(),
@@ -115,6 +124,15 @@ mod tests {
         assert!(!s.endpoint.is_empty());
         assert!(s.model.is_empty());
         assert!(!s.is_enabled());
+        assert_eq!(s.provider, "auto");
+    }
+
+    #[test]
+    fn provider_deserializes_and_defaults_to_auto() {
+        let s: AISettings = toml::from_str("model = \"m\"").unwrap();
+        assert_eq!(s.provider, "auto");
+        let s: AISettings = toml::from_str("provider = \"anthropic\"\nmodel = \"m\"").unwrap();
+        assert_eq!(s.provider, "anthropic");
     }
 
     #[test]
@@ -238,6 +256,7 @@ mod tests {
     #[test]
     fn roundtrip_toml() {
         let s = AISettings {
+            provider: "gemini".to_string(),
             endpoint: "https://api.example.com/v1".to_string(),
             api_key: Some("sk-test".to_string()),
             model: "gpt-4".to_string(),
@@ -246,6 +265,7 @@ mod tests {
         };
         let toml_str = toml::to_string_pretty(&s).unwrap();
         let loaded: AISettings = toml::from_str(&toml_str).unwrap();
+        assert_eq!(loaded.provider, s.provider);
         assert_eq!(loaded.endpoint, s.endpoint);
         assert_eq!(loaded.api_key, s.api_key);
         assert_eq!(loaded.model, s.model);
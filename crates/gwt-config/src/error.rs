@@ -27,6 +27,10 @@ pub enum ConfigError {
     /// Validation failed.
     #[error("validation error: {reason}")]
     ValidationError { reason: String },
+
+    /// The OS keychain holding secret profile values failed.
+    #[error("secret store error: {reason}")]
+    SecretStore { reason: String },
 }
 
 #[cfg(test)]
@@ -0,0 +1,258 @@
+//! User-defined widgets in the GUI status strip.
+//!
+//! The status strip shows a fixed set of agent counters. `[[footer.widgets]]`
+//! entries append widgets of their own, in the order they are listed, each
+//! refreshed on its own interval:
+//!
+//! ```toml
+//! [[footer.widgets]]
+//! kind = "clock"
+//!
+//! [[footer.widgets]]
+//! kind = "command"
+//! label = "K8S"
+//! command = "kubectl config current-context"
+//! refresh_secs = 120
+//! ```
+//!
+//! `command` widgets run through the platform shell in the project
+//! directory; their output is cached for `refresh_secs` and shared by every
+//! connected window.
+
+use serde::{Deserialize, Serialize};
+
+/// Widgets beyond this count are dropped.
+pub const MAX_FOOTER_WIDGETS: usize = 8;
+
+/// Shortest refresh interval of a `command` widget.
+pub const MIN_COMMAND_REFRESH_SECS: u64 = 5;
+
+/// `[footer]` table.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FooterConfig {
+    pub widgets: Vec<FooterWidgetConfig>,
+}
+
+/// One `[[footer.widgets]]` entry as written.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FooterWidgetConfig {
+    /// `clock`, `profile`, `running_agents` or `command`.
+    pub kind: String,
+    /// Text before the value. Defaults to the kind's own label.
+    pub label: Option<String>,
+    /// Shell command of a `command` widget; the first output line is shown.
+    pub command: Option<String>,
+    /// Seconds between refreshes. Defaults per kind.
+    pub refresh_secs: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FooterWidgetKind {
+    /// Local time, `HH:MM`.
+    Clock,
+    /// Name of the active profile.
+    Profile,
+    /// Agents running across the open projects.
+    RunningAgents,
+    /// First output line of a shell command.
+    Command,
+}
+
+impl FooterWidgetKind {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "clock" => Some(Self::Clock),
+            "profile" => Some(Self::Profile),
+            "running_agents" => Some(Self::RunningAgents),
+            "command" => Some(Self::Command),
+            _ => None,
+        }
+    }
+
+    fn default_label(self) -> &'static str {
+        match self {
+            Self::Clock => "TIME",
+            Self::Profile => "PROFILE",
+            Self::RunningAgents => "AGENTS",
+            Self::Command => "CMD",
+        }
+    }
+
+    fn default_refresh_secs(self) -> u64 {
+        match self {
+            Self::Clock | Self::Profile => 30,
+            Self::RunningAgents => 5,
+            Self::Command => 60,
+        }
+    }
+
+    fn min_refresh_secs(self) -> u64 {
+        match self {
+            Self::Command => MIN_COMMAND_REFRESH_SECS,
+            _ => 1,
+        }
+    }
+}
+
+/// A validated widget, ready to render.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FooterWidget {
+    /// Position in `[[footer.widgets]]`; command output refers to it.
+    pub index: usize,
+    pub kind: FooterWidgetKind,
+    pub label: String,
+    pub command: Option<String>,
+    pub refresh_secs: u64,
+}
+
+/// Valid widgets plus the problems found in the table.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ResolvedFooterWidgets {
+    pub widgets: Vec<FooterWidget>,
+    pub errors: Vec<String>,
+}
+
+impl FooterConfig {
+    /// Validate the widgets. Unknown kinds and command widgets without a
+    /// command are dropped; refresh intervals below the kind's minimum are
+    /// raised to it.
+    pub fn resolve(&self) -> ResolvedFooterWidgets {
+        let mut resolved = ResolvedFooterWidgets::default();
+        for (index, widget) in self.widgets.iter().enumerate() {
+            if index >= MAX_FOOTER_WIDGETS {
+                resolved.errors.push(format!(
+                    "footer.widgets: only the first {MAX_FOOTER_WIDGETS} widgets are shown"
+                ));
+                break;
+            }
+            let Some(kind) = FooterWidgetKind::from_name(&widget.kind) else {
+                resolved.errors.push(format!(
+                    "footer.widgets[{index}]: unknown kind \"{}\"",
+                    widget.kind
+                ));
+                continue;
+            };
+            let command = widget
+                .command
+                .as_deref()
+                .map(str::trim)
+                .filter(|command| !command.is_empty())
+                .map(str::to_string);
+            if kind == FooterWidgetKind::Command && command.is_none() {
+                resolved
+                    .errors
+                    .push(format!("footer.widgets[{index}]: command is empty"));
+                continue;
+            }
+            let mut refresh_secs = widget
+                .refresh_secs
+                .unwrap_or_else(|| kind.default_refresh_secs());
+            if refresh_secs < kind.min_refresh_secs() {
+                resolved.errors.push(format!(
+                    "footer.widgets[{index}]: refresh_secs raised to {}",
+                    kind.min_refresh_secs()
+                ));
+                refresh_secs = kind.min_refresh_secs();
+            }
+            let label = widget
+                .label
+                .as_deref()
+                .map(str::trim)
+                .filter(|label| !label.is_empty())
+                .unwrap_or(kind.default_label())
+                .to_string();
+            resolved.widgets.push(FooterWidget {
+                index,
+                kind,
+                label,
+                command: command.filter(|_| kind == FooterWidgetKind::Command),
+                refresh_secs,
+            });
+        }
+        resolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widgets_keep_order_and_report_invalid_entries() {
+        let config: FooterConfig = toml::from_str(
+            r#"
+[[widgets]]
+kind = "command"
+label = "K8S"
+command = " kubectl config current-context "
+refresh_secs = 1
+
+[[widgets]]
+kind = "weather"
+
+[[widgets]]
+kind = "running-agents"
+
+[[widgets]]
+kind = "command"
+
+[[widgets]]
+kind = "clock"
+command = "date"
+"#,
+        )
+        .unwrap();
+
+        let resolved = config.resolve();
+        let widgets: Vec<(usize, FooterWidgetKind, &str, u64)> = resolved
+            .widgets
+            .iter()
+            .map(|widget| {
+                (
+                    widget.index,
+                    widget.kind,
+                    widget.label.as_str(),
+                    widget.refresh_secs,
+                )
+            })
+            .collect();
+        assert_eq!(
+            widgets,
+            [
+                (
+                    0,
+                    FooterWidgetKind::Command,
+                    "K8S",
+                    MIN_COMMAND_REFRESH_SECS
+                ),
+                (2, FooterWidgetKind::RunningAgents, "AGENTS", 5),
+                (4, FooterWidgetKind::Clock, "TIME", 30),
+            ]
+        );
+        assert_eq!(
+            resolved.widgets[0].command.as_deref(),
+            Some("kubectl config current-context")
+        );
+        assert_eq!(resolved.widgets[2].command, None);
+        assert_eq!(resolved.errors.len(), 3, "{:?}", resolved.errors);
+    }
+
+    #[test]
+    fn widgets_past_the_limit_are_dropped() {
+        let config = FooterConfig {
+            widgets: vec![
+                FooterWidgetConfig {
+                    kind: "clock".to_string(),
+                    ..FooterWidgetConfig::default()
+                };
+                MAX_FOOTER_WIDGETS + 2
+            ],
+        };
+        let resolved = config.resolve();
+        assert_eq!(resolved.widgets.len(), MAX_FOOTER_WIDGETS);
+        assert_eq!(resolved.errors.len(), 1);
+    }
+}
@@ -0,0 +1,254 @@
+//! User-defined keyboard shortcuts for the GUI's global actions.
+//!
+//! Each key of the `[keybindings]` table names an action from
+//! [`KEYBINDING_ACTIONS`]; the value is a combo such as `"ctrl+shift+k"`.
+//! An empty value (or `"none"`) removes the shortcut. Unlisted actions keep
+//! their default.
+//!
+//! ```toml
+//! [keybindings]
+//! command_palette = "ctrl+shift+p"
+//! open_logs = ""
+//! ```
+//!
+//! [`KeybindingsConfig::resolve`] validates the table: unknown actions,
+//! malformed combos, and combos claimed twice are reported and the affected
+//! action keeps its default, so a typo never leaves the GUI without its
+//! shortcuts.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Rebindable actions and their default combos, in Help-card order.
+pub const KEYBINDING_ACTIONS: &[(&str, &str)] = &[
+    ("command_palette", "cmd+k"),
+    ("project_switcher", "cmd+p"),
+    ("open_board", "cmd+b"),
+    ("open_work", "cmd+g"),
+    ("open_logs", "cmd+l"),
+    ("help", "cmd+shift+/"),
+];
+
+/// Combos the GUI binds itself that no action may take over.
+const RESERVED_COMBOS: &[&str] = &["shift+?", "escape"];
+
+/// Named keys accepted besides single printable characters.
+const NAMED_KEYS: &[&str] = &[
+    "escape",
+    "enter",
+    "tab",
+    "backspace",
+    "delete",
+    "arrowup",
+    "arrowdown",
+    "arrowleft",
+    "arrowright",
+    "home",
+    "end",
+    "pageup",
+    "pagedown",
+];
+
+/// `[keybindings]` table: action name → combo.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KeybindingsConfig(pub BTreeMap<String, String>);
+
+/// One action with its effective combo; `None` when unbound.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ResolvedKeybinding {
+    pub action: String,
+    pub combo: Option<String>,
+}
+
+/// Effective bindings for every action plus the problems found in the table.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ResolvedKeybindings {
+    pub bindings: Vec<ResolvedKeybinding>,
+    pub errors: Vec<String>,
+}
+
+impl KeybindingsConfig {
+    /// Effective binding for every action in [`KEYBINDING_ACTIONS`].
+    pub fn resolve(&self) -> ResolvedKeybindings {
+        let mut errors = Vec::new();
+        for action in self.0.keys() {
+            if !KEYBINDING_ACTIONS.iter().any(|(name, _)| name == action) {
+                errors.push(format!("keybindings.{action}: unknown action"));
+            }
+        }
+
+        // Configured combos first, so a default never wins over a combo the
+        // user chose for another action.
+        let mut chosen: Vec<(&str, Option<String>, bool)> = Vec::new();
+        for (action, default) in KEYBINDING_ACTIONS {
+            let entry = match self.0.get(*action) {
+                None => (*action, Some(default.to_string()), false),
+                Some(raw) => match normalize_combo(raw) {
+                    Ok(combo) => (*action, combo, true),
+                    Err(reason) => {
+                        errors.push(format!("keybindings.{action}: {reason}"));
+                        (*action, Some(default.to_string()), false)
+                    }
+                },
+            };
+            chosen.push(entry);
+        }
+
+        // (combo, owner) pairs already claimed.
+        let mut taken: Vec<(String, &str)> = RESERVED_COMBOS
+            .iter()
+            .map(|combo| (combo.to_string(), "gwt"))
+            .collect();
+        let mut bindings: Vec<ResolvedKeybinding> = chosen
+            .iter()
+            .map(|(action, _, _)| ResolvedKeybinding {
+                action: action.to_string(),
+                combo: None,
+            })
+            .collect();
+        for configured_pass in [true, false] {
+            for (index, (action, combo, configured)) in chosen.iter().enumerate() {
+                let Some(combo) = combo.as_ref().filter(|_| *configured == configured_pass) else {
+                    continue;
+                };
+                if let Some((_, owner)) = taken.iter().find(|(taken, _)| taken == combo) {
+                    errors.push(format!(
+                        "keybindings.{action}: \"{combo}\" is already bound to {owner}"
+                    ));
+                    continue;
+                }
+                taken.push((combo.clone(), action));
+                bindings[index].combo = Some(combo.clone());
+            }
+        }
+        ResolvedKeybindings { bindings, errors }
+    }
+}
+
+/// Canonical `cmd+alt+shift+<key>` form of `raw`, or `None` to unbind.
+/// `ctrl` / `meta` / `mod` are spelled `cmd`, like the GUI's hotkey parser.
+fn normalize_combo(raw: &str) -> Result<Option<String>, String> {
+    let raw = raw.trim().to_ascii_lowercase();
+    if raw.is_empty() || raw == "none" {
+        return Ok(None);
+    }
+    let (mut cmd, mut alt, mut shift) = (false, false, false);
+    let mut key: Option<&str> = None;
+    for part in raw.split('+').map(str::trim) {
+        match part {
+            "cmd" | "ctrl" | "control" | "meta" | "mod" => cmd = true,
+            "alt" | "option" | "opt" => alt = true,
+            "shift" => shift = true,
+            "" => return Err(format!("\"{raw}\" has an empty key")),
+            other if key.is_some() => {
+                return Err(format!("\"{raw}\" has more than one key ({other})"))
+            }
+            other => key = Some(other),
+        }
+    }
+    let Some(key) = key else {
+        return Err(format!("\"{raw}\" has no key"));
+    };
+    let function_key = key
+        .strip_prefix('f')
+        .and_then(|n| n.parse::<u8>().ok())
+        .is_some_and(|n| (1..=12).contains(&n));
+    let single_char = key.chars().count() == 1;
+    if !single_char && !function_key && !NAMED_KEYS.contains(&key) {
+        return Err(format!("\"{raw}\" has an unknown key \"{key}\""));
+    }
+    if !cmd && !alt && !function_key {
+        return Err(format!("\"{raw}\" needs cmd/ctrl or alt"));
+    }
+    let mut combo = String::new();
+    for (on, name) in [(cmd, "cmd"), (alt, "alt"), (shift, "shift")] {
+        if on {
+            combo.push_str(name);
+            combo.push('+');
+        }
+    }
+    combo.push_str(key);
+    Ok(Some(combo))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(toml_text: &str) -> KeybindingsConfig {
+        toml::from_str(toml_text).unwrap()
+    }
+
+    fn combo_for<'a>(resolved: &'a ResolvedKeybindings, action: &str) -> Option<&'a str> {
+        resolved
+            .bindings
+            .iter()
+            .find(|binding| binding.action == action)
+            .and_then(|binding| binding.combo.as_deref())
+    }
+
+    #[test]
+    fn empty_table_resolves_to_defaults() {
+        let resolved = KeybindingsConfig::default().resolve();
+        assert!(resolved.errors.is_empty());
+        assert_eq!(resolved.bindings.len(), KEYBINDING_ACTIONS.len());
+        assert_eq!(combo_for(&resolved, "command_palette"), Some("cmd+k"));
+        assert_eq!(combo_for(&resolved, "help"), Some("cmd+shift+/"));
+    }
+
+    #[test]
+    fn overrides_are_normalized_and_can_unbind() {
+        let resolved = config(
+            r#"
+command_palette = "Shift+Ctrl+K"
+open_logs = ""
+open_board = "F2"
+"#,
+        )
+        .resolve();
+        assert!(resolved.errors.is_empty(), "{:?}", resolved.errors);
+        assert_eq!(combo_for(&resolved, "command_palette"), Some("cmd+shift+k"));
+        assert_eq!(combo_for(&resolved, "open_logs"), None);
+        assert_eq!(combo_for(&resolved, "open_board"), Some("f2"));
+    }
+
+    #[test]
+    fn invalid_entries_are_reported_and_keep_defaults() {
+        let resolved = config(
+            r#"
+open_work = "cmd+alt"
+open_logs = "l"
+help = "cmd+hyper"
+unknown = "cmd+u"
+"#,
+        )
+        .resolve();
+        assert_eq!(resolved.errors.len(), 4, "{:?}", resolved.errors);
+        assert_eq!(combo_for(&resolved, "open_work"), Some("cmd+g"));
+        assert_eq!(combo_for(&resolved, "open_logs"), Some("cmd+l"));
+        assert_eq!(combo_for(&resolved, "help"), Some("cmd+shift+/"));
+    }
+
+    #[test]
+    fn conflicts_prefer_configured_combos() {
+        // The palette takes the board's default; the board loses its
+        // shortcut instead of stealing it back.
+        let resolved = config(r#"command_palette = "ctrl+b""#).resolve();
+        assert_eq!(combo_for(&resolved, "command_palette"), Some("cmd+b"));
+        assert_eq!(combo_for(&resolved, "open_board"), None);
+        assert_eq!(resolved.errors.len(), 1);
+        assert!(resolved.errors[0].contains("command_palette"));
+
+        let resolved = config(
+            r#"
+open_board = "cmd+j"
+open_logs = "ctrl+j"
+"#,
+        )
+        .resolve();
+        assert_eq!(combo_for(&resolved, "open_board"), Some("cmd+j"));
+        assert_eq!(combo_for(&resolved, "open_logs"), None);
+    }
+}
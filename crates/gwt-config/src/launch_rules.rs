@@ -0,0 +1,190 @@
+//! Rules that pick Docker or host launches per branch.
+//!
+//! The Launch Wizard pre-selects the runtime (and Docker service) from the
+//! first rule whose conditions all hold for the branch being launched. The
+//! user can still change the selection; the rule only sets the default.
+//!
+//! ```toml
+//! [[launch_rules]]
+//! changed_paths = ["services/"]
+//! runtime = "docker"
+//! service = "app"
+//!
+//! [[launch_rules]]
+//! only_paths = ["docs/", "*.md"]
+//! runtime = "host"
+//! ```
+//!
+//! In patterns `*` matches any run of characters, including `/`, and a
+//! pattern ending in `/` matches everything below that directory.
+
+use serde::{Deserialize, Serialize};
+
+/// Runtime a rule selects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchRuleRuntime {
+    #[default]
+    Host,
+    Docker,
+}
+
+/// One `[[launch_rules]]` entry. A rule without conditions matches every
+/// branch.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LaunchRule {
+    /// Branch name pattern, e.g. `docs/*`.
+    pub branch: Option<String>,
+    /// Holds when any file the branch changed matches one of these.
+    pub changed_paths: Vec<String>,
+    /// Holds when the branch changed at least one file and every changed
+    /// file matches one of these.
+    pub only_paths: Vec<String>,
+    pub runtime: LaunchRuleRuntime,
+    /// Compose service to pre-select for a `docker` rule. Unset keeps the
+    /// project's suggested service.
+    pub service: Option<String>,
+}
+
+impl LaunchRule {
+    /// Whether [`Self::matches`] looks at the branch's changed files.
+    pub fn uses_changed_files(&self) -> bool {
+        !self.changed_paths.is_empty() || !self.only_paths.is_empty()
+    }
+
+    /// Whether the rule holds for `branch`, whose changes since its base are
+    /// `changed_files` (repository-relative, `/`-separated).
+    pub fn matches(&self, branch: &str, changed_files: &[String]) -> bool {
+        let any = |patterns: &[String], file: &str| {
+            patterns
+                .iter()
+                .any(|pattern| pattern_matches(pattern, file))
+        };
+        self.branch
+            .as_deref()
+            .is_none_or(|pattern| pattern_matches(pattern, branch))
+            && (self.changed_paths.is_empty()
+                || changed_files
+                    .iter()
+                    .any(|file| any(&self.changed_paths, file)))
+            && (self.only_paths.is_empty()
+                || (!changed_files.is_empty()
+                    && changed_files.iter().all(|file| any(&self.only_paths, file))))
+    }
+
+    /// Short description recorded with the session, e.g.
+    /// `changed services/ → docker`.
+    pub fn label(&self) -> String {
+        let mut conditions = Vec::new();
+        if let Some(branch) = &self.branch {
+            conditions.push(format!("branch {branch}"));
+        }
+        if !self.changed_paths.is_empty() {
+            conditions.push(format!("changed {}", self.changed_paths.join(", ")));
+        }
+        if !self.only_paths.is_empty() {
+            conditions.push(format!("only {}", self.only_paths.join(", ")));
+        }
+        if conditions.is_empty() {
+            conditions.push("any branch".to_string());
+        }
+        let runtime = match self.runtime {
+            LaunchRuleRuntime::Host => "host",
+            LaunchRuleRuntime::Docker => "docker",
+        };
+        format!("{} → {runtime}", conditions.join("; "))
+    }
+}
+
+/// First rule that holds for `branch`. `changed_files` is only called when
+/// a rule needs it, and then at most once.
+pub fn first_matching_rule<'a>(
+    rules: &'a [LaunchRule],
+    branch: &str,
+    changed_files: impl FnOnce() -> Vec<String>,
+) -> Option<&'a LaunchRule> {
+    let mut changed_files = Some(changed_files);
+    let mut files = Vec::new();
+    rules.iter().find(|rule| {
+        if rule.uses_changed_files() {
+            if let Some(load) = changed_files.take() {
+                files = load();
+            }
+        }
+        rule.matches(branch, &files)
+    })
+}
+
+fn pattern_matches(pattern: &str, value: &str) -> bool {
+    if pattern.ends_with('/') {
+        return value.starts_with(pattern);
+    }
+    fn matches(pattern: &[u8], value: &[u8]) -> bool {
+        match pattern {
+            [] => value.is_empty(),
+            [b'*', rest @ ..] => {
+                matches(rest, value) || (!value.is_empty() && matches(pattern, &value[1..]))
+            }
+            [head, rest @ ..] => value.first() == Some(head) && matches(rest, &value[1..]),
+        }
+    }
+    matches(pattern.as_bytes(), value.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files(paths: &[&str]) -> Vec<String> {
+        paths.iter().map(|path| path.to_string()).collect()
+    }
+
+    #[test]
+    fn first_matching_rule_checks_branch_and_changed_files() {
+        let settings: crate::Settings = toml::from_str(
+            r#"
+[[launch_rules]]
+branch = "release/*"
+runtime = "host"
+
+[[launch_rules]]
+changed_paths = ["services/"]
+runtime = "docker"
+service = "app"
+
+[[launch_rules]]
+only_paths = ["docs/", "*.md"]
+runtime = "host"
+"#,
+        )
+        .unwrap();
+        let rules = settings.launch_rules;
+
+        let pick = |branch: &str, changed: &[&str]| {
+            first_matching_rule(&rules, branch, || files(changed)).map(|rule| rule.runtime)
+        };
+        assert_eq!(pick("release/1.0", &[]), Some(LaunchRuleRuntime::Host));
+        assert_eq!(
+            pick("feature/api", &["services/api/main.rs", "README.md"]),
+            Some(LaunchRuleRuntime::Docker)
+        );
+        assert_eq!(
+            pick("feature/docs", &["docs/guide.md", "CHANGELOG.md"]),
+            Some(LaunchRuleRuntime::Host)
+        );
+        assert_eq!(pick("feature/new", &[]), None);
+        assert_eq!(pick("feature/src", &["src/lib.rs"]), None);
+        assert_eq!(rules[1].label(), "changed services/ → docker");
+    }
+
+    #[test]
+    fn changed_files_are_not_loaded_for_branch_only_rules() {
+        let rules = vec![LaunchRule {
+            branch: Some("docs/*".to_string()),
+            ..LaunchRule::default()
+        }];
+        let found = first_matching_rule(&rules, "docs/intro", || panic!("not needed"));
+        assert_eq!(found, rules.first());
+    }
+}
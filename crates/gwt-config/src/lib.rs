@@ -8,23 +8,53 @@ pub mod ai_settings;
 pub mod atomic;
 pub mod board_config;
 pub mod error;
+pub mod footer_widgets;
+pub mod keybindings;
+pub mod launch_rules;
+pub mod lifecycle_hooks;
 pub mod locale;
 pub mod profile;
+pub mod secrets;
 pub mod settings;
+pub mod theme_config;
 pub mod usage_config;
+pub mod validate;
 pub mod voice_config;
 
-pub use agent_config::AgentConfig;
+pub use agent_config::{AgentConfig, AgentExitPolicy};
 pub use ai_settings::AISettings;
 pub use board_config::{
     BoardConfig, BoardProviderKind, ProjectBoardConfig, SlackConfig, TeamsConfig,
     DEFAULT_OAUTH_REDIRECT_PORT, PROJECT_BOARD_FILE,
 };
 pub use error::{ConfigError, Result};
+pub use footer_widgets::{
+    FooterConfig, FooterWidget, FooterWidgetConfig, FooterWidgetKind, ResolvedFooterWidgets,
+    MAX_FOOTER_WIDGETS,
+};
+pub use keybindings::{
+    KeybindingsConfig, ResolvedKeybinding, ResolvedKeybindings, KEYBINDING_ACTIONS,
+};
+pub use launch_rules::{first_matching_rule, LaunchRule, LaunchRuleRuntime};
+pub use lifecycle_hooks::{LifecycleHookEntry, LifecycleHooksConfig, LIFECYCLE_EVENT_NAMES};
 pub use locale::{
     detect_user_locale, detect_user_locale_from, detect_user_locale_from_env_and_system,
 };
-pub use profile::{Profile, ProfilesConfig};
-pub use settings::{ServerConfig, Settings};
+pub use profile::{
+    load_repo_profiles, Profile, ProfileOrigin, ProfilesConfig, RepoProfiles, WatchdogAction,
+    WatchdogConfig, PROJECT_PROFILES_DIR,
+};
+pub use secrets::{KeyringSecretStore, MemorySecretStore, SecretStore};
+pub use settings::{
+    BackgroundFetchConfig, BranchProtectionConfig, CommitTrailersConfig, DockerConfig,
+    GitHooksConfig, InsightsConfig, JobsConfig, LogRedactionConfig, LogRotationConfig,
+    OpenCommandsConfig, RefreshConfig, ServerConfig, SessionIssueCommentsConfig, Settings,
+    UpdateConfig, WorktreeFilesConfig, WorktreeIntegrityConfig, WorktreeTrashConfig,
+};
+pub use theme_config::{
+    ResolvedThemePalette, ResolvedThemePalettes, ThemeConfig, ThemePaletteConfig,
+    BUILTIN_THEME_PALETTES, THEME_COLOR_TOKENS,
+};
 pub use usage_config::UsageConfig;
+pub use validate::{ConfigDiagnostic, DiagnosticSeverity};
 pub use voice_config::VoiceConfig;
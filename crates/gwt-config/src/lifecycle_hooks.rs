@@ -0,0 +1,127 @@
+//! User-defined lifecycle hook scripts.
+//!
+//! gwt runs these commands when worktrees and agents change state so users
+//! can wire gwt into their own automation. Each hook receives the event as a
+//! JSON object on stdin; see `gwt::lifecycle_hooks` for the runner.
+//!
+//! ```toml
+//! [lifecycle_hooks]
+//! timeout_secs = 30
+//!
+//! [[lifecycle_hooks.hooks]]
+//! event = "worktree.created"
+//! command = "/home/me/bin/on-worktree"
+//! args = ["--notify"]
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// Event names a hook can subscribe to. `*` subscribes to all of them.
+pub const LIFECYCLE_EVENT_NAMES: &[&str] = &[
+    "worktree.created",
+    "worktree.removed",
+    "agent.started",
+    "agent.finished",
+    "agent.stalled",
+    "cleanup.completed",
+];
+
+fn default_timeout_secs() -> u64 {
+    30
+}
+
+/// `[lifecycle_hooks]` table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LifecycleHooksConfig {
+    /// Seconds a hook may run before it is killed. Per-hook
+    /// `timeout_secs` overrides it.
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+    /// Hook commands, run in declaration order.
+    pub hooks: Vec<LifecycleHookEntry>,
+}
+
+impl Default for LifecycleHooksConfig {
+    fn default() -> Self {
+        Self {
+            timeout_secs: default_timeout_secs(),
+            hooks: Vec::new(),
+        }
+    }
+}
+
+/// One `[[lifecycle_hooks.hooks]]` entry.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LifecycleHookEntry {
+    /// Event name from [`LIFECYCLE_EVENT_NAMES`], or `*`.
+    pub event: String,
+    /// Executable to run. Resolved through `PATH` like any other command.
+    pub command: String,
+    pub args: Vec<String>,
+    pub timeout_secs: Option<u64>,
+}
+
+impl LifecycleHooksConfig {
+    /// Hooks subscribed to `event`, skipping entries without a command.
+    pub fn hooks_for<'a>(&'a self, event: &'a str) -> impl Iterator<Item = &'a LifecycleHookEntry> {
+        self.hooks.iter().filter(move |hook| {
+            !hook.command.trim().is_empty() && (hook.event == event || hook.event == "*")
+        })
+    }
+
+    /// Effective timeout for `hook` in seconds.
+    pub fn timeout_secs_for(&self, hook: &LifecycleHookEntry) -> u64 {
+        hook.timeout_secs.unwrap_or(self.timeout_secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_table_uses_defaults() {
+        let config: LifecycleHooksConfig = toml::from_str("").unwrap();
+        assert_eq!(config.timeout_secs, 30);
+        assert!(config.hooks.is_empty());
+    }
+
+    #[test]
+    fn hooks_for_matches_event_and_wildcard() {
+        let config: LifecycleHooksConfig = toml::from_str(
+            r#"
+timeout_secs = 5
+
+[[hooks]]
+event = "worktree.created"
+command = "notify"
+args = ["created"]
+
+[[hooks]]
+event = "*"
+command = "audit"
+timeout_secs = 60
+
+[[hooks]]
+event = "worktree.created"
+command = "  "
+"#,
+        )
+        .unwrap();
+
+        let created: Vec<&str> = config
+            .hooks_for("worktree.created")
+            .map(|hook| hook.command.as_str())
+            .collect();
+        assert_eq!(created, ["notify", "audit"]);
+        let finished: Vec<&str> = config
+            .hooks_for("agent.finished")
+            .map(|hook| hook.command.as_str())
+            .collect();
+        assert_eq!(finished, ["audit"]);
+        assert_eq!(config.timeout_secs_for(&config.hooks[0]), 5);
+        assert_eq!(config.timeout_secs_for(&config.hooks[1]), 60);
+    }
+}
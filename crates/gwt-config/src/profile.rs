@@ -1,10 +1,26 @@
 //! Profile management for environment variables.
-
-use std::collections::{BTreeMap, HashMap};
+//!
+//! Profiles live in `~/.gwt/config.toml`. A repository can also commit
+//! shared ones under [`PROJECT_PROFILES_DIR`], one profile per `*.toml`
+//! file in the [`Profile::to_toml`] format that `gwt profile export` writes.
+//! [`ProfilesConfig::merge_repo_profiles`] adds them next to the user's
+//! profiles; a user profile with the same name wins.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::Path,
+};
 
 use serde::{Deserialize, Serialize};
 
-use crate::ai_settings::AISettings;
+use crate::{
+    ai_settings::AISettings,
+    error::{ConfigError, Result as ConfigResult},
+    secrets::{is_sensitive_env_key, SecretStore},
+};
+
+/// Repository directory with shared profiles, relative to the repo root.
+pub const PROJECT_PROFILES_DIR: &str = ".gwt/profiles";
 
 /// An environment profile with optional AI settings.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -21,12 +37,146 @@ pub struct Profile {
     /// OS environment variables to suppress when this profile is active.
     #[serde(default)]
     pub disabled_env: Vec<String>,
+    /// Environment variables whose values are kept in the OS keychain
+    /// instead of `env_vars`; see [`crate::secrets`].
+    #[serde(default)]
+    pub secret_env: Vec<String>,
     /// AI provider settings (optional).
     #[serde(default)]
     pub ai_settings: Option<AISettings>,
+    /// Stall limits for agents launched while this profile is active.
+    #[serde(default)]
+    pub watchdog: Option<WatchdogConfig>,
+}
+
+/// What the agent watchdog does with a stalled agent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchdogAction {
+    /// Mark the session stalled and tell the user.
+    #[default]
+    Notify,
+    /// Also stop the agent's terminal.
+    Kill,
+}
+
+/// When an agent launched under a profile counts as stalled. Unset limits
+/// are not checked.
+///
+/// ```toml
+/// [[profiles.profiles]]
+/// name = "overnight"
+///
+/// [profiles.profiles.watchdog]
+/// idle_minutes = 20
+/// max_runtime_minutes = 240
+/// action = "kill"
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WatchdogConfig {
+    /// Minutes a running agent may go without session activity.
+    pub idle_minutes: Option<u64>,
+    /// Wall-clock minutes since launch.
+    pub max_runtime_minutes: Option<u64>,
+    pub action: WatchdogAction,
+}
+
+/// Where a profile in the merged list comes from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileOrigin {
+    /// `~/.gwt/config.toml` only.
+    #[default]
+    User,
+    /// The repository's [`PROJECT_PROFILES_DIR`] only.
+    Repo,
+    /// Both; the user's copy is the one used.
+    UserOverridesRepo,
+}
+
+/// Profiles read from a repository's [`PROJECT_PROFILES_DIR`].
+#[derive(Debug, Clone, Default)]
+pub struct RepoProfiles {
+    pub profiles: Vec<Profile>,
+    /// Files that could not be read or parsed, and duplicate names.
+    pub errors: Vec<String>,
+}
+
+/// Read the shared profiles of the repository at `repo_root`, sorted by
+/// file name. A file's profile is named after the file when it has no
+/// `name`. A missing directory has no profiles.
+pub fn load_repo_profiles(repo_root: &Path) -> RepoProfiles {
+    let dir = repo_root.join(PROJECT_PROFILES_DIR);
+    let mut loaded = RepoProfiles::default();
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return loaded;
+    };
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+    for path in paths {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let stem = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let profile = std::fs::read_to_string(&path)
+            .map_err(|error| error.to_string())
+            .and_then(|content| Profile::from_toml(&content, &stem).map_err(|e| e.to_string()));
+        match profile {
+            Ok(profile) if loaded.profiles.iter().any(|p| p.name == profile.name) => {
+                loaded.errors.push(format!(
+                    "{PROJECT_PROFILES_DIR}/{file_name}: profile '{}' is defined twice",
+                    profile.name
+                ));
+            }
+            Ok(profile) => loaded.profiles.push(profile),
+            Err(error) => loaded
+                .errors
+                .push(format!("{PROJECT_PROFILES_DIR}/{file_name}: {error}")),
+        }
+    }
+    loaded
 }
 
 impl Profile {
+    /// Serialize the profile as a standalone file for export or for a
+    /// repository's [`PROJECT_PROFILES_DIR`]. Keys are sorted so exports
+    /// diff cleanly.
+    pub fn to_toml(&self) -> ConfigResult<String> {
+        let value = toml::Value::try_from(self).map_err(|error| ConfigError::WriteError {
+            reason: format!("failed to serialize profile: {error}"),
+        })?;
+        toml::to_string_pretty(&value).map_err(|error| ConfigError::WriteError {
+            reason: format!("failed to serialize profile: {error}"),
+        })
+    }
+
+    /// Parse a profile file written by [`Self::to_toml`]. `fallback_name`
+    /// names a profile whose file has no `name`.
+    pub fn from_toml(content: &str, fallback_name: &str) -> ConfigResult<Self> {
+        let mut profile: Profile =
+            toml::from_str(content).map_err(|error| ConfigError::ParseError {
+                reason: error.to_string(),
+            })?;
+        profile.name = profile.name.trim().to_string();
+        if profile.name.is_empty() {
+            profile.name = fallback_name.trim().to_string();
+        }
+        if profile.name.is_empty() {
+            return Err(ConfigError::ValidationError {
+                reason: "profile name cannot be empty".to_string(),
+            });
+        }
+        Ok(profile)
+    }
+
     /// Create a new profile with the given name.
     pub fn new(name: impl Into<String>) -> Self {
         Self {
@@ -47,6 +197,61 @@ impl Profile {
         self
     }
 
+    /// Whether the value of `key` is kept in the keychain.
+    pub fn is_secret(&self, key: &str) -> bool {
+        self.secret_env.iter().any(|secret| secret == key)
+    }
+
+    /// Move the values of secret keys out of `env_vars` into `store`, so
+    /// the profile can be saved without them. Returns the keys moved.
+    pub fn store_secrets(&mut self, store: &dyn SecretStore) -> ConfigResult<Vec<String>> {
+        let mut moved = Vec::new();
+        for key in &self.secret_env {
+            if let Some(value) = self.env_vars.get(key) {
+                store.set(&self.name, key, value)?;
+                moved.push(key.clone());
+            }
+        }
+        for key in &moved {
+            self.env_vars.remove(key);
+        }
+        moved.sort();
+        Ok(moved)
+    }
+
+    /// Put the stored values of secret keys back into `env_vars`. Returns
+    /// the secret keys that have no stored value.
+    pub fn load_secrets(&mut self, store: &dyn SecretStore) -> ConfigResult<Vec<String>> {
+        let mut missing = Vec::new();
+        for key in &self.secret_env {
+            if self.env_vars.contains_key(key) {
+                continue;
+            }
+            match store.get(&self.name, key)? {
+                Some(value) => {
+                    self.env_vars.insert(key.clone(), value);
+                }
+                None => missing.push(key.clone()),
+            }
+        }
+        Ok(missing)
+    }
+
+    /// Flag the plaintext `env_vars` whose names look like credentials as
+    /// secret. Returns the keys newly flagged.
+    pub fn flag_sensitive_env(&mut self) -> Vec<String> {
+        let mut flagged: Vec<String> = self
+            .env_vars
+            .keys()
+            .filter(|key| is_sensitive_env_key(key) && !self.is_secret(key))
+            .cloned()
+            .collect();
+        flagged.sort();
+        self.secret_env.extend(flagged.iter().cloned());
+        self.secret_env.sort();
+        flagged
+    }
+
     /// Build a sorted effective environment preview by removing disabled OS
     /// variables and then overlaying profile-owned environment variables.
     pub fn merged_env_pairs<I>(&self, base_env: I) -> Vec<(String, String)>
@@ -206,6 +411,42 @@ impl ProfilesConfig {
         Ok(())
     }
 
+    /// [`Profile::store_secrets`] for every profile. Returns the
+    /// `(profile, key)` pairs moved into `store`.
+    pub fn store_secrets(
+        &mut self,
+        store: &dyn SecretStore,
+    ) -> ConfigResult<Vec<(String, String)>> {
+        let mut moved = Vec::new();
+        for profile in &mut self.profiles {
+            for key in profile.store_secrets(store)? {
+                moved.push((profile.name.clone(), key));
+            }
+        }
+        Ok(moved)
+    }
+
+    /// Add the repository profiles whose names the user does not have, and
+    /// report where each profile of the merged list comes from.
+    pub fn merge_repo_profiles(&mut self, repo: &[Profile]) -> BTreeMap<String, ProfileOrigin> {
+        let mut origins = BTreeMap::new();
+        for profile in &self.profiles {
+            let origin = if repo.iter().any(|shared| shared.name == profile.name) {
+                ProfileOrigin::UserOverridesRepo
+            } else {
+                ProfileOrigin::User
+            };
+            origins.insert(profile.name.clone(), origin);
+        }
+        for shared in repo {
+            if !origins.contains_key(&shared.name) {
+                origins.insert(shared.name.clone(), ProfileOrigin::Repo);
+                self.profiles.push(shared.clone());
+            }
+        }
+        origins
+    }
+
     /// Remove a profile by name. Returns the removed profile if found.
     pub fn remove(&mut self, name: &str) -> Option<Profile> {
         if let Some(idx) = self.profiles.iter().position(|p| p.name == name) {
@@ -374,6 +615,30 @@ mod tests {
         assert!(p.ai_settings.is_none());
     }
 
+    #[test]
+    fn watchdog_limits_parse_per_profile() {
+        let settings: crate::Settings = toml::from_str(
+            r#"
+[[profiles.profiles]]
+name = "overnight"
+
+[profiles.profiles.watchdog]
+idle_minutes = 20
+action = "kill"
+"#,
+        )
+        .unwrap();
+        let watchdog = settings
+            .profiles
+            .get("overnight")
+            .unwrap()
+            .watchdog
+            .unwrap();
+        assert_eq!(watchdog.idle_minutes, Some(20));
+        assert_eq!(watchdog.max_runtime_minutes, None);
+        assert_eq!(watchdog.action, WatchdogAction::Kill);
+    }
+
     #[test]
     fn builder_methods() {
         let p = Profile::new("dev")
@@ -513,4 +778,84 @@ mod tests {
             Some(&"val".to_string())
         );
     }
+
+    #[test]
+    fn profile_file_round_trips_and_sorts_env_keys() {
+        let profile = Profile::new("team")
+            .with_env("ZED", "1")
+            .with_env("ALPHA", "2");
+        let exported = profile.to_toml().unwrap();
+        assert!(exported.find("ALPHA").unwrap() < exported.find("ZED").unwrap());
+
+        let imported = Profile::from_toml(&exported, "ignored").unwrap();
+        assert_eq!(imported.name, "team");
+        assert_eq!(imported.env_vars, profile.env_vars);
+
+        let unnamed = Profile::from_toml("[env_vars]\nRUST_LOG = \"debug\"\n", "ci").unwrap();
+        assert_eq!(unnamed.name, "ci");
+        assert!(Profile::from_toml("", " ").is_err());
+        assert!(Profile::from_toml("env_vars = 1", "x").is_err());
+    }
+
+    #[test]
+    fn repo_profiles_merge_under_user_profiles() {
+        let repo = tempfile::tempdir().unwrap();
+        let dir = repo.path().join(PROJECT_PROFILES_DIR);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("ci.toml"), "[env_vars]\nCI = \"1\"\n").unwrap();
+        std::fs::write(
+            dir.join("dev.toml"),
+            "name = \"dev\"\n[env_vars]\nFROM = \"repo\"\n",
+        )
+        .unwrap();
+        std::fs::write(dir.join("dup.toml"), "name = \"ci\"\n").unwrap();
+        std::fs::write(dir.join("broken.toml"), "name = [\n").unwrap();
+        std::fs::write(dir.join("notes.md"), "not a profile").unwrap();
+
+        let shared = load_repo_profiles(repo.path());
+        let names: Vec<&str> = shared.profiles.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["ci", "dev"]);
+        assert_eq!(shared.errors.len(), 2, "{:?}", shared.errors);
+        assert!(shared.errors[0].starts_with(".gwt/profiles/broken.toml: "));
+        assert!(shared.errors[1].contains("defined twice"));
+
+        let mut config = ProfilesConfig::default();
+        config
+            .add(Profile::new("dev").with_env("FROM", "user"))
+            .unwrap();
+        let origins = config.merge_repo_profiles(&shared.profiles);
+        assert_eq!(origins["default"], ProfileOrigin::User);
+        assert_eq!(origins["dev"], ProfileOrigin::UserOverridesRepo);
+        assert_eq!(origins["ci"], ProfileOrigin::Repo);
+        assert_eq!(config.get("dev").unwrap().env_vars["FROM"], "user");
+        assert_eq!(config.get("ci").unwrap().env_vars["CI"], "1");
+
+        assert!(load_repo_profiles(&repo.path().join("missing"))
+            .profiles
+            .is_empty());
+    }
+
+    #[test]
+    fn secret_values_move_to_the_store_and_back() {
+        let store = crate::MemorySecretStore::default();
+        let mut profile = Profile::new("dev")
+            .with_env("GITHUB_TOKEN", "ghp_1")
+            .with_env("RUST_LOG", "debug");
+        assert_eq!(profile.flag_sensitive_env(), ["GITHUB_TOKEN"]);
+        assert!(profile.flag_sensitive_env().is_empty());
+
+        assert_eq!(profile.store_secrets(&store).unwrap(), ["GITHUB_TOKEN"]);
+        assert!(!profile.env_vars.contains_key("GITHUB_TOKEN"));
+        let saved = toml::to_string(&profile).unwrap();
+        assert!(!saved.contains("ghp_1"), "{saved}");
+        assert!(saved.contains("secret_env = [\"GITHUB_TOKEN\"]"), "{saved}");
+
+        let mut loaded: Profile = toml::from_str(&saved).unwrap();
+        assert!(loaded.load_secrets(&store).unwrap().is_empty());
+        assert_eq!(loaded.env_vars["GITHUB_TOKEN"], "ghp_1");
+
+        let mut other = Profile::new("ci");
+        other.secret_env.push("NPM_TOKEN".to_string());
+        assert_eq!(other.load_secrets(&store).unwrap(), ["NPM_TOKEN"]);
+    }
 }
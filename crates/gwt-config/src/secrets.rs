@@ -0,0 +1,139 @@
+//! OS keychain storage for secret profile environment variables.
+//!
+//! A profile lists the keys whose values are secret in
+//! [`Profile::secret_env`](crate::Profile::secret_env). Their values are not
+//! written to `~/.gwt/config.toml`; they live in the OS keychain (macOS
+//! Keychain, Windows Credential Manager, the Secret Service on Linux) under
+//! the service [`KEYCHAIN_SERVICE`] and the account `<profile>/<KEY>`.
+//!
+//! [`Profile::store_secrets`](crate::Profile::store_secrets) moves values out
+//! of the profile into a [`SecretStore`] and
+//! [`Profile::load_secrets`](crate::Profile::load_secrets) brings them back
+//! for an agent launch.
+
+use std::{collections::BTreeMap, sync::Mutex};
+
+use crate::error::{ConfigError, Result};
+
+/// Keychain service name of gwt's secrets.
+pub const KEYCHAIN_SERVICE: &str = "gwt";
+
+/// Whether an environment variable name looks like it holds a credential.
+/// Such values are masked in previews, and `gwt profile migrate-secrets`
+/// moves their plaintext values into the keychain.
+pub fn is_sensitive_env_key(key: &str) -> bool {
+    let key = key.to_ascii_uppercase();
+    key.starts_with("AWS_")
+        || [
+            "TOKEN",
+            "SECRET",
+            "PASSWORD",
+            "PASS",
+            "API_KEY",
+            "APIKEY",
+            "CREDENTIAL",
+            "PRIVATE_KEY",
+            "ACCESS_KEY",
+        ]
+        .iter()
+        .any(|marker| key.contains(marker))
+}
+
+/// Where secret values are kept.
+pub trait SecretStore {
+    fn get(&self, profile: &str, key: &str) -> Result<Option<String>>;
+    fn set(&self, profile: &str, key: &str, value: &str) -> Result<()>;
+    /// Deleting a missing secret is not an error.
+    fn delete(&self, profile: &str, key: &str) -> Result<()>;
+}
+
+/// The OS keychain.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KeyringSecretStore;
+
+impl KeyringSecretStore {
+    fn entry(profile: &str, key: &str) -> Result<keyring::Entry> {
+        keyring::Entry::new(KEYCHAIN_SERVICE, &format!("{profile}/{key}"))
+            .map_err(|error| secret_error(profile, key, error))
+    }
+}
+
+impl SecretStore for KeyringSecretStore {
+    fn get(&self, profile: &str, key: &str) -> Result<Option<String>> {
+        match Self::entry(profile, key)?.get_password() {
+            Ok(value) => Ok(Some(value)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(error) => Err(secret_error(profile, key, error)),
+        }
+    }
+
+    fn set(&self, profile: &str, key: &str, value: &str) -> Result<()> {
+        Self::entry(profile, key)?
+            .set_password(value)
+            .map_err(|error| secret_error(profile, key, error))
+    }
+
+    fn delete(&self, profile: &str, key: &str) -> Result<()> {
+        match Self::entry(profile, key)?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(error) => Err(secret_error(profile, key, error)),
+        }
+    }
+}
+
+fn secret_error(profile: &str, key: &str, error: keyring::Error) -> ConfigError {
+    ConfigError::SecretStore {
+        reason: format!("{key} of profile '{profile}': {error}"),
+    }
+}
+
+/// In-memory store for tests and for callers that must not touch the
+/// keychain.
+#[derive(Debug, Default)]
+pub struct MemorySecretStore {
+    values: Mutex<BTreeMap<(String, String), String>>,
+}
+
+impl SecretStore for MemorySecretStore {
+    fn get(&self, profile: &str, key: &str) -> Result<Option<String>> {
+        let values = self.values.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(values.get(&(profile.to_string(), key.to_string())).cloned())
+    }
+
+    fn set(&self, profile: &str, key: &str, value: &str) -> Result<()> {
+        let mut values = self.values.lock().unwrap_or_else(|e| e.into_inner());
+        values.insert((profile.to_string(), key.to_string()), value.to_string());
+        Ok(())
+    }
+
+    fn delete(&self, profile: &str, key: &str) -> Result<()> {
+        let mut values = self.values.lock().unwrap_or_else(|e| e.into_inner());
+        values.remove(&(profile.to_string(), key.to_string()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sensitive_keys_are_matched_case_insensitively() {
+        assert!(is_sensitive_env_key("GITHUB_TOKEN"));
+        assert!(is_sensitive_env_key("openai_api_key"));
+        assert!(is_sensitive_env_key("AWS_SECRET_ACCESS_KEY"));
+        assert!(!is_sensitive_env_key("PATH"));
+        assert!(!is_sensitive_env_key("KEYBOARD_LAYOUT"));
+    }
+
+    #[test]
+    fn memory_store_round_trips_and_deletes() {
+        let store = MemorySecretStore::default();
+        store.set("dev", "TOKEN", "abc").unwrap();
+        assert_eq!(store.get("dev", "TOKEN").unwrap().as_deref(), Some("abc"));
+        assert_eq!(store.get("ci", "TOKEN").unwrap(), None);
+        store.delete("dev", "TOKEN").unwrap();
+        store.delete("dev", "TOKEN").unwrap();
+        assert_eq!(store.get("dev", "TOKEN").unwrap(), None);
+    }
+}
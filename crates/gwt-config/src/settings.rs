@@ -1,6 +1,7 @@
 //! Top-level application settings backed by `~/.gwt/config.toml`.
 
 use std::{
+    collections::BTreeMap,
     ffi::OsString,
     num::NonZeroU16,
     path::{Path, PathBuf},
@@ -17,7 +18,12 @@ use crate::{
     atomic::write_atomic,
     board_config::BoardConfig,
     error::{ConfigError, Result},
+    footer_widgets::FooterConfig,
+    keybindings::KeybindingsConfig,
+    launch_rules::LaunchRule,
+    lifecycle_hooks::LifecycleHooksConfig,
     profile::ProfilesConfig,
+    theme_config::ThemeConfig,
     usage_config::UsageConfig,
     voice_config::VoiceConfig,
 };
@@ -41,6 +47,363 @@ pub struct ServerConfig {
     /// Last successfully bound implicit port. Zero normalizes to absent.
     #[serde(default, deserialize_with = "deserialize_optional_nonzero_port")]
     pub embedded_port: Option<NonZeroU16>,
+    /// Serve the dashboard without mutating endpoints (worktree changes,
+    /// settings writes, agent launches). `gwt --read-only` forces it on.
+    pub read_only: bool,
+    /// Accept agent tasks on `/api/tasks` and run them headlessly in the
+    /// branch worktree. Off by default; ignored in read-only mode.
+    pub task_queue: bool,
+    /// Serve Swagger UI for `/api/openapi.json` at `/api/docs`. Off by
+    /// default; the page loads its assets from a CDN.
+    pub swagger_ui: bool,
+}
+
+/// Docker launch state persisted under `[docker]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DockerConfig {
+    /// Host ports assigned to each worktree's compose services, keyed by
+    /// worktree path and then `service/original_port`. Kept across launches
+    /// so worktrees of one project never compete for the same host port.
+    pub port_assignments: BTreeMap<String, BTreeMap<String, u16>>,
+}
+
+/// Headless job scheduling persisted under `[jobs]`. Unset or zero values
+/// use the defaults noted on each field.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JobsConfig {
+    /// Agents that run jobs at the same time, across every gwt process on
+    /// this machine. Defaults to 1.
+    pub max_parallel_agents: Option<usize>,
+    /// Runs per job including the first, so 3 retries a failed job twice.
+    /// Defaults to 1 (no retry).
+    pub max_attempts: Option<u32>,
+    /// Seconds a failed job waits before it is queued again. Defaults to 60.
+    pub retry_delay_secs: Option<u64>,
+}
+
+impl JobsConfig {
+    pub fn max_parallel_agents(&self) -> usize {
+        self.max_parallel_agents.filter(|n| *n > 0).unwrap_or(1)
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts.filter(|n| *n > 0).unwrap_or(1)
+    }
+
+    pub fn retry_delay_secs(&self) -> u64 {
+        self.retry_delay_secs.filter(|n| *n > 0).unwrap_or(60)
+    }
+}
+
+/// Local usage insights settings persisted under `[insights]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InsightsConfig {
+    /// Count which screens, commands, and agents are used, in
+    /// `~/.gwt/insights.json`. Opt-in; the counters never leave the machine
+    /// unless exported by hand with `gwt insights export`.
+    pub enabled: bool,
+}
+
+/// Log size rotation persisted under `[log_rotation]`. Unset or zero
+/// values disable the corresponding limit.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogRotationConfig {
+    /// Split the day's `gwt.log.YYYY-MM-DD` into numbered segments once it
+    /// reaches this many MiB.
+    pub max_file_size_mb: Option<u64>,
+    /// Delete the oldest log files once a project's log directory exceeds
+    /// this many MiB.
+    pub max_total_size_mb: Option<u64>,
+}
+
+impl LogRotationConfig {
+    /// `max_file_size_mb` in bytes, `None` when unset or zero.
+    pub fn max_file_bytes(&self) -> Option<u64> {
+        mib_to_bytes(self.max_file_size_mb)
+    }
+
+    /// `max_total_size_mb` in bytes, `None` when unset or zero.
+    pub fn max_total_bytes(&self) -> Option<u64> {
+        mib_to_bytes(self.max_total_size_mb)
+    }
+}
+
+fn mib_to_bytes(mib: Option<u64>) -> Option<u64> {
+    mib.filter(|mib| *mib > 0)
+        .map(|mib| mib.saturating_mul(1024 * 1024))
+}
+
+/// Secret masking for log records, persisted under `[log_redaction]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogRedactionConfig {
+    /// Key name patterns whose values are masked in launch lines, the log
+    /// files and the Logs window. Unset keeps the built-in
+    /// `TOKEN` / `KEY` / `SECRET` / `PASSWORD`; an empty list masks only
+    /// token-shaped values.
+    pub key_patterns: Option<Vec<String>>,
+}
+
+/// Trailers appended to commits made inside agent sessions, persisted under
+/// `[commit_trailers]`. Both are off by default.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CommitTrailersConfig {
+    /// Append `Co-authored-by: <agent>` to agent commits.
+    pub co_authored_by: bool,
+    /// Append `Signed-off-by` for the configured git identity (DCO).
+    pub signed_off_by: bool,
+    /// `Name <email>` co-author identity per agent command, overriding the
+    /// generated `<command>@agents.gwt.invalid` address.
+    pub co_authors: BTreeMap<String, String>,
+}
+
+impl CommitTrailersConfig {
+    /// Whether any trailer is requested.
+    pub fn enabled(&self) -> bool {
+        self.co_authored_by || self.signed_off_by
+    }
+}
+
+/// Commands behind the "open worktree" actions, persisted under `[open]`.
+/// Each value is a program followed by whitespace-separated arguments; the
+/// directory is appended as the last argument.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OpenCommandsConfig {
+    /// Opens a directory in the file manager. Unset uses the platform
+    /// opener (`open`, `xdg-open`, `explorer`).
+    pub file_manager: Option<String>,
+    /// Opens a directory in an editor, e.g. `code -n`. Unset uses
+    /// `$VISUAL`, then `$EDITOR`.
+    pub editor: Option<String>,
+}
+
+/// Posting agent session summaries to the linked GitHub Issue, persisted
+/// under `[session_issue_comments]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionIssueCommentsConfig {
+    /// Post for every repository.
+    pub enabled: bool,
+    /// Repository roots to post for when `enabled` is off.
+    pub repositories: Vec<PathBuf>,
+}
+
+impl SessionIssueCommentsConfig {
+    /// Whether sessions in the repository rooted at `repo_root` post a
+    /// comment when they finish.
+    pub fn enabled_for(&self, repo_root: &Path) -> bool {
+        self.enabled
+            || self
+                .repositories
+                .iter()
+                .any(|configured| same_repo_root(configured, repo_root))
+    }
+}
+
+/// Integrity checks of a worktree after an agent session there ends,
+/// persisted under `[worktree_integrity]`. Off by default.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorktreeIntegrityConfig {
+    /// Check the index, interrupted git operations and `git fsck` when an
+    /// agent session stops.
+    pub verify_after_session: bool,
+}
+
+/// Untracked files copied into each new worktree, persisted under
+/// `[worktree_files]`. Empty by default.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorktreeFilesConfig {
+    /// Glob patterns relative to the source directory, e.g. `.env*` or
+    /// `config/*.local.toml`. Files the new worktree already has are kept.
+    pub copy_on_create: Vec<String>,
+    /// Directory to copy from instead of the main worktree. Relative paths
+    /// are resolved against the main worktree.
+    pub template_dir: Option<PathBuf>,
+}
+
+impl WorktreeFilesConfig {
+    /// Directory the files are copied from for a repository whose main
+    /// worktree is `main_worktree`.
+    pub fn source_dir(&self, main_worktree: &Path) -> PathBuf {
+        match &self.template_dir {
+            Some(dir) => main_worktree.join(dir),
+            None => main_worktree.to_path_buf(),
+        }
+    }
+}
+
+/// How often the GUI runs its periodic work, persisted under `[refresh]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RefreshConfig {
+    /// Seconds without keyboard or pointer input after which the GUI counts
+    /// as idle.
+    pub idle_after_secs: u64,
+    /// While idle, periodic refreshes run this many times less often.
+    /// `1` turns the backoff off.
+    pub idle_backoff: u32,
+    /// Stop periodic refreshes while the window is hidden or minimized.
+    pub pause_when_hidden: bool,
+}
+
+impl RefreshConfig {
+    /// Shortest idle threshold honoured.
+    pub const MIN_IDLE_AFTER_SECS: u64 = 10;
+    /// Largest backoff honoured.
+    pub const MAX_IDLE_BACKOFF: u32 = 30;
+
+    /// The config with out-of-range values clamped.
+    pub fn clamped(&self) -> Self {
+        Self {
+            idle_after_secs: self.idle_after_secs.max(Self::MIN_IDLE_AFTER_SECS),
+            idle_backoff: self.idle_backoff.clamp(1, Self::MAX_IDLE_BACKOFF),
+            pause_when_hidden: self.pause_when_hidden,
+        }
+    }
+}
+
+impl Default for RefreshConfig {
+    fn default() -> Self {
+        Self {
+            idle_after_secs: 120,
+            idle_backoff: 4,
+            pause_when_hidden: true,
+        }
+    }
+}
+
+/// Branch protection synced from GitHub, persisted under
+/// `[branch_protection]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BranchProtectionConfig {
+    /// Protect the branches GitHub reports as protected (branch protection
+    /// rules and rulesets) like `protected_branches`. On by default.
+    pub sync_from_github: bool,
+    /// Server-protected branches gwt should still treat as ordinary
+    /// branches. Does not affect main / master / develop or
+    /// `protected_branches`.
+    pub unprotected: Vec<String>,
+}
+
+impl Default for BranchProtectionConfig {
+    fn default() -> Self {
+        Self {
+            sync_from_github: true,
+            unprotected: Vec::new(),
+        }
+    }
+}
+
+/// Self-update settings persisted under `[update]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdateConfig {
+    /// Release channel `gwt update` and the update checks follow: `stable`,
+    /// `beta` (also release candidates) or `nightly` (every pre-release).
+    /// Unset means `stable`.
+    pub channel: Option<String>,
+}
+
+impl UpdateConfig {
+    /// Channel names gwt understands.
+    pub const CHANNELS: [&'static str; 3] = ["stable", "beta", "nightly"];
+
+    /// The configured channel, `stable` when unset or unknown.
+    pub fn channel(&self) -> &str {
+        self.channel
+            .as_deref()
+            .filter(|channel| Self::CHANNELS.contains(channel))
+            .unwrap_or("stable")
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        match self.channel.as_deref() {
+            Some(channel) if !Self::CHANNELS.contains(&channel) => {
+                Err(ConfigError::ValidationError {
+                    reason: format!(
+                        "unknown channel `{channel}`; expected {}",
+                        Self::CHANNELS.join(", ")
+                    ),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Git hooks written by `gwt hooks install`, persisted under `[git_hooks]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GitHooksConfig {
+    /// Make the `pre-push` hook refuse pushes to protected branches
+    /// (main / master / develop, `protected_branches` and the branches
+    /// synced from GitHub). Off by default.
+    pub block_protected_push: bool,
+}
+
+/// Staging area for removed worktrees, persisted under `[worktree_trash]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorktreeTrashConfig {
+    /// Days a removed worktree stays in `.gwt-trash/` before it is deleted
+    /// for good. `0` deletes removed worktrees immediately.
+    pub retention_days: u32,
+}
+
+impl Default for WorktreeTrashConfig {
+    fn default() -> Self {
+        Self { retention_days: 7 }
+    }
+}
+
+/// Scheduled `git fetch --prune`, persisted under `[background_fetch]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackgroundFetchConfig {
+    /// Fetch the open projects in the background while the GUI or
+    /// `gwtd daemon` runs. Off by default.
+    pub enabled: bool,
+    /// Minutes between two fetches of a project.
+    pub interval_minutes: u64,
+}
+
+impl BackgroundFetchConfig {
+    /// Shortest interval honoured.
+    pub const MIN_INTERVAL_MINUTES: u64 = 5;
+
+    /// Time between two fetches, or `None` when background fetch is off.
+    pub fn interval(&self) -> Option<std::time::Duration> {
+        self.enabled.then(|| {
+            std::time::Duration::from_secs(
+                self.interval_minutes.max(Self::MIN_INTERVAL_MINUTES) * 60,
+            )
+        })
+    }
+}
+
+impl Default for BackgroundFetchConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_minutes: 15,
+        }
+    }
+}
+
+fn same_repo_root(left: &Path, right: &Path) -> bool {
+    match (std::fs::canonicalize(left), std::fs::canonicalize(right)) {
+        (Ok(left), Ok(right)) => left == right,
+        _ => left == right,
+    }
 }
 
 fn resolve_config_home_dir(
@@ -81,10 +444,19 @@ fn resolve_existing_settings_target(path: &Path) -> Result<PathBuf> {
 pub struct Settings {
     /// Branches that cannot be deleted.
     pub protected_branches: Vec<String>,
+    /// Protected branches synced from GitHub and their overrides.
+    pub branch_protection: BranchProtectionConfig,
     /// Default base branch for new worktrees.
     pub default_base_branch: String,
     /// Worktree root directory override.
     pub worktree_root: Option<PathBuf>,
+    /// Repository roots registered with this gwt instance. They appear in the
+    /// project switcher and under `/api/repos` alongside recent projects.
+    pub repositories: Vec<PathBuf>,
+    /// Maximum length of generated worktree paths on Windows. `None` keeps
+    /// the built-in budget below `MAX_PATH`; longer paths are collapsed into
+    /// a hashed directory name.
+    pub worktree_max_path_len: Option<usize>,
     /// Enable debug logging.
     pub debug: bool,
     /// Enable performance profiling.
@@ -106,6 +478,46 @@ pub struct Settings {
     pub usage: UsageConfig,
     /// Embedded browser server configuration (SPEC-3287).
     pub server: ServerConfig,
+    /// Docker launch state.
+    pub docker: DockerConfig,
+    /// Concurrency and retry limits for headless agent jobs.
+    pub jobs: JobsConfig,
+    /// Per-branch Docker / host defaults for the Launch Wizard.
+    pub launch_rules: Vec<LaunchRule>,
+    /// User scripts run on worktree / agent lifecycle events.
+    pub lifecycle_hooks: LifecycleHooksConfig,
+    /// Local-only usage insights.
+    pub insights: InsightsConfig,
+    /// File manager / editor commands for opening worktree directories.
+    pub open: OpenCommandsConfig,
+    /// Agent session summaries posted to the linked Issue.
+    pub session_issue_comments: SessionIssueCommentsConfig,
+    /// Worktree checks after agent sessions.
+    pub worktree_integrity: WorktreeIntegrityConfig,
+    /// Untracked files (`.env`, local config) copied into new worktrees.
+    pub worktree_files: WorktreeFilesConfig,
+    /// Size limits for the JSONL log files.
+    pub log_rotation: LogRotationConfig,
+    /// Secret key patterns masked in logs.
+    pub log_redaction: LogRedactionConfig,
+    /// Co-author / sign-off trailers for agent commits.
+    pub commit_trailers: CommitTrailersConfig,
+    /// Shortcut overrides for the GUI's global actions.
+    pub keybindings: KeybindingsConfig,
+    /// User-defined GUI color palettes.
+    pub theme: ThemeConfig,
+    /// Extra widgets in the GUI status strip.
+    pub footer: FooterConfig,
+    /// Idle backoff of the GUI's periodic refreshes.
+    pub refresh: RefreshConfig,
+    /// Release channel for self-updates.
+    pub update: UpdateConfig,
+    /// Behavior of the git hooks installed by `gwt hooks install`.
+    pub git_hooks: GitHooksConfig,
+    /// Where removed worktrees wait for an undo.
+    pub worktree_trash: WorktreeTrashConfig,
+    /// Periodic `git fetch --prune` of the open projects.
+    pub background_fetch: BackgroundFetchConfig,
 }
 
 impl Default for Settings {
@@ -116,8 +528,11 @@ impl Default for Settings {
                 "master".to_string(),
                 "develop".to_string(),
             ],
+            branch_protection: BranchProtectionConfig::default(),
             default_base_branch: "main".to_string(),
             worktree_root: None,
+            repositories: Vec::new(),
+            worktree_max_path_len: None,
             debug: false,
             profiling: false,
             profiles: ProfilesConfig::default(),
@@ -127,6 +542,26 @@ impl Default for Settings {
             board: BoardConfig::default(),
             usage: UsageConfig::default(),
             server: ServerConfig::default(),
+            docker: DockerConfig::default(),
+            jobs: JobsConfig::default(),
+            launch_rules: Vec::new(),
+            lifecycle_hooks: LifecycleHooksConfig::default(),
+            insights: InsightsConfig::default(),
+            open: OpenCommandsConfig::default(),
+            session_issue_comments: SessionIssueCommentsConfig::default(),
+            worktree_integrity: WorktreeIntegrityConfig::default(),
+            worktree_files: WorktreeFilesConfig::default(),
+            log_rotation: LogRotationConfig::default(),
+            log_redaction: LogRedactionConfig::default(),
+            commit_trailers: CommitTrailersConfig::default(),
+            keybindings: KeybindingsConfig::default(),
+            theme: ThemeConfig::default(),
+            footer: FooterConfig::default(),
+            refresh: RefreshConfig::default(),
+            update: UpdateConfig::default(),
+            git_hooks: GitHooksConfig::default(),
+            worktree_trash: WorktreeTrashConfig::default(),
+            background_fetch: BackgroundFetchConfig::default(),
         }
     }
 }
@@ -284,6 +719,10 @@ mod tests {
             toml::from_str("default_base_branch = \"main\"\ndebug = false\n").unwrap();
         assert!(s.usage.codex_enabled);
         assert!(!s.usage.claude_account_enabled);
+        assert!(!s.insights.enabled, "usage insights are opt-in");
+        assert!(s.branch_protection.sync_from_github);
+        assert!(s.open.editor.is_none());
+        assert!(!s.session_issue_comments.enabled_for(Path::new("/src/gwt")));
     }
 
     #[test]
@@ -330,12 +769,16 @@ mod tests {
         let s = Settings {
             debug: true,
             worktree_root: Some(PathBuf::from("/tmp/wt")),
+            worktree_max_path_len: Some(180),
+            repositories: vec![PathBuf::from("/src/a"), PathBuf::from("/src/b")],
             ..Default::default()
         };
         let toml_str = toml::to_string_pretty(&s).unwrap();
         let loaded: Settings = toml::from_str(&toml_str).unwrap();
         assert!(loaded.debug);
         assert_eq!(loaded.worktree_root, Some(PathBuf::from("/tmp/wt")));
+        assert_eq!(loaded.worktree_max_path_len, Some(180));
+        assert_eq!(loaded.repositories.len(), 2);
     }
 
     #[test]
@@ -471,4 +914,131 @@ debug = true
         assert_eq!(loaded.voice.language, "auto");
         assert!(!loaded.voice.enabled);
     }
+
+    #[test]
+    fn log_rotation_section_converts_to_bytes() {
+        let toml_str = r#"
+[log_rotation]
+max_file_size_mb = 64
+max_total_size_mb = 0
+"#;
+        let loaded: Settings = toml::from_str(toml_str).unwrap();
+        assert_eq!(loaded.log_rotation.max_file_bytes(), Some(64 * 1024 * 1024));
+        assert_eq!(loaded.log_rotation.max_total_bytes(), None);
+        assert_eq!(Settings::default().log_rotation.max_file_bytes(), None);
+    }
+
+    #[test]
+    fn jobs_section_falls_back_to_defaults_for_zero() {
+        let toml_str = r#"
+[jobs]
+max_parallel_agents = 3
+max_attempts = 0
+"#;
+        let loaded: Settings = toml::from_str(toml_str).unwrap();
+        assert_eq!(loaded.jobs.max_parallel_agents(), 3);
+        assert_eq!(loaded.jobs.max_attempts(), 1);
+        assert_eq!(loaded.jobs.retry_delay_secs(), 60);
+    }
+
+    #[test]
+    fn commit_trailers_section_parses_co_author_overrides() {
+        let toml_str = r#"
+[commit_trailers]
+co_authored_by = true
+
+[commit_trailers.co_authors]
+codex = "Codex <codex@example.com>"
+"#;
+        let loaded: Settings = toml::from_str(toml_str).unwrap();
+        assert!(loaded.commit_trailers.enabled());
+        assert!(!loaded.commit_trailers.signed_off_by);
+        assert_eq!(
+            loaded
+                .commit_trailers
+                .co_authors
+                .get("codex")
+                .map(String::as_str),
+            Some("Codex <codex@example.com>")
+        );
+        assert!(!Settings::default().commit_trailers.enabled());
+    }
+
+    #[test]
+    fn log_redaction_section_overrides_key_patterns() {
+        let loaded: Settings = toml::from_str(
+            r#"
+[log_redaction]
+key_patterns = ["TOKEN", "COOKIE"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            loaded.log_redaction.key_patterns,
+            Some(vec!["TOKEN".to_string(), "COOKIE".to_string()])
+        );
+        assert!(Settings::default().log_redaction.key_patterns.is_none());
+    }
+
+    #[test]
+    fn worktree_files_section_resolves_template_dir() {
+        let loaded: Settings = toml::from_str(
+            r#"
+[worktree_files]
+copy_on_create = [".env*", "config/*.local.toml"]
+template_dir = ".gwt/worktree-template"
+"#,
+        )
+        .unwrap();
+        assert_eq!(loaded.worktree_files.copy_on_create.len(), 2);
+        assert_eq!(
+            loaded.worktree_files.source_dir(Path::new("/src/gwt")),
+            Path::new("/src/gwt/.gwt/worktree-template")
+        );
+        assert_eq!(
+            Settings::default()
+                .worktree_files
+                .source_dir(Path::new("/src/gwt")),
+            Path::new("/src/gwt")
+        );
+    }
+
+    #[test]
+    fn refresh_section_is_clamped() {
+        let loaded: Settings = toml::from_str(
+            r#"
+[refresh]
+idle_after_secs = 1
+idle_backoff = 0
+"#,
+        )
+        .unwrap();
+        assert!(loaded.refresh.pause_when_hidden);
+        let clamped = loaded.refresh.clamped();
+        assert_eq!(clamped.idle_after_secs, RefreshConfig::MIN_IDLE_AFTER_SECS);
+        assert_eq!(clamped.idle_backoff, 1);
+        assert_eq!(
+            Settings::default().refresh.clamped(),
+            RefreshConfig::default()
+        );
+    }
+
+    #[test]
+    fn background_fetch_is_off_by_default_and_interval_has_a_floor() {
+        assert_eq!(Settings::default().background_fetch.interval(), None);
+        let loaded: Settings = toml::from_str(
+            r#"
+[background_fetch]
+enabled = true
+interval_minutes = 1
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            loaded.background_fetch.interval(),
+            Some(std::time::Duration::from_secs(
+                BackgroundFetchConfig::MIN_INTERVAL_MINUTES * 60
+            ))
+        );
+    }
 }
@@ -0,0 +1,231 @@
+//! User-defined GUI color palettes.
+//!
+//! The GUI ships a `default` and a `solarized` palette, each with a dark and
+//! a light variant that follow the dark / light / auto theme toggle. The
+//! `[theme.palettes.<name>]` tables add palettes of their own: each variant
+//! overrides design tokens by name (without the leading `--`), and tokens it
+//! leaves out keep the default palette's value.
+//!
+//! ```toml
+//! [theme.palettes.ocean.dark]
+//! color-canvas = "#001b2e"
+//! color-text = "#d6e6f2"
+//!
+//! [theme.palettes.ocean.light]
+//! color-canvas = "#eef6fb"
+//! ```
+//!
+//! Which palette is active is a per-browser preference, like the theme
+//! toggle, so it is not stored here.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Palettes built into the GUI stylesheet.
+pub const BUILTIN_THEME_PALETTES: &[&str] = &["default", "solarized"];
+
+/// Design tokens a palette may override.
+pub const THEME_COLOR_TOKENS: &[&str] = &[
+    "color-canvas",
+    "color-surface",
+    "color-surface-elevated",
+    "color-overlay",
+    "color-scrim",
+    "color-text",
+    "color-text-strong",
+    "color-text-muted",
+    "color-text-disabled",
+    "color-display-fg",
+    "color-status-strip-bg",
+    "color-status-strip-fg",
+    "color-status-strip-divider",
+    "color-button-bg",
+    "color-button-bg-hover",
+    "color-button-fg",
+    "color-button-border",
+    "color-border",
+    "color-border-strong",
+    "color-focus-ring",
+    "color-link",
+    "color-link-hover",
+    "color-state-active",
+    "color-state-idle",
+    "color-state-blocked",
+    "color-state-done",
+    "color-state-needs-input",
+    "color-state-remote",
+    "agent-claude",
+    "agent-codex",
+    "agent-gemini",
+    "agent-opencode",
+    "agent-copilot",
+    "agent-custom",
+];
+
+/// `[theme]` table.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    /// User palettes keyed by name.
+    pub palettes: BTreeMap<String, ThemePaletteConfig>,
+}
+
+/// One `[theme.palettes.<name>]` table: token overrides per variant.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemePaletteConfig {
+    pub dark: BTreeMap<String, String>,
+    pub light: BTreeMap<String, String>,
+}
+
+/// A validated user palette, ready to apply as CSS custom properties.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ResolvedThemePalette {
+    pub name: String,
+    pub dark: BTreeMap<String, String>,
+    pub light: BTreeMap<String, String>,
+}
+
+/// Valid user palettes plus the problems found in the table.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ResolvedThemePalettes {
+    pub palettes: Vec<ResolvedThemePalette>,
+    pub errors: Vec<String>,
+}
+
+impl ThemeConfig {
+    /// Validate the user palettes. Invalid names drop the palette; unknown
+    /// tokens and values that are not plain CSS colors drop the entry.
+    pub fn resolve(&self) -> ResolvedThemePalettes {
+        let mut resolved = ResolvedThemePalettes::default();
+        for (name, palette) in &self.palettes {
+            if BUILTIN_THEME_PALETTES.contains(&name.as_str()) {
+                resolved.errors.push(format!(
+                    "theme.palettes.{name}: name is taken by a built-in palette"
+                ));
+                continue;
+            }
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            {
+                resolved.errors.push(format!(
+                    "theme.palettes.{name}: use letters, digits, '-' or '_' in palette names"
+                ));
+                continue;
+            }
+            resolved.palettes.push(ResolvedThemePalette {
+                name: name.clone(),
+                dark: resolve_variant(name, "dark", &palette.dark, &mut resolved.errors),
+                light: resolve_variant(name, "light", &palette.light, &mut resolved.errors),
+            });
+        }
+        resolved
+    }
+}
+
+fn resolve_variant(
+    name: &str,
+    variant: &str,
+    tokens: &BTreeMap<String, String>,
+    errors: &mut Vec<String>,
+) -> BTreeMap<String, String> {
+    let mut valid = BTreeMap::new();
+    for (token, value) in tokens {
+        let token = token.trim().trim_start_matches("--");
+        if !THEME_COLOR_TOKENS.contains(&token) {
+            errors.push(format!(
+                "theme.palettes.{name}.{variant}: unknown token \"{token}\""
+            ));
+            continue;
+        }
+        let value = value.trim();
+        if !is_css_color(value) {
+            errors.push(format!(
+                "theme.palettes.{name}.{variant}.{token}: \"{value}\" is not a CSS color"
+            ));
+            continue;
+        }
+        valid.insert(token.to_string(), value.to_string());
+    }
+    valid
+}
+
+/// `#rgb` / `#rgba` / `#rrggbb` / `#rrggbbaa`, a color function such as
+/// `rgb(…)` / `hsl(…)` / `oklch(…)` with numeric arguments, or a bare named
+/// color. Anything else could smuggle other CSS into the style attribute.
+fn is_css_color(value: &str) -> bool {
+    if let Some(hex) = value.strip_prefix('#') {
+        return matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+    if let Some((function, rest)) = value.split_once('(') {
+        let Some(args) = rest.strip_suffix(')') else {
+            return false;
+        };
+        return matches!(
+            function.to_ascii_lowercase().as_str(),
+            "rgb" | "rgba" | "hsl" | "hsla" | "hwb" | "lab" | "lch" | "oklab" | "oklch"
+        ) && args
+            .chars()
+            .all(|c| c.is_ascii_digit() || " .,%/-+".contains(c) || c.is_ascii_alphabetic());
+    }
+    !value.is_empty() && value.len() <= 32 && value.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palettes_keep_valid_tokens_and_report_the_rest() {
+        let config: ThemeConfig = toml::from_str(
+            r##"
+[palettes.ocean.dark]
+color-canvas = "#001b2e"
+"--color-text" = "rgb(214 230 242 / 90%)"
+color-link = "red; background: url(x)"
+not-a-token = "#fff"
+
+[palettes.ocean.light]
+color-canvas = "white"
+
+[palettes.solarized.dark]
+color-canvas = "#002b36"
+
+[palettes."bad name".dark]
+color-canvas = "#000"
+"##,
+        )
+        .unwrap();
+
+        let resolved = config.resolve();
+        assert_eq!(resolved.palettes.len(), 1);
+        let ocean = &resolved.palettes[0];
+        assert_eq!(ocean.name, "ocean");
+        assert_eq!(
+            ocean.dark,
+            BTreeMap::from([
+                ("color-canvas".to_string(), "#001b2e".to_string()),
+                (
+                    "color-text".to_string(),
+                    "rgb(214 230 242 / 90%)".to_string()
+                ),
+            ])
+        );
+        assert_eq!(ocean.light["color-canvas"], "white");
+        assert_eq!(resolved.errors.len(), 4, "{:?}", resolved.errors);
+    }
+
+    #[test]
+    fn css_color_check_rejects_declarations() {
+        assert!(is_css_color("#0a0d12"));
+        assert!(is_css_color("hsl(200, 50%, 40%)"));
+        assert!(is_css_color("rebeccapurple"));
+        assert!(!is_css_color("#12345"));
+        assert!(!is_css_color("url(x)"));
+        assert!(!is_css_color("rgb(1,2,3); color: red"));
+        assert!(!is_css_color("var(--color-text)"));
+    }
+}
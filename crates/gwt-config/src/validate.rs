@@ -0,0 +1,372 @@
+//! Strict validation of `~/.gwt/config.toml`.
+//!
+//! [`Settings::load`](crate::Settings::load) callers usually fall back to the
+//! defaults when the file does not parse, so a typo quietly turns every
+//! setting off. [`validate_str`] reports what is wrong instead, with the line
+//! and column of the offending key:
+//!
+//! - errors: TOML syntax errors and values of the wrong type, which make the
+//!   whole file fall back to the defaults;
+//! - warnings: keys gwt does not know (typos, removed settings) and entries
+//!   the keybinding, theme palette and footer widget tables drop or adjust.
+
+use std::{fmt, ops::Range, path::Path};
+
+use serde::Serialize;
+use toml_edit::{Document, Item, TableLike, Value};
+
+use crate::{
+    error::{ConfigError, Result},
+    settings::Settings,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning,
+}
+
+impl DiagnosticSeverity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+        }
+    }
+}
+
+/// One problem found in the config file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigDiagnostic {
+    pub severity: DiagnosticSeverity,
+    /// Dotted key path, e.g. `footer.widgets[1].kind`, when known.
+    pub key: Option<String>,
+    pub message: String,
+    /// 1-based line of the problem, when known.
+    pub line: Option<usize>,
+    /// 1-based column of the problem, when known.
+    pub column: Option<usize>,
+}
+
+impl fmt::Display for ConfigDiagnostic {
+    /// `3:1: warning: agent.check_api_key: unknown key`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let (Some(line), Some(column)) = (self.line, self.column) {
+            write!(f, "{line}:{column}: ")?;
+        }
+        write!(f, "{}: ", self.severity.as_str())?;
+        if let Some(key) = &self.key {
+            write!(f, "{key}: ")?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+/// Validate config file contents. An empty result means the file is fine.
+pub fn validate_str(content: &str) -> Vec<ConfigDiagnostic> {
+    let settings = match toml::from_str::<Settings>(content) {
+        Ok(settings) => settings,
+        Err(error) => {
+            let (line, column) = error
+                .span()
+                .map(|span| line_column(content, span.start))
+                .unzip();
+            return vec![ConfigDiagnostic {
+                severity: DiagnosticSeverity::Error,
+                key: None,
+                message: format!(
+                    "{}; gwt uses the default settings until this is fixed",
+                    error.message().trim()
+                ),
+                line,
+                column,
+            }];
+        }
+    };
+    // The typed parse succeeded, so the raw parse cannot fail.
+    let Ok(document) = Document::parse(content) else {
+        return Vec::new();
+    };
+
+    let mut diagnostics = Vec::new();
+    let known = known_keys(&settings);
+    unknown_keys(
+        content,
+        document.as_table(),
+        known.as_ref(),
+        "",
+        &mut diagnostics,
+    );
+
+    let mut resolve_errors = settings.keybindings.resolve().errors;
+    resolve_errors.extend(settings.theme.resolve().errors);
+    resolve_errors.extend(settings.footer.resolve().errors);
+    if let Err(error) = settings.voice.validate() {
+        resolve_errors.push(format!("voice: {}", error_reason(&error)));
+    }
+    if let Err(error) = settings.update.validate() {
+        resolve_errors.push(format!("update.channel: {}", error_reason(&error)));
+    }
+    for error in resolve_errors {
+        let (key, message) = match error.split_once(": ") {
+            Some((key, message)) => (Some(key.to_string()), message.to_string()),
+            None => (None, error),
+        };
+        let (line, column) = key
+            .as_deref()
+            .and_then(|key| key_span(&document, key))
+            .map(|span| line_column(content, span.start))
+            .unzip();
+        diagnostics.push(ConfigDiagnostic {
+            severity: DiagnosticSeverity::Warning,
+            key,
+            message,
+            line,
+            column,
+        });
+    }
+    diagnostics
+}
+
+/// Validate the config file at `path`.
+pub fn validate_file(path: &Path) -> Result<Vec<ConfigDiagnostic>> {
+    let content = std::fs::read_to_string(path).map_err(|error| ConfigError::ParseError {
+        reason: format!("{}: {error}", path.display()),
+    })?;
+    Ok(validate_str(&content))
+}
+
+/// Validate `~/.gwt/config.toml`. A missing file is valid.
+pub fn validate_global() -> Result<Vec<ConfigDiagnostic>> {
+    match Settings::global_config_path() {
+        Some(path) if path.exists() => validate_file(&path),
+        Some(_) => Ok(Vec::new()),
+        None => Err(ConfigError::NoConfigPath),
+    }
+}
+
+fn error_reason(error: &ConfigError) -> String {
+    match error {
+        ConfigError::ValidationError { reason } | ConfigError::ParseError { reason } => {
+            reason.clone()
+        }
+        other => other.to_string(),
+    }
+}
+
+/// Keys gwt reads: the parsed settings written back out, merged with the
+/// defaults so unset optional keys count as known too.
+fn known_keys(settings: &Settings) -> Option<toml::Value> {
+    let mut known = toml::Value::try_from(settings).ok()?;
+    if let Ok(defaults) = toml::Value::try_from(Settings::default()) {
+        merge_keys(&mut known, defaults);
+    }
+    Some(known)
+}
+
+fn merge_keys(into: &mut toml::Value, from: toml::Value) {
+    if let (toml::Value::Table(into), toml::Value::Table(from)) = (into, from) {
+        for (key, value) in from {
+            match into.get_mut(&key) {
+                Some(existing) => merge_keys(existing, value),
+                None => {
+                    into.insert(key, value);
+                }
+            }
+        }
+    }
+}
+
+fn unknown_keys(
+    content: &str,
+    table: &dyn TableLike,
+    known: Option<&toml::Value>,
+    prefix: &str,
+    diagnostics: &mut Vec<ConfigDiagnostic>,
+) {
+    // Maps keyed by user names (`[keybindings]`, `[theme.palettes]`) come
+    // back from the round trip with the same keys, so only struct fields
+    // that serde skipped are reported.
+    let Some(toml::Value::Table(known)) = known else {
+        return;
+    };
+    for (name, item) in table.iter() {
+        let path = if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{prefix}.{name}")
+        };
+        let Some(known_item) = known.get(name) else {
+            let (line, column) = table
+                .get_key_value(name)
+                .and_then(|(key, _)| key.span())
+                .map(|span| line_column(content, span.start))
+                .unzip();
+            diagnostics.push(ConfigDiagnostic {
+                severity: DiagnosticSeverity::Warning,
+                key: Some(path),
+                message: "unknown key; it is ignored".to_string(),
+                line,
+                column,
+            });
+            continue;
+        };
+        match item {
+            Item::Table(table) => {
+                unknown_keys(content, table, Some(known_item), &path, diagnostics);
+            }
+            Item::Value(Value::InlineTable(table)) => {
+                unknown_keys(content, table, Some(known_item), &path, diagnostics);
+            }
+            Item::ArrayOfTables(array) => {
+                for (index, table) in array.iter().enumerate() {
+                    unknown_keys(
+                        content,
+                        table,
+                        array_entry(known_item, index),
+                        &format!("{path}[{index}]"),
+                        diagnostics,
+                    );
+                }
+            }
+            Item::Value(Value::Array(array)) => {
+                for (index, value) in array.iter().enumerate() {
+                    if let Value::InlineTable(table) = value {
+                        unknown_keys(
+                            content,
+                            table,
+                            array_entry(known_item, index),
+                            &format!("{path}[{index}]"),
+                            diagnostics,
+                        );
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn array_entry(known: &toml::Value, index: usize) -> Option<&toml::Value> {
+    known.as_array().and_then(|array| array.get(index))
+}
+
+/// Span of the key at a dotted path such as `footer.widgets[1]`.
+fn key_span(document: &Document<&str>, path: &str) -> Option<Range<usize>> {
+    let mut table: &dyn TableLike = document.as_table();
+    let mut span = None;
+    let mut segments = path.split('.').peekable();
+    while let Some(segment) = segments.next() {
+        let (name, index) = match segment.split_once('[') {
+            Some((name, rest)) => (name, rest.trim_end_matches(']').parse::<usize>().ok()),
+            None => (segment, None),
+        };
+        let (key, item) = table.get_key_value(name)?;
+        span = key.span();
+        let item_table: Option<&dyn TableLike> = match (item, index) {
+            (Item::ArrayOfTables(array), Some(index)) => {
+                let entry = array.get(index)?;
+                span = entry.span().or(span);
+                Some(entry)
+            }
+            (Item::Value(Value::Array(array)), Some(index)) => {
+                let entry = array.get(index)?;
+                span = entry.span().or(span);
+                entry.as_inline_table().map(|table| table as &dyn TableLike)
+            }
+            (item, _) => item.as_table_like(),
+        };
+        if segments.peek().is_none() {
+            break;
+        }
+        table = item_table?;
+    }
+    span
+}
+
+/// 1-based line and column of byte `offset` in `content`.
+fn line_column(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    (line, before[line_start..].chars().count() + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn type_errors_point_at_the_value() {
+        let diagnostics = validate_str("debug = false\n[agent]\ncheck_api_keys = \"yes\"\n");
+        assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+        let diagnostic = &diagnostics[0];
+        assert_eq!(diagnostic.severity, DiagnosticSeverity::Error);
+        assert_eq!((diagnostic.line, diagnostic.column), (Some(3), Some(18)));
+        assert!(diagnostic.message.contains("default settings"));
+
+        let syntax = validate_str("debug = \n");
+        assert_eq!(syntax[0].line, Some(1));
+    }
+
+    #[test]
+    fn unknown_keys_and_dropped_entries_are_warnings() {
+        let content = r#"debug = true
+protected_branchs = ["main"]
+
+[agent]
+check_api_key = true
+
+[refresh]
+idle_backoff = 2
+
+[[footer.widgets]]
+kind = "clock"
+colour = "red"
+
+[[footer.widgets]]
+kind = "weather"
+
+[keybindings]
+no_such_action = "Ctrl+K"
+"#;
+        let rendered: Vec<String> = validate_str(content)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            rendered,
+            [
+                "2:1: warning: protected_branchs: unknown key; it is ignored",
+                "5:1: warning: agent.check_api_key: unknown key; it is ignored",
+                "12:1: warning: footer.widgets[0].colour: unknown key; it is ignored",
+                "18:1: warning: keybindings.no_such_action: unknown action",
+                "14:1: warning: footer.widgets[1]: unknown kind \"weather\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_update_channel_is_a_warning() {
+        let rendered: Vec<String> = validate_str("[update]\nchannel = \"canary\"\n")
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            rendered,
+            ["2:1: warning: update.channel: unknown channel `canary`; expected stable, beta, nightly"]
+        );
+        assert!(validate_str("[update]\nchannel = \"beta\"\n").is_empty());
+    }
+
+    #[test]
+    fn valid_and_empty_files_have_no_diagnostics() {
+        assert!(validate_str("").is_empty());
+        assert!(validate_str(
+            "worktree_root = \"/tmp/wt\"\n[server]\nembedded_port = 4173\n\
+             [theme.palettes.dusk.dark]\ncolor-canvas = \"#001b2e\"\n"
+        )
+        .is_empty());
+    }
+}
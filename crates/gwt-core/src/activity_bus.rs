@@ -0,0 +1,201 @@
+//! Process-wide bus for agent and worktree activity.
+//!
+//! Producers (worktree create/cleanup, the agent launch flow, hook state
+//! changes, title sync) call [`publish`]; consumers call [`subscribe`] and
+//! receive every event published after that point. The embedded server
+//! forwards the bus to websocket clients and serves it as SSE at
+//! `GET /api/events`, so the frontend and external tools can react without
+//! polling.
+//!
+//! Delivery is best-effort over a `tokio::sync::broadcast` channel: a slow
+//! subscriber receives `Lagged` and skips ahead. Envelopes carry a
+//! monotonically increasing `seq` so consumers can detect the gap.
+
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, OnceLock,
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+/// Broadcast channel capacity. Subscribers further behind than this lag.
+const DEFAULT_BROADCAST_CAPACITY: usize = 256;
+
+static GLOBAL_BUS: OnceLock<ActivityBus> = OnceLock::new();
+
+/// One activity change. Serialized with an `event` tag holding
+/// [`ActivityEvent::name`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event")]
+pub enum ActivityEvent {
+    #[serde(rename = "worktree.created")]
+    WorktreeCreated {
+        repo_path: Option<String>,
+        branch: Option<String>,
+        worktree_path: String,
+        /// Files copied in by `[worktree_files] copy_on_create`.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        copied_files: Vec<String>,
+    },
+    #[serde(rename = "worktree.removed")]
+    WorktreeRemoved {
+        repo_path: Option<String>,
+        branch: Option<String>,
+        worktree_path: String,
+    },
+    #[serde(rename = "agent.started")]
+    AgentStarted {
+        session_id: String,
+        agent_id: String,
+        repo_path: Option<String>,
+        branch: Option<String>,
+        worktree_path: Option<String>,
+    },
+    #[serde(rename = "agent.stopped")]
+    AgentStopped {
+        session_id: String,
+        agent_id: String,
+        repo_path: Option<String>,
+        branch: Option<String>,
+        worktree_path: Option<String>,
+    },
+    /// The agent finished its turn and is waiting for the user.
+    #[serde(rename = "agent.waiting_input")]
+    AgentWaitingInput {
+        session_id: Option<String>,
+        agent_id: Option<String>,
+        window_id: String,
+    },
+    /// The agent-authored session title (`title_summary`) or its detail line
+    /// changed.
+    #[serde(rename = "session.summary_updated")]
+    SessionSummaryUpdated {
+        session_id: String,
+        window_id: String,
+        summary: Option<String>,
+        detail: Option<String>,
+    },
+}
+
+impl ActivityEvent {
+    /// Wire name, also used as the SSE `event:` field.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::WorktreeCreated { .. } => "worktree.created",
+            Self::WorktreeRemoved { .. } => "worktree.removed",
+            Self::AgentStarted { .. } => "agent.started",
+            Self::AgentStopped { .. } => "agent.stopped",
+            Self::AgentWaitingInput { .. } => "agent.waiting_input",
+            Self::SessionSummaryUpdated { .. } => "session.summary_updated",
+        }
+    }
+}
+
+/// An [`ActivityEvent`] as delivered to subscribers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityEnvelope {
+    pub seq: u64,
+    pub occurred_at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub event: ActivityEvent,
+}
+
+/// Cheap `Clone` handle to an activity bus. All clones share one sender.
+#[derive(Clone)]
+pub struct ActivityBus {
+    sender: broadcast::Sender<ActivityEnvelope>,
+    seq: Arc<AtomicU64>,
+}
+
+impl ActivityBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(DEFAULT_BROADCAST_CAPACITY);
+        Self {
+            sender,
+            seq: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Stamp and broadcast `event`. Events published with no live
+    /// subscriber are dropped.
+    pub fn publish(&self, event: ActivityEvent) -> ActivityEnvelope {
+        let envelope = ActivityEnvelope {
+            seq: self.seq.fetch_add(1, Ordering::Relaxed) + 1,
+            occurred_at: Utc::now(),
+            event,
+        };
+        let _ = self.sender.send(envelope.clone());
+        envelope
+    }
+
+    /// Receive every event published after this call.
+    pub fn subscribe(&self) -> broadcast::Receiver<ActivityEnvelope> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for ActivityBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The process-wide bus.
+pub fn global() -> &'static ActivityBus {
+    GLOBAL_BUS.get_or_init(ActivityBus::new)
+}
+
+/// Publish `event` on the process-wide bus.
+pub fn publish(event: ActivityEvent) {
+    global().publish(event);
+}
+
+/// Subscribe to the process-wide bus.
+pub fn subscribe() -> broadcast::Receiver<ActivityEnvelope> {
+    global().subscribe()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subscribers_receive_events_published_after_subscribing() {
+        let bus = ActivityBus::new();
+        bus.publish(ActivityEvent::AgentWaitingInput {
+            session_id: None,
+            agent_id: None,
+            window_id: "before".to_string(),
+        });
+        let mut receiver = bus.subscribe();
+        bus.publish(ActivityEvent::WorktreeCreated {
+            repo_path: Some("/src/gwt".to_string()),
+            branch: Some("work/x".to_string()),
+            worktree_path: "/src/work/x".to_string(),
+            copied_files: Vec::new(),
+        });
+
+        let envelope = receiver.try_recv().unwrap();
+        assert_eq!(envelope.seq, 2);
+        assert_eq!(envelope.event.name(), "worktree.created");
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn envelope_serializes_flat_with_event_tag() {
+        let envelope = ActivityBus::new().publish(ActivityEvent::SessionSummaryUpdated {
+            session_id: "s-1".to_string(),
+            window_id: "tab-1::agent-1".to_string(),
+            summary: Some("Fix login".to_string()),
+            detail: None,
+        });
+        let value = serde_json::to_value(&envelope).unwrap();
+        assert_eq!(value["event"], "session.summary_updated");
+        assert_eq!(value["seq"], 1);
+        assert_eq!(value["summary"], "Fix login");
+        let round_trip: ActivityEnvelope = serde_json::from_value(value).unwrap();
+        assert_eq!(round_trip, envelope);
+    }
+}
@@ -0,0 +1,299 @@
+//! Per-branch notes and tags, and their copy in the PR description.
+//!
+//! Notes are machine-local: `~/.gwt/notes/<repo hash>/notes.json` keeps one
+//! entry per branch. So that the context follows the branch, the same entry
+//! can be written into a marked section of the branch's pull request
+//! description and read back from it on another machine:
+//!
+//! ```text
+//! <!-- gwt:branch-notes BEGIN -->
+//! <details><summary>gwt branch notes</summary> … </details>
+//! <!-- gwt:branch-notes-data {"note":…,"tags":[…],"updated_at":…} -->
+//! <!-- gwt:branch-notes END -->
+//! ```
+//!
+//! The `<details>` block is what teammates see on GitHub (collapsed); the
+//! data comment is what gwt parses. [`reconcile`] picks the side with the
+//! newer `updated_at`, so editing either copy and syncing converges.
+//! Clearing a branch's notes keeps an empty, timestamped entry so the
+//! next sync removes the PR copy instead of pulling it back.
+
+use std::{collections::BTreeMap, fs, path::Path, sync::OnceLock};
+
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::{GwtError, Result};
+
+/// Opening marker of the PR description section.
+pub const PR_NOTES_BEGIN: &str = "<!-- gwt:branch-notes BEGIN -->";
+/// Closing marker of the PR description section.
+pub const PR_NOTES_END: &str = "<!-- gwt:branch-notes END -->";
+const PR_NOTES_DATA_PREFIX: &str = "<!-- gwt:branch-notes-data ";
+
+/// Free-form note and tags attached to one branch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BranchNotes {
+    #[serde(default)]
+    pub note: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl BranchNotes {
+    /// Notes stamped now. Tags are trimmed, de-duplicated and empty ones
+    /// dropped, keeping their first-seen order.
+    pub fn new(note: impl Into<String>, tags: impl IntoIterator<Item = String>) -> Self {
+        let mut unique: Vec<String> = Vec::new();
+        for tag in tags {
+            let tag = tag.trim();
+            if !tag.is_empty() && !unique.iter().any(|seen| seen == tag) {
+                unique.push(tag.to_string());
+            }
+        }
+        Self {
+            note: note.into().trim().to_string(),
+            tags: unique,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.note.is_empty() && self.tags.is_empty()
+    }
+
+    fn same_content(&self, other: &BranchNotes) -> bool {
+        self.note == other.note && self.tags == other.tags
+    }
+}
+
+/// `notes.json`: branch name → notes.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BranchNotesStore {
+    #[serde(default)]
+    branches: BTreeMap<String, BranchNotes>,
+}
+
+fn read_store(path: &Path) -> Result<BranchNotesStore> {
+    match fs::read(path) {
+        Ok(bytes) => serde_json::from_slice(&bytes)
+            .map_err(|err| GwtError::Other(format!("{}: {err}", path.display()))),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(BranchNotesStore::default()),
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// Notes stored for `branch` in the store at `path`.
+pub fn load_branch_notes(path: &Path, branch: &str) -> Result<Option<BranchNotes>> {
+    Ok(read_store(path)?.branches.remove(branch))
+}
+
+/// Store `notes` for `branch`.
+pub fn save_branch_notes(path: &Path, branch: &str, notes: &BranchNotes) -> Result<()> {
+    let mut store = read_store(path)?;
+    store.branches.insert(branch.to_string(), notes.clone());
+    write_store(path, &store)
+}
+
+fn write_store(path: &Path, store: &BranchNotesStore) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let payload = serde_json::to_vec_pretty(store)
+        .map_err(|err| GwtError::Other(format!("serialize branch notes: {err}")))?;
+    let tmp = path.with_extension(format!("json.tmp-{}", std::process::id()));
+    fs::write(&tmp, payload)?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Move the notes of `from` to `to` (branch rename). No-op without notes.
+pub fn rename_branch_notes(path: &Path, from: &str, to: &str) -> Result<()> {
+    let mut store = read_store(path)?;
+    let Some(notes) = store.branches.remove(from) else {
+        return Ok(());
+    };
+    store.branches.insert(to.to_string(), notes);
+    write_store(path, &store)
+}
+
+/// The marked PR description section for `notes`.
+pub fn render_pr_section(notes: &BranchNotes) -> String {
+    let mut section = format!("{PR_NOTES_BEGIN}\n<details><summary>gwt branch notes</summary>\n\n");
+    if !notes.tags.is_empty() {
+        let tags: Vec<String> = notes.tags.iter().map(|tag| format!("`{tag}`")).collect();
+        section.push_str(&format!("Tags: {}\n\n", tags.join(" ")));
+    }
+    if !notes.note.is_empty() {
+        // A literal comment opener in the note would end the section early.
+        section.push_str(&notes.note.replace("<!--", "&lt;!--"));
+        section.push_str("\n\n");
+    }
+    section.push_str("</details>\n");
+    // `>` only appears inside JSON strings, so escaping it keeps the
+    // payload valid JSON that cannot close the comment.
+    let data = serde_json::to_string(notes)
+        .unwrap_or_default()
+        .replace('>', "\\u003e");
+    section.push_str(&format!("{PR_NOTES_DATA_PREFIX}{data} -->\n{PR_NOTES_END}"));
+    section
+}
+
+fn section_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(&format!(
+            r"(?s)\n*{}.*?{}\n?",
+            regex::escape(PR_NOTES_BEGIN),
+            regex::escape(PR_NOTES_END)
+        ))
+        .expect("valid branch notes regex")
+    })
+}
+
+/// Notes carried by a PR description, if it has a readable section.
+pub fn parse_pr_section(body: &str) -> Option<BranchNotes> {
+    let section = section_regex().find(body)?.as_str();
+    let start = section.find(PR_NOTES_DATA_PREFIX)? + PR_NOTES_DATA_PREFIX.len();
+    let end = start + section[start..].find(" -->")?;
+    serde_json::from_str(&section[start..end]).ok()
+}
+
+/// `body` with its notes section replaced by `notes` (appended when there
+/// is none yet), or removed when `notes` is `None` or empty. Text outside
+/// the section is kept as is.
+pub fn splice_pr_section(body: &str, notes: Option<&BranchNotes>) -> String {
+    let stripped = section_regex().replace(body, "\n");
+    let stripped = stripped.trim_end();
+    match notes.filter(|notes| !notes.is_empty()) {
+        None => format!("{stripped}\n").trim_start_matches('\n').to_string(),
+        Some(notes) if stripped.is_empty() => format!("{}\n", render_pr_section(notes)),
+        Some(notes) => format!("{stripped}\n\n{}\n", render_pr_section(notes)),
+    }
+}
+
+/// Which copy a sync should keep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotesSyncAction {
+    /// Neither side has notes (or only an empty local entry).
+    Nothing,
+    /// Both sides already agree.
+    InSync,
+    /// The local notes are newer (or the PR has none): write them to the PR.
+    Push,
+    /// The PR notes are newer (or there are no local ones): store them.
+    Pull,
+}
+
+/// Decide the sync direction between the local and PR copies.
+pub fn reconcile(local: Option<&BranchNotes>, remote: Option<&BranchNotes>) -> NotesSyncAction {
+    match (local, remote) {
+        (None, None) => NotesSyncAction::Nothing,
+        (Some(local), None) if local.is_empty() => NotesSyncAction::Nothing,
+        (Some(_), None) => NotesSyncAction::Push,
+        (None, Some(_)) => NotesSyncAction::Pull,
+        (Some(local), Some(remote)) if local.same_content(remote) => NotesSyncAction::InSync,
+        (Some(local), Some(remote)) if remote.updated_at > local.updated_at => {
+            NotesSyncAction::Pull
+        }
+        (Some(_), Some(_)) => NotesSyncAction::Push,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn notes(note: &str, tags: &[&str], second: u32) -> BranchNotes {
+        BranchNotes {
+            note: note.to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            updated_at: Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, second).unwrap(),
+        }
+    }
+
+    #[test]
+    fn new_normalizes_tags() {
+        let notes = BranchNotes::new(
+            "  try the retry path ",
+            [
+                "bug".to_string(),
+                " bug".to_string(),
+                String::new(),
+                "auth".to_string(),
+            ],
+        );
+        assert_eq!(notes.note, "try the retry path");
+        assert_eq!(notes.tags, ["bug", "auth"]);
+    }
+
+    #[test]
+    fn store_saves_renames_and_keeps_cleared_entries() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("repo").join("notes.json");
+        assert_eq!(load_branch_notes(&path, "feature/a").unwrap(), None);
+
+        let a = notes("context", &["wip"], 0);
+        save_branch_notes(&path, "feature/a", &a).unwrap();
+        save_branch_notes(&path, "feature/b", &notes("other", &[], 0)).unwrap();
+        rename_branch_notes(&path, "feature/a", "feature/c").unwrap();
+
+        assert_eq!(load_branch_notes(&path, "feature/a").unwrap(), None);
+        assert_eq!(load_branch_notes(&path, "feature/c").unwrap(), Some(a));
+        let cleared = notes("", &[], 1);
+        save_branch_notes(&path, "feature/b", &cleared).unwrap();
+        assert_eq!(
+            load_branch_notes(&path, "feature/b").unwrap(),
+            Some(cleared)
+        );
+    }
+
+    #[test]
+    fn pr_section_round_trips_and_keeps_the_rest_of_the_body() {
+        let original = notes("Waiting on <!-- review --> --> from infra", &["blocked"], 5);
+        let body = splice_pr_section("## Summary\n\nAdds retries.\n", Some(&original));
+        assert!(body.starts_with("## Summary\n\nAdds retries.\n\n<!-- gwt:branch-notes BEGIN -->"));
+        assert!(body.contains("Tags: `blocked`"));
+        assert_eq!(parse_pr_section(&body), Some(original));
+
+        let updated = notes("Unblocked", &[], 9);
+        let body = splice_pr_section(&body, Some(&updated));
+        assert_eq!(body.matches(PR_NOTES_BEGIN).count(), 1);
+        assert_eq!(parse_pr_section(&body), Some(updated));
+
+        assert_eq!(
+            splice_pr_section(&body, None),
+            "## Summary\n\nAdds retries.\n"
+        );
+        assert_eq!(parse_pr_section("no section here"), None);
+    }
+
+    #[test]
+    fn reconcile_prefers_the_newer_copy() {
+        let old = notes("a", &[], 0);
+        let new = notes("b", &[], 1);
+        assert_eq!(reconcile(None, None), NotesSyncAction::Nothing);
+        assert_eq!(reconcile(Some(&old), None), NotesSyncAction::Push);
+        assert_eq!(
+            reconcile(Some(&notes("", &[], 0)), None),
+            NotesSyncAction::Nothing
+        );
+        // A cleared local entry newer than the PR copy removes it.
+        assert_eq!(
+            reconcile(Some(&notes("", &[], 9)), Some(&old)),
+            NotesSyncAction::Push
+        );
+        assert_eq!(reconcile(None, Some(&old)), NotesSyncAction::Pull);
+        assert_eq!(reconcile(Some(&old), Some(&new)), NotesSyncAction::Pull);
+        assert_eq!(reconcile(Some(&new), Some(&old)), NotesSyncAction::Push);
+        assert_eq!(
+            reconcile(Some(&old), Some(&notes("a", &[], 7))),
+            NotesSyncAction::InSync
+        );
+    }
+}
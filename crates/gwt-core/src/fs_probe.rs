@@ -0,0 +1,191 @@
+//! Filesystem capability probe.
+//!
+//! Worktrees on NFS / SMB shares can lack working advisory locks, keep
+//! modification times only to the second, or fold case. Liveness checks
+//! built on locks and caches keyed on `(mtime, size)` then give wrong
+//! answers instead of failing, so callers probe the directory once and fall
+//! back to the conservative path when a capability is missing.
+//!
+//! The probe writes a short-lived `.gwt-fs-probe-*` file in the directory and
+//! removes it again; results are cached per directory for the process.
+
+use std::{
+    collections::HashMap,
+    fs::{self, File, OpenOptions},
+    io,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+    time::{Duration, SystemTime},
+};
+
+use serde::Serialize;
+
+/// What a directory's filesystem supports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct FsCapabilities {
+    /// An exclusive advisory lock can be taken and excludes a second handle.
+    pub advisory_locks: bool,
+    /// Modification times keep sub-second precision.
+    pub subsecond_mtime: bool,
+    /// `a` and `A` name different files.
+    pub case_sensitive: bool,
+}
+
+impl Default for FsCapabilities {
+    /// A local filesystem; used when the probe itself cannot run.
+    fn default() -> Self {
+        Self {
+            advisory_locks: true,
+            subsecond_mtime: true,
+            case_sensitive: !cfg!(any(windows, target_os = "macos")),
+        }
+    }
+}
+
+impl FsCapabilities {
+    /// Lock-based liveness or `(mtime, size)` change detection is unreliable
+    /// here. Case folding alone is normal on Windows and macOS and does not
+    /// count.
+    pub fn is_degraded(&self) -> bool {
+        !self.advisory_locks || !self.subsecond_mtime
+    }
+
+    /// One line per missing capability, naming what gwt does about it.
+    pub fn warnings(&self) -> Vec<&'static str> {
+        let mut warnings = Vec::new();
+        if !self.advisory_locks {
+            warnings.push(
+                "file locks are not enforced; session liveness cannot be verified, so branch cleanup never reports branches as safe",
+            );
+        }
+        if !self.subsecond_mtime {
+            warnings.push(
+                "modification times are only second-precise; cached session and workspace state is re-read on every refresh",
+            );
+        }
+        if !self.case_sensitive && cfg!(not(any(windows, target_os = "macos"))) {
+            warnings.push(
+                "file names are case-insensitive; branches differing only in case share a worktree path",
+            );
+        }
+        warnings
+    }
+}
+
+/// Probe `dir`, which must exist and be writable.
+pub fn probe(dir: &Path) -> io::Result<FsCapabilities> {
+    let name = format!(
+        ".gwt-fs-probe-{}-{}",
+        std::process::id(),
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos()
+    );
+    let path = dir.join(&name);
+    let file = OpenOptions::new()
+        .create_new(true)
+        .read(true)
+        .write(true)
+        .open(&path)?;
+    let result = probe_file(dir, &path, &name, &file);
+    drop(file);
+    let _ = fs::remove_file(&path);
+    result
+}
+
+fn probe_file(dir: &Path, path: &Path, name: &str, file: &File) -> io::Result<FsCapabilities> {
+    let advisory_locks = match fs2::FileExt::try_lock_exclusive(file) {
+        Ok(()) => {
+            let second = File::open(path)?;
+            let excluded = fs2::FileExt::try_lock_exclusive(&second).is_err();
+            let _ = fs2::FileExt::unlock(file);
+            excluded
+        }
+        Err(_) => false,
+    };
+
+    // Ask for a time with a fractional part and see whether it survives.
+    let requested = SystemTime::UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789);
+    file.set_modified(requested)?;
+    let stored = fs::metadata(path)?.modified()?;
+    let subsecond_mtime = stored
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .is_ok_and(|since| since.subsec_nanos() != 0);
+
+    let case_sensitive = !dir.join(name.to_uppercase()).exists();
+
+    Ok(FsCapabilities {
+        advisory_locks,
+        subsecond_mtime,
+        case_sensitive,
+    })
+}
+
+/// [`probe`] once per directory for the life of the process. A directory
+/// that cannot be probed (missing, read-only) is treated as local.
+pub fn capabilities(dir: &Path) -> FsCapabilities {
+    static CACHE: OnceLock<Mutex<HashMap<PathBuf, FsCapabilities>>> = OnceLock::new();
+    let key = dunce::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf());
+    let cache = CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+    if let Some(found) = cache
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .get(&key)
+    {
+        return *found;
+    }
+    let found = match probe(&key) {
+        Ok(found) => {
+            for warning in found.warnings() {
+                tracing::warn!(
+                    target: "gwt_core::fs_probe",
+                    path = %key.display(),
+                    "{warning}"
+                );
+            }
+            found
+        }
+        Err(error) => {
+            tracing::debug!(
+                target: "gwt_core::fs_probe",
+                path = %key.display(),
+                error = %error,
+                "filesystem probe skipped"
+            );
+            FsCapabilities::default()
+        }
+    };
+    cache
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .insert(key, found);
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn probe_reports_local_tempdir_capabilities_and_cleans_up() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let found = probe(dir.path()).expect("probe");
+        assert!(found.advisory_locks);
+        assert_eq!(fs::read_dir(dir.path()).expect("read_dir").count(), 0);
+        assert_eq!(capabilities(dir.path()), found);
+    }
+
+    #[test]
+    fn degraded_capabilities_explain_the_fallback() {
+        let degraded = FsCapabilities {
+            advisory_locks: false,
+            subsecond_mtime: false,
+            case_sensitive: true,
+        };
+        assert!(degraded.is_degraded());
+        assert_eq!(degraded.warnings().len(), 2);
+        assert!(!FsCapabilities::default().is_degraded());
+        assert!(capabilities(Path::new("/nonexistent/gwt-fs-probe")) == FsCapabilities::default());
+    }
+}
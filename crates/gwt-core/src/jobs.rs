@@ -0,0 +1,380 @@
+//! Scheduling for headless agent jobs.
+//!
+//! Jobs are [`crate::task_queue`] records; this module decides when they
+//! run. At most [`JobPolicy::max_parallel`] agents run at once across every
+//! gwt process on the machine: a runner must hold one of that many slot
+//! locks under `<dir>/slots/` before it claims a job. A failed job is put
+//! back in the queue until it has run [`JobPolicy::max_attempts`] times,
+//! waiting [`JobPolicy::retry_delay`] between runs.
+//!
+//! Each transition is appended to `<dir>/events.jsonl` as a [`JobEvent`] so
+//! the UI and `gwt jobs events` can follow an overnight drain.
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    lock,
+    task_queue::{self, TaskClaim, TaskRecord, TaskRequest, TaskState},
+    Result,
+};
+
+/// The event log is rotated to `events.jsonl.1` past this size.
+const EVENTS_MAX_BYTES: u64 = 1024 * 1024;
+
+/// How often a runner waiting for a free slot or a retry time checks again.
+const WAIT_POLL: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Concurrency and retry limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JobPolicy {
+    /// Agents running at the same time; at least 1.
+    pub max_parallel: usize,
+    /// Runs per job including the first; at least 1.
+    pub max_attempts: u32,
+    /// Wait between a failed run and the next attempt.
+    pub retry_delay: Duration,
+}
+
+impl Default for JobPolicy {
+    fn default() -> Self {
+        Self {
+            max_parallel: 1,
+            max_attempts: 1,
+            retry_delay: Duration::seconds(60),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum JobEventKind {
+    Queued,
+    Started {
+        attempt: u32,
+    },
+    /// Attempt `attempt` failed and the job runs again from `retry_at`.
+    Retrying {
+        attempt: u32,
+        retry_at: DateTime<Utc>,
+    },
+    Completed,
+    /// The last attempt failed.
+    Failed {
+        #[serde(default)]
+        message: Option<String>,
+    },
+}
+
+/// One line of `events.jsonl`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobEvent {
+    pub id: String,
+    pub at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub kind: JobEventKind,
+}
+
+/// Queue a job and record its `queued` event.
+pub fn enqueue(dir: &Path, request: TaskRequest) -> Result<TaskRecord> {
+    let record = task_queue::enqueue(dir, request)?;
+    rotate_events(dir);
+    emit(dir, &record.id, JobEventKind::Queued);
+    Ok(record)
+}
+
+/// Events from byte `offset` of the log on, and the offset to poll from
+/// next. An offset past the end (the log was rotated) starts over.
+pub fn read_events(dir: &Path, offset: u64) -> (Vec<JobEvent>, u64) {
+    let Ok(mut file) = File::open(events_path(dir)) else {
+        return (Vec::new(), 0);
+    };
+    let len = file.metadata().map(|meta| meta.len()).unwrap_or(0);
+    let offset = if offset > len { 0 } else { offset };
+    let mut text = String::new();
+    if file.seek(SeekFrom::Start(offset)).is_err() || file.read_to_string(&mut text).is_err() {
+        return (Vec::new(), offset);
+    }
+    // A line still being appended has no newline yet; leave it for later.
+    let complete = text.rfind('\n').map_or(0, |end| end + 1);
+    let events = text[..complete]
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    (events, offset + complete as u64)
+}
+
+/// Held while an agent runs; the slot frees when this is dropped.
+#[derive(Debug)]
+pub struct JobSlot {
+    _lock: lock::OwnedLock,
+}
+
+/// Take a free slot out of `max_parallel`, or `None` when all are in use.
+pub fn try_acquire_slot(dir: &Path, max_parallel: usize) -> Result<Option<JobSlot>> {
+    let slots = dir.join("slots");
+    fs::create_dir_all(&slots)?;
+    for index in 0..max_parallel.max(1) {
+        let path = slots.join(format!("{index}.lock"));
+        if let Some(lock) = lock::try_acquire(&path, &format!("job slot {index}"))? {
+            return Ok(Some(JobSlot { _lock: lock }));
+        }
+    }
+    Ok(None)
+}
+
+fn acquire_slot(dir: &Path, max_parallel: usize) -> Result<JobSlot> {
+    loop {
+        if let Some(slot) = try_acquire_slot(dir, max_parallel)? {
+            return Ok(slot);
+        }
+        std::thread::sleep(WAIT_POLL);
+    }
+}
+
+/// Run a claimed job with `run`, which must finish the claim, then record
+/// the outcome: a failed attempt is requeued while attempts remain.
+/// Returns the record as it stands afterwards.
+pub fn run_claimed(
+    dir: &Path,
+    claim: TaskClaim,
+    policy: &JobPolicy,
+    run: impl FnOnce(TaskClaim),
+) -> Option<TaskRecord> {
+    let id = claim.record().id.clone();
+    let attempt = claim.record().attempts;
+    emit(dir, &id, JobEventKind::Started { attempt });
+    run(claim);
+    let record = task_queue::load_task(dir, &id)?;
+    match record.state {
+        TaskState::Completed => emit(dir, &id, JobEventKind::Completed),
+        TaskState::Failed if attempt < policy.max_attempts => {
+            let retry_at = Utc::now() + policy.retry_delay;
+            match task_queue::requeue(dir, &id, retry_at) {
+                Ok(Some(requeued)) => {
+                    emit(dir, &id, JobEventKind::Retrying { attempt, retry_at });
+                    return Some(requeued);
+                }
+                Ok(None) => {}
+                Err(error) => tracing::warn!(
+                    target: "gwt_core::jobs",
+                    id = %id,
+                    error = %error,
+                    "job requeue failed"
+                ),
+            }
+            let message = record.message.clone();
+            emit(dir, &id, JobEventKind::Failed { message });
+        }
+        TaskState::Failed | TaskState::Interrupted => {
+            let message = record.message.clone();
+            emit(dir, &id, JobEventKind::Failed { message });
+        }
+        TaskState::Queued | TaskState::Running => {}
+    }
+    Some(record)
+}
+
+/// Run job `id` to its final state in this thread, waiting for a free slot
+/// before each attempt and for the retry time between attempts. Returns
+/// `None` when the job does not exist or another runner already took it.
+pub fn run_job(
+    dir: &Path,
+    id: &str,
+    policy: &JobPolicy,
+    run: impl Fn(TaskClaim),
+) -> Result<Option<TaskRecord>> {
+    loop {
+        let slot = acquire_slot(dir, policy.max_parallel)?;
+        let Some(claim) = task_queue::claim(dir, id)? else {
+            return Ok(None);
+        };
+        let record = run_claimed(dir, claim, policy, &run);
+        drop(slot);
+        match record {
+            Some(record) if record.state == TaskState::Queued => {
+                let retry_at = record.retry_at.unwrap_or_else(Utc::now);
+                if let Ok(wait) = (retry_at - Utc::now()).to_std() {
+                    std::thread::sleep(wait);
+                }
+            }
+            other => return Ok(other),
+        }
+    }
+}
+
+/// Run queued jobs until none is ready, with up to
+/// [`JobPolicy::max_parallel`] of them (counting other processes) at once.
+/// Jobs waiting for a retry are left for a later call.
+pub fn drain(dir: &Path, policy: &JobPolicy, run: impl Fn(TaskClaim) + Sync) {
+    std::thread::scope(|scope| {
+        for _ in 0..policy.max_parallel.max(1) {
+            scope.spawn(|| loop {
+                let slot = match try_acquire_slot(dir, policy.max_parallel) {
+                    Ok(Some(slot)) => slot,
+                    Ok(None) => return,
+                    Err(error) => {
+                        tracing::warn!(target: "gwt_core::jobs", error = %error, "job slot failed");
+                        return;
+                    }
+                };
+                let claim = match task_queue::claim_next(dir) {
+                    Ok(Some(claim)) => claim,
+                    Ok(None) => return,
+                    Err(error) => {
+                        tracing::warn!(target: "gwt_core::jobs", error = %error, "job claim failed");
+                        return;
+                    }
+                };
+                run_claimed(dir, claim, policy, &run);
+                drop(slot);
+            });
+        }
+    });
+}
+
+fn emit(dir: &Path, id: &str, kind: JobEventKind) {
+    tracing::info!(target: "gwt_core::jobs", id = %id, event = ?kind, "job event");
+    let event = JobEvent {
+        id: id.to_string(),
+        at: Utc::now(),
+        kind,
+    };
+    let result = serde_json::to_string(&event)
+        .map_err(io::Error::other)
+        .and_then(|line| {
+            // One write per line so concurrent appenders never interleave.
+            OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(events_path(dir))?
+                .write_all(format!("{line}\n").as_bytes())
+        });
+    if let Err(error) = result {
+        tracing::warn!(target: "gwt_core::jobs", id = %id, error = %error, "job event write failed");
+    }
+}
+
+fn rotate_events(dir: &Path) {
+    let path = events_path(dir);
+    if fs::metadata(&path).is_ok_and(|meta| meta.len() > EVENTS_MAX_BYTES) {
+        let _ = fs::rename(&path, dir.join("events.jsonl.1"));
+    }
+}
+
+fn events_path(dir: &Path) -> PathBuf {
+    dir.join("events.jsonl")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    fn request(branch: &str) -> TaskRequest {
+        TaskRequest {
+            repo_path: PathBuf::from("/repo"),
+            branch: branch.to_string(),
+            agent: "codex".to_string(),
+            prompt: "fix it".to_string(),
+        }
+    }
+
+    fn kinds(dir: &Path, id: &str) -> Vec<JobEventKind> {
+        read_events(dir, 0)
+            .0
+            .into_iter()
+            .filter(|event| event.id == id)
+            .map(|event| event.kind)
+            .collect()
+    }
+
+    #[test]
+    fn drain_never_exceeds_max_parallel() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        for index in 0..6 {
+            enqueue(dir.path(), request(&format!("feature/{index}"))).expect("enqueue");
+        }
+        let policy = JobPolicy {
+            max_parallel: 2,
+            ..JobPolicy::default()
+        };
+        let running = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        drain(dir.path(), &policy, |claim| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            running.fetch_sub(1, Ordering::SeqCst);
+            claim.finish(Some(0), None);
+        });
+
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+        let records = task_queue::list_tasks(dir.path());
+        assert_eq!(records.len(), 6);
+        assert!(records
+            .iter()
+            .all(|record| record.state == TaskState::Completed));
+        let (events, offset) = read_events(dir.path(), 0);
+        assert_eq!(events.len(), 18);
+        assert_eq!(read_events(dir.path(), offset).0, Vec::new());
+    }
+
+    #[test]
+    fn failed_job_is_retried_until_attempts_run_out() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let job = enqueue(dir.path(), request("feature/flaky")).expect("enqueue");
+        let policy = JobPolicy {
+            max_parallel: 1,
+            max_attempts: 2,
+            retry_delay: Duration::zero(),
+        };
+        let runs = AtomicUsize::new(0);
+        let record = run_job(dir.path(), &job.id, &policy, |claim| {
+            runs.fetch_add(1, Ordering::SeqCst);
+            claim.finish(Some(1), Some("tests fail".to_string()));
+        })
+        .expect("run")
+        .expect("record");
+
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+        assert_eq!(record.state, TaskState::Failed);
+        assert_eq!(record.attempts, 2);
+        let events = kinds(dir.path(), &job.id);
+        assert_eq!(
+            events[..2],
+            [JobEventKind::Queued, JobEventKind::Started { attempt: 1 }]
+        );
+        assert!(matches!(
+            events[2],
+            JobEventKind::Retrying { attempt: 1, .. }
+        ));
+        assert_eq!(
+            events[3..],
+            [
+                JobEventKind::Started { attempt: 2 },
+                JobEventKind::Failed {
+                    message: Some("tests fail".to_string())
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn slots_are_shared_until_released() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let first = try_acquire_slot(dir.path(), 1)
+            .expect("slot")
+            .expect("free");
+        assert!(try_acquire_slot(dir.path(), 1).expect("slot").is_none());
+        drop(first);
+        assert!(try_acquire_slot(dir.path(), 1).expect("slot").is_some());
+    }
+}
@@ -4,16 +4,22 @@
 //! execution helpers. No business logic lives here — domain crates
 //! (gwt-git, gwt-agent, etc.) build on top of these primitives.
 
+pub mod activity_bus;
 pub mod board_remote_roots;
+pub mod branch_notes;
 pub mod config;
 pub mod coordination;
 pub mod daemon;
 pub mod error;
+pub mod fs_probe;
 pub mod index;
 pub mod index_coordinator;
+pub mod jobs;
+pub mod lock;
 pub mod logging;
 pub mod migration;
 pub mod operation_deadline;
+pub mod operation_progress;
 pub mod paths;
 pub mod process;
 pub mod process_console;
@@ -22,7 +28,10 @@ mod release_contract;
 pub mod release_notes;
 pub mod repo_hash;
 pub mod runtime;
+pub mod session_search;
+pub mod session_stream;
 pub mod skill_state;
+pub mod task_queue;
 #[cfg(any(test, feature = "test-support"))]
 pub mod test_support;
 pub mod update;
@@ -0,0 +1,363 @@
+//! Advisory file locks that record who holds them.
+//!
+//! gwt coordinates processes with `fs2` advisory locks on `*.lock` files
+//! under `~/.gwt`. The OS drops such a lock when its process exits, but a
+//! lock can still outlive its owner: a descriptor inherited by a child that
+//! keeps running, a network home directory, or an owner that hung. Locks
+//! taken through [`try_acquire`] / [`acquire`] write a [`LockOwner`] (pid,
+//! hostname, acquisition time, purpose) into the lock file so `gwt locks`
+//! can show who holds what, and a lock whose recorded owner is a dead
+//! process on this host is [`LockState::Stale`]: acquiring it replaces the
+//! lock file instead of waiting forever.
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+
+/// Directories [`scan`] does not descend into.
+const SCAN_SKIP_DIRS: &[&str] = &[".git", "node_modules", "target", ".venv"];
+const SCAN_MAX_DEPTH: usize = 6;
+
+/// Who holds a lock, as written into the lock file by its holder.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockOwner {
+    pub pid: u32,
+    pub hostname: String,
+    pub acquired_at: DateTime<Utc>,
+    /// What the lock protects, e.g. `job slot 0`.
+    pub purpose: String,
+}
+
+impl LockOwner {
+    fn current(purpose: &str) -> Self {
+        Self {
+            pid: std::process::id(),
+            hostname: hostname(),
+            acquired_at: Utc::now(),
+            purpose: purpose.to_string(),
+        }
+    }
+
+    /// The owner ran on this host and its process is gone.
+    pub fn is_dead(&self) -> bool {
+        self.hostname == hostname() && !is_process_alive(self.pid)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockState {
+    /// Nobody holds the lock.
+    Free,
+    /// A live process holds the lock.
+    Held,
+    /// The lock is held, but its recorded owner is a dead process on this
+    /// host.
+    Stale,
+}
+
+impl LockState {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Free => "free",
+            Self::Held => "held",
+            Self::Stale => "stale",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LockStatus {
+    pub path: PathBuf,
+    pub state: LockState,
+    /// The recorded owner; for a free lock, the last one that did not
+    /// release it cleanly.
+    pub owner: Option<LockOwner>,
+}
+
+/// A held lock. Dropping it clears the owner record and unlocks.
+#[derive(Debug)]
+pub struct OwnedLock {
+    path: PathBuf,
+    file: File,
+}
+
+impl OwnedLock {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for OwnedLock {
+    fn drop(&mut self) {
+        let _ = self.file.set_len(0);
+        let _ = FileExt::unlock(&self.file);
+    }
+}
+
+/// Take the lock at `path` without waiting; `None` while a live process
+/// holds it. A stale lock is replaced.
+pub fn try_acquire(path: &Path, purpose: &str) -> io::Result<Option<OwnedLock>> {
+    let file = open(path)?;
+    match file.try_lock_exclusive() {
+        Ok(()) => own(path, file, purpose).map(Some),
+        Err(error) if error.kind() == io::ErrorKind::WouldBlock || is_contended(&error) => {
+            if !recover_stale(path)? {
+                return Ok(None);
+            }
+            let file = open(path)?;
+            match file.try_lock_exclusive() {
+                Ok(()) => own(path, file, purpose).map(Some),
+                Err(_) => Ok(None),
+            }
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Take the lock at `path`, waiting for a live holder (up to the current
+/// [`crate::operation_deadline`]). A stale lock is replaced.
+pub fn acquire(path: &Path, purpose: &str) -> io::Result<OwnedLock> {
+    if let Some(lock) = try_acquire(path, purpose)? {
+        return Ok(lock);
+    }
+    let file = open(path)?;
+    crate::operation_deadline::lock_exclusive(&file)?;
+    own(path, file, purpose)
+}
+
+/// Record the current process as the holder of `file`, which the caller
+/// has just locked. For locks not taken through [`try_acquire`].
+pub fn record_owner(file: &File, purpose: &str) -> io::Result<()> {
+    let payload = serde_json::to_vec(&LockOwner::current(purpose)).map_err(io::Error::other)?;
+    let mut file = file;
+    file.set_len(0)?;
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(&payload)?;
+    file.flush()
+}
+
+/// Read the owner record of the lock file at `path`.
+pub fn read_owner(path: &Path) -> Option<LockOwner> {
+    let mut content = String::new();
+    File::open(path).ok()?.read_to_string(&mut content).ok()?;
+    serde_json::from_str(content.trim()).ok()
+}
+
+/// Current state of the lock at `path`. Probing briefly takes a free lock.
+pub fn inspect(path: &Path) -> io::Result<LockStatus> {
+    let owner = read_owner(path);
+    let file = OpenOptions::new().read(true).write(true).open(path)?;
+    let state = match file.try_lock_exclusive() {
+        Ok(()) => {
+            let _ = FileExt::unlock(&file);
+            LockState::Free
+        }
+        Err(_) if owner.as_ref().is_some_and(LockOwner::is_dead) => LockState::Stale,
+        Err(_) => LockState::Held,
+    };
+    Ok(LockStatus {
+        path: path.to_path_buf(),
+        state,
+        owner,
+    })
+}
+
+/// Every `*.lock` file under `root`, sorted by path.
+pub fn scan(root: &Path) -> Vec<LockStatus> {
+    let mut paths = Vec::new();
+    collect_lock_files(root, 0, &mut paths);
+    paths.sort();
+    paths
+        .into_iter()
+        .filter_map(|path| inspect(&path).ok())
+        .collect()
+}
+
+/// Remove the lock file at `path` so the next acquirer starts from a fresh
+/// file. A process still holding the old file keeps its lock on the removed
+/// file only, so this is for stale locks and hung owners.
+pub fn force_release(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+        _ => Ok(()),
+    }
+}
+
+fn recover_stale(path: &Path) -> io::Result<bool> {
+    let Some(owner) = read_owner(path).filter(LockOwner::is_dead) else {
+        return Ok(false);
+    };
+    tracing::warn!(
+        target: "gwt_core::lock",
+        path = %path.display(),
+        pid = owner.pid,
+        purpose = %owner.purpose,
+        "replacing lock held on behalf of a dead process"
+    );
+    force_release(path)?;
+    Ok(true)
+}
+
+fn own(path: &Path, file: File, purpose: &str) -> io::Result<OwnedLock> {
+    record_owner(&file, purpose)?;
+    Ok(OwnedLock {
+        path: path.to_path_buf(),
+        file,
+    })
+}
+
+fn open(path: &Path) -> io::Result<File> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .read(true)
+        .write(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// `fs2` reports contention on Windows as a raw OS error rather than
+/// `WouldBlock`.
+fn is_contended(error: &io::Error) -> bool {
+    error.raw_os_error() == fs2::lock_contended_error().raw_os_error()
+}
+
+fn collect_lock_files(dir: &Path, depth: usize, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_dir() {
+            let skipped = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| SCAN_SKIP_DIRS.contains(&name));
+            if depth < SCAN_MAX_DEPTH && !skipped {
+                collect_lock_files(&path, depth + 1, out);
+            }
+        } else if file_type.is_file() && path.extension().is_some_and(|ext| ext == "lock") {
+            out.push(path);
+        }
+    }
+}
+
+/// This machine's host name, or an empty string when it is unknown.
+pub fn hostname() -> String {
+    #[cfg(unix)]
+    {
+        let mut buffer = [0u8; 256];
+        // SAFETY: the buffer is valid for its length; gethostname writes a
+        // NUL-terminated name into it on success.
+        let rc = unsafe { libc::gethostname(buffer.as_mut_ptr().cast(), buffer.len()) };
+        if rc != 0 {
+            return String::new();
+        }
+        let end = buffer
+            .iter()
+            .position(|&byte| byte == 0)
+            .unwrap_or(buffer.len());
+        String::from_utf8_lossy(&buffer[..end]).into_owned()
+    }
+    #[cfg(not(unix))]
+    {
+        std::env::var("COMPUTERNAME").unwrap_or_default()
+    }
+}
+
+fn is_process_alive(pid: u32) -> bool {
+    if pid == 0 {
+        return false;
+    }
+    if pid == std::process::id() {
+        return true;
+    }
+    #[cfg(unix)]
+    {
+        // SAFETY: signal 0 only checks whether the process exists.
+        let rc = unsafe { libc::kill(pid as libc::pid_t, 0) };
+        rc == 0 || io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+    }
+    #[cfg(not(unix))]
+    {
+        // Without a liveness probe the owner is assumed alive, so a lock is
+        // never taken over from a running process.
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A pid far above any `pid_max`, so it is never a live process.
+    const DEAD_PID: u32 = 0x3fff_fff0;
+
+    #[test]
+    fn owned_lock_records_and_clears_its_owner() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("slots").join("0.lock");
+        let lock = try_acquire(&path, "job slot 0")
+            .unwrap()
+            .expect("free lock");
+        let owner = read_owner(&path).expect("owner recorded");
+        assert_eq!(owner.pid, std::process::id());
+        assert_eq!(owner.purpose, "job slot 0");
+        assert_eq!(inspect(&path).unwrap().state, LockState::Held);
+        assert!(try_acquire(&path, "again").unwrap().is_none());
+
+        drop(lock);
+        let status = inspect(&path).unwrap();
+        assert_eq!(status.state, LockState::Free);
+        assert_eq!(status.owner, None);
+    }
+
+    #[test]
+    fn lock_held_for_a_dead_owner_is_stale_and_taken_over() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("probe.lock");
+        // A descriptor that outlived its process: locked here, but the
+        // record names a dead pid.
+        let orphan = open(&path).unwrap();
+        orphan.try_lock_exclusive().unwrap();
+        let dead = LockOwner {
+            pid: DEAD_PID,
+            hostname: hostname(),
+            acquired_at: Utc::now(),
+            purpose: "probe".to_string(),
+        };
+        fs::write(&path, serde_json::to_vec(&dead).unwrap()).unwrap();
+
+        let status = inspect(&path).unwrap();
+        assert_eq!(status.state, LockState::Stale);
+        assert_eq!(status.owner.as_ref().map(|owner| owner.pid), Some(DEAD_PID));
+        assert_eq!(scan(temp.path()), vec![status]);
+
+        let lock = try_acquire(&path, "probe")
+            .unwrap()
+            .expect("stale lock taken over");
+        assert_eq!(read_owner(lock.path()).unwrap().pid, std::process::id());
+    }
+
+    #[test]
+    fn scan_skips_vendored_directories() {
+        let temp = tempfile::tempdir().unwrap();
+        fs::create_dir_all(temp.path().join("node_modules")).unwrap();
+        fs::write(temp.path().join("node_modules").join("x.lock"), "").unwrap();
+        fs::write(temp.path().join("works.lock"), "").unwrap();
+        let paths: Vec<PathBuf> = scan(temp.path()).into_iter().map(|s| s.path).collect();
+        assert_eq!(paths, [temp.path().join("works.lock")]);
+    }
+}
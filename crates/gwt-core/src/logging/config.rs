@@ -100,6 +100,18 @@ pub struct LoggingConfig {
     /// Housekeeping retention window in days. Files older than this are
     /// deleted on startup. Pass `0` to disable.
     pub retention_days: u32,
+    /// Size at which the day's log file is split into a numbered segment.
+    /// `None` keeps one file per day.
+    pub max_file_bytes: Option<u64>,
+    /// Budget for all rolling log files; the oldest are deleted on startup
+    /// and after each size split until the directory fits. `None` disables.
+    pub max_total_bytes: Option<u64>,
+    /// OTLP/HTTP traces URL. Falls back to the standard `OTEL_EXPORTER_OTLP_*`
+    /// variables; only honoured when built with the `otlp` feature.
+    pub otlp_endpoint: Option<String>,
+    /// Key patterns whose values are masked in every log record. `None`
+    /// keeps [`super::redact::DEFAULT_SECRET_KEY_PATTERNS`].
+    pub secret_key_patterns: Option<Vec<String>>,
 }
 
 impl LoggingConfig {
@@ -110,9 +122,36 @@ impl LoggingConfig {
             default_level: LogLevel::Info,
             config_file_level: None,
             retention_days: 7,
+            max_file_bytes: None,
+            max_total_bytes: None,
+            otlp_endpoint: None,
+            secret_key_patterns: None,
         }
     }
 
+    /// Set the size rotation limits. Zero is treated as unset.
+    pub fn with_size_limits(
+        mut self,
+        max_file_bytes: Option<u64>,
+        max_total_bytes: Option<u64>,
+    ) -> Self {
+        self.max_file_bytes = max_file_bytes.filter(|bytes| *bytes > 0);
+        self.max_total_bytes = max_total_bytes.filter(|bytes| *bytes > 0);
+        self
+    }
+
+    /// Export spans to the OTLP/HTTP traces URL `endpoint`.
+    pub fn with_otlp_endpoint(mut self, endpoint: Option<String>) -> Self {
+        self.otlp_endpoint = endpoint;
+        self
+    }
+
+    /// Replace the default secret key patterns.
+    pub fn with_secret_key_patterns(mut self, patterns: Option<Vec<String>>) -> Self {
+        self.secret_key_patterns = patterns;
+        self
+    }
+
     /// Override the config-file level (used by Settings UI and
     /// `config.toml` round-trip).
     pub fn with_config_file_level(mut self, level: Option<LogLevel>) -> Self {
@@ -8,7 +8,9 @@ use tracing_subscriber::fmt::{self, time::ChronoLocal};
 ///
 /// Each event is serialised as a single JSON object per line with:
 /// `timestamp` (RFC3339 local), `level`, `target`, `message`, and any
-/// structured fields captured from the `tracing` call site.
+/// structured fields captured from the `tracing` call site. Records pass
+/// through [`super::redact::RedactingMakeWriter`] so secret values never
+/// reach the file.
 pub fn build<S>(writer: NonBlocking) -> impl tracing_subscriber::Layer<S>
 where
     S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
@@ -23,5 +25,5 @@ where
         .with_thread_ids(false)
         .with_thread_names(false)
         .with_timer(ChronoLocal::rfc_3339())
-        .with_writer(writer)
+        .with_writer(super::redact::RedactingMakeWriter::new(writer))
 }
@@ -1,24 +1,63 @@
-//! Startup housekeeping: delete rotated log files older than the retention window.
+//! Startup housekeeping: delete rotated log files older than the retention
+//! window, compress the ones from before yesterday with zstd, and delete the
+//! oldest files once the directory exceeds its size budget.
+//!
+//! All passes go through [`purge`], which `gwt logs purge` also runs in
+//! dry-run mode to preview what would be removed.
 
 use std::{
-    fs,
+    ffi::OsString,
+    fs, io,
     path::{Path, PathBuf},
 };
 
 use chrono::{NaiveDate, Utc};
 
-use super::writer::LOG_FILE_BASENAME;
+use super::writer::{is_compressed_log_file, parse_log_file_name, COMPRESSED_LOG_SUFFIX};
 
 /// Summary of a housekeeping run. Non-fatal errors are collected into
 /// `errors` rather than being returned as `Err`, so that a single
 /// unreadable file cannot block TUI startup.
+///
+/// In a dry run the paths and byte counts describe what would happen;
+/// nothing on disk changes.
 #[derive(Debug, Default)]
 pub struct HousekeepReport {
     pub inspected: usize,
     pub deleted: Vec<PathBuf>,
+    /// Bytes held by the `deleted` files.
+    pub deleted_bytes: u64,
+    /// Plain files replaced by a `.zst` copy, by their original path.
+    pub compressed: Vec<PathBuf>,
+    /// Size of the `compressed` files before compression.
+    pub compressed_bytes: u64,
     pub errors: Vec<(PathBuf, String)>,
 }
 
+/// Passes run by [`purge`], in this order. Every pass is off by default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PurgeOptions {
+    /// Delete files older than this many days, today included. `0` keeps
+    /// every file.
+    pub retention_days: u32,
+    /// Compress plain files dated before yesterday. Yesterday's file is left
+    /// alone because a writer may still be flushing into it around midnight.
+    pub compress: bool,
+    /// Delete the oldest files until the directory totals at most this many
+    /// bytes. `None` or `0` disables the budget.
+    pub max_total_bytes: Option<u64>,
+    /// Only report what would be deleted or compressed.
+    pub dry_run: bool,
+}
+
+/// A rolling log file found in the log directory.
+struct LogFile {
+    path: PathBuf,
+    date: NaiveDate,
+    segment: Option<u32>,
+    size: u64,
+}
+
 /// Delete rotated log files older than `retention_days` relative to today's
 /// UTC date, matching the rolling writer's UTC boundary. Returns a
 /// `HousekeepReport` describing what was done.
@@ -32,8 +71,56 @@ pub fn housekeep(log_dir: &Path, retention_days: u32) -> HousekeepReport {
 
 /// Deterministic version of `housekeep` that lets tests pin `today`.
 pub fn housekeep_at(log_dir: &Path, retention_days: u32, today: NaiveDate) -> HousekeepReport {
+    let options = PurgeOptions {
+        retention_days,
+        ..PurgeOptions::default()
+    };
+    purge_at(log_dir, &options, today)
+}
+
+/// Delete the oldest log files until the rolling files in `log_dir` total
+/// at most `max_total_bytes`. Today's active file is never deleted, so a
+/// single oversized active file can keep the directory above the budget.
+///
+/// `max_total_bytes == 0` disables the check.
+pub fn prune_to_total_size(log_dir: &Path, max_total_bytes: u64) -> HousekeepReport {
+    prune_to_total_size_at(log_dir, max_total_bytes, Utc::now().date_naive())
+}
+
+/// Deterministic version of `prune_to_total_size` that lets tests pin `today`.
+pub fn prune_to_total_size_at(
+    log_dir: &Path,
+    max_total_bytes: u64,
+    today: NaiveDate,
+) -> HousekeepReport {
+    let options = PurgeOptions {
+        max_total_bytes: Some(max_total_bytes),
+        ..PurgeOptions::default()
+    };
+    purge_at(log_dir, &options, today)
+}
+
+/// Compress every plain rolling file dated before yesterday to
+/// `<name>.zst` and remove the original.
+pub fn compress_old_logs(log_dir: &Path) -> HousekeepReport {
+    let options = PurgeOptions {
+        compress: true,
+        ..PurgeOptions::default()
+    };
+    purge_at(log_dir, &options, Utc::now().date_naive())
+}
+
+/// Run the retention, compression and size-budget passes selected by
+/// `options` over `log_dir`.
+pub fn purge(log_dir: &Path, options: &PurgeOptions) -> HousekeepReport {
+    purge_at(log_dir, options, Utc::now().date_naive())
+}
+
+/// Deterministic version of `purge` that lets tests pin `today`.
+pub fn purge_at(log_dir: &Path, options: &PurgeOptions, today: NaiveDate) -> HousekeepReport {
     let mut report = HousekeepReport::default();
-    if retention_days == 0 {
+    let max_total_bytes = options.max_total_bytes.filter(|bytes| *bytes > 0);
+    if options.retention_days == 0 && !options.compress && max_total_bytes.is_none() {
         return report;
     }
 
@@ -41,41 +128,91 @@ pub fn housekeep_at(log_dir: &Path, retention_days: u32, today: NaiveDate) -> Ho
         Ok(e) => e,
         Err(err) => {
             // Missing directory is not an error — nothing to clean up.
-            if err.kind() != std::io::ErrorKind::NotFound {
+            if err.kind() != io::ErrorKind::NotFound {
                 report.errors.push((log_dir.to_path_buf(), err.to_string()));
             }
             return report;
         }
     };
 
-    // "Keep the last N days (inclusive of today)" ⇒ cutoff = today - (N - 1).
-    // A file dated exactly `cutoff` is still within the retention window.
-    let cutoff = today - chrono::Duration::days((retention_days.saturating_sub(1)) as i64);
-
+    let mut files = Vec::new();
     for entry in entries.flatten() {
         let path = entry.path();
         let Some(file_name) = path.file_name().and_then(|s| s.to_str()) else {
             continue;
         };
         report.inspected += 1;
-
-        // Active file: skip.
-        if file_name == LOG_FILE_BASENAME {
+        // Rolling files look like `gwt.log.YYYY-MM-DD`, or
+        // `gwt.log.YYYY-MM-DD.N` when split by size, optionally with a
+        // `.zst` suffix. Anything else is left alone.
+        let Some((date, segment)) = parse_log_file_name(file_name) else {
             continue;
+        };
+        match entry.metadata() {
+            Ok(metadata) => files.push(LogFile {
+                path,
+                date,
+                segment,
+                size: metadata.len(),
+            }),
+            Err(err) => report.errors.push((path, err.to_string())),
         }
+    }
 
-        // Rotated files look like `gwt.log.YYYY-MM-DD`.
-        let Some(suffix) = file_name.strip_prefix(&format!("{LOG_FILE_BASENAME}.")) else {
-            continue;
-        };
-        let Ok(date) = NaiveDate::parse_from_str(suffix, "%Y-%m-%d") else {
-            continue;
-        };
+    if options.retention_days > 0 {
+        // "Keep the last N days (inclusive of today)" ⇒ cutoff = today - (N - 1).
+        // A file dated exactly `cutoff` is still within the retention window.
+        let cutoff =
+            today - chrono::Duration::days((options.retention_days.saturating_sub(1)) as i64);
+        let (expired, kept): (Vec<_>, Vec<_>) =
+            files.into_iter().partition(|file| file.date < cutoff);
+        files = kept;
+        for file in expired {
+            delete(file, options.dry_run, &mut report);
+        }
+    }
+
+    if options.compress {
+        let yesterday = today - chrono::Duration::days(1);
+        for file in &mut files {
+            if file.date >= yesterday || is_compressed_log_file(&file.path) {
+                continue;
+            }
+            let compressed = if options.dry_run {
+                Ok((compressed_path(&file.path), file.size))
+            } else {
+                compress_file(&file.path)
+            };
+            match compressed {
+                Ok((path, size)) => {
+                    report
+                        .compressed
+                        .push(std::mem::replace(&mut file.path, path));
+                    report.compressed_bytes += file.size;
+                    file.size = size;
+                }
+                Err(err) => report.errors.push((file.path.clone(), err.to_string())),
+            }
+        }
+    }
 
-        if date < cutoff {
-            match fs::remove_file(&path) {
-                Ok(()) => report.deleted.push(path),
-                Err(err) => report.errors.push((path, err.to_string())),
+    if let Some(max_total_bytes) = max_total_bytes {
+        let mut total: u64 = files.iter().map(|file| file.size).sum();
+        // Today's unsplit file is the active one and is never deleted. It
+        // sorts after the day's numbered segments anyway because it holds
+        // that day's newest lines.
+        let mut candidates: Vec<LogFile> = files
+            .into_iter()
+            .filter(|file| file.date != today || file.segment.is_some())
+            .collect();
+        candidates.sort_by_key(|file| (file.date, file.segment.unwrap_or(u32::MAX)));
+        for file in candidates {
+            if total <= max_total_bytes {
+                break;
+            }
+            let size = file.size;
+            if delete(file, options.dry_run, &mut report) {
+                total -= size;
             }
         }
     }
@@ -83,6 +220,66 @@ pub fn housekeep_at(log_dir: &Path, retention_days: u32, today: NaiveDate) -> Ho
     report
 }
 
+/// Delete `file` (or pretend to in a dry run). Returns whether it is gone.
+fn delete(file: LogFile, dry_run: bool, report: &mut HousekeepReport) -> bool {
+    if !dry_run {
+        if let Err(err) = fs::remove_file(&file.path) {
+            report.errors.push((file.path, err.to_string()));
+            return false;
+        }
+    }
+    report.deleted_bytes += file.size;
+    report.deleted.push(file.path);
+    true
+}
+
+fn compressed_path(path: &Path) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(COMPRESSED_LOG_SUFFIX);
+    PathBuf::from(name)
+}
+
+/// Replace `path` with a zstd-compressed `<path>.zst`, returning the new
+/// path and its size. The copy is written under a per-process temporary
+/// name and renamed into place, so an interrupted run never leaves a
+/// truncated `.zst` behind.
+fn compress_file(path: &Path) -> io::Result<(PathBuf, u64)> {
+    let target = compressed_path(path);
+    let mut temp = OsString::from(target.as_os_str());
+    temp.push(format!(".{}.tmp", std::process::id()));
+    let temp = PathBuf::from(temp);
+
+    let written = (|| {
+        let input = fs::File::open(path)?;
+        zstd::stream::copy_encode(
+            input,
+            create_private(&temp)?,
+            zstd::DEFAULT_COMPRESSION_LEVEL,
+        )?;
+        fs::rename(&temp, &target)
+    })();
+    if let Err(err) = written {
+        let _ = fs::remove_file(&temp);
+        return Err(err);
+    }
+    fs::remove_file(path)?;
+    let size = fs::metadata(&target)?.len();
+    Ok((target, size))
+}
+
+/// Create (or truncate) `path` with mode `0600` on Unix, like the writer's
+/// log files.
+fn create_private(path: &Path) -> io::Result<fs::File> {
+    let mut options = fs::OpenOptions::new();
+    options.create(true).write(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,6 +291,10 @@ mod tests {
         std::fs::write(path, b"").expect("write file");
     }
 
+    fn write_bytes(path: &Path, len: usize) {
+        std::fs::write(path, vec![b'x'; len]).expect("write file");
+    }
+
     #[test]
     fn missing_directory_is_not_an_error() {
         let dir = tempfile::tempdir().expect("tempdir");
@@ -143,4 +344,129 @@ mod tests {
         assert!(report.deleted.is_empty());
         assert!(dir.path().join("gwt.log.not-a-date").exists());
     }
+
+    #[test]
+    fn size_split_segments_follow_the_retention_window() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let today = NaiveDate::from_ymd_opt(2026, 4, 10).unwrap();
+        touch(&dir.path().join("gwt.log.2026-04-09.1"));
+        touch(&dir.path().join("gwt.log.2026-03-15.2"));
+
+        let report = housekeep_at(dir.path(), 7, today);
+
+        assert_eq!(report.deleted.len(), 1);
+        assert!(dir.path().join("gwt.log.2026-04-09.1").exists());
+        assert!(!dir.path().join("gwt.log.2026-03-15.2").exists());
+    }
+
+    #[test]
+    fn prune_deletes_oldest_files_until_under_budget() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let today = NaiveDate::from_ymd_opt(2026, 4, 10).unwrap();
+        write_bytes(&dir.path().join("gwt.log.2026-04-08"), 10);
+        write_bytes(&dir.path().join("gwt.log.2026-04-09.1"), 10);
+        write_bytes(&dir.path().join("gwt.log.2026-04-09"), 10);
+        write_bytes(&dir.path().join("gwt.log.2026-04-10.1"), 10);
+        write_bytes(&dir.path().join("gwt.log.2026-04-10"), 10);
+        write_bytes(&dir.path().join("unrelated.txt"), 100);
+
+        let report = prune_to_total_size_at(dir.path(), 25, today);
+
+        assert_eq!(report.deleted.len(), 3);
+        assert!(!dir.path().join("gwt.log.2026-04-08").exists());
+        assert!(!dir.path().join("gwt.log.2026-04-09.1").exists());
+        assert!(!dir.path().join("gwt.log.2026-04-09").exists());
+        assert!(dir.path().join("gwt.log.2026-04-10.1").exists());
+        assert!(dir.path().join("gwt.log.2026-04-10").exists());
+        assert!(dir.path().join("unrelated.txt").exists());
+    }
+
+    #[test]
+    fn prune_never_deletes_todays_active_file() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let today = NaiveDate::from_ymd_opt(2026, 4, 10).unwrap();
+        write_bytes(&dir.path().join("gwt.log.2026-04-10"), 50);
+
+        let report = prune_to_total_size_at(dir.path(), 10, today);
+
+        assert!(report.deleted.is_empty());
+        assert!(dir.path().join("gwt.log.2026-04-10").exists());
+    }
+
+    #[test]
+    fn prune_with_zero_budget_is_disabled() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        write_bytes(&dir.path().join("gwt.log.2020-01-01"), 50);
+        let report = prune_to_total_size(dir.path(), 0);
+        assert!(report.deleted.is_empty());
+    }
+
+    #[test]
+    fn compresses_files_from_before_yesterday() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let today = NaiveDate::from_ymd_opt(2026, 4, 10).unwrap();
+        std::fs::write(dir.path().join("gwt.log.2026-04-08.1"), "old line\n").unwrap();
+        write_bytes(&dir.path().join("gwt.log.2026-04-09"), 10);
+        write_bytes(&dir.path().join("gwt.log.2026-04-10"), 10);
+        let options = PurgeOptions {
+            compress: true,
+            ..PurgeOptions::default()
+        };
+
+        let report = purge_at(dir.path(), &options, today);
+
+        assert_eq!(
+            report.compressed,
+            vec![dir.path().join("gwt.log.2026-04-08.1")]
+        );
+        assert_eq!(report.compressed_bytes, 9);
+        assert!(!dir.path().join("gwt.log.2026-04-08.1").exists());
+        let compressed = std::fs::read(dir.path().join("gwt.log.2026-04-08.1.zst")).unwrap();
+        assert_eq!(zstd::decode_all(&compressed[..]).unwrap(), b"old line\n");
+        assert!(dir.path().join("gwt.log.2026-04-09").exists());
+        assert!(dir.path().join("gwt.log.2026-04-10").exists());
+
+        // Already compressed files are not compressed again.
+        let report = purge_at(dir.path(), &options, today);
+        assert!(report.compressed.is_empty());
+    }
+
+    #[test]
+    fn dry_run_reports_every_pass_without_touching_files() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let today = NaiveDate::from_ymd_opt(2026, 4, 10).unwrap();
+        write_bytes(&dir.path().join("gwt.log.2026-03-01"), 10);
+        write_bytes(&dir.path().join("gwt.log.2026-04-07.zst"), 10);
+        write_bytes(&dir.path().join("gwt.log.2026-04-08"), 10);
+        write_bytes(&dir.path().join("gwt.log.2026-04-10"), 10);
+        let options = PurgeOptions {
+            retention_days: 7,
+            compress: true,
+            max_total_bytes: Some(25),
+            dry_run: true,
+        };
+
+        let report = purge_at(dir.path(), &options, today);
+
+        assert_eq!(
+            report.deleted,
+            vec![
+                dir.path().join("gwt.log.2026-03-01"),
+                dir.path().join("gwt.log.2026-04-07.zst"),
+            ]
+        );
+        assert_eq!(report.deleted_bytes, 20);
+        assert_eq!(
+            report.compressed,
+            vec![dir.path().join("gwt.log.2026-04-08")]
+        );
+        for name in [
+            "gwt.log.2026-03-01",
+            "gwt.log.2026-04-07.zst",
+            "gwt.log.2026-04-08",
+            "gwt.log.2026-04-10",
+        ] {
+            assert!(dir.path().join(name).exists(), "{name}");
+        }
+    }
 }
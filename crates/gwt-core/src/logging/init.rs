@@ -14,7 +14,7 @@ use tracing_subscriber::{
 
 use super::{
     config::{LogLevel, LoggingConfig},
-    fmt_layer, housekeep,
+    fmt_layer, housekeep, otlp, redact,
     ui_forwarder::UiForwarderLayer,
     writer, LogEvent,
 };
@@ -50,6 +50,10 @@ pub struct LoggingHandles {
     /// lines. SPEC-1924 FR-038. Cheap to clone; hand a clone to the GUI
     /// runtime so the Logs window can subscribe.
     pub process_console_hub: Arc<ProcessConsoleHub>,
+    /// Flushes and shuts down the OTLP exporter on drop; `None` when span
+    /// export is off.
+    #[cfg(feature = "otlp")]
+    pub otlp_guard: Option<super::otlp::OtlpGuard>,
 }
 
 impl LoggingHandles {
@@ -97,18 +101,29 @@ pub fn apply_log_level_to_handle(handle: &ReloadHandle, level: LogLevel) -> Resu
 /// that the TUI does not have to wait for an async task.
 pub fn init(config: LoggingConfig) -> Result<LoggingHandles, String> {
     // Startup housekeeping — best effort. Errors are returned inside
-    // the report but never block initialization.
-    let report = housekeep::housekeep(&config.log_dir, config.retention_days);
-    if !report.errors.is_empty() {
-        // We cannot emit a tracing event yet (the subscriber is not
-        // installed). Swallow silently; the caller can inspect the
-        // report if they care by calling `housekeep` themselves. A
-        // future enhancement could return the report alongside the
-        // handles.
+    // the report but never block initialization. We cannot emit a
+    // tracing event yet (the subscriber is not installed), so the report
+    // is dropped; `gwt logs purge --dry-run` shows the same passes.
+    let _ = housekeep::purge(
+        &config.log_dir,
+        &housekeep::PurgeOptions {
+            retention_days: config.retention_days,
+            compress: true,
+            max_total_bytes: config.max_total_bytes,
+            dry_run: false,
+        },
+    );
+
+    if let Some(patterns) = &config.secret_key_patterns {
+        redact::set_global(redact::SecretRedactor::new(patterns));
     }
 
-    let (non_blocking, guard) =
-        writer::build(&config.log_dir).map_err(|e| format!("log writer init failed: {e}"))?;
+    let (non_blocking, guard) = writer::build(
+        &config.log_dir,
+        config.max_file_bytes,
+        config.max_total_bytes,
+    )
+    .map_err(|e| format!("log writer init failed: {e}"))?;
 
     let (ui_tx, ui_rx) = unbounded_channel::<LogEvent>();
 
@@ -154,10 +169,28 @@ pub fn init(config: LoggingConfig) -> Result<LoggingHandles, String> {
     // "terminal-like output" mental model. The canonical JSONL log
     // file still receives every tracing event via the `fmt` layer
     // above, and the Logs window reads from that file.
+    let otlp_endpoint = otlp::traces_endpoint_from_env(config.otlp_endpoint.as_deref());
+    #[cfg(feature = "otlp")]
+    let (otlp_layer, otlp_guard, otlp_error) = match otlp_endpoint.as_deref() {
+        Some(endpoint) => match otlp::build_layer(endpoint) {
+            Ok((layer, guard)) => (Some(layer), Some(guard), None),
+            Err(err) => (None, None, Some(err)),
+        },
+        None => (None, None, None),
+    };
+    #[cfg(not(feature = "otlp"))]
+    let (otlp_layer, otlp_error) = (
+        None::<tracing_subscriber::layer::Identity>,
+        otlp_endpoint
+            .as_ref()
+            .map(|_| "gwt was built without the `otlp` feature".to_string()),
+    );
+
     Registry::default()
         .with(reloadable_filter)
         .with(fmt)
         .with(ui)
+        .with(otlp_layer)
         .try_init()
         .map_err(|e| format!("subscriber init failed: {e}"))?;
 
@@ -171,6 +204,15 @@ pub fn init(config: LoggingConfig) -> Result<LoggingHandles, String> {
         );
     }
 
+    if let Some(err) = otlp_error {
+        tracing::warn!(
+            target: "gwt_core::logging",
+            endpoint = ?otlp_endpoint,
+            error = %err,
+            "OTLP span export disabled"
+        );
+    }
+
     Ok(LoggingHandles {
         guard,
         reload_handle,
@@ -178,5 +220,7 @@ pub fn init(config: LoggingConfig) -> Result<LoggingHandles, String> {
         ui_tx,
         log_dir: config.log_dir,
         process_console_hub: Arc::new(process_console_hub),
+        #[cfg(feature = "otlp")]
+        otlp_guard,
     })
 }
@@ -6,11 +6,15 @@
 //! 1. A reloadable `EnvFilter` (level control via `reload::Handle`)
 //! 2. A JSONL formatting layer writing to
 //!    `~/.gwt/projects/<repo-hash>/logs/gwt.log.YYYY-MM-DD` via a non-blocking,
-//!    daily-rolling appender (`tracing_appender`)
+//!    daily-rolling appender (`tracing_appender`), optionally split by size.
+//!    Older files are zstd-compressed by startup housekeeping.
+//!    Secrets are masked before each record is written (see [`redact`]).
 //! 3. A UI forwarder layer that sends `LogEvent`s to an
 //!    `UnboundedSender<LogEvent>` so that TUI surfaces (toasts, error
 //!    modal) can react to `Info`/`Warn`/`Error` events without
 //!    parsing the log file.
+//! 4. With the `otlp` feature and an endpoint configured, an OpenTelemetry
+//!    layer exporting spans over OTLP/HTTP (see [`otlp`]).
 //!
 //! The file is the single source of truth for the Logs tab. The UI
 //! forwarder channel is only used to drive ephemeral surfaces that
@@ -25,16 +29,26 @@ pub mod event;
 pub mod fmt_layer;
 pub mod housekeep;
 pub mod init;
+pub mod otlp;
 pub mod reader;
+pub mod redact;
+pub mod stream;
 pub mod ui_forwarder;
 pub mod writer;
 
 pub use config::{LogLevel, LoggingConfig};
 pub use event::LogEvent;
-pub use housekeep::{housekeep, HousekeepReport};
+pub use housekeep::{
+    compress_old_logs, housekeep, prune_to_total_size, purge, HousekeepReport, PurgeOptions,
+};
 pub use init::{apply_log_level_to_handle, init, LoggingHandles, ReloadHandle};
 pub use reader::{read_log_file, LogFileEntry, ReadDiagnostics, ReadOutcome};
-pub use writer::{current_log_file, log_file_for_date, LOG_FILE_BASENAME};
+pub use redact::{SecretRedactor, DEFAULT_SECRET_KEY_PATTERNS};
+pub use stream::{LogChunk, LogStreamEntry, LogStreamReader};
+pub use writer::{
+    current_log_file, is_compressed_log_file, log_file_for_date, COMPRESSED_LOG_SUFFIX,
+    LOG_FILE_BASENAME,
+};
 
 // SPEC-1924 Update 2026-05-20: re-export ProcessConsoleHub family so
 // downstream crates can access them through `gwt_core::logging::...`
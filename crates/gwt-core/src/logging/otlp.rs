@@ -0,0 +1,125 @@
+//! Optional OTLP trace export.
+//!
+//! With the `otlp` cargo feature enabled and an endpoint configured, `init`
+//! adds a `tracing-opentelemetry` layer that ships spans (worktree creation,
+//! agent launches, git invocations) and the events recorded inside them to
+//! an OTLP/HTTP collector, next to the JSONL files. Without the feature the
+//! endpoint is ignored and `init` logs a warning so the missing export is
+//! visible.
+
+/// Standard OpenTelemetry variable for a traces-specific endpoint, used as is.
+pub const OTLP_TRACES_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_TRACES_ENDPOINT";
+/// Standard OpenTelemetry base endpoint; `/v1/traces` is appended.
+pub const OTLP_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+
+/// Service name reported to the collector.
+pub const OTLP_SERVICE_NAME: &str = "gwt";
+
+/// Resolve the OTLP/HTTP traces URL.
+///
+/// Precedence (highest first):
+/// 1. `configured` (from [`super::LoggingConfig::otlp_endpoint`]), used as is
+/// 2. `OTEL_EXPORTER_OTLP_TRACES_ENDPOINT`, used as is
+/// 3. `OTEL_EXPORTER_OTLP_ENDPOINT` with `/v1/traces` appended
+pub fn traces_endpoint(
+    configured: Option<&str>,
+    traces_env: Option<&str>,
+    base_env: Option<&str>,
+) -> Option<String> {
+    let non_empty = |value: Option<&str>| {
+        value
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+    non_empty(configured)
+        .or_else(|| non_empty(traces_env))
+        .or_else(|| {
+            non_empty(base_env).map(|base| format!("{}/v1/traces", base.trim_end_matches('/')))
+        })
+}
+
+/// [`traces_endpoint`] over the process environment.
+pub fn traces_endpoint_from_env(configured: Option<&str>) -> Option<String> {
+    traces_endpoint(
+        configured,
+        std::env::var(OTLP_TRACES_ENDPOINT_ENV).ok().as_deref(),
+        std::env::var(OTLP_ENDPOINT_ENV).ok().as_deref(),
+    )
+}
+
+/// Keeps the tracer provider alive; dropping it flushes pending spans and
+/// shuts the exporter down.
+#[cfg(feature = "otlp")]
+pub struct OtlpGuard {
+    provider: opentelemetry_sdk::trace::SdkTracerProvider,
+}
+
+#[cfg(feature = "otlp")]
+impl Drop for OtlpGuard {
+    fn drop(&mut self) {
+        let _ = self.provider.shutdown();
+    }
+}
+
+/// Build the export layer for `endpoint`. Subprocess output lines stay out of
+/// the export, as they stay out of the JSONL file.
+#[cfg(feature = "otlp")]
+pub(crate) fn build_layer<S>(
+    endpoint: &str,
+) -> Result<(impl tracing_subscriber::Layer<S>, OtlpGuard), String>
+where
+    S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+{
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry_otlp::WithExportConfig as _;
+    use tracing_subscriber::{filter::FilterFn, Layer as _};
+
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .with_endpoint(endpoint)
+        .build()
+        .map_err(|err| format!("OTLP exporter init failed: {err}"))?;
+    let provider = opentelemetry_sdk::trace::SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(
+            opentelemetry_sdk::Resource::builder()
+                .with_service_name(OTLP_SERVICE_NAME)
+                .build(),
+        )
+        .build();
+    let tracer = provider.tracer(OTLP_SERVICE_NAME);
+    let layer = tracing_opentelemetry::layer()
+        .with_tracer(tracer)
+        .with_filter(FilterFn::new(|metadata| {
+            !metadata.target().starts_with("gwt.process.line")
+        }));
+    Ok((layer, OtlpGuard { provider }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn traces_endpoint_prefers_config_then_traces_env_then_base_env() {
+        assert_eq!(
+            traces_endpoint(
+                Some("http://config:4318/v1/traces"),
+                Some("http://traces"),
+                Some("http://base")
+            )
+            .as_deref(),
+            Some("http://config:4318/v1/traces")
+        );
+        assert_eq!(
+            traces_endpoint(Some("  "), Some("http://traces/v1/traces"), None).as_deref(),
+            Some("http://traces/v1/traces")
+        );
+        assert_eq!(
+            traces_endpoint(None, None, Some("http://collector:4318/")).as_deref(),
+            Some("http://collector:4318/v1/traces")
+        );
+        assert_eq!(traces_endpoint(None, Some(""), None), None);
+    }
+}
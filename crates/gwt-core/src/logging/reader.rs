@@ -22,14 +22,18 @@
 //! flush never blanks the snapshot.
 //!
 //! Logs window, future CLI subcommands, and the daemon must all go through
-//! this reader; do not re-implement `serde_json::from_str::<LogEvent>` on
-//! disk lines (SPEC-1924 FR-037 / SC-011).
+//! this reader (or its incremental counterpart, [`super::stream`]); do not
+//! re-implement `serde_json::from_str::<LogEvent>` on disk lines
+//! (SPEC-1924 FR-037 / SC-011).
+//!
+//! Files compressed by housekeeping (`gwt.log.YYYY-MM-DD[.N].zst`) are
+//! decoded on the fly, so callers can pass either form.
 //!
 //! See [`mod@tracing_subscriber::fmt`] for the writer side; the relevant
 //! configuration lives in `crates/gwt-core/src/logging/fmt_layer.rs`.
 
 use std::{
-    io::{self, BufRead, BufReader},
+    io::{self, BufRead, BufReader, Read},
     path::{Path, PathBuf},
 };
 
@@ -108,6 +112,8 @@ impl LogFileEntry {
 /// - Lines that fail to decode are skipped and counted in
 ///   `diagnostics.skipped`; the rest are returned.
 /// - `id` is assigned in read order starting from `1` and is in-memory only.
+/// - Secret values are masked with the global [`super::redact`] redactor.
+/// - `.zst` files are zstd-decoded; a corrupt compressed stream is an `Err`.
 pub fn read_log_file(path: &Path) -> io::Result<ReadOutcome> {
     let file = match std::fs::File::open(path) {
         Ok(file) => file,
@@ -123,7 +129,11 @@ pub fn read_log_file(path: &Path) -> io::Result<ReadOutcome> {
         Err(error) => return Err(error),
     };
 
-    let reader = BufReader::new(file);
+    let reader: BufReader<Box<dyn Read>> = if super::writer::is_compressed_log_file(path) {
+        BufReader::new(Box::new(zstd::stream::read::Decoder::new(file)?))
+    } else {
+        BufReader::new(Box::new(file))
+    };
     let mut entries = Vec::new();
     let mut skipped = 0_usize;
     let mut next_id: u64 = 1;
@@ -134,12 +144,12 @@ pub fn read_log_file(path: &Path) -> io::Result<ReadOutcome> {
         if trimmed.is_empty() {
             continue;
         }
-        match serde_json::from_str::<LogFileEntry>(trimmed) {
-            Ok(entry) => {
-                entries.push(entry.into_log_event(next_id));
+        match decode_line(trimmed, next_id) {
+            Some(event) => {
+                entries.push(event);
                 next_id += 1;
             }
-            Err(_) => {
+            None => {
                 skipped += 1;
             }
         }
@@ -154,6 +164,18 @@ pub fn read_log_file(path: &Path) -> io::Result<ReadOutcome> {
     })
 }
 
+/// Decode one non-empty JSONL line into a redacted [`LogEvent`] with the
+/// given `id`, or `None` when the line is malformed. Shared with the
+/// incremental reader in [`super::stream`].
+pub(super) fn decode_line(line: &str, id: u64) -> Option<LogEvent> {
+    let entry = serde_json::from_str::<LogFileEntry>(line).ok()?;
+    let mut event = entry.into_log_event(id);
+    // Files written before redaction existed (or with other patterns) are
+    // masked on the way out as well.
+    super::redact::global().redact_event(&mut event);
+    Some(event)
+}
+
 fn parse_level(level: &str) -> LogLevel {
     match level.to_ascii_uppercase().as_str() {
         "ERROR" => LogLevel::Error,
@@ -338,4 +360,19 @@ mod tests {
             Some(&serde_json::Value::String("v".to_string()))
         );
     }
+
+    #[test]
+    fn reads_zstd_compressed_files() {
+        let (dir, plain) = write_lines(&[PROD_LINE_INFO, MALFORMED_LINE, PROD_LINE_ERROR]);
+        let path = dir.path().join("gwt.log.2026-05-20.zst");
+        let compressed = zstd::encode_all(&std::fs::read(&plain).unwrap()[..], 0).unwrap();
+        std::fs::write(&path, compressed).expect("write compressed");
+
+        let outcome = read_log_file(&path).expect("read ok");
+
+        assert_eq!(outcome.entries.len(), 2);
+        assert_eq!(outcome.diagnostics.skipped, 1);
+        assert_eq!(outcome.entries[1].message, "git failed");
+        assert_eq!(outcome.diagnostics.path, path);
+    }
 }
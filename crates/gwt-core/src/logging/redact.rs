@@ -0,0 +1,367 @@
+//! Key-based secret redaction for the structured log pipeline.
+//!
+//! [`crate::process_console::redact_line`] catches token *shapes*
+//! (`Authorization:` headers, `ghp_…`). This module catches secrets by the
+//! *name* they travel under: a field, env var or `KEY=value` assignment whose
+//! key matches one of the configured patterns has its value replaced with
+//! [`REDACTED`]. It is applied to
+//!
+//! - every JSONL line before it reaches the log file ([`RedactingMakeWriter`]),
+//! - `LogEvent`s forwarded to the UI and read back from disk, so the Logs
+//!   window never shows values written before redaction existed,
+//! - Console banners and agent launch stage lines (via `redact_line`).
+//!
+//! A pattern matches a key when its segments appear as a contiguous run of
+//! the key's segments. Keys split on non-alphanumerics and camelCase
+//! boundaries, so `KEY` matches `OPENAI_API_KEY`, `apiKey` and `--api-key`
+//! but not `hotkey` or `monkey`, and `TOKEN` does not match `tokens_used`.
+
+use std::{
+    io::{self, Write},
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use regex::Regex;
+use serde_json::Value;
+use tracing_subscriber::fmt::MakeWriter;
+
+use super::LogEvent;
+pub use crate::process_console::REDACTED;
+
+/// Key patterns masked when `[log_redaction]` does not override them.
+pub const DEFAULT_SECRET_KEY_PATTERNS: &[&str] = &["TOKEN", "KEY", "SECRET", "PASSWORD"];
+
+/// Masks values whose key matches a configured pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretRedactor {
+    patterns: Vec<Vec<String>>,
+}
+
+impl Default for SecretRedactor {
+    fn default() -> Self {
+        Self::new(DEFAULT_SECRET_KEY_PATTERNS)
+    }
+}
+
+impl SecretRedactor {
+    /// Build a redactor from key patterns. Matching is case-insensitive; an
+    /// empty list disables key-based masking.
+    pub fn new<I, S>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            patterns: patterns
+                .into_iter()
+                .map(|pattern| key_segments(pattern.as_ref()))
+                .filter(|segments| !segments.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Whether values stored under `key` are masked.
+    pub fn is_secret_key(&self, key: &str) -> bool {
+        let segments = key_segments(key);
+        self.patterns.iter().any(|pattern| {
+            segments
+                .windows(pattern.len())
+                .any(|window| window == pattern.as_slice())
+        })
+    }
+
+    /// Mask the value of every `KEY=value` / `KEY: value` assignment in free
+    /// text whose key is secret.
+    pub fn redact_text(&self, text: &str) -> String {
+        if self.patterns.is_empty() {
+            return text.to_string();
+        }
+        assignment_re()
+            .replace_all(text, |caps: &regex::Captures<'_>| {
+                if self.is_secret_key(&caps["key"]) {
+                    format!("{}{}{REDACTED}", &caps["key"], &caps["sep"])
+                } else {
+                    caps[0].to_string()
+                }
+            })
+            .into_owned()
+    }
+
+    /// Redact a JSON value in place: scalars under a secret key are masked,
+    /// every other string goes through [`Self::redact_text`] and the token
+    /// shape patterns. Returns whether anything changed.
+    pub fn redact_value(&self, value: &mut Value) -> bool {
+        match value {
+            Value::String(text) => redact_string(self, text),
+            Value::Array(items) => items
+                .iter_mut()
+                .fold(false, |changed, item| self.redact_value(item) | changed),
+            Value::Object(map) => self.redact_fields(map),
+            _ => false,
+        }
+    }
+
+    /// [`Self::redact_value`] over every entry of a field map.
+    pub fn redact_fields(&self, fields: &mut serde_json::Map<String, Value>) -> bool {
+        let mut changed = false;
+        for (key, value) in fields.iter_mut() {
+            let masks_scalar = !matches!(value, Value::Null | Value::Array(_) | Value::Object(_));
+            if masks_scalar && self.is_secret_key(key) {
+                if value.as_str() != Some(REDACTED) {
+                    *value = Value::String(REDACTED.to_string());
+                    changed = true;
+                }
+            } else {
+                changed |= self.redact_value(value);
+            }
+        }
+        changed
+    }
+
+    /// Redact the message, detail and fields of a log event.
+    pub fn redact_event(&self, event: &mut LogEvent) {
+        redact_string(self, &mut event.message);
+        if let Some(detail) = event.detail.as_mut() {
+            redact_string(self, detail);
+        }
+        self.redact_fields(&mut event.fields);
+    }
+
+    /// Redact one JSONL record. Lines that are not JSON objects fall back to
+    /// text redaction; unchanged lines are returned as-is so the writer does
+    /// not reorder keys needlessly.
+    pub fn redact_json_line(&self, line: &str) -> String {
+        match serde_json::from_str::<Value>(line) {
+            Ok(mut value @ Value::Object(_)) => {
+                if self.redact_value(&mut value) {
+                    value.to_string()
+                } else {
+                    line.to_string()
+                }
+            }
+            _ => crate::process_console::redact_line(line),
+        }
+    }
+}
+
+fn redact_string(redactor: &SecretRedactor, text: &mut String) -> bool {
+    let redacted = crate::process_console::redact::redact_tokens(&redactor.redact_text(text));
+    if redacted == *text {
+        return false;
+    }
+    *text = redacted;
+    true
+}
+
+/// Upper-cased segments of `key`, split on non-alphanumerics and on
+/// lower-to-upper camelCase boundaries.
+fn key_segments(key: &str) -> Vec<String> {
+    let mut segments = Vec::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+    for ch in key.chars() {
+        if !ch.is_alphanumeric() {
+            if !current.is_empty() {
+                segments.push(std::mem::take(&mut current));
+            }
+            prev_lower = false;
+            continue;
+        }
+        if ch.is_uppercase() && prev_lower && !current.is_empty() {
+            segments.push(std::mem::take(&mut current));
+        }
+        prev_lower = ch.is_lowercase() || ch.is_numeric();
+        current.extend(ch.to_uppercase());
+    }
+    if !current.is_empty() {
+        segments.push(current);
+    }
+    segments
+}
+
+fn assignment_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r#"(?P<key>[A-Za-z_][A-Za-z0-9_.\-]*)(?P<sep>\s*[=:]\s*)(?:"[^"]*"|'[^']*'|[^\s,;&"']+)"#,
+        )
+        .expect("assignment regex")
+    })
+}
+
+fn global_slot() -> &'static RwLock<Arc<SecretRedactor>> {
+    static SLOT: OnceLock<RwLock<Arc<SecretRedactor>>> = OnceLock::new();
+    SLOT.get_or_init(|| RwLock::new(Arc::new(SecretRedactor::default())))
+}
+
+/// Install the process-wide redactor. `logging::init` calls this with the
+/// configured patterns; until then the default patterns apply.
+pub fn set_global(redactor: SecretRedactor) {
+    let mut slot = global_slot()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *slot = Arc::new(redactor);
+}
+
+/// The process-wide redactor.
+pub fn global() -> Arc<SecretRedactor> {
+    global_slot()
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone()
+}
+
+/// `MakeWriter` that redacts each JSONL record before handing it to `inner`.
+#[derive(Debug, Clone)]
+pub struct RedactingMakeWriter<M> {
+    inner: M,
+}
+
+impl<M> RedactingMakeWriter<M> {
+    pub fn new(inner: M) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, M> MakeWriter<'a> for RedactingMakeWriter<M>
+where
+    M: MakeWriter<'a>,
+{
+    type Writer = RedactingWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        RedactingWriter {
+            inner: self.inner.make_writer(),
+        }
+    }
+}
+
+/// Per-event writer produced by [`RedactingMakeWriter`]. The fmt layer
+/// writes one complete record per `write_all`, so each buffer is redacted
+/// line by line as a whole.
+pub struct RedactingWriter<W> {
+    inner: W,
+}
+
+impl<W: Write> Write for RedactingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let Ok(text) = std::str::from_utf8(buf) else {
+            self.inner.write_all(buf)?;
+            return Ok(buf.len());
+        };
+        let redactor = global();
+        let mut out = String::with_capacity(text.len());
+        for line in text.split_inclusive('\n') {
+            let (body, newline) = match line.strip_suffix('\n') {
+                Some(body) => (body, "\n"),
+                None => (line, ""),
+            };
+            if body.trim().is_empty() {
+                out.push_str(line);
+            } else {
+                out.push_str(&redactor.redact_json_line(body));
+                out.push_str(newline);
+            }
+        }
+        self.inner.write_all(out.as_bytes())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_patterns_match_key_segments() {
+        let redactor = SecretRedactor::default();
+        for key in [
+            "OPENAI_API_KEY",
+            "GH_TOKEN",
+            "apiKey",
+            "--api-key",
+            "db.password",
+            "CLIENT_SECRET",
+        ] {
+            assert!(redactor.is_secret_key(key), "{key} should be secret");
+        }
+        for key in [
+            "hotkey",
+            "tokens_used",
+            "ANTHROPIC_BASE_URL",
+            "monkey",
+            "path",
+        ] {
+            assert!(!redactor.is_secret_key(key), "{key} should not be secret");
+        }
+    }
+
+    #[test]
+    fn custom_patterns_replace_defaults() {
+        let redactor = SecretRedactor::new(["session_id"]);
+        assert!(redactor.is_secret_key("GWT_SESSION_ID"));
+        assert!(!redactor.is_secret_key("GH_TOKEN"));
+        assert!(!SecretRedactor::new(Vec::<String>::new()).is_secret_key("GH_TOKEN"));
+    }
+
+    #[test]
+    fn redact_text_masks_secret_assignments_only() {
+        let redactor = SecretRedactor::default();
+        let out = redactor.redact_text(
+            "docker exec -e OPENAI_API_KEY=sk-live-123 -e TERM=xterm app --api-key \"abc def\"",
+        );
+        assert_eq!(
+            out,
+            format!(
+                "docker exec -e OPENAI_API_KEY={REDACTED} -e TERM=xterm app --api-key \"abc def\""
+            )
+        );
+        assert_eq!(
+            redactor.redact_text("password: hunter2, user: dev"),
+            format!("password: {REDACTED}, user: dev")
+        );
+    }
+
+    #[test]
+    fn redact_json_line_masks_fields_and_text() {
+        let redactor = SecretRedactor::default();
+        let line = r#"{"level":"INFO","fields":{"message":"launch","token":"abc","label":"codex GH_TOKEN=xyz","count":3}}"#;
+        let out: Value = serde_json::from_str(&redactor.redact_json_line(line)).unwrap();
+        assert_eq!(out["fields"]["token"], REDACTED);
+        assert_eq!(out["fields"]["label"], format!("codex GH_TOKEN={REDACTED}"));
+        assert_eq!(out["fields"]["count"], 3);
+
+        let clean = r#"{"level":"INFO","fields":{"message":"ok"}}"#;
+        assert_eq!(redactor.redact_json_line(clean), clean);
+    }
+
+    #[test]
+    fn writer_redacts_each_record() {
+        let buffer = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        struct Shared(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+        impl Write for Shared {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        let mut writer = RedactingWriter {
+            inner: Shared(buffer.clone()),
+        };
+        writer
+            .write_all(b"{\"fields\":{\"api_key\":\"sk-1\"}}\n")
+            .unwrap();
+        let written = String::from_utf8(buffer.lock().unwrap().clone()).unwrap();
+        assert_eq!(
+            written,
+            format!("{{\"fields\":{{\"api_key\":\"{REDACTED}\"}}}}\n")
+        );
+    }
+}
@@ -0,0 +1,334 @@
+//! Incremental log reader with byte offsets.
+//!
+//! [`read_log_file`](super::read_log_file) decodes a whole file on every
+//! call, which is wasteful for a multi-megabyte daily file that only grows
+//! at the end. [`LogStreamReader`] remembers the byte offset it stopped at
+//! and each [`LogStreamReader::read_available`] call decodes only the lines
+//! appended since. Every returned entry carries the offset just past its
+//! line, so a consumer can resume from any entry (the `/api/logs/stream`
+//! SSE endpoint uses it as the event id).
+//!
+//! Only complete lines are consumed from a plain file: a trailing line
+//! without its newline may still be mid-flush and is picked up by the next
+//! call. Compressed files are closed, so they are read to the end.
+//!
+//! In follow mode ([`LogStreamReader::follow`]) the reader tracks
+//! [`current_log_file`](super::current_log_file): after the UTC date rolls
+//! over it drains what is left of the old file and then starts over at the
+//! beginning of the new one. Starting over (rollover, or a file that
+//! shrank below the offset) is reported by [`LogChunk::reset`]; ids then
+//! restart at `1` as in [`read_log_file`](super::read_log_file).
+
+use std::{
+    fs::File,
+    io::{self, Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+use super::LogEvent;
+
+/// One decoded line of a [`LogChunk`].
+#[derive(Debug, Clone)]
+pub struct LogStreamEntry {
+    /// Byte offset just past this line; reading on from it resumes after
+    /// this entry.
+    pub offset: u64,
+    pub event: LogEvent,
+}
+
+/// Result of one [`LogStreamReader::read_available`] call.
+#[derive(Debug, Clone)]
+pub struct LogChunk {
+    /// File the chunk was read from.
+    pub path: PathBuf,
+    /// The reader started over at the beginning of `path` before reading
+    /// this chunk; entries read earlier are superseded.
+    pub reset: bool,
+    /// Decoded lines in source order.
+    pub entries: Vec<LogStreamEntry>,
+    /// Non-empty lines in this chunk that failed to decode.
+    pub skipped: usize,
+    /// Offset the next read continues from.
+    pub offset: u64,
+}
+
+#[derive(Debug, Clone)]
+enum Source {
+    File,
+    Follow(PathBuf),
+}
+
+/// Offset-tracking reader over a canonical structured log file.
+#[derive(Debug, Clone)]
+pub struct LogStreamReader {
+    source: Source,
+    path: PathBuf,
+    offset: u64,
+    next_id: u64,
+    skipped: usize,
+}
+
+impl LogStreamReader {
+    /// Read `path` from byte `offset` on. An offset inside a line starts
+    /// decoding at the next line.
+    pub fn open(path: impl Into<PathBuf>, offset: u64) -> Self {
+        Self {
+            source: Source::File,
+            path: path.into(),
+            offset,
+            next_id: 1,
+            skipped: 0,
+        }
+    }
+
+    /// Read today's file in `log_dir` from byte `offset` on and move to the
+    /// next day's file when the date rolls over.
+    pub fn follow(log_dir: impl Into<PathBuf>, offset: u64) -> Self {
+        let log_dir = log_dir.into();
+        let path = super::current_log_file(&log_dir);
+        Self {
+            source: Source::Follow(log_dir),
+            path,
+            offset,
+            next_id: 1,
+            skipped: 0,
+        }
+    }
+
+    /// File currently read.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Offset the next read continues from.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Malformed lines skipped since the reader last started over.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// Decode the lines appended since the previous call. A missing file
+    /// yields an empty chunk (nothing has been logged yet today).
+    pub fn read_available(&mut self) -> io::Result<LogChunk> {
+        let chunk = self.read_current(false)?;
+        if let Source::Follow(log_dir) = &self.source {
+            let current = super::current_log_file(log_dir);
+            // The writer has moved on, so once the old file is drained
+            // nothing more will be appended to it.
+            if chunk.entries.is_empty() && chunk.skipped == 0 && current != self.path {
+                self.path = current;
+                return self.read_current(true);
+            }
+        }
+        Ok(chunk)
+    }
+
+    fn read_current(&mut self, mut reset: bool) -> io::Result<LogChunk> {
+        if reset {
+            self.start_over();
+        }
+        let (bytes, complete) = match self.read_tail() {
+            Ok(Some(tail)) => tail,
+            Ok(None) => {
+                // The file shrank below the offset: it was replaced.
+                reset = true;
+                self.start_over();
+                self.read_tail()?.unwrap_or_default()
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => (Vec::new(), false),
+            Err(error) => return Err(error),
+        };
+
+        let consumed = if complete {
+            bytes.len()
+        } else {
+            bytes
+                .iter()
+                .rposition(|byte| *byte == b'\n')
+                .map_or(0, |index| index + 1)
+        };
+        let mut entries = Vec::new();
+        let mut skipped = 0_usize;
+        let mut line_start = 0_usize;
+        // A resumed read that lands inside a line skips to the next one.
+        if self.offset > 0 && !self.at_line_start()? {
+            line_start = bytes[..consumed]
+                .iter()
+                .position(|byte| *byte == b'\n')
+                .map_or(consumed, |index| index + 1);
+        }
+        for line in bytes[line_start..consumed].split_inclusive(|byte| *byte == b'\n') {
+            let end = line_start + line.len();
+            line_start = end;
+            let text = String::from_utf8_lossy(line);
+            let trimmed = text.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            match super::reader::decode_line(trimmed, self.next_id) {
+                Some(event) => {
+                    entries.push(LogStreamEntry {
+                        offset: self.offset + end as u64,
+                        event,
+                    });
+                    self.next_id += 1;
+                }
+                None => skipped += 1,
+            }
+        }
+        self.offset += consumed as u64;
+        self.skipped += skipped;
+        Ok(LogChunk {
+            path: self.path.clone(),
+            reset,
+            entries,
+            skipped,
+            offset: self.offset,
+        })
+    }
+
+    fn start_over(&mut self) {
+        self.offset = 0;
+        self.next_id = 1;
+        self.skipped = 0;
+    }
+
+    /// Bytes from the offset to the end of the file, and whether they end
+    /// at a point that cannot grow (compressed files). `None` when the file
+    /// is shorter than the offset.
+    fn read_tail(&self) -> io::Result<Option<(Vec<u8>, bool)>> {
+        let mut file = File::open(&self.path)?;
+        let mut bytes = Vec::new();
+        if super::writer::is_compressed_log_file(&self.path) {
+            let mut decoder = zstd::stream::read::Decoder::new(file)?;
+            let skipped = io::copy(&mut (&mut decoder).take(self.offset), &mut io::sink())?;
+            if skipped < self.offset {
+                return Ok(None);
+            }
+            decoder.read_to_end(&mut bytes)?;
+            return Ok(Some((bytes, true)));
+        }
+        if file.metadata()?.len() < self.offset {
+            return Ok(None);
+        }
+        file.seek(SeekFrom::Start(self.offset))?;
+        file.read_to_end(&mut bytes)?;
+        Ok(Some((bytes, false)))
+    }
+
+    /// Whether the byte before the offset is a newline, i.e. the offset is
+    /// where a line starts.
+    fn at_line_start(&self) -> io::Result<bool> {
+        if self.offset == 0 || super::writer::is_compressed_log_file(&self.path) {
+            return Ok(true);
+        }
+        let mut file = File::open(&self.path)?;
+        file.seek(SeekFrom::Start(self.offset - 1))?;
+        let mut byte = [0_u8; 1];
+        file.read_exact(&mut byte)?;
+        Ok(byte[0] == b'\n')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use tempfile::tempdir;
+
+    use super::*;
+
+    const LINE_A: &str = r#"{"timestamp":"2026-05-20T09:00:00Z","level":"INFO","fields":{"message":"a"},"target":"gwt::a"}"#;
+    const LINE_B: &str = r#"{"timestamp":"2026-05-20T09:00:01Z","level":"WARN","fields":{"message":"b"},"target":"gwt::b"}"#;
+
+    fn append(path: &Path, text: &str) {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .unwrap();
+        file.write_all(text.as_bytes()).unwrap();
+    }
+
+    fn messages(chunk: &LogChunk) -> Vec<&str> {
+        chunk
+            .entries
+            .iter()
+            .map(|entry| entry.event.message.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn reads_only_appended_complete_lines() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("gwt.log.2026-05-20");
+        let mut reader = LogStreamReader::open(&path, 0);
+        assert!(reader.read_available().unwrap().entries.is_empty());
+
+        append(
+            &path,
+            &format!("{LINE_A}\n{{\"foo\":1}}\n{}", &LINE_B[..20]),
+        );
+        let chunk = reader.read_available().unwrap();
+        assert_eq!(messages(&chunk), ["a"]);
+        assert_eq!(chunk.skipped, 1);
+        assert_eq!(chunk.entries[0].offset, LINE_A.len() as u64 + 1);
+        assert!(!chunk.reset);
+
+        // The half-written line is completed by the next flush.
+        append(&path, &format!("{}\n", &LINE_B[20..]));
+        let chunk = reader.read_available().unwrap();
+        assert_eq!(messages(&chunk), ["b"]);
+        assert_eq!(chunk.entries[0].event.id, 2);
+        assert_eq!(chunk.offset, std::fs::metadata(&path).unwrap().len());
+        assert_eq!(reader.skipped(), 1);
+
+        // Resuming from an entry's offset continues after it.
+        let mut resumed = LogStreamReader::open(&path, LINE_A.len() as u64 + 1);
+        assert_eq!(reader.read_available().unwrap().entries.len(), 0);
+        assert_eq!(resumed.read_available().unwrap().skipped, 1);
+        // An offset inside a line starts at the next one.
+        let mut mid = LogStreamReader::open(&path, 5);
+        assert_eq!(mid.read_available().unwrap().skipped, 1);
+    }
+
+    #[test]
+    fn starts_over_when_the_file_is_replaced() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("gwt.log.2026-05-20");
+        append(&path, &format!("{LINE_A}\n{LINE_B}\n"));
+        let mut reader = LogStreamReader::open(&path, 0);
+        assert_eq!(reader.read_available().unwrap().entries.len(), 2);
+
+        std::fs::write(&path, format!("{LINE_B}\n")).unwrap();
+        let chunk = reader.read_available().unwrap();
+        assert!(chunk.reset);
+        assert_eq!(messages(&chunk), ["b"]);
+        assert_eq!(chunk.entries[0].event.id, 1);
+    }
+
+    #[test]
+    fn follow_reads_todays_file_and_compressed_files_to_the_end() {
+        let dir = tempdir().unwrap();
+        let today = super::super::current_log_file(dir.path());
+        append(&today, &format!("{LINE_A}\n"));
+        let mut reader = LogStreamReader::follow(dir.path(), 0);
+        assert_eq!(reader.path(), today);
+        assert_eq!(messages(&reader.read_available().unwrap()), ["a"]);
+
+        let compressed = dir.path().join("gwt.log.2026-05-19.zst");
+        let payload = format!("{LINE_A}\n{LINE_B}");
+        std::fs::write(
+            &compressed,
+            zstd::encode_all(payload.as_bytes(), 0).unwrap(),
+        )
+        .unwrap();
+        let mut reader = LogStreamReader::open(&compressed, LINE_A.len() as u64 + 1);
+        let chunk = reader.read_available().unwrap();
+        assert_eq!(messages(&chunk), ["b"]);
+        assert_eq!(chunk.offset, payload.len() as u64);
+    }
+}
@@ -65,6 +65,7 @@ where
             log_event = log_event.with_detail(detail);
         }
         log_event.fields = fields;
+        super::redact::global().redact_event(&mut log_event);
 
         // Ignore send errors: during shutdown the receiver may have been
         // dropped and we do not want `tracing::error!` in a `Drop` to
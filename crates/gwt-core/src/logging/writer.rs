@@ -6,18 +6,37 @@
 //! day. The Logs tab and housekeeping code must therefore reference the
 //! dated filename directly. Use `current_log_file()` to compute it.
 
-use std::path::{Path, PathBuf};
+//!
+//! **Size rotation:** with a maximum file size configured, a day's file
+//! that would grow past the limit is renamed to `gwt.log.YYYY-MM-DD.N`
+//! (`N` counting up from 1) and a fresh `gwt.log.YYYY-MM-DD` is started,
+//! so the active filename never changes within a day.
+//!
+//! **Compression:** startup housekeeping compresses files from before
+//! yesterday to `gwt.log.YYYY-MM-DD[.N].zst` (see
+//! [`housekeep::compress_old_logs`]); the reader decodes them transparently.
+
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
 
-use chrono::Utc;
+use chrono::{NaiveDate, Utc};
 use tracing_appender::{
     non_blocking::{NonBlocking, WorkerGuard},
     rolling,
 };
 
+use super::housekeep;
+
 /// Basename prefix used for the rolling log files. The full name is
 /// `gwt.log.YYYY-MM-DD` (no bare `gwt.log` file exists at any point).
 pub const LOG_FILE_BASENAME: &str = "gwt.log";
 
+/// Suffix appended to rolling log files once they are zstd-compressed.
+pub const COMPRESSED_LOG_SUFFIX: &str = ".zst";
+
 /// Return the path of today's active log file
 /// (`{log_dir}/gwt.log.YYYY-MM-DD`, UTC date).
 ///
@@ -38,8 +57,34 @@ pub fn log_file_for_date(log_dir: &Path, date: chrono::NaiveDate) -> PathBuf {
     log_dir.join(format!("{LOG_FILE_BASENAME}.{date}"))
 }
 
+/// Split a rolling log file name into its UTC date and, for files split off
+/// by size rotation (`gwt.log.YYYY-MM-DD.N`), the segment number. Compressed
+/// files (`….zst`) parse like their plain originals. Returns `None` for names
+/// that are not rolling log files.
+pub fn parse_log_file_name(name: &str) -> Option<(NaiveDate, Option<u32>)> {
+    let name = name.strip_suffix(COMPRESSED_LOG_SUFFIX).unwrap_or(name);
+    let suffix = name.strip_prefix(LOG_FILE_BASENAME)?.strip_prefix('.')?;
+    let (date, segment) = match suffix.split_once('.') {
+        Some((date, segment)) => (date, Some(segment.parse().ok()?)),
+        None => (suffix, None),
+    };
+    let date = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+    Some((date, segment))
+}
+
+/// Whether `path` names a zstd-compressed rolling log file.
+pub fn is_compressed_log_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.ends_with(COMPRESSED_LOG_SUFFIX))
+}
+
 /// Create a daily-rolling, non-blocking writer targeting `log_dir/gwt.log`.
 ///
+/// With `max_file_bytes` set the day's file is additionally split by size
+/// (see the module docs), and after each split the directory is pruned to
+/// `max_total_bytes` via [`housekeep::prune_to_total_size`].
+///
 /// The returned `WorkerGuard` must be kept alive (for example in a
 /// `LoggingHandles` held by `main`) until the process exits, otherwise
 /// the background writer thread shuts down and events are dropped.
@@ -52,10 +97,19 @@ pub fn log_file_for_date(log_dir: &Path, date: chrono::NaiveDate) -> PathBuf {
 /// internal paths. Failures to set permissions are non-fatal — they
 /// are logged via `tracing::warn!` (once the subscriber is up) and the
 /// writer still starts.
-pub fn build(log_dir: &Path) -> std::io::Result<(NonBlocking, WorkerGuard)> {
+pub fn build(
+    log_dir: &Path,
+    max_file_bytes: Option<u64>,
+    max_total_bytes: Option<u64>,
+) -> std::io::Result<(NonBlocking, WorkerGuard)> {
     std::fs::create_dir_all(log_dir)?;
     tighten_log_dir_permissions(log_dir);
 
+    if let Some(max_file_bytes) = max_file_bytes {
+        let writer = SizeRollingWriter::open(log_dir, max_file_bytes, max_total_bytes)?;
+        return Ok(tracing_appender::non_blocking(writer));
+    }
+
     // `tracing_appender 0.2.4` rotates `rolling::daily` files on UTC
     // boundaries and names files with the UTC date. Keep the helper
     // contract aligned with that behavior so tests, housekeeping, and
@@ -65,6 +119,118 @@ pub fn build(log_dir: &Path) -> std::io::Result<(NonBlocking, WorkerGuard)> {
     Ok((non_blocking, guard))
 }
 
+/// Daily writer that also splits the day's file once it reaches
+/// `max_file_bytes`. A single write larger than the limit still goes into
+/// one file; the split happens before the next write.
+struct SizeRollingWriter {
+    log_dir: PathBuf,
+    max_file_bytes: u64,
+    max_total_bytes: Option<u64>,
+    date: NaiveDate,
+    /// `None` only between closing the old file and opening the new one,
+    /// or after a failed reopen (retried on the next write).
+    file: Option<File>,
+    written: u64,
+}
+
+impl SizeRollingWriter {
+    fn open(log_dir: &Path, max_file_bytes: u64, max_total_bytes: Option<u64>) -> io::Result<Self> {
+        let date = Utc::now().date_naive();
+        let (file, written) = open_append(&log_file_for_date(log_dir, date))?;
+        Ok(Self {
+            log_dir: log_dir.to_path_buf(),
+            max_file_bytes,
+            max_total_bytes,
+            date,
+            file: Some(file),
+            written,
+        })
+    }
+
+    /// Start a new active file for `today`, moving the current one aside
+    /// when it is still today's.
+    fn roll(&mut self, today: NaiveDate) -> io::Result<()> {
+        // Close before renaming: Windows refuses to rename open files.
+        self.file = None;
+        if today == self.date {
+            let active = log_file_for_date(&self.log_dir, self.date);
+            fs::rename(&active, next_segment_path(&self.log_dir, self.date))?;
+        }
+        self.date = today;
+        self.reopen()?;
+        if let Some(max_total_bytes) = self.max_total_bytes {
+            housekeep::prune_to_total_size_at(&self.log_dir, max_total_bytes, today);
+        }
+        Ok(())
+    }
+
+    fn reopen(&mut self) -> io::Result<()> {
+        let (file, written) = open_append(&log_file_for_date(&self.log_dir, self.date))?;
+        self.file = Some(file);
+        self.written = written;
+        Ok(())
+    }
+}
+
+impl Write for SizeRollingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let today = Utc::now().date_naive();
+        if today != self.date
+            || (self.written > 0 && self.written + buf.len() as u64 > self.max_file_bytes)
+        {
+            self.roll(today)?;
+        }
+        if self.file.is_none() {
+            self.reopen()?;
+        }
+        let file = self.file.as_mut().expect("log file reopened above");
+        let written = file.write(buf)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self.file.as_mut() {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// First unused `gwt.log.YYYY-MM-DD.N` path for `date`.
+fn next_segment_path(log_dir: &Path, date: NaiveDate) -> PathBuf {
+    let highest = fs::read_dir(log_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name();
+            match parse_log_file_name(name.to_str()?)? {
+                (file_date, Some(segment)) if file_date == date => Some(segment),
+                _ => None,
+            }
+        })
+        .max()
+        .unwrap_or(0);
+    log_dir.join(format!("{LOG_FILE_BASENAME}.{date}.{}", highest + 1))
+}
+
+/// Open `path` for appending, returning the file and its current length.
+/// New files get mode `0600` on Unix, matching
+/// `tighten_log_dir_permissions`.
+fn open_append(path: &Path) -> io::Result<(File, u64)> {
+    let mut options = fs::OpenOptions::new();
+    options.create(true).append(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let file = options.open(path)?;
+    let len = file.metadata()?.len();
+    Ok((file, len))
+}
+
 #[cfg(unix)]
 fn tighten_log_dir_permissions(log_dir: &Path) {
     use std::os::unix::fs::PermissionsExt;
@@ -111,7 +277,59 @@ mod tests {
         let dir = tempfile::tempdir().expect("tempdir");
         let nested = dir.path().join("a").join("b");
         assert!(!nested.exists());
-        let (_writer, _guard) = build(&nested).expect("build writer");
+        let (_writer, _guard) = build(&nested, None, None).expect("build writer");
         assert!(nested.is_dir());
     }
+
+    #[test]
+    fn parse_log_file_name_reads_date_and_segment() {
+        let date = NaiveDate::from_ymd_opt(2026, 4, 10).unwrap();
+        assert_eq!(
+            parse_log_file_name("gwt.log.2026-04-10"),
+            Some((date, None))
+        );
+        assert_eq!(
+            parse_log_file_name("gwt.log.2026-04-10.3"),
+            Some((date, Some(3)))
+        );
+        assert_eq!(
+            parse_log_file_name("gwt.log.2026-04-10.3.zst"),
+            Some((date, Some(3)))
+        );
+        assert_eq!(parse_log_file_name("gwt.log"), None);
+        assert_eq!(parse_log_file_name("gwt.log.2026-04-10.x"), None);
+        assert_eq!(parse_log_file_name("gwt.logger.2026-04-10"), None);
+    }
+
+    #[test]
+    fn size_rolling_writer_splits_the_active_file_at_the_limit() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut writer = SizeRollingWriter::open(dir.path(), 10, None).expect("open writer");
+        writer.write_all(b"0123456\n").unwrap();
+        writer.write_all(b"abcdefg\n").unwrap();
+        writer.write_all(b"ABCDEFG\n").unwrap();
+        writer.flush().unwrap();
+
+        let date = writer.date;
+        let read = |name: String| std::fs::read_to_string(dir.path().join(name)).unwrap();
+        assert_eq!(read(format!("gwt.log.{date}.1")), "0123456\n");
+        assert_eq!(read(format!("gwt.log.{date}.2")), "abcdefg\n");
+        assert_eq!(read(format!("gwt.log.{date}")), "ABCDEFG\n");
+    }
+
+    #[test]
+    fn size_rolling_writer_prunes_to_total_size_after_a_split() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        std::fs::write(dir.path().join("gwt.log.2020-01-01"), [b'x'; 64]).unwrap();
+        let mut writer = SizeRollingWriter::open(dir.path(), 10, Some(20)).expect("open writer");
+        writer.write_all(b"0123456\n").unwrap();
+        writer.write_all(b"abcdefg\n").unwrap();
+        writer.flush().unwrap();
+
+        assert!(!dir.path().join("gwt.log.2020-01-01").exists());
+        assert!(dir
+            .path()
+            .join(format!("gwt.log.{}.1", writer.date))
+            .exists());
+    }
 }
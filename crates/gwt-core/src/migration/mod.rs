@@ -1,15 +1,19 @@
 //! Migration of legacy Normal Git repositories into the Nested Bare+Worktree
 //! layout used by gwt (`<workspace>/<repo>.git/` + `<workspace>/<branch>/`).
 //!
-//! Entry point: [`executor::execute_migration`].
+//! Entry point: [`executor::execute_migration`]. The reverse migration back
+//! to a Normal repository is driven by `gwt::migration::reverse`, using the
+//! same backup and rollback.
 
 pub mod backup;
 pub mod executor;
+pub mod report;
 pub mod rollback;
 pub mod types;
 pub mod validator;
 
+pub use report::{MigrationReport, PlannedWorktree};
 pub use types::{
     MigrationError, MigrationOptions, MigrationOutcome, MigrationPhase, MigrationPlan,
-    RecoveryState, WorktreeMigration,
+    RecoveryState, ReverseMigrationOptions, ReverseMigrationOutcome, WorktreeMigration,
 };
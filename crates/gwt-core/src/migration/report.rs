@@ -0,0 +1,92 @@
+//! Dry-run report of a Normal → Bare+Worktree migration: every worktree
+//! that would move and where to, the disk space involved, uncommitted
+//! changes, pre-flight problems, and a rough duration. Built by
+//! `gwt::migration::dry_run_migration` without touching the repository.
+
+use std::{path::PathBuf, time::Duration};
+
+use serde::Serialize;
+
+/// Throughput assumed for local copies (the backup) and moves.
+const LOCAL_COPY_BYTES_PER_SEC: u64 = 100 * 1024 * 1024;
+/// Throughput assumed when the bare repository is cloned from `origin`.
+const CLONE_BYTES_PER_SEC: u64 = 10 * 1024 * 1024;
+/// Fixed cost of the git invocations around each worktree.
+const PER_WORKTREE: Duration = Duration::from_secs(2);
+const BASE: Duration = Duration::from_secs(3);
+
+/// One worktree as the migration would move it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedWorktree {
+    pub branch: String,
+    pub from: PathBuf,
+    pub to: PathBuf,
+    pub is_main_repo: bool,
+    pub is_dirty: bool,
+    pub is_locked: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MigrationReport {
+    pub project_root: PathBuf,
+    pub bare_repo_path: PathBuf,
+    /// `origin`, which the bare repository is cloned from when set.
+    pub remote_url: Option<String>,
+    pub worktrees: Vec<PlannedWorktree>,
+    /// Size of the project directory.
+    pub project_bytes: u64,
+    /// Free space the migration needs (see
+    /// [`super::validator::check_disk_space`]).
+    pub required_bytes: u64,
+    /// `None` when the free space could not be read.
+    pub available_bytes: Option<u64>,
+    pub estimated_seconds: u64,
+    /// Pre-flight failures; the migration would stop at validation while
+    /// any is present.
+    pub problems: Vec<String>,
+}
+
+impl MigrationReport {
+    pub fn has_dirty(&self) -> bool {
+        self.worktrees.iter().any(|worktree| worktree.is_dirty)
+    }
+
+    pub fn can_migrate(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+/// Rough wall-clock estimate: the backup copies the project, a clone from
+/// `origin` transfers the repository again, and each worktree costs a few
+/// git invocations.
+pub fn estimate_duration(
+    project_bytes: u64,
+    git_bytes: u64,
+    worktrees: usize,
+    clones_origin: bool,
+) -> Duration {
+    let copy = Duration::from_secs(project_bytes / LOCAL_COPY_BYTES_PER_SEC);
+    let clone = if clones_origin {
+        Duration::from_secs(git_bytes / CLONE_BYTES_PER_SEC)
+    } else {
+        Duration::ZERO
+    };
+    BASE + copy + clone + PER_WORKTREE * worktrees.max(1) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_grows_with_size_worktrees_and_clone() {
+        let small = estimate_duration(0, 0, 1, false);
+        assert_eq!(small, Duration::from_secs(5));
+        let gib = 1024 * 1024 * 1024;
+        assert_eq!(estimate_duration(gib, 0, 1, false), Duration::from_secs(15));
+        assert_eq!(
+            estimate_duration(gib, gib, 3, true),
+            Duration::from_secs(121)
+        );
+    }
+}
@@ -1,4 +1,5 @@
-//! Domain types for the Normal-to-Bare+Worktree migration workflow.
+//! Domain types for the Normal-to-Bare+Worktree migration workflow and its
+//! reverse.
 
 use std::path::PathBuf;
 
@@ -13,6 +14,16 @@ pub struct MigrationOptions {
     pub branch_override: Option<String>,
 }
 
+/// Options for the reverse (Bare+Worktree → Normal) migration.
+#[derive(Debug, Clone, Default)]
+pub struct ReverseMigrationOptions {
+    /// Branch checked out in the resulting repository. Defaults to the bare
+    /// repository's `HEAD`.
+    pub primary_branch: Option<String>,
+    /// When true, keep the migration backup directory after a successful run.
+    pub keep_backup_on_success: bool,
+}
+
 /// One worktree slated for migration.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WorktreeMigration {
@@ -40,6 +51,8 @@ pub enum MigrationPhase {
     Validate,
     Backup,
     Bareify,
+    /// Reverse migration: the bare repository becomes the project's `.git`.
+    Unbare,
     Worktrees,
     Submodules,
     Tracking,
@@ -57,6 +70,7 @@ impl MigrationPhase {
             Self::Validate => "validate",
             Self::Backup => "backup",
             Self::Bareify => "bareify",
+            Self::Unbare => "unbare",
             Self::Worktrees => "worktrees",
             Self::Submodules => "submodules",
             Self::Tracking => "tracking",
@@ -82,6 +96,16 @@ pub struct MigrationOutcome {
     pub migrated_worktrees: Vec<PathBuf>,
 }
 
+/// Successful outcome of the reverse migration.
+#[derive(Debug, Clone)]
+pub struct ReverseMigrationOutcome {
+    /// The project root, now a Normal Git repository.
+    pub repo_root: PathBuf,
+    pub primary_branch: String,
+    /// Worktrees of other branches that were removed; their branches stay.
+    pub removed_worktrees: Vec<PathBuf>,
+}
+
 /// Recovery state attached to a [`MigrationError`] so the UI can decide
 /// what to offer the user (Retry / Restore / Quit).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
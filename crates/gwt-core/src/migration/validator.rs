@@ -3,6 +3,9 @@
 //! - disk space (project size × 2 must be available)
 //! - locked worktrees
 //! - write permission on the project root
+//!
+//! [`validate_bare_layout`] runs the same checks for the reverse migration
+//! (Bare+Worktree → Normal) against the bare repository.
 
 use std::{
     fs,
@@ -16,8 +19,10 @@ use crate::process::hidden_command;
 #[derive(Debug)]
 pub enum ValidationError {
     NotNormalGitDirectory(PathBuf),
+    NotBareLayout(PathBuf),
     InsufficientDiskSpace { required: u64, available: u64 },
     LockedWorktrees(Vec<PathBuf>),
+    DirtyWorktrees(Vec<PathBuf>),
     WritePermissionDenied(PathBuf),
     Io(std::io::Error),
 }
@@ -30,6 +35,11 @@ impl std::fmt::Display for ValidationError {
                 "not a normal Git repository with a .git directory: {}",
                 path.display()
             ),
+            Self::NotBareLayout(path) => write!(
+                f,
+                "not a gwt Bare+Worktree project (no bare repository and no .git directory expected): {}",
+                path.display()
+            ),
             Self::InsufficientDiskSpace {
                 required,
                 available,
@@ -38,6 +48,9 @@ impl std::fmt::Display for ValidationError {
                 "insufficient disk space: required {required} bytes, available {available} bytes"
             ),
             Self::LockedWorktrees(paths) => write!(f, "locked worktrees: {paths:?}"),
+            Self::DirtyWorktrees(paths) => {
+                write!(f, "worktrees with uncommitted changes: {paths:?}")
+            }
             Self::WritePermissionDenied(path) => {
                 write!(f, "write permission denied: {}", path.display())
             }
@@ -71,15 +84,33 @@ pub fn evaluate_disk_space(required: u64, available: u64) -> Result<(), Validati
 /// migration writes a full backup before mutating the layout, so we require
 /// `directory_size × 2` bytes of headroom.
 pub fn check_disk_space(project_root: &Path) -> Result<(), ValidationError> {
+    let space = measure_disk_space(project_root)?;
+    evaluate_disk_space(space.required, space.available)
+}
+
+/// Byte counts behind [`check_disk_space`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiskSpace {
+    /// Size of the project directory.
+    pub used: u64,
+    /// Headroom the migration needs: `used × 2`.
+    pub required: u64,
+    /// Free space on the project's file system.
+    pub available: u64,
+}
+
+pub fn measure_disk_space(project_root: &Path) -> Result<DiskSpace, ValidationError> {
     let used = directory_size(project_root)?;
-    let required = used.saturating_mul(2);
-    let available = available_space(project_root).map_err(ValidationError::Io)?;
-    evaluate_disk_space(required, available)
+    Ok(DiskSpace {
+        used,
+        required: used.saturating_mul(2),
+        available: available_space(project_root).map_err(ValidationError::Io)?,
+    })
 }
 
 /// Compute the total bytes consumed by `path`, walking subdirectories.
 /// Symlinks are not followed.
-fn directory_size(path: &Path) -> Result<u64, ValidationError> {
+pub fn directory_size(path: &Path) -> Result<u64, ValidationError> {
     let mut total: u64 = 0;
     let mut stack = vec![path.to_path_buf()];
     while let Some(current) = stack.pop() {
@@ -181,3 +212,47 @@ pub fn validate(project_root: &Path) -> Result<(), ValidationError> {
     check_locked_worktrees(project_root)?;
     Ok(())
 }
+
+/// Locate the bare repository of a Nested Bare+Worktree project: the
+/// `bare_repo_name` of `.gwt/project.toml`, else `<dir name>.git`. The project
+/// root must not have a `.git` of its own.
+pub fn check_bare_layout(project_root: &Path) -> Result<PathBuf, ValidationError> {
+    let not_bare = || ValidationError::NotBareLayout(project_root.to_path_buf());
+    if project_root.join(".git").exists() {
+        return Err(not_bare());
+    }
+    let bare_repo_name = match crate::config::BareProjectConfig::load(project_root) {
+        Ok(Some(config)) => config.bare_repo_name,
+        _ => format!(
+            "{}.git",
+            project_root
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("repo")
+        ),
+    };
+    let bare = project_root.join(bare_repo_name);
+    if !bare.is_dir() {
+        return Err(not_bare());
+    }
+    let output = hidden_command("git")
+        .args(["rev-parse", "--is-bare-repository"])
+        .current_dir(&bare)
+        .output()
+        .map_err(ValidationError::Io)?;
+    if output.status.success() && String::from_utf8_lossy(&output.stdout).trim() == "true" {
+        Ok(bare)
+    } else {
+        Err(not_bare())
+    }
+}
+
+/// Pre-flight checks for the reverse migration. Returns the bare repository
+/// path. Short-circuits on the first failure.
+pub fn validate_bare_layout(project_root: &Path) -> Result<PathBuf, ValidationError> {
+    let bare = check_bare_layout(project_root)?;
+    check_write_permission(project_root)?;
+    check_disk_space(project_root)?;
+    check_locked_worktrees(&bare)?;
+    Ok(bare)
+}
@@ -0,0 +1,439 @@
+//! Persisted progress of long-running background operations.
+//!
+//! Migrations and bulk branch cleanups run on background threads and used to
+//! report progress only to the window that started them. Each run now keeps
+//! a record under `~/.gwt/operations/<id>.json` (operation id, step, percent,
+//! final state) so another gwt instance — or the next one after a restart —
+//! can show it, follow it, and cancel it.
+//!
+//! Liveness follows `index_coordinator`: the owner holds an exclusive lock on
+//! `<id>.lock` for the whole run, so a `Running` record whose lock can be
+//! taken belongs to a process that is gone and is reported as
+//! [`OperationState::Interrupted`]. Cancellation is a `<id>.cancel` marker
+//! the owner polls between steps; the record itself is only ever written by
+//! its owner, except when an interrupted record is dismissed.
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{GwtError, Result};
+
+/// Finished records older than this are deleted when a new operation starts.
+pub const FINISHED_RETENTION_DAYS: i64 = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationKind {
+    /// Normal Git → Bare+Worktree migration (SPEC-1934).
+    Migration,
+    /// Cleanup of several branches / worktrees from the Branches window.
+    BranchCleanup,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationState {
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+    /// The owning process exited while the operation was running.
+    Interrupted,
+}
+
+impl OperationState {
+    pub fn is_finished(self) -> bool {
+        !matches!(self, Self::Running | Self::Interrupted)
+    }
+}
+
+/// One operation as stored on disk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperationRecord {
+    pub id: String,
+    pub kind: OperationKind,
+    pub project_root: PathBuf,
+    /// Short description of the current step, e.g. a migration phase.
+    pub step: String,
+    /// Completion of `step` (migration) or of the whole run (cleanup), 0–100.
+    pub percent: u8,
+    pub state: OperationState,
+    /// Result or error summary once finished.
+    #[serde(default)]
+    pub message: Option<String>,
+    /// Whether the owner honours cancel requests.
+    pub cancellable: bool,
+    /// Set when a cancel marker is present; derived on read, not stored.
+    #[serde(default, skip_serializing)]
+    pub cancel_requested: bool,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Why [`request_cancel`] refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CancelRejection {
+    NotFound,
+    AlreadyFinished,
+    NotCancellable,
+    WriteFailed(String),
+}
+
+impl std::fmt::Display for CancelRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound => f.write_str("operation not found"),
+            Self::AlreadyFinished => f.write_str("operation already finished"),
+            Self::NotCancellable => f.write_str("operation cannot be cancelled"),
+            Self::WriteFailed(error) => write!(f, "cancel request not recorded: {error}"),
+        }
+    }
+}
+
+/// Owner side of a running operation. Dropping it without
+/// [`OperationTracker::finish`] records the run as failed.
+pub struct OperationTracker {
+    dir: PathBuf,
+    record: OperationRecord,
+    lock: Option<File>,
+}
+
+impl OperationTracker {
+    /// Create the record and take its lock. Also prunes finished records
+    /// older than [`FINISHED_RETENTION_DAYS`].
+    pub fn start(
+        dir: &Path,
+        kind: OperationKind,
+        project_root: &Path,
+        cancellable: bool,
+    ) -> Result<Self> {
+        fs::create_dir_all(dir)?;
+        prune_finished(dir, Utc::now() - Duration::days(FINISHED_RETENTION_DAYS));
+
+        let id = uuid::Uuid::new_v4().simple().to_string();
+        let lock = open_lock_file(&lock_path(dir, &id))?;
+        fs2::FileExt::try_lock_exclusive(&lock)?;
+        let now = Utc::now();
+        let tracker = Self {
+            dir: dir.to_path_buf(),
+            record: OperationRecord {
+                id,
+                kind,
+                project_root: project_root.to_path_buf(),
+                step: "starting".to_string(),
+                percent: 0,
+                state: OperationState::Running,
+                message: None,
+                cancellable,
+                cancel_requested: false,
+                started_at: now,
+                updated_at: now,
+            },
+            lock: Some(lock),
+        };
+        write_record(&tracker.dir, &tracker.record)?;
+        Ok(tracker)
+    }
+
+    pub fn id(&self) -> &str {
+        &self.record.id
+    }
+
+    pub fn record(&self) -> &OperationRecord {
+        &self.record
+    }
+
+    /// Persist the current step. Write failures are logged and otherwise
+    /// ignored: losing a progress update must not abort the operation.
+    pub fn update(&mut self, step: impl Into<String>, percent: u8) {
+        let step = step.into();
+        let percent = percent.min(100);
+        if step == self.record.step && percent == self.record.percent {
+            return;
+        }
+        self.record.step = step;
+        self.record.percent = percent;
+        self.record.updated_at = Utc::now();
+        self.persist();
+    }
+
+    /// Whether another instance asked this operation to stop.
+    pub fn is_cancel_requested(&self) -> bool {
+        self.record.cancellable && cancel_path(&self.dir, &self.record.id).exists()
+    }
+
+    /// Record the final state and release the lock.
+    pub fn finish(mut self, state: OperationState, message: Option<String>) {
+        self.record.state = state;
+        self.record.message = message;
+        if state == OperationState::Completed {
+            self.record.percent = 100;
+        }
+        self.record.updated_at = Utc::now();
+        self.persist();
+    }
+
+    fn persist(&self) {
+        if let Err(error) = write_record(&self.dir, &self.record) {
+            tracing::warn!(
+                target: "gwt_core::operation_progress",
+                id = %self.record.id,
+                error = %error,
+                "operation progress write failed"
+            );
+        }
+    }
+}
+
+impl Drop for OperationTracker {
+    fn drop(&mut self) {
+        if self.record.state == OperationState::Running {
+            self.record.state = OperationState::Failed;
+            self.record.message = Some("operation ended without a result".to_string());
+            self.record.updated_at = Utc::now();
+            self.persist();
+        }
+        if let Some(lock) = self.lock.take() {
+            let _ = fs2::FileExt::unlock(&lock);
+        }
+        let _ = fs::remove_file(lock_path(&self.dir, &self.record.id));
+        let _ = fs::remove_file(cancel_path(&self.dir, &self.record.id));
+    }
+}
+
+/// All operations in `dir`, newest first. `Running` records whose owner is
+/// gone come back as [`OperationState::Interrupted`].
+pub fn list_operations(dir: &Path) -> Vec<OperationRecord> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut records: Vec<OperationRecord> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let id = path
+                .file_name()?
+                .to_str()?
+                .strip_suffix(".json")?
+                .to_string();
+            load_operation(dir, &id)
+        })
+        .collect();
+    records.sort_by_key(|record| std::cmp::Reverse(record.started_at));
+    records
+}
+
+/// One operation by id, with liveness and cancel state resolved.
+pub fn load_operation(dir: &Path, id: &str) -> Option<OperationRecord> {
+    if !is_valid_id(id) {
+        return None;
+    }
+    let raw = fs::read(record_path(dir, id)).ok()?;
+    let mut record: OperationRecord = serde_json::from_slice(&raw).ok()?;
+    if record.state == OperationState::Running && !owner_alive(dir, id) {
+        record.state = OperationState::Interrupted;
+    }
+    record.cancel_requested = cancel_path(dir, id).exists();
+    Some(record)
+}
+
+/// Ask a running operation to stop, or dismiss an interrupted one by
+/// marking it cancelled. Returns the record as it now stands.
+pub fn request_cancel(
+    dir: &Path,
+    id: &str,
+) -> std::result::Result<OperationRecord, CancelRejection> {
+    let mut record = load_operation(dir, id).ok_or(CancelRejection::NotFound)?;
+    match record.state {
+        OperationState::Running if !record.cancellable => Err(CancelRejection::NotCancellable),
+        OperationState::Running => {
+            fs::write(cancel_path(dir, id), b"")
+                .map_err(|error| CancelRejection::WriteFailed(error.to_string()))?;
+            record.cancel_requested = true;
+            Ok(record)
+        }
+        OperationState::Interrupted => {
+            record.state = OperationState::Cancelled;
+            record.message = Some("dismissed after the owning gwt process exited".to_string());
+            record.updated_at = Utc::now();
+            write_record(dir, &record)
+                .map_err(|error| CancelRejection::WriteFailed(error.to_string()))?;
+            Ok(record)
+        }
+        _ => Err(CancelRejection::AlreadyFinished),
+    }
+}
+
+fn prune_finished(dir: &Path, cutoff: DateTime<Utc>) {
+    for record in list_operations(dir) {
+        if record.state.is_finished() && record.updated_at < cutoff {
+            let _ = fs::remove_file(record_path(dir, &record.id));
+        }
+    }
+}
+
+fn owner_alive(dir: &Path, id: &str) -> bool {
+    let path = lock_path(dir, id);
+    if !path.exists() {
+        return false;
+    }
+    let Ok(file) = open_lock_file(&path) else {
+        return false;
+    };
+    match fs2::FileExt::try_lock_exclusive(&file) {
+        Ok(()) => {
+            let _ = fs2::FileExt::unlock(&file);
+            false
+        }
+        Err(_) => true,
+    }
+}
+
+/// Ids are generated here; anything else (e.g. from a URL) is rejected
+/// before it is joined onto `dir`.
+fn is_valid_id(id: &str) -> bool {
+    !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn record_path(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("{id}.json"))
+}
+
+fn lock_path(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("{id}.lock"))
+}
+
+fn cancel_path(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("{id}.cancel"))
+}
+
+fn open_lock_file(path: &Path) -> io::Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .read(true)
+        .write(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Atomic publish so readers in other processes never see a torn record.
+fn write_record(dir: &Path, record: &OperationRecord) -> Result<()> {
+    let path = record_path(dir, &record.id);
+    let tmp = dir.join(format!(".{}.tmp-{}", record.id, std::process::id()));
+    let payload =
+        serde_json::to_vec_pretty(record).map_err(|error| GwtError::Other(error.to_string()))?;
+    fs::write(&tmp, payload)?;
+    fs::rename(&tmp, &path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracker_persists_progress_and_final_state() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut tracker = OperationTracker::start(
+            dir.path(),
+            OperationKind::Migration,
+            Path::new("/repo"),
+            false,
+        )
+        .expect("start");
+        tracker.update("backup", 40);
+        let id = tracker.id().to_string();
+
+        let running = load_operation(dir.path(), &id).expect("record");
+        assert_eq!(running.state, OperationState::Running);
+        assert_eq!((running.step.as_str(), running.percent), ("backup", 40));
+
+        tracker.finish(OperationState::Completed, None);
+        let done = load_operation(dir.path(), &id).expect("record");
+        assert_eq!(done.state, OperationState::Completed);
+        assert_eq!(done.percent, 100);
+        assert!(!lock_path(dir.path(), &id).exists());
+    }
+
+    #[test]
+    fn running_record_without_live_owner_is_interrupted_and_can_be_dismissed() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let tracker = OperationTracker::start(
+            dir.path(),
+            OperationKind::BranchCleanup,
+            Path::new("/repo"),
+            true,
+        )
+        .expect("start");
+        let mut orphan = tracker.record().clone();
+        orphan.id = "orphan".to_string();
+        write_record(dir.path(), &orphan).expect("write orphan");
+
+        let listed = list_operations(dir.path());
+        let state_of = |id: &str| listed.iter().find(|r| r.id == id).map(|r| r.state);
+        assert_eq!(state_of("orphan"), Some(OperationState::Interrupted));
+        assert_eq!(state_of(tracker.id()), Some(OperationState::Running));
+
+        let dismissed = request_cancel(dir.path(), "orphan").expect("dismiss");
+        assert_eq!(dismissed.state, OperationState::Cancelled);
+        assert_eq!(
+            request_cancel(dir.path(), "orphan"),
+            Err(CancelRejection::AlreadyFinished)
+        );
+    }
+
+    #[test]
+    fn cancel_request_reaches_cancellable_owner_only() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let cleanup = OperationTracker::start(
+            dir.path(),
+            OperationKind::BranchCleanup,
+            Path::new("/repo"),
+            true,
+        )
+        .expect("start");
+        let migration = OperationTracker::start(
+            dir.path(),
+            OperationKind::Migration,
+            Path::new("/repo"),
+            false,
+        )
+        .expect("start");
+
+        assert!(!cleanup.is_cancel_requested());
+        let requested = request_cancel(dir.path(), cleanup.id()).expect("cancel");
+        assert!(requested.cancel_requested);
+        assert!(cleanup.is_cancel_requested());
+        assert_eq!(
+            request_cancel(dir.path(), migration.id()),
+            Err(CancelRejection::NotCancellable)
+        );
+        assert_eq!(
+            request_cancel(dir.path(), "../escape"),
+            Err(CancelRejection::NotFound)
+        );
+    }
+
+    #[test]
+    fn dropped_tracker_records_failure() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let id = {
+            let tracker = OperationTracker::start(
+                dir.path(),
+                OperationKind::Migration,
+                Path::new("/repo"),
+                false,
+            )
+            .expect("start");
+            tracker.id().to_string()
+        };
+        let record = load_operation(dir.path(), &id).expect("record");
+        assert_eq!(record.state, OperationState::Failed);
+    }
+}
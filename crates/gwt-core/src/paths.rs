@@ -573,6 +573,16 @@ pub fn gwt_update_cache_path() -> PathBuf {
     gwt_cache_dir().join("update-check.json")
 }
 
+/// Return the long-running operation records directory (`~/.gwt/operations/`).
+pub fn gwt_operations_dir() -> PathBuf {
+    gwt_home().join("operations")
+}
+
+/// Return the queued agent task directory (`~/.gwt/tasks/`).
+pub fn gwt_tasks_dir() -> PathBuf {
+    gwt_home().join("tasks")
+}
+
 /// Return the updates staging directory (`~/.gwt/updates/`).
 pub fn gwt_updates_dir() -> PathBuf {
     gwt_home().join("updates")
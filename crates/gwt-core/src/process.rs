@@ -74,6 +74,8 @@ pub fn run_git_logged(
     let spawn_id = next_git_spawn_id();
     let label = format!("git {}", args.join(" "));
     let started_at = std::time::Instant::now();
+    // Exported as an OTLP span when trace export is on (`logging::otlp`).
+    let _span = tracing::info_span!(target: "gwt.git", "git", command = %label).entered();
 
     tracing::info!(
         target: "gwt.process.summary",
@@ -155,6 +157,8 @@ pub fn run_git_logged_with_stdin(
     let spawn_id = next_git_spawn_id();
     let label = format!("git {}", args.join(" "));
     let started_at = std::time::Instant::now();
+    // Exported as an OTLP span when trace export is on (`logging::otlp`).
+    let _span = tracing::info_span!(target: "gwt.git", "git", command = %label).entered();
 
     tracing::info!(
         target: "gwt.process.summary",
@@ -242,6 +246,8 @@ pub fn push_command_banner_to_hub(
         Some(dir) => format!("$ {} (cwd={})", label, dir.display()),
         None => format!("$ {label}"),
     };
+    // Launch commands can carry `-e KEY=value` env pairs; mask secret ones.
+    let banner = crate::process_console::redact_line(&banner);
     hub.push(crate::process_console::ProcessLine::new(
         kind,
         spawn_id,
@@ -10,6 +10,11 @@
 //! - `token=<value>` URL parameters
 //! - GitHub Personal Access Token prefixes (`gh_` / `ghp_` / `ghs_` / `ghu_`) followed by 16+ alphanumerics
 //!
+//! On top of these shapes, `KEY=value` assignments whose key matches the
+//! configured secret key patterns are masked (see
+//! [`crate::logging::redact`]), which covers env vars echoed in launch
+//! banners such as `docker exec -e OPENAI_API_KEY=...`.
+//!
 //! Replacement is `***redacted***`. The function returns the raw line
 //! unchanged when it does not match any pattern, so callers can compare
 //! the input and output cheaply.
@@ -27,6 +32,11 @@ pub const REDACTED: &str = "***redacted***";
 /// in-place replace. When no pattern matches, the result is byte-equal
 /// to the input.
 pub fn redact_line(line: &str) -> String {
+    redact_tokens(&crate::logging::redact::global().redact_text(line))
+}
+
+/// Token-shape patterns only, without the key-based assignment pass.
+pub(crate) fn redact_tokens(line: &str) -> String {
     let mut out = line.to_string();
     out = authorization_re().replace_all(&out, REDACTED).into_owned();
     out = url_token_re().replace_all(&out, REDACTED).into_owned();
@@ -99,6 +109,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn redacts_secret_env_assignments() {
+        let line = "$ docker exec -e OPENAI_API_KEY=sk-live-123 -e TERM=xterm app";
+        let out = redact_line(line);
+        assert!(!out.contains("sk-live-123"));
+        assert!(out.contains("TERM=xterm"));
+    }
+
     #[test]
     fn passes_through_clean_line() {
         let line = "fatal: could not find object";
@@ -55,9 +55,21 @@ pub fn installer_asset_name(os: &str, arch: &str) -> Option<String> {
         .cloned()
 }
 
+/// Delta bundle published next to `portable_asset` for updating from
+/// release `from_version`, e.g. `gwt-linux-x86_64-from-v1.2.3.delta.tar`.
+pub fn delta_asset_name(portable_asset: &str, from_version: &str) -> Option<String> {
+    let stem = portable_asset
+        .strip_suffix(".tar.gz")
+        .or_else(|| portable_asset.strip_suffix(".zip"))?;
+    Some(format!(
+        "{stem}-from-v{}.delta.tar",
+        from_version.trim_start_matches('v')
+    ))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{bundle_binary_names, installer_asset_name, portable_asset_name};
+    use super::{bundle_binary_names, delta_asset_name, installer_asset_name, portable_asset_name};
 
     #[test]
     fn release_contract_reads_shared_assets() {
@@ -83,6 +95,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn delta_asset_name_derives_from_the_portable_archive() {
+        assert_eq!(
+            delta_asset_name("gwt-windows-x86_64.zip", "v1.2.3").as_deref(),
+            Some("gwt-windows-x86_64-from-v1.2.3.delta.tar")
+        );
+        assert_eq!(
+            delta_asset_name("gwt-macos-arm64.tar.gz", "1.2.3").as_deref(),
+            Some("gwt-macos-arm64-from-v1.2.3.delta.tar")
+        );
+        assert_eq!(delta_asset_name("gwt-windows-x86_64.msi", "1.2.3"), None);
+    }
+
     #[test]
     fn release_contract_normalizes_node_platform_and_arch_names() {
         assert_eq!(
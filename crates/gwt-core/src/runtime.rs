@@ -269,19 +269,9 @@ pub fn invalidate_project_index_probe_cache() {
     let _ = std::fs::remove_file(cache);
 }
 
-fn acquire_probe_lock(runtime_dir: &Path) -> Result<std::fs::File> {
-    use fs2::FileExt;
-    let lock_path = runtime_dir.join(PROBE_LOCK_FILE);
-    let lock = std::fs::OpenOptions::new()
-        .create(true)
-        .read(true)
-        .write(true)
-        .truncate(false)
-        .open(&lock_path)
-        .map_err(|err| GwtError::Other(format!("open probe lock: {err}")))?;
-    lock.lock_exclusive()
-        .map_err(|err| GwtError::Other(format!("acquire probe lock: {err}")))?;
-    Ok(lock)
+fn acquire_probe_lock(runtime_dir: &Path) -> Result<crate::lock::OwnedLock> {
+    crate::lock::acquire(&runtime_dir.join(PROBE_LOCK_FILE), "runtime probe")
+        .map_err(|err| GwtError::Other(format!("acquire probe lock: {err}")))
 }
 
 fn venv_python_identity(venv_python: &Path) -> String {
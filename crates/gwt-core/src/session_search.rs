@@ -0,0 +1,530 @@
+//! Full-text search over agent session transcripts.
+//!
+//! Scans the local transcript stores the usage readers already know about —
+//! Claude Code `~/.claude/projects/*/<session-id>.jsonl` and Codex
+//! `$CODEX_HOME/sessions/**/rollout-*.jsonl` — newest first, so "which session
+//! touched the auth middleware" can be answered without opening each agent.
+//! Message text and tool-call inputs are searched; a message matches when it
+//! contains every whitespace-separated query term, case-insensitively.
+//! Transcripts are read on demand (bounded by [`MAX_SCANNED_FILES`]) rather
+//! than kept in a persistent index.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::usage::{claude, codex};
+
+/// Newest transcripts scanned per agent for one query.
+pub const MAX_SCANNED_FILES: usize = 400;
+/// Default cap on returned hits.
+pub const DEFAULT_SEARCH_LIMIT: usize = 50;
+const SNIPPET_CONTEXT_CHARS: usize = 80;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionSource {
+    Claude,
+    Codex,
+}
+
+/// One matching message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionSearchHit {
+    pub source: SessionSource,
+    pub session_id: String,
+    pub transcript_path: PathBuf,
+    /// Working directory the session ran in, when the transcript records it.
+    pub cwd: Option<String>,
+    pub timestamp: Option<String>,
+    /// `user`, `assistant` or `tool`.
+    pub role: String,
+    /// Text around the first matching term.
+    pub snippet: String,
+}
+
+/// One message of a transcript, for readers of the whole conversation
+/// (session export).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TranscriptEntry {
+    /// `user`, `assistant` or `tool`.
+    pub role: String,
+    pub text: String,
+    pub timestamp: Option<String>,
+    /// Tools the message called, in order (`Edit`, `shell`, ...).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<String>,
+    /// Files those tool calls wrote (edits, new files, patches), in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<String>,
+}
+
+/// A searchable message extracted from one transcript line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TranscriptMessage {
+    role: String,
+    text: String,
+    timestamp: Option<String>,
+}
+
+/// Search the Claude and Codex transcript stores of the current user.
+pub fn search_sessions(query: &str, limit: usize) -> Vec<SessionSearchHit> {
+    let mut files = Vec::new();
+    if let Some(home) = claude::claude_home() {
+        files.extend(
+            claude::transcripts_modified_since(&home, SystemTime::UNIX_EPOCH, MAX_SCANNED_FILES)
+                .into_iter()
+                .map(|path| (SessionSource::Claude, path)),
+        );
+    }
+    if let Some(home) = codex::codex_home() {
+        files.extend(
+            codex::recent_rollouts(&home, MAX_SCANNED_FILES)
+                .into_iter()
+                .map(|path| (SessionSource::Codex, path)),
+        );
+    }
+    files.sort_by_key(|(_, path)| {
+        std::cmp::Reverse(
+            fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .unwrap_or(SystemTime::UNIX_EPOCH),
+        )
+    });
+    search_transcripts(&files, query, limit)
+}
+
+/// Search `files` in order, returning at most `limit` hits.
+pub fn search_transcripts(
+    files: &[(SessionSource, PathBuf)],
+    query: &str,
+    limit: usize,
+) -> Vec<SessionSearchHit> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    let mut hits = Vec::new();
+    if terms.is_empty() {
+        return hits;
+    }
+    for (source, path) in files {
+        if hits.len() >= limit {
+            break;
+        }
+        let Ok(content) = fs::read_to_string(path) else {
+            continue;
+        };
+        search_transcript(*source, path, &content, &terms, limit, &mut hits);
+    }
+    hits
+}
+
+fn search_transcript(
+    source: SessionSource,
+    path: &Path,
+    content: &str,
+    terms: &[String],
+    limit: usize,
+    hits: &mut Vec<SessionSearchHit>,
+) {
+    let mut session_id = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or_default()
+        .to_string();
+    let mut cwd = None;
+    for line in content.lines() {
+        let Ok(value) = serde_json::from_str::<Value>(line) else {
+            continue;
+        };
+        if let Some(meta) = session_meta(source, &value) {
+            if let Some(id) = meta.0 {
+                session_id = id;
+            }
+            cwd = meta.1.or(cwd);
+        }
+        let Some(message) = transcript_message(source, &value) else {
+            continue;
+        };
+        let lower = message.text.to_lowercase();
+        if !terms.iter().all(|term| lower.contains(term.as_str())) {
+            continue;
+        }
+        hits.push(SessionSearchHit {
+            source,
+            session_id: session_id.clone(),
+            transcript_path: path.to_path_buf(),
+            cwd: cwd.clone(),
+            timestamp: message.timestamp,
+            role: message.role,
+            snippet: snippet(&message.text, &lower, &terms[0]),
+        });
+        if hits.len() >= limit {
+            return;
+        }
+    }
+}
+
+/// Text of the last assistant message in a transcript — usually the agent's
+/// closing report of what it did.
+pub fn last_assistant_message(source: SessionSource, content: &str) -> Option<String> {
+    content
+        .lines()
+        .rev()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter_map(|value| transcript_message(source, &value))
+        .find(|message| message.role == "assistant")
+        .map(|message| message.text)
+}
+
+/// Every message of a transcript in order, with the tools each one called.
+pub fn transcript_entries(source: SessionSource, content: &str) -> Vec<TranscriptEntry> {
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter_map(|value| transcript_entry(source, &value))
+        .collect()
+}
+
+/// The message of one decoded transcript line, or `None` for lines that
+/// carry no message (metadata, token counts, ...).
+pub(crate) fn transcript_entry(source: SessionSource, value: &Value) -> Option<TranscriptEntry> {
+    let message = transcript_message(source, value)?;
+    Some(TranscriptEntry {
+        role: message.role,
+        text: message.text,
+        timestamp: message.timestamp,
+        tools: tool_names(source, value),
+        files: written_files(source, value),
+    })
+}
+
+/// Names of the tools a transcript line calls.
+fn tool_names(source: SessionSource, value: &Value) -> Vec<String> {
+    let name = |value: &Value| {
+        value
+            .get("name")
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+    match source {
+        SessionSource::Claude => value
+            .get("message")
+            .and_then(|message| message.get("content"))
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter(|block| block.get("type").and_then(Value::as_str) == Some("tool_use"))
+            .filter_map(name)
+            .collect(),
+        SessionSource::Codex => value
+            .get("payload")
+            .filter(|payload| {
+                matches!(
+                    payload.get("type").and_then(Value::as_str),
+                    Some("function_call" | "custom_tool_call")
+                )
+            })
+            .and_then(name)
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// Files written by the tool calls of a transcript line: the path of a
+/// Claude `Edit` / `MultiEdit` / `Write` / `NotebookEdit`, and the files an
+/// `apply_patch` envelope adds, updates or deletes (Codex).
+fn written_files(source: SessionSource, value: &Value) -> Vec<String> {
+    let mut files = Vec::new();
+    match source {
+        SessionSource::Claude => {
+            let blocks = value
+                .get("message")
+                .and_then(|message| message.get("content"))
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter(|block| block.get("type").and_then(Value::as_str) == Some("tool_use"));
+            for block in blocks {
+                let writes = matches!(
+                    block.get("name").and_then(Value::as_str),
+                    Some("Edit" | "MultiEdit" | "Write" | "NotebookEdit")
+                );
+                let path = block.get("input").and_then(|input| {
+                    input
+                        .get("file_path")
+                        .or_else(|| input.get("notebook_path"))
+                        .and_then(Value::as_str)
+                });
+                if let (true, Some(path)) = (writes, path) {
+                    files.push(path.to_string());
+                }
+            }
+        }
+        SessionSource::Codex => {
+            let Some(payload) = value.get("payload") else {
+                return files;
+            };
+            let body = payload
+                .get("input")
+                .or_else(|| payload.get("arguments"))
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            // `arguments` is a JSON string, so a patch inside it keeps its
+            // newlines escaped.
+            for line in body.split("\\n").flat_map(str::lines) {
+                let path = ["*** Update File: ", "*** Add File: ", "*** Delete File: "]
+                    .iter()
+                    .find_map(|marker| line.trim_start().strip_prefix(marker));
+                if let Some(path) = path {
+                    files.push(path.trim().trim_end_matches('"').to_string());
+                }
+            }
+        }
+    }
+    files
+}
+
+/// `(session id, cwd)` recorded by a transcript line, if any.
+fn session_meta(source: SessionSource, value: &Value) -> Option<(Option<String>, Option<String>)> {
+    let string =
+        |value: &Value, key: &str| value.get(key).and_then(Value::as_str).map(str::to_string);
+    match source {
+        SessionSource::Claude => {
+            let cwd = string(value, "cwd")?;
+            Some((string(value, "sessionId"), Some(cwd)))
+        }
+        SessionSource::Codex => {
+            if value.get("type").and_then(Value::as_str) != Some("session_meta") {
+                return None;
+            }
+            let payload = value.get("payload")?;
+            Some((string(payload, "id"), string(payload, "cwd")))
+        }
+    }
+}
+
+fn transcript_message(source: SessionSource, value: &Value) -> Option<TranscriptMessage> {
+    let timestamp = value
+        .get("timestamp")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    let (role, text) = match source {
+        SessionSource::Claude => {
+            let kind = value.get("type").and_then(Value::as_str)?;
+            if kind != "user" && kind != "assistant" {
+                return None;
+            }
+            let content = value.get("message")?.get("content")?;
+            let (text, tool_only) = content_text(content);
+            (if tool_only { "tool" } else { kind }.to_string(), text)
+        }
+        SessionSource::Codex => {
+            if value.get("type").and_then(Value::as_str) != Some("response_item") {
+                return None;
+            }
+            let payload = value.get("payload")?;
+            match payload.get("type").and_then(Value::as_str)? {
+                "message" => (
+                    payload.get("role").and_then(Value::as_str)?.to_string(),
+                    content_text(payload.get("content")?).0,
+                ),
+                "function_call" | "custom_tool_call" => {
+                    let input = payload
+                        .get("arguments")
+                        .or_else(|| payload.get("input"))
+                        .and_then(Value::as_str)
+                        .unwrap_or_default();
+                    ("tool".to_string(), input.to_string())
+                }
+                _ => return None,
+            }
+        }
+    };
+    let text = text.trim().to_string();
+    (!text.is_empty()).then_some(TranscriptMessage {
+        role,
+        text,
+        timestamp,
+    })
+}
+
+/// Text of a message `content` (a string or a block array), and whether it
+/// consisted only of tool calls / results.
+fn content_text(content: &Value) -> (String, bool) {
+    if let Some(text) = content.as_str() {
+        return (text.to_string(), false);
+    }
+    let mut parts = Vec::new();
+    let mut has_text = false;
+    for block in content.as_array().into_iter().flatten() {
+        match block.get("type").and_then(Value::as_str) {
+            Some("text" | "input_text" | "output_text") => {
+                if let Some(text) = block.get("text").and_then(Value::as_str) {
+                    parts.push(text.to_string());
+                    has_text = true;
+                }
+            }
+            Some("tool_use") => {
+                if let Some(input) = block.get("input") {
+                    parts.push(input.to_string());
+                }
+            }
+            Some("tool_result") => match block.get("content") {
+                Some(Value::String(text)) => parts.push(text.clone()),
+                Some(nested) => parts.push(content_text(nested).0),
+                None => {}
+            },
+            _ => {}
+        }
+    }
+    (parts.join("\n"), !has_text && !parts.is_empty())
+}
+
+/// Up to [`SNIPPET_CONTEXT_CHARS`] characters either side of `term`'s first
+/// occurrence, on one line.
+fn snippet(text: &str, lower: &str, term: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let lower_chars: Vec<char> = lower.chars().collect();
+    let term_chars: Vec<char> = term.chars().collect();
+    // Lowercasing can change the length of some characters; fall back to
+    // the start of the text when the offsets no longer line up.
+    let start = if chars.len() == lower_chars.len() {
+        lower_chars
+            .windows(term_chars.len().max(1))
+            .position(|window| window == term_chars.as_slice())
+            .unwrap_or(0)
+    } else {
+        0
+    };
+    let from = start.saturating_sub(SNIPPET_CONTEXT_CHARS);
+    let to = (start + term_chars.len() + SNIPPET_CONTEXT_CHARS).min(chars.len());
+    let mut out: String = chars[from..to].iter().collect();
+    out = out.split_whitespace().collect::<Vec<_>>().join(" ");
+    if from > 0 {
+        out.insert(0, '…');
+    }
+    if to < chars.len() {
+        out.push('…');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_matches_all_terms_across_claude_and_codex_transcripts() {
+        let tmp = tempfile::tempdir().unwrap();
+        let claude_file = tmp.path().join("abc.jsonl");
+        fs::write(
+            &claude_file,
+            [
+                r#"{"type":"user","cwd":"/src/app","sessionId":"abc","timestamp":"2026-01-01T00:00:00Z","message":{"role":"user","content":"Fix the auth middleware redirect"}}"#,
+                r#"{"type":"assistant","cwd":"/src/app","sessionId":"abc","message":{"role":"assistant","content":[{"type":"tool_use","name":"Edit","input":{"file_path":"src/auth/middleware.rs"}}]}}"#,
+                r#"{"type":"assistant","message":{"content":[{"type":"text","text":"Unrelated reply"}]}}"#,
+            ]
+            .join("\n"),
+        )
+        .unwrap();
+        let codex_file = tmp.path().join("rollout-2026-01-02-xyz.jsonl");
+        fs::write(
+            &codex_file,
+            [
+                r#"{"type":"session_meta","payload":{"id":"xyz","cwd":"/src/api"}}"#,
+                r#"{"type":"response_item","payload":{"type":"message","role":"user","content":[{"type":"input_text","text":"Rename the AUTH middleware"}]}}"#,
+                r#"{"type":"response_item","payload":{"type":"function_call","name":"shell","arguments":"{\"cmd\":\"ls\"}"}}"#,
+            ]
+            .join("\n"),
+        )
+        .unwrap();
+        let files = vec![
+            (SessionSource::Claude, claude_file),
+            (SessionSource::Codex, codex_file),
+        ];
+
+        let hits = search_transcripts(&files, "auth middleware", 10);
+
+        assert_eq!(hits.len(), 3, "{hits:?}");
+        assert_eq!(hits[0].session_id, "abc");
+        assert_eq!(hits[0].cwd.as_deref(), Some("/src/app"));
+        assert_eq!(hits[0].role, "user");
+        assert_eq!(hits[0].snippet, "Fix the auth middleware redirect");
+        assert_eq!(hits[1].role, "tool");
+        assert!(hits[1].snippet.contains("src/auth/middleware.rs"));
+        assert_eq!(hits[2].source, SessionSource::Codex);
+        assert_eq!(hits[2].session_id, "xyz");
+        assert_eq!(hits[2].cwd.as_deref(), Some("/src/api"));
+        assert_eq!(search_transcripts(&files, "auth middleware", 1).len(), 1);
+        assert!(search_transcripts(&files, "   ", 10).is_empty());
+    }
+
+    #[test]
+    fn snippet_trims_long_text_around_the_match() {
+        let text = format!("{}needle{}", "a ".repeat(100), " b".repeat(100));
+        let lower = text.to_lowercase();
+        let out = snippet(&text, &lower, "needle");
+        assert!(out.starts_with('…') && out.ends_with('…'), "{out}");
+        assert!(out.contains("needle"));
+        assert!(out.chars().count() < text.chars().count());
+    }
+
+    #[test]
+    fn last_assistant_message_skips_tool_only_turns() {
+        let content = [
+            r#"{"type":"assistant","message":{"content":[{"type":"text","text":"Working on it"}]}}"#,
+            r#"{"type":"assistant","message":{"content":[{"type":"text","text":"Done: added retry"}]}}"#,
+            r#"{"type":"assistant","message":{"content":[{"type":"tool_use","input":{"cmd":"ls"}}]}}"#,
+        ]
+        .join("\n");
+        assert_eq!(
+            last_assistant_message(SessionSource::Claude, &content).as_deref(),
+            Some("Done: added retry")
+        );
+        assert_eq!(last_assistant_message(SessionSource::Codex, &content), None);
+    }
+
+    #[test]
+    fn transcript_entries_keep_order_and_tool_names() {
+        let claude = [
+            r#"{"type":"user","timestamp":"2026-01-01T00:00:00Z","message":{"content":"Fix the redirect"}}"#,
+            r#"{"type":"assistant","message":{"content":[{"type":"text","text":"Editing"},{"type":"tool_use","name":"Edit","input":{"file_path":"a.rs"}}]}}"#,
+        ]
+        .join("\n");
+        let entries = transcript_entries(SessionSource::Claude, &claude);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].role, "user");
+        assert_eq!(
+            entries[0].timestamp.as_deref(),
+            Some("2026-01-01T00:00:00Z")
+        );
+        assert!(entries[0].tools.is_empty());
+        assert_eq!(entries[1].role, "assistant");
+        assert_eq!(entries[1].tools, ["Edit"]);
+
+        let codex = r#"{"type":"response_item","payload":{"type":"function_call","name":"shell","arguments":"{\"cmd\":\"ls\"}"}}"#;
+        let entries = transcript_entries(SessionSource::Codex, codex);
+        assert_eq!(entries[0].role, "tool");
+        assert_eq!(entries[0].tools, ["shell"]);
+
+        assert_eq!(entries_files(SessionSource::Claude, &claude), ["a.rs"]);
+        let patch = r#"{"type":"response_item","payload":{"type":"custom_tool_call","name":"apply_patch","input":"*** Begin Patch\n*** Update File: src/a.rs\n@@\n*** Add File: src/b.rs\n*** End Patch"}}"#;
+        assert_eq!(
+            entries_files(SessionSource::Codex, patch),
+            ["src/a.rs", "src/b.rs"]
+        );
+        let shell = r#"{"type":"response_item","payload":{"type":"function_call","name":"shell","arguments":"{\"command\":[\"apply_patch\",\"*** Begin Patch\\n*** Delete File: old.rs\\n*** End Patch\"]}"}}"#;
+        assert_eq!(entries_files(SessionSource::Codex, shell), ["old.rs"]);
+    }
+
+    fn entries_files(source: SessionSource, content: &str) -> Vec<String> {
+        transcript_entries(source, content)
+            .into_iter()
+            .flat_map(|entry| entry.files)
+            .collect()
+    }
+}
@@ -0,0 +1,445 @@
+//! Streaming reader over agent session transcripts.
+//!
+//! [`transcript_entries`](crate::session_search::transcript_entries) decodes
+//! a transcript held in memory, which for a multi-hundred-megabyte Claude
+//! Code session means loading all of it at once. [`TranscriptStream`] reads
+//! line by line from a byte offset instead: memory is bounded by one line
+//! ([`MAX_LINE_BYTES`]; longer lines, typically inlined images, are skipped)
+//! and by the `max_entries` of one [`TranscriptStream::read_available`]
+//! call. Entries can be limited to a [`TimeRange`], and every entry carries
+//! the offset just past its line so a consumer can resume from any entry.
+//!
+//! Following a live session works like
+//! [`LogStreamReader`](crate::logging::LogStreamReader): only complete
+//! lines are consumed, so a line still being written is picked up by the
+//! next call, and a file that shrank below the offset is read again from
+//! the start and reported by [`TranscriptChunk::reset`].
+//! [`TranscriptStream::tail`] starts at the current end of the file, so
+//! only messages appended afterwards are returned.
+
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{self, BufRead, BufReader, Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::session_search::{transcript_entry, SessionSource, TranscriptEntry};
+
+/// Longest transcript line decoded; longer lines are skipped unread.
+pub const MAX_LINE_BYTES: usize = 4 * 1024 * 1024;
+
+/// Entries read per step by [`TranscriptStream::into_entries`].
+const ITER_CHUNK_ENTRIES: usize = 256;
+
+/// Inclusive time window over message timestamps. An unbounded range keeps
+/// every message; a bounded one drops messages without a timestamp.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TimeRange {
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl TimeRange {
+    pub fn is_unbounded(&self) -> bool {
+        self.since.is_none() && self.until.is_none()
+    }
+
+    /// Whether a message with the RFC 3339 `timestamp` falls in the range.
+    pub fn contains(&self, timestamp: Option<&str>) -> bool {
+        if self.is_unbounded() {
+            return true;
+        }
+        let Some(time) = timestamp
+            .and_then(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+            .map(|time| time.with_timezone(&Utc))
+        else {
+            return false;
+        };
+        self.since.is_none_or(|since| time >= since) && self.until.is_none_or(|until| time <= until)
+    }
+
+    /// Whether a message at `timestamp` is past the end of the range.
+    fn is_past(&self, timestamp: Option<&str>) -> bool {
+        let Some(until) = self.until else {
+            return false;
+        };
+        timestamp
+            .and_then(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+            .is_some_and(|time| time.with_timezone(&Utc) > until)
+    }
+}
+
+/// One message of a [`TranscriptChunk`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptStreamEntry {
+    /// Byte offset just past this message's line; reading on from it
+    /// resumes after this entry.
+    pub offset: u64,
+    pub entry: TranscriptEntry,
+}
+
+/// Result of one [`TranscriptStream::read_available`] call.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TranscriptChunk {
+    /// The stream started over at the beginning of the file before reading
+    /// this chunk; entries read earlier are superseded.
+    pub reset: bool,
+    /// Messages in source order.
+    pub entries: Vec<TranscriptStreamEntry>,
+    /// Lines in this chunk that were not JSON or exceeded [`MAX_LINE_BYTES`].
+    pub skipped: usize,
+    /// Offset the next read continues from.
+    pub offset: u64,
+    /// Every complete line has been read. `false` when the call stopped at
+    /// its `max_entries` and more can be read right away.
+    pub at_end: bool,
+}
+
+/// Offset-tracking reader over one Claude Code or Codex transcript.
+#[derive(Debug, Clone)]
+pub struct TranscriptStream {
+    source: SessionSource,
+    path: PathBuf,
+    offset: u64,
+    range: TimeRange,
+}
+
+impl TranscriptStream {
+    /// Read `path` from byte `offset` on. An offset inside a line starts at
+    /// the next line.
+    pub fn open(source: SessionSource, path: impl Into<PathBuf>, offset: u64) -> Self {
+        Self {
+            source,
+            path: path.into(),
+            offset,
+            range: TimeRange::default(),
+        }
+    }
+
+    /// Read only what is appended to `path` from now on.
+    pub fn tail(source: SessionSource, path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let offset = match std::fs::metadata(&path) {
+            Ok(metadata) => metadata.len(),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => 0,
+            Err(error) => return Err(error),
+        };
+        Ok(Self::open(source, path, offset))
+    }
+
+    /// Keep only messages inside `range`.
+    pub fn with_range(mut self, range: TimeRange) -> Self {
+        self.range = range;
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Offset the next read continues from.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Decode up to `max_entries` messages appended since the previous
+    /// call. A missing file yields an empty chunk (the agent has not
+    /// written its first line yet).
+    pub fn read_available(&mut self, max_entries: usize) -> io::Result<TranscriptChunk> {
+        let mut chunk = TranscriptChunk {
+            at_end: true,
+            ..TranscriptChunk::default()
+        };
+        let mut file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                chunk.offset = self.offset;
+                return Ok(chunk);
+            }
+            Err(error) => return Err(error),
+        };
+        if file.metadata()?.len() < self.offset {
+            // The file shrank below the offset: it was replaced.
+            self.offset = 0;
+            chunk.reset = true;
+        }
+        let resume_mid_line = self.offset > 0 && !byte_before_is_newline(&mut file, self.offset)?;
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut reader = BufReader::new(file);
+        let mut line = Vec::new();
+
+        if resume_mid_line {
+            match read_line_bounded(&mut reader, &mut line)? {
+                Line::Complete { len, .. } => self.offset += len,
+                Line::Incomplete => {
+                    chunk.offset = self.offset;
+                    return Ok(chunk);
+                }
+            }
+        }
+        while chunk.entries.len() < max_entries {
+            let (len, oversized) = match read_line_bounded(&mut reader, &mut line)? {
+                Line::Complete { len, oversized } => (len, oversized),
+                Line::Incomplete => break,
+            };
+            self.offset += len;
+            if oversized {
+                chunk.skipped += 1;
+                continue;
+            }
+            let text = String::from_utf8_lossy(&line);
+            let text = text.trim();
+            if text.is_empty() {
+                continue;
+            }
+            let Ok(value) = serde_json::from_str::<Value>(text) else {
+                chunk.skipped += 1;
+                continue;
+            };
+            let Some(entry) = transcript_entry(self.source, &value) else {
+                continue;
+            };
+            if self.range.contains(entry.timestamp.as_deref()) {
+                chunk.entries.push(TranscriptStreamEntry {
+                    offset: self.offset,
+                    entry,
+                });
+            }
+        }
+        if chunk.entries.len() >= max_entries {
+            chunk.at_end = reader.fill_buf()?.is_empty();
+        }
+        chunk.offset = self.offset;
+        Ok(chunk)
+    }
+
+    /// Every message from the offset to the current end of the file, read
+    /// in bounded steps. Reading stops early at the first message past the
+    /// end of the range.
+    pub fn into_entries(self) -> TranscriptEntries {
+        TranscriptEntries {
+            stream: self,
+            pending: VecDeque::new(),
+            done: false,
+        }
+    }
+}
+
+/// Iterator returned by [`TranscriptStream::into_entries`].
+#[derive(Debug)]
+pub struct TranscriptEntries {
+    stream: TranscriptStream,
+    pending: VecDeque<TranscriptStreamEntry>,
+    done: bool,
+}
+
+impl Iterator for TranscriptEntries {
+    type Item = io::Result<TranscriptStreamEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.pending.pop_front() {
+                return Some(Ok(entry));
+            }
+            if self.done {
+                return None;
+            }
+            // Read unfiltered so a message past the range ends the walk.
+            let range = std::mem::take(&mut self.stream.range);
+            let chunk = self.stream.read_available(ITER_CHUNK_ENTRIES);
+            self.stream.range = range;
+            let chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(error));
+                }
+            };
+            self.done = chunk.at_end;
+            for entry in chunk.entries {
+                let timestamp = entry.entry.timestamp.as_deref();
+                if range.is_past(timestamp) {
+                    self.done = true;
+                    break;
+                }
+                if range.contains(timestamp) {
+                    self.pending.push_back(entry);
+                }
+            }
+        }
+    }
+}
+
+enum Line {
+    /// A line ending in `\n`, `len` bytes long including it. When
+    /// `oversized`, the buffer holds nothing.
+    Complete { len: u64, oversized: bool },
+    /// End of file before the next newline.
+    Incomplete,
+}
+
+/// Read one `\n`-terminated line into `line`, keeping at most
+/// [`MAX_LINE_BYTES`] of it in memory.
+fn read_line_bounded(reader: &mut impl BufRead, line: &mut Vec<u8>) -> io::Result<Line> {
+    line.clear();
+    let mut len = 0_u64;
+    let mut oversized = false;
+    loop {
+        let available = reader.fill_buf()?;
+        if available.is_empty() {
+            return Ok(Line::Incomplete);
+        }
+        let (part, complete) = match available.iter().position(|byte| *byte == b'\n') {
+            Some(index) => (&available[..=index], true),
+            None => (available, false),
+        };
+        let part_len = part.len();
+        if !oversized && line.len() + part_len > MAX_LINE_BYTES {
+            oversized = true;
+            line.clear();
+            line.shrink_to_fit();
+        }
+        if !oversized {
+            line.extend_from_slice(part);
+        }
+        reader.consume(part_len);
+        len += part_len as u64;
+        if complete {
+            return Ok(Line::Complete { len, oversized });
+        }
+    }
+}
+
+fn byte_before_is_newline(file: &mut File, offset: u64) -> io::Result<bool> {
+    file.seek(SeekFrom::Start(offset - 1))?;
+    let mut byte = [0_u8; 1];
+    file.read_exact(&mut byte)?;
+    Ok(byte[0] == b'\n')
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use tempfile::tempdir;
+
+    use super::*;
+
+    fn claude_line(role: &str, text: &str, timestamp: &str) -> String {
+        format!(
+            r#"{{"type":"{role}","timestamp":"{timestamp}","message":{{"role":"{role}","content":"{text}"}}}}"#
+        ) + "\n"
+    }
+
+    fn append(path: &Path, text: &str) {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .unwrap();
+        file.write_all(text.as_bytes()).unwrap();
+    }
+
+    fn texts(chunk: &TranscriptChunk) -> Vec<&str> {
+        chunk
+            .entries
+            .iter()
+            .map(|entry| entry.entry.text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn reads_complete_lines_in_bounded_steps_and_follows_appends() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("session.jsonl");
+        append(&path, &claude_line("user", "one", "2026-05-20T09:00:00Z"));
+        append(&path, "not json\n");
+        append(
+            &path,
+            &claude_line("assistant", "two", "2026-05-20T09:00:01Z"),
+        );
+        append(&path, &claude_line("user", "three", "2026-05-20T09:00:02Z"));
+
+        let mut stream = TranscriptStream::open(SessionSource::Claude, &path, 0);
+        let first = stream.read_available(2).unwrap();
+        assert_eq!(texts(&first), ["one", "two"]);
+        assert_eq!(first.skipped, 1);
+        assert!(!first.at_end);
+        let second = stream.read_available(2).unwrap();
+        assert_eq!(texts(&second), ["three"]);
+        assert!(second.at_end);
+
+        // A line still being written waits for its newline.
+        let partial = claude_line("assistant", "four", "2026-05-20T09:00:03Z");
+        let (head, rest) = partial.split_at(20);
+        append(&path, head);
+        assert!(stream.read_available(10).unwrap().entries.is_empty());
+        append(&path, rest);
+        let appended = stream.read_available(10).unwrap();
+        assert_eq!(texts(&appended), ["four"]);
+
+        // Resuming from an entry's offset continues after it.
+        let resumed = TranscriptStream::open(SessionSource::Claude, &path, first.entries[1].offset)
+            .read_available(10)
+            .unwrap();
+        assert_eq!(texts(&resumed), ["three", "four"]);
+
+        // A replaced, shorter file starts over.
+        std::fs::write(&path, claude_line("user", "new", "2026-05-21T00:00:00Z")).unwrap();
+        let replaced = stream.read_available(10).unwrap();
+        assert!(replaced.reset);
+        assert_eq!(texts(&replaced), ["new"]);
+    }
+
+    #[test]
+    fn tail_skips_existing_lines_and_ranges_filter_messages() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("session.jsonl");
+        append(&path, &claude_line("user", "old", "2026-05-20T09:00:00Z"));
+        let mut tail = TranscriptStream::tail(SessionSource::Claude, &path).unwrap();
+        assert!(tail.read_available(10).unwrap().entries.is_empty());
+        append(
+            &path,
+            &claude_line("assistant", "live", "2026-05-20T10:00:00Z"),
+        );
+        append(&path, &claude_line("user", "later", "2026-05-20T11:00:00Z"));
+        assert_eq!(texts(&tail.read_available(10).unwrap()), ["live", "later"]);
+
+        let range = TimeRange {
+            since: Some("2026-05-20T09:30:00Z".parse().unwrap()),
+            until: Some("2026-05-20T10:30:00Z".parse().unwrap()),
+        };
+        let entries: Vec<String> = TranscriptStream::open(SessionSource::Claude, &path, 0)
+            .with_range(range)
+            .into_entries()
+            .map(|entry| entry.unwrap().entry.text)
+            .collect();
+        assert_eq!(entries, ["live"]);
+        assert!(!range.contains(None));
+        assert!(TimeRange::default().contains(None));
+    }
+
+    #[test]
+    fn oversized_lines_are_skipped_without_buffering() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("session.jsonl");
+        let huge = format!(
+            r#"{{"type":"user","message":{{"role":"user","content":"{}"}}}}"#,
+            "x".repeat(MAX_LINE_BYTES)
+        ) + "\n";
+        append(&path, &huge);
+        append(
+            &path,
+            &claude_line("assistant", "after", "2026-05-20T09:00:00Z"),
+        );
+        let chunk = TranscriptStream::open(SessionSource::Claude, &path, 0)
+            .read_available(10)
+            .unwrap();
+        assert_eq!(chunk.skipped, 1);
+        assert_eq!(texts(&chunk), ["after"]);
+    }
+}
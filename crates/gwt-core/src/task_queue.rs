@@ -0,0 +1,489 @@
+//! Agent tasks queued for headless runs.
+//!
+//! CI enqueues work such as "fix the failing test on branch X" through the
+//! embedded server's `/api/tasks`, and `gwt run` enqueues a job and runs it
+//! itself (see [`claim`]). Each task is a record under
+//! `~/.gwt/tasks/<id>.json` plus the agent output in `<id>.log`. A gwt
+//! instance with `[server] task_queue` enabled claims queued tasks one at a
+//! time, runs the agent in the branch worktree, and records the outcome.
+//!
+//! Claiming and liveness follow [`crate::operation_progress`]: the worker
+//! holds an exclusive lock on `<id>.lock` while the task runs, so two
+//! instances never run the same task and a `Running` record whose lock can
+//! be taken belongs to a process that is gone ([`TaskState::Interrupted`]).
+//!
+//! Scheduling on top of this store (parallel limits, retries, lifecycle
+//! events) lives in [`crate::jobs`].
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{GwtError, Result};
+
+/// Finished records and logs older than this are deleted on enqueue.
+pub const FINISHED_RETENTION_DAYS: i64 = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskState {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+    /// The worker process exited while the task was running.
+    Interrupted,
+}
+
+impl TaskState {
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Completed | Self::Failed | Self::Interrupted)
+    }
+}
+
+/// What the caller asks for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskRequest {
+    pub repo_path: PathBuf,
+    pub branch: String,
+    /// Agent command name, e.g. `claude` or `codex`.
+    pub agent: String,
+    pub prompt: String,
+}
+
+/// One task as stored on disk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskRecord {
+    pub id: String,
+    #[serde(flatten)]
+    pub request: TaskRequest,
+    pub state: TaskState,
+    /// Worktree the agent ran in, once resolved.
+    #[serde(default)]
+    pub worktree: Option<PathBuf>,
+    #[serde(default)]
+    pub exit_code: Option<i32>,
+    /// Error summary for failed tasks.
+    #[serde(default)]
+    pub message: Option<String>,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub started_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Runs started so far, including the current one.
+    #[serde(default)]
+    pub attempts: u32,
+    /// A requeued task is not claimed by [`claim_next`] before this time.
+    #[serde(default)]
+    pub retry_at: Option<DateTime<Utc>>,
+}
+
+/// Queue a task. Also prunes finished tasks older than
+/// [`FINISHED_RETENTION_DAYS`].
+pub fn enqueue(dir: &Path, request: TaskRequest) -> Result<TaskRecord> {
+    fs::create_dir_all(dir)?;
+    prune_finished(dir, Utc::now() - Duration::days(FINISHED_RETENTION_DAYS));
+    let record = TaskRecord {
+        id: uuid::Uuid::new_v4().simple().to_string(),
+        request,
+        state: TaskState::Queued,
+        worktree: None,
+        exit_code: None,
+        message: None,
+        created_at: Utc::now(),
+        started_at: None,
+        finished_at: None,
+        attempts: 0,
+        retry_at: None,
+    };
+    write_record(dir, &record)?;
+    Ok(record)
+}
+
+/// All tasks in `dir`, newest first.
+pub fn list_tasks(dir: &Path) -> Vec<TaskRecord> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut records: Vec<TaskRecord> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let id = path
+                .file_name()?
+                .to_str()?
+                .strip_suffix(".json")?
+                .to_string();
+            load_task(dir, &id)
+        })
+        .collect();
+    records.sort_by_key(|record| std::cmp::Reverse(record.created_at));
+    records
+}
+
+/// One task by id, with liveness resolved.
+pub fn load_task(dir: &Path, id: &str) -> Option<TaskRecord> {
+    if !is_valid_id(id) {
+        return None;
+    }
+    let raw = fs::read(record_path(dir, id)).ok()?;
+    let mut record: TaskRecord = serde_json::from_slice(&raw).ok()?;
+    if record.state == TaskState::Running && !owner_alive(dir, id) {
+        record.state = TaskState::Interrupted;
+    }
+    Some(record)
+}
+
+/// Agent output of a task from byte `offset` on, for incremental polling.
+/// `None` when the task does not exist.
+pub fn read_log(dir: &Path, id: &str, offset: u64) -> Option<String> {
+    load_task(dir, id)?;
+    let mut file = match File::open(log_path(dir, id)) {
+        Ok(file) => file,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Some(String::new()),
+        Err(_) => return None,
+    };
+    file.seek(SeekFrom::Start(offset)).ok()?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes).ok()?;
+    Some(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Claim the oldest queued task whose retry time has come, or `None` when
+/// nothing is ready.
+pub fn claim_next(dir: &Path) -> Result<Option<TaskClaim>> {
+    let now = Utc::now();
+    let mut queued: Vec<TaskRecord> = list_tasks(dir)
+        .into_iter()
+        .filter(|record| record.state == TaskState::Queued)
+        .filter(|record| record.retry_at.is_none_or(|at| at <= now))
+        .collect();
+    queued.sort_by_key(|record| record.created_at);
+    for candidate in queued {
+        if let Some(claim) = claim(dir, &candidate.id)? {
+            return Ok(Some(claim));
+        }
+    }
+    Ok(None)
+}
+
+/// Claim one specific task, or `None` when it does not exist, is no longer
+/// queued, or another worker holds it.
+pub fn claim(dir: &Path, id: &str) -> Result<Option<TaskClaim>> {
+    if !is_valid_id(id) || !record_path(dir, id).is_file() {
+        return Ok(None);
+    }
+    let lock = open_lock_file(&lock_path(dir, id))?;
+    if fs2::FileExt::try_lock_exclusive(&lock).is_err() {
+        return Ok(None);
+    }
+    // Another instance may have claimed and finished it meanwhile.
+    let Some(mut record) = load_task(dir, id) else {
+        let _ = fs2::FileExt::unlock(&lock);
+        return Ok(None);
+    };
+    if record.state != TaskState::Queued {
+        let _ = fs2::FileExt::unlock(&lock);
+        return Ok(None);
+    }
+    crate::lock::record_owner(&lock, &format!("task {id}"))?;
+    record.state = TaskState::Running;
+    record.started_at = Some(Utc::now());
+    record.attempts += 1;
+    record.retry_at = None;
+    write_record(dir, &record)?;
+    Ok(Some(TaskClaim {
+        dir: dir.to_path_buf(),
+        record,
+        lock: Some(lock),
+    }))
+}
+
+/// Worker side of a running task. Dropping it without
+/// [`TaskClaim::finish`] records the task as failed.
+pub struct TaskClaim {
+    dir: PathBuf,
+    record: TaskRecord,
+    lock: Option<File>,
+}
+
+impl TaskClaim {
+    pub fn record(&self) -> &TaskRecord {
+        &self.record
+    }
+
+    /// File the agent output goes to.
+    pub fn log_path(&self) -> PathBuf {
+        log_path(&self.dir, &self.record.id)
+    }
+
+    /// Persist the worktree the task runs in.
+    pub fn set_worktree(&mut self, worktree: &Path) {
+        self.record.worktree = Some(worktree.to_path_buf());
+        self.persist();
+    }
+
+    /// Record the outcome and release the lock. A zero exit code completes
+    /// the task; anything else, or no exit code at all, fails it.
+    pub fn finish(mut self, exit_code: Option<i32>, message: Option<String>) {
+        self.record.state = if exit_code == Some(0) {
+            TaskState::Completed
+        } else {
+            TaskState::Failed
+        };
+        self.record.exit_code = exit_code;
+        self.record.message = message;
+        self.record.finished_at = Some(Utc::now());
+        self.persist();
+    }
+
+    fn persist(&self) {
+        if let Err(error) = write_record(&self.dir, &self.record) {
+            tracing::warn!(
+                target: "gwt_core::task_queue",
+                id = %self.record.id,
+                error = %error,
+                "task record write failed"
+            );
+        }
+    }
+}
+
+impl Drop for TaskClaim {
+    fn drop(&mut self) {
+        if self.record.state == TaskState::Running {
+            self.record.state = TaskState::Failed;
+            self.record.message = Some("task ended without a result".to_string());
+            self.record.finished_at = Some(Utc::now());
+            self.persist();
+        }
+        if let Some(lock) = self.lock.take() {
+            let _ = fs2::FileExt::unlock(&lock);
+        }
+        let _ = fs::remove_file(lock_path(&self.dir, &self.record.id));
+    }
+}
+
+/// Put a failed task back in the queue, claimable from `retry_at` on. The
+/// last error message is kept until the next run finishes. `None` when the
+/// task does not exist, did not fail, or is held by a worker.
+pub fn requeue(dir: &Path, id: &str, retry_at: DateTime<Utc>) -> Result<Option<TaskRecord>> {
+    if !is_valid_id(id) || !record_path(dir, id).is_file() {
+        return Ok(None);
+    }
+    let path = lock_path(dir, id);
+    let lock = open_lock_file(&path)?;
+    if fs2::FileExt::try_lock_exclusive(&lock).is_err() {
+        return Ok(None);
+    }
+    let requeued = match load_task(dir, id) {
+        Some(mut record) if record.state == TaskState::Failed => {
+            record.state = TaskState::Queued;
+            record.retry_at = Some(retry_at);
+            record.exit_code = None;
+            record.finished_at = None;
+            write_record(dir, &record).map(|()| Some(record))
+        }
+        _ => Ok(None),
+    };
+    let _ = fs2::FileExt::unlock(&lock);
+    let _ = fs::remove_file(&path);
+    requeued
+}
+
+fn prune_finished(dir: &Path, cutoff: DateTime<Utc>) {
+    for record in list_tasks(dir) {
+        let finished_at = record.finished_at.unwrap_or(record.created_at);
+        if record.state.is_finished() && finished_at < cutoff {
+            let _ = fs::remove_file(record_path(dir, &record.id));
+            let _ = fs::remove_file(log_path(dir, &record.id));
+        }
+    }
+}
+
+fn owner_alive(dir: &Path, id: &str) -> bool {
+    let path = lock_path(dir, id);
+    if !path.exists() {
+        return false;
+    }
+    let Ok(file) = open_lock_file(&path) else {
+        return false;
+    };
+    match fs2::FileExt::try_lock_exclusive(&file) {
+        Ok(()) => {
+            let _ = fs2::FileExt::unlock(&file);
+            false
+        }
+        // A lock kept by a dead owner's leaked descriptor does not keep
+        // the task running.
+        Err(_) => !crate::lock::read_owner(&path).is_some_and(|owner| owner.is_dead()),
+    }
+}
+
+/// Ids are generated here; anything else (e.g. from a URL) is rejected
+/// before it is joined onto `dir`.
+fn is_valid_id(id: &str) -> bool {
+    !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn record_path(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("{id}.json"))
+}
+
+fn log_path(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("{id}.log"))
+}
+
+fn lock_path(dir: &Path, id: &str) -> PathBuf {
+    dir.join(format!("{id}.lock"))
+}
+
+fn open_lock_file(path: &Path) -> io::Result<File> {
+    OpenOptions::new()
+        .create(true)
+        .read(true)
+        .write(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Atomic publish so readers in other processes never see a torn record.
+fn write_record(dir: &Path, record: &TaskRecord) -> Result<()> {
+    let path = record_path(dir, &record.id);
+    let tmp = dir.join(format!(".{}.tmp-{}", record.id, std::process::id()));
+    let payload =
+        serde_json::to_vec_pretty(record).map_err(|error| GwtError::Other(error.to_string()))?;
+    fs::write(&tmp, payload)?;
+    fs::rename(&tmp, &path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(branch: &str) -> TaskRequest {
+        TaskRequest {
+            repo_path: PathBuf::from("/repo"),
+            branch: branch.to_string(),
+            agent: "claude".to_string(),
+            prompt: "fix the failing test".to_string(),
+        }
+    }
+
+    #[test]
+    fn claim_runs_oldest_queued_task_once() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let first = enqueue(dir.path(), request("feature/a")).expect("enqueue");
+        let second = enqueue(dir.path(), request("feature/b")).expect("enqueue");
+
+        let mut claim = claim_next(dir.path()).expect("claim").expect("task");
+        assert_eq!(claim.record().id, first.id);
+        claim.set_worktree(Path::new("/repo/feature-a"));
+        let running = load_task(dir.path(), &first.id).expect("record");
+        assert_eq!(running.state, TaskState::Running);
+        assert_eq!(
+            running.worktree.as_deref(),
+            Some(Path::new("/repo/feature-a"))
+        );
+
+        let next = claim_next(dir.path()).expect("claim").expect("task");
+        assert_eq!(next.record().id, second.id);
+        assert!(claim_next(dir.path()).expect("claim").is_none());
+
+        fs::write(claim.log_path(), "line 1\nline 2\n").expect("log");
+        claim.finish(Some(0), None);
+        next.finish(Some(1), Some("tests still fail".to_string()));
+
+        let done = load_task(dir.path(), &first.id).expect("record");
+        assert_eq!(done.state, TaskState::Completed);
+        assert_eq!(done.exit_code, Some(0));
+        assert_eq!(
+            read_log(dir.path(), &first.id, 7).as_deref(),
+            Some("line 2\n")
+        );
+        assert_eq!(
+            load_task(dir.path(), &second.id).expect("record").state,
+            TaskState::Failed
+        );
+        assert_eq!(read_log(dir.path(), "missing", 0), None);
+    }
+
+    #[test]
+    fn running_task_without_live_worker_is_interrupted() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let mut orphan = enqueue(dir.path(), request("feature/a")).expect("enqueue");
+        orphan.state = TaskState::Running;
+        write_record(dir.path(), &orphan).expect("write");
+
+        let listed = list_tasks(dir.path());
+        assert_eq!(listed[0].state, TaskState::Interrupted);
+        assert!(claim_next(dir.path()).expect("claim").is_none());
+    }
+
+    #[test]
+    fn claim_by_id_takes_only_that_queued_task() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let first = enqueue(dir.path(), request("feature/a")).expect("enqueue");
+        let second = enqueue(dir.path(), request("feature/b")).expect("enqueue");
+
+        let claimed = claim(dir.path(), &second.id).expect("claim").expect("task");
+        assert_eq!(claimed.record().id, second.id);
+        assert!(claim(dir.path(), &second.id).expect("claim").is_none());
+        assert!(claim(dir.path(), "missing").expect("claim").is_none());
+        assert!(!lock_path(dir.path(), "missing").exists());
+        assert_eq!(
+            claim_next(dir.path())
+                .expect("claim")
+                .expect("task")
+                .record()
+                .id,
+            first.id
+        );
+        claimed.finish(Some(0), None);
+    }
+
+    #[test]
+    fn requeued_task_waits_for_retry_time() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let task = enqueue(dir.path(), request("feature/a")).expect("enqueue");
+        let claimed = claim_next(dir.path()).expect("claim").expect("task");
+        assert_eq!(claimed.record().attempts, 1);
+        assert!(requeue(dir.path(), &task.id, Utc::now())
+            .expect("requeue")
+            .is_none());
+        claimed.finish(Some(1), Some("tests fail".to_string()));
+
+        let later = Utc::now() + Duration::hours(1);
+        let requeued = requeue(dir.path(), &task.id, later)
+            .expect("requeue")
+            .expect("failed task");
+        assert_eq!(requeued.state, TaskState::Queued);
+        assert_eq!(requeued.message.as_deref(), Some("tests fail"));
+        assert!(claim_next(dir.path()).expect("claim").is_none());
+
+        let mut due = requeued;
+        due.retry_at = Some(Utc::now() - Duration::seconds(1));
+        write_record(dir.path(), &due).expect("write");
+        let again = claim_next(dir.path()).expect("claim").expect("task");
+        assert_eq!(again.record().attempts, 2);
+        assert_eq!(again.record().retry_at, None);
+        again.finish(Some(0), None);
+    }
+
+    #[test]
+    fn dropped_claim_records_failure() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let task = enqueue(dir.path(), request("feature/a")).expect("enqueue");
+        drop(claim_next(dir.path()).expect("claim").expect("task"));
+        let record = load_task(dir.path(), &task.id).expect("record");
+        assert_eq!(record.state, TaskState::Failed);
+        assert!(!lock_path(dir.path(), &task.id).exists());
+    }
+}
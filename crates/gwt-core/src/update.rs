@@ -1,12 +1,14 @@
 //! Self-update support via GitHub Releases.
 //!
 //! This module implements:
-//! - Update discovery via GitHub Releases (latest)
+//! - Update discovery via GitHub Releases, per release channel (stable / beta / nightly)
 //! - TTL-based local cache to avoid repeated API calls
 //! - User-approved apply flow:
-//!   - Portable payload (tar.gz/zip) => extract and replace the running executable, then restart
+//!   - Portable payload (tar.gz/zip) => extract and replace the running executable, then restart;
+//!     a delta bundle against the installed release ([`delta`]) is tried first
 //!   - Installer payload (.dmg/.pkg/.msi) => run installer with privileges/UAC, then restart
 //! - Internal helper modes (`__internal`) to safely apply updates after the parent process exits
+//! - Rollback to the previous binary the portable apply flow keeps next to the new one
 
 use crate::process_executor::{
     ExecutionMode, ProcessExecutor, ProcessRequest, SystemProcessExecutor,
@@ -23,6 +25,8 @@ use std::io;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+pub mod delta;
+
 pub fn is_ci() -> bool {
     std::env::var("CI")
         .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
@@ -37,6 +41,60 @@ const DOCKER_LINUX_PRIMARY_BINARY_NAME: &str = "gwt";
 
 const CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
 const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Releases listed when a pre-release channel looks for its newest release.
+const CHANNEL_RELEASES_PER_PAGE: u32 = 30;
+
+/// Which releases an update check considers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateChannel {
+    /// Published, non-pre-release versions only.
+    #[default]
+    Stable,
+    /// Stable releases plus `-beta.N` and `-rc.N` pre-releases.
+    Beta,
+    /// Every published release, including nightly builds.
+    Nightly,
+}
+
+impl UpdateChannel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Stable => "stable",
+            Self::Beta => "beta",
+            Self::Nightly => "nightly",
+        }
+    }
+
+    /// Whether a release of `version` belongs to this channel.
+    fn accepts(self, version: &Version, prerelease: bool) -> bool {
+        let is_stable = !prerelease && version.pre.is_empty();
+        match self {
+            Self::Stable => is_stable,
+            Self::Beta => {
+                is_stable
+                    || version.pre.as_str().starts_with("beta")
+                    || version.pre.as_str().starts_with("rc")
+            }
+            Self::Nightly => true,
+        }
+    }
+}
+
+impl std::str::FromStr for UpdateChannel {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "stable" => Ok(Self::Stable),
+            "beta" => Ok(Self::Beta),
+            "nightly" => Ok(Self::Nightly),
+            other => Err(format!(
+                "unknown update channel `{other}`; expected stable, beta or nightly"
+            )),
+        }
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct UpdateCacheFile {
@@ -50,6 +108,9 @@ struct UpdateCacheFile {
     /// Legacy cache field (used by older versions).
     #[serde(default)]
     asset_url: Option<String>,
+    /// Channel the cached check ran against; older caches are stable.
+    #[serde(default)]
+    channel: UpdateChannel,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -97,6 +158,10 @@ struct GitHubRelease {
     tag_name: String,
     html_url: String,
     #[serde(default)]
+    prerelease: bool,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
     body: Option<String>,
     #[serde(default)]
     assets: Vec<GitHubAsset>,
@@ -374,6 +439,7 @@ pub struct UpdateManager {
     repo: String,
     ttl: Duration,
     api_base_url: String,
+    channel: UpdateChannel,
     cache_path: PathBuf,
     updates_dir: PathBuf,
     client: Client,
@@ -428,12 +494,23 @@ impl UpdateManager {
             repo: DEFAULT_REPO.to_string(),
             ttl: DEFAULT_TTL,
             api_base_url,
+            channel: UpdateChannel::default(),
             cache_path,
             updates_dir,
             client,
         }
     }
 
+    /// Follow `channel` instead of the stable releases.
+    pub fn with_channel(mut self, channel: UpdateChannel) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    pub fn channel(&self) -> UpdateChannel {
+        self.channel
+    }
+
     #[cfg(test)]
     fn with_api_base_url(mut self, api_base_url: impl Into<String>) -> Self {
         self.api_base_url = api_base_url.into();
@@ -458,7 +535,10 @@ impl UpdateManager {
 
     pub fn check_for_executable(&self, force: bool, current_exe: Option<&Path>) -> UpdateState {
         let now = Utc::now();
-        let cache = read_cache(&self.cache_path).ok();
+        // A cache written for another channel says nothing about this one.
+        let cache = read_cache(&self.cache_path)
+            .ok()
+            .filter(|cache| cache.channel == self.channel);
 
         if !force {
             if let Some(cache) = &cache {
@@ -512,6 +592,7 @@ impl UpdateManager {
                     portable_asset_url,
                     installer_asset_url,
                     asset_url: asset_url.clone(),
+                    channel: self.channel,
                 };
                 let _ = write_cache(&self.cache_path, &cache_file);
 
@@ -568,6 +649,17 @@ impl UpdateManager {
         let asset_name = asset_name_from_url(asset_url).unwrap_or_else(|| "gwt-update".to_string());
         let dest = update_dir.join(&asset_name);
 
+        // Deltas are much smaller than the archive; any failure falls back
+        // to the full download below.
+        match self.try_prepare_delta(latest, asset_url, &asset_name, &update_dir, progress) {
+            Ok(Some(path)) => return Ok(PreparedPayload::PortableBinary { path }),
+            Ok(None) => {}
+            Err(reason) => log_update_event(
+                "delta_fallback",
+                &[("version", latest), ("reason", reason.as_str())],
+            ),
+        }
+
         self.download_asset_with_progress(asset_url, &dest, progress)?;
 
         let dest_str = dest.to_string_lossy().to_string();
@@ -612,6 +704,69 @@ impl UpdateManager {
         Ok(PreparedPayload::PortableBinary { path: dest })
     }
 
+    /// Patch the installed binaries up to `latest` with the delta bundle
+    /// published next to the portable `asset_url`. `Ok(None)` means no delta
+    /// applies: an installer payload, or a running executable that is not
+    /// an installed gwt bundle.
+    fn try_prepare_delta(
+        &self,
+        latest: &str,
+        asset_url: &str,
+        asset_name: &str,
+        update_dir: &Path,
+        progress: &mut dyn FnMut(u64, Option<u64>),
+    ) -> Result<Option<PathBuf>, String> {
+        let current = self.current_version.to_string();
+        let Some(delta_name) = crate::release_contract::delta_asset_name(asset_name, &current)
+        else {
+            return Ok(None);
+        };
+        let platform = Platform::detect();
+        let binary_name = platform.binary_name();
+        let Some(installed_dir) = std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(Path::to_path_buf))
+        else {
+            return Ok(None);
+        };
+        let bundle_names = crate::release_contract::bundle_binary_names(&platform.os)
+            .unwrap_or_else(|| vec![binary_name.clone(), companion_binary_name(&binary_name)]);
+        if !bundle_names
+            .iter()
+            .all(|name| installed_dir.join(name).is_file())
+        {
+            return Ok(None);
+        }
+
+        let delta_url = format!(
+            "{}{delta_name}",
+            asset_url.strip_suffix(asset_name).unwrap_or(asset_url)
+        );
+        let bundle = update_dir.join(&delta_name);
+        let out_dir = update_dir.join("delta");
+        let _ = fs::remove_dir_all(&out_dir);
+        let result = self
+            .download_asset_with_progress(&delta_url, &bundle, progress)
+            .and_then(|()| {
+                delta::apply_delta_bundle(&bundle, &current, latest, &installed_dir, &out_dir)
+            })
+            .and_then(|_| {
+                for name in &bundle_names {
+                    let path = out_dir.join(name);
+                    if !path.is_file() {
+                        return Err(format!("Delta bundle does not update {name}"));
+                    }
+                    ensure_executable(&path)?;
+                }
+                Ok(out_dir.join(&binary_name))
+            });
+        let _ = fs::remove_file(&bundle);
+        if result.is_err() {
+            let _ = fs::remove_dir_all(&out_dir);
+        }
+        result.map(Some)
+    }
+
     pub fn install_latest_docker_linux_bundle(
         &self,
         target_arch: &str,
@@ -903,13 +1058,38 @@ impl UpdateManager {
     }
 
     fn fetch_latest_release(&self) -> Result<GitHubRelease, String> {
+        if self.channel == UpdateChannel::Stable {
+            let url = format!(
+                "{}/repos/{}/{}/releases/latest",
+                self.api_base_url.trim_end_matches('/'),
+                self.owner,
+                self.repo
+            );
+            return self.fetch_release(&url, "latest release");
+        }
+
+        // `releases/latest` never returns a pre-release, so the other
+        // channels pick the newest matching entry of the release list.
         let url = format!(
-            "{}/repos/{}/{}/releases/latest",
+            "{}/repos/{}/{}/releases?per_page={CHANNEL_RELEASES_PER_PAGE}",
             self.api_base_url.trim_end_matches('/'),
             self.owner,
             self.repo
         );
-        self.fetch_release(&url, "latest release")
+        let label = format!("{} releases", self.channel.as_str());
+        let res = self
+            .client
+            .get(&url)
+            .send()
+            .map_err(|e| format!("Failed to fetch {label}: {e}"))?;
+        if !res.status().is_success() {
+            return Err(format!("Failed to fetch {label}: status {}", res.status()));
+        }
+        let releases = res
+            .json::<Vec<GitHubRelease>>()
+            .map_err(|e| format!("Failed to parse GitHub releases JSON: {e}"))?;
+        newest_channel_release(releases, self.channel)
+            .ok_or_else(|| format!("No release found on the {} channel", self.channel.as_str()))
     }
 
     /// SPEC #2780 v2 Amendment (FR-011): fetch a release by its tag name so the
@@ -1260,6 +1440,23 @@ fn write_json_atomic<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
     Ok(())
 }
 
+fn newest_channel_release(
+    releases: Vec<GitHubRelease>,
+    channel: UpdateChannel,
+) -> Option<GitHubRelease> {
+    releases
+        .into_iter()
+        .filter(|release| !release.draft)
+        .filter_map(|release| {
+            let version = parse_tag_version(&release.tag_name)?;
+            channel
+                .accepts(&version, release.prerelease)
+                .then_some((version, release))
+        })
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, release)| release)
+}
+
 fn parse_tag_version(tag: &str) -> Option<Version> {
     let trimmed = tag.trim();
     let v = trimmed.strip_prefix('v').unwrap_or(trimmed);
@@ -2032,6 +2229,61 @@ fn replace_bundle_executables(target_exe: &Path, source_exe: &Path) -> Result<()
     ])
 }
 
+/// Where a portable update keeps the binary it replaced: `<name>.old` next
+/// to `target_exe`.
+pub fn previous_binary_path(target_exe: &Path) -> Result<PathBuf, String> {
+    let file_name = target_exe
+        .file_name()
+        .and_then(OsStr::to_str)
+        .ok_or_else(|| "Target executable has invalid filename".to_string())?;
+    Ok(target_exe.with_file_name(format!("{file_name}.old")))
+}
+
+/// Swap `target_exe` and its `gwtd` companion back to the binaries the last
+/// portable update kept as `<name>.old`. The replaced binaries become the
+/// new `.old` files, so a second rollback returns to the update.
+///
+/// Installer updates (.dmg/.pkg/.msi) keep no previous binary and cannot be
+/// rolled back this way.
+pub fn rollback_update(target_exe: &Path) -> Result<(), String> {
+    if !previous_binary_path(target_exe)?.is_file() {
+        return Err(format!(
+            "No previous version is kept next to {}",
+            target_exe.display()
+        ));
+    }
+    let mut targets = Vec::with_capacity(2);
+    let companion = companion_binary_path(target_exe)?;
+    if companion.exists() && previous_binary_path(&companion)?.is_file() {
+        targets.push(companion);
+    }
+    targets.push(target_exe.to_path_buf());
+
+    for (idx, target) in targets.iter().enumerate() {
+        if let Err(err) = swap_with_previous(target) {
+            for swapped in targets[..idx].iter().rev() {
+                let _ = swap_with_previous(swapped);
+            }
+            return Err(format!(
+                "Failed to restore the previous {}: {err}",
+                target.display()
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn swap_with_previous(target: &Path) -> io::Result<()> {
+    let previous = previous_binary_path(target).map_err(io::Error::other)?;
+    let parked = previous.with_extension(format!("gwt-rollback-{}.tmp", std::process::id()));
+    fs::rename(target, &parked)?;
+    if let Err(err) = fs::rename(&previous, target) {
+        let _ = fs::rename(&parked, target);
+        return Err(err);
+    }
+    fs::rename(&parked, &previous)
+}
+
 fn replace_executables_with_retry(pairs: &[(&Path, &Path)]) -> Result<(), String> {
     // Windows: file replacement can fail while the parent app is still shutting down.
     const MAX_RETRIES: usize = 200;
@@ -2107,7 +2359,7 @@ fn stage_replacement(target_exe: &Path, source_exe: &Path) -> Result<PlannedRepl
         let _ = fs::set_permissions(&tmp_path, perms);
     }
 
-    let backup_path = target_dir.join(format!("{file_name}.old"));
+    let backup_path = previous_binary_path(target_exe)?;
     let _ = fs::remove_file(&backup_path);
 
     Ok(PlannedReplacement {
@@ -2278,6 +2530,91 @@ mod tests {
         assert_eq!(parse_tag_version("1.2.3"), Some(Version::new(1, 2, 3)));
     }
 
+    #[test]
+    fn channels_pick_the_newest_release_they_accept() {
+        let release = |tag: &str, prerelease: bool, draft: bool| GitHubRelease {
+            tag_name: tag.to_string(),
+            html_url: format!("https://example.com/{tag}"),
+            prerelease,
+            draft,
+            body: None,
+            assets: Vec::new(),
+        };
+        let releases = || {
+            vec![
+                release("v2.1.0-nightly.20261016", true, false),
+                release("v2.1.0-beta.2", true, false),
+                release("v2.2.0-beta.1", true, true),
+                release("v2.0.0", false, false),
+                release("v1.9.0", false, false),
+            ]
+        };
+        let tag = |channel| {
+            newest_channel_release(releases(), channel)
+                .map(|release| release.tag_name)
+                .unwrap()
+        };
+
+        assert_eq!(tag(UpdateChannel::Stable), "v2.0.0");
+        assert_eq!(tag(UpdateChannel::Beta), "v2.1.0-beta.2");
+        assert_eq!(tag(UpdateChannel::Nightly), "v2.1.0-nightly.20261016");
+        assert_eq!("Beta".parse::<UpdateChannel>(), Ok(UpdateChannel::Beta));
+        assert!("canary".parse::<UpdateChannel>().is_err());
+    }
+
+    #[test]
+    fn cache_from_another_channel_is_ignored() {
+        let temp = tempfile::tempdir().unwrap();
+        let mgr = UpdateManager::new()
+            .with_channel(UpdateChannel::Beta)
+            .with_api_base_url("http://127.0.0.1:9")
+            .with_cache_path(temp.path().join("update-check.json"))
+            .with_updates_dir(temp.path().join("updates"));
+        let cache = UpdateCacheFile {
+            checked_at: Utc::now(),
+            latest_version: Some("999.0.0".to_string()),
+            release_url: None,
+            portable_asset_url: None,
+            installer_asset_url: None,
+            asset_url: None,
+            channel: UpdateChannel::Stable,
+        };
+        write_cache(mgr.cache_path(), &cache).unwrap();
+
+        assert!(matches!(mgr.check(false), UpdateState::Failed { .. }));
+    }
+
+    #[test]
+    fn rollback_swaps_in_the_previous_binaries_and_back() {
+        let temp = tempfile::tempdir().unwrap();
+        let exe = temp.path().join("gwt");
+        let daemon = temp.path().join("gwtd");
+        for (path, contents) in [
+            (&exe, "gwt new"),
+            (&daemon, "gwtd new"),
+            (&temp.path().join("gwt.old"), "gwt old"),
+            (&temp.path().join("gwtd.old"), "gwtd old"),
+        ] {
+            fs::write(path, contents).unwrap();
+        }
+
+        rollback_update(&exe).unwrap();
+        assert_eq!(fs::read_to_string(&exe).unwrap(), "gwt old");
+        assert_eq!(fs::read_to_string(&daemon).unwrap(), "gwtd old");
+        assert_eq!(
+            fs::read_to_string(previous_binary_path(&exe).unwrap()).unwrap(),
+            "gwt new"
+        );
+
+        rollback_update(&exe).unwrap();
+        assert_eq!(fs::read_to_string(&exe).unwrap(), "gwt new");
+        assert_eq!(fs::read_dir(temp.path()).unwrap().count(), 4);
+
+        fs::remove_file(previous_binary_path(&exe).unwrap()).unwrap();
+        let err = rollback_update(&exe).unwrap_err();
+        assert!(err.contains("No previous version"), "{err}");
+    }
+
     #[test]
     fn asset_name_from_url_extracts_filename() {
         assert_eq!(
@@ -2301,6 +2638,7 @@ mod tests {
             portable_asset_url: None,
             installer_asset_url: None,
             asset_url: Some("https://example.com/asset".to_string()),
+            channel: UpdateChannel::Stable,
         };
         write_cache(mgr.cache_path(), &cache).unwrap();
 
@@ -2326,6 +2664,7 @@ mod tests {
             portable_asset_url: None,
             installer_asset_url: None,
             asset_url: Some("https://example.com/asset".to_string()),
+            channel: UpdateChannel::Stable,
         };
         write_cache(mgr.cache_path(), &cache).unwrap();
 
@@ -3404,6 +3743,7 @@ mod tests {
             portable_asset_url: Some("https://example.com/gwt-linux-x86_64.tar.gz".to_string()),
             installer_asset_url: Some("https://example.com/gwt-windows-x86_64.msi".to_string()),
             asset_url: Some("https://example.com/legacy.zip".to_string()),
+            channel: UpdateChannel::Stable,
         };
 
         write_cache(&cache_path, &cache).unwrap();
@@ -3439,6 +3779,7 @@ mod tests {
             portable_asset_url: None,
             installer_asset_url: None,
             asset_url: None,
+            channel: UpdateChannel::Stable,
         };
         assert_eq!(
             mgr.state_from_cache(&missing, None),
@@ -0,0 +1,270 @@
+//! Binary delta updates between two releases.
+//!
+//! A delta bundle is an uncompressed tar published next to the portable
+//! archive as `<portable stem>-from-v<version>.delta.tar`
+//! ([`crate::release_contract::delta_asset_name`]). It holds
+//! [`DELTA_MANIFEST_NAME`] and, for every bundle binary, a zstd stream
+//! compressed with the installed binary as reference prefix
+//! (`zstd --patch-from`). Both the installed binary and the patched result
+//! are checked against the manifest's SHA-256 digests, so a delta is only
+//! ever applied to the exact build it was made from.
+
+use std::{
+    fs,
+    io::{Read, Write},
+    path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+pub const DELTA_MANIFEST_NAME: &str = "delta.json";
+
+const PATCH_SUFFIX: &str = ".zst";
+const PATCH_LEVEL: i32 = 19;
+
+/// `delta.json` of a delta bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeltaManifest {
+    /// Version the patches apply to.
+    pub from: String,
+    /// Version the patches produce.
+    pub to: String,
+    pub files: Vec<DeltaFile>,
+}
+
+/// One patched binary, e.g. `gwt` or `gwtd.exe`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeltaFile {
+    pub name: String,
+    pub source_sha256: String,
+    pub target_sha256: String,
+    pub target_size: u64,
+}
+
+/// Write a delta bundle turning each `(old, new)` binary pair of release
+/// `from` into release `to`. Used by the release workflow; the file names
+/// of the new binaries become the names in the bundle.
+pub fn create_delta_bundle(
+    from: &str,
+    to: &str,
+    pairs: &[(&Path, &Path)],
+    out: &Path,
+) -> Result<DeltaManifest, String> {
+    let mut files = Vec::with_capacity(pairs.len());
+    let mut patches = Vec::with_capacity(pairs.len());
+    for (old, new) in pairs {
+        let name = file_name(new)?;
+        let old_bytes = read(old)?;
+        let new_bytes = read(new)?;
+        let mut encoder =
+            zstd::stream::write::Encoder::with_ref_prefix(Vec::new(), PATCH_LEVEL, &old_bytes)
+                .map_err(|e| format!("Failed to start patch for {name}: {e}"))?;
+        encoder
+            .window_log(window_log(old_bytes.len(), new_bytes.len()))
+            .and_then(|()| encoder.long_distance_matching(true))
+            .map_err(|e| format!("Failed to configure patch for {name}: {e}"))?;
+        encoder
+            .write_all(&new_bytes)
+            .map_err(|e| format!("Failed to write patch for {name}: {e}"))?;
+        let patch = encoder
+            .finish()
+            .map_err(|e| format!("Failed to finish patch for {name}: {e}"))?;
+        files.push(DeltaFile {
+            name: name.clone(),
+            source_sha256: sha256_hex(&old_bytes),
+            target_sha256: sha256_hex(&new_bytes),
+            target_size: new_bytes.len() as u64,
+        });
+        patches.push((format!("{name}{PATCH_SUFFIX}"), patch));
+    }
+    let manifest = DeltaManifest {
+        from: from.trim_start_matches('v').to_string(),
+        to: to.trim_start_matches('v').to_string(),
+        files,
+    };
+    let manifest_json = serde_json::to_vec_pretty(&manifest)
+        .map_err(|e| format!("Failed to serialize delta manifest: {e}"))?;
+
+    let file =
+        fs::File::create(out).map_err(|e| format!("Failed to create {}: {e}", out.display()))?;
+    let mut archive = tar::Builder::new(file);
+    for (name, bytes) in
+        std::iter::once((DELTA_MANIFEST_NAME.to_string(), manifest_json)).chain(patches)
+    {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(bytes.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        archive
+            .append_data(&mut header, &name, bytes.as_slice())
+            .map_err(|e| format!("Failed to write {name} to the delta bundle: {e}"))?;
+    }
+    archive
+        .finish()
+        .map_err(|e| format!("Failed to finish the delta bundle: {e}"))?;
+    Ok(manifest)
+}
+
+/// Apply the delta bundle at `bundle` to the binaries in `installed_dir`,
+/// writing the patched binaries into `out_dir`. Fails without touching
+/// `installed_dir` when the bundle is not `from` → `to` or a checksum does
+/// not match.
+pub fn apply_delta_bundle(
+    bundle: &Path,
+    from: &str,
+    to: &str,
+    installed_dir: &Path,
+    out_dir: &Path,
+) -> Result<DeltaManifest, String> {
+    let file =
+        fs::File::open(bundle).map_err(|e| format!("Failed to open {}: {e}", bundle.display()))?;
+    let mut archive = tar::Archive::new(file);
+    let mut manifest = None;
+    let mut patches = std::collections::BTreeMap::new();
+    for entry in archive
+        .entries()
+        .map_err(|e| format!("Failed to read the delta bundle: {e}"))?
+    {
+        let mut entry = entry.map_err(|e| format!("Failed to read the delta bundle: {e}"))?;
+        let name = entry
+            .path()
+            .map_err(|e| format!("Invalid delta bundle entry: {e}"))?
+            .to_string_lossy()
+            .to_string();
+        let mut bytes = Vec::new();
+        entry
+            .read_to_end(&mut bytes)
+            .map_err(|e| format!("Failed to read {name} from the delta bundle: {e}"))?;
+        if name == DELTA_MANIFEST_NAME {
+            manifest = Some(
+                serde_json::from_slice::<DeltaManifest>(&bytes)
+                    .map_err(|e| format!("Invalid delta manifest: {e}"))?,
+            );
+        } else {
+            patches.insert(name, bytes);
+        }
+    }
+    let manifest = manifest.ok_or_else(|| format!("{DELTA_MANIFEST_NAME} is missing"))?;
+    let (from, to) = (from.trim_start_matches('v'), to.trim_start_matches('v'));
+    if manifest.from != from || manifest.to != to {
+        return Err(format!(
+            "Delta bundle is v{} → v{}, expected v{from} → v{to}",
+            manifest.from, manifest.to
+        ));
+    }
+
+    fs::create_dir_all(out_dir)
+        .map_err(|e| format!("Failed to create {}: {e}", out_dir.display()))?;
+    for delta in &manifest.files {
+        if delta.name.contains(['/', '\\']) || delta.name.starts_with('.') {
+            return Err(format!("Invalid binary name in the delta: {}", delta.name));
+        }
+        let source = read(&installed_dir.join(&delta.name))?;
+        if sha256_hex(&source) != delta.source_sha256 {
+            return Err(format!(
+                "Installed {} is not the v{from} build the delta was made from",
+                delta.name
+            ));
+        }
+        let patch = patches
+            .get(&format!("{}{PATCH_SUFFIX}", delta.name))
+            .ok_or_else(|| format!("Patch for {} is missing", delta.name))?;
+        let target_size = usize::try_from(delta.target_size)
+            .map_err(|_| format!("Patched {} would be too large", delta.name))?;
+        let mut decoder = zstd::stream::read::Decoder::with_ref_prefix(patch.as_slice(), &source)
+            .map_err(|e| format!("Failed to start patching {}: {e}", delta.name))?;
+        decoder
+            .window_log_max(window_log(source.len(), target_size))
+            .map_err(|e| format!("Failed to configure patching {}: {e}", delta.name))?;
+        let mut target = Vec::with_capacity(target_size);
+        decoder
+            .read_to_end(&mut target)
+            .map_err(|e| format!("Failed to patch {}: {e}", delta.name))?;
+        if target.len() != target_size || sha256_hex(&target) != delta.target_sha256 {
+            return Err(format!(
+                "Patched {} does not match the v{to} checksum",
+                delta.name
+            ));
+        }
+        let out = out_dir.join(&delta.name);
+        fs::write(&out, &target).map_err(|e| format!("Failed to write {}: {e}", out.display()))?;
+    }
+    Ok(manifest)
+}
+
+/// Window large enough to reference anywhere in the old binary while
+/// producing the new one, as `zstd --patch-from` picks it.
+fn window_log(source_len: usize, target_len: usize) -> u32 {
+    let span = source_len.max(target_len).max(1) as u64;
+    (u64::BITS - (span - 1).leading_zeros() + 1).clamp(10, 30)
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, String> {
+    fs::read(path).map_err(|e| format!("Failed to read {}: {e}", path.display()))
+}
+
+fn file_name(path: &Path) -> Result<String, String> {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map(str::to_string)
+        .ok_or_else(|| format!("Invalid binary path: {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn binary(seed: u8, len: usize) -> Vec<u8> {
+        (0..len)
+            .map(|i| (i as u8).wrapping_mul(31).wrapping_add(seed))
+            .collect()
+    }
+
+    #[test]
+    fn delta_round_trips_and_rejects_mismatches() {
+        let temp = tempfile::tempdir().unwrap();
+        let old_dir = temp.path().join("old");
+        let new_dir = temp.path().join("new");
+        fs::create_dir_all(&old_dir).unwrap();
+        fs::create_dir_all(&new_dir).unwrap();
+        let old_bin = binary(1, 256 * 1024);
+        let mut new_bin = old_bin.clone();
+        new_bin[1000..1100].copy_from_slice(&[7; 100]);
+        new_bin.extend_from_slice(b"new tail");
+        fs::write(old_dir.join("gwt"), &old_bin).unwrap();
+        fs::write(new_dir.join("gwt"), &new_bin).unwrap();
+        fs::write(old_dir.join("gwtd"), binary(2, 4096)).unwrap();
+        fs::write(new_dir.join("gwtd"), binary(3, 4096)).unwrap();
+
+        let bundle = temp.path().join("gwt-linux-x86_64-from-v1.0.0.delta.tar");
+        let pairs: Vec<(PathBuf, PathBuf)> = ["gwt", "gwtd"]
+            .iter()
+            .map(|name| (old_dir.join(name), new_dir.join(name)))
+            .collect();
+        let pairs: Vec<(&Path, &Path)> = pairs
+            .iter()
+            .map(|(old, new)| (old.as_path(), new.as_path()))
+            .collect();
+        create_delta_bundle("v1.0.0", "v1.1.0", &pairs, &bundle).unwrap();
+        assert!(fs::metadata(&bundle).unwrap().len() < new_bin.len() as u64 / 4);
+
+        let out = temp.path().join("out");
+        apply_delta_bundle(&bundle, "1.0.0", "1.1.0", &old_dir, &out).unwrap();
+        assert_eq!(fs::read(out.join("gwt")).unwrap(), new_bin);
+        assert_eq!(fs::read(out.join("gwtd")).unwrap(), binary(3, 4096));
+
+        let err = apply_delta_bundle(&bundle, "1.0.1", "1.1.0", &old_dir, &out).unwrap_err();
+        assert!(err.contains("expected v1.0.1"), "{err}");
+
+        fs::write(old_dir.join("gwt"), binary(9, 1024)).unwrap();
+        let err = apply_delta_bundle(&bundle, "1.0.0", "1.1.0", &old_dir, &out).unwrap_err();
+        assert!(err.contains("not the v1.0.0 build"), "{err}");
+    }
+}
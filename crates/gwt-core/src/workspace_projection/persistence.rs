@@ -970,6 +970,11 @@ impl WorkItemsCache {
     }
 
     fn lookup(&self, work_items_path: &Path) -> Option<WorkItemsProjection> {
+        // Second-precise mtimes cannot tell a same-size rewrite apart.
+        let dir = work_items_path.parent()?;
+        if !crate::fs_probe::capabilities(dir).subsecond_mtime {
+            return None;
+        }
         let meta = fs::metadata(work_items_path).ok()?;
         let mtime = meta.modified().ok()?;
         let hit = self.entries.get(work_items_path)?;
@@ -21,6 +21,10 @@ fn init_writes_tracing_events_as_jsonl_to_gwt_log() {
         default_level: LogLevel::Debug,
         config_file_level: None,
         retention_days: 0, // disable housekeeping — test has a clean dir
+        max_file_bytes: None,
+        max_total_bytes: None,
+        otlp_endpoint: None,
+        secret_key_patterns: None,
     };
 
     let handles = init(config).expect("init should succeed");
@@ -31,6 +35,12 @@ fn init_writes_tracing_events_as_jsonl_to_gwt_log() {
         "hello from test"
     );
     tracing::warn!(target: "gwt_core::logging::test", "warning sample");
+    tracing::info!(
+        target: "gwt_core::logging::test",
+        api_key = "sk-live-secret",
+        label = "codex GH_TOKEN=gh-live-secret",
+        "secret sample"
+    );
 
     // SPEC-2809 revised — the Console window only shows actual
     // subprocess stdout/stderr (terminal-like view); gwt-domain
@@ -91,6 +101,12 @@ fn init_writes_tracing_events_as_jsonl_to_gwt_log() {
         content.contains("warning sample"),
         "expected warn event in log file"
     );
+    assert!(
+        content.contains("secret sample")
+            && !content.contains("sk-live-secret")
+            && !content.contains("gh-live-secret"),
+        "expected secret values to be redacted, got: {content}"
+    );
 
     // SPEC-1924 US-14 / T-LFR-006: the on-disk JSONL produced by the live
     // writer must be replayable by the new reader without any skipped lines.
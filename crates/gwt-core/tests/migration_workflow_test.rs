@@ -200,6 +200,33 @@ fn t026_validate_aggregates_all_checks_for_clean_normal_repo() {
     validator::validate(tmp.path()).expect("clean Normal Git must pass aggregate validate()");
 }
 
+#[test]
+fn check_bare_layout_finds_the_bare_repo_named_in_project_toml() {
+    let tmp = tempfile::tempdir().unwrap();
+    let bare = tmp.path().join("sample.git");
+    gwt_core::process::hidden_command("git")
+        .args(["init", "--bare", bare.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(matches!(
+        validator::check_bare_layout(tmp.path()),
+        Err(ValidationError::NotBareLayout(_))
+    ));
+
+    BareProjectConfig {
+        bare_repo_name: "sample.git".to_string(),
+        remote_url: None,
+        created_at: "2026-01-01T00:00:00Z".to_string(),
+        migrated_from: Some("normal".to_string()),
+    }
+    .save(tmp.path())
+    .unwrap();
+    assert_eq!(validator::check_bare_layout(tmp.path()).unwrap(), bare);
+
+    std::fs::create_dir(tmp.path().join(".git")).unwrap();
+    assert!(validator::check_bare_layout(tmp.path()).is_err());
+}
+
 #[test]
 fn t026_validate_rejects_git_file_worktree_marker_before_backup() {
     let tmp = tempfile::tempdir().unwrap();
@@ -690,6 +717,7 @@ fn t015_migration_phase_has_stable_string_keys() {
         (MigrationPhase::Validate, "validate"),
         (MigrationPhase::Backup, "backup"),
         (MigrationPhase::Bareify, "bareify"),
+        (MigrationPhase::Unbare, "unbare"),
         (MigrationPhase::Worktrees, "worktrees"),
         (MigrationPhase::Submodules, "submodules"),
         (MigrationPhase::Tracking, "tracking"),
@@ -17,6 +17,10 @@ fn process_line_events_are_excluded_from_canonical_log_file() {
         default_level: LogLevel::Debug,
         config_file_level: None,
         retention_days: 0,
+        max_file_bytes: None,
+        max_total_bytes: None,
+        otlp_endpoint: None,
+        secret_key_patterns: None,
     };
 
     let handles = init(config).expect("init should succeed");
@@ -17,7 +17,8 @@ pub struct ComposeService {
     pub image: Option<String>,
     /// Explicit target platform (if specified), e.g. `linux/arm64`.
     pub platform: Option<String>,
-    /// Published ports (raw strings, e.g. "8080:80").
+    /// Published ports in short syntax (e.g. "8080:80"); bare numbers and
+    /// long-syntax entries are normalized to it.
     pub ports: Vec<String>,
     /// Services this service depends on.
     pub depends_on: Vec<String>,
@@ -25,6 +26,8 @@ pub struct ComposeService {
     pub working_dir: Option<String>,
     /// Volume mounts declared for the service.
     pub volumes: Vec<ComposeVolumeMount>,
+    /// Compose profiles the service belongs to. Empty means always enabled.
+    pub profiles: Vec<String>,
 }
 
 /// A parsed Compose volume mount.
@@ -71,11 +74,7 @@ fn parse_compose_content(content: &str) -> Result<Vec<ComposeService>> {
         let ports = value
             .get("ports")
             .and_then(|v| v.as_sequence())
-            .map(|seq| {
-                seq.iter()
-                    .filter_map(|v| v.as_str().map(std::string::ToString::to_string))
-                    .collect()
-            })
+            .map(|seq| seq.iter().filter_map(port_spec).collect())
             .unwrap_or_default();
 
         let depends_on = extract_depends_on(value);
@@ -84,6 +83,15 @@ fn parse_compose_content(content: &str) -> Result<Vec<ComposeService>> {
             .and_then(|v| v.as_str())
             .map(std::string::ToString::to_string);
         let volumes = extract_volumes(value);
+        let profiles = value
+            .get("profiles")
+            .and_then(|v| v.as_sequence())
+            .map(|seq| {
+                seq.iter()
+                    .filter_map(|v| v.as_str().map(std::string::ToString::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
 
         result.push(ComposeService {
             name,
@@ -93,6 +101,7 @@ fn parse_compose_content(content: &str) -> Result<Vec<ComposeService>> {
             depends_on,
             working_dir,
             volumes,
+            profiles,
         });
     }
 
@@ -104,6 +113,50 @@ fn parse_compose_content(content: &str) -> Result<Vec<ComposeService>> {
     Ok(result)
 }
 
+/// Distinct profiles declared across `services`, sorted.
+pub fn compose_profiles(services: &[ComposeService]) -> Vec<String> {
+    let mut profiles = services
+        .iter()
+        .flat_map(|service| service.profiles.iter().cloned())
+        .collect::<Vec<_>>();
+    profiles.sort();
+    profiles.dedup();
+    profiles
+}
+
+/// Short-syntax form of one `ports` entry: strings as written, bare numbers
+/// as container-only ports, and long-syntax mappings as
+/// `[host_ip:][published:]target[/protocol]`.
+fn port_spec(entry: &Value) -> Option<String> {
+    match entry {
+        Value::String(raw) => Some(raw.trim().to_string()),
+        Value::Number(number) => Some(number.to_string()),
+        Value::Mapping(map) => {
+            let field = |name: &str| {
+                map.get(Value::String(name.to_string()))
+                    .and_then(|v| match v {
+                        Value::String(s) => Some(s.clone()),
+                        Value::Number(n) => Some(n.to_string()),
+                        _ => None,
+                    })
+                    .filter(|v| !v.is_empty())
+            };
+            let mut spec = field("target")?;
+            if let Some(published) = field("published") {
+                spec = format!("{published}:{spec}");
+                if let Some(host_ip) = field("host_ip") {
+                    spec = format!("{host_ip}:{spec}");
+                }
+            }
+            if let Some(protocol) = field("protocol") {
+                spec = format!("{spec}/{protocol}");
+            }
+            Some(spec)
+        }
+        _ => None,
+    }
+}
+
 /// Extract depends_on which can be either a list of strings or a mapping.
 fn extract_depends_on(service: &Value) -> Vec<String> {
     match service.get("depends_on") {
@@ -236,6 +289,47 @@ services:
         assert_eq!(db.image.as_deref(), Some("postgres:15"));
     }
 
+    #[test]
+    fn parse_ports_normalizes_numbers_and_long_syntax() {
+        let yaml = r#"
+services:
+  web:
+    image: nginx
+    ports:
+      - 9229
+      - target: 80
+        published: "8080"
+        host_ip: 127.0.0.1
+        protocol: tcp
+      - target: 53
+"#;
+        let services = parse_compose_content(yaml).unwrap();
+        assert_eq!(
+            services[0].ports,
+            vec!["9229", "127.0.0.1:8080:80/tcp", "53"]
+        );
+    }
+
+    #[test]
+    fn parse_profiles_and_collect_distinct() {
+        let yaml = r#"
+services:
+  app:
+    image: node:18
+  debug:
+    image: busybox
+    profiles: ["debug", "tools"]
+  worker:
+    image: node:18
+    profiles:
+      - tools
+"#;
+        let services = parse_compose_content(yaml).unwrap();
+        let app = services.iter().find(|s| s.name == "app").unwrap();
+        assert!(app.profiles.is_empty());
+        assert_eq!(compose_profiles(&services), vec!["debug", "tools"]);
+    }
+
     #[test]
     fn parse_depends_on_list() {
         let yaml = r#"
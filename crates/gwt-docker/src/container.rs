@@ -90,8 +90,8 @@ fn next_docker_spawn_id() -> u64 {
     DOCKER_SPAWN_COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
 }
 
-fn emit_docker_start(spawn_id: u64, action: &str, args: &[&str]) {
-    let label = format!("docker {}", args.join(" "));
+fn emit_docker_start(spawn_id: u64, action: &str, program: &str, args: &[&str]) {
+    let label = format!("{program} {}", args.join(" "));
     tracing::info!(
         target: "gwt.process.summary",
         kind = "docker",
@@ -171,7 +171,7 @@ fn docker_timeout() -> Duration {
         .unwrap_or_else(|| Duration::from_millis(DEFAULT_TIMEOUT_MS))
 }
 
-fn docker_compose_up_timeout() -> Duration {
+pub(crate) fn docker_compose_up_timeout() -> Duration {
     const DEFAULT_TIMEOUT_MS: u64 = 300_000;
     std::env::var("GWT_DOCKER_COMPOSE_UP_TIMEOUT_MS")
         .ok()
@@ -181,7 +181,7 @@ fn docker_compose_up_timeout() -> Duration {
         .unwrap_or_else(|| Duration::from_millis(DEFAULT_TIMEOUT_MS))
 }
 
-fn docker_compose_exec_timeout() -> Duration {
+pub(crate) fn docker_compose_exec_timeout() -> Duration {
     const DEFAULT_TIMEOUT_MS: u64 = 120_000;
     std::env::var("GWT_DOCKER_COMPOSE_EXEC_TIMEOUT_MS")
         .ok()
@@ -196,7 +196,7 @@ fn docker_compose_exec_timeout() -> Duration {
 /// `docker_timeout()` proved too aggressive on busy hosts (Issue #3029:
 /// a normally-0.2s `compose ps` exceeded 5s while ChromaDB indexing
 /// saturated the CPU, aborting the whole agent launch).
-fn docker_status_timeout() -> Duration {
+pub(crate) fn docker_status_timeout() -> Duration {
     const DEFAULT_TIMEOUT_MS: u64 = 30_000;
     std::env::var("GWT_DOCKER_STATUS_TIMEOUT_MS")
         .ok()
@@ -322,6 +322,31 @@ fn run_docker_with_output_streaming_in_dir_and_timeout<F>(
     timeout: Duration,
     on_line: F,
 ) -> Result<Output>
+where
+    F: FnMut(CommandOutputStream, &str),
+{
+    run_program_with_output_streaming(
+        docker_binary(),
+        "docker",
+        args,
+        action,
+        current_dir,
+        timeout,
+        on_line,
+    )
+}
+
+/// Run `program` with the docker timeout / streaming / Console logging
+/// semantics. `label` names the program in the Console banner.
+pub(crate) fn run_program_with_output_streaming<F>(
+    program: OsString,
+    label: &str,
+    args: &[&str],
+    action: &str,
+    current_dir: Option<&std::path::Path>,
+    timeout: Duration,
+    on_line: F,
+) -> Result<Output>
 where
     F: FnMut(CommandOutputStream, &str),
 {
@@ -332,11 +357,11 @@ where
     // would risk regressing compose timeout semantics.
     let hub = gwt_core::process_console::global();
     let spawn_id = next_docker_spawn_id();
-    emit_docker_start(spawn_id, action, args);
+    emit_docker_start(spawn_id, action, label, args);
     let started_at = Instant::now();
     let mut on_line = wrap_on_line_with_hub(on_line, hub.clone(), spawn_id);
 
-    let mut command = gwt_core::process::hidden_command(docker_binary());
+    let mut command = gwt_core::process::hidden_command(program);
     command
         .args(args)
         .stdout(Stdio::piped())
@@ -461,6 +486,17 @@ fn compose_file_args(compose_files: &[PathBuf]) -> Vec<String> {
         .collect()
 }
 
+/// `-f` for each Compose file followed by `--profile` for each profile.
+/// Both are global `docker compose` options, so they precede the subcommand.
+pub fn compose_project_args(compose_files: &[PathBuf], profiles: &[String]) -> Vec<String> {
+    let mut args = compose_file_args(compose_files);
+    for profile in profiles {
+        args.push("--profile".to_string());
+        args.push(profile.clone());
+    }
+    args
+}
+
 fn compose_files_label(compose_files: &[PathBuf]) -> String {
     compose_files
         .iter()
@@ -475,8 +511,12 @@ pub fn compose_up(compose_file: &Path, service: &str) -> Result<()> {
 }
 
 /// Start a compose service in detached mode using all provided Compose files.
-pub fn compose_up_with_files(compose_files: &[PathBuf], service: &str) -> Result<()> {
-    compose_up_with_files_output(compose_files, service, |_, _| {})
+pub fn compose_up_with_files(
+    compose_files: &[PathBuf],
+    profiles: &[String],
+    service: &str,
+) -> Result<()> {
+    compose_up_with_files_output(compose_files, profiles, service, |_, _| {})
 }
 
 /// Start a compose service in detached mode and force container recreation.
@@ -487,9 +527,10 @@ pub fn compose_up_force_recreate(compose_file: &Path, service: &str) -> Result<(
 /// Start a compose service with all Compose files and force recreation.
 pub fn compose_up_force_recreate_with_files(
     compose_files: &[PathBuf],
+    profiles: &[String],
     service: &str,
 ) -> Result<()> {
-    compose_up_force_recreate_with_files_output(compose_files, service, |_, _| {})
+    compose_up_force_recreate_with_files_output(compose_files, profiles, service, |_, _| {})
 }
 
 /// Start a compose service in detached mode while streaming stdout/stderr lines.
@@ -497,12 +538,13 @@ pub fn compose_up_with_output<F>(compose_file: &Path, service: &str, on_line: F)
 where
     F: FnMut(CommandOutputStream, &str),
 {
-    compose_up_with_files_output(&[compose_file.to_path_buf()], service, on_line)
+    compose_up_with_files_output(&[compose_file.to_path_buf()], &[], service, on_line)
 }
 
 /// Start a compose service using all Compose files while streaming output.
 pub fn compose_up_with_files_output<F>(
     compose_files: &[PathBuf],
+    profiles: &[String],
     service: &str,
     on_line: F,
 ) -> Result<()>
@@ -510,7 +552,7 @@ where
     F: FnMut(CommandOutputStream, &str),
 {
     let mut args = vec!["compose".to_string()];
-    args.extend(compose_file_args(compose_files));
+    args.extend(compose_project_args(compose_files, profiles));
     args.extend(["up".to_string(), "-d".to_string(), service.to_string()]);
     let arg_refs = args.iter().map(String::as_str).collect::<Vec<_>>();
     let output = run_docker_with_output_streaming_in_dir_and_timeout(
@@ -548,13 +590,19 @@ pub fn compose_up_force_recreate_with_output<F>(
 where
     F: FnMut(CommandOutputStream, &str),
 {
-    compose_up_force_recreate_with_files_output(&[compose_file.to_path_buf()], service, on_line)
+    compose_up_force_recreate_with_files_output(
+        &[compose_file.to_path_buf()],
+        &[],
+        service,
+        on_line,
+    )
 }
 
 /// Start a compose service using all Compose files while forcing recreation and
 /// streaming stdout/stderr lines.
 pub fn compose_up_force_recreate_with_files_output<F>(
     compose_files: &[PathBuf],
+    profiles: &[String],
     service: &str,
     on_line: F,
 ) -> Result<()>
@@ -562,7 +610,7 @@ where
     F: FnMut(CommandOutputStream, &str),
 {
     let mut args = vec!["compose".to_string()];
-    args.extend(compose_file_args(compose_files));
+    args.extend(compose_project_args(compose_files, profiles));
     args.extend([
         "up".to_string(),
         "-d".to_string(),
@@ -596,14 +644,15 @@ where
 }
 
 fn compose_service_statuses(compose_file: &Path) -> Result<Vec<(String, ComposeServiceStatus)>> {
-    compose_service_statuses_with_files(&[compose_file.to_path_buf()])
+    compose_service_statuses_with_files(&[compose_file.to_path_buf()], &[])
 }
 
 fn compose_service_statuses_with_files(
     compose_files: &[PathBuf],
+    profiles: &[String],
 ) -> Result<Vec<(String, ComposeServiceStatus)>> {
     let mut args = vec!["compose".to_string()];
-    args.extend(compose_file_args(compose_files));
+    args.extend(compose_project_args(compose_files, profiles));
     args.extend([
         "ps".to_string(),
         "--all".to_string(),
@@ -655,9 +704,13 @@ pub fn compose_service_is_running(compose_file: &Path, service: &str) -> Result<
 /// Return whether a compose service from all Compose files is currently running.
 pub fn compose_service_is_running_with_files(
     compose_files: &[PathBuf],
+    profiles: &[String],
     service: &str,
 ) -> Result<bool> {
-    Ok(compose_service_status_with_files(compose_files, service)? == ComposeServiceStatus::Running)
+    Ok(
+        compose_service_status_with_files(compose_files, profiles, service)?
+            == ComposeServiceStatus::Running,
+    )
 }
 
 /// Return the current status of a compose service.
@@ -671,12 +724,15 @@ pub fn compose_service_status(compose_file: &Path, service: &str) -> Result<Comp
 /// Return the current status of a compose service using all Compose files.
 pub fn compose_service_status_with_files(
     compose_files: &[PathBuf],
+    profiles: &[String],
     service: &str,
 ) -> Result<ComposeServiceStatus> {
-    Ok(compose_service_statuses_with_files(compose_files)?
-        .into_iter()
-        .find_map(|(candidate, status)| (candidate == service).then_some(status))
-        .unwrap_or(ComposeServiceStatus::NotFound))
+    Ok(
+        compose_service_statuses_with_files(compose_files, profiles)?
+            .into_iter()
+            .find_map(|(candidate, status)| (candidate == service).then_some(status))
+            .unwrap_or(ComposeServiceStatus::NotFound),
+    )
 }
 
 /// Return recent logs for a compose service.
@@ -714,17 +770,18 @@ pub fn compose_service_has_command(
     service: &str,
     command: &str,
 ) -> Result<bool> {
-    compose_service_has_command_with_files(&[compose_file.to_path_buf()], service, command)
+    compose_service_has_command_with_files(&[compose_file.to_path_buf()], &[], service, command)
 }
 
 /// Return whether a compose service can resolve a command using all Compose files.
 pub fn compose_service_has_command_with_files(
     compose_files: &[PathBuf],
+    profiles: &[String],
     service: &str,
     command: &str,
 ) -> Result<bool> {
     let mut docker_args = vec!["compose".to_string()];
-    docker_args.extend(compose_file_args(compose_files));
+    docker_args.extend(compose_project_args(compose_files, profiles));
     docker_args.extend([
         "exec".to_string(),
         "-T".to_string(),
@@ -766,6 +823,7 @@ pub fn compose_service_exec_capture(
 ) -> Result<Output> {
     compose_service_exec_capture_with_files(
         &[compose_file.to_path_buf()],
+        &[],
         service,
         working_dir,
         args,
@@ -775,12 +833,13 @@ pub fn compose_service_exec_capture(
 /// Execute a command inside a compose service using all Compose files.
 pub fn compose_service_exec_capture_with_files(
     compose_files: &[PathBuf],
+    profiles: &[String],
     service: &str,
     working_dir: Option<&str>,
     args: &[String],
 ) -> Result<Output> {
     let mut docker_args = vec!["compose".to_string()];
-    docker_args.extend(compose_file_args(compose_files));
+    docker_args.extend(compose_project_args(compose_files, profiles));
     docker_args.extend(["exec".to_string(), "-T".to_string()]);
     if let Some(working_dir) = working_dir {
         docker_args.push("-w".to_string());
@@ -798,18 +857,64 @@ pub fn compose_service_exec_capture_with_files(
     )
 }
 
+/// Run a devcontainer lifecycle command (e.g. `postCreateCommand`) inside a
+/// compose service. Uses the `compose up` timeout because these commands
+/// typically install dependencies.
+pub fn compose_service_run_lifecycle_command_with_files(
+    compose_files: &[PathBuf],
+    profiles: &[String],
+    service: &str,
+    working_dir: Option<&str>,
+    args: &[String],
+) -> Result<()> {
+    let mut docker_args = vec!["compose".to_string()];
+    docker_args.extend(compose_project_args(compose_files, profiles));
+    docker_args.extend(["exec".to_string(), "-T".to_string()]);
+    if let Some(working_dir) = working_dir {
+        docker_args.push("-w".to_string());
+        docker_args.push(working_dir.to_string());
+    }
+    docker_args.push(service.to_string());
+    docker_args.extend(args.iter().cloned());
+    let arg_refs = docker_args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let output = run_docker_with_output_streaming_in_dir_and_timeout(
+        &arg_refs,
+        "docker compose exec",
+        Some(compose_parent_dir_for_files(compose_files)),
+        docker_compose_up_timeout(),
+        |_, _| {},
+    )?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(GwtError::Docker(if stderr.is_empty() {
+            format!("lifecycle command failed with {}", output.status)
+        } else {
+            format!("lifecycle command failed: {stderr}")
+        }));
+    }
+    debug!(
+        category = "docker",
+        service = service,
+        "devcontainer lifecycle command finished"
+    );
+    Ok(())
+}
+
 /// Return whether a compose service executes as root inside the container.
 pub fn compose_service_user_is_root(compose_file: &Path, service: &str) -> Result<bool> {
-    compose_service_user_is_root_with_files(&[compose_file.to_path_buf()], service)
+    compose_service_user_is_root_with_files(&[compose_file.to_path_buf()], &[], service)
 }
 
 /// Return whether a compose service executes as root using all Compose files.
 pub fn compose_service_user_is_root_with_files(
     compose_files: &[PathBuf],
+    profiles: &[String],
     service: &str,
 ) -> Result<bool> {
     let output = compose_service_exec_capture_with_files(
         compose_files,
+        profiles,
         service,
         None,
         &["sh".to_string(), "-lc".to_string(), "id -u".to_string()],
@@ -852,13 +957,17 @@ pub fn compose_stop(compose_file: &Path, service: &str) -> Result<()> {
 
 /// Restart a compose service.
 pub fn compose_restart(compose_file: &Path, service: &str) -> Result<()> {
-    compose_restart_with_files(&[compose_file.to_path_buf()], service)
+    compose_restart_with_files(&[compose_file.to_path_buf()], &[], service)
 }
 
 /// Restart a compose service using all Compose files.
-pub fn compose_restart_with_files(compose_files: &[PathBuf], service: &str) -> Result<()> {
+pub fn compose_restart_with_files(
+    compose_files: &[PathBuf],
+    profiles: &[String],
+    service: &str,
+) -> Result<()> {
     let mut args = vec!["compose".to_string()];
-    args.extend(compose_file_args(compose_files));
+    args.extend(compose_project_args(compose_files, profiles));
     args.extend(["restart".to_string(), service.to_string()]);
     let arg_refs = args.iter().map(String::as_str).collect::<Vec<_>>();
     let output = run_docker_with_timeout_in_dir(
@@ -1493,6 +1602,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compose_project_args_appends_profiles_after_files() {
+        assert_eq!(
+            compose_project_args(
+                &[PathBuf::from("docker-compose.yml")],
+                &["debug".to_string(), "tools".to_string()],
+            ),
+            vec![
+                "-f".to_string(),
+                "docker-compose.yml".to_string(),
+                "--profile".to_string(),
+                "debug".to_string(),
+                "--profile".to_string(),
+                "tools".to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn compose_up_force_recreate_invokes_docker_with_expected_arguments() {
         let log_dir = tempfile::tempdir().expect("temp log dir");
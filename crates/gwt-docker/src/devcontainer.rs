@@ -2,13 +2,33 @@
 //!
 //! Parses `.devcontainer/devcontainer.json` files, supporting JSON with
 //! comments (JSONC) as used by VS Code DevContainers.
+//!
+//! Launch Agent runs everything through `docker compose`, so an image- or
+//! Dockerfile-based devcontainer is translated into a generated single-service
+//! compose file ([`DevContainerConfig::write_generated_compose`]).
+//! `postCreateCommand` runs once after gwt creates the container and
+//! `remoteEnv` is exported to the agent process. Features need the
+//! devcontainer CLI to build; gwt only reports them so the image can be
+//! prebuilt. When the CLI is installed, launches bypass this translation
+//! (see [`crate::devcontainer_cli`]).
 
-use std::path::Path;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
 
 use gwt_core::{GwtError, Result};
 use serde::Deserialize;
 use tracing::debug;
 
+/// Compose service name used for image- and Dockerfile-based devcontainers.
+pub const DEVCONTAINER_SERVICE_NAME: &str = "devcontainer";
+/// File (in the project root) holding the compose translation of an
+/// image- or Dockerfile-based devcontainer.
+pub const DEVCONTAINER_GENERATED_COMPOSE_FILE_NAME: &str = "docker-compose.gwt.devcontainer.yml";
+const DEVCONTAINER_GENERATED_COMPOSE_HEADER: &str =
+    "# Auto-generated by gwt from .devcontainer/devcontainer.json; do not edit\n";
+
 /// Parsed devcontainer.json configuration.
 #[derive(Debug, Clone, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -37,6 +57,16 @@ pub struct DevContainerConfig {
     /// Working directory inside the container.
     #[serde(default)]
     pub workspace_folder: Option<String>,
+    /// Dev Container Features keyed by feature id, with their options.
+    #[serde(default)]
+    pub features: BTreeMap<String, serde_json::Value>,
+    /// Environment set on the container itself.
+    #[serde(default)]
+    pub container_env: BTreeMap<String, String>,
+    /// Environment for processes started in the container. `null` values
+    /// unset the variable.
+    #[serde(default)]
+    pub remote_env: BTreeMap<String, Option<String>>,
 }
 
 /// Build configuration inside devcontainer.json.
@@ -102,6 +132,216 @@ impl DevContainerConfig {
     pub fn uses_compose(&self) -> bool {
         self.docker_compose_file.is_some()
     }
+
+    /// `dockerComposeFile` entries resolved against `devcontainer_dir`,
+    /// keeping only files that exist, in declaration order.
+    pub fn compose_files(&self, devcontainer_dir: &Path) -> Vec<PathBuf> {
+        self.docker_compose_file
+            .as_ref()
+            .map(StringOrArray::to_vec)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|candidate| devcontainer_dir.join(candidate))
+            .filter(|path| path.is_file())
+            .collect()
+    }
+
+    /// Workspace folder inside the container. Falls back to the spec
+    /// default `/workspaces/<project dir name>` for image- and
+    /// Dockerfile-based configs; compose-based configs take theirs from the
+    /// service.
+    pub fn container_workspace_folder(&self, project_root: &Path) -> Option<String> {
+        if let Some(folder) = &self.workspace_folder {
+            return Some(folder.clone());
+        }
+        if self.uses_compose() {
+            return None;
+        }
+        let name = project_root.file_name()?.to_string_lossy();
+        Some(format!("/workspaces/{name}"))
+    }
+
+    /// `postCreateCommand` as exec arguments: a string runs through
+    /// `/bin/sh -c`, an array is executed directly.
+    pub fn post_create_args(&self) -> Option<Vec<String>> {
+        match self.post_create_command.as_ref()? {
+            StringOrArray::String(command) if command.trim().is_empty() => None,
+            StringOrArray::String(command) => Some(vec![
+                "/bin/sh".to_string(),
+                "-c".to_string(),
+                command.clone(),
+            ]),
+            StringOrArray::Array(args) if args.is_empty() => None,
+            StringOrArray::Array(args) => Some(args.clone()),
+        }
+    }
+
+    /// Feature ids declared in `features`.
+    pub fn feature_ids(&self) -> Vec<String> {
+        self.features.keys().cloned().collect()
+    }
+
+    /// `remoteEnv` with host-side variables substituted. `null` entries and
+    /// values referencing `${containerEnv:…}` (which only the container can
+    /// resolve) are left out.
+    pub fn remote_env_vars(
+        &self,
+        project_root: &Path,
+        container_workspace_folder: Option<&str>,
+    ) -> BTreeMap<String, String> {
+        self.remote_env_vars_with(project_root, container_workspace_folder, |name| {
+            std::env::var(name).ok()
+        })
+    }
+
+    fn remote_env_vars_with(
+        &self,
+        project_root: &Path,
+        container_workspace_folder: Option<&str>,
+        local_env: impl Fn(&str) -> Option<String>,
+    ) -> BTreeMap<String, String> {
+        self.remote_env
+            .iter()
+            .filter_map(|(key, value)| {
+                let value = value.as_deref()?;
+                let resolved = substitute_variables(
+                    value,
+                    project_root,
+                    container_workspace_folder,
+                    &local_env,
+                )?;
+                Some((key.clone(), resolved))
+            })
+            .collect()
+    }
+
+    /// Compose translation of an image- or Dockerfile-based devcontainer,
+    /// or `None` when the config is compose-based or names neither.
+    pub fn generated_compose_content(
+        &self,
+        devcontainer_dir: &Path,
+        project_root: &Path,
+    ) -> Option<String> {
+        if self.uses_compose() {
+            return None;
+        }
+        let source = if let Some(image) = &self.image {
+            format!("    image: {}\n", yaml_quote(image))
+        } else {
+            let build = self.build.as_ref()?;
+            let context = devcontainer_dir.join(build.context.as_deref().unwrap_or("."));
+            let dockerfile = devcontainer_dir.join(build.dockerfile.as_deref()?);
+            format!(
+                "    build:\n      context: {}\n      dockerfile: {}\n",
+                yaml_quote(&compose_path(&context)),
+                yaml_quote(&compose_path(&dockerfile)),
+            )
+        };
+        let workspace = self.container_workspace_folder(project_root)?;
+
+        let mut content = String::from(DEVCONTAINER_GENERATED_COMPOSE_HEADER);
+        content.push_str("services:\n");
+        content.push_str(&format!("  {DEVCONTAINER_SERVICE_NAME}:\n"));
+        content.push_str(&source);
+        content.push_str(&format!("    working_dir: {}\n", yaml_quote(&workspace)));
+        content.push_str("    command: [\"sleep\", \"infinity\"]\n");
+        content.push_str("    volumes:\n");
+        content.push_str(&format!(
+            "      - {}\n",
+            yaml_quote(&format!("{}:{workspace}", compose_path(project_root)))
+        ));
+        if !self.container_env.is_empty() {
+            content.push_str("    environment:\n");
+            for (key, value) in &self.container_env {
+                content.push_str(&format!("      {key}: {}\n", yaml_quote(value)));
+            }
+        }
+        let ports = self.get_forward_ports();
+        if !ports.is_empty() {
+            content.push_str("    ports:\n");
+            for port in ports {
+                content.push_str(&format!("      - \"{port}:{port}\"\n"));
+            }
+        }
+        Some(content)
+    }
+
+    /// Write [`Self::generated_compose_content`] to
+    /// [`DEVCONTAINER_GENERATED_COMPOSE_FILE_NAME`] in `project_root`,
+    /// rewriting it only when it changed. Returns `None` for compose-based
+    /// configs.
+    pub fn write_generated_compose(
+        &self,
+        devcontainer_dir: &Path,
+        project_root: &Path,
+    ) -> Result<Option<PathBuf>> {
+        let Some(content) = self.generated_compose_content(devcontainer_dir, project_root) else {
+            return Ok(None);
+        };
+        let path = project_root.join(DEVCONTAINER_GENERATED_COMPOSE_FILE_NAME);
+        if std::fs::read_to_string(&path).ok().as_deref() != Some(content.as_str()) {
+            std::fs::write(&path, content).map_err(|e| {
+                GwtError::Docker(format!(
+                    "failed to write {}: {e}",
+                    DEVCONTAINER_GENERATED_COMPOSE_FILE_NAME
+                ))
+            })?;
+        }
+        Ok(Some(path))
+    }
+}
+
+/// Resolve `${localEnv:NAME[:default]}`, `${localWorkspaceFolder}`,
+/// `${localWorkspaceFolderBasename}` and `${containerWorkspaceFolder}`.
+/// Returns `None` when the value needs `${containerEnv:…}`.
+fn substitute_variables(
+    value: &str,
+    project_root: &Path,
+    container_workspace_folder: Option<&str>,
+    local_env: &impl Fn(&str) -> Option<String>,
+) -> Option<String> {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find('}') else {
+            out.push_str(&rest[start..]);
+            return Some(out);
+        };
+        let variable = &after[..end];
+        let resolved = match variable.split_once(':') {
+            Some(("localEnv" | "env", spec)) => {
+                let (name, default) = match spec.split_once(':') {
+                    Some((name, default)) => (name, default),
+                    None => (spec, ""),
+                };
+                local_env(name).unwrap_or_else(|| default.to_string())
+            }
+            Some(("containerEnv", _)) => return None,
+            _ => match variable {
+                "localWorkspaceFolder" => project_root.display().to_string(),
+                "localWorkspaceFolderBasename" => project_root
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default(),
+                "containerWorkspaceFolder" => container_workspace_folder?.to_string(),
+                _ => format!("${{{variable}}}"),
+            },
+        };
+        out.push_str(&resolved);
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
+fn compose_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+fn yaml_quote(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("\"{value}\""))
 }
 
 /// Strip single-line (`//`) and multi-line (`/* */`) comments from JSONC.
@@ -280,4 +520,149 @@ mod tests {
         let cfg = DevContainerConfig::default();
         assert!(cfg.get_forward_ports().is_empty());
     }
+
+    #[test]
+    fn load_features_and_remote_env() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("devcontainer.json");
+        std::fs::write(
+            &path,
+            r#"{
+                "image": "mcr.microsoft.com/devcontainers/base:ubuntu",
+                // Features are reported, not installed.
+                "features": {
+                    "ghcr.io/devcontainers/features/node:1": { "version": "20" },
+                    "ghcr.io/devcontainers/features/rust:1": {}
+                },
+                "remoteEnv": {
+                    "TOKEN_FROM_HOST": "${localEnv:GWT_TEST_TOKEN}",
+                    "FALLBACK": "${localEnv:GWT_TEST_MISSING:none}",
+                    "WORKSPACE": "${containerWorkspaceFolder}/src",
+                    "EXTENDED_PATH": "${containerEnv:PATH}:/opt/bin",
+                    "UNSET_ME": null
+                }
+            }"#,
+        )
+        .unwrap();
+        let cfg = DevContainerConfig::load(&path).unwrap();
+        assert_eq!(
+            cfg.feature_ids(),
+            vec![
+                "ghcr.io/devcontainers/features/node:1",
+                "ghcr.io/devcontainers/features/rust:1",
+            ]
+        );
+
+        let project = Path::new("/home/dev/repo");
+        let workspace = cfg.container_workspace_folder(project);
+        assert_eq!(workspace.as_deref(), Some("/workspaces/repo"));
+        let env = cfg.remote_env_vars_with(project, workspace.as_deref(), |name| {
+            (name == "GWT_TEST_TOKEN").then(|| "abc".to_string())
+        });
+        assert_eq!(
+            env,
+            BTreeMap::from([
+                ("FALLBACK".to_string(), "none".to_string()),
+                ("TOKEN_FROM_HOST".to_string(), "abc".to_string()),
+                ("WORKSPACE".to_string(), "/workspaces/repo/src".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn post_create_args_wraps_strings_in_shell() {
+        let shell = DevContainerConfig {
+            post_create_command: Some(StringOrArray::String("npm ci && npm test".into())),
+            ..Default::default()
+        };
+        assert_eq!(
+            shell.post_create_args(),
+            Some(vec![
+                "/bin/sh".to_string(),
+                "-c".to_string(),
+                "npm ci && npm test".to_string()
+            ])
+        );
+        let exec = DevContainerConfig {
+            post_create_command: Some(StringOrArray::Array(vec!["make".into(), "setup".into()])),
+            ..Default::default()
+        };
+        assert_eq!(
+            exec.post_create_args(),
+            Some(vec!["make".to_string(), "setup".to_string()])
+        );
+        assert_eq!(DevContainerConfig::default().post_create_args(), None);
+    }
+
+    #[test]
+    fn compose_files_resolve_relative_to_devcontainer_dir() {
+        let tmp = TempDir::new().unwrap();
+        let dir = tmp.path().join(".devcontainer");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(tmp.path().join("docker-compose.yml"), "services: {}\n").unwrap();
+        let cfg = DevContainerConfig {
+            docker_compose_file: Some(StringOrArray::Array(vec![
+                "missing.yml".into(),
+                "../docker-compose.yml".into(),
+            ])),
+            ..Default::default()
+        };
+        assert_eq!(
+            cfg.compose_files(&dir),
+            vec![dir.join("../docker-compose.yml")]
+        );
+        assert!(cfg.generated_compose_content(&dir, tmp.path()).is_none());
+        assert_eq!(cfg.container_workspace_folder(tmp.path()), None);
+    }
+
+    #[test]
+    fn image_config_generates_single_service_compose() {
+        let tmp = TempDir::new().unwrap();
+        let project = tmp.path().join("repo");
+        let dir = project.join(".devcontainer");
+        std::fs::create_dir_all(&dir).unwrap();
+        let cfg = DevContainerConfig {
+            image: Some("node:20".into()),
+            forward_ports: Some(vec![3000]),
+            container_env: BTreeMap::from([("NODE_ENV".to_string(), "development".to_string())]),
+            ..Default::default()
+        };
+
+        let path = cfg
+            .write_generated_compose(&dir, &project)
+            .unwrap()
+            .expect("generated compose");
+        assert_eq!(path, project.join(DEVCONTAINER_GENERATED_COMPOSE_FILE_NAME));
+        let services = crate::parse_compose_file(&path).unwrap();
+        assert_eq!(services.len(), 1);
+        let service = &services[0];
+        assert_eq!(service.name, DEVCONTAINER_SERVICE_NAME);
+        assert_eq!(service.image.as_deref(), Some("node:20"));
+        assert_eq!(service.working_dir.as_deref(), Some("/workspaces/repo"));
+        assert_eq!(service.ports, vec!["3000:3000"]);
+        assert_eq!(service.volumes[0].target, "/workspaces/repo");
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("NODE_ENV: \"development\""));
+    }
+
+    #[test]
+    fn dockerfile_config_generates_build_section() {
+        let tmp = TempDir::new().unwrap();
+        let dir = tmp.path().join(".devcontainer");
+        let cfg = DevContainerConfig {
+            build: Some(BuildConfig {
+                dockerfile: Some("Dockerfile".into()),
+                context: Some("..".into()),
+            }),
+            workspace_folder: Some("/work".into()),
+            ..Default::default()
+        };
+        let content = cfg.generated_compose_content(&dir, tmp.path()).unwrap();
+        assert!(content.contains("    build:\n"));
+        assert!(content.contains(&compose_path(&dir.join("Dockerfile"))));
+        assert!(content.contains("working_dir: \"/work\""));
+        assert!(DevContainerConfig::default()
+            .generated_compose_content(&dir, tmp.path())
+            .is_none());
+    }
 }
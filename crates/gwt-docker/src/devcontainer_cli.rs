@@ -0,0 +1,251 @@
+//! `devcontainer` CLI integration.
+//!
+//! When a project has `.devcontainer/devcontainer.json` and the
+//! [devcontainer CLI](https://github.com/devcontainers/cli) is installed,
+//! Launch Agent starts the container with `devcontainer up` and runs the
+//! agent with `devcontainer exec`. Features, lifecycle hooks, `remoteEnv`,
+//! and `remoteUser` mapping then behave exactly as in VS Code. Without the
+//! CLI, launches use the compose translation in [`crate::devcontainer`].
+//!
+//! `GWT_DEVCONTAINER_BIN` overrides the CLI path; `GWT_DEVCONTAINER_CLI=0`
+//! forces the compose path even when the CLI is installed.
+
+use std::{ffi::OsString, path::Path, process::Output};
+
+use gwt_core::{GwtError, Result};
+use serde::Deserialize;
+
+use crate::container::{
+    docker_compose_exec_timeout, docker_compose_up_timeout, docker_status_timeout,
+    run_program_with_output_streaming,
+};
+
+/// Result of a successful `devcontainer up`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DevContainerUp {
+    pub container_id: String,
+    /// User the CLI runs `exec` commands as.
+    pub remote_user: Option<String>,
+    /// Workspace folder inside the container.
+    pub remote_workspace_folder: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpOutcome {
+    outcome: String,
+    #[serde(default)]
+    container_id: Option<String>,
+    #[serde(default)]
+    remote_user: Option<String>,
+    #[serde(default)]
+    remote_workspace_folder: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+/// Program used for devcontainer CLI calls.
+pub fn devcontainer_binary() -> OsString {
+    std::env::var_os("GWT_DEVCONTAINER_BIN").unwrap_or_else(|| OsString::from("devcontainer"))
+}
+
+/// Whether launches should go through the devcontainer CLI: it is not
+/// disabled with `GWT_DEVCONTAINER_CLI=0` and `devcontainer --version`
+/// runs.
+pub fn devcontainer_cli_available() -> bool {
+    if std::env::var("GWT_DEVCONTAINER_CLI").is_ok_and(|value| value.trim() == "0") {
+        return false;
+    }
+    run_devcontainer(
+        &["--version"],
+        "devcontainer version",
+        docker_status_timeout,
+    )
+    .is_ok_and(|output| output.status.success())
+}
+
+/// `devcontainer up` for `workspace`. `remove_existing` recreates the
+/// container; each `mounts` entry is passed as `--mount` (Docker `--mount`
+/// syntax).
+pub fn devcontainer_up(
+    workspace: &Path,
+    remove_existing: bool,
+    mounts: &[String],
+) -> Result<DevContainerUp> {
+    let workspace = workspace.display().to_string();
+    let mut args = vec!["up", "--workspace-folder", workspace.as_str()];
+    if remove_existing {
+        args.push("--remove-existing-container");
+    }
+    for mount in mounts {
+        args.push("--mount");
+        args.push(mount);
+    }
+    let output = run_devcontainer(&args, "devcontainer up", docker_compose_up_timeout)?;
+    parse_up_output(&output)
+}
+
+/// Arguments for `devcontainer exec` of `command` in `workspace`'s
+/// container, with `env` passed as `--remote-env`.
+pub fn devcontainer_exec_args<'a>(
+    workspace: &Path,
+    env: impl IntoIterator<Item = (&'a str, &'a str)>,
+    command: &[String],
+) -> Vec<String> {
+    let mut args = vec![
+        "exec".to_string(),
+        "--workspace-folder".to_string(),
+        workspace.display().to_string(),
+    ];
+    for (key, value) in env {
+        args.push("--remote-env".to_string());
+        args.push(format!("{key}={value}"));
+    }
+    args.extend(command.iter().cloned());
+    args
+}
+
+/// Run `command` in `workspace`'s container and capture its output. A
+/// non-zero exit of the command itself is returned via `Output`.
+pub fn devcontainer_exec_capture(workspace: &Path, command: &[String]) -> Result<Output> {
+    let args = devcontainer_exec_args(workspace, [], command);
+    let arg_refs = args.iter().map(String::as_str).collect::<Vec<_>>();
+    run_devcontainer(&arg_refs, "devcontainer exec", docker_compose_exec_timeout)
+}
+
+/// Whether `command` resolves inside `workspace`'s container.
+pub fn devcontainer_has_command(workspace: &Path, command: &str) -> Result<bool> {
+    let output = devcontainer_exec_capture(
+        workspace,
+        &[
+            "sh".to_string(),
+            "-lc".to_string(),
+            "command -v \"$1\" >/dev/null 2>&1".to_string(),
+            "sh".to_string(),
+            command.to_string(),
+        ],
+    )?;
+    Ok(output.status.success())
+}
+
+fn run_devcontainer(
+    args: &[&str],
+    action: &str,
+    timeout: fn() -> std::time::Duration,
+) -> Result<Output> {
+    run_program_with_output_streaming(
+        devcontainer_binary(),
+        "devcontainer",
+        args,
+        action,
+        None,
+        timeout(),
+        |_, _| {},
+    )
+}
+
+/// `devcontainer up` logs to stderr and prints one JSON outcome line on
+/// stdout.
+fn parse_up_output(output: &Output) -> Result<DevContainerUp> {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let outcome = stdout
+        .lines()
+        .rev()
+        .map(str::trim)
+        .filter(|line| line.starts_with('{'))
+        .find_map(|line| serde_json::from_str::<UpOutcome>(line).ok());
+    match outcome {
+        Some(outcome) if outcome.outcome == "success" => Ok(DevContainerUp {
+            container_id: outcome.container_id.unwrap_or_default(),
+            remote_user: outcome.remote_user,
+            remote_workspace_folder: outcome.remote_workspace_folder,
+        }),
+        Some(outcome) => {
+            let detail = [outcome.message, outcome.description]
+                .into_iter()
+                .flatten()
+                .filter(|text| !text.trim().is_empty())
+                .collect::<Vec<_>>()
+                .join(": ");
+            Err(GwtError::Docker(format!(
+                "devcontainer up failed: {detail}"
+            )))
+        }
+        None => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let last = stderr.lines().rev().find(|line| !line.trim().is_empty());
+            Err(GwtError::Docker(format!(
+                "devcontainer up failed with {}{}",
+                output.status,
+                last.map(|line| format!(": {}", line.trim()))
+                    .unwrap_or_default()
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    fn output(stdout: &str, code: i32) -> Output {
+        use std::os::unix::process::ExitStatusExt;
+        Output {
+            status: std::process::ExitStatus::from_raw(code << 8),
+            stdout: stdout.as_bytes().to_vec(),
+            stderr: b"[1 ms] Start: Run: docker build\n".to_vec(),
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn up_output_is_parsed_from_the_last_json_line() {
+        let up = parse_up_output(&output(
+            "log line\n{\"outcome\":\"success\",\"containerId\":\"abc\",\"remoteUser\":\"node\",\"remoteWorkspaceFolder\":\"/workspaces/app\"}\n",
+            0,
+        ))
+        .unwrap();
+        assert_eq!(
+            up,
+            DevContainerUp {
+                container_id: "abc".to_string(),
+                remote_user: Some("node".to_string()),
+                remote_workspace_folder: Some("/workspaces/app".to_string()),
+            }
+        );
+
+        let error = parse_up_output(&output(
+            "{\"outcome\":\"error\",\"message\":\"Command failed\",\"description\":\"postCreateCommand exited 1\"}\n",
+            1,
+        ))
+        .unwrap_err();
+        assert!(error.to_string().contains("postCreateCommand exited 1"));
+
+        let error = parse_up_output(&output("", 1)).unwrap_err();
+        assert!(error.to_string().contains("docker build"));
+    }
+
+    #[test]
+    fn exec_args_pass_env_as_remote_env() {
+        let args = devcontainer_exec_args(
+            Path::new("/repo/wt"),
+            [("TERM", "xterm-256color")],
+            &["claude".to_string(), "--print".to_string()],
+        );
+        assert_eq!(
+            args,
+            [
+                "exec",
+                "--workspace-folder",
+                "/repo/wt",
+                "--remote-env",
+                "TERM=xterm-256color",
+                "claude",
+                "--print",
+            ]
+        );
+    }
+}
@@ -7,14 +7,16 @@ pub mod compose;
 pub mod container;
 pub mod detect;
 pub mod devcontainer;
+pub mod devcontainer_cli;
 pub mod port;
 
-pub use compose::{parse_compose_file, ComposeService};
+pub use compose::{compose_profiles, parse_compose_file, ComposeService};
 pub use container::{
-    compose_restart, compose_restart_with_files, compose_service_exec_capture,
-    compose_service_exec_capture_with_files, compose_service_has_command,
-    compose_service_has_command_with_files, compose_service_is_running,
-    compose_service_is_running_with_files, compose_service_logs, compose_service_status,
+    compose_project_args, compose_restart, compose_restart_with_files,
+    compose_service_exec_capture, compose_service_exec_capture_with_files,
+    compose_service_has_command, compose_service_has_command_with_files,
+    compose_service_is_running, compose_service_is_running_with_files, compose_service_logs,
+    compose_service_run_lifecycle_command_with_files, compose_service_status,
     compose_service_status_with_files, compose_service_user_is_root,
     compose_service_user_is_root_with_files, compose_stop, compose_up, compose_up_force_recreate,
     compose_up_force_recreate_with_files, compose_up_force_recreate_with_files_output,
@@ -26,8 +28,17 @@ pub use detect::{
     compose_available, daemon_running, detect_docker_files, docker_available, launch_preflight,
     DockerFiles,
 };
-pub use devcontainer::DevContainerConfig;
-pub use port::{check_port_available, PortAllocator, PortMapping};
+pub use devcontainer::{
+    DevContainerConfig, DEVCONTAINER_GENERATED_COMPOSE_FILE_NAME, DEVCONTAINER_SERVICE_NAME,
+};
+pub use devcontainer_cli::{
+    devcontainer_cli_available, devcontainer_exec_args, devcontainer_exec_capture,
+    devcontainer_has_command, devcontainer_up, DevContainerUp,
+};
+pub use port::{
+    check_port_available, parse_published_port, port_assignment_key, port_override_content,
+    PortAllocator, PortAssignments, PortMapping, PublishedPort,
+};
 
 /// Crate-wide lock for tests that mutate the process-global
 /// `GWT_DOCKER_BIN` / docker timeout env vars. `detect` and `container`
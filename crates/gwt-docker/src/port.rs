@@ -1,12 +1,25 @@
 //! Port management utilities.
 //!
 //! Provides port availability checks and an allocator for finding free host
-//! ports when running Docker containers.
+//! ports when running Docker containers, including remapping the published
+//! ports of a compose project so several worktrees can run it side by side.
 
-use std::net::TcpListener;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    net::TcpListener,
+};
 
 use tracing::debug;
 
+use crate::compose::ComposeService;
+
+/// Host port chosen for each fixed published port of a compose project,
+/// keyed by [`port_assignment_key`].
+pub type PortAssignments = BTreeMap<String, u16>;
+
+const PORT_OVERRIDE_HEADER: &str =
+    "# Auto-generated by gwt to remap conflicting host ports; do not edit\n";
+
 /// A mapping between a host port and a container port.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PortMapping {
@@ -28,6 +41,88 @@ impl PortMapping {
     }
 }
 
+/// A short-syntax port entry that publishes a single fixed host port, e.g.
+/// `127.0.0.1:8080:80/tcp`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublishedPort {
+    /// Host interface, including brackets for IPv6.
+    pub host_ip: Option<String>,
+    /// Published host port.
+    pub host_port: u16,
+    /// Container side as written: port or range, plus any `/protocol`.
+    pub container: String,
+}
+
+impl PublishedPort {
+    /// Short-syntax entry publishing the same container side on `host_port`.
+    pub fn with_host_port(&self, host_port: u16) -> String {
+        match &self.host_ip {
+            Some(ip) => format!("{ip}:{host_port}:{}", self.container),
+            None => format!("{host_port}:{}", self.container),
+        }
+    }
+}
+
+/// Parse a short-syntax port entry. `None` for container-only ports and
+/// host port ranges, which never need remapping.
+pub fn parse_published_port(spec: &str) -> Option<PublishedPort> {
+    let mut parts = spec.trim().rsplitn(3, ':');
+    let container = parts.next()?.to_string();
+    let host_port = parts.next()?.parse().ok().filter(|port| *port != 0)?;
+    let host_ip = parts.next().map(str::to_string);
+    Some(PublishedPort {
+        host_ip,
+        host_port,
+        container,
+    })
+}
+
+/// Key under which `service`'s published `host_port` is assigned.
+pub fn port_assignment_key(service: &str, host_port: u16) -> String {
+    format!("{service}/{host_port}")
+}
+
+/// Compose override that republishes every service with a remapped port on
+/// its assigned host ports, or `None` when all ports keep their original
+/// number. `ports: !override` replaces the base list instead of appending to
+/// it, which needs Docker Compose 2.24 or later.
+pub fn port_override_content(
+    services: &[ComposeService],
+    assignments: &PortAssignments,
+) -> Option<String> {
+    let mut content = String::from(PORT_OVERRIDE_HEADER);
+    content.push_str("services:\n");
+    let mut remapped_any = false;
+    for service in services {
+        let mut remapped = false;
+        let ports = service
+            .ports
+            .iter()
+            .map(|spec| {
+                let Some(published) = parse_published_port(spec) else {
+                    return spec.clone();
+                };
+                match assignments.get(&port_assignment_key(&service.name, published.host_port)) {
+                    Some(&port) if port != published.host_port => {
+                        remapped = true;
+                        published.with_host_port(port)
+                    }
+                    _ => spec.clone(),
+                }
+            })
+            .collect::<Vec<_>>();
+        if !remapped {
+            continue;
+        }
+        remapped_any = true;
+        content.push_str(&format!("  {}:\n    ports: !override\n", service.name));
+        for port in ports {
+            content.push_str(&format!("      - \"{port}\"\n"));
+        }
+    }
+    remapped_any.then_some(content)
+}
+
 /// Check whether a port is available for binding on localhost.
 pub fn check_port_available(port: u16) -> bool {
     TcpListener::bind(("127.0.0.1", port)).is_ok()
@@ -72,13 +167,23 @@ impl PortAllocator {
     /// Searches incrementally within the configured range, up to
     /// `MAX_SEARCH_ATTEMPTS` tries.
     pub fn find_available(&self, base_port: u16) -> Option<u16> {
+        self.find_available_excluding(base_port, &BTreeSet::new())
+    }
+
+    /// Like [`Self::find_available`], but never returns a port in `excluded`
+    /// (ports promised elsewhere that may not be bound yet).
+    pub fn find_available_excluding(
+        &self,
+        base_port: u16,
+        excluded: &BTreeSet<u16>,
+    ) -> Option<u16> {
         let start = base_port.max(self.range_start);
         for offset in 0..MAX_SEARCH_ATTEMPTS {
             let port = start.saturating_add(offset);
             if port > self.range_end {
                 break;
             }
-            if check_port_available(port) {
+            if !excluded.contains(&port) && check_port_available(port) {
                 debug!(category = "docker", port = port, "found available port");
                 return Some(port);
             }
@@ -90,6 +195,63 @@ impl PortAllocator {
         );
         None
     }
+
+    /// Host port for every fixed published port of `services`.
+    ///
+    /// Ports in `previous` are kept so a worktree gets the same ports on
+    /// every launch, even while its own containers hold them. New ports keep
+    /// their original number when it is free and not in `reserved` (ports
+    /// held by other worktrees), otherwise the next free port above it. When
+    /// nothing is free the original is kept and compose reports the clash.
+    pub fn assign_compose_ports(
+        &self,
+        services: &[ComposeService],
+        previous: &PortAssignments,
+        reserved: &BTreeSet<u16>,
+    ) -> PortAssignments {
+        let requested = services
+            .iter()
+            .flat_map(|service| {
+                service.ports.iter().filter_map(|spec| {
+                    parse_published_port(spec).map(|published| {
+                        (
+                            port_assignment_key(&service.name, published.host_port),
+                            published.host_port,
+                        )
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut assigned = PortAssignments::new();
+        let mut taken = reserved.clone();
+        for (key, _) in &requested {
+            if let Some(&port) = previous.get(key).filter(|port| !reserved.contains(port)) {
+                assigned.insert(key.clone(), port);
+                taken.insert(port);
+            }
+        }
+        for (key, original) in requested {
+            if assigned.contains_key(&key) {
+                continue;
+            }
+            let port = if !taken.contains(&original) && check_port_available(original) {
+                original
+            } else {
+                self.find_available_excluding(original.saturating_add(1), &taken)
+                    .unwrap_or(original)
+            };
+            debug!(
+                category = "docker",
+                key = key.as_str(),
+                port = port,
+                "assigned compose host port"
+            );
+            assigned.insert(key, port);
+            taken.insert(port);
+        }
+        assigned
+    }
 }
 
 #[cfg(test)]
@@ -135,6 +297,90 @@ mod tests {
         assert!((30000..=30100).contains(&p));
     }
 
+    fn service(name: &str, ports: &[&str]) -> ComposeService {
+        ComposeService {
+            name: name.to_string(),
+            image: None,
+            platform: None,
+            ports: ports.iter().map(|port| port.to_string()).collect(),
+            depends_on: Vec::new(),
+            working_dir: None,
+            volumes: Vec::new(),
+            profiles: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn parse_published_port_handles_short_syntax() {
+        let published = parse_published_port("127.0.0.1:8080:80/udp").unwrap();
+        assert_eq!(published.host_ip.as_deref(), Some("127.0.0.1"));
+        assert_eq!(published.host_port, 8080);
+        assert_eq!(published.with_host_port(9090), "127.0.0.1:9090:80/udp");
+        assert_eq!(
+            parse_published_port("[::1]:8080:80")
+                .unwrap()
+                .host_ip
+                .as_deref(),
+            Some("[::1]")
+        );
+        assert!(parse_published_port("80").is_none());
+        assert!(parse_published_port("8000-8010:8000-8010").is_none());
+    }
+
+    #[test]
+    fn assign_compose_ports_remaps_busy_and_reserved_ports() {
+        let busy = TcpListener::bind("127.0.0.1:0").unwrap();
+        let busy_port = busy.local_addr().unwrap().port();
+        let free = TcpListener::bind("127.0.0.1:0").unwrap();
+        let free_port = free.local_addr().unwrap().port();
+        drop(free);
+        let services = [service(
+            "web",
+            &[
+                &format!("{busy_port}:80"),
+                &format!("{free_port}:81"),
+                "9229",
+            ],
+        )];
+        let alloc = PortAllocator::with_range(1024, 65535);
+
+        let assigned =
+            alloc.assign_compose_ports(&services, &PortAssignments::new(), &BTreeSet::new());
+        let web_busy = assigned[&port_assignment_key("web", busy_port)];
+        assert_ne!(web_busy, busy_port);
+        assert_eq!(assigned[&port_assignment_key("web", free_port)], free_port);
+        assert_eq!(assigned.len(), 2);
+
+        // A previous assignment is kept even though the port is now in use.
+        let again = alloc.assign_compose_ports(&services, &assigned, &BTreeSet::new());
+        assert_eq!(again, assigned);
+
+        // A port reserved by another worktree is never handed out.
+        let reserved = BTreeSet::from([free_port]);
+        let other = alloc.assign_compose_ports(&services, &PortAssignments::new(), &reserved);
+        assert_ne!(other[&port_assignment_key("web", free_port)], free_port);
+        drop(busy);
+    }
+
+    #[test]
+    fn port_override_lists_full_port_set_of_remapped_services() {
+        let services = [
+            service("web", &["8080:80", "9229"]),
+            service("db", &["5432:5432"]),
+        ];
+        let assignments = PortAssignments::from([
+            (port_assignment_key("web", 8080), 8081),
+            (port_assignment_key("db", 5432), 5432),
+        ]);
+        let content = port_override_content(&services, &assignments).unwrap();
+        assert!(content
+            .contains("  web:\n    ports: !override\n      - \"8081:80\"\n      - \"9229\"\n"));
+        assert!(!content.contains("db:"));
+
+        let unchanged = PortAssignments::from([(port_assignment_key("web", 8080), 8080)]);
+        assert!(port_override_content(&services, &unchanged).is_none());
+    }
+
     #[test]
     fn default_allocator() {
         let alloc = PortAllocator::default();
@@ -191,7 +191,7 @@ fn detect_cleanable_target_for_remote(
 /// but must not look "safe to delete". Used by the Workspace surface
 /// "safe to delete" badge; run it off the UI hot path (background scan).
 pub fn merged_base_target(repo_path: &Path, branch: &str) -> Result<Option<MergeTargetRef>> {
-    if is_protected_branch(branch) {
+    if crate::is_protected_branch_in(repo_path, branch) {
         return Ok(None);
     }
     let (_, target) = detect_cleanable_target_for_remote(repo_path, branch, "origin")?;
@@ -205,7 +205,7 @@ pub fn cleanup_readiness_base_target(
     repo_path: &Path,
     branch: &str,
 ) -> Result<Option<CleanupReadinessTarget>> {
-    if is_protected_branch(branch) {
+    if crate::is_protected_branch_in(repo_path, branch) {
         return Ok(None);
     }
     for (base, target) in CANONICAL_BASE_BRANCHES {
@@ -308,7 +308,14 @@ pub fn list_gone_branches(repo_path: &Path) -> Result<HashSet<String>> {
 /// uses `git branch -D`, otherwise `git branch -d` (which refuses to delete
 /// unmerged branches). The function is a no-op when the branch does not
 /// exist so cleanup runs remain idempotent against half-cleaned state.
+/// Branches protected on GitHub (see [`crate::is_synced_protected_branch`])
+/// are refused; main / master / develop stay deletable locally (FR-070).
 pub fn delete_local_branch(repo_path: &Path, name: &str, force: bool) -> Result<()> {
+    if crate::is_synced_protected_branch(repo_path, name) {
+        return Err(GwtError::Git(format!(
+            "refusing to delete protected branch: {name}"
+        )));
+    }
     if !ref_exists(repo_path, &format!("refs/heads/{name}"))? {
         return Ok(());
     }
@@ -1080,6 +1087,21 @@ mod tests {
         delete_local_branch(repo, "feature/nope", true).unwrap();
     }
 
+    #[test]
+    fn delete_local_branch_refuses_branches_protected_on_github() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path();
+        init_named_repo(repo);
+        run(&["branch", "release/1.x"], repo);
+        crate::branch_protection::save_synced_protected_branches(
+            repo,
+            &["release/1.x".to_string()],
+        )
+        .unwrap();
+        assert!(delete_local_branch(repo, "release/1.x", true).is_err());
+        assert!(ref_exists(repo, "refs/heads/release/1.x").unwrap());
+    }
+
     #[test]
     fn parse_cherry_output_treats_minus_lines_as_merged() {
         assert!(parse_cherry_output(""));
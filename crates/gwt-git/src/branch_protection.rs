@@ -8,6 +8,28 @@
 //! wired in a later phase; the pure classifier here is the testable core and
 //! must fail closed: anything other than a fully verified protection is
 //! gate-unavailable.
+//!
+//! The module also keeps the list of branches GitHub reports as protected,
+//! synced into the repository's git directory, so [`is_protected_branch_in`]
+//! guards them like the built-in protected branches.
+//!
+//! [`ProtectionPolicy`] adds the `protected_branches` patterns from the
+//! settings: worktree cleanup, local and remote branch deletion refuse a
+//! matching branch unless the user typed its name to confirm.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use globset::{GlobBuilder, GlobMatcher};
+use gwt_core::{GwtError, Result};
+use serde::{Deserialize, Serialize};
+
+/// File in the repository's common git directory holding the protected
+/// branches last synced from GitHub.
+pub const SYNCED_PROTECTED_BRANCHES_FILE: &str = "gwt-protected-branches.json";
 
 /// Branch-protection status relevant to autonomous-mode eligibility (FR-010).
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -150,10 +172,234 @@ pub fn fetch_branch_protection(repo_slug: &str, branch: &str) -> BranchProtectio
     classify_branch_protection_fetch(output.success(), &output.stdout, &output.stderr)
 }
 
+/// Protected branches last synced from GitHub for one repository.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncedProtectedBranches {
+    pub synced_at: DateTime<Utc>,
+    pub branches: Vec<String>,
+}
+
+/// Names of the branches GitHub reports as protected, by branch protection
+/// or a ruleset, for the repository checked out at `repo_path`.
+pub fn fetch_protected_branch_names(repo_path: &Path) -> Result<Vec<String>> {
+    let hub = gwt_core::process_console::global();
+    let endpoint = "repos/{owner}/{repo}/branches?protected=true&per_page=100";
+    let args = ["api", "--paginate", endpoint, "--jq", ".[].name"];
+    let output = gwt_core::process_console::spawn_logged_blocking(
+        &hub,
+        gwt_core::process_console::ProcessKind::Gh,
+        "gh",
+        &args,
+        gwt_core::process_console::SpawnOptions::new("gh api protected branches")
+            .current_dir(repo_path),
+    )
+    .map_err(|error| GwtError::Git(format!("gh api {endpoint}: {error}")))?;
+    if !output.success() {
+        return Err(GwtError::Git(format!(
+            "gh api {endpoint}: {}",
+            output.stderr.trim()
+        )));
+    }
+    Ok(parse_protected_branch_names(&output.stdout))
+}
+
+fn parse_protected_branch_names(stdout: &str) -> Vec<String> {
+    let mut names: Vec<String> = stdout
+        .lines()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Store `branches` as the synced protected branches of the repository
+/// that contains `repo_path`.
+pub fn save_synced_protected_branches(repo_path: &Path, branches: &[String]) -> Result<()> {
+    let output = gwt_core::process::run_git_logged(
+        &["rev-parse", "--path-format=absolute", "--git-common-dir"],
+        Some(repo_path),
+    )
+    .map_err(|error| GwtError::Git(format!("rev-parse: {error}")))?;
+    if !output.status.success() {
+        return Err(GwtError::Git(format!(
+            "rev-parse: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    let common_dir = PathBuf::from(String::from_utf8_lossy(&output.stdout).trim());
+    let synced = SyncedProtectedBranches {
+        synced_at: Utc::now(),
+        branches: branches.to_vec(),
+    };
+    let json = serde_json::to_string_pretty(&synced)
+        .map_err(|error| GwtError::Other(format!("protected branches: {error}")))?;
+    fs::write(common_dir.join(SYNCED_PROTECTED_BRANCHES_FILE), json)?;
+    Ok(())
+}
+
+/// The synced protected branches of the repository that contains
+/// `repo_path`, if a sync has run. Reads the `.git` entry directly instead
+/// of running git, since guards call this for every branch they check.
+pub fn load_synced_protected_branches(repo_path: &Path) -> Option<SyncedProtectedBranches> {
+    let path = common_git_dir(repo_path)?.join(SYNCED_PROTECTED_BRANCHES_FILE);
+    serde_json::from_str(&fs::read_to_string(path).ok()?).ok()
+}
+
+/// Whether `name` (or `origin/<name>`) is one of the branches synced from
+/// GitHub for the repository that contains `repo_path`.
+pub fn is_synced_protected_branch(repo_path: &Path, name: &str) -> bool {
+    let bare = name.strip_prefix("origin/").unwrap_or(name);
+    load_synced_protected_branches(repo_path)
+        .is_some_and(|synced| synced.branches.iter().any(|branch| branch == bare))
+}
+
+/// [`crate::is_protected_branch`], plus the branches synced from GitHub for
+/// the repository that contains `repo_path`.
+pub fn is_protected_branch_in(repo_path: &Path, name: &str) -> bool {
+    crate::is_protected_branch(name) || is_synced_protected_branch(repo_path, name)
+}
+
+/// Destructive operation checked by [`ProtectionPolicy::check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DestructiveOp {
+    /// Remove the branch's worktree and delete the local branch.
+    CleanupBranch,
+    DeleteRemoteBranch,
+    RemoveWorktree,
+}
+
+impl DestructiveOp {
+    fn verb(self) -> &'static str {
+        match self {
+            Self::CleanupBranch => "clean up",
+            Self::DeleteRemoteBranch => "delete the remote of",
+            Self::RemoveWorktree => "remove the worktree of",
+        }
+    }
+}
+
+/// Branch patterns (`release/*`, `hotfix/**`, or plain names) that
+/// destructive operations refuse, plus the branches the user confirmed by
+/// typing their names. The default policy protects nothing.
+#[derive(Debug, Clone, Default)]
+pub struct ProtectionPolicy {
+    patterns: Vec<(String, GlobMatcher)>,
+    confirmed: Vec<String>,
+}
+
+impl ProtectionPolicy {
+    /// Policy for `patterns`. `*` stops at `/` and `**` does not; patterns
+    /// that are not valid globs match their exact text.
+    pub fn new(patterns: &[String]) -> Self {
+        let patterns = patterns
+            .iter()
+            .map(|pattern| pattern.trim())
+            .filter(|pattern| !pattern.is_empty())
+            .map(|pattern| {
+                let matcher = GlobBuilder::new(pattern)
+                    .literal_separator(true)
+                    .build()
+                    .or_else(|_| GlobBuilder::new(&globset::escape(pattern)).build())
+                    .expect("an escaped pattern is a valid glob")
+                    .compile_matcher();
+                (pattern.to_string(), matcher)
+            })
+            .collect();
+        Self {
+            patterns,
+            confirmed: Vec::new(),
+        }
+    }
+
+    /// Let the operations through for `branches`, each typed in full by the
+    /// user.
+    pub fn with_confirmed(mut self, branches: impl IntoIterator<Item = String>) -> Self {
+        self.confirmed.extend(branches);
+        self
+    }
+
+    /// The first pattern protecting `branch` (or `origin/<branch>`).
+    pub fn matching_pattern(&self, branch: &str) -> Option<&str> {
+        let bare = branch.strip_prefix("origin/").unwrap_or(branch);
+        self.patterns
+            .iter()
+            .find(|(_, matcher)| matcher.is_match(bare))
+            .map(|(pattern, _)| pattern.as_str())
+    }
+
+    /// Refuse `op` on a protected `branch` the user did not confirm.
+    pub fn check(&self, op: DestructiveOp, branch: &str) -> Result<()> {
+        let Some(pattern) = self.matching_pattern(branch) else {
+            return Ok(());
+        };
+        if self.confirmed.iter().any(|confirmed| confirmed == branch) {
+            return Ok(());
+        }
+        Err(GwtError::Git(format!(
+            "refusing to {} protected branch {branch} (matches `{pattern}`); \
+             type the branch name to confirm",
+            op.verb()
+        )))
+    }
+}
+
+/// Common git directory of the repository or worktree at `repo_path`.
+fn common_git_dir(repo_path: &Path) -> Option<PathBuf> {
+    let dot_git = repo_path.join(".git");
+    if dot_git.is_dir() {
+        return Some(dot_git);
+    }
+    let pointer = fs::read_to_string(&dot_git).ok()?;
+    let git_dir = repo_path.join(pointer.strip_prefix("gitdir:")?.trim());
+    match fs::read_to_string(git_dir.join("commondir")) {
+        Ok(common) => Some(git_dir.join(common.trim())),
+        Err(_) => Some(git_dir),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn protection_policy_matches_globs_and_requires_typed_confirmation() {
+        let policy = ProtectionPolicy::new(&[
+            "release/*".to_string(),
+            "hotfix/**".to_string(),
+            " staging ".to_string(),
+            "[broken".to_string(),
+        ]);
+        assert_eq!(policy.matching_pattern("release/1.x"), Some("release/*"));
+        assert_eq!(policy.matching_pattern("origin/staging"), Some("staging"));
+        assert_eq!(policy.matching_pattern("hotfix/a/b"), Some("hotfix/**"));
+        assert_eq!(policy.matching_pattern("[broken"), Some("[broken"));
+        assert_eq!(policy.matching_pattern("release/1.x/rc"), None);
+        assert_eq!(policy.matching_pattern("feature/release"), None);
+
+        let err = policy
+            .check(DestructiveOp::CleanupBranch, "release/1.x")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("matches `release/*`"), "{err}");
+        policy
+            .check(DestructiveOp::CleanupBranch, "feature/x")
+            .unwrap();
+
+        let confirmed = policy.with_confirmed(["release/1.x".to_string()]);
+        confirmed
+            .check(DestructiveOp::DeleteRemoteBranch, "release/1.x")
+            .unwrap();
+        assert!(confirmed
+            .check(DestructiveOp::RemoveWorktree, "release/2.x")
+            .is_err());
+        ProtectionPolicy::default()
+            .check(DestructiveOp::CleanupBranch, "release/1.x")
+            .unwrap();
+    }
+
     #[test]
     fn fully_protected_branch_is_verified() {
         let json = r#"{
@@ -269,4 +515,61 @@ mod tests {
         let status = classify_branch_protection_fetch(false, "", "could not resolve host");
         assert!(matches!(status, BranchProtectionStatus::Unreadable(_)));
     }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::run_git_logged(args, Some(dir)).unwrap();
+        assert!(output.status.success(), "git {args:?} failed");
+    }
+
+    #[test]
+    fn protected_branch_names_are_trimmed_sorted_and_unique() {
+        assert_eq!(
+            parse_protected_branch_names("release/1.x\nmain\n\n  main \n"),
+            ["main", "release/1.x"]
+        );
+    }
+
+    #[test]
+    fn synced_branches_are_protected_in_every_worktree() {
+        let temp = tempfile::tempdir().unwrap();
+        let repo = temp.path().join("repo");
+        std::fs::create_dir(&repo).unwrap();
+        git(&repo, &["init", "-q", "-b", "main"]);
+        git(
+            &repo,
+            &[
+                "-c",
+                "user.name=t",
+                "-c",
+                "user.email=t@example.com",
+                "commit",
+                "-q",
+                "--allow-empty",
+                "-m",
+                "init",
+            ],
+        );
+        let linked = temp.path().join("linked");
+        git(
+            &repo,
+            &[
+                "worktree",
+                "add",
+                "-q",
+                "-b",
+                "feature/x",
+                linked.to_str().unwrap(),
+            ],
+        );
+
+        assert!(load_synced_protected_branches(&linked).is_none());
+        assert!(!is_protected_branch_in(&linked, "release/1.x"));
+
+        save_synced_protected_branches(&linked, &["release/1.x".to_string()]).unwrap();
+        assert!(is_protected_branch_in(&repo, "release/1.x"));
+        assert!(is_protected_branch_in(&linked, "origin/release/1.x"));
+        assert!(is_protected_branch_in(&linked, "main"));
+        assert!(!is_protected_branch_in(&linked, "feature/x"));
+        assert!(!is_synced_protected_branch(&linked, "main"));
+    }
 }
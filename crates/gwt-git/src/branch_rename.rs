@@ -0,0 +1,445 @@
+//! Pattern-based bulk rename of local branches.
+//!
+//! A pattern pair such as `users/me/*` → `feature/*` selects every local
+//! branch matching the left side and maps it to the right side. For each
+//! branch the rename also moves its worktree when the worktree sits at the
+//! layout gwt derives from the branch name, and re-points the upstream when
+//! the remote already has a branch under the new name.
+//!
+//! [`plan_bulk_rename`] only reads; it reports every rename plus anything
+//! that would block it. [`apply_bulk_rename`] performs a conflict-free plan
+//! and records each step in a [`RenameJournal`]. The journal undoes
+//! everything done so far on failure, and callers that migrate their own
+//! metadata afterwards can roll it back too. Remote branches are never
+//! renamed or deleted.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::{Path, PathBuf},
+};
+
+use gwt_core::{GwtError, Result};
+use serde::Serialize;
+
+use crate::{
+    branch_protection::is_protected_branch_in,
+    worktree::{main_worktree_root, sibling_worktree_path_with_max_len, WorktreeManager},
+};
+
+/// `from` → `to` branch name mapping with at most one `*` on each side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenamePattern {
+    from_prefix: String,
+    from_suffix: String,
+    to_prefix: String,
+    to_suffix: String,
+    wildcard: bool,
+}
+
+impl RenamePattern {
+    /// Parse a pattern pair. Either both sides carry exactly one `*` (the
+    /// matched part is carried over) or neither does (a single rename).
+    pub fn parse(from: &str, to: &str) -> Result<Self> {
+        let (from, to) = (from.trim(), to.trim());
+        if from.is_empty() || to.is_empty() {
+            return Err(GwtError::Git(
+                "rename pattern needs both a source and a target".to_string(),
+            ));
+        }
+        let split = |pattern: &str| -> Result<Option<(String, String)>> {
+            match pattern.matches('*').count() {
+                0 => Ok(None),
+                1 => {
+                    let (prefix, suffix) = pattern.split_once('*').unwrap_or((pattern, ""));
+                    Ok(Some((prefix.to_string(), suffix.to_string())))
+                }
+                _ => Err(GwtError::Git(format!(
+                    "rename pattern {pattern} may contain at most one '*'"
+                ))),
+            }
+        };
+        match (split(from)?, split(to)?) {
+            (Some((from_prefix, from_suffix)), Some((to_prefix, to_suffix))) => Ok(Self {
+                from_prefix,
+                from_suffix,
+                to_prefix,
+                to_suffix,
+                wildcard: true,
+            }),
+            (None, None) => Ok(Self {
+                from_prefix: from.to_string(),
+                from_suffix: String::new(),
+                to_prefix: to.to_string(),
+                to_suffix: String::new(),
+                wildcard: false,
+            }),
+            _ => Err(GwtError::Git(
+                "use '*' on both sides of the rename pattern or on neither".to_string(),
+            )),
+        }
+    }
+
+    /// The new name for `branch`, or `None` when it does not match.
+    pub fn apply(&self, branch: &str) -> Option<String> {
+        if !self.wildcard {
+            return (branch == self.from_prefix).then(|| self.to_prefix.clone());
+        }
+        let rest = branch.strip_prefix(&self.from_prefix)?;
+        let matched = rest.strip_suffix(&self.from_suffix)?;
+        if matched.is_empty() {
+            return None;
+        }
+        Some(format!("{}{matched}{}", self.to_prefix, self.to_suffix))
+    }
+}
+
+/// One branch of a [`RenamePlan`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BranchRename {
+    pub from: String,
+    pub to: String,
+    /// Worktree that has `from` checked out.
+    pub worktree: Option<PathBuf>,
+    /// Where that worktree moves to; `None` leaves it in place.
+    pub new_worktree: Option<PathBuf>,
+    /// Current upstream, e.g. `origin/users/me/x`.
+    pub upstream: Option<String>,
+    /// Upstream after the rename; `None` keeps the current one.
+    pub new_upstream: Option<String>,
+}
+
+/// Renames selected by a pattern, plus whatever blocks applying them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RenamePlan {
+    pub renames: Vec<BranchRename>,
+    pub conflicts: Vec<String>,
+}
+
+impl RenamePlan {
+    pub fn is_applicable(&self) -> bool {
+        !self.renames.is_empty() && self.conflicts.is_empty()
+    }
+}
+
+/// Work out what renaming every local branch matching `pattern` involves.
+/// Nothing is changed.
+pub fn plan_bulk_rename(
+    repo_path: &Path,
+    pattern: &RenamePattern,
+    max_path_len: Option<usize>,
+) -> Result<RenamePlan> {
+    let main = main_worktree_root(repo_path)?;
+    let worktrees = WorktreeManager::new(&main).list()?;
+    let mut local = Vec::new();
+    let mut upstreams = HashMap::new();
+    for line in git_stdout(
+        &main,
+        &[
+            "for-each-ref",
+            "--format=%(refname:short)\t%(upstream:short)",
+            "refs/heads/",
+        ],
+    )?
+    .lines()
+    {
+        let (name, upstream) = line.split_once('\t').unwrap_or((line, ""));
+        if !upstream.is_empty() {
+            upstreams.insert(name.to_string(), upstream.to_string());
+        }
+        local.push(name.to_string());
+    }
+    let remote_refs: BTreeSet<String> = git_stdout(
+        &main,
+        &["for-each-ref", "--format=%(refname:short)", "refs/remotes/"],
+    )?
+    .lines()
+    .map(str::to_string)
+    .collect();
+
+    let mut plan = RenamePlan::default();
+    let mut targets = BTreeSet::new();
+    for from in &local {
+        let Some(to) = pattern.apply(from) else {
+            continue;
+        };
+        if is_protected_branch_in(&main, from) {
+            plan.conflicts.push(format!("{from} is a protected branch"));
+        }
+        if !valid_branch_name(&main, &to) {
+            plan.conflicts
+                .push(format!("{from} -> {to}: not a valid branch name"));
+        }
+        if local.contains(&to) {
+            plan.conflicts
+                .push(format!("{from} -> {to}: {to} already exists"));
+        }
+        if !targets.insert(to.clone()) {
+            plan.conflicts
+                .push(format!("{from} -> {to}: several branches map to {to}"));
+        }
+
+        let checkout = worktrees
+            .iter()
+            .find(|worktree| worktree.branch.as_deref() == Some(from.as_str()));
+        let worktree = checkout.map(|worktree| worktree.path.clone());
+        let new_worktree = checkout.and_then(|checkout| {
+            let derived = sibling_worktree_path_with_max_len(&main, from, max_path_len);
+            (checkout.path != main && same_path(&checkout.path, &derived))
+                .then(|| sibling_worktree_path_with_max_len(&main, &to, max_path_len))
+        });
+        if let Some(checkout) = checkout.filter(|_| new_worktree.is_some()) {
+            if checkout.locked {
+                plan.conflicts.push(format!(
+                    "{from}: worktree {} is locked",
+                    checkout.path.display()
+                ));
+            }
+        }
+        if let Some(target) = &new_worktree {
+            if target.exists() {
+                plan.conflicts.push(format!(
+                    "{from} -> {to}: {} already exists",
+                    target.display()
+                ));
+            }
+        }
+
+        let upstream = upstreams.get(from).cloned();
+        let new_upstream = upstream.as_deref().and_then(|upstream| {
+            let remote = upstream.strip_suffix(from.as_str())?.strip_suffix('/')?;
+            let candidate = format!("{remote}/{to}");
+            remote_refs.contains(&candidate).then_some(candidate)
+        });
+
+        plan.renames.push(BranchRename {
+            from: from.clone(),
+            to,
+            worktree,
+            new_worktree,
+            upstream,
+            new_upstream,
+        });
+    }
+    Ok(plan)
+}
+
+/// A completed step of [`apply_bulk_rename`], kept so it can be undone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum RenameStep {
+    Branch { from: String, to: String },
+    Worktree { from: PathBuf, to: PathBuf },
+    Upstream { branch: String, previous: String },
+}
+
+/// Steps performed by [`apply_bulk_rename`], newest last.
+#[derive(Debug, Default)]
+pub struct RenameJournal {
+    main: PathBuf,
+    steps: Vec<RenameStep>,
+}
+
+impl RenameJournal {
+    /// Undo every recorded step, newest first. Keeps going after a failed
+    /// step and returns the errors it met.
+    pub fn rollback(self) -> Vec<String> {
+        let mut errors = Vec::new();
+        for step in self.steps.into_iter().rev() {
+            let undone = match &step {
+                RenameStep::Branch { from, to } => {
+                    git_stdout(&self.main, &["branch", "-m", to, from]).map(drop)
+                }
+                RenameStep::Worktree { from, to } => move_worktree(&self.main, to, from),
+                RenameStep::Upstream { branch, previous } => git_stdout(
+                    &self.main,
+                    &["branch", &format!("--set-upstream-to={previous}"), branch],
+                )
+                .map(drop),
+            };
+            if let Err(error) = undone {
+                errors.push(format!("undo {step:?}: {error}"));
+            }
+        }
+        errors
+    }
+}
+
+/// Apply a conflict-free plan. On failure everything done so far is rolled
+/// back before the error is returned; on success the journal is handed back
+/// so the caller can still undo the rename.
+pub fn apply_bulk_rename(repo_path: &Path, plan: &RenamePlan) -> Result<RenameJournal> {
+    if let Some(conflict) = plan.conflicts.first() {
+        return Err(GwtError::Git(format!("rename blocked: {conflict}")));
+    }
+    let mut journal = RenameJournal {
+        main: main_worktree_root(repo_path)?,
+        steps: Vec::new(),
+    };
+    for rename in &plan.renames {
+        if let Err(error) = apply_one(&mut journal, rename) {
+            let undo_errors = journal.rollback();
+            let mut message = format!("{} -> {}: {error}", rename.from, rename.to);
+            if !undo_errors.is_empty() {
+                message.push_str(&format!(
+                    "; rollback incomplete: {}",
+                    undo_errors.join("; ")
+                ));
+            }
+            return Err(GwtError::Git(message));
+        }
+    }
+    Ok(journal)
+}
+
+fn apply_one(journal: &mut RenameJournal, rename: &BranchRename) -> Result<()> {
+    let main = journal.main.clone();
+    git_stdout(&main, &["branch", "-m", &rename.from, &rename.to])?;
+    journal.steps.push(RenameStep::Branch {
+        from: rename.from.clone(),
+        to: rename.to.clone(),
+    });
+    if let (Some(from), Some(to)) = (&rename.worktree, &rename.new_worktree) {
+        move_worktree(&main, from, to)?;
+        journal.steps.push(RenameStep::Worktree {
+            from: from.clone(),
+            to: to.clone(),
+        });
+    }
+    if let (Some(previous), Some(upstream)) = (&rename.upstream, &rename.new_upstream) {
+        git_stdout(
+            &main,
+            &[
+                "branch",
+                &format!("--set-upstream-to={upstream}"),
+                &rename.to,
+            ],
+        )?;
+        journal.steps.push(RenameStep::Upstream {
+            branch: rename.to.clone(),
+            previous: previous.clone(),
+        });
+    }
+    Ok(())
+}
+
+/// `git worktree move`, creating the target's parent and removing parents
+/// the move left empty (e.g. `../users/me/`).
+fn move_worktree(main: &Path, from: &Path, to: &Path) -> Result<()> {
+    if let Some(parent) = to.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let (from_arg, to_arg) = (from.to_string_lossy(), to.to_string_lossy());
+    git_stdout(main, &["worktree", "move", &from_arg, &to_arg])?;
+    let layout_root = main.parent().unwrap_or(main);
+    let mut dir = from.parent();
+    while let Some(current) = dir {
+        if current == layout_root || std::fs::remove_dir(current).is_err() {
+            break;
+        }
+        dir = current.parent();
+    }
+    Ok(())
+}
+
+fn valid_branch_name(repo: &Path, name: &str) -> bool {
+    gwt_core::process::run_git_logged(&["check-ref-format", "--branch", name], Some(repo))
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+fn same_path(a: &Path, b: &Path) -> bool {
+    let canonical = |path: &Path| path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    a == b || canonical(a) == canonical(b)
+}
+
+fn git_stdout(dir: &Path, args: &[&str]) -> Result<String> {
+    let label = args[..2.min(args.len())].join(" ");
+    let output = gwt_core::process::run_git_logged(args, Some(dir))
+        .map_err(|e| GwtError::Git(format!("{label}: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(GwtError::Git(format!("{label}: {}", stderr.trim())));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::run_git_logged(args, Some(dir)).unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn pattern_maps_wildcard_and_exact_names() {
+        let pattern = RenamePattern::parse("users/me/*", "feature/*").unwrap();
+        assert_eq!(
+            pattern.apply("users/me/login").as_deref(),
+            Some("feature/login")
+        );
+        assert_eq!(pattern.apply("users/you/login"), None);
+        assert_eq!(pattern.apply("users/me/"), None);
+
+        let exact = RenamePattern::parse("old", "new").unwrap();
+        assert_eq!(exact.apply("old").as_deref(), Some("new"));
+        assert_eq!(exact.apply("older"), None);
+
+        assert!(RenamePattern::parse("a/*", "b").is_err());
+        assert!(RenamePattern::parse("a/*/*", "b/*/*").is_err());
+    }
+
+    #[test]
+    fn rename_moves_derived_worktree_and_rolls_back() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--initial-branch=main"]);
+        git(&repo, &["config", "user.email", "t@example.com"]);
+        git(&repo, &["config", "user.name", "T"]);
+        git(&repo, &["commit", "--allow-empty", "-m", "init"]);
+        let old_path = sibling_worktree_path_with_max_len(&repo, "users/me/a", None);
+        git(
+            &repo,
+            &[
+                "worktree",
+                "add",
+                "-b",
+                "users/me/a",
+                old_path.to_str().unwrap(),
+            ],
+        );
+        git(&repo, &["branch", "users/me/b"]);
+        git(&repo, &["branch", "feature/b"]);
+
+        let pattern = RenamePattern::parse("users/me/*", "feature/*").unwrap();
+        let plan = plan_bulk_rename(&repo, &pattern, None).unwrap();
+        assert_eq!(plan.renames.len(), 2);
+        assert_eq!(
+            plan.conflicts,
+            vec!["users/me/b -> feature/b: feature/b already exists"]
+        );
+        assert!(apply_bulk_rename(&repo, &plan).is_err());
+
+        git(&repo, &["branch", "-D", "feature/b"]);
+        let plan = plan_bulk_rename(&repo, &pattern, None).unwrap();
+        assert!(plan.is_applicable(), "{:?}", plan.conflicts);
+        let new_path = sibling_worktree_path_with_max_len(&repo, "feature/a", None);
+        assert_eq!(plan.renames[0].new_worktree.as_ref(), Some(&new_path));
+
+        let journal = apply_bulk_rename(&repo, &plan).unwrap();
+        assert!(new_path.join(".git").exists());
+        assert!(!tmp.path().join("users").exists());
+        let branches = git_stdout(&repo, &["branch", "--format=%(refname:short)"]).unwrap();
+        assert_eq!(branches, "feature/a\nfeature/b\nmain\n");
+
+        assert!(journal.rollback().is_empty());
+        assert!(old_path.join(".git").exists());
+        let branches = git_stdout(&repo, &["branch", "--format=%(refname:short)"]).unwrap();
+        assert_eq!(branches, "main\nusers/me/a\nusers/me/b\n");
+    }
+}
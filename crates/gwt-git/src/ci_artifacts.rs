@@ -0,0 +1,274 @@
+//! Download the CI artifacts of a branch into its worktree.
+//!
+//! [`download_ci_artifacts`] finds the branch's newest completed workflow
+//! runs with `gh run list` and fetches their artifacts with `gh run
+//! download` into [`CI_ARTIFACTS_DIR`] inside the worktree, one directory
+//! per run. The directory carries its own `.gitignore`, so the downloads
+//! never show up as untracked files.
+
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use gwt_core::{GwtError, Result};
+use serde::Serialize;
+
+use crate::pr_status::run_gh_command;
+
+/// Directory inside the worktree the artifacts are downloaded into.
+pub const CI_ARTIFACTS_DIR: &str = ".gwt-ci-artifacts";
+
+/// Workflow runs of the branch looked at by [`download_ci_artifacts`].
+const BRANCH_RUN_LIMIT: &str = "20";
+
+/// One workflow run whose artifacts can be downloaded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CiArtifactRun {
+    pub run_id: u64,
+    pub workflow: String,
+    pub head_sha: String,
+}
+
+impl CiArtifactRun {
+    /// Directory name of the run under [`CI_ARTIFACTS_DIR`].
+    pub fn dir_name(&self) -> String {
+        let slug: String = self
+            .workflow
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        let slug = slug
+            .split('-')
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        if slug.is_empty() {
+            self.run_id.to_string()
+        } else {
+            format!("{slug}-{}", self.run_id)
+        }
+    }
+}
+
+/// What [`download_ci_artifacts`] fetched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CiArtifactDownload {
+    pub branch: String,
+    pub head_sha: String,
+    pub dir: PathBuf,
+    pub runs: Vec<CiArtifactRun>,
+    /// Downloaded artifacts as `<run dir>/<artifact>`.
+    pub artifacts: Vec<String>,
+    /// Runs `gh run download` failed for, with the reason.
+    pub failures: Vec<String>,
+}
+
+impl CiArtifactDownload {
+    /// One-line summary, e.g. `feature/x@1a2b3c4: 3 artifacts from 2 runs in
+    /// /wt/.gwt-ci-artifacts`.
+    pub fn summary(&self) -> String {
+        let short_sha: String = self.head_sha.chars().take(7).collect();
+        let mut summary = match self.artifacts.len() {
+            0 => format!("{}@{short_sha}: no artifacts downloaded", self.branch),
+            count => format!(
+                "{}@{short_sha}: {count} artifact{} from {} run{} in {}",
+                self.branch,
+                if count == 1 { "" } else { "s" },
+                self.runs.len(),
+                if self.runs.len() == 1 { "" } else { "s" },
+                self.dir.display()
+            ),
+        };
+        if !self.failures.is_empty() {
+            summary.push_str(&format!(" ({})", self.failures.join("; ")));
+        }
+        summary
+    }
+}
+
+/// Parse `gh run list --json databaseId,headSha,workflowName,status,conclusion`
+/// (newest run first) into the completed runs of the newest head commit,
+/// the newest one per workflow.
+pub fn parse_latest_completed_runs(json: &str) -> Result<Vec<CiArtifactRun>> {
+    let runs: Vec<serde_json::Value> = serde_json::from_str(json)
+        .map_err(|e| GwtError::Other(format!("gh run list JSON: {e}")))?;
+    let field = |run: &serde_json::Value, name: &str| {
+        run.get(name)
+            .and_then(serde_json::Value::as_str)
+            .map(str::trim)
+            .unwrap_or("")
+            .to_string()
+    };
+    let mut head_sha: Option<String> = None;
+    let mut seen_workflows = HashSet::new();
+    let mut latest = Vec::new();
+    for run in &runs {
+        let Some(run_id) = run.get("databaseId").and_then(serde_json::Value::as_u64) else {
+            continue;
+        };
+        let sha = field(run, "headSha");
+        if sha.is_empty() {
+            continue;
+        }
+        // Only the newest commit with runs counts.
+        if *head_sha.get_or_insert_with(|| sha.clone()) != sha {
+            continue;
+        }
+        let workflow = field(run, "workflowName");
+        if !field(run, "status").eq_ignore_ascii_case("completed")
+            || !seen_workflows.insert(workflow.clone())
+        {
+            continue;
+        }
+        latest.push(CiArtifactRun {
+            run_id,
+            workflow,
+            head_sha: sha,
+        });
+    }
+    Ok(latest)
+}
+
+/// Download the artifacts of the latest completed runs of `branch` into
+/// [`CI_ARTIFACTS_DIR`] of `worktree`, replacing an earlier download of the
+/// same run. `pattern` limits the download to matching artifact names.
+pub fn download_ci_artifacts(
+    worktree: &Path,
+    branch: &str,
+    pattern: Option<&str>,
+) -> Result<CiArtifactDownload> {
+    let output = run_gh_command(
+        worktree,
+        &[
+            "run",
+            "list",
+            "--branch",
+            branch,
+            "--json",
+            "databaseId,headSha,workflowName,status,conclusion",
+            "--limit",
+            BRANCH_RUN_LIMIT,
+        ],
+    )?;
+    if !output.success {
+        return Err(GwtError::Git(format!(
+            "gh run list: {}",
+            output.stderr.trim()
+        )));
+    }
+    let runs = parse_latest_completed_runs(&output.stdout)?;
+    let Some(head_sha) = runs.first().map(|run| run.head_sha.clone()) else {
+        return Err(GwtError::Other(format!(
+            "{branch} has no completed workflow runs"
+        )));
+    };
+
+    let dir = worktree.join(CI_ARTIFACTS_DIR);
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join(".gitignore"), "*\n")?;
+
+    let pattern = pattern.map(str::trim).filter(|pattern| !pattern.is_empty());
+    let mut artifacts = Vec::new();
+    let mut failures = Vec::new();
+    for run in &runs {
+        let run_dir = dir.join(run.dir_name());
+        if run_dir.exists() {
+            fs::remove_dir_all(&run_dir)?;
+        }
+        let run_id = run.run_id.to_string();
+        let run_dir_arg = run_dir.to_string_lossy().into_owned();
+        let mut args = vec!["run", "download", run_id.as_str(), "--dir", &run_dir_arg];
+        if let Some(pattern) = pattern {
+            args.extend(["--pattern", pattern]);
+        }
+        let output = run_gh_command(worktree, &args)?;
+        if !output.success {
+            // Runs without (matching) artifacts fail here too.
+            let reason = output.stderr.trim();
+            failures.push(format!("{}: {}", run.workflow, reason));
+            continue;
+        }
+        artifacts.extend(
+            list_artifact_dirs(&run_dir)?
+                .into_iter()
+                .map(|artifact| format!("{}/{artifact}", run.dir_name())),
+        );
+    }
+
+    Ok(CiArtifactDownload {
+        branch: branch.to_string(),
+        head_sha,
+        dir,
+        runs,
+        artifacts,
+        failures,
+    })
+}
+
+fn list_artifact_dirs(run_dir: &Path) -> Result<Vec<String>> {
+    let entries = match fs::read_dir(run_dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error.into()),
+    };
+    let mut names = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            names.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latest_completed_runs_keep_the_newest_commit_once_per_workflow() {
+        let json = r#"[
+            {"databaseId": 30, "headSha": "new", "workflowName": "CI", "status": "in_progress", "conclusion": ""},
+            {"databaseId": 29, "headSha": "new", "workflowName": "Build Docs", "status": "completed", "conclusion": "failure"},
+            {"databaseId": 28, "headSha": "new", "workflowName": "CI", "status": "completed", "conclusion": "success"},
+            {"databaseId": 27, "headSha": "new", "workflowName": "Build Docs", "status": "completed", "conclusion": "success"},
+            {"databaseId": 26, "headSha": "old", "workflowName": "Release", "status": "completed", "conclusion": "success"}
+        ]"#;
+        let runs = parse_latest_completed_runs(json).unwrap();
+        let ids: Vec<u64> = runs.iter().map(|run| run.run_id).collect();
+        assert_eq!(ids, [29, 28]);
+        assert_eq!(runs[0].dir_name(), "build-docs-29");
+        assert!(parse_latest_completed_runs("[]").unwrap().is_empty());
+        assert!(parse_latest_completed_runs("not json").is_err());
+    }
+
+    #[test]
+    fn summary_counts_artifacts_and_failures() {
+        let download = CiArtifactDownload {
+            branch: "feature/x".to_string(),
+            head_sha: "1a2b3c4d5e".to_string(),
+            dir: PathBuf::from("/wt/.gwt-ci-artifacts"),
+            runs: vec![CiArtifactRun {
+                run_id: 7,
+                workflow: "CI".to_string(),
+                head_sha: "1a2b3c4d5e".to_string(),
+            }],
+            artifacts: vec!["ci-7/test-report".to_string()],
+            failures: vec!["Docs: no valid artifacts found to download".to_string()],
+        };
+        assert_eq!(
+            download.summary(),
+            "feature/x@1a2b3c4: 1 artifact from 1 run in /wt/.gwt-ci-artifacts \
+             (Docs: no valid artifacts found to download)"
+        );
+    }
+}
@@ -1,6 +1,6 @@
-//! Git commit log queries
+//! Git commit log queries and commit creation
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use gwt_core::{GwtError, Result};
 use serde::{Deserialize, Serialize};
@@ -79,6 +79,91 @@ pub fn branch_recent_subjects(repo_path: &Path, branch: &str, count: usize) -> R
         .collect())
 }
 
+/// Options for [`create_commit`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitOptions {
+    /// Add a `Signed-off-by` trailer (`--signoff`).
+    #[serde(default)]
+    pub signoff: bool,
+    /// GPG-sign the commit (`-S`) with the configured signing key.
+    #[serde(default)]
+    pub gpg_sign: bool,
+}
+
+/// Stage `paths` for the next commit. Additions, modifications and
+/// deletions are all recorded, so a removed file can be staged too.
+pub fn stage_paths(repo_path: &Path, paths: &[PathBuf]) -> Result<()> {
+    if paths.is_empty() {
+        return Ok(());
+    }
+    let mut args = vec!["add", "-A", "--"];
+    args.extend(paths.iter().filter_map(|path| path.to_str()));
+    let output = gwt_core::process::run_git_logged(&args, Some(repo_path))
+        .map_err(|e| GwtError::Git(format!("add: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(GwtError::Git(format!("add: {}", stderr.trim())));
+    }
+    Ok(())
+}
+
+/// The full staged diff, used as the input for a suggested commit message.
+pub fn staged_diff(repo_path: &Path) -> Result<String> {
+    let output = gwt_core::process::run_git_logged(
+        &["diff", "--cached", "--no-color", "--no-ext-diff"],
+        Some(repo_path),
+    )
+    .map_err(|e| GwtError::Git(format!("diff --cached: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(GwtError::Git(format!("diff --cached: {}", stderr.trim())));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Commit the index with `message` and return the new commit.
+///
+/// The message is passed on stdin, so it may span several lines. Fails when
+/// the message is blank or nothing is staged.
+pub fn create_commit(
+    repo_path: &Path,
+    message: &str,
+    options: &CommitOptions,
+) -> Result<CommitEntry> {
+    let message = message.trim();
+    if message.is_empty() {
+        return Err(GwtError::Git("commit: message is empty".to_string()));
+    }
+    let mut args = vec!["commit", "--file=-"];
+    if options.signoff {
+        args.push("--signoff");
+    }
+    if options.gpg_sign {
+        args.push("--gpg-sign");
+    }
+    let output = gwt_core::process::run_git_logged_with_stdin(
+        &args,
+        Some(repo_path),
+        format!("{message}\n").as_bytes(),
+    )
+    .map_err(|e| GwtError::Git(format!("commit: {e}")))?;
+    if !output.status.success() {
+        // "nothing to commit" is reported on stdout, hook and signing
+        // failures on stderr.
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let detail = if stderr.trim().is_empty() {
+            String::from_utf8_lossy(&output.stdout).to_string()
+        } else {
+            stderr.to_string()
+        };
+        return Err(GwtError::Git(format!("commit: {}", detail.trim())));
+    }
+    recent_commits(repo_path, 1)?
+        .into_iter()
+        .next()
+        .ok_or_else(|| GwtError::Git("commit: HEAD not found after commit".to_string()))
+}
+
 /// Parse tab-separated git log output.
 pub fn parse_log_output(output: &str) -> Vec<CommitEntry> {
     output
@@ -178,6 +263,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn create_commit_stages_paths_and_adds_signoff() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path();
+        gwt_core::process::hidden_command("git")
+            .args(["init", path.to_str().unwrap()])
+            .output()
+            .unwrap();
+        set_test_identity(path);
+        std::fs::write(path.join("a.txt"), "a\n").unwrap();
+        std::fs::write(path.join("b.txt"), "b\n").unwrap();
+
+        let options = CommitOptions {
+            signoff: true,
+            gpg_sign: false,
+        };
+        let err = create_commit(path, "  ", &options).unwrap_err();
+        assert!(err.to_string().contains("message is empty"), "{err}");
+
+        stage_paths(path, &[PathBuf::from("a.txt")]).unwrap();
+        assert!(staged_diff(path).unwrap().contains("+++ b/a.txt"));
+        let commit = create_commit(path, "feat: add a\n\nBody line.", &options).unwrap();
+        assert_eq!(commit.subject, "feat: add a");
+
+        let body = gwt_core::process::hidden_command("git")
+            .args(["log", "-1", "--format=%B"])
+            .current_dir(path)
+            .output()
+            .unwrap();
+        let body = String::from_utf8_lossy(&body.stdout);
+        assert!(body.contains("Body line."), "{body}");
+        assert!(
+            body.contains("Signed-off-by: Test <test@example.com>"),
+            "{body}"
+        );
+        // b.txt was not selected, so it stays untracked and uncommitted.
+        let err = create_commit(path, "second", &CommitOptions::default()).unwrap_err();
+        assert!(err.to_string().starts_with("Git error: commit:"), "{err}");
+    }
+
     #[test]
     fn recent_commits_in_test_repo() {
         let tmp = tempfile::tempdir().unwrap();
@@ -0,0 +1,212 @@
+//! Canary worktrees for dependency upgrades.
+//!
+//! A dependency upgrade runs on its own `deps/upgrade-<date>` branch so a
+//! broken lockfile never lands on a working branch. This module holds the
+//! git and package-manager half of the flow: the branch name, the upgrade
+//! command of the package manager the worktree uses, running it, and
+//! committing and pushing the result. Launching the agent that fixes the
+//! fallout and opening the draft PR is up to the caller (`gwt deps
+//! upgrade`).
+
+use std::path::Path;
+
+use chrono::NaiveDate;
+use gwt_core::{
+    process_executor::{ExecutionMode, ProcessExecutor, ProcessRequest},
+    GwtError, Result,
+};
+
+/// Prefix of the canary branches.
+pub const UPGRADE_BRANCH_PREFIX: &str = "deps/upgrade-";
+
+/// Lines of upgrade output kept for the report and the agent prompt.
+const OUTPUT_TAIL_LINES: usize = 40;
+
+/// `deps/upgrade-<YYYY-MM-DD>`.
+pub fn upgrade_branch_name(date: NaiveDate) -> String {
+    format!("{UPGRADE_BRANCH_PREFIX}{}", date.format("%Y-%m-%d"))
+}
+
+/// The upgrade command of one package manager.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpgradeCommand {
+    /// Package manager name (`cargo`, `pnpm`, ...).
+    pub manager: &'static str,
+    pub argv: Vec<String>,
+}
+
+/// Upgrade command for the package manager `worktree` uses, picked from its
+/// lockfile (or manifest when there is none). JavaScript lockfiles are
+/// checked before `package.json` so pnpm / yarn / bun projects are not
+/// upgraded with npm.
+pub fn detect_upgrade_command(worktree: &Path) -> Option<UpgradeCommand> {
+    let has = |name: &str| worktree.join(name).is_file();
+    let (manager, argv): (&'static str, &[&str]) = if has("Cargo.toml") {
+        ("cargo", &["cargo", "update"])
+    } else if has("pnpm-lock.yaml") {
+        ("pnpm", &["pnpm", "update"])
+    } else if has("yarn.lock") {
+        ("yarn", &["yarn", "upgrade"])
+    } else if has("bun.lock") || has("bun.lockb") {
+        ("bun", &["bun", "update"])
+    } else if has("package.json") {
+        ("npm", &["npm", "update"])
+    } else if has("go.mod") {
+        ("go", &["go", "get", "-u", "./..."])
+    } else if has("uv.lock") {
+        ("uv", &["uv", "lock", "--upgrade"])
+    } else if has("poetry.lock") {
+        ("poetry", &["poetry", "update"])
+    } else if has("Gemfile") {
+        ("bundler", &["bundle", "update"])
+    } else {
+        return None;
+    };
+    Some(UpgradeCommand {
+        manager,
+        argv: argv.iter().map(|part| part.to_string()).collect(),
+    })
+}
+
+/// Result of running an [`UpgradeCommand`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpgradeRun {
+    pub success: bool,
+    pub code: Option<i32>,
+    /// Last lines of the combined stdout / stderr.
+    pub output_tail: Vec<String>,
+}
+
+/// Run `command` in `worktree`. An error means the command could not be
+/// started; a non-zero exit is reported in [`UpgradeRun::success`].
+pub fn run_upgrade(
+    executor: &dyn ProcessExecutor,
+    worktree: &Path,
+    command: &UpgradeCommand,
+) -> Result<UpgradeRun> {
+    let (program, args) = command
+        .argv
+        .split_first()
+        .ok_or_else(|| GwtError::Other("empty upgrade command".to_string()))?;
+    let request = ProcessRequest::new(program)
+        .args(args)
+        .cwd(worktree)
+        .mode(ExecutionMode::Capture)
+        .hide_window(true);
+    let output = executor
+        .run(request)
+        .map_err(|message| GwtError::Other(format!("{}: {message}", command.argv.join(" "))))?;
+    let combined = format!("{}{}", output.stdout, output.stderr);
+    let lines: Vec<&str> = combined.lines().collect();
+    Ok(UpgradeRun {
+        success: output.success,
+        code: output.code,
+        output_tail: lines[lines.len().saturating_sub(OUTPUT_TAIL_LINES)..]
+            .iter()
+            .map(|line| line.to_string())
+            .collect(),
+    })
+}
+
+/// Files the upgrade changed (`git status --porcelain` paths).
+pub fn changed_files(worktree: &Path) -> Result<Vec<String>> {
+    let status = git_stdout(worktree, &["status", "--porcelain"])?;
+    Ok(status
+        .lines()
+        .filter_map(|line| line.get(3..))
+        .map(|path| path.trim().to_string())
+        .filter(|path| !path.is_empty())
+        .collect())
+}
+
+/// Stage everything in `worktree` and commit it. Returns `false` when there
+/// was nothing to commit.
+pub fn commit_all(worktree: &Path, message: &str) -> Result<bool> {
+    if changed_files(worktree)?.is_empty() {
+        return Ok(false);
+    }
+    git_stdout(worktree, &["add", "-A"])?;
+    git_stdout(worktree, &["commit", "-m", message])?;
+    Ok(true)
+}
+
+/// Push `branch` to `origin` and track it.
+pub fn push_branch(worktree: &Path, branch: &str) -> Result<()> {
+    git_stdout(worktree, &["push", "-u", "origin", branch]).map(|_| ())
+}
+
+fn git_stdout(dir: &Path, args: &[&str]) -> Result<String> {
+    let label = args[..2.min(args.len())].join(" ");
+    let output = gwt_core::process::run_git_logged(args, Some(dir))
+        .map_err(|e| GwtError::Git(format!("{label}: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(GwtError::Git(format!("{label}: {}", stderr.trim())));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use gwt_core::process_executor::{MockProcessExecutor, ProcessOutput};
+
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::run_git_logged(args, Some(dir)).unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn detects_the_package_manager_from_lockfiles() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert_eq!(detect_upgrade_command(tmp.path()), None);
+        fs::write(tmp.path().join("package.json"), "{}").unwrap();
+        assert_eq!(detect_upgrade_command(tmp.path()).unwrap().manager, "npm");
+        fs::write(tmp.path().join("pnpm-lock.yaml"), "").unwrap();
+        let command = detect_upgrade_command(tmp.path()).unwrap();
+        assert_eq!(command.manager, "pnpm");
+        assert_eq!(command.argv, ["pnpm", "update"]);
+        fs::write(tmp.path().join("Cargo.toml"), "").unwrap();
+        assert_eq!(detect_upgrade_command(tmp.path()).unwrap().manager, "cargo");
+        assert_eq!(
+            upgrade_branch_name(NaiveDate::from_ymd_opt(2026, 3, 9).unwrap()),
+            "deps/upgrade-2026-03-09"
+        );
+    }
+
+    #[test]
+    fn runs_the_upgrade_and_commits_its_changes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path();
+        git(repo, &["init", "--initial-branch=main"]);
+        git(repo, &["config", "user.email", "t@example.com"]);
+        git(repo, &["config", "user.name", "T"]);
+        fs::write(repo.join("Cargo.toml"), "[package]\n").unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-m", "init"]);
+
+        let executor = MockProcessExecutor::new();
+        executor.push_response(ProcessOutput::failed(101).with_stderr("error: no lock\n"));
+        let command = detect_upgrade_command(repo).unwrap();
+        let run = run_upgrade(&executor, repo, &command).unwrap();
+        assert!(!run.success);
+        assert_eq!(run.code, Some(101));
+        assert_eq!(run.output_tail, ["error: no lock"]);
+        let request = &executor.requests()[0];
+        assert_eq!(request.program_name(), "cargo");
+        assert_eq!(request.args_lossy(), ["update"]);
+
+        assert!(!commit_all(repo, "chore(deps): upgrade dependencies").unwrap());
+        fs::write(repo.join("Cargo.lock"), "version = 4\n").unwrap();
+        assert_eq!(changed_files(repo).unwrap(), ["Cargo.lock"]);
+        assert!(commit_all(repo, "chore(deps): upgrade dependencies").unwrap());
+        assert!(changed_files(repo).unwrap().is_empty());
+    }
+}
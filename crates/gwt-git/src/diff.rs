@@ -119,6 +119,241 @@ pub fn parse_porcelain_status(output: &str) -> Vec<FileEntry> {
     entries
 }
 
+/// `git diff --shortstat <base_ref>...HEAD` in `repo_path`, e.g.
+/// `3 files changed, 10 insertions(+), 2 deletions(-)`: what the branch
+/// changed since it forked from `base_ref`. `None` when nothing changed.
+pub fn branch_shortstat(repo_path: &Path, base_ref: &str) -> Result<Option<String>> {
+    let range = format!("{base_ref}...HEAD");
+    let output =
+        gwt_core::process::run_git_logged(&["diff", "--shortstat", &range], Some(repo_path))
+            .map_err(|e| GwtError::Git(format!("diff --shortstat: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(GwtError::Git(format!(
+            "diff --shortstat: {}",
+            stderr.trim()
+        )));
+    }
+    let stat = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok((!stat.is_empty()).then_some(stat))
+}
+
+/// `git diff --name-only <base_ref>...<branch>` in `repo_path`: the files
+/// `branch` changed since it forked from `base_ref`, `/`-separated.
+pub fn branch_changed_files(repo_path: &Path, base_ref: &str, branch: &str) -> Result<Vec<String>> {
+    let range = format!("{base_ref}...{branch}");
+    let output =
+        gwt_core::process::run_git_logged(&["diff", "--name-only", &range], Some(repo_path))
+            .map_err(|e| GwtError::Git(format!("diff --name-only: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(GwtError::Git(format!(
+            "diff --name-only: {}",
+            stderr.trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Kind of a line inside a diff hunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffLineKind {
+    Context,
+    Added,
+    Removed,
+}
+
+/// One line of a hunk, with its number on the old and/or new side.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub old_line: Option<u32>,
+    pub new_line: Option<u32>,
+    /// Line content without the leading `+`/`-`/space marker.
+    pub text: String,
+}
+
+/// One `@@ -a,b +c,d @@` hunk of a file diff.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffHunk {
+    /// The `@@ … @@` line, including any function context git appends.
+    pub header: String,
+    pub old_start: u32,
+    pub new_start: u32,
+    pub lines: Vec<DiffLine>,
+    /// The hunk exactly as git printed it, used to build a one-hunk patch.
+    #[serde(skip)]
+    raw: String,
+}
+
+/// Unified diff of a single file, either index vs `HEAD` (`staged`) or
+/// working tree vs index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileDiff {
+    pub path: PathBuf,
+    pub staged: bool,
+    /// Git reported `Binary files … differ`; there are no hunks to show.
+    pub binary: bool,
+    pub hunks: Vec<DiffHunk>,
+    /// `diff --git` / `---` / `+++` lines preceding the first hunk.
+    #[serde(skip)]
+    header: String,
+}
+
+impl FileDiff {
+    /// A patch holding only hunk `index`, suitable for `git apply`.
+    pub fn hunk_patch(&self, index: usize) -> Option<String> {
+        let hunk = self.hunks.get(index)?;
+        Some(format!("{}{}", self.header, hunk.raw))
+    }
+}
+
+/// Load the staged (`git diff --cached`) or unstaged (`git diff`) diff of
+/// `path`, relative to `repo_path`.
+pub fn file_diff(repo_path: &Path, path: &Path, staged: bool) -> Result<FileDiff> {
+    let path_str = path.to_str().unwrap_or("");
+    let mut args = vec![
+        "diff",
+        "--no-color",
+        "--no-ext-diff",
+        // Pin the prefixes so `diff.noprefix` / `diff.mnemonicPrefix` in the
+        // user's config cannot break `git apply` on the hunk patches.
+        "--src-prefix=a/",
+        "--dst-prefix=b/",
+    ];
+    if staged {
+        args.push("--cached");
+    }
+    args.extend(["--", path_str]);
+    let output = gwt_core::process::run_git_logged(&args, Some(repo_path))
+        .map_err(|e| GwtError::Git(format!("diff: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(GwtError::Git(format!("diff: {}", stderr.trim())));
+    }
+    Ok(parse_file_diff(
+        path,
+        staged,
+        &String::from_utf8_lossy(&output.stdout),
+    ))
+}
+
+/// Parse the unified diff git prints for a single file.
+pub fn parse_file_diff(path: &Path, staged: bool, output: &str) -> FileDiff {
+    let mut diff = FileDiff {
+        path: path.to_path_buf(),
+        staged,
+        binary: false,
+        hunks: Vec::new(),
+        header: String::new(),
+    };
+    let mut old_line = 0;
+    let mut new_line = 0;
+    for line in output.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if let Some((old_start, new_start)) = parse_hunk_header(content) {
+            old_line = old_start;
+            new_line = new_start;
+            diff.hunks.push(DiffHunk {
+                header: content.to_string(),
+                old_start,
+                new_start,
+                lines: Vec::new(),
+                raw: line.to_string(),
+            });
+            continue;
+        }
+        let Some(hunk) = diff.hunks.last_mut() else {
+            if content.starts_with("Binary files ") {
+                diff.binary = true;
+            }
+            diff.header.push_str(line);
+            continue;
+        };
+        hunk.raw.push_str(line);
+        let (kind, text) = match content.as_bytes().first() {
+            Some(b'+') => (DiffLineKind::Added, &content[1..]),
+            Some(b'-') => (DiffLineKind::Removed, &content[1..]),
+            Some(b' ') => (DiffLineKind::Context, &content[1..]),
+            // `\ No newline at end of file` and blank context lines.
+            Some(b'\\') => continue,
+            _ => (DiffLineKind::Context, ""),
+        };
+        let (old, new) = match kind {
+            DiffLineKind::Added => (None, Some(new_line)),
+            DiffLineKind::Removed => (Some(old_line), None),
+            DiffLineKind::Context => (Some(old_line), Some(new_line)),
+        };
+        if old.is_some() {
+            old_line += 1;
+        }
+        if new.is_some() {
+            new_line += 1;
+        }
+        hunk.lines.push(DiffLine {
+            kind,
+            old_line: old,
+            new_line: new,
+            text: text.to_string(),
+        });
+    }
+    diff
+}
+
+/// `@@ -12,3 +12,4 @@ fn x` → `(12, 12)`.
+fn parse_hunk_header(line: &str) -> Option<(u32, u32)> {
+    let ranges = line.strip_prefix("@@ -")?;
+    let (ranges, _) = ranges.split_once(" @@")?;
+    let (old, new) = ranges.split_once(" +")?;
+    let start = |range: &str| range.split(',').next()?.parse().ok();
+    Some((start(old)?, start(new)?))
+}
+
+/// Stage hunk `hunk_index` of the unstaged diff of `path`.
+pub fn stage_hunk(repo_path: &Path, path: &Path, hunk_index: usize) -> Result<()> {
+    apply_hunk_to_index(repo_path, path, hunk_index, false)
+}
+
+/// Unstage hunk `hunk_index` of the staged diff of `path`.
+pub fn unstage_hunk(repo_path: &Path, path: &Path, hunk_index: usize) -> Result<()> {
+    apply_hunk_to_index(repo_path, path, hunk_index, true)
+}
+
+/// Re-read the diff so the hunk index refers to what git sees now, then
+/// apply (or, for a staged hunk, reverse-apply) that one hunk to the index.
+fn apply_hunk_to_index(
+    repo_path: &Path,
+    path: &Path,
+    hunk_index: usize,
+    staged: bool,
+) -> Result<()> {
+    let diff = file_diff(repo_path, path, staged)?;
+    let patch = diff.hunk_patch(hunk_index).ok_or_else(|| {
+        GwtError::Git(format!(
+            "{} has no hunk {hunk_index} to {}",
+            path.display(),
+            if staged { "unstage" } else { "stage" }
+        ))
+    })?;
+    let mut args = vec!["apply", "--cached", "--whitespace=nowarn"];
+    if staged {
+        args.push("--reverse");
+    }
+    let output =
+        gwt_core::process::run_git_logged_with_stdin(&args, Some(repo_path), patch.as_bytes())
+            .map_err(|e| GwtError::Git(format!("apply: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(GwtError::Git(format!("apply: {}", stderr.trim())));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -228,4 +463,86 @@ mod tests {
         assert_eq!(entries.len(), 1);
         assert_eq!(entries[0].status, FileStatus::Untracked);
     }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::run_git_logged(args, Some(dir)).unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn parse_file_diff_numbers_lines_per_side() {
+        let output = concat!(
+            "diff --git a/src/lib.rs b/src/lib.rs\n",
+            "index 1111111..2222222 100644\n",
+            "--- a/src/lib.rs\n",
+            "+++ b/src/lib.rs\n",
+            "@@ -3,3 +3,3 @@ fn main() {\n",
+            " keep\n",
+            "-old\n",
+            "+new\n",
+            " tail\n",
+            "\\ No newline at end of file\n",
+        );
+        let diff = parse_file_diff(Path::new("src/lib.rs"), false, output);
+
+        assert!(!diff.binary);
+        assert_eq!(diff.hunks.len(), 1);
+        let hunk = &diff.hunks[0];
+        assert_eq!(hunk.header, "@@ -3,3 +3,3 @@ fn main() {");
+        let lines: Vec<_> = hunk
+            .lines
+            .iter()
+            .map(|line| (line.kind, line.old_line, line.new_line, line.text.as_str()))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                (DiffLineKind::Context, Some(3), Some(3), "keep"),
+                (DiffLineKind::Removed, Some(4), None, "old"),
+                (DiffLineKind::Added, None, Some(4), "new"),
+                (DiffLineKind::Context, Some(5), Some(5), "tail"),
+            ]
+        );
+        assert_eq!(diff.hunk_patch(0).unwrap(), output);
+        assert!(diff.hunk_patch(1).is_none());
+    }
+
+    #[test]
+    fn stage_and_unstage_a_single_hunk() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path();
+        git(repo, &["init", "--initial-branch=main"]);
+        git(repo, &["config", "user.email", "t@example.com"]);
+        git(repo, &["config", "user.name", "T"]);
+        let original: String = (1..=30).map(|n| format!("line {n}\n")).collect();
+        std::fs::write(repo.join("f.txt"), &original).unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-m", "init"]);
+        let edited = original
+            .replace("line 2\n", "line two\n")
+            .replace("line 28\n", "line twenty-eight\n");
+        std::fs::write(repo.join("f.txt"), edited).unwrap();
+        let path = Path::new("f.txt");
+        assert_eq!(file_diff(repo, path, false).unwrap().hunks.len(), 2);
+
+        stage_hunk(repo, path, 1).unwrap();
+
+        let staged = file_diff(repo, path, true).unwrap();
+        assert_eq!(staged.hunks.len(), 1);
+        assert!(staged.hunks[0]
+            .lines
+            .iter()
+            .any(|line| line.text == "line twenty-eight"));
+        assert_eq!(file_diff(repo, path, false).unwrap().hunks.len(), 1);
+
+        unstage_hunk(repo, path, 0).unwrap();
+
+        assert!(file_diff(repo, path, true).unwrap().hunks.is_empty());
+        assert_eq!(file_diff(repo, path, false).unwrap().hunks.len(), 2);
+        assert!(stage_hunk(repo, path, 5).is_err());
+    }
 }
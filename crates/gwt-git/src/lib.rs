@@ -6,20 +6,39 @@
 pub mod blob;
 pub mod branch;
 pub mod branch_protection;
+pub mod branch_rename;
+pub mod ci_artifacts;
 pub mod commit;
+pub mod deps_upgrade;
 pub mod diff;
 pub mod issue;
 pub mod migration;
 pub mod pr_status;
+pub mod rebase;
 pub mod refs;
 pub mod repository;
+pub mod review_provider;
+pub mod stash;
+pub mod trailers;
+pub mod upstream;
+pub mod windows_path;
 pub mod worktree;
+pub mod worktree_archive;
+pub mod worktree_files;
+pub mod worktree_integrity;
+pub mod worktree_matrix;
+pub mod worktree_review;
+pub mod worktree_transplant;
+pub mod worktree_trash;
 
 pub use branch::{
     delete_local_branch, detect_cleanable_target, detect_cleanable_target_with_remote_names,
     git_divergence, is_branch_merged_into, is_protected_branch, list_gone_branches,
     list_remote_names, Branch, DivergenceInfo, MergeTarget, MergeTargetRef,
 };
+pub use branch_protection::{
+    is_protected_branch_in, is_synced_protected_branch, DestructiveOp, ProtectionPolicy,
+};
 pub use commit::CommitEntry;
 pub use diff::{FileEntry, FileStatus};
 pub use issue::{Issue, IssueCache};
@@ -32,4 +51,13 @@ pub use repository::{
     initialize_workspace, install_develop_protection, GitHubProjectCloneOutcome,
     GitHubProjectCloneTarget, RepoType, Repository,
 };
+pub use review_provider::{
+    fetch_merged_review_branches, fetch_review_list, fetch_review_titles_by_branch, ReviewProvider,
+};
+pub use trailers::{
+    commits_missing_trailers, install_commit_trailer_hook, signoff_trailer, CommitTrailerGap,
+    CO_AUTHORED_BY, GWT_COMMIT_TRAILERS_ENV, SIGNED_OFF_BY,
+};
+pub use upstream::{push_first_commit_with_upstream, UpstreamPushOutcome, DEFAULT_UPSTREAM_REMOTE};
 pub use worktree::{sibling_worktree_path, RemoteDeleteOutcome, WorktreeInfo, WorktreeManager};
+pub use worktree_files::{copy_on_create, WorktreeFileCopy};
@@ -342,11 +342,176 @@ pub fn parse_merged_pr_branches(json: &str) -> Result<std::collections::BTreeSet
     Ok(branches)
 }
 
+/// One PR's head branch and state, as listed by [`fetch_pr_heads`].
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct GhCliOutput {
-    success: bool,
-    stdout: String,
-    stderr: String,
+pub struct PrHead {
+    pub number: u64,
+    pub head_ref_name: String,
+    pub state: PrState,
+}
+
+/// Every PR's head branch and state (open, closed and merged), fetched in ONE
+/// `gh pr list --state all` call. Like [`fetch_merged_pr_branches`], a
+/// transient failure is an `Err` rather than an empty list so callers never
+/// mistake "gh unavailable" for "no PRs".
+pub fn fetch_pr_heads(repo_path: &Path) -> Result<Vec<PrHead>> {
+    let output = run_gh_command(
+        repo_path,
+        &[
+            "pr",
+            "list",
+            "--json",
+            "number,headRefName,state",
+            "--state",
+            "all",
+            "--limit",
+            "999",
+        ],
+    )?;
+    if !output.success {
+        return Err(GwtError::Git(format!(
+            "gh pr list all: {}",
+            output.stderr.trim()
+        )));
+    }
+    parse_pr_heads_json(&output.stdout)
+}
+
+/// Parse `gh pr list --json number,headRefName,state`. Entries with an empty
+/// head ref or an unknown state are skipped.
+pub fn parse_pr_heads_json(json: &str) -> Result<Vec<PrHead>> {
+    let arr: Vec<serde_json::Value> =
+        serde_json::from_str(json).map_err(|e| GwtError::Other(format!("gh pr list JSON: {e}")))?;
+    let heads = arr
+        .iter()
+        .filter_map(|value| {
+            let head_ref_name = value
+                .get("headRefName")
+                .and_then(serde_json::Value::as_str)
+                .map(str::trim)
+                .filter(|name| !name.is_empty())?;
+            let state = match value.get("state").and_then(serde_json::Value::as_str)? {
+                state if state.eq_ignore_ascii_case("open") => PrState::Open,
+                state if state.eq_ignore_ascii_case("closed") => PrState::Closed,
+                state if state.eq_ignore_ascii_case("merged") => PrState::Merged,
+                _ => return None,
+            };
+            Some(PrHead {
+                number: value.get("number").and_then(serde_json::Value::as_u64)?,
+                head_ref_name: head_ref_name.to_string(),
+                state,
+            })
+        })
+        .collect();
+    Ok(heads)
+}
+
+/// Latest CI result of one branch, as listed by [`fetch_branch_ci_statuses`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BranchCiStatus {
+    pub status: CiStatus,
+    /// Commit the runs were for: the branch's newest run head.
+    pub head_sha: String,
+    /// The first failing run, or the newest run otherwise.
+    pub url: Option<String>,
+}
+
+/// Workflow runs listed per branch by [`fetch_branch_ci_statuses`].
+const BRANCH_CI_RUN_LIMIT: &str = "200";
+
+/// CI state of every branch with recent workflow runs, fetched in ONE
+/// `gh run list` call, so branches without a PR are covered too. Only the
+/// runs for each branch's newest head commit count, one per workflow.
+pub fn fetch_branch_ci_statuses(
+    repo_path: &Path,
+) -> Result<std::collections::HashMap<String, BranchCiStatus>> {
+    let output = run_gh_command(
+        repo_path,
+        &[
+            "run",
+            "list",
+            "--json",
+            "headBranch,headSha,status,conclusion,workflowName,url",
+            "--limit",
+            BRANCH_CI_RUN_LIMIT,
+        ],
+    )?;
+    if !output.success {
+        return Err(GwtError::Git(format!(
+            "gh run list: {}",
+            output.stderr.trim()
+        )));
+    }
+    parse_branch_ci_statuses(&output.stdout)
+}
+
+/// Parse `gh run list --json headBranch,headSha,status,conclusion,workflowName,url`
+/// (newest run first) into a `branch -> CI status` map. Any failed run of
+/// the head commit makes the branch failing; otherwise any unfinished run
+/// makes it pending.
+pub fn parse_branch_ci_statuses(
+    json: &str,
+) -> Result<std::collections::HashMap<String, BranchCiStatus>> {
+    let runs: Vec<serde_json::Value> = serde_json::from_str(json)
+        .map_err(|e| GwtError::Other(format!("gh run list JSON: {e}")))?;
+    let field = |run: &serde_json::Value, name: &str| {
+        run.get(name)
+            .and_then(serde_json::Value::as_str)
+            .map(str::trim)
+            .unwrap_or("")
+            .to_string()
+    };
+    let mut statuses: std::collections::HashMap<String, BranchCiStatus> =
+        std::collections::HashMap::new();
+    let mut seen_workflows: std::collections::HashSet<(String, String)> =
+        std::collections::HashSet::new();
+    for run in &runs {
+        let branch = field(run, "headBranch");
+        let sha = field(run, "headSha");
+        if branch.is_empty() || sha.is_empty() {
+            continue;
+        }
+        let entry = statuses
+            .entry(branch.clone())
+            .or_insert_with(|| BranchCiStatus {
+                status: CiStatus::Passing,
+                head_sha: sha.clone(),
+                url: None,
+            });
+        // Older commits and reruns of a workflow already seen do not count.
+        if entry.head_sha != sha || !seen_workflows.insert((branch, field(run, "workflowName"))) {
+            continue;
+        }
+        let url = Some(field(run, "url")).filter(|url| !url.is_empty());
+        let run_status = if !field(run, "status").eq_ignore_ascii_case("completed") {
+            CiStatus::Pending
+        } else {
+            match field(run, "conclusion").to_ascii_lowercase().as_str() {
+                "success" | "neutral" | "skipped" => CiStatus::Passing,
+                _ => CiStatus::Failing,
+            }
+        };
+        if entry.url.is_none() {
+            entry.url = url.clone();
+        }
+        match (&entry.status, run_status) {
+            (CiStatus::Failing, _) => {}
+            (_, CiStatus::Failing) => {
+                entry.status = CiStatus::Failing;
+                entry.url = url;
+            }
+            (_, CiStatus::Pending) => entry.status = CiStatus::Pending,
+            _ => {}
+        }
+    }
+    Ok(statuses)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GhCliOutput {
+    pub(crate) success: bool,
+    pub(crate) stdout: String,
+    pub(crate) stderr: String,
 }
 
 fn fetch_pr_list_with<F>(repo_path: &Path, mut run_gh: F) -> Result<Vec<PrStatus>>
@@ -388,7 +553,7 @@ where
     parse_rest_pr_list_json(&rest.stdout)
 }
 
-fn run_gh_command(repo_path: &Path, args: &[&str]) -> Result<GhCliOutput> {
+pub(crate) fn run_gh_command(repo_path: &Path, args: &[&str]) -> Result<GhCliOutput> {
     let hub = gwt_core::process_console::global();
     let label = format!("gh {}", args.join(" "));
     let options =
@@ -461,7 +626,8 @@ fn parse_rest_pr_list_json(json: &str) -> Result<Vec<PrStatus>> {
 // ── Extended PR check report ──
 
 /// PR status check states.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum CiStatus {
     Passing,
     Failing,
@@ -879,6 +1045,61 @@ mod tests {
         assert_eq!(branches.len(), 2, "empty head refs are skipped");
     }
 
+    #[test]
+    fn parse_branch_ci_statuses_uses_newest_head_per_workflow() {
+        let json = r#"[
+            {"headBranch": "work/a", "headSha": "a2", "status": "in_progress", "conclusion": "", "workflowName": "CI", "url": "u1"},
+            {"headBranch": "work/a", "headSha": "a2", "status": "completed", "conclusion": "success", "workflowName": "Lint", "url": "u2"},
+            {"headBranch": "work/a", "headSha": "a1", "status": "completed", "conclusion": "failure", "workflowName": "CI", "url": "u3"},
+            {"headBranch": "work/b", "headSha": "b1", "status": "completed", "conclusion": "success", "workflowName": "CI", "url": "u4"},
+            {"headBranch": "work/b", "headSha": "b1", "status": "completed", "conclusion": "failure", "workflowName": "Lint", "url": "u5"},
+            {"headBranch": "work/c", "headSha": "c1", "status": "completed", "conclusion": "success", "workflowName": "CI", "url": "u6"},
+            {"headBranch": "work/c", "headSha": "c1", "status": "completed", "conclusion": "failure", "workflowName": "CI", "url": "u7"}
+        ]"#;
+        let statuses = parse_branch_ci_statuses(json).unwrap();
+        assert_eq!(statuses["work/a"].status, CiStatus::Pending);
+        assert_eq!(statuses["work/a"].url.as_deref(), Some("u1"));
+        assert_eq!(statuses["work/b"].status, CiStatus::Failing);
+        assert_eq!(statuses["work/b"].url.as_deref(), Some("u5"));
+        assert_eq!(
+            statuses["work/c"].status,
+            CiStatus::Passing,
+            "an older run of the same workflow is ignored"
+        );
+    }
+
+    #[test]
+    fn parse_pr_heads_json_keeps_number_branch_and_state() {
+        let json = r#"[
+            {"number": 7, "headRefName": "work/a", "state": "MERGED"},
+            {"number": 8, "headRefName": "work/b", "state": "closed"},
+            {"number": 9, "headRefName": "work/c", "state": "OPEN"},
+            {"number": 10, "headRefName": "", "state": "MERGED"},
+            {"number": 11, "headRefName": "work/d", "state": "DRAFT"}
+        ]"#;
+        let heads = parse_pr_heads_json(json).unwrap();
+        assert_eq!(
+            heads,
+            vec![
+                PrHead {
+                    number: 7,
+                    head_ref_name: "work/a".to_string(),
+                    state: PrState::Merged,
+                },
+                PrHead {
+                    number: 8,
+                    head_ref_name: "work/b".to_string(),
+                    state: PrState::Closed,
+                },
+                PrHead {
+                    number: 9,
+                    head_ref_name: "work/c".to_string(),
+                    state: PrState::Open,
+                },
+            ]
+        );
+    }
+
     #[test]
     fn parse_pr_status_open() {
         let json = r#"{
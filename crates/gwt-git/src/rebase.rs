@@ -0,0 +1,345 @@
+//! Rebasing a worktree branch onto an updated base.
+//!
+//! [`Branch::rebase_onto`] runs a plain (non-interactive) `git rebase` in the
+//! worktree that has the branch checked out. When git stops on conflicts
+//! the rebase is left in progress and the outcome lists the conflicted
+//! files, so the caller can show them, hand them to an agent, and later
+//! [`continue_rebase`] or [`abort_rebase`].
+
+use std::path::Path;
+
+use gwt_core::{GwtError, Result};
+use serde::Serialize;
+
+use crate::branch::Branch;
+
+/// How a conflicted path differs between the two sides, from the
+/// `git status --porcelain` XY code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictKind {
+    BothModified,
+    BothAdded,
+    BothDeleted,
+    AddedByUs,
+    AddedByThem,
+    DeletedByUs,
+    DeletedByThem,
+}
+
+impl ConflictKind {
+    fn from_xy(xy: &str) -> Option<Self> {
+        Some(match xy {
+            "UU" => Self::BothModified,
+            "AA" => Self::BothAdded,
+            "DD" => Self::BothDeleted,
+            "AU" => Self::AddedByUs,
+            "UA" => Self::AddedByThem,
+            "DU" => Self::DeletedByUs,
+            "UD" => Self::DeletedByThem,
+            _ => return None,
+        })
+    }
+
+    /// Wording used by `git status`.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::BothModified => "both modified",
+            Self::BothAdded => "both added",
+            Self::BothDeleted => "both deleted",
+            Self::AddedByUs => "added by us",
+            Self::AddedByThem => "added by them",
+            Self::DeletedByUs => "deleted by us",
+            Self::DeletedByThem => "deleted by them",
+        }
+    }
+}
+
+/// A file git could not merge on its own.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConflictFile {
+    /// Path relative to the worktree root.
+    pub path: String,
+    pub kind: ConflictKind,
+}
+
+/// Result of [`Branch::rebase_onto`] or [`continue_rebase`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum RebaseOutcome {
+    /// `onto` was already contained in the branch; nothing ran.
+    UpToDate { onto: String },
+    /// Every commit was replayed; `head` is the new branch tip.
+    Rebased { onto: String, head: String },
+    /// git stopped with conflicts and the rebase is still in progress.
+    Conflicted {
+        onto: String,
+        /// Subject of the commit being replayed, when git reports it.
+        stopped_at: Option<String>,
+        conflicts: Vec<ConflictFile>,
+    },
+}
+
+impl Branch {
+    /// Rebase this branch onto `onto` inside `worktree`, which must have the
+    /// branch checked out. A conflict is not an error: it is returned as
+    /// [`RebaseOutcome::Conflicted`] with the rebase left in progress.
+    pub fn rebase_onto(&self, worktree: &Path, onto: &str) -> Result<RebaseOutcome> {
+        let head = git_stdout(worktree, &["rev-parse", "--abbrev-ref", "HEAD"])?;
+        if head.trim() != self.name {
+            return Err(GwtError::Git(format!(
+                "{} is not checked out in {}",
+                self.name,
+                worktree.display()
+            )));
+        }
+        if rebase_in_progress(worktree) {
+            return Err(GwtError::Git(format!(
+                "a rebase is already in progress in {}",
+                worktree.display()
+            )));
+        }
+        let contained = gwt_core::process::run_git_logged(
+            &["merge-base", "--is-ancestor", onto, "HEAD"],
+            Some(worktree),
+        )
+        .map(|output| output.status.success())
+        .unwrap_or(false);
+        if contained {
+            return Ok(RebaseOutcome::UpToDate {
+                onto: onto.to_string(),
+            });
+        }
+        run_rebase(worktree, onto, &["rebase", onto])
+    }
+}
+
+/// Continue a stopped rebase after its conflicts were resolved and staged.
+pub fn continue_rebase(worktree: &Path) -> Result<RebaseOutcome> {
+    let onto = in_progress_onto(worktree).ok_or_else(|| {
+        GwtError::Git(format!(
+            "no rebase is in progress in {}",
+            worktree.display()
+        ))
+    })?;
+    let unresolved = conflicted_files(worktree)?;
+    if !unresolved.is_empty() {
+        return Ok(RebaseOutcome::Conflicted {
+            onto,
+            stopped_at: stopped_at(worktree),
+            conflicts: unresolved,
+        });
+    }
+    // `true` as the editor accepts the replayed commit messages unchanged.
+    run_rebase(
+        worktree,
+        &onto,
+        &["-c", "core.editor=true", "rebase", "--continue"],
+    )
+}
+
+/// Abort a stopped rebase and restore the branch to where it started.
+pub fn abort_rebase(worktree: &Path) -> Result<()> {
+    git_stdout(worktree, &["rebase", "--abort"]).map(drop)
+}
+
+/// Whether `worktree` is in the middle of a rebase.
+pub fn rebase_in_progress(worktree: &Path) -> bool {
+    ["rebase-merge", "rebase-apply"]
+        .iter()
+        .any(|dir| git_path(worktree, dir).is_some_and(|path| path.is_dir()))
+}
+
+/// Branch being rebased in `worktree`. HEAD is detached while a rebase is
+/// stopped, so worktree listings do not show the branch.
+pub fn rebasing_branch(worktree: &Path) -> Option<String> {
+    ["rebase-merge/head-name", "rebase-apply/head-name"]
+        .iter()
+        .filter_map(|file| git_path(worktree, file))
+        .find_map(|path| std::fs::read_to_string(path).ok())
+        .and_then(|name| name.trim().strip_prefix("refs/heads/").map(str::to_string))
+}
+
+/// Unmerged paths in `worktree`.
+pub fn conflicted_files(worktree: &Path) -> Result<Vec<ConflictFile>> {
+    let status = git_stdout(worktree, &["status", "--porcelain=v1", "-z"])?;
+    Ok(parse_conflicts(&status))
+}
+
+fn parse_conflicts(status: &str) -> Vec<ConflictFile> {
+    let mut conflicts = Vec::new();
+    let mut records = status.split('\0');
+    while let Some(record) = records.next() {
+        if record.len() < 4 {
+            continue;
+        }
+        let (xy, path) = record.split_at(2);
+        // Renames carry the original path as the next record.
+        if xy.starts_with('R') || xy.starts_with('C') {
+            records.next();
+        }
+        if let Some(kind) = ConflictKind::from_xy(xy) {
+            conflicts.push(ConflictFile {
+                path: path[1..].to_string(),
+                kind,
+            });
+        }
+    }
+    conflicts
+}
+
+fn run_rebase(worktree: &Path, onto: &str, args: &[&str]) -> Result<RebaseOutcome> {
+    let output = gwt_core::process::run_git_logged(args, Some(worktree))
+        .map_err(|e| GwtError::Git(format!("rebase: {e}")))?;
+    if output.status.success() {
+        let head = git_stdout(worktree, &["rev-parse", "HEAD"])?;
+        return Ok(RebaseOutcome::Rebased {
+            onto: onto.to_string(),
+            head: head.trim().to_string(),
+        });
+    }
+    if rebase_in_progress(worktree) {
+        return Ok(RebaseOutcome::Conflicted {
+            onto: onto.to_string(),
+            stopped_at: stopped_at(worktree),
+            conflicts: conflicted_files(worktree)?,
+        });
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    Err(GwtError::Git(format!("rebase: {}", stderr.trim())))
+}
+
+/// Commit the in-progress rebase is replaying onto, abbreviated.
+fn in_progress_onto(worktree: &Path) -> Option<String> {
+    if !rebase_in_progress(worktree) {
+        return None;
+    }
+    ["rebase-merge/onto", "rebase-apply/onto"]
+        .iter()
+        .filter_map(|file| git_path(worktree, file))
+        .find_map(|path| std::fs::read_to_string(path).ok())
+        .map(|sha| sha.trim().chars().take(12).collect())
+}
+
+fn stopped_at(worktree: &Path) -> Option<String> {
+    let subject = git_stdout(worktree, &["log", "-1", "--format=%s", "REBASE_HEAD"]).ok()?;
+    let subject = subject.trim();
+    (!subject.is_empty()).then(|| subject.to_string())
+}
+
+fn git_path(worktree: &Path, name: &str) -> Option<std::path::PathBuf> {
+    let path = git_stdout(worktree, &["rev-parse", "--git-path", name]).ok()?;
+    Some(worktree.join(path.trim()))
+}
+
+fn git_stdout(dir: &Path, args: &[&str]) -> Result<String> {
+    let label = args[..2.min(args.len())].join(" ");
+    let output = gwt_core::process::run_git_logged(args, Some(dir))
+        .map_err(|e| GwtError::Git(format!("{label}: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(GwtError::Git(format!("{label}: {}", stderr.trim())));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::run_git_logged(args, Some(dir)).unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn branch(name: &str) -> Branch {
+        Branch {
+            name: name.to_string(),
+            remote_name: None,
+            remote_branch_name: None,
+            is_local: true,
+            is_remote: false,
+            is_head: true,
+            upstream: None,
+            ahead: 0,
+            behind: 0,
+            last_commit_date: None,
+        }
+    }
+
+    #[test]
+    fn parses_unmerged_status_codes() {
+        let status = "UU src/lib.rs\0M  README.md\0DU gone.rs\0R  new.rs\0old.rs\0AA both.rs\0";
+        assert_eq!(
+            parse_conflicts(status),
+            vec![
+                ConflictFile {
+                    path: "src/lib.rs".to_string(),
+                    kind: ConflictKind::BothModified,
+                },
+                ConflictFile {
+                    path: "gone.rs".to_string(),
+                    kind: ConflictKind::DeletedByUs,
+                },
+                ConflictFile {
+                    path: "both.rs".to_string(),
+                    kind: ConflictKind::BothAdded,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rebase_stops_on_conflict_then_continues() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path();
+        git(repo, &["init", "--initial-branch=main"]);
+        git(repo, &["config", "user.email", "t@example.com"]);
+        git(repo, &["config", "user.name", "T"]);
+        std::fs::write(repo.join("a.txt"), "base\n").unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-m", "base"]);
+        git(repo, &["checkout", "-b", "work"]);
+        std::fs::write(repo.join("a.txt"), "work\n").unwrap();
+        git(repo, &["commit", "-am", "work change"]);
+        git(repo, &["checkout", "main"]);
+        std::fs::write(repo.join("a.txt"), "main\n").unwrap();
+        git(repo, &["commit", "-am", "main change"]);
+        git(repo, &["checkout", "work"]);
+
+        assert!(branch("main").rebase_onto(repo, "main").is_err());
+        let outcome = branch("work").rebase_onto(repo, "main").unwrap();
+        let RebaseOutcome::Conflicted {
+            stopped_at,
+            conflicts,
+            ..
+        } = outcome
+        else {
+            panic!("expected conflict, got {outcome:?}");
+        };
+        assert_eq!(stopped_at.as_deref(), Some("work change"));
+        assert_eq!(conflicts[0].path, "a.txt");
+        assert_eq!(conflicts[0].kind, ConflictKind::BothModified);
+        assert_eq!(rebasing_branch(repo).as_deref(), Some("work"));
+        assert!(matches!(
+            continue_rebase(repo).unwrap(),
+            RebaseOutcome::Conflicted { .. }
+        ));
+
+        std::fs::write(repo.join("a.txt"), "resolved\n").unwrap();
+        git(repo, &["add", "a.txt"]);
+        assert!(matches!(
+            continue_rebase(repo).unwrap(),
+            RebaseOutcome::Rebased { .. }
+        ));
+        assert!(!rebase_in_progress(repo));
+        assert!(matches!(
+            branch("work").rebase_onto(repo, "main").unwrap(),
+            RebaseOutcome::UpToDate { .. }
+        ));
+    }
+}
@@ -341,15 +341,26 @@ fi
 }
 
 fn upsert_managed_hook_block(existing: &str) -> String {
-    if let Some(start) = existing.find(GWT_HOOK_START) {
-        if let Some(end_rel) = existing[start..].find(GWT_HOOK_END) {
-            let end = start + end_rel + GWT_HOOK_END.len();
-            let mut rewritten = String::with_capacity(existing.len() + hook_script_block().len());
+    upsert_hook_block(existing, GWT_HOOK_START, GWT_HOOK_END, &hook_script_block())
+}
+
+/// Replace the `start_marker`..`end_marker` block of a hook script with
+/// `block`, or append `block` (creating a `#!/bin/sh` script when empty).
+pub(crate) fn upsert_hook_block(
+    existing: &str,
+    start_marker: &str,
+    end_marker: &str,
+    block: &str,
+) -> String {
+    if let Some(start) = existing.find(start_marker) {
+        if let Some(end_rel) = existing[start..].find(end_marker) {
+            let end = start + end_rel + end_marker.len();
+            let mut rewritten = String::with_capacity(existing.len() + block.len());
             rewritten.push_str(existing[..start].trim_end());
             if !rewritten.is_empty() {
                 rewritten.push('\n');
             }
-            rewritten.push_str(&hook_script_block());
+            rewritten.push_str(block);
             let suffix = existing[end..].trim_start_matches('\n');
             if !suffix.is_empty() {
                 rewritten.push('\n');
@@ -363,9 +374,9 @@ fn upsert_managed_hook_block(existing: &str) -> String {
     }
 
     if existing.is_empty() {
-        format!("#!/bin/sh\n{}\n", hook_script_block())
+        format!("#!/bin/sh\n{block}\n")
     } else {
-        format!("{}\n{}\n", existing.trim_end(), hook_script_block())
+        format!("{}\n{block}\n", existing.trim_end())
     }
 }
 
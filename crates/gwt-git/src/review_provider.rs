@@ -0,0 +1,390 @@
+//! Code-review provider abstraction (GitHub pull requests / GitLab merge
+//! requests).
+//!
+//! The PR helpers in [`crate::pr_status`] speak the GitHub CLI. Repositories
+//! hosted on GitLab expose the same title / state / merged surface through
+//! merge requests and the `glab` CLI. [`ReviewProvider::detect`] keys the
+//! choice off the `origin` remote URL so callers can ask for "the review title
+//! of each branch" or "the merged branches" without knowing the host.
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::Path,
+};
+
+use chrono::{DateTime, Utc};
+use gwt_core::{GwtError, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::pr_status::{self, PrState, PrStatus};
+
+/// Hosting provider that owns code review for a repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReviewProvider {
+    /// GitHub pull requests via `gh`.
+    GitHub,
+    /// GitLab merge requests via `glab`.
+    GitLab,
+}
+
+impl ReviewProvider {
+    /// Classify a remote URL. Returns `None` for hosts that are neither GitHub
+    /// nor GitLab (including local paths).
+    ///
+    /// Self-hosted instances are recognized when the host name contains
+    /// `github` / `gitlab` (e.g. `gitlab.example.com`).
+    pub fn from_remote_url(url: &str) -> Option<Self> {
+        let host = remote_url_host(url)?.to_ascii_lowercase();
+        if host.contains("github") {
+            Some(Self::GitHub)
+        } else if host.contains("gitlab") {
+            Some(Self::GitLab)
+        } else {
+            None
+        }
+    }
+
+    /// Detect the provider from the `origin` remote of `repo_path`.
+    ///
+    /// Falls back to [`ReviewProvider::GitHub`] when `origin` is missing or
+    /// its host is unknown, which preserves the historical GitHub-only
+    /// behavior.
+    pub fn detect(repo_path: &Path) -> Self {
+        origin_url(repo_path)
+            .as_deref()
+            .and_then(Self::from_remote_url)
+            .unwrap_or(Self::GitHub)
+    }
+
+    /// Short user-facing noun for a review request on this provider.
+    pub fn review_noun(self) -> &'static str {
+        match self {
+            Self::GitHub => "PR",
+            Self::GitLab => "MR",
+        }
+    }
+
+    /// CLI binary used to talk to this provider.
+    pub fn cli(self) -> &'static str {
+        match self {
+            Self::GitHub => "gh",
+            Self::GitLab => "glab",
+        }
+    }
+}
+
+/// Recent review requests (PRs or MRs) for the repository at `repo_path`.
+pub fn fetch_review_list(repo_path: &Path) -> Result<Vec<PrStatus>> {
+    match ReviewProvider::detect(repo_path) {
+        ReviewProvider::GitHub => pr_status::fetch_pr_list(repo_path),
+        ReviewProvider::GitLab => {
+            let output = run_glab(repo_path, &["mr", "list", "--all", "--output", "json"])?;
+            parse_gitlab_mr_list_json(&output)
+        }
+    }
+}
+
+/// `branch -> review title` for every PR/MR of the repository. The most
+/// recent request per branch wins.
+pub fn fetch_review_titles_by_branch(repo_path: &Path) -> Result<HashMap<String, String>> {
+    match ReviewProvider::detect(repo_path) {
+        ReviewProvider::GitHub => pr_status::fetch_pr_titles_by_branch(repo_path),
+        ReviewProvider::GitLab => {
+            let output = run_glab(
+                repo_path,
+                &[
+                    "mr",
+                    "list",
+                    "--all",
+                    "--per-page",
+                    "100",
+                    "--output",
+                    "json",
+                ],
+            )?;
+            parse_gitlab_mr_titles_by_branch(&output)
+        }
+    }
+}
+
+/// Branches whose PR/MR has merged. A failed query returns an `Err` so callers
+/// never act on a false "not merged" signal.
+pub fn fetch_merged_review_branches(repo_path: &Path) -> Result<BTreeSet<String>> {
+    match ReviewProvider::detect(repo_path) {
+        ReviewProvider::GitHub => pr_status::fetch_merged_pr_branches(repo_path),
+        ReviewProvider::GitLab => {
+            let output = run_glab(
+                repo_path,
+                &[
+                    "mr",
+                    "list",
+                    "--merged",
+                    "--per-page",
+                    "100",
+                    "--output",
+                    "json",
+                ],
+            )?;
+            parse_gitlab_merged_branches(&output)
+        }
+    }
+}
+
+/// Parse `glab mr list --output json` (GitLab REST merge request objects)
+/// into the provider-neutral [`PrStatus`] shape.
+pub fn parse_gitlab_mr_list_json(json: &str) -> Result<Vec<PrStatus>> {
+    Ok(parse_gitlab_mr_array(json)?
+        .iter()
+        .map(|value| PrStatus {
+            number: value
+                .get("iid")
+                .and_then(serde_json::Value::as_u64)
+                .unwrap_or(0),
+            title: str_field(value, "title").unwrap_or_default().to_string(),
+            state: gitlab_state(value),
+            url: str_field(value, "web_url").unwrap_or_default().to_string(),
+            created_at: str_field(value, "created_at")
+                .and_then(|raw| DateTime::parse_from_rfc3339(raw).ok())
+                .map(|dt| dt.with_timezone(&Utc)),
+            ci_status: gitlab_pipeline_status(value),
+            mergeable: gitlab_mergeable(value),
+            merge_state_status: "UNKNOWN".to_string(),
+            review_status: "UNKNOWN".to_string(),
+        })
+        .collect())
+}
+
+/// Parse GitLab merge requests into a `source_branch -> title` map. The
+/// highest `iid` per branch wins.
+pub fn parse_gitlab_mr_titles_by_branch(json: &str) -> Result<HashMap<String, String>> {
+    let mut best: HashMap<String, (u64, String)> = HashMap::new();
+    for value in parse_gitlab_mr_array(json)? {
+        let (Some(branch), Some(title)) = (
+            str_field(&value, "source_branch"),
+            str_field(&value, "title"),
+        ) else {
+            continue;
+        };
+        let iid = value
+            .get("iid")
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or(0);
+        match best.get(branch) {
+            Some((seen, _)) if *seen >= iid => {}
+            _ => {
+                best.insert(branch.to_string(), (iid, title.to_string()));
+            }
+        }
+    }
+    Ok(best
+        .into_iter()
+        .map(|(branch, (_, title))| (branch, title))
+        .collect())
+}
+
+/// Parse GitLab merge requests into the set of source branches whose MR is
+/// merged.
+pub fn parse_gitlab_merged_branches(json: &str) -> Result<BTreeSet<String>> {
+    Ok(parse_gitlab_mr_array(json)?
+        .iter()
+        .filter(|value| gitlab_state(value) == PrState::Merged)
+        .filter_map(|value| str_field(value, "source_branch").map(str::to_string))
+        .collect())
+}
+
+fn parse_gitlab_mr_array(json: &str) -> Result<Vec<serde_json::Value>> {
+    serde_json::from_str(json).map_err(|e| GwtError::Other(format!("glab mr list JSON: {e}")))
+}
+
+fn str_field<'a>(value: &'a serde_json::Value, key: &str) -> Option<&'a str> {
+    value
+        .get(key)
+        .and_then(serde_json::Value::as_str)
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+}
+
+fn gitlab_state(value: &serde_json::Value) -> PrState {
+    match str_field(value, "state").unwrap_or("opened") {
+        "merged" => PrState::Merged,
+        "closed" | "locked" => PrState::Closed,
+        _ => PrState::Open,
+    }
+}
+
+fn gitlab_pipeline_status(value: &serde_json::Value) -> String {
+    let status = value
+        .get("head_pipeline")
+        .or_else(|| value.get("pipeline"))
+        .and_then(|pipeline| str_field(pipeline, "status"));
+    match status {
+        Some("success") => "SUCCESS",
+        Some("failed" | "canceled") => "FAILURE",
+        Some("running" | "pending" | "created" | "waiting_for_resource" | "preparing") => "PENDING",
+        _ => "UNKNOWN",
+    }
+    .to_string()
+}
+
+fn gitlab_mergeable(value: &serde_json::Value) -> String {
+    if value
+        .get("has_conflicts")
+        .and_then(serde_json::Value::as_bool)
+        .unwrap_or(false)
+    {
+        return "CONFLICTING".to_string();
+    }
+    match str_field(value, "detailed_merge_status").or_else(|| str_field(value, "merge_status")) {
+        Some("mergeable" | "can_be_merged") => "MERGEABLE",
+        Some("cannot_be_merged" | "conflict") => "CONFLICTING",
+        _ => "UNKNOWN",
+    }
+    .to_string()
+}
+
+fn origin_url(repo_path: &Path) -> Option<String> {
+    let output =
+        gwt_core::process::run_git_logged(&["remote", "get-url", "origin"], Some(repo_path))
+            .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let url = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!url.is_empty()).then_some(url)
+}
+
+/// Extract the host of an `https://`, `ssh://`, or scp-style
+/// (`git@host:owner/repo`) remote URL.
+fn remote_url_host(url: &str) -> Option<&str> {
+    let url = url.trim();
+    let rest = if let Some((_, rest)) = url.split_once("://") {
+        rest
+    } else if url.contains(':') && !url.starts_with('/') {
+        url.split(':').next()?
+    } else {
+        return None;
+    };
+    let authority = rest.split('/').next()?;
+    let host = authority.rsplit('@').next()?;
+    let host = host.split(':').next()?;
+    (!host.is_empty()).then_some(host)
+}
+
+fn run_glab(repo_path: &Path, args: &[&str]) -> Result<String> {
+    let label = format!("glab {}", args.join(" "));
+    let output = gwt_core::process::hidden_command("glab")
+        .args(args)
+        .current_dir(repo_path)
+        .output()
+        .map_err(|e| GwtError::Git(format!("{label}: {e}")))?;
+    if !output.status.success() {
+        return Err(GwtError::Git(format!(
+            "{label}: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MR_LIST: &str = r#"[
+        {"iid": 7, "title": "feat: newer", "state": "opened", "source_branch": "feature/a",
+         "web_url": "https://gitlab.com/g/p/-/merge_requests/7",
+         "created_at": "2026-01-02T00:00:00.000Z",
+         "head_pipeline": {"status": "running"}, "detailed_merge_status": "mergeable"},
+        {"iid": 3, "title": "feat: older", "state": "closed", "source_branch": "feature/a"},
+        {"iid": 5, "title": "fix: merged", "state": "merged", "source_branch": "fix/b",
+         "has_conflicts": false, "head_pipeline": {"status": "success"}},
+        {"iid": 6, "title": "wip", "state": "locked", "source_branch": "wip/c", "has_conflicts": true}
+    ]"#;
+
+    #[test]
+    fn remote_url_classification() {
+        let cases = [
+            (
+                "https://github.com/akiojin/gwt.git",
+                Some(ReviewProvider::GitHub),
+            ),
+            (
+                "git@github.com:akiojin/gwt.git",
+                Some(ReviewProvider::GitHub),
+            ),
+            (
+                "ssh://git@gitlab.com/group/sub/proj.git",
+                Some(ReviewProvider::GitLab),
+            ),
+            (
+                "git@gitlab.com:group/proj.git",
+                Some(ReviewProvider::GitLab),
+            ),
+            (
+                "https://user@gitlab.example.com:8443/g/p",
+                Some(ReviewProvider::GitLab),
+            ),
+            ("https://bitbucket.org/team/repo.git", None),
+            ("/srv/git/repo.git", None),
+            ("", None),
+        ];
+        for (url, expected) in cases {
+            assert_eq!(ReviewProvider::from_remote_url(url), expected, "{url}");
+        }
+    }
+
+    #[test]
+    fn detect_falls_back_to_github_without_origin() {
+        let tmp = tempfile::tempdir().unwrap();
+        gwt_core::process::hidden_command("git")
+            .args(["init", tmp.path().to_str().unwrap()])
+            .output()
+            .unwrap();
+        assert_eq!(ReviewProvider::detect(tmp.path()), ReviewProvider::GitHub);
+
+        gwt_core::process::hidden_command("git")
+            .args(["remote", "add", "origin", "git@gitlab.com:g/p.git"])
+            .current_dir(tmp.path())
+            .output()
+            .unwrap();
+        assert_eq!(ReviewProvider::detect(tmp.path()), ReviewProvider::GitLab);
+    }
+
+    #[test]
+    fn gitlab_mr_list_maps_to_pr_status() {
+        let mrs = parse_gitlab_mr_list_json(MR_LIST).unwrap();
+        assert_eq!(mrs.len(), 4);
+        assert_eq!(mrs[0].number, 7);
+        assert_eq!(mrs[0].state, PrState::Open);
+        assert_eq!(mrs[0].ci_status, "PENDING");
+        assert_eq!(mrs[0].mergeable, "MERGEABLE");
+        assert!(mrs[0].created_at.is_some());
+        assert_eq!(mrs[1].state, PrState::Closed);
+        assert_eq!(mrs[2].state, PrState::Merged);
+        assert_eq!(mrs[2].ci_status, "SUCCESS");
+        assert_eq!(mrs[3].state, PrState::Closed);
+        assert_eq!(mrs[3].mergeable, "CONFLICTING");
+    }
+
+    #[test]
+    fn gitlab_titles_prefer_highest_iid_per_branch() {
+        let titles = parse_gitlab_mr_titles_by_branch(MR_LIST).unwrap();
+        assert_eq!(
+            titles.get("feature/a").map(String::as_str),
+            Some("feat: newer")
+        );
+        assert_eq!(titles.get("fix/b").map(String::as_str), Some("fix: merged"));
+    }
+
+    #[test]
+    fn gitlab_merged_branches_only_include_merged_state() {
+        let merged = parse_gitlab_merged_branches(MR_LIST).unwrap();
+        assert_eq!(merged.into_iter().collect::<Vec<_>>(), vec!["fix/b"]);
+    }
+
+    #[test]
+    fn malformed_gitlab_json_is_an_error() {
+        assert!(parse_gitlab_mr_list_json("not json").is_err());
+    }
+}
@@ -0,0 +1,161 @@
+//! Per-worktree stash helpers.
+//!
+//! Git keeps one stash stack per repository, shared by all of its worktrees.
+//! [`stash_list`] narrows it to the entries recorded on the worktree's
+//! current branch, so stashing in one worktree and unstashing in another
+//! does not pick up someone else's changes.
+
+use std::path::Path;
+
+use gwt_core::{GwtError, Result};
+use serde::{Deserialize, Serialize};
+
+/// One `git stash list` entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StashEntry {
+    /// Stash reference, e.g. `stash@{0}`. Indices shift as entries are
+    /// pushed and popped, so resolve it again before each use.
+    pub reference: String,
+    /// Branch the stash was taken on; `None` for a detached HEAD.
+    pub branch: Option<String>,
+    pub message: String,
+}
+
+/// Stash tracked and untracked changes in `worktree` under `message`.
+/// Returns `false` without creating an entry when the worktree is clean.
+pub fn stash_push(worktree: &Path, message: &str) -> Result<bool> {
+    if crate::diff::get_status(worktree)?.is_empty() {
+        return Ok(false);
+    }
+    run_stash(
+        worktree,
+        &["stash", "push", "--include-untracked", "-m", message],
+    )?;
+    Ok(true)
+}
+
+/// Apply `reference` to `worktree` and drop it. A conflicting pop leaves the
+/// entry in place and returns git's error.
+pub fn stash_pop(worktree: &Path, reference: &str) -> Result<()> {
+    run_stash(worktree, &["stash", "pop", reference])?;
+    Ok(())
+}
+
+/// Stash entries taken on `worktree`'s current branch, newest first.
+pub fn stash_list(worktree: &Path) -> Result<Vec<StashEntry>> {
+    let branch = current_branch(worktree)?;
+    let stdout = run_stash(worktree, &["stash", "list", "--format=%gd%x1f%gs"])?;
+    Ok(parse_stash_list(&stdout)
+        .into_iter()
+        .filter(|entry| entry.branch == branch)
+        .collect())
+}
+
+/// Parse `git stash list --format=%gd%x1f%gs`. Subjects look like
+/// `On <branch>: <message>` or `WIP on <branch>: <sha> <subject>`.
+pub fn parse_stash_list(output: &str) -> Vec<StashEntry> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (reference, subject) = line.split_once('\x1f')?;
+            let rest = subject
+                .strip_prefix("WIP on ")
+                .or_else(|| subject.strip_prefix("On "))?;
+            let (branch, message) = rest.split_once(": ").unwrap_or((rest, ""));
+            Some(StashEntry {
+                reference: reference.to_string(),
+                branch: (branch != "(no branch)").then(|| branch.to_string()),
+                message: message.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn current_branch(worktree: &Path) -> Result<Option<String>> {
+    let output = gwt_core::process::run_git_logged(
+        &["symbolic-ref", "--quiet", "--short", "HEAD"],
+        Some(worktree),
+    )
+    .map_err(|e| GwtError::Git(format!("symbolic-ref HEAD: {e}")))?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+    let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok((!branch.is_empty()).then_some(branch))
+}
+
+fn run_stash(worktree: &Path, args: &[&str]) -> Result<String> {
+    let label = args[..2].join(" ");
+    let output = gwt_core::process::run_git_logged(args, Some(worktree))
+        .map_err(|e| GwtError::Git(format!("{label}: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(GwtError::Git(format!("{label}: {}", stderr.trim())));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::run_git_logged(args, Some(dir)).unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn parse_stash_list_reads_branch_and_message() {
+        let output = "stash@{0}\x1fOn work/x: before rebase\n\
+                      stash@{1}\x1fWIP on main: abc1234 Initial commit\n\
+                      stash@{2}\x1fOn (no branch): detached\n";
+        let entries = parse_stash_list(output);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].reference, "stash@{0}");
+        assert_eq!(entries[0].branch.as_deref(), Some("work/x"));
+        assert_eq!(entries[0].message, "before rebase");
+        assert_eq!(entries[1].branch.as_deref(), Some("main"));
+        assert_eq!(entries[2].branch, None);
+    }
+
+    #[test]
+    fn stash_push_list_and_pop_are_scoped_to_the_worktree_branch() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--initial-branch=main"]);
+        git(&repo, &["config", "user.email", "t@example.com"]);
+        git(&repo, &["config", "user.name", "T"]);
+        std::fs::write(repo.join("file.txt"), "one\n").unwrap();
+        git(&repo, &["add", "file.txt"]);
+        git(&repo, &["commit", "-m", "init"]);
+        let feature = tmp.path().join("feature");
+        git(
+            &repo,
+            &["worktree", "add", "-b", "work/x", feature.to_str().unwrap()],
+        );
+
+        assert!(!stash_push(&feature, "nothing").unwrap());
+        std::fs::write(feature.join("file.txt"), "two\n").unwrap();
+        std::fs::write(feature.join("new.txt"), "new\n").unwrap();
+        assert!(stash_push(&feature, "switching away").unwrap());
+        assert!(crate::diff::get_status(&feature).unwrap().is_empty());
+
+        let entries = stash_list(&feature).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].message, "switching away");
+        assert!(stash_list(&repo).unwrap().is_empty());
+
+        stash_pop(&feature, &entries[0].reference).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(feature.join("file.txt")).unwrap(),
+            "two\n"
+        );
+        assert!(feature.join("new.txt").exists());
+        assert!(stash_list(&feature).unwrap().is_empty());
+    }
+}
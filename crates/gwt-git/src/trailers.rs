@@ -0,0 +1,253 @@
+//! Commit trailers for agent-made commits.
+//!
+//! gwt does not write commits itself; the agents running in its worktrees
+//! do. When `[commit_trailers]` is enabled the agent launch exports the
+//! trailers in [`GWT_COMMIT_TRAILERS_ENV`] and installs a gwt-managed
+//! `prepare-commit-msg` block that appends them with
+//! `git interpret-trailers`. Commits made outside an agent session do not
+//! see the variable and are left alone. [`commits_missing_trailers`] checks
+//! a branch before a pull request is opened.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use gwt_core::{GwtError, Result};
+
+use crate::repository::upsert_hook_block;
+
+/// Newline-separated `Key: value` trailers the managed hook appends.
+pub const GWT_COMMIT_TRAILERS_ENV: &str = "GWT_COMMIT_TRAILERS";
+pub const CO_AUTHORED_BY: &str = "Co-authored-by";
+pub const SIGNED_OFF_BY: &str = "Signed-off-by";
+
+const TRAILER_HOOK_START: &str = "# >>> gwt-managed: commit trailers";
+const TRAILER_HOOK_END: &str = "# <<< gwt-managed";
+
+fn trailer_hook_block() -> String {
+    format!(
+        r#"{TRAILER_HOOK_START}
+if [ -n "${GWT_COMMIT_TRAILERS_ENV}" ]; then
+  printf '%s\n' "${GWT_COMMIT_TRAILERS_ENV}" | while IFS= read -r gwt_trailer; do
+    if [ -n "$gwt_trailer" ]; then
+      git interpret-trailers --in-place --if-exists addIfDifferent --trailer "$gwt_trailer" "$1"
+    fi
+  done
+fi
+{TRAILER_HOOK_END}"#
+    )
+}
+
+/// Add the trailer block to the repository's `prepare-commit-msg` hook,
+/// keeping any existing hook content. Returns the hook path.
+///
+/// Refuses when `core.hooksPath` is set: that directory is usually tracked
+/// (husky, lefthook) and gwt must not edit project files.
+pub fn install_commit_trailer_hook(worktree: &Path) -> Result<PathBuf> {
+    if git_output(worktree, &["config", "--get", "core.hooksPath"])?.is_some() {
+        return Err(GwtError::Git(
+            "core.hooksPath is set; add the commit trailers to that hook manually".to_string(),
+        ));
+    }
+    let common_dir = git_output(
+        worktree,
+        &["rev-parse", "--path-format=absolute", "--git-common-dir"],
+    )?
+    .ok_or_else(|| GwtError::Git("rev-parse --git-common-dir: no output".to_string()))?;
+    let hooks_dir = PathBuf::from(common_dir).join("hooks");
+    fs::create_dir_all(&hooks_dir)?;
+
+    let hook_path = hooks_dir.join("prepare-commit-msg");
+    let existing = match fs::read_to_string(&hook_path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(error) => return Err(error.into()),
+    };
+    let updated = upsert_hook_block(
+        &existing,
+        TRAILER_HOOK_START,
+        TRAILER_HOOK_END,
+        &trailer_hook_block(),
+    );
+    if updated != existing {
+        fs::write(&hook_path, updated)?;
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&hook_path, fs::Permissions::from_mode(0o755))?;
+    }
+    Ok(hook_path)
+}
+
+/// `Signed-off-by: Name <email>` for the committer identity git would use in
+/// `worktree`, or `None` when no identity is configured.
+pub fn signoff_trailer(worktree: &Path) -> Option<String> {
+    let ident = git_output(worktree, &["var", "GIT_COMMITTER_IDENT"]).ok()??;
+    let end = ident.find('>')?;
+    Some(format!("{SIGNED_OFF_BY}: {}", &ident[..=end]))
+}
+
+/// A commit that lacks some required trailer keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitTrailerGap {
+    pub hash: String,
+    pub subject: String,
+    pub missing: Vec<String>,
+}
+
+/// Non-merge commits in `range` (e.g. `origin/main..HEAD`) missing any of
+/// the `required` trailer keys. Keys compare case-insensitively.
+pub fn commits_missing_trailers(
+    worktree: &Path,
+    range: &str,
+    required: &[&str],
+) -> Result<Vec<CommitTrailerGap>> {
+    if required.is_empty() {
+        return Ok(Vec::new());
+    }
+    let output = gwt_core::process::run_git_logged(
+        &[
+            "log",
+            "--no-merges",
+            "--format=%h%x1f%s%x1f%(trailers:only,unfold)%x1e",
+            range,
+            "--",
+        ],
+        Some(worktree),
+    )
+    .map_err(|e| GwtError::Git(format!("log {range}: {e}")))?;
+    if !output.status.success() {
+        return Err(GwtError::Git(format!(
+            "log {range}: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(parse_trailer_log(
+        &String::from_utf8_lossy(&output.stdout),
+        required,
+    ))
+}
+
+fn parse_trailer_log(output: &str, required: &[&str]) -> Vec<CommitTrailerGap> {
+    output
+        .split('\x1e')
+        .filter_map(|record| {
+            let mut fields = record.trim_start_matches('\n').splitn(3, '\x1f');
+            let hash = fields.next()?.trim();
+            let subject = fields.next()?;
+            let trailers = fields.next().unwrap_or_default();
+            if hash.is_empty() {
+                return None;
+            }
+            let present: Vec<String> = trailers
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .map(|(key, _)| key.trim().to_ascii_lowercase())
+                .collect();
+            let missing: Vec<String> = required
+                .iter()
+                .filter(|key| !present.contains(&key.to_ascii_lowercase()))
+                .map(|key| key.to_string())
+                .collect();
+            (!missing.is_empty()).then(|| CommitTrailerGap {
+                hash: hash.to_string(),
+                subject: subject.to_string(),
+                missing,
+            })
+        })
+        .collect()
+}
+
+/// Trimmed stdout of a git command, `None` when it fails or prints nothing.
+fn git_output(worktree: &Path, args: &[&str]) -> Result<Option<String>> {
+    let output = gwt_core::process::run_git_logged(args, Some(worktree))
+        .map_err(|e| GwtError::Git(format!("{}: {e}", args.join(" "))))?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok((!stdout.is_empty()).then_some(stdout))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(repo: &Path, args: &[&str]) {
+        let output = gwt_core::process::run_git_logged(args, Some(repo)).expect("git");
+        assert!(
+            output.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn init_repo() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().expect("tempdir");
+        git(dir.path(), &["init", "-q", "-b", "main"]);
+        git(dir.path(), &["config", "user.name", "Dev"]);
+        git(dir.path(), &["config", "user.email", "dev@example.com"]);
+        git(dir.path(), &["commit", "-q", "--allow-empty", "-m", "init"]);
+        dir
+    }
+
+    #[test]
+    fn parse_trailer_log_reports_missing_keys() {
+        let log =
+            "abc1234\x1ffeat: a\x1fCo-authored-by: Codex <x@y>\nSigned-off-by: Dev <d@e>\n\x1e\n\
+                   def5678\x1ffix: b\x1fsigned-off-by: Dev <d@e>\n\x1e\n";
+        let gaps = parse_trailer_log(log, &[CO_AUTHORED_BY, SIGNED_OFF_BY]);
+        assert_eq!(
+            gaps,
+            vec![CommitTrailerGap {
+                hash: "def5678".to_string(),
+                subject: "fix: b".to_string(),
+                missing: vec![CO_AUTHORED_BY.to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn managed_hook_appends_exported_trailers_once() {
+        let repo = init_repo();
+        let hook = install_commit_trailer_hook(repo.path()).expect("install hook");
+        install_commit_trailer_hook(repo.path()).expect("reinstall hook");
+        let content = fs::read_to_string(&hook).expect("hook");
+        assert_eq!(content.matches(TRAILER_HOOK_START).count(), 1);
+
+        let trailers = format!(
+            "{CO_AUTHORED_BY}: Codex <codex@agents.gwt.invalid>\n{}",
+            signoff_trailer(repo.path()).expect("identity")
+        );
+        let output = gwt_core::process::hidden_command("git")
+            .args(["commit", "-q", "--allow-empty", "-m", "feat: work"])
+            .env(GWT_COMMIT_TRAILERS_ENV, &trailers)
+            .current_dir(repo.path())
+            .output()
+            .expect("git commit");
+        assert!(output.status.success());
+        git(
+            repo.path(),
+            &["commit", "-q", "--allow-empty", "-m", "chore: manual"],
+        );
+
+        let gaps = commits_missing_trailers(
+            repo.path(),
+            "HEAD~2..HEAD",
+            &[CO_AUTHORED_BY, SIGNED_OFF_BY],
+        )
+        .expect("gaps");
+        assert_eq!(gaps.len(), 1);
+        assert_eq!(gaps[0].subject, "chore: manual");
+    }
+
+    #[test]
+    fn install_refuses_custom_hooks_path() {
+        let repo = init_repo();
+        git(repo.path(), &["config", "core.hooksPath", ".husky"]);
+        assert!(install_commit_trailer_hook(repo.path()).is_err());
+        assert!(!repo.path().join(".husky").exists());
+    }
+}
@@ -0,0 +1,210 @@
+//! Upstream tracking setup for freshly committed worktree branches.
+//!
+//! Agents create commits on branches that were never pushed, which leaves the
+//! safety checks and PR creation without an upstream to compare against.
+//! [`push_first_commit_with_upstream`] publishes such a branch with
+//! `git push -u <remote> <branch>` exactly once: branches that already track
+//! an upstream, or that carry no commits the remote lacks, are left alone.
+
+use std::path::Path;
+
+use gwt_core::{GwtError, Result};
+
+/// Default remote used when publishing a branch for the first time.
+pub const DEFAULT_UPSTREAM_REMOTE: &str = "origin";
+
+/// Outcome of [`push_first_commit_with_upstream`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpstreamPushOutcome {
+    /// HEAD is detached or unborn; there is no branch to publish.
+    NoBranch,
+    /// The branch already has an upstream configured.
+    AlreadyTracking { upstream: String },
+    /// The branch has no commits that are missing from the remote.
+    NoNewCommits { branch: String },
+    /// The branch was pushed and now tracks `<remote>/<branch>`.
+    Pushed {
+        branch: String,
+        remote: String,
+        commits: usize,
+    },
+}
+
+/// Publish the current branch of `worktree` with `git push -u` when it has
+/// local commits but no upstream yet.
+///
+/// The remote must already exist; a missing remote surfaces as an error so
+/// callers can decide whether to log or ignore it.
+pub fn push_first_commit_with_upstream(
+    worktree: &Path,
+    remote: &str,
+) -> Result<UpstreamPushOutcome> {
+    let Some(branch) = current_branch(worktree)? else {
+        return Ok(UpstreamPushOutcome::NoBranch);
+    };
+    if let Some(upstream) = configured_upstream(worktree)? {
+        return Ok(UpstreamPushOutcome::AlreadyTracking { upstream });
+    }
+
+    let commits = commits_missing_from_remote(worktree, remote)?;
+    if commits == 0 {
+        return Ok(UpstreamPushOutcome::NoNewCommits { branch });
+    }
+
+    let output =
+        gwt_core::process::run_git_logged(&["push", "-u", remote, &branch], Some(worktree))
+            .map_err(|e| GwtError::Git(format!("push -u {remote} {branch}: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(GwtError::Git(format!(
+            "push -u {remote} {branch}: {stderr}"
+        )));
+    }
+
+    Ok(UpstreamPushOutcome::Pushed {
+        branch,
+        remote: remote.to_string(),
+        commits,
+    })
+}
+
+fn current_branch(worktree: &Path) -> Result<Option<String>> {
+    let output = gwt_core::process::run_git_logged(
+        &["symbolic-ref", "--quiet", "--short", "HEAD"],
+        Some(worktree),
+    )
+    .map_err(|e| GwtError::Git(format!("symbolic-ref HEAD: {e}")))?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+    let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if branch.is_empty() {
+        return Ok(None);
+    }
+    let unborn = !gwt_core::process::run_git_logged(
+        &["rev-parse", "--verify", "--quiet", "HEAD"],
+        Some(worktree),
+    )
+    .map_err(|e| GwtError::Git(format!("rev-parse HEAD: {e}")))?
+    .status
+    .success();
+    Ok((!unborn).then_some(branch))
+}
+
+fn configured_upstream(worktree: &Path) -> Result<Option<String>> {
+    let output = gwt_core::process::run_git_logged(
+        &[
+            "rev-parse",
+            "--abbrev-ref",
+            "--symbolic-full-name",
+            "@{upstream}",
+        ],
+        Some(worktree),
+    )
+    .map_err(|e| GwtError::Git(format!("rev-parse @{{upstream}}: {e}")))?;
+    if !output.status.success() {
+        return Ok(None);
+    }
+    let upstream = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok((!upstream.is_empty()).then_some(upstream))
+}
+
+fn commits_missing_from_remote(worktree: &Path, remote: &str) -> Result<usize> {
+    let remotes = format!("--remotes={remote}");
+    let output = gwt_core::process::run_git_logged(
+        &["rev-list", "--count", "HEAD", "--not", &remotes],
+        Some(worktree),
+    )
+    .map_err(|e| GwtError::Git(format!("rev-list --count: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(GwtError::Git(format!("rev-list --count: {stderr}")));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .unwrap_or(0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::hidden_command("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn clone_with_remote() -> (tempfile::TempDir, std::path::PathBuf) {
+        let tmp = tempfile::tempdir().unwrap();
+        let remote = tmp.path().join("remote.git");
+        let work = tmp.path().join("work");
+        std::fs::create_dir_all(&remote).unwrap();
+        git(&remote, &["init", "--bare", "--initial-branch=main"]);
+        git(
+            tmp.path(),
+            &["clone", remote.to_str().unwrap(), work.to_str().unwrap()],
+        );
+        git(&work, &["config", "user.email", "t@example.com"]);
+        git(&work, &["config", "user.name", "T"]);
+        git(&work, &["commit", "--allow-empty", "-m", "init"]);
+        git(&work, &["push", "-u", "origin", "main"]);
+        (tmp, work)
+    }
+
+    #[test]
+    fn new_branch_without_commits_is_not_pushed() {
+        let (_tmp, work) = clone_with_remote();
+        git(&work, &["switch", "-c", "feature/empty"]);
+
+        let outcome = push_first_commit_with_upstream(&work, DEFAULT_UPSTREAM_REMOTE).unwrap();
+        assert_eq!(
+            outcome,
+            UpstreamPushOutcome::NoNewCommits {
+                branch: "feature/empty".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn first_commit_is_pushed_with_upstream() {
+        let (_tmp, work) = clone_with_remote();
+        git(&work, &["switch", "-c", "feature/first"]);
+        git(&work, &["commit", "--allow-empty", "-m", "feat: first"]);
+
+        let outcome = push_first_commit_with_upstream(&work, DEFAULT_UPSTREAM_REMOTE).unwrap();
+        assert_eq!(
+            outcome,
+            UpstreamPushOutcome::Pushed {
+                branch: "feature/first".to_string(),
+                remote: "origin".to_string(),
+                commits: 1,
+            }
+        );
+
+        let again = push_first_commit_with_upstream(&work, DEFAULT_UPSTREAM_REMOTE).unwrap();
+        assert_eq!(
+            again,
+            UpstreamPushOutcome::AlreadyTracking {
+                upstream: "origin/feature/first".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn detached_head_has_no_branch() {
+        let (_tmp, work) = clone_with_remote();
+        git(&work, &["switch", "--detach", "HEAD"]);
+
+        let outcome = push_first_commit_with_upstream(&work, DEFAULT_UPSTREAM_REMOTE).unwrap();
+        assert_eq!(outcome, UpstreamPushOutcome::NoBranch);
+    }
+}
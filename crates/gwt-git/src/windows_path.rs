@@ -0,0 +1,181 @@
+//! Windows-safe directory names for generated worktree paths.
+//!
+//! [`crate::worktree::sibling_worktree_path`] turns a branch name into a
+//! directory hierarchy. On Windows that breaks for branch segments that are
+//! reserved device names (`con`, `aux`, `com1`, ...), contain characters the
+//! filesystem rejects, or end in a dot or space, and for long branch names
+//! that push the worktree past `MAX_PATH`. [`windows_worktree_segments`]
+//! rewrites the branch segments so the generated path is always creatable:
+//!
+//! - invalid characters become `-`, trailing dots/spaces are trimmed;
+//! - reserved device names get a `_` after the stem (`aux` -> `aux_`);
+//! - when the full path would exceed the length budget, the hierarchy is
+//!   collapsed into one truncated segment with a stable hash suffix so two
+//!   long branches never share a directory.
+//!
+//! Extended-length roots (`\\?\C:\...`) are not subject to `MAX_PATH`, so only
+//! the per-segment rules apply to them.
+
+/// Default budget for the worktree directory itself. Leaves room under the
+/// 260-character `MAX_PATH` for files nested inside the worktree.
+pub const DEFAULT_WINDOWS_MAX_WORKTREE_PATH_LEN: usize = 200;
+
+/// Hex digits of the hash appended to collapsed segments.
+const HASH_SUFFIX_LEN: usize = 8;
+
+const RESERVED_DEVICE_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Whether `segment` names a Windows device. The check ignores case and any
+/// extension, because `aux.txt` is just as unusable as `aux`.
+pub fn is_windows_reserved_name(segment: &str) -> bool {
+    let stem = segment.split('.').next().unwrap_or(segment).trim_end();
+    RESERVED_DEVICE_NAMES
+        .iter()
+        .any(|name| name.eq_ignore_ascii_case(stem))
+}
+
+/// Make one path segment valid on Windows. Returns `None` when nothing
+/// usable is left (for example a segment made only of dots).
+pub fn sanitize_windows_segment(segment: &str) -> Option<String> {
+    let replaced: String = segment
+        .chars()
+        .map(|ch| match ch {
+            '<' | '>' | ':' | '"' | '\\' | '|' | '?' | '*' => '-',
+            ch if ch.is_control() => '-',
+            ch => ch,
+        })
+        .collect();
+    let trimmed = replaced.trim_end_matches(['.', ' ']);
+    if trimmed.is_empty() {
+        return None;
+    }
+    if is_windows_reserved_name(trimmed) {
+        // Suffix the stem, not the extension: `con.txt_` is still `CON`.
+        let (stem, extension) = trimmed.split_at(trimmed.find('.').unwrap_or(trimmed.len()));
+        return Some(format!("{stem}_{extension}"));
+    }
+    Some(trimmed.to_string())
+}
+
+/// Directory segments for `branch` below `layout_root` that are valid on
+/// Windows and keep the joined path within `max_path_len` characters.
+///
+/// `layout_root` is the directory the segments are appended to, e.g.
+/// `C:\src`, `C:\`, or `\\server\share\src`; only its length matters.
+pub fn windows_worktree_segments(
+    layout_root: &str,
+    branch: &str,
+    max_path_len: usize,
+) -> Vec<String> {
+    let segments: Vec<String> = branch
+        .split(['/', '\\'])
+        .filter(|segment| !segment.is_empty())
+        .filter_map(sanitize_windows_segment)
+        .collect();
+    if layout_root.starts_with(r"\\?\") {
+        return segments;
+    }
+
+    let root_len = layout_root.trim_end_matches(['\\', '/']).chars().count();
+    let joined_len = root_len
+        + segments
+            .iter()
+            .map(|segment| 1 + segment.chars().count())
+            .sum::<usize>();
+    if joined_len <= max_path_len || segments.is_empty() {
+        return segments;
+    }
+
+    // Collapse into `<prefix>-<hash>`; the prefix keeps the directory
+    // recognisable while the hash of the original branch keeps it unique.
+    let hash = format!("{:0width$x}", fnv1a(branch), width = HASH_SUFFIX_LEN);
+    let budget = max_path_len
+        .saturating_sub(root_len + 1)
+        .saturating_sub(HASH_SUFFIX_LEN + 1);
+    let flattened = segments.join("-");
+    let prefix: String = flattened.chars().take(budget).collect();
+    let prefix = prefix.trim_end_matches(['.', ' ', '-']);
+    if prefix.is_empty() {
+        return vec![hash];
+    }
+    vec![format!("{prefix}-{hash}")]
+}
+
+/// 32-bit FNV-1a; stable across platforms and releases, unlike `std`'s
+/// `DefaultHasher`, so collapsed paths do not move between gwt versions.
+fn fnv1a(value: &str) -> u32 {
+    value.bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reserved_names_and_invalid_characters_are_rewritten() {
+        assert_eq!(
+            windows_worktree_segments(r"C:\src", "feature/aux", 200),
+            vec!["feature", "aux_"]
+        );
+        assert_eq!(
+            windows_worktree_segments(r"C:\src", "CON.txt/fix: a|b?.", 200),
+            vec!["CON_.txt", "fix- a-b-"]
+        );
+        assert_eq!(sanitize_windows_segment("..."), None);
+        assert!(is_windows_reserved_name("Lpt9"));
+        assert!(!is_windows_reserved_name("console"));
+    }
+
+    #[test]
+    fn long_branch_collapses_to_hashed_segment_within_budget() {
+        let root = r"C:\Users\developer\source\repos";
+        let branch = format!("feature/{}", "very-long-branch-name-".repeat(12));
+        let segments = windows_worktree_segments(root, &branch, 120);
+
+        assert_eq!(segments.len(), 1);
+        let joined = format!(r"{root}\{}", segments[0]);
+        assert!(joined.chars().count() <= 120, "{joined}");
+        assert!(segments[0].starts_with("feature-very-long-branch-name"));
+
+        let other = windows_worktree_segments(root, &format!("{branch}x"), 120);
+        assert_ne!(segments, other, "distinct branches must not collide");
+        assert_eq!(segments, windows_worktree_segments(root, &branch, 120));
+    }
+
+    #[test]
+    fn drive_root_and_unc_roots_count_toward_the_budget() {
+        let branch = "feature/abcdefghij";
+        // `C:\` + `feature\abcdefghij` = 2 + 8 + 11 = 21 characters.
+        assert_eq!(
+            windows_worktree_segments(r"C:\", branch, 21),
+            vec!["feature", "abcdefghij"]
+        );
+        assert_eq!(windows_worktree_segments(r"C:\", branch, 20).len(), 1);
+
+        let unc = r"\\fileserver\share\projects";
+        let collapsed = windows_worktree_segments(unc, branch, 40);
+        assert_eq!(collapsed.len(), 1);
+        assert!(format!(r"{unc}\{}", collapsed[0]).chars().count() <= 40);
+    }
+
+    #[test]
+    fn extended_length_roots_skip_the_length_budget() {
+        let root = r"\\?\C:\src";
+        let branch = format!("feature/{}", "x".repeat(300));
+        let segments = windows_worktree_segments(root, &branch, 200);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[1].len(), 300);
+    }
+
+    #[test]
+    fn budget_smaller_than_root_still_yields_a_hash_segment() {
+        let segments = windows_worktree_segments(r"\\server\share\deep\root", "feature/x", 10);
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].len(), HASH_SUFFIX_LEN);
+    }
+}
@@ -9,7 +9,13 @@ use std::{
 };
 
 use gwt_core::{paths::normalize_windows_child_process_path, GwtError, Result};
+
+use crate::{
+    branch_protection::{DestructiveOp, ProtectionPolicy},
+    worktree_trash::{trash_worktree, WorktreeTrash},
+};
 use serde::{Deserialize, Serialize};
+use tracing::instrument;
 
 const REMOTE_DELETE_TIMEOUT: Duration = Duration::from_secs(120);
 const PROCESS_POLL_INTERVAL: Duration = Duration::from_millis(25);
@@ -30,6 +36,8 @@ pub struct WorktreeInfo {
 /// Manages Git worktrees for a repository.
 pub struct WorktreeManager {
     repo_path: PathBuf,
+    protection: ProtectionPolicy,
+    trash: Option<WorktreeTrash>,
 }
 
 struct GitOutput {
@@ -78,9 +86,26 @@ impl WorktreeManager {
     pub fn new(repo_path: impl AsRef<Path>) -> Self {
         Self {
             repo_path: repo_path.as_ref().to_path_buf(),
+            protection: ProtectionPolicy::default(),
+            trash: None,
         }
     }
 
+    /// Refuse cleanup and remote deletion of the branches `policy`
+    /// protects.
+    pub fn with_protection(mut self, policy: ProtectionPolicy) -> Self {
+        self.protection = policy;
+        self
+    }
+
+    /// Move the worktrees removed by branch cleanup into `trash` instead of
+    /// deleting them, so they can be restored with
+    /// [`crate::worktree_trash::restore_trashed`].
+    pub fn with_trash(mut self, trash: WorktreeTrash) -> Self {
+        self.trash = Some(trash);
+        self
+    }
+
     /// List all worktrees for this repository.
     pub fn list(&self) -> Result<Vec<WorktreeInfo>> {
         let output = run_git_observing_operation_deadline(
@@ -234,6 +259,7 @@ impl WorktreeManager {
     }
 
     /// Create a new worktree at `path` for `branch`.
+    #[instrument(target = "gwt.worktree", skip_all, fields(branch = %branch, path = %path.display()))]
     pub fn create(&self, branch: &str, path: &Path) -> Result<()> {
         let path_arg = path_arg_for_git(path);
         let output = gwt_core::process::run_git_logged(
@@ -257,6 +283,7 @@ impl WorktreeManager {
     /// this does not create or check out a branch: the intake worktree exists
     /// only to host a short-lived agent session and is removed when that
     /// session ends, so it must not leave a branch ref behind.
+    #[instrument(target = "gwt.worktree", skip_all, fields(base = %base_ref, path = %path.display()))]
     pub fn create_detached(&self, base_ref: &str, path: &Path) -> Result<()> {
         if path.exists() {
             return Err(GwtError::Git(format!(
@@ -378,6 +405,7 @@ impl WorktreeManager {
     }
 
     /// Create a new worktree at `path`, creating `new_branch` from `base_branch`.
+    #[instrument(target = "gwt.worktree", skip_all, fields(base = %base_branch, branch = %new_branch, path = %path.display()))]
     pub fn create_from_base(&self, base_branch: &str, new_branch: &str, path: &Path) -> Result<()> {
         if path.exists() {
             return Err(GwtError::Git(format!(
@@ -448,11 +476,13 @@ impl WorktreeManager {
     ) -> Result<RemoteDeleteOutcome> {
         // SPEC-2009 FR-071: defense in depth — refuse to delete a protected base
         // branch (main/master/develop) from the remote even if a caller asks.
-        if crate::is_protected_branch(local_branch) {
+        if crate::is_protected_branch_in(&self.repo_path, local_branch) {
             return Err(GwtError::Git(format!(
                 "refusing to delete protected remote branch: {local_branch}"
             )));
         }
+        self.protection
+            .check(DestructiveOp::DeleteRemoteBranch, local_branch)?;
         let remote_ref = upstream
             .map(normalize_remote_ref)
             .unwrap_or_else(|| format!("origin/{local_branch}"));
@@ -484,6 +514,7 @@ impl WorktreeManager {
     /// Create a local worktree branch from a remote-tracking branch.
     ///
     /// `remote_ref` must be `origin/<name>` or `refs/remotes/origin/<name>`.
+    #[instrument(target = "gwt.worktree", skip_all, fields(remote = %remote_ref, branch = %local_branch, path = %path.display()))]
     pub fn create_from_remote(
         &self,
         remote_ref: &str,
@@ -630,6 +661,8 @@ impl WorktreeManager {
         branch: &str,
         force_filesystem_delete: bool,
     ) -> Result<()> {
+        self.protection
+            .check(DestructiveOp::CleanupBranch, branch)?;
         let worktree_path = self
             .list()?
             .into_iter()
@@ -637,10 +670,12 @@ impl WorktreeManager {
             .map(|wt| wt.path);
 
         if let Some(path) = worktree_path {
-            let remove_result = if force_filesystem_delete {
-                self.remove_force_twice(&path)
-            } else {
-                self.remove_force(&path)
+            let remove_result = match &self.trash {
+                Some(trash) if path.exists() => {
+                    trash_worktree(&self.repo_path, trash, &path, branch).map(|_| ())
+                }
+                _ if force_filesystem_delete => self.remove_force_twice(&path),
+                _ => self.remove_force(&path),
             };
             match remove_result {
                 Ok(()) => {}
@@ -967,14 +1002,39 @@ fn first_child_bare_repository(repo_path: &Path) -> Option<PathBuf> {
 /// The layout root stays at the same directory level as the repository or
 /// bare common-dir, while the branch name itself becomes the relative
 /// directory hierarchy (for example `feature/aaa` -> `../feature/aaa`).
+///
+/// On Windows the segments are passed through
+/// [`crate::windows_path::windows_worktree_segments`] with the default
+/// length budget; use [`sibling_worktree_path_with_max_len`] to override it.
 pub fn sibling_worktree_path(repo_path: &Path, branch: &str) -> PathBuf {
+    sibling_worktree_path_with_max_len(repo_path, branch, None)
+}
+
+/// [`sibling_worktree_path`] with an explicit Windows path length budget
+/// (`None` uses [`crate::windows_path::DEFAULT_WINDOWS_MAX_WORKTREE_PATH_LEN`]).
+/// The budget is ignored on other platforms.
+pub fn sibling_worktree_path_with_max_len(
+    repo_path: &Path,
+    branch: &str,
+    max_path_len: Option<usize>,
+) -> PathBuf {
     let layout_root = repo_path.parent().unwrap_or(repo_path);
     let mut path = layout_root.to_path_buf();
-
-    for segment in branch.trim_matches('/').split('/') {
-        if segment.is_empty() {
-            continue;
-        }
+    let segments: Vec<String> = if cfg!(windows) {
+        crate::windows_path::windows_worktree_segments(
+            &layout_root.to_string_lossy(),
+            branch,
+            max_path_len.unwrap_or(crate::windows_path::DEFAULT_WINDOWS_MAX_WORKTREE_PATH_LEN),
+        )
+    } else {
+        branch
+            .trim_matches('/')
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(str::to_string)
+            .collect()
+    };
+    for segment in segments {
         path.push(segment);
     }
 
@@ -1850,6 +1910,32 @@ prunable gitdir file points to non-existent location
         );
     }
 
+    #[test]
+    fn cleanup_branch_refuses_protected_patterns_until_confirmed() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo_path = tmp.path().join("repo");
+        std::fs::create_dir_all(&repo_path).unwrap();
+        init_git_repo(&repo_path);
+        git_commit_allow_empty(&repo_path, "initial commit");
+
+        let policy = ProtectionPolicy::new(&["release/*".to_string()]);
+        let manager = WorktreeManager::new(&repo_path).with_protection(policy.clone());
+        let worktree_path = sibling_worktree_path(&repo_path, "release/1.x");
+        manager
+            .create_from_base("main", "release/1.x", &worktree_path)
+            .or_else(|_| manager.create_from_base("master", "release/1.x", &worktree_path))
+            .unwrap();
+
+        assert!(manager.cleanup_branch("release/1.x").is_err());
+        assert!(worktree_path.exists());
+
+        WorktreeManager::new(&repo_path)
+            .with_protection(policy.with_confirmed(["release/1.x".to_string()]))
+            .cleanup_branch("release/1.x")
+            .unwrap();
+        assert!(!worktree_path.exists());
+    }
+
     #[test]
     fn cleanup_branch_is_idempotent_for_missing_branch() {
         let tmp = tempfile::tempdir().unwrap();
@@ -0,0 +1,272 @@
+//! Worktree archive and restore.
+//!
+//! Archiving packs a worktree's tracked and untracked files into a
+//! `.tar.gz` (ignored files are left out), records the branch, HEAD commit
+//! and deleted tracked files in the archive index, and removes the worktree.
+//! Restoring checks the branch out into a new worktree at the original path
+//! and unpacks the archive over it, so uncommitted changes come back. The
+//! branch itself is never touched, and restoring refuses when it has moved
+//! since the archive was taken. Staged and unstaged changes are not told
+//! apart after a restore.
+
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use gwt_core::{GwtError, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::worktree::WorktreeManager;
+
+/// Index file listing the archives in an archive directory.
+pub const ARCHIVE_INDEX_FILE: &str = "index.json";
+
+/// One archived worktree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorktreeArchive {
+    pub branch: String,
+    /// Commit the branch pointed at when the worktree was archived.
+    pub head: String,
+    /// Where the worktree lived; restore recreates it here.
+    pub worktree_path: PathBuf,
+    /// Archive file name inside the archive directory.
+    pub archive_file: String,
+    /// Tracked files that had been deleted in the worktree.
+    #[serde(default)]
+    pub deleted_files: Vec<String>,
+    /// RFC 3339 timestamp.
+    pub archived_at: String,
+}
+
+/// Default archive directory for a repository
+/// (`~/.gwt/projects/<repo-hash>/worktree-archives`). Resolved from the main
+/// worktree so every worktree of the repository shares it.
+pub fn default_archive_dir(repo_path: &Path) -> PathBuf {
+    let root =
+        crate::worktree::main_worktree_root(repo_path).unwrap_or_else(|_| repo_path.to_path_buf());
+    gwt_core::paths::gwt_project_dir_for_repo_path(&root).join("worktree-archives")
+}
+
+/// Archives recorded in `archive_dir`, oldest first.
+pub fn list_archives(archive_dir: &Path) -> Result<Vec<WorktreeArchive>> {
+    let path = archive_dir.join(ARCHIVE_INDEX_FILE);
+    match fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|e| GwtError::Other(format!("{}: {e}", path.display()))),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn write_archives(archive_dir: &Path, archives: &[WorktreeArchive]) -> Result<()> {
+    fs::create_dir_all(archive_dir)?;
+    let content = serde_json::to_string_pretty(archives)
+        .map_err(|e| GwtError::Other(format!("serialize worktree archives: {e}")))?;
+    fs::write(archive_dir.join(ARCHIVE_INDEX_FILE), content)?;
+    Ok(())
+}
+
+/// Archive the worktree that has `branch` checked out and remove it.
+pub fn archive_worktree(
+    repo_path: &Path,
+    archive_dir: &Path,
+    branch: &str,
+) -> Result<WorktreeArchive> {
+    let manager = WorktreeManager::new(repo_path);
+    let worktrees = manager.list()?;
+    let position = worktrees
+        .iter()
+        .position(|worktree| worktree.branch.as_deref() == Some(branch))
+        .ok_or_else(|| GwtError::Git(format!("no worktree has {branch} checked out")))?;
+    if position == 0 {
+        return Err(GwtError::Git(format!(
+            "{branch} is checked out in the main worktree, which cannot be archived"
+        )));
+    }
+    let worktree = worktrees[position].path.clone();
+    let mut archives = list_archives(archive_dir)?;
+    if archives.iter().any(|archive| archive.branch == branch) {
+        return Err(GwtError::Git(format!("{branch} already has an archive")));
+    }
+
+    let head = git_stdout(&worktree, &["rev-parse", "HEAD"])?
+        .trim()
+        .to_string();
+    let deleted_files = ls_files(&worktree, &["--deleted"])?;
+    let deleted: HashSet<&str> = deleted_files.iter().map(String::as_str).collect();
+    let files: Vec<String> = ls_files(&worktree, &["--cached", "--others", "--exclude-standard"])?
+        .into_iter()
+        .filter(|file| !deleted.contains(file.as_str()))
+        .collect();
+
+    let now = chrono::Utc::now();
+    let archive_file = format!(
+        "{}-{}.tar.gz",
+        branch.replace(['/', '\\'], "-"),
+        now.format("%Y%m%d%H%M%S")
+    );
+    fs::create_dir_all(archive_dir)?;
+    let archive_path = archive_dir.join(&archive_file);
+    if let Err(error) = write_tarball(&worktree, &files, &archive_path) {
+        let _ = fs::remove_file(&archive_path);
+        return Err(error);
+    }
+
+    let archive = WorktreeArchive {
+        branch: branch.to_string(),
+        head,
+        worktree_path: worktree.clone(),
+        archive_file,
+        deleted_files,
+        archived_at: now.to_rfc3339(),
+    };
+    archives.push(archive.clone());
+    write_archives(archive_dir, &archives)?;
+    if let Err(error) = manager.remove_force(&worktree) {
+        archives.pop();
+        let _ = write_archives(archive_dir, &archives);
+        let _ = fs::remove_file(&archive_path);
+        return Err(error);
+    }
+    Ok(archive)
+}
+
+/// Recreate the archived worktree of `branch` with its uncommitted changes
+/// and drop the archive. Returns the worktree path.
+pub fn restore_worktree(repo_path: &Path, archive_dir: &Path, branch: &str) -> Result<PathBuf> {
+    let mut archives = list_archives(archive_dir)?;
+    let position = archives
+        .iter()
+        .position(|archive| archive.branch == branch)
+        .ok_or_else(|| GwtError::Git(format!("no archive for {branch}")))?;
+    let archive = archives[position].clone();
+    let tip = git_stdout(
+        repo_path,
+        &["rev-parse", "--verify", &format!("refs/heads/{branch}")],
+    )?;
+    if tip.trim() != archive.head {
+        return Err(GwtError::Git(format!(
+            "{branch} has moved since it was archived; restore would overwrite newer commits"
+        )));
+    }
+    if archive.worktree_path.exists() {
+        return Err(GwtError::Git(format!(
+            "{} already exists",
+            archive.worktree_path.display()
+        )));
+    }
+
+    let manager = WorktreeManager::new(repo_path);
+    manager.create(branch, &archive.worktree_path)?;
+    let archive_path = archive_dir.join(&archive.archive_file);
+    let file = fs::File::open(&archive_path)?;
+    tar::Archive::new(GzDecoder::new(file)).unpack(&archive.worktree_path)?;
+    for deleted in &archive.deleted_files {
+        let _ = fs::remove_file(archive.worktree_path.join(deleted));
+    }
+
+    archives.remove(position);
+    write_archives(archive_dir, &archives)?;
+    let _ = fs::remove_file(&archive_path);
+    Ok(archive.worktree_path)
+}
+
+fn write_tarball(worktree: &Path, files: &[String], archive_path: &Path) -> Result<()> {
+    let encoder = GzEncoder::new(fs::File::create(archive_path)?, Compression::default());
+    let mut builder = tar::Builder::new(encoder);
+    builder.follow_symlinks(false);
+    for file in files {
+        let path = worktree.join(file);
+        // Submodule gitlinks are listed as directories; their contents belong
+        // to the submodule, not this worktree.
+        match fs::symlink_metadata(&path) {
+            Ok(metadata) if !metadata.is_dir() => builder.append_path_with_name(&path, file)?,
+            _ => {}
+        }
+    }
+    builder.into_inner()?.finish()?;
+    Ok(())
+}
+
+fn ls_files(worktree: &Path, args: &[&str]) -> Result<Vec<String>> {
+    let mut full = vec!["ls-files", "-z"];
+    full.extend_from_slice(args);
+    Ok(git_stdout(worktree, &full)?
+        .split('\0')
+        .filter(|file| !file.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+fn git_stdout(dir: &Path, args: &[&str]) -> Result<String> {
+    let label = args[..2.min(args.len())].join(" ");
+    let output = gwt_core::process::run_git_logged(args, Some(dir))
+        .map_err(|e| GwtError::Git(format!("{label}: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(GwtError::Git(format!("{label}: {}", stderr.trim())));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::run_git_logged(args, Some(dir)).unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn archive_then_restore_keeps_uncommitted_changes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path().join("repo");
+        fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--initial-branch=main"]);
+        git(&repo, &["config", "user.email", "t@example.com"]);
+        git(&repo, &["config", "user.name", "T"]);
+        fs::write(repo.join(".gitignore"), "target/\n").unwrap();
+        fs::write(repo.join("kept.txt"), "one\n").unwrap();
+        fs::write(repo.join("gone.txt"), "gone\n").unwrap();
+        git(&repo, &["add", "."]);
+        git(&repo, &["commit", "-m", "init"]);
+        let feature = tmp.path().join("feature");
+        git(
+            &repo,
+            &["worktree", "add", "-b", "work/x", feature.to_str().unwrap()],
+        );
+        fs::write(feature.join("kept.txt"), "two\n").unwrap();
+        fs::write(feature.join("new.txt"), "new\n").unwrap();
+        fs::remove_file(feature.join("gone.txt")).unwrap();
+        fs::create_dir_all(feature.join("target")).unwrap();
+        fs::write(feature.join("target/build.out"), "ignored\n").unwrap();
+        let archive_dir = tmp.path().join("archives");
+
+        assert!(archive_worktree(&repo, &archive_dir, "main").is_err());
+        let archive = archive_worktree(&repo, &archive_dir, "work/x").unwrap();
+        assert!(!feature.exists());
+        assert_eq!(archive.deleted_files, vec!["gone.txt".to_string()]);
+        assert_eq!(list_archives(&archive_dir).unwrap(), vec![archive.clone()]);
+        assert!(archive_worktree(&repo, &archive_dir, "work/x").is_err());
+
+        let restored = restore_worktree(&repo, &archive_dir, "work/x").unwrap();
+        assert_eq!(restored, archive.worktree_path);
+        assert_eq!(
+            fs::read_to_string(feature.join("kept.txt")).unwrap(),
+            "two\n"
+        );
+        assert!(feature.join("new.txt").exists());
+        assert!(!feature.join("gone.txt").exists());
+        assert!(!feature.join("target").exists());
+        assert!(list_archives(&archive_dir).unwrap().is_empty());
+        assert!(restore_worktree(&repo, &archive_dir, "work/x").is_err());
+    }
+}
@@ -0,0 +1,242 @@
+//! Copy untracked local files into a freshly created worktree.
+//!
+//! A new worktree only contains tracked files, so `.env`, `.envrc` and other
+//! git-ignored local config has to be carried over by hand. Given glob
+//! patterns relative to the source directory (the main worktree or a
+//! template directory), [`copy_on_create`] copies every matching file that
+//! the new worktree does not already have. Existing files are never
+//! overwritten and `.git` is never entered.
+
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use globset::{GlobBuilder, GlobMatcher};
+use gwt_core::Result;
+use serde::Serialize;
+
+/// What [`copy_on_create`] did.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct WorktreeFileCopy {
+    pub source: PathBuf,
+    /// Files copied, relative to the worktree, `/`-separated.
+    pub copied: Vec<String>,
+    /// Matching files the worktree already had.
+    pub skipped: Vec<String>,
+    /// Patterns that are not valid globs or reach outside the source.
+    pub invalid_patterns: Vec<String>,
+}
+
+impl WorktreeFileCopy {
+    /// One-line summary, e.g. `Copied 2 files from /src/gwt: .env, .envrc
+    /// (1 already present)`.
+    pub fn summary(&self) -> String {
+        let mut summary = match self.copied.len() {
+            0 => format!("No files copied from {}", self.source.display()),
+            1 => format!(
+                "Copied 1 file from {}: {}",
+                self.source.display(),
+                self.copied[0]
+            ),
+            count => format!(
+                "Copied {count} files from {}: {}",
+                self.source.display(),
+                self.copied.join(", ")
+            ),
+        };
+        if !self.skipped.is_empty() {
+            summary.push_str(&format!(" ({} already present)", self.skipped.len()));
+        }
+        summary
+    }
+}
+
+const GLOB_CHARS: [char; 5] = ['*', '?', '[', '{', '\\'];
+
+struct Pattern {
+    matcher: GlobMatcher,
+    /// Leading directory without glob characters, where the walk starts.
+    base: PathBuf,
+    /// Directory levels below `base` a match can be at; `None` for `**`.
+    max_depth: Option<usize>,
+}
+
+impl Pattern {
+    fn parse(pattern: &str) -> Option<Self> {
+        let pattern = pattern.trim().trim_start_matches("./");
+        let segments: Vec<&str> = pattern.split('/').collect();
+        if pattern.is_empty() || Path::new(pattern).is_absolute() || segments.contains(&"..") {
+            return None;
+        }
+        let matcher = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .ok()?
+            .compile_matcher();
+        let literal = segments
+            .iter()
+            .take_while(|segment| !segment.contains(GLOB_CHARS))
+            .count()
+            .min(segments.len() - 1);
+        let base: PathBuf = segments[..literal].iter().collect();
+        let max_depth = (!pattern.contains("**")).then(|| segments.len() - literal - 1);
+        Some(Self {
+            matcher,
+            base,
+            max_depth,
+        })
+    }
+}
+
+/// Copy the files under `source` matching `patterns` into `worktree`.
+pub fn copy_on_create(
+    source: &Path,
+    worktree: &Path,
+    patterns: &[String],
+) -> Result<WorktreeFileCopy> {
+    let mut outcome = WorktreeFileCopy {
+        source: source.to_path_buf(),
+        ..WorktreeFileCopy::default()
+    };
+    let mut matches = BTreeSet::new();
+    for raw in patterns {
+        let Some(pattern) = Pattern::parse(raw) else {
+            outcome.invalid_patterns.push(raw.clone());
+            continue;
+        };
+        collect_matches(
+            source,
+            &pattern.base,
+            &pattern,
+            pattern.max_depth,
+            &mut matches,
+        )?;
+    }
+
+    for relative in matches {
+        let target = worktree.join(&relative);
+        if fs::symlink_metadata(&target).is_ok() {
+            outcome.skipped.push(relative);
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(source.join(&relative), &target)?;
+        outcome.copied.push(relative);
+    }
+    Ok(outcome)
+}
+
+fn collect_matches(
+    root: &Path,
+    dir: &Path,
+    pattern: &Pattern,
+    depth_left: Option<usize>,
+    matches: &mut BTreeSet<String>,
+) -> Result<()> {
+    let entries = match fs::read_dir(root.join(dir)) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(error) => return Err(error.into()),
+    };
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name();
+        if name == ".git" {
+            continue;
+        }
+        let relative = dir.join(&name);
+        // Symlinked directories are not followed.
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            if depth_left != Some(0) {
+                collect_matches(
+                    root,
+                    &relative,
+                    pattern,
+                    depth_left.map(|depth| depth - 1),
+                    matches,
+                )?;
+            }
+        } else if file_type.is_file() {
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            if pattern.matcher.is_match(&relative) {
+                matches.insert(relative);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, relative: &str, contents: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn copies_matching_files_without_overwriting() {
+        let source = tempfile::tempdir().unwrap();
+        let worktree = tempfile::tempdir().unwrap();
+        write(source.path(), ".env", "SECRET=1");
+        write(source.path(), ".envrc", "use flake");
+        write(source.path(), "config/app.local.toml", "debug = true");
+        write(source.path(), "config/app.toml", "tracked");
+        write(source.path(), "web/deep/.env", "NESTED=1");
+        write(source.path(), ".git/config", "[core]");
+        write(worktree.path(), ".envrc", "already here");
+
+        let outcome = copy_on_create(
+            source.path(),
+            worktree.path(),
+            &[
+                ".env*".to_string(),
+                "config/*.local.toml".to_string(),
+                "**/deep/.env".to_string(),
+                "../outside".to_string(),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            outcome.copied,
+            [".env", "config/app.local.toml", "web/deep/.env"]
+        );
+        assert_eq!(outcome.skipped, [".envrc"]);
+        assert_eq!(outcome.invalid_patterns, ["../outside"]);
+        assert_eq!(
+            fs::read_to_string(worktree.path().join(".envrc")).unwrap(),
+            "already here"
+        );
+        assert_eq!(
+            fs::read_to_string(worktree.path().join("web/deep/.env")).unwrap(),
+            "NESTED=1"
+        );
+        assert!(!worktree.path().join("config/app.toml").exists());
+        assert!(outcome.summary().starts_with("Copied 3 files from "));
+        assert!(outcome.summary().ends_with("(1 already present)"));
+    }
+
+    #[test]
+    fn single_star_does_not_cross_directories() {
+        let source = tempfile::tempdir().unwrap();
+        let worktree = tempfile::tempdir().unwrap();
+        write(source.path(), "sub/.env", "X=1");
+
+        let outcome = copy_on_create(
+            source.path(),
+            worktree.path(),
+            &["*.env".to_string(), ".env".to_string()],
+        )
+        .unwrap();
+        assert!(outcome.copied.is_empty());
+        assert!(outcome.summary().starts_with("No files copied"));
+    }
+}
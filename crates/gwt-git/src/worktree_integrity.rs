@@ -0,0 +1,322 @@
+//! Worktree integrity checks.
+//!
+//! An agent interrupted in the middle of a git command can leave a worktree
+//! with a stale `index.lock`, a truncated index, a half-finished rebase or
+//! merge, or (rarely) broken objects. [`check_worktree_integrity`] looks for
+//! each of these, scoping `git fsck` to what `HEAD` reaches so it stays cheap,
+//! and [`save_report`] keeps the result in the worktree's own git directory
+//! so the branch list can show it later. [`repair_worktree_integrity`] fixes
+//! what can be fixed without losing work and says what is left to do by hand.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use gwt_core::{GwtError, Result};
+use serde::{Deserialize, Serialize};
+
+/// Report file inside the worktree's git directory.
+pub const INTEGRITY_REPORT_FILE: &str = "gwt-integrity.json";
+
+/// `fsck` lines kept in a report.
+const MAX_FSCK_LINES: usize = 20;
+
+/// Operations whose state files mean git stopped half-way.
+const INTERRUPTED_OPERATIONS: [(&str, &str); 5] = [
+    ("rebase-merge", "rebase"),
+    ("rebase-apply", "rebase"),
+    ("MERGE_HEAD", "merge"),
+    ("CHERRY_PICK_HEAD", "cherry-pick"),
+    ("REVERT_HEAD", "revert"),
+];
+
+/// One problem found in a worktree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum IntegrityIssue {
+    /// `index.lock` left behind by a git process that is gone.
+    StaleIndexLock,
+    /// The index cannot be read.
+    CorruptIndex { message: String },
+    /// A rebase, merge, cherry-pick or revert was left in progress.
+    InterruptedOperation { operation: String },
+    /// `git fsck` reported missing or broken objects.
+    ObjectErrors { lines: Vec<String> },
+}
+
+impl IntegrityIssue {
+    /// One-line description for the branch list.
+    pub fn summary(&self) -> String {
+        match self {
+            Self::StaleIndexLock => "stale index.lock".to_string(),
+            Self::CorruptIndex { .. } => "corrupt index".to_string(),
+            Self::InterruptedOperation { operation } => format!("interrupted {operation}"),
+            Self::ObjectErrors { lines } => format!("{} object error(s)", lines.len()),
+        }
+    }
+}
+
+/// Result of one integrity check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityReport {
+    pub checked_at: DateTime<Utc>,
+    pub issues: Vec<IntegrityIssue>,
+}
+
+impl IntegrityReport {
+    pub fn is_healthy(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Check `worktree` for the leftovers of an interrupted git command. Only
+/// call this when no git process is running in the worktree, e.g. after the
+/// agent session there has ended: a live `index.lock` would be reported as
+/// stale.
+pub fn check_worktree_integrity(worktree: &Path) -> Result<IntegrityReport> {
+    let git_dir = worktree_git_dir(worktree)?;
+    let mut issues = Vec::new();
+    if git_dir.join("index.lock").exists() {
+        issues.push(IntegrityIssue::StaleIndexLock);
+    }
+    let index = git_output(worktree, &["ls-files", "--stage", "-z"])?;
+    if !index.status.success() {
+        issues.push(IntegrityIssue::CorruptIndex {
+            message: first_line(&index.stderr),
+        });
+    }
+    for (marker, operation) in INTERRUPTED_OPERATIONS {
+        let already_reported = issues.iter().any(|issue| {
+            matches!(issue, IntegrityIssue::InterruptedOperation { operation: reported } if reported == operation)
+        });
+        if !already_reported && git_dir.join(marker).exists() {
+            issues.push(IntegrityIssue::InterruptedOperation {
+                operation: operation.to_string(),
+            });
+        }
+    }
+    let fsck = git_output(
+        worktree,
+        &[
+            "fsck",
+            "--no-dangling",
+            "--no-progress",
+            "--no-reflogs",
+            "--connectivity-only",
+            "HEAD",
+        ],
+    )?;
+    let lines = fsck_problem_lines(&format!("{}{}", fsck.stdout, fsck.stderr));
+    if !lines.is_empty() {
+        issues.push(IntegrityIssue::ObjectErrors { lines });
+    } else if !fsck.status.success() {
+        issues.push(IntegrityIssue::ObjectErrors {
+            lines: vec![first_line(&fsck.stderr)],
+        });
+    }
+    Ok(IntegrityReport {
+        checked_at: Utc::now(),
+        issues,
+    })
+}
+
+/// Store `report` in the worktree's git directory. A healthy report removes
+/// the file instead, so only worktrees with problems carry one.
+pub fn save_report(worktree: &Path, report: &IntegrityReport) -> Result<()> {
+    let path = worktree_git_dir(worktree)?.join(INTEGRITY_REPORT_FILE);
+    if report.is_healthy() {
+        return match fs::remove_file(&path) {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(error.into()),
+            _ => Ok(()),
+        };
+    }
+    let json = serde_json::to_string_pretty(report)
+        .map_err(|error| GwtError::Other(format!("integrity report: {error}")))?;
+    fs::write(path, json)?;
+    Ok(())
+}
+
+/// The last saved report of `worktree`, if it had problems. Reads the
+/// `.git` entry directly instead of running git, since the branch list calls
+/// this for every worktree.
+pub fn load_report(worktree: &Path) -> Option<IntegrityReport> {
+    let dot_git = worktree.join(".git");
+    let git_dir = if dot_git.is_dir() {
+        dot_git
+    } else {
+        let pointer = fs::read_to_string(&dot_git).ok()?;
+        let target = Path::new(pointer.strip_prefix("gitdir:")?.trim());
+        worktree.join(target)
+    };
+    serde_json::from_str(&fs::read_to_string(git_dir.join(INTEGRITY_REPORT_FILE)).ok()?).ok()
+}
+
+/// Fix what can be fixed without losing work: remove a stale `index.lock`
+/// and rebuild a corrupt index from `HEAD` (the working tree is left alone,
+/// so only the staged/unstaged split is lost). Interrupted operations and
+/// object errors need a decision and are only described. Re-checks and
+/// saves the report afterwards; returns one line per issue.
+pub fn repair_worktree_integrity(worktree: &Path) -> Result<(Vec<String>, IntegrityReport)> {
+    let git_dir = worktree_git_dir(worktree)?;
+    let report = check_worktree_integrity(worktree)?;
+    let mut notes = Vec::new();
+    for issue in &report.issues {
+        match issue {
+            IntegrityIssue::StaleIndexLock => {
+                fs::remove_file(git_dir.join("index.lock"))?;
+                notes.push("removed stale index.lock".to_string());
+            }
+            IntegrityIssue::CorruptIndex { .. } => {
+                let index = git_dir.join("index");
+                if index.exists() {
+                    fs::rename(&index, git_dir.join("index.corrupt"))?;
+                }
+                let read_tree = git_output(worktree, &["read-tree", "HEAD"])?;
+                if !read_tree.status.success() {
+                    return Err(GwtError::Git(format!(
+                        "read-tree: {}",
+                        first_line(&read_tree.stderr)
+                    )));
+                }
+                notes.push(
+                    "rebuilt the index from HEAD (old index kept as index.corrupt)".to_string(),
+                );
+            }
+            IntegrityIssue::InterruptedOperation { operation } => notes.push(format!(
+                "{operation} in progress: run `git {operation} --continue` or `git {operation} --abort`"
+            )),
+            IntegrityIssue::ObjectErrors { .. } => notes.push(
+                "object errors need manual repair: try `git fetch`, then `git fsck`".to_string(),
+            ),
+        }
+    }
+    let after = check_worktree_integrity(worktree)?;
+    save_report(worktree, &after)?;
+    Ok((notes, after))
+}
+
+/// Problem lines of `git fsck` output.
+fn fsck_problem_lines(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| {
+            ["error", "missing", "broken", "bad ", "fatal"]
+                .iter()
+                .any(|marker| line.starts_with(marker))
+        })
+        .take(MAX_FSCK_LINES)
+        .map(str::to_string)
+        .collect()
+}
+
+fn worktree_git_dir(worktree: &Path) -> Result<PathBuf> {
+    let output = git_output(worktree, &["rev-parse", "--absolute-git-dir"])?;
+    if !output.status.success() {
+        return Err(GwtError::Git(format!(
+            "rev-parse: {}",
+            first_line(&output.stderr)
+        )));
+    }
+    Ok(PathBuf::from(output.stdout.trim()))
+}
+
+struct GitOutput {
+    status: std::process::ExitStatus,
+    stdout: String,
+    stderr: String,
+}
+
+fn git_output(dir: &Path, args: &[&str]) -> Result<GitOutput> {
+    let output = gwt_core::process::run_git_logged(args, Some(dir))
+        .map_err(|e| GwtError::Git(format!("{}: {e}", args[0])))?;
+    Ok(GitOutput {
+        status: output.status,
+        stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+    })
+}
+
+fn first_line(text: &str) -> String {
+    text.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or("failed")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::run_git_logged(args, Some(dir)).unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn repo() -> tempfile::TempDir {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path();
+        git(repo, &["init", "--initial-branch=main"]);
+        git(repo, &["config", "user.email", "t@example.com"]);
+        git(repo, &["config", "user.name", "T"]);
+        fs::write(repo.join("a.txt"), "a\n").unwrap();
+        git(repo, &["add", "."]);
+        git(repo, &["commit", "-m", "init"]);
+        tmp
+    }
+
+    #[test]
+    fn healthy_worktree_has_no_issues_and_no_report() {
+        let tmp = repo();
+        let report = check_worktree_integrity(tmp.path()).unwrap();
+        assert!(report.is_healthy(), "{:?}", report.issues);
+        save_report(tmp.path(), &report).unwrap();
+        assert_eq!(load_report(tmp.path()), None);
+    }
+
+    #[test]
+    fn stale_lock_and_corrupt_index_are_reported_and_repaired() {
+        let tmp = repo();
+        let git_dir = tmp.path().join(".git");
+        fs::write(git_dir.join("index.lock"), "").unwrap();
+        fs::write(git_dir.join("index"), "garbage").unwrap();
+        fs::write(git_dir.join("MERGE_HEAD"), "0000\n").unwrap();
+
+        let report = check_worktree_integrity(tmp.path()).unwrap();
+        let summaries: Vec<String> = report.issues.iter().map(IntegrityIssue::summary).collect();
+        assert_eq!(
+            summaries,
+            ["stale index.lock", "corrupt index", "interrupted merge"]
+        );
+        save_report(tmp.path(), &report).unwrap();
+        assert_eq!(load_report(tmp.path()), Some(report));
+
+        let (notes, after) = repair_worktree_integrity(tmp.path()).unwrap();
+        assert_eq!(notes.len(), 3);
+        assert!(notes[2].contains("git merge --abort"));
+        assert_eq!(
+            after.issues,
+            [IntegrityIssue::InterruptedOperation {
+                operation: "merge".to_string()
+            }]
+        );
+        assert!(!git_dir.join("index.lock").exists());
+        assert!(git_dir.join("index.corrupt").exists());
+        assert_eq!(load_report(tmp.path()), Some(after));
+    }
+
+    #[test]
+    fn fsck_problem_lines_keep_only_errors() {
+        let lines = fsck_problem_lines(
+            "Checking connectivity\nmissing blob 1234\nbroken link from tree 5678\nnotice: x\n",
+        );
+        assert_eq!(lines, ["missing blob 1234", "broken link from tree 5678"]);
+    }
+}
@@ -0,0 +1,376 @@
+//! Run one command across several worktrees and collect a pass/fail matrix.
+//!
+//! Used to tell whether a flaky test failure is branch-specific: the same
+//! test command runs in every selected worktree with bounded concurrency,
+//! and each run is captured as a [`MatrixCell`] (exit status, duration, and
+//! an output tail) instead of streaming to the terminal.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::Instant,
+};
+
+use gwt_core::process_executor::{ExecutionMode, ProcessExecutor, ProcessRequest};
+use serde::{Deserialize, Serialize};
+
+use crate::worktree::WorktreeInfo;
+
+/// Default number of worktrees tested at the same time.
+pub const DEFAULT_MATRIX_CONCURRENCY: usize = 2;
+
+/// Number of trailing output lines kept per cell.
+const OUTPUT_TAIL_LINES: usize = 20;
+
+/// One worktree selected for a matrix run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatrixTarget {
+    pub branch: String,
+    /// `None` when the branch has no worktree; the cell reports an error.
+    pub path: Option<PathBuf>,
+}
+
+/// Outcome of the command in one worktree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MatrixOutcome {
+    Passed,
+    Failed {
+        code: Option<i32>,
+    },
+    /// The command could not run (missing worktree, spawn failure).
+    Error {
+        message: String,
+    },
+}
+
+impl MatrixOutcome {
+    /// Short label used in the rendered matrix.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Passed => "PASS",
+            Self::Failed { .. } => "FAIL",
+            Self::Error { .. } => "ERROR",
+        }
+    }
+}
+
+/// Structured result for one worktree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatrixCell {
+    pub branch: String,
+    pub path: Option<PathBuf>,
+    pub outcome: MatrixOutcome,
+    pub duration_ms: u128,
+    /// Last lines of combined stdout/stderr.
+    pub output_tail: Vec<String>,
+}
+
+/// Full matrix in target order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatrixReport {
+    pub command: Vec<String>,
+    pub cells: Vec<MatrixCell>,
+}
+
+impl MatrixReport {
+    pub fn passed(&self) -> usize {
+        self.cells
+            .iter()
+            .filter(|cell| cell.outcome == MatrixOutcome::Passed)
+            .count()
+    }
+
+    /// Whether every selected worktree passed.
+    pub fn all_passed(&self) -> bool {
+        !self.cells.is_empty() && self.passed() == self.cells.len()
+    }
+}
+
+/// Pair the requested branches with their worktrees. An empty `branches`
+/// list selects every worktree that has a branch checked out.
+pub fn resolve_matrix_targets(
+    worktrees: &[WorktreeInfo],
+    branches: &[String],
+) -> Vec<MatrixTarget> {
+    if branches.is_empty() {
+        return worktrees
+            .iter()
+            .filter(|wt| !wt.prunable)
+            .filter_map(|wt| {
+                wt.branch.as_ref().map(|branch| MatrixTarget {
+                    branch: branch.clone(),
+                    path: Some(wt.path.clone()),
+                })
+            })
+            .collect();
+    }
+    branches
+        .iter()
+        .map(|branch| MatrixTarget {
+            branch: branch.clone(),
+            path: worktrees
+                .iter()
+                .find(|wt| wt.branch.as_deref() == Some(branch.as_str()))
+                .map(|wt| wt.path.clone()),
+        })
+        .collect()
+}
+
+/// Guess the repository's test command from marker files in `worktree`.
+pub fn detect_test_command(worktree: &Path) -> Option<Vec<String>> {
+    let command: &[&str] = if worktree.join("Cargo.toml").is_file() {
+        &["cargo", "test"]
+    } else if worktree.join("package.json").is_file() {
+        &["npm", "test"]
+    } else if worktree.join("go.mod").is_file() {
+        &["go", "test", "./..."]
+    } else if worktree.join("pyproject.toml").is_file() || worktree.join("pytest.ini").is_file() {
+        &["pytest"]
+    } else if worktree.join("Makefile").is_file() {
+        &["make", "test"]
+    } else {
+        return None;
+    };
+    Some(command.iter().map(|part| part.to_string()).collect())
+}
+
+/// Run `command` in every target with at most `concurrency` runs in flight.
+/// Cells are returned in target order regardless of completion order.
+pub fn run_matrix(
+    executor: &dyn ProcessExecutor,
+    targets: &[MatrixTarget],
+    command: &[String],
+    concurrency: usize,
+) -> MatrixReport {
+    let next = AtomicUsize::new(0);
+    let cells: Mutex<Vec<Option<MatrixCell>>> = Mutex::new(vec![None; targets.len()]);
+    let workers = concurrency.clamp(1, targets.len().max(1));
+
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::SeqCst);
+                let Some(target) = targets.get(index) else {
+                    break;
+                };
+                let cell = run_cell(executor, target, command);
+                cells
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner)[index] = Some(cell);
+            });
+        }
+    });
+
+    MatrixReport {
+        command: command.to_vec(),
+        cells: cells
+            .into_inner()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .into_iter()
+            .flatten()
+            .collect(),
+    }
+}
+
+fn run_cell(
+    executor: &dyn ProcessExecutor,
+    target: &MatrixTarget,
+    command: &[String],
+) -> MatrixCell {
+    let started = Instant::now();
+    let cell = |outcome, output_tail| MatrixCell {
+        branch: target.branch.clone(),
+        path: target.path.clone(),
+        outcome,
+        duration_ms: started.elapsed().as_millis(),
+        output_tail,
+    };
+    let Some(path) = target.path.as_ref() else {
+        return cell(
+            MatrixOutcome::Error {
+                message: format!("no worktree for branch {}", target.branch),
+            },
+            Vec::new(),
+        );
+    };
+    let Some((program, args)) = command.split_first() else {
+        return cell(
+            MatrixOutcome::Error {
+                message: "empty test command".to_string(),
+            },
+            Vec::new(),
+        );
+    };
+
+    let request = ProcessRequest::new(program)
+        .args(args)
+        .cwd(path)
+        .mode(ExecutionMode::Capture)
+        .hide_window(true);
+    match executor.run(request) {
+        Ok(output) => {
+            let combined = format!("{}{}", output.stdout, output.stderr);
+            let outcome = if output.success {
+                MatrixOutcome::Passed
+            } else {
+                MatrixOutcome::Failed { code: output.code }
+            };
+            cell(outcome, output_tail(&combined))
+        }
+        Err(message) => cell(MatrixOutcome::Error { message }, Vec::new()),
+    }
+}
+
+fn output_tail(output: &str) -> Vec<String> {
+    let lines: Vec<&str> = output.lines().collect();
+    lines[lines.len().saturating_sub(OUTPUT_TAIL_LINES)..]
+        .iter()
+        .map(|line| line.to_string())
+        .collect()
+}
+
+/// Render the report as an aligned `branch | result | duration` table.
+pub fn render_matrix(report: &MatrixReport) -> String {
+    let width = report
+        .cells
+        .iter()
+        .map(|cell| cell.branch.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("branch".len());
+    let mut out = format!(
+        "# test matrix: {} ({}/{} passed)\n",
+        report.command.join(" "),
+        report.passed(),
+        report.cells.len()
+    );
+    out.push_str(&format!("{:<width$} | result | duration\n", "branch"));
+    for cell in &report.cells {
+        let detail = match &cell.outcome {
+            MatrixOutcome::Failed { code: Some(code) } => format!(" (exit {code})"),
+            MatrixOutcome::Error { message } => format!(" ({message})"),
+            _ => String::new(),
+        };
+        out.push_str(&format!(
+            "{:<width$} | {:<6} | {:.1}s{detail}\n",
+            cell.branch,
+            cell.outcome.label(),
+            cell.duration_ms as f64 / 1000.0,
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use gwt_core::process_executor::{MockProcessExecutor, ProcessOutput};
+
+    use super::*;
+
+    fn worktree(path: &str, branch: Option<&str>) -> WorktreeInfo {
+        WorktreeInfo {
+            path: PathBuf::from(path),
+            branch: branch.map(str::to_string),
+            locked: false,
+            prunable: false,
+        }
+    }
+
+    #[test]
+    fn resolve_targets_defaults_to_every_branch_worktree() {
+        let worktrees = vec![
+            worktree("/repo/main", Some("main")),
+            worktree("/repo/detached", None),
+            worktree("/repo/feature", Some("feature/x")),
+        ];
+        let targets = resolve_matrix_targets(&worktrees, &[]);
+        assert_eq!(
+            targets
+                .iter()
+                .map(|t| t.branch.as_str())
+                .collect::<Vec<_>>(),
+            vec!["main", "feature/x"]
+        );
+
+        let targets = resolve_matrix_targets(&worktrees, &["feature/x".into(), "gone".into()]);
+        assert_eq!(targets[0].path, Some(PathBuf::from("/repo/feature")));
+        assert_eq!(targets[1].path, None);
+    }
+
+    #[test]
+    fn run_matrix_keeps_target_order_and_classifies_outcomes() {
+        let executor = MockProcessExecutor::new();
+        executor.push_response(ProcessOutput::succeeded().with_stdout("ok\n"));
+        executor.push_response(ProcessOutput::succeeded().with_stdout("ok\n"));
+        let targets = vec![
+            MatrixTarget {
+                branch: "main".into(),
+                path: Some("/repo/main".into()),
+            },
+            MatrixTarget {
+                branch: "gone".into(),
+                path: None,
+            },
+            MatrixTarget {
+                branch: "feature".into(),
+                path: Some("/repo/feature".into()),
+            },
+        ];
+        let command = vec!["cargo".to_string(), "test".to_string()];
+
+        let report = run_matrix(&executor, &targets, &command, 4);
+
+        assert_eq!(report.cells.len(), 3);
+        assert_eq!(report.cells[0].branch, "main");
+        assert_eq!(report.cells[0].outcome, MatrixOutcome::Passed);
+        assert!(matches!(
+            report.cells[1].outcome,
+            MatrixOutcome::Error { .. }
+        ));
+        assert_eq!(report.cells[2].outcome, MatrixOutcome::Passed);
+        assert_eq!(report.passed(), 2);
+        assert!(!report.all_passed());
+        let requests = executor.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests.iter().all(|r| r.mode == ExecutionMode::Capture));
+    }
+
+    #[test]
+    fn failed_runs_keep_exit_code_and_output_tail() {
+        let executor = MockProcessExecutor::new();
+        let noisy: String = (0..30).map(|i| format!("line {i}\n")).collect();
+        executor.push_response(ProcessOutput::failed(101).with_stderr(noisy));
+        let targets = vec![MatrixTarget {
+            branch: "flaky".into(),
+            path: Some("/repo/flaky".into()),
+        }];
+
+        let report = run_matrix(&executor, &targets, &["cargo".into(), "test".into()], 1);
+
+        let cell = &report.cells[0];
+        assert_eq!(cell.outcome, MatrixOutcome::Failed { code: Some(101) });
+        assert_eq!(cell.output_tail.len(), OUTPUT_TAIL_LINES);
+        assert_eq!(cell.output_tail.last().map(String::as_str), Some("line 29"));
+        assert!(render_matrix(&report).contains("FAIL   | 0.0s (exit 101)"));
+    }
+
+    #[test]
+    fn detect_test_command_prefers_cargo() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert_eq!(detect_test_command(tmp.path()), None);
+        std::fs::write(tmp.path().join("package.json"), "{}").unwrap();
+        assert_eq!(
+            detect_test_command(tmp.path()),
+            Some(vec!["npm".to_string(), "test".to_string()])
+        );
+        std::fs::write(tmp.path().join("Cargo.toml"), "").unwrap();
+        assert_eq!(
+            detect_test_command(tmp.path()),
+            Some(vec!["cargo".to_string(), "test".to_string()])
+        );
+    }
+}
@@ -0,0 +1,296 @@
+//! Read-only review worktrees.
+//!
+//! A review worktree checks a ref out with a detached HEAD (so the branch
+//! under review may stay checked out elsewhere) and then strips the write
+//! permission from every file and directory in it, so a review agent can
+//! read the code and comment on it but cannot change it. The index at `~/.gwt/projects/<repo-hash>/review-worktrees.json`
+//! records which worktrees are review worktrees; agent launches consult it
+//! to also ask the agent's own sandbox to deny writes.
+//!
+//! Nothing in a review worktree is the user's work, so removing one skips
+//! the dirty / local-commit safety checks other cleanups apply: write
+//! permission is restored and the worktree is force-removed.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use gwt_core::{GwtError, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::worktree::WorktreeManager;
+
+/// Index file listing the review worktrees of a repository.
+pub const REVIEW_INDEX_FILE: &str = "review-worktrees.json";
+
+/// One review worktree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewWorktree {
+    /// Ref the worktree was created from, as given (branch, tag, commit).
+    pub reference: String,
+    /// Commit checked out.
+    pub head: String,
+    pub worktree_path: PathBuf,
+    /// RFC 3339 timestamp.
+    pub created_at: String,
+}
+
+/// Default state directory for review worktrees
+/// (`~/.gwt/projects/<repo-hash>`). Resolved from the main worktree so every
+/// worktree of the repository shares it.
+pub fn default_review_dir(repo_path: &Path) -> PathBuf {
+    let root =
+        crate::worktree::main_worktree_root(repo_path).unwrap_or_else(|_| repo_path.to_path_buf());
+    gwt_core::paths::gwt_project_dir_for_repo_path(&root)
+}
+
+/// Default location of the review worktree for `reference`: the sibling
+/// worktree path of `review/<reference>`.
+pub fn review_worktree_path(repo_path: &Path, reference: &str) -> PathBuf {
+    let root =
+        crate::worktree::main_worktree_root(repo_path).unwrap_or_else(|_| repo_path.to_path_buf());
+    crate::worktree::sibling_worktree_path(&root, &format!("review/{reference}"))
+}
+
+/// Review worktrees recorded in `state_dir`, oldest first.
+pub fn list_review_worktrees(state_dir: &Path) -> Result<Vec<ReviewWorktree>> {
+    let path = state_dir.join(REVIEW_INDEX_FILE);
+    match fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|e| GwtError::Other(format!("{}: {e}", path.display()))),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn write_review_worktrees(state_dir: &Path, reviews: &[ReviewWorktree]) -> Result<()> {
+    fs::create_dir_all(state_dir)?;
+    let content = serde_json::to_string_pretty(reviews)
+        .map_err(|e| GwtError::Other(format!("serialize review worktrees: {e}")))?;
+    fs::write(state_dir.join(REVIEW_INDEX_FILE), content)?;
+    Ok(())
+}
+
+/// Whether `path` (or a directory inside it) is a recorded review worktree
+/// of its repository.
+pub fn is_review_worktree(path: &Path) -> bool {
+    let Ok(reviews) = list_review_worktrees(&default_review_dir(path)) else {
+        return false;
+    };
+    let path = canonical(path);
+    reviews
+        .iter()
+        .any(|review| path.starts_with(canonical(&review.worktree_path)))
+}
+
+/// Check `reference` out read-only at `worktree_path` and record it.
+pub fn create_review_worktree(
+    repo_path: &Path,
+    state_dir: &Path,
+    reference: &str,
+    worktree_path: &Path,
+) -> Result<ReviewWorktree> {
+    let mut reviews = list_review_worktrees(state_dir)?;
+    if reviews
+        .iter()
+        .any(|review| review.worktree_path == worktree_path)
+    {
+        return Err(GwtError::Git(format!(
+            "{} is already a review worktree",
+            worktree_path.display()
+        )));
+    }
+    let head = git_stdout(
+        repo_path,
+        &["rev-parse", "--verify", &format!("{reference}^{{commit}}")],
+    )?
+    .trim()
+    .to_string();
+
+    let manager = WorktreeManager::new(repo_path);
+    manager.create_detached(&head, worktree_path)?;
+    if let Err(error) = set_tree_read_only(worktree_path, true) {
+        let _ = set_tree_read_only(worktree_path, false);
+        let _ = manager.remove_force(worktree_path);
+        return Err(error);
+    }
+
+    let review = ReviewWorktree {
+        reference: reference.to_string(),
+        head,
+        worktree_path: worktree_path.to_path_buf(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+    };
+    reviews.push(review.clone());
+    write_review_worktrees(state_dir, &reviews)?;
+    Ok(review)
+}
+
+/// Remove the review worktree created from `target` (a ref as given at
+/// creation, or the worktree path) without any safety checks.
+pub fn remove_review_worktree(
+    repo_path: &Path,
+    state_dir: &Path,
+    target: &str,
+) -> Result<ReviewWorktree> {
+    let mut reviews = list_review_worktrees(state_dir)?;
+    let target_path = canonical(Path::new(target));
+    let position = reviews
+        .iter()
+        .position(|review| {
+            review.reference == target || canonical(&review.worktree_path) == target_path
+        })
+        .ok_or_else(|| GwtError::Git(format!("no review worktree for {target}")))?;
+    let review = reviews.remove(position);
+
+    if review.worktree_path.exists() {
+        set_tree_read_only(&review.worktree_path, false)?;
+        let manager = WorktreeManager::new(repo_path);
+        manager.remove_force(&review.worktree_path)?;
+    } else {
+        WorktreeManager::new(repo_path).prune()?;
+    }
+    write_review_worktrees(state_dir, &reviews)?;
+    Ok(review)
+}
+
+/// Add or strip the write permission of every file and directory under
+/// `root`. The top-level `.git` file (the link to the worktree's admin
+/// directory) is left alone so git keeps working. Symlinks are not
+/// followed.
+pub fn set_tree_read_only(root: &Path, read_only: bool) -> Result<()> {
+    // Directories are made writable before and read-only after their
+    // entries, so the walk never locks itself out.
+    if !read_only {
+        set_read_only(root, false)?;
+    }
+    for entry in fs::read_dir(root)? {
+        let entry = entry?;
+        if entry.file_name() == ".git" {
+            continue;
+        }
+        set_entry_read_only(&entry.path(), read_only)?;
+    }
+    if read_only {
+        set_read_only(root, true)?;
+    }
+    Ok(())
+}
+
+fn set_entry_read_only(path: &Path, read_only: bool) -> Result<()> {
+    let file_type = fs::symlink_metadata(path)?.file_type();
+    if file_type.is_symlink() {
+        return Ok(());
+    }
+    if file_type.is_dir() {
+        if !read_only {
+            set_read_only(path, false)?;
+        }
+        for entry in fs::read_dir(path)? {
+            set_entry_read_only(&entry?.path(), read_only)?;
+        }
+        if read_only {
+            set_read_only(path, true)?;
+        }
+        return Ok(());
+    }
+    set_read_only(path, read_only)
+}
+
+fn set_read_only(path: &Path, read_only: bool) -> Result<()> {
+    let mut permissions = fs::metadata(path)?.permissions();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = permissions.mode();
+        // Restoring only gives the owner write access back, which is what
+        // `git worktree add` created the files with under a normal umask.
+        permissions.set_mode(if read_only {
+            mode & !0o222
+        } else {
+            mode | 0o200
+        });
+    }
+    #[cfg(not(unix))]
+    {
+        #[allow(clippy::permissions_set_readonly_false)]
+        permissions.set_readonly(read_only);
+    }
+    fs::set_permissions(path, permissions)?;
+    Ok(())
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn git_stdout(dir: &Path, args: &[&str]) -> Result<String> {
+    let label = args[..2.min(args.len())].join(" ");
+    let output = gwt_core::process::run_git_logged(args, Some(dir))
+        .map_err(|e| GwtError::Git(format!("{label}: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(GwtError::Git(format!("{label}: {}", stderr.trim())));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::run_git_logged(args, Some(dir)).unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn writable(path: &Path) -> bool {
+        !fs::metadata(path).unwrap().permissions().readonly()
+    }
+
+    #[test]
+    fn review_worktree_is_read_only_and_removed_without_checks() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path().join("repo");
+        fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--initial-branch=main"]);
+        git(&repo, &["config", "user.email", "t@example.com"]);
+        git(&repo, &["config", "user.name", "T"]);
+        fs::create_dir_all(repo.join("src")).unwrap();
+        fs::write(repo.join("src/lib.rs"), "fn main() {}\n").unwrap();
+        git(&repo, &["add", "."]);
+        git(&repo, &["commit", "-m", "init"]);
+        let state = tmp.path().join("state");
+        let path = tmp.path().join("review");
+
+        assert!(create_review_worktree(&repo, &state, "no-such-ref", &path).is_err());
+        let review = create_review_worktree(&repo, &state, "main", &path).unwrap();
+        assert_eq!(review.worktree_path, path);
+        assert!(!writable(&path));
+        assert!(!writable(&path.join("src")));
+        assert!(!writable(&path.join("src/lib.rs")));
+        assert!(writable(&path.join(".git")));
+        // The branch stays checked out in the main worktree.
+        assert_eq!(
+            git_stdout(&path, &["rev-parse", "HEAD"]).unwrap().trim(),
+            review.head
+        );
+        assert_eq!(list_review_worktrees(&state).unwrap(), vec![review.clone()]);
+        assert!(create_review_worktree(&repo, &state, "main", &path).is_err());
+
+        // Leftovers the review produced despite the lock do not block removal.
+        set_read_only(&path.join("src"), false).unwrap();
+        fs::write(path.join("src/scratch.txt"), "note\n").unwrap();
+        set_read_only(&path.join("src"), true).unwrap();
+        let removed = remove_review_worktree(&repo, &state, "main").unwrap();
+        assert_eq!(removed, review);
+        assert!(!path.exists());
+        assert!(list_review_worktrees(&state).unwrap().is_empty());
+        assert!(remove_review_worktree(&repo, &state, "main").is_err());
+    }
+}
@@ -0,0 +1,227 @@
+//! Start a new branch from another branch's uncommitted state.
+//!
+//! When an agent went down a wrong path but parts of its work are worth
+//! keeping, the salvageable state can be carried into a fresh branch
+//! instead of resetting the original. The new branch starts at the source
+//! branch's HEAD in its own worktree; the source's staged changes are
+//! applied to the new index, its unstaged changes to the new working tree,
+//! and its untracked files are copied over. The source worktree is only
+//! read, never modified.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use gwt_core::{GwtError, Result};
+use serde::Serialize;
+
+use crate::worktree::WorktreeManager;
+
+/// What [`transplant_uncommitted`] carried into the new worktree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TransplantOutcome {
+    pub branch: String,
+    pub source_branch: String,
+    pub worktree_path: PathBuf,
+    /// Commit the new branch starts at (the source branch's HEAD).
+    pub base_commit: String,
+    /// Files with staged changes, applied to the new index.
+    pub staged_files: Vec<String>,
+    /// Files with unstaged changes, applied to the new working tree.
+    pub unstaged_files: Vec<String>,
+    /// Untracked files copied from the source worktree.
+    pub untracked_files: Vec<String>,
+}
+
+impl TransplantOutcome {
+    /// One-line summary, e.g. `Started try/b from work/a's uncommitted
+    /// state (2 staged, 1 unstaged, 1 untracked) at ../try/b`.
+    pub fn summary(&self) -> String {
+        format!(
+            "Started {} from {}'s uncommitted state ({} staged, {} unstaged, {} untracked) at {}",
+            self.branch,
+            self.source_branch,
+            self.staged_files.len(),
+            self.unstaged_files.len(),
+            self.untracked_files.len(),
+            self.worktree_path.display()
+        )
+    }
+}
+
+/// Create `new_branch` at `path` from the HEAD of the worktree that has
+/// `source_branch` checked out, and transplant that worktree's uncommitted
+/// changes into it. When anything fails after the worktree was created, the
+/// worktree and the new branch are removed again.
+pub fn transplant_uncommitted(
+    repo_path: &Path,
+    source_branch: &str,
+    new_branch: &str,
+    path: &Path,
+) -> Result<TransplantOutcome> {
+    let manager = WorktreeManager::new(repo_path);
+    let source = manager
+        .list()?
+        .into_iter()
+        .find(|worktree| worktree.branch.as_deref() == Some(source_branch))
+        .ok_or_else(|| GwtError::Git(format!("no worktree has {source_branch} checked out")))?
+        .path;
+
+    let base_commit = git_stdout(&source, &["rev-parse", "HEAD"])?
+        .trim()
+        .to_string();
+    // Patches stay bytes: file contents need not be UTF-8.
+    let staged_patch = git_output(&source, &["diff", "--cached", "--binary"])?;
+    let unstaged_patch = git_output(&source, &["diff", "--binary"])?;
+    let staged_files = name_only(&source, &["diff", "--cached"])?;
+    let unstaged_files = name_only(&source, &["diff"])?;
+    let untracked_files = name_only(&source, &["ls-files", "--others", "--exclude-standard"])?;
+    if staged_files.is_empty() && unstaged_files.is_empty() && untracked_files.is_empty() {
+        return Err(GwtError::Git(format!(
+            "{source_branch} has no uncommitted changes"
+        )));
+    }
+
+    manager.create_from_base(&base_commit, new_branch, path)?;
+    let applied = apply_patch(path, &["apply", "--index", "--binary"], &staged_patch)
+        .and_then(|()| apply_patch(path, &["apply", "--binary"], &unstaged_patch))
+        .and_then(|()| copy_files(&source, path, &untracked_files));
+    if let Err(error) = applied {
+        let _ = manager.remove_force(path);
+        let _ = gwt_core::process::run_git_logged(&["branch", "-D", new_branch], Some(repo_path));
+        return Err(error);
+    }
+
+    Ok(TransplantOutcome {
+        branch: new_branch.to_string(),
+        source_branch: source_branch.to_string(),
+        worktree_path: path.to_path_buf(),
+        base_commit,
+        staged_files,
+        unstaged_files,
+        untracked_files,
+    })
+}
+
+fn apply_patch(worktree: &Path, args: &[&str], patch: &[u8]) -> Result<()> {
+    if patch.is_empty() {
+        return Ok(());
+    }
+    let output = gwt_core::process::run_git_logged_with_stdin(args, Some(worktree), patch)
+        .map_err(|e| GwtError::Git(format!("git apply: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(GwtError::Git(format!("git apply: {}", stderr.trim())));
+    }
+    Ok(())
+}
+
+fn copy_files(from: &Path, to: &Path, files: &[String]) -> Result<()> {
+    for file in files {
+        let source = from.join(file);
+        // Nested repositories are listed as directories; leave them alone.
+        if fs::symlink_metadata(&source)?.is_dir() {
+            continue;
+        }
+        let target = to.join(file);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(&source, &target)?;
+    }
+    Ok(())
+}
+
+fn name_only(worktree: &Path, args: &[&str]) -> Result<Vec<String>> {
+    let mut full = args.to_vec();
+    full.push("-z");
+    if full[0] == "diff" {
+        full.push("--name-only");
+    }
+    Ok(git_stdout(worktree, &full)?
+        .split('\0')
+        .filter(|file| !file.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+fn git_stdout(dir: &Path, args: &[&str]) -> Result<String> {
+    Ok(String::from_utf8_lossy(&git_output(dir, args)?).into_owned())
+}
+
+fn git_output(dir: &Path, args: &[&str]) -> Result<Vec<u8>> {
+    let label = args[..2.min(args.len())].join(" ");
+    let output = gwt_core::process::run_git_logged(args, Some(dir))
+        .map_err(|e| GwtError::Git(format!("{label}: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(GwtError::Git(format!("{label}: {}", stderr.trim())));
+    }
+    Ok(output.stdout)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::run_git_logged(args, Some(dir)).unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn transplant_copies_uncommitted_state_and_leaves_source_alone() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path().join("repo");
+        fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--initial-branch=main"]);
+        git(&repo, &["config", "user.email", "t@example.com"]);
+        git(&repo, &["config", "user.name", "T"]);
+        fs::write(repo.join("a.txt"), "a\n").unwrap();
+        fs::write(repo.join("b.txt"), "b\n").unwrap();
+        git(&repo, &["add", "."]);
+        git(&repo, &["commit", "-m", "init"]);
+        let source = tmp.path().join("work-a");
+        git(
+            &repo,
+            &["worktree", "add", "-b", "work/a", source.to_str().unwrap()],
+        );
+        fs::write(source.join("a.txt"), "a staged\n").unwrap();
+        git(&source, &["add", "a.txt"]);
+        fs::write(source.join("b.txt"), "b unstaged\n").unwrap();
+        fs::create_dir_all(source.join("notes")).unwrap();
+        fs::write(source.join("notes/idea.md"), "keep\n").unwrap();
+        let status_before = git_stdout(&source, &["status", "--porcelain"]).unwrap();
+
+        let target = tmp.path().join("try-b");
+        let outcome = transplant_uncommitted(&repo, "work/a", "try/b", &target).unwrap();
+
+        assert_eq!(outcome.staged_files, vec!["a.txt"]);
+        assert_eq!(outcome.unstaged_files, vec!["b.txt"]);
+        assert_eq!(outcome.untracked_files, vec!["notes/idea.md"]);
+        assert_eq!(
+            git_stdout(&target, &["status", "--porcelain"]).unwrap(),
+            status_before
+        );
+        assert_eq!(
+            fs::read_to_string(target.join("notes/idea.md")).unwrap(),
+            "keep\n"
+        );
+        assert_eq!(
+            git_stdout(&source, &["status", "--porcelain"]).unwrap(),
+            status_before
+        );
+        assert!(outcome.summary().starts_with(
+            "Started try/b from work/a's uncommitted state (1 staged, 1 unstaged, 1 untracked)"
+        ));
+
+        let err =
+            transplant_uncommitted(&repo, "main", "try/c", &tmp.path().join("try-c")).unwrap_err();
+        assert!(err.to_string().contains("no uncommitted changes"), "{err}");
+    }
+}
@@ -0,0 +1,322 @@
+//! Worktree trash and undo.
+//!
+//! A [`WorktreeManager`] configured with [`WorktreeManager::with_trash`]
+//! moves a removed worktree into the `.gwt-trash/` staging area next to the
+//! worktrees instead of deleting it, and records the branch and HEAD commit
+//! in the trash index. Restoring recreates the branch at that commit when it
+//! has been deleted since, registers a worktree at the original path again
+//! and moves the files back, so uncommitted changes return as unstaged
+//! changes. Entries older than the retention period are purged whenever
+//! another worktree is trashed.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use gwt_core::{GwtError, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::worktree::WorktreeManager;
+
+/// Staging directory name, created next to the worktrees.
+pub const TRASH_DIR_NAME: &str = ".gwt-trash";
+
+/// Index file listing the trashed worktrees in a trash directory.
+pub const TRASH_INDEX_FILE: &str = "index.json";
+
+/// One removed worktree waiting in the trash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashedWorktree {
+    pub branch: String,
+    /// Commit the branch pointed at when the worktree was removed.
+    pub head: String,
+    /// Where the worktree lived; restore recreates it here.
+    pub worktree_path: PathBuf,
+    /// Directory name inside the trash directory.
+    pub trash_name: String,
+    /// RFC 3339 timestamp.
+    pub removed_at: String,
+}
+
+/// Trash settings of a [`WorktreeManager`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreeTrash {
+    pub dir: PathBuf,
+    /// Days a trashed worktree is kept before it is purged.
+    pub retention_days: u32,
+}
+
+impl WorktreeTrash {
+    /// Trash in [`default_trash_dir`] of the repository at `repo_path`.
+    pub fn for_repo(repo_path: &Path, retention_days: u32) -> Self {
+        Self {
+            dir: default_trash_dir(repo_path),
+            retention_days,
+        }
+    }
+}
+
+/// Default trash directory for a repository: `.gwt-trash` in the directory
+/// that holds the main worktree and its sibling worktrees, so moving a
+/// worktree there stays on the same filesystem.
+pub fn default_trash_dir(repo_path: &Path) -> PathBuf {
+    let root =
+        crate::worktree::main_worktree_root(repo_path).unwrap_or_else(|_| repo_path.to_path_buf());
+    root.parent().unwrap_or(&root).join(TRASH_DIR_NAME)
+}
+
+/// Worktrees recorded in `trash_dir`, oldest first.
+pub fn list_trashed(trash_dir: &Path) -> Result<Vec<TrashedWorktree>> {
+    let path = trash_dir.join(TRASH_INDEX_FILE);
+    match fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content)
+            .map_err(|e| GwtError::Other(format!("{}: {e}", path.display()))),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn write_trashed(trash_dir: &Path, entries: &[TrashedWorktree]) -> Result<()> {
+    fs::create_dir_all(trash_dir)?;
+    let content = serde_json::to_string_pretty(entries)
+        .map_err(|e| GwtError::Other(format!("serialize worktree trash: {e}")))?;
+    fs::write(trash_dir.join(TRASH_INDEX_FILE), content)?;
+    Ok(())
+}
+
+/// Move the worktree at `worktree` (with `branch` checked out) into the
+/// trash and unregister it from the repository.
+pub fn trash_worktree(
+    repo_path: &Path,
+    trash: &WorktreeTrash,
+    worktree: &Path,
+    branch: &str,
+) -> Result<TrashedWorktree> {
+    purge_expired(&trash.dir, trash.retention_days, Utc::now())?;
+    let mut entries = list_trashed(&trash.dir)?;
+    let head = git_stdout(worktree, &["rev-parse", "HEAD"])?
+        .trim()
+        .to_string();
+    let now = Utc::now();
+    let trash_name = format!(
+        "{}-{}",
+        branch.replace(['/', '\\'], "-"),
+        now.format("%Y%m%d%H%M%S")
+    );
+    let target = trash.dir.join(&trash_name);
+    if target.exists() {
+        return Err(GwtError::Git(format!(
+            "{} already exists",
+            target.display()
+        )));
+    }
+    fs::create_dir_all(&trash.dir)?;
+    // Locked worktrees are never pruned; the lock goes with the worktree.
+    let worktree_arg = worktree.to_string_lossy();
+    let _ = git_stdout(repo_path, &["worktree", "unlock", worktree_arg.as_ref()]);
+    fs::rename(worktree, &target).map_err(|e| {
+        GwtError::Git(format!(
+            "move {} to {}: {e}",
+            worktree.display(),
+            trash.dir.display()
+        ))
+    })?;
+    WorktreeManager::new(repo_path).prune()?;
+
+    let entry = TrashedWorktree {
+        branch: branch.to_string(),
+        head,
+        worktree_path: worktree.to_path_buf(),
+        trash_name,
+        removed_at: now.to_rfc3339(),
+    };
+    entries.push(entry.clone());
+    write_trashed(&trash.dir, &entries)?;
+    Ok(entry)
+}
+
+/// Restore the most recently trashed worktree of `branch`, or the most
+/// recently trashed worktree of any branch when `branch` is `None`.
+pub fn restore_trashed(
+    repo_path: &Path,
+    trash_dir: &Path,
+    branch: Option<&str>,
+) -> Result<TrashedWorktree> {
+    let mut entries = list_trashed(trash_dir)?;
+    let position = entries
+        .iter()
+        .rposition(|entry| branch.is_none() || branch == Some(entry.branch.as_str()))
+        .ok_or_else(|| match branch {
+            Some(branch) => GwtError::Git(format!("no removed worktree of {branch} in the trash")),
+            None => GwtError::Git("the worktree trash is empty".to_string()),
+        })?;
+    let entry = entries[position].clone();
+    let source = trash_dir.join(&entry.trash_name);
+    if !source.is_dir() {
+        return Err(GwtError::Git(format!("{} is missing", source.display())));
+    }
+    if entry.worktree_path.exists() {
+        return Err(GwtError::Git(format!(
+            "{} already exists",
+            entry.worktree_path.display()
+        )));
+    }
+
+    let branch_ref = format!("refs/heads/{}", entry.branch);
+    match git_stdout(
+        repo_path,
+        &["rev-parse", "--verify", "--quiet", &branch_ref],
+    ) {
+        Ok(tip) if tip.trim() != entry.head => {
+            return Err(GwtError::Git(format!(
+                "{} has moved since the worktree was removed; restore would overwrite newer commits",
+                entry.branch
+            )));
+        }
+        Ok(_) => {}
+        Err(_) => {
+            git_stdout(repo_path, &["branch", &entry.branch, &entry.head])?;
+        }
+    }
+    let worktree_arg = entry.worktree_path.to_string_lossy();
+    git_stdout(
+        repo_path,
+        &[
+            "worktree",
+            "add",
+            "--no-checkout",
+            worktree_arg.as_ref(),
+            &entry.branch,
+        ],
+    )?;
+    for child in fs::read_dir(&source)? {
+        let child = child?;
+        if child.file_name() == ".git" {
+            continue;
+        }
+        fs::rename(child.path(), entry.worktree_path.join(child.file_name()))?;
+    }
+    // `--no-checkout` leaves the index empty; rebuild it from HEAD without
+    // touching the restored files.
+    git_stdout(&entry.worktree_path, &["reset", "-q"])?;
+    fs::remove_dir_all(&source)?;
+
+    entries.remove(position);
+    write_trashed(trash_dir, &entries)?;
+    Ok(entry)
+}
+
+/// Delete the worktrees trashed more than `retention_days` before `now`.
+/// Returns how many were deleted.
+pub fn purge_expired(trash_dir: &Path, retention_days: u32, now: DateTime<Utc>) -> Result<usize> {
+    let entries = list_trashed(trash_dir)?;
+    if entries.is_empty() {
+        return Ok(0);
+    }
+    let cutoff = now - chrono::Duration::days(i64::from(retention_days));
+    let (expired, kept): (Vec<_>, Vec<_>) = entries.into_iter().partition(|entry| {
+        DateTime::parse_from_rfc3339(&entry.removed_at).is_ok_and(|removed| removed <= cutoff)
+    });
+    for entry in &expired {
+        let path = trash_dir.join(&entry.trash_name);
+        if path.exists() {
+            fs::remove_dir_all(&path)?;
+        }
+    }
+    write_trashed(trash_dir, &kept)?;
+    Ok(expired.len())
+}
+
+fn git_stdout(dir: &Path, args: &[&str]) -> Result<String> {
+    let label = args[..2.min(args.len())].join(" ");
+    let output = gwt_core::process::run_git_logged(args, Some(dir))
+        .map_err(|e| GwtError::Git(format!("{label}: {e}")))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        return Err(GwtError::Git(format!("{label}: {}", stderr.trim())));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::run_git_logged(args, Some(dir)).unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn repo_with_worktree(tmp: &Path) -> (PathBuf, PathBuf) {
+        let repo = tmp.join("repo");
+        fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--initial-branch=main"]);
+        git(&repo, &["config", "user.email", "t@example.com"]);
+        git(&repo, &["config", "user.name", "T"]);
+        fs::write(repo.join("kept.txt"), "one\n").unwrap();
+        git(&repo, &["add", "."]);
+        git(&repo, &["commit", "-m", "init"]);
+        let feature = tmp.join("work").join("x");
+        git(
+            &repo,
+            &["worktree", "add", "-b", "work/x", feature.to_str().unwrap()],
+        );
+        (repo, feature)
+    }
+
+    #[test]
+    fn cleanup_trashes_the_worktree_and_undo_brings_it_back() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (repo, feature) = repo_with_worktree(tmp.path());
+        fs::write(feature.join("kept.txt"), "two\n").unwrap();
+        fs::write(feature.join("new.txt"), "new\n").unwrap();
+        let trash = WorktreeTrash::for_repo(&repo, 7);
+        assert_eq!(trash.dir, tmp.path().join(TRASH_DIR_NAME));
+
+        let manager = WorktreeManager::new(&repo).with_trash(trash.clone());
+        manager.cleanup_branch("work/x").unwrap();
+        assert!(!feature.exists());
+        let trashed = list_trashed(&trash.dir).unwrap();
+        assert_eq!(trashed.len(), 1);
+        assert_eq!(trashed[0].branch, "work/x");
+        assert!(trash
+            .dir
+            .join(&trashed[0].trash_name)
+            .join("new.txt")
+            .exists());
+        assert_eq!(manager.list().unwrap().len(), 1);
+
+        let restored = restore_trashed(&repo, &trash.dir, None).unwrap();
+        assert_eq!(restored.worktree_path, feature);
+        assert_eq!(
+            fs::read_to_string(feature.join("kept.txt")).unwrap(),
+            "two\n"
+        );
+        assert!(feature.join("new.txt").exists());
+        let status = git_stdout(&feature, &["status", "--porcelain"]).unwrap();
+        assert!(status.contains(" M kept.txt"), "{status}");
+        assert!(status.contains("?? new.txt"), "{status}");
+        assert!(list_trashed(&trash.dir).unwrap().is_empty());
+        assert!(restore_trashed(&repo, &trash.dir, Some("work/x")).is_err());
+    }
+
+    #[test]
+    fn purge_deletes_only_expired_entries() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (repo, feature) = repo_with_worktree(tmp.path());
+        let trash = WorktreeTrash::for_repo(&repo, 3);
+        let entry = trash_worktree(&repo, &trash, &feature, "work/x").unwrap();
+
+        assert_eq!(purge_expired(&trash.dir, 3, Utc::now()).unwrap(), 0);
+        let later = Utc::now() + chrono::Duration::days(4);
+        assert_eq!(purge_expired(&trash.dir, 3, later).unwrap(), 1);
+        assert!(!trash.dir.join(&entry.trash_name).exists());
+        assert!(list_trashed(&trash.dir).unwrap().is_empty());
+    }
+}
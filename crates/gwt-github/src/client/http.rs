@@ -328,6 +328,13 @@ fn execute_reqwest(
 // HttpIssueClient
 // ---------------------------------------------------------------------------
 
+/// Pull request opened by [`HttpIssueClient::create_pull_request`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct CreatedPullRequest {
+    pub number: u64,
+    pub url: String,
+}
+
 /// Real [`IssueClient`] that talks to GitHub via an [`HttpTransport`].
 pub struct HttpIssueClient<T: HttpTransport = ReqwestTransport> {
     transport: T,
@@ -440,6 +447,62 @@ impl<T: HttpTransport> HttpIssueClient<T> {
         &self.transport
     }
 
+    /// Open a pull request from `head` into `base` (`POST /repos/.../pulls`).
+    pub fn create_pull_request(
+        &self,
+        head: &str,
+        base: &str,
+        title: &str,
+        body: &str,
+        draft: bool,
+    ) -> Result<CreatedPullRequest, ApiError> {
+        let path = format!("/repos/{}/{}/pulls", self.owner, self.repo);
+        let resp = self.rest_post(
+            &path,
+            json!({
+                "head": head,
+                "base": base,
+                "title": title,
+                "body": body,
+                "draft": draft,
+            }),
+        )?;
+        let value: Value = serde_json::from_str(&resp.body)
+            .map_err(|e| ApiError::Unexpected(format!("create_pull_request json: {e}")))?;
+        let number = value
+            .get("number")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| ApiError::Unexpected("create_pull_request: number missing".into()))?;
+        let url = value
+            .get("html_url")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        Ok(CreatedPullRequest { number, url })
+    }
+
+    /// Description of pull request `number` (`GET /repos/.../pulls/{n}`);
+    /// empty when the PR has no body.
+    pub fn pull_request_body(&self, number: u64) -> Result<String, ApiError> {
+        let path = format!("/repos/{}/{}/pulls/{number}", self.owner, self.repo);
+        let resp = self.rest_get(&path)?;
+        let value: Value = serde_json::from_str(&resp.body)
+            .map_err(|e| ApiError::Unexpected(format!("pull_request_body json: {e}")))?;
+        Ok(value
+            .get("body")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string())
+    }
+
+    /// Replace the description of pull request `number`
+    /// (`PATCH /repos/.../pulls/{n}`).
+    pub fn update_pull_request_body(&self, number: u64, body: &str) -> Result<(), ApiError> {
+        let path = format!("/repos/{}/{}/pulls/{number}", self.owner, self.repo);
+        self.rest_patch(&path, json!({ "body": body }))?;
+        Ok(())
+    }
+
     fn auth_headers(&self) -> Vec<(String, String)> {
         vec![
             (
@@ -487,6 +550,20 @@ impl<T: HttpTransport> HttpIssueClient<T> {
         Ok(resp)
     }
 
+    fn rest_get(&self, path: &str) -> Result<HttpResponse, ApiError> {
+        let resp = self
+            .transport
+            .execute(HttpRequest {
+                method: HttpMethod::Get,
+                url: format!("{}{}", self.rest_base, path),
+                headers: self.auth_headers(),
+                body: None,
+            })
+            .map_err(|e| ApiError::Network(e.to_string()))?;
+        check_status(&resp)?;
+        Ok(resp)
+    }
+
     fn rest_delete(&self, path: &str) -> Result<HttpResponse, ApiError> {
         let resp = self
             .transport
@@ -271,6 +271,51 @@ fn red_56_create_issue_posts_with_labels() {
     assert_eq!(snap.labels, labels);
 }
 
+#[test]
+fn create_pull_request_posts_head_base_and_draft() {
+    let transport = FakeTransport::new();
+    transport.enqueue(created(
+        r#"{"number":321,"html_url":"https://github.com/octo/gwt/pull/321"}"#,
+    ));
+    let client = client_with(transport);
+    let pr = client
+        .create_pull_request("feature/x", "main", "Add x", "Body", true)
+        .unwrap();
+
+    let reqs = client.transport().recorded();
+    assert_eq!(reqs[0].method, HttpMethod::Post);
+    assert_eq!(reqs[0].url, "https://api.github.com/repos/octo/gwt/pulls");
+    let payload: serde_json::Value =
+        serde_json::from_str(reqs[0].body.as_deref().unwrap()).unwrap();
+    assert_eq!(payload["head"], "feature/x");
+    assert_eq!(payload["base"], "main");
+    assert_eq!(payload["draft"], true);
+    assert_eq!(pr.number, 321);
+    assert_eq!(pr.url, "https://github.com/octo/gwt/pull/321");
+}
+
+#[test]
+fn pull_request_body_reads_and_patches_the_description() {
+    let transport = FakeTransport::new();
+    transport.enqueue(ok_body(r#"{"number":321,"body":null}"#));
+    transport.enqueue(ok_body(r#"{"number":321,"body":"New"}"#));
+    let client = client_with(transport);
+
+    assert_eq!(client.pull_request_body(321).unwrap(), "");
+    client.update_pull_request_body(321, "New").unwrap();
+
+    let reqs = client.transport().recorded();
+    assert_eq!(reqs[0].method, HttpMethod::Get);
+    assert_eq!(
+        reqs[0].url,
+        "https://api.github.com/repos/octo/gwt/pulls/321"
+    );
+    assert_eq!(reqs[1].method, HttpMethod::Patch);
+    let payload: serde_json::Value =
+        serde_json::from_str(reqs[1].body.as_deref().unwrap()).unwrap();
+    assert_eq!(payload, serde_json::json!({"body": "New"}));
+}
+
 // -----------------------------------------------------------------------
 // RED-57: set_labels PATCHes the issue with the labels field
 // -----------------------------------------------------------------------
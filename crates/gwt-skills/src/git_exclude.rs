@@ -90,6 +90,7 @@ fn resolve_git_exclude_path(worktree: &Path) -> io::Result<PathBuf> {
 fn replace_managed_block(content: &str) -> io::Result<String> {
     let mut patterns = exclude_patterns_for_targets(&ManagedAssetTarget::ALL);
     push_unique(&mut patterns, "docker-compose.override.yml");
+    push_unique(&mut patterns, "docker-compose.gwt.devcontainer.yml");
     replace_managed_block_with_patterns(content, &patterns)
 }
 
@@ -245,6 +246,7 @@ mod tests {
         assert!(result.contains("\n.gwt/*\n"));
         assert!(result.contains("\n!.gwt/work/\n"));
         assert!(result.contains("docker-compose.override.yml"));
+        assert!(result.contains("docker-compose.gwt.devcontainer.yml"));
         assert!(!result.contains(".gwt/discussion.md"));
         assert!(!result.contains(".gwt/opencode/"));
         assert!(!result.contains(".gwt/openclaw/"));
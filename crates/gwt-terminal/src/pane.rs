@@ -12,7 +12,10 @@ use crate::{
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PaneStatus {
     Running,
+    /// Exited with this code.
     Completed(i32),
+    /// Terminated by the named signal (e.g. "Terminated").
+    Signaled(String),
     Error(String),
 }
 
@@ -177,11 +180,10 @@ impl Pane {
     pub fn check_status(&mut self) -> Result<&PaneStatus, TerminalError> {
         if self.status == PaneStatus::Running {
             if let Some(exit_status) = self.pty.try_wait()? {
-                if exit_status.success() {
-                    self.status = PaneStatus::Completed(0);
-                } else {
-                    self.status = PaneStatus::Completed(1);
-                }
+                self.status = match exit_status.signal() {
+                    Some(signal) => PaneStatus::Signaled(signal.to_string()),
+                    None => PaneStatus::Completed(exit_status.exit_code() as i32),
+                };
             }
         }
         Ok(&self.status)
@@ -640,6 +642,28 @@ mod tests {
         assert_eq!(pane.status(), &PaneStatus::Completed(0));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_pane_check_status_keeps_exit_code() {
+        let _pty_guard = lock_pty_test();
+        let mut pane = test_pane(
+            "test-exit-code",
+            TestCommand {
+                command: "/bin/sh".to_string(),
+                args: vec!["-c".to_string(), "exit 3".to_string()],
+            },
+        );
+
+        for _ in 0..50 {
+            if matches!(pane.check_status(), Ok(status) if *status != PaneStatus::Running) {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+
+        assert_eq!(pane.status(), &PaneStatus::Completed(3));
+    }
+
     #[test]
     fn test_pane_mark_error() {
         let _pty_guard = lock_pty_test();
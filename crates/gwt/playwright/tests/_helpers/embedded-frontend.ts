@@ -48,6 +48,8 @@ const ROOT_MODULES = new Set([
   // SPEC-2008 camera-focus / FR-094 — always-on Fleet Minimap carrier.
   "fleet-minimap.js",
   "focus-trap.js",
+  "footer-widgets.js",
+  "refresh-scheduler.js",
   "hotkey.js",
   "improvement-inbox-surface.js",
   "index-settings-panel.js",
@@ -67,6 +69,8 @@ const ROOT_MODULES = new Set([
   "migration-modal.js",
   "operator-shell.js",
   "project-clone-modal.js",
+  // Drag-and-drop a folder onto the canvas to open it as a project.
+  "project-folder-drop.js",
   // SPEC-3064 Phase 3 (E6e) — Profile window surface.
   "profile-window-surface.js",
   // SPEC-3064 Phase 3 (E3) — Project Index window surface.
@@ -100,6 +104,8 @@ const ROOT_MODULES = new Set([
   "terminal-wheel-scroll.js",
   "terminal-viewport-reflow.js",
   "theme-manager.js",
+  "theme-settings-panel.js",
+  "config-diagnostics-panel.js",
   "theme-toggle.js",
   "ui-trace-profiler.js",
   "ui-trace-wiring.js",
@@ -0,0 +1,30 @@
+//! Agent watchdog ticker.
+//!
+//! Asks the event loop once a minute to check live agent sessions against
+//! the active profile's watchdog limits (`gwt_agent::watchdog`).
+
+use std::time::Duration;
+
+use tao::event_loop::EventLoopProxy;
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::UserEvent;
+
+const TICK_SECS: u64 = 60;
+
+/// Spawn the watchdog ticker onto the shared tokio runtime. It stops when the
+/// event loop is gone.
+pub fn spawn_agent_watchdog(runtime: &tokio::runtime::Runtime, proxy: EventLoopProxy<UserEvent>) {
+    drop(runtime.handle().spawn(async move {
+        let mut ticker = interval(Duration::from_secs(TICK_SECS));
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // The first tick completes immediately; nothing can be stalled yet.
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            if proxy.send_event(UserEvent::AgentWatchdogTick).is_err() {
+                break;
+            }
+        }
+    }));
+}
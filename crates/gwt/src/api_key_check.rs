@@ -0,0 +1,108 @@
+//! Pre-launch API key checks for the launch wizard.
+//!
+//! With `[agent] check_api_keys = true` in `~/.gwt/config.toml`, resolving
+//! the wizard's runtime also checks the provider key the selected agent
+//! will run with. A rejected or rate-limited key becomes a warning in the
+//! Confirm step instead of an agent that exits right after launch. Agents
+//! signed in without an API key are not checked. The checks themselves live
+//! in [`gwt_ai::key_check`].
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    sync::{Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+
+use gwt_agent::{AgentId, LaunchConfig};
+use gwt_ai::key_check::{check_key_blocking, KeyHealth, KeyProvider};
+
+/// How long a definite check result is reused for the same key.
+pub const KEY_CHECK_TTL: Duration = Duration::from_secs(10 * 60);
+
+type KeyCache = HashMap<(KeyProvider, u64), (Instant, KeyHealth)>;
+
+fn cache() -> &'static Mutex<KeyCache> {
+    static CACHE: OnceLock<Mutex<KeyCache>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+/// Provider whose key `agent` runs with, for the agents that take one.
+pub fn provider_for_agent(agent: &AgentId) -> Option<KeyProvider> {
+    match agent {
+        AgentId::ClaudeCode => Some(KeyProvider::Anthropic),
+        AgentId::Codex => Some(KeyProvider::OpenAi),
+        AgentId::Gemini => Some(KeyProvider::Gemini),
+        _ => None,
+    }
+}
+
+/// Warning about the API key `config` launches with, or `None` when the
+/// check is off, the agent uses no key, or the key looks fine.
+pub fn launch_key_warning(config: &LaunchConfig) -> Option<String> {
+    let enabled = gwt_config::Settings::load()
+        .map(|settings| settings.agent.check_api_keys)
+        .unwrap_or(false);
+    if !enabled {
+        return None;
+    }
+    let provider = provider_for_agent(&config.agent_id)?;
+    let (env_var, key) = provider.find_key(|name| {
+        if let Some(value) = config.env_vars.get(name) {
+            return Some(value.clone());
+        }
+        if config.remove_env.iter().any(|removed| removed == name) {
+            return None;
+        }
+        std::env::var(name).ok()
+    })?;
+    key_health(provider, &key).warning(provider, env_var)
+}
+
+fn key_health(provider: KeyProvider, key: &str) -> KeyHealth {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    let cache_key = (provider, hasher.finish());
+    if let Some((checked_at, health)) = cache()
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get(&cache_key).cloned())
+    {
+        if checked_at.elapsed() < KEY_CHECK_TTL {
+            return health;
+        }
+    }
+    let health = check_key_blocking(provider, key);
+    if let KeyHealth::Unknown { detail } = &health {
+        tracing::debug!(provider = provider.label(), %detail, "API key check inconclusive");
+    } else if let Ok(mut cache) = cache().lock() {
+        cache.insert(cache_key, (Instant::now(), health.clone()));
+    }
+    health
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_key_based_agents_map_to_a_provider() {
+        assert_eq!(
+            provider_for_agent(&AgentId::ClaudeCode),
+            Some(KeyProvider::Anthropic)
+        );
+        assert_eq!(
+            provider_for_agent(&AgentId::Codex),
+            Some(KeyProvider::OpenAi)
+        );
+        assert_eq!(
+            provider_for_agent(&AgentId::Gemini),
+            Some(KeyProvider::Gemini)
+        );
+        assert_eq!(provider_for_agent(&AgentId::OpenCode), None);
+        assert_eq!(
+            provider_for_agent(&AgentId::Custom("my-agent".to_string())),
+            None
+        );
+    }
+}
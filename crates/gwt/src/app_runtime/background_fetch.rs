@@ -0,0 +1,39 @@
+use super::*;
+
+impl AppRuntime {
+    /// Fetch and prune every open repository off the event loop. Projects whose
+    /// remote-tracking refs changed are announced as
+    /// `BackendEvent::GitRefsChanged`, which reloads the Branches lists.
+    pub(crate) fn background_fetch_tick(&mut self) {
+        let mut roots: Vec<PathBuf> = self
+            .tabs
+            .iter()
+            .filter(|tab| tab.kind != gwt::ProjectKind::NonRepo)
+            .map(ProjectTabRuntime::main_worktree_root)
+            .collect();
+        roots.sort();
+        roots.dedup();
+        if roots.is_empty() {
+            return;
+        }
+        let proxy = self.proxy.clone();
+        self.blocking_tasks.spawn(move || {
+            for root in roots {
+                match gwt::background_fetch::refresh_project(&root) {
+                    Ok(true) => proxy.send(UserEvent::Dispatch(vec![OutboundEvent::broadcast(
+                        BackendEvent::GitRefsChanged {
+                            hook: gwt::background_fetch::FETCH_HOOK.to_string(),
+                            branch: None,
+                        },
+                    )])),
+                    Ok(false) => {}
+                    Err(error) => tracing::warn!(
+                        error = %error,
+                        repo = %root.display(),
+                        "background fetch failed"
+                    ),
+                }
+            }
+        });
+    }
+}
@@ -11,18 +11,30 @@
 //!   SPEC-2006 Phase 2 write-back, with the error mapping helpers
 //!   (`file_content_save_error`, `write_error_to_event`,
 //!   `file_content_error_to_event`)
+//! - [`AppRuntime::load_git_changes_event`] /
+//!   [`AppRuntime::load_file_diff_event`] /
+//!   [`AppRuntime::toggle_diff_hunk_events`] — the Changes pane: changed
+//!   files, per-file staged/unstaged diffs and hunk-level staging through
+//!   `gwt_git::diff`
+//! - [`AppRuntime::suggest_commit_message_events`] /
+//!   [`AppRuntime::create_commit_events`] — the Changes pane commit form,
+//!   run off the event loop (AI calls and GPG signing can take a while)
 //!
 //! Behavior-preserving move: the filesystem domain logic stays in
 //! `crate::file_tree` / `crate::file_content` (re-exported via the `gwt`
 //! library crate).
 
-use std::path::Path;
+use std::{
+    path::{Path, PathBuf},
+    thread,
+};
 
 use base64::Engine as _;
 
 use super::{
     list_directory_entries, read_binary_chunk, read_text_file, AppRuntime, BackendEvent,
-    ContentLimits, FileContentError, FileContentErrorKind, FileContentMode, WindowPreset,
+    ContentLimits, FileContentError, FileContentErrorKind, FileContentMode, OutboundEvent,
+    UserEvent, WindowPreset,
 };
 
 fn file_content_save_error(
@@ -500,4 +512,196 @@ impl AppRuntime {
             }
         }
     }
+
+    pub(crate) fn load_git_changes_event(&self, id: &str) -> BackendEvent {
+        let result = self
+            .resolve_file_tree_root(id)
+            .and_then(|root| gwt_git::diff::get_status(&root).map_err(|err| err.to_string()));
+        match result {
+            Ok(entries) => BackendEvent::GitChanges {
+                id: id.to_string(),
+                entries,
+            },
+            Err(message) => BackendEvent::FileDiffError {
+                id: id.to_string(),
+                path: String::new(),
+                message,
+            },
+        }
+    }
+
+    pub(crate) fn load_file_diff_event(&self, id: &str, path: &str, staged: bool) -> BackendEvent {
+        let result = self.resolve_file_tree_root(id).and_then(|root| {
+            gwt_git::diff::file_diff(&root, Path::new(path), staged).map_err(|err| err.to_string())
+        });
+        match result {
+            Ok(diff) => BackendEvent::FileDiff {
+                id: id.to_string(),
+                diff,
+            },
+            Err(message) => BackendEvent::FileDiffError {
+                id: id.to_string(),
+                path: path.to_string(),
+                message,
+            },
+        }
+    }
+
+    /// Stage (or, from the staged view, unstage) one hunk, then send the
+    /// refreshed diff and change list so the pane reflects the new index.
+    pub(crate) fn toggle_diff_hunk_events(
+        &self,
+        id: &str,
+        path: &str,
+        staged: bool,
+        hunk_index: usize,
+    ) -> Vec<BackendEvent> {
+        let result = self.resolve_file_tree_root(id).and_then(|root| {
+            let relative = Path::new(path);
+            let applied = if staged {
+                gwt_git::diff::unstage_hunk(&root, relative, hunk_index)
+            } else {
+                gwt_git::diff::stage_hunk(&root, relative, hunk_index)
+            };
+            applied.map_err(|err| err.to_string())
+        });
+        if let Err(message) = result {
+            return vec![BackendEvent::FileDiffError {
+                id: id.to_string(),
+                path: path.to_string(),
+                message,
+            }];
+        }
+        vec![
+            self.load_file_diff_event(id, path, staged),
+            self.load_git_changes_event(id),
+        ]
+    }
+
+    /// Ask the configured AI provider for a message describing the staged
+    /// changes. The suggestion (or the error) is sent back to `client_id`.
+    pub(crate) fn suggest_commit_message_events(
+        &self,
+        client_id: &str,
+        id: &str,
+    ) -> Vec<OutboundEvent> {
+        let root = match self.resolve_file_tree_root(id) {
+            Ok(root) => root,
+            Err(message) => return commit_error_reply(client_id, id, message),
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let event = match suggest_commit_message(&root) {
+                Ok(message) => BackendEvent::CommitMessageSuggestion {
+                    id: window_id,
+                    message,
+                },
+                Err(message) => BackendEvent::CommitError {
+                    id: window_id,
+                    message,
+                },
+            };
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id, event,
+            )]));
+        });
+        Vec::new()
+    }
+
+    /// Stage `paths` and commit the index, then send the new commit and the
+    /// refreshed change list.
+    pub(crate) fn create_commit_events(
+        &self,
+        client_id: &str,
+        id: &str,
+        paths: Vec<String>,
+        message: String,
+        options: gwt_git::commit::CommitOptions,
+    ) -> Vec<OutboundEvent> {
+        let root = match self.resolve_file_tree_root(id) {
+            Ok(root) => root,
+            Err(message) => return commit_error_reply(client_id, id, message),
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let paths: Vec<PathBuf> = paths.into_iter().map(PathBuf::from).collect();
+            let result = gwt_git::commit::stage_paths(&root, &paths)
+                .and_then(|()| gwt_git::commit::create_commit(&root, &message, &options));
+            let mut events = Vec::new();
+            match result {
+                Ok(commit) => {
+                    tracing::info!(hash = %commit.hash, subject = %commit.subject, "commit created");
+                    events.push(BackendEvent::CommitCreated {
+                        id: window_id.clone(),
+                        commit,
+                    });
+                }
+                Err(error) => events.push(BackendEvent::CommitError {
+                    id: window_id.clone(),
+                    message: error.to_string(),
+                }),
+            }
+            // Staging may have succeeded even when the commit did not.
+            if let Ok(entries) = gwt_git::diff::get_status(&root) {
+                events.push(BackendEvent::GitChanges {
+                    id: window_id,
+                    entries,
+                });
+            }
+            proxy.send(UserEvent::Dispatch(
+                events
+                    .into_iter()
+                    .map(|event| OutboundEvent::reply(client_id.clone(), event))
+                    .collect(),
+            ));
+        });
+        Vec::new()
+    }
+}
+
+fn commit_error_reply(client_id: &str, id: &str, message: String) -> Vec<OutboundEvent> {
+    vec![OutboundEvent::reply(
+        client_id.to_string(),
+        BackendEvent::CommitError {
+            id: id.to_string(),
+            message,
+        },
+    )]
+}
+
+fn suggest_commit_message(root: &Path) -> Result<String, String> {
+    let diff = gwt_git::commit::staged_diff(root).map_err(|err| err.to_string())?;
+    if diff.trim().is_empty() {
+        return Err("Stage some changes before asking for a message".to_string());
+    }
+    let ai = gwt_config::Settings::load().unwrap_or_default().ai;
+    if !ai.is_enabled() {
+        return Err("AI is not configured".to_string());
+    }
+    let provider = gwt_ai::build_provider(
+        &ai.provider,
+        &ai.endpoint,
+        ai.api_key.as_deref().unwrap_or(""),
+        &ai.model,
+    )
+    .map_err(|err| err.to_string())?;
+    let subjects =
+        gwt_git::commit::recent_commits(root, gwt_ai::commit_message::STYLE_SUBJECT_COUNT)
+            .map(|commits| {
+                commits
+                    .into_iter()
+                    .map(|commit| commit.subject)
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+    gwt_ai::run_ai_job(
+        "Commit message suggestion",
+        gwt_ai::AiJobPriority::Interactive,
+        || gwt_ai::suggest_commit_message(provider.as_ref(), &diff, &subjects),
+    )
+    .map_err(|err| gwt_ai::format_error_for_display(provider.kind(), &err))
 }
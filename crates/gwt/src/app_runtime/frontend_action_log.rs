@@ -145,6 +145,9 @@ pub(super) fn frontend_user_action_log(event: &FrontendEvent) -> Option<Frontend
                 .mode(if *enabled { "on" } else { "off" })
         }
         FrontendEvent::RefreshUsage => FrontendUserActionLog::new("refresh_usage", "usage"),
+        FrontendEvent::CancelAiJob { job_id } => {
+            FrontendUserActionLog::new("cancel_ai_job", "status").target(job_id.to_string())
+        }
         FrontendEvent::OpenProjectDialog => {
             FrontendUserActionLog::new("open_project_dialog", "project")
         }
@@ -162,6 +165,12 @@ pub(super) fn frontend_user_action_log(event: &FrontendEvent) -> Option<Frontend
         FrontendEvent::ReopenRecentProject { path } => {
             FrontendUserActionLog::new("reopen_recent_project", "project").target(path)
         }
+        FrontendEvent::ConsolidateDuplicateProject { path } => {
+            FrontendUserActionLog::new("consolidate_duplicate_project", "project").target(path)
+        }
+        FrontendEvent::DropProjectFolder { path, .. } => {
+            FrontendUserActionLog::new("drop_project_folder", "project").target(path)
+        }
         FrontendEvent::SelectProjectTab { tab_id } => {
             FrontendUserActionLog::new("select_project_tab", "project").target(tab_id)
         }
@@ -253,6 +262,45 @@ pub(super) fn frontend_user_action_log(event: &FrontendEvent) -> Option<Frontend
                 .target(path)
                 .mode(format!("{mode:?}"))
         }
+        FrontendEvent::LoadGitChanges { id } => {
+            FrontendUserActionLog::new("load_git_changes", "file").window(id)
+        }
+        FrontendEvent::LoadFileDiff { id, path, staged } => {
+            FrontendUserActionLog::new("load_file_diff", "file")
+                .window(id)
+                .target(path)
+                .mode(if *staged { "staged" } else { "unstaged" })
+        }
+        FrontendEvent::ToggleDiffHunk {
+            id, path, staged, ..
+        } => FrontendUserActionLog::new(
+            if *staged {
+                "unstage_diff_hunk"
+            } else {
+                "stage_diff_hunk"
+            },
+            "file",
+        )
+        .window(id)
+        .target(path),
+        FrontendEvent::SuggestCommitMessage { id } => {
+            FrontendUserActionLog::new("suggest_commit_message", "file").window(id)
+        }
+        FrontendEvent::CreateCommit {
+            id,
+            paths,
+            signoff,
+            gpg_sign,
+            ..
+        } => FrontendUserActionLog::new("create_commit", "file")
+            .window(id)
+            .count(paths.len())
+            .mode(match (*signoff, *gpg_sign) {
+                (false, false) => "plain",
+                (true, false) => "signoff",
+                (false, true) => "gpg_sign",
+                (true, true) => "signoff_gpg_sign",
+            }),
         FrontendEvent::SaveFileContent { id, path, mode, .. } => {
             FrontendUserActionLog::new("save_file_content", "file")
                 .window(id)
@@ -265,11 +313,109 @@ pub(super) fn frontend_user_action_log(event: &FrontendEvent) -> Option<Frontend
         FrontendEvent::RequestRemoteStartWorkBranches { id } => {
             FrontendUserActionLog::new("request_remote_start_work_branches", "branches").window(id)
         }
+        FrontendEvent::OpenWorktreePath { id, target, .. } => {
+            FrontendUserActionLog::new("open_worktree_path", "branches")
+                .window(id)
+                .mode(format!("{target:?}"))
+        }
+        FrontendEvent::StashWorktree { id, action, .. } => {
+            FrontendUserActionLog::new("stash_worktree", "branches")
+                .window(id)
+                .mode(format!("{action:?}"))
+        }
+        FrontendEvent::ArchiveWorktrees { id, branches } => {
+            FrontendUserActionLog::new("archive_worktrees", "branches")
+                .window(id)
+                .target(summarize_ui_action_values(
+                    branches.iter().map(String::as_str),
+                ))
+                .count(branches.len())
+        }
+        FrontendEvent::RestoreWorktree { id, branch } => {
+            FrontendUserActionLog::new("restore_worktree", "branches")
+                .window(id)
+                .target(branch.clone())
+        }
+        FrontendEvent::RepairWorktree { id, branch } => {
+            FrontendUserActionLog::new("repair_worktree", "branches")
+                .window(id)
+                .target(branch.clone())
+        }
+        FrontendEvent::TransplantWorktree { id, branch, .. } => {
+            FrontendUserActionLog::new("transplant_worktree", "branches")
+                .window(id)
+                .target(branch.clone())
+        }
+        FrontendEvent::DownloadCiArtifacts { id, branch, .. } => {
+            FrontendUserActionLog::new("download_ci_artifacts", "branches")
+                .window(id)
+                .target(branch.clone())
+        }
+        FrontendEvent::RebaseBranch { id, branch, onto } => {
+            FrontendUserActionLog::new("rebase_branch", "branches")
+                .window(id)
+                .target(branch.clone())
+                .mode(onto.clone().unwrap_or_else(|| "default_base".to_string()))
+        }
+        FrontendEvent::ContinueRebase { id, branch } => {
+            FrontendUserActionLog::new("continue_rebase", "branches")
+                .window(id)
+                .target(branch.clone())
+        }
+        FrontendEvent::AbortRebase { id, branch } => {
+            FrontendUserActionLog::new("abort_rebase", "branches")
+                .window(id)
+                .target(branch.clone())
+        }
+        FrontendEvent::ResolveRebaseConflicts { id, branch } => {
+            FrontendUserActionLog::new("resolve_rebase_conflicts", "branches")
+                .window(id)
+                .target(branch.clone())
+        }
+        FrontendEvent::LoadBranchCiStatus { id } => {
+            FrontendUserActionLog::new("load_branch_ci_status", "branches").window(id)
+        }
+        FrontendEvent::CreatePullRequest { id, branch } => {
+            FrontendUserActionLog::new("create_pull_request", "branches")
+                .window(id)
+                .target(branch.clone())
+        }
+        FrontendEvent::DetectExternalAgents { id } => {
+            FrontendUserActionLog::new("detect_external_agents", "branches").window(id)
+        }
+        FrontendEvent::AdoptExternalAgent { id, pid, branch } => {
+            FrontendUserActionLog::new("adopt_external_agent", "branches")
+                .window(id)
+                .target(branch.clone())
+                .mode(format!("pid {pid}"))
+        }
+        FrontendEvent::LoadStaleRemoteBranches { id, min_age_days } => {
+            FrontendUserActionLog::new("load_stale_remote_branches", "branches")
+                .window(id)
+                .mode(format!("{min_age_days}d"))
+        }
+        FrontendEvent::DeleteStaleRemoteBranches { id, branches } => {
+            FrontendUserActionLog::new("delete_stale_remote_branches", "branches")
+                .window(id)
+                .target(summarize_ui_action_values(
+                    branches.iter().map(String::as_str),
+                ))
+                .count(branches.len())
+        }
+        FrontendEvent::LoadRemovedWorktrees { id } => {
+            FrontendUserActionLog::new("load_removed_worktrees", "branches").window(id)
+        }
+        FrontendEvent::UndoWorktreeRemoval { id, branch } => {
+            FrontendUserActionLog::new("undo_worktree_removal", "branches")
+                .window(id)
+                .target(branch.clone())
+        }
         FrontendEvent::RunBranchCleanup {
             id,
             branches,
             delete_remote,
             force_filesystem_delete,
+            ..
         } => FrontendUserActionLog::new("run_branch_cleanup", "branches")
             .window(id)
             .target(summarize_ui_action_values(
@@ -482,7 +628,8 @@ pub(super) fn frontend_user_action_log(event: &FrontendEvent) -> Option<Frontend
                 | LaunchWizardAction::SetReasoning { reasoning: value }
                 | LaunchWizardAction::SetVersion { version: value }
                 | LaunchWizardAction::SetExecutionMode { mode: value }
-                | LaunchWizardAction::SetDockerService { service: value } => {
+                | LaunchWizardAction::SetDockerService { service: value }
+                | LaunchWizardAction::SetDockerProfile { profile: value } => {
                     log = log.target(value);
                 }
                 LaunchWizardAction::SubmitText { value }
@@ -572,6 +719,9 @@ pub(super) fn frontend_user_action_log(event: &FrontendEvent) -> Option<Frontend
         FrontendEvent::StartMigration { tab_id } => {
             FrontendUserActionLog::new("start_migration", "migration").target(tab_id)
         }
+        FrontendEvent::MigrationDryRun { tab_id } => {
+            FrontendUserActionLog::new("migration_dry_run", "migration").target(tab_id)
+        }
         FrontendEvent::SkipMigration { tab_id } => {
             FrontendUserActionLog::new("skip_migration", "migration").target(tab_id)
         }
@@ -611,6 +761,9 @@ pub(super) fn frontend_user_action_log(event: &FrontendEvent) -> Option<Frontend
         } => FrontendUserActionLog::new("update_system_settings", "settings")
             .target(language)
             .force(codex_trust_managed_hooks.unwrap_or(false)),
+        FrontendEvent::ApplyAiPreset { preset } => {
+            FrontendUserActionLog::new("apply_ai_preset", "settings").target(preset)
+        }
         FrontendEvent::GetAutostartStatus => {
             FrontendUserActionLog::new("get_autostart_status", "settings")
         }
@@ -698,6 +851,8 @@ pub(super) fn frontend_user_action_log(event: &FrontendEvent) -> Option<Frontend
         // payloads. They are handled by more specific logs or diagnostics.
         FrontendEvent::StartupAutoResumeReady { .. }
         | FrontendEvent::UpdateViewport { .. }
+        | FrontendEvent::RefreshFooterWidget { .. }
+        | FrontendEvent::HydrateBranches { .. }
         | FrontendEvent::UpdateWindowGeometry { .. }
         | FrontendEvent::TerminalInput { .. }
         | FrontendEvent::PasteImage { .. }
@@ -707,6 +862,25 @@ pub(super) fn frontend_user_action_log(event: &FrontendEvent) -> Option<Frontend
     Some(log)
 }
 
+/// Count the action (and, for new windows, the screen) in the local usage
+/// insights. Uses the same allow-list as the log, so high-volume and
+/// sensitive events are never counted.
+pub(super) fn record_frontend_insights(
+    insights: &mut gwt::insights::InsightsRecorder,
+    event: &FrontendEvent,
+) {
+    if !insights.is_enabled() {
+        return;
+    }
+    let Some(log) = frontend_user_action_log(event) else {
+        return;
+    };
+    insights.record(gwt::insights::InsightKind::Command, log.action);
+    if let FrontendEvent::CreateWindow { preset, .. } = event {
+        insights.record(gwt::insights::InsightKind::Screen, preset.id_prefix());
+    }
+}
+
 pub(super) fn log_frontend_user_action(client_id: &str, event: &FrontendEvent) {
     let Some(log) = frontend_user_action_log(event) else {
         return;
@@ -437,7 +437,7 @@ fn session_status_category(status: gwt_agent::AgentStatus) -> &'static str {
     match status {
         gwt_agent::AgentStatus::Running | gwt_agent::AgentStatus::WaitingInput => "active",
         gwt_agent::AgentStatus::Idle | gwt_agent::AgentStatus::Stopped => "idle",
-        gwt_agent::AgentStatus::Interrupted => "blocked",
+        gwt_agent::AgentStatus::Interrupted | gwt_agent::AgentStatus::Stalled => "blocked",
         gwt_agent::AgentStatus::Unknown => "unknown",
     }
 }
@@ -111,6 +111,12 @@ pub(super) fn launch_config_from_persisted_session(
     if let Some(service) = session.docker_service.clone() {
         builder = builder.docker_service(service);
     }
+    if let Some(profile) = session.docker_profile.clone() {
+        builder = builder.docker_profile(profile);
+    }
+    if let Some(rule) = session.runtime_rule.clone() {
+        builder = builder.runtime_rule(rule);
+    }
     builder = builder.docker_lifecycle_intent(session.docker_lifecycle_intent);
     if let Some(shell) = session.windows_shell {
         builder = builder.windows_shell(shell);
@@ -151,6 +157,9 @@ impl AgentWindowPlacement {
     }
 }
 
+/// Sessions of each agent offered by the wizard's recent-session picker.
+const RECENT_SESSIONS_PER_AGENT: usize = 5;
+
 #[derive(Debug, Clone)]
 pub struct LaunchWizardMemoryCache {
     sessions: Vec<gwt_agent::Session>,
@@ -248,6 +257,20 @@ impl LaunchWizardMemoryCache {
         )
     }
 
+    /// Reads transcripts; call off the GUI thread.
+    pub(super) fn recent_sessions(
+        &self,
+        repo_path: &Path,
+        branch_name: &str,
+    ) -> Vec<gwt_agent::session_export::RecentSession> {
+        gwt::launch_wizard::load_recent_sessions(
+            repo_path,
+            branch_name,
+            &self.sessions,
+            RECENT_SESSIONS_PER_AGENT,
+        )
+    }
+
     fn latest_resumable_branch_session(
         &self,
         repo_path: &Path,
@@ -344,7 +367,7 @@ pub(crate) fn emit_agent_launch_stage(spawn_id: u64, stage: &str, detail: &str)
     // stage banner in real time (the summary event alone lives in
     // canonical log + Logs window only).
     let hub = gwt_core::process_console::global();
-    let label = format!("[{stage}] {detail}");
+    let label = gwt_core::process_console::redact_line(&format!("[{stage}] {detail}"));
     hub.push(gwt_core::process_console::ProcessLine::new(
         gwt_core::process_console::ProcessKind::AgentBootstrap,
         spawn_id,
@@ -755,6 +778,27 @@ impl AppRuntime {
                 let geometry = window.geometry.clone();
                 let session_id_for_restore = session_id.clone();
 
+                self.insights
+                    .record(gwt::insights::InsightKind::Agent, &agent_id.to_string());
+                gwt_core::activity_bus::publish(
+                    gwt_core::activity_bus::ActivityEvent::AgentStarted {
+                        session_id: session_id.clone(),
+                        agent_id: agent_id.to_string(),
+                        repo_path: Some(project_root.display().to_string()),
+                        branch: Some(branch_name.clone()),
+                        worktree_path: Some(worktree_path.display().to_string()),
+                    },
+                );
+                gwt::lifecycle_hooks::emit(
+                    gwt::lifecycle_hooks::LifecycleEvent::AgentStarted,
+                    serde_json::json!({
+                        "repo_path": project_root.display().to_string(),
+                        "session_id": session_id,
+                        "agent_id": agent_id.to_string(),
+                        "branch": branch_name,
+                        "worktree_path": worktree_path.display().to_string(),
+                    }),
+                );
                 self.active_agent_sessions.insert(
                     window_id.clone(),
                     ActiveAgentSession {
@@ -784,6 +828,14 @@ impl AppRuntime {
                 }
                 self.refresh_launch_wizard_session_cache(&window_id);
 
+                let _launch_span = tracing::info_span!(
+                    target: "gwt.agent",
+                    "agent_launch",
+                    agent = %agent_id,
+                    branch = %self.active_agent_sessions[&window_id].branch_name,
+                    worktree = %worktree_path.display(),
+                )
+                .entered();
                 // SPEC-2809 — Launch Wizard always spawns an AI agent
                 // launch sequence (binary resolve / env prep / PTY
                 // spawn) so the Console window's `agent` tab shows the
@@ -1045,7 +1097,7 @@ impl AppRuntime {
             }
         };
 
-        let effective_env = match self.active_profile_spawn_env() {
+        let effective_env = match self.active_profile_spawn_env(Some(project_root.as_path())) {
             Ok(env) => env,
             Err(error) => {
                 self.set_window_status(tab_id, raw_id, WindowProcessStatus::Error);
@@ -1480,8 +1532,9 @@ impl AppRuntime {
             if config.working_dir.is_some() {
                 config.working_dir = Some(worktree_path.clone());
             }
-            gwt_agent::LaunchEnvironment::from_active_profile(
+            gwt_agent::LaunchEnvironment::from_project_profile(
                 &profile_config_path,
+                Some(Path::new(&project_root)),
                 config.runtime_target,
             )?
             .with_project_root(&worktree_path)
@@ -1576,6 +1629,8 @@ impl AppRuntime {
             session.codex_fast_mode = config.codex_fast_mode;
             session.runtime_target = config.runtime_target;
             session.docker_service = config.docker_service.clone();
+            session.docker_profile = config.docker_profile.clone();
+            session.runtime_rule = config.runtime_rule.clone();
             session.docker_lifecycle_intent = config.docker_lifecycle_intent;
             session.linked_issue_number = config.linked_issue_number;
             session.launch_command = config.command.clone();
@@ -1626,8 +1681,12 @@ impl AppRuntime {
             finalize_docker_agent_launch_config(Path::new(&project_root), &mut config)?;
             let runtime_target = config.runtime_target;
             let agent_project_root = if runtime_target == gwt_agent::LaunchRuntimeTarget::Docker {
-                resolve_docker_launch_plan(&worktree_path, config.docker_service.as_deref())?
-                    .container_cwd
+                resolve_docker_launch_plan(
+                    &worktree_path,
+                    config.docker_service.as_deref(),
+                    config.docker_profile.as_deref(),
+                )?
+                .container_cwd
             } else {
                 config
                     .env_vars
@@ -1847,6 +1906,26 @@ impl AppRuntime {
         let Some(session) = self.active_agent_sessions.remove(window_id) else {
             return;
         };
+        let repo_path = self
+            .tab(&session.tab_id)
+            .map(|tab| tab.project_root.display().to_string());
+        gwt_core::activity_bus::publish(gwt_core::activity_bus::ActivityEvent::AgentStopped {
+            session_id: session.session_id.clone(),
+            agent_id: session.agent_id.clone(),
+            repo_path: repo_path.clone(),
+            branch: Some(session.branch_name.clone()),
+            worktree_path: Some(session.worktree_path.display().to_string()),
+        });
+        gwt::lifecycle_hooks::emit(
+            gwt::lifecycle_hooks::LifecycleEvent::AgentFinished,
+            serde_json::json!({
+                "repo_path": repo_path,
+                "session_id": session.session_id,
+                "agent_id": session.agent_id,
+                "branch": session.branch_name,
+                "worktree_path": session.worktree_path.display().to_string(),
+            }),
+        );
         // SPEC-3214 (FR-002 / T-005 / T-007): an ephemeral intake session runs
         // in a throwaway detached `.intake-*` worktree and produces NO Work
         // identity. On session end, remove the worktree when clean; keep it
@@ -1862,6 +1941,8 @@ impl AppRuntime {
             self.launch_wizard_cache.mark_stopped(&session.session_id);
             return;
         }
+        self.publish_session_issue_comment(&session);
+        Self::verify_worktree_integrity_after_session(&session);
         if let Some(project_root) = self
             .tab(&session.tab_id)
             .map(|tab| tab.project_root.clone())
@@ -1892,6 +1973,82 @@ impl AppRuntime {
         self.launch_wizard_cache.mark_stopped(&session.session_id);
     }
 
+    /// Check the stopped session's worktree for the leftovers of an
+    /// interrupted git command when `[worktree_integrity]` enables it. Runs
+    /// off the event loop; the report is saved for the branch list.
+    fn verify_worktree_integrity_after_session(session: &ActiveAgentSession) {
+        let worktree = session.worktree_path.clone();
+        let branch = session.branch_name.clone();
+        thread::spawn(
+            move || match gwt::worktree_integrity::verify_after_session(&worktree) {
+                Ok(Some(issues)) if !issues.is_empty() => tracing::warn!(
+                    branch = %branch,
+                    worktree = %worktree.display(),
+                    issues = %issues.join(", "),
+                    "worktree integrity problems after agent session"
+                ),
+                Ok(_) => {}
+                Err(error) => tracing::warn!(
+                    branch = %branch,
+                    error = %error,
+                    "failed to verify worktree integrity"
+                ),
+            },
+        );
+    }
+
+    /// Post the finished session's summary to its linked Issue when
+    /// `[session_issue_comments]` enables it for the project. Runs off the
+    /// event loop; failures are only logged.
+    fn publish_session_issue_comment(&self, session: &ActiveAgentSession) {
+        let session_path = self
+            .sessions_dir
+            .join(format!("{}.toml", session.session_id));
+        let Some(issue_number) = gwt_agent::Session::load(&session_path)
+            .ok()
+            .and_then(|persisted| persisted.linked_issue_number)
+        else {
+            return;
+        };
+        let Some(project_root) = self
+            .tab(&session.tab_id)
+            .map(|tab| tab.project_root.clone())
+        else {
+            return;
+        };
+        let window = self
+            .window_lookup
+            .get(&session.window_id)
+            .and_then(|address| self.tab(&address.tab_id)?.workspace.window(&address.raw_id));
+        let comment = gwt::session_issue_comment::SessionIssueComment {
+            issue_number,
+            agent_id: session.agent_id.clone(),
+            branch: session.branch_name.clone(),
+            summary: window.and_then(|window| window.dynamic_title.clone()),
+            detail: window.and_then(|window| window.dynamic_title_detail.clone()),
+        };
+        let worktree = session.worktree_path.clone();
+        thread::spawn(move || {
+            match gwt::session_issue_comment::publish_session_issue_comment(
+                &project_root,
+                &worktree,
+                &comment,
+            ) {
+                Ok(true) => tracing::info!(
+                    issue_number = comment.issue_number,
+                    branch = %comment.branch,
+                    "posted session summary to linked issue"
+                ),
+                Ok(false) => {}
+                Err(error) => tracing::warn!(
+                    issue_number = comment.issue_number,
+                    error = %error,
+                    "failed to post session summary to linked issue"
+                ),
+            }
+        });
+    }
+
     /// SPEC-3214 (codex #3235 review): whether a stopped session is an
     /// ephemeral intake session. The `.intake-*` basename alone is not enough —
     /// a normal branch worktree a user happens to name `.intake-*` must keep its
@@ -63,7 +63,8 @@ impl AppRuntime {
             gwt::LaunchWizardAction::SetLaunchPath { .. }
             | gwt::LaunchWizardAction::SelectQuickStart { .. }
             | gwt::LaunchWizardAction::SelectLiveSession { .. }
-            | gwt::LaunchWizardAction::UseStartMethod { .. } => "launch_path_select",
+            | gwt::LaunchWizardAction::UseStartMethod { .. }
+            | gwt::LaunchWizardAction::ResumeRecentSession { .. } => "launch_path_select",
             gwt::LaunchWizardAction::FocusExistingSession { .. } => "focus_existing_session",
             gwt::LaunchWizardAction::SetAgent { .. } => "agent_select",
             gwt::LaunchWizardAction::SetLaunchTarget { .. } => "launch_target_select",
@@ -84,6 +85,7 @@ impl AppRuntime {
             gwt::LaunchWizardAction::SelectQuickStart { .. } => "select_quick_start",
             gwt::LaunchWizardAction::SelectLiveSession { .. } => "select_live_session",
             gwt::LaunchWizardAction::FocusExistingSession { .. } => "focus_existing_session",
+            gwt::LaunchWizardAction::ResumeRecentSession { .. } => "resume_recent_session",
             gwt::LaunchWizardAction::SetBranchMode { .. } => "set_branch_mode",
             gwt::LaunchWizardAction::SetBranchType { .. } => "set_branch_type",
             gwt::LaunchWizardAction::SetBranchName { .. } => "set_branch_name",
@@ -96,6 +98,7 @@ impl AppRuntime {
             gwt::LaunchWizardAction::SetRuntimeTarget { .. } => "set_runtime_target",
             gwt::LaunchWizardAction::SetWindowsShell { .. } => "set_windows_shell",
             gwt::LaunchWizardAction::SetDockerService { .. } => "set_docker_service",
+            gwt::LaunchWizardAction::SetDockerProfile { .. } => "set_docker_profile",
             gwt::LaunchWizardAction::SetDockerLifecycle { .. } => "set_docker_lifecycle",
             gwt::LaunchWizardAction::SetVersion { .. } => "set_version",
             gwt::LaunchWizardAction::SetExecutionMode { .. } => "set_execution_mode",
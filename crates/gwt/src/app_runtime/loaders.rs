@@ -4,9 +4,11 @@
 //! Owns:
 //! - [`AppRuntime::load_branches_events`] — Branches/Work surface async
 //!   branch list load via `crate::repo_browser::spawn_branch_load_async`
+//! - [`AppRuntime::hydrate_branches_events`] — on-demand hydration of the
+//!   visible rows of a lazy branch list load
 //! - [`AppRuntime::load_logs_events`] — Logs surface load through the
-//!   SPEC-1924 FR-035 reader ([`load_log_entries_from_dir`]) with the
-//!   FR-036 skipped-lines warning ([`skipped_lines_warning`])
+//!   SPEC-1924 FR-035 reader, incrementally via [`LogSnapshotCache`], with
+//!   the FR-036 skipped-lines warning ([`skipped_lines_warning`])
 //!
 //! Behavior-preserving move: the Board window loaders live in `board.rs`,
 //! Knowledge loaders in `knowledge.rs`.
@@ -14,23 +16,61 @@
 use std::path::Path;
 
 use super::{
-    spawn_branch_load_async, spawn_remote_start_work_branches_async, AppRuntime, BackendEvent,
-    OutboundEvent, WindowPreset,
+    spawn_branch_hydration_async, spawn_branch_load_async, spawn_remote_start_work_branches_async,
+    AppRuntime, BackendEvent, OutboundEvent, WindowPreset,
 };
 
-/// Read the active canonical log file via the SPEC-1924 FR-035 reader.
-///
-/// Returns the decoded snapshot together with `ReadDiagnostics` so the caller
-/// can surface a non-blocking warning when malformed lines were skipped
-/// (FR-036 / SC-010). IO errors other than `NotFound` are forwarded as a
-/// human-readable message so the Logs window can switch to an error state
-/// without crashing the agent.
-pub(super) fn load_log_entries_from_dir(
-    log_dir: &Path,
-) -> Result<gwt_core::logging::ReadOutcome, String> {
-    let path = gwt_core::logging::current_log_file(log_dir);
-    gwt_core::logging::read_log_file(&path)
-        .map_err(|error| format!("Failed to read log file {}: {error}", path.display()))
+/// Logs window snapshot kept between loads. The first load decodes today's
+/// file; later loads only decode the lines appended since, through
+/// [`gwt_core::logging::LogStreamReader`], instead of re-parsing a
+/// multi-megabyte file on every refresh. A new day (or a replaced file)
+/// starts the snapshot over.
+#[derive(Debug, Default)]
+pub(crate) struct LogSnapshotCache {
+    reader: Option<gwt_core::logging::LogStreamReader>,
+    entries: Vec<gwt_core::logging::LogEvent>,
+}
+
+impl LogSnapshotCache {
+    /// Catch up with the active canonical log file in `log_dir` and return
+    /// the whole snapshot together with its `ReadDiagnostics`, so the caller
+    /// can surface a non-blocking warning when malformed lines were skipped
+    /// (FR-036 / SC-010). IO errors other than `NotFound` are forwarded as a
+    /// human-readable message so the Logs window can switch to an error
+    /// state without crashing the agent.
+    pub(super) fn refresh(
+        &mut self,
+        log_dir: &Path,
+    ) -> Result<gwt_core::logging::ReadOutcome, String> {
+        let reader = self
+            .reader
+            .get_or_insert_with(|| gwt_core::logging::LogStreamReader::follow(log_dir, 0));
+        // After a UTC rollover the first read drains the old file and the
+        // second starts the snapshot over on the new one.
+        for _ in 0..2 {
+            let chunk = reader.read_available().map_err(|error| {
+                format!(
+                    "Failed to read log file {}: {error}",
+                    reader.path().display()
+                )
+            })?;
+            if chunk.reset {
+                self.entries.clear();
+            }
+            self.entries
+                .extend(chunk.entries.into_iter().map(|entry| entry.event));
+            if reader.path() == gwt_core::logging::current_log_file(log_dir) {
+                break;
+            }
+        }
+        Ok(gwt_core::logging::ReadOutcome {
+            entries: self.entries.clone(),
+            diagnostics: gwt_core::logging::ReadDiagnostics {
+                path: reader.path().to_path_buf(),
+                skipped: reader.skipped(),
+            },
+        })
+    }
 }
 
 /// Build the synthetic warning event surfaced when `read_log_file` skipped
@@ -104,6 +144,48 @@ impl AppRuntime {
         Vec::new()
     }
 
+    /// Hydrate the `branches` rows of a lazy Branches load. Replies
+    /// asynchronously with `Partial` branch_entries chunks.
+    pub(crate) fn hydrate_branches_events(
+        &self,
+        client_id: &str,
+        id: &str,
+        branches: Vec<String>,
+        load_id: u64,
+    ) -> Vec<OutboundEvent> {
+        let Some(address) = self.window_lookup.get(id) else {
+            return vec![OutboundEvent::reply(
+                client_id,
+                BackendEvent::BranchError {
+                    id: id.to_string(),
+                    message: "Window not found".to_string(),
+                },
+            )];
+        };
+        let Some(tab) = self.tab(&address.tab_id) else {
+            return vec![OutboundEvent::reply(
+                client_id,
+                BackendEvent::BranchError {
+                    id: id.to_string(),
+                    message: "Project tab not found".to_string(),
+                },
+            )];
+        };
+        if branches.is_empty() {
+            return Vec::new();
+        }
+        spawn_branch_hydration_async(
+            self.proxy.clone(),
+            id.to_string(),
+            tab.project_root.clone(),
+            branches,
+            self.active_session_branches_for_tab(&address.tab_id),
+            self.sessions_dir.clone(),
+            load_id,
+        );
+        Vec::new()
+    }
+
     /// SPEC-2359 US-83: serve the Workspace "Open a branch…" picker. Resolves
     /// the requesting window's project, then computes the eligible existing
     /// remote branches off the UI thread and replies with
@@ -178,7 +260,8 @@ impl AppRuntime {
             )];
         }
 
-        match load_log_entries_from_dir(&self.log_dir) {
+        let loaded = self.log_snapshot_cache.borrow_mut().refresh(&self.log_dir);
+        match loaded {
             Ok(outcome) => {
                 let mut events = vec![OutboundEvent::reply(
                     client_id,
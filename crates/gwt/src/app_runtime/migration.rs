@@ -7,13 +7,16 @@
 use std::path::Path;
 
 use gwt::ProjectKind;
-use gwt_core::migration::{MigrationOptions, MigrationPhase, RecoveryState};
+use gwt_core::{
+    migration::{MigrationOptions, MigrationPhase, RecoveryState},
+    operation_progress::{OperationKind, OperationState},
+};
 
 use crate::UserEvent;
 
 use super::{
-    load_restored_workspace_state, recovery_state_label, AppRuntime, BackendEvent, OutboundEvent,
-    WindowCanvasState,
+    load_restored_workspace_state, recovery_state_label, workspace::start_operation_tracker,
+    AppRuntime, BackendEvent, OutboundEvent, WindowCanvasState,
 };
 
 impl AppRuntime {
@@ -31,10 +34,17 @@ impl AppRuntime {
         std::thread::spawn(move || {
             let progress_tab = tab_id_owned.clone();
             let progress_proxy = proxy.clone();
+            // Not cancellable: stopping between phases would leave a
+            // half-converted repository; errors already roll back.
+            let mut tracker =
+                start_operation_tracker(OperationKind::Migration, &project_root, false);
             let outcome = gwt::migration::execute_migration(
                 &project_root,
                 MigrationOptions::default(),
-                move |phase, percent| {
+                |phase, percent| {
+                    if let Some(tracker) = tracker.as_mut() {
+                        tracker.update(phase.as_str(), percent);
+                    }
                     progress_proxy.send(UserEvent::MigrationProgress {
                         tab_id: progress_tab.clone(),
                         phase,
@@ -43,22 +53,56 @@ impl AppRuntime {
                 },
             );
             match outcome {
-                Ok(result) => proxy.send(UserEvent::MigrationDone {
-                    tab_id: tab_id_owned,
-                    branch_worktree_path: result.branch_worktree_path,
-                }),
-                Err(error) => proxy.send(UserEvent::MigrationError {
-                    tab_id: tab_id_owned,
-                    phase: error.phase,
-                    message: error.message,
-                    recovery: error.recovery,
-                }),
+                Ok(result) => {
+                    if let Some(tracker) = tracker {
+                        tracker.finish(
+                            OperationState::Completed,
+                            Some(result.branch_worktree_path.display().to_string()),
+                        );
+                    }
+                    proxy.send(UserEvent::MigrationDone {
+                        tab_id: tab_id_owned,
+                        branch_worktree_path: result.branch_worktree_path,
+                    })
+                }
+                Err(error) => {
+                    if let Some(tracker) = tracker {
+                        tracker.finish(
+                            OperationState::Failed,
+                            Some(format!("{}: {}", error.phase, error.message)),
+                        );
+                    }
+                    proxy.send(UserEvent::MigrationError {
+                        tab_id: tab_id_owned,
+                        phase: error.phase,
+                        message: error.message,
+                        recovery: error.recovery,
+                    })
+                }
             }
         });
 
         Vec::new()
     }
 
+    /// The confirmation modal asks for a dry run; the report is computed off
+    /// the event loop because it walks the whole project for its size.
+    pub(crate) fn migration_dry_run_events(&mut self, tab_id: &str) -> Vec<OutboundEvent> {
+        let Some(tab) = self.tabs.iter().find(|tab| tab.id == tab_id) else {
+            return Vec::new();
+        };
+        let project_root = tab.project_root.clone();
+        let proxy = self.proxy.clone();
+        let tab_id = tab_id.to_string();
+        self.blocking_tasks.spawn(move || {
+            let report = gwt::migration::dry_run_migration(&project_root);
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::broadcast(
+                BackendEvent::MigrationReport { tab_id, report },
+            )]));
+        });
+        Vec::new()
+    }
+
     /// SPEC-1934 US-6.7: user dismissed the modal. Drop the in-memory flag so
     /// the rest of the GUI proceeds without further detection events.
     pub(crate) fn skip_migration_events(&mut self, tab_id: &str) -> Vec<OutboundEvent> {
@@ -93,6 +93,7 @@ struct RuntimeStopThreads {
 }
 
 mod attachments;
+mod background_fetch;
 mod board;
 mod file_windows;
 mod frontend_action_log;
@@ -111,6 +112,7 @@ mod settings_update;
 mod startup;
 mod title_sync;
 mod ui_trace;
+mod watchdog;
 mod window;
 mod wizard;
 mod workspace;
@@ -125,7 +127,7 @@ use attachments::{
 pub use board::BoardPostRequest;
 #[cfg(test)]
 use frontend_action_log::frontend_user_action_log;
-use frontend_action_log::log_frontend_user_action;
+use frontend_action_log::{log_frontend_user_action, record_frontend_insights};
 use knowledge::knowledge_error_event;
 #[cfg(test)]
 use knowledge::KnowledgeRefreshTask;
@@ -141,7 +143,7 @@ use launch::{
 use launch::{launch_config_from_persisted_session, IssueBranchLinkStore};
 pub use launch::{AgentLaunchResult, LaunchWizardMemoryCache, ProcessLaunch};
 #[cfg(test)]
-use loaders::{load_log_entries_from_dir, skipped_lines_warning};
+use loaders::skipped_lines_warning;
 use profile::ProfileSaveRequest;
 #[cfg(test)]
 use project_tabs::parse_github_repository_search_results;
@@ -150,6 +152,7 @@ use project_tabs::recovery_state_label;
 use settings_update::{os_url_open_command, validate_server_url, validate_update_log_path};
 use startup::mark_auto_resume_source_completed;
 use ui_trace::save_ui_trace_to_log_dir;
+use workspace::RebaseAction;
 use workspace::{
     active_agent_summary_from_session, merge_active_sessions_into_projection,
     retain_live_workspace_agents, save_shell_work_projection, save_workspace_launch_projection,
@@ -481,6 +484,14 @@ pub struct AppRuntime {
     pub(crate) tabs: Vec<ProjectTabRuntime>,
     pub(crate) active_tab_id: Option<String>,
     pub(crate) recent_projects: Vec<gwt::RecentProjectEntry>,
+    pub(crate) registered_projects: Vec<gwt::RecentProjectEntry>,
+    /// Recent / registered projects that are second clones of another
+    /// entry's origin remote; filled by `spawn_duplicate_project_scan`.
+    pub(crate) duplicate_projects: Vec<gwt::repo_registry::DuplicateProject>,
+    /// Output of `command` footer widgets, shared by every window.
+    pub(crate) footer_command_cache: Arc<Mutex<gwt::footer_widgets::FooterCommandCache>>,
+    /// Opt-in local usage counters (`[insights] enabled`); a no-op when off.
+    pub(crate) insights: gwt::insights::InsightsRecorder,
     pub(crate) profile_selections: HashMap<String, String>,
     pub(crate) profile_config_path: Option<PathBuf>,
     pub(crate) runtimes: HashMap<String, WindowRuntime>,
@@ -553,6 +564,9 @@ pub struct AppRuntime {
     /// the projection build path).
     pub(crate) local_worktree_branches:
         std::cell::RefCell<HashMap<PathBuf, std::collections::HashSet<String>>>,
+    /// Logs window snapshot of today's log file; reloads decode only the
+    /// lines appended since the previous load.
+    pub(crate) log_snapshot_cache: std::cell::RefCell<loaders::LogSnapshotCache>,
     pub(crate) window_pty_statuses: HashMap<String, WindowProcessStatus>,
     pub(crate) window_hook_states: HashMap<String, WindowProcessStatus>,
     pub(crate) recoverable_agent_error_windows: HashSet<String>,
@@ -706,6 +720,14 @@ impl AppRuntime {
             recent_projects: prune_missing_recent_projects(dedupe_recent_projects(
                 normalize_recent_projects(persisted.recent_projects),
             )),
+            registered_projects: gwt::repo_registry::registered_project_entries(
+                &gwt_config::Settings::load()
+                    .map(|settings| settings.repositories)
+                    .unwrap_or_default(),
+            ),
+            duplicate_projects: Vec::new(),
+            footer_command_cache: Arc::default(),
+            insights: gwt::insights::InsightsRecorder::from_settings(),
             profile_selections: HashMap::new(),
             profile_config_path: None,
             runtimes: HashMap::new(),
@@ -739,6 +761,7 @@ impl AppRuntime {
             ),
             last_work_events_ingest: std::cell::RefCell::new(HashMap::new()),
             local_worktree_branches: std::cell::RefCell::new(HashMap::new()),
+            log_snapshot_cache: Default::default(),
             window_pty_statuses: HashMap::new(),
             window_hook_states: HashMap::new(),
             recoverable_agent_error_windows: HashSet::new(),
@@ -760,6 +783,7 @@ impl AppRuntime {
         app.rebuild_window_lookup();
         app.seed_window_pty_statuses();
         app.seed_restored_window_details();
+        app.spawn_duplicate_project_scan();
         Ok(app)
     }
 
@@ -987,7 +1011,8 @@ impl AppRuntime {
     }
 
     /// SPEC-3075: resolve every branch's PR title off the UI thread in ONE
-    /// `gh pr list` call (the GitHub API may paginate), then hand the
+    /// `gh pr list` call (the GitHub API may paginate; GitLab origins use
+    /// `glab mr list` via [`gwt_git::ReviewProvider`]), then hand the
     /// `branch -> title` map to the event loop via [`UserEvent::WorkPrTitles`].
     /// The PR title is the human-written purpose of the work — the strongest
     /// "what work was running" signal. Network-dependent: an empty map (offline
@@ -997,7 +1022,7 @@ impl AppRuntime {
         let proxy = self.proxy.clone();
         thread::spawn(move || {
             let pr_titles =
-                gwt_git::pr_status::fetch_pr_titles_by_branch(&project_root).unwrap_or_default();
+                gwt_git::fetch_review_titles_by_branch(&project_root).unwrap_or_default();
             if pr_titles.is_empty() {
                 return;
             }
@@ -1042,12 +1067,19 @@ impl AppRuntime {
             if inputs.is_empty() {
                 return;
             }
-            let Ok(client) =
-                gwt_ai::AIClient::new(&ai.endpoint, ai.api_key.as_deref().unwrap_or(""), &ai.model)
-            else {
+            let Ok(client) = gwt_ai::build_provider(
+                &ai.provider,
+                &ai.endpoint,
+                ai.api_key.as_deref().unwrap_or(""),
+                &ai.model,
+            ) else {
                 return;
             };
-            let Ok(ai_summaries) = gwt_ai::summarize_work_purposes(&client, &inputs) else {
+            let Ok(ai_summaries) = gwt_ai::run_ai_job(
+                format!("Work summaries ({} branches)", inputs.len()),
+                gwt_ai::AiJobPriority::Background,
+                || gwt_ai::summarize_work_purposes(client.as_ref(), &inputs),
+            ) else {
                 return;
             };
             if ai_summaries.is_empty() {
@@ -1793,6 +1825,7 @@ impl AppRuntime {
         event: FrontendEvent,
     ) -> Vec<OutboundEvent> {
         log_frontend_user_action(&client_id, &event);
+        record_frontend_insights(&mut self.insights, &event);
         match event {
             FrontendEvent::FrontendReady => {
                 // SPEC-2970: kick an immediate usage poll on connect so the
@@ -1808,6 +1841,10 @@ impl AppRuntime {
                 self.set_claude_account_usage_enabled_events(enabled)
             }
             FrontendEvent::RefreshUsage => self.request_usage_refresh_events(),
+            FrontendEvent::CancelAiJob { job_id } => {
+                gwt_ai::global_queue().cancel(job_id);
+                Vec::new()
+            }
             FrontendEvent::StartupAutoResumeReady { bounds } => {
                 self.startup_auto_resume_ready_events(bounds)
             }
@@ -1824,6 +1861,12 @@ impl AppRuntime {
             FrontendEvent::ReopenRecentProject { path } => {
                 self.open_project_path_events(PathBuf::from(path))
             }
+            FrontendEvent::ConsolidateDuplicateProject { path } => {
+                self.consolidate_duplicate_project_events(&client_id, &path)
+            }
+            FrontendEvent::DropProjectFolder { path, bounds } => {
+                self.drop_project_folder_events(&client_id, PathBuf::from(path), bounds)
+            }
             FrontendEvent::SelectProjectTab { tab_id } => self.select_project_tab_events(&tab_id),
             FrontendEvent::CloseProjectTab { tab_id } => self.close_project_tab_events(&tab_id),
             FrontendEvent::CreateWindow { preset, bounds } => {
@@ -2005,7 +2048,46 @@ impl AppRuntime {
                     hex_byte,
                 ),
             )],
+            FrontendEvent::LoadGitChanges { id } => vec![OutboundEvent::reply(
+                client_id,
+                self.load_git_changes_event(&id),
+            )],
+            FrontendEvent::LoadFileDiff { id, path, staged } => vec![OutboundEvent::reply(
+                client_id,
+                self.load_file_diff_event(&id, &path, staged),
+            )],
+            FrontendEvent::ToggleDiffHunk {
+                id,
+                path,
+                staged,
+                hunk_index,
+            } => self
+                .toggle_diff_hunk_events(&id, &path, staged, hunk_index)
+                .into_iter()
+                .map(|event| OutboundEvent::reply(client_id.clone(), event))
+                .collect(),
+            FrontendEvent::SuggestCommitMessage { id } => {
+                self.suggest_commit_message_events(&client_id, &id)
+            }
+            FrontendEvent::CreateCommit {
+                id,
+                paths,
+                message,
+                signoff,
+                gpg_sign,
+            } => self.create_commit_events(
+                &client_id,
+                &id,
+                paths,
+                message,
+                gwt_git::commit::CommitOptions { signoff, gpg_sign },
+            ),
             FrontendEvent::LoadBranches { id } => self.load_branches_events(&client_id, &id),
+            FrontendEvent::HydrateBranches {
+                id,
+                branches,
+                load_id,
+            } => self.hydrate_branches_events(&client_id, &id, branches, load_id),
             FrontendEvent::RequestRemoteStartWorkBranches { id } => {
                 self.request_remote_start_work_branches_events(&client_id, &id)
             }
@@ -2111,13 +2193,78 @@ impl AppRuntime {
                 branches,
                 delete_remote,
                 force_filesystem_delete,
+                confirmed_protected,
             } => self.run_branch_cleanup_events(
                 &client_id,
                 &id,
                 &branches,
-                delete_remote,
-                force_filesystem_delete,
+                BranchCleanupOptions {
+                    delete_remote,
+                    force_filesystem_delete,
+                    confirmed_protected,
+                },
             ),
+            FrontendEvent::OpenWorktreePath { id, path, target } => {
+                self.open_worktree_path_events(&client_id, &id, &path, target)
+            }
+            FrontendEvent::StashWorktree { id, path, action } => {
+                self.stash_worktree_events(&client_id, &id, &path, action)
+            }
+            FrontendEvent::ArchiveWorktrees { id, branches } => {
+                self.archive_worktrees_events(&client_id, &id, branches)
+            }
+            FrontendEvent::RestoreWorktree { id, branch } => {
+                self.restore_worktree_events(&client_id, &id, branch)
+            }
+            FrontendEvent::RepairWorktree { id, branch } => {
+                self.repair_worktree_events(&client_id, &id, branch)
+            }
+            FrontendEvent::TransplantWorktree {
+                id,
+                branch,
+                new_branch,
+            } => self.transplant_worktree_events(&client_id, &id, branch, new_branch),
+            FrontendEvent::DownloadCiArtifacts {
+                id,
+                branch,
+                pattern,
+            } => self.download_ci_artifacts_events(&client_id, &id, branch, pattern),
+            FrontendEvent::RebaseBranch { id, branch, onto } => {
+                self.rebase_branch_events(&client_id, &id, branch, RebaseAction::Start { onto })
+            }
+            FrontendEvent::ContinueRebase { id, branch } => {
+                self.rebase_branch_events(&client_id, &id, branch, RebaseAction::Continue)
+            }
+            FrontendEvent::AbortRebase { id, branch } => {
+                self.rebase_branch_events(&client_id, &id, branch, RebaseAction::Abort)
+            }
+            FrontendEvent::ResolveRebaseConflicts { id, branch } => {
+                self.open_rebase_resolution_wizard(&client_id, &id, &branch)
+            }
+            FrontendEvent::LoadBranchCiStatus { id } => {
+                self.load_branch_ci_status_events(&client_id, &id)
+            }
+            FrontendEvent::CreatePullRequest { id, branch } => {
+                self.create_pull_request_events(&client_id, &id, branch)
+            }
+            FrontendEvent::DetectExternalAgents { id } => {
+                self.detect_external_agents_events(&client_id, &id)
+            }
+            FrontendEvent::AdoptExternalAgent { id, pid, branch } => {
+                self.adopt_external_agent_events(&client_id, &id, pid, branch)
+            }
+            FrontendEvent::LoadStaleRemoteBranches { id, min_age_days } => {
+                self.load_stale_remote_branches_events(&client_id, &id, min_age_days)
+            }
+            FrontendEvent::DeleteStaleRemoteBranches { id, branches } => {
+                self.delete_stale_remote_branches_events(&client_id, &id, branches)
+            }
+            FrontendEvent::LoadRemovedWorktrees { id } => {
+                self.load_removed_worktrees_events(&client_id, &id)
+            }
+            FrontendEvent::UndoWorktreeRemoval { id, branch } => {
+                self.undo_worktree_removal_events(&client_id, &id, branch)
+            }
             FrontendEvent::RunWorkspaceCleanup {
                 branch,
                 delete_remote,
@@ -2185,6 +2332,7 @@ impl AppRuntime {
                 description,
                 env_vars,
                 disabled_env,
+                secret_env,
             } => self.save_profile_events(
                 &client_id,
                 &id,
@@ -2194,6 +2342,7 @@ impl AppRuntime {
                     description,
                     env_vars,
                     disabled_env,
+                    secret_env,
                 },
             ),
             FrontendEvent::DeleteProfile { id, profile_name } => {
@@ -2467,6 +2616,7 @@ impl AppRuntime {
                 gwt::agent_backend_dispatch::test_connection_event(agent, &base_url, &api_key),
             )],
             FrontendEvent::StartMigration { tab_id } => self.start_migration_events(&tab_id),
+            FrontendEvent::MigrationDryRun { tab_id } => self.migration_dry_run_events(&tab_id),
             FrontendEvent::SkipMigration { tab_id } => self.skip_migration_events(&tab_id),
             FrontendEvent::QuitMigration { tab_id } => self.quit_migration_events(&tab_id),
             FrontendEvent::GetSystemSettings => self.system_settings_get_events(client_id),
@@ -2519,7 +2669,13 @@ impl AppRuntime {
                 codex_trust_managed_hooks,
                 board_provider,
             ),
+            FrontendEvent::ApplyAiPreset { preset } => {
+                self.spawn_ai_preset_apply(client_id, preset)
+            }
             FrontendEvent::GetAutostartStatus => self.autostart_status_events(client_id),
+            FrontendEvent::RefreshFooterWidget { index } => {
+                self.footer_widget_refresh_events(&client_id, index)
+            }
             FrontendEvent::UpdateAutostart { enabled } => {
                 self.autostart_update_events(client_id, enabled)
             }
@@ -2583,6 +2739,10 @@ impl AppRuntime {
         if let Some(event) = self.active_work_projection_reply(client_id) {
             events.insert(1, event);
         }
+        events.insert(1, keybindings_reply(client_id));
+        events.insert(1, theme_palettes_reply(client_id));
+        events.insert(1, footer_widgets_reply(client_id));
+        events.insert(1, refresh_budget_reply(client_id));
         self.schedule_active_improvement_candidates_refresh();
         // SPEC-1934 US-6.1: surface pending migrations to a newly-connected
         // frontend during state hydration so the modal opens without waiting
@@ -2593,6 +2753,158 @@ impl AppRuntime {
     }
 }
 
+/// Global shortcuts resolved from `[keybindings]`, so the frontend rebinds
+/// before the first keypress. Unreadable settings resolve to the defaults.
+fn keybindings_reply(client_id: &str) -> OutboundEvent {
+    let resolved = gwt_config::Settings::load()
+        .unwrap_or_default()
+        .keybindings
+        .resolve();
+    if !resolved.errors.is_empty() {
+        tracing::warn!(errors = ?resolved.errors, "ignored [keybindings] entries");
+    }
+    OutboundEvent::reply(
+        client_id,
+        BackendEvent::Keybindings {
+            bindings: resolved.bindings,
+            errors: resolved.errors,
+        },
+    )
+}
+
+/// User palettes resolved from `[theme.palettes]`, so a stored custom
+/// palette preference applies as soon as the frontend connects.
+fn theme_palettes_reply(client_id: &str) -> OutboundEvent {
+    let resolved = gwt_config::Settings::load()
+        .unwrap_or_default()
+        .theme
+        .resolve();
+    if !resolved.errors.is_empty() {
+        tracing::warn!(errors = ?resolved.errors, "ignored [theme.palettes] entries");
+    }
+    OutboundEvent::reply(
+        client_id,
+        BackendEvent::ThemePalettes {
+            palettes: resolved.palettes,
+            errors: resolved.errors,
+        },
+    )
+}
+
+/// Status strip widgets resolved from `[[footer.widgets]]`, so each window
+/// starts its refresh timers on connect.
+fn footer_widgets_reply(client_id: &str) -> OutboundEvent {
+    let resolved = gwt_config::Settings::load()
+        .unwrap_or_default()
+        .footer
+        .resolve();
+    if !resolved.errors.is_empty() {
+        tracing::warn!(errors = ?resolved.errors, "ignored [[footer.widgets]] entries");
+    }
+    OutboundEvent::reply(
+        client_id,
+        BackendEvent::FooterWidgets {
+            widgets: resolved.widgets,
+            errors: resolved.errors,
+        },
+    )
+}
+
+fn refresh_budget_reply(client_id: &str) -> OutboundEvent {
+    let refresh = gwt_config::Settings::load()
+        .unwrap_or_default()
+        .refresh
+        .clamped();
+    OutboundEvent::reply(
+        client_id,
+        BackendEvent::RefreshBudget {
+            idle_after_secs: refresh.idle_after_secs,
+            idle_backoff: refresh.idle_backoff,
+            pause_when_hidden: refresh.pause_when_hidden,
+        },
+    )
+}
+
+impl AppRuntime {
+    /// Value of the `profile` or `command` widget at `index`. Command output
+    /// is served from the shared cache while it is younger than the widget's
+    /// `refresh_secs`; otherwise the command runs on a blocking task in the
+    /// active project.
+    pub(crate) fn footer_widget_refresh_events(
+        &self,
+        client_id: &str,
+        index: usize,
+    ) -> Vec<OutboundEvent> {
+        let settings = gwt_config::Settings::load().unwrap_or_default();
+        let reply = |value: Option<String>, error: Option<String>| {
+            vec![OutboundEvent::reply(
+                client_id,
+                BackendEvent::FooterWidgetValue {
+                    index,
+                    value,
+                    error,
+                },
+            )]
+        };
+        let Some(widget) = settings
+            .footer
+            .resolve()
+            .widgets
+            .into_iter()
+            .find(|widget| widget.index == index)
+        else {
+            return reply(None, Some(format!("no footer widget at {index}")));
+        };
+        let command = match (widget.kind, widget.command) {
+            (gwt_config::FooterWidgetKind::Profile, _) => {
+                let mut profiles = settings.profiles;
+                return reply(Some(profiles.normalize_active_profile().name), None);
+            }
+            (gwt_config::FooterWidgetKind::Command, Some(command)) => command,
+            _ => return reply(None, Some("widget is rendered locally".to_string())),
+        };
+        let max_age = Duration::from_secs(widget.refresh_secs);
+        if let Some(output) = self
+            .footer_command_cache
+            .lock()
+            .ok()
+            .and_then(|cache| cache.fresh(index, &command, max_age).map(str::to_string))
+        {
+            return reply(Some(output), None);
+        }
+
+        let cache = Arc::clone(&self.footer_command_cache);
+        let cwd = self.active_project_root().map(Path::to_path_buf);
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        self.blocking_tasks.spawn(move || {
+            let result = gwt::footer_widgets::run_widget_command(
+                &command,
+                cwd.as_deref(),
+                gwt::footer_widgets::FOOTER_COMMAND_TIMEOUT,
+            );
+            if let Ok(output) = &result {
+                if let Ok(mut cache) = cache.lock() {
+                    cache.store(index, &command, output);
+                }
+            }
+            let (value, error) = match result {
+                Ok(output) => (Some(output), None),
+                Err(error) => (None, Some(error)),
+            };
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id,
+                BackendEvent::FooterWidgetValue {
+                    index,
+                    value,
+                    error,
+                },
+            )]));
+        });
+        Vec::new()
+    }
+}
+
 impl AppRuntime {
     pub(crate) fn app_state_view(&self) -> gwt::AppStateView {
         gwt::AppStateView {
@@ -2625,6 +2937,24 @@ impl AppRuntime {
                     kind: project.kind,
                 })
                 .collect(),
+            registered_projects: self
+                .registered_projects
+                .iter()
+                .map(|project| gwt::RecentProjectView {
+                    path: project.path.display().to_string(),
+                    title: project.title.clone(),
+                    kind: project.kind,
+                })
+                .collect(),
+            duplicate_projects: self
+                .duplicate_projects
+                .iter()
+                .map(|duplicate| gwt::DuplicateProjectView {
+                    path: duplicate.path.display().to_string(),
+                    canonical_path: duplicate.canonical_path.display().to_string(),
+                    remote: duplicate.remote.clone(),
+                })
+                .collect(),
         }
     }
 
@@ -20,11 +20,12 @@
 //!   dispatch arm
 //!
 //! Persistence flows through `gwt::profile_dispatch::*`, agent launch
-//! integration uses `gwt_agent::LaunchEnvironment::from_active_profile`,
+//! integration uses `gwt_agent::LaunchEnvironment::from_project_profile`
+//! (user profiles merged with the tab's `.gwt/profiles/`),
 //! and broadcasts use `BackendEvent::ProfileSnapshot` /
 //! `BackendEvent::ProfileError`.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use gwt::{profile_dispatch, ProfileEnvEntryView};
 
@@ -36,19 +37,23 @@ pub(super) struct ProfileSaveRequest {
     pub(super) description: String,
     pub(super) env_vars: Vec<ProfileEnvEntryView>,
     pub(super) disabled_env: Vec<String>,
+    pub(super) secret_env: Vec<String>,
 }
 
 impl AppRuntime {
     pub(crate) fn load_profile_events(&mut self, client_id: &str, id: &str) -> Vec<OutboundEvent> {
-        if let Err(message) = self.resolve_profile_window_context(id) {
-            return vec![OutboundEvent::reply(
-                client_id,
-                BackendEvent::ProfileError {
-                    id: id.to_string(),
-                    message,
-                },
-            )];
-        }
+        let tab_id = match self.resolve_profile_window_context(id) {
+            Ok(tab_id) => tab_id,
+            Err(message) => {
+                return vec![OutboundEvent::reply(
+                    client_id,
+                    BackendEvent::ProfileError {
+                        id: id.to_string(),
+                        message,
+                    },
+                )];
+            }
+        };
 
         let selected_profile = self.profile_selections.get(id).cloned();
         let config_path = match self.profile_config_path() {
@@ -63,7 +68,12 @@ impl AppRuntime {
                 )];
             }
         };
-        match profile_dispatch::load_snapshot_at(&config_path, selected_profile.as_deref()) {
+        let project_root = self.profile_project_root(&tab_id);
+        match profile_dispatch::load_snapshot_at(
+            &config_path,
+            project_root.as_deref(),
+            selected_profile.as_deref(),
+        ) {
             Ok(snapshot) => {
                 self.profile_selections
                     .insert(id.to_string(), snapshot.selected_profile.clone());
@@ -184,7 +194,12 @@ impl AppRuntime {
                 )];
             }
         };
-        if let Err(error) = profile_dispatch::switch_active_profile_at(&config_path, profile_name) {
+        let project_root = self.profile_project_root(&tab_id);
+        if let Err(error) = profile_dispatch::switch_active_profile_at(
+            &config_path,
+            project_root.as_deref(),
+            profile_name,
+        ) {
             return vec![OutboundEvent::reply(
                 client_id,
                 BackendEvent::ProfileError {
@@ -230,13 +245,16 @@ impl AppRuntime {
                 )];
             }
         };
+        let project_root = self.profile_project_root(&tab_id);
         if let Err(error) = profile_dispatch::save_profile_at(
             &config_path,
+            project_root.as_deref(),
             &request.current_name,
             &request.name,
             &request.description,
             &request.env_vars,
             &request.disabled_env,
+            &request.secret_env,
         ) {
             return vec![OutboundEvent::reply(
                 client_id,
@@ -283,7 +301,10 @@ impl AppRuntime {
                 )];
             }
         };
-        if let Err(error) = profile_dispatch::delete_profile_at(&config_path, profile_name) {
+        let project_root = self.profile_project_root(&tab_id);
+        if let Err(error) =
+            profile_dispatch::delete_profile_at(&config_path, project_root.as_deref(), profile_name)
+        {
             return vec![OutboundEvent::reply(
                 client_id,
                 BackendEvent::ProfileError {
@@ -336,10 +357,20 @@ impl AppRuntime {
         profile_dispatch::config_path().map_err(|error| error.to_string())
     }
 
-    pub(super) fn active_profile_spawn_env(&self) -> Result<gwt_agent::LaunchEnvironment, String> {
+    /// Project root of the tab, whose `.gwt/profiles/` is shared with the
+    /// user's profiles.
+    fn profile_project_root(&self, tab_id: &str) -> Option<PathBuf> {
+        self.tab(tab_id).map(|tab| tab.project_root.clone())
+    }
+
+    pub(super) fn active_profile_spawn_env(
+        &self,
+        project_root: Option<&Path>,
+    ) -> Result<gwt_agent::LaunchEnvironment, String> {
         let config_path = self.profile_config_path()?;
-        gwt_agent::LaunchEnvironment::from_active_profile(
+        gwt_agent::LaunchEnvironment::from_project_profile(
             &config_path,
+            project_root,
             gwt_agent::LaunchRuntimeTarget::Host,
         )
     }
@@ -351,6 +382,7 @@ impl AppRuntime {
         client_id: &str,
     ) -> Vec<OutboundEvent> {
         let window_ids = self.profile_window_ids_for_tab(tab_id);
+        let project_root = self.profile_project_root(tab_id);
         let mut events = Vec::new();
 
         for window_id in window_ids {
@@ -367,7 +399,11 @@ impl AppRuntime {
                     )];
                 }
             };
-            match profile_dispatch::load_snapshot_at(&config_path, selected_profile.as_deref()) {
+            match profile_dispatch::load_snapshot_at(
+                &config_path,
+                project_root.as_deref(),
+                selected_profile.as_deref(),
+            ) {
                 Ok(snapshot) => {
                     self.profile_selections
                         .insert(window_id.clone(), snapshot.selected_profile.clone());
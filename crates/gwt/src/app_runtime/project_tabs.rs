@@ -10,6 +10,9 @@
 //!   (`search_github_repositories`, `parse_github_repository_search_results`)
 //! - Project tab selection / close ([`AppRuntime::select_project_tab_events`],
 //!   [`AppRuntime::close_project_tab_events`]) and recent-project bookkeeping
+//! - Duplicate clones of one remote among the recent / registered projects
+//!   ([`AppRuntime::spawn_duplicate_project_scan`],
+//!   [`AppRuntime::consolidate_duplicate_project_events`])
 //! - SPEC-1934 migration detection broadcasts / replies
 //!   (`recovery_state_label` stays re-exported through `mod.rs` for
 //!   `migration.rs`)
@@ -22,7 +25,8 @@ use std::path::{Path, PathBuf};
 use super::{
     combined_window_id, load_restored_workspace_state, normalize_recent_project_path,
     resolve_project_target, same_worktree_path, AppRuntime, BackendEvent, OutboundEvent,
-    ProjectOpenTarget, ProjectTabRuntime, UserEvent, Uuid, WindowCanvasState,
+    ProjectOpenTarget, ProjectTabRuntime, UserEvent, Uuid, WindowCanvasState, WindowGeometry,
+    WindowPreset,
 };
 
 pub(super) fn recovery_state_label(recovery: gwt_core::migration::RecoveryState) -> &'static str {
@@ -300,6 +304,63 @@ impl AppRuntime {
         }
     }
 
+    /// A folder dropped onto the window. It goes through the same repo-type
+    /// detection as startup ([`resolve_project_target`]): a Git workspace
+    /// (normal, which may raise the migration modal, or bare) opens as a
+    /// project tab with its Branches window brought up at `bounds`; any
+    /// other folder is not registered and the frontend offers the clone
+    /// wizard with the folder as destination.
+    pub(crate) fn drop_project_folder_events(
+        &mut self,
+        client_id: &str,
+        path: PathBuf,
+        bounds: WindowGeometry,
+    ) -> Vec<OutboundEvent> {
+        let target = match resolve_project_target(&path) {
+            Ok(target) => target,
+            Err(message) => {
+                return vec![OutboundEvent::broadcast(BackendEvent::ProjectOpenError {
+                    message,
+                })]
+            }
+        };
+        if target.kind == gwt::ProjectKind::NonRepo {
+            return vec![OutboundEvent::reply(
+                client_id,
+                BackendEvent::ProjectDropNeedsClone {
+                    path: target.project_root.display().to_string(),
+                },
+            )];
+        }
+        let mut events = self.open_project_path_events(path);
+        let opened = self
+            .active_project_root()
+            .is_some_and(|root| same_worktree_path(root, &target.project_root));
+        if !opened {
+            return events;
+        }
+        events.extend(self.show_branches_window_events(bounds));
+        events
+    }
+
+    /// Focus the active tab's Branches window, creating one when the tab
+    /// has none.
+    fn show_branches_window_events(&mut self, bounds: WindowGeometry) -> Vec<OutboundEvent> {
+        let existing = self.active_tab_id.as_ref().and_then(|tab_id| {
+            let tab = self.tab(tab_id)?;
+            tab.workspace
+                .persisted()
+                .windows
+                .iter()
+                .find(|window| window.preset == WindowPreset::Branches)
+                .map(|window| combined_window_id(tab_id, &window.id))
+        });
+        match existing {
+            Some(window_id) => self.focus_window_events(&window_id, Some(bounds)),
+            None => self.create_window_events(WindowPreset::Branches, bounds),
+        }
+    }
+
     pub(crate) fn handle_clone_project_done(
         &mut self,
         workspace_home: &Path,
@@ -344,6 +405,7 @@ impl AppRuntime {
         if self.recent_projects.len() > 12 {
             self.recent_projects.truncate(12);
         }
+        self.spawn_duplicate_project_scan();
     }
 
     pub(crate) fn open_project_path(&mut self, path: PathBuf) -> Result<bool, String> {
@@ -360,6 +422,10 @@ impl AppRuntime {
             return Ok(wizard_closed);
         }
 
+        // Probe once per project so network-share limitations are logged
+        // up front; callers that depend on them re-check the cached result.
+        gwt_core::fs_probe::capabilities(&target.project_root);
+
         let tab_id = format!("project-{}", Uuid::new_v4().simple());
         self.tabs.push(ProjectTabRuntime {
             id: tab_id.clone(),
@@ -479,6 +545,116 @@ impl AppRuntime {
         if self.recent_projects.len() > 12 {
             self.recent_projects.truncate(12);
         }
+        self.spawn_duplicate_project_scan();
+    }
+
+    /// Look for separate clones of one origin remote among the registered
+    /// and recent projects off the UI thread. Sends
+    /// [`UserEvent::DuplicateProjects`] only when the result changed.
+    pub(crate) fn spawn_duplicate_project_scan(&self) {
+        let projects: Vec<gwt::RecentProjectEntry> = self
+            .registered_projects
+            .iter()
+            .chain(&self.recent_projects)
+            .cloned()
+            .collect();
+        let known = self.duplicate_projects.clone();
+        let proxy = self.proxy.clone();
+        self.blocking_tasks.spawn(move || {
+            let duplicates = gwt::repo_registry::find_duplicate_projects(&projects);
+            if duplicates != known {
+                proxy.send(UserEvent::DuplicateProjects { duplicates });
+            }
+        });
+    }
+
+    pub(crate) fn apply_duplicate_projects(
+        &mut self,
+        duplicates: Vec<gwt::repo_registry::DuplicateProject>,
+    ) -> Vec<OutboundEvent> {
+        self.duplicate_projects = duplicates;
+        vec![self.workspace_state_broadcast()]
+    }
+
+    pub(crate) fn consolidate_duplicate_project_events(
+        &mut self,
+        client_id: &str,
+        path: &str,
+    ) -> Vec<OutboundEvent> {
+        let Some(duplicate) = self
+            .duplicate_projects
+            .iter()
+            .find(|duplicate| duplicate.path == Path::new(path))
+            .cloned()
+        else {
+            return vec![OutboundEvent::reply(
+                client_id,
+                BackendEvent::DuplicateProjectConsolidateError {
+                    path: path.to_string(),
+                    message: "not a duplicate of another project".to_string(),
+                },
+            )];
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        self.blocking_tasks.spawn(move || {
+            let result = gwt::repo_registry::consolidate_duplicate(
+                &duplicate.path,
+                &duplicate.canonical_path,
+            );
+            proxy.send(UserEvent::DuplicateProjectConsolidated {
+                client_id,
+                duplicate,
+                result,
+            });
+        });
+        Vec::new()
+    }
+
+    /// Drop a consolidated duplicate from the recent history and from
+    /// `repositories` in the global config. Its files stay on disk.
+    pub(crate) fn handle_duplicate_project_consolidated(
+        &mut self,
+        client_id: String,
+        duplicate: gwt::repo_registry::DuplicateProject,
+        result: Result<gwt::repo_registry::ConsolidationOutcome, String>,
+    ) -> Vec<OutboundEvent> {
+        let path = duplicate.path.display().to_string();
+        let outcome = match result {
+            Ok(outcome) => outcome,
+            Err(message) => {
+                return vec![OutboundEvent::reply(
+                    client_id,
+                    BackendEvent::DuplicateProjectConsolidateError { path, message },
+                )];
+            }
+        };
+        let is_duplicate = |entry: &Path| same_worktree_path(entry, &duplicate.path);
+        self.recent_projects
+            .retain(|entry| !is_duplicate(&entry.path));
+        self.registered_projects
+            .retain(|entry| !is_duplicate(&entry.path));
+        self.duplicate_projects
+            .retain(|entry| entry.path != duplicate.path);
+        if let Err(error) = gwt_config::Settings::update_global(|settings| {
+            settings.repositories.retain(|entry| !is_duplicate(entry));
+            Ok(())
+        }) {
+            tracing::warn!(%error, "failed to drop consolidated duplicate from repositories");
+        }
+        let _ = self.persist();
+        vec![
+            self.workspace_state_broadcast(),
+            OutboundEvent::reply(
+                client_id,
+                BackendEvent::DuplicateProjectConsolidated {
+                    path,
+                    canonical_path: duplicate.canonical_path.display().to_string(),
+                    fetched_branches: outcome.fetched_branches,
+                    skipped_branches: outcome.skipped_branches,
+                },
+            ),
+        ]
     }
 
     pub(crate) fn select_project_tab_events(&mut self, tab_id: &str) -> Vec<OutboundEvent> {
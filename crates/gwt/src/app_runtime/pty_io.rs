@@ -320,6 +320,8 @@ impl AppRuntime {
         // kind-aware hooks (e.g. recording the PTY exit code as a
         // summary at thread end).
         let proxy = self.proxy.clone();
+        let exit_policy = self.agent_exit_policy(&id);
+        let started = Instant::now();
         thread::spawn(move || {
             let reader = match pane
                 .lock()
@@ -394,7 +396,11 @@ impl AppRuntime {
 
             match status {
                 Ok(status) => {
-                    let (status, detail) = Self::runtime_status_from_pane_status(&status);
+                    let (status, detail) = Self::runtime_status_from_pane_status(
+                        &status,
+                        started.elapsed(),
+                        exit_policy.as_ref(),
+                    );
                     proxy.send(UserEvent::RuntimeStatus { id, status, detail });
                 }
                 Err(error) => {
@@ -410,6 +416,8 @@ impl AppRuntime {
 
     pub(crate) fn spawn_status_thread(&self, id: String, pane: Arc<Mutex<Pane>>) -> JoinHandle<()> {
         let proxy = self.proxy.clone();
+        let exit_policy = self.agent_exit_policy(&id);
+        let started = Instant::now();
         thread::spawn(move || loop {
             thread::sleep(Duration::from_millis(100));
             let status = pane
@@ -424,12 +432,16 @@ impl AppRuntime {
             match status {
                 Ok(PaneStatus::Running) => continue,
                 Ok(status) => {
-                    if matches!(status, PaneStatus::Completed(_)) {
+                    if matches!(status, PaneStatus::Completed(_) | PaneStatus::Signaled(_)) {
                         if let Ok(pane) = pane.lock() {
                             let _ = pane.kill();
                         }
                     }
-                    let (status, detail) = Self::runtime_status_from_pane_status(&status);
+                    let (status, detail) = Self::runtime_status_from_pane_status(
+                        &status,
+                        started.elapsed(),
+                        exit_policy.as_ref(),
+                    );
                     proxy.send(UserEvent::RuntimeStatus { id, status, detail });
                     break;
                 }
@@ -445,23 +457,36 @@ impl AppRuntime {
         })
     }
 
+    /// Exit policy for an agent window, from `[agent.exit_policies]`.
+    /// `None` for windows without an agent session.
+    fn agent_exit_policy(&self, id: &str) -> Option<gwt_config::AgentExitPolicy> {
+        let session = self.active_agent_sessions.get(id)?;
+        let settings = gwt_config::Settings::load().unwrap_or_default();
+        Some(settings.agent.exit_policy(&session.agent_id))
+    }
+
     fn runtime_status_from_pane_status(
         status: &PaneStatus,
+        uptime: Duration,
+        exit_policy: Option<&gwt_config::AgentExitPolicy>,
     ) -> (WindowProcessStatus, Option<String>) {
+        let state = match exit_policy {
+            Some(policy) => {
+                gwt::window_state::agent_window_state_from_pane_status(status, uptime, policy)
+            }
+            None => gwt::window_state::window_state_from_pane_status(status),
+        };
         match status {
             PaneStatus::Running => (WindowProcessStatus::Running, None),
-            PaneStatus::Completed(0) => (
-                gwt::window_state::window_state_from_pane_status(status),
-                Some("Process exited".to_string()),
-            ),
-            PaneStatus::Completed(code) => (
-                gwt::window_state::window_state_from_pane_status(status),
-                Some(format!("Process exited with status {code}")),
-            ),
-            PaneStatus::Error(message) => (
-                gwt::window_state::window_state_from_pane_status(status),
-                Some(message.clone()),
+            PaneStatus::Completed(0) => (state, Some("Process exited".to_string())),
+            PaneStatus::Completed(code) => {
+                (state, Some(format!("Process exited with status {code}")))
+            }
+            PaneStatus::Signaled(signal) => (
+                state,
+                Some(format!("Process terminated by signal: {signal}")),
             ),
+            PaneStatus::Error(message) => (state, Some(message.clone())),
         }
     }
 }
@@ -297,6 +297,16 @@ impl AppRuntime {
         }
         self.window_hook_states
             .insert(window_id.clone(), hook_state);
+        if is_waiting_input_hook(&event, hook_state) {
+            let session = self.active_agent_sessions.get(&window_id);
+            gwt_core::activity_bus::publish(
+                gwt_core::activity_bus::ActivityEvent::AgentWaitingInput {
+                    session_id: session.map(|session| session.session_id.clone()),
+                    agent_id: session.map(|session| session.agent_id.clone()),
+                    window_id: window_id.clone(),
+                },
+            );
+        }
         let Some(composed_state) = self.recompute_window_state(&window_id) else {
             return events;
         };
@@ -378,6 +388,14 @@ impl AppRuntime {
     }
 }
 
+/// The agent is blocked on the user: an explicit waiting status, or the end
+/// of a turn (`Stop`).
+fn is_waiting_input_hook(event: &gwt::RuntimeHookEvent, hook_state: WindowProcessStatus) -> bool {
+    hook_state == WindowProcessStatus::Waiting
+        || (hook_state == WindowProcessStatus::Idle
+            && event.source_event.as_deref() == Some("Stop"))
+}
+
 #[cfg(unix)]
 const RUNTIME_DAEMON_PUBLISH_QUEUE_CAPACITY: usize = 4096;
 
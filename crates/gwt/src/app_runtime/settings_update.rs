@@ -555,10 +555,13 @@ impl AppRuntime {
                 )];
             }
         };
-        vec![OutboundEvent::reply(
-            client_id,
-            gwt::system_settings::get_event(&path),
-        )]
+        vec![
+            OutboundEvent::reply(client_id.clone(), gwt::system_settings::get_event(&path)),
+            OutboundEvent::reply(
+                client_id,
+                gwt::system_settings::config_diagnostics_event(&path),
+            ),
+        ]
     }
 
     pub(super) fn system_settings_update_events(
@@ -657,6 +660,31 @@ impl AppRuntime {
         });
     }
 
+    /// Apply an AI preset off the UI thread: the Ollama preset asks the
+    /// local server for its models before writing `[ai]`.
+    pub(super) fn spawn_ai_preset_apply(
+        &self,
+        client_id: ClientId,
+        preset: String,
+    ) -> Vec<OutboundEvent> {
+        let Some(path) = gwt_config::Settings::global_config_path() else {
+            return vec![OutboundEvent::reply(
+                client_id,
+                BackendEvent::SystemSettingsError {
+                    message: "unable to resolve home directory (`~/.gwt/config.toml`)".to_string(),
+                },
+            )];
+        };
+        let proxy = self.proxy.clone();
+        self.blocking_tasks.spawn(move || {
+            let event = gwt::system_settings::ai_preset_event(&path, &preset);
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id, event,
+            )]));
+        });
+        Vec::new()
+    }
+
     pub(super) fn apply_pending_update_events(&self, client_id: &str) -> Vec<OutboundEvent> {
         match self.pending_update.clone() {
             Some(
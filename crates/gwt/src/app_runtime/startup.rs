@@ -78,6 +78,10 @@ fn startup_auto_resume_is_fresh(
 }
 
 fn startup_auto_resume_window_was_open(session: &gwt_agent::Session) -> bool {
+    // An adopted agent runs in a terminal gwt does not own.
+    if session.adopted_pid.is_some() {
+        return false;
+    }
     if session.restore_window_on_startup {
         return true;
     }
@@ -2339,6 +2339,10 @@ fn sample_runtime_with_events(
         tabs,
         active_tab_id: active_tab_id.map(str::to_owned),
         recent_projects: Vec::new(),
+        registered_projects: Vec::new(),
+        duplicate_projects: Vec::new(),
+        footer_command_cache: Arc::default(),
+        insights: gwt::insights::InsightsRecorder::new(PathBuf::new(), false),
         profile_selections: HashMap::new(),
         profile_config_path: Some(temp_root.join("profile-config.toml")),
         runtimes: HashMap::new(),
@@ -2372,6 +2376,7 @@ fn sample_runtime_with_events(
         ),
         last_work_events_ingest: std::cell::RefCell::new(HashMap::new()),
         local_worktree_branches: std::cell::RefCell::new(HashMap::new()),
+        log_snapshot_cache: Default::default(),
         window_pty_statuses: HashMap::new(),
         window_hook_states: HashMap::new(),
         recoverable_agent_error_windows: HashSet::new(),
@@ -2666,6 +2671,10 @@ fn sample_launch_wizard_session(tab_id: &str, project_root: &Path) -> LaunchWiza
                     cleanup: BranchCleanupInfo::default(),
                     resume: gwt::BranchResumeInfo::unavailable(),
                     start_work_eligibility: None,
+                    worktree_path: None,
+                    archived: false,
+                    integrity_issues: Vec::new(),
+                    linked_spec: None,
                 },
                 normalized_branch_name: "feature/demo".to_string(),
                 worktree_path: None,
@@ -2837,6 +2846,10 @@ fn sample_no_agent_launch_wizard_session(tab_id: &str, project_root: &Path) -> L
                     cleanup: BranchCleanupInfo::default(),
                     resume: gwt::BranchResumeInfo::unavailable(),
                     start_work_eligibility: None,
+                    worktree_path: None,
+                    archived: false,
+                    integrity_issues: Vec::new(),
+                    linked_spec: None,
                 },
                 normalized_branch_name: "feature/demo".to_string(),
                 worktree_path: Some(project_root.to_path_buf()),
@@ -2878,6 +2891,10 @@ fn sample_start_work_confirm_session(tab_id: &str, project_root: &Path) -> Launc
                 cleanup: BranchCleanupInfo::default(),
                 resume: gwt::BranchResumeInfo::unavailable(),
                 start_work_eligibility: None,
+                worktree_path: None,
+                archived: false,
+                integrity_issues: Vec::new(),
+                linked_spec: None,
             },
             normalized_branch_name: work_branch.clone(),
             worktree_path: None,
@@ -2913,6 +2930,7 @@ fn sample_start_work_confirm_session(tab_id: &str, project_root: &Path) -> Launc
         quick_start_entries: Vec::new(),
         previous_profiles: Some(Default::default()),
         open_branch_candidates: Vec::new(),
+        runtime_rule: None,
     });
     wizard.apply(LaunchWizardAction::Submit);
     assert!(wizard.view().show_confirm);
@@ -2950,6 +2968,10 @@ fn sample_ready_agent_launch_wizard_session(
                     cleanup: BranchCleanupInfo::default(),
                     resume: gwt::BranchResumeInfo::unavailable(),
                     start_work_eligibility: None,
+                    worktree_path: None,
+                    archived: false,
+                    integrity_issues: Vec::new(),
+                    linked_spec: None,
                 },
                 normalized_branch_name: "feature/demo".to_string(),
                 worktree_path: Some(project_root.to_path_buf()),
@@ -3021,6 +3043,20 @@ fn app_runtime_frontend_ready_replies_only_to_requesting_client_and_starts_with_
         event.event,
         BackendEvent::UpdateState(gwt_core::update::UpdateState::UpToDate { .. })
     )));
+    assert!(events.iter().any(|event| matches!(
+        &event.event,
+        BackendEvent::Keybindings { bindings, .. } if !bindings.is_empty()
+    )));
+    assert!(events
+        .iter()
+        .any(|event| matches!(&event.event, BackendEvent::ThemePalettes { .. })));
+    assert!(events
+        .iter()
+        .any(|event| matches!(&event.event, BackendEvent::FooterWidgets { .. })));
+    assert!(events.iter().any(|event| matches!(
+        &event.event,
+        BackendEvent::RefreshBudget { idle_backoff, .. } if *idle_backoff >= 1
+    )));
 }
 
 #[test]
@@ -4235,6 +4271,58 @@ fn app_runtime_open_project_path_emits_active_work_projection_for_new_tab() {
         .contains_key(&canonical_other));
 }
 
+#[test]
+fn drop_project_folder_opens_git_workspaces_and_offers_clone_for_other_folders() {
+    let temp = tempdir().expect("tempdir");
+    let home = temp.path().join("home");
+    fs::create_dir_all(&home).expect("create home");
+    let tabs = vec![sample_project_tab(
+        "tab-1",
+        "Home",
+        home,
+        ProjectKind::NonRepo,
+        &[],
+    )];
+    let mut runtime = sample_runtime(temp.path(), tabs, Some("tab-1"));
+
+    let plain = temp.path().join("plain");
+    fs::create_dir_all(&plain).expect("create plain");
+    let events = runtime.drop_project_folder_events("client-1", plain.clone(), canvas_bounds());
+    assert!(events.iter().any(|event| matches!(
+        &event.event,
+        BackendEvent::ProjectDropNeedsClone { path }
+            if Path::new(path) == dunce::canonicalize(&plain).unwrap()
+    )));
+    assert_eq!(runtime.tabs.len(), 1);
+
+    let repo = temp.path().join("repo");
+    fs::create_dir_all(&repo).expect("create repo");
+    init_repo_with_initial_commit(&repo);
+    let branches_windows = |runtime: &AppRuntime| {
+        runtime
+            .active_tab_id
+            .as_deref()
+            .and_then(|tab_id| runtime.tab(tab_id))
+            .map(|tab| {
+                tab.workspace
+                    .persisted()
+                    .windows
+                    .iter()
+                    .filter(|window| window.preset == WindowPreset::Branches)
+                    .count()
+            })
+            .unwrap_or_default()
+    };
+    runtime.drop_project_folder_events("client-1", repo.clone(), canvas_bounds());
+    assert_eq!(runtime.tabs.len(), 2);
+    assert_eq!(branches_windows(&runtime), 1);
+
+    // Dropping the same folder again reuses the tab and its Branches window.
+    runtime.drop_project_folder_events("client-1", repo, canvas_bounds());
+    assert_eq!(runtime.tabs.len(), 2);
+    assert_eq!(branches_windows(&runtime), 1);
+}
+
 #[test]
 fn app_runtime_runtime_status_uses_lightweight_events_for_non_structural_status() {
     // Scoped HOME: with FR-382 the projection broadcast also fires when home
@@ -13018,6 +13106,49 @@ fn stop_window_events_keeps_window_and_marks_stopped() {
     )));
 }
 
+#[test]
+fn agent_stalled_events_flag_the_window_without_stopping_it() {
+    let temp = tempdir().expect("tempdir");
+    let repo = temp.path().join("repo");
+    fs::create_dir_all(&repo).expect("create repo");
+    let tab = sample_project_tab_with_window_at(
+        "tab-1",
+        "agent-1",
+        repo,
+        WindowPreset::Agent,
+        WindowProcessStatus::Running,
+    );
+    let mut runtime = sample_runtime(temp.path(), vec![tab], Some("tab-1"));
+    let window_id = combined_window_id("tab-1", "agent-1");
+    runtime.active_agent_sessions.insert(
+        window_id.clone(),
+        sample_active_agent_session("tab-1", &window_id),
+    );
+    let reason = gwt_agent::StallReason::Idle { minutes: 25 };
+
+    assert!(runtime
+        .agent_stalled_events(
+            &window_id,
+            "other-session",
+            reason,
+            gwt_config::WatchdogAction::Notify,
+        )
+        .is_empty());
+    let events = runtime.agent_stalled_events(
+        &window_id,
+        "session-1",
+        reason,
+        gwt_config::WatchdogAction::Notify,
+    );
+
+    assert!(runtime.active_agent_sessions.contains_key(&window_id));
+    assert!(events.iter().any(|event| matches!(
+        &event.event,
+        BackendEvent::TerminalStatus { id, detail: Some(detail), .. }
+            if id == &window_id && detail == "Stalled: no session activity for 25 min"
+    )));
+}
+
 // SPEC-2356 安心 Addendum (FR-041): StopWindow is idempotent — stopping an
 // already-stopped window keeps it on the canvas and stays Stopped.
 #[test]
@@ -15362,6 +15493,7 @@ fn app_runtime_select_and_save_profile_broadcasts_snapshot_to_profile_windows()
                 value: "override".to_string(),
             }],
             disabled_env: vec!["SECRET".to_string()],
+            secret_env: vec![],
         },
     );
 
@@ -15421,6 +15553,7 @@ fn app_runtime_logs_profile_save_user_action_without_env_values() {
                     value: "must-not-leak".to_string(),
                 }],
                 disabled_env: vec![],
+                secret_env: vec![],
             },
         );
     });
@@ -20759,7 +20892,7 @@ fn workspace_view_for_tab_omits_work_item_history_from_workspace_state() {
 }
 
 // SPEC-1924 US-14 FR-035 / FR-036 / SC-010 / SC-011 — verify the Logs
-// window snapshot reader goes through `gwt_core::logging::read_log_file`
+// window snapshot reader goes through `gwt_core::logging`
 // and that the synthetic warning event is well-formed when malformed
 // lines are skipped.
 
@@ -20777,11 +20910,13 @@ fn write_canonical_log_file(log_dir: &Path, lines: &[&str]) {
 }
 
 #[test]
-fn load_log_entries_from_dir_returns_outcome_with_no_skipped_lines() {
+fn log_snapshot_cache_returns_outcome_with_no_skipped_lines() {
     let dir = tempdir().expect("tempdir");
     write_canonical_log_file(dir.path(), &[PROD_LINE_INFO]);
 
-    let outcome = super::load_log_entries_from_dir(dir.path()).expect("read ok");
+    let outcome = super::loaders::LogSnapshotCache::default()
+        .refresh(dir.path())
+        .expect("read ok");
 
     assert_eq!(outcome.entries.len(), 1);
     assert_eq!(outcome.entries[0].message, "PTY resize completed");
@@ -20789,29 +20924,53 @@ fn load_log_entries_from_dir_returns_outcome_with_no_skipped_lines() {
 }
 
 #[test]
-fn load_log_entries_from_dir_counts_skipped_lines() {
+fn log_snapshot_cache_counts_skipped_lines() {
     let dir = tempdir().expect("tempdir");
     write_canonical_log_file(
         dir.path(),
         &[PROD_LINE_INFO, MALFORMED_LINE, PROD_LINE_INFO],
     );
 
-    let outcome = super::load_log_entries_from_dir(dir.path()).expect("read ok");
+    let outcome = super::loaders::LogSnapshotCache::default()
+        .refresh(dir.path())
+        .expect("read ok");
 
     assert_eq!(outcome.entries.len(), 2);
     assert_eq!(outcome.diagnostics.skipped, 1);
 }
 
 #[test]
-fn load_log_entries_from_dir_returns_empty_outcome_when_file_missing() {
+fn log_snapshot_cache_returns_empty_outcome_when_file_missing() {
     let dir = tempdir().expect("tempdir");
 
-    let outcome = super::load_log_entries_from_dir(dir.path()).expect("read ok");
+    let outcome = super::loaders::LogSnapshotCache::default()
+        .refresh(dir.path())
+        .expect("read ok");
 
     assert!(outcome.entries.is_empty());
     assert_eq!(outcome.diagnostics.skipped, 0);
 }
 
+#[test]
+fn log_snapshot_cache_appends_only_new_lines_on_reload() {
+    let dir = tempdir().expect("tempdir");
+    write_canonical_log_file(dir.path(), &[PROD_LINE_INFO]);
+    let mut cache = super::loaders::LogSnapshotCache::default();
+    assert_eq!(cache.refresh(dir.path()).expect("read ok").entries.len(), 1);
+
+    let mut file = fs::OpenOptions::new()
+        .append(true)
+        .open(current_log_file(dir.path()))
+        .expect("open log file");
+    file.write_all(format!("{MALFORMED_LINE}\n{PROD_LINE_INFO}\n").as_bytes())
+        .expect("append");
+
+    let outcome = cache.refresh(dir.path()).expect("read ok");
+    assert_eq!(outcome.entries.len(), 2);
+    assert_eq!(outcome.entries[1].id, 2);
+    assert_eq!(outcome.diagnostics.skipped, 1);
+}
+
 #[test]
 fn skipped_lines_warning_is_warn_severity_and_includes_count_and_path() {
     let diagnostics = gwt_core::logging::ReadDiagnostics {
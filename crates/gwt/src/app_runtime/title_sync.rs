@@ -111,23 +111,37 @@ impl AppRuntime {
                     .filter(|value| !value.is_empty())
                     .map(str::to_string)
                     .or_else(|| issue_fallback_title.clone());
-                Some((window_id, title, agent.current_focus.clone()))
+                Some((
+                    window_id,
+                    agent.session_id.clone(),
+                    title,
+                    agent.current_focus.clone(),
+                ))
             })
             .collect::<Vec<_>>();
 
         let mut changed = false;
-        for (window_id, title, detail) in updates {
+        for (window_id, session_id, title, detail) in updates {
             let Some(address) = self.window_lookup.get(&window_id).cloned() else {
                 continue;
             };
             let Some(tab) = self.tab_mut(&address.tab_id) else {
                 continue;
             };
-            if tab
-                .workspace
-                .set_dynamic_title_with_detail(&address.raw_id, title, detail)
-            {
+            if tab.workspace.set_dynamic_title_with_detail(
+                &address.raw_id,
+                title.clone(),
+                detail.clone(),
+            ) {
                 changed = true;
+                gwt_core::activity_bus::publish(
+                    gwt_core::activity_bus::ActivityEvent::SessionSummaryUpdated {
+                        session_id,
+                        window_id,
+                        summary: title,
+                        detail,
+                    },
+                );
             }
         }
         changed
@@ -0,0 +1,125 @@
+use super::*;
+
+impl AppRuntime {
+    /// Check the live agent sessions against the active profile's watchdog
+    /// limits off the event loop. Newly stalled sessions are persisted as
+    /// `AgentStatus::Stalled` and come back as `UserEvent::AgentStalled`.
+    pub(crate) fn agent_watchdog_tick(&mut self) {
+        if self.active_agent_sessions.is_empty() {
+            return;
+        }
+        let sessions: Vec<(String, String)> = self
+            .active_agent_sessions
+            .values()
+            .map(|session| (session.window_id.clone(), session.session_id.clone()))
+            .collect();
+        let sessions_dir = self.sessions_dir.clone();
+        let proxy = self.proxy.clone();
+        self.blocking_tasks.spawn(move || {
+            let Ok(settings) = gwt_config::Settings::load() else {
+                return;
+            };
+            let Some(policy) = gwt_agent::WatchdogPolicy::for_active_profile(&settings) else {
+                return;
+            };
+            for (window_id, session_id) in sessions {
+                let Some(reason) = mark_stalled_session(&sessions_dir, &session_id, &policy) else {
+                    continue;
+                };
+                proxy.send(UserEvent::AgentStalled {
+                    window_id,
+                    session_id,
+                    reason,
+                    action: policy.action,
+                });
+            }
+        });
+    }
+
+    /// Tell hooks and clients about a stalled agent, and stop it when the
+    /// profile asks for that.
+    pub(crate) fn agent_stalled_events(
+        &mut self,
+        window_id: &str,
+        session_id: &str,
+        reason: gwt_agent::StallReason,
+        action: gwt_config::WatchdogAction,
+    ) -> Vec<OutboundEvent> {
+        let Some(session) = self
+            .active_agent_sessions
+            .get(window_id)
+            .filter(|session| session.session_id == session_id)
+            .cloned()
+        else {
+            return Vec::new();
+        };
+        let kill = action == gwt_config::WatchdogAction::Kill;
+        tracing::warn!(
+            session_id = %session.session_id,
+            branch = %session.branch_name,
+            reason = %reason,
+            kill,
+            "agent stalled"
+        );
+        gwt::lifecycle_hooks::emit(
+            gwt::lifecycle_hooks::LifecycleEvent::AgentStalled,
+            serde_json::json!({
+                "repo_path": self
+                    .tab(&session.tab_id)
+                    .map(|tab| tab.project_root.display().to_string()),
+                "session_id": session.session_id,
+                "agent_id": session.agent_id,
+                "branch": session.branch_name,
+                "worktree_path": session.worktree_path.display().to_string(),
+                "reason": reason.as_str(),
+                "detail": reason.to_string(),
+                "action": if kill { "kill" } else { "notify" },
+            }),
+        );
+        if kill {
+            let mut events = self.stop_window_events(window_id);
+            events.extend(Self::status_events(
+                window_id.to_string(),
+                WindowProcessStatus::Stopped,
+                Some(format!("Stalled: {reason}; stopped by the watchdog")),
+            ));
+            return events;
+        }
+        let status = self
+            .window_status(window_id)
+            .unwrap_or(WindowProcessStatus::Running);
+        Self::status_events(
+            window_id.to_string(),
+            status,
+            Some(format!("Stalled: {reason}")),
+        )
+    }
+}
+
+/// Re-check `session_id` under the session lock and mark it stalled. Returns
+/// the reason only when this call changed the status.
+fn mark_stalled_session(
+    sessions_dir: &Path,
+    session_id: &str,
+    policy: &gwt_agent::WatchdogPolicy,
+) -> Option<gwt_agent::StallReason> {
+    let path = sessions_dir.join(format!("{session_id}.toml"));
+    let now = chrono::Utc::now();
+    policy.check(&gwt_agent::Session::load(&path).ok()?, now)?;
+    let mut found = None;
+    let updated = gwt_agent::update_session(sessions_dir, session_id, |session| {
+        found = policy.check(session, now);
+        if found.is_some() {
+            session.update_status(gwt_agent::AgentStatus::Stalled);
+        }
+        Ok(())
+    });
+    if let Err(error) = updated {
+        tracing::warn!(session_id, error = %error, "failed to mark agent session stalled");
+        return None;
+    }
+    let reason = found?;
+    let _ = gwt_agent::SessionRuntimeState::new(gwt_agent::AgentStatus::Stalled)
+        .save(&gwt_agent::runtime_state_path(sessions_dir, session_id));
+    Some(reason)
+}
@@ -98,17 +98,17 @@ fn build_review_dispatch_prompt(dispatch: &gwt::AutonomousReviewDispatch) -> Str
 
 use super::{
     build_shell_process_launch, combined_window_id, detect_wizard_docker_context_and_status,
-    knowledge_error_event, knowledge_kind_for_preset, linked_issue_workspace_context,
-    list_branch_entries_with_active_sessions, normalize_branch_name, preferred_issue_launch_branch,
-    resolve_shell_launch_worktree, save_shell_work_projection, session_exact_resume_materializable,
-    synthetic_branch_entry, workspace_projection_for_current_resume,
-    workspace_resume_branch_exists, workspace_resume_branch_from_journal_project_root,
-    workspace_resume_context_for_work_item, workspace_resume_context_from_journal,
-    workspace_resume_context_from_projection, workspace_resume_owner_issue_number,
-    AgentKanbanLaunchTarget, AppEventProxy, AppRuntime, BackendEvent, DispatchTarget,
-    IssueLaunchWizardPrepared, IssueMonitorProfileSaveContext, LaunchFeedbackContext,
-    LaunchWizardMemoryCache, LaunchWizardSession, OutboundEvent, WindowPreset, WindowProcessStatus,
-    WorkspaceResumeContext, WORKSPACE_OVERVIEW_JOURNAL_LIMIT,
+    knowledge_error_event, knowledge_kind_for_preset, launch_rule_for_branch,
+    linked_issue_workspace_context, list_branch_entries_with_active_sessions,
+    normalize_branch_name, preferred_issue_launch_branch, resolve_shell_launch_worktree,
+    save_shell_work_projection, session_exact_resume_materializable, synthetic_branch_entry,
+    workspace_projection_for_current_resume, workspace_resume_branch_exists,
+    workspace_resume_branch_from_journal_project_root, workspace_resume_context_for_work_item,
+    workspace_resume_context_from_journal, workspace_resume_context_from_projection,
+    workspace_resume_owner_issue_number, AgentKanbanLaunchTarget, AppEventProxy, AppRuntime,
+    BackendEvent, DispatchTarget, IssueLaunchWizardPrepared, IssueMonitorProfileSaveContext,
+    LaunchFeedbackContext, LaunchWizardMemoryCache, LaunchWizardSession, OutboundEvent,
+    WindowPreset, WindowProcessStatus, WorkspaceResumeContext, WORKSPACE_OVERVIEW_JOURNAL_LIMIT,
 };
 use crate::usable_worktree_path_for_branch;
 
@@ -199,6 +199,48 @@ impl AppRuntime {
         }
     }
 
+    /// Branches list "Open agent to resolve": the Launch Wizard for `branch`
+    /// with a prompt listing the conflicts of its stopped rebase, so the
+    /// agent picked in the wizard resolves them.
+    pub(crate) fn open_rebase_resolution_wizard(
+        &mut self,
+        client_id: &str,
+        id: &str,
+        branch_name: &str,
+    ) -> Vec<OutboundEvent> {
+        let Some(project_root) = self
+            .window_lookup
+            .get(id)
+            .and_then(|address| self.tab(&address.tab_id))
+            .map(|tab| tab.project_root.clone())
+        else {
+            return launch_agent_open_error(client_id, "Window not found");
+        };
+        let prompt = match gwt::rebase_helper::project_conflict_resolution_prompt(
+            &project_root,
+            branch_name,
+        ) {
+            Ok(prompt) => prompt,
+            Err(error) => return launch_agent_open_error(client_id, error),
+        };
+        let previous_wizard = self
+            .launch_wizard
+            .as_ref()
+            .map(|session| session.wizard_id.clone());
+        let events = self.open_launch_wizard(client_id, id, branch_name, None);
+        let Some(session) = self
+            .launch_wizard
+            .as_mut()
+            .filter(|session| Some(&session.wizard_id) != previous_wizard.as_ref())
+        else {
+            return events;
+        };
+        session
+            .wizard
+            .apply(gwt::LaunchWizardAction::SetInitialPrompt { value: prompt });
+        vec![self.launch_wizard_state_outbound()]
+    }
+
     pub(crate) fn open_launch_wizard_for_branch(
         &mut self,
         tab_id: &str,
@@ -268,6 +310,11 @@ impl AppRuntime {
         wizard.set_hermes_needs_setup(!gwt_skills::hermes_is_configured_global());
         wizard.set_opencode_needs_setup(!gwt_skills::opencode_is_configured_global());
         wizard.mark_runtime_context_unresolved();
+        self.spawn_launch_wizard_recent_sessions_load(
+            wizard_id.clone(),
+            wizard.context.quick_start_root.clone(),
+            wizard.context.normalized_branch_name.clone(),
+        );
         self.launch_wizard = Some(LaunchWizardSession {
             tab_id: tab_id.to_string(),
             wizard_id,
@@ -2286,6 +2333,13 @@ impl AppRuntime {
                 session
                     .wizard
                     .mark_runtime_resolution_pending("Preparing runtime context...");
+                session.wizard.set_api_key_warning(None);
+                if let gwt::LaunchWizardLaunchRequest::Agent(agent_config) = config.as_ref() {
+                    self.spawn_launch_wizard_api_key_check(
+                        wizard_id.clone(),
+                        agent_config.as_ref().clone(),
+                    );
+                }
                 thread::spawn(move || {
                     let result = resolve_launch_wizard_runtime_context_hydration(
                         &project_root,
@@ -2486,6 +2540,72 @@ impl AppRuntime {
         events
     }
 
+    /// Summarize the branch's recent agent sessions off-thread; the result
+    /// arrives as `LaunchWizardRecentSessionsLoaded`.
+    fn spawn_launch_wizard_recent_sessions_load(
+        &self,
+        wizard_id: String,
+        repo_path: PathBuf,
+        branch_name: String,
+    ) {
+        let cache = self.launch_wizard_cache.clone();
+        let proxy = self.proxy.clone();
+        thread::spawn(move || {
+            let sessions = cache.recent_sessions(&repo_path, &branch_name);
+            if !sessions.is_empty() {
+                proxy.send(UserEvent::LaunchWizardRecentSessionsLoaded {
+                    wizard_id,
+                    sessions,
+                });
+            }
+        });
+    }
+
+    /// Check the API key `config` launches with off-thread; a warning
+    /// arrives as `LaunchWizardApiKeyChecked`.
+    fn spawn_launch_wizard_api_key_check(
+        &self,
+        wizard_id: String,
+        config: gwt_agent::LaunchConfig,
+    ) {
+        let proxy = self.proxy.clone();
+        thread::spawn(move || {
+            if let Some(warning) = gwt::api_key_check::launch_key_warning(&config) {
+                proxy.send(UserEvent::LaunchWizardApiKeyChecked { wizard_id, warning });
+            }
+        });
+    }
+
+    pub(crate) fn handle_launch_wizard_api_key_checked(
+        &mut self,
+        wizard_id: String,
+        warning: String,
+    ) -> Vec<OutboundEvent> {
+        let Some(session) = self.launch_wizard.as_mut() else {
+            return Vec::new();
+        };
+        if session.wizard_id != wizard_id {
+            return Vec::new();
+        }
+        session.wizard.set_api_key_warning(Some(warning));
+        vec![self.launch_wizard_state_outbound()]
+    }
+
+    pub(crate) fn handle_launch_wizard_recent_sessions_loaded(
+        &mut self,
+        wizard_id: String,
+        sessions: Vec<gwt_agent::session_export::RecentSession>,
+    ) -> Vec<OutboundEvent> {
+        let Some(session) = self.launch_wizard.as_mut() else {
+            return Vec::new();
+        };
+        if session.wizard_id != wizard_id {
+            return Vec::new();
+        }
+        session.wizard.set_recent_sessions(sessions);
+        vec![self.launch_wizard_state_outbound()]
+    }
+
     pub(crate) fn handle_launch_wizard_runtime_resolved(
         &mut self,
         wizard_id: String,
@@ -2541,6 +2661,10 @@ fn resolve_launch_wizard_runtime_context_hydration(
     let agent_options = cache.agent_options();
     let (docker_context, docker_service_status) =
         detect_wizard_docker_context_and_status(&context_path);
+    let runtime_rule = docker_context
+        .is_some()
+        .then(|| launch_rule_for_branch(&context_path, &branch_name))
+        .flatten();
     Ok(LaunchWizardHydration {
         selected_branch: None,
         normalized_branch_name: branch_name,
@@ -2553,6 +2677,7 @@ fn resolve_launch_wizard_runtime_context_hydration(
         previous_profiles: Some(previous_profiles),
         // Runtime re-resolution preserves picker candidates set at first hydration.
         open_branch_candidates: Vec::new(),
+        runtime_rule,
     })
 }
 
@@ -2865,8 +2990,9 @@ impl AppRuntime {
                 .working_dir
                 .clone()
                 .unwrap_or_else(|| PathBuf::from(&project_root));
-            gwt_agent::LaunchEnvironment::from_active_profile(
+            gwt_agent::LaunchEnvironment::from_project_profile(
                 &profile_config_path,
+                Some(Path::new(&project_root)),
                 config.runtime_target,
             )?
             .with_project_root(&worktree_path)
@@ -2906,6 +3032,7 @@ impl AppRuntime {
             previous_profiles: None,
             // Cache refresh preserves picker candidates set at first hydration.
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
     }
 }
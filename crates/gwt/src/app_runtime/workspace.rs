@@ -26,6 +26,8 @@
 use std::path::{Path, PathBuf};
 use std::thread;
 
+use gwt_core::operation_progress::{OperationKind, OperationState, OperationTracker};
+
 use super::{
     active_work_cleanup_candidate_view_from_candidate,
     active_work_projection_from_saved_with_journal, cleanup_selected_branches_with_progress,
@@ -427,6 +429,75 @@ fn clear_workspace_cleanup_git_details_event(project_root: &Path) -> Option<Outb
     ))
 }
 
+/// Record a background operation under `~/.gwt/operations` so other gwt
+/// instances can follow or cancel it. Tracking is best effort: the operation
+/// runs untracked when the record cannot be created.
+pub(super) fn start_operation_tracker(
+    kind: OperationKind,
+    project_root: &Path,
+    cancellable: bool,
+) -> Option<OperationTracker> {
+    match OperationTracker::start(
+        &gwt_core::paths::gwt_operations_dir(),
+        kind,
+        project_root,
+        cancellable,
+    ) {
+        Ok(tracker) => Some(tracker),
+        Err(error) => {
+            tracing::warn!(
+                ?kind,
+                project_root = %project_root.display(),
+                %error,
+                "operation progress tracking unavailable"
+            );
+            None
+        }
+    }
+}
+
+/// Share of the batch finished once `progress` is reported.
+fn cleanup_percent(progress: &gwt::BranchCleanupProgressEntry) -> u8 {
+    let finished = match progress.phase {
+        gwt::BranchCleanupProgressPhase::Running => progress.index.saturating_sub(1),
+        _ => progress.index,
+    };
+    (finished * 100 / progress.total.max(1)).min(100) as u8
+}
+
+/// Branches list rebase actions handled by
+/// [`AppRuntime::rebase_branch_events`].
+pub(crate) enum RebaseAction {
+    Start { onto: Option<String> },
+    Continue,
+    Abort,
+}
+
+fn rebase_result_message(branch: &str, outcome: Option<&gwt_git::rebase::RebaseOutcome>) -> String {
+    use gwt_git::rebase::RebaseOutcome;
+    match outcome {
+        None => format!("Aborted the rebase of {branch}"),
+        Some(RebaseOutcome::UpToDate { onto }) => format!("{branch} already contains {onto}"),
+        Some(RebaseOutcome::Rebased { onto, .. }) => format!("Rebased {branch} onto {onto}"),
+        Some(RebaseOutcome::Conflicted {
+            onto, conflicts, ..
+        }) => format!(
+            "Rebase of {branch} onto {onto} stopped: {} conflicted file(s)",
+            conflicts.len()
+        ),
+    }
+}
+
+fn branch_window_not_found(client_id: &str, id: &str) -> Vec<OutboundEvent> {
+    vec![OutboundEvent::reply(
+        client_id,
+        BackendEvent::BranchError {
+            id: id.to_string(),
+            message: "Window not found".to_string(),
+        },
+    )]
+}
+
 fn spawn_branch_cleanup_async(
     proxy: AppEventProxy,
     client_id: ClientId,
@@ -444,12 +515,26 @@ fn spawn_branch_cleanup_async(
                     let progress_proxy = proxy.clone();
                     let progress_client_id = client_id.clone();
                     let progress_window_id = window_id.clone();
-                    let results = cleanup_selected_branches_with_progress(
+                    let tracker = std::cell::RefCell::new(start_operation_tracker(
+                        OperationKind::BranchCleanup,
+                        &project_root,
+                        true,
+                    ));
+                    let results = gwt::cleanup_selected_branches_with_cancel(
                         &project_root,
                         &entries,
                         &branches,
                         options,
-                        move |progress| {
+                        |progress| {
+                            if let Some(tracker) = tracker.borrow_mut().as_mut() {
+                                tracker.update(
+                                    format!(
+                                        "{} ({}/{})",
+                                        progress.branch, progress.index, progress.total
+                                    ),
+                                    cleanup_percent(&progress),
+                                );
+                            }
                             progress_proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
                                 progress_client_id.clone(),
                                 BackendEvent::BranchCleanupProgress {
@@ -463,7 +548,36 @@ fn spawn_branch_cleanup_async(
                                 },
                             )]));
                         },
+                        || {
+                            tracker
+                                .borrow()
+                                .as_ref()
+                                .is_some_and(OperationTracker::is_cancel_requested)
+                        },
                     );
+                    if let Some(tracker) = tracker.into_inner() {
+                        let cancelled = tracker.is_cancel_requested();
+                        let failed = results
+                            .iter()
+                            .filter(|result| {
+                                result.status == gwt::BranchCleanupResultStatus::Failed
+                            })
+                            .count();
+                        let (state, message) = if cancelled {
+                            (OperationState::Cancelled, "cancelled".to_string())
+                        } else if failed > 0 {
+                            (
+                                OperationState::Failed,
+                                format!("{failed} of {} branches failed", results.len()),
+                            )
+                        } else {
+                            (
+                                OperationState::Completed,
+                                format!("{} branches cleaned up", results.len()),
+                            )
+                        };
+                        tracker.finish(state, Some(message));
+                    }
                     let mut events = vec![OutboundEvent::reply(
                         client_id.clone(),
                         BackendEvent::BranchCleanupResult {
@@ -515,8 +629,7 @@ impl AppRuntime {
         client_id: &str,
         id: &str,
         branches: &[String],
-        delete_remote: bool,
-        force_filesystem_delete: bool,
+        options: BranchCleanupOptions,
     ) -> Vec<OutboundEvent> {
         let Some(address) = self.window_lookup.get(id) else {
             return vec![OutboundEvent::reply(
@@ -563,14 +676,577 @@ impl AppRuntime {
             tab.project_root.clone(),
             self.active_session_branches_for_tab(&address.tab_id),
             branches.to_vec(),
-            BranchCleanupOptions {
-                delete_remote,
-                force_filesystem_delete,
-            },
+            options,
         );
         Vec::new()
     }
 
+    pub(crate) fn open_worktree_path_events(
+        &self,
+        client_id: &str,
+        id: &str,
+        path: &str,
+        target: gwt::worktree_open::WorktreeOpenTarget,
+    ) -> Vec<OutboundEvent> {
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        let path = PathBuf::from(path);
+        thread::spawn(move || {
+            if let Err(message) =
+                gwt::worktree_open::open_project_worktree(&project_root, &path, target)
+            {
+                proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                    client_id,
+                    BackendEvent::BranchError {
+                        id: window_id,
+                        message,
+                    },
+                )]));
+            }
+        });
+        Vec::new()
+    }
+
+    pub(crate) fn stash_worktree_events(
+        &self,
+        client_id: &str,
+        id: &str,
+        path: &str,
+        action: gwt::worktree_stash::WorktreeStashAction,
+    ) -> Vec<OutboundEvent> {
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        let path = PathBuf::from(path);
+        thread::spawn(move || {
+            let event =
+                match gwt::worktree_stash::run_project_worktree_stash(&project_root, &path, action)
+                {
+                    Ok(message) => BackendEvent::WorktreeStashResult {
+                        id: window_id,
+                        message,
+                    },
+                    Err(message) => BackendEvent::BranchError {
+                        id: window_id,
+                        message,
+                    },
+                };
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id, event,
+            )]));
+        });
+        Vec::new()
+    }
+
+    pub(crate) fn archive_worktrees_events(
+        &self,
+        client_id: &str,
+        id: &str,
+        branches: Vec<String>,
+    ) -> Vec<OutboundEvent> {
+        let Some(address) = self.window_lookup.get(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let active_session_branches = self.active_session_branches_for_tab(&address.tab_id);
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let results = gwt::worktree_archive::archive_project_worktrees(
+                &project_root,
+                &branches,
+                &active_session_branches,
+            );
+            let mut events = vec![OutboundEvent::reply(
+                client_id.clone(),
+                BackendEvent::BranchCleanupResult {
+                    id: window_id.clone(),
+                    results,
+                },
+            )];
+            if let Ok(entries) =
+                list_branch_entries_with_active_sessions(&project_root, &active_session_branches)
+            {
+                events.push(OutboundEvent::reply(
+                    client_id,
+                    BackendEvent::BranchEntries {
+                        id: window_id,
+                        phase: BranchEntriesPhase::Hydrated,
+                        entries,
+                        load_id: gwt::next_branch_load_id(),
+                    },
+                ));
+            }
+            proxy.send(UserEvent::Dispatch(events));
+        });
+        Vec::new()
+    }
+
+    pub(crate) fn restore_worktree_events(
+        &self,
+        client_id: &str,
+        id: &str,
+        branch: String,
+    ) -> Vec<OutboundEvent> {
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let event =
+                match gwt::worktree_archive::restore_project_worktree(&project_root, &branch) {
+                    Ok(message) => BackendEvent::WorktreeRestoreResult {
+                        id: window_id,
+                        message,
+                    },
+                    Err(message) => BackendEvent::BranchError {
+                        id: window_id,
+                        message,
+                    },
+                };
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id, event,
+            )]));
+        });
+        Vec::new()
+    }
+
+    pub(crate) fn repair_worktree_events(
+        &self,
+        client_id: &str,
+        id: &str,
+        branch: String,
+    ) -> Vec<OutboundEvent> {
+        let Some(address) = self.window_lookup.get(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let active_session_branches = self.active_session_branches_for_tab(&address.tab_id);
+        if active_session_branches.contains(&branch) {
+            return vec![OutboundEvent::reply(
+                client_id,
+                BackendEvent::BranchError {
+                    id: id.to_string(),
+                    message: "An agent session is running in this worktree".to_string(),
+                },
+            )];
+        }
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let event =
+                match gwt::worktree_integrity::repair_project_worktree(&project_root, &branch) {
+                    Ok(message) => BackendEvent::WorktreeRepairResult {
+                        id: window_id.clone(),
+                        message,
+                    },
+                    Err(message) => BackendEvent::BranchError {
+                        id: window_id.clone(),
+                        message,
+                    },
+                };
+            let mut events = vec![OutboundEvent::reply(client_id.clone(), event)];
+            if let Ok(entries) =
+                list_branch_entries_with_active_sessions(&project_root, &active_session_branches)
+            {
+                events.push(OutboundEvent::reply(
+                    client_id,
+                    BackendEvent::BranchEntries {
+                        id: window_id,
+                        phase: BranchEntriesPhase::Hydrated,
+                        entries,
+                        load_id: gwt::next_branch_load_id(),
+                    },
+                ));
+            }
+            proxy.send(UserEvent::Dispatch(events));
+        });
+        Vec::new()
+    }
+
+    pub(crate) fn transplant_worktree_events(
+        &self,
+        client_id: &str,
+        id: &str,
+        branch: String,
+        new_branch: String,
+    ) -> Vec<OutboundEvent> {
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let event = match gwt::worktree_transplant::transplant_project_worktree(
+                &project_root,
+                &branch,
+                &new_branch,
+            ) {
+                Ok(outcome) => BackendEvent::WorktreeTransplantResult {
+                    id: window_id,
+                    message: outcome.summary(),
+                },
+                Err(message) => BackendEvent::BranchError {
+                    id: window_id,
+                    message,
+                },
+            };
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id, event,
+            )]));
+        });
+        Vec::new()
+    }
+
+    pub(crate) fn download_ci_artifacts_events(
+        &self,
+        client_id: &str,
+        id: &str,
+        branch: String,
+        pattern: Option<String>,
+    ) -> Vec<OutboundEvent> {
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let event = match gwt::ci_artifacts::download_branch_ci_artifacts(
+                &project_root,
+                &branch,
+                pattern.as_deref(),
+            ) {
+                Ok(message) => BackendEvent::CiArtifactsDownloadResult {
+                    id: window_id,
+                    message,
+                },
+                Err(message) => BackendEvent::BranchError {
+                    id: window_id,
+                    message,
+                },
+            };
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id, event,
+            )]));
+        });
+        Vec::new()
+    }
+
+    pub(crate) fn load_branch_ci_status_events(
+        &self,
+        client_id: &str,
+        id: &str,
+    ) -> Vec<OutboundEvent> {
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let event = BackendEvent::BranchCiStatuses {
+                id: window_id,
+                statuses: gwt::branch_ci_status::branch_ci_statuses(&project_root),
+            };
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id, event,
+            )]));
+        });
+        Vec::new()
+    }
+
+    pub(crate) fn create_pull_request_events(
+        &self,
+        client_id: &str,
+        id: &str,
+        branch: String,
+    ) -> Vec<OutboundEvent> {
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let event = match gwt::pr_create::create_branch_pr(&project_root, &branch, None, false)
+            {
+                Ok(created) => BackendEvent::PullRequestCreateResult {
+                    id: window_id,
+                    message: created.summary(),
+                    branch: created.branch,
+                    number: created.number,
+                    url: created.url,
+                },
+                Err(message) => BackendEvent::BranchError {
+                    id: window_id,
+                    message,
+                },
+            };
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id, event,
+            )]));
+        });
+        Vec::new()
+    }
+
+    pub(crate) fn detect_external_agents_events(
+        &self,
+        client_id: &str,
+        id: &str,
+    ) -> Vec<OutboundEvent> {
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let event = match gwt::external_agents::detect_external_agents(&project_root) {
+                Ok(agents) => BackendEvent::ExternalAgents {
+                    id: window_id,
+                    agents,
+                },
+                Err(message) => BackendEvent::BranchError {
+                    id: window_id,
+                    message,
+                },
+            };
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id, event,
+            )]));
+        });
+        Vec::new()
+    }
+
+    pub(crate) fn adopt_external_agent_events(
+        &self,
+        client_id: &str,
+        id: &str,
+        pid: u32,
+        branch: String,
+    ) -> Vec<OutboundEvent> {
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let event =
+                match gwt::external_agents::adopt_external_agent(&project_root, pid, &branch) {
+                    Ok(session) => BackendEvent::ExternalAgentAdopted {
+                        id: window_id,
+                        pid,
+                        message: format!(
+                            "Adopted {} (pid {pid}) on {}",
+                            session.agent_id.display_name(),
+                            session.branch
+                        ),
+                        branch: session.branch,
+                    },
+                    Err(message) => BackendEvent::BranchError {
+                        id: window_id,
+                        message,
+                    },
+                };
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id, event,
+            )]));
+        });
+        Vec::new()
+    }
+
+    pub(crate) fn rebase_branch_events(
+        &self,
+        client_id: &str,
+        id: &str,
+        branch: String,
+        action: RebaseAction,
+    ) -> Vec<OutboundEvent> {
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let result = match action {
+                RebaseAction::Start { onto } => gwt::rebase_helper::rebase_project_branch(
+                    &project_root,
+                    &branch,
+                    onto.as_deref(),
+                )
+                .map(Some),
+                RebaseAction::Continue => {
+                    gwt::rebase_helper::continue_project_rebase(&project_root, &branch).map(Some)
+                }
+                RebaseAction::Abort => {
+                    gwt::rebase_helper::abort_project_rebase(&project_root, &branch).map(|()| None)
+                }
+            };
+            let event = match result {
+                Ok(outcome) => BackendEvent::BranchRebaseResult {
+                    id: window_id,
+                    message: rebase_result_message(&branch, outcome.as_ref()),
+                    branch,
+                    outcome,
+                },
+                Err(message) => BackendEvent::BranchError {
+                    id: window_id,
+                    message,
+                },
+            };
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id, event,
+            )]));
+        });
+        Vec::new()
+    }
+
+    pub(crate) fn load_stale_remote_branches_events(
+        &self,
+        client_id: &str,
+        id: &str,
+        min_age_days: u32,
+    ) -> Vec<OutboundEvent> {
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let event = match gwt::stale_remote_branches::find_stale_remote_branches(
+                &project_root,
+                min_age_days,
+            ) {
+                Ok(entries) => BackendEvent::StaleRemoteBranches {
+                    id: window_id,
+                    min_age_days,
+                    entries,
+                },
+                Err(message) => BackendEvent::BranchError {
+                    id: window_id,
+                    message,
+                },
+            };
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id, event,
+            )]));
+        });
+        Vec::new()
+    }
+
+    pub(crate) fn load_removed_worktrees_events(
+        &self,
+        client_id: &str,
+        id: &str,
+    ) -> Vec<OutboundEvent> {
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let event = match gwt::worktree_trash::removed_worktrees(&project_root) {
+                Ok(entries) => BackendEvent::RemovedWorktrees {
+                    id: window_id,
+                    entries,
+                },
+                Err(message) => BackendEvent::BranchError {
+                    id: window_id,
+                    message,
+                },
+            };
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id, event,
+            )]));
+        });
+        Vec::new()
+    }
+
+    pub(crate) fn undo_worktree_removal_events(
+        &self,
+        client_id: &str,
+        id: &str,
+        branch: String,
+    ) -> Vec<OutboundEvent> {
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let event =
+                match gwt::worktree_trash::undo_worktree_removal(&project_root, Some(&branch)) {
+                    Ok(message) => BackendEvent::WorktreeRestoreResult {
+                        id: window_id,
+                        message,
+                    },
+                    Err(message) => BackendEvent::BranchError {
+                        id: window_id,
+                        message,
+                    },
+                };
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id, event,
+            )]));
+        });
+        Vec::new()
+    }
+
+    pub(crate) fn delete_stale_remote_branches_events(
+        &self,
+        client_id: &str,
+        id: &str,
+        branches: Vec<String>,
+    ) -> Vec<OutboundEvent> {
+        let Some(project_root) = self.branch_window_project_root(id) else {
+            return branch_window_not_found(client_id, id);
+        };
+        let proxy = self.proxy.clone();
+        let client_id = client_id.to_string();
+        let window_id = id.to_string();
+        thread::spawn(move || {
+            let results =
+                gwt::stale_remote_branches::delete_stale_remote_branches(&project_root, &branches);
+            proxy.send(UserEvent::Dispatch(vec![OutboundEvent::reply(
+                client_id,
+                BackendEvent::StaleRemoteBranchesDeleted {
+                    id: window_id,
+                    results,
+                },
+            )]));
+        });
+        Vec::new()
+    }
+
+    fn branch_window_project_root(&self, id: &str) -> Option<PathBuf> {
+        self.window_lookup
+            .get(id)
+            .and_then(|address| self.tab(&address.tab_id))
+            .map(|tab| tab.project_root.clone())
+    }
+
     pub(crate) fn run_workspace_cleanup_events(
         &self,
         client_id: &str,
@@ -606,6 +1282,7 @@ impl AppRuntime {
             BranchCleanupOptions {
                 delete_remote,
                 force_filesystem_delete,
+                confirmed_protected: Vec::new(),
             },
         );
         Vec::new()
@@ -0,0 +1,153 @@
+//! Scheduled background fetch.
+//!
+//! With `[background_fetch] enabled`, the GUI and `gwtd daemon start` run
+//! [`refresh_project`] for their projects every `interval_minutes`. When a
+//! fetch moves a remote-tracking ref they announce it on the same `git_refs`
+//! channel the git hooks use (hook [`FETCH_HOOK`]), and open Branches lists
+//! reload, recomputing cleanup safety and CI / PR state without a manual
+//! refresh.
+
+use std::{
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// `hook` name of the refs change a background fetch reports.
+pub const FETCH_HOOK: &str = "fetch";
+
+/// How often the schedulers check whether a fetch is due, so a changed
+/// interval takes effect without a restart.
+pub const SCHEDULER_TICK: Duration = Duration::from_secs(60);
+
+/// Configured fetch interval; `None` while background fetch is off.
+pub fn configured_interval() -> Option<Duration> {
+    gwt_config::Settings::load()
+        .ok()?
+        .background_fetch
+        .interval()
+}
+
+/// When the last background fetch ran.
+#[derive(Debug, Default)]
+pub struct FetchSchedule {
+    last: Option<Instant>,
+}
+
+impl FetchSchedule {
+    /// Whether a fetch is due at `now` and, if so, record it as started.
+    /// The first check after background fetch is turned on is due.
+    pub fn take_due(&mut self, now: Instant, interval: Option<Duration>) -> bool {
+        let Some(interval) = interval else {
+            self.last = None;
+            return false;
+        };
+        if self
+            .last
+            .is_some_and(|last| now.duration_since(last) < interval)
+        {
+            return false;
+        }
+        self.last = Some(now);
+        true
+    }
+}
+
+/// One scheduled refresh of the repository at `repo_path`: fetch and prune,
+/// then resync the branches GitHub protects when that cache is stale.
+/// Returns whether a remote-tracking ref changed.
+pub fn refresh_project(repo_path: &Path) -> Result<bool, String> {
+    let changed = fetch_and_prune(repo_path)?;
+    crate::branch_protection::sync_protected_branches_if_stale(repo_path);
+    Ok(changed)
+}
+
+/// Run `git fetch --prune` in the repository at `repo_path`. Returns whether
+/// a remote-tracking ref changed. Credential prompts are turned off: a fetch
+/// that needs one fails instead of waiting for input nobody sees.
+pub fn fetch_and_prune(repo_path: &Path) -> Result<bool, String> {
+    let before = remote_refs(repo_path)?;
+    let output = gwt_core::process::hidden_command("git")
+        .args(["fetch", "--prune", "--quiet"])
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .current_dir(repo_path)
+        .output()
+        .map_err(|error| format!("git fetch --prune: {error}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git fetch --prune: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(remote_refs(repo_path)? != before)
+}
+
+fn remote_refs(repo_path: &Path) -> Result<String, String> {
+    let output = gwt_core::process::run_git_logged(
+        &[
+            "for-each-ref",
+            "--format=%(objectname) %(refname)",
+            "refs/remotes",
+        ],
+        Some(repo_path),
+    )
+    .map_err(|error| format!("git for-each-ref: {error}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git for-each-ref: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schedule_is_due_once_per_interval_and_resets_when_disabled() {
+        let mut schedule = FetchSchedule::default();
+        let start = Instant::now();
+        let interval = Some(Duration::from_secs(300));
+
+        assert!(!schedule.take_due(start, None));
+        assert!(schedule.take_due(start, interval));
+        assert!(!schedule.take_due(start + Duration::from_secs(299), interval));
+        assert!(schedule.take_due(start + Duration::from_secs(300), interval));
+
+        assert!(!schedule.take_due(start + Duration::from_secs(301), None));
+        assert!(schedule.take_due(start + Duration::from_secs(302), interval));
+    }
+
+    #[test]
+    fn fetch_reports_pruned_remote_branches() {
+        let tmp = tempfile::tempdir().unwrap();
+        let git = |dir: &Path, args: &[&str]| {
+            let output = gwt_core::process::run_git_logged(args, Some(dir)).unwrap();
+            assert!(
+                output.status.success(),
+                "git {args:?}: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        };
+        let origin = tmp.path().join("origin.git");
+        let repo = tmp.path().join("repo");
+        std::fs::create_dir_all(&origin).unwrap();
+        git(&origin, &["init", "--bare", "--initial-branch=main"]);
+        git(
+            tmp.path(),
+            &["clone", origin.to_str().unwrap(), repo.to_str().unwrap()],
+        );
+        git(&repo, &["symbolic-ref", "HEAD", "refs/heads/main"]);
+        git(&repo, &["config", "user.email", "t@example.com"]);
+        git(&repo, &["config", "user.name", "T"]);
+        git(&repo, &["commit", "--allow-empty", "-m", "init"]);
+        git(&repo, &["push", "origin", "main", "main:feature/x"]);
+        git(&repo, &["fetch", "origin"]);
+
+        assert_eq!(fetch_and_prune(&repo), Ok(false));
+        git(&origin, &["branch", "-D", "feature/x"]);
+        assert_eq!(fetch_and_prune(&repo), Ok(true));
+        assert!(!remote_refs(&repo).unwrap().contains("feature/x"));
+    }
+}
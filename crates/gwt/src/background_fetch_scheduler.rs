@@ -0,0 +1,37 @@
+//! Background fetch scheduler.
+//!
+//! Checks once a minute whether `[background_fetch]` wants a fetch and, when
+//! one is due, asks the event loop to fetch the open projects
+//! (`gwt::background_fetch`).
+
+use std::time::Instant;
+
+use gwt::background_fetch::{configured_interval, FetchSchedule, SCHEDULER_TICK};
+use tao::event_loop::EventLoopProxy;
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::UserEvent;
+
+/// Spawn the scheduler onto the shared tokio runtime. It stops when the
+/// event loop is gone.
+pub fn spawn_background_fetch_scheduler(
+    runtime: &tokio::runtime::Runtime,
+    proxy: EventLoopProxy<UserEvent>,
+) {
+    drop(runtime.handle().spawn(async move {
+        let mut schedule = FetchSchedule::default();
+        let mut ticker = interval(SCHEDULER_TICK);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // The first tick completes immediately; let startup settle first.
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            if !schedule.take_due(Instant::now(), configured_interval()) {
+                continue;
+            }
+            if proxy.send_event(UserEvent::BackgroundFetchTick).is_err() {
+                break;
+            }
+        }
+    }));
+}
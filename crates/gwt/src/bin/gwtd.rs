@@ -18,6 +18,8 @@ fn main() -> ExitCode {
             print_help();
             return ExitCode::SUCCESS;
         }
+        Some("completions") => return run_completions(&argv),
+        Some("__complete") => return run_complete_helper(&argv),
         None if std::io::stdin().is_terminal() => {
             print_help();
             return ExitCode::SUCCESS;
@@ -47,6 +49,9 @@ fn print_help() {
     println!("Usage: gwtd < stdin-json-envelope");
     println!("       gwtd hook event <Event>   Managed hook transport exception");
     println!("       gwtd --help <command>   Show subcommands for a family");
+    println!(
+        "       gwtd completions <bash|zsh|fish|powershell>   Print a shell completion script"
+    );
     println!();
     println!("Commands:");
     println!("  issue       Manage GitHub Issues and SPEC sections");
@@ -574,6 +579,40 @@ fn format_update_help() -> String {
     .join("\n")
 }
 
+fn run_completions(argv: &[String]) -> ExitCode {
+    let shell = argv
+        .get(2)
+        .and_then(|name| gwt::cli::completions::CompletionShell::from_name(name));
+    match (shell, argv.get(3)) {
+        (Some(shell), None) => {
+            print!(
+                "{}",
+                gwt::cli::completions::completion_script(shell, "gwtd")
+            );
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("Usage: gwtd completions <bash|zsh|fish|powershell>");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Dynamic candidates for the generated completion scripts. Always exits 0
+/// so a broken repository never surfaces as a shell error mid-completion.
+fn run_complete_helper(argv: &[String]) -> ExitCode {
+    let repo_path = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let families: Vec<&str> = SUGGESTION_VERBS
+        .iter()
+        .copied()
+        .filter(|family| family_help(family).is_some())
+        .collect();
+    for candidate in gwt::cli::completions::run_helper(&argv[2..], &families, &repo_path) {
+        println!("{candidate}");
+    }
+    ExitCode::SUCCESS
+}
+
 fn run_json_envelope_cli(argv: &[String]) -> i32 {
     let repo_path = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
     if let Some((owner, repo)) = resolve_repo_coordinates() {
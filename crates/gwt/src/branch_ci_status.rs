@@ -0,0 +1,52 @@
+//! Per-branch CI state for the Branches list.
+//!
+//! The Branches window asks for CI state after every list refresh, so the
+//! `gh run list` result is kept per repository for [`BRANCH_CI_TTL`]. An
+//! unreachable `gh` yields no statuses rather than an error: CI badges are
+//! an enrichment and their absence must not block the list.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+
+use gwt_git::pr_status::BranchCiStatus;
+
+/// How long a fetched CI map is reused.
+pub const BRANCH_CI_TTL: Duration = Duration::from_secs(60);
+
+type CiMap = HashMap<String, BranchCiStatus>;
+
+fn cache() -> &'static Mutex<HashMap<PathBuf, (Instant, CiMap)>> {
+    static CACHE: OnceLock<Mutex<HashMap<PathBuf, (Instant, CiMap)>>> = OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+/// CI state of each branch of the repository at `project_root` with recent
+/// workflow runs.
+pub fn branch_ci_statuses(project_root: &Path) -> CiMap {
+    let root = gwt_git::worktree::main_worktree_root(project_root)
+        .unwrap_or_else(|_| project_root.to_path_buf());
+    if let Some((fetched_at, statuses)) = cache()
+        .lock()
+        .ok()
+        .and_then(|cache| cache.get(&root).cloned())
+    {
+        if fetched_at.elapsed() < BRANCH_CI_TTL {
+            return statuses;
+        }
+    }
+    let statuses = match gwt_git::pr_status::fetch_branch_ci_statuses(&root) {
+        Ok(statuses) => statuses,
+        Err(error) => {
+            tracing::debug!(repo = %root.display(), error = %error, "branch CI status unavailable");
+            return CiMap::new();
+        }
+    };
+    if let Ok(mut cache) = cache().lock() {
+        cache.insert(root, (Instant::now(), statuses.clone()));
+    }
+    statuses
+}
@@ -1,8 +1,12 @@
 use std::{collections::HashMap, path::Path};
 
+use gwt_core::activity_bus::{self, ActivityEvent};
 use serde::{Deserialize, Serialize};
 
-use crate::{BranchCleanupAvailability, BranchCleanupBlockedReason, BranchListEntry};
+use crate::{
+    lifecycle_hooks::{self, LifecycleEvent},
+    BranchCleanupAvailability, BranchCleanupBlockedReason, BranchListEntry,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -20,10 +24,13 @@ pub struct BranchCleanupResultEntry {
     pub message: String,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct BranchCleanupOptions {
     pub delete_remote: bool,
     pub force_filesystem_delete: bool,
+    /// Branches matching a `protected_branches` pattern whose names the user
+    /// typed to confirm; other protected branches are refused.
+    pub confirmed_protected: Vec<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -57,6 +64,7 @@ pub fn cleanup_selected_branches(
         BranchCleanupOptions {
             delete_remote,
             force_filesystem_delete: false,
+            confirmed_protected: Vec::new(),
         },
     )
 }
@@ -71,25 +79,64 @@ pub fn cleanup_selected_branches_with_options(
 }
 
 pub fn cleanup_selected_branches_with_progress(
+    repo_path: &Path,
+    entries: &[BranchListEntry],
+    selected_branches: &[String],
+    options: BranchCleanupOptions,
+    progress: impl FnMut(BranchCleanupProgressEntry),
+) -> Vec<BranchCleanupResultEntry> {
+    cleanup_selected_branches_with_cancel(
+        repo_path,
+        entries,
+        selected_branches,
+        options,
+        progress,
+        || false,
+    )
+}
+
+/// Like [`cleanup_selected_branches_with_progress`], checking `cancelled`
+/// before each branch. Once it returns `true` the remaining branches are
+/// reported as failed without being touched.
+pub fn cleanup_selected_branches_with_cancel(
     repo_path: &Path,
     entries: &[BranchListEntry],
     selected_branches: &[String],
     options: BranchCleanupOptions,
     mut progress: impl FnMut(BranchCleanupProgressEntry),
+    mut cancelled: impl FnMut() -> bool,
 ) -> Vec<BranchCleanupResultEntry> {
     let git_root = git_command_root(repo_path);
-    let manager = gwt_git::WorktreeManager::new(&git_root);
+    let mut manager = gwt_git::WorktreeManager::new(&git_root).with_protection(
+        crate::branch_protection::protection_policy()
+            .with_confirmed(options.confirmed_protected.iter().cloned()),
+    );
+    if let Some(trash) = crate::worktree_trash::configured_trash(&git_root) {
+        manager = manager.with_trash(trash);
+    }
     let lookup: HashMap<&str, &BranchListEntry> = entries
         .iter()
         .map(|entry| (entry.name.as_str(), entry))
         .collect();
     let total = selected_branches.len();
+    let mut cancel_seen = false;
 
-    selected_branches
+    let results: Vec<BranchCleanupResultEntry> = selected_branches
         .iter()
         .enumerate()
         .map(|(offset, branch_name)| {
             let index = offset + 1;
+            cancel_seen = cancel_seen || cancelled();
+            if cancel_seen {
+                let result = BranchCleanupResultEntry {
+                    branch: branch_name.clone(),
+                    execution_branch: None,
+                    status: BranchCleanupResultStatus::Failed,
+                    message: "Cancelled before cleanup".to_string(),
+                };
+                emit_result_progress(&mut progress, &result, index, total);
+                return result;
+            }
             let Some(entry) = lookup.get(branch_name.as_str()).copied() else {
                 let result = BranchCleanupResultEntry {
                     branch: branch_name.clone(),
@@ -155,18 +202,39 @@ pub fn cleanup_selected_branches_with_progress(
                 return result;
             }
 
+            let removed_worktree = manager.list().ok().and_then(|worktrees| {
+                worktrees
+                    .into_iter()
+                    .find(|worktree| worktree.branch.as_deref() == Some(target_branch.as_str()))
+                    .map(|worktree| worktree.path)
+            });
             let cleanup_result = if options.force_filesystem_delete {
                 manager.cleanup_branch_with_force_filesystem_delete(&target_branch, true)
             } else {
                 manager.cleanup_branch(&target_branch)
             };
+            if let (Ok(()), Some(worktree_path)) = (&cleanup_result, &removed_worktree) {
+                activity_bus::publish(ActivityEvent::WorktreeRemoved {
+                    repo_path: Some(git_root.display().to_string()),
+                    branch: Some(target_branch.clone()),
+                    worktree_path: worktree_path.display().to_string(),
+                });
+                lifecycle_hooks::emit(
+                    LifecycleEvent::WorktreeRemoved,
+                    serde_json::json!({
+                        "repo_path": git_root.display().to_string(),
+                        "branch": target_branch,
+                        "worktree_path": worktree_path.display().to_string(),
+                    }),
+                );
+            }
             let result = match cleanup_result {
                 Ok(()) => {
                     // SPEC-2009 FR-071: never delete a protected base branch
                     // from the remote, regardless of the delete-remote flag.
                     if options.delete_remote
                         && entry.cleanup.upstream.is_some()
-                        && !gwt_git::is_protected_branch(&target_branch)
+                        && !gwt_git::is_protected_branch_in(&git_root, &target_branch)
                     {
                         match manager
                             .delete_remote_branch(&target_branch, entry.cleanup.upstream.as_deref())
@@ -215,7 +283,18 @@ pub fn cleanup_selected_branches_with_progress(
             emit_result_progress(&mut progress, &result, index, total);
             result
         })
-        .collect()
+        .collect();
+
+    if !results.is_empty() {
+        lifecycle_hooks::emit(
+            LifecycleEvent::CleanupCompleted,
+            serde_json::json!({
+                "repo_path": git_root.display().to_string(),
+                "results": results,
+            }),
+        );
+    }
+    results
 }
 
 fn emit_result_progress(
@@ -289,6 +368,10 @@ mod tests {
             cleanup: BranchCleanupInfo::default(),
             resume: crate::BranchResumeInfo::unavailable(),
             start_work_eligibility: None,
+            worktree_path: None,
+            archived: false,
+            integrity_issues: Vec::new(),
+            linked_spec: None,
         }
     }
 
@@ -398,4 +481,26 @@ mod tests {
             "Only gwt-managed workspaces can be cleaned up"
         );
     }
+
+    #[test]
+    fn cleanup_selected_branches_with_cancel_skips_remaining_branches() {
+        let repo = tempdir().expect("tempdir");
+        let mut checks = 0;
+
+        let results = cleanup_selected_branches_with_cancel(
+            repo.path(),
+            &[],
+            &[String::from("feature/a"), String::from("feature/b")],
+            BranchCleanupOptions::default(),
+            |_| {},
+            || {
+                checks += 1;
+                checks > 1
+            },
+        );
+
+        assert_eq!(results[0].message, "Branch not found");
+        assert_eq!(results[1].status, BranchCleanupResultStatus::Failed);
+        assert_eq!(results[1].message, "Cancelled before cleanup");
+    }
 }
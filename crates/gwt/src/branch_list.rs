@@ -22,6 +22,16 @@ pub fn next_branch_load_id() -> u64 {
     BRANCH_LOAD_SEQUENCE.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
 }
 
+/// Lists with more rows than this are not hydrated in one pass: the
+/// inventory is sent as a lazy load and the Branches window asks for the
+/// cleanup safety of the rows around its viewport as they scroll in.
+pub const LAZY_HYDRATION_THRESHOLD: usize = 200;
+
+/// Whether a list of `entries` rows should be hydrated on demand.
+pub fn wants_lazy_hydration(entries: &[BranchListEntry]) -> bool {
+    entries.len() > LAZY_HYDRATION_THRESHOLD
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum BranchCleanupAvailability {
@@ -49,6 +59,13 @@ pub enum BranchCleanupRisk {
     // SPEC-2009 FR-070: a protected base branch (main/master/develop) selectable
     // for LOCAL cleanup only — its remote counterpart is always protected.
     ProtectedBase,
+    // Matches a `protected_branches` pattern from the settings: cleanup runs
+    // only after the user types the branch name.
+    ProtectedPattern,
+    // The repository's filesystem (NFS/SMB) does not enforce file locks or
+    // keeps only second-precise mtimes, so an active session may go
+    // undetected; nothing is reported as Safe there.
+    UnreliableFilesystem,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -129,6 +146,21 @@ pub struct BranchListEntry {
     /// backends (serde default).
     #[serde(default)]
     pub start_work_eligibility: Option<RemoteStartWorkEligibility>,
+    /// Directory of the worktree that has this local branch checked out.
+    #[serde(default)]
+    pub worktree_path: Option<String>,
+    /// Whether this local branch's worktree is archived and can be restored.
+    #[serde(default)]
+    pub archived: bool,
+    /// Problems found by the last integrity check of this branch's worktree
+    /// (see [`gwt_git::worktree_integrity`]). Empty when it was healthy or
+    /// never checked.
+    #[serde(default)]
+    pub integrity_issues: Vec<String>,
+    /// Number of the `gwt-spec` issue this branch is linked to, shown as a
+    /// `SPEC-<n>` badge. `None` for plain issue links and unlinked branches.
+    #[serde(default)]
+    pub linked_spec: Option<u64>,
 }
 
 pub fn list_branch_entries(repo_path: &Path) -> std::io::Result<Vec<BranchListEntry>> {
@@ -139,7 +171,93 @@ pub fn list_branch_inventory(repo_path: &Path) -> std::io::Result<Vec<BranchList
     let git_root = git_command_root(repo_path)?;
     let branches = gwt_git::branch::list_branches(&git_root)
         .map_err(|error| std::io::Error::other(error.to_string()))?;
-    Ok(adapt_branch_inventory(branches))
+    let mut entries = adapt_branch_inventory(branches);
+    // Best-effort: a failed worktree listing only hides the path column.
+    if let Ok(worktrees) = gwt_git::WorktreeManager::new(&git_root).list() {
+        apply_worktree_paths(&mut entries, &worktrees);
+    }
+    for entry in entries.iter_mut() {
+        let Some(report) = entry
+            .worktree_path
+            .as_deref()
+            .and_then(|path| gwt_git::worktree_integrity::load_report(Path::new(path)))
+        else {
+            continue;
+        };
+        entry.integrity_issues = report.issues.iter().map(|issue| issue.summary()).collect();
+    }
+    let archived = crate::worktree_archive::archived_branches(&git_root);
+    for entry in entries
+        .iter_mut()
+        .filter(|entry| entry.scope == BranchScope::Local && entry.worktree_path.is_none())
+    {
+        entry.archived = archived.contains(&entry.name);
+    }
+    if let Some(repo_hash) = crate::index_worker::detect_repo_hash(&git_root) {
+        let cache_root = gwt_core::paths::gwt_cache_dir();
+        let links = load_issue_branch_links(
+            &cache_root
+                .join("issue-links")
+                .join(format!("{}.json", repo_hash.as_str())),
+        );
+        let issues = gwt_github::Cache::new(cache_root.join("issues").join(repo_hash.as_str()));
+        apply_linked_specs(&mut entries, &links, &issues);
+    }
+    Ok(entries)
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct IssueBranchLinkStore {
+    #[serde(default)]
+    branches: HashMap<String, u64>,
+}
+
+fn load_issue_branch_links(path: &Path) -> HashMap<String, u64> {
+    std::fs::read(path)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice::<IssueBranchLinkStore>(&bytes).ok())
+        .map(|store| store.branches)
+        .unwrap_or_default()
+}
+
+/// Marks the local rows whose linked issue is cached as a `gwt-spec` issue.
+fn apply_linked_specs(
+    entries: &mut [BranchListEntry],
+    links: &HashMap<String, u64>,
+    issues: &gwt_github::Cache,
+) {
+    let mut is_spec = HashMap::<u64, bool>::new();
+    for entry in entries
+        .iter_mut()
+        .filter(|entry| entry.scope == BranchScope::Local)
+    {
+        let Some(&number) = links.get(&entry.name) else {
+            continue;
+        };
+        let spec = *is_spec.entry(number).or_insert_with(|| {
+            issues
+                .load_entry(gwt_github::IssueNumber(number))
+                .is_some_and(|cached| {
+                    crate::launch_wizard::has_gwt_spec_label(&cached.snapshot.labels)
+                })
+        });
+        entry.linked_spec = spec.then_some(number);
+    }
+}
+
+fn apply_worktree_paths(entries: &mut [BranchListEntry], worktrees: &[gwt_git::WorktreeInfo]) {
+    let paths: HashMap<&str, &Path> = worktrees
+        .iter()
+        .filter_map(|worktree| Some((worktree.branch.as_deref()?, worktree.path.as_path())))
+        .collect();
+    for entry in entries
+        .iter_mut()
+        .filter(|entry| entry.scope == BranchScope::Local)
+    {
+        entry.worktree_path = paths
+            .get(entry.name.as_str())
+            .map(|path| path.display().to_string());
+    }
 }
 
 pub fn hydrate_branch_entries_with_active_sessions(
@@ -150,17 +268,163 @@ pub fn hydrate_branch_entries_with_active_sessions(
     let git_root = git_command_root(repo_path)?;
     let gone_branches = gwt_git::list_gone_branches(&git_root)
         .map_err(|error| std::io::Error::other(error.to_string()))?;
-    let cleanup_targets = build_cleanup_targets(&git_root, &entries, &gone_branches)?;
+    let locals: Vec<(&str, Option<&str>)> = entries
+        .iter()
+        .filter(|branch| branch.scope == BranchScope::Local)
+        .map(|branch| (branch.name.as_str(), branch.upstream.as_deref()))
+        .collect();
+    let cleanup_targets =
+        build_cleanup_targets(&git_root, locals, &gone_branches, &|| false)?.unwrap_or_default();
+    Ok(finish_hydration(
+        &git_root,
+        entries,
+        active_session_branches,
+        &cleanup_targets,
+    ))
+}
+
+/// Hydrate only the rows named in `names` (the Branches viewport of a lazy
+/// load). Git is only asked about the local branches those rows clean up,
+/// and `is_cancelled` is checked between branches; a cancelled run returns
+/// `Ok(None)`. Names that are no longer in the inventory are skipped.
+pub fn hydrate_branch_subset_with_active_sessions(
+    repo_path: &Path,
+    names: &HashSet<String>,
+    active_session_branches: &HashSet<String>,
+    is_cancelled: &dyn Fn() -> bool,
+) -> std::io::Result<Option<Vec<BranchListEntry>>> {
+    let entries = list_branch_inventory(repo_path)?;
+    let git_root = git_command_root(repo_path)?;
+    let gone_branches = gwt_git::list_gone_branches(&git_root)
+        .map_err(|error| std::io::Error::other(error.to_string()))?;
+    let local_upstreams: HashMap<String, Option<String>> = entries
+        .iter()
+        .filter(|branch| branch.scope == BranchScope::Local)
+        .map(|branch| (branch.name.clone(), branch.upstream.clone()))
+        .collect();
+    let execution_branches: HashSet<String> = entries
+        .iter()
+        .filter(|branch| names.contains(&branch.name))
+        .filter_map(|branch| cleanup_execution_branch(branch, &local_upstreams))
+        .collect();
+    let locals: Vec<(&str, Option<&str>)> = execution_branches
+        .iter()
+        .map(|name| {
+            let upstream = local_upstreams
+                .get(name)
+                .and_then(|upstream| upstream.as_deref());
+            (name.as_str(), upstream)
+        })
+        .collect();
+    let Some(cleanup_targets) =
+        build_cleanup_targets(&git_root, locals, &gone_branches, is_cancelled)?
+    else {
+        return Ok(None);
+    };
+    let entries = finish_hydration(
+        &git_root,
+        entries,
+        active_session_branches,
+        &cleanup_targets,
+    );
+    Ok(Some(
+        entries
+            .into_iter()
+            .filter(|entry| names.contains(&entry.name))
+            .collect(),
+    ))
+}
+
+fn finish_hydration(
+    git_root: &Path,
+    entries: Vec<BranchListEntry>,
+    active_session_branches: &HashSet<String>,
+    cleanup_targets: &HashMap<String, Option<gwt_git::MergeTargetRef>>,
+) -> Vec<BranchListEntry> {
     // SPEC-2009 FR-070: a bare repo's symbolic HEAD (e.g. main) is NOT a real
     // worktree checkout — gwt resolves the default branch from origin/HEAD — so
     // it must not block its branch from local cleanup.
-    let head_is_real_checkout = !repo_root_is_bare(&git_root);
-    Ok(hydrate_branch_entries(
+    let head_is_real_checkout = !repo_root_is_bare(git_root);
+    let entries = hydrate_branch_entries(
         entries,
         active_session_branches,
-        &cleanup_targets,
+        cleanup_targets,
         head_is_real_checkout,
-    ))
+    );
+    let entries = block_github_protected_cleanup(git_root, entries);
+    let entries =
+        mark_pattern_protected_cleanup(&crate::branch_protection::protection_policy(), entries);
+    if gwt_core::fs_probe::capabilities(git_root).is_degraded() {
+        return downgrade_safe_cleanup(entries);
+    }
+    entries
+}
+
+/// Block cleanup of branches protected on GitHub (synced by
+/// [`crate::branch_protection`]). main / master / develop keep their
+/// local-only Risky cleanup (FR-070).
+fn block_github_protected_cleanup(
+    git_root: &Path,
+    mut entries: Vec<BranchListEntry>,
+) -> Vec<BranchListEntry> {
+    let Some(synced) = gwt_git::branch_protection::load_synced_protected_branches(git_root) else {
+        return entries;
+    };
+    for entry in &mut entries {
+        let Some(branch) = entry.cleanup.execution_branch.clone() else {
+            continue;
+        };
+        if synced.branches.contains(&branch) && !gwt_git::is_protected_branch(&branch) {
+            let upstream = entry.cleanup.upstream.clone();
+            entry.cleanup = blocked_cleanup_info(
+                Some(branch),
+                upstream,
+                BranchCleanupBlockedReason::ProtectedBranch,
+            );
+        }
+    }
+    entries
+}
+
+/// Mark cleanable branches matching a `protected_branches` pattern Risky
+/// with [`BranchCleanupRisk::ProtectedPattern`], so the cleanup dialog asks
+/// for their names.
+fn mark_pattern_protected_cleanup(
+    policy: &gwt_git::ProtectionPolicy,
+    mut entries: Vec<BranchListEntry>,
+) -> Vec<BranchListEntry> {
+    for entry in &mut entries {
+        if entry.cleanup.availability == BranchCleanupAvailability::Blocked {
+            continue;
+        }
+        let Some(branch) = entry.cleanup.execution_branch.as_deref() else {
+            continue;
+        };
+        if policy.matching_pattern(branch).is_some() {
+            entry.cleanup.availability = BranchCleanupAvailability::Risky;
+            entry
+                .cleanup
+                .risks
+                .push(BranchCleanupRisk::ProtectedPattern);
+        }
+    }
+    entries
+}
+
+/// Turn every Safe cleanup verdict into Risky with
+/// [`BranchCleanupRisk::UnreliableFilesystem`], so "Select all safe" never
+/// sweeps up branches whose liveness could not be verified.
+fn downgrade_safe_cleanup(mut entries: Vec<BranchListEntry>) -> Vec<BranchListEntry> {
+    for entry in &mut entries {
+        if entry.cleanup.availability == BranchCleanupAvailability::Safe {
+            entry.cleanup.availability = BranchCleanupAvailability::Risky;
+            entry
+                .cleanup
+                .risks
+                .push(BranchCleanupRisk::UnreliableFilesystem);
+        }
+    }
+    entries
 }
 
 fn repo_root_is_bare(git_root: &Path) -> bool {
@@ -177,28 +441,31 @@ pub fn list_branch_entries_with_active_sessions(
     hydrate_branch_entries_with_active_sessions(repo_path, entries, active_session_branches)
 }
 
-fn build_cleanup_targets(
+/// Merge target of each `(local branch, upstream)` pair, or `None` when
+/// `is_cancelled` fires before every branch is checked.
+fn build_cleanup_targets<'a>(
     repo_path: &Path,
-    entries: &[BranchListEntry],
+    locals: impl IntoIterator<Item = (&'a str, Option<&'a str>)>,
     gone_branches: &HashSet<String>,
-) -> std::io::Result<HashMap<String, Option<gwt_git::MergeTargetRef>>> {
+    is_cancelled: &dyn Fn() -> bool,
+) -> std::io::Result<Option<HashMap<String, Option<gwt_git::MergeTargetRef>>>> {
     let remote_names = gwt_git::list_remote_names(repo_path).unwrap_or_default();
     let mut cleanup_targets = HashMap::new();
-    for branch in entries
-        .iter()
-        .filter(|branch| branch.scope == BranchScope::Local)
-    {
+    for (name, upstream) in locals {
+        if is_cancelled() {
+            return Ok(None);
+        }
         let target = gwt_git::detect_cleanable_target_with_remote_names(
             repo_path,
-            &branch.name,
-            branch.upstream.as_deref(),
+            name,
+            upstream,
             gone_branches,
             &remote_names,
         )
         .map_err(|error| std::io::Error::other(error.to_string()))?;
-        cleanup_targets.insert(branch.name.clone(), target);
+        cleanup_targets.insert(name.to_string(), target);
     }
-    Ok(cleanup_targets)
+    Ok(Some(cleanup_targets))
 }
 
 fn git_command_root(repo_path: &Path) -> std::io::Result<std::path::PathBuf> {
@@ -226,6 +493,10 @@ fn adapt_branch_inventory(branches: Vec<gwt_git::Branch>) -> Vec<BranchListEntry
             cleanup: BranchCleanupInfo::default(),
             resume: BranchResumeInfo::unavailable(),
             start_work_eligibility: None,
+            worktree_path: None,
+            archived: false,
+            integrity_issues: Vec::new(),
+            linked_spec: None,
         })
         .collect();
 
@@ -639,6 +910,55 @@ mod tests {
         assert!(b < c, "expected {b} < {c}");
     }
 
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::run_git_logged(args, Some(dir)).unwrap();
+        assert!(
+            output.status.success(),
+            "git {args:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    #[test]
+    fn subset_hydration_returns_only_requested_rows_and_honors_cancel() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--initial-branch=main"]);
+        git(&repo, &["config", "user.email", "t@example.com"]);
+        git(&repo, &["config", "user.name", "T"]);
+        git(&repo, &["commit", "--allow-empty", "-m", "init"]);
+        for branch in ["feature/a", "feature/b", "feature/c"] {
+            git(&repo, &["branch", branch]);
+        }
+        let names = HashSet::from(["feature/b".to_string(), "gone/branch".to_string()]);
+
+        let entries =
+            hydrate_branch_subset_with_active_sessions(&repo, &names, &HashSet::new(), &|| false)
+                .unwrap()
+                .unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "feature/b");
+        assert!(entries[0].cleanup_ready);
+
+        let cancelled =
+            hydrate_branch_subset_with_active_sessions(&repo, &names, &HashSet::new(), &|| true)
+                .unwrap();
+        assert_eq!(cancelled, None);
+    }
+
+    #[test]
+    fn lazy_hydration_starts_above_the_threshold() {
+        let entries = adapt_branch_inventory(vec![
+            make_branch("main", true, true, None);
+            LAZY_HYDRATION_THRESHOLD
+        ]);
+        assert!(!wants_lazy_hydration(&entries));
+        let mut more = entries.clone();
+        more.push(more[0].clone());
+        assert!(wants_lazy_hydration(&more));
+    }
+
     fn make_branch(
         name: &str,
         is_local: bool,
@@ -1011,6 +1331,10 @@ mod tests {
             cleanup: BranchCleanupInfo::default(),
             resume: BranchResumeInfo::unavailable(),
             start_work_eligibility: None,
+            worktree_path: None,
+            archived: false,
+            integrity_issues: Vec::new(),
+            linked_spec: None,
         }];
         let cleanup_targets = HashMap::from([(
             String::from("feature/demo"),
@@ -1050,9 +1374,50 @@ mod tests {
             cleanup: BranchCleanupInfo::default(),
             resume: BranchResumeInfo::unavailable(),
             start_work_eligibility: None,
+            worktree_path: None,
+            archived: false,
+            integrity_issues: Vec::new(),
+            linked_spec: None,
         }
     }
 
+    #[test]
+    fn linked_spec_marks_only_branches_linked_to_spec_issues() {
+        use gwt_github::{CommentSnapshot, IssueNumber, IssueSnapshot, IssueState, UpdatedAt};
+
+        let dir = tempfile::tempdir().unwrap();
+        let issues = gwt_github::Cache::new(dir.path().to_path_buf());
+        for (number, labels) in [(12, vec!["gwt-spec"]), (13, vec!["bug"])] {
+            issues
+                .write_snapshot(&IssueSnapshot {
+                    number: IssueNumber(number),
+                    title: format!("Issue {number}"),
+                    body: String::new(),
+                    labels: labels.into_iter().map(str::to_string).collect(),
+                    state: IssueState::Open,
+                    updated_at: UpdatedAt::new("2026-05-20T00:00:00Z".to_string()),
+                    comments: Vec::<CommentSnapshot>::new(),
+                })
+                .unwrap();
+        }
+        let links = HashMap::from([
+            ("feature/issue-12".to_string(), 12),
+            ("feature/issue-13".to_string(), 13),
+            ("feature/issue-14".to_string(), 14),
+        ]);
+        let mut entries = vec![
+            local_entry("feature/issue-12", false),
+            local_entry("feature/issue-13", false),
+            local_entry("feature/issue-14", false),
+            local_entry("develop", true),
+        ];
+
+        apply_linked_specs(&mut entries, &links, &issues);
+
+        let specs: Vec<_> = entries.iter().map(|entry| entry.linked_spec).collect();
+        assert_eq!(specs, vec![Some(12), None, None, None]);
+    }
+
     #[test]
     fn protected_local_branch_is_risky_selectable_and_remote_protected() {
         // SPEC-2009 FR-070: local main/develop are selectable for LOCAL cleanup
@@ -1075,6 +1440,81 @@ mod tests {
             .contains(&BranchCleanupRisk::ProtectedBase));
     }
 
+    #[test]
+    fn protected_patterns_make_cleanable_branches_risky() {
+        let mut cleanup_targets = HashMap::new();
+        cleanup_targets.insert(
+            "work/keep-logs".to_string(),
+            Some(gwt_git::MergeTargetRef::new(
+                gwt_git::MergeTarget::Gone,
+                "origin/work/keep-logs",
+            )),
+        );
+        let policy = gwt_git::ProtectionPolicy::new(&["work/keep-*".to_string()]);
+        let marked = mark_pattern_protected_cleanup(
+            &policy,
+            hydrate_branch_entries(
+                vec![
+                    local_entry("work/keep-logs", false),
+                    local_entry("work/keep-head", true),
+                    local_entry("work/other", false),
+                ],
+                &HashSet::new(),
+                &cleanup_targets,
+                true,
+            ),
+        );
+        assert_eq!(
+            marked[0].cleanup.availability,
+            BranchCleanupAvailability::Risky
+        );
+        assert_eq!(
+            marked[0].cleanup.risks,
+            vec![BranchCleanupRisk::ProtectedPattern]
+        );
+        assert_eq!(
+            marked[1].cleanup.availability,
+            BranchCleanupAvailability::Blocked
+        );
+        assert!(!marked[2]
+            .cleanup
+            .risks
+            .contains(&BranchCleanupRisk::ProtectedPattern));
+    }
+
+    #[test]
+    fn unreliable_filesystem_downgrades_only_safe_verdicts() {
+        let mut cleanup_targets = HashMap::new();
+        cleanup_targets.insert(
+            "feature/done".to_string(),
+            Some(gwt_git::MergeTargetRef::new(
+                gwt_git::MergeTarget::Gone,
+                "origin/feature/done",
+            )),
+        );
+        let hydrated = downgrade_safe_cleanup(hydrate_branch_entries(
+            vec![
+                local_entry("feature/done", false),
+                local_entry("feature/wip", false),
+            ],
+            &HashSet::new(),
+            &cleanup_targets,
+            true,
+        ));
+        assert_eq!(
+            hydrated[0].cleanup.availability,
+            BranchCleanupAvailability::Risky
+        );
+        assert_eq!(
+            hydrated[0].cleanup.risks,
+            vec![BranchCleanupRisk::UnreliableFilesystem]
+        );
+        assert!(!hydrated[1]
+            .cleanup
+            .risks
+            .contains(&BranchCleanupRisk::UnreliableFilesystem));
+    }
+
     #[test]
     fn protected_branch_checked_out_stays_blocked() {
         // FR-070: a checked-out protected branch is still Blocked (git refuses
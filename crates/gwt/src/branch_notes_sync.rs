@@ -0,0 +1,90 @@
+//! Branch notes ↔ pull request description sync.
+//!
+//! The notes themselves live in [`gwt_core::branch_notes`]. This module
+//! finds the branch's open PR, reads its description, and lets
+//! [`gwt_core::branch_notes::reconcile`] pick the newer copy: local notes
+//! are written into the description's notes section, or the section's
+//! notes are stored locally (e.g. after checking the branch out on another
+//! machine).
+//!
+//! Shared by `worktree.notes` with `sync: true`.
+
+use std::path::{Path, PathBuf};
+
+use gwt_core::branch_notes::{
+    load_branch_notes, parse_pr_section, reconcile, save_branch_notes, splice_pr_section,
+    BranchNotes, NotesSyncAction,
+};
+use gwt_github::client::http::HttpIssueClient;
+use serde::Serialize;
+
+/// Result of [`sync_branch_notes`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BranchNotesSyncOutcome {
+    pub branch: String,
+    pub pr_number: u64,
+    pub action: NotesSyncAction,
+    /// Notes both sides hold after the sync.
+    pub notes: Option<BranchNotes>,
+}
+
+impl BranchNotesSyncOutcome {
+    pub fn summary(&self) -> String {
+        let what = match self.action {
+            NotesSyncAction::Nothing => "no notes on either side",
+            NotesSyncAction::InSync => "already in sync",
+            NotesSyncAction::Push => "wrote local notes to the PR description",
+            NotesSyncAction::Pull => "stored the PR description's notes locally",
+        };
+        format!("{} (PR #{}): {what}", self.branch, self.pr_number)
+    }
+}
+
+/// Machine-local notes store of the repository at `repo_path`.
+pub fn branch_notes_path(repo_path: &Path) -> PathBuf {
+    let main = gwt_git::worktree::main_worktree_root(repo_path)
+        .unwrap_or_else(|_| repo_path.to_path_buf());
+    gwt_core::paths::gwt_notes_state_path_for_repo_path(&main)
+}
+
+/// Sync the notes of `branch` with the description of its open PR.
+pub fn sync_branch_notes(repo_path: &Path, branch: &str) -> Result<BranchNotesSyncOutcome, String> {
+    let main = gwt_git::worktree::main_worktree_root(repo_path)
+        .unwrap_or_else(|_| repo_path.to_path_buf());
+    let pr_number = gwt_git::pr_status::fetch_open_pr_number_for_branch(&main, branch)
+        .or_else(|| crate::pr_create::cached_branch_pr(&main, branch))
+        .ok_or_else(|| format!("{branch} has no pull request to sync notes with"))?;
+    let (owner, repo) = crate::issue_monitor_worker::github_remote_owner_and_repo(&main)
+        .map_err(|error| error.to_string())?;
+    let client = HttpIssueClient::from_gh_auth(&owner, &repo).map_err(|error| error.to_string())?;
+    let body = client
+        .pull_request_body(pr_number)
+        .map_err(|error| error.to_string())?;
+
+    let path = branch_notes_path(&main);
+    let local = load_branch_notes(&path, branch).map_err(|error| error.to_string())?;
+    let remote = parse_pr_section(&body);
+    let action = reconcile(local.as_ref(), remote.as_ref());
+    let notes = match action {
+        NotesSyncAction::Nothing => None,
+        NotesSyncAction::InSync => local,
+        NotesSyncAction::Push => {
+            client
+                .update_pull_request_body(pr_number, &splice_pr_section(&body, local.as_ref()))
+                .map_err(|error| error.to_string())?;
+            local.filter(|notes| !notes.is_empty())
+        }
+        NotesSyncAction::Pull => {
+            if let Some(remote) = &remote {
+                save_branch_notes(&path, branch, remote).map_err(|error| error.to_string())?;
+            }
+            remote
+        }
+    };
+    Ok(BranchNotesSyncOutcome {
+        branch: branch.to_string(),
+        pr_number,
+        action,
+        notes,
+    })
+}
@@ -0,0 +1,123 @@
+//! Branch protection synced from GitHub.
+//!
+//! Branches GitHub protects (branch protection rules or rulesets) are fetched
+//! with `gh api` and stored in the repository's git directory, where
+//! [`gwt_git::is_protected_branch_in`] picks them up. Worktree cleanup,
+//! remote deletion and rebases then refuse them like main / master / develop.
+//! `[branch_protection]` in `~/.gwt/config.toml` turns the sync off or lists
+//! server-protected branches gwt should still treat as ordinary branches.
+//!
+//! [`protection_policy`] turns the `protected_branches` patterns into the
+//! [`gwt_git::ProtectionPolicy`] that cleanup and remote deletion enforce.
+
+use std::path::Path;
+
+use chrono::{Duration, Utc};
+use gwt_config::BranchProtectionConfig;
+
+/// Minimum time between two syncs of one repository.
+pub const SYNC_INTERVAL_MINUTES: i64 = 60;
+
+/// Fetch the protected branches of the repository at `repo_path` and store
+/// them, minus the overrides in `config`. A disabled sync clears the list.
+pub fn sync_protected_branches(
+    repo_path: &Path,
+    config: &BranchProtectionConfig,
+) -> Result<Vec<String>, String> {
+    let branches = if config.sync_from_github {
+        let fetched = gwt_git::branch_protection::fetch_protected_branch_names(repo_path)
+            .map_err(|error| error.to_string())?;
+        apply_overrides(fetched, config)
+    } else {
+        Vec::new()
+    };
+    gwt_git::branch_protection::save_synced_protected_branches(repo_path, &branches)
+        .map_err(|error| error.to_string())?;
+    Ok(branches)
+}
+
+/// [`sync_protected_branches`] with the saved settings, unless the last sync
+/// is younger than [`SYNC_INTERVAL_MINUTES`]. Failures are logged: gwt keeps
+/// the previous list when `gh` is missing or offline.
+pub fn sync_protected_branches_if_stale(repo_path: &Path) {
+    let config = gwt_config::Settings::load()
+        .map(|settings| settings.branch_protection)
+        .unwrap_or_default();
+    let last = gwt_git::branch_protection::load_synced_protected_branches(repo_path);
+    let fresh = last.as_ref().is_some_and(|synced| {
+        Utc::now() - synced.synced_at < Duration::minutes(SYNC_INTERVAL_MINUTES)
+    });
+    let stale_after_disable =
+        !config.sync_from_github && last.is_some_and(|synced| !synced.branches.is_empty());
+    if fresh && !stale_after_disable {
+        return;
+    }
+    if let Err(error) = sync_protected_branches(repo_path, &config) {
+        tracing::warn!(
+            error = %error,
+            repo = %repo_path.display(),
+            "branch protection sync failed"
+        );
+    }
+}
+
+/// Policy for the `protected_branches` patterns in the settings. The
+/// built-in protected branches are left out so they keep their local-only
+/// cleanup (FR-070); their remote is refused regardless.
+pub fn protection_policy() -> gwt_git::ProtectionPolicy {
+    let patterns = gwt_config::Settings::load()
+        .map(|settings| settings.protected_branches)
+        .unwrap_or_default();
+    policy_for_patterns(&patterns)
+}
+
+fn policy_for_patterns(patterns: &[String]) -> gwt_git::ProtectionPolicy {
+    let patterns: Vec<String> = patterns
+        .iter()
+        .filter(|pattern| !gwt_git::is_protected_branch(pattern.trim()))
+        .cloned()
+        .collect();
+    gwt_git::ProtectionPolicy::new(&patterns)
+}
+
+/// Server-protected branches minus the `unprotected` overrides. The built-in
+/// protected branches are left out: their local cleanup rules stay as they
+/// are (FR-070).
+fn apply_overrides(fetched: Vec<String>, config: &BranchProtectionConfig) -> Vec<String> {
+    fetched
+        .into_iter()
+        .filter(|branch| !config.unprotected.contains(branch))
+        .filter(|branch| !gwt_git::is_protected_branch(branch))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_skips_built_in_branches() {
+        let policy = policy_for_patterns(&[
+            "main".to_string(),
+            "develop".to_string(),
+            "work/keep-*".to_string(),
+        ]);
+        assert_eq!(policy.matching_pattern("main"), None);
+        assert_eq!(
+            policy.matching_pattern("work/keep-logs"),
+            Some("work/keep-*")
+        );
+    }
+
+    #[test]
+    fn overrides_and_built_in_branches_are_left_out() {
+        let config = BranchProtectionConfig {
+            sync_from_github: true,
+            unprotected: vec!["staging".to_string()],
+        };
+        let fetched = ["main", "release/1.x", "staging"]
+            .map(str::to_string)
+            .to_vec();
+        assert_eq!(apply_overrides(fetched, &config), ["release/1.x"]);
+    }
+}
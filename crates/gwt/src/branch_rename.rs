@@ -0,0 +1,353 @@
+//! Pattern-based bulk branch rename with gwt metadata migration.
+//!
+//! The git side (branches, worktree moves, upstreams) lives in
+//! [`gwt_git::branch_rename`]. This module adds what only gwt knows about:
+//! persisted agent sessions (their branch, worktree and conversation
+//! history), the issue ↔ branch links, and branch notes. Sessions that are still running
+//! in an affected worktree block the rename. Every metadata file is backed
+//! up before it is rewritten; if any step fails the backups are restored
+//! and the git rename is rolled back, so the rename applies fully or not
+//! at all.
+
+use std::path::{Path, PathBuf};
+
+use gwt_agent::{AgentStatus, Session};
+use gwt_git::branch_rename::{
+    apply_bulk_rename, plan_bulk_rename, BranchRename, RenamePattern, RenamePlan,
+};
+
+/// Result of [`rename_project_branches`].
+#[derive(Debug, Clone)]
+pub struct BulkRenameOutcome {
+    pub plan: RenamePlan,
+    /// `false` for a preview.
+    pub applied: bool,
+    /// Session files rewritten to the new branch / worktree.
+    pub migrated_sessions: usize,
+    /// Issue links moved to the new branch names.
+    pub migrated_issue_links: usize,
+}
+
+/// Preview (`apply == false`) or apply the rename `from` → `to` across the
+/// repository at `project_root`. A preview never fails on conflicts; they
+/// are reported in the plan. Applying a plan with conflicts is an error.
+pub fn rename_project_branches(
+    project_root: &Path,
+    from: &str,
+    to: &str,
+    apply: bool,
+) -> Result<BulkRenameOutcome, String> {
+    let pattern = RenamePattern::parse(from, to).map_err(|error| error.to_string())?;
+    let main_repo_path = gwt_git::worktree::main_worktree_root(project_root)
+        .unwrap_or_else(|_| project_root.to_path_buf());
+    let max_path_len = gwt_config::Settings::load()
+        .ok()
+        .and_then(|settings| settings.worktree_max_path_len);
+    let mut plan = plan_bulk_rename(&main_repo_path, &pattern, max_path_len)
+        .map_err(|error| error.to_string())?;
+    let sessions_dir = gwt_core::paths::gwt_sessions_dir();
+    let repo_hash = crate::index_worker::detect_repo_hash(&main_repo_path)
+        .map(|hash| hash.as_str().to_string());
+    let sessions = load_sessions(&sessions_dir);
+    for (_, session) in &sessions {
+        if let Some(rename) = affected_rename(&plan.renames, session, repo_hash.as_deref()) {
+            if is_live(session.status) {
+                plan.conflicts.push(format!(
+                    "{}: agent session {} is still active in {}",
+                    rename.from,
+                    session.id,
+                    session.worktree_path.display()
+                ));
+            }
+        }
+    }
+
+    let mut outcome = BulkRenameOutcome {
+        plan,
+        applied: false,
+        migrated_sessions: 0,
+        migrated_issue_links: 0,
+    };
+    if !apply {
+        return Ok(outcome);
+    }
+    if outcome.plan.renames.is_empty() {
+        return Err(format!("no local branch matches {from}"));
+    }
+
+    let journal =
+        apply_bulk_rename(&main_repo_path, &outcome.plan).map_err(|error| error.to_string())?;
+    let mut backups = MetadataBackups::default();
+    let migrated = migrate_metadata(
+        &outcome.plan.renames,
+        &sessions,
+        &sessions_dir,
+        &main_repo_path,
+        repo_hash.as_deref(),
+        &mut backups,
+    );
+    match migrated {
+        Ok((sessions, issue_links)) => {
+            outcome.migrated_sessions = sessions;
+            outcome.migrated_issue_links = issue_links;
+        }
+        Err(error) => {
+            let mut undo_errors = backups.restore();
+            undo_errors.extend(journal.rollback());
+            let mut message = format!("metadata migration failed, rename rolled back: {error}");
+            if !undo_errors.is_empty() {
+                message.push_str(&format!(
+                    "; rollback incomplete: {}",
+                    undo_errors.join("; ")
+                ));
+            }
+            return Err(message);
+        }
+    }
+    outcome.applied = true;
+
+    Ok(outcome)
+}
+
+impl BulkRenameOutcome {
+    /// Human-readable preview or result table.
+    pub fn summary(&self) -> String {
+        let mut out = String::new();
+        if self.plan.renames.is_empty() {
+            out.push_str("No local branch matches the pattern.\n");
+            return out;
+        }
+        out.push_str(if self.applied {
+            "Renamed:\n"
+        } else {
+            "Would rename:\n"
+        });
+        for rename in &self.plan.renames {
+            out.push_str(&format!("  {} -> {}\n", rename.from, rename.to));
+            if let (Some(from), Some(to)) = (&rename.worktree, &rename.new_worktree) {
+                out.push_str(&format!(
+                    "    worktree {} -> {}\n",
+                    from.display(),
+                    to.display()
+                ));
+            }
+            match (&rename.upstream, &rename.new_upstream) {
+                (Some(from), Some(to)) => {
+                    out.push_str(&format!("    upstream {from} -> {to}\n"));
+                }
+                (Some(upstream), None) => {
+                    out.push_str(&format!("    upstream stays {upstream}\n"));
+                }
+                _ => {}
+            }
+        }
+        if !self.plan.conflicts.is_empty() {
+            out.push_str("Blocked:\n");
+            for conflict in &self.plan.conflicts {
+                out.push_str(&format!("  {conflict}\n"));
+            }
+        }
+        if self.applied {
+            out.push_str(&format!(
+                "Migrated {} session(s) and {} issue link(s). Remote branches were not renamed.\n",
+                self.migrated_sessions, self.migrated_issue_links
+            ));
+        } else if self.plan.conflicts.is_empty() {
+            out.push_str("Preview only; pass apply to rename. Remote branches are not renamed.\n");
+        }
+        out
+    }
+}
+
+fn is_live(status: AgentStatus) -> bool {
+    matches!(
+        status,
+        AgentStatus::Running | AgentStatus::WaitingInput | AgentStatus::Stalled
+    )
+}
+
+/// The rename touching `session`: same worktree, or same branch in the
+/// same repository.
+fn affected_rename<'a>(
+    renames: &'a [BranchRename],
+    session: &Session,
+    repo_hash: Option<&str>,
+) -> Option<&'a BranchRename> {
+    renames.iter().find(|rename| {
+        let same_worktree = rename.worktree.as_deref() == Some(session.worktree_path.as_path());
+        let same_repo = repo_hash.is_some() && session.repo_hash.as_deref() == repo_hash;
+        session.branch == rename.from && (same_worktree || same_repo)
+    })
+}
+
+fn load_sessions(sessions_dir: &Path) -> Vec<(PathBuf, Session)> {
+    let Ok(entries) = std::fs::read_dir(sessions_dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("toml"))
+        .filter_map(|path| Session::load(&path).ok().map(|session| (path, session)))
+        .collect()
+}
+
+/// Rewrite sessions, issue links and branch notes; returns how many
+/// sessions and issue links changed.
+fn migrate_metadata(
+    renames: &[BranchRename],
+    sessions: &[(PathBuf, Session)],
+    sessions_dir: &Path,
+    repo_path: &Path,
+    repo_hash: Option<&str>,
+    backups: &mut MetadataBackups,
+) -> Result<(usize, usize), String> {
+    let mut migrated_sessions = 0;
+    for (path, session) in sessions {
+        let Some(rename) = affected_rename(renames, session, repo_hash) else {
+            continue;
+        };
+        backups.keep(path)?;
+        let mut session = session.clone();
+        session.branch = rename.to.clone();
+        if let (Some(from), Some(to)) = (&rename.worktree, &rename.new_worktree) {
+            if &session.worktree_path == from {
+                session.worktree_path = to.clone();
+            }
+            if session.project_state_root.as_ref() == Some(from) {
+                session.project_state_root = Some(to.clone());
+            }
+        }
+        session
+            .save(sessions_dir)
+            .map_err(|error| format!("{}: {error}", path.display()))?;
+        migrated_sessions += 1;
+    }
+
+    let migrated_links = match repo_hash {
+        Some(hash) => {
+            let path = gwt_core::paths::gwt_cache_dir()
+                .join("issue-links")
+                .join(format!("{hash}.json"));
+            migrate_issue_links(&path, renames, backups)?
+        }
+        None => {
+            tracing::debug!(
+                repo = %repo_path.display(),
+                "repository hash unavailable; issue links not migrated"
+            );
+            0
+        }
+    };
+    let notes_path = crate::branch_notes_sync::branch_notes_path(repo_path);
+    if notes_path.exists() {
+        backups.keep(&notes_path)?;
+        for rename in renames {
+            gwt_core::branch_notes::rename_branch_notes(&notes_path, &rename.from, &rename.to)
+                .map_err(|error| format!("{}: {error}", notes_path.display()))?;
+        }
+    }
+    Ok((migrated_sessions, migrated_links))
+}
+
+/// Move `branches` keys of the issue-link store to their new names. Other
+/// fields of the store are kept as they are.
+fn migrate_issue_links(
+    path: &Path,
+    renames: &[BranchRename],
+    backups: &mut MetadataBackups,
+) -> Result<usize, String> {
+    let bytes = match std::fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(error) => return Err(format!("{}: {error}", path.display())),
+    };
+    let mut store: serde_json::Value =
+        serde_json::from_slice(&bytes).map_err(|error| format!("{}: {error}", path.display()))?;
+    let Some(branches) = store
+        .get_mut("branches")
+        .and_then(serde_json::Value::as_object_mut)
+    else {
+        return Ok(0);
+    };
+    let mut migrated = 0;
+    for rename in renames {
+        if let Some(issue) = branches.remove(&rename.from) {
+            branches.insert(rename.to.clone(), issue);
+            migrated += 1;
+        }
+    }
+    if migrated == 0 {
+        return Ok(0);
+    }
+    backups.keep(path)?;
+    let bytes = serde_json::to_vec_pretty(&store)
+        .map_err(|error| format!("{}: {error}", path.display()))?;
+    gwt_github::cache::write_atomic(path, &bytes)
+        .map_err(|error| format!("{}: {error}", path.display()))?;
+    Ok(migrated)
+}
+
+/// Original bytes of every metadata file rewritten so far.
+#[derive(Default)]
+struct MetadataBackups {
+    files: Vec<(PathBuf, Vec<u8>)>,
+}
+
+impl MetadataBackups {
+    fn keep(&mut self, path: &Path) -> Result<(), String> {
+        let bytes = std::fs::read(path).map_err(|error| format!("{}: {error}", path.display()))?;
+        self.files.push((path.to_path_buf(), bytes));
+        Ok(())
+    }
+
+    fn restore(self) -> Vec<String> {
+        self.files
+            .into_iter()
+            .rev()
+            .filter_map(|(path, bytes)| {
+                gwt_github::cache::write_atomic(&path, &bytes)
+                    .err()
+                    .map(|error| format!("restore {}: {error}", path.display()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rename(from: &str, to: &str) -> BranchRename {
+        BranchRename {
+            from: from.to_string(),
+            to: to.to_string(),
+            worktree: Some(PathBuf::from(format!("/wt/{from}"))),
+            new_worktree: Some(PathBuf::from(format!("/wt/{to}"))),
+            upstream: None,
+            new_upstream: None,
+        }
+    }
+
+    #[test]
+    fn issue_links_move_to_new_branch_names_and_restore() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("links.json");
+        let original = r#"{"branches":{"users/me/a":12,"main":1}}"#;
+        std::fs::write(&path, original).unwrap();
+        let mut backups = MetadataBackups::default();
+
+        let migrated =
+            migrate_issue_links(&path, &[rename("users/me/a", "feature/a")], &mut backups).unwrap();
+
+        assert_eq!(migrated, 1);
+        let store: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(
+            store["branches"],
+            serde_json::json!({ "feature/a": 12, "main": 1 })
+        );
+        assert!(backups.restore().is_empty());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), original);
+    }
+}
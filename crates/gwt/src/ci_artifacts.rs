@@ -0,0 +1,24 @@
+//! CI artifact downloads from the Branches list.
+//!
+//! `a` on a branch row downloads the artifacts of the branch's latest
+//! completed workflow runs into its worktree, so build outputs and test
+//! reports can be inspected without re-running the pipeline. The download
+//! itself lives in [`gwt_git::ci_artifacts`].
+
+use std::path::Path;
+
+use crate::worktree_integrity::branch_worktree;
+
+/// Download the CI artifacts of `branch` into its worktree. Returns a
+/// one-line summary for the Branches notice.
+pub fn download_branch_ci_artifacts(
+    project_root: &Path,
+    branch: &str,
+    pattern: Option<&str>,
+) -> Result<String, String> {
+    let worktree =
+        branch_worktree(project_root, branch).ok_or_else(|| format!("{branch} has no worktree"))?;
+    gwt_git::ci_artifacts::download_ci_artifacts(&worktree, branch, pattern)
+        .map(|download| download.summary())
+        .map_err(|error| error.to_string())
+}
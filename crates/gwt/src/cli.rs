@@ -10,12 +10,16 @@ pub(crate) mod artifact_operability;
 mod board;
 mod build;
 mod commands;
+pub mod completions;
+mod config;
 pub mod daemon;
+mod deps;
 mod diagnostics;
 mod discuss;
 pub(crate) mod discussion;
 mod env;
 pub mod execution_state;
+mod git_hooks;
 pub mod gwtd_resolver;
 pub mod hook;
 pub mod improvement;
@@ -23,18 +27,29 @@ pub mod improvement_contract;
 mod improvement_owner;
 mod improvement_store;
 pub(crate) mod index;
+mod insights;
 pub(crate) mod intake_outcome;
 pub(crate) mod issue;
 mod issue_spec;
+mod issue_work;
+mod jobs;
 mod json_envelope;
+mod locks;
+mod logs;
+mod mcp;
 pub(crate) mod memory;
+mod migrate;
 pub mod open;
 mod pane;
+mod plain_ui;
 mod plan;
 mod pr;
+mod profile;
 pub(crate) mod register;
 pub(crate) mod search;
+mod session;
 mod skill_state_runtime;
+mod table;
 #[cfg(test)]
 mod test_support;
 mod title_summary_guard;
@@ -45,6 +60,7 @@ pub mod verification_record;
 pub(crate) mod verify_derivation;
 mod workflow;
 mod workspace;
+pub(crate) mod worktree;
 
 use std::{
     io::{self},
@@ -53,16 +69,27 @@ use std::{
 
 pub use board::{BoardCommand, BoardPostCommand};
 pub use commands::{IssueCommand, PrCommand};
+pub use config::ConfigCommand;
+pub use deps::DepsCommand;
 pub use diagnostics::DiagnosticsCommand;
 pub use discuss::DiscussAction;
 pub use discussion::DiscussionCommand;
 pub(crate) use env::ClientRef;
 pub use env::{dispatch, CliEnv, DefaultCliEnv, TargetIssueCreateCall, TestEnv};
+pub use git_hooks::HooksCommand;
 use gwt_github::{ApiError, SpecOpsError};
 pub use improvement::ImprovementCommand;
 pub use index::{IndexCommand, IndexScope};
+pub use insights::InsightsCommand;
+pub use jobs::JobsCommand;
+pub use locks::LocksCommand;
+pub use logs::LogsCommand;
+pub use mcp::McpCommand;
 pub use memory::MemoryCommand;
+pub use migrate::MigrateCommand;
+pub use profile::ProfileCommand;
 pub use search::SearchCommand;
+pub use session::SessionCommand;
 pub(crate) use title_summary_guard::validate_title_summary_work_name;
 
 /// Compact linked PR summary used by `issue.linked_prs`.
@@ -159,11 +186,41 @@ pub enum CliCommand {
     Actions(ActionsCommand),
     Board(BoardCommand),
     Hook(HookCommand),
+    /// `gwt hooks install|uninstall`: git hooks that report commits and
+    /// pushes to gwt.
+    Hooks(HooksCommand),
     Improvement(ImprovementCommand),
     Index(IndexCommand),
     /// SPEC-3248 P7A: `intake.outcome.record` JSON operation (FR-012).
     Intake(intake_outcome::IntakeCommand),
     Diagnostics(DiagnosticsCommand),
+    /// `gwt insights show|export|reset` over the local usage counters.
+    Insights(InsightsCommand),
+    /// `gwt run` / `gwt jobs status|log|events|conduct|pair`: headless agent jobs.
+    Jobs(JobsCommand),
+    /// `gwt locks [--stale]` / `gwt locks release <path> [--force]` over
+    /// the lock files under `~/.gwt`.
+    Locks(LocksCommand),
+    /// `gwt logs purge [--dry-run]` over the project's log directory.
+    Logs(LogsCommand),
+    /// `gwt mcp serve --stdio`: MCP server with worktree tools for agents.
+    Mcp(McpCommand),
+    /// `gwt migrate to-normal [--branch <name>]`: leave the Bare+Worktree
+    /// layout for a Normal repository.
+    Migrate(MigrateCommand),
+    /// `gwt config validate [--path <file>]` over `~/.gwt/config.toml`.
+    Config(ConfigCommand),
+    /// `gwt profile export <name>` / `gwt profile import --file <path>`:
+    /// profile files for sharing and `.gwt/profiles/`;
+    /// `gwt profile migrate-secrets` moves plaintext credentials into the
+    /// OS keychain.
+    Profile(ProfileCommand),
+    /// `gwt session export|diff|bundle <branch>` / `gwt session replay-plan`:
+    /// agent session transcripts of a branch, the comparison of two of its
+    /// runs, and bug-report bundles of them.
+    Session(SessionCommand),
+    /// `gwt deps upgrade`: dependency upgrade in a canary worktree.
+    Deps(DepsCommand),
     Memory(MemoryCommand),
     Discuss(DiscussCommand),
     Discussion(DiscussionCommand),
@@ -177,6 +234,7 @@ pub enum CliCommand {
     Verify(verification_record::VerifyCommand),
     Daemon(DaemonCommand),
     Workspace(WorkspaceCommand),
+    Worktree(worktree::WorktreeCommand),
     Workflow(WorkflowCommand),
     Pane(PaneCommand),
     /// SPEC #2920 FR-006: `gwt open` reads tray lock + opens browser.
@@ -287,10 +345,17 @@ pub enum HookCommand {
 /// SPEC-1942 command model for update and internal updater operations.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UpdateCommand {
-    /// Update check-only mode.
-    CheckOnly,
+    /// Update check-only mode. `channel` overrides the configured
+    /// `[update] channel` for this run.
+    CheckOnly {
+        channel: Option<gwt_core::update::UpdateChannel>,
+    },
     /// Check and, with approval, download and apply.
-    Apply,
+    Apply {
+        channel: Option<gwt_core::update::UpdateChannel>,
+    },
+    /// `gwtd update rollback` — restore the binary the last update replaced.
+    Rollback,
     /// `gwtd __internal apply-update ...`.
     InternalApply { rest: Vec<String> },
     /// `gwtd __internal run-installer ...`.
@@ -388,10 +453,22 @@ pub fn should_dispatch_cli(args: &[String]) -> bool {
                     | "actions"
                     | "board"
                     | "hook"
+                    | "hooks"
                     | "update"
                     | "__internal"
                     | "index"
                     | "diagnostics"
+                    | "insights"
+                    | "logs"
+                    | "locks"
+                    | "mcp"
+                    | "migrate"
+                    | "config"
+                    | "profile"
+                    | "session"
+                    | "deps"
+                    | "run"
+                    | "jobs"
                     | "memory"
                     | "lessons"
                     | "discuss"
@@ -404,6 +481,7 @@ pub fn should_dispatch_cli(args: &[String]) -> bool {
                     | "pane"
                     | "open"
                     | "search"
+                    | "--plain-ui"
             )
         })
         .unwrap_or(false)
@@ -436,6 +514,66 @@ pub fn parse_index_args(args: &[String]) -> Result<CliCommand, CliParseError> {
     index::parse(args).map(CliCommand::Index)
 }
 
+/// Parse an `insights ...` argv slice into a [`CliCommand`].
+pub fn parse_insights_args(args: &[String]) -> Result<CliCommand, CliParseError> {
+    insights::parse(args).map(CliCommand::Insights)
+}
+
+/// Parse a `logs ...` argv slice into a [`CliCommand`].
+pub fn parse_logs_args(args: &[String]) -> Result<CliCommand, CliParseError> {
+    logs::parse(args).map(CliCommand::Logs)
+}
+
+/// Parse a `config ...` argv slice into a [`CliCommand`].
+pub fn parse_config_args(args: &[String]) -> Result<CliCommand, CliParseError> {
+    config::parse(args).map(CliCommand::Config)
+}
+
+/// Parse a `locks ...` argv slice into a [`CliCommand`].
+pub fn parse_locks_args(args: &[String]) -> Result<CliCommand, CliParseError> {
+    locks::parse(args).map(CliCommand::Locks)
+}
+
+/// Parse a `hooks ...` argv slice into a [`CliCommand`].
+pub fn parse_hooks_args(args: &[String]) -> Result<CliCommand, CliParseError> {
+    git_hooks::parse(args).map(CliCommand::Hooks)
+}
+
+/// Parse a `mcp ...` argv slice into a [`CliCommand`].
+pub fn parse_mcp_args(args: &[String]) -> Result<CliCommand, CliParseError> {
+    mcp::parse(args).map(CliCommand::Mcp)
+}
+
+/// Parse a `migrate ...` argv slice into a [`CliCommand`].
+pub fn parse_migrate_args(args: &[String]) -> Result<CliCommand, CliParseError> {
+    migrate::parse(args).map(CliCommand::Migrate)
+}
+
+/// Parse a `profile ...` argv slice into a [`CliCommand`].
+pub fn parse_profile_args(args: &[String]) -> Result<CliCommand, CliParseError> {
+    profile::parse(args).map(CliCommand::Profile)
+}
+
+/// Parse a `session ...` argv slice into a [`CliCommand`].
+pub fn parse_session_args(args: &[String]) -> Result<CliCommand, CliParseError> {
+    session::parse(args).map(CliCommand::Session)
+}
+
+/// Parse a `deps ...` argv slice into a [`CliCommand`].
+pub fn parse_deps_args(args: &[String]) -> Result<CliCommand, CliParseError> {
+    deps::parse(args).map(CliCommand::Deps)
+}
+
+/// Parse a `run ...` argv slice into a [`CliCommand`].
+pub fn parse_run_args(args: &[String]) -> Result<CliCommand, CliParseError> {
+    jobs::parse_run(args).map(CliCommand::Jobs)
+}
+
+/// Parse a `jobs ...` argv slice into a [`CliCommand`].
+pub fn parse_jobs_args(args: &[String]) -> Result<CliCommand, CliParseError> {
+    jobs::parse_jobs(args).map(CliCommand::Jobs)
+}
+
 /// Parse a legacy `diagnostics ...` argv slice into a [`CliCommand`].
 pub fn parse_diagnostics_args(args: &[String]) -> Result<CliCommand, CliParseError> {
     diagnostics::parse(args).map(CliCommand::Diagnostics)
@@ -686,11 +824,25 @@ pub(crate) fn run_collect<E: CliEnv>(
             0
         }
         CliCommand::Diagnostics(inner) => diagnostics::run(env, inner, &mut out)?,
-        CliCommand::Update(UpdateCommand::CheckOnly) => {
-            std::process::exit(update::run(update::UpdateRunMode::CheckOnly));
+        CliCommand::Insights(inner) => insights::run(env, inner, &mut out)?,
+        CliCommand::Logs(inner) => logs::run(env, inner, &mut out)?,
+        CliCommand::Locks(inner) => locks::run(env, inner, &mut out)?,
+        CliCommand::Hooks(inner) => git_hooks::run(env, inner, &mut out)?,
+        CliCommand::Mcp(McpCommand::Serve) => std::process::exit(mcp::run_stdio(env)),
+        CliCommand::Migrate(inner) => migrate::run(env, inner, &mut out)?,
+        CliCommand::Config(inner) => config::run(env, inner, &mut out)?,
+        CliCommand::Profile(inner) => profile::run(env, inner, &mut out)?,
+        CliCommand::Session(inner) => session::run(env, inner, &mut out)?,
+        CliCommand::Deps(inner) => deps::run(env, inner, &mut out)?,
+        CliCommand::Jobs(inner) => jobs::run(env, inner, &mut out)?,
+        CliCommand::Update(UpdateCommand::CheckOnly { channel }) => {
+            std::process::exit(update::run(update::UpdateRunMode::CheckOnly, channel));
+        }
+        CliCommand::Update(UpdateCommand::Apply { channel }) => {
+            std::process::exit(update::run(update::UpdateRunMode::Apply, channel));
         }
-        CliCommand::Update(UpdateCommand::Apply) => {
-            std::process::exit(update::run(update::UpdateRunMode::Apply));
+        CliCommand::Update(UpdateCommand::Rollback) => {
+            std::process::exit(update::run_rollback());
         }
         CliCommand::Update(UpdateCommand::InternalApply { rest }) => {
             std::process::exit(update::run_internal_apply_update(&rest));
@@ -700,6 +852,7 @@ pub(crate) fn run_collect<E: CliEnv>(
         }
         CliCommand::Daemon(inner) => daemon::run(env, inner, &mut out)?,
         CliCommand::Workspace(inner) => workspace::run(env, inner, &mut out)?,
+        CliCommand::Worktree(inner) => worktree::run(env, inner, &mut out)?,
         CliCommand::Workflow(inner) => workflow::run(env, inner, &mut out)?,
         CliCommand::Pane(inner) => pane::run(env, inner, &mut out)?,
         CliCommand::Open(args) => open::run(env, args, &mut out)?,
@@ -734,209 +887,4 @@ pub(crate) fn fake_gh_test_lock() -> &'static std::sync::Mutex<()> {
 mod family_split_tests;
 
 #[cfg(test)]
-mod tests {
-    use tempfile::tempdir;
-
-    use gwt_github::{IssueNumber, IssueSnapshot, IssueState};
-
-    use super::*;
-
-    #[test]
-    fn render_helpers_include_issue_pr_and_review_details() {
-        let mut out = String::new();
-        let issue = crate::cli::test_support::sample_issue_snapshot();
-        let pr = crate::cli::test_support::sample_pr_status();
-
-        crate::cli::issue::render_issue(&mut out, &issue);
-        crate::cli::issue::render_issue_comments(&mut out, &issue);
-        crate::cli::issue::render_linked_prs(
-            &mut out,
-            &[LinkedPrSummary {
-                number: 128,
-                title: "Enforce coverage".to_string(),
-                state: "OPEN".to_string(),
-                url: pr.url.clone(),
-                will_close_target: true,
-            }],
-        );
-        crate::cli::pr::render_pr(&mut out, &pr);
-        crate::cli::pr::render_pr_checks(
-            &mut out,
-            &PrChecksSummary {
-                summary: "All checks passed".to_string(),
-                ci_status: "SUCCESS".to_string(),
-                merge_status: "MERGEABLE".to_string(),
-                review_status: "APPROVED".to_string(),
-                checks: vec![PrCheckItem {
-                    name: "CI".to_string(),
-                    state: "COMPLETED".to_string(),
-                    conclusion: "SUCCESS".to_string(),
-                    url: "https://github.com/akiojin/gwt/actions/runs/1".to_string(),
-                    started_at: "2026-04-20T00:00:00Z".to_string(),
-                    completed_at: "2026-04-20T00:01:00Z".to_string(),
-                    workflow: "coverage".to_string(),
-                }],
-            },
-        );
-        crate::cli::pr::render_pr_reviews(
-            &mut out,
-            &[PrReview {
-                id: "review-1".to_string(),
-                state: "APPROVED".to_string(),
-                author: "reviewer".to_string(),
-                submitted_at: "2026-04-20T02:00:00Z".to_string(),
-                body: "Looks good.".to_string(),
-            }],
-        );
-        crate::cli::pr::render_pr_review_threads(
-            &mut out,
-            &[PrReviewThread {
-                comments: vec![PrReviewThreadComment {
-                    id: "comment-1".to_string(),
-                    body: "Please add a push gate.".to_string(),
-                    created_at: "2026-04-20T03:00:00Z".to_string(),
-                    updated_at: "2026-04-20T03:00:00Z".to_string(),
-                    author: "reviewer".to_string(),
-                }],
-                ..crate::cli::test_support::sample_thread()
-            }],
-        );
-
-        assert!(out.contains("#42 [OPEN] Coverage gate"));
-        assert!(out.contains("labels: gwt-spec, coverage"));
-        assert!(out.contains("=== comment:7 (2026-04-20T01:00:00Z) ==="));
-        assert!(out.contains("#128 [OPEN] Enforce coverage"));
-        assert!(out.contains("ci: SUCCESS"));
-        assert!(out.contains("merge_state: CLEAN"));
-        assert!(out.contains("workflow: coverage"));
-        assert!(out.contains("=== review:review-1 [APPROVED] by reviewer"));
-        assert!(out.contains(
-            "=== thread:thread-1 resolved=false outdated=false path=src/lib.rs line=12 ==="
-        ));
-    }
-
-    #[test]
-    fn cache_and_parse_helpers_cover_fallback_paths() {
-        let temp = tempdir().expect("tempdir");
-        let number = IssueNumber(77);
-        let linked_prs = vec![LinkedPrSummary {
-            number: 9,
-            title: "Hook coverage".to_string(),
-            state: "MERGED".to_string(),
-            url: "https://github.com/akiojin/gwt/pull/9".to_string(),
-            will_close_target: true,
-        }];
-
-        assert!(
-            crate::cli::issue::read_linked_prs_cache(temp.path(), number)
-                .unwrap()
-                .is_none()
-        );
-        crate::cli::issue::write_linked_prs_cache(temp.path(), number, &linked_prs).unwrap();
-        assert_eq!(
-            crate::cli::issue::read_linked_prs_cache(temp.path(), number).unwrap(),
-            Some(linked_prs)
-        );
-
-        assert_eq!(
-            crate::cli::pr::extract_pr_url(
-                "note\n https://github.com/akiojin/gwt/pull/55 \nignored"
-            ),
-            Some("https://github.com/akiojin/gwt/pull/55".to_string())
-        );
-        assert_eq!(
-            crate::cli::pr::parse_pr_number_from_url("https://github.com/akiojin/gwt/pull/55/"),
-            Some(55)
-        );
-        assert_eq!(
-            crate::cli::pr::parse_available_fields("error\nAvailable fields:\n  number\n  title\n"),
-            vec!["number".to_string(), "title".to_string()]
-        );
-
-        let checks = crate::cli::pr::parse_pr_checks_items_json(
-            r#"[{"name":"coverage","status":"IN_PROGRESS","bucket":"pending","link":"https://example.com/check"}]"#,
-        )
-        .unwrap();
-        assert_eq!(checks[0].state, "IN_PROGRESS");
-        assert_eq!(checks[0].conclusion, "pending");
-        assert_eq!(checks[0].url, "https://example.com/check");
-    }
-
-    #[test]
-    fn parse_and_guard_helpers_cover_additional_error_paths() {
-        assert!(should_dispatch_cli(&[
-            "gwt".to_string(),
-            "issue".to_string()
-        ]));
-        assert!(!should_dispatch_cli(&[
-            "gwt".to_string(),
-            "help".to_string()
-        ]));
-
-        let check = "--check".to_string();
-        assert!(expect_flag(Some(&check), "--check").is_ok());
-        assert!(expect_flag(Some(&check), "--refresh").is_err());
-
-        let number = "42".to_string();
-        let bad_number = "abc".to_string();
-        assert_eq!(parse_required_number(Some(&number)).unwrap(), 42);
-        assert!(parse_required_number(Some(&bad_number)).is_err());
-        assert!(ensure_no_remaining_args([].iter()).is_ok());
-        assert!(ensure_no_remaining_args([check].iter()).is_err());
-
-        assert!(matches!(parse_hook_args(&[]), Err(CliParseError::Usage)));
-        assert_eq!(
-            crate::cli::issue::issue_state_label(IssueState::Closed),
-            "CLOSED"
-        );
-        assert!(io_as_api_error(io::Error::other("boom"))
-            .to_string()
-            .contains("boom"));
-        assert!(crate::cli::pr::edit_or_create_repo_guard("", "repo").is_err());
-        assert!(crate::cli::pr::edit_or_create_repo_guard("akiojin", "gwt").is_ok());
-    }
-
-    #[test]
-    fn render_helpers_cover_empty_states_and_url_parsing_fallbacks() {
-        let issue = IssueSnapshot {
-            comments: Vec::new(),
-            ..crate::cli::test_support::sample_issue_snapshot()
-        };
-        let mut out = String::new();
-
-        crate::cli::issue::render_issue_comments(&mut out, &issue);
-        crate::cli::issue::render_linked_prs(&mut out, &[]);
-        crate::cli::pr::render_pr_checks(
-            &mut out,
-            &PrChecksSummary {
-                summary: "pending".to_string(),
-                ci_status: "PENDING".to_string(),
-                merge_status: "UNKNOWN".to_string(),
-                review_status: "PENDING".to_string(),
-                checks: Vec::new(),
-            },
-        );
-        crate::cli::pr::render_pr_reviews(&mut out, &[]);
-        crate::cli::pr::render_pr_review_threads(&mut out, &[]);
-
-        assert!(out.contains("no comments"));
-        assert!(out.contains("no linked pull requests"));
-        assert!(out.contains("no checks"));
-        assert!(out.contains("no reviews"));
-        assert!(out.contains("no review threads"));
-        assert_eq!(crate::cli::pr::extract_pr_url("no pull request here"), None);
-        assert_eq!(
-            crate::cli::pr::parse_pr_number_from_url(
-                "https://github.com/akiojin/gwt/pull/not-a-number"
-            ),
-            None
-        );
-        assert!(crate::cli::pr::parse_available_fields("plain error").is_empty());
-        assert_eq!(
-            crate::cli::pr::parse_available_fields(
-                "oops\nAvailable fields:\n  number\n\n  title\n"
-            ),
-            vec!["number".to_string(), "title".to_string()]
-        );
-    }
-}
+mod tests;
@@ -104,6 +104,19 @@ pub enum IssueCommand {
         reviewed_sha: String,
         verdict_raw: String,
     },
+    /// `gwt issue list [--plain]`: open issues in the local cache.
+    List {
+        plain: bool,
+    },
+    /// `gwt issue start <n>`: queue an agent job on a branch named after the
+    /// issue, prompted with its body.
+    Start {
+        number: u64,
+        agent: String,
+        branch: Option<String>,
+        ai: bool,
+        detach: bool,
+    },
 }
 
 /// SPEC-1942 command model for `pr.*` JSON operations.
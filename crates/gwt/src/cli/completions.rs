@@ -0,0 +1,254 @@
+//! Shell completion for the `gwtd` argv surface.
+//!
+//! `gwtd completions <bash|zsh|fish|powershell>` prints a script for the
+//! requested shell. Every script is a thin shim that forwards the words on
+//! the command line to `gwtd __complete args -- <words...>`, so candidate
+//! logic lives here and stays identical across shells:
+//!
+//! - `gwtd __complete args -- <words...>` completes the last word.
+//! - `gwtd __complete branches [prefix]` lists local and remote branch names.
+//! - `gwtd __complete worktrees [prefix]` lists worktree paths.
+//!
+//! The helper never fails loudly: a missing repository yields no candidates.
+
+use std::{collections::BTreeSet, path::Path};
+
+use gwt_git::WorktreeManager;
+
+/// Shells `gwtd completions` can generate a script for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionShell {
+    Bash,
+    Zsh,
+    Fish,
+    PowerShell,
+}
+
+impl CompletionShell {
+    pub const NAMES: [&'static str; 4] = ["bash", "zsh", "fish", "powershell"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "bash" => Some(Self::Bash),
+            "zsh" => Some(Self::Zsh),
+            "fish" => Some(Self::Fish),
+            "powershell" | "pwsh" => Some(Self::PowerShell),
+            _ => None,
+        }
+    }
+}
+
+/// Top-level argv words accepted by `gwtd` outside the JSON envelope.
+const TOP_LEVEL_WORDS: &[&str] = &["--help", "--version", "completions", "hook"];
+const HOOK_SUBCOMMANDS: &[&str] = &["event", "provider-event", "gwt-self-improvement-stop"];
+const HOOK_EVENTS: &[&str] = &[
+    "PreToolUse",
+    "PostToolUse",
+    "SessionStart",
+    "Stop",
+    "UserPromptSubmit",
+];
+const HELPER_KINDS: &[&str] = &["args", "branches", "worktrees"];
+
+/// Render the completion script for `shell`, registered for `bin`.
+pub fn completion_script(shell: CompletionShell, bin: &str) -> String {
+    let function = format!("_{}", bin.replace(['-', '.'], "_"));
+    match shell {
+        CompletionShell::Bash => format!(
+            "# bash completion for {bin}\n\
+             {function}() {{\n\
+             \x20   local IFS=$'\\n'\n\
+             \x20   COMPREPLY=($({bin} __complete args -- \"${{COMP_WORDS[@]:1:COMP_CWORD}}\" 2>/dev/null))\n\
+             }}\n\
+             complete -o default -F {function} {bin}\n"
+        ),
+        CompletionShell::Zsh => format!(
+            "#compdef {bin}\n\
+             {function}() {{\n\
+             \x20   local -a candidates\n\
+             \x20   candidates=(\"${{(@f)$({bin} __complete args -- \"${{(@)words[2,CURRENT]}}\" 2>/dev/null)}}\")\n\
+             \x20   compadd -a candidates\n\
+             }}\n\
+             compdef {function} {bin}\n"
+        ),
+        CompletionShell::Fish => format!(
+            "# fish completion for {bin}\n\
+             function __{function}_complete\n\
+             \x20   set -l words (commandline -opc)\n\
+             \x20   set -e words[1]\n\
+             \x20   {bin} __complete args -- $words (commandline -ct) 2>/dev/null\n\
+             end\n\
+             complete -c {bin} -f -a '(__{function}_complete)'\n"
+        ),
+        CompletionShell::PowerShell => format!(
+            "# PowerShell completion for {bin}\n\
+             Register-ArgumentCompleter -Native -CommandName {bin} -ScriptBlock {{\n\
+             \x20   param($wordToComplete, $commandAst, $cursorPosition)\n\
+             \x20   $words = @($commandAst.CommandElements | Select-Object -Skip 1 | ForEach-Object {{ $_.ToString() }})\n\
+             \x20   if ($wordToComplete -eq '') {{ $words += '' }}\n\
+             \x20   & {bin} __complete args -- @words 2>$null | ForEach-Object {{\n\
+             \x20       [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)\n\
+             \x20   }}\n\
+             }}\n"
+        ),
+    }
+}
+
+/// Run the `__complete` helper. `args` are the words after `__complete`;
+/// `families` are the names accepted by `gwtd --help <family>`.
+pub fn run_helper(args: &[String], families: &[&str], repo_path: &Path) -> Vec<String> {
+    let prefix = args.get(1).map(String::as_str).unwrap_or("");
+    match args.first().map(String::as_str) {
+        Some("args") => {
+            let words = match args.get(1).map(String::as_str) {
+                Some("--") => &args[2..],
+                _ => &args[1..],
+            };
+            complete_args(words, families)
+        }
+        Some("branches") => filter_prefix(branch_candidates(repo_path), prefix),
+        Some("worktrees") => filter_prefix(worktree_candidates(repo_path), prefix),
+        _ => Vec::new(),
+    }
+}
+
+/// Candidates for the last entry of `words` (the word under the cursor),
+/// given the words that precede it.
+pub fn complete_args(words: &[String], families: &[&str]) -> Vec<String> {
+    let (current, previous) = match words.split_last() {
+        Some((current, previous)) => (current.as_str(), previous),
+        None => ("", words),
+    };
+    let previous: Vec<&str> = previous.iter().map(String::as_str).collect();
+    let candidates: &[&str] = match previous.as_slice() {
+        [] => TOP_LEVEL_WORDS,
+        ["--help" | "-h"] => families,
+        ["completions"] => &CompletionShell::NAMES,
+        ["hook"] => HOOK_SUBCOMMANDS,
+        ["hook", "event"] => HOOK_EVENTS,
+        ["__complete"] => HELPER_KINDS,
+        _ => &[],
+    };
+    filter_prefix(candidates.iter().map(|c| c.to_string()), current)
+}
+
+fn branch_candidates(repo_path: &Path) -> Vec<String> {
+    let branches = gwt_git::branch::list_branches(repo_path).unwrap_or_default();
+    branches
+        .into_iter()
+        .map(|branch| match branch.remote_branch_name {
+            Some(name) if branch.is_remote => name,
+            _ => branch.name,
+        })
+        .filter(|name| name != "HEAD")
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+fn worktree_candidates(repo_path: &Path) -> Vec<String> {
+    WorktreeManager::new(repo_path)
+        .list()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|worktree| !worktree.prunable)
+        .map(|worktree| worktree.path.display().to_string())
+        .collect()
+}
+
+fn filter_prefix(candidates: impl IntoIterator<Item = String>, prefix: &str) -> Vec<String> {
+    candidates
+        .into_iter()
+        .filter(|candidate| candidate.starts_with(prefix))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(items: &[&str]) -> Vec<String> {
+        items.iter().map(|item| item.to_string()).collect()
+    }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::hidden_command("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {args:?}");
+    }
+
+    #[test]
+    fn complete_args_follows_argv_surface() {
+        let families = ["issue", "pr", "pane"];
+        assert_eq!(complete_args(&words(&["co"]), &families), ["completions"]);
+        assert_eq!(
+            complete_args(&words(&["--help", "p"]), &families),
+            ["pr", "pane"]
+        );
+        assert_eq!(
+            complete_args(&words(&["completions", ""]), &families),
+            CompletionShell::NAMES
+        );
+        assert_eq!(
+            complete_args(&words(&["hook", "event", "S"]), &families),
+            ["SessionStart", "Stop"]
+        );
+        assert!(complete_args(&words(&["issue", "x", ""]), &families).is_empty());
+    }
+
+    #[test]
+    fn scripts_forward_words_to_helper() {
+        for name in CompletionShell::NAMES {
+            let shell = CompletionShell::from_name(name).unwrap();
+            let script = completion_script(shell, "gwtd");
+            assert!(
+                script.contains("gwtd __complete args --"),
+                "{name}: {script}"
+            );
+        }
+        assert!(completion_script(CompletionShell::Bash, "gwtd")
+            .contains("complete -o default -F _gwtd gwtd"));
+        assert_eq!(
+            CompletionShell::from_name("pwsh"),
+            Some(CompletionShell::PowerShell)
+        );
+        assert_eq!(CompletionShell::from_name("tcsh"), None);
+    }
+
+    #[test]
+    fn helper_lists_branches_and_worktrees() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--initial-branch=main"]);
+        git(&repo, &["config", "user.email", "t@example.com"]);
+        git(&repo, &["config", "user.name", "T"]);
+        git(&repo, &["commit", "--allow-empty", "-m", "init"]);
+        let feature = tmp.path().join("feature");
+        git(
+            &repo,
+            &[
+                "worktree",
+                "add",
+                "-b",
+                "feature/x",
+                feature.to_str().unwrap(),
+            ],
+        );
+
+        assert_eq!(
+            run_helper(&words(&["branches"]), &[], &repo),
+            ["feature/x", "main"]
+        );
+        assert_eq!(
+            run_helper(&words(&["branches", "fe"]), &[], &repo),
+            ["feature/x"]
+        );
+        let worktrees = run_helper(&words(&["worktrees"]), &[], &repo);
+        assert_eq!(worktrees.len(), 2, "{worktrees:?}");
+        assert!(run_helper(&words(&["branches"]), &[], tmp.path()).is_empty());
+    }
+}
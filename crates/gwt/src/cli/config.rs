@@ -0,0 +1,135 @@
+//! `gwt config ...` family module.
+//!
+//! `gwt config validate` checks `~/.gwt/config.toml` (or `--path <file>`)
+//! with [`gwt_config::validate`] and prints one line per problem, prefixed
+//! with the file and the line/column it was found at.
+
+use std::path::{Path, PathBuf};
+
+use gwt_config::{ConfigDiagnostic, DiagnosticSeverity};
+use gwt_github::SpecOpsError;
+
+use super::{CliEnv, CliParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigCommand {
+    /// `gwt config validate [--path <file>]` — exit 1 when the file has
+    /// errors, 0 when it only has warnings or none.
+    Validate { path: Option<PathBuf> },
+}
+
+pub fn parse(args: &[String]) -> Result<ConfigCommand, CliParseError> {
+    let (head, rest) = args.split_first().ok_or(CliParseError::Usage)?;
+    if head != "validate" {
+        return Err(CliParseError::UnknownSubcommand(head.to_string()));
+    }
+    let mut path = None;
+    let mut iter = rest.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--path" => {
+                let value = iter.next().ok_or(CliParseError::MissingFlag("--path"))?;
+                path = Some(PathBuf::from(value));
+            }
+            other => return Err(CliParseError::UnknownSubcommand(other.to_string())),
+        }
+    }
+    Ok(ConfigCommand::Validate { path })
+}
+
+pub fn run<E: CliEnv>(
+    _env: &mut E,
+    cmd: ConfigCommand,
+    out: &mut String,
+) -> Result<i32, SpecOpsError> {
+    let ConfigCommand::Validate { path } = cmd;
+    let Some(path) = path.or_else(gwt_config::Settings::global_config_path) else {
+        out.push_str("error: could not determine the config path\n");
+        return Ok(1);
+    };
+    if !path.exists() {
+        out.push_str(&format!(
+            "{}: not found; gwt uses the default settings\n",
+            path.display()
+        ));
+        return Ok(0);
+    }
+    match gwt_config::validate::validate_file(&path) {
+        Ok(diagnostics) => {
+            render_diagnostics(&path, &diagnostics, out);
+            let failed = diagnostics
+                .iter()
+                .any(|diagnostic| diagnostic.severity == DiagnosticSeverity::Error);
+            Ok(i32::from(failed))
+        }
+        Err(error) => {
+            out.push_str(&format!("error: {error}\n"));
+            Ok(1)
+        }
+    }
+}
+
+fn render_diagnostics(path: &Path, diagnostics: &[ConfigDiagnostic], out: &mut String) {
+    if diagnostics.is_empty() {
+        out.push_str(&format!("{}: OK\n", path.display()));
+        return;
+    }
+    for diagnostic in diagnostics {
+        let separator = if diagnostic.line.is_some() { ":" } else { ": " };
+        out.push_str(&format!("{}{separator}{diagnostic}\n", path.display()));
+    }
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == DiagnosticSeverity::Error)
+        .count();
+    out.push_str(&format!(
+        "{errors} error(s), {} warning(s)\n",
+        diagnostics.len() - errors
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_validate_flags() {
+        assert_eq!(
+            parse(&args(&["validate"])).unwrap(),
+            ConfigCommand::Validate { path: None }
+        );
+        assert_eq!(
+            parse(&args(&["validate", "--path", "/tmp/gwt.toml"])).unwrap(),
+            ConfigCommand::Validate {
+                path: Some(PathBuf::from("/tmp/gwt.toml"))
+            }
+        );
+        assert!(parse(&args(&["validate", "--path"])).is_err());
+        assert!(parse(&args(&["show"])).is_err());
+        assert!(parse(&[]).is_err());
+    }
+
+    #[test]
+    fn diagnostics_are_prefixed_with_the_file() {
+        let diagnostics = gwt_config::validate::validate_str("debug = 1\n");
+        let mut out = String::new();
+        render_diagnostics(
+            Path::new("/home/me/.gwt/config.toml"),
+            &diagnostics,
+            &mut out,
+        );
+        assert!(
+            out.starts_with("/home/me/.gwt/config.toml:1:9: error: "),
+            "{out}"
+        );
+        assert!(out.ends_with("1 error(s), 0 warning(s)\n"), "{out}");
+
+        let mut out = String::new();
+        render_diagnostics(Path::new("/c.toml"), &[], &mut out);
+        assert_eq!(out, "/c.toml: OK\n");
+    }
+}
@@ -129,6 +129,7 @@ pub async fn run_server(
     let shutdown = Arc::new(Notify::new());
     spawn_signal_watcher(Arc::clone(&shutdown));
     spawn_issue_monitor_worker(endpoint.scope.clone(), hub.clone(), Arc::clone(&shutdown));
+    spawn_background_fetch_worker(endpoint.scope.clone(), hub.clone(), Arc::clone(&shutdown));
 
     let endpoint = Arc::new(endpoint);
     let started_at = Instant::now();
@@ -288,6 +289,55 @@ fn spawn_issue_monitor_worker(scope: RuntimeScope, hub: BroadcastHub, shutdown:
     });
 }
 
+/// `[background_fetch]`: fetch and prune the daemon's project on schedule
+/// and publish moved remote refs on the `git_refs` channel, which GUI
+/// subscribers turn into a Branches reload.
+fn spawn_background_fetch_worker(scope: RuntimeScope, hub: BroadcastHub, shutdown: Arc<Notify>) {
+    tokio::spawn(async move {
+        let mut schedule = crate::background_fetch::FetchSchedule::default();
+        let mut ticker = tokio::time::interval(crate::background_fetch::SCHEDULER_TICK);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                biased;
+                _ = shutdown.notified() => break,
+                _ = ticker.tick() => {}
+            }
+            let interval = crate::background_fetch::configured_interval();
+            if !schedule.take_due(Instant::now(), interval) {
+                continue;
+            }
+            let project_root = scope.project_root.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                crate::background_fetch::refresh_project(&project_root)
+            })
+            .await;
+            match result {
+                Ok(Ok(true)) => {
+                    let channel = crate::runtime_daemon_events::GIT_REFS_CHANNEL;
+                    let _ = hub.publish(
+                        channel,
+                        DaemonFrame::Event {
+                            channel: channel.to_string(),
+                            payload: serde_json::json!({
+                                "hook": crate::background_fetch::FETCH_HOOK,
+                                "branch": null,
+                            }),
+                        },
+                    );
+                }
+                Ok(Ok(false)) => {}
+                Ok(Err(error)) => {
+                    tracing::warn!(error = %error, "gwtd daemon: background fetch failed");
+                }
+                Err(error) => {
+                    tracing::warn!(error = %error, "gwtd daemon: background fetch panicked");
+                }
+            }
+        }
+    });
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum IssueMonitorControl {
     Enabled(bool),
@@ -0,0 +1,269 @@
+//! `gwt deps upgrade` family module.
+//!
+//! Runs the recurring dependency-upgrade chore in a canary worktree (see
+//! [`gwt_git::deps_upgrade`]): create or reuse `deps/upgrade-<date>`, run the
+//! package manager's upgrade there and commit the result, hand the worktree
+//! to an agent as a `gwt run` job to fix breakages and update changelogs,
+//! then push the branch and open a draft PR.
+
+use std::collections::HashMap;
+
+use gwt_core::process_executor::SystemProcessExecutor;
+use gwt_git::{
+    deps_upgrade::{
+        changed_files, commit_all, detect_upgrade_command, push_branch, run_upgrade,
+        upgrade_branch_name, UpgradeCommand, UpgradeRun,
+    },
+    worktree_matrix::detect_test_command,
+};
+use gwt_github::{client::ApiError, SpecOpsError};
+
+use super::{
+    jobs::{self, JobPrompt, JobsCommand},
+    CliEnv, CliParseError,
+};
+
+/// Agent used without `--agent`.
+const DEFAULT_AGENT: &str = "claude";
+/// Base branch used without `--base`, as for any new work branch.
+const DEFAULT_BASE: &str = "develop";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DepsCommand {
+    /// `gwt deps upgrade [--agent <a>] [--base <branch>] [--no-pr]`
+    Upgrade {
+        agent: String,
+        base: Option<String>,
+        pr: bool,
+    },
+}
+
+pub fn parse(args: &[String]) -> Result<DepsCommand, CliParseError> {
+    let (head, rest) = args.split_first().ok_or(CliParseError::Usage)?;
+    if head != "upgrade" {
+        return Err(CliParseError::UnknownSubcommand(head.to_string()));
+    }
+    let mut agent = DEFAULT_AGENT.to_string();
+    let mut base = None;
+    let mut pr = true;
+    let mut iter = rest.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--agent" => {
+                agent = iter
+                    .next()
+                    .ok_or(CliParseError::MissingFlag("--agent"))?
+                    .clone();
+            }
+            "--base" => {
+                let value = iter.next().ok_or(CliParseError::MissingFlag("--base"))?;
+                base = Some(value.clone()).filter(|value| !value.trim().is_empty());
+            }
+            "--no-pr" => pr = false,
+            other => return Err(CliParseError::UnknownSubcommand(other.to_string())),
+        }
+    }
+    if gwt_agent::headless::headless_agent(&agent).is_none() {
+        return Err(CliParseError::InvalidValue {
+            flag: "--agent",
+            reason: "expected claude or codex",
+        });
+    }
+    Ok(DepsCommand::Upgrade { agent, base, pr })
+}
+
+pub fn run<E: CliEnv>(
+    env: &mut E,
+    cmd: DepsCommand,
+    out: &mut String,
+) -> Result<i32, SpecOpsError> {
+    let DepsCommand::Upgrade { agent, base, pr } = cmd;
+    let branch = upgrade_branch_name(chrono::Local::now().date_naive());
+    let mut working_dir = None;
+    gwt_agent::prepare::resolve_launch_worktree_request(
+        env.repo_path(),
+        Some(&branch),
+        base.as_deref(),
+        &mut working_dir,
+        &mut HashMap::new(),
+    )
+    .map_err(unexpected_error)?;
+    let worktree =
+        working_dir.ok_or_else(|| unexpected_error(format!("no worktree for branch {branch}")))?;
+    out.push_str(&format!(
+        "branch: {branch}\nworktree: {}\n",
+        worktree.display()
+    ));
+
+    let Some(command) = detect_upgrade_command(&worktree) else {
+        out.push_str("no supported package manager found\n");
+        return Ok(1);
+    };
+    let upgrade =
+        run_upgrade(&SystemProcessExecutor, &worktree, &command).map_err(unexpected_error)?;
+    out.push_str(&format!(
+        "{}: {}\n",
+        command.argv.join(" "),
+        if upgrade.success { "ok" } else { "failed" }
+    ));
+    let changed = changed_files(&worktree).map_err(unexpected_error)?;
+    let committed = commit_all(
+        &worktree,
+        &format!("chore(deps): upgrade {} dependencies", command.manager),
+    )
+    .map_err(unexpected_error)?;
+    if !committed && upgrade.success {
+        out.push_str("dependencies are already up to date\n");
+        return Ok(0);
+    }
+
+    let prompt = upgrade_prompt(&command, &upgrade, &changed, detect_test_command(&worktree));
+    let agent_code = jobs::run(
+        env,
+        JobsCommand::Run {
+            branch: branch.clone(),
+            agent,
+            prompt: JobPrompt::Text(prompt),
+            detach: false,
+        },
+        out,
+    )?;
+    if !pr {
+        return Ok(agent_code);
+    }
+
+    push_branch(&worktree, &branch).map_err(unexpected_error)?;
+    let pr = env
+        .create_pr(
+            base.as_deref().unwrap_or(DEFAULT_BASE),
+            Some(&branch),
+            &format!("chore(deps): upgrade {} dependencies", command.manager),
+            &pr_body(&command, &changed, agent_code),
+            &[],
+            true,
+        )
+        .map_err(super::io_as_api_error)?;
+    out.push_str(&format!("draft PR #{}: {}\n", pr.number, pr.url));
+    Ok(agent_code)
+}
+
+/// Prompt of the agent job that follows the upgrade.
+fn upgrade_prompt(
+    command: &UpgradeCommand,
+    upgrade: &UpgradeRun,
+    changed: &[String],
+    test_command: Option<Vec<String>>,
+) -> String {
+    let mut prompt = format!(
+        "This worktree is a canary for a dependency upgrade. `{}` was run here",
+        command.argv.join(" ")
+    );
+    if changed.is_empty() {
+        prompt.push_str(" and changed no files.\n");
+    } else {
+        prompt.push_str(&format!(
+            " and its changes are committed: {}.\n",
+            changed.join(", ")
+        ));
+    }
+    if !upgrade.success {
+        prompt.push_str(&format!(
+            "\nThe upgrade command failed{}. Its last output lines:\n\n```\n{}\n```\n",
+            upgrade
+                .code
+                .map(|code| format!(" with exit code {code}"))
+                .unwrap_or_default(),
+            upgrade.output_tail.join("\n")
+        ));
+    }
+    prompt.push_str("\nBuild the project");
+    if let Some(test_command) = test_command {
+        prompt.push_str(&format!(" and run `{}`", test_command.join(" ")));
+    }
+    prompt.push_str(
+        ", fix everything the upgrade broke, and record the upgraded dependencies in the \
+         changelog(s). Commit your changes on this branch; do not push.\n",
+    );
+    prompt
+}
+
+fn pr_body(command: &UpgradeCommand, changed: &[String], agent_code: i32) -> String {
+    let mut body = format!(
+        "Dependency upgrade canary created by `gwt deps upgrade`.\n\n- Upgrade: `{}`\n",
+        command.argv.join(" ")
+    );
+    if !changed.is_empty() {
+        body.push_str(&format!("- Changed: {}\n", changed.join(", ")));
+    }
+    body.push_str(if agent_code == 0 {
+        "- Follow-up fixes: agent job completed\n"
+    } else {
+        "- Follow-up fixes: agent job did not complete; check `gwt jobs status`\n"
+    });
+    body
+}
+
+fn unexpected_error(err: impl ToString) -> SpecOpsError {
+    SpecOpsError::from(ApiError::Unexpected(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_upgrade_defaults_and_flags() {
+        assert_eq!(
+            parse(&args(&["upgrade"])).unwrap(),
+            DepsCommand::Upgrade {
+                agent: "claude".to_string(),
+                base: None,
+                pr: true,
+            }
+        );
+        assert_eq!(
+            parse(&args(&[
+                "upgrade", "--agent", "codex", "--base", "main", "--no-pr"
+            ]))
+            .unwrap(),
+            DepsCommand::Upgrade {
+                agent: "codex".to_string(),
+                base: Some("main".to_string()),
+                pr: false,
+            }
+        );
+        assert!(parse(&args(&["upgrade", "--agent", "gemini"])).is_err());
+        assert!(parse(&args(&["upgrade", "--force"])).is_err());
+        assert!(parse(&args(&["outdated"])).is_err());
+        assert!(parse(&[]).is_err());
+    }
+
+    #[test]
+    fn prompt_carries_changes_failures_and_test_command() {
+        let command = UpgradeCommand {
+            manager: "cargo",
+            argv: args(&["cargo", "update"]),
+        };
+        let failed = UpgradeRun {
+            success: false,
+            code: Some(101),
+            output_tail: args(&["error: conflict"]),
+        };
+        let prompt = upgrade_prompt(
+            &command,
+            &failed,
+            &args(&["Cargo.lock"]),
+            Some(args(&["cargo", "test"])),
+        );
+        assert!(prompt.contains("`cargo update`"));
+        assert!(prompt.contains("committed: Cargo.lock"));
+        assert!(prompt.contains("exit code 101"));
+        assert!(prompt.contains("error: conflict"));
+        assert!(prompt.contains("run `cargo test`"));
+        assert!(prompt.contains("changelog"));
+    }
+}
@@ -229,6 +229,17 @@ pub fn dispatch<E: CliEnv>(env: &mut E, args: &[String]) -> i32 {
     if top_verb.is_empty() {
         return super::json_envelope::dispatch(env, prog);
     }
+    if top_verb == "--plain-ui" {
+        let repo_path = env.repo_path().to_path_buf();
+        let stdin = io::stdin();
+        return match super::plain_ui::run(&repo_path, &mut stdin.lock(), env.stdout()) {
+            Ok(code) => code,
+            Err(e) => {
+                let _ = writeln!(env.stderr(), "{prog} --plain-ui: {e}");
+                1
+            }
+        };
+    }
 
     let parse_result = match top_verb {
         "issue" => parse_issue_args(&rest),
@@ -237,6 +248,18 @@ pub fn dispatch<E: CliEnv>(env: &mut E, args: &[String]) -> i32 {
         "board" => parse_board_args(&rest),
         "index" => super::parse_index_args(&rest),
         "diagnostics" => super::parse_diagnostics_args(&rest),
+        "insights" => super::parse_insights_args(&rest),
+        "logs" => super::parse_logs_args(&rest),
+        "locks" => super::parse_locks_args(&rest),
+        "hooks" => super::parse_hooks_args(&rest),
+        "mcp" => super::parse_mcp_args(&rest),
+        "migrate" => super::parse_migrate_args(&rest),
+        "config" => super::parse_config_args(&rest),
+        "profile" => super::parse_profile_args(&rest),
+        "session" => super::parse_session_args(&rest),
+        "deps" => super::parse_deps_args(&rest),
+        "run" => super::parse_run_args(&rest),
+        "jobs" => super::parse_jobs_args(&rest),
         "memory" | "lessons" => parse_memory_args(&rest),
         "discussion" => parse_discussion_args(&rest),
         "hook" => parse_hook_args(&rest),
@@ -249,14 +272,7 @@ pub fn dispatch<E: CliEnv>(env: &mut E, args: &[String]) -> i32 {
         "pane" => parse_pane_args(&rest),
         "open" => super::open::parse_args(&rest),
         "search" => super::search::parse_args(&rest),
-        "update" => {
-            let mode = if rest.iter().any(|a| a == "--check") {
-                super::UpdateCommand::CheckOnly
-            } else {
-                super::UpdateCommand::Apply
-            };
-            Ok(super::CliCommand::Update(mode))
-        }
+        "update" => super::update::parse_args(&rest).map(super::CliCommand::Update),
         "__internal" => match rest.first().map(String::as_str) {
             Some("apply-update") => Ok(super::CliCommand::Update(
                 super::UpdateCommand::InternalApply {
@@ -268,6 +284,12 @@ pub fn dispatch<E: CliEnv>(env: &mut E, args: &[String]) -> i32 {
                     rest: rest[1..].to_vec(),
                 },
             )),
+            Some("run-job") => match &rest[1..] {
+                [id] => Ok(super::CliCommand::Jobs(super::JobsCommand::InternalRun {
+                    id: id.clone(),
+                })),
+                _ => Err(CliParseError::Usage),
+            },
             Some("daemon-hook") => parse_hook_args(&rest[1..]).map(|cmd| match cmd {
                 super::CliCommand::Hook(super::HookCommand::Run { name, rest }) => {
                     super::CliCommand::Hook(super::HookCommand::InternalDaemon { name, rest })
@@ -1052,10 +1052,10 @@ fn run_reopen_locked(
         );
         return Ok(2);
     };
-    if !record
+    if record
         .blocked_reason
         .as_deref()
-        .is_some_and(|value| !value.trim().is_empty())
+        .is_none_or(|value| value.trim().is_empty())
     {
         out.push_str(
             "execution: reopen refused — the Blocked record has no non-empty blocker reason and cannot be recovered canonically\n",
@@ -217,8 +217,11 @@ fn cli_command_family_split_round_trip_parses() {
 
     // `update --check` is parsed inline by `dispatch`. Round-trip it via
     // the public CliCommand builder to keep the family contract pinned.
-    let cmd = CliCommand::Update(UpdateCommand::CheckOnly);
-    assert!(matches!(cmd, CliCommand::Update(UpdateCommand::CheckOnly)));
+    let cmd = CliCommand::Update(UpdateCommand::CheckOnly { channel: None });
+    assert!(matches!(
+        cmd,
+        CliCommand::Update(UpdateCommand::CheckOnly { .. })
+    ));
 
     // SPEC-1942 US-15 legacy parser fixture: `search --issues "<query>"` (flag-first agent
     // shape) round-trips through the search family.
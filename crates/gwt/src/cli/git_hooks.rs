@@ -0,0 +1,350 @@
+//! `gwt hooks ...` family module: git hooks that report back to gwt.
+//!
+//! `gwt hooks install [--force]` writes `post-commit` and `pre-push` into
+//! the repository's hooks directory (`git rev-parse --git-path hooks`, which
+//! every worktree shares and which honors `core.hooksPath`). Both hooks call
+//! `gwt hooks event <hook>`, which publishes a `git_refs` daemon event so
+//! open Branches lists reload their safety and PR state right away. With
+//! `[git_hooks] block_protected_push = true` the `pre-push` hook also
+//! refuses pushes to protected branches. `gwt hooks uninstall` removes the
+//! hooks gwt wrote and leaves any others alone.
+//!
+//! Not to be confused with the `gwt hook ...` family, which handles agent
+//! hooks.
+
+use std::path::{Path, PathBuf};
+
+use gwt_github::{ApiError, SpecOpsError};
+
+use super::{CliEnv, CliParseError};
+
+/// Comment that marks a hook as written by gwt.
+const MANAGED_MARKER: &str = "# gwt-managed git hook";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitHook {
+    PostCommit,
+    PrePush,
+}
+
+impl GitHook {
+    const ALL: [GitHook; 2] = [GitHook::PostCommit, GitHook::PrePush];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::PostCommit => "post-commit",
+            Self::PrePush => "pre-push",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|hook| hook.name() == name)
+    }
+
+    /// Hook script calling `exe`. `post-commit` never fails the commit;
+    /// `pre-push` hands over its stdin and exit code so a push to a
+    /// protected branch can be refused.
+    fn script(self, exe: &str) -> String {
+        let body = match self {
+            Self::PostCommit => {
+                format!("'{exe}' hooks event post-commit >/dev/null 2>&1 || true\n")
+            }
+            Self::PrePush => {
+                format!("if [ -x '{exe}' ]; then\n  exec '{exe}' hooks event pre-push \"$@\"\nfi\n")
+            }
+        };
+        format!("#!/bin/sh\n{MANAGED_MARKER}; remove with `gwt hooks uninstall`.\n{body}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HooksCommand {
+    /// `gwt hooks install [--force]` — `--force` replaces hooks gwt did not
+    /// write.
+    Install { force: bool },
+    /// `gwt hooks uninstall`
+    Uninstall,
+    /// `gwt hooks event <hook> [args...]`, run by the installed hooks.
+    Event { hook: GitHook },
+}
+
+pub fn parse(args: &[String]) -> Result<HooksCommand, CliParseError> {
+    let (head, rest) = args.split_first().ok_or(CliParseError::Usage)?;
+    match head.as_str() {
+        "install" => {
+            let mut force = false;
+            for arg in rest {
+                match arg.as_str() {
+                    "--force" => force = true,
+                    other => return Err(CliParseError::UnknownSubcommand(other.to_string())),
+                }
+            }
+            Ok(HooksCommand::Install { force })
+        }
+        "uninstall" => match rest.first() {
+            None => Ok(HooksCommand::Uninstall),
+            Some(other) => Err(CliParseError::UnknownSubcommand(other.to_string())),
+        },
+        // Git passes the remote name and URL to `pre-push`; they are not
+        // needed, the pushed refs come in on stdin.
+        "event" => {
+            let name = rest.first().ok_or(CliParseError::Usage)?;
+            GitHook::from_name(name)
+                .map(|hook| HooksCommand::Event { hook })
+                .ok_or_else(|| CliParseError::UnknownSubcommand(name.to_string()))
+        }
+        other => Err(CliParseError::UnknownSubcommand(other.to_string())),
+    }
+}
+
+pub fn run<E: CliEnv>(
+    env: &mut E,
+    cmd: HooksCommand,
+    out: &mut String,
+) -> Result<i32, SpecOpsError> {
+    match cmd {
+        HooksCommand::Install { force } => {
+            let dir = hooks_dir(env.repo_path()).map_err(string_error)?;
+            let exe = std::env::current_exe()
+                .map_err(|error| string_error(format!("locate the gwt executable: {error}")))?;
+            let skipped = install(&dir, &exe, force, out).map_err(string_error)?;
+            if skipped > 0 {
+                out.push_str("rerun with --force to replace hooks gwt did not write\n");
+                return Ok(1);
+            }
+            Ok(0)
+        }
+        HooksCommand::Uninstall => {
+            let dir = hooks_dir(env.repo_path()).map_err(string_error)?;
+            uninstall(&dir, out).map_err(string_error)?;
+            Ok(0)
+        }
+        HooksCommand::Event { hook } => {
+            let repo_path = env.repo_path().to_path_buf();
+            let branches = match hook {
+                GitHook::PostCommit => current_branch(&repo_path).into_iter().collect(),
+                GitHook::PrePush => pushed_branches(&env.read_stdin().unwrap_or_default()),
+            };
+            if hook == GitHook::PrePush {
+                let settings = gwt_config::Settings::load().unwrap_or_default();
+                let blocked: Vec<&String> = branches
+                    .iter()
+                    .filter(|branch| {
+                        gwt_git::is_protected_branch_in(&repo_path, branch)
+                            || settings.protected_branches.contains(*branch)
+                    })
+                    .collect();
+                if settings.git_hooks.block_protected_push && !blocked.is_empty() {
+                    for branch in blocked {
+                        out.push_str(&format!(
+                            "error: gwt blocks pushes to protected branch {branch} \
+                             ([git_hooks] block_protected_push)\n"
+                        ));
+                    }
+                    return Ok(1);
+                }
+            }
+            publish_refs_change(&repo_path, hook, branches.first().map(String::as_str));
+            Ok(0)
+        }
+    }
+}
+
+/// Write every hook into `dir`. Returns how many were skipped because a
+/// hook gwt did not write is already there.
+fn install(dir: &Path, exe: &Path, force: bool, out: &mut String) -> Result<usize, String> {
+    std::fs::create_dir_all(dir).map_err(|error| format!("create {}: {error}", dir.display()))?;
+    let exe = exe.to_string_lossy().replace('\\', "/");
+    let mut skipped = 0;
+    for hook in GitHook::ALL {
+        let path = dir.join(hook.name());
+        if !force && path.exists() && !is_managed(&path) {
+            out.push_str(&format!("skipped {}: not written by gwt\n", path.display()));
+            skipped += 1;
+            continue;
+        }
+        std::fs::write(&path, hook.script(&exe))
+            .map_err(|error| format!("write {}: {error}", path.display()))?;
+        make_executable(&path)?;
+        out.push_str(&format!("installed {}\n", path.display()));
+    }
+    Ok(skipped)
+}
+
+fn uninstall(dir: &Path, out: &mut String) -> Result<(), String> {
+    let mut removed = 0;
+    for hook in GitHook::ALL {
+        let path = dir.join(hook.name());
+        if is_managed(&path) {
+            std::fs::remove_file(&path)
+                .map_err(|error| format!("remove {}: {error}", path.display()))?;
+            out.push_str(&format!("removed {}\n", path.display()));
+            removed += 1;
+        }
+    }
+    if removed == 0 {
+        out.push_str("no gwt git hooks installed\n");
+    }
+    Ok(())
+}
+
+fn is_managed(path: &Path) -> bool {
+    std::fs::read_to_string(path).is_ok_and(|script| script.contains(MANAGED_MARKER))
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<(), String> {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+        .map_err(|error| format!("chmod {}: {error}", path.display()))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<(), String> {
+    Ok(())
+}
+
+fn hooks_dir(repo_path: &Path) -> Result<PathBuf, String> {
+    git_output(
+        repo_path,
+        &["rev-parse", "--path-format=absolute", "--git-path", "hooks"],
+    )
+    .map(PathBuf::from)
+}
+
+fn current_branch(repo_path: &Path) -> Option<String> {
+    git_output(repo_path, &["symbolic-ref", "--quiet", "--short", "HEAD"]).ok()
+}
+
+fn git_output(repo_path: &Path, args: &[&str]) -> Result<String, String> {
+    let output = gwt_core::process::hidden_command("git")
+        .args(args)
+        .current_dir(repo_path)
+        .output()
+        .map_err(|error| format!("git {}: {error}", args.join(" ")))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git {}: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Remote branches a `pre-push` stdin (`<local ref> <local sha> <remote
+/// ref> <remote sha>` per line) updates or deletes.
+fn pushed_branches(stdin: &str) -> Vec<String> {
+    let mut branches = Vec::new();
+    for line in stdin.lines() {
+        let Some(remote_ref) = line.split_whitespace().nth(2) else {
+            continue;
+        };
+        if let Some(branch) = remote_ref.strip_prefix("refs/heads/") {
+            if !branches.iter().any(|known| known == branch) {
+                branches.push(branch.to_string());
+            }
+        }
+    }
+    branches
+}
+
+#[cfg(unix)]
+fn publish_refs_change(repo_path: &Path, hook: GitHook, branch: Option<&str>) {
+    let result = crate::daemon_publisher::publish_event(
+        repo_path,
+        crate::runtime_daemon_events::GIT_REFS_CHANNEL,
+        serde_json::json!({"hook": hook.name(), "branch": branch}),
+    );
+    if let Err(err) = result {
+        tracing::debug!(
+            error = %err,
+            repo_path = %repo_path.display(),
+            "hooks.event: daemon publish failed (non-fatal)"
+        );
+    }
+}
+
+#[cfg(not(unix))]
+fn publish_refs_change(_repo_path: &Path, _hook: GitHook, _branch: Option<&str>) {}
+
+fn string_error(error: String) -> SpecOpsError {
+    SpecOpsError::from(ApiError::Network(error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_hooks_subcommands() {
+        assert_eq!(
+            parse(&args(&["install"])).unwrap(),
+            HooksCommand::Install { force: false }
+        );
+        assert_eq!(
+            parse(&args(&["install", "--force"])).unwrap(),
+            HooksCommand::Install { force: true }
+        );
+        assert_eq!(
+            parse(&args(&["uninstall"])).unwrap(),
+            HooksCommand::Uninstall
+        );
+        assert_eq!(
+            parse(&args(&[
+                "event",
+                "pre-push",
+                "origin",
+                "git@github.com:o/r.git"
+            ]))
+            .unwrap(),
+            HooksCommand::Event {
+                hook: GitHook::PrePush
+            }
+        );
+        assert!(parse(&args(&["event", "pre-commit"])).is_err());
+        assert!(parse(&args(&["install", "--all"])).is_err());
+        assert!(parse(&[]).is_err());
+    }
+
+    #[test]
+    fn install_keeps_foreign_hooks_unless_forced_and_uninstall_removes_only_managed() {
+        let dir = tempfile::tempdir().unwrap();
+        let hooks = dir.path().join("hooks");
+        std::fs::create_dir_all(&hooks).unwrap();
+        std::fs::write(hooks.join("pre-push"), "#!/bin/sh\nexit 0\n").unwrap();
+        let exe = Path::new("/opt/gwt/bin/gwt");
+
+        let mut out = String::new();
+        assert_eq!(install(&hooks, exe, false, &mut out).unwrap(), 1);
+        assert!(out.contains("skipped"), "{out}");
+        let post_commit = std::fs::read_to_string(hooks.join("post-commit")).unwrap();
+        assert!(post_commit.contains("'/opt/gwt/bin/gwt' hooks event post-commit"));
+        assert!(!is_managed(&hooks.join("pre-push")));
+
+        let mut out = String::new();
+        assert_eq!(install(&hooks, exe, true, &mut out).unwrap(), 0);
+        let pre_push = std::fs::read_to_string(hooks.join("pre-push")).unwrap();
+        assert!(pre_push.contains("exec '/opt/gwt/bin/gwt' hooks event pre-push \"$@\""));
+
+        std::fs::write(hooks.join("post-commit"), "#!/bin/sh\necho mine\n").unwrap();
+        let mut out = String::new();
+        uninstall(&hooks, &mut out).unwrap();
+        assert!(!hooks.join("pre-push").exists());
+        assert!(hooks.join("post-commit").exists());
+    }
+
+    #[test]
+    fn pushed_branches_reads_remote_heads_from_pre_push_stdin() {
+        let stdin = "refs/heads/feature/x 1111 refs/heads/main 2222\n\
+                     refs/tags/v1 3333 refs/tags/v1 0000\n\
+                     (delete) 0000 refs/heads/release 4444\n\
+                     refs/heads/y 5555 refs/heads/main 2222\n";
+        assert_eq!(pushed_branches(stdin), ["main", "release"]);
+        assert!(pushed_branches("").is_empty());
+    }
+}
@@ -9,9 +9,9 @@ use std::{path::Path, time::Instant};
 
 use super::{
     board_reminder, diagnostics, execution_completion_stop_check, execution_control_stop_check,
-    intake_completion_stop_check, skill_build_spec_stop_check, skill_discussion_stop_check,
-    skill_plan_spec_stop_check, skill_register_spec_stop_check, workflow_policy,
-    workspace_identity, HookError, HookOutput, IntentBoundaryEvent,
+    first_commit_push, intake_completion_stop_check, skill_build_spec_stop_check,
+    skill_discussion_stop_check, skill_plan_spec_stop_check, skill_register_spec_stop_check,
+    workflow_policy, workspace_identity, HookError, HookOutput, IntentBoundaryEvent,
 };
 use crate::discussion_resume::{load_pending_goal, PendingDiscussionGoal};
 
@@ -235,6 +235,11 @@ fn handle_stop(
     run_step(event, "completed-stop", || {
         super::runtime_state::record_completed_stop_from_env()
     })?;
+    // Opt-in `[agent] auto_push_first_commit`: publish a branch that gained
+    // its first commit so later safety checks have an upstream. Fail-open.
+    run_value(event, "first-commit-push", || {
+        first_commit_push::handle(worktree_root)
+    });
 
     Ok(reminder)
 }
@@ -0,0 +1,110 @@
+//! Stop-time upstream publishing for branches that just received their
+//! first agent commit.
+//!
+//! When `[agent] auto_push_first_commit = true`, a completed Stop runs
+//! `git push -u origin <branch>` for a worktree branch that has local
+//! commits but no upstream yet, so safety checks and PR creation have a
+//! remote branch to work with. Branches that already track an upstream are
+//! never pushed again.
+//!
+//! Fail-open: a disabled setting, a missing remote, or a rejected push is
+//! logged and never blocks the Stop.
+
+use std::path::Path;
+
+use gwt_git::{push_first_commit_with_upstream, UpstreamPushOutcome, DEFAULT_UPSTREAM_REMOTE};
+
+/// Run the first-commit push for `worktree_root` when the setting is on.
+pub fn handle(worktree_root: &Path) {
+    let enabled = gwt_config::Settings::load()
+        .map(|settings| settings.agent.auto_push_first_commit)
+        .unwrap_or(false);
+    if !enabled {
+        return;
+    }
+    handle_enabled(worktree_root);
+}
+
+fn handle_enabled(worktree_root: &Path) -> Option<UpstreamPushOutcome> {
+    let worktree = gwt_core::paths::resolve_current_worktree_root(worktree_root);
+    match push_first_commit_with_upstream(&worktree, DEFAULT_UPSTREAM_REMOTE) {
+        Ok(outcome) => {
+            if let UpstreamPushOutcome::Pushed {
+                branch,
+                remote,
+                commits,
+            } = &outcome
+            {
+                tracing::info!(
+                    %branch,
+                    %remote,
+                    commits,
+                    "published first agent commit with upstream tracking"
+                );
+            }
+            Some(outcome)
+        }
+        Err(error) => {
+            tracing::warn!(
+                worktree = %worktree.display(),
+                %error,
+                "first-commit upstream push failed"
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::hidden_command("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {args:?}");
+    }
+
+    #[test]
+    fn enabled_push_publishes_new_branch_once() {
+        let tmp = tempfile::tempdir().unwrap();
+        let remote = tmp.path().join("remote.git");
+        let work = tmp.path().join("work");
+        std::fs::create_dir_all(&remote).unwrap();
+        git(&remote, &["init", "--bare", "--initial-branch=main"]);
+        git(
+            tmp.path(),
+            &["clone", remote.to_str().unwrap(), work.to_str().unwrap()],
+        );
+        git(&work, &["config", "user.email", "t@example.com"]);
+        git(&work, &["config", "user.name", "T"]);
+        git(&work, &["commit", "--allow-empty", "-m", "init"]);
+        git(&work, &["push", "-u", "origin", "main"]);
+        git(&work, &["switch", "-c", "feature/agent"]);
+        git(
+            &work,
+            &["commit", "--allow-empty", "-m", "feat: agent work"],
+        );
+
+        assert!(matches!(
+            handle_enabled(&work),
+            Some(UpstreamPushOutcome::Pushed { .. })
+        ));
+        assert!(matches!(
+            handle_enabled(&work),
+            Some(UpstreamPushOutcome::AlreadyTracking { .. })
+        ));
+    }
+
+    #[test]
+    fn non_repository_has_nothing_to_push() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert_eq!(
+            handle_enabled(tmp.path()),
+            Some(UpstreamPushOutcome::NoBranch)
+        );
+    }
+}
@@ -24,6 +24,7 @@ pub mod envelope;
 pub mod event_dispatcher;
 pub mod execution_completion_stop_check;
 pub mod execution_control_stop_check;
+pub mod first_commit_push;
 pub mod forward;
 pub mod gwt_self_improvement_stop;
 pub mod health;
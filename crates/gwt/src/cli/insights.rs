@@ -0,0 +1,115 @@
+//! `gwt insights ...` family module.
+//!
+//! Reads the local usage counters written by [`crate::insights`]. Nothing
+//! here talks to the network; `export` only prints JSON for the user to
+//! share by hand.
+
+use std::path::Path;
+
+use gwt_github::{client::ApiError, SpecOpsError};
+
+use super::{CliEnv, CliParseError};
+use crate::insights::{insights_path, UsageInsights};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InsightsCommand {
+    /// `gwt insights show` — human-readable counters, most used first.
+    Show,
+    /// `gwt insights export` — the raw counters as JSON on stdout.
+    Export,
+    /// `gwt insights reset` — delete the stored counters.
+    Reset,
+}
+
+pub fn parse(args: &[String]) -> Result<InsightsCommand, CliParseError> {
+    let (head, rest) = args.split_first().ok_or(CliParseError::Usage)?;
+    if !rest.is_empty() {
+        return Err(CliParseError::Usage);
+    }
+    match head.as_str() {
+        "show" => Ok(InsightsCommand::Show),
+        "export" => Ok(InsightsCommand::Export),
+        "reset" => Ok(InsightsCommand::Reset),
+        other => Err(CliParseError::UnknownSubcommand(other.to_string())),
+    }
+}
+
+pub fn run<E: CliEnv>(
+    _env: &mut E,
+    cmd: InsightsCommand,
+    out: &mut String,
+) -> Result<i32, SpecOpsError> {
+    run_at(&insights_path(), cmd, out)
+}
+
+fn run_at(path: &Path, cmd: InsightsCommand, out: &mut String) -> Result<i32, SpecOpsError> {
+    match cmd {
+        InsightsCommand::Show => {
+            let enabled = gwt_config::Settings::load()
+                .map(|settings| settings.insights.enabled)
+                .unwrap_or(false);
+            if !enabled {
+                out.push_str(
+                    "Usage insights are off. Set `[insights] enabled = true` in ~/.gwt/config.toml to start counting locally.\n",
+                );
+            }
+            out.push_str(&UsageInsights::load(path).render_text());
+        }
+        InsightsCommand::Export => {
+            let rendered = serde_json::to_string_pretty(&UsageInsights::load(path))
+                .map_err(unexpected_error)?;
+            out.push_str(&rendered);
+            out.push('\n');
+        }
+        InsightsCommand::Reset => match std::fs::remove_file(path) {
+            Ok(()) => out.push_str("Usage insights cleared.\n"),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                out.push_str("No usage insights stored.\n")
+            }
+            Err(error) => return Err(unexpected_error(error)),
+        },
+    }
+    Ok(0)
+}
+
+fn unexpected_error(err: impl ToString) -> SpecOpsError {
+    SpecOpsError::from(ApiError::Unexpected(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::insights::InsightKind;
+
+    #[test]
+    fn parse_accepts_show_export_and_reset_only() {
+        let args = |values: &[&str]| values.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(parse(&args(&["show"])).unwrap(), InsightsCommand::Show);
+        assert_eq!(parse(&args(&["export"])).unwrap(), InsightsCommand::Export);
+        assert_eq!(parse(&args(&["reset"])).unwrap(), InsightsCommand::Reset);
+        assert!(parse(&args(&["show", "--all"])).is_err());
+        assert!(parse(&args(&["upload"])).is_err());
+        assert!(parse(&[]).is_err());
+    }
+
+    #[test]
+    fn export_prints_stored_counters_and_reset_removes_them() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("insights.json");
+        let mut insights = UsageInsights::default();
+        insights.bump(InsightKind::Agent, "codex", 4);
+        insights.save(&path).unwrap();
+
+        let mut out = String::new();
+        run_at(&path, InsightsCommand::Export, &mut out).unwrap();
+        let exported: UsageInsights = serde_json::from_str(&out).unwrap();
+        assert_eq!(exported.agents["codex"], 4);
+
+        let mut out = String::new();
+        run_at(&path, InsightsCommand::Reset, &mut out).unwrap();
+        assert!(!path.exists());
+        let mut out = String::new();
+        run_at(&path, InsightsCommand::Reset, &mut out).unwrap();
+        assert_eq!(out, "No usage insights stored.\n");
+    }
+}
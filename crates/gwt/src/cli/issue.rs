@@ -22,6 +22,8 @@ pub(super) fn parse(args: &[String]) -> Result<IssueCommand, CliParseError> {
         }
         Some("create") => parse_issue_create_args(it.collect::<Vec<_>>().as_slice()),
         Some("comment") => parse_issue_comment_args(it.collect::<Vec<_>>().as_slice()),
+        Some("list") => super::issue_work::parse_list(it.collect::<Vec<_>>().as_slice()),
+        Some("start") => super::issue_work::parse_start(it.collect::<Vec<_>>().as_slice()),
         Some(other) => Err(CliParseError::UnknownSubcommand(other.to_string())),
         None => Err(CliParseError::Usage),
     }
@@ -144,6 +146,14 @@ pub(super) fn run<E: CliEnv>(
             reviewed_sha,
             verdict_raw,
         } => run_monitor_review_verdict(env, issue_number, &reviewed_sha, &verdict_raw, out),
+        IssueCommand::List { plain } => super::issue_work::run_list(env, plain, out),
+        IssueCommand::Start {
+            number,
+            agent,
+            branch,
+            ai,
+            detach,
+        } => super::issue_work::run_start(env, number, agent, branch, ai, detach, out)?,
         _ => unreachable!("issue::run called with non-issue command"),
     };
     Ok(code)
@@ -0,0 +1,275 @@
+//! `gwt issue list` / `gwt issue start <n>`: go from an open issue to an
+//! agent working on it.
+//!
+//! `list` prints the open issues in the local issue cache. `start` fetches
+//! the issue, names a branch after it (with the configured AI when
+//! available, otherwise `work/issue-<n>-<title slug>`), and queues a
+//! headless job whose prompt carries the issue title and body. The job
+//! creates the branch worktree exactly like `gwt run`, including
+//! `--detach`.
+
+use gwt_github::{Cache, IssueNumber, IssueSnapshot, IssueState, SpecOpsError};
+
+use super::{
+    issue::load_or_refresh_issue,
+    jobs::{self, JobPrompt, JobsCommand},
+    table::{Table, TableStyle},
+    CliEnv, CliParseError, IssueCommand,
+};
+
+/// Agent used by `gwt issue start` without `--agent`.
+const DEFAULT_AGENT: &str = "claude";
+/// Longest title slug in a generated branch name.
+const MAX_SLUG_CHARS: usize = 40;
+
+/// Parse `gwt issue list [--plain]`.
+pub(super) fn parse_list(args: &[&String]) -> Result<IssueCommand, CliParseError> {
+    let mut plain = false;
+    for arg in args {
+        match arg.as_str() {
+            "--plain" => plain = true,
+            other => return Err(CliParseError::UnknownSubcommand(other.to_string())),
+        }
+    }
+    Ok(IssueCommand::List { plain })
+}
+
+/// Parse `gwt issue start <n> [--agent <a>] [--branch <b>] [--no-ai]
+/// [--detach]`.
+pub(super) fn parse_start(args: &[&String]) -> Result<IssueCommand, CliParseError> {
+    let Some(number_arg) = args.first() else {
+        return Err(CliParseError::Usage);
+    };
+    let number = number_arg
+        .parse()
+        .map_err(|_| CliParseError::InvalidNumber((*number_arg).clone()))?;
+    let mut agent = DEFAULT_AGENT.to_string();
+    let mut branch = None;
+    let mut ai = true;
+    let mut detach = false;
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--agent" => {
+                agent = (*iter.next().ok_or(CliParseError::MissingFlag("--agent"))?).clone();
+            }
+            "--branch" => {
+                let value = iter.next().ok_or(CliParseError::MissingFlag("--branch"))?;
+                branch = Some((*value).clone()).filter(|value| !value.trim().is_empty());
+            }
+            "--no-ai" => ai = false,
+            "--detach" => detach = true,
+            other => return Err(CliParseError::UnknownSubcommand(other.to_string())),
+        }
+    }
+    if gwt_agent::headless::headless_agent(&agent).is_none() {
+        return Err(CliParseError::InvalidValue {
+            flag: "--agent",
+            reason: "expected claude or codex",
+        });
+    }
+    Ok(IssueCommand::Start {
+        number,
+        agent,
+        branch,
+        ai,
+        detach,
+    })
+}
+
+pub(super) fn run_list<E: CliEnv>(env: &mut E, plain: bool, out: &mut String) -> i32 {
+    let mut issues: Vec<IssueSnapshot> = Cache::new(env.cache_root())
+        .list_entries()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|entry| entry.snapshot)
+        .filter(|snapshot| snapshot.state == IssueState::Open)
+        .collect();
+    if issues.is_empty() && !plain {
+        out.push_str("No open issues in the local cache.\n");
+        return 0;
+    }
+    issues.sort_by(|left, right| right.updated_at.0.cmp(&left.updated_at.0));
+    let mut table = Table::new(&["ISSUE", "TITLE", "LABELS", "UPDATED"]);
+    for issue in issues {
+        table.row([
+            format!("#{}", issue.number.0),
+            issue.title,
+            issue.labels.join(","),
+            issue.updated_at.0,
+        ]);
+    }
+    out.push_str(&table.render(TableStyle::from_flag(plain)));
+    0
+}
+
+pub(super) fn run_start<E: CliEnv>(
+    env: &mut E,
+    number: u64,
+    agent: String,
+    branch: Option<String>,
+    ai: bool,
+    detach: bool,
+    out: &mut String,
+) -> Result<i32, SpecOpsError> {
+    let snapshot = load_or_refresh_issue(env, IssueNumber(number), true)?.snapshot;
+    if snapshot.state != IssueState::Open {
+        out.push_str(&format!("issue #{number} is closed\n"));
+        return Ok(1);
+    }
+    let branch = match branch {
+        Some(branch) => branch,
+        None => {
+            let suggested = if ai { ai_branch_name(&snapshot) } else { None };
+            suggested.unwrap_or_else(|| issue_branch_name(number, &snapshot.title))
+        }
+    };
+    out.push_str(&format!("#{number} {}\nbranch: {branch}\n", snapshot.title));
+    jobs::run(
+        env,
+        JobsCommand::Run {
+            branch,
+            agent,
+            prompt: JobPrompt::Text(issue_prompt(&snapshot)),
+            detach,
+        },
+        out,
+    )
+}
+
+/// `work/issue-<n>-<slug>`, the Launch Wizard's issue branch plus the title.
+fn issue_branch_name(number: u64, title: &str) -> String {
+    let mut slug = String::new();
+    for word in title
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+    {
+        if slug.len() + word.len() + 1 > MAX_SLUG_CHARS {
+            break;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(&word.to_ascii_lowercase());
+    }
+    if slug.is_empty() {
+        format!("work/issue-{number}")
+    } else {
+        format!("work/issue-{number}-{slug}")
+    }
+}
+
+/// First AI suggestion for the issue, or `None` without a configured AI.
+fn ai_branch_name(snapshot: &IssueSnapshot) -> Option<String> {
+    let ai = gwt_config::Settings::load().unwrap_or_default().ai;
+    if !ai.is_enabled() {
+        return None;
+    }
+    let provider = gwt_ai::build_provider(
+        &ai.provider,
+        &ai.endpoint,
+        ai.api_key.as_deref().unwrap_or(""),
+        &ai.model,
+    )
+    .ok()?;
+    let context = format!("{}\n\n{}", snapshot.title, snapshot.body);
+    match gwt_ai::run_ai_job(
+        "Branch name suggestions",
+        gwt_ai::AiJobPriority::Interactive,
+        || gwt_ai::suggest_branch_name(provider.as_ref(), &context),
+    ) {
+        Ok(suggestions) => suggestions.into_iter().next(),
+        Err(error) => {
+            tracing::info!(issue = snapshot.number.0, error = %error, "AI branch name unavailable");
+            None
+        }
+    }
+}
+
+fn issue_prompt(snapshot: &IssueSnapshot) -> String {
+    let mut prompt = format!(
+        "Resolve GitHub issue #{}: {}\n",
+        snapshot.number.0, snapshot.title
+    );
+    let body = snapshot.body.trim();
+    if !body.is_empty() {
+        prompt.push_str(&format!("\n{body}\n"));
+    }
+    prompt.push_str(&format!(
+        "\nReference #{} in your commits.\n",
+        snapshot.number.0
+    ));
+    prompt
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_start_defaults_and_flags() {
+        let args = s(&["42"]);
+        let refs: Vec<&String> = args.iter().collect();
+        assert_eq!(
+            parse_start(&refs).unwrap(),
+            IssueCommand::Start {
+                number: 42,
+                agent: "claude".to_string(),
+                branch: None,
+                ai: true,
+                detach: false,
+            }
+        );
+        let args = s(&[
+            "7", "--agent", "codex", "--branch", "fix/x", "--no-ai", "--detach",
+        ]);
+        let refs: Vec<&String> = args.iter().collect();
+        assert_eq!(
+            parse_start(&refs).unwrap(),
+            IssueCommand::Start {
+                number: 7,
+                agent: "codex".to_string(),
+                branch: Some("fix/x".to_string()),
+                ai: false,
+                detach: true,
+            }
+        );
+        let args = s(&["7", "--agent", "gemini"]);
+        let refs: Vec<&String> = args.iter().collect();
+        assert!(parse_start(&refs).is_err());
+    }
+
+    #[test]
+    fn branch_name_slugs_the_title() {
+        assert_eq!(
+            issue_branch_name(42, "Crash when saving: \"settings.toml\"!"),
+            "work/issue-42-crash-when-saving-settings-toml"
+        );
+        assert_eq!(issue_branch_name(9, "設定の保存"), "work/issue-9");
+        assert_eq!(
+            issue_branch_name(1, &"word ".repeat(20)),
+            "work/issue-1-word-word-word-word-word-word-word-word"
+        );
+    }
+
+    #[test]
+    fn list_shows_open_cached_issues() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut env = crate::cli::TestEnv::new(tmp.path().to_path_buf());
+        let cache = Cache::new(tmp.path().to_path_buf());
+        let mut snapshot = crate::cli::test_support::sample_issue_snapshot();
+        cache.write_snapshot(&snapshot).unwrap();
+        snapshot.number = IssueNumber(43);
+        snapshot.state = IssueState::Closed;
+        cache.write_snapshot(&snapshot).unwrap();
+
+        let mut out = String::new();
+        assert_eq!(run_list(&mut env, true, &mut out), 0);
+        assert_eq!(out.lines().count(), 1);
+        assert!(out.starts_with("#42\t"));
+    }
+}
@@ -0,0 +1,866 @@
+//! `gwt run ...` / `gwt jobs ...` family module.
+//!
+//! A job is a [`gwt_core::task_queue`] record: `gwt run` queues one for the
+//! current repository and runs it through [`gwt_agent::headless::run_task`],
+//! which creates or reuses the branch worktree and sends the agent output to
+//! the job log. With `--detach` the run happens in a background
+//! `gwt __internal run-job <id>` process and only the job id is printed.
+//! Either way the run waits for a free `[jobs] max_parallel_agents` slot and
+//! retries per `[jobs] max_attempts` (see [`gwt_core::jobs`]), so queueing
+//! many detached runs drains them a few at a time.
+//!
+//! `gwt jobs conduct <plan.json>` is the conductor mode on top of that: it
+//! queues one detached job per sub-task of the plan (each on its own branch
+//! worktree), waits until all of them have finished and prints one summary
+//! of their outcomes. `gwt jobs pair` has an implementer and a reviewer
+//! agent take turns on one branch (see [`pair`]).
+
+use std::{
+    collections::HashSet,
+    io::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use gwt_core::{
+    jobs::{self, JobEventKind},
+    task_queue::{self, TaskRecord, TaskRequest},
+};
+use gwt_github::{client::ApiError, SpecOpsError};
+use serde::Deserialize;
+
+mod pair;
+
+use super::{
+    table::{Table, TableStyle},
+    CliEnv, CliParseError,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobPrompt {
+    Text(String),
+    File(PathBuf),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobsCommand {
+    /// `gwt run --branch <b> --agent <a> (--prompt <text> | --prompt-file
+    /// <path>) [--detach]`.
+    Run {
+        branch: String,
+        agent: String,
+        prompt: JobPrompt,
+        detach: bool,
+    },
+    /// `gwt jobs status [--plain] [<id>]` — one job, or all jobs newest
+    /// first.
+    Status { id: Option<String>, plain: bool },
+    /// `gwt jobs log <id>` — the agent output recorded so far.
+    Log { id: String },
+    /// `gwt jobs events [--plain]` — queued / started / retrying / finished
+    /// history.
+    Events { plain: bool },
+    /// `gwt jobs conduct [--plain] <plan.json>` — run every task of the plan
+    /// as a detached job and summarize them once all have finished.
+    Conduct { plan: PathBuf, plain: bool },
+    /// `gwt jobs pair --branch <b> --implementer <a> --reviewer <a>
+    /// (--prompt <text> | --prompt-file <path>) [--rounds <n>]`.
+    Pair {
+        branch: String,
+        implementer: String,
+        reviewer: String,
+        prompt: JobPrompt,
+        rounds: u32,
+    },
+    /// `gwt __internal run-job <id>` — body of a detached run.
+    InternalRun { id: String },
+}
+
+/// Parse `gwt run ...` after the verb has been stripped.
+pub fn parse_run(args: &[String]) -> Result<JobsCommand, CliParseError> {
+    let mut branch = None;
+    let mut agent = None;
+    let mut prompt = None;
+    let mut detach = false;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--branch" => {
+                branch = Some(iter.next().ok_or(CliParseError::MissingFlag("--branch"))?);
+            }
+            "--agent" => {
+                agent = Some(iter.next().ok_or(CliParseError::MissingFlag("--agent"))?);
+            }
+            "--prompt" | "--prompt-file" if prompt.is_some() => {
+                return Err(CliParseError::InvalidValue {
+                    flag: "--prompt",
+                    reason: "use either --prompt or --prompt-file",
+                });
+            }
+            "--prompt" => {
+                let text = iter.next().ok_or(CliParseError::MissingFlag("--prompt"))?;
+                prompt = Some(JobPrompt::Text(text.clone()));
+            }
+            "--prompt-file" => {
+                let path = iter
+                    .next()
+                    .ok_or(CliParseError::MissingFlag("--prompt-file"))?;
+                prompt = Some(JobPrompt::File(PathBuf::from(path)));
+            }
+            "--detach" => detach = true,
+            other => return Err(CliParseError::UnknownSubcommand(other.to_string())),
+        }
+    }
+    let branch = branch
+        .filter(|branch| !branch.trim().is_empty())
+        .ok_or(CliParseError::MissingFlag("--branch"))?;
+    let agent = agent.ok_or(CliParseError::MissingFlag("--agent"))?;
+    if gwt_agent::headless::headless_agent(agent).is_none() {
+        return Err(CliParseError::InvalidValue {
+            flag: "--agent",
+            reason: "expected claude or codex",
+        });
+    }
+    Ok(JobsCommand::Run {
+        branch: branch.clone(),
+        agent: agent.clone(),
+        prompt: prompt.ok_or(CliParseError::MissingFlag("--prompt-file"))?,
+        detach,
+    })
+}
+
+/// Parse `gwt jobs ...` after the verb has been stripped.
+pub fn parse_jobs(args: &[String]) -> Result<JobsCommand, CliParseError> {
+    let plain = args.iter().any(|arg| arg == "--plain");
+    let args: Vec<String> = args
+        .iter()
+        .filter(|arg| *arg != "--plain")
+        .cloned()
+        .collect();
+    match args.as_slice() {
+        [head] if head == "status" => Ok(JobsCommand::Status { id: None, plain }),
+        [head, id] if head == "status" => Ok(JobsCommand::Status {
+            id: Some(id.clone()),
+            plain,
+        }),
+        [head, id] if head == "log" && !plain => Ok(JobsCommand::Log { id: id.clone() }),
+        [head] if head == "events" => Ok(JobsCommand::Events { plain }),
+        [head, plan] if head == "conduct" => Ok(JobsCommand::Conduct {
+            plan: PathBuf::from(plan),
+            plain,
+        }),
+        [head, rest @ ..] if head == "pair" && !plain => parse_pair(rest),
+        [head, ..]
+            if !matches!(
+                head.as_str(),
+                "status" | "log" | "events" | "conduct" | "pair"
+            ) =>
+        {
+            Err(CliParseError::UnknownSubcommand(head.clone()))
+        }
+        _ => Err(CliParseError::Usage),
+    }
+}
+
+fn parse_pair(args: &[String]) -> Result<JobsCommand, CliParseError> {
+    let mut branch = None;
+    let mut implementer = None;
+    let mut reviewer = None;
+    let mut prompt = None;
+    let mut rounds = pair::DEFAULT_PAIR_ROUNDS;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--branch" => {
+                branch = Some(iter.next().ok_or(CliParseError::MissingFlag("--branch"))?);
+            }
+            "--implementer" => {
+                implementer = Some(
+                    iter.next()
+                        .ok_or(CliParseError::MissingFlag("--implementer"))?,
+                );
+            }
+            "--reviewer" => {
+                reviewer = Some(
+                    iter.next()
+                        .ok_or(CliParseError::MissingFlag("--reviewer"))?,
+                );
+            }
+            "--prompt" | "--prompt-file" if prompt.is_some() => {
+                return Err(CliParseError::InvalidValue {
+                    flag: "--prompt",
+                    reason: "use either --prompt or --prompt-file",
+                });
+            }
+            "--prompt" => {
+                let text = iter.next().ok_or(CliParseError::MissingFlag("--prompt"))?;
+                prompt = Some(JobPrompt::Text(text.clone()));
+            }
+            "--prompt-file" => {
+                let path = iter
+                    .next()
+                    .ok_or(CliParseError::MissingFlag("--prompt-file"))?;
+                prompt = Some(JobPrompt::File(PathBuf::from(path)));
+            }
+            "--rounds" => {
+                let raw = iter.next().ok_or(CliParseError::MissingFlag("--rounds"))?;
+                rounds = raw
+                    .parse()
+                    .ok()
+                    .filter(|rounds| *rounds > 0)
+                    .ok_or_else(|| CliParseError::InvalidNumber(raw.clone()))?;
+            }
+            other => return Err(CliParseError::UnknownSubcommand(other.to_string())),
+        }
+    }
+    let branch = branch
+        .filter(|branch| !branch.trim().is_empty())
+        .ok_or(CliParseError::MissingFlag("--branch"))?;
+    let implementer = implementer.ok_or(CliParseError::MissingFlag("--implementer"))?;
+    let reviewer = reviewer.ok_or(CliParseError::MissingFlag("--reviewer"))?;
+    for (flag, agent) in [("--implementer", implementer), ("--reviewer", reviewer)] {
+        if gwt_agent::headless::headless_agent(agent).is_none() {
+            return Err(CliParseError::InvalidValue {
+                flag,
+                reason: "expected claude or codex",
+            });
+        }
+    }
+    Ok(JobsCommand::Pair {
+        branch: branch.clone(),
+        implementer: implementer.clone(),
+        reviewer: reviewer.clone(),
+        prompt: prompt.ok_or(CliParseError::MissingFlag("--prompt-file"))?,
+        rounds,
+    })
+}
+
+pub fn run<E: CliEnv>(
+    env: &mut E,
+    cmd: JobsCommand,
+    out: &mut String,
+) -> Result<i32, SpecOpsError> {
+    let dir = gwt_core::paths::gwt_tasks_dir();
+    match cmd {
+        JobsCommand::Run {
+            branch,
+            agent,
+            prompt,
+            detach,
+        } => {
+            let prompt = read_prompt(prompt)?;
+            let record = jobs::enqueue(
+                &dir,
+                TaskRequest {
+                    repo_path: env.repo_path().to_path_buf(),
+                    branch,
+                    agent,
+                    prompt,
+                },
+            )
+            .map_err(unexpected_error)?;
+            if detach {
+                spawn_detached_run(&record.id).map_err(unexpected_error)?;
+                out.push_str(&format!("{}\n", record.id));
+                return Ok(0);
+            }
+            run_job(&dir, &record.id, out)
+        }
+        JobsCommand::Conduct { plan, plain } => {
+            let text = std::fs::read_to_string(&plan)
+                .map_err(|error| unexpected_error(format!("read {}: {error}", plan.display())))?;
+            let tasks = parse_plan(&text).map_err(unexpected_error)?;
+            let mut ids = Vec::with_capacity(tasks.len());
+            for task in tasks {
+                let record = jobs::enqueue(
+                    &dir,
+                    TaskRequest {
+                        repo_path: env.repo_path().to_path_buf(),
+                        branch: task.branch,
+                        agent: task.agent,
+                        prompt: task.prompt,
+                    },
+                )
+                .map_err(unexpected_error)?;
+                spawn_detached_run(&record.id).map_err(unexpected_error)?;
+                let _ = writeln!(
+                    env.stderr(),
+                    "queued {} on {}",
+                    record.id,
+                    record.request.branch
+                );
+                ids.push(record.id);
+            }
+            let records = wait_for_jobs(&dir, &ids, || std::thread::sleep(CONDUCT_POLL_INTERVAL));
+            out.push_str(&render_conduct_summary(
+                &records,
+                TableStyle::from_flag(plain),
+            ));
+            Ok(
+                if records.len() == ids.len()
+                    && records
+                        .iter()
+                        .all(|record| record.state == task_queue::TaskState::Completed)
+                {
+                    0
+                } else {
+                    1
+                },
+            )
+        }
+        JobsCommand::Pair {
+            branch,
+            implementer,
+            reviewer,
+            prompt,
+            rounds,
+        } => {
+            let prompt = read_prompt(prompt)?;
+            let session = pair::PairSession {
+                implementer: &implementer,
+                reviewer: &reviewer,
+                prompt: &prompt,
+                rounds,
+                task_file: pair::task_file_path(&dir),
+            };
+            let policy = gwt_agent::headless::job_policy();
+            let repo_path = env.repo_path().to_path_buf();
+            pair::run_pair(
+                &session,
+                |agent, prompt| {
+                    let record = jobs::enqueue(
+                        &dir,
+                        TaskRequest {
+                            repo_path: repo_path.clone(),
+                            branch: branch.clone(),
+                            agent: agent.to_string(),
+                            prompt,
+                        },
+                    )
+                    .map_err(|error| error.to_string())?;
+                    let record =
+                        jobs::run_job(&dir, &record.id, &policy, gwt_agent::headless::run_task)
+                            .map_err(|error| error.to_string())?
+                            .ok_or_else(|| {
+                                format!("job {} was taken by another runner", record.id)
+                            })?;
+                    let output = task_queue::read_log(&dir, &record.id, 0).unwrap_or_default();
+                    Ok((record, output))
+                },
+                out,
+            )
+            .map_err(unexpected_error)
+        }
+        JobsCommand::InternalRun { id } => run_job(&dir, &id, out),
+        JobsCommand::Status { id, plain } => {
+            status_at(&dir, id.as_deref(), TableStyle::from_flag(plain), out)
+        }
+        JobsCommand::Events { plain } => {
+            events_at(&dir, TableStyle::from_flag(plain), out);
+            Ok(0)
+        }
+        JobsCommand::Log { id } => match task_queue::read_log(&dir, &id, 0) {
+            Some(log) => {
+                out.push_str(&log);
+                Ok(0)
+            }
+            None => {
+                out.push_str(&format!("no job {id}\n"));
+                Ok(1)
+            }
+        },
+    }
+}
+
+fn read_prompt(prompt: JobPrompt) -> Result<String, SpecOpsError> {
+    let prompt = match prompt {
+        JobPrompt::Text(text) => text,
+        JobPrompt::File(path) => std::fs::read_to_string(&path)
+            .map_err(|error| unexpected_error(format!("read {}: {error}", path.display())))?,
+    };
+    if prompt.trim().is_empty() {
+        return Err(unexpected_error("prompt is empty"));
+    }
+    Ok(prompt)
+}
+
+/// Run job `id` in this process, retries included, and print its final
+/// status. The exit code is 0 only when the agent completed successfully.
+fn run_job(dir: &Path, id: &str, out: &mut String) -> Result<i32, SpecOpsError> {
+    let policy = gwt_agent::headless::job_policy();
+    let Some(record) =
+        jobs::run_job(dir, id, &policy, gwt_agent::headless::run_task).map_err(unexpected_error)?
+    else {
+        out.push_str(&format!("job {id} is not queued\n"));
+        return Ok(1);
+    };
+    out.push_str(&render_record(&record));
+    Ok(if record.state == task_queue::TaskState::Completed {
+        0
+    } else {
+        1
+    })
+}
+
+/// How often `conduct` re-reads the records of its jobs.
+const CONDUCT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A conductor plan: `{"tasks": [{"branch", "agent", "prompt"}, ...]}`.
+#[derive(Debug, Deserialize)]
+struct ConductPlan {
+    tasks: Vec<ConductTask>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+struct ConductTask {
+    branch: String,
+    agent: String,
+    prompt: String,
+}
+
+/// Parse and check a plan: at least one task, a headless agent and a
+/// prompt for each, and no two tasks on the same branch worktree.
+fn parse_plan(text: &str) -> Result<Vec<ConductTask>, String> {
+    let plan: ConductPlan =
+        serde_json::from_str(text).map_err(|error| format!("invalid plan: {error}"))?;
+    if plan.tasks.is_empty() {
+        return Err("plan has no tasks".to_string());
+    }
+    let mut branches = HashSet::new();
+    for task in &plan.tasks {
+        if task.branch.trim().is_empty() {
+            return Err("plan task without a branch".to_string());
+        }
+        if !branches.insert(task.branch.trim()) {
+            return Err(format!("plan has two tasks on {}", task.branch));
+        }
+        if gwt_agent::headless::headless_agent(&task.agent).is_none() {
+            return Err(format!(
+                "{}: agent {} is not claude or codex",
+                task.branch, task.agent
+            ));
+        }
+        if task.prompt.trim().is_empty() {
+            return Err(format!("{}: prompt is empty", task.branch));
+        }
+    }
+    Ok(plan.tasks)
+}
+
+/// Poll the records of `ids`, calling `wait` between rounds, until every
+/// one of them has finished. Jobs whose record is gone are left out.
+fn wait_for_jobs(dir: &Path, ids: &[String], mut wait: impl FnMut()) -> Vec<TaskRecord> {
+    loop {
+        let records: Vec<TaskRecord> = ids
+            .iter()
+            .filter_map(|id| task_queue::load_task(dir, id))
+            .collect();
+        if records.iter().all(|record| record.state.is_finished()) {
+            return records;
+        }
+        wait();
+    }
+}
+
+fn render_conduct_summary(records: &[TaskRecord], style: TableStyle) -> String {
+    let mut table = Table::new(&["JOB", "STATE", "BRANCH", "WORKTREE", "RESULT"]);
+    for record in records {
+        let result = match (&record.message, record.exit_code) {
+            (Some(message), _) => message.clone(),
+            (None, Some(code)) => format!("exit {code}"),
+            (None, None) => String::new(),
+        };
+        table.row([
+            record.id.clone(),
+            state_label(record.state).to_string(),
+            record.request.branch.clone(),
+            record
+                .worktree
+                .as_ref()
+                .map(|path| path.display().to_string())
+                .unwrap_or_default(),
+            result,
+        ]);
+    }
+    let mut text = table.render(style);
+    if style != TableStyle::Plain {
+        let completed = records
+            .iter()
+            .filter(|record| record.state == task_queue::TaskState::Completed)
+            .count();
+        text.push_str(&format!(
+            "{completed} of {} tasks completed\n",
+            records.len()
+        ));
+    }
+    text
+}
+
+fn status_at(
+    dir: &Path,
+    id: Option<&str>,
+    style: TableStyle,
+    out: &mut String,
+) -> Result<i32, SpecOpsError> {
+    match id {
+        Some(id) => match task_queue::load_task(dir, id) {
+            Some(record) => out.push_str(&render_record(&record)),
+            None => {
+                out.push_str(&format!("no job {id}\n"));
+                return Ok(1);
+            }
+        },
+        None => {
+            let mut table = Table::new(&["JOB", "STATE", "AGENT", "BRANCH"]);
+            for record in task_queue::list_tasks(dir) {
+                table.row([
+                    record.id,
+                    state_label(record.state).to_string(),
+                    record.request.agent,
+                    record.request.branch,
+                ]);
+            }
+            if table.is_empty() && style != TableStyle::Plain {
+                out.push_str("No jobs.\n");
+            } else {
+                out.push_str(&table.render(style));
+            }
+        }
+    }
+    Ok(0)
+}
+
+fn events_at(dir: &Path, style: TableStyle, out: &mut String) {
+    let (events, _) = jobs::read_events(dir, 0);
+    if events.is_empty() && style != TableStyle::Plain {
+        out.push_str("No job events.\n");
+        return;
+    }
+    let mut table = Table::new(&["TIME", "JOB", "EVENT"]);
+    for event in events {
+        let detail = match event.kind {
+            JobEventKind::Queued => "queued".to_string(),
+            JobEventKind::Started { attempt } => format!("started (attempt {attempt})"),
+            JobEventKind::Retrying { attempt, retry_at } => format!(
+                "attempt {attempt} failed, retrying at {}",
+                retry_at.format("%Y-%m-%d %H:%M:%S UTC")
+            ),
+            JobEventKind::Completed => "completed".to_string(),
+            JobEventKind::Failed { message } => match message {
+                Some(message) => format!("failed: {message}"),
+                None => "failed".to_string(),
+            },
+        };
+        table.row([
+            event.at.format("%Y-%m-%d %H:%M:%S").to_string(),
+            event.id,
+            detail,
+        ]);
+    }
+    out.push_str(&table.render(style));
+}
+
+fn render_record(record: &TaskRecord) -> String {
+    let mut text = format!(
+        "job:      {}\nstate:    {}\nagent:    {}\nbranch:   {}\n",
+        record.id,
+        state_label(record.state),
+        record.request.agent,
+        record.request.branch
+    );
+    if let Some(worktree) = &record.worktree {
+        text.push_str(&format!("worktree: {}\n", worktree.display()));
+    }
+    if record.attempts > 1 {
+        text.push_str(&format!("attempts: {}\n", record.attempts));
+    }
+    if let Some(retry_at) = record.retry_at {
+        text.push_str(&format!(
+            "retry at: {}\n",
+            retry_at.format("%Y-%m-%d %H:%M:%S UTC")
+        ));
+    }
+    if let Some(code) = record.exit_code {
+        text.push_str(&format!("exit:     {code}\n"));
+    }
+    if let Some(message) = &record.message {
+        text.push_str(&format!("message:  {message}\n"));
+    }
+    text
+}
+
+fn state_label(state: task_queue::TaskState) -> &'static str {
+    match state {
+        task_queue::TaskState::Queued => "queued",
+        task_queue::TaskState::Running => "running",
+        task_queue::TaskState::Completed => "completed",
+        task_queue::TaskState::Failed => "failed",
+        task_queue::TaskState::Interrupted => "interrupted",
+    }
+}
+
+/// Start `gwt __internal run-job <id>` in its own process group with no
+/// stdio, so it keeps running after this command and its terminal exit.
+fn spawn_detached_run(id: &str) -> std::io::Result<()> {
+    let exe = std::env::current_exe()?;
+    let mut command = gwt_core::process::hidden_command(exe);
+    command
+        .args(["__internal", "run-job", id])
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+    command.spawn().map(drop)
+}
+
+fn unexpected_error(err: impl ToString) -> SpecOpsError {
+    SpecOpsError::from(ApiError::Unexpected(err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_run_requires_branch_agent_and_one_prompt() {
+        assert_eq!(
+            parse_run(&args(&[
+                "--branch",
+                "feature/x",
+                "--agent",
+                "codex",
+                "--prompt-file",
+                "task.md",
+                "--detach",
+            ]))
+            .unwrap(),
+            JobsCommand::Run {
+                branch: "feature/x".to_string(),
+                agent: "codex".to_string(),
+                prompt: JobPrompt::File(PathBuf::from("task.md")),
+                detach: true,
+            }
+        );
+        assert!(parse_run(&args(&["--agent", "codex", "--prompt", "x"])).is_err());
+        assert!(parse_run(&args(&[
+            "--branch", "b", "--agent", "gemini", "--prompt", "x"
+        ]))
+        .is_err());
+        assert!(parse_run(&args(&[
+            "--branch",
+            "b",
+            "--agent",
+            "codex",
+            "--prompt",
+            "x",
+            "--prompt-file",
+            "t.md"
+        ]))
+        .is_err());
+        assert!(parse_run(&args(&["--branch", "b", "--agent", "codex"])).is_err());
+    }
+
+    #[test]
+    fn parse_jobs_accepts_status_and_log() {
+        assert_eq!(
+            parse_jobs(&args(&["status"])).unwrap(),
+            JobsCommand::Status {
+                id: None,
+                plain: false
+            }
+        );
+        assert_eq!(
+            parse_jobs(&args(&["status", "--plain", "abc"])).unwrap(),
+            JobsCommand::Status {
+                id: Some("abc".to_string()),
+                plain: true
+            }
+        );
+        assert_eq!(
+            parse_jobs(&args(&["log", "abc"])).unwrap(),
+            JobsCommand::Log {
+                id: "abc".to_string()
+            }
+        );
+        assert_eq!(
+            parse_jobs(&args(&["events"])).unwrap(),
+            JobsCommand::Events { plain: false }
+        );
+        assert!(parse_jobs(&args(&["log"])).is_err());
+        assert!(parse_jobs(&args(&["log", "--plain", "abc"])).is_err());
+        assert_eq!(
+            parse_jobs(&args(&["conduct", "--plain", "plan.json"])).unwrap(),
+            JobsCommand::Conduct {
+                plan: PathBuf::from("plan.json"),
+                plain: true
+            }
+        );
+        assert!(parse_jobs(&args(&["conduct"])).is_err());
+        assert_eq!(
+            parse_jobs(&args(&[
+                "pair",
+                "--branch",
+                "feature/x",
+                "--implementer",
+                "claude",
+                "--reviewer",
+                "codex",
+                "--prompt",
+                "add it",
+                "--rounds",
+                "3",
+            ]))
+            .unwrap(),
+            JobsCommand::Pair {
+                branch: "feature/x".to_string(),
+                implementer: "claude".to_string(),
+                reviewer: "codex".to_string(),
+                prompt: JobPrompt::Text("add it".to_string()),
+                rounds: 3,
+            }
+        );
+        assert!(parse_jobs(&args(&[
+            "pair",
+            "--branch",
+            "b",
+            "--implementer",
+            "claude",
+            "--reviewer",
+            "gemini",
+            "--prompt",
+            "x"
+        ]))
+        .is_err());
+        assert!(parse_jobs(&args(&[
+            "pair",
+            "--branch",
+            "b",
+            "--implementer",
+            "claude",
+            "--reviewer",
+            "codex",
+            "--prompt",
+            "x",
+            "--rounds",
+            "0"
+        ]))
+        .is_err());
+        assert!(parse_jobs(&args(&["cancel", "abc"])).is_err());
+    }
+
+    #[test]
+    fn parse_plan_checks_tasks() {
+        let tasks = parse_plan(
+            r#"{"tasks": [
+                {"branch": "sub/api", "agent": "codex", "prompt": "add the endpoint"},
+                {"branch": "sub/ui", "agent": "claude", "prompt": "add the button"}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[1].branch, "sub/ui");
+
+        assert!(parse_plan(r#"{"tasks": []}"#).is_err());
+        let duplicate = r#"{"tasks": [
+            {"branch": "sub/a", "agent": "codex", "prompt": "x"},
+            {"branch": "sub/a", "agent": "codex", "prompt": "y"}
+        ]}"#;
+        assert!(parse_plan(duplicate).unwrap_err().contains("two tasks"));
+        let agent = r#"{"tasks": [{"branch": "b", "agent": "gemini", "prompt": "x"}]}"#;
+        assert!(parse_plan(agent).is_err());
+        let prompt = r#"{"tasks": [{"branch": "b", "agent": "codex", "prompt": " "}]}"#;
+        assert!(parse_plan(prompt).is_err());
+    }
+
+    #[test]
+    fn conduct_waits_for_every_job_and_summarizes_them() {
+        let dir = tempfile::tempdir().unwrap();
+        let request = |branch: &str| TaskRequest {
+            repo_path: PathBuf::from("/repo"),
+            branch: branch.to_string(),
+            agent: "codex".to_string(),
+            prompt: "do it".to_string(),
+        };
+        let ok = jobs::enqueue(dir.path(), request("sub/ok")).unwrap();
+        let bad = jobs::enqueue(dir.path(), request("sub/bad")).unwrap();
+        let ids = vec![ok.id.clone(), bad.id.clone()];
+
+        let mut rounds = 0;
+        let records = wait_for_jobs(dir.path(), &ids, || {
+            rounds += 1;
+            let (id, code) = if rounds == 1 {
+                (&ok.id, Some(0))
+            } else {
+                (&bad.id, Some(3))
+            };
+            task_queue::claim(dir.path(), id)
+                .unwrap()
+                .unwrap()
+                .finish(code, None);
+        });
+        assert_eq!(rounds, 2);
+        assert_eq!(records.len(), 2);
+
+        let summary = render_conduct_summary(&records, TableStyle::Aligned { width: None });
+        assert!(summary.starts_with("JOB"));
+        assert!(summary.contains("completed") && summary.contains("exit 3"));
+        assert!(summary.ends_with("1 of 2 tasks completed\n"));
+        let plain = render_conduct_summary(&records, TableStyle::Plain);
+        assert_eq!(
+            plain.lines().next().unwrap(),
+            format!("{}\tcompleted\tsub/ok\t\texit 0", ok.id)
+        );
+    }
+
+    #[test]
+    fn status_lists_jobs_and_reports_missing_ids() {
+        let aligned = TableStyle::Aligned { width: None };
+        let dir = tempfile::tempdir().unwrap();
+        let mut out = String::new();
+        status_at(dir.path(), None, aligned, &mut out).unwrap();
+        assert_eq!(out, "No jobs.\n");
+
+        let record = jobs::enqueue(
+            dir.path(),
+            TaskRequest {
+                repo_path: PathBuf::from("/repo"),
+                branch: "feature/x".to_string(),
+                agent: "codex".to_string(),
+                prompt: "fix it".to_string(),
+            },
+        )
+        .unwrap();
+        let mut out = String::new();
+        status_at(dir.path(), None, aligned, &mut out).unwrap();
+        assert!(out.starts_with("JOB"));
+        assert!(out.lines().nth(1).unwrap().starts_with(&record.id));
+        assert!(out.contains("queued") && out.contains("feature/x"));
+        let mut out = String::new();
+        status_at(dir.path(), None, TableStyle::Plain, &mut out).unwrap();
+        assert_eq!(out, format!("{}\tqueued\tcodex\tfeature/x\n", record.id));
+
+        let mut out = String::new();
+        assert_eq!(
+            status_at(dir.path(), Some(&record.id), aligned, &mut out).unwrap(),
+            0
+        );
+        assert!(out.contains("state:    queued"));
+        let mut out = String::new();
+        assert_eq!(
+            status_at(dir.path(), Some("missing"), aligned, &mut out).unwrap(),
+            1
+        );
+
+        let mut out = String::new();
+        events_at(dir.path(), aligned, &mut out);
+        assert!(out.contains(&format!("{}  queued", record.id)));
+    }
+}
@@ -0,0 +1,261 @@
+//! `gwt jobs pair`: an implementer and a reviewer agent taking turns on
+//! one branch worktree.
+//!
+//! Every turn is an ordinary headless job on the same branch, so the turns
+//! show up in `gwt jobs status` as they run. gwt relays between the two
+//! agents through a shared task file in the jobs directory: it starts with
+//! the task, each turn's prompt carries the whole file, and the tail of each
+//! turn's output is appended to it. The session ends when the reviewer's
+//! last line is [`APPROVED_MARKER`], when a turn fails, or after the given
+//! number of rounds.
+
+use std::path::{Path, PathBuf};
+
+use gwt_core::task_queue::{TaskRecord, TaskState};
+
+pub(super) const DEFAULT_PAIR_ROUNDS: u32 = 2;
+
+/// Line the reviewer ends its turn with when nothing is left to change.
+const APPROVED_MARKER: &str = "APPROVED";
+
+/// Characters of a turn's output that are relayed to the other agent.
+const RELAY_TAIL_CHARS: usize = 4000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    Implementer,
+    Reviewer,
+}
+
+impl Role {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Implementer => "implementer",
+            Self::Reviewer => "reviewer",
+        }
+    }
+
+    fn instructions(self) -> &'static str {
+        match self {
+            Self::Implementer => {
+                "You are the implementer of a paired session in this worktree. Make the \
+                 changes the task asks for and address the reviewer's notes below, if any. \
+                 Finish with a short summary of what you changed."
+            }
+            Self::Reviewer => {
+                "You are the reviewer of a paired session in this worktree. Review the \
+                 changes in the worktree (git diff and recent commits) against the task \
+                 below without editing any files. List what still has to change, or end \
+                 with a line that says only APPROVED when nothing is left."
+            }
+        }
+    }
+}
+
+pub(super) struct PairSession<'a> {
+    pub(super) implementer: &'a str,
+    pub(super) reviewer: &'a str,
+    pub(super) prompt: &'a str,
+    pub(super) rounds: u32,
+    /// The shared task file.
+    pub(super) task_file: PathBuf,
+}
+
+/// Shared task file for a session started now.
+pub(super) fn task_file_path(dir: &Path) -> PathBuf {
+    dir.join(format!(
+        "pair-{}.md",
+        chrono::Utc::now().format("%Y%m%d-%H%M%S")
+    ))
+}
+
+/// Run the turns of `session`. `run_turn(agent, prompt)` runs one headless
+/// job on the session branch and returns its final record and output. The
+/// exit code is 0 only when the reviewer approved.
+pub(super) fn run_pair(
+    session: &PairSession<'_>,
+    mut run_turn: impl FnMut(&str, String) -> Result<(TaskRecord, String), String>,
+    out: &mut String,
+) -> Result<i32, String> {
+    let mut transcript = format!("# Task\n\n{}\n", session.prompt.trim());
+    write_task_file(&session.task_file, &transcript)?;
+    out.push_str(&format!("task file: {}\n", session.task_file.display()));
+    for round in 1..=session.rounds {
+        for role in [Role::Implementer, Role::Reviewer] {
+            let agent = match role {
+                Role::Implementer => session.implementer,
+                Role::Reviewer => session.reviewer,
+            };
+            let prompt = format!("{}\n\n{transcript}", role.instructions());
+            let (record, output) = run_turn(agent, prompt)?;
+            transcript.push_str(&format!(
+                "\n## Round {round} — {} ({agent})\n\n{}\n",
+                role.label(),
+                relay_tail(&output)
+            ));
+            write_task_file(&session.task_file, &transcript)?;
+            out.push_str(&format!(
+                "round {round} {} {agent}: {} (job {})\n",
+                role.label(),
+                super::state_label(record.state),
+                record.id
+            ));
+            if record.state != TaskState::Completed {
+                out.push_str(&format!("stopped: the {} turn failed\n", role.label()));
+                return Ok(1);
+            }
+            if role == Role::Reviewer && is_approved(&output) {
+                out.push_str(&format!("approved in round {round}\n"));
+                return Ok(0);
+            }
+        }
+    }
+    out.push_str(&format!("not approved after {} rounds\n", session.rounds));
+    Ok(1)
+}
+
+fn write_task_file(path: &Path, transcript: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|error| format!("create {}: {error}", parent.display()))?;
+    }
+    std::fs::write(path, transcript).map_err(|error| format!("write {}: {error}", path.display()))
+}
+
+fn relay_tail(output: &str) -> &str {
+    let output = output.trim();
+    let start = output
+        .char_indices()
+        .rev()
+        .nth(RELAY_TAIL_CHARS - 1)
+        .map_or(0, |(index, _)| index);
+    &output[start..]
+}
+
+fn is_approved(output: &str) -> bool {
+    output
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .is_some_and(|line| line.trim_matches('*') == APPROVED_MARKER)
+}
+
+#[cfg(test)]
+mod tests {
+    use gwt_core::task_queue::TaskRequest;
+
+    use super::*;
+
+    fn record(id: &str, agent: &str, state: TaskState) -> TaskRecord {
+        TaskRecord {
+            id: id.to_string(),
+            request: TaskRequest {
+                repo_path: PathBuf::from("/repo"),
+                branch: "feature/x".to_string(),
+                agent: agent.to_string(),
+                prompt: String::new(),
+            },
+            state,
+            worktree: None,
+            exit_code: None,
+            message: None,
+            created_at: chrono::Utc::now(),
+            started_at: None,
+            finished_at: None,
+            attempts: 1,
+            retry_at: None,
+        }
+    }
+
+    #[test]
+    fn turns_alternate_and_relay_output_until_approved() {
+        let dir = tempfile::tempdir().unwrap();
+        let session = PairSession {
+            implementer: "claude",
+            reviewer: "codex",
+            prompt: "Add a --json flag",
+            rounds: 3,
+            task_file: dir.path().join("pair.md"),
+        };
+        let replies = [
+            "Added the flag.",
+            "Missing a test for --json.",
+            "Added the test.",
+            "Looks good.\n**APPROVED**\n",
+        ];
+        let mut prompts = Vec::new();
+        let mut out = String::new();
+        let code = run_pair(
+            &session,
+            |agent, prompt| {
+                let turn = prompts.len();
+                prompts.push((agent.to_string(), prompt));
+                Ok((
+                    record(&format!("job-{turn}"), agent, TaskState::Completed),
+                    replies[turn].to_string(),
+                ))
+            },
+            &mut out,
+        )
+        .unwrap();
+
+        assert_eq!(code, 0);
+        let agents: Vec<&str> = prompts.iter().map(|(agent, _)| agent.as_str()).collect();
+        assert_eq!(agents, ["claude", "codex", "claude", "codex"]);
+        assert!(prompts[0].1.contains("Add a --json flag"));
+        assert!(prompts[2].1.contains("Missing a test for --json."));
+        assert!(out.ends_with("approved in round 2\n"), "{out}");
+        let file = std::fs::read_to_string(&session.task_file).unwrap();
+        assert!(file.starts_with("# Task\n\nAdd a --json flag\n"));
+        assert!(file.contains("## Round 2 — reviewer (codex)"));
+    }
+
+    #[test]
+    fn a_failed_turn_or_missing_approval_ends_with_an_error_code() {
+        let dir = tempfile::tempdir().unwrap();
+        let session = PairSession {
+            implementer: "codex",
+            reviewer: "claude",
+            prompt: "Fix the crash",
+            rounds: 1,
+            task_file: dir.path().join("pair.md"),
+        };
+        let mut out = String::new();
+        let code = run_pair(
+            &session,
+            |agent, _| Ok((record("job", agent, TaskState::Failed), String::new())),
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(code, 1);
+        assert!(
+            out.contains("stopped: the implementer turn failed"),
+            "{out}"
+        );
+
+        let mut out = String::new();
+        let code = run_pair(
+            &session,
+            |agent, _| {
+                Ok((
+                    record("job", agent, TaskState::Completed),
+                    "Not APPROVED yet".to_string(),
+                ))
+            },
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(code, 1);
+        assert!(out.ends_with("not approved after 1 rounds\n"), "{out}");
+    }
+
+    #[test]
+    fn relay_tail_keeps_the_end_of_long_output() {
+        let long = format!("{}end", "é".repeat(RELAY_TAIL_CHARS));
+        let tail = relay_tail(&long);
+        assert_eq!(tail.chars().count(), RELAY_TAIL_CHARS);
+        assert!(tail.ends_with("end"));
+        assert_eq!(relay_tail("  short \n"), "short");
+    }
+}
@@ -100,6 +100,65 @@ fn parse(input: &str) -> Result<ParsedEnvelope, CliParseError> {
                 ids: optional_string_vec(params, "ids")?,
             })
         }
+        "worktree.test_matrix" | "worktree.test-matrix" => {
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::TestMatrix {
+                branches: optional_string_vec(params, "branches")?,
+                command: optional_string_vec(params, "command")?,
+                concurrency: optional_usize(params, "concurrency")?,
+                json: optional_bool(params, "json")?.unwrap_or(false),
+            })
+        }
+        "worktree.archive" => {
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Archive {
+                branch: required_string(params, "branch")?,
+            })
+        }
+        "worktree.restore" => {
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Restore {
+                branch: required_string(params, "branch")?,
+            })
+        }
+        "worktree.compare" => {
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Compare {
+                branches: optional_string_vec(params, "branches")?,
+                base: optional_string(params, "base")?,
+                run_tests: optional_bool(params, "run_tests")?.unwrap_or(false),
+                command: optional_string_vec(params, "command")?,
+                concurrency: optional_usize(params, "concurrency")?,
+                markdown: optional_bool(params, "markdown")?.unwrap_or(false),
+                output: optional_path(params, "output")?,
+                json: optional_bool(params, "json")?.unwrap_or(false),
+            })
+        }
+        "worktree.archives" => {
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Archives)
+        }
+        "worktree.undo" => CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Undo {
+            branch: optional_string(params, "branch")?,
+        }),
+        "worktree.removed" => CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Removed),
+        "worktree.transplant" => {
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Transplant {
+                from: required_string(params, "from")?,
+                branch: required_string(params, "branch")?,
+            })
+        }
+        "worktree.rename" => CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Rename {
+            from: required_string(params, "from")?,
+            to: required_string(params, "to")?,
+            apply: optional_bool(params, "apply")?.unwrap_or(false),
+        }),
+        "worktree.notes" => worktree_notes(params)?,
+        "worktree.review" => CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Review {
+            reference: required_string(params, "ref")?,
+            path: optional_path(params, "path")?,
+        }),
+        "worktree.reviews" => CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Reviews),
+        "worktree.review_remove" | "worktree.review-remove" => {
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::ReviewRemove {
+                target: required_string(params, "target")?,
+            })
+        }
         "board.show" => board_show(params)?,
         "board.post" => board_post(params)?,
         "board.config.show" | "board.config-show" => {
@@ -440,6 +499,31 @@ fn workspace_ensure(params: &Map<String, Value>) -> Result<CliCommand, CliParseE
     }))
 }
 
+/// `note: ""` and `tags: []` clear the stored value; leaving a key out
+/// keeps it.
+fn worktree_notes(params: &Map<String, Value>) -> Result<CliCommand, CliParseError> {
+    let note = match params.get("note") {
+        None | Some(Value::Null) => None,
+        Some(Value::String(text)) => Some(text.clone()),
+        Some(_) => {
+            return Err(CliParseError::InvalidJson(
+                "note must be a string".to_string(),
+            ))
+        }
+    };
+    Ok(CliCommand::Worktree(
+        crate::cli::worktree::WorktreeCommand::Notes {
+            branch: required_string(params, "branch")?,
+            note,
+            tags: params
+                .contains_key("tags")
+                .then(|| optional_string_vec(params, "tags"))
+                .transpose()?,
+            sync: optional_bool(params, "sync")?.unwrap_or(false),
+        },
+    ))
+}
+
 fn board_show(params: &Map<String, Value>) -> Result<CliCommand, CliParseError> {
     Ok(CliCommand::Board(BoardCommand::Show {
         json: true,
@@ -1918,6 +2002,192 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn worktree_test_matrix_parses_optional_params() {
+        match ok(
+            "worktree.test_matrix",
+            json!({"branches": ["main", "feature/x"], "command": ["cargo", "test"], "concurrency": 3}),
+        ) {
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::TestMatrix {
+                branches,
+                command,
+                concurrency,
+                json,
+            }) => {
+                assert_eq!(branches, vec!["main", "feature/x"]);
+                assert_eq!(command, vec!["cargo", "test"]);
+                assert_eq!(concurrency, Some(3));
+                assert!(!json);
+            }
+            other => panic!("unexpected command: {other:?}"),
+        }
+        assert!(matches!(
+            ok("worktree.test-matrix", json!({})),
+            CliCommand::Worktree(_)
+        ));
+    }
+
+    #[test]
+    fn worktree_compare_parses_optional_params() {
+        match ok(
+            "worktree.compare",
+            json!({"branches": ["try/a", "try/b"], "base": "develop", "run_tests": true, "output": "cmp.md"}),
+        ) {
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Compare {
+                branches,
+                base,
+                run_tests,
+                output,
+                markdown,
+                ..
+            }) => {
+                assert_eq!(branches, vec!["try/a", "try/b"]);
+                assert_eq!(base.as_deref(), Some("develop"));
+                assert!(run_tests);
+                assert_eq!(output, Some(std::path::PathBuf::from("cmp.md")));
+                assert!(!markdown);
+            }
+            other => panic!("unexpected command: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn worktree_archive_operations_require_branch() {
+        assert_eq!(
+            ok("worktree.archive", json!({"branch": "work/x"})),
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Archive {
+                branch: "work/x".to_string()
+            })
+        );
+        assert!(matches!(
+            err("worktree.restore", json!({})),
+            CliParseError::MissingFlag("branch")
+        ));
+        assert!(matches!(
+            ok("worktree.archives", json!({})),
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Archives)
+        ));
+    }
+
+    #[test]
+    fn worktree_undo_branch_is_optional() {
+        assert_eq!(
+            ok("worktree.undo", json!({})),
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Undo { branch: None })
+        );
+        assert_eq!(
+            ok("worktree.undo", json!({"branch": "work/x"})),
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Undo {
+                branch: Some("work/x".to_string())
+            })
+        );
+        assert!(matches!(
+            ok("worktree.removed", json!({})),
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Removed)
+        ));
+    }
+
+    #[test]
+    fn worktree_review_operations_parse_ref_and_target() {
+        assert_eq!(
+            ok("worktree.review", json!({"ref": "origin/feature/x"})),
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Review {
+                reference: "origin/feature/x".to_string(),
+                path: None,
+            })
+        );
+        assert!(matches!(
+            err("worktree.review", json!({})),
+            CliParseError::MissingFlag("ref")
+        ));
+        assert_eq!(
+            ok(
+                "worktree.review-remove",
+                json!({"target": "origin/feature/x"})
+            ),
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::ReviewRemove {
+                target: "origin/feature/x".to_string(),
+            })
+        );
+        assert!(matches!(
+            ok("worktree.reviews", json!({})),
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Reviews)
+        ));
+    }
+
+    #[test]
+    fn worktree_transplant_requires_source_and_new_branch() {
+        assert_eq!(
+            ok(
+                "worktree.transplant",
+                json!({"from": "work/a", "branch": "try/b"})
+            ),
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Transplant {
+                from: "work/a".to_string(),
+                branch: "try/b".to_string(),
+            })
+        );
+        assert!(matches!(
+            err("worktree.transplant", json!({"branch": "try/b"})),
+            CliParseError::MissingFlag("from")
+        ));
+    }
+
+    #[test]
+    fn worktree_rename_previews_unless_apply_is_set() {
+        assert_eq!(
+            ok(
+                "worktree.rename",
+                json!({"from": "users/me/*", "to": "feature/*"})
+            ),
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Rename {
+                from: "users/me/*".to_string(),
+                to: "feature/*".to_string(),
+                apply: false,
+            })
+        );
+        assert!(matches!(
+            ok(
+                "worktree.rename",
+                json!({"from": "a", "to": "b", "apply": true})
+            ),
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Rename { apply: true, .. })
+        ));
+        assert!(matches!(
+            err("worktree.rename", json!({"from": "a"})),
+            CliParseError::MissingFlag("to")
+        ));
+    }
+
+    #[test]
+    fn worktree_notes_distinguishes_missing_and_empty_values() {
+        assert_eq!(
+            ok("worktree.notes", json!({"branch": "feature/a"})),
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Notes {
+                branch: "feature/a".to_string(),
+                note: None,
+                tags: None,
+                sync: false,
+            })
+        );
+        assert_eq!(
+            ok(
+                "worktree.notes",
+                json!({"branch": "feature/a", "note": "", "tags": [], "sync": true})
+            ),
+            CliCommand::Worktree(crate::cli::worktree::WorktreeCommand::Notes {
+                branch: "feature/a".to_string(),
+                note: Some(String::new()),
+                tags: Some(Vec::new()),
+                sync: true,
+            })
+        );
+        assert!(matches!(
+            err("worktree.notes", json!({})),
+            CliParseError::MissingFlag("branch")
+        ));
+    }
+
     #[test]
     fn pane_operations_parse() {
         assert!(matches!(
@@ -0,0 +1,188 @@
+//! `gwt locks ...` family module.
+//!
+//! `gwt locks [--stale]` lists the `*.lock` files under `~/.gwt` with their
+//! state and recorded owner (see [`gwt_core::lock`]). `gwt locks release
+//! <path> [--force]` removes a stale lock; `--force` also removes one whose
+//! owner still looks alive, for a hung owner or one on another host.
+
+use std::path::{Path, PathBuf};
+
+use gwt_core::lock::{LockState, LockStatus};
+use gwt_github::SpecOpsError;
+
+use super::{CliEnv, CliParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocksCommand {
+    /// `gwt locks [--stale]` — `--stale` lists only stale locks.
+    List { stale_only: bool },
+    /// `gwt locks release <path> [--force]` — a relative path is taken
+    /// from `~/.gwt`.
+    Release { path: PathBuf, force: bool },
+}
+
+pub fn parse(args: &[String]) -> Result<LocksCommand, CliParseError> {
+    match args {
+        [] => Ok(LocksCommand::List { stale_only: false }),
+        [flag] if flag == "--stale" => Ok(LocksCommand::List { stale_only: true }),
+        [head, rest @ ..] if head == "release" => {
+            let mut path = None;
+            let mut force = false;
+            for arg in rest {
+                match arg.as_str() {
+                    "--force" => force = true,
+                    other if !other.starts_with('-') && path.is_none() => {
+                        path = Some(PathBuf::from(other));
+                    }
+                    other => return Err(CliParseError::UnknownSubcommand(other.to_string())),
+                }
+            }
+            Ok(LocksCommand::Release {
+                path: path.ok_or(CliParseError::Usage)?,
+                force,
+            })
+        }
+        [other, ..] => Err(CliParseError::UnknownSubcommand(other.to_string())),
+    }
+}
+
+pub fn run<E: CliEnv>(
+    _env: &mut E,
+    cmd: LocksCommand,
+    out: &mut String,
+) -> Result<i32, SpecOpsError> {
+    let root = gwt_core::paths::gwt_home();
+    match cmd {
+        LocksCommand::List { stale_only } => {
+            let locks = gwt_core::lock::scan(&root);
+            render_locks(&root, &locks, stale_only, out);
+            Ok(0)
+        }
+        LocksCommand::Release { path, force } => Ok(release(&root.join(path), force, out)),
+    }
+}
+
+fn render_locks(root: &Path, locks: &[LockStatus], stale_only: bool, out: &mut String) {
+    let shown: Vec<&LockStatus> = locks
+        .iter()
+        .filter(|lock| !stale_only || lock.state == LockState::Stale)
+        .collect();
+    if shown.is_empty() {
+        out.push_str(if stale_only {
+            "no stale locks\n"
+        } else {
+            "no locks\n"
+        });
+        return;
+    }
+    for lock in shown {
+        let path = lock.path.strip_prefix(root).unwrap_or(&lock.path);
+        out.push_str(&format!("{:<5}  {}", lock.state.as_str(), path.display()));
+        // A free lock's leftover record only names who last held it.
+        if let Some(owner) = lock
+            .owner
+            .as_ref()
+            .filter(|_| lock.state != LockState::Free)
+        {
+            out.push_str(&format!(
+                "  pid {} on {} since {} ({})",
+                owner.pid,
+                owner.hostname,
+                owner.acquired_at.format("%Y-%m-%d %H:%M:%S UTC"),
+                owner.purpose
+            ));
+        }
+        out.push('\n');
+    }
+}
+
+fn release(path: &Path, force: bool, out: &mut String) -> i32 {
+    let status = match gwt_core::lock::inspect(path) {
+        Ok(status) => status,
+        Err(error) => {
+            out.push_str(&format!("error: {}: {error}\n", path.display()));
+            return 1;
+        }
+    };
+    if status.state == LockState::Held && !force {
+        out.push_str(&format!(
+            "error: {} is held by a live process; pass --force to remove it anyway\n",
+            path.display()
+        ));
+        return 1;
+    }
+    match gwt_core::lock::force_release(path) {
+        Ok(()) => {
+            out.push_str(&format!(
+                "released {} ({})\n",
+                path.display(),
+                status.state.as_str()
+            ));
+            0
+        }
+        Err(error) => {
+            out.push_str(&format!("error: {}: {error}\n", path.display()));
+            1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_list_and_release_flags() {
+        assert_eq!(
+            parse(&[]).unwrap(),
+            LocksCommand::List { stale_only: false }
+        );
+        assert_eq!(
+            parse(&args(&["--stale"])).unwrap(),
+            LocksCommand::List { stale_only: true }
+        );
+        assert_eq!(
+            parse(&args(&["release", "jobs/slots/0.lock", "--force"])).unwrap(),
+            LocksCommand::Release {
+                path: PathBuf::from("jobs/slots/0.lock"),
+                force: true,
+            }
+        );
+        assert!(parse(&args(&["release"])).is_err());
+        assert!(parse(&args(&["release", "a.lock", "b.lock"])).is_err());
+        assert!(parse(&args(&["clear"])).is_err());
+    }
+
+    #[test]
+    fn release_refuses_a_held_lock_without_force() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("works.lock");
+        let held = gwt_core::lock::try_acquire(&path, "works")
+            .unwrap()
+            .unwrap();
+
+        let mut out = String::new();
+        render_locks(
+            temp.path(),
+            &gwt_core::lock::scan(temp.path()),
+            false,
+            &mut out,
+        );
+        assert!(out.starts_with("held   works.lock  pid "), "{out}");
+        assert!(out.ends_with("(works)\n"), "{out}");
+
+        let mut out = String::new();
+        assert_eq!(release(&path, false, &mut out), 1);
+        assert!(out.contains("--force"), "{out}");
+        assert!(path.exists());
+
+        let mut out = String::new();
+        assert_eq!(release(&path, true, &mut out), 0);
+        assert!(!path.exists());
+        drop(held);
+    }
+}
@@ -0,0 +1,189 @@
+//! `gwt logs ...` family module.
+//!
+//! Runs the startup log housekeeping ([`gwt_core::logging::purge`]) on
+//! demand for the current project's log directory, or previews it with
+//! `--dry-run`.
+
+use std::path::Path;
+
+use gwt_core::logging::{HousekeepReport, PurgeOptions};
+use gwt_github::SpecOpsError;
+
+use super::{CliEnv, CliParseError};
+
+/// Retention applied by `gwt logs purge`, matching the startup default in
+/// `LoggingConfig::new`.
+const PURGE_RETENTION_DAYS: u32 = 7;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogsCommand {
+    /// `gwt logs purge [--dry-run] [--max-total-mb <N>]` — delete expired
+    /// log files, compress older ones and enforce the size budget. The
+    /// budget defaults to `[log_rotation] max_total_size_mb`.
+    Purge {
+        dry_run: bool,
+        max_total_mb: Option<u64>,
+    },
+}
+
+pub fn parse(args: &[String]) -> Result<LogsCommand, CliParseError> {
+    let (head, rest) = args.split_first().ok_or(CliParseError::Usage)?;
+    if head != "purge" {
+        return Err(CliParseError::UnknownSubcommand(head.to_string()));
+    }
+    let mut dry_run = false;
+    let mut max_total_mb = None;
+    let mut iter = rest.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--dry-run" => dry_run = true,
+            "--max-total-mb" => {
+                let value = iter
+                    .next()
+                    .ok_or(CliParseError::MissingFlag("--max-total-mb"))?;
+                max_total_mb = Some(value.parse().map_err(|_| CliParseError::InvalidValue {
+                    flag: "--max-total-mb",
+                    reason: "expected a whole number of MiB",
+                })?);
+            }
+            other => return Err(CliParseError::UnknownSubcommand(other.to_string())),
+        }
+    }
+    Ok(LogsCommand::Purge {
+        dry_run,
+        max_total_mb,
+    })
+}
+
+pub fn run<E: CliEnv>(
+    env: &mut E,
+    cmd: LogsCommand,
+    out: &mut String,
+) -> Result<i32, SpecOpsError> {
+    let LogsCommand::Purge {
+        dry_run,
+        max_total_mb,
+    } = cmd;
+    let max_total_bytes = match max_total_mb {
+        Some(mb) => Some(mb.saturating_mul(1024 * 1024)),
+        None => gwt_config::Settings::load()
+            .ok()
+            .and_then(|settings| settings.log_rotation.max_total_bytes()),
+    };
+    let log_dir = gwt_core::paths::gwt_project_logs_dir_for_project_path(env.repo_path());
+    let options = PurgeOptions {
+        retention_days: PURGE_RETENTION_DAYS,
+        compress: true,
+        max_total_bytes,
+        dry_run,
+    };
+    let report = gwt_core::logging::purge(&log_dir, &options);
+    render_report(&log_dir, &options, &report, out);
+    Ok(if report.errors.is_empty() { 0 } else { 1 })
+}
+
+fn render_report(
+    log_dir: &Path,
+    options: &PurgeOptions,
+    report: &HousekeepReport,
+    out: &mut String,
+) {
+    let (delete, compress) = if options.dry_run {
+        ("Would delete", "Would compress")
+    } else {
+        ("Deleted", "Compressed")
+    };
+    out.push_str(&format!("Logs: {}\n", log_dir.display()));
+    out.push_str(&format!(
+        "{delete} {} file(s), {}\n",
+        report.deleted.len(),
+        format_mib(report.deleted_bytes)
+    ));
+    for path in &report.deleted {
+        out.push_str(&format!("  - {}\n", file_name(path)));
+    }
+    out.push_str(&format!(
+        "{compress} {} file(s), {} before compression\n",
+        report.compressed.len(),
+        format_mib(report.compressed_bytes)
+    ));
+    for path in &report.compressed {
+        out.push_str(&format!("  - {}\n", file_name(path)));
+    }
+    match options.max_total_bytes {
+        Some(bytes) => out.push_str(&format!("Size budget: {}\n", format_mib(bytes))),
+        None => out.push_str("Size budget: none ([log_rotation] max_total_size_mb)\n"),
+    }
+    for (path, error) in &report.errors {
+        out.push_str(&format!("error: {}: {error}\n", path.display()));
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+fn format_mib(bytes: u64) -> String {
+    format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_purge_flags() {
+        assert_eq!(
+            parse(&args(&["purge"])).unwrap(),
+            LogsCommand::Purge {
+                dry_run: false,
+                max_total_mb: None
+            }
+        );
+        assert_eq!(
+            parse(&args(&["purge", "--dry-run", "--max-total-mb", "50"])).unwrap(),
+            LogsCommand::Purge {
+                dry_run: true,
+                max_total_mb: Some(50)
+            }
+        );
+        assert!(parse(&args(&["purge", "--max-total-mb"])).is_err());
+        assert!(parse(&args(&["purge", "--max-total-mb", "lots"])).is_err());
+        assert!(parse(&args(&["tail"])).is_err());
+        assert!(parse(&[]).is_err());
+    }
+
+    #[test]
+    fn dry_run_summary_lists_files_and_sizes() {
+        let options = PurgeOptions {
+            retention_days: PURGE_RETENTION_DAYS,
+            compress: true,
+            max_total_bytes: None,
+            dry_run: true,
+        };
+        let report = HousekeepReport {
+            deleted: vec!["/logs/gwt.log.2026-03-01".into()],
+            deleted_bytes: 3 * 1024 * 1024,
+            compressed: vec!["/logs/gwt.log.2026-04-08".into()],
+            compressed_bytes: 512 * 1024,
+            ..HousekeepReport::default()
+        };
+        let mut out = String::new();
+
+        render_report(Path::new("/logs"), &options, &report, &mut out);
+
+        assert_eq!(
+            out,
+            "Logs: /logs\n\
+             Would delete 1 file(s), 3.0 MiB\n  - gwt.log.2026-03-01\n\
+             Would compress 1 file(s), 0.5 MiB before compression\n  - gwt.log.2026-04-08\n\
+             Size budget: none ([log_rotation] max_total_size_mb)\n"
+        );
+    }
+}
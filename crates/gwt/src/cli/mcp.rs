@@ -0,0 +1,459 @@
+//! `gwt mcp serve --stdio` family module.
+//!
+//! A Model Context Protocol server over stdin/stdout (newline-delimited
+//! JSON-RPC 2.0) so an agent running inside a worktree can ask gwt for
+//! sibling worktrees for sub-tasks. Tools:
+//!
+//! - `list_worktrees` — branches and paths of the repository's worktrees.
+//! - `create_worktree` — a worktree for `branch` where a launch would put
+//!   it, starting a new branch from `base` when given.
+//! - `remove_worktree` — removes the worktree of `branch`; the main and the
+//!   calling worktree are refused, and so is a branch matching a
+//!   `protected_branches` pattern unless `confirm` repeats its name.
+//! - `launch_agent` — opens the Launch Wizard for `branch` in the running
+//!   gwt (needs a gwt-launched pane, like `pane.*`).
+//! - `get_session_summary` — the Markdown transcript export of `branch`'s
+//!   agent sessions, as `gwt session export` prints it.
+
+use std::{
+    io::{self, BufRead, Write},
+    path::{Path, PathBuf},
+};
+
+use gwt_agent::session_export::ExportFormat;
+use gwt_git::WorktreeManager;
+use serde_json::{json, Value};
+
+use super::{CliCommand, CliEnv, CliParseError, SessionCommand};
+
+const PROTOCOL_VERSION: &str = "2024-11-05";
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum McpCommand {
+    /// `gwt mcp serve --stdio`.
+    Serve,
+}
+
+pub fn parse(args: &[String]) -> Result<McpCommand, CliParseError> {
+    let (head, rest) = args.split_first().ok_or(CliParseError::Usage)?;
+    if head != "serve" {
+        return Err(CliParseError::UnknownSubcommand(head.to_string()));
+    }
+    match rest {
+        [flag] if flag == "--stdio" => Ok(McpCommand::Serve),
+        [] => Err(CliParseError::MissingFlag("--stdio")),
+        [other, ..] => Err(CliParseError::UnknownSubcommand(other.to_string())),
+    }
+}
+
+/// Serve on the process's stdin/stdout until stdin closes.
+pub fn run_stdio<E: CliEnv>(env: &mut E) -> i32 {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    match serve(env, stdin.lock(), stdout.lock()) {
+        Ok(()) => 0,
+        Err(err) => {
+            eprintln!("gwt mcp: {err}");
+            1
+        }
+    }
+}
+
+pub fn serve<E: CliEnv, R: BufRead, W: Write>(
+    env: &mut E,
+    input: R,
+    mut output: W,
+) -> io::Result<()> {
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let reply = match serde_json::from_str::<Value>(&line) {
+            Ok(message) => handle_message(env, &message),
+            Err(err) => Some(error_reply(
+                Value::Null,
+                PARSE_ERROR,
+                format!("invalid JSON: {err}"),
+            )),
+        };
+        if let Some(reply) = reply {
+            writeln!(output, "{reply}")?;
+            output.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// The reply to one JSON-RPC message; `None` for notifications.
+fn handle_message<E: CliEnv>(env: &mut E, message: &Value) -> Option<Value> {
+    let id = message.get("id")?.clone();
+    let Some(method) = message.get("method").and_then(Value::as_str) else {
+        return Some(error_reply(id, INVALID_REQUEST, "missing method".into()));
+    };
+    let params = message.get("params").cloned().unwrap_or(Value::Null);
+    let result = match method {
+        "initialize" => Ok(json!({
+            "protocolVersion": PROTOCOL_VERSION,
+            "capabilities": { "tools": {} },
+            "serverInfo": { "name": "gwt", "version": env!("CARGO_PKG_VERSION") },
+        })),
+        "ping" => Ok(json!({})),
+        "tools/list" => Ok(json!({ "tools": tool_definitions() })),
+        "tools/call" => call_tool(env, &params),
+        other => Err((METHOD_NOT_FOUND, format!("unknown method {other}"))),
+    };
+    Some(match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err((code, message)) => error_reply(id, code, message),
+    })
+}
+
+fn error_reply(id: Value, code: i64, message: String) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
+
+fn tool_definitions() -> Value {
+    let branch = json!({ "type": "string", "description": "Branch name" });
+    json!([
+        {
+            "name": "list_worktrees",
+            "description": "List the worktrees of this repository with their branches and paths.",
+            "inputSchema": { "type": "object", "properties": {} },
+        },
+        {
+            "name": "create_worktree",
+            "description": "Create a sibling worktree for a branch. With `base`, a new branch is started from it; otherwise the existing branch is checked out.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "branch": branch,
+                    "base": { "type": "string", "description": "Branch to start the new branch from" },
+                },
+                "required": ["branch"],
+            },
+        },
+        {
+            "name": "remove_worktree",
+            "description": "Remove the worktree that has a branch checked out. The branch itself is kept.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "branch": branch,
+                    "force": { "type": "boolean", "description": "Also remove a worktree with uncommitted changes" },
+                    "confirm": { "type": "string", "description": "The branch name again, to remove the worktree of a protected branch" },
+                },
+                "required": ["branch"],
+            },
+        },
+        {
+            "name": "launch_agent",
+            "description": "Open the gwt Launch Wizard for a branch so an agent can be started in its worktree.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "branch": branch,
+                    "issue": { "type": "integer", "description": "Issue number to link the launch to" },
+                },
+                "required": ["branch"],
+            },
+        },
+        {
+            "name": "get_session_summary",
+            "description": "Markdown export of the agent sessions that ran on a branch.",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "branch": branch },
+                "required": ["branch"],
+            },
+        },
+    ])
+}
+
+fn call_tool<E: CliEnv>(env: &mut E, params: &Value) -> Result<Value, (i64, String)> {
+    let name = params
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or((INVALID_PARAMS, "tools/call needs a tool name".to_string()))?;
+    let args = params.get("arguments").cloned().unwrap_or(Value::Null);
+    let outcome = match name {
+        "list_worktrees" => list_worktrees(env.repo_path()),
+        "create_worktree" => create_worktree(env.repo_path(), &args),
+        "remove_worktree" => remove_worktree(env.repo_path(), &args),
+        "launch_agent" => required_str(&args, "branch").and_then(|branch| {
+            let issue = args.get("issue").and_then(Value::as_u64);
+            super::pane::open_launch_wizard(branch, issue)
+        }),
+        "get_session_summary" => session_summary(env, &args),
+        other => return Err((INVALID_PARAMS, format!("unknown tool {other}"))),
+    };
+    let (text, is_error) = match outcome {
+        Ok(text) => (text, false),
+        Err(err) => (err, true),
+    };
+    Ok(json!({
+        "content": [{ "type": "text", "text": text }],
+        "isError": is_error,
+    }))
+}
+
+fn required_str<'a>(args: &'a Value, key: &str) -> Result<&'a str, String> {
+    args.get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| format!("`{key}` is required"))
+}
+
+fn main_repo_path(repo_path: &Path) -> PathBuf {
+    gwt_git::worktree::main_worktree_root(repo_path).unwrap_or_else(|_| repo_path.to_path_buf())
+}
+
+fn list_worktrees(repo_path: &Path) -> Result<String, String> {
+    let worktrees = WorktreeManager::new(main_repo_path(repo_path))
+        .list()
+        .map_err(|err| err.to_string())?;
+    let rows = worktrees
+        .iter()
+        .map(|worktree| {
+            json!({
+                "branch": worktree.branch,
+                "path": worktree.path.display().to_string(),
+                "locked": worktree.locked,
+                "prunable": worktree.prunable,
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&rows).map_err(|err| err.to_string())
+}
+
+fn create_worktree(repo_path: &Path, args: &Value) -> Result<String, String> {
+    let branch = required_str(args, "branch")?;
+    let base = args
+        .get("base")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|base| !base.is_empty());
+    let main_repo_path = main_repo_path(repo_path);
+    let max_path_len = gwt_config::Settings::load()
+        .ok()
+        .and_then(|settings| settings.worktree_max_path_len);
+    let path = gwt_git::worktree::sibling_worktree_path_with_max_len(
+        &main_repo_path,
+        branch,
+        max_path_len,
+    );
+    let manager = WorktreeManager::new(&main_repo_path);
+    match base {
+        Some(base) => manager.create_from_base(base, branch, &path),
+        None => manager.create(branch, &path),
+    }
+    .map_err(|err| err.to_string())?;
+    Ok(json!({ "branch": branch, "path": path.display().to_string() }).to_string())
+}
+
+fn remove_worktree(repo_path: &Path, args: &Value) -> Result<String, String> {
+    let branch = required_str(args, "branch")?;
+    let force = args.get("force").and_then(Value::as_bool).unwrap_or(false);
+    let main_repo_path = main_repo_path(repo_path);
+    let manager = WorktreeManager::new(&main_repo_path);
+    let worktrees = manager.list().map_err(|err| err.to_string())?;
+    let worktree = worktrees
+        .iter()
+        .find(|worktree| worktree.branch.as_deref() == Some(branch))
+        .ok_or_else(|| format!("no worktree has {branch} checked out"))?;
+    if same_path(&worktree.path, &main_repo_path) {
+        return Err(format!("{branch} is checked out in the main worktree"));
+    }
+    if same_path(&worktree.path, repo_path) {
+        return Err(format!("{branch} is the calling worktree"));
+    }
+    let confirmed = args
+        .get("confirm")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    crate::branch_protection::protection_policy()
+        .with_confirmed(confirmed)
+        .check(gwt_git::DestructiveOp::RemoveWorktree, branch)
+        .map_err(|err| err.to_string())?;
+    if force {
+        manager.remove_force(&worktree.path)
+    } else {
+        manager.remove(&worktree.path)
+    }
+    .map_err(|err| err.to_string())?;
+    Ok(format!("removed {}", worktree.path.display()))
+}
+
+fn same_path(left: &Path, right: &Path) -> bool {
+    let canonical = |path: &Path| std::fs::canonicalize(path).unwrap_or_else(|_| path.into());
+    canonical(left) == canonical(right)
+}
+
+fn session_summary<E: CliEnv>(env: &mut E, args: &Value) -> Result<String, String> {
+    let branch = required_str(args, "branch")?.to_string();
+    let command = CliCommand::Session(SessionCommand::Export {
+        branch,
+        format: ExportFormat::Markdown,
+        output: None,
+    });
+    match super::run_collect(env, command) {
+        Ok((0, out)) => Ok(out),
+        Ok((_, out)) => Err(out.trim().to_string()),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::hidden_command("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .expect("git");
+        assert!(
+            output.status.success(),
+            "git {args:?} failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    fn exchange(env: &mut crate::cli::TestEnv, messages: &[Value]) -> Vec<Value> {
+        let input = messages
+            .iter()
+            .map(Value::to_string)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut output = Vec::new();
+        serve(env, input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    fn call(id: u64, name: &str, arguments: Value) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": "tools/call",
+            "params": { "name": name, "arguments": arguments },
+        })
+    }
+
+    #[test]
+    fn parse_requires_the_stdio_transport() {
+        assert_eq!(
+            parse(&args(&["serve", "--stdio"])).unwrap(),
+            McpCommand::Serve
+        );
+        assert_eq!(
+            parse(&args(&["serve"])),
+            Err(CliParseError::MissingFlag("--stdio"))
+        );
+        assert!(parse(&args(&["serve", "--tcp"])).is_err());
+        assert!(parse(&args(&["start"])).is_err());
+        assert!(parse(&[]).is_err());
+    }
+
+    #[test]
+    fn serve_answers_handshake_and_rejects_unknown_methods() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut env = crate::cli::TestEnv::new(tmp.path().to_path_buf());
+        let replies = exchange(
+            &mut env,
+            &[
+                json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }),
+                json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }),
+                json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" }),
+                json!({ "jsonrpc": "2.0", "id": 3, "method": "resources/list" }),
+                call(4, "rm_rf", json!({})),
+            ],
+        );
+
+        assert_eq!(replies.len(), 4);
+        assert_eq!(replies[0]["result"]["serverInfo"]["name"], "gwt");
+        let tools = replies[1]["result"]["tools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|tool| tool["name"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tools,
+            [
+                "list_worktrees",
+                "create_worktree",
+                "remove_worktree",
+                "launch_agent",
+                "get_session_summary"
+            ]
+        );
+        assert_eq!(replies[2]["error"]["code"], METHOD_NOT_FOUND);
+        assert_eq!(replies[3]["error"]["code"], INVALID_PARAMS);
+    }
+
+    #[test]
+    fn worktree_tools_create_list_and_remove_sibling_worktrees() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "-b", "main", "."]);
+        git(&repo, &["config", "user.email", "test@example.com"]);
+        git(&repo, &["config", "user.name", "Test"]);
+        std::fs::write(repo.join("README.md"), "# sample\n").unwrap();
+        git(&repo, &["add", "README.md"]);
+        git(&repo, &["commit", "-m", "init"]);
+        let mut env = crate::cli::TestEnv::new(tmp.path().to_path_buf());
+        env.repo_path = repo.clone();
+
+        let replies = exchange(
+            &mut env,
+            &[
+                call(
+                    1,
+                    "create_worktree",
+                    json!({ "branch": "sub/a", "base": "main" }),
+                ),
+                call(2, "list_worktrees", json!({})),
+                call(3, "remove_worktree", json!({ "branch": "main" })),
+                call(4, "remove_worktree", json!({ "branch": "sub/a" })),
+                call(5, "create_worktree", json!({})),
+            ],
+        );
+
+        let text = |index: usize| replies[index]["result"]["content"][0]["text"].clone();
+        let is_error = |index: usize| replies[index]["result"]["isError"].as_bool().unwrap();
+        assert!(!is_error(0), "{}", text(0));
+        let created: Value = serde_json::from_str(text(0).as_str().unwrap()).unwrap();
+        let path = PathBuf::from(created["path"].as_str().unwrap());
+        assert!(path.ends_with("sub/a"), "{}", path.display());
+        let listed: Value = serde_json::from_str(text(1).as_str().unwrap()).unwrap();
+        assert!(listed
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|row| row["branch"] == "sub/a"));
+        assert!(is_error(2));
+        assert!(!is_error(3), "{}", text(3));
+        assert!(!path.exists());
+        assert!(is_error(4));
+    }
+}
@@ -0,0 +1,139 @@
+//! `gwt migrate ...` family module.
+//!
+//! `gwt migrate to-normal [--branch <name>] [--project <dir>]
+//! [--keep-backup]` converts a Nested Bare+Worktree project back into a
+//! Normal repository with [`crate::migration::execute_reverse_migration`].
+//! The project defaults to the current directory.
+
+use std::path::PathBuf;
+
+use gwt_core::migration::{RecoveryState, ReverseMigrationOptions};
+use gwt_github::SpecOpsError;
+
+use super::{CliEnv, CliParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrateCommand {
+    /// `gwt migrate to-normal` — `--branch` picks the branch checked out in
+    /// the result (default: the bare repository's HEAD).
+    ToNormal {
+        project: Option<PathBuf>,
+        branch: Option<String>,
+        keep_backup: bool,
+    },
+}
+
+pub fn parse(args: &[String]) -> Result<MigrateCommand, CliParseError> {
+    let (head, rest) = args.split_first().ok_or(CliParseError::Usage)?;
+    if head != "to-normal" {
+        return Err(CliParseError::UnknownSubcommand(head.to_string()));
+    }
+    let mut project = None;
+    let mut branch = None;
+    let mut keep_backup = false;
+    let mut iter = rest.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--project" => {
+                let value = iter.next().ok_or(CliParseError::MissingFlag("--project"))?;
+                project = Some(PathBuf::from(value));
+            }
+            "--branch" => {
+                let value = iter.next().ok_or(CliParseError::MissingFlag("--branch"))?;
+                branch = Some(value.clone());
+            }
+            "--keep-backup" => keep_backup = true,
+            other => return Err(CliParseError::UnknownSubcommand(other.to_string())),
+        }
+    }
+    Ok(MigrateCommand::ToNormal {
+        project,
+        branch,
+        keep_backup,
+    })
+}
+
+pub fn run<E: CliEnv>(
+    _env: &mut E,
+    cmd: MigrateCommand,
+    out: &mut String,
+) -> Result<i32, SpecOpsError> {
+    let MigrateCommand::ToNormal {
+        project,
+        branch,
+        keep_backup,
+    } = cmd;
+    let Some(project) = project.or_else(|| std::env::current_dir().ok()) else {
+        out.push_str("error: could not determine the project directory\n");
+        return Ok(1);
+    };
+    let options = ReverseMigrationOptions {
+        primary_branch: branch,
+        keep_backup_on_success: keep_backup,
+    };
+    match crate::migration::execute_reverse_migration(&project, options, |_, _| {}) {
+        Ok(outcome) => {
+            out.push_str(&format!(
+                "{} is now a normal repository on {}\n",
+                outcome.repo_root.display(),
+                outcome.primary_branch
+            ));
+            for removed in &outcome.removed_worktrees {
+                out.push_str(&format!("removed worktree {}\n", removed.display()));
+            }
+            Ok(0)
+        }
+        Err(error) => {
+            out.push_str(&format!("error: {error}\n"));
+            if error.recovery == RecoveryState::Partial {
+                out.push_str(&format!(
+                    "the backup is kept in {}\n",
+                    project
+                        .join(gwt_core::migration::backup::BACKUP_DIR_NAME)
+                        .display()
+                ));
+            }
+            Ok(1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_to_normal_flags() {
+        assert_eq!(
+            parse(&args(&["to-normal"])).unwrap(),
+            MigrateCommand::ToNormal {
+                project: None,
+                branch: None,
+                keep_backup: false,
+            }
+        );
+        assert_eq!(
+            parse(&args(&[
+                "to-normal",
+                "--branch",
+                "main",
+                "--project",
+                "/work/repo",
+                "--keep-backup"
+            ]))
+            .unwrap(),
+            MigrateCommand::ToNormal {
+                project: Some(PathBuf::from("/work/repo")),
+                branch: Some("main".to_string()),
+                keep_backup: true,
+            }
+        );
+        assert!(parse(&args(&["to-normal", "--branch"])).is_err());
+        assert!(parse(&args(&["to-bare"])).is_err());
+        assert!(parse(&[]).is_err());
+    }
+}
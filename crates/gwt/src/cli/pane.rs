@@ -230,6 +230,35 @@ async fn close_pane(
     }
 }
 
+/// Ask the running gwt to open the Launch Wizard for `branch`, optionally
+/// linked to an Issue. Used by the MCP `launch_agent` tool; the user
+/// confirms the agent and its options in the wizard.
+pub(super) fn open_launch_wizard(
+    branch: &str,
+    linked_issue_number: Option<u64>,
+) -> Result<String, String> {
+    let ws_url = pane_websocket_url_from_env()?;
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|err| format!("failed to create pane runtime: {err}"))?;
+    runtime.block_on(async {
+        let (mut socket, _) = connect_async(ws_url.as_str())
+            .await
+            .map_err(|err| format!("pane websocket connect failed ({ws_url}): {err}"))?;
+        send_frontend_event(
+            &mut socket,
+            json!({
+                "kind": "open_active_work_launch_wizard",
+                "branch_name": branch,
+                "linked_issue_number": linked_issue_number,
+            }),
+        )
+        .await?;
+        Ok(format!("opened the launch wizard for {branch}\n"))
+    })
+}
+
 async fn send_frontend_event(
     socket: &mut tokio_tungstenite::WebSocketStream<
         tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
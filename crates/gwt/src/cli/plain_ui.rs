@@ -0,0 +1,592 @@
+//! `gwt --plain-ui`: linear, screen-reader friendly front end.
+//!
+//! The main flows of the Branches window — list branches, create a
+//! worktree, launch an agent, carry a session over to another agent, trace
+//! those conversions, clean up branches — as numbered menus and
+//! plain text lines. Nothing is redrawn in place and nothing relies on
+//! colour or box drawing, so every line a screen reader announces stays
+//! meaningful on its own. The flows call the same backend the GUI uses
+//! ([`crate::branch_list`], [`crate::branch_cleanup`],
+//! [`crate::session_conversion`], [`gwt_agent::prepare_agent_launch`]).
+//!
+//! Every prompt accepts `q` (or end of input) to go back.
+
+use std::{
+    collections::{HashMap, HashSet},
+    io::{self, BufRead, Write},
+    path::Path,
+};
+
+use gwt_agent::{AgentDetector, AgentLaunchBuilder, PreparedAgentLaunch};
+
+use crate::{
+    branch_cleanup::{cleanup_selected_branches, BranchCleanupResultStatus},
+    session_conversion::{
+        conversion_format, conversion_lineage, convert_agent_session, record_provenance,
+        render_lineage,
+    },
+    BranchCleanupAvailability, BranchListEntry, BranchScope,
+};
+
+const MAIN_MENU: &[&str] = &[
+    "List branches",
+    "Create worktree",
+    "Launch agent",
+    "Convert session to another agent",
+    "Show session conversions",
+    "Clean up branches",
+    "Quit",
+];
+
+/// Sessions of each agent offered by "Convert session to another agent".
+const CONVERTIBLE_SESSIONS_PER_AGENT: usize = 5;
+
+/// Run the plain UI for the repository at `repo_path` until the user quits.
+pub fn run(repo_path: &Path, input: &mut dyn BufRead, output: &mut dyn Write) -> io::Result<i32> {
+    let mut ui = Prompter { input, output };
+    ui.line(&format!(
+        "gwt plain mode. Repository: {}",
+        repo_path.display()
+    ))?;
+    loop {
+        ui.line("")?;
+        let Some(choice) = ui.choose("Main menu", MAIN_MENU)? else {
+            break;
+        };
+        let result = match choice {
+            0 => list_branches(&mut ui, repo_path),
+            1 => create_worktree(&mut ui, repo_path),
+            2 => launch_agent(&mut ui, repo_path),
+            3 => convert_session(&mut ui, repo_path),
+            4 => show_conversion_lineage(&mut ui, repo_path),
+            5 => cleanup_branches(&mut ui, repo_path),
+            _ => break,
+        };
+        if let Err(error) = result {
+            ui.line(&format!("Error: {error}"))?;
+        }
+    }
+    ui.line("Goodbye.")?;
+    Ok(0)
+}
+
+fn list_branches(ui: &mut Prompter<'_>, repo_path: &Path) -> io::Result<()> {
+    let entries = crate::branch_list::list_branch_entries(repo_path)?;
+    ui.line(&format!("{} branches:", entries.len()))?;
+    for (index, entry) in entries.iter().enumerate() {
+        ui.line(&format!("{}. {}", index + 1, describe_branch(entry)))?;
+    }
+    Ok(())
+}
+
+fn create_worktree(ui: &mut Prompter<'_>, repo_path: &Path) -> io::Result<()> {
+    let Some(branch) = ui.ask("New or existing branch name")? else {
+        return Ok(());
+    };
+    let Some(base) = ui.ask_optional("Base branch, empty for develop")? else {
+        return Ok(());
+    };
+    ui.line(&format!("Creating worktree for {branch}..."))?;
+    let mut activity = gwt_core::activity_bus::subscribe();
+    let mut working_dir = None;
+    gwt_agent::resolve_launch_worktree_request(
+        repo_path,
+        Some(&branch),
+        base.as_deref(),
+        &mut working_dir,
+        &mut HashMap::new(),
+    )
+    .map_err(io::Error::other)?;
+    let Some(path) = working_dir else {
+        return ui.line("No worktree was created: this folder is not a Git repository.");
+    };
+    ui.line(&format!("Worktree ready: {}", path.display()))?;
+    // `[worktree_files] copy_on_create` results ride on the created event.
+    while let Ok(envelope) = activity.try_recv() {
+        if let gwt_core::activity_bus::ActivityEvent::WorktreeCreated { copied_files, .. } =
+            envelope.event
+        {
+            if !copied_files.is_empty() {
+                ui.line(&format!("Copied: {}", copied_files.join(", ")))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn launch_agent(ui: &mut Prompter<'_>, repo_path: &Path) -> io::Result<()> {
+    let Some(branch) = choose_local_branch(ui, repo_path)? else {
+        return Ok(());
+    };
+    let agents = AgentDetector::detect_all();
+    if agents.is_empty() {
+        return ui.line("No coding agent is installed.");
+    }
+    let labels: Vec<String> = agents
+        .iter()
+        .map(|agent| match &agent.version {
+            Some(version) => format!("{} {version}", agent.agent_id.display_name()),
+            None => agent.agent_id.display_name().to_string(),
+        })
+        .collect();
+    let Some(agent) = ui.choose("Agent", labels.as_slice())? else {
+        return Ok(());
+    };
+
+    let config = AgentLaunchBuilder::new(agents[agent].agent_id.clone())
+        .branch(branch)
+        .build();
+    let prepared = gwt_agent::prepare_agent_launch(
+        repo_path,
+        &gwt_core::paths::gwt_sessions_dir(),
+        config,
+        None,
+        |_| Ok(()),
+    )
+    .map_err(io::Error::other)?;
+    run_agent(ui, &labels[agent], prepared)
+}
+
+/// List the conversion chains of a branch's sessions, with what each
+/// conversion dropped.
+fn show_conversion_lineage(ui: &mut Prompter<'_>, repo_path: &Path) -> io::Result<()> {
+    let Some(branch) = choose_local_branch(ui, repo_path)? else {
+        return Ok(());
+    };
+    let sessions = crate::launch_wizard::load_sessions(&gwt_core::paths::gwt_sessions_dir());
+    let lines = render_lineage(&conversion_lineage(&sessions, &branch));
+    if lines.is_empty() {
+        return ui.line(&format!("No session on {branch} was converted."));
+    }
+    for line in lines {
+        ui.line(&line)?;
+    }
+    Ok(())
+}
+
+/// Convert a Claude Code or Codex session of a branch to another agent's
+/// format and start that agent on it.
+fn convert_session(ui: &mut Prompter<'_>, repo_path: &Path) -> io::Result<()> {
+    let Some(branch) = choose_local_branch(ui, repo_path)? else {
+        return Ok(());
+    };
+    let sessions_dir = gwt_core::paths::gwt_sessions_dir();
+    let sessions = crate::launch_wizard::load_sessions(&sessions_dir);
+    let recent: Vec<_> = crate::launch_wizard::load_recent_sessions(
+        repo_path,
+        &branch,
+        &sessions,
+        CONVERTIBLE_SESSIONS_PER_AGENT,
+    )
+    .into_iter()
+    .filter(|session| session.turns > 0)
+    .collect();
+    if recent.is_empty() {
+        return ui.line(&format!(
+            "No Claude Code or Codex session on {branch} has a readable transcript."
+        ));
+    }
+    let labels: Vec<String> = recent
+        .iter()
+        .map(|session| {
+            format!(
+                "{}, {}, {} turns: {}",
+                session.agent,
+                session
+                    .started_at
+                    .with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M"),
+                session.turns,
+                session.preview.as_deref().unwrap_or("no preview")
+            )
+        })
+        .collect();
+    let Some(picked) = ui.choose("Session to convert", labels.as_slice())? else {
+        return Ok(());
+    };
+    let source = &recent[picked];
+    let Some(source_agent) = gwt_agent::builtin_agent_descriptor_for_command(&source.agent_id)
+        .map(|descriptor| descriptor.id.clone())
+    else {
+        return ui.line(&format!("{} sessions cannot be converted.", source.agent));
+    };
+
+    let targets: Vec<_> = AgentDetector::detect_all()
+        .into_iter()
+        .filter(|agent| {
+            agent.agent_id != source_agent && conversion_format(&agent.agent_id).is_some()
+        })
+        .collect();
+    if targets.is_empty() {
+        return ui.line("No other agent that can take over a converted session is installed.");
+    }
+    let target_labels: Vec<String> = targets
+        .iter()
+        .map(|agent| agent.agent_id.display_name().to_string())
+        .collect();
+    let Some(target) = ui.choose("Continue with", target_labels.as_slice())? else {
+        return Ok(());
+    };
+    let target_agent = targets[target].agent_id.clone();
+
+    let converted = convert_agent_session(
+        &sessions_dir,
+        &source_agent,
+        &source.agent_session_id,
+        &target_agent,
+    )
+    .map_err(io::Error::other)?;
+    ui.line(&format!(
+        "Converted to {}: {}",
+        converted.history_path.display(),
+        converted.loss.summary()
+    ))?;
+    if !converted.loss.files_touched.is_empty() {
+        ui.line(&format!(
+            "Files the session changed: {}",
+            converted.loss.files_touched.join(", ")
+        ))?;
+    }
+    if !ui.confirm(&format!(
+        "Start {} seeded with this conversation?",
+        target_labels[target]
+    ))? {
+        let _ = std::fs::remove_file(&converted.history_path);
+        return ui.line("Cancelled.");
+    }
+
+    let mut builder = AgentLaunchBuilder::new(target_agent).branch(branch);
+    for arg in converted.seed_args() {
+        builder = builder.extra_arg(arg);
+    }
+    let prepared = gwt_agent::prepare_agent_launch(
+        repo_path,
+        &sessions_dir,
+        builder.build(),
+        None,
+        |_| Ok(()),
+    )
+    .map_err(io::Error::other)?;
+    record_provenance(&sessions_dir, &prepared.session.id, &converted)?;
+    run_agent(ui, &target_labels[target], prepared)
+}
+
+fn choose_local_branch(ui: &mut Prompter<'_>, repo_path: &Path) -> io::Result<Option<String>> {
+    let mut branches: Vec<String> = crate::branch_list::list_branch_inventory(repo_path)?
+        .into_iter()
+        .filter(|entry| entry.scope == BranchScope::Local)
+        .map(|entry| entry.name)
+        .collect();
+    Ok(ui
+        .choose("Branch to work on", branches.as_slice())?
+        .map(|index| branches.swap_remove(index)))
+}
+
+/// Run a prepared agent in this terminal until it exits.
+fn run_agent(ui: &mut Prompter<'_>, label: &str, prepared: PreparedAgentLaunch) -> io::Result<()> {
+    ui.line(&format!(
+        "Starting {label} in {}. The agent takes over this terminal until it exits.",
+        prepared.worktree_path.display()
+    ))?;
+    let launch = prepared.process_launch;
+    let mut command = std::process::Command::new(&launch.command);
+    command.args(&launch.args).envs(&launch.env);
+    for key in &launch.remove_env {
+        command.env_remove(key);
+    }
+    if let Some(cwd) = &launch.cwd {
+        command.current_dir(cwd);
+    }
+    let status = command.status()?;
+    match status.code() {
+        Some(code) => ui.line(&format!("Agent exited with code {code}.")),
+        None => ui.line("Agent was terminated by a signal."),
+    }
+}
+
+fn cleanup_branches(ui: &mut Prompter<'_>, repo_path: &Path) -> io::Result<()> {
+    ui.line("Checking which branches can be cleaned up...")?;
+    let entries = crate::branch_list::list_branch_entries(repo_path)?;
+    let candidates: Vec<&BranchListEntry> = entries
+        .iter()
+        .filter(|entry| {
+            entry.scope == BranchScope::Local
+                && entry.cleanup.availability != BranchCleanupAvailability::Blocked
+        })
+        .collect();
+    if candidates.is_empty() {
+        return ui.line("No branch can be cleaned up.");
+    }
+    let labels: Vec<String> = candidates
+        .iter()
+        .map(|entry| describe_branch(entry))
+        .collect();
+    let Some(selected) = ui.choose_many("Branches to clean up", labels.as_slice())? else {
+        return Ok(());
+    };
+    let names: Vec<String> = selected
+        .iter()
+        .map(|index| candidates[*index].name.clone())
+        .collect();
+    if !ui.confirm(&format!(
+        "Remove the worktrees and local branches of {}?",
+        names.join(", ")
+    ))? {
+        return ui.line("Cancelled.");
+    }
+    for result in cleanup_selected_branches(repo_path, &entries, &names, false) {
+        let status = match result.status {
+            BranchCleanupResultStatus::Success => "done",
+            BranchCleanupResultStatus::Partial => "partly done",
+            BranchCleanupResultStatus::Failed => "failed",
+        };
+        let message = if result.message.is_empty() {
+            String::new()
+        } else {
+            format!(": {}", result.message)
+        };
+        ui.line(&format!("{}: {status}{message}", result.branch))?;
+    }
+    Ok(())
+}
+
+/// One branch as a sentence, e.g. `feature/a, local, current, 2 ahead,
+/// worktree /repo/feature-a, safe to clean up`.
+fn describe_branch(entry: &BranchListEntry) -> String {
+    let mut parts = vec![
+        entry.name.clone(),
+        match entry.scope {
+            BranchScope::Local => "local".to_string(),
+            BranchScope::Remote => "remote".to_string(),
+        },
+    ];
+    if entry.is_head {
+        parts.push("current".to_string());
+    }
+    if entry.ahead > 0 {
+        parts.push(format!("{} ahead", entry.ahead));
+    }
+    if entry.behind > 0 {
+        parts.push(format!("{} behind", entry.behind));
+    }
+    if let Some(path) = &entry.worktree_path {
+        parts.push(format!("worktree {path}"));
+    }
+    if let Some(spec) = entry.linked_spec {
+        parts.push(format!("SPEC-{spec}"));
+    }
+    if entry.archived {
+        parts.push("archived".to_string());
+    }
+    if !entry.integrity_issues.is_empty() {
+        parts.push(format!("integrity: {}", entry.integrity_issues.join(", ")));
+    }
+    if entry.scope == BranchScope::Local {
+        parts.push(
+            match entry.cleanup.availability {
+                BranchCleanupAvailability::Safe => "safe to clean up",
+                BranchCleanupAvailability::Risky => "cleanup has risks",
+                BranchCleanupAvailability::Blocked => "cannot be cleaned up",
+            }
+            .to_string(),
+        );
+    }
+    parts.join(", ")
+}
+
+/// Numbered prompts over a line reader / writer.
+struct Prompter<'a> {
+    input: &'a mut dyn BufRead,
+    output: &'a mut dyn Write,
+}
+
+impl Prompter<'_> {
+    fn line(&mut self, text: &str) -> io::Result<()> {
+        writeln!(self.output, "{text}")?;
+        self.output.flush()
+    }
+
+    /// Next trimmed input line, or `None` at end of input and on `q`.
+    fn read(&mut self, prompt: &str) -> io::Result<Option<String>> {
+        write!(self.output, "{prompt}: ")?;
+        self.output.flush()?;
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            writeln!(self.output)?;
+            return Ok(None);
+        }
+        let line = line.trim().to_string();
+        Ok((!line.eq_ignore_ascii_case("q")).then_some(line))
+    }
+
+    fn ask(&mut self, prompt: &str) -> io::Result<Option<String>> {
+        loop {
+            match self.read(prompt)? {
+                Some(answer) if answer.is_empty() => self.line("A value is required.")?,
+                answer => return Ok(answer),
+            }
+        }
+    }
+
+    /// `Some(None)` for an empty answer, `None` to go back.
+    fn ask_optional(&mut self, prompt: &str) -> io::Result<Option<Option<String>>> {
+        Ok(self
+            .read(prompt)?
+            .map(|answer| Some(answer).filter(|answer| !answer.is_empty())))
+    }
+
+    fn list<S: AsRef<str>>(&mut self, title: &str, options: &[S]) -> io::Result<()> {
+        self.line(&format!("{title}, {} options:", options.len()))?;
+        for (index, option) in options.iter().enumerate() {
+            self.line(&format!("{}. {}", index + 1, option.as_ref()))?;
+        }
+        Ok(())
+    }
+
+    /// Index of one option, asked again until the answer is valid.
+    fn choose<S: AsRef<str>>(&mut self, title: &str, options: &[S]) -> io::Result<Option<usize>> {
+        if options.is_empty() {
+            self.line(&format!("{title}: nothing to choose from."))?;
+            return Ok(None);
+        }
+        self.list(title, options)?;
+        loop {
+            let Some(answer) = self.read(&format!("Enter a number from 1 to {}", options.len()))?
+            else {
+                return Ok(None);
+            };
+            match parse_choice(&answer, options.len()) {
+                Some(index) => return Ok(Some(index)),
+                None => self.line(&format!("{answer:?} is not an option."))?,
+            }
+        }
+    }
+
+    /// Indexes of several options (`1 3`, `1,3` or `all`).
+    fn choose_many<S: AsRef<str>>(
+        &mut self,
+        title: &str,
+        options: &[S],
+    ) -> io::Result<Option<Vec<usize>>> {
+        self.list(title, options)?;
+        loop {
+            let Some(answer) = self.read("Enter numbers separated by spaces, or all")? else {
+                return Ok(None);
+            };
+            match parse_choices(&answer, options.len()) {
+                Some(indexes) => return Ok(Some(indexes)),
+                None => self.line(&format!("{answer:?} is not a list of options."))?,
+            }
+        }
+    }
+
+    fn confirm(&mut self, question: &str) -> io::Result<bool> {
+        loop {
+            let Some(answer) = self.read(&format!("{question} Enter y or n"))? else {
+                return Ok(false);
+            };
+            match answer.to_ascii_lowercase().as_str() {
+                "y" | "yes" => return Ok(true),
+                "n" | "no" => return Ok(false),
+                _ => self.line("Please enter y or n.")?,
+            }
+        }
+    }
+}
+
+fn parse_choice(answer: &str, count: usize) -> Option<usize> {
+    let number: usize = answer.parse().ok()?;
+    (1..=count).contains(&number).then(|| number - 1)
+}
+
+fn parse_choices(answer: &str, count: usize) -> Option<Vec<usize>> {
+    if answer.eq_ignore_ascii_case("all") {
+        return Some((0..count).collect());
+    }
+    let mut seen = HashSet::new();
+    let indexes: Vec<usize> = answer
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .map(|part| parse_choice(part, count))
+        .collect::<Option<_>>()?;
+    let indexes: Vec<usize> = indexes
+        .into_iter()
+        .filter(|index| seen.insert(*index))
+        .collect();
+    (!indexes.is_empty()).then_some(indexes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prompter_run<T>(
+        input: &str,
+        body: impl FnOnce(&mut Prompter<'_>) -> io::Result<T>,
+    ) -> (T, String) {
+        let mut reader = io::Cursor::new(input.as_bytes().to_vec());
+        let mut output = Vec::new();
+        let value = body(&mut Prompter {
+            input: &mut reader,
+            output: &mut output,
+        })
+        .unwrap();
+        (value, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn choose_lists_numbered_options_and_reprompts_on_invalid_input() {
+        let (choice, output) = prompter_run("7\nx\n2\n", |ui| {
+            ui.choose("Agent", &["Claude Code", "Codex"])
+        });
+        assert_eq!(choice, Some(1));
+        assert!(output.starts_with("Agent, 2 options:\n1. Claude Code\n2. Codex\n"));
+        assert!(output.contains("\"7\" is not an option."));
+        assert!(output.contains("\"x\" is not an option."));
+
+        let (choice, _) = prompter_run("q\n", |ui| ui.choose("Agent", &["Codex"]));
+        assert_eq!(choice, None);
+        let (choice, _) = prompter_run("", |ui| ui.choose("Agent", &["Codex"]));
+        assert_eq!(choice, None);
+    }
+
+    #[test]
+    fn choose_many_accepts_lists_and_all() {
+        assert_eq!(parse_choices("1 3", 3), Some(vec![0, 2]));
+        assert_eq!(parse_choices("3,1, 3", 3), Some(vec![2, 0]));
+        assert_eq!(parse_choices("all", 2), Some(vec![0, 1]));
+        assert_eq!(parse_choices("1 4", 3), None);
+        assert_eq!(parse_choices("", 3), None);
+
+        let (confirmed, _) = prompter_run("maybe\ny\n", |ui| ui.confirm("Remove?"));
+        assert!(confirmed);
+    }
+
+    #[test]
+    fn branches_read_as_one_sentence() {
+        let entry = BranchListEntry {
+            name: "feature/a".to_string(),
+            scope: BranchScope::Local,
+            is_head: true,
+            upstream: None,
+            ahead: 2,
+            behind: 0,
+            last_commit_date: None,
+            cleanup_ready: false,
+            cleanup: crate::BranchCleanupInfo {
+                availability: BranchCleanupAvailability::Safe,
+                ..Default::default()
+            },
+            resume: Default::default(),
+            start_work_eligibility: None,
+            worktree_path: Some("/repo/feature-a".to_string()),
+            archived: false,
+            integrity_issues: Vec::new(),
+            linked_spec: None,
+        };
+        assert_eq!(
+            describe_branch(&entry),
+            "feature/a, local, current, 2 ahead, worktree /repo/feature-a, safe to clean up"
+        );
+    }
+}
@@ -124,6 +124,15 @@ pub(super) fn run<E: CliEnv>(
             return Ok(2);
         }
     }
+    if let PrCommand::Create {
+        base, head: None, ..
+    }
+    | PrCommand::CreateBody {
+        base, head: None, ..
+    } = &cmd
+    {
+        warn_missing_commit_trailers(env.repo_path(), base, out);
+    }
     let code = match cmd {
         PrCommand::Current => {
             match env.fetch_current_pr().map_err(super::io_as_api_error)? {
@@ -358,6 +367,45 @@ fn requested_head_matches_workspace_branch(
     stored_branch == Some(requested_branch) || current_branch == Some(requested_branch)
 }
 
+/// Warn (never refuse) when `[commit_trailers]` is enabled and commits on
+/// the current branch lack the configured trailers, e.g. commits made
+/// before the setting was turned on or outside an agent session.
+fn warn_missing_commit_trailers(repo_path: &std::path::Path, base: &str, out: &mut String) {
+    let Ok(settings) = gwt_config::Settings::load() else {
+        return;
+    };
+    let trailers = &settings.commit_trailers;
+    let mut required = Vec::new();
+    if trailers.co_authored_by {
+        required.push(gwt_git::CO_AUTHORED_BY);
+    }
+    if trailers.signed_off_by {
+        required.push(gwt_git::SIGNED_OFF_BY);
+    }
+    if required.is_empty() {
+        return;
+    }
+    let gaps = [format!("origin/{base}..HEAD"), format!("{base}..HEAD")]
+        .iter()
+        .find_map(|range| gwt_git::commits_missing_trailers(repo_path, range, &required).ok())
+        .unwrap_or_default();
+    if gaps.is_empty() {
+        return;
+    }
+    out.push_str(&format!(
+        "warning: {} commit(s) missing required trailers:\n",
+        gaps.len()
+    ));
+    for gap in &gaps {
+        out.push_str(&format!(
+            "  {} {} (missing: {})\n",
+            gap.hash,
+            gap.subject,
+            gap.missing.join(", ")
+        ));
+    }
+}
+
 fn split_head_owner_and_branch(head: &str) -> (Option<&str>, &str) {
     match head.split_once(':') {
         Some((owner, branch)) if !owner.is_empty() && !branch.is_empty() => (Some(owner), branch),
@@ -0,0 +1,346 @@
+//! `gwt profile ...` family module.
+//!
+//! `gwt profile export <name> [--file <path>]` writes one profile in the
+//! standalone format of [`gwt_config::Profile::to_toml`], the format of the
+//! repository's shared `.gwt/profiles/*.toml`. `gwt profile import --file
+//! <path>` adds such a file to `~/.gwt/config.toml`.
+//!
+//! `gwt profile migrate-secrets [--dry-run]` moves plaintext values of
+//! credential-looking variables (and of variables already flagged secret)
+//! out of the config file into the OS keychain; see [`gwt_config::secrets`].
+
+use std::path::{Path, PathBuf};
+
+use gwt_config::{KeyringSecretStore, Profile, SecretStore, Settings};
+use gwt_github::SpecOpsError;
+
+use super::{CliEnv, CliParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileCommand {
+    /// `gwt profile export <name> [--file <path>]` — user profiles first,
+    /// then the repository's shared ones; stdout without `--file`.
+    Export { name: String, file: Option<PathBuf> },
+    /// `gwt profile import --file <path> [--name <name>] [--force]` —
+    /// `--force` replaces a user profile of the same name.
+    Import {
+        file: PathBuf,
+        name: Option<String>,
+        force: bool,
+    },
+    /// `gwt profile migrate-secrets [--dry-run]` over every user profile.
+    MigrateSecrets { dry_run: bool },
+}
+
+pub fn parse(args: &[String]) -> Result<ProfileCommand, CliParseError> {
+    let (head, rest) = args.split_first().ok_or(CliParseError::Usage)?;
+    if head == "migrate-secrets" {
+        return match rest {
+            [] => Ok(ProfileCommand::MigrateSecrets { dry_run: false }),
+            [flag] if flag == "--dry-run" => Ok(ProfileCommand::MigrateSecrets { dry_run: true }),
+            [other, ..] => Err(CliParseError::UnknownSubcommand(other.to_string())),
+        };
+    }
+    let mut positional = None;
+    let mut file = None;
+    let mut name = None;
+    let mut force = false;
+    let mut iter = rest.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--file" => {
+                let value = iter.next().ok_or(CliParseError::MissingFlag("--file"))?;
+                file = Some(PathBuf::from(value));
+            }
+            "--name" if head == "import" => {
+                let value = iter.next().ok_or(CliParseError::MissingFlag("--name"))?;
+                name = Some(value.clone());
+            }
+            "--force" if head == "import" => force = true,
+            other if !other.starts_with('-') && positional.is_none() && head == "export" => {
+                positional = Some(other.to_string());
+            }
+            other => return Err(CliParseError::UnknownSubcommand(other.to_string())),
+        }
+    }
+    match head.as_str() {
+        "export" => Ok(ProfileCommand::Export {
+            name: positional.ok_or(CliParseError::Usage)?,
+            file,
+        }),
+        "import" => Ok(ProfileCommand::Import {
+            file: file.ok_or(CliParseError::MissingFlag("--file"))?,
+            name,
+            force,
+        }),
+        other => Err(CliParseError::UnknownSubcommand(other.to_string())),
+    }
+}
+
+pub fn run<E: CliEnv>(
+    env: &mut E,
+    cmd: ProfileCommand,
+    out: &mut String,
+) -> Result<i32, SpecOpsError> {
+    let Some(config_path) = Settings::global_config_path() else {
+        out.push_str("error: could not determine the config path\n");
+        return Ok(1);
+    };
+    let result = match cmd {
+        ProfileCommand::Export { name, file } => {
+            export(&config_path, env.repo_path(), &name, file.as_deref(), out)
+        }
+        ProfileCommand::Import { file, name, force } => {
+            import(&config_path, &file, name.as_deref(), force, out)
+        }
+        ProfileCommand::MigrateSecrets { dry_run } => {
+            migrate_secrets(&config_path, &KeyringSecretStore, dry_run, out)
+        }
+    };
+    match result {
+        Ok(()) => Ok(0),
+        Err(message) => {
+            out.push_str(&format!("error: {message}\n"));
+            Ok(1)
+        }
+    }
+}
+
+fn load_settings(config_path: &Path) -> Result<Settings, String> {
+    if config_path.exists() {
+        Settings::load_from_path(config_path).map_err(|error| error.to_string())
+    } else {
+        Ok(Settings::default())
+    }
+}
+
+fn export(
+    config_path: &Path,
+    repo_root: &Path,
+    name: &str,
+    file: Option<&Path>,
+    out: &mut String,
+) -> Result<(), String> {
+    let mut settings = load_settings(config_path)?;
+    settings
+        .profiles
+        .merge_repo_profiles(&gwt_config::load_repo_profiles(repo_root).profiles);
+    let profile = settings
+        .profiles
+        .get(name)
+        .ok_or_else(|| format!("profile not found: {name}"))?;
+    let content = profile.to_toml().map_err(|error| error.to_string())?;
+    match file {
+        Some(file) => {
+            std::fs::write(file, content)
+                .map_err(|error| format!("{}: {error}", file.display()))?;
+            out.push_str(&format!("exported '{name}' to {}\n", file.display()));
+        }
+        None => out.push_str(&content),
+    }
+    Ok(())
+}
+
+fn import(
+    config_path: &Path,
+    file: &Path,
+    name: Option<&str>,
+    force: bool,
+    out: &mut String,
+) -> Result<(), String> {
+    let content =
+        std::fs::read_to_string(file).map_err(|error| format!("{}: {error}", file.display()))?;
+    let stem = file
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let mut profile = Profile::from_toml(&content, &stem)
+        .map_err(|error| format!("{}: {error}", file.display()))?;
+    if let Some(name) = name.map(str::trim).filter(|name| !name.is_empty()) {
+        profile.name = name.to_string();
+    }
+    let profile_name = profile.name.clone();
+
+    let mut settings = load_settings(config_path)?;
+    let existing = settings
+        .profiles
+        .profiles
+        .iter()
+        .position(|existing| existing.name == profile_name);
+    match existing {
+        Some(_) if !force => {
+            return Err(format!(
+                "profile '{profile_name}' already exists; pass --force to replace it or --name to import under another name"
+            ));
+        }
+        Some(index) => settings.profiles.profiles[index] = profile,
+        None => settings.profiles.add(profile)?,
+    }
+    settings
+        .save(config_path)
+        .map_err(|error| error.to_string())?;
+    out.push_str(&format!(
+        "imported '{profile_name}' into {}\n",
+        config_path.display()
+    ));
+    Ok(())
+}
+
+fn migrate_secrets(
+    config_path: &Path,
+    store: &dyn SecretStore,
+    dry_run: bool,
+    out: &mut String,
+) -> Result<(), String> {
+    if !config_path.exists() {
+        out.push_str("no profiles to migrate\n");
+        return Ok(());
+    }
+    let mut settings = load_settings(config_path)?;
+    let mut pending = Vec::new();
+    for profile in &mut settings.profiles.profiles {
+        profile.flag_sensitive_env();
+        for key in &profile.secret_env {
+            if profile.env_vars.contains_key(key) {
+                pending.push(format!("{}/{key}", profile.name));
+            }
+        }
+    }
+    if pending.is_empty() {
+        out.push_str("no plaintext secrets found\n");
+        return Ok(());
+    }
+    if dry_run {
+        for entry in &pending {
+            out.push_str(&format!("would move {entry}\n"));
+        }
+        return Ok(());
+    }
+    // Values are in the keychain before the config file drops them, so a
+    // keychain failure leaves the file untouched.
+    let moved = settings
+        .profiles
+        .store_secrets(store)
+        .map_err(|error| error.to_string())?;
+    settings
+        .save(config_path)
+        .map_err(|error| error.to_string())?;
+    for (profile, key) in &moved {
+        out.push_str(&format!("moved {profile}/{key} to the keychain\n"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_export_and_import_flags() {
+        assert_eq!(
+            parse(&args(&["export", "dev"])).unwrap(),
+            ProfileCommand::Export {
+                name: "dev".to_string(),
+                file: None
+            }
+        );
+        assert_eq!(
+            parse(&args(&["export", "dev", "--file", "dev.toml"])).unwrap(),
+            ProfileCommand::Export {
+                name: "dev".to_string(),
+                file: Some(PathBuf::from("dev.toml"))
+            }
+        );
+        assert_eq!(
+            parse(&args(&[
+                "import", "--file", "dev.toml", "--name", "mine", "--force"
+            ]))
+            .unwrap(),
+            ProfileCommand::Import {
+                file: PathBuf::from("dev.toml"),
+                name: Some("mine".to_string()),
+                force: true,
+            }
+        );
+        assert!(parse(&args(&["export"])).is_err());
+        assert!(parse(&args(&["export", "dev", "--force"])).is_err());
+        assert!(parse(&args(&["import"])).is_err());
+        assert!(parse(&args(&["import", "dev.toml"])).is_err());
+        assert_eq!(
+            parse(&args(&["migrate-secrets", "--dry-run"])).unwrap(),
+            ProfileCommand::MigrateSecrets { dry_run: true }
+        );
+        assert!(parse(&args(&["migrate-secrets", "--force"])).is_err());
+        assert!(parse(&args(&["list"])).is_err());
+        assert!(parse(&[]).is_err());
+    }
+
+    #[test]
+    fn export_then_import_round_trips_through_a_file() {
+        let temp = tempfile::tempdir().unwrap();
+        let config_path = temp.path().join("config.toml");
+        let mut settings = Settings::default();
+        settings
+            .profiles
+            .add(Profile::new("dev").with_env("RUST_LOG", "debug"))
+            .unwrap();
+        settings.save(&config_path).unwrap();
+
+        let file = temp.path().join("dev.toml");
+        let mut out = String::new();
+        export(&config_path, temp.path(), "dev", Some(&file), &mut out).unwrap();
+        assert!(export(&config_path, temp.path(), "missing", None, &mut out).is_err());
+
+        let error = import(&config_path, &file, None, false, &mut out).unwrap_err();
+        assert!(error.contains("--force"), "{error}");
+        import(&config_path, &file, Some("copy"), false, &mut out).unwrap();
+
+        let settings = Settings::load_from_path(&config_path).unwrap();
+        assert_eq!(
+            settings.profiles.get("copy").unwrap().env_vars["RUST_LOG"],
+            "debug"
+        );
+    }
+
+    #[test]
+    fn migrate_secrets_moves_credential_values_out_of_the_config() {
+        let temp = tempfile::tempdir().unwrap();
+        let config_path = temp.path().join("config.toml");
+        let mut settings = Settings::default();
+        settings
+            .profiles
+            .add(
+                Profile::new("dev")
+                    .with_env("GITHUB_TOKEN", "ghp_1")
+                    .with_env("RUST_LOG", "debug"),
+            )
+            .unwrap();
+        settings.save(&config_path).unwrap();
+        let store = gwt_config::MemorySecretStore::default();
+
+        let mut out = String::new();
+        migrate_secrets(&config_path, &store, true, &mut out).unwrap();
+        assert_eq!(out, "would move dev/GITHUB_TOKEN\n");
+        assert!(std::fs::read_to_string(&config_path)
+            .unwrap()
+            .contains("ghp_1"));
+
+        let mut out = String::new();
+        migrate_secrets(&config_path, &store, false, &mut out).unwrap();
+        assert_eq!(out, "moved dev/GITHUB_TOKEN to the keychain\n");
+        let saved = std::fs::read_to_string(&config_path).unwrap();
+        assert!(!saved.contains("ghp_1"), "{saved}");
+        assert_eq!(
+            store.get("dev", "GITHUB_TOKEN").unwrap().as_deref(),
+            Some("ghp_1")
+        );
+
+        let mut out = String::new();
+        migrate_secrets(&config_path, &store, false, &mut out).unwrap();
+        assert_eq!(out, "no plaintext secrets found\n");
+    }
+}
@@ -0,0 +1,344 @@
+//! `gwt session ...` family module.
+//!
+//! `gwt session export <branch>` renders the transcripts of the agent
+//! sessions that ran on a branch (see [`crate::session_export`]) as
+//! Markdown, HTML or JSON, to stdout or to `--output`.
+//!
+//! `gwt session diff <branch> [<left> <right>]` compares two of those runs
+//! (see [`gwt_agent::session_diff`]): message counts, tools used, files
+//! touched and where their decisions diverged. Without run ids the two most
+//! recent runs are compared.
+//!
+//! `gwt session bundle <branch>` writes an anonymized bundle of those runs
+//! (transcripts, launch plans, redacted environment, gwt version and git
+//! metadata) to attach to a bug report. `gwt session replay-plan <bundle>`
+//! reads one back and prints the steps that reproduce its launches (see
+//! [`gwt_agent::session_bundle`]).
+
+use std::path::PathBuf;
+
+use gwt_agent::{
+    session_bundle::{read_bundle, render_replay_plan, write_bundle},
+    session_diff::{diff_sessions, render_diff_markdown, select_runs},
+    session_export::{render_export, ExportFormat},
+};
+use gwt_github::{client::ApiError, SpecOpsError};
+
+use super::{CliEnv, CliParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionCommand {
+    /// `gwt session export <branch> [--format md|html|json] [--output <path>]`
+    Export {
+        branch: String,
+        format: ExportFormat,
+        output: Option<PathBuf>,
+    },
+    /// `gwt session diff <branch> [<left> <right>] [--json]`
+    Diff {
+        branch: String,
+        left: Option<String>,
+        right: Option<String>,
+        json: bool,
+    },
+    /// `gwt session bundle <branch> [--output <path>]`
+    Bundle {
+        branch: String,
+        output: Option<PathBuf>,
+    },
+    /// `gwt session replay-plan <bundle>`
+    ReplayPlan { bundle: PathBuf },
+}
+
+pub fn parse(args: &[String]) -> Result<SessionCommand, CliParseError> {
+    let (head, rest) = args.split_first().ok_or(CliParseError::Usage)?;
+    match head.as_str() {
+        "export" => parse_export(rest),
+        "diff" => parse_diff(rest),
+        "bundle" => parse_bundle(rest),
+        "replay-plan" => parse_replay_plan(rest),
+        other => Err(CliParseError::UnknownSubcommand(other.to_string())),
+    }
+}
+
+fn parse_export(rest: &[String]) -> Result<SessionCommand, CliParseError> {
+    let mut branch = None;
+    let mut format = ExportFormat::Markdown;
+    let mut output = None;
+    let mut iter = rest.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--format" => {
+                let value = iter.next().ok_or(CliParseError::MissingFlag("--format"))?;
+                format = value.parse().map_err(|_| CliParseError::InvalidValue {
+                    flag: "--format",
+                    reason: "expected md, html or json",
+                })?;
+            }
+            "--output" => {
+                let value = iter.next().ok_or(CliParseError::MissingFlag("--output"))?;
+                output = Some(PathBuf::from(value));
+            }
+            other if other.starts_with("--") || branch.is_some() => {
+                return Err(CliParseError::UnknownSubcommand(other.to_string()));
+            }
+            other => branch = Some(other.to_string()),
+        }
+    }
+    Ok(SessionCommand::Export {
+        branch: branch.ok_or(CliParseError::MissingFlag("<branch>"))?,
+        format,
+        output,
+    })
+}
+
+fn parse_diff(rest: &[String]) -> Result<SessionCommand, CliParseError> {
+    let mut json = false;
+    let mut positional = Vec::new();
+    for arg in rest {
+        match arg.as_str() {
+            "--json" => json = true,
+            other if other.starts_with("--") => {
+                return Err(CliParseError::UnknownSubcommand(other.to_string()));
+            }
+            other => positional.push(other.to_string()),
+        }
+    }
+    let mut positional = positional.into_iter();
+    let branch = positional
+        .next()
+        .ok_or(CliParseError::MissingFlag("<branch>"))?;
+    let (left, right) = match (positional.next(), positional.next(), positional.next()) {
+        (None, _, _) => (None, None),
+        (Some(left), Some(right), None) => (Some(left), Some(right)),
+        (Some(_), None, _) => return Err(CliParseError::MissingFlag("<right>")),
+        (Some(_), Some(_), Some(extra)) => return Err(CliParseError::UnknownSubcommand(extra)),
+    };
+    Ok(SessionCommand::Diff {
+        branch,
+        left,
+        right,
+        json,
+    })
+}
+
+fn parse_bundle(rest: &[String]) -> Result<SessionCommand, CliParseError> {
+    let mut branch = None;
+    let mut output = None;
+    let mut iter = rest.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--output" => {
+                let value = iter.next().ok_or(CliParseError::MissingFlag("--output"))?;
+                output = Some(PathBuf::from(value));
+            }
+            other if other.starts_with("--") || branch.is_some() => {
+                return Err(CliParseError::UnknownSubcommand(other.to_string()));
+            }
+            other => branch = Some(other.to_string()),
+        }
+    }
+    Ok(SessionCommand::Bundle {
+        branch: branch.ok_or(CliParseError::MissingFlag("<branch>"))?,
+        output,
+    })
+}
+
+fn parse_replay_plan(rest: &[String]) -> Result<SessionCommand, CliParseError> {
+    match rest {
+        [bundle] if !bundle.starts_with("--") => Ok(SessionCommand::ReplayPlan {
+            bundle: PathBuf::from(bundle),
+        }),
+        [] => Err(CliParseError::MissingFlag("<bundle>")),
+        [_, extra, ..] => Err(CliParseError::UnknownSubcommand(extra.to_string())),
+        [other] => Err(CliParseError::UnknownSubcommand(other.to_string())),
+    }
+}
+
+pub fn run<E: CliEnv>(
+    env: &mut E,
+    cmd: SessionCommand,
+    out: &mut String,
+) -> Result<i32, SpecOpsError> {
+    let (branch, format, output) = match cmd {
+        SessionCommand::Export {
+            branch,
+            format,
+            output,
+        } => (branch, format, output),
+        SessionCommand::Diff {
+            branch,
+            left,
+            right,
+            json,
+        } => return run_diff(env, &branch, left.as_deref(), right.as_deref(), json, out),
+        SessionCommand::Bundle { branch, output } => return run_bundle(env, &branch, output, out),
+        SessionCommand::ReplayPlan { bundle } => {
+            let bundle = read_bundle(&bundle).map_err(|err| {
+                SpecOpsError::from(ApiError::Unexpected(format!("{}: {err}", bundle.display())))
+            })?;
+            out.push_str(&render_replay_plan(&bundle, env!("CARGO_PKG_VERSION")));
+            return Ok(0);
+        }
+    };
+    let export = crate::session_export::export_branch_sessions(env.repo_path(), &branch);
+    let rendered = render_export(&export, format);
+    match output {
+        Some(path) => {
+            std::fs::write(&path, rendered).map_err(|err| {
+                SpecOpsError::from(ApiError::Unexpected(format!("{}: {err}", path.display())))
+            })?;
+            out.push_str(&format!(
+                "Wrote {} session(s) of {branch} to {}\n",
+                export.sessions.len(),
+                path.display()
+            ));
+        }
+        None => out.push_str(&rendered),
+    }
+    Ok(0)
+}
+
+fn run_diff<E: CliEnv>(
+    env: &mut E,
+    branch: &str,
+    left: Option<&str>,
+    right: Option<&str>,
+    json: bool,
+    out: &mut String,
+) -> Result<i32, SpecOpsError> {
+    let export = crate::session_export::export_branch_sessions(env.repo_path(), branch);
+    let (left, right) = match select_runs(&export, left, right) {
+        Ok(runs) => runs,
+        Err(message) => {
+            out.push_str(&format!("{message}\n"));
+            return Ok(1);
+        }
+    };
+    let diff = diff_sessions(branch, left, right);
+    if json {
+        let rendered = serde_json::to_string_pretty(&diff)
+            .map_err(|err| SpecOpsError::from(ApiError::Unexpected(err.to_string())))?;
+        out.push_str(&rendered);
+        out.push('\n');
+    } else {
+        out.push_str(&render_diff_markdown(&diff));
+    }
+    Ok(0)
+}
+
+fn run_bundle<E: CliEnv>(
+    env: &mut E,
+    branch: &str,
+    output: Option<PathBuf>,
+    out: &mut String,
+) -> Result<i32, SpecOpsError> {
+    let bundle = crate::session_export::bundle_branch_sessions(env.repo_path(), branch)
+        .map_err(|err| SpecOpsError::from(ApiError::Unexpected(err.to_string())))?;
+    let path = output.unwrap_or_else(|| {
+        PathBuf::from(crate::session_export::export_file_name(
+            branch,
+            "bundle.json.gz",
+        ))
+    });
+    write_bundle(&path, &bundle).map_err(|err| {
+        SpecOpsError::from(ApiError::Unexpected(format!("{}: {err}", path.display())))
+    })?;
+    out.push_str(&format!(
+        "Wrote a bundle of {} launch(es) and {} session(s) of {branch} to {}\n\
+         Review it before attaching it to a bug report.\n",
+        bundle.launches.len(),
+        bundle.export.sessions.len(),
+        path.display()
+    ));
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_export_branch_and_flags() {
+        assert_eq!(
+            parse(&args(&["export", "feature/a"])).unwrap(),
+            SessionCommand::Export {
+                branch: "feature/a".to_string(),
+                format: ExportFormat::Markdown,
+                output: None,
+            }
+        );
+        assert_eq!(
+            parse(&args(&[
+                "export",
+                "--format",
+                "html",
+                "feature/a",
+                "--output",
+                "run.html"
+            ]))
+            .unwrap(),
+            SessionCommand::Export {
+                branch: "feature/a".to_string(),
+                format: ExportFormat::Html,
+                output: Some(PathBuf::from("run.html")),
+            }
+        );
+        assert!(parse(&args(&["export"])).is_err());
+        assert!(parse(&args(&["export", "a", "b"])).is_err());
+        assert!(parse(&args(&["export", "a", "--format", "pdf"])).is_err());
+        assert!(parse(&args(&["list"])).is_err());
+        assert!(parse(&[]).is_err());
+    }
+
+    #[test]
+    fn parse_diff_branch_runs_and_json() {
+        assert_eq!(
+            parse(&args(&["diff", "feature/a"])).unwrap(),
+            SessionCommand::Diff {
+                branch: "feature/a".to_string(),
+                left: None,
+                right: None,
+                json: false,
+            }
+        );
+        assert_eq!(
+            parse(&args(&["diff", "feature/a", "0191", "0192", "--json"])).unwrap(),
+            SessionCommand::Diff {
+                branch: "feature/a".to_string(),
+                left: Some("0191".to_string()),
+                right: Some("0192".to_string()),
+                json: true,
+            }
+        );
+        assert!(parse(&args(&["diff"])).is_err());
+        assert!(parse(&args(&["diff", "a", "0191"])).is_err());
+        assert!(parse(&args(&["diff", "a", "1", "2", "3"])).is_err());
+        assert!(parse(&args(&["diff", "a", "--stat"])).is_err());
+    }
+
+    #[test]
+    fn parse_bundle_and_replay_plan() {
+        assert_eq!(
+            parse(&args(&["bundle", "feature/a", "--output", "bug.json.gz"])).unwrap(),
+            SessionCommand::Bundle {
+                branch: "feature/a".to_string(),
+                output: Some(PathBuf::from("bug.json.gz")),
+            }
+        );
+        assert_eq!(
+            parse(&args(&["replay-plan", "bug.json.gz"])).unwrap(),
+            SessionCommand::ReplayPlan {
+                bundle: PathBuf::from("bug.json.gz"),
+            }
+        );
+        assert!(parse(&args(&["bundle"])).is_err());
+        assert!(parse(&args(&["bundle", "a", "--format", "md"])).is_err());
+        assert!(parse(&args(&["replay-plan"])).is_err());
+        assert!(parse(&args(&["replay-plan", "a", "b"])).is_err());
+    }
+}
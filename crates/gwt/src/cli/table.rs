@@ -0,0 +1,205 @@
+//! Shared table output for list-style CLI commands.
+//!
+//! Commands build a [`Table`] and render it with the [`TableStyle`] picked
+//! from their flags: aligned columns with a header for people, or
+//! `--plain` tab-separated rows without a header for `cut` / `awk`. Aligned
+//! output fits the terminal width from `COLUMNS` by truncating the widest
+//! columns with `…`; without `COLUMNS` (piped output) nothing is cut.
+
+/// Gap between aligned columns.
+const COLUMN_GAP: &str = "  ";
+/// Aligned columns are never truncated below this many characters.
+const MIN_COLUMN_WIDTH: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TableStyle {
+    /// Header plus space-aligned columns, fitted to `width` when known.
+    Aligned { width: Option<usize> },
+    /// One tab-separated line per row, no header.
+    Plain,
+}
+
+impl TableStyle {
+    /// `Plain` for `--plain`, otherwise aligned to the terminal width.
+    pub(crate) fn from_flag(plain: bool) -> Self {
+        if plain {
+            Self::Plain
+        } else {
+            Self::Aligned {
+                width: terminal_width(),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub(crate) fn new(headers: &[&'static str]) -> Self {
+        Self {
+            headers: headers.to_vec(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Append a row; missing cells render empty, extra cells are dropped.
+    pub(crate) fn row<I, S>(&mut self, cells: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut row: Vec<String> = cells
+            .into_iter()
+            .map(Into::into)
+            .take(self.headers.len())
+            .collect();
+        row.resize(self.headers.len(), String::new());
+        self.rows.push(row);
+        self
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub(crate) fn render(&self, style: TableStyle) -> String {
+        match style {
+            TableStyle::Plain => self.render_plain(),
+            TableStyle::Aligned { width } => self.render_aligned(width),
+        }
+    }
+
+    fn render_plain(&self) -> String {
+        let mut out = String::new();
+        for row in &self.rows {
+            let cells: Vec<String> = row
+                .iter()
+                .map(|cell| cell.replace(['\t', '\n', '\r'], " "))
+                .collect();
+            out.push_str(&cells.join("\t"));
+            out.push('\n');
+        }
+        out
+    }
+
+    fn render_aligned(&self, width: Option<usize>) -> String {
+        let mut widths: Vec<usize> = self
+            .headers
+            .iter()
+            .map(|header| display_width(header))
+            .collect();
+        for row in &self.rows {
+            for (column, cell) in row.iter().enumerate() {
+                widths[column] = widths[column].max(display_width(first_line(cell)));
+            }
+        }
+        if let Some(width) = width {
+            fit_widths(&mut widths, width);
+        }
+
+        let mut out = String::new();
+        let headers: Vec<String> = self.headers.iter().map(|h| h.to_string()).collect();
+        for row in std::iter::once(&headers).chain(&self.rows) {
+            let last = row.len().saturating_sub(1);
+            let mut line = String::new();
+            for (column, cell) in row.iter().enumerate() {
+                let cell = truncate(first_line(cell), widths[column]);
+                line.push_str(&cell);
+                if column != last {
+                    let pad = widths[column] - display_width(&cell);
+                    line.push_str(&" ".repeat(pad));
+                    line.push_str(COLUMN_GAP);
+                }
+            }
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// `COLUMNS`, when set to a positive number.
+pub(crate) fn terminal_width() -> Option<usize> {
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|value| value.trim().parse::<usize>().ok())
+        .filter(|width| *width > 0)
+}
+
+/// Shrink the widest columns, one character at a time, until the row fits
+/// `total` or every column is at its minimum.
+fn fit_widths(widths: &mut [usize], total: usize) {
+    let gaps = COLUMN_GAP.len() * widths.len().saturating_sub(1);
+    while widths.iter().sum::<usize>() + gaps > total {
+        let Some(widest) = widths
+            .iter_mut()
+            .filter(|width| **width > MIN_COLUMN_WIDTH)
+            .max_by_key(|width| **width)
+        else {
+            return;
+        };
+        *widest -= 1;
+    }
+}
+
+fn first_line(cell: &str) -> &str {
+    cell.lines().next().unwrap_or("")
+}
+
+fn display_width(text: &str) -> usize {
+    text.chars().count()
+}
+
+fn truncate(text: &str, width: usize) -> String {
+    if display_width(text) <= width {
+        return text.to_string();
+    }
+    let mut cut: String = text.chars().take(width.saturating_sub(1)).collect();
+    cut.push('…');
+    cut
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Table {
+        let mut table = Table::new(&["ID", "STATE", "BRANCH"]);
+        table
+            .row(["a1", "running", "feature/very-long-branch-name"])
+            .row(["b2", "queued", "main"]);
+        table
+    }
+
+    #[test]
+    fn aligned_pads_columns_and_fits_width() {
+        assert_eq!(
+            sample().render(TableStyle::Aligned { width: None }),
+            "ID  STATE    BRANCH\n\
+             a1  running  feature/very-long-branch-name\n\
+             b2  queued   main\n"
+        );
+        assert_eq!(
+            sample().render(TableStyle::Aligned { width: Some(28) }),
+            "ID  STATE    BRANCH\n\
+             a1  running  feature/very-l…\n\
+             b2  queued   main\n"
+        );
+    }
+
+    #[test]
+    fn plain_is_tab_separated_without_header() {
+        let mut table = sample();
+        table.row(["c3", "failed", "tab\there"]);
+        assert_eq!(
+            table.render(TableStyle::Plain),
+            "a1\trunning\tfeature/very-long-branch-name\n\
+             b2\tqueued\tmain\n\
+             c3\tfailed\ttab here\n"
+        );
+    }
+}
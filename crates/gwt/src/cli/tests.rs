@@ -0,0 +1,199 @@
+use tempfile::tempdir;
+
+use gwt_github::{IssueNumber, IssueSnapshot, IssueState};
+
+use super::*;
+
+#[test]
+fn render_helpers_include_issue_pr_and_review_details() {
+    let mut out = String::new();
+    let issue = crate::cli::test_support::sample_issue_snapshot();
+    let pr = crate::cli::test_support::sample_pr_status();
+
+    crate::cli::issue::render_issue(&mut out, &issue);
+    crate::cli::issue::render_issue_comments(&mut out, &issue);
+    crate::cli::issue::render_linked_prs(
+        &mut out,
+        &[LinkedPrSummary {
+            number: 128,
+            title: "Enforce coverage".to_string(),
+            state: "OPEN".to_string(),
+            url: pr.url.clone(),
+            will_close_target: true,
+        }],
+    );
+    crate::cli::pr::render_pr(&mut out, &pr);
+    crate::cli::pr::render_pr_checks(
+        &mut out,
+        &PrChecksSummary {
+            summary: "All checks passed".to_string(),
+            ci_status: "SUCCESS".to_string(),
+            merge_status: "MERGEABLE".to_string(),
+            review_status: "APPROVED".to_string(),
+            checks: vec![PrCheckItem {
+                name: "CI".to_string(),
+                state: "COMPLETED".to_string(),
+                conclusion: "SUCCESS".to_string(),
+                url: "https://github.com/akiojin/gwt/actions/runs/1".to_string(),
+                started_at: "2026-04-20T00:00:00Z".to_string(),
+                completed_at: "2026-04-20T00:01:00Z".to_string(),
+                workflow: "coverage".to_string(),
+            }],
+        },
+    );
+    crate::cli::pr::render_pr_reviews(
+        &mut out,
+        &[PrReview {
+            id: "review-1".to_string(),
+            state: "APPROVED".to_string(),
+            author: "reviewer".to_string(),
+            submitted_at: "2026-04-20T02:00:00Z".to_string(),
+            body: "Looks good.".to_string(),
+        }],
+    );
+    crate::cli::pr::render_pr_review_threads(
+        &mut out,
+        &[PrReviewThread {
+            comments: vec![PrReviewThreadComment {
+                id: "comment-1".to_string(),
+                body: "Please add a push gate.".to_string(),
+                created_at: "2026-04-20T03:00:00Z".to_string(),
+                updated_at: "2026-04-20T03:00:00Z".to_string(),
+                author: "reviewer".to_string(),
+            }],
+            ..crate::cli::test_support::sample_thread()
+        }],
+    );
+
+    assert!(out.contains("#42 [OPEN] Coverage gate"));
+    assert!(out.contains("labels: gwt-spec, coverage"));
+    assert!(out.contains("=== comment:7 (2026-04-20T01:00:00Z) ==="));
+    assert!(out.contains("#128 [OPEN] Enforce coverage"));
+    assert!(out.contains("ci: SUCCESS"));
+    assert!(out.contains("merge_state: CLEAN"));
+    assert!(out.contains("workflow: coverage"));
+    assert!(out.contains("=== review:review-1 [APPROVED] by reviewer"));
+    assert!(out
+        .contains("=== thread:thread-1 resolved=false outdated=false path=src/lib.rs line=12 ==="));
+}
+
+#[test]
+fn cache_and_parse_helpers_cover_fallback_paths() {
+    let temp = tempdir().expect("tempdir");
+    let number = IssueNumber(77);
+    let linked_prs = vec![LinkedPrSummary {
+        number: 9,
+        title: "Hook coverage".to_string(),
+        state: "MERGED".to_string(),
+        url: "https://github.com/akiojin/gwt/pull/9".to_string(),
+        will_close_target: true,
+    }];
+
+    assert!(
+        crate::cli::issue::read_linked_prs_cache(temp.path(), number)
+            .unwrap()
+            .is_none()
+    );
+    crate::cli::issue::write_linked_prs_cache(temp.path(), number, &linked_prs).unwrap();
+    assert_eq!(
+        crate::cli::issue::read_linked_prs_cache(temp.path(), number).unwrap(),
+        Some(linked_prs)
+    );
+
+    assert_eq!(
+        crate::cli::pr::extract_pr_url("note\n https://github.com/akiojin/gwt/pull/55 \nignored"),
+        Some("https://github.com/akiojin/gwt/pull/55".to_string())
+    );
+    assert_eq!(
+        crate::cli::pr::parse_pr_number_from_url("https://github.com/akiojin/gwt/pull/55/"),
+        Some(55)
+    );
+    assert_eq!(
+        crate::cli::pr::parse_available_fields("error\nAvailable fields:\n  number\n  title\n"),
+        vec!["number".to_string(), "title".to_string()]
+    );
+
+    let checks = crate::cli::pr::parse_pr_checks_items_json(
+        r#"[{"name":"coverage","status":"IN_PROGRESS","bucket":"pending","link":"https://example.com/check"}]"#,
+    )
+    .unwrap();
+    assert_eq!(checks[0].state, "IN_PROGRESS");
+    assert_eq!(checks[0].conclusion, "pending");
+    assert_eq!(checks[0].url, "https://example.com/check");
+}
+
+#[test]
+fn parse_and_guard_helpers_cover_additional_error_paths() {
+    assert!(should_dispatch_cli(&[
+        "gwt".to_string(),
+        "issue".to_string()
+    ]));
+    assert!(!should_dispatch_cli(&[
+        "gwt".to_string(),
+        "help".to_string()
+    ]));
+
+    let check = "--check".to_string();
+    assert!(expect_flag(Some(&check), "--check").is_ok());
+    assert!(expect_flag(Some(&check), "--refresh").is_err());
+
+    let number = "42".to_string();
+    let bad_number = "abc".to_string();
+    assert_eq!(parse_required_number(Some(&number)).unwrap(), 42);
+    assert!(parse_required_number(Some(&bad_number)).is_err());
+    assert!(ensure_no_remaining_args([].iter()).is_ok());
+    assert!(ensure_no_remaining_args([check].iter()).is_err());
+
+    assert!(matches!(parse_hook_args(&[]), Err(CliParseError::Usage)));
+    assert_eq!(
+        crate::cli::issue::issue_state_label(IssueState::Closed),
+        "CLOSED"
+    );
+    assert!(io_as_api_error(io::Error::other("boom"))
+        .to_string()
+        .contains("boom"));
+    assert!(crate::cli::pr::edit_or_create_repo_guard("", "repo").is_err());
+    assert!(crate::cli::pr::edit_or_create_repo_guard("akiojin", "gwt").is_ok());
+}
+
+#[test]
+fn render_helpers_cover_empty_states_and_url_parsing_fallbacks() {
+    let issue = IssueSnapshot {
+        comments: Vec::new(),
+        ..crate::cli::test_support::sample_issue_snapshot()
+    };
+    let mut out = String::new();
+
+    crate::cli::issue::render_issue_comments(&mut out, &issue);
+    crate::cli::issue::render_linked_prs(&mut out, &[]);
+    crate::cli::pr::render_pr_checks(
+        &mut out,
+        &PrChecksSummary {
+            summary: "pending".to_string(),
+            ci_status: "PENDING".to_string(),
+            merge_status: "UNKNOWN".to_string(),
+            review_status: "PENDING".to_string(),
+            checks: Vec::new(),
+        },
+    );
+    crate::cli::pr::render_pr_reviews(&mut out, &[]);
+    crate::cli::pr::render_pr_review_threads(&mut out, &[]);
+
+    assert!(out.contains("no comments"));
+    assert!(out.contains("no linked pull requests"));
+    assert!(out.contains("no checks"));
+    assert!(out.contains("no reviews"));
+    assert!(out.contains("no review threads"));
+    assert_eq!(crate::cli::pr::extract_pr_url("no pull request here"), None);
+    assert_eq!(
+        crate::cli::pr::parse_pr_number_from_url(
+            "https://github.com/akiojin/gwt/pull/not-a-number"
+        ),
+        None
+    );
+    assert!(crate::cli::pr::parse_available_fields("plain error").is_empty());
+    assert_eq!(
+        crate::cli::pr::parse_available_fields("oops\nAvailable fields:\n  number\n\n  title\n"),
+        vec!["number".to_string(), "title".to_string()]
+    );
+}
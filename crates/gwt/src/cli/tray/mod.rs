@@ -50,12 +50,16 @@ pub fn open_browser_for_url(url: &str) -> std::io::Result<()> {
 /// compatibility — the tray menu `Open` action is what actually opens the
 /// browser now, so the auto-open default is `false` regardless of this
 /// flag.
+///
+/// `--read-only` serves the dashboard with every mutating frontend event
+/// and upload endpoint disabled, so it can be exposed on a shared host.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TrayArgs {
     pub bind: IpAddr,
     pub port: Option<u16>,
     pub no_tray: bool,
     pub no_open: bool,
+    pub read_only: bool,
 }
 
 impl Default for TrayArgs {
@@ -65,6 +69,7 @@ impl Default for TrayArgs {
             port: None,
             no_tray: false,
             no_open: false,
+            read_only: false,
         }
     }
 }
@@ -89,7 +94,8 @@ pub enum TrayArgParseError {
 
 /// Canonical usage hint printed alongside any [`TrayArgParseError`]. Kept
 /// as a constant so `main()`, the parser, and the README stay in sync.
-pub const TRAY_USAGE_HINT: &str = "usage: gwt [--bind <ip>] [--port <n>] [--no-tray] [--no-open]";
+pub const TRAY_USAGE_HINT: &str =
+    "usage: gwt [--bind <ip>] [--port <n>] [--no-tray] [--no-open] [--read-only]";
 
 impl std::fmt::Display for TrayArgParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -157,6 +163,7 @@ pub fn parse_tray_argv(argv: &[String]) -> Result<TrayArgs, TrayArgParseError> {
             }
             "--no-tray" => args.no_tray = true,
             "--no-open" => args.no_open = true,
+            "--read-only" => args.read_only = true,
             flag if flag.starts_with("--") => {
                 return Err(TrayArgParseError::UnknownFlag(flag.to_string()));
             }
@@ -192,6 +199,15 @@ mod tests {
         assert_eq!(args.port, None);
         assert!(!args.no_tray);
         assert!(!args.no_open);
+        assert!(!args.read_only);
+    }
+
+    #[test]
+    fn parse_tray_argv_accepts_read_only_flag() {
+        let args = parse_tray_argv(&argv(&["gwt", "--bind", "0.0.0.0", "--read-only"]))
+            .expect("flags parse");
+        assert!(args.read_only);
+        assert_eq!(args.bind, IpAddr::V4(Ipv4Addr::UNSPECIFIED));
     }
 
     #[test]
@@ -1,16 +1,23 @@
 //! `gwtd update` — manual update check and apply.
 //!
+//! `gwtd update [--check] [--channel stable|beta|nightly]` follows the
+//! `[update] channel` setting unless `--channel` overrides it for the run;
+//! `gwtd update rollback` restores the binary the last update replaced.
+//!
 //! SPEC-1942 SC-027 split: `mod.rs` keeps the public CLI entry points
-//! (`UpdateRunMode`, `parse_args`, `run`, `run_internal_apply_update`,
-//! `run_internal_run_installer`). The `UpdateCliOps` trait and its production
-//! `RealUpdateCliOps` impl live in the private sibling `ops` module; tests
-//! live in the `#[cfg(test)] mod tests` sibling.
+//! (`UpdateRunMode`, `parse_args`, `run`, `run_rollback`,
+//! `run_internal_apply_update`, `run_internal_run_installer`). The
+//! `UpdateCliOps` trait and its production `RealUpdateCliOps` impl live in the
+//! private sibling `ops` module; tests live in the `#[cfg(test)] mod tests`
+//! sibling.
 
 mod ops;
 #[cfg(test)]
 mod tests;
 
-use gwt_core::update::{InstallerKind, PreparedPayload, UpdateState};
+use gwt_core::update::{InstallerKind, PreparedPayload, UpdateChannel, UpdateState};
+
+use super::{CliParseError, UpdateCommand};
 
 use ops::{RealUpdateCliOps, UpdateCliOps};
 
@@ -28,13 +35,48 @@ enum RunOutcome {
     ExitSuccess,
 }
 
-/// Parse `gwtd update [--check]` arguments.
-pub fn parse_args(args: &[String]) -> UpdateRunMode {
-    if args.iter().any(|a| a == "--check") {
-        UpdateRunMode::CheckOnly
-    } else {
-        UpdateRunMode::Apply
+/// Parse `gwtd update [--check] [--channel <name>]` and
+/// `gwtd update rollback` arguments.
+pub fn parse_args(args: &[String]) -> Result<UpdateCommand, CliParseError> {
+    if let [first, rest @ ..] = args {
+        if first == "rollback" {
+            return match rest {
+                [] => Ok(UpdateCommand::Rollback),
+                [extra, ..] => Err(CliParseError::UnknownSubcommand(extra.clone())),
+            };
+        }
+    }
+    let mut check_only = false;
+    let mut channel = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--check" => check_only = true,
+            "--channel" => {
+                let value = iter.next().ok_or(CliParseError::MissingFlag("--channel"))?;
+                channel = Some(value.parse::<UpdateChannel>().map_err(|_| {
+                    CliParseError::InvalidValue {
+                        flag: "--channel",
+                        reason: "expected stable, beta or nightly",
+                    }
+                })?);
+            }
+            other => return Err(CliParseError::UnknownSubcommand(other.to_string())),
+        }
     }
+    Ok(if check_only {
+        UpdateCommand::CheckOnly { channel }
+    } else {
+        UpdateCommand::Apply { channel }
+    })
+}
+
+/// The `[update] channel` from `~/.gwt/config.toml`; stable when unset.
+pub fn configured_channel() -> UpdateChannel {
+    gwt_config::Settings::load()
+        .ok()
+        .and_then(|settings| settings.update.channel().parse().ok())
+        .unwrap_or_default()
 }
 
 fn run_with(ops: &mut impl UpdateCliOps, cmd: UpdateRunMode) -> RunOutcome {
@@ -46,10 +88,16 @@ fn run_with(ops: &mut impl UpdateCliOps, cmd: UpdateRunMode) -> RunOutcome {
     let force = true; // `gwtd update` always ignores the TTL cache
     let current_exe = ops.current_exe().ok();
     let state = ops.check_for_executable(force, current_exe.as_deref());
+    let channel = ops.channel();
+    let on_channel = if channel == UpdateChannel::Stable {
+        String::new()
+    } else {
+        format!(" on the {} channel", channel.as_str())
+    };
 
     match state {
         UpdateState::UpToDate { .. } => {
-            ops.write_stdout("gwt is up to date.\n");
+            ops.write_stdout(&format!("gwt is up to date{on_channel}.\n"));
             RunOutcome::Code(0)
         }
         UpdateState::Failed { message, .. } => {
@@ -62,7 +110,9 @@ fn run_with(ops: &mut impl UpdateCliOps, cmd: UpdateRunMode) -> RunOutcome {
             asset_url,
             ..
         } => {
-            ops.write_stdout(&format!("Update available: v{current} → v{latest}\n"));
+            ops.write_stdout(&format!(
+                "Update available{on_channel}: v{current} → v{latest}\n"
+            ));
 
             if cmd == UpdateRunMode::CheckOnly {
                 return RunOutcome::Code(0);
@@ -165,17 +215,48 @@ fn run_with(ops: &mut impl UpdateCliOps, cmd: UpdateRunMode) -> RunOutcome {
     }
 }
 
-/// Run the update command.
+/// Run the update command against `channel`, or the configured channel
+/// when `None`.
 ///
 /// Returns the process exit code (0 = success, non-zero = error).
-pub fn run(cmd: UpdateRunMode) -> i32 {
-    let mut ops = RealUpdateCliOps::default();
+pub fn run(cmd: UpdateRunMode, channel: Option<UpdateChannel>) -> i32 {
+    let mut ops = RealUpdateCliOps::new(channel.unwrap_or_else(configured_channel));
     match run_with(&mut ops, cmd) {
         RunOutcome::Code(code) => code,
         RunOutcome::ExitSuccess => std::process::exit(0),
     }
 }
 
+fn rollback_with(ops: &mut impl UpdateCliOps) -> i32 {
+    let current_exe = match ops.current_exe() {
+        Ok(path) => path,
+        Err(error) => {
+            ops.write_stderr(&format!("Failed to locate current executable: {error}\n"));
+            return 1;
+        }
+    };
+    match ops.rollback_update(&current_exe) {
+        Ok(()) => {
+            ops.write_stdout(
+                "Restored the previous gwt. Restart gwt to use it; run `gwtd update rollback` again to undo.\n",
+            );
+            0
+        }
+        Err(error) => {
+            ops.write_stderr(&format!("Rollback failed: {error}\n"));
+            1
+        }
+    }
+}
+
+/// Swap the running binaries with the ones the last portable update kept.
+///
+/// Returns the process exit code (0 = success, non-zero = error).
+pub fn run_rollback() -> i32 {
+    let mut ops = RealUpdateCliOps::new(configured_channel());
+    rollback_with(&mut ops)
+}
+
 /// Parse `gwtd __internal apply-update` arguments and execute the internal update helper.
 ///
 /// argv format: `--old-pid <pid> --target <path> --source <path> --args-file <path>`
@@ -8,7 +8,9 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use gwt_core::update::{is_ci, InstallerKind, PreparedPayload, UpdateManager, UpdateState};
+use gwt_core::update::{
+    is_ci, InstallerKind, PreparedPayload, UpdateChannel, UpdateManager, UpdateState,
+};
 
 pub(super) trait UpdateCliOps {
     fn is_ci(&self) -> bool;
@@ -18,10 +20,12 @@ pub(super) trait UpdateCliOps {
     fn write_stdout(&mut self, text: &str);
     fn write_stderr(&mut self, text: &str);
     fn flush_stdout(&mut self) -> io::Result<()>;
+    fn channel(&self) -> UpdateChannel;
     fn check_for_executable(&mut self, force: bool, current_exe: Option<&Path>) -> UpdateState;
     fn prepare_update(&mut self, latest: &str, asset_url: &str) -> Result<PreparedPayload, String>;
     fn write_restart_args_file(&mut self, path: &Path, args: Vec<String>) -> Result<(), String>;
     fn make_helper_copy(&mut self, current_exe: &Path, latest: &str) -> Result<PathBuf, String>;
+    fn rollback_update(&mut self, current_exe: &Path) -> Result<(), String>;
     fn spawn_internal_apply_update(
         &mut self,
         helper_exe: &Path,
@@ -45,10 +49,10 @@ pub(super) struct RealUpdateCliOps {
     mgr: UpdateManager,
 }
 
-impl Default for RealUpdateCliOps {
-    fn default() -> Self {
+impl RealUpdateCliOps {
+    pub(super) fn new(channel: UpdateChannel) -> Self {
         Self {
-            mgr: UpdateManager::new(),
+            mgr: UpdateManager::new().with_channel(channel),
         }
     }
 }
@@ -82,6 +86,10 @@ impl UpdateCliOps for RealUpdateCliOps {
         io::stdout().flush()
     }
 
+    fn channel(&self) -> UpdateChannel {
+        self.mgr.channel()
+    }
+
     fn check_for_executable(&mut self, force: bool, current_exe: Option<&Path>) -> UpdateState {
         self.mgr.check_for_executable(force, current_exe)
     }
@@ -98,6 +106,22 @@ impl UpdateCliOps for RealUpdateCliOps {
         self.mgr.make_helper_copy(current_exe, latest)
     }
 
+    fn rollback_update(&mut self, current_exe: &Path) -> Result<(), String> {
+        let result = gwt_core::update::rollback_update(current_exe);
+        let outcome = match &result {
+            Ok(()) => "ok".to_string(),
+            Err(error) => error.clone(),
+        };
+        gwt_core::update::log_update_event(
+            "rollback",
+            &[
+                ("target", &current_exe.to_string_lossy()),
+                ("outcome", &outcome),
+            ],
+        );
+        result
+    }
+
     fn spawn_internal_apply_update(
         &mut self,
         helper_exe: &Path,
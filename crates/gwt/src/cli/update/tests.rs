@@ -11,13 +11,14 @@ use std::{
 
 use super::ops::UpdateCliOps;
 use super::*;
-use gwt_core::update::{InstallerKind, PreparedPayload, UpdateState};
+use gwt_core::update::{InstallerKind, PreparedPayload, UpdateChannel, UpdateState};
 
 // Mutex to serialize tests that mutate the process-global CI environment variable.
 static CI_ENV_MUTEX: Mutex<()> = Mutex::new(());
 
 struct FakeUpdateCliOps {
     is_ci: bool,
+    channel: UpdateChannel,
     state: UpdateState,
     current_exe: io::Result<PathBuf>,
     current_args: Vec<String>,
@@ -34,12 +35,15 @@ struct FakeUpdateCliOps {
     helper_copy_calls: Vec<(PathBuf, String)>,
     apply_calls: Vec<(PathBuf, u32, PathBuf, PathBuf, PathBuf)>,
     installer_calls: Vec<(PathBuf, u32, PathBuf, PathBuf, InstallerKind, PathBuf)>,
+    rollback_result: Result<(), String>,
+    rollback_calls: Vec<PathBuf>,
 }
 
 impl FakeUpdateCliOps {
     fn available(asset_url: Option<&str>) -> Self {
         Self {
             is_ci: false,
+            channel: UpdateChannel::Stable,
             state: UpdateState::Available {
                 current: "1.0.0".to_string(),
                 latest: "1.1.0".to_string(),
@@ -64,6 +68,8 @@ impl FakeUpdateCliOps {
             helper_copy_calls: Vec::new(),
             apply_calls: Vec::new(),
             installer_calls: Vec::new(),
+            rollback_result: Ok(()),
+            rollback_calls: Vec::new(),
         }
     }
 }
@@ -106,6 +112,10 @@ impl UpdateCliOps for FakeUpdateCliOps {
         Ok(())
     }
 
+    fn channel(&self) -> UpdateChannel {
+        self.channel
+    }
+
     fn check_for_executable(&mut self, _force: bool, _current_exe: Option<&Path>) -> UpdateState {
         self.state.clone()
     }
@@ -130,6 +140,11 @@ impl UpdateCliOps for FakeUpdateCliOps {
         self.make_helper_copy_result.clone()
     }
 
+    fn rollback_update(&mut self, current_exe: &Path) -> Result<(), String> {
+        self.rollback_calls.push(current_exe.to_path_buf());
+        self.rollback_result.clone()
+    }
+
     fn spawn_internal_apply_update(
         &mut self,
         helper_exe: &Path,
@@ -169,16 +184,47 @@ impl UpdateCliOps for FakeUpdateCliOps {
     }
 }
 
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
 #[test]
 fn parse_args_defaults_to_apply() {
-    let args: Vec<String> = vec![];
-    assert_eq!(parse_args(&args), UpdateRunMode::Apply);
+    assert_eq!(
+        parse_args(&[]).unwrap(),
+        UpdateCommand::Apply { channel: None }
+    );
 }
 
 #[test]
 fn parse_args_check_flag() {
-    let args = vec!["--check".to_string()];
-    assert_eq!(parse_args(&args), UpdateRunMode::CheckOnly);
+    assert_eq!(
+        parse_args(&args(&["--check"])).unwrap(),
+        UpdateCommand::CheckOnly { channel: None }
+    );
+}
+
+#[test]
+fn parse_args_channel_and_rollback() {
+    assert_eq!(
+        parse_args(&args(&["--channel", "beta", "--check"])).unwrap(),
+        UpdateCommand::CheckOnly {
+            channel: Some(UpdateChannel::Beta)
+        }
+    );
+    assert_eq!(
+        parse_args(&args(&["--channel", "nightly"])).unwrap(),
+        UpdateCommand::Apply {
+            channel: Some(UpdateChannel::Nightly)
+        }
+    );
+    assert_eq!(
+        parse_args(&args(&["rollback"])).unwrap(),
+        UpdateCommand::Rollback
+    );
+    assert!(parse_args(&args(&["--channel"])).is_err());
+    assert!(parse_args(&args(&["--channel", "canary"])).is_err());
+    assert!(parse_args(&args(&["rollback", "--check"])).is_err());
 }
 
 #[test]
@@ -199,7 +245,7 @@ fn run_check_only_returns_zero_in_ci() {
         .lock()
         .unwrap_or_else(std::sync::PoisonError::into_inner);
     std::env::set_var("CI", "true");
-    let code = run(UpdateRunMode::CheckOnly);
+    let code = run(UpdateRunMode::CheckOnly, None);
     std::env::remove_var("CI");
     assert_eq!(code, 0);
 }
@@ -210,7 +256,7 @@ fn run_apply_returns_zero_in_ci() {
         .lock()
         .unwrap_or_else(std::sync::PoisonError::into_inner);
     std::env::set_var("CI", "true");
-    let code = run(UpdateRunMode::Apply);
+    let code = run(UpdateRunMode::Apply, None);
     std::env::remove_var("CI");
     assert_eq!(code, 0);
 }
@@ -287,6 +333,34 @@ fn run_with_covers_ci_and_non_available_states() {
     assert!(failed.stderr.contains("network down"));
 }
 
+#[test]
+fn run_with_names_a_non_stable_channel() {
+    let mut beta = FakeUpdateCliOps::available(Some("https://example.test/gwt.zip"));
+    beta.channel = UpdateChannel::Beta;
+    assert!(matches!(
+        run_with(&mut beta, UpdateRunMode::CheckOnly),
+        RunOutcome::Code(0)
+    ));
+    assert!(beta
+        .stdout
+        .contains("Update available on the beta channel: v1.0.0 → v1.1.0"));
+}
+
+#[test]
+fn rollback_with_reports_success_and_failure() {
+    let mut ok = FakeUpdateCliOps::available(None);
+    assert_eq!(rollback_with(&mut ok), 0);
+    assert_eq!(ok.rollback_calls, vec![PathBuf::from("C:/gwt/gwt.exe")]);
+    assert!(ok.stdout.contains("Restored the previous gwt"));
+
+    let mut missing = FakeUpdateCliOps::available(None);
+    missing.rollback_result = Err("No previous version is kept".to_string());
+    assert_eq!(rollback_with(&mut missing), 1);
+    assert!(missing
+        .stderr
+        .contains("Rollback failed: No previous version"));
+}
+
 #[test]
 fn run_with_covers_check_only_cancel_and_missing_asset_paths() {
     let mut check_only = FakeUpdateCliOps::available(Some("https://example.test/gwt.zip"));
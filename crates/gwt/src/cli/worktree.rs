@@ -0,0 +1,565 @@
+//! `worktree.*` JSON operations: commands that act across the worktrees of
+//! the current repository.
+//!
+//! - `worktree.test_matrix` runs the repository test command in several
+//!   worktrees in parallel (bounded concurrency) and prints a pass/fail
+//!   matrix, so a flaky failure can be checked for branch specificity.
+//! - `worktree.archive` packs a branch's worktree, including uncommitted
+//!   changes, into the project's archive directory and removes it;
+//!   `worktree.restore` recreates it and `worktree.archives` lists archives.
+//! - `worktree.undo` restores the most recently removed worktree (of
+//!   `branch`, when given) from the `.gwt-trash/` staging area that branch
+//!   cleanup moves removed worktrees into; `worktree.removed` lists them.
+//! - `worktree.compare` compares the branches of a multi-agent fan-out
+//!   (commits, diffstat, optional test run, agent summary, token usage) and
+//!   can export the report as Markdown.
+//! - `worktree.transplant` starts a new branch from another branch's
+//!   uncommitted state without touching that branch.
+//! - `worktree.rename` previews or applies a pattern-based bulk branch
+//!   rename (`users/me/*` → `feature/*`), moving worktrees, upstreams and
+//!   gwt session / issue-link / notes metadata along with the branches.
+//! - `worktree.notes` shows or edits a branch's notes and tags and, with
+//!   `sync`, syncs them with the hidden section of the branch's PR
+//!   description.
+//! - `worktree.review` checks a ref out into a read-only review worktree
+//!   for review agents; `worktree.reviews` lists them and
+//!   `worktree.review_remove` deletes one without the usual safety checks.
+
+use std::path::{Path, PathBuf};
+
+use gwt_agent::comparison::{
+    build_comparison, read_session_insight, render_comparison_markdown, render_comparison_table,
+};
+use gwt_core::{branch_notes, process_executor::SystemProcessExecutor};
+use gwt_git::{
+    worktree_archive,
+    worktree_matrix::{
+        detect_test_command, render_matrix, resolve_matrix_targets, run_matrix,
+        DEFAULT_MATRIX_CONCURRENCY,
+    },
+    worktree_review, WorktreeManager,
+};
+use gwt_github::{client::ApiError, SpecOpsError};
+
+use super::CliEnv;
+
+/// Commands of the `worktree.*` JSON operation family.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorktreeCommand {
+    /// `worktree.test_matrix`.
+    TestMatrix {
+        /// Branches to test; empty selects every worktree branch.
+        branches: Vec<String>,
+        /// Command argv; empty detects the repository test command.
+        command: Vec<String>,
+        concurrency: Option<usize>,
+        /// Emit the structured report as JSON instead of a table.
+        json: bool,
+    },
+    /// `worktree.archive`.
+    Archive { branch: String },
+    /// `worktree.restore`.
+    Restore { branch: String },
+    /// `worktree.archives`.
+    Archives,
+    /// `worktree.undo`.
+    Undo {
+        /// Branch whose removal to undo; `None` undoes the latest removal.
+        branch: Option<String>,
+    },
+    /// `worktree.removed`.
+    Removed,
+    /// `worktree.transplant`.
+    Transplant {
+        /// Branch whose uncommitted changes are carried over.
+        from: String,
+        /// New branch to create from `from`'s HEAD.
+        branch: String,
+    },
+    /// `worktree.rename`.
+    Rename {
+        /// Source pattern; one `*` matches the carried-over part.
+        from: String,
+        /// Target pattern, with a `*` exactly when `from` has one.
+        to: String,
+        /// Perform the rename; otherwise only preview it.
+        apply: bool,
+    },
+    /// `worktree.notes`.
+    Notes {
+        branch: String,
+        /// Replace the note; `None` keeps the stored one.
+        note: Option<String>,
+        /// Replace the tags; `None` keeps the stored ones.
+        tags: Option<Vec<String>>,
+        /// Sync with the PR description after any edit.
+        sync: bool,
+    },
+    /// `worktree.review`.
+    Review {
+        /// Ref to review (branch, tag or commit).
+        reference: String,
+        /// Worktree location; defaults to the sibling path of
+        /// `review/<reference>`.
+        path: Option<PathBuf>,
+    },
+    /// `worktree.reviews`.
+    Reviews,
+    /// `worktree.review_remove`.
+    ReviewRemove {
+        /// Ref the review worktree was created from, or its path.
+        target: String,
+    },
+    /// `worktree.compare`.
+    Compare {
+        /// Branches to compare; empty selects every worktree branch except
+        /// the base.
+        branches: Vec<String>,
+        /// Base ref; defaults to the repository's default base branch.
+        base: Option<String>,
+        /// Run the test command in each worktree and include the outcome.
+        run_tests: bool,
+        /// Test command argv; empty detects the repository test command.
+        command: Vec<String>,
+        concurrency: Option<usize>,
+        /// Print the Markdown report instead of a table.
+        markdown: bool,
+        /// Also write the Markdown report to this file.
+        output: Option<PathBuf>,
+        /// Emit the structured report as JSON.
+        json: bool,
+    },
+}
+
+pub(super) fn run<E: CliEnv>(
+    env: &mut E,
+    command: WorktreeCommand,
+    out: &mut String,
+) -> Result<i32, SpecOpsError> {
+    match command {
+        WorktreeCommand::TestMatrix {
+            branches,
+            command,
+            concurrency,
+            json,
+        } => {
+            let worktree = gwt_core::paths::resolve_current_worktree_root(env.repo_path());
+            let command = if command.is_empty() {
+                detect_test_command(&worktree).ok_or_else(|| {
+                    unexpected(
+                        "worktree.test_matrix could not detect a test command; pass params.command",
+                    )
+                })?
+            } else {
+                command
+            };
+            let worktrees = WorktreeManager::new(&worktree)
+                .list()
+                .map_err(|err| unexpected(&err.to_string()))?;
+            let targets = resolve_matrix_targets(&worktrees, &branches);
+            if targets.is_empty() {
+                return Err(unexpected(
+                    "worktree.test_matrix found no worktrees with a branch checked out",
+                ));
+            }
+            let report = run_matrix(
+                &SystemProcessExecutor,
+                &targets,
+                &command,
+                concurrency.unwrap_or(DEFAULT_MATRIX_CONCURRENCY),
+            );
+            if json {
+                out.push_str(
+                    &serde_json::to_string_pretty(&report)
+                        .map_err(|err| unexpected(&err.to_string()))?,
+                );
+                out.push('\n');
+            } else {
+                out.push_str(&render_matrix(&report));
+            }
+            Ok(if report.all_passed() { 0 } else { 1 })
+        }
+        WorktreeCommand::Archive { branch } => {
+            let repo = env.repo_path().to_path_buf();
+            let archive = worktree_archive::archive_worktree(
+                &repo,
+                &worktree_archive::default_archive_dir(&repo),
+                &branch,
+            )
+            .map_err(|err| unexpected(&err.to_string()))?;
+            out.push_str(&format!(
+                "Archived {} ({}) to {}\n",
+                archive.branch,
+                archive.worktree_path.display(),
+                archive.archive_file
+            ));
+            Ok(0)
+        }
+        WorktreeCommand::Restore { branch } => {
+            let repo = env.repo_path().to_path_buf();
+            let path = worktree_archive::restore_worktree(
+                &repo,
+                &worktree_archive::default_archive_dir(&repo),
+                &branch,
+            )
+            .map_err(|err| unexpected(&err.to_string()))?;
+            out.push_str(&format!("Restored {branch} at {}\n", path.display()));
+            Ok(0)
+        }
+        WorktreeCommand::Undo { branch } => {
+            let message =
+                crate::worktree_trash::undo_worktree_removal(env.repo_path(), branch.as_deref())
+                    .map_err(|err| unexpected(&err))?;
+            out.push_str(&format!("{message}\n"));
+            Ok(0)
+        }
+        WorktreeCommand::Removed => {
+            let entries = crate::worktree_trash::removed_worktrees(env.repo_path())
+                .map_err(|err| unexpected(&err))?;
+            for entry in entries {
+                out.push_str(&format!(
+                    "{}\t{}\t{}\n",
+                    entry.branch,
+                    entry.removed_at,
+                    entry.worktree_path.display()
+                ));
+            }
+            Ok(0)
+        }
+        WorktreeCommand::Review { reference, path } => {
+            let repo = env.repo_path().to_path_buf();
+            let path =
+                path.unwrap_or_else(|| worktree_review::review_worktree_path(&repo, &reference));
+            let review = worktree_review::create_review_worktree(
+                &repo,
+                &worktree_review::default_review_dir(&repo),
+                &reference,
+                &path,
+            )
+            .map_err(|err| unexpected(&err.to_string()))?;
+            out.push_str(&format!(
+                "Review worktree for {} ({}) at {} (read-only)\n",
+                review.reference,
+                short_sha(&review.head),
+                review.worktree_path.display()
+            ));
+            Ok(0)
+        }
+        WorktreeCommand::Reviews => {
+            let reviews = worktree_review::list_review_worktrees(
+                &worktree_review::default_review_dir(env.repo_path()),
+            )
+            .map_err(|err| unexpected(&err.to_string()))?;
+            for review in reviews {
+                out.push_str(&format!(
+                    "{}\t{}\t{}\t{}\n",
+                    review.reference,
+                    short_sha(&review.head),
+                    review.created_at,
+                    review.worktree_path.display()
+                ));
+            }
+            Ok(0)
+        }
+        WorktreeCommand::ReviewRemove { target } => {
+            let repo = env.repo_path().to_path_buf();
+            let review = worktree_review::remove_review_worktree(
+                &repo,
+                &worktree_review::default_review_dir(&repo),
+                &target,
+            )
+            .map_err(|err| unexpected(&err.to_string()))?;
+            out.push_str(&format!(
+                "Removed review worktree {}\n",
+                review.worktree_path.display()
+            ));
+            Ok(0)
+        }
+        WorktreeCommand::Transplant { from, branch } => {
+            let outcome = crate::worktree_transplant::transplant_project_worktree(
+                env.repo_path(),
+                &from,
+                &branch,
+            )
+            .map_err(|err| unexpected(&err))?;
+            out.push_str(&outcome.summary());
+            out.push('\n');
+            Ok(0)
+        }
+        WorktreeCommand::Rename { from, to, apply } => {
+            let outcome =
+                crate::branch_rename::rename_project_branches(env.repo_path(), &from, &to, apply)
+                    .map_err(|err| unexpected(&err))?;
+            out.push_str(&outcome.summary());
+            Ok(if outcome.plan.conflicts.is_empty() {
+                0
+            } else {
+                1
+            })
+        }
+        WorktreeCommand::Notes {
+            branch,
+            note,
+            tags,
+            sync,
+        } => {
+            let path = crate::branch_notes_sync::branch_notes_path(env.repo_path());
+            let mut notes = branch_notes::load_branch_notes(&path, &branch)
+                .map_err(|err| unexpected(&err.to_string()))?;
+            if note.is_some() || tags.is_some() {
+                let current = notes.take();
+                let edited = branch_notes::BranchNotes::new(
+                    note.unwrap_or_else(|| {
+                        current
+                            .as_ref()
+                            .map(|notes| notes.note.clone())
+                            .unwrap_or_default()
+                    }),
+                    tags.unwrap_or_else(|| current.map(|notes| notes.tags).unwrap_or_default()),
+                );
+                branch_notes::save_branch_notes(&path, &branch, &edited)
+                    .map_err(|err| unexpected(&err.to_string()))?;
+                notes = Some(edited);
+            }
+            if sync {
+                let outcome = crate::branch_notes_sync::sync_branch_notes(env.repo_path(), &branch)
+                    .map_err(|err| unexpected(&err))?;
+                out.push_str(&outcome.summary());
+                out.push('\n');
+                notes = outcome.notes;
+            }
+            out.push_str(&render_branch_notes(&branch, notes.as_ref()));
+            Ok(0)
+        }
+        WorktreeCommand::Compare {
+            branches,
+            base,
+            run_tests,
+            command,
+            concurrency,
+            markdown,
+            output,
+            json,
+        } => {
+            let worktree = gwt_core::paths::resolve_current_worktree_root(env.repo_path());
+            let base = base.unwrap_or_else(|| {
+                crate::issue_monitor_worker::resolve_default_base_branch(&worktree)
+            });
+            let worktrees = WorktreeManager::new(&worktree)
+                .list()
+                .map_err(|err| unexpected(&err.to_string()))?;
+            let mut targets = resolve_matrix_targets(&worktrees, &branches);
+            if branches.is_empty() {
+                targets.retain(|target| target.branch != base);
+            }
+            if targets.is_empty() {
+                return Err(unexpected(
+                    "worktree.compare found no branches to compare; pass params.branches",
+                ));
+            }
+            let tests = if run_tests {
+                let command = if command.is_empty() {
+                    detect_test_command(&worktree).ok_or_else(|| {
+                        unexpected(
+                            "worktree.compare could not detect a test command; pass params.command",
+                        )
+                    })?
+                } else {
+                    command
+                };
+                Some(run_matrix(
+                    &SystemProcessExecutor,
+                    &targets,
+                    &command,
+                    concurrency.unwrap_or(DEFAULT_MATRIX_CONCURRENCY),
+                ))
+            } else {
+                None
+            };
+            let sessions = load_sessions(&gwt_core::paths::gwt_sessions_dir());
+            let report = build_comparison(
+                &worktree,
+                &base,
+                &targets,
+                &sessions,
+                tests.as_ref(),
+                read_session_insight,
+            );
+            let rendered_markdown = render_comparison_markdown(&report);
+            if let Some(path) = &output {
+                std::fs::write(path, &rendered_markdown)
+                    .map_err(|err| unexpected(&format!("{}: {err}", path.display())))?;
+            }
+            if json {
+                out.push_str(
+                    &serde_json::to_string_pretty(&report)
+                        .map_err(|err| unexpected(&err.to_string()))?,
+                );
+                out.push('\n');
+            } else if markdown {
+                out.push_str(&rendered_markdown);
+            } else {
+                out.push_str(&render_comparison_table(&report));
+            }
+            if let Some(path) = &output {
+                out.push_str(&format!("Wrote {}\n", path.display()));
+            }
+            Ok(0)
+        }
+        WorktreeCommand::Archives => {
+            let archives = worktree_archive::list_archives(&worktree_archive::default_archive_dir(
+                env.repo_path(),
+            ))
+            .map_err(|err| unexpected(&err.to_string()))?;
+            for archive in archives {
+                out.push_str(&format!(
+                    "{}\t{}\t{}\n",
+                    archive.branch,
+                    archive.archived_at,
+                    archive.worktree_path.display()
+                ));
+            }
+            Ok(0)
+        }
+    }
+}
+
+fn render_branch_notes(branch: &str, notes: Option<&branch_notes::BranchNotes>) -> String {
+    match notes.filter(|notes| !notes.is_empty()) {
+        None => format!("{branch}: no notes\n"),
+        Some(notes) => {
+            let mut out = format!("{branch} (updated {})\n", notes.updated_at.to_rfc3339());
+            if !notes.tags.is_empty() {
+                out.push_str(&format!("Tags: {}\n", notes.tags.join(", ")));
+            }
+            if !notes.note.is_empty() {
+                out.push_str(&notes.note);
+                out.push('\n');
+            }
+            out
+        }
+    }
+}
+
+fn short_sha(sha: &str) -> &str {
+    &sha[..sha.len().min(7)]
+}
+
+fn load_sessions(sessions_dir: &Path) -> Vec<gwt_agent::Session> {
+    let Ok(entries) = std::fs::read_dir(sessions_dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("toml"))
+        .filter_map(|path| gwt_agent::Session::load_and_migrate(&path).ok())
+        .collect()
+}
+
+fn unexpected(message: &str) -> SpecOpsError {
+    SpecOpsError::from(ApiError::Unexpected(message.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::TestEnv;
+
+    fn git(dir: &std::path::Path, args: &[&str]) {
+        let output = gwt_core::process::hidden_command("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {args:?}");
+    }
+
+    #[test]
+    fn test_matrix_runs_command_in_each_selected_worktree() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--initial-branch=main"]);
+        git(&repo, &["config", "user.email", "t@example.com"]);
+        git(&repo, &["config", "user.name", "T"]);
+        git(&repo, &["commit", "--allow-empty", "-m", "init"]);
+        let feature = tmp.path().join("feature");
+        git(
+            &repo,
+            &[
+                "worktree",
+                "add",
+                "-b",
+                "feature/x",
+                feature.to_str().unwrap(),
+            ],
+        );
+        std::fs::write(feature.join("marker"), "").unwrap();
+
+        let mut env = TestEnv::new(repo.clone());
+        let mut out = String::new();
+        let code = run(
+            &mut env,
+            WorktreeCommand::TestMatrix {
+                branches: Vec::new(),
+                command: vec!["test".into(), "-f".into(), "marker".into()],
+                concurrency: Some(2),
+                json: false,
+            },
+            &mut out,
+        )
+        .unwrap();
+
+        assert_eq!(code, 1, "{out}");
+        assert!(out.contains("(1/2 passed)"), "{out}");
+        assert!(out.contains("feature/x | PASS"), "{out}");
+        assert!(out.contains("main      | FAIL"), "{out}");
+    }
+
+    #[test]
+    fn compare_reports_every_non_base_worktree_and_exports_markdown() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--initial-branch=main"]);
+        git(&repo, &["config", "user.email", "t@example.com"]);
+        git(&repo, &["config", "user.name", "T"]);
+        git(&repo, &["commit", "--allow-empty", "-m", "init"]);
+        let attempt = tmp.path().join("attempt");
+        git(
+            &repo,
+            &["worktree", "add", "-b", "try/a", attempt.to_str().unwrap()],
+        );
+        std::fs::write(attempt.join("marker"), "x\n").unwrap();
+        git(&attempt, &["add", "marker"]);
+        git(&attempt, &["commit", "-m", "attempt"]);
+        let export = tmp.path().join("report.md");
+
+        let mut env = TestEnv::new(repo.clone());
+        let mut out = String::new();
+        let code = run(
+            &mut env,
+            WorktreeCommand::Compare {
+                branches: Vec::new(),
+                base: Some("main".into()),
+                run_tests: true,
+                command: vec!["test".into(), "-f".into(), "marker".into()],
+                concurrency: Some(1),
+                markdown: false,
+                output: Some(export.clone()),
+                json: false,
+            },
+            &mut out,
+        )
+        .unwrap();
+
+        assert_eq!(code, 0, "{out}");
+        assert!(out.contains("against main (1 branches)"), "{out}");
+        assert!(out.contains("try/a"), "{out}");
+        assert!(out.contains("1 files +1 -0 | PASS"), "{out}");
+        let markdown = std::fs::read_to_string(&export).unwrap();
+        assert!(markdown.starts_with("# Agent comparison against `main`"));
+        assert!(markdown.contains("## `try/a`"));
+    }
+}
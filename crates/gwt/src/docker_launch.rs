@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use super::*;
 
 pub fn detect_wizard_docker_context_and_status(
@@ -24,15 +26,34 @@ pub fn detect_wizard_docker_context_and_status(
     let suggested_service = docker_devcontainer_defaults(project_root, &files)
         .and_then(|defaults| defaults.service)
         .or_else(|| services.first().map(|service| service.name.clone()));
+    let profiles = gwt_docker::compose_profiles(&services);
     (
         Some(DockerWizardContext {
             services: services.into_iter().map(|service| service.name).collect(),
             suggested_service,
+            profiles,
         }),
         gwt_docker::ComposeServiceStatus::Unknown,
     )
 }
 
+/// First `[[launch_rules]]` entry that holds for `branch`. Changed files are
+/// read against `origin/<default_base_branch>`, falling back to the local
+/// base branch, and only when some rule looks at them.
+pub fn launch_rule_for_branch(repo_path: &Path, branch: &str) -> Option<gwt_config::LaunchRule> {
+    let settings = gwt_config::Settings::load().ok()?;
+    let base = settings.default_base_branch.as_str();
+    gwt_config::first_matching_rule(&settings.launch_rules, branch, || {
+        [format!("origin/{base}"), base.to_string()]
+            .iter()
+            .find_map(|base_ref| {
+                gwt_git::diff::branch_changed_files(repo_path, base_ref, branch).ok()
+            })
+            .unwrap_or_default()
+    })
+    .cloned()
+}
+
 #[derive(Debug, Clone)]
 pub struct DockerLaunchPlan {
     pub(crate) compose_files: Vec<PathBuf>,
@@ -40,6 +61,13 @@ pub struct DockerLaunchPlan {
     pub(crate) override_file: PathBuf,
     pub(crate) service: String,
     pub(crate) container_cwd: String,
+    pub(crate) profiles: Vec<String>,
+    pub(crate) remote_env: BTreeMap<String, String>,
+    pub(crate) post_create_command: Option<Vec<String>>,
+    pub(crate) features: Vec<String>,
+    /// Workspace folder handed to the devcontainer CLI when the launch runs
+    /// through `devcontainer up` / `exec` instead of `docker compose`.
+    pub(crate) devcontainer_workspace: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -65,6 +93,9 @@ pub struct DevContainerLaunchDefaults {
     pub(crate) service: Option<String>,
     pub(crate) workspace_folder: Option<String>,
     pub(crate) compose_files: Vec<PathBuf>,
+    pub(crate) remote_env: BTreeMap<String, String>,
+    pub(crate) post_create_command: Option<Vec<String>>,
+    pub(crate) features: Vec<String>,
 }
 
 impl DockerLaunchPlan {
@@ -91,11 +122,28 @@ pub fn apply_docker_runtime_to_launch_config(
             .clone()
             .unwrap_or_else(|| repo_path.to_path_buf()),
     );
-    let launch = resolve_docker_launch_plan(&worktree, config.docker_service.as_deref())?;
+    let launch = resolve_docker_launch_plan(
+        &worktree,
+        config.docker_service.as_deref(),
+        config.docker_profile.as_deref(),
+    )?;
     ensure_docker_launch_runtime_ready()?;
-    ensure_docker_gwt_binary_setup(&launch)?;
+    let mut launch = launch;
+    // The devcontainer CLI owns its container: gwt's compose overrides do
+    // not apply to it, and it installs features itself.
+    if launch.devcontainer_workspace.is_none() {
+        ensure_docker_gwt_binary_setup(&launch)?;
+        ensure_docker_port_override(&worktree, &mut launch)?;
+        warn_unapplied_devcontainer_features(&launch);
+    }
     ensure_docker_launch_service_ready(&launch, config.docker_lifecycle_intent)?;
     maybe_inject_docker_sandbox_env(&launch, config)?;
+    for (key, value) in &launch.remote_env {
+        config
+            .env_vars
+            .entry(key.clone())
+            .or_insert_with(|| value.clone());
+    }
     install_launch_gwt_bin_env(&mut config.env_vars, gwt_agent::LaunchRuntimeTarget::Docker)?;
     let runtime_program = resolve_docker_exec_program(&launch, config)?;
     config.command = runtime_program.executable;
@@ -121,17 +169,33 @@ pub fn finalize_docker_agent_launch_config(
             .clone()
             .unwrap_or_else(|| repo_path.to_path_buf()),
     );
-    let launch = resolve_docker_launch_plan(&worktree, config.docker_service.as_deref())?;
+    let launch = resolve_docker_launch_plan(
+        &worktree,
+        config.docker_service.as_deref(),
+        config.docker_profile.as_deref(),
+    )?;
     let runtime_program = PackageRunnerProgram {
         executable: config.command.clone(),
         args: config.args.clone(),
     };
 
-    let mut args = vec!["compose".to_string()];
-    for compose_file in launch.compose_files_for_runtime() {
-        args.push("-f".to_string());
-        args.push(compose_file.display().to_string());
+    if let Some(workspace) = &launch.devcontainer_workspace {
+        let mut command = vec![runtime_program.executable];
+        command.extend(runtime_program.args);
+        config.command = devcontainer_binary_for_launch();
+        config.args = gwt_docker::devcontainer_exec_args(
+            workspace,
+            docker_exec_env(&config.env_vars),
+            &command,
+        );
+        return Ok(());
     }
+
+    let mut args = vec!["compose".to_string()];
+    args.extend(gwt_docker::compose_project_args(
+        &launch.compose_files_for_runtime(),
+        &launch.profiles,
+    ));
     args.extend(["exec".to_string(), "-w".to_string(), launch.container_cwd]);
     args.extend(docker_compose_exec_env_args(&config.env_vars));
     args.push(launch.service);
@@ -182,6 +246,22 @@ pub fn docker_bundle_override_content(service: &str, bundle: &DockerBundleMounts
     )
 }
 
+/// Give the worktree its own host ports (see [`gwt_agent::docker_ports`])
+/// and include the generated port override in the launch.
+pub fn ensure_docker_port_override(
+    worktree: &Path,
+    launch: &mut DockerLaunchPlan,
+) -> Result<(), String> {
+    if let Some(port_override_file) =
+        gwt_agent::ensure_docker_port_override(worktree, &launch.compose_files_for_runtime())?
+    {
+        if !launch.compose_files.contains(&port_override_file) {
+            launch.compose_files.push(port_override_file);
+        }
+    }
+    Ok(())
+}
+
 pub fn ensure_docker_gwt_binary_setup(launch: &DockerLaunchPlan) -> Result<(), String> {
     use std::fs;
 
@@ -234,10 +314,20 @@ fn maybe_inject_docker_sandbox_env(
         return Ok(());
     }
 
-    let is_root = gwt_docker::compose_service_user_is_root_with_files(
-        &launch.compose_files_for_runtime(),
-        &launch.service,
-    )
+    let is_root = match &launch.devcontainer_workspace {
+        Some(workspace) => gwt_docker::devcontainer_exec_capture(
+            workspace,
+            &["sh".to_string(), "-lc".to_string(), "id -u".to_string()],
+        )
+        .map(|output| {
+            output.status.success() && String::from_utf8_lossy(&output.stdout).trim() == "0"
+        }),
+        None => gwt_docker::compose_service_user_is_root_with_files(
+            &launch.compose_files_for_runtime(),
+            &launch.profiles,
+            &launch.service,
+        ),
+    }
     .map_err(|err| {
         format!(
             "Failed to determine Docker user for service '{}': {err}",
@@ -253,13 +343,23 @@ fn maybe_inject_docker_sandbox_env(
 }
 
 pub fn docker_compose_exec_env_args(env_vars: &HashMap<String, String>) -> Vec<String> {
+    let mut args = Vec::new();
+    for (key, value) in docker_exec_env(env_vars) {
+        args.push("-e".to_string());
+        args.push(format!("{key}={value}"));
+    }
+    args
+}
+
+/// Host env entries forwarded into the container, sorted by key.
+pub fn docker_exec_env(env_vars: &HashMap<String, String>) -> Vec<(&str, &str)> {
     let mut keys = env_vars.keys().collect::<Vec<_>>();
     keys.sort();
 
-    let mut args = Vec::new();
+    let mut env = Vec::new();
     for key in keys {
-        let key = key.trim();
-        if key.is_empty() || !is_valid_docker_env_key(key) {
+        let trimmed = key.trim();
+        if trimmed.is_empty() || !is_valid_docker_env_key(trimmed) {
             continue;
         }
         // Never override the container's PATH: the image PATH carries the
@@ -267,14 +367,12 @@ pub fn docker_compose_exec_env_args(env_vars: &HashMap<String, String>) -> Vec<S
         // Injecting the host-assembled PATH made the post-probe agent exec
         // fail with `exec: "bunx": executable file not found in $PATH`
         // while the env-free probe succeeded (Issue #3029).
-        if key.eq_ignore_ascii_case("PATH") {
+        if trimmed.eq_ignore_ascii_case("PATH") {
             continue;
         }
-        let value = env_vars.get(key).map(String::as_str).unwrap_or_default();
-        args.push("-e".to_string());
-        args.push(format!("{key}={value}"));
+        env.push((trimmed, env_vars[key].as_str()));
     }
-    args
+    env
 }
 
 pub fn is_valid_docker_env_key(key: &str) -> bool {
@@ -331,12 +429,18 @@ fn resolve_docker_package_runner(
     ];
 
     for candidate in candidates {
-        let output = gwt_docker::compose_service_exec_capture_with_files(
-            &launch.compose_files_for_runtime(),
-            &launch.service,
-            Some(&launch.container_cwd),
-            &candidate.probe_args(),
-        )
+        let output = match &launch.devcontainer_workspace {
+            Some(workspace) => {
+                gwt_docker::devcontainer_exec_capture(workspace, &candidate.probe_args())
+            }
+            None => gwt_docker::compose_service_exec_capture_with_files(
+                &launch.compose_files_for_runtime(),
+                &launch.profiles,
+                &launch.service,
+                Some(&launch.container_cwd),
+                &candidate.probe_args(),
+            ),
+        }
         .map_err(|err| err.to_string())?;
         if output.status.success() {
             return Ok(candidate.into_exec_program(agent_args));
@@ -363,13 +467,7 @@ pub fn strip_package_runner_args(args: &[String], version_spec: &str) -> Vec<Str
 
 pub fn resolve_docker_shell_command(launch: &DockerLaunchPlan) -> Result<String, String> {
     for candidate in ["bash", "sh"] {
-        let available = gwt_docker::compose_service_has_command_with_files(
-            &launch.compose_files_for_runtime(),
-            &launch.service,
-            candidate,
-        )
-        .map_err(|err| err.to_string())?;
-        if available {
+        if docker_launch_has_command(launch, candidate)? {
             return Ok(candidate.to_string());
         }
     }
@@ -384,13 +482,7 @@ fn ensure_docker_launch_command_ready(
     launch: &DockerLaunchPlan,
     command: &str,
 ) -> Result<(), String> {
-    let available = gwt_docker::compose_service_has_command_with_files(
-        &launch.compose_files_for_runtime(),
-        &launch.service,
-        command,
-    )
-    .map_err(|err| err.to_string())?;
-    if available {
+    if docker_launch_has_command(launch, command)? {
         Ok(())
     } else {
         Err(format!(
@@ -400,6 +492,19 @@ fn ensure_docker_launch_command_ready(
     }
 }
 
+fn docker_launch_has_command(launch: &DockerLaunchPlan, command: &str) -> Result<bool, String> {
+    match &launch.devcontainer_workspace {
+        Some(workspace) => gwt_docker::devcontainer_has_command(workspace, command),
+        None => gwt_docker::compose_service_has_command_with_files(
+            &launch.compose_files_for_runtime(),
+            &launch.profiles,
+            &launch.service,
+            command,
+        ),
+    }
+    .map_err(|err| err.to_string())
+}
+
 impl DockerPackageRunnerCandidate {
     fn probe_args(&self) -> Vec<String> {
         let mut args = vec![self.executable.to_string()];
@@ -422,25 +527,114 @@ pub fn ensure_docker_launch_service_ready(
     launch: &DockerLaunchPlan,
     intent: gwt_agent::DockerLifecycleIntent,
 ) -> Result<(), String> {
+    if let Some(workspace) = &launch.devcontainer_workspace {
+        return devcontainer_cli_up(workspace, intent);
+    }
     let compose_files = launch.compose_files_for_runtime();
-    let status = gwt_docker::compose_service_status_with_files(&compose_files, &launch.service)
-        .map_err(|err| err.to_string())?;
-    match normalize_docker_launch_action(intent, status) {
-        DockerLaunchServiceAction::Connect => Ok(()),
+    let status = gwt_docker::compose_service_status_with_files(
+        &compose_files,
+        &launch.profiles,
+        &launch.service,
+    )
+    .map_err(|err| err.to_string())?;
+    let action = normalize_docker_launch_action(intent, status);
+    match action {
+        DockerLaunchServiceAction::Connect => return Ok(()),
         DockerLaunchServiceAction::Start => {
-            gwt_docker::compose_up_with_files(&compose_files, &launch.service)
+            gwt_docker::compose_up_with_files(&compose_files, &launch.profiles, &launch.service)
                 .map_err(|err| err.to_string())?;
-            Ok(())
         }
         DockerLaunchServiceAction::Restart => {
-            gwt_docker::compose_restart_with_files(&compose_files, &launch.service)
-                .map_err(|err| err.to_string())
+            gwt_docker::compose_restart_with_files(
+                &compose_files,
+                &launch.profiles,
+                &launch.service,
+            )
+            .map_err(|err| err.to_string())?;
         }
         DockerLaunchServiceAction::Recreate => {
-            gwt_docker::compose_up_force_recreate_with_files(&compose_files, &launch.service)
-                .map_err(|err| err.to_string())
+            gwt_docker::compose_up_force_recreate_with_files(
+                &compose_files,
+                &launch.profiles,
+                &launch.service,
+            )
+            .map_err(|err| err.to_string())?;
         }
     }
+    if docker_launch_creates_container(action, status) {
+        run_devcontainer_post_create(launch, &compose_files)?;
+    }
+    Ok(())
+}
+
+/// `devcontainer up` reuses a running container and starts a stopped one,
+/// so only a recreate needs a flag. The gwtd bundle is bind-mounted like
+/// the compose override does.
+fn devcontainer_cli_up(
+    workspace: &Path,
+    intent: gwt_agent::DockerLifecycleIntent,
+) -> Result<(), String> {
+    let mut mounts = Vec::new();
+    if let Ok(home) = resolve_user_home_dir() {
+        let bundle = docker_bundle_mounts_for_home(&home);
+        if bundle.host_gwtd.exists() {
+            mounts.push(format!(
+                "type=bind,source={},target={DOCKER_GWTD_BIN_PATH}",
+                docker_compose_mount_path(&bundle.host_gwtd)
+            ));
+        }
+    }
+    let remove_existing = intent == gwt_agent::DockerLifecycleIntent::Recreate;
+    eprintln!("Starting devcontainer with the devcontainer CLI");
+    gwt_docker::devcontainer_up(workspace, remove_existing, &mounts)
+        .map(|_| ())
+        .map_err(|err| err.to_string())
+}
+
+/// Whether `action` leaves a freshly created container, which is when the
+/// devcontainer `postCreateCommand` runs.
+fn docker_launch_creates_container(
+    action: DockerLaunchServiceAction,
+    status: gwt_docker::ComposeServiceStatus,
+) -> bool {
+    match action {
+        DockerLaunchServiceAction::Recreate => true,
+        DockerLaunchServiceAction::Start => status == gwt_docker::ComposeServiceStatus::NotFound,
+        DockerLaunchServiceAction::Connect | DockerLaunchServiceAction::Restart => false,
+    }
+}
+
+fn run_devcontainer_post_create(
+    launch: &DockerLaunchPlan,
+    compose_files: &[PathBuf],
+) -> Result<(), String> {
+    let Some(args) = launch.post_create_command.as_deref() else {
+        return Ok(());
+    };
+    eprintln!(
+        "Running devcontainer postCreateCommand in service '{}'",
+        launch.service
+    );
+    gwt_docker::compose_service_run_lifecycle_command_with_files(
+        compose_files,
+        &launch.profiles,
+        &launch.service,
+        Some(&launch.container_cwd),
+        args,
+    )
+    .map_err(|err| format!("devcontainer postCreateCommand failed: {err}"))
+}
+
+fn warn_unapplied_devcontainer_features(launch: &DockerLaunchPlan) {
+    if launch.features.is_empty() {
+        return;
+    }
+    tracing::warn!(
+        category = "docker",
+        service = %launch.service,
+        features = %launch.features.join(", "),
+        "devcontainer features are not installed by gwt; prebuild the image with `devcontainer build`"
+    );
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -560,6 +754,7 @@ fn looks_like_relative_bind_mount(source: &str) -> bool {
 pub fn resolve_docker_launch_plan(
     worktree: &Path,
     selected_service: Option<&str>,
+    selected_profile: Option<&str>,
 ) -> Result<DockerLaunchPlan, String> {
     let files = gwt_docker::detect_docker_files(worktree);
     let compose_files = docker_compose_files_for_launch(worktree, &files)?;
@@ -610,19 +805,71 @@ pub fn resolve_docker_launch_plan(
             )
         })?;
 
+    let profiles = docker_launch_profiles(&services, service, selected_profile)?;
+    // Prefer the devcontainer CLI for the devcontainer's own service; it
+    // applies remoteEnv, lifecycle commands, and features itself.
+    let devcontainer_workspace = devcontainer_defaults
+        .as_ref()
+        .filter(|defaults| defaults.service.as_deref() == Some(service.name.as_str()))
+        .filter(|_| gwt_docker::devcontainer_cli_available())
+        .map(|_| worktree.to_path_buf());
+    let mut devcontainer_defaults = devcontainer_defaults.unwrap_or_default();
+    if devcontainer_workspace.is_some() {
+        devcontainer_defaults.remote_env.clear();
+        devcontainer_defaults.post_create_command = None;
+        devcontainer_defaults.features.clear();
+    }
     Ok(DockerLaunchPlan {
         compose_files,
         compose_file,
         override_file: worktree.join("docker-compose.override.yml"),
         service: service.name.clone(),
         container_cwd,
+        profiles,
+        remote_env: devcontainer_defaults.remote_env,
+        post_create_command: devcontainer_defaults.post_create_command,
+        features: devcontainer_defaults.features,
+        devcontainer_workspace,
     })
 }
 
+/// Compose profiles to activate: the selected one plus any the service itself
+/// belongs to, so `ps`/`exec` see the service even without a selection.
+fn docker_launch_profiles(
+    services: &[gwt_docker::ComposeService],
+    service: &gwt_docker::ComposeService,
+    selected_profile: Option<&str>,
+) -> Result<Vec<String>, String> {
+    let mut profiles = Vec::new();
+    if let Some(profile) = selected_profile.filter(|profile| !profile.is_empty()) {
+        if !gwt_docker::compose_profiles(services)
+            .iter()
+            .any(|known| known == profile)
+        {
+            return Err(format!(
+                "Selected Docker profile was not found in compose file: {profile}"
+            ));
+        }
+        profiles.push(profile.to_string());
+    }
+    for profile in &service.profiles {
+        if !profiles.contains(profile) {
+            profiles.push(profile.clone());
+        }
+    }
+    Ok(profiles)
+}
+
 pub fn docker_binary_for_launch() -> String {
     std::env::var("GWT_DOCKER_BIN").unwrap_or_else(|_| "docker".to_string())
 }
 
+pub fn devcontainer_binary_for_launch() -> String {
+    gwt_docker::devcontainer_cli::devcontainer_binary()
+        .to_string_lossy()
+        .into_owned()
+}
+
 pub fn docker_compose_files_for_launch(
     project_root: &Path,
     files: &gwt_docker::DockerFiles,
@@ -657,20 +904,24 @@ pub fn docker_devcontainer_defaults(
 
     let config = gwt_docker::DevContainerConfig::load(&path).ok()?;
     let mut compose_files = config
-        .docker_compose_file
-        .as_ref()
-        .map(|value| {
-            value
-                .to_vec()
-                .into_iter()
-                .map(|candidate| {
-                    let joined = devcontainer_dir.join(candidate);
-                    dunce::canonicalize(&joined).unwrap_or(joined)
-                })
-                .filter(|path| path.is_file())
-                .collect::<Vec<_>>()
-        })
-        .unwrap_or_default();
+        .compose_files(devcontainer_dir)
+        .into_iter()
+        .map(|path| dunce::canonicalize(&path).unwrap_or(path))
+        .collect::<Vec<_>>();
+    let generated_compose = config
+        .write_generated_compose(devcontainer_dir, project_root)
+        .unwrap_or_else(|err| {
+            tracing::warn!(category = "docker", error = %err, "devcontainer compose generation failed");
+            None
+        });
+    let service = config.service.clone().or_else(|| {
+        generated_compose
+            .as_ref()
+            .map(|_| gwt_docker::DEVCONTAINER_SERVICE_NAME.to_string())
+    });
+    if compose_files.is_empty() {
+        compose_files.extend(generated_compose);
+    }
     if compose_files.is_empty() {
         if let Some(compose_file) = files.compose_file.clone() {
             compose_files.push(compose_file);
@@ -682,9 +933,13 @@ pub fn docker_devcontainer_defaults(
         }
     }
 
+    let workspace_folder = config.container_workspace_folder(project_root);
     Some(DevContainerLaunchDefaults {
-        service: config.service,
-        workspace_folder: config.workspace_folder,
+        service,
+        remote_env: config.remote_env_vars(project_root, workspace_folder.as_deref()),
+        post_create_command: config.post_create_args(),
+        features: config.feature_ids(),
+        workspace_folder,
         compose_files,
     })
 }
@@ -10,19 +10,22 @@ use axum::{
     extract::{
         connect_info::ConnectInfo,
         ws::{Message, WebSocket, WebSocketUpgrade},
-        Query, Request, State,
+        Path, Query, Request, State,
     },
     http::{
         header::{AUTHORIZATION, HOST, ORIGIN, USER_AGENT},
         HeaderMap, StatusCode,
     },
     middleware::{self, Next},
-    response::{Html, IntoResponse, Response},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        Html, IntoResponse, Response,
+    },
     routing::{get, post},
     Json, Router,
 };
 use futures_util::{SinkExt, StreamExt};
-use gwt::{FrontendEvent, HookForwardTarget, RuntimeHookEvent};
+use gwt::{repo_registry::ListQuery, FrontendEvent, HookForwardTarget, RuntimeHookEvent};
 use gwt_terminal::PtyHandle;
 use serde::{Deserialize, Serialize};
 use tokio::{io::AsyncWriteExt, net::TcpListener, runtime::Runtime, sync::oneshot};
@@ -512,6 +515,9 @@ struct ServerState {
     attachment_upload_token: String,
     attachment_uploads: AttachmentUploadStore,
     pty_writers: PtyWriterRegistry,
+    /// `--read-only` / `[server] read_only`: drop mutating frontend events
+    /// and refuse attachment uploads.
+    read_only: bool,
     // Held only so the in-process sink stays alive for the lifetime of the
     // server. Read directly through [`EmbeddedServer::access_log`] in tests.
     #[allow(dead_code)]
@@ -582,6 +588,7 @@ impl EmbeddedServer {
             clients,
             pty_writers,
             attachment_uploads,
+            false,
         )
     }
 
@@ -596,6 +603,7 @@ impl EmbeddedServer {
         clients: ClientHub,
         pty_writers: PtyWriterRegistry,
         attachment_uploads: AttachmentUploadStore,
+        read_only: bool,
     ) -> std::io::Result<Self> {
         listener.set_nonblocking(true)?;
         let addr = listener.local_addr()?;
@@ -631,14 +639,53 @@ impl EmbeddedServer {
                 post(attachment_upload_handler),
             )
             .route("/internal/hook-live", post(hook_live_handler))
+            .route("/api/openapi.json", get(openapi_handler))
+            .route("/api/docs", get(swagger_ui_handler))
+            .route("/api/events", get(activity_events_handler))
+            .route("/api/logs/stream", get(log_stream_handler))
+            .route("/api/repos", get(repos_handler))
+            .route("/api/repos/{id}/worktrees", get(repo_worktrees_handler))
+            .route("/api/repos/{id}/branches", get(repo_branches_handler))
+            .route(
+                "/api/repos/{id}/branches/{branch}/pr",
+                post(branch_pr_handler),
+            )
+            .route(
+                "/api/repos/{id}/branches/{branch}/sessions/export",
+                get(session_export_handler),
+            )
+            .route(
+                "/api/repos/{id}/branches/{branch}/sessions/diff",
+                get(session_diff_handler),
+            )
+            .route("/api/sessions/search", get(session_search_handler))
+            .route(
+                "/api/sessions/{agent}/{session_id}/stream",
+                get(session_stream_handler),
+            )
+            .route("/api/operations", get(operations_handler))
+            .route("/api/operations/{id}", get(operation_handler))
+            .route(
+                "/api/operations/{id}/cancel",
+                post(operation_cancel_handler),
+            )
+            .route(
+                "/api/ai/branch-suggestions",
+                post(branch_suggestions_handler),
+            )
+            .route("/api/ai/explain-hunk", post(explain_hunk_handler))
+            .route("/api/tasks", get(tasks_handler).post(task_enqueue_handler))
+            .route("/api/tasks/{id}", get(task_handler))
+            .route("/api/tasks/{id}/log", get(task_log_handler))
             .route("/ws", get(websocket_handler))
             .with_state(ServerState {
                 proxy,
-                clients,
+                clients: clients.clone(),
                 hook_forward_token: hook_forward_token.clone(),
                 attachment_upload_token,
                 attachment_uploads,
                 pty_writers,
+                read_only,
                 access_log: access_log.clone(),
             })
             .layer(middleware::from_fn_with_state(
@@ -687,6 +734,8 @@ impl EmbeddedServer {
         }
 
         runtime.spawn(async move {
+            let activity_forwarder = tokio::spawn(forward_activity_events(clients));
+            let task_worker = (!read_only).then(|| tokio::spawn(run_task_queue_worker()));
             let server = axum::serve(
                 listener,
                 app.into_make_service_with_connect_info::<SocketAddr>(),
@@ -697,6 +746,10 @@ impl EmbeddedServer {
             if let Err(error) = server.await {
                 eprintln!("embedded server error: {error}");
             }
+            activity_forwarder.abort();
+            if let Some(task_worker) = task_worker {
+                task_worker.abort();
+            }
         });
 
         Ok(Self {
@@ -853,10 +906,14 @@ struct AttachmentUploadResponse {
     size: u64,
 }
 
-async fn attachment_upload_token_handler(State(state): State<ServerState>) -> impl IntoResponse {
+async fn attachment_upload_token_handler(State(state): State<ServerState>) -> Response {
+    if state.read_only {
+        return StatusCode::FORBIDDEN.into_response();
+    }
     Json(AttachmentUploadTokenResponse {
         token: state.attachment_upload_token,
     })
+    .into_response()
 }
 
 async fn attachment_upload_handler(
@@ -865,7 +922,7 @@ async fn attachment_upload_handler(
     State(state): State<ServerState>,
     request: Request,
 ) -> Response {
-    if !websocket_origin_authorized(&headers) {
+    if state.read_only || !websocket_origin_authorized(&headers) {
         return StatusCode::FORBIDDEN.into_response();
     }
     let authorized = headers
@@ -1081,6 +1138,961 @@ async fn hook_live_handler(
     StatusCode::NO_CONTENT
 }
 
+/// Relay the activity bus to every websocket client as `activity_event`.
+async fn forward_activity_events(clients: ClientHub) {
+    let mut receiver = gwt_core::activity_bus::subscribe();
+    loop {
+        match receiver.recv().await {
+            Ok(envelope) => clients.dispatch(vec![OutboundEvent::broadcast(
+                gwt::BackendEvent::ActivityEvent { event: envelope },
+            )]),
+            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+        }
+    }
+}
+
+/// `GET /api/events`: the activity bus as `text/event-stream`. Each SSE
+/// event is named after the activity (`agent.started`, ...), carries the
+/// envelope `seq` as its id and the envelope JSON as data.
+async fn activity_events_handler(headers: HeaderMap) -> Response {
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    let receiver = gwt_core::activity_bus::subscribe();
+    let stream = futures_util::stream::unfold(receiver, |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(envelope) => {
+                    let event = activity_sse_event(&envelope);
+                    return Some((Ok::<_, std::convert::Infallible>(event), receiver));
+                }
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    });
+    Sse::new(stream)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+fn activity_sse_event(envelope: &gwt_core::activity_bus::ActivityEnvelope) -> Event {
+    Event::default()
+        .event(envelope.event.name())
+        .id(envelope.seq.to_string())
+        .json_data(envelope)
+        .unwrap_or_else(|_| Event::default().event(envelope.event.name()))
+}
+
+/// How often a following `/api/logs/stream` checks the log file for new
+/// lines.
+const LOG_STREAM_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(500);
+
+#[derive(Debug, Deserialize)]
+struct LogStreamQuery {
+    /// Repository id from `/api/repos`.
+    repo: String,
+    #[serde(default)]
+    offset: u64,
+    #[serde(default = "default_log_stream_follow")]
+    follow: bool,
+}
+
+fn default_log_stream_follow() -> bool {
+    true
+}
+
+/// `GET /api/logs/stream?repo=<id>&offset=<bytes>&follow=<bool>`: today's
+/// structured log of one repository as `text/event-stream`. Each `log`
+/// event carries a log entry as data and the byte offset just past its
+/// line as id, so a reconnecting client (`Last-Event-ID`) resumes where it
+/// stopped. A `reset` event means the stream started over on a new file
+/// (UTC date rollover). Without `follow` the stream ends at the end of the
+/// file.
+async fn log_stream_handler(headers: HeaderMap, Query(query): Query<LogStreamQuery>) -> Response {
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    let repo_id = query.repo.clone();
+    let log_dir = match tokio::task::spawn_blocking(move || {
+        gwt::repo_registry::load_repos()
+            .into_iter()
+            .find(|repo| repo.id == repo_id)
+            .map(|repo| {
+                gwt_core::paths::gwt_project_logs_dir_for_project_path(std::path::Path::new(
+                    &repo.path,
+                ))
+            })
+    })
+    .await
+    {
+        Ok(Some(log_dir)) => log_dir,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(error) => {
+            return (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response()
+        }
+    };
+    let offset = headers
+        .get("last-event-id")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(query.offset);
+    let reader = gwt_core::logging::LogStreamReader::follow(log_dir, offset);
+    let state = (
+        Some(reader),
+        std::collections::VecDeque::<Event>::new(),
+        query.follow,
+    );
+    let stream =
+        futures_util::stream::unfold(state, |(mut reader, mut pending, follow)| async move {
+            loop {
+                if let Some(event) = pending.pop_front() {
+                    return Some((
+                        Ok::<_, std::convert::Infallible>(event),
+                        (reader, pending, follow),
+                    ));
+                }
+                let mut current = reader.take()?;
+                let (current, chunk) = tokio::task::spawn_blocking(move || {
+                    let chunk = current.read_available();
+                    (current, chunk)
+                })
+                .await
+                .ok()?;
+                let chunk = match chunk {
+                    Ok(chunk) => chunk,
+                    Err(error) => {
+                        // Report the failure, then end the stream.
+                        pending.push_back(Event::default().event("error").data(error.to_string()));
+                        continue;
+                    }
+                };
+                let drained = !chunk.reset && chunk.entries.is_empty() && chunk.skipped == 0;
+                pending.extend(log_stream_events(&chunk));
+                if drained {
+                    if !follow {
+                        return None;
+                    }
+                    tokio::time::sleep(LOG_STREAM_POLL_INTERVAL).await;
+                }
+                reader = Some(current);
+            }
+        });
+    Sse::new(stream)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+fn log_stream_events(chunk: &gwt_core::logging::LogChunk) -> Vec<Event> {
+    let mut events = Vec::with_capacity(chunk.entries.len() + 1);
+    if chunk.reset {
+        events.push(
+            Event::default()
+                .event("reset")
+                .json_data(serde_json::json!({ "path": chunk.path }))
+                .unwrap_or_else(|_| Event::default().event("reset")),
+        );
+    }
+    for entry in &chunk.entries {
+        if let Ok(event) = Event::default()
+            .event("log")
+            .id(entry.offset.to_string())
+            .json_data(&entry.event)
+        {
+            events.push(event);
+        }
+    }
+    events
+}
+
+/// Messages a `/api/sessions/{agent}/{session_id}/stream` step decodes
+/// before yielding to the client.
+const SESSION_STREAM_CHUNK_ENTRIES: usize = 200;
+
+#[derive(Debug, Deserialize)]
+struct SessionStreamQuery {
+    #[serde(default)]
+    offset: u64,
+    /// Start at the current end of the transcript.
+    #[serde(default)]
+    tail: bool,
+    #[serde(default)]
+    since: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default)]
+    until: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default = "default_log_stream_follow")]
+    follow: bool,
+}
+
+/// `GET /api/sessions/{agent}/{session_id}/stream`: the Claude Code or
+/// Codex transcript of one agent session as `text/event-stream`, read
+/// incrementally so a live session can be watched without re-reading the
+/// whole file. Each `message` event carries a transcript entry and the
+/// byte offset just past its line as id (`Last-Event-ID` resumes after
+/// it); `reset` means the transcript was replaced and is re-sent from the
+/// start. `tail` starts at the current end, `since` / `until` limit the
+/// messages by timestamp, and without `follow` the stream ends at the end
+/// of the file.
+async fn session_stream_handler(
+    headers: HeaderMap,
+    Path((agent, session_id)): Path<(String, String)>,
+    Query(query): Query<SessionStreamQuery>,
+) -> Response {
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    let Some(agent_id) = gwt_agent::resolve_agent_id(&agent) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let resume = headers
+        .get("last-event-id")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok());
+    let range = gwt_core::session_stream::TimeRange {
+        since: query.since,
+        until: query.until,
+    };
+    let opened = tokio::task::spawn_blocking(move || {
+        let (source, path) =
+            gwt_agent::session_export::session_transcript_path(&agent_id, &session_id)?;
+        let stream = match resume {
+            Some(offset) => gwt_core::session_stream::TranscriptStream::open(source, path, offset),
+            None if query.tail => {
+                gwt_core::session_stream::TranscriptStream::tail(source, path).ok()?
+            }
+            None => gwt_core::session_stream::TranscriptStream::open(source, path, query.offset),
+        };
+        Some(stream.with_range(range))
+    })
+    .await;
+    let reader = match opened {
+        Ok(Some(reader)) => reader,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(error) => {
+            return (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response()
+        }
+    };
+    let state = (
+        Some(reader),
+        std::collections::VecDeque::<Event>::new(),
+        query.follow,
+    );
+    let stream =
+        futures_util::stream::unfold(state, |(mut reader, mut pending, follow)| async move {
+            loop {
+                if let Some(event) = pending.pop_front() {
+                    return Some((
+                        Ok::<_, std::convert::Infallible>(event),
+                        (reader, pending, follow),
+                    ));
+                }
+                let mut current = reader.take()?;
+                let (current, chunk) = tokio::task::spawn_blocking(move || {
+                    let chunk = current.read_available(SESSION_STREAM_CHUNK_ENTRIES);
+                    (current, chunk)
+                })
+                .await
+                .ok()?;
+                let chunk = match chunk {
+                    Ok(chunk) => chunk,
+                    Err(error) => {
+                        // Report the failure, then end the stream.
+                        pending.push_back(Event::default().event("error").data(error.to_string()));
+                        continue;
+                    }
+                };
+                let drained = chunk.at_end && !chunk.reset && chunk.entries.is_empty();
+                pending.extend(session_stream_events(&chunk));
+                if drained {
+                    if !follow {
+                        return None;
+                    }
+                    tokio::time::sleep(LOG_STREAM_POLL_INTERVAL).await;
+                }
+                reader = Some(current);
+            }
+        });
+    Sse::new(stream)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+fn session_stream_events(chunk: &gwt_core::session_stream::TranscriptChunk) -> Vec<Event> {
+    let mut events = Vec::with_capacity(chunk.entries.len() + 1);
+    if chunk.reset {
+        events.push(Event::default().event("reset").data(""));
+    }
+    for entry in &chunk.entries {
+        if let Ok(event) = Event::default()
+            .event("message")
+            .id(entry.offset.to_string())
+            .json_data(&entry.entry)
+        {
+            events.push(event);
+        }
+    }
+    events
+}
+
+/// `GET /api/openapi.json`: OpenAPI description of the `/api` routes.
+async fn openapi_handler() -> Response {
+    Json(gwt::openapi::document()).into_response()
+}
+
+/// `GET /api/docs`: Swagger UI for `/api/openapi.json`, only while
+/// `[server] swagger_ui` is on.
+async fn swagger_ui_handler() -> Response {
+    let enabled = tokio::task::spawn_blocking(|| {
+        gwt_config::Settings::load()
+            .map(|settings| settings.server.swagger_ui)
+            .unwrap_or(false)
+    })
+    .await
+    .unwrap_or(false);
+    if !enabled {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    Html(gwt::openapi::swagger_ui_page()).into_response()
+}
+
+/// `GET /api/repos`: registered repositories and recent projects.
+async fn repos_handler(headers: HeaderMap, Query(query): Query<ListQuery>) -> Response {
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    match tokio::task::spawn_blocking(gwt::repo_registry::load_repos).await {
+        Ok(repos) => list_page_response(repos, &query),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+/// `GET /api/repos/{id}/worktrees`: worktrees of one repository from
+/// `/api/repos`.
+async fn repo_worktrees_handler(
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    Query(query): Query<ListQuery>,
+) -> Response {
+    repo_list_response(headers, id, query, gwt::repo_registry::repo_worktrees).await
+}
+
+/// `GET /api/repos/{id}/branches`: local branches of one repository from
+/// `/api/repos`.
+async fn repo_branches_handler(
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    Query(query): Query<ListQuery>,
+) -> Response {
+    repo_list_response(headers, id, query, gwt::repo_registry::repo_branches).await
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct CreateBranchPrRequest {
+    /// Base branch; the repository's default base when omitted.
+    #[serde(default)]
+    base: Option<String>,
+    #[serde(default)]
+    draft: bool,
+}
+
+/// `POST /api/repos/{id}/branches/{branch}/pr`: push the branch and open a
+/// pull request with an AI-drafted title and body. `branch` is
+/// percent-encoded, so `feature%2Fx` names `feature/x`.
+async fn branch_pr_handler(
+    headers: HeaderMap,
+    Path((id, branch)): Path<(String, String)>,
+    Json(request): Json<CreateBranchPrRequest>,
+) -> Response {
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    let result = tokio::task::spawn_blocking(move || {
+        let repo = gwt::repo_registry::load_repos()
+            .into_iter()
+            .find(|repo| repo.id == id)?;
+        Some(gwt::pr_create::create_branch_pr(
+            std::path::Path::new(&repo.path),
+            &branch,
+            request.base.as_deref(),
+            request.draft,
+        ))
+    })
+    .await;
+    match result {
+        Ok(Some(Ok(created))) => (StatusCode::CREATED, Json(created)).into_response(),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Ok(Some(Err(error))) => (StatusCode::BAD_GATEWAY, error).into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct SessionExportQuery {
+    /// `md` (default), `html` or `json`.
+    #[serde(default)]
+    format: Option<String>,
+}
+
+/// `GET /api/repos/{id}/branches/{branch}/sessions/export?format=md|html|json`:
+/// transcripts of the agent sessions that ran on the branch, as a file
+/// download (the same output as `gwt session export`).
+async fn session_export_handler(
+    headers: HeaderMap,
+    Path((id, branch)): Path<(String, String)>,
+    Query(query): Query<SessionExportQuery>,
+) -> Response {
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    let format = match query
+        .format
+        .as_deref()
+        .map(str::parse::<gwt_agent::session_export::ExportFormat>)
+        .transpose()
+    {
+        Ok(format) => format.unwrap_or_default(),
+        Err(error) => return (StatusCode::BAD_REQUEST, error).into_response(),
+    };
+    let result = tokio::task::spawn_blocking(move || {
+        let repo = gwt::repo_registry::load_repos()
+            .into_iter()
+            .find(|repo| repo.id == id)?;
+        let export =
+            gwt::session_export::export_branch_sessions(std::path::Path::new(&repo.path), &branch);
+        Some((
+            gwt::session_export::export_file_name(&branch, format.extension()),
+            gwt_agent::session_export::render_export(&export, format),
+        ))
+    })
+    .await;
+    match result {
+        Ok(Some((file_name, body))) => (
+            [
+                ("content-type", format.content_type().to_string()),
+                (
+                    "content-disposition",
+                    format!("attachment; filename=\"{file_name}\""),
+                ),
+            ],
+            body,
+        )
+            .into_response(),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct SessionDiffQuery {
+    /// Agent session id (or unique prefix) of the left run.
+    #[serde(default)]
+    left: Option<String>,
+    /// Agent session id (or unique prefix) of the right run.
+    #[serde(default)]
+    right: Option<String>,
+}
+
+/// `GET /api/repos/{id}/branches/{branch}/sessions/diff?left=&right=`:
+/// comparison of two agent runs of the branch (the same data as
+/// `gwt session diff --json`). Without `left` / `right` the two most recent
+/// runs are compared.
+async fn session_diff_handler(
+    headers: HeaderMap,
+    Path((id, branch)): Path<(String, String)>,
+    Query(query): Query<SessionDiffQuery>,
+) -> Response {
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    let result = tokio::task::spawn_blocking(move || {
+        let repo = gwt::repo_registry::load_repos()
+            .into_iter()
+            .find(|repo| repo.id == id)?;
+        let export =
+            gwt::session_export::export_branch_sessions(std::path::Path::new(&repo.path), &branch);
+        Some(
+            gwt_agent::session_diff::select_runs(
+                &export,
+                query.left.as_deref(),
+                query.right.as_deref(),
+            )
+            .map(|(left, right)| gwt_agent::session_diff::diff_sessions(&branch, left, right)),
+        )
+    })
+    .await;
+    match result {
+        Ok(Some(Ok(diff))) => Json(diff).into_response(),
+        Ok(Some(Err(error))) => (StatusCode::BAD_REQUEST, error).into_response(),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+async fn repo_list_response<T>(
+    headers: HeaderMap,
+    id: String,
+    query: ListQuery,
+    list: fn(&std::path::Path) -> Result<Vec<T>, String>,
+) -> Response
+where
+    T: gwt::repo_registry::ListItem + Serialize + Send + 'static,
+{
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    let result = tokio::task::spawn_blocking(move || {
+        let repo = gwt::repo_registry::load_repos()
+            .into_iter()
+            .find(|repo| repo.id == id)?;
+        Some(list(std::path::Path::new(&repo.path)))
+    })
+    .await;
+    match result {
+        Ok(Some(Ok(items))) => list_page_response(items, &query),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Ok(Some(Err(error))) => (StatusCode::BAD_GATEWAY, error).into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+/// JSON array of one page, with the filtered item count in `X-Total-Count`
+/// and the page geometry in `X-Page` / `X-Per-Page`.
+fn list_page_response<T>(items: Vec<T>, query: &ListQuery) -> Response
+where
+    T: gwt::repo_registry::ListItem + Serialize,
+{
+    match gwt::repo_registry::paginate(items, query) {
+        Ok(page) => (
+            [
+                ("x-total-count", page.total.to_string()),
+                ("x-page", page.page.to_string()),
+                ("x-per-page", page.per_page.to_string()),
+            ],
+            Json(page.items),
+        )
+            .into_response(),
+        Err(error) => (StatusCode::BAD_REQUEST, error).into_response(),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SessionSearchQuery {
+    q: String,
+    limit: Option<usize>,
+}
+
+/// `GET /api/sessions/search?q=<terms>&limit=<n>`: agent transcript messages
+/// containing every term, newest sessions first.
+async fn session_search_handler(
+    headers: HeaderMap,
+    Query(query): Query<SessionSearchQuery>,
+) -> Response {
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if query.q.trim().is_empty() {
+        return (StatusCode::BAD_REQUEST, "q must not be empty").into_response();
+    }
+    let limit = query
+        .limit
+        .unwrap_or(gwt_core::session_search::DEFAULT_SEARCH_LIMIT)
+        .clamp(1, 500);
+    match tokio::task::spawn_blocking(move || {
+        gwt_core::session_search::search_sessions(&query.q, limit)
+    })
+    .await
+    {
+        Ok(hits) => Json(hits).into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+/// `GET /api/operations`: migrations and bulk cleanups started by any gwt
+/// instance, newest first, including interrupted ones.
+async fn operations_handler(headers: HeaderMap) -> Response {
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    match tokio::task::spawn_blocking(|| {
+        gwt_core::operation_progress::list_operations(&gwt_core::paths::gwt_operations_dir())
+    })
+    .await
+    {
+        Ok(records) => Json(records).into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+/// `GET /api/operations/{id}`: one operation, for polling its progress.
+async fn operation_handler(headers: HeaderMap, Path(id): Path<String>) -> Response {
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    match tokio::task::spawn_blocking(move || {
+        gwt_core::operation_progress::load_operation(&gwt_core::paths::gwt_operations_dir(), &id)
+    })
+    .await
+    {
+        Ok(Some(record)) => Json(record).into_response(),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+/// `POST /api/operations/{id}/cancel`: ask a running operation to stop, or
+/// dismiss one whose gwt process exited.
+async fn operation_cancel_handler(
+    headers: HeaderMap,
+    State(state): State<ServerState>,
+    Path(id): Path<String>,
+) -> Response {
+    if state.read_only || !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    use gwt_core::operation_progress::{request_cancel, CancelRejection};
+    match tokio::task::spawn_blocking(move || {
+        request_cancel(&gwt_core::paths::gwt_operations_dir(), &id)
+    })
+    .await
+    {
+        Ok(Ok(record)) => Json(record).into_response(),
+        Ok(Err(rejection @ CancelRejection::NotFound)) => {
+            (StatusCode::NOT_FOUND, rejection.to_string()).into_response()
+        }
+        Ok(Err(rejection @ CancelRejection::WriteFailed(_))) => {
+            (StatusCode::INTERNAL_SERVER_ERROR, rejection.to_string()).into_response()
+        }
+        Ok(Err(rejection)) => (StatusCode::CONFLICT, rejection.to_string()).into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+/// How often the worker looks for queued tasks.
+const TASK_QUEUE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+fn task_queue_enabled() -> bool {
+    gwt_config::Settings::load()
+        .map(|settings| settings.server.task_queue)
+        .unwrap_or(false)
+}
+
+#[derive(Debug, Deserialize)]
+struct TaskEnqueueRequest {
+    /// Repository id from `/api/repos`, or its path.
+    repo: String,
+    branch: String,
+    prompt: String,
+    #[serde(default = "default_task_agent")]
+    agent: String,
+}
+
+fn default_task_agent() -> String {
+    "claude".to_string()
+}
+
+/// `POST /api/tasks`: queue a headless agent run on a branch. Replies
+/// `202 Accepted` with the queued record; poll `/api/tasks/{id}` for its
+/// state and `/api/tasks/{id}/log` for the agent output.
+async fn task_enqueue_handler(
+    headers: HeaderMap,
+    State(state): State<ServerState>,
+    Json(request): Json<TaskEnqueueRequest>,
+) -> Response {
+    if state.read_only || !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if request.branch.trim().is_empty() || request.prompt.trim().is_empty() {
+        return (StatusCode::BAD_REQUEST, "branch and prompt are required").into_response();
+    }
+    if gwt_agent::headless::headless_agent(&request.agent).is_none() {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!(
+                "agent must be one of: {}",
+                gwt_agent::headless::HEADLESS_AGENTS.join(", ")
+            ),
+        )
+            .into_response();
+    }
+    let result = tokio::task::spawn_blocking(move || {
+        if !task_queue_enabled() {
+            return Err((
+                StatusCode::SERVICE_UNAVAILABLE,
+                "task queue is disabled; set [server] task_queue = true".to_string(),
+            ));
+        }
+        let repo = gwt::repo_registry::load_repos()
+            .into_iter()
+            .find(|repo| repo.id == request.repo || repo.path == request.repo)
+            .ok_or_else(|| (StatusCode::NOT_FOUND, "repository not found".to_string()))?;
+        gwt_core::jobs::enqueue(
+            &gwt_core::paths::gwt_tasks_dir(),
+            gwt_core::task_queue::TaskRequest {
+                repo_path: std::path::PathBuf::from(repo.path),
+                branch: request.branch,
+                agent: request.agent,
+                prompt: request.prompt,
+            },
+        )
+        .map_err(|error| (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()))
+    })
+    .await;
+    match result {
+        Ok(Ok(record)) => (StatusCode::ACCEPTED, Json(record)).into_response(),
+        Ok(Err(error)) => error.into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+/// `GET /api/tasks`: queued, running and finished tasks, newest first.
+async fn tasks_handler(headers: HeaderMap) -> Response {
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    match tokio::task::spawn_blocking(|| {
+        gwt_core::task_queue::list_tasks(&gwt_core::paths::gwt_tasks_dir())
+    })
+    .await
+    {
+        Ok(records) => Json(records).into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+/// `GET /api/tasks/{id}`: one task, for polling its state.
+async fn task_handler(headers: HeaderMap, Path(id): Path<String>) -> Response {
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    match tokio::task::spawn_blocking(move || {
+        gwt_core::task_queue::load_task(&gwt_core::paths::gwt_tasks_dir(), &id)
+    })
+    .await
+    {
+        Ok(Some(record)) => Json(record).into_response(),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TaskLogQuery {
+    #[serde(default)]
+    offset: u64,
+}
+
+/// `GET /api/tasks/{id}/log?offset=<bytes>`: agent output as plain text,
+/// from `offset` on so pollers only fetch what is new.
+async fn task_log_handler(
+    headers: HeaderMap,
+    Path(id): Path<String>,
+    Query(query): Query<TaskLogQuery>,
+) -> Response {
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    match tokio::task::spawn_blocking(move || {
+        gwt_core::task_queue::read_log(&gwt_core::paths::gwt_tasks_dir(), &id, query.offset)
+    })
+    .await
+    {
+        Ok(Some(log)) => log.into_response(),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+/// Runs queued tasks one at a time while `[server] task_queue` is on.
+async fn run_task_queue_worker() {
+    let mut interval = tokio::time::interval(TASK_QUEUE_POLL_INTERVAL);
+    loop {
+        interval.tick().await;
+        if let Err(error) = tokio::task::spawn_blocking(run_queued_tasks).await {
+            tracing::warn!(error = %error, "task queue worker panicked");
+        }
+    }
+}
+
+fn run_queued_tasks() {
+    if !task_queue_enabled() {
+        return;
+    }
+    gwt_core::jobs::drain(
+        &gwt_core::paths::gwt_tasks_dir(),
+        &gwt_agent::headless::job_policy(),
+        gwt_agent::headless::run_task,
+    );
+}
+
+#[derive(Debug, Deserialize)]
+struct BranchSuggestionsRequest {
+    description: String,
+    #[serde(default)]
+    stream: bool,
+}
+
+/// `POST /api/ai/branch-suggestions`: branch name candidates for a task
+/// description, using the configured AI provider.
+///
+/// With `"stream": true` the reply is `text/event-stream`: a `partial` event
+/// each time another name completes, then a single `done` or `error` event.
+async fn branch_suggestions_handler(
+    headers: HeaderMap,
+    Json(request): Json<BranchSuggestionsRequest>,
+) -> Response {
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if request.description.trim().is_empty() {
+        return (StatusCode::BAD_REQUEST, "description is empty").into_response();
+    }
+    let description = request.description;
+
+    if !request.stream {
+        let result = tokio::task::spawn_blocking(move || {
+            let provider = configured_ai_provider()?;
+            gwt_ai::run_ai_job(
+                "Branch name suggestions",
+                gwt_ai::AiJobPriority::Interactive,
+                || gwt_ai::suggest_branch_name(provider.as_ref(), &description),
+            )
+            .map_err(|error| ai_job_error(provider.as_ref(), &error))
+        })
+        .await;
+        return match result {
+            Ok(Ok(suggestions)) => {
+                Json(serde_json::json!({ "suggestions": suggestions })).into_response()
+            }
+            Ok(Err(error)) => error.into_response(),
+            Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+        };
+    }
+
+    let (events, receiver) = tokio::sync::mpsc::unbounded_channel::<Event>();
+    tokio::task::spawn_blocking(move || {
+        let partial = events.clone();
+        let mut on_partial = |names: &[String]| {
+            let _ = partial.send(suggestions_event("partial", names));
+        };
+        let result = configured_ai_provider().and_then(|provider| {
+            gwt_ai::run_ai_job(
+                "Branch name suggestions",
+                gwt_ai::AiJobPriority::Interactive,
+                || {
+                    gwt_ai::suggest_branch_name_streaming(
+                        provider.as_ref(),
+                        &description,
+                        &mut on_partial,
+                    )
+                },
+            )
+            .map_err(|error| ai_job_error(provider.as_ref(), &error))
+        });
+        let last = match result {
+            Ok(names) => suggestions_event("done", &names),
+            Err((_, message)) => Event::default().event("error").data(message),
+        };
+        let _ = events.send(last);
+    });
+    let stream = futures_util::stream::unfold(receiver, |mut receiver| async move {
+        let event = receiver.recv().await?;
+        Some((Ok::<_, std::convert::Infallible>(event), receiver))
+    });
+    Sse::new(stream).into_response()
+}
+
+fn suggestions_event(name: &str, suggestions: &[String]) -> Event {
+    Event::default()
+        .event(name)
+        .data(serde_json::json!({ "suggestions": suggestions }).to_string())
+}
+
+#[derive(Debug, Deserialize)]
+struct ExplainHunkRequest {
+    /// Path of the changed file, relative to the repository root.
+    path: String,
+    /// One unified diff hunk, starting at its `@@` header.
+    hunk: String,
+}
+
+/// `POST /api/ai/explain-hunk`: a short explanation of one diff hunk, for
+/// the "Explain this change" action. Explanations are cached per hunk hash
+/// for the lifetime of the process; `cached` reports a cache hit.
+async fn explain_hunk_handler(
+    headers: HeaderMap,
+    Json(request): Json<ExplainHunkRequest>,
+) -> Response {
+    if !websocket_origin_authorized(&headers) {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    if request.hunk.trim().is_empty() {
+        return (StatusCode::BAD_REQUEST, "hunk is empty").into_response();
+    }
+    static CACHE: std::sync::OnceLock<gwt_ai::HunkExplanationCache> = std::sync::OnceLock::new();
+    let result = tokio::task::spawn_blocking(move || {
+        let hash = gwt_ai::hunk_hash(&request.path, &request.hunk);
+        let cache = CACHE.get_or_init(gwt_ai::HunkExplanationCache::default);
+        if let Some(explanation) = cache.get(&hash) {
+            return Ok((hash, explanation, true));
+        }
+        let provider = configured_ai_provider()?;
+        let (explanation, cached) = gwt_ai::run_ai_job(
+            format!("Explain change in {}", request.path),
+            gwt_ai::AiJobPriority::Interactive,
+            || cache.explain(provider.as_ref(), &request.path, &request.hunk),
+        )
+        .map_err(|error| ai_job_error(provider.as_ref(), &error))?;
+        Ok((hash, explanation, cached))
+    })
+    .await;
+    match result {
+        Ok(Ok((hash, explanation, cached))) => Json(serde_json::json!({
+            "hash": hash,
+            "explanation": explanation,
+            "cached": cached,
+        }))
+        .into_response(),
+        Ok(Err(error)) => error.into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+/// The configured AI provider, or `503` when AI is not set up.
+fn configured_ai_provider() -> Result<Box<dyn gwt_ai::AIProvider>, (StatusCode, String)> {
+    let ai = gwt_config::Settings::load().unwrap_or_default().ai;
+    if !ai.is_enabled() {
+        return Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            "AI is not configured".to_string(),
+        ));
+    }
+    let resolved = gwt_ai::ResolvedAISettings::resolve(
+        &ai.provider,
+        &ai.endpoint,
+        ai.api_key.as_deref().unwrap_or(""),
+        &ai.model,
+    )
+    .map_err(|error| (StatusCode::SERVICE_UNAVAILABLE, error.to_string()))?;
+    resolved.build().map_err(|error| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            resolved.format_error(&error),
+        )
+    })
+}
+
+/// `502` carrying the provider's error in display form.
+fn ai_job_error(
+    provider: &dyn gwt_ai::AIProvider,
+    error: &gwt_ai::AIError,
+) -> (StatusCode, String) {
+    (
+        StatusCode::BAD_GATEWAY,
+        gwt_ai::format_error_for_display(provider.kind(), error),
+    )
+}
+
 async fn client_session(socket: WebSocket, state: ServerState) {
     let client_id = Uuid::new_v4().to_string();
     let outbound = state.clients.register(client_id.clone());
@@ -1088,6 +2100,18 @@ async fn client_session(socket: WebSocket, state: ServerState) {
 
     let input_seq = Arc::new(AtomicU64::new(0));
 
+    if state.read_only {
+        // Sent ahead of the outbound queue so the banner is up before the
+        // first snapshot renders.
+        let mode = gwt::BackendEvent::ServerMode { read_only: true };
+        if let Ok(payload) = serde_json::to_string(&mode) {
+            if sender.send(Message::Text(payload.into())).await.is_err() {
+                state.clients.unregister(&client_id);
+                return;
+            }
+        }
+    }
+
     loop {
         tokio::select! {
             step = outbound.next() => {
@@ -1150,6 +2174,13 @@ fn handle_frontend_message(
     text_len: usize,
     event: FrontendEvent,
 ) {
+    if state.read_only && !event.allowed_in_read_only() {
+        tracing::warn!(
+            client_id = %client_id,
+            "dropping mutating frontend event: embedded server is read-only"
+        );
+        return;
+    }
     let (id, data) = match event {
         FrontendEvent::TerminalInput { id, data } => (id, data),
         other => {
@@ -1316,6 +2347,7 @@ mod tests {
                 attachment_upload_token: "upload-token".to_string(),
                 attachment_uploads: AttachmentUploadStore::in_system_temp(),
                 pty_writers: Arc::new(RwLock::new(HashMap::new())),
+                read_only: false,
                 access_log: super::AccessLogSink::default(),
             },
             events,
@@ -1386,6 +2418,46 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn handle_frontend_message_drops_mutating_events_in_read_only_mode() {
+        let (mut state, events) = sample_server_state();
+        state.read_only = true;
+
+        for event in [
+            FrontendEvent::TerminalInput {
+                id: "tab-1::shell-1".to_string(),
+                data: "rm -rf .\n".to_string(),
+            },
+            FrontendEvent::GetSystemSettings,
+        ] {
+            handle_frontend_message(&state, "client-1", &AtomicU64::new(0), 48, event);
+        }
+
+        let recorded = events
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        assert!(matches!(
+            recorded.as_slice(),
+            [UserEvent::Frontend {
+                event: FrontendEvent::GetSystemSettings,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn attachment_upload_token_is_refused_in_read_only_mode() {
+        let (mut state, _events) = sample_server_state();
+        state.read_only = true;
+        let runtime = Runtime::new().expect("runtime");
+
+        let response = runtime.block_on(super::attachment_upload_token_handler(
+            axum::extract::State(state),
+        ));
+
+        assert_eq!(response.status(), axum::http::StatusCode::FORBIDDEN);
+    }
+
     fn terminal_output(pane: &str, data: &str) -> BackendEvent {
         BackendEvent::TerminalOutput {
             id: pane.to_string(),
@@ -1965,6 +3037,251 @@ mod tests {
         server.shutdown();
     }
 
+    #[test]
+    fn repo_routes_reject_cross_origin_and_unknown_ids() {
+        let runtime = Runtime::new().expect("tokio runtime");
+        let (proxy, _events) = AppEventProxy::stub();
+        let mut server = EmbeddedServer::start(
+            &runtime,
+            proxy,
+            ClientHub::default(),
+            Arc::new(RwLock::new(HashMap::new())),
+            AttachmentUploadStore::in_system_temp(),
+        )
+        .expect("server");
+        let client = reqwest::blocking::Client::new();
+
+        let cross_origin = client
+            .get(format!("{}api/repos", server.url()))
+            .header("origin", "http://evil.example")
+            .send()
+            .expect("repos request");
+        assert_eq!(cross_origin.status(), HttpStatusCode::FORBIDDEN);
+
+        let unknown = client
+            .get(format!("{}api/repos/not-a-repo/worktrees", server.url()))
+            .send()
+            .expect("worktrees request");
+        assert_eq!(unknown.status(), HttpStatusCode::NOT_FOUND);
+
+        server.shutdown();
+    }
+
+    #[test]
+    fn operation_routes_reject_cross_origin_and_unknown_ids() {
+        let runtime = Runtime::new().expect("tokio runtime");
+        let (proxy, _events) = AppEventProxy::stub();
+        let mut server = EmbeddedServer::start(
+            &runtime,
+            proxy,
+            ClientHub::default(),
+            Arc::new(RwLock::new(HashMap::new())),
+            AttachmentUploadStore::in_system_temp(),
+        )
+        .expect("server");
+        let client = reqwest::blocking::Client::new();
+
+        let cross_origin = client
+            .get(format!("{}api/operations", server.url()))
+            .header("origin", "http://evil.example")
+            .send()
+            .expect("operations request");
+        assert_eq!(cross_origin.status(), HttpStatusCode::FORBIDDEN);
+
+        let unknown = client
+            .get(format!("{}api/operations/notanoperation", server.url()))
+            .send()
+            .expect("operation request");
+        assert_eq!(unknown.status(), HttpStatusCode::NOT_FOUND);
+
+        let cancel_unknown = client
+            .post(format!(
+                "{}api/operations/notanoperation/cancel",
+                server.url()
+            ))
+            .send()
+            .expect("cancel request");
+        assert_eq!(cancel_unknown.status(), HttpStatusCode::NOT_FOUND);
+
+        server.shutdown();
+    }
+
+    #[test]
+    fn task_routes_validate_origin_payload_and_ids() {
+        let runtime = Runtime::new().expect("tokio runtime");
+        let (proxy, _events) = AppEventProxy::stub();
+        let mut server = EmbeddedServer::start(
+            &runtime,
+            proxy,
+            ClientHub::default(),
+            Arc::new(RwLock::new(HashMap::new())),
+            AttachmentUploadStore::in_system_temp(),
+        )
+        .expect("server");
+        let client = reqwest::blocking::Client::new();
+        let url = format!("{}api/tasks", server.url());
+        let body = serde_json::json!({
+            "repo": "not-a-repo",
+            "branch": "feature/ci",
+            "prompt": "fix the failing test",
+        });
+
+        let cross_origin = client
+            .post(&url)
+            .header("origin", "http://evil.example")
+            .json(&body)
+            .send()
+            .expect("enqueue request");
+        assert_eq!(cross_origin.status(), HttpStatusCode::FORBIDDEN);
+
+        let unknown_agent = client
+            .post(&url)
+            .json(&serde_json::json!({
+                "repo": "not-a-repo",
+                "branch": "feature/ci",
+                "prompt": "fix the failing test",
+                "agent": "gemini",
+            }))
+            .send()
+            .expect("enqueue request");
+        assert_eq!(unknown_agent.status(), HttpStatusCode::BAD_REQUEST);
+
+        let empty_prompt = client
+            .post(&url)
+            .json(&serde_json::json!({
+                "repo": "not-a-repo",
+                "branch": "feature/ci",
+                "prompt": " ",
+            }))
+            .send()
+            .expect("enqueue request");
+        assert_eq!(empty_prompt.status(), HttpStatusCode::BAD_REQUEST);
+
+        let unknown = client
+            .get(format!("{url}/notatask"))
+            .send()
+            .expect("task request");
+        assert_eq!(unknown.status(), HttpStatusCode::NOT_FOUND);
+        let unknown_log = client
+            .get(format!("{url}/notatask/log"))
+            .send()
+            .expect("log request");
+        assert_eq!(unknown_log.status(), HttpStatusCode::NOT_FOUND);
+
+        server.shutdown();
+    }
+
+    #[test]
+    fn branch_suggestions_route_validates_origin_and_description() {
+        let runtime = Runtime::new().expect("tokio runtime");
+        let (proxy, _events) = AppEventProxy::stub();
+        let mut server = EmbeddedServer::start(
+            &runtime,
+            proxy,
+            ClientHub::default(),
+            Arc::new(RwLock::new(HashMap::new())),
+            AttachmentUploadStore::in_system_temp(),
+        )
+        .expect("server");
+        let client = reqwest::blocking::Client::new();
+        let url = format!("{}api/ai/branch-suggestions", server.url());
+
+        let cross_origin = client
+            .post(&url)
+            .header("origin", "http://evil.example")
+            .json(&serde_json::json!({ "description": "Add login" }))
+            .send()
+            .expect("cross-origin request");
+        assert_eq!(cross_origin.status(), HttpStatusCode::FORBIDDEN);
+
+        let empty = client
+            .post(&url)
+            .json(&serde_json::json!({ "description": "  ", "stream": true }))
+            .send()
+            .expect("empty description request");
+        assert_eq!(empty.status(), HttpStatusCode::BAD_REQUEST);
+
+        server.shutdown();
+    }
+
+    #[test]
+    fn explain_hunk_route_validates_origin_and_hunk() {
+        let runtime = Runtime::new().expect("tokio runtime");
+        let (proxy, _events) = AppEventProxy::stub();
+        let mut server = EmbeddedServer::start(
+            &runtime,
+            proxy,
+            ClientHub::default(),
+            Arc::new(RwLock::new(HashMap::new())),
+            AttachmentUploadStore::in_system_temp(),
+        )
+        .expect("server");
+        let client = reqwest::blocking::Client::new();
+        let url = format!("{}api/ai/explain-hunk", server.url());
+
+        let cross_origin = client
+            .post(&url)
+            .header("origin", "http://evil.example")
+            .json(&serde_json::json!({ "path": "src/lib.rs", "hunk": "@@ -1 +1 @@\n-a\n+b" }))
+            .send()
+            .expect("cross-origin request");
+        assert_eq!(cross_origin.status(), HttpStatusCode::FORBIDDEN);
+
+        let empty = client
+            .post(&url)
+            .json(&serde_json::json!({ "path": "src/lib.rs", "hunk": " \n" }))
+            .send()
+            .expect("empty hunk request");
+        assert_eq!(empty.status(), HttpStatusCode::BAD_REQUEST);
+
+        server.shutdown();
+    }
+
+    #[test]
+    fn activity_events_route_streams_published_activity() {
+        use std::io::Read;
+
+        let runtime = Runtime::new().expect("tokio runtime");
+        let (proxy, _events) = AppEventProxy::stub();
+        let mut server = EmbeddedServer::start(
+            &runtime,
+            proxy,
+            ClientHub::default(),
+            Arc::new(RwLock::new(HashMap::new())),
+            AttachmentUploadStore::in_system_temp(),
+        )
+        .expect("server");
+        let client = reqwest::blocking::Client::new();
+        let url = format!("{}api/events", server.url());
+
+        let cross_origin = client
+            .get(&url)
+            .header("origin", "http://evil.example")
+            .send()
+            .expect("cross-origin request");
+        assert_eq!(cross_origin.status(), HttpStatusCode::FORBIDDEN);
+
+        let mut stream = client.get(&url).send().expect("event stream");
+        assert_eq!(stream.status(), HttpStatusCode::OK);
+        gwt_core::activity_bus::publish(gwt_core::activity_bus::ActivityEvent::AgentStarted {
+            session_id: "session-events".to_string(),
+            agent_id: "claude".to_string(),
+            repo_path: None,
+            branch: Some("work/events".to_string()),
+            worktree_path: None,
+        });
+        let mut received = String::new();
+        let mut buffer = [0_u8; 1024];
+        while !received.contains("session-events") {
+            let read = stream.read(&mut buffer).expect("read event stream");
+            assert!(read > 0, "event stream closed early: {received}");
+            received.push_str(&String::from_utf8_lossy(&buffer[..read]));
+        }
+        assert!(received.contains("event: agent.started"), "{received}");
+
+        server.shutdown();
+    }
+
     #[test]
     fn unsuccessful_hook_live_requests_remain_in_access_log_ring() {
         let runtime = Runtime::new().expect("tokio runtime");
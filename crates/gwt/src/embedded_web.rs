@@ -74,6 +74,8 @@ root_js_modules! {
     // SPEC-2013 2026-06-16 amendment: project switcher popover and
     // Shift+Cmd+Up/Down project tab cycling helpers.
     "project-switcher.js" => "createProjectSwitcherController",
+    // Drag-and-drop a folder onto the canvas to open it as a project.
+    "project-folder-drop.js" => "installProjectFolderDrop",
     // SPEC-2008 Camera Focus: rail-safe viewport framing math for local
     // per-viewer camera moves.
     "camera-framing.js" => "computeCameraFrameArea",
@@ -100,6 +102,10 @@ root_js_modules! {
     "theme-toggle.js" => "wireThemeToggle",
     "hotkey.js" => "createHotkeyManager",
     "operator-shell.js" => "initOperatorShell",
+    // Status Strip widgets from `[[footer.widgets]]`.
+    "footer-widgets.js" => "createFooterWidgets",
+    // Shared scheduler for periodic refreshes, budgeted by `[refresh]`.
+    "refresh-scheduler.js" => "createRefreshScheduler",
     "focus-trap.js" => "createFocusTrap",
     // Issue #2698 — stable project tab renderer. Keeps tab DOM keyed by
     // project tab id so status-only workspace refreshes do not rebuild the
@@ -111,6 +117,10 @@ root_js_modules! {
     "window-tabs-renderer.js" => "renderWindowTabs",
     // SPEC-1939 Phase 12 / T-IDX-106 — Settings.Index tab renderer.
     "index-settings-panel.js" => "renderIndexSettingsPanel",
+    // Settings > Appearance tab renderer (theme + palette picker).
+    "theme-settings-panel.js" => "renderThemeSettingsPanel",
+    // Settings banner for `~/.gwt/config.toml` validation problems.
+    "config-diagnostics-panel.js" => "renderConfigDiagnostics",
     // SPEC-2008 Phase 24 — terminal viewport reflow primitives.
     "terminal-viewport-reflow.js" => "attachHostResizeReflow",
     // SPEC-2008 Phase 25 — revision-aware window geometry sync primitives.
@@ -0,0 +1,312 @@
+//! Coding agents started outside gwt.
+//!
+//! gwt only knows the agents it launched in its own terminals. An agent
+//! started by hand in another terminal or multiplexer pane is found here by
+//! its running command: a built-in agent command as the executable or as a
+//! script argument (`node …/claude`), in a process that is not a descendant
+//! of this gwt. Its working directory maps it to a worktree and branch when
+//! it runs inside one of the project's worktrees; otherwise the user picks
+//! the branch. Adopting one persists a [`Session`] with
+//! [`Session::adopted_pid`] set, so the branch shows the agent as running
+//! until [`refresh_adopted_sessions`] sees the process exit.
+
+use std::{
+    collections::HashSet,
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+use gwt_agent::{AgentId, AgentStatus, Session};
+use serde::Serialize;
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+/// An agent process gwt did not launch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ExternalAgent {
+    pub pid: u32,
+    #[serde(skip)]
+    pub agent_id: AgentId,
+    /// Agent display name.
+    pub agent: String,
+    pub command_line: String,
+    pub cwd: PathBuf,
+    /// Worktree of the project that contains `cwd`, if any.
+    pub worktree_path: Option<PathBuf>,
+    /// Branch checked out in `worktree_path`; `None` asks the user to map it.
+    pub branch: Option<String>,
+}
+
+/// Agents running outside gwt, excluding those already adopted. Stale
+/// adoptions are refreshed first.
+pub fn detect_external_agents(project_root: &Path) -> Result<Vec<ExternalAgent>, String> {
+    let worktrees: Vec<(PathBuf, Option<String>)> = gwt_git::WorktreeManager::new(project_root)
+        .list()
+        .map_err(|error| error.to_string())?
+        .into_iter()
+        .map(|worktree| (canonical(&worktree.path), worktree.branch))
+        .collect();
+    let system = process_snapshot();
+    let sessions_dir = gwt_core::paths::gwt_sessions_dir();
+    refresh_adopted_sessions(&sessions_dir, &system);
+    let adopted: HashSet<u32> = adopted_sessions(&sessions_dir)
+        .into_iter()
+        .filter(|session| session.status != AgentStatus::Stopped)
+        .filter_map(|session| session.adopted_pid)
+        .collect();
+
+    let own_pid = Pid::from_u32(std::process::id());
+    let mut agents: Vec<ExternalAgent> = system
+        .processes()
+        .values()
+        .filter(|process| !adopted.contains(&process.pid().as_u32()))
+        .filter(|process| !descends_from(&system, process.pid(), own_pid))
+        .filter_map(|process| {
+            let agent = agent_for_command(process.name(), process.cmd())?;
+            let cwd = canonical(process.cwd()?);
+            let worktree = worktrees
+                .iter()
+                .filter(|(path, _)| cwd.starts_with(path))
+                .max_by_key(|(path, _)| path.as_os_str().len());
+            Some(ExternalAgent {
+                pid: process.pid().as_u32(),
+                agent: agent.display_name().to_string(),
+                agent_id: agent,
+                command_line: join_command(process.cmd()),
+                cwd,
+                worktree_path: worktree.map(|(path, _)| path.clone()),
+                branch: worktree.and_then(|(_, branch)| branch.clone()),
+            })
+        })
+        .collect();
+    // A wrapper and the agent it execs both match; keep the outermost.
+    let pids: HashSet<u32> = agents.iter().map(|agent| agent.pid).collect();
+    agents.retain(|agent| {
+        !system
+            .process(Pid::from_u32(agent.pid))
+            .and_then(|process| process.parent())
+            .is_some_and(|parent| pids.contains(&parent.as_u32()))
+    });
+    agents.sort_by(|left, right| {
+        (left.branch.is_none(), &left.cwd, left.pid).cmp(&(
+            right.branch.is_none(),
+            &right.cwd,
+            right.pid,
+        ))
+    });
+    Ok(agents)
+}
+
+/// Track the external agent `pid` as a running session on `branch`.
+pub fn adopt_external_agent(
+    project_root: &Path,
+    pid: u32,
+    branch: &str,
+) -> Result<Session, String> {
+    let branch = branch.trim();
+    if branch.is_empty() {
+        return Err("choose a branch for the agent".to_string());
+    }
+    let agent = detect_external_agents(project_root)?
+        .into_iter()
+        .find(|agent| agent.pid == pid)
+        .ok_or_else(|| format!("process {pid} is not an agent running outside gwt"))?;
+    let worktree_path = gwt_git::WorktreeManager::new(project_root)
+        .list()
+        .map_err(|error| error.to_string())?
+        .into_iter()
+        .find(|worktree| worktree.branch.as_deref() == Some(branch))
+        .map(|worktree| worktree.path)
+        .unwrap_or_else(|| agent.cwd.clone());
+
+    let mut session = Session::new(worktree_path, branch, agent.agent_id);
+    session.adopted_pid = Some(pid);
+    session.launch_command = agent.command_line;
+    session.update_status(AgentStatus::Running);
+    session
+        .save(&gwt_core::paths::gwt_sessions_dir())
+        .map_err(|error| error.to_string())?;
+    Ok(session)
+}
+
+/// Mark adopted sessions whose process exited (or no longer runs an agent)
+/// as stopped. Returns how many changed.
+pub fn refresh_adopted_sessions(sessions_dir: &Path, system: &System) -> usize {
+    let mut stopped = 0;
+    for session in adopted_sessions(sessions_dir) {
+        if session.status == AgentStatus::Stopped {
+            continue;
+        }
+        let Some(pid) = session.adopted_pid else {
+            continue;
+        };
+        let alive = system
+            .process(Pid::from_u32(pid))
+            .and_then(|process| agent_for_command(process.name(), process.cmd()))
+            .is_some_and(|agent| agent == session.agent_id);
+        if alive {
+            continue;
+        }
+        let updated = gwt_agent::update_session(sessions_dir, &session.id, |session| {
+            session.update_status(AgentStatus::Stopped);
+            Ok(())
+        });
+        if updated.is_ok() {
+            stopped += 1;
+        }
+    }
+    stopped
+}
+
+/// Branches of `project_root` with a live adopted agent, after marking
+/// exited ones stopped. Skips the process scan when nothing is adopted.
+pub fn live_adopted_branches(project_root: &Path, sessions_dir: &Path) -> HashSet<String> {
+    let repo_hash =
+        gwt_core::repo_hash::detect_repo_hash(project_root).map(|hash| hash.as_str().to_string());
+    let in_project = |session: &Session| {
+        session.status != AgentStatus::Stopped
+            && repo_hash.is_some()
+            && session.repo_hash == repo_hash
+    };
+    if !adopted_sessions(sessions_dir).iter().any(in_project) {
+        return HashSet::new();
+    }
+    refresh_adopted_sessions(sessions_dir, &process_snapshot());
+    adopted_sessions(sessions_dir)
+        .into_iter()
+        .filter(in_project)
+        .map(|session| session.branch)
+        .collect()
+}
+
+fn adopted_sessions(sessions_dir: &Path) -> Vec<Session> {
+    let Ok(entries) = std::fs::read_dir(sessions_dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("toml"))
+        .filter_map(|path| Session::load(&path).ok())
+        .filter(|session| session.adopted_pid.is_some())
+        .collect()
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    dunce::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn process_snapshot() -> System {
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing()
+            .with_cmd(UpdateKind::Always)
+            .with_cwd(UpdateKind::Always),
+    );
+    system
+}
+
+fn descends_from(system: &System, pid: Pid, ancestor: Pid) -> bool {
+    let mut current = Some(pid);
+    // Bounded walk: a pid cycle in a racy snapshot must not hang the scan.
+    for _ in 0..64 {
+        match current {
+            Some(pid) if pid == ancestor => return true,
+            Some(pid) => current = system.process(pid).and_then(|process| process.parent()),
+            None => return false,
+        }
+    }
+    false
+}
+
+/// Runtimes that start npm-distributed agents as scripts.
+const INTERPRETERS: &[&str] = &["node", "bun", "deno"];
+
+/// The built-in agent a process runs, judged by its executable name or, for
+/// interpreters, by the script it runs. `gh` counts only as `gh copilot`.
+fn agent_for_command(name: &std::ffi::OsStr, cmd: &[OsString]) -> Option<AgentId> {
+    let args: Vec<String> = cmd
+        .iter()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
+    let executable = args
+        .first()
+        .map(|arg| basename(arg))
+        .unwrap_or_else(|| name.to_string_lossy().into_owned());
+    let script = INTERPRETERS
+        .contains(&executable.as_str())
+        .then(|| {
+            args.iter()
+                .enumerate()
+                .skip(1)
+                .find(|(_, arg)| !arg.starts_with('-'))
+                .map(|(index, arg)| (index, basename(arg)))
+        })
+        .flatten();
+    let candidates = std::iter::once((0, executable)).chain(script);
+    for (index, candidate) in candidates {
+        let Some(descriptor) = gwt_agent::types::builtin_agent_descriptor_for_command(&candidate)
+        else {
+            continue;
+        };
+        if descriptor.id == AgentId::Copilot
+            && args.get(index + 1).map(String::as_str) != Some("copilot")
+        {
+            continue;
+        }
+        return Some(descriptor.id.clone());
+    }
+    None
+}
+
+/// File name without a script or executable extension
+/// (`/opt/node_modules/.bin/claude.js` → `claude`).
+fn basename(arg: &str) -> String {
+    let name = Path::new(arg)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+    [".js", ".mjs", ".cjs", ".exe", ".cmd"]
+        .iter()
+        .find_map(|ext| name.strip_suffix(ext))
+        .map(str::to_string)
+        .unwrap_or(name)
+}
+
+fn join_command(cmd: &[OsString]) -> String {
+    cmd.iter()
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect(cmd: &[&str]) -> Option<AgentId> {
+        let cmd: Vec<OsString> = cmd.iter().map(OsString::from).collect();
+        let name = cmd.first().cloned().unwrap_or_default();
+        agent_for_command(&name, &cmd)
+    }
+
+    #[test]
+    fn agents_are_recognized_by_executable_or_script() {
+        assert_eq!(
+            detect(&["/usr/local/bin/claude"]),
+            Some(AgentId::ClaudeCode)
+        );
+        assert_eq!(
+            detect(&["node", "/opt/node_modules/.bin/codex", "--yolo"]),
+            Some(AgentId::Codex)
+        );
+        assert_eq!(
+            detect(&["gh", "copilot", "suggest"]),
+            Some(AgentId::Copilot)
+        );
+        assert_eq!(detect(&["gh", "pr", "list"]), None);
+        assert_eq!(detect(&["vim", "claude.md"]), None);
+        assert_eq!(detect(&["zsh"]), None);
+    }
+}
@@ -0,0 +1,178 @@
+//! Runs and caches the shell commands behind `command` footer widgets.
+//!
+//! Each window polls its widgets on their own `refresh_secs`; the cache keeps
+//! several windows from running the same command more than once per
+//! interval. Only the first non-empty output line is kept.
+
+use std::{
+    collections::HashMap,
+    io::Read,
+    path::Path,
+    process::Stdio,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Commands still running after this are killed.
+pub const FOOTER_COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Characters of output shown in the strip.
+const MAX_OUTPUT_CHARS: usize = 80;
+const POLL_INTERVAL: Duration = Duration::from_millis(25);
+
+#[derive(Debug, Clone)]
+struct CachedOutput {
+    command: String,
+    output: String,
+    fetched_at: Instant,
+}
+
+/// Last output of each command widget, keyed by widget index.
+#[derive(Debug, Default)]
+pub struct FooterCommandCache {
+    entries: HashMap<usize, CachedOutput>,
+}
+
+impl FooterCommandCache {
+    /// Cached output of `command` at `index` if younger than `max_age`. An
+    /// edited command invalidates its entry.
+    pub fn fresh(&self, index: usize, command: &str, max_age: Duration) -> Option<&str> {
+        self.entries
+            .get(&index)
+            .filter(|entry| entry.command == command && entry.fetched_at.elapsed() < max_age)
+            .map(|entry| entry.output.as_str())
+    }
+
+    pub fn store(&mut self, index: usize, command: &str, output: &str) {
+        self.entries.insert(
+            index,
+            CachedOutput {
+                command: command.to_string(),
+                output: output.to_string(),
+                fetched_at: Instant::now(),
+            },
+        );
+    }
+}
+
+/// Run `command` through the platform shell in `cwd` and return its first
+/// non-empty stdout line, or an error describing why there is none.
+pub fn run_widget_command(
+    command: &str,
+    cwd: Option<&Path>,
+    timeout: Duration,
+) -> Result<String, String> {
+    let mut process = if cfg!(windows) {
+        let mut process = gwt_core::process::hidden_command("cmd");
+        process.args(["/C", command]);
+        process
+    } else {
+        let mut process = gwt_core::process::hidden_command("sh");
+        process.args(["-c", command]);
+        process
+    };
+    if let Some(cwd) = cwd {
+        process.current_dir(cwd);
+    }
+    let mut child = process
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|error| format!("failed to start: {error}"))?;
+    let stdout = child.stdout.take().map(|mut stdout| {
+        thread::spawn(move || {
+            let mut buffer = Vec::new();
+            let _ = stdout.read_to_end(&mut buffer);
+            buffer
+        })
+    });
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if started.elapsed() >= timeout => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("timed out after {}s", timeout.as_secs()));
+            }
+            Ok(None) => thread::sleep(POLL_INTERVAL),
+            Err(error) => return Err(format!("failed to wait: {error}")),
+        }
+    };
+    if !status.success() {
+        return Err(format!("exited with {status}"));
+    }
+    let stdout = stdout
+        .and_then(|reader| reader.join().ok())
+        .unwrap_or_default();
+    Ok(first_line(&String::from_utf8_lossy(&stdout)))
+}
+
+fn first_line(output: &str) -> String {
+    let line = output
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .unwrap_or_default();
+    if line.chars().count() <= MAX_OUTPUT_CHARS {
+        return line.to_string();
+    }
+    let mut truncated: String = line.chars().take(MAX_OUTPUT_CHARS - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_line_skips_blank_lines_and_truncates() {
+        assert_eq!(
+            first_line("\n  \n  prod-cluster \nsecond\n"),
+            "prod-cluster"
+        );
+        assert_eq!(first_line(""), "");
+        let long = "x".repeat(MAX_OUTPUT_CHARS + 5);
+        let shown = first_line(&long);
+        assert_eq!(shown.chars().count(), MAX_OUTPUT_CHARS);
+        assert!(shown.ends_with('…'));
+    }
+
+    #[test]
+    fn cache_expires_and_tracks_the_command() {
+        let mut cache = FooterCommandCache::default();
+        cache.store(0, "date", "12:00");
+        assert_eq!(
+            cache.fresh(0, "date", Duration::from_secs(60)),
+            Some("12:00")
+        );
+        assert_eq!(cache.fresh(0, "uptime", Duration::from_secs(60)), None);
+        assert_eq!(cache.fresh(0, "date", Duration::ZERO), None);
+        assert_eq!(cache.fresh(1, "date", Duration::from_secs(60)), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn widget_command_reports_output_failure_and_timeout() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            run_widget_command(
+                "printf '\\nfrom %s\\n' ok",
+                Some(dir.path()),
+                FOOTER_COMMAND_TIMEOUT
+            ),
+            Ok("from ok".to_string())
+        );
+        assert!(run_widget_command("exit 3", None, FOOTER_COMMAND_TIMEOUT)
+            .unwrap_err()
+            .contains("exited"));
+        assert!(
+            run_widget_command("sleep 5", None, Duration::from_millis(50))
+                .unwrap_err()
+                .contains("timed out")
+        );
+    }
+}
@@ -0,0 +1,248 @@
+//! Local-only usage insights.
+//!
+//! With `[insights] enabled = true` in `~/.gwt/config.toml`, gwt counts which
+//! screens are opened, which commands are run, and which agents are started,
+//! and keeps the totals in `~/.gwt/insights.json`. This module has no network
+//! code: the counters only leave the machine when the user runs
+//! `gwt insights export` and shares the output by hand.
+//!
+//! Only fixed identifiers are recorded (action names, window presets, agent
+//! ids), never paths, branch names, or typed text.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+pub const INSIGHTS_FILE_NAME: &str = "insights.json";
+
+/// Longest a recorded count stays in memory before it is merged into the
+/// file. Pending counts are also flushed when the recorder is dropped.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsightKind {
+    Screen,
+    Command,
+    Agent,
+}
+
+/// Counter totals as stored in `insights.json` and printed by
+/// `gwt insights export`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UsageInsights {
+    /// RFC 3339 time of the first recorded count.
+    pub since: Option<String>,
+    /// RFC 3339 time of the last flush.
+    pub updated_at: Option<String>,
+    pub screens: BTreeMap<String, u64>,
+    pub commands: BTreeMap<String, u64>,
+    pub agents: BTreeMap<String, u64>,
+}
+
+impl UsageInsights {
+    /// Read `path`. A missing or unreadable file yields empty insights.
+    pub fn load(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let raw = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        gwt_config::atomic::write_atomic(path, &raw).map_err(io::Error::other)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.screens.is_empty() && self.commands.is_empty() && self.agents.is_empty()
+    }
+
+    pub fn bump(&mut self, kind: InsightKind, name: &str, by: u64) {
+        let counters = match kind {
+            InsightKind::Screen => &mut self.screens,
+            InsightKind::Command => &mut self.commands,
+            InsightKind::Agent => &mut self.agents,
+        };
+        *counters.entry(name.to_string()).or_default() += by;
+    }
+
+    /// Add `other`'s counts into `self`, keeping the earlier `since`.
+    pub fn merge(&mut self, other: &UsageInsights) {
+        for (kind, counters) in [
+            (InsightKind::Screen, &other.screens),
+            (InsightKind::Command, &other.commands),
+            (InsightKind::Agent, &other.agents),
+        ] {
+            for (name, count) in counters {
+                self.bump(kind, name, *count);
+            }
+        }
+        self.since = match (self.since.take(), other.since.clone()) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+    }
+
+    /// Human-readable summary for `gwt insights show`, most used first.
+    pub fn render_text(&self) -> String {
+        let mut out = String::new();
+        if self.is_empty() {
+            out.push_str("No usage recorded yet.\n");
+            return out;
+        }
+        if let Some(since) = &self.since {
+            let _ = writeln!(out, "Since {since}");
+        }
+        for (title, counters) in [
+            ("Screens", &self.screens),
+            ("Commands", &self.commands),
+            ("Agents", &self.agents),
+        ] {
+            if counters.is_empty() {
+                continue;
+            }
+            let mut rows: Vec<(&String, &u64)> = counters.iter().collect();
+            rows.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+            let width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+            let _ = writeln!(out, "\n{title}");
+            for (name, count) in rows {
+                let _ = writeln!(out, "  {name:<width$}  {count}");
+            }
+        }
+        out
+    }
+}
+
+/// `~/.gwt/insights.json`.
+pub fn insights_path() -> PathBuf {
+    gwt_core::paths::gwt_home().join(INSIGHTS_FILE_NAME)
+}
+
+/// Buffers counts in memory and merges them into the insights file at most
+/// every [`FLUSH_INTERVAL`]. Does nothing unless insights are enabled.
+#[derive(Debug)]
+pub struct InsightsRecorder {
+    path: PathBuf,
+    enabled: bool,
+    pending: UsageInsights,
+    last_flush: Instant,
+}
+
+impl InsightsRecorder {
+    pub fn new(path: PathBuf, enabled: bool) -> Self {
+        Self {
+            path,
+            enabled,
+            pending: UsageInsights::default(),
+            last_flush: Instant::now(),
+        }
+    }
+
+    /// Recorder for the global config's `[insights]` setting.
+    pub fn from_settings() -> Self {
+        let enabled = gwt_config::Settings::load()
+            .map(|settings| settings.insights.enabled)
+            .unwrap_or(false);
+        Self::new(insights_path(), enabled)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn record(&mut self, kind: InsightKind, name: &str) {
+        if !self.enabled || name.is_empty() {
+            return;
+        }
+        if self.pending.since.is_none() {
+            self.pending.since = Some(now_rfc3339());
+        }
+        self.pending.bump(kind, name, 1);
+        if self.last_flush.elapsed() >= FLUSH_INTERVAL {
+            self.flush();
+        }
+    }
+
+    /// Merge pending counts into the file.
+    pub fn flush(&mut self) {
+        self.last_flush = Instant::now();
+        if self.pending.is_empty() {
+            return;
+        }
+        let mut stored = UsageInsights::load(&self.path);
+        stored.merge(&self.pending);
+        stored.updated_at = Some(now_rfc3339());
+        match stored.save(&self.path) {
+            Ok(()) => self.pending = UsageInsights::default(),
+            Err(error) => tracing::debug!(
+                path = %self.path.display(),
+                error = %error,
+                "failed to write usage insights"
+            ),
+        }
+    }
+}
+
+impl Drop for InsightsRecorder {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+fn now_rfc3339() -> String {
+    chrono::Utc::now().to_rfc3339()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recorder_merges_pending_counts_into_file_only_when_enabled() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(INSIGHTS_FILE_NAME);
+
+        let mut disabled = InsightsRecorder::new(path.clone(), false);
+        disabled.record(InsightKind::Command, "create_window");
+        drop(disabled);
+        assert!(!path.exists(), "disabled recorder must not write");
+
+        for _ in 0..2 {
+            let mut recorder = InsightsRecorder::new(path.clone(), true);
+            recorder.record(InsightKind::Command, "create_window");
+            recorder.record(InsightKind::Screen, "board");
+            recorder.record(InsightKind::Agent, "claude");
+        }
+
+        let stored = UsageInsights::load(&path);
+        assert_eq!(stored.commands["create_window"], 2);
+        assert_eq!(stored.screens["board"], 2);
+        assert_eq!(stored.agents["claude"], 2);
+        assert!(stored.since.is_some());
+        assert!(stored.updated_at.is_some());
+    }
+
+    #[test]
+    fn render_text_sorts_by_count() {
+        let mut insights = UsageInsights::default();
+        insights.bump(InsightKind::Command, "focus_window", 1);
+        insights.bump(InsightKind::Command, "create_window", 3);
+
+        let text = insights.render_text();
+        let create = text.find("create_window").unwrap();
+        let focus = text.find("focus_window").unwrap();
+        assert!(create < focus, "{text}");
+        assert!(!text.contains("Agents"));
+        assert_eq!(
+            UsageInsights::default().render_text(),
+            "No usage recorded yet.\n"
+        );
+    }
+}
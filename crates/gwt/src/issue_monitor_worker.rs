@@ -165,7 +165,7 @@ pub fn reconcile_issue_monitor_merges(monitor: &mut IssueMonitorState, repo_path
     if monitor.active_launched_branches().is_empty() {
         return;
     }
-    match gwt_git::pr_status::fetch_merged_pr_branches(repo_path) {
+    match gwt_git::fetch_merged_review_branches(repo_path) {
         Ok(merged_branches) => {
             let merged = monitor.reconcile_merged_branches(&merged_branches);
             if !merged.is_empty() {
@@ -953,12 +953,45 @@ fn spec_detail_view(entry: &CacheEntry) -> KnowledgeDetailView {
 
 fn spec_list_meta(entry: &CacheEntry) -> String {
     let phase = effective_spec_lifecycle_label(entry);
+    let tasks = entry
+        .spec_body
+        .sections
+        .get(&SectionName("tasks".to_string()))
+        .map(String::as_str)
+        .and_then(task_progress)
+        .map(|(done, total)| format!(" · Tasks {done}/{total}"))
+        .unwrap_or_default();
     format!(
-        "{phase} · Updated {}",
+        "{phase}{tasks} · Updated {}",
         short_updated_at(&entry.snapshot.updated_at.0)
     )
 }
 
+/// Checked and total `- [ ]` / `- [x]` items of a `tasks` section, or
+/// `None` when it has no checkboxes.
+fn task_progress(markdown: &str) -> Option<(usize, usize)> {
+    let mut done = 0;
+    let mut total = 0;
+    for line in markdown.lines() {
+        let Some(rest) = line
+            .trim_start()
+            .strip_prefix("- [")
+            .or_else(|| line.trim_start().strip_prefix("* ["))
+        else {
+            continue;
+        };
+        match rest.get(..2) {
+            Some("x]" | "X]") => {
+                done += 1;
+                total += 1;
+            }
+            Some(" ]") => total += 1,
+            _ => {}
+        }
+    }
+    (total > 0).then_some((done, total))
+}
+
 fn effective_spec_lifecycle_label(entry: &CacheEntry) -> &'static str {
     if entry.snapshot.state == IssueState::Closed {
         return "Done";
@@ -1515,6 +1548,21 @@ Extra context.
         assert!(!html.contains("javascript:"), "{html}");
     }
 
+    #[test]
+    fn task_progress_counts_checked_items() {
+        let tasks = concat!(
+            "## Phase 1\n",
+            "- [x] T-001 parse\n",
+            "  - [X] T-002 nested\n",
+            "* [ ] T-003 render\n",
+            "- [ ] T-004 toggle\n",
+            "- plain note\n",
+            "- [?] not a checkbox\n",
+        );
+        assert_eq!(task_progress(tasks), Some((2, 4)));
+        assert_eq!(task_progress("No tasks yet."), None);
+    }
+
     #[derive(Debug, Default)]
     struct FakeSemanticSearchClient {
         hits: Vec<SemanticSearchHit>,
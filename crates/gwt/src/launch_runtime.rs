@@ -166,6 +166,22 @@ pub fn resolve_launch_worktree_request(
             .create_from_remote(&remote_branch_ref, &branch_name, &worktree_path)
             .map_err(|err| err.to_string())?;
     }
+    let copied_files = gwt_agent::copy_configured_worktree_files(&main_repo_path, &worktree_path);
+    gwt_core::activity_bus::publish(gwt_core::activity_bus::ActivityEvent::WorktreeCreated {
+        repo_path: Some(main_repo_path.display().to_string()),
+        branch: Some(branch_name.clone()),
+        worktree_path: worktree_path.display().to_string(),
+        copied_files: copied_files.clone(),
+    });
+    gwt::lifecycle_hooks::emit(
+        gwt::lifecycle_hooks::LifecycleEvent::WorktreeCreated,
+        serde_json::json!({
+            "repo_path": main_repo_path.display().to_string(),
+            "branch": branch_name,
+            "worktree_path": worktree_path.display().to_string(),
+            "copied_files": copied_files,
+        }),
+    );
 
     set_worktree_launch_path(working_dir, env_vars, &worktree_path);
     Ok(())
@@ -377,9 +393,16 @@ pub fn build_shell_process_launch(
         });
     }
 
-    let launch = resolve_docker_launch_plan(&worktree, config.docker_service.as_deref())?;
+    let mut launch = resolve_docker_launch_plan(
+        &worktree,
+        config.docker_service.as_deref(),
+        config.docker_profile.as_deref(),
+    )?;
     ensure_docker_launch_runtime_ready()?;
-    ensure_docker_gwt_binary_setup(&launch)?;
+    if launch.devcontainer_workspace.is_none() {
+        ensure_docker_gwt_binary_setup(&launch)?;
+        ensure_docker_port_override(&worktree, &mut launch)?;
+    }
     ensure_docker_launch_service_ready(&launch, config.docker_lifecycle_intent)?;
     let shell_command = resolve_docker_shell_command(&launch)?;
     env.insert("GWT_PROJECT_ROOT".to_string(), launch.container_cwd.clone());
@@ -387,14 +410,28 @@ pub fn build_shell_process_launch(
     config.docker_service = Some(launch.service.clone());
     config.env_vars = env.clone();
 
-    let mut args = vec![
-        "compose".to_string(),
-        "-f".to_string(),
-        launch.compose_file.display().to_string(),
+    if let Some(workspace) = &launch.devcontainer_workspace {
+        let args =
+            gwt_docker::devcontainer_exec_args(workspace, docker_exec_env(&env), &[shell_command]);
+        return Ok(ProcessLaunch {
+            command: devcontainer_binary_for_launch(),
+            args,
+            env,
+            remove_env: Vec::new(),
+            cwd: Some(worktree),
+        });
+    }
+
+    let mut args = vec!["compose".to_string()];
+    args.extend(gwt_docker::compose_project_args(
+        std::slice::from_ref(&launch.compose_file),
+        &launch.profiles,
+    ));
+    args.extend([
         "exec".to_string(),
         "-w".to_string(),
         launch.container_cwd.clone(),
-    ];
+    ]);
     args.extend(docker_compose_exec_env_args(&env));
     args.push(launch.service);
     args.push(shell_command);
@@ -2541,6 +2578,7 @@ mod tests {
             display_name: "Shell".to_string(),
             runtime_target: gwt_agent::LaunchRuntimeTarget::Host,
             docker_service: None,
+            docker_profile: None,
             docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent::Connect,
             windows_shell: Some(gwt_agent::WindowsShellKind::CommandPrompt),
             env_vars: HashMap::new(),
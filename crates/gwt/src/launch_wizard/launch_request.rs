@@ -140,6 +140,12 @@ impl LaunchWizardState {
         if let Some(docker_service) = self.docker_service.as_deref() {
             builder = builder.docker_service(docker_service.to_string());
         }
+        if let Some(docker_profile) = self.docker_profile.as_deref() {
+            builder = builder.docker_profile(docker_profile.to_string());
+        }
+        if let Some(rule) = self.runtime_rule.as_deref() {
+            builder = builder.runtime_rule(rule);
+        }
         builder = builder.docker_lifecycle_intent(self.docker_lifecycle_intent);
         // SPEC-2014 2026-05-18 amendment FR-A:
         // Execution Mode `"resume"` always maps to `SessionMode::Resume`.
@@ -223,6 +229,7 @@ impl LaunchWizardState {
             display_name: "Shell".to_string(),
             runtime_target: self.runtime_target,
             docker_service: self.docker_service.clone(),
+            docker_profile: self.docker_profile.clone(),
             docker_lifecycle_intent: self.docker_lifecycle_intent,
             windows_shell: self.windows_shell_for_launch(),
             env_vars,
@@ -529,6 +536,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["api".to_string()],
             suggested_service: Some("api".to_string()),
+            profiles: Vec::new(),
         });
         let mut state = LaunchWizardState::open_with(ctx, sample_agent_options(), Vec::new());
 
@@ -25,7 +25,7 @@ pub use profiles::{
     previous_launch_profile_from_sessions, previous_launch_profiles_for_repo_from_sessions,
     previous_launch_profiles_from_sessions, quick_start_entries_from_sessions,
 };
-pub use quick_start::{load_quick_start_entries, load_sessions};
+pub use quick_start::{load_quick_start_entries, load_recent_sessions, load_sessions};
 
 const DEFAULT_NEW_BRANCH_BASE_BRANCH: &str = "develop";
 const BRANCH_TYPE_PREFIXES: [&str; 4] = ["feature/", "bugfix/", "hotfix/", "release/"];
@@ -109,6 +109,7 @@ pub enum LaunchWizardStep {
     RuntimeTarget,
     WindowsShell,
     DockerServiceSelect,
+    DockerProfileSelect,
     DockerLifecycle,
     VersionSelect,
     ExecutionMode,
@@ -271,6 +272,19 @@ pub struct LaunchWizardLiveSessionView {
     pub runtime_status: String,
 }
 
+/// Recent-session picker row (dispatches `ResumeRecentSession`).
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LaunchWizardRecentSessionView {
+    pub index: usize,
+    pub agent_id: String,
+    pub agent_label: String,
+    pub session_id: String,
+    /// RFC 3339 start time of the agent session.
+    pub started_at: String,
+    pub preview: Option<String>,
+    pub turns: usize,
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct LaunchWizardSummaryView {
     pub label: String,
@@ -301,6 +315,7 @@ pub struct LaunchWizardView {
     pub start_methods: Vec<LaunchWizardStartMethodView>,
     pub quick_start_entries: Vec<LaunchWizardQuickStartView>,
     pub live_sessions: Vec<LaunchWizardLiveSessionView>,
+    pub recent_sessions: Vec<LaunchWizardRecentSessionView>,
     pub selected_launch_path: String,
     pub selected_quick_start_index: Option<usize>,
     pub selected_live_session_index: Option<usize>,
@@ -321,6 +336,10 @@ pub struct LaunchWizardView {
     pub selected_windows_shell: Option<String>,
     pub docker_service_options: Vec<LaunchWizardOptionView>,
     pub selected_docker_service: Option<String>,
+    pub docker_profile_options: Vec<LaunchWizardOptionView>,
+    pub selected_docker_profile: Option<String>,
+    /// Label of the `[[launch_rules]]` entry behind the runtime selection.
+    pub runtime_rule: Option<String>,
     pub docker_lifecycle_options: Vec<LaunchWizardOptionView>,
     pub selected_docker_lifecycle: String,
     pub version_options: Vec<LaunchWizardOptionView>,
@@ -333,6 +352,7 @@ pub struct LaunchWizardView {
     pub show_runtime_target: bool,
     pub show_windows_shell: bool,
     pub show_docker_service: bool,
+    pub show_docker_profile: bool,
     pub show_docker_lifecycle: bool,
     pub show_version: bool,
     pub show_execution_mode: bool,
@@ -380,6 +400,9 @@ pub struct LaunchWizardView {
     /// Legacy Codex-only compatibility field for older frontend payloads.
     pub codex_fast_mode: bool,
     pub launch_summary: Vec<LaunchWizardSummaryView>,
+    /// Problem with the selected agent's API key found by the pre-launch
+    /// check (`[agent] check_api_keys`). Never blocks launch.
+    pub api_key_warning: Option<String>,
     /// SPEC-2014 FR-126/FR-128: 現在のウィザードフェーズ（rail 表示・クリック判定用）。
     pub phase: WizardPhase,
     pub error: Option<String>,
@@ -409,6 +432,7 @@ pub struct QuickStartEntry {
     pub codex_fast_mode: bool,
     pub runtime_target: gwt_agent::LaunchRuntimeTarget,
     pub docker_service: Option<String>,
+    pub docker_profile: Option<String>,
     pub docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent,
 }
 
@@ -502,6 +526,7 @@ pub struct ShellLaunchConfig {
     pub display_name: String,
     pub runtime_target: gwt_agent::LaunchRuntimeTarget,
     pub docker_service: Option<String>,
+    pub docker_profile: Option<String>,
     pub docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent,
     pub windows_shell: Option<gwt_agent::WindowsShellKind>,
     pub env_vars: HashMap<String, String>,
@@ -547,6 +572,9 @@ impl QuickStartEntry {
 pub struct DockerWizardContext {
     pub services: Vec<String>,
     pub suggested_service: Option<String>,
+    /// Compose profiles declared across the services. Empty when the project
+    /// does not use profiles, which hides the profile step.
+    pub profiles: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -605,6 +633,9 @@ pub struct LaunchWizardHydration {
     /// pick to continue on (the "open existing branch" picker). Empty when no
     /// branch listing was available. Preserved across runtime re-resolution.
     pub open_branch_candidates: Vec<String>,
+    /// First `[[launch_rules]]` entry that holds for the branch; it decides
+    /// the initial runtime selection when the project has a Docker context.
+    pub runtime_rule: Option<gwt_config::LaunchRule>,
 }
 
 #[derive(Debug, Clone)]
@@ -703,6 +734,11 @@ pub enum LaunchWizardAction {
     FocusExistingSession {
         index: usize,
     },
+    /// Resume the chosen row of the recent-session picker instead of the
+    /// latest session.
+    ResumeRecentSession {
+        index: usize,
+    },
     SetBranchMode {
         create_new: bool,
     },
@@ -746,6 +782,10 @@ pub enum LaunchWizardAction {
     SetDockerService {
         service: String,
     },
+    /// Compose profile to activate; an empty string clears the selection.
+    SetDockerProfile {
+        profile: String,
+    },
     SetDockerLifecycle {
         intent: gwt_agent::DockerLifecycleIntent,
     },
@@ -823,7 +863,11 @@ pub struct LaunchWizardState {
     pub runtime_target: gwt_agent::LaunchRuntimeTarget,
     pub windows_shell: gwt_agent::WindowsShellKind,
     pub docker_service: Option<String>,
+    pub docker_profile: Option<String>,
     pub docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent,
+    /// Label of the launch rule that chose `runtime_target`; cleared once
+    /// the user picks a different target.
+    pub runtime_rule: Option<String>,
     pub skip_permissions: bool,
     pub codex_fast_mode: bool,
     /// SPEC-3152: Hermes-specific launch options. Free-text fields map to
@@ -878,4 +922,10 @@ pub struct LaunchWizardState {
     /// SPEC-2359 US-83 / FR-444: eligible existing remote branches offered by the
     /// "open existing branch" picker. Set at hydration / Start Work open.
     pub open_branch_candidates: Vec<String>,
+    /// Agent sessions of the branch's worktree, newest first, offered by the
+    /// recent-session picker. Loaded off-thread after the wizard opens.
+    pub recent_sessions: Vec<gwt_agent::session_export::RecentSession>,
+    /// Problem with the API key of the runtime being resolved, checked
+    /// off-thread by the app runtime.
+    pub api_key_warning: Option<String>,
 }
@@ -547,7 +547,8 @@ impl<'a> LaunchWizardFlow<'a> {
             LaunchWizardStep::ReasoningLevel => self.next_after_agent_configuration(),
             LaunchWizardStep::RuntimeTarget => self.next_after_runtime_target(),
             LaunchWizardStep::WindowsShell => self.next_after_windows_shell(),
-            LaunchWizardStep::DockerServiceSelect => Some(LaunchWizardStep::DockerLifecycle),
+            LaunchWizardStep::DockerServiceSelect => self.next_after_docker_service(),
+            LaunchWizardStep::DockerProfileSelect => Some(LaunchWizardStep::DockerLifecycle),
             LaunchWizardStep::DockerLifecycle => self.next_after_docker_lifecycle(),
             LaunchWizardStep::VersionSelect => Some(LaunchWizardStep::SkipPermissions),
             LaunchWizardStep::ExecutionMode => Some(LaunchWizardStep::SkipPermissions),
@@ -596,11 +597,12 @@ impl<'a> LaunchWizardFlow<'a> {
             }
             LaunchWizardStep::WindowsShell => self.previous_before_windows_shell(),
             LaunchWizardStep::DockerServiceSelect => Some(LaunchWizardStep::RuntimeTarget),
+            LaunchWizardStep::DockerProfileSelect => self.previous_before_docker_profile(),
             LaunchWizardStep::DockerLifecycle => {
-                if self.state.docker_service_prompt_required() {
-                    Some(LaunchWizardStep::DockerServiceSelect)
+                if self.state.docker_profile_prompt_required() {
+                    Some(LaunchWizardStep::DockerProfileSelect)
                 } else {
-                    Some(LaunchWizardStep::RuntimeTarget)
+                    self.previous_before_docker_profile()
                 }
             }
             LaunchWizardStep::VersionSelect => self.previous_before_version_select(),
@@ -634,12 +636,28 @@ impl<'a> LaunchWizardFlow<'a> {
         {
             Some(LaunchWizardStep::DockerServiceSelect)
         } else if self.state.runtime_target == gwt_agent::LaunchRuntimeTarget::Docker {
-            Some(LaunchWizardStep::DockerLifecycle)
+            self.next_after_docker_service()
         } else {
             self.next_after_host_runtime()
         }
     }
 
+    fn next_after_docker_service(&self) -> Option<LaunchWizardStep> {
+        if self.state.docker_profile_prompt_required() {
+            Some(LaunchWizardStep::DockerProfileSelect)
+        } else {
+            Some(LaunchWizardStep::DockerLifecycle)
+        }
+    }
+
+    fn previous_before_docker_profile(&self) -> Option<LaunchWizardStep> {
+        if self.state.docker_service_prompt_required() {
+            Some(LaunchWizardStep::DockerServiceSelect)
+        } else {
+            Some(LaunchWizardStep::RuntimeTarget)
+        }
+    }
+
     fn next_after_host_runtime(&self) -> Option<LaunchWizardStep> {
         if self.state.runtime_context_resolved && self.state.show_windows_shell_selection() {
             Some(LaunchWizardStep::WindowsShell)
@@ -773,6 +791,11 @@ pub(super) fn step_default_selection(step: LaunchWizardStep, state: &LaunchWizar
                     .position(|option| option == service)
             })
             .unwrap_or(0),
+        LaunchWizardStep::DockerProfileSelect => state
+            .docker_profile_options()
+            .iter()
+            .position(|option| *option == state.docker_profile)
+            .unwrap_or(0),
         LaunchWizardStep::DockerLifecycle => state
             .docker_lifecycle_options()
             .iter()
@@ -1323,6 +1346,7 @@ mod tests {
         docker.context.docker_context = Some(DockerWizardContext {
             services: vec!["api".to_string(), "worker".to_string()],
             suggested_service: Some("api".to_string()),
+            profiles: Vec::new(),
         });
         docker.runtime_target = gwt_agent::LaunchRuntimeTarget::Docker;
 
@@ -1556,6 +1580,7 @@ mod tests {
             codex_fast_mode: true,
             runtime_target: gwt_agent::LaunchRuntimeTarget::Docker,
             docker_service: Some("gwt".to_string()),
+            docker_profile: None,
             docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent::Restart,
         });
 
@@ -1568,6 +1593,7 @@ mod tests {
         docker_context.docker_context = Some(DockerWizardContext {
             services: vec!["api".to_string(), "worker".to_string()],
             suggested_service: Some("worker".to_string()),
+            profiles: Vec::new(),
         });
         docker_context.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         let mut state =
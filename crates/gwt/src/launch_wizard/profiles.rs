@@ -450,6 +450,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["api".to_string()],
             suggested_service: Some("api".to_string()),
+            profiles: Vec::new(),
         });
         ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Stopped;
 
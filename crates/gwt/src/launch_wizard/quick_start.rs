@@ -33,6 +33,30 @@ pub fn load_quick_start_entries(
     collect_quick_start_entries_from_sessions(repo_path, branch_name, load_sessions(sessions_dir))
 }
 
+/// Agent sessions for the recent-session picker: those of `branch_name`
+/// under `repo_path`, newest first, at most `per_agent` of each agent, with
+/// previews read from their transcripts.
+pub fn load_recent_sessions(
+    repo_path: &Path,
+    branch_name: &str,
+    sessions: &[gwt_agent::Session],
+    per_agent: usize,
+) -> Vec<gwt_agent::session_export::RecentSession> {
+    let repo_scope = WorktreePathScope::new(repo_path);
+    let branch_sessions: Vec<gwt_agent::Session> = sessions
+        .iter()
+        .filter(|session| {
+            session.branch == branch_name && repo_scope.matches(&session.worktree_path)
+        })
+        .cloned()
+        .collect();
+    gwt_agent::session_export::recent_sessions(
+        &branch_sessions,
+        per_agent,
+        gwt_agent::session_export::summarize_session_transcript,
+    )
+}
+
 pub(super) fn collect_quick_start_entries_from_sessions(
     repo_path: &Path,
     branch_name: &str,
@@ -87,6 +111,7 @@ pub(super) fn collect_quick_start_entries_from_sessions(
             codex_fast_mode: session.fast_mode_enabled(),
             runtime_target: session.runtime_target,
             docker_service: session.docker_service.clone(),
+            docker_profile: session.docker_profile.clone(),
             docker_lifecycle_intent: session.docker_lifecycle_intent,
         })
         .collect()
@@ -67,6 +67,7 @@ impl LaunchWizardState {
             runtime_target,
             windows_shell: default_windows_shell_kind(),
             docker_service,
+            docker_profile: None,
             docker_lifecycle_intent,
             skip_permissions: false,
             codex_fast_mode: false,
@@ -97,6 +98,9 @@ impl LaunchWizardState {
             settings_revisited: false,
             resolved_branch_name: None,
             open_branch_candidates: Vec::new(),
+            recent_sessions: Vec::new(),
+            api_key_warning: None,
+            runtime_rule: None,
         };
         state.branch_name = state.context.normalized_branch_name.clone();
         state.sync_selected_agent_options();
@@ -309,6 +313,7 @@ impl LaunchWizardState {
             mut quick_start_entries,
             previous_profiles,
             open_branch_candidates,
+            runtime_rule,
         } = hydration;
         // FR-444: preserve picker candidates from the initial hydration across
         // runtime re-resolution (which carries an empty list).
@@ -355,7 +360,12 @@ impl LaunchWizardState {
                 );
             self.runtime_target = resolved_target;
             self.docker_service = resolved_service;
+            self.docker_profile = None;
             self.docker_lifecycle_intent = resolved_lifecycle;
+            self.runtime_rule = None;
+            if let Some(rule) = runtime_rule {
+                self.apply_launch_rule(&rule);
+            }
         }
         self.sync_selected_agent_options();
         // SPEC-2014 FR-054 / FR-056 (2026-05-15 Wizard Hydration Preserves User-Selected Agent):
@@ -401,6 +411,8 @@ impl LaunchWizardState {
         self.context.docker_service_status = gwt_docker::ComposeServiceStatus::NotFound;
         self.runtime_target = gwt_agent::LaunchRuntimeTarget::Host;
         self.docker_service = None;
+        self.docker_profile = None;
+        self.runtime_rule = None;
         self.docker_lifecycle_intent =
             default_docker_lifecycle_intent(self.context.docker_service_status);
         self.sync_docker_lifecycle_default();
@@ -494,6 +506,9 @@ impl LaunchWizardState {
             LaunchWizardAction::FocusExistingSession { index } => {
                 self.focus_existing_session(index);
             }
+            LaunchWizardAction::ResumeRecentSession { index } => {
+                self.resume_recent_session(index);
+            }
             LaunchWizardAction::SetBranchMode { create_new } => {
                 self.set_branch_mode(create_new);
             }
@@ -530,6 +545,9 @@ impl LaunchWizardState {
             LaunchWizardAction::SetDockerService { service } => {
                 self.set_docker_service(&service);
             }
+            LaunchWizardAction::SetDockerProfile { profile } => {
+                self.set_docker_profile(&profile);
+            }
             LaunchWizardAction::SetDockerLifecycle { intent } => {
                 self.set_docker_lifecycle(intent);
             }
@@ -689,17 +707,11 @@ impl LaunchWizardState {
                 }
             }
             LaunchWizardStep::RuntimeTarget => {
-                self.runtime_target = if self.selected == 0 {
+                self.set_runtime_target(if self.selected == 0 {
                     gwt_agent::LaunchRuntimeTarget::Host
                 } else {
                     gwt_agent::LaunchRuntimeTarget::Docker
-                };
-                if self.runtime_target == gwt_agent::LaunchRuntimeTarget::Host {
-                    self.docker_service = None;
-                } else if self.docker_service.is_none() {
-                    self.docker_service = self.preferred_docker_service().map(str::to_string);
-                }
-                self.sync_docker_lifecycle_default();
+                });
             }
             LaunchWizardStep::WindowsShell => {
                 if let Some(option) = WINDOWS_SHELL_OPTIONS.get(self.selected) {
@@ -712,6 +724,11 @@ impl LaunchWizardState {
                 }
                 self.sync_docker_lifecycle_default();
             }
+            LaunchWizardStep::DockerProfileSelect => {
+                if let Some(profile) = self.docker_profile_options().get(self.selected) {
+                    self.docker_profile = profile.clone();
+                }
+            }
             LaunchWizardStep::DockerLifecycle => {
                 if let Some(option) = self.docker_lifecycle_options().get(self.selected) {
                     self.docker_lifecycle_intent = option.intent;
@@ -973,10 +990,23 @@ impl LaunchWizardState {
         self.selected_quick_start_index = Some(index);
         self.launch_path = LaunchWizardLaunchPath::QuickStart;
         self.start_method_selected = true;
+        self.apply_quick_start_session_settings(&entry);
+        if let Some(resume_session_id) = entry.resume_session_id.clone() {
+            self.mode = "resume".to_string();
+            self.resume_session_id = Some(resume_session_id);
+        } else {
+            self.mode = "continue".to_string();
+            self.resume_session_id = None;
+        }
+        self.finish_launch_request();
+    }
+
+    /// Launch settings of a saved session, used when continuing it.
+    fn apply_quick_start_session_settings(&mut self, entry: &QuickStartEntry) {
         self.launch_target = LaunchTargetKind::Agent;
         self.agent_id = entry.agent_id.clone();
         self.sync_selected_agent_options();
-        self.apply_quick_start_runtime_selection(&entry);
+        self.apply_quick_start_runtime_selection(entry);
         self.apply_saved_model(entry.model.as_deref());
         self.apply_restored_reasoning(entry.reasoning.clone());
         if let Some(version) = entry.version.clone() {
@@ -984,13 +1014,41 @@ impl LaunchWizardState {
         }
         self.skip_permissions = entry.skip_permissions;
         self.codex_fast_mode = entry.codex_fast_mode && self.current_agent_supports_fast_mode();
-        if let Some(resume_session_id) = entry.resume_session_id.clone() {
-            self.mode = "resume".to_string();
-            self.resume_session_id = Some(resume_session_id);
-        } else {
-            self.mode = "continue".to_string();
-            self.resume_session_id = None;
+    }
+
+    /// Resume the picked recent session. Settings come from the saved
+    /// session of the same agent when there is one; otherwise only the agent
+    /// and model of the picked session are restored.
+    fn resume_recent_session(&mut self, index: usize) {
+        let Some(recent) = self.recent_sessions.get(index).cloned() else {
+            self.error = Some("Recent session is unavailable".to_string());
+            return;
+        };
+        if !self.agent_supports_resume_session_id(&recent.agent_id) {
+            self.error = Some("Session resume is unavailable for this agent".to_string());
+            return;
         }
+        self.launch_path = LaunchWizardLaunchPath::QuickStart;
+        self.start_method_selected = true;
+        match self
+            .quick_start_entries
+            .iter()
+            .position(|entry| entry.agent_id == recent.agent_id)
+        {
+            Some(entry_index) => {
+                let entry = self.quick_start_entries[entry_index].clone();
+                self.selected_quick_start_index = Some(entry_index);
+                self.apply_quick_start_session_settings(&entry);
+            }
+            None => {
+                self.launch_target = LaunchTargetKind::Agent;
+                self.agent_id = recent.agent_id.clone();
+                self.sync_selected_agent_options();
+                self.apply_saved_model(recent.model.as_deref());
+            }
+        }
+        self.mode = "resume".to_string();
+        self.resume_session_id = Some(recent.agent_session_id);
         self.finish_launch_request();
     }
 
@@ -1075,6 +1133,11 @@ impl LaunchWizardState {
     fn apply_quick_start_runtime_selection(&mut self, entry: &QuickStartEntry) {
         self.runtime_target = entry.runtime_target;
         self.docker_service = entry.docker_service.clone();
+        self.docker_profile = entry.docker_profile.clone().filter(|profile| {
+            self.docker_profile_options()
+                .iter()
+                .any(|option| option.as_deref() == Some(profile.as_str()))
+        });
         self.docker_lifecycle_intent = entry.docker_lifecycle_intent;
         self.sync_docker_lifecycle_default();
     }
@@ -1363,10 +1426,43 @@ impl LaunchWizardState {
         self.sync_reasoning_state();
     }
 
+    /// Pre-select the runtime a `[[launch_rules]]` entry asks for. Without a
+    /// Docker context there is nothing to choose, so the rule is ignored.
+    fn apply_launch_rule(&mut self, rule: &gwt_config::LaunchRule) {
+        let Some(docker_context) = self.context.docker_context.as_ref() else {
+            return;
+        };
+        match rule.runtime {
+            gwt_config::LaunchRuleRuntime::Host => {
+                self.runtime_target = gwt_agent::LaunchRuntimeTarget::Host;
+                self.docker_service = None;
+                self.docker_profile = None;
+            }
+            gwt_config::LaunchRuleRuntime::Docker => {
+                let rule_service = rule
+                    .service
+                    .as_ref()
+                    .filter(|service| docker_context.services.contains(service))
+                    .cloned();
+                self.runtime_target = gwt_agent::LaunchRuntimeTarget::Docker;
+                if let Some(service) = rule_service {
+                    self.docker_service = Some(service);
+                } else if self.docker_service.is_none() {
+                    self.docker_service = self.preferred_docker_service().map(str::to_string);
+                }
+            }
+        }
+        self.runtime_rule = Some(rule.label());
+    }
+
     pub(super) fn set_runtime_target(&mut self, target: gwt_agent::LaunchRuntimeTarget) {
+        if target != self.runtime_target {
+            self.runtime_rule = None;
+        }
         self.runtime_target = target;
         if self.runtime_target == gwt_agent::LaunchRuntimeTarget::Host {
             self.docker_service = None;
+            self.docker_profile = None;
         } else if self.docker_service.is_none() {
             self.docker_service = self.preferred_docker_service().map(str::to_string);
         }
@@ -1386,6 +1482,15 @@ impl LaunchWizardState {
         }
     }
 
+    pub(super) fn set_docker_profile(&mut self, profile: &str) {
+        let profile = (!profile.is_empty()).then(|| profile.to_string());
+        if self.docker_profile_options().contains(&profile) {
+            self.docker_profile = profile;
+        } else {
+            self.error = Some("Docker profile is unavailable".to_string());
+        }
+    }
+
     pub(super) fn set_docker_lifecycle(&mut self, intent: gwt_agent::DockerLifecycleIntent) {
         if self
             .docker_lifecycle_options()
@@ -1622,6 +1727,21 @@ impl LaunchWizardState {
         self.hermes_provider_choices = choices;
     }
 
+    /// Recent agent sessions of the branch's worktree, loaded off-thread by
+    /// the app runtime after the wizard opens.
+    pub fn set_recent_sessions(
+        &mut self,
+        recent_sessions: Vec<gwt_agent::session_export::RecentSession>,
+    ) {
+        self.recent_sessions = recent_sessions;
+    }
+
+    /// Warning about the API key of the runtime being resolved; `None`
+    /// clears it.
+    pub fn set_api_key_warning(&mut self, warning: Option<String>) {
+        self.api_key_warning = warning;
+    }
+
     /// SPEC-3152 FR-005: whether the user's global Hermes home is unconfigured,
     /// populated by the app runtime at wizard open.
     pub fn set_hermes_needs_setup(&mut self, needs_setup: bool) {
@@ -1658,6 +1778,7 @@ impl LaunchWizardState {
             display_name: "OpenCode Setup".to_string(),
             runtime_target: gwt_agent::LaunchRuntimeTarget::Host,
             docker_service: None,
+            docker_profile: None,
             docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent::default(),
             windows_shell: self.windows_shell_for_launch(),
             env_vars: HashMap::new(),
@@ -1800,6 +1921,26 @@ impl LaunchWizardState {
             .unwrap_or_default()
     }
 
+    pub(super) fn docker_profile_prompt_required(&self) -> bool {
+        self.context
+            .docker_context
+            .as_ref()
+            .is_some_and(|ctx| !ctx.profiles.is_empty())
+    }
+
+    /// `None` (no profile) followed by every profile declared in the compose
+    /// file.
+    pub(super) fn docker_profile_options(&self) -> Vec<Option<String>> {
+        std::iter::once(None)
+            .chain(
+                self.context
+                    .docker_context
+                    .iter()
+                    .flat_map(|ctx| ctx.profiles.iter().cloned().map(Some)),
+            )
+            .collect()
+    }
+
     pub(super) fn docker_lifecycle_options(&self) -> &'static [DockerLifecycleOption] {
         match self.context.docker_service_status {
             gwt_docker::ComposeServiceStatus::Unknown => &[DockerLifecycleOption {
@@ -2157,6 +2298,7 @@ mod tests {
                 codex_fast_mode: true,
                 runtime_target: gwt_agent::LaunchRuntimeTarget::Host,
                 docker_service: None,
+                docker_profile: None,
                 docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent::Connect,
             }],
         );
@@ -2260,6 +2402,7 @@ mod tests {
                 codex_fast_mode: false,
                 runtime_target: gwt_agent::LaunchRuntimeTarget::Host,
                 docker_service: None,
+                docker_profile: None,
                 docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent::Connect,
             }],
         );
@@ -2307,6 +2450,7 @@ mod tests {
                 codex_fast_mode: false,
                 runtime_target: gwt_agent::LaunchRuntimeTarget::Host,
                 docker_service: None,
+                docker_profile: None,
                 docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent::Connect,
             }],
         );
@@ -2507,6 +2651,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["api".to_string(), "gwt".to_string()],
             suggested_service: Some("api".to_string()),
+            profiles: Vec::new(),
         });
         ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         let state = LaunchWizardState::open_with_previous_profile(
@@ -2581,12 +2726,14 @@ mod tests {
             docker_context: Some(DockerWizardContext {
                 services: vec!["api".to_string()],
                 suggested_service: Some("api".to_string()),
+                profiles: Vec::new(),
             }),
             docker_service_status: gwt_docker::ComposeServiceStatus::Running,
             agent_options: sample_agent_options(),
             quick_start_entries: Vec::new(),
             previous_profiles: None,
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
         let view = state.view();
         assert_eq!(view.selected_runtime_target, "host");
@@ -2601,6 +2748,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["only".to_string()],
             suggested_service: None,
+            profiles: Vec::new(),
         });
         ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         let state = LaunchWizardState::open_with_previous_profile(
@@ -2634,6 +2782,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["api".to_string(), "gwt".to_string()],
             suggested_service: Some("api".to_string()),
+            profiles: Vec::new(),
         });
         ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         let state = LaunchWizardState::open_with_previous_profile(
@@ -2670,6 +2819,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["api".to_string(), "worker".to_string()],
             suggested_service: Some("api".to_string()),
+            profiles: Vec::new(),
         });
         ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         let state = LaunchWizardState::open_with_previous_profile(
@@ -2773,6 +2923,7 @@ mod tests {
             quick_start_entries: Vec::new(),
             previous_profiles: Some(Default::default()),
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
 
         state.apply(LaunchWizardAction::Submit);
@@ -2856,6 +3007,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["api".to_string(), "worker".to_string()],
             suggested_service: Some("worker".to_string()),
+            profiles: Vec::new(),
         });
         ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         let state = LaunchWizardState::open_with_previous_profile(
@@ -3011,6 +3163,7 @@ mod tests {
             docker_context: Some(DockerWizardContext {
                 services: vec!["app".to_string()],
                 suggested_service: Some("app".to_string()),
+                profiles: Vec::new(),
             }),
             docker_service_status: gwt_docker::ComposeServiceStatus::Running,
             agent_options: sample_agent_options(),
@@ -3031,6 +3184,7 @@ mod tests {
                 },
             ))),
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
 
         assert_eq!(
@@ -3077,6 +3231,7 @@ mod tests {
             quick_start_entries: Vec::new(),
             previous_profiles: None,
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
 
         let view = state.view();
@@ -3128,6 +3283,7 @@ mod tests {
             quick_start_entries: Vec::new(),
             previous_profiles: Some(previous_profiles),
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
 
         let view = state.view();
@@ -3173,6 +3329,7 @@ mod tests {
                 quick_start_entries: Vec::new(),
                 previous_profiles: Some(previous_profiles),
                 open_branch_candidates: Vec::new(),
+                runtime_rule: None,
             });
 
             assert_eq!(state.view().selected_execution_mode, mode);
@@ -3204,6 +3361,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn resume_recent_session_launches_the_picked_conversation() {
+        let mut state = LaunchWizardState::open_with(
+            context(branch("feature/current"), "feature/current"),
+            sample_agent_options(),
+            Vec::new(),
+        );
+        state.set_recent_sessions(vec![gwt_agent::session_export::RecentSession {
+            agent_id: "codex".to_string(),
+            agent: "Codex".to_string(),
+            agent_session_id: "rollout-older".to_string(),
+            model: None,
+            started_at: Utc::now(),
+            preview: Some("Fix the flaky test".to_string()),
+            turns: 3,
+        }]);
+
+        let view = state.view();
+        assert_eq!(view.recent_sessions[0].session_id, "rollout-older");
+        assert_eq!(view.recent_sessions[0].turns, 3);
+
+        state.apply(LaunchWizardAction::ResumeRecentSession { index: 1 });
+        assert_eq!(
+            state.error.as_deref(),
+            Some("Recent session is unavailable")
+        );
+        assert!(state.completion.is_none());
+
+        state.apply(LaunchWizardAction::ResumeRecentSession { index: 0 });
+        match state.completion.as_ref() {
+            Some(LaunchWizardCompletion::Launch(config)) => match config.as_ref() {
+                LaunchWizardLaunchRequest::Agent(config) => {
+                    assert_eq!(config.agent_id, gwt_agent::AgentId::Codex);
+                    assert_eq!(config.session_mode, gwt_agent::SessionMode::Resume);
+                    assert_eq!(config.resume_session_id.as_deref(), Some("rollout-older"));
+                }
+                other => panic!("expected agent launch request, got {other:?}"),
+            },
+            other => panic!("expected launch completion, got {other:?}"),
+        }
+    }
+
     #[test]
     fn continue_last_session_without_exact_resume_id_uses_agent_latest_session() {
         let mut state = LaunchWizardState::open_with(
@@ -3380,6 +3579,7 @@ mod tests {
             quick_start_entries: Vec::new(),
             previous_profiles: Some(previous_profiles),
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
 
         let view = state.view();
@@ -3434,6 +3634,7 @@ mod tests {
             quick_start_entries: Vec::new(),
             previous_profiles: Some(Default::default()),
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
 
         assert!(state.view().fast_mode);
@@ -3592,6 +3793,7 @@ mod tests {
             docker_context: Some(DockerWizardContext {
                 services: vec!["app".to_string(), "worker".to_string()],
                 suggested_service: Some("app".to_string()),
+                profiles: Vec::new(),
             }),
             docker_service_status: gwt_docker::ComposeServiceStatus::Running,
             agent_options: sample_agent_options(),
@@ -3608,10 +3810,12 @@ mod tests {
                 codex_fast_mode: true,
                 runtime_target: gwt_agent::LaunchRuntimeTarget::Host,
                 docker_service: None,
+                docker_profile: None,
                 docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent::Connect,
             }],
             previous_profiles: Some(LaunchWizardPreviousProfiles::default()),
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
 
         let view = state.view();
@@ -3634,4 +3838,59 @@ mod tests {
         assert_eq!(view.agent_options.len(), 2);
         assert_eq!(view.selected_runtime_target, "docker");
     }
+    #[test]
+    fn launch_rule_decides_runtime_until_user_overrides_it() {
+        let hydrate = |rule: gwt_config::LaunchRule| {
+            let mut state = LaunchWizardState::open_loading(
+                context(branch("feature/api"), "feature/api"),
+                Vec::new(),
+            );
+            let worktree = PathBuf::from("/tmp/repo-api");
+            state.apply_hydration(LaunchWizardHydration {
+                selected_branch: None,
+                normalized_branch_name: "feature/api".to_string(),
+                worktree_path: Some(worktree.clone()),
+                quick_start_root: worktree,
+                docker_context: Some(DockerWizardContext {
+                    services: vec!["app".to_string(), "worker".to_string()],
+                    suggested_service: Some("app".to_string()),
+                    profiles: Vec::new(),
+                }),
+                docker_service_status: gwt_docker::ComposeServiceStatus::Running,
+                agent_options: sample_agent_options(),
+                quick_start_entries: Vec::new(),
+                previous_profiles: Some(LaunchWizardPreviousProfiles::default()),
+                open_branch_candidates: Vec::new(),
+                runtime_rule: Some(rule),
+            });
+            state
+        };
+
+        let host = hydrate(gwt_config::LaunchRule {
+            only_paths: vec!["docs/".to_string()],
+            ..gwt_config::LaunchRule::default()
+        });
+        assert_eq!(host.runtime_target, gwt_agent::LaunchRuntimeTarget::Host);
+        assert_eq!(host.docker_service, None);
+        assert_eq!(
+            host.view().runtime_rule.as_deref(),
+            Some("only docs/ → host")
+        );
+
+        let mut docker = hydrate(gwt_config::LaunchRule {
+            changed_paths: vec!["services/".to_string()],
+            runtime: gwt_config::LaunchRuleRuntime::Docker,
+            service: Some("worker".to_string()),
+            ..gwt_config::LaunchRule::default()
+        });
+        assert_eq!(
+            docker.runtime_target,
+            gwt_agent::LaunchRuntimeTarget::Docker
+        );
+        assert_eq!(docker.docker_service.as_deref(), Some("worker"));
+        assert!(docker.runtime_rule.is_some());
+
+        docker.set_runtime_target(gwt_agent::LaunchRuntimeTarget::Host);
+        assert_eq!(docker.runtime_rule, None);
+    }
 }
@@ -65,6 +65,10 @@ pub(super) fn branch(name: &str) -> BranchListEntry {
         cleanup: crate::BranchCleanupInfo::default(),
         resume: crate::BranchResumeInfo::unavailable(),
         start_work_eligibility: None,
+        worktree_path: None,
+        archived: false,
+        integrity_issues: Vec::new(),
+        linked_spec: None,
     }
 }
 
@@ -225,6 +229,7 @@ pub(super) fn quick_start_entry(
         codex_fast_mode,
         runtime_target,
         docker_service: docker_service.map(str::to_string),
+        docker_profile: None,
         docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent::Restart,
     }
 }
@@ -45,6 +45,7 @@ impl LaunchWizardState {
             start_methods: self.start_methods_view(),
             quick_start_entries: self.quick_start_entries_view(),
             live_sessions: self.live_sessions_view(),
+            recent_sessions: self.recent_sessions_view(),
             selected_launch_path: self.launch_path.value().to_string(),
             selected_quick_start_index: self.selected_quick_start_index,
             selected_live_session_index: self.selected_live_session_index,
@@ -71,6 +72,9 @@ impl LaunchWizardState {
                 .map(|shell| windows_shell_option_value(shell).to_string()),
             docker_service_options: self.docker_service_options_view(),
             selected_docker_service: self.docker_service.clone(),
+            docker_profile_options: self.docker_profile_options_view(),
+            selected_docker_profile: self.docker_profile.clone(),
+            runtime_rule: self.runtime_rule.clone(),
             docker_lifecycle_options: self.docker_lifecycle_options_view(),
             selected_docker_lifecycle: docker_lifecycle_value(self.docker_lifecycle_intent)
                 .to_string(),
@@ -92,6 +96,9 @@ impl LaunchWizardState {
             show_docker_service: self.runtime_target == gwt_agent::LaunchRuntimeTarget::Docker
                 && show_runtime_confirmation
                 && self.docker_service_prompt_required(),
+            show_docker_profile: self.runtime_target == gwt_agent::LaunchRuntimeTarget::Docker
+                && show_runtime_confirmation
+                && self.docker_profile_prompt_required(),
             show_docker_lifecycle: self.runtime_target == gwt_agent::LaunchRuntimeTarget::Docker
                 && show_runtime_confirmation,
             show_version: show_manual_setup
@@ -134,6 +141,10 @@ impl LaunchWizardState {
             fast_mode,
             codex_fast_mode: self.codex_fast_mode && self.agent_is_codex(),
             launch_summary: self.launch_summary_view(),
+            api_key_warning: self
+                .api_key_warning
+                .clone()
+                .filter(|_| self.launch_target_is_agent()),
             phase: self.current_phase(),
             error: self.error.clone(),
         }
@@ -364,6 +375,22 @@ impl LaunchWizardState {
             .collect()
     }
 
+    fn recent_sessions_view(&self) -> Vec<LaunchWizardRecentSessionView> {
+        self.recent_sessions
+            .iter()
+            .enumerate()
+            .map(|(index, session)| LaunchWizardRecentSessionView {
+                index,
+                agent_id: session.agent_id.clone(),
+                agent_label: session.agent.clone(),
+                session_id: session.agent_session_id.clone(),
+                started_at: session.started_at.to_rfc3339(),
+                preview: session.preview.clone(),
+                turns: session.turns,
+            })
+            .collect()
+    }
+
     fn agent_options_view(&self) -> Vec<LaunchWizardOptionView> {
         self.detected_agents
             .iter()
@@ -412,6 +439,26 @@ impl LaunchWizardState {
             .collect()
     }
 
+    fn docker_profile_options_view(&self) -> Vec<LaunchWizardOptionView> {
+        self.docker_profile_options()
+            .into_iter()
+            .map(|profile| match profile {
+                Some(profile) => LaunchWizardOptionView {
+                    value: profile.clone(),
+                    label: profile,
+                    description: Some("Docker Compose profile".to_string()),
+                    color: None,
+                },
+                None => LaunchWizardOptionView {
+                    value: String::new(),
+                    label: "No profile".to_string(),
+                    description: Some("Only start services without a profile".to_string()),
+                    color: None,
+                },
+            })
+            .collect()
+    }
+
     fn docker_lifecycle_options_view(&self) -> Vec<LaunchWizardOptionView> {
         self.docker_lifecycle_options()
             .iter()
@@ -850,6 +897,7 @@ impl LaunchWizardState {
                     color: None,
                 })
                 .collect(),
+            LaunchWizardStep::DockerProfileSelect => self.docker_profile_options_view(),
             LaunchWizardStep::DockerLifecycle => self
                 .docker_lifecycle_options()
                 .iter()
@@ -1536,6 +1584,7 @@ mod tests {
             quick_start_entries: Vec::new(),
             previous_profiles: Some(LaunchWizardPreviousProfiles::from_profile(Some(previous))),
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
 
         let confirmation = state.view();
@@ -1576,6 +1625,7 @@ mod tests {
                 codex_fast_mode: true,
                 runtime_target: gwt_agent::LaunchRuntimeTarget::Docker,
                 docker_service: Some("gwt".to_string()),
+                docker_profile: None,
                 docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent::Restart,
             }],
         );
@@ -1615,6 +1665,7 @@ mod tests {
                 codex_fast_mode: true,
                 runtime_target: gwt_agent::LaunchRuntimeTarget::Host,
                 docker_service: None,
+                docker_profile: None,
                 docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent::Connect,
             }],
         );
@@ -1666,6 +1717,7 @@ mod tests {
                 codex_fast_mode: true,
                 runtime_target: gwt_agent::LaunchRuntimeTarget::Host,
                 docker_service: None,
+                docker_profile: None,
                 docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent::Connect,
             }],
         );
@@ -1740,6 +1792,7 @@ mod tests {
                 codex_fast_mode: true,
                 runtime_target: gwt_agent::LaunchRuntimeTarget::Host,
                 docker_service: None,
+                docker_profile: None,
                 docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent::Connect,
             }],
         );
@@ -1781,6 +1834,7 @@ mod tests {
                 codex_fast_mode: true,
                 runtime_target: gwt_agent::LaunchRuntimeTarget::Host,
                 docker_service: None,
+                docker_profile: None,
                 docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent::Connect,
             }],
         );
@@ -1865,6 +1919,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["api".to_string(), "worker".to_string()],
             suggested_service: Some("worker".to_string()),
+            profiles: Vec::new(),
         });
         ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         let mut state = LaunchWizardState::open_with(ctx, sample_agent_options(), Vec::new());
@@ -1959,6 +2014,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["api".to_string(), "worker".to_string()],
             suggested_service: Some("api".to_string()),
+            profiles: Vec::new(),
         });
         ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         ctx.live_sessions = vec![LiveSessionEntry {
@@ -2176,6 +2232,7 @@ mod tests {
                 codex_fast_mode: false,
                 runtime_target: gwt_agent::LaunchRuntimeTarget::Host,
                 docker_service: None,
+                docker_profile: None,
                 docker_lifecycle_intent: gwt_agent::DockerLifecycleIntent::Connect,
             }],
         );
@@ -2205,6 +2262,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["app".to_string()],
             suggested_service: Some("app".to_string()),
+            profiles: Vec::new(),
         });
         ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         let mut state = LaunchWizardState::open_with_previous_profiles(
@@ -2236,12 +2294,14 @@ mod tests {
             docker_context: Some(DockerWizardContext {
                 services: vec!["app".to_string()],
                 suggested_service: Some("app".to_string()),
+                profiles: Vec::new(),
             }),
             docker_service_status: gwt_docker::ComposeServiceStatus::Running,
             agent_options: sample_agent_options(),
             quick_start_entries: Vec::new(),
             previous_profiles: Some(Default::default()),
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
 
         let view = state.view();
@@ -2272,6 +2332,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["app".to_string()],
             suggested_service: Some("app".to_string()),
+            profiles: Vec::new(),
         });
         ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         let mut state = LaunchWizardState::open_with(ctx, sample_agent_options(), Vec::new());
@@ -2295,12 +2356,14 @@ mod tests {
             docker_context: Some(DockerWizardContext {
                 services: vec!["app".to_string()],
                 suggested_service: Some("app".to_string()),
+                profiles: Vec::new(),
             }),
             docker_service_status: gwt_docker::ComposeServiceStatus::Running,
             agent_options: sample_agent_options(),
             quick_start_entries: Vec::new(),
             previous_profiles: Some(Default::default()),
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
 
         let view = state.view();
@@ -2343,6 +2406,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["app".to_string()],
             suggested_service: Some("app".to_string()),
+            profiles: Vec::new(),
         });
         ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         let mut state = LaunchWizardState::open_with(ctx, sample_agent_options(), Vec::new());
@@ -2366,12 +2430,14 @@ mod tests {
             docker_context: Some(DockerWizardContext {
                 services: vec!["app".to_string()],
                 suggested_service: Some("app".to_string()),
+                profiles: Vec::new(),
             }),
             docker_service_status: gwt_docker::ComposeServiceStatus::Running,
             agent_options: sample_agent_options(),
             quick_start_entries: Vec::new(),
             previous_profiles: Some(Default::default()),
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
 
         // Runtime ステップ: 編集 UI が見え、primary は Confirm へ進む "Continue"。
@@ -2428,6 +2494,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["app".to_string()],
             suggested_service: Some("app".to_string()),
+            profiles: Vec::new(),
         });
         ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         let mut state = LaunchWizardState::open_with(ctx, sample_agent_options(), Vec::new());
@@ -2445,12 +2512,14 @@ mod tests {
             docker_context: Some(DockerWizardContext {
                 services: vec!["app".to_string()],
                 suggested_service: Some("app".to_string()),
+                profiles: Vec::new(),
             }),
             docker_service_status: gwt_docker::ComposeServiceStatus::Running,
             agent_options: sample_agent_options(),
             quick_start_entries: Vec::new(),
             previous_profiles: Some(Default::default()),
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
         state
     }
@@ -2529,6 +2598,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["app".to_string()],
             suggested_service: Some("app".to_string()),
+            profiles: Vec::new(),
         });
         ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         let mut state = LaunchWizardState::open_with_previous_profiles(
@@ -2576,12 +2646,14 @@ mod tests {
             docker_context: Some(DockerWizardContext {
                 services: vec!["app".to_string()],
                 suggested_service: Some("app".to_string()),
+                profiles: Vec::new(),
             }),
             docker_service_status: gwt_docker::ComposeServiceStatus::Running,
             agent_options: sample_agent_options(),
             quick_start_entries: Vec::new(),
             previous_profiles: Some(Default::default()),
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
 
         let view = state.view();
@@ -2599,6 +2671,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["app".to_string()],
             suggested_service: Some("app".to_string()),
+            profiles: Vec::new(),
         });
         ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         let entry = quick_start_entry(
@@ -2632,12 +2705,14 @@ mod tests {
             docker_context: Some(DockerWizardContext {
                 services: vec!["app".to_string()],
                 suggested_service: Some("app".to_string()),
+                profiles: Vec::new(),
             }),
             docker_service_status: gwt_docker::ComposeServiceStatus::Running,
             agent_options: sample_agent_options(),
             quick_start_entries: vec![entry],
             previous_profiles: Some(Default::default()),
             open_branch_candidates: Vec::new(),
+            runtime_rule: None,
         });
         state.apply(LaunchWizardAction::SetRuntimeTarget {
             target: gwt_agent::LaunchRuntimeTarget::Host,
@@ -2753,6 +2828,7 @@ mod tests {
         ctx.docker_context = Some(DockerWizardContext {
             services: vec!["api".to_string(), "worker".to_string()],
             suggested_service: Some("worker".to_string()),
+            profiles: Vec::new(),
         });
         ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         let mut state = LaunchWizardState::open_with(
@@ -2992,6 +3068,7 @@ mod tests {
         docker_ctx.docker_context = Some(DockerWizardContext {
             services: vec!["api".to_string(), "worker".to_string()],
             suggested_service: Some("worker".to_string()),
+            profiles: Vec::new(),
         });
         docker_ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
         let mut docker =
@@ -3028,4 +3105,52 @@ mod tests {
         });
         assert_eq!(docker.mode, "continue");
     }
+
+    #[test]
+    fn docker_profile_step_follows_service_and_feeds_launch_config() {
+        let mut ctx = context(branch("feature/gui"), "feature/gui");
+        ctx.docker_context = Some(DockerWizardContext {
+            services: vec!["app".to_string()],
+            suggested_service: Some("app".to_string()),
+            profiles: vec!["debug".to_string(), "tools".to_string()],
+        });
+        ctx.docker_service_status = gwt_docker::ComposeServiceStatus::Running;
+        let mut state = LaunchWizardState::open_with(ctx, sample_agent_options(), Vec::new());
+        state.agent_id = "claude".to_string();
+        state.model = "sonnet".to_string();
+        state.set_runtime_target(gwt_agent::LaunchRuntimeTarget::Docker);
+
+        assert_eq!(
+            next_step(LaunchWizardStep::RuntimeTarget, &state),
+            Some(LaunchWizardStep::DockerProfileSelect)
+        );
+        assert_eq!(
+            prev_step(LaunchWizardStep::DockerLifecycle, &state),
+            Some(LaunchWizardStep::DockerProfileSelect)
+        );
+        let view = state.view();
+        assert!(view.show_docker_profile);
+        let values = view
+            .docker_profile_options
+            .iter()
+            .map(|option| option.value.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec!["", "debug", "tools"]);
+
+        state.apply(LaunchWizardAction::SetDockerProfile {
+            profile: "tools".to_string(),
+        });
+        assert_eq!(state.docker_profile.as_deref(), Some("tools"));
+        state.apply(LaunchWizardAction::SetDockerProfile {
+            profile: "missing".to_string(),
+        });
+        assert_eq!(state.docker_profile.as_deref(), Some("tools"));
+        assert!(state.error.is_some());
+
+        let config = state.build_launch_config().expect("launch config");
+        assert_eq!(config.docker_profile.as_deref(), Some("tools"));
+
+        state.set_runtime_target(gwt_agent::LaunchRuntimeTarget::Host);
+        assert!(state.docker_profile.is_none());
+    }
 }
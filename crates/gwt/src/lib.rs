@@ -1,11 +1,18 @@
 pub mod agent_backend_dispatch;
 pub(crate) mod agent_project_state;
+pub mod api_key_check;
 pub mod backend_service;
+pub mod background_fetch;
 pub mod board_audience;
 pub mod board_provider;
 pub mod board_remote;
+pub mod branch_ci_status;
 pub mod branch_cleanup;
 pub mod branch_list;
+pub mod branch_notes_sync;
+pub mod branch_protection;
+pub mod branch_rename;
+pub mod ci_artifacts;
 pub mod cli;
 pub mod custom_agents_dispatch;
 pub mod custom_agents_service;
@@ -15,12 +22,15 @@ pub mod daemon_runtime;
 #[cfg(unix)]
 pub mod daemon_subscriber;
 mod discussion_resume;
+pub mod external_agents;
 pub mod file_content;
 pub mod file_tree;
+pub mod footer_widgets;
 pub mod gui_single_instance;
 pub mod handlers;
 pub mod index_search;
 pub mod index_worker;
+pub mod insights;
 pub mod issue_cache;
 pub mod issue_monitor;
 pub mod issue_monitor_authz;
@@ -29,23 +39,38 @@ pub mod issue_monitor_review;
 pub mod issue_monitor_worker;
 pub mod knowledge_bridge;
 pub mod launch_wizard;
+pub mod lifecycle_hooks;
 pub mod managed_assets;
 pub mod migration;
 pub mod native_app;
+pub mod openapi;
 pub(crate) mod path_filter;
 pub mod persistence;
+pub mod pr_create;
 pub mod preset;
 pub mod process;
 pub mod profile_dispatch;
 pub mod protocol;
+pub mod rebase_helper;
+pub mod repo_registry;
 pub mod runtime_daemon_events;
+pub mod session_conversion;
+pub mod session_export;
+pub mod session_issue_comment;
+pub mod stale_remote_branches;
 pub mod start_work;
 pub mod system_settings;
 pub mod web_protocol_enums;
 pub mod window_canvas;
 pub mod window_state;
 pub mod work_notes;
+pub mod worktree_archive;
+pub mod worktree_integrity;
 pub mod worktree_inventory;
+pub mod worktree_open;
+pub mod worktree_stash;
+pub mod worktree_transplant;
+pub mod worktree_trash;
 
 #[cfg(test)]
 pub(crate) fn env_test_lock() -> &'static std::sync::Mutex<()> {
@@ -53,13 +78,15 @@ pub(crate) fn env_test_lock() -> &'static std::sync::Mutex<()> {
 }
 
 pub use branch_cleanup::{
-    cleanup_selected_branches, cleanup_selected_branches_with_options,
-    cleanup_selected_branches_with_progress, BranchCleanupOptions, BranchCleanupProgressEntry,
-    BranchCleanupProgressPhase, BranchCleanupResultEntry, BranchCleanupResultStatus,
+    cleanup_selected_branches, cleanup_selected_branches_with_cancel,
+    cleanup_selected_branches_with_options, cleanup_selected_branches_with_progress,
+    BranchCleanupOptions, BranchCleanupProgressEntry, BranchCleanupProgressPhase,
+    BranchCleanupResultEntry, BranchCleanupResultStatus,
 };
 pub use branch_list::{
-    hydrate_branch_entries_with_active_sessions, list_branch_entries_with_active_sessions,
-    BranchCleanupAvailability, BranchCleanupBlockedReason, BranchCleanupInfo, BranchCleanupRisk,
+    hydrate_branch_entries_with_active_sessions, hydrate_branch_subset_with_active_sessions,
+    list_branch_entries_with_active_sessions, wants_lazy_hydration, BranchCleanupAvailability,
+    BranchCleanupBlockedReason, BranchCleanupInfo, BranchCleanupRisk,
 };
 pub use branch_list::{
     list_branch_entries, list_branch_inventory, next_branch_load_id, BranchListEntry,
@@ -112,9 +139,9 @@ pub use launch_wizard::{
     LaunchWizardContext, LaunchWizardHydration, LaunchWizardLaunchPath, LaunchWizardLaunchRequest,
     LaunchWizardLiveSessionView, LaunchWizardMode, LaunchWizardOptionView,
     LaunchWizardPreviousProfile, LaunchWizardPreviousProfiles, LaunchWizardProgressStepView,
-    LaunchWizardQuickStartView, LaunchWizardStartMethodKind, LaunchWizardStartMethodView,
-    LaunchWizardState, LaunchWizardStep, LaunchWizardSummaryView, LaunchWizardView,
-    LinkedIssueKind, LiveSessionEntry, QuickStartEntry, QuickStartLaunchMode,
+    LaunchWizardQuickStartView, LaunchWizardRecentSessionView, LaunchWizardStartMethodKind,
+    LaunchWizardStartMethodView, LaunchWizardState, LaunchWizardStep, LaunchWizardSummaryView,
+    LaunchWizardView, LinkedIssueKind, LiveSessionEntry, QuickStartEntry, QuickStartLaunchMode,
     ResumableAgentLifecycleStatus, ResumableAgentResumeKind, ResumableAgentView, ShellLaunchConfig,
 };
 pub use managed_assets::{
@@ -143,14 +170,14 @@ pub use protocol::{
     ActiveWorkAgentView, ActiveWorkCleanupCandidateView, ActiveWorkItemView,
     ActiveWorkProjectionView, ActiveWorkspaceWorkView, AppStateView, ArrangeMode,
     AttachmentProgressPhase, BackendEvent, BranchEntriesPhase, CustomAgentErrorCode,
-    FileAttachment, FileContentErrorKind, FileContentMode, FileContentSaveErrorKind,
-    FocusCycleDirection, FrontendEvent, GitHubRepositorySearchResultView, IndexSearchMatchMode,
-    IndexSearchResult, IndexSearchScope, IndexSearchTarget, ManagedHookHealthView,
-    ManagedHookPendingDiscussionView, ManagedHookPendingGoalView, ManagedHookSlowHandlerView,
-    ProfileEntryView, ProfileEnvEntryView, ProfileSnapshotView, ProjectTabView, RecentProjectView,
-    RunningAgentSummary, UiTraceEntry, UiTracePayload, WorkAgentView, WorkEventView, WorkItemView,
-    WorkspaceExecutionContainerView, WorkspaceHistoryAgentView, WorkspaceHistoryEventView,
-    WorkspaceHistorySessionView, WorkspaceHistoryView, WorkspaceJournalEntryView,
-    WorkspaceResumeSource, WorkspaceView,
+    DuplicateProjectView, FileAttachment, FileContentErrorKind, FileContentMode,
+    FileContentSaveErrorKind, FocusCycleDirection, FrontendEvent, GitHubRepositorySearchResultView,
+    IndexSearchMatchMode, IndexSearchResult, IndexSearchScope, IndexSearchTarget,
+    ManagedHookHealthView, ManagedHookPendingDiscussionView, ManagedHookPendingGoalView,
+    ManagedHookSlowHandlerView, ProfileEntryView, ProfileEnvEntryView, ProfileSnapshotView,
+    ProjectTabView, RecentProjectView, RunningAgentSummary, UiTraceEntry, UiTracePayload,
+    WorkAgentView, WorkEventView, WorkItemView, WorkspaceExecutionContainerView,
+    WorkspaceHistoryAgentView, WorkspaceHistoryEventView, WorkspaceHistorySessionView,
+    WorkspaceHistoryView, WorkspaceJournalEntryView, WorkspaceResumeSource, WorkspaceView,
 };
 pub use window_canvas::WindowCanvasState;
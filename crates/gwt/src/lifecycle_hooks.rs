@@ -0,0 +1,258 @@
+//! Runs the user scripts configured under `[lifecycle_hooks]`.
+//!
+//! Each hook is started with the event as a JSON object on stdin:
+//!
+//! ```json
+//! {"event":"worktree.created","occurred_at":"2026-01-01T00:00:00Z",
+//!  "repo_path":"/src/gwt","branch":"work/x","worktree_path":"/src/work/x"}
+//! ```
+//!
+//! and `GWT_LIFECYCLE_EVENT` set to the event name. Hooks run on a
+//! background thread in declaration order; a hook that exits non-zero or
+//! outlives its timeout is logged and never affects the gwt operation that
+//! triggered it.
+
+use std::{
+    io::{Read, Write},
+    process::{ExitStatus, Stdio},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use gwt_config::{LifecycleHookEntry, LifecycleHooksConfig, Settings};
+use serde_json::{Map, Value};
+
+pub const GWT_LIFECYCLE_EVENT_ENV: &str = "GWT_LIFECYCLE_EVENT";
+
+/// Bytes of hook stderr kept for the failure log.
+const STDERR_TAIL_BYTES: usize = 2048;
+const POLL_INTERVAL: Duration = Duration::from_millis(25);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifecycleEvent {
+    WorktreeCreated,
+    WorktreeRemoved,
+    AgentStarted,
+    AgentFinished,
+    AgentStalled,
+    CleanupCompleted,
+}
+
+impl LifecycleEvent {
+    /// Wire name, also used as the `event` key in `[[lifecycle_hooks.hooks]]`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::WorktreeCreated => "worktree.created",
+            Self::WorktreeRemoved => "worktree.removed",
+            Self::AgentStarted => "agent.started",
+            Self::AgentFinished => "agent.finished",
+            Self::AgentStalled => "agent.stalled",
+            Self::CleanupCompleted => "cleanup.completed",
+        }
+    }
+}
+
+/// Fire `event` against the hooks in the global config. Returns
+/// immediately; hooks run on a background thread.
+pub fn emit(event: LifecycleEvent, payload: Value) {
+    let Ok(settings) = Settings::load() else {
+        return;
+    };
+    let _ = emit_with_config(&settings.lifecycle_hooks, event, payload);
+}
+
+/// Fire `event` against `config`. Returns the worker handle, or `None` when
+/// no hook subscribes to the event.
+pub fn emit_with_config(
+    config: &LifecycleHooksConfig,
+    event: LifecycleEvent,
+    payload: Value,
+) -> Option<JoinHandle<()>> {
+    let hooks: Vec<(LifecycleHookEntry, Duration)> = config
+        .hooks_for(event.as_str())
+        .map(|hook| {
+            (
+                hook.clone(),
+                Duration::from_secs(config.timeout_secs_for(hook)),
+            )
+        })
+        .collect();
+    if hooks.is_empty() {
+        return None;
+    }
+    let input = event_payload(event, payload).to_string();
+    Some(thread::spawn(move || {
+        for (hook, timeout) in hooks {
+            match run_hook(&hook, event, input.as_bytes(), timeout) {
+                Ok(()) => tracing::debug!(
+                    event = event.as_str(),
+                    command = %hook.command,
+                    "lifecycle hook completed"
+                ),
+                Err(error) => tracing::warn!(
+                    event = event.as_str(),
+                    command = %hook.command,
+                    error = %error,
+                    "lifecycle hook failed"
+                ),
+            }
+        }
+    }))
+}
+
+/// Run one hook to completion, killing it after `timeout`.
+pub fn run_hook(
+    hook: &LifecycleHookEntry,
+    event: LifecycleEvent,
+    input: &[u8],
+    timeout: Duration,
+) -> Result<(), String> {
+    let mut child = gwt_core::process::hidden_command(&hook.command)
+        .args(&hook.args)
+        .env(GWT_LIFECYCLE_EVENT_ENV, event.as_str())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| format!("failed to start: {error}"))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // A hook that ignores its input may exit before reading it.
+        if let Err(error) = stdin.write_all(input) {
+            if error.kind() != std::io::ErrorKind::BrokenPipe {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("failed to write payload: {error}"));
+            }
+        }
+    }
+    let stderr = child.stderr.take().map(|mut stderr| {
+        thread::spawn(move || {
+            let mut buffer = Vec::new();
+            let _ = stderr.read_to_end(&mut buffer);
+            buffer
+        })
+    });
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if started.elapsed() >= timeout => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("timed out after {}s", timeout.as_secs()));
+            }
+            Ok(None) => thread::sleep(POLL_INTERVAL),
+            Err(error) => return Err(format!("failed to wait: {error}")),
+        }
+    };
+    let stderr = stderr
+        .and_then(|reader| reader.join().ok())
+        .unwrap_or_default();
+    check_status(status, &stderr)
+}
+
+fn check_status(status: ExitStatus, stderr: &[u8]) -> Result<(), String> {
+    if status.success() {
+        return Ok(());
+    }
+    let tail = &stderr[stderr.len().saturating_sub(STDERR_TAIL_BYTES)..];
+    let tail = String::from_utf8_lossy(tail);
+    let tail = tail.trim();
+    if tail.is_empty() {
+        Err(format!("exited with {status}"))
+    } else {
+        Err(format!("exited with {status}: {tail}"))
+    }
+}
+
+/// `payload` fields plus `event` and `occurred_at`.
+fn event_payload(event: LifecycleEvent, payload: Value) -> Value {
+    let mut object = match payload {
+        Value::Object(object) => object,
+        Value::Null => Map::new(),
+        other => Map::from_iter([("data".to_string(), other)]),
+    };
+    object.insert("event".to_string(), Value::from(event.as_str()));
+    object.insert(
+        "occurred_at".to_string(),
+        Value::from(chrono::Utc::now().to_rfc3339()),
+    );
+    Value::Object(object)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn shell_hook(event: &str, script: &str) -> LifecycleHookEntry {
+        LifecycleHookEntry {
+            event: event.to_string(),
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), script.to_string()],
+            timeout_secs: None,
+        }
+    }
+
+    #[test]
+    fn hook_receives_json_payload_and_event_env() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("out.json");
+        let config = LifecycleHooksConfig {
+            hooks: vec![shell_hook(
+                "worktree.created",
+                &format!(
+                    "cat > '{}'; printf '%s' \"$GWT_LIFECYCLE_EVENT\" > '{}.env'",
+                    out.display(),
+                    out.display()
+                ),
+            )],
+            ..LifecycleHooksConfig::default()
+        };
+
+        emit_with_config(
+            &config,
+            LifecycleEvent::WorktreeCreated,
+            serde_json::json!({ "branch": "work/x" }),
+        )
+        .expect("hook subscribed")
+        .join()
+        .unwrap();
+
+        let payload: Value = serde_json::from_str(&std::fs::read_to_string(&out).unwrap()).unwrap();
+        assert_eq!(payload["event"], "worktree.created");
+        assert_eq!(payload["branch"], "work/x");
+        assert!(payload["occurred_at"].is_string());
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("out.json.env")).unwrap(),
+            "worktree.created"
+        );
+        assert!(emit_with_config(&config, LifecycleEvent::AgentStarted, Value::Null).is_none());
+    }
+
+    #[test]
+    fn run_hook_reports_failure_and_timeout() {
+        let failing = shell_hook("*", "echo boom >&2; exit 3");
+        let error = run_hook(
+            &failing,
+            LifecycleEvent::AgentFinished,
+            b"{}",
+            Duration::from_secs(5),
+        )
+        .unwrap_err();
+        assert!(error.contains("boom"), "{error}");
+
+        let slow = shell_hook("*", "sleep 5");
+        let started = Instant::now();
+        let error = run_hook(
+            &slow,
+            LifecycleEvent::AgentFinished,
+            b"{}",
+            Duration::from_millis(200),
+        )
+        .unwrap_err();
+        assert!(error.contains("timed out"), "{error}");
+        assert!(started.elapsed() < Duration::from_secs(4));
+    }
+}
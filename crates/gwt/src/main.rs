@@ -11,7 +11,8 @@ use std::{
 };
 
 use crate::repo_browser::{
-    preferred_issue_launch_branch, spawn_branch_load_async, spawn_remote_start_work_branches_async,
+    preferred_issue_launch_branch, spawn_branch_hydration_async, spawn_branch_load_async,
+    spawn_remote_start_work_branches_async,
 };
 use base64::Engine;
 use gwt::protocol::{FileContentErrorKind, FileContentMode};
@@ -39,8 +40,10 @@ use tray_icon::{
 };
 use uuid::Uuid;
 
+mod agent_watchdog;
 mod app_runtime;
 mod attachment_upload;
+mod background_fetch_scheduler;
 mod docker_launch;
 mod embedded_server;
 mod embedded_web;
@@ -74,8 +77,9 @@ pub(crate) use app_runtime::{
 pub(crate) use attachment_upload::{AttachmentUploadStore, UploadedAttachment};
 pub(crate) use docker_launch::{
     apply_docker_runtime_to_launch_config, detect_wizard_docker_context_and_status,
-    docker_binary_for_launch, docker_compose_exec_env_args, ensure_docker_gwt_binary_setup,
-    ensure_docker_launch_service_ready, finalize_docker_agent_launch_config,
+    devcontainer_binary_for_launch, docker_binary_for_launch, docker_compose_exec_env_args,
+    docker_exec_env, ensure_docker_gwt_binary_setup, ensure_docker_launch_service_ready,
+    ensure_docker_port_override, finalize_docker_agent_launch_config, launch_rule_for_branch,
     package_runner_version_spec, resolve_docker_launch_plan, resolve_docker_shell_command,
     strip_package_runner_args,
 };
@@ -282,6 +286,7 @@ fn frontend_event_may_change_project_tabs(event: &FrontendEvent) -> bool {
         event,
         FrontendEvent::OpenProjectDialog
             | FrontendEvent::ReopenRecentProject { .. }
+            | FrontendEvent::DropProjectFolder { .. }
             | FrontendEvent::CloseProjectTab { .. }
     )
 }
@@ -834,6 +839,7 @@ fn daemon_subscriber_channels() -> Vec<String> {
     vec![
         "board".to_string(),
         "workspace".to_string(),
+        gwt::runtime_daemon_events::GIT_REFS_CHANNEL.to_string(),
         gwt::runtime_daemon_events::RUNTIME_OUTPUT_CHANNEL.to_string(),
         gwt::runtime_daemon_events::RUNTIME_STATUS_CHANNEL.to_string(),
         gwt::runtime_daemon_events::RUNTIME_HOOK_CHANNEL.to_string(),
@@ -858,6 +864,17 @@ fn daemon_broadcast_user_event(
             project_root: project_root.to_path_buf(),
         });
     }
+    if channel == gwt::runtime_daemon_events::GIT_REFS_CHANNEL {
+        return Some(UserEvent::Dispatch(vec![OutboundEvent::broadcast(
+            BackendEvent::GitRefsChanged {
+                hook: payload.get("hook")?.as_str()?.to_string(),
+                branch: payload
+                    .get("branch")
+                    .and_then(serde_json::Value::as_str)
+                    .map(str::to_string),
+            },
+        )]));
+    }
 
     match gwt::runtime_daemon_events::decode_runtime_daemon_event(channel, payload, current_pid)? {
         gwt::runtime_daemon_events::RuntimeDaemonEvent::Output { id, data } => {
@@ -1062,6 +1079,18 @@ enum UserEvent {
     },
     RuntimeHook(gwt::RuntimeHookEvent),
     DaemonRuntimeHook(gwt::RuntimeHookEvent),
+    /// Periodic request from `agent_watchdog` to check live agent sessions.
+    AgentWatchdogTick,
+    /// `background_fetch_scheduler` found a background fetch due.
+    BackgroundFetchTick,
+    /// A live agent session went past its profile's watchdog limits and was
+    /// marked `AgentStatus::Stalled`.
+    AgentStalled {
+        window_id: String,
+        session_id: String,
+        reason: gwt_agent::StallReason,
+        action: gwt_config::WatchdogAction,
+    },
     IssueMonitorLaunchRequest {
         issue_number: u64,
         linked_issue_kind: gwt::LinkedIssueKind,
@@ -1100,6 +1129,14 @@ enum UserEvent {
         wizard_id: String,
         result: Box<Result<gwt::LaunchWizardHydration, String>>,
     },
+    LaunchWizardRecentSessionsLoaded {
+        wizard_id: String,
+        sessions: Vec<gwt_agent::session_export::RecentSession>,
+    },
+    LaunchWizardApiKeyChecked {
+        wizard_id: String,
+        warning: String,
+    },
     LaunchWizardLaunchMaterializationRequested {
         wizard_id: String,
         client_id: Option<ClientId>,
@@ -1201,6 +1238,15 @@ enum UserEvent {
     CloneProjectError {
         message: String,
     },
+    /// Background scan result of [`AppRuntime::spawn_duplicate_project_scan`].
+    DuplicateProjects {
+        duplicates: Vec<gwt::repo_registry::DuplicateProject>,
+    },
+    DuplicateProjectConsolidated {
+        client_id: ClientId,
+        duplicate: gwt::repo_registry::DuplicateProject,
+        result: Result<gwt::repo_registry::ConsolidationOutcome, String>,
+    },
     /// SPEC-1934 US-6.8: user chose Quit from the migration modal. The event
     /// loop exits through the same cleanup path as a window close request.
     QuitApp,
@@ -1271,6 +1317,7 @@ mod tests {
 
         assert!(channels.iter().any(|channel| channel == "board"));
         assert!(channels.iter().any(|channel| channel == "workspace"));
+        assert!(channels.iter().any(|channel| channel == "git_refs"));
         assert!(channels
             .iter()
             .any(|channel| channel == gwt::runtime_daemon_events::RUNTIME_OUTPUT_CHANNEL));
@@ -2440,6 +2487,10 @@ mod tests {
             tabs,
             active_tab_id: active_tab_id.map(str::to_owned),
             recent_projects: Vec::new(),
+            registered_projects: Vec::new(),
+            duplicate_projects: Vec::new(),
+            footer_command_cache: Arc::default(),
+            insights: gwt::insights::InsightsRecorder::new(PathBuf::new(), false),
             profile_selections: HashMap::new(),
             profile_config_path: Some(temp_root.join("profile-config.toml")),
             runtimes: HashMap::new(),
@@ -2473,6 +2524,7 @@ mod tests {
             ),
             last_work_events_ingest: std::cell::RefCell::new(HashMap::new()),
             local_worktree_branches: std::cell::RefCell::new(HashMap::new()),
+            log_snapshot_cache: Default::default(),
             window_pty_statuses: HashMap::new(),
             window_hook_states: HashMap::new(),
             recoverable_agent_error_windows: std::collections::HashSet::new(),
@@ -2514,6 +2566,10 @@ mod tests {
                         cleanup: BranchCleanupInfo::default(),
                         resume: gwt::BranchResumeInfo::unavailable(),
                         start_work_eligibility: None,
+                        worktree_path: None,
+                        archived: false,
+                        integrity_issues: Vec::new(),
+                        linked_spec: None,
                     },
                     normalized_branch_name: "feature/demo".to_string(),
                     worktree_path: None,
@@ -2550,6 +2606,10 @@ mod tests {
             cleanup: BranchCleanupInfo::default(),
             resume: gwt::BranchResumeInfo::unavailable(),
             start_work_eligibility: None,
+            worktree_path: None,
+            archived: false,
+            integrity_issues: Vec::new(),
+            linked_spec: None,
         }
     }
 
@@ -2617,6 +2677,7 @@ mod tests {
             codex_fast_mode: true,
             runtime_target: LaunchRuntimeTarget::Host,
             docker_service: None,
+            docker_profile: None,
             docker_lifecycle_intent: DockerLifecycleIntent::Connect,
         }
     }
@@ -3489,16 +3550,24 @@ mod tests {
                 if message == "Window is not a knowledge bridge"
         ));
 
-        let cleanup_missing =
-            runtime.run_branch_cleanup_events("client-1", "missing", &[], false, false);
+        let cleanup_missing = runtime.run_branch_cleanup_events(
+            "client-1",
+            "missing",
+            &[],
+            BranchCleanupOptions::default(),
+        );
         assert_eq!(cleanup_missing.len(), 1);
         assert!(matches!(
             cleanup_missing[0].event,
             BackendEvent::BranchError { ref message, .. } if message == "Window not found"
         ));
 
-        let cleanup_wrong =
-            runtime.run_branch_cleanup_events("client-1", &file_tree_id, &[], false, false);
+        let cleanup_wrong = runtime.run_branch_cleanup_events(
+            "client-1",
+            &file_tree_id,
+            &[],
+            BranchCleanupOptions::default(),
+        );
         assert_eq!(cleanup_wrong.len(), 1);
         assert!(matches!(
             cleanup_wrong[0].event,
@@ -3924,8 +3993,7 @@ mod tests {
             "client-1",
             &branches_id,
             &[String::from("feature/prune-me")],
-            false,
-            false,
+            BranchCleanupOptions::default(),
         );
         assert!(cleanup_events.is_empty());
         wait_for_recorded_event("branch cleanup progress dispatch", &events, |events| {
@@ -4236,6 +4304,7 @@ mod tests {
                 branches: vec!["feature/missing".to_string()],
                 delete_remote: false,
                 force_filesystem_delete: false,
+                confirmed_protected: Vec::new(),
             },
         );
         assert!(cleanup_events.is_empty());
@@ -4878,7 +4947,9 @@ mod tests {
     fn failed_completed_pane_status_is_not_auto_close_eligible() {
         let status = match PaneStatus::Completed(1) {
             PaneStatus::Completed(0) => WindowProcessStatus::Exited,
-            PaneStatus::Completed(_) | PaneStatus::Error(_) => WindowProcessStatus::Error,
+            PaneStatus::Completed(_) | PaneStatus::Signaled(_) | PaneStatus::Error(_) => {
+                WindowProcessStatus::Error
+            }
             PaneStatus::Running => WindowProcessStatus::Exited,
         };
 
@@ -5151,6 +5222,7 @@ mod tests {
             display_name: "Shell".to_string(),
             runtime_target: LaunchRuntimeTarget::Host,
             docker_service: None,
+            docker_profile: None,
             docker_lifecycle_intent: DockerLifecycleIntent::Connect,
             env_vars: HashMap::from([("EXTRA_FLAG".to_string(), "1".to_string())]),
             remove_env: vec!["SECRET".to_string()],
@@ -5190,6 +5262,7 @@ mod tests {
             display_name: "Shell".to_string(),
             runtime_target: LaunchRuntimeTarget::Host,
             docker_service: None,
+            docker_profile: None,
             docker_lifecycle_intent: DockerLifecycleIntent::Connect,
             env_vars: HashMap::new(),
             remove_env: Vec::new(),
@@ -5226,6 +5299,7 @@ mod tests {
             display_name: "OpenCode Setup".to_string(),
             runtime_target: LaunchRuntimeTarget::Host,
             docker_service: None,
+            docker_profile: None,
             docker_lifecycle_intent: DockerLifecycleIntent::Connect,
             env_vars: HashMap::new(),
             remove_env: Vec::new(),
@@ -5424,6 +5498,10 @@ mod tests {
                 cleanup: BranchCleanupInfo::default(),
                 resume: gwt::BranchResumeInfo::unavailable(),
                 start_work_eligibility: None,
+                worktree_path: None,
+                archived: false,
+                integrity_issues: Vec::new(),
+                linked_spec: None,
             },
             BranchListEntry {
                 name: "develop".to_string(),
@@ -5437,6 +5515,10 @@ mod tests {
                 cleanup: BranchCleanupInfo::default(),
                 resume: gwt::BranchResumeInfo::unavailable(),
                 start_work_eligibility: None,
+                worktree_path: None,
+                archived: false,
+                integrity_issues: Vec::new(),
+                linked_spec: None,
             },
         ];
         assert_eq!(
@@ -5456,6 +5538,10 @@ mod tests {
             cleanup: BranchCleanupInfo::default(),
             resume: gwt::BranchResumeInfo::unavailable(),
             start_work_eligibility: None,
+            worktree_path: None,
+            archived: false,
+            integrity_issues: Vec::new(),
+            linked_spec: None,
         }];
         assert_eq!(
             super::preferred_issue_launch_branch(&head_only),
@@ -5696,6 +5782,7 @@ mod tests {
                 target: "/workspaces/repo".to_string(),
                 mode: None,
             }],
+            profiles: Vec::new(),
         };
         assert_eq!(
             super::compose_workspace_mount_target(&project_root, &service),
@@ -5735,7 +5822,7 @@ mod tests {
         )
         .expect("write devcontainer");
 
-        let plan = super::resolve_docker_launch_plan(&project, None).expect("launch plan");
+        let plan = super::resolve_docker_launch_plan(&project, None, None).expect("launch plan");
         assert_eq!(plan.compose_files.len(), 2);
         assert!(super::same_worktree_path(&plan.compose_files[0], &base));
         assert!(super::same_worktree_path(
@@ -6237,6 +6324,7 @@ mod tests {
             display_name: "Shell".to_string(),
             runtime_target: LaunchRuntimeTarget::Host,
             docker_service: None,
+            docker_profile: None,
             docker_lifecycle_intent: DockerLifecycleIntent::Connect,
             env_vars: HashMap::new(),
             remove_env: Vec::new(),
@@ -6558,7 +6646,7 @@ mod tests {
         )
         .expect("write devcontainer");
 
-        let plan = super::resolve_docker_launch_plan(&project, None).expect("launch plan");
+        let plan = super::resolve_docker_launch_plan(&project, None, None).expect("launch plan");
         assert_eq!(plan.service, "app");
         assert_eq!(plan.container_cwd, "/workspace/dev");
         assert_eq!(plan.compose_file, project.join("docker-compose.yml"));
@@ -6576,10 +6664,11 @@ mod tests {
             "services:\n  app:\n    image: alpine:3.19\n  worker:\n    image: alpine:3.19\n",
         )
         .expect("write multi compose");
-        let multi_err = super::resolve_docker_launch_plan(&multi, None).expect_err("multi service");
+        let multi_err =
+            super::resolve_docker_launch_plan(&multi, None, None).expect_err("multi service");
         assert!(multi_err.contains("Multiple Docker services detected"));
 
-        let invalid_service = super::resolve_docker_launch_plan(&project, Some("missing"))
+        let invalid_service = super::resolve_docker_launch_plan(&project, Some("missing"), None)
             .expect_err("missing docker service");
         assert!(invalid_service.contains("Selected Docker service was not found"));
 
@@ -6591,11 +6680,11 @@ mod tests {
         )
         .expect("write no-cwd compose");
         let no_cwd_err =
-            super::resolve_docker_launch_plan(&no_cwd, Some("app")).expect_err("no cwd");
+            super::resolve_docker_launch_plan(&no_cwd, Some("app"), None).expect_err("no cwd");
         assert!(no_cwd_err.contains("missing working_dir/workspaceFolder"));
 
-        let missing_compose =
-            super::resolve_docker_launch_plan(temp.path(), None).expect_err("missing compose");
+        let missing_compose = super::resolve_docker_launch_plan(temp.path(), None, None)
+            .expect_err("missing compose");
         assert!(missing_compose.contains("docker-compose.yml"));
 
         assert_eq!(
@@ -6804,6 +6893,64 @@ mod tests {
         );
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn finalize_docker_agent_launch_config_prefers_devcontainer_cli() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let _env_lock = crate::env_test_lock()
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let temp = tempdir().expect("tempdir");
+        let project = temp.path().join("project");
+        fs::create_dir_all(project.join(".devcontainer")).expect("create devcontainer dir");
+        fs::write(
+            project.join(".devcontainer").join("devcontainer.json"),
+            r#"{ "image": "alpine:3.19" }"#,
+        )
+        .expect("write devcontainer.json");
+        let fake_cli = temp.path().join("devcontainer");
+        fs::write(&fake_cli, "#!/bin/sh\nexit 0\n").expect("write fake devcontainer");
+        let mut perms = fs::metadata(&fake_cli)
+            .expect("fake devcontainer metadata")
+            .permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&fake_cli, perms).expect("chmod fake devcontainer");
+
+        let mut config = sample_versioned_launch_config();
+        config.runtime_target = LaunchRuntimeTarget::Docker;
+        config.working_dir = Some(project.clone());
+        config.docker_service = None;
+
+        let old_cli_bin = std::env::var_os("GWT_DEVCONTAINER_BIN");
+        std::env::set_var("GWT_DEVCONTAINER_BIN", &fake_cli);
+        let result = super::finalize_docker_agent_launch_config(&project, &mut config);
+        match old_cli_bin {
+            Some(value) => std::env::set_var("GWT_DEVCONTAINER_BIN", value),
+            None => std::env::remove_var("GWT_DEVCONTAINER_BIN"),
+        }
+        result.expect("finalize devcontainer launch");
+
+        assert_eq!(config.command, fake_cli.display().to_string());
+        assert_eq!(
+            config.args[..3],
+            [
+                "exec".to_string(),
+                "--workspace-folder".to_string(),
+                project.display().to_string(),
+            ]
+        );
+        assert!(config
+            .args
+            .windows(2)
+            .any(|pair| pair == ["--remote-env", "TERM=xterm-256color"]));
+        assert!(config.args.ends_with(&[
+            "bunx".to_string(),
+            "@anthropic-ai/claude-code@latest".to_string(),
+            "--print".to_string(),
+        ]));
+    }
+
     #[test]
     fn branch_selection_and_mount_helpers_cover_preferred_paths() {
         assert_eq!(
@@ -6872,6 +7019,7 @@ mod tests {
                 target: "/workspace".to_string(),
                 mode: None,
             }],
+            profiles: Vec::new(),
         };
         assert_eq!(
             super::compose_workspace_mount_target(&project_root, &service).as_deref(),
@@ -7135,11 +7283,24 @@ fn main() -> std::io::Result<()> {
     // Diagnostic trace for intermittent key-input drop (bugfix/input-key) is
     // emitted at `debug` level under `target: "gwt_input_trace"`. Enable with
     // `RUST_LOG=gwt_input_trace=debug`.
-    let mut log_handles = gwt_core::logging::init(gwt_core::logging::LoggingConfig::new(log_dir))
-        .map_err(|error| {
-            eprintln!("gwt logging init failed: {error}");
-        })
-        .ok();
+    //
+    // `[log_rotation]` in `~/.gwt/config.toml` adds size-based splitting
+    // and a per-directory size budget on top of the daily files;
+    // `[log_redaction]` overrides the secret key patterns.
+    let settings = gwt_config::Settings::load().unwrap_or_default();
+    let log_rotation = settings.log_rotation;
+    let mut log_handles = gwt_core::logging::init(
+        gwt_core::logging::LoggingConfig::new(log_dir)
+            .with_size_limits(
+                log_rotation.max_file_bytes(),
+                log_rotation.max_total_bytes(),
+            )
+            .with_secret_key_patterns(settings.log_redaction.key_patterns),
+    )
+    .map_err(|error| {
+        eprintln!("gwt logging init failed: {error}");
+    })
+    .ok();
 
     if let Err(error) = gwt::cli::hook::prepare_daemon_front_door_for_path(&startup_dir) {
         eprintln!("gwt daemon bootstrap: {error}");
@@ -7281,9 +7442,15 @@ fn main() -> std::io::Result<()> {
     // callback so remote Board sign-in works regardless of the (ephemeral or
     // operator-chosen) main port. Read fresh from config so a Settings change
     // takes effect on the next launch.
-    let oauth_redirect_port = gwt_config::Settings::load()
+    let server_settings = gwt_config::Settings::load().ok();
+    let oauth_redirect_port = server_settings
+        .as_ref()
         .map(|settings| settings.board.oauth_redirect_port)
         .unwrap_or(gwt_config::DEFAULT_OAUTH_REDIRECT_PORT);
+    let read_only = tray_args.read_only
+        || server_settings
+            .as_ref()
+            .is_some_and(|settings| settings.server.read_only);
     let mut server = EmbeddedServer::start_with_listener(
         &runtime,
         prepared_listener.into_listener(),
@@ -7292,6 +7459,7 @@ fn main() -> std::io::Result<()> {
         clients.clone(),
         pty_writers.clone(),
         attachment_uploads,
+        read_only,
     )
     .expect("embedded server");
     debug_assert_eq!(server.bound_port(), prepared_port);
@@ -7321,6 +7489,17 @@ fn main() -> std::io::Result<()> {
     app.set_usage_refresh(usage_refresh.clone());
     usage_poller::spawn_usage_poller(&runtime, clients.clone(), usage_refresh);
     runtime_health_poller::spawn_runtime_health_poller(&runtime, clients.clone(), pty_writers);
+    {
+        // Status strip AI cell: every AI job queue change is broadcast.
+        let clients = clients.clone();
+        gwt_ai::global_queue().set_listener(move |status| {
+            clients.dispatch(vec![OutboundEvent::broadcast(
+                BackendEvent::AiQueueStatus { status },
+            )]);
+        });
+    }
+    agent_watchdog::spawn_agent_watchdog(&runtime, proxy.clone());
+    background_fetch_scheduler::spawn_background_fetch_scheduler(&runtime, proxy.clone());
     eprintln!("gwt browser URL: {browser_url}");
     // SPEC-1939 T-IDX-109/110 / Issue #2584 — Playwright e2e seam.
     // When `GWT_BROWSER_URL_FILE` is set, the embedded server URL is also
@@ -7558,6 +7737,21 @@ fn main() -> std::io::Result<()> {
                 let events = app.handle_work_events_ingested(project_root, changed);
                 clients.dispatch(events);
             }
+            Event::UserEvent(UserEvent::AgentWatchdogTick) => {
+                app.agent_watchdog_tick();
+            }
+            Event::UserEvent(UserEvent::BackgroundFetchTick) => {
+                app.background_fetch_tick();
+            }
+            Event::UserEvent(UserEvent::AgentStalled {
+                window_id,
+                session_id,
+                reason,
+                action,
+            }) => {
+                let events = app.agent_stalled_events(&window_id, &session_id, reason, action);
+                clients.dispatch(events);
+            }
             Event::UserEvent(UserEvent::WorkMergeStatus {
                 project_root,
                 merged_branches,
@@ -7660,6 +7854,17 @@ fn main() -> std::io::Result<()> {
                 let events = app.handle_launch_wizard_runtime_resolved(wizard_id, *result);
                 clients.dispatch(events);
             }
+            Event::UserEvent(UserEvent::LaunchWizardRecentSessionsLoaded {
+                wizard_id,
+                sessions,
+            }) => {
+                let events = app.handle_launch_wizard_recent_sessions_loaded(wizard_id, sessions);
+                clients.dispatch(events);
+            }
+            Event::UserEvent(UserEvent::LaunchWizardApiKeyChecked { wizard_id, warning }) => {
+                let events = app.handle_launch_wizard_api_key_checked(wizard_id, warning);
+                clients.dispatch(events);
+            }
             Event::UserEvent(UserEvent::LaunchWizardLaunchMaterializationRequested {
                 wizard_id,
                 client_id,
@@ -7987,6 +8192,19 @@ fn main() -> std::io::Result<()> {
                     BackendEvent::CloneProjectError { message },
                 )]);
             }
+            Event::UserEvent(UserEvent::DuplicateProjects { duplicates }) => {
+                let events = app.apply_duplicate_projects(duplicates);
+                clients.dispatch(events);
+            }
+            Event::UserEvent(UserEvent::DuplicateProjectConsolidated {
+                client_id,
+                duplicate,
+                result,
+            }) => {
+                let events =
+                    app.handle_duplicate_project_consolidated(client_id, duplicate, result);
+                clients.dispatch(events);
+            }
             // SPEC #2920 Phase 4: the tray menu event handler is now
             // cross-platform. The tray icon menu (Open / About / Quit) is the
             // only producer; the legacy macOS native menubar
@@ -13,6 +13,7 @@ use std::{
 use chrono::Utc;
 use gwt_core::config::BareProjectConfig;
 use gwt_core::migration::backup::{self, BackupSnapshot};
+use gwt_core::migration::report::{self, MigrationReport, PlannedWorktree};
 use gwt_core::migration::rollback;
 use gwt_core::migration::types::{
     MigrationError, MigrationOptions, MigrationOutcome, MigrationPhase, RecoveryState,
@@ -87,6 +88,83 @@ pub fn execute_migration(
     }
 }
 
+/// Report what [`execute_migration`] would do to `project_root` without
+/// changing anything: the worktrees it would move and where to, the disk
+/// space involved, and every pre-flight check that would fail.
+pub fn dry_run_migration(project_root: &Path) -> MigrationReport {
+    let mut problems = Vec::new();
+    let checks = [
+        validator::check_normal_git_directory(project_root),
+        validator::check_write_permission(project_root),
+        validator::check_locked_worktrees(project_root),
+    ];
+    problems.extend(
+        checks
+            .into_iter()
+            .filter_map(Result::err)
+            .map(|e| e.to_string()),
+    );
+
+    let dot_git = project_root.join(".git");
+    let (project_bytes, required_bytes, available_bytes) =
+        match validator::measure_disk_space(project_root) {
+            Ok(space) => {
+                if let Err(e) = validator::evaluate_disk_space(space.required, space.available) {
+                    problems.push(e.to_string());
+                }
+                (space.used, space.required, Some(space.available))
+            }
+            Err(e) => {
+                problems.push(e.to_string());
+                (0, 0, None)
+            }
+        };
+
+    let mut worktrees = git_migration::list_worktrees(project_root).unwrap_or_default();
+    if worktrees.is_empty() {
+        if let Some(branch) = current_branch(&dot_git, project_root) {
+            worktrees.push(WorktreeMigration {
+                path: project_root.to_path_buf(),
+                is_dirty: has_uncommitted_changes(project_root),
+                branch,
+                is_main_repo: true,
+                is_locked: false,
+            });
+        }
+    }
+    worktrees.sort_by_key(|worktree| !worktree.is_main_repo);
+    let remote_url = read_origin_url(&dot_git);
+    let git_bytes = validator::directory_size(&dot_git).unwrap_or(0);
+    let estimated = report::estimate_duration(
+        project_bytes,
+        git_bytes,
+        worktrees.len(),
+        remote_url.is_some(),
+    );
+
+    MigrationReport {
+        project_root: project_root.to_path_buf(),
+        bare_repo_path: project_root.join(derive_bare_repo_name(project_root)),
+        remote_url,
+        worktrees: worktrees
+            .into_iter()
+            .map(|worktree| PlannedWorktree {
+                to: worktree_target_path(project_root, &worktree.branch),
+                branch: worktree.branch,
+                from: worktree.path,
+                is_main_repo: worktree.is_main_repo,
+                is_dirty: worktree.is_dirty,
+                is_locked: worktree.is_locked,
+            })
+            .collect(),
+        project_bytes,
+        required_bytes,
+        available_bytes,
+        estimated_seconds: estimated.as_secs(),
+        problems,
+    }
+}
+
 fn run_post_backup(
     project_root: &Path,
     options: &MigrationOptions,
@@ -494,6 +572,14 @@ fn move_top_level_into(src: &Path, dst: &Path, extra_exclusions: &[String]) -> s
     Ok(())
 }
 
+fn has_uncommitted_changes(work_dir: &Path) -> bool {
+    gwt_core::process::hidden_command("git")
+        .args(["status", "--porcelain"])
+        .current_dir(work_dir)
+        .output()
+        .is_ok_and(|output| output.status.success() && !output.stdout.is_empty())
+}
+
 fn current_branch(git_dir: &Path, work_dir: &Path) -> Option<String> {
     let output = gwt_core::process::hidden_command("git")
         .args(["symbolic-ref", "--short", "HEAD"])
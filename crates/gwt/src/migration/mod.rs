@@ -5,5 +5,7 @@
 //! operations) without introducing a cycle.
 
 pub mod executor;
+pub mod reverse;
 
-pub use executor::execute_migration;
+pub use executor::{dry_run_migration, execute_migration};
+pub use reverse::execute_reverse_migration;
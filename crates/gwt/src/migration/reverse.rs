@@ -0,0 +1,424 @@
+//! Drives the reverse Nested Bare+Worktree → Normal migration, for projects
+//! leaving the bare layout.
+//!
+//! The bare repository becomes `<project_root>/.git` and the primary
+//! branch's files end up in `<project_root>`: its worktree's files are moved
+//! there, uncommitted changes included, or the branch is checked out when it
+//! has no worktree. Worktrees of other branches are removed and must be
+//! clean (their ignored files go with them); their branches stay. Like the
+//! forward migration, the project is backed up first and restored from the
+//! backup when a later phase fails.
+
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use gwt_core::config::BareProjectConfig;
+use gwt_core::migration::backup::{self, BACKUP_DIR_NAME};
+use gwt_core::migration::rollback;
+use gwt_core::migration::types::{
+    MigrationError, MigrationPhase, RecoveryState, ReverseMigrationOptions,
+    ReverseMigrationOutcome, WorktreeMigration,
+};
+use gwt_core::migration::validator::{self, ValidationError};
+use gwt_core::process::hidden_command;
+use gwt_git::migration as git_migration;
+
+const PARKED_PRIMARY_DIR: &str = ".gwt-migration-primary";
+
+/// What the validated project looks like.
+struct ReversePlan {
+    bare: PathBuf,
+    primary_branch: String,
+    /// The primary branch's worktree, when it has one.
+    primary: Option<WorktreeMigration>,
+    /// Worktrees of the other branches.
+    others: Vec<WorktreeMigration>,
+}
+
+/// Execute every reverse migration phase end-to-end. Calls
+/// `progress(phase, 0..=100)` at phase boundaries.
+pub fn execute_reverse_migration(
+    project_root: &Path,
+    options: ReverseMigrationOptions,
+    mut progress: impl FnMut(MigrationPhase, u8),
+) -> Result<ReverseMigrationOutcome, MigrationError> {
+    progress(MigrationPhase::Validate, 0);
+    let plan = plan(project_root, &options).map_err(|message| MigrationError {
+        phase: MigrationPhase::Validate,
+        message,
+        recovery: RecoveryState::Untouched,
+    })?;
+
+    progress(MigrationPhase::Backup, 0);
+    let external_roots: Vec<PathBuf> = plan
+        .primary
+        .iter()
+        .chain(&plan.others)
+        .filter(|worktree| !path_is_inside(&worktree.path, project_root))
+        .map(|worktree| worktree.path.clone())
+        .collect();
+    let snapshot =
+        backup::create_with_external_roots(project_root, &external_roots).map_err(|e| {
+            MigrationError {
+                phase: MigrationPhase::Backup,
+                message: e.to_string(),
+                recovery: RecoveryState::Untouched,
+            }
+        })?;
+
+    match run_post_backup(project_root, &plan, &mut progress) {
+        Ok(outcome) => {
+            if !options.keep_backup_on_success {
+                let _ = backup::discard(snapshot);
+            }
+            progress(MigrationPhase::Done, 100);
+            Ok(outcome)
+        }
+        Err(err) => {
+            let recovery = if rollback::rollback_migration(&snapshot).is_ok() {
+                RecoveryState::RolledBack
+            } else {
+                RecoveryState::Partial
+            };
+            Err(MigrationError {
+                phase: err.phase,
+                message: err.message,
+                recovery,
+            })
+        }
+    }
+}
+
+fn plan(project_root: &Path, options: &ReverseMigrationOptions) -> Result<ReversePlan, String> {
+    let bare = validator::validate_bare_layout(project_root).map_err(|e| e.to_string())?;
+    let primary_branch = options
+        .primary_branch
+        .clone()
+        .or_else(|| bare_head_branch(&bare))
+        .ok_or_else(|| "the bare repository has no HEAD branch; choose one".to_string())?;
+    if !git_ok(
+        &bare,
+        &[
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/heads/{primary_branch}"),
+        ],
+    ) {
+        return Err(format!("branch not found: {primary_branch}"));
+    }
+
+    let worktrees = git_migration::list_worktrees(&bare).map_err(|e| e.to_string())?;
+    let (primary, others): (Vec<_>, Vec<_>) = worktrees
+        .into_iter()
+        .partition(|worktree| worktree.branch == primary_branch);
+    let primary = primary.into_iter().next();
+    if let Some(primary) = &primary {
+        if !path_is_inside(&primary.path, project_root) {
+            return Err(format!(
+                "the worktree of {primary_branch} is outside the project: {}",
+                primary.path.display()
+            ));
+        }
+    }
+    let dirty: Vec<PathBuf> = others
+        .iter()
+        .filter(|worktree| worktree.is_dirty)
+        .map(|worktree| worktree.path.clone())
+        .collect();
+    if !dirty.is_empty() {
+        return Err(ValidationError::DirtyWorktrees(dirty).to_string());
+    }
+
+    let plan = ReversePlan {
+        bare,
+        primary_branch,
+        primary,
+        others,
+    };
+    check_collisions(project_root, &plan)?;
+    Ok(plan)
+}
+
+/// The primary branch's top-level entries land in `project_root`; anything
+/// already there that the migration does not remove must not share a name.
+fn check_collisions(project_root: &Path, plan: &ReversePlan) -> Result<(), String> {
+    let incoming = match &plan.primary {
+        Some(primary) => top_level_names(&primary.path)
+            .map_err(|e| format!("read {}: {e}", primary.path.display()))?,
+        None => tracked_top_level_names(&plan.bare, &plan.primary_branch)?,
+    };
+    let mut vacated = BTreeSet::new();
+    vacated.extend(first_segment(project_root, &plan.bare));
+    for worktree in plan.primary.iter().chain(&plan.others) {
+        vacated.extend(first_segment(project_root, &worktree.path));
+    }
+    if only_project_config(project_root) {
+        vacated.insert(".gwt".to_string());
+    }
+    let remaining = top_level_names(project_root)
+        .map_err(|e| format!("read {}: {e}", project_root.display()))?
+        .into_iter()
+        .filter(|name| !vacated.contains(name) && !name.starts_with(BACKUP_DIR_NAME));
+    let collisions: Vec<String> = remaining.filter(|name| incoming.contains(name)).collect();
+    if collisions.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "entries of {} would overwrite files in the project root: {}",
+            plan.primary_branch,
+            collisions.join(", ")
+        ))
+    }
+}
+
+fn run_post_backup(
+    project_root: &Path,
+    plan: &ReversePlan,
+    progress: &mut impl FnMut(MigrationPhase, u8),
+) -> Result<ReverseMigrationOutcome, MigrationError> {
+    progress(MigrationPhase::Worktrees, 0);
+    let mut removed_worktrees = Vec::with_capacity(plan.others.len());
+    for worktree in &plan.others {
+        let path = worktree.path.to_string_lossy();
+        run_git(
+            &plan.bare,
+            &["worktree", "remove", &path],
+            MigrationPhase::Worktrees,
+        )?;
+        remove_empty_parents(&worktree.path, project_root);
+        removed_worktrees.push(worktree.path.clone());
+    }
+
+    progress(MigrationPhase::Unbare, 0);
+    // `.gwt/project.toml` marks the bare layout; the rest of `.gwt/` stays.
+    let config_path = BareProjectConfig::config_path(project_root);
+    if config_path.exists() {
+        fs::remove_file(&config_path).map_err(|e| phase_error(MigrationPhase::Unbare, e))?;
+    }
+    if let Some(dir) = config_path.parent() {
+        let _ = fs::remove_dir(dir);
+    }
+
+    // Park the primary worktree at the top of the project so its files can
+    // be moved up even when one of them shares its directory's name. A
+    // rollback clears it along with everything else outside the backup.
+    let parked = project_root.join(PARKED_PRIMARY_DIR);
+    let primary_admin = match &plan.primary {
+        Some(primary) => {
+            let admin = worktree_admin_dir(&primary.path).ok_or_else(|| MigrationError {
+                phase: MigrationPhase::Unbare,
+                message: format!("{} has no worktree .git file", primary.path.display()),
+                recovery: RecoveryState::Partial,
+            })?;
+            fs::rename(&primary.path, &parked)
+                .map_err(|e| phase_error(MigrationPhase::Unbare, e))?;
+            remove_empty_parents(&primary.path, project_root);
+            Some(admin)
+        }
+        None => None,
+    };
+
+    let dot_git = project_root.join(".git");
+    fs::rename(&plan.bare, &dot_git).map_err(|e| phase_error(MigrationPhase::Unbare, e))?;
+    run_git(
+        project_root,
+        &["config", "--bool", "core.bare", "false"],
+        MigrationPhase::Unbare,
+    )?;
+    let head = format!("refs/heads/{}", plan.primary_branch);
+    run_git(
+        project_root,
+        &["symbolic-ref", "HEAD", &head],
+        MigrationPhase::Unbare,
+    )?;
+
+    match primary_admin {
+        Some(admin) => {
+            // The worktree's index keeps what was staged there.
+            let admin = dot_git.join("worktrees").join(admin);
+            let index = admin.join("index");
+            if index.is_file() {
+                fs::copy(&index, dot_git.join("index"))
+                    .map_err(|e| phase_error(MigrationPhase::Unbare, e))?;
+            }
+            let _ = fs::remove_file(parked.join(".git"));
+            move_entries(&parked, project_root)
+                .map_err(|e| phase_error(MigrationPhase::Unbare, e))?;
+            let _ = fs::remove_dir_all(&admin);
+            // Refresh stat data of the moved files.
+            let _ = hidden_command("git")
+                .args(["update-index", "-q", "--refresh"])
+                .current_dir(project_root)
+                .output();
+        }
+        None => run_git(
+            project_root,
+            &["checkout", "--force", &plan.primary_branch],
+            MigrationPhase::Unbare,
+        )?,
+    }
+    let _ = hidden_command("git")
+        .args(["worktree", "prune"])
+        .current_dir(project_root)
+        .output();
+
+    progress(MigrationPhase::Submodules, 0);
+    let _ = git_migration::init_submodules(project_root);
+
+    progress(MigrationPhase::Tracking, 0);
+    let _ = git_migration::set_upstream(project_root, &plan.primary_branch);
+
+    progress(MigrationPhase::Cleanup, 0);
+    let _ = fs::remove_dir_all(&parked);
+
+    Ok(ReverseMigrationOutcome {
+        repo_root: project_root.to_path_buf(),
+        primary_branch: plan.primary_branch.clone(),
+        removed_worktrees,
+    })
+}
+
+fn phase_error(phase: MigrationPhase, error: impl std::fmt::Display) -> MigrationError {
+    MigrationError {
+        phase,
+        message: error.to_string(),
+        recovery: RecoveryState::Partial,
+    }
+}
+
+fn run_git(dir: &Path, args: &[&str], phase: MigrationPhase) -> Result<(), MigrationError> {
+    let output = hidden_command("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .map_err(|e| phase_error(phase, format!("git {}: {e}", args.join(" "))))?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(phase_error(
+            phase,
+            format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+        ))
+    }
+}
+
+fn git_ok(dir: &Path, args: &[&str]) -> bool {
+    hidden_command("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+fn bare_head_branch(bare: &Path) -> Option<String> {
+    let output = hidden_command("git")
+        .args(["symbolic-ref", "--short", "HEAD"])
+        .current_dir(bare)
+        .output()
+        .ok()?;
+    let branch = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !branch.is_empty()).then_some(branch)
+}
+
+/// Name of the worktree's admin directory under `<bare>/worktrees/`, read
+/// from its `.git` file (`gitdir: <bare>/worktrees/<name>`).
+fn worktree_admin_dir(worktree: &Path) -> Option<String> {
+    let marker = fs::read_to_string(worktree.join(".git")).ok()?;
+    let gitdir = marker.trim().strip_prefix("gitdir:")?.trim();
+    Path::new(gitdir)
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+}
+
+fn tracked_top_level_names(bare: &Path, branch: &str) -> Result<BTreeSet<String>, String> {
+    let output = hidden_command("git")
+        .args(["ls-tree", "--name-only", branch])
+        .current_dir(bare)
+        .output()
+        .map_err(|e| format!("git ls-tree: {e}"))?;
+    if !output.status.success() {
+        return Err(format!(
+            "git ls-tree {branch} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::to_string)
+        .collect())
+}
+
+fn top_level_names(dir: &Path) -> std::io::Result<BTreeSet<String>> {
+    let mut names = BTreeSet::new();
+    for entry in fs::read_dir(dir)? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if name != ".git" {
+            names.insert(name);
+        }
+    }
+    Ok(names)
+}
+
+/// `.gwt/` holds nothing but the bare layout's `project.toml`.
+fn only_project_config(project_root: &Path) -> bool {
+    let config_path = BareProjectConfig::config_path(project_root);
+    let Some(dir) = config_path.parent() else {
+        return false;
+    };
+    fs::read_dir(dir)
+        .is_ok_and(|entries| entries.flatten().all(|entry| entry.path() == config_path))
+}
+
+fn first_segment(root: &Path, path: &Path) -> Option<String> {
+    let canonical_root = fs::canonicalize(root).unwrap_or_else(|_| root.to_path_buf());
+    let canonical_path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    match canonical_path
+        .strip_prefix(&canonical_root)
+        .ok()?
+        .components()
+        .next()?
+    {
+        Component::Normal(first) => Some(first.to_string_lossy().into_owned()),
+        _ => None,
+    }
+}
+
+fn path_is_inside(path: &Path, root: &Path) -> bool {
+    let canonical_root = fs::canonicalize(root).unwrap_or_else(|_| root.to_path_buf());
+    let canonical_path = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    canonical_path.starts_with(&canonical_root)
+}
+
+/// Remove the now-empty directories between a removed worktree and
+/// `project_root` (`feature/` after `feature/x`).
+fn remove_empty_parents(path: &Path, project_root: &Path) {
+    let canonical_root =
+        fs::canonicalize(project_root).unwrap_or_else(|_| project_root.to_path_buf());
+    let mut current = path.parent();
+    while let Some(dir) = current {
+        let canonical_dir = fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf());
+        if canonical_dir == canonical_root
+            || !canonical_dir.starts_with(&canonical_root)
+            || fs::remove_dir(dir).is_err()
+        {
+            break;
+        }
+        current = dir.parent();
+    }
+}
+
+fn move_entries(src: &Path, dst: &Path) -> std::io::Result<()> {
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        fs::rename(entry.path(), dst.join(entry.file_name()))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,705 @@
+//! OpenAPI 3.1 description of the embedded server's `/api` routes.
+//!
+//! Served at `/api/openapi.json` so external tooling and the frontend can
+//! generate clients from, or validate requests against, one document. The
+//! document is written by hand next to the routes rather than derived from
+//! the handlers; a route added to the router must be added to [`document`]
+//! too. With `[server] swagger_ui = true` the server also serves
+//! [`swagger_ui_page`] at `/api/docs`.
+
+use serde_json::{json, Value};
+
+/// Pinned `swagger-ui-dist` release loaded by [`swagger_ui_page`].
+const SWAGGER_UI_VERSION: &str = "5.17.14";
+
+/// The OpenAPI document for the `/api` routes.
+pub fn document() -> Value {
+    json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "gwt embedded server API",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Routes served by a running gwt instance. Browser \
+                requests must come from the gwt dashboard origin; other origins get 403.",
+        },
+        "paths": paths(),
+        "components": {
+            "parameters": {
+                "RepoId": path_param("id", "Repository id from `/api/repos`."),
+                "Page": query_param("page", integer(), "1-based page number."),
+                "PerPage": query_param(
+                    "per_page",
+                    integer(),
+                    "Items per page, at most 500; 50 when only `page` is given.",
+                ),
+                "Filter": query_param(
+                    "filter",
+                    string(),
+                    "Case-insensitive substring matched against the item's text fields.",
+                ),
+                "Sort": query_param(
+                    "sort",
+                    string(),
+                    "Field to sort by; a leading `-` sorts descending.",
+                ),
+            },
+            "headers": {
+                "TotalCount": header("Items left after filtering."),
+                "Page": header("Page returned."),
+                "PerPage": header("Page size used."),
+            },
+            "schemas": schemas(),
+        },
+    })
+}
+
+/// HTML page that renders [`document`] with Swagger UI.
+pub fn swagger_ui_page() -> String {
+    format!(
+        "<!doctype html><html><head><meta charset=\"utf-8\"><title>gwt API</title>\
+         <link rel=\"stylesheet\" \
+         href=\"https://unpkg.com/swagger-ui-dist@{SWAGGER_UI_VERSION}/swagger-ui.css\">\
+         </head><body><div id=\"swagger-ui\"></div>\
+         <script src=\"https://unpkg.com/swagger-ui-dist@{SWAGGER_UI_VERSION}/swagger-ui-bundle.js\">\
+         </script>\
+         <script>SwaggerUIBundle({{ url: \"/api/openapi.json\", dom_id: \"#swagger-ui\" }});</script>\
+         </body></html>"
+    )
+}
+
+fn paths() -> Value {
+    json!({
+        "/api/openapi.json": {
+            "get": {
+                "summary": "This document.",
+                "responses": { "200": json_response("OpenAPI document.", json!({ "type": "object" })) },
+            },
+        },
+        "/api/events": {
+            "get": {
+                "summary": "Activity bus as server-sent events.",
+                "description": "Each event is named after the activity (`agent.started`, ...), \
+                    carries the envelope `seq` as its id and the envelope JSON as data.",
+                "responses": {
+                    "200": { "description": "Event stream.", "content": { "text/event-stream": {} } },
+                    "403": forbidden(),
+                },
+            },
+        },
+        "/api/logs/stream": {
+            "get": {
+                "summary": "Structured log of one repository as server-sent events.",
+                "description": "Each `log` event carries a log entry as data and the byte offset \
+                    just past its line as id; a reconnect with `Last-Event-ID` resumes after \
+                    it. A `reset` event means the stream started over on a new daily file.",
+                "parameters": [
+                    required(query_param("repo", string(), "Repository id from `/api/repos`.")),
+                    query_param("offset", integer(), "Byte offset of today's file to start at."),
+                    query_param(
+                        "follow",
+                        boolean(),
+                        "Keep streaming new lines (default); `false` ends at the end of the file.",
+                    ),
+                ],
+                "responses": {
+                    "200": { "description": "Event stream.", "content": { "text/event-stream": {} } },
+                    "403": forbidden(),
+                    "404": not_found("Unknown repository."),
+                },
+            },
+        },
+        "/api/repos": {
+            "get": list_operation(
+                "Registered repositories and recent projects.",
+                "RepoSummary",
+                false,
+            ),
+        },
+        "/api/repos/{id}/worktrees": {
+            "get": list_operation("Worktrees of one repository.", "RepoWorktreeSummary", true),
+        },
+        "/api/repos/{id}/branches": {
+            "get": list_operation("Local branches of one repository.", "RepoBranchSummary", true),
+        },
+        "/api/repos/{id}/branches/{branch}/pr": {
+            "post": {
+                "summary": "Push a branch and open its pull request.",
+                "description": "Title and body are drafted by the configured AI from the \
+                    branch's commits, diffstat and agent session summary, or listed from \
+                    the commit subjects without AI.",
+                "parameters": [
+                    component_param("RepoId"),
+                    path_param("branch", "Branch name, percent-encoded (`feature%2Fx`)."),
+                ],
+                "requestBody": json_body(schema_ref("CreateBranchPrRequest")),
+                "responses": {
+                    "201": json_response("Pull request opened.", schema_ref("CreatedBranchPr")),
+                    "403": forbidden(),
+                    "404": not_found("Unknown repository."),
+                    "502": text_response("Push, GitHub or git failure, or an open PR exists."),
+                },
+            },
+        },
+        "/api/repos/{id}/branches/{branch}/sessions/export": {
+            "get": {
+                "summary": "Download the agent session transcripts of a branch.",
+                "description": "Every Claude Code / Codex session gwt recorded on the branch, \
+                    oldest first, with timestamps and tool-call annotations.",
+                "parameters": [
+                    component_param("RepoId"),
+                    path_param("branch", "Branch name, percent-encoded (`feature%2Fx`)."),
+                    query_param(
+                        "format",
+                        string_enum(&["md", "html", "json"]),
+                        "Output format; `md` when omitted.",
+                    ),
+                ],
+                "responses": {
+                    "200": {
+                        "description": "Transcript file (`Content-Disposition: attachment`).",
+                        "content": {
+                            "text/markdown": {},
+                            "text/html": {},
+                            "application/json": {},
+                        },
+                    },
+                    "400": text_response("Unknown `format`."),
+                    "403": forbidden(),
+                    "404": not_found("Unknown repository."),
+                },
+            },
+        },
+        "/api/repos/{id}/branches/{branch}/sessions/diff": {
+            "get": {
+                "summary": "Compare two agent runs of a branch.",
+                "description": "Message counts, tools used, files touched, the first divergent \
+                    message and the decisions only one run made. Without `left` / `right` the \
+                    two most recent runs are compared.",
+                "parameters": [
+                    component_param("RepoId"),
+                    path_param("branch", "Branch name, percent-encoded (`feature%2Fx`)."),
+                    query_param("left", string(), "Agent session id (or unique prefix) of the left run."),
+                    query_param("right", string(), "Agent session id (or unique prefix) of the right run."),
+                ],
+                "responses": {
+                    "200": json_response("Comparison.", schema_ref("SessionDiff")),
+                    "400": text_response("Fewer than two runs, or `left` / `right` match no single run."),
+                    "403": forbidden(),
+                    "404": not_found("Unknown repository."),
+                },
+            },
+        },
+        "/api/sessions/search": {
+            "get": {
+                "summary": "Agent transcript messages containing every term.",
+                "parameters": [
+                    required(query_param("q", string(), "Search terms.")),
+                    query_param("limit", integer(), "Maximum hits, 1–500."),
+                ],
+                "responses": {
+                    "200": json_response("Hits, newest sessions first.", array_of("SessionSearchHit")),
+                    "400": text_response("`q` is empty."),
+                    "403": forbidden(),
+                },
+            },
+        },
+        "/api/sessions/{agent}/{session_id}/stream": {
+            "get": {
+                "summary": "One agent session transcript as server-sent events.",
+                "description": "Each `message` event carries a transcript entry as data and the \
+                    byte offset just past its line as id; a reconnect with `Last-Event-ID` \
+                    resumes after it. A `reset` event means the transcript was replaced and \
+                    is sent again from the start.",
+                "parameters": [
+                    path_param("agent", "`claude` or `codex`."),
+                    path_param("session_id", "Agent-side session id."),
+                    query_param("offset", integer(), "Byte offset of the transcript to start at."),
+                    query_param("tail", boolean(), "Start at the current end of the transcript."),
+                    query_param("since", date_time(), "Skip messages before this time."),
+                    query_param("until", date_time(), "Skip messages after this time."),
+                    query_param(
+                        "follow",
+                        boolean(),
+                        "Keep streaming new messages (default); `false` ends at the end of the file.",
+                    ),
+                ],
+                "responses": {
+                    "200": { "description": "Event stream.", "content": { "text/event-stream": {} } },
+                    "403": forbidden(),
+                    "404": not_found("Unknown agent or session."),
+                },
+            },
+        },
+        "/api/operations": {
+            "get": {
+                "summary": "Migrations and bulk cleanups, newest first.",
+                "responses": {
+                    "200": json_response("Operations.", array_of("OperationRecord")),
+                    "403": forbidden(),
+                },
+            },
+        },
+        "/api/operations/{id}": {
+            "get": {
+                "summary": "One operation, for polling its progress.",
+                "parameters": [path_param("id", "Operation id.")],
+                "responses": {
+                    "200": json_response("Operation.", schema_ref("OperationRecord")),
+                    "403": forbidden(),
+                    "404": not_found("Unknown operation."),
+                },
+            },
+        },
+        "/api/operations/{id}/cancel": {
+            "post": {
+                "summary": "Cancel a running operation or dismiss an interrupted one.",
+                "parameters": [path_param("id", "Operation id.")],
+                "responses": {
+                    "200": json_response("Updated operation.", schema_ref("OperationRecord")),
+                    "403": forbidden(),
+                    "404": not_found("Unknown operation."),
+                    "409": text_response("Already finished or not cancellable."),
+                },
+            },
+        },
+        "/api/ai/branch-suggestions": {
+            "post": {
+                "summary": "Branch name candidates for a task description.",
+                "description": "With `stream: true` the reply is an event stream: a `partial` \
+                    event each time another name completes, then one `done` or `error` event.",
+                "requestBody": json_body(schema_ref("BranchSuggestionsRequest")),
+                "responses": {
+                    "200": {
+                        "description": "Suggestions, or their event stream.",
+                        "content": {
+                            "application/json": { "schema": schema_ref("BranchSuggestions") },
+                            "text/event-stream": {},
+                        },
+                    },
+                    "400": text_response("`description` is empty."),
+                    "403": forbidden(),
+                    "502": text_response("The AI provider failed."),
+                    "503": text_response("AI is not configured."),
+                },
+            },
+        },
+        "/api/ai/explain-hunk": {
+            "post": {
+                "summary": "Short explanation of one diff hunk, cached per hunk hash.",
+                "requestBody": json_body(schema_ref("ExplainHunkRequest")),
+                "responses": {
+                    "200": json_response("Explanation.", schema_ref("HunkExplanation")),
+                    "400": text_response("`hunk` is empty."),
+                    "403": forbidden(),
+                    "502": text_response("The AI provider failed."),
+                    "503": text_response("AI is not configured."),
+                },
+            },
+        },
+        "/api/tasks": {
+            "get": {
+                "summary": "Queued, running and finished tasks, newest first.",
+                "responses": {
+                    "200": json_response("Tasks.", array_of("TaskRecord")),
+                    "403": forbidden(),
+                },
+            },
+            "post": {
+                "summary": "Queue a headless agent run on a branch.",
+                "requestBody": json_body(schema_ref("TaskEnqueueRequest")),
+                "responses": {
+                    "202": json_response("Queued task.", schema_ref("TaskRecord")),
+                    "400": text_response("Missing branch or prompt, or unknown agent."),
+                    "403": forbidden(),
+                    "404": not_found("Unknown repository."),
+                    "503": text_response("`[server] task_queue` is off."),
+                },
+            },
+        },
+        "/api/tasks/{id}": {
+            "get": {
+                "summary": "One task, for polling its state.",
+                "parameters": [path_param("id", "Task id.")],
+                "responses": {
+                    "200": json_response("Task.", schema_ref("TaskRecord")),
+                    "403": forbidden(),
+                    "404": not_found("Unknown task."),
+                },
+            },
+        },
+        "/api/tasks/{id}/log": {
+            "get": {
+                "summary": "Agent output of a task from a byte offset.",
+                "parameters": [
+                    path_param("id", "Task id."),
+                    query_param("offset", integer(), "Byte offset to read from."),
+                ],
+                "responses": {
+                    "200": text_response("Log text after `offset`."),
+                    "403": forbidden(),
+                    "404": not_found("Unknown task."),
+                },
+            },
+        },
+    })
+}
+
+fn schemas() -> Value {
+    json!({
+        "RepoSummary": object(
+            &["id", "title", "path", "kind", "registered"],
+            json!({
+                "id": string(),
+                "title": string(),
+                "path": string(),
+                "kind": string_enum(&["git", "bare", "non_repo"]),
+                "registered": boolean(),
+            }),
+        ),
+        "RepoWorktreeSummary": object(
+            &["path", "branch", "locked", "prunable"],
+            json!({
+                "path": string(),
+                "branch": nullable(string()),
+                "locked": boolean(),
+                "prunable": boolean(),
+            }),
+        ),
+        "RepoBranchSummary": object(
+            &["name", "is_head", "upstream", "ahead", "behind", "last_commit_date", "worktree_path"],
+            json!({
+                "name": string(),
+                "is_head": boolean(),
+                "upstream": nullable(string()),
+                "ahead": integer(),
+                "behind": integer(),
+                "last_commit_date": nullable(string()),
+                "worktree_path": nullable(string()),
+            }),
+        ),
+        "CreateBranchPrRequest": object(
+            &[],
+            json!({
+                "base": nullable(string()),
+                "draft": boolean(),
+            }),
+        ),
+        "CreatedBranchPr": object(
+            &["branch", "base", "number", "url", "title", "ai_drafted"],
+            json!({
+                "branch": string(),
+                "base": string(),
+                "number": integer(),
+                "url": string(),
+                "title": string(),
+                "ai_drafted": boolean(),
+            }),
+        ),
+        "SessionSearchHit": object(
+            &["source", "session_id", "transcript_path", "cwd", "timestamp", "role", "snippet"],
+            json!({
+                "source": string_enum(&["claude", "codex"]),
+                "session_id": string(),
+                "transcript_path": string(),
+                "cwd": nullable(string()),
+                "timestamp": nullable(string()),
+                "role": string_enum(&["user", "assistant", "tool"]),
+                "snippet": string(),
+            }),
+        ),
+        "SessionRunSummary": object(
+            &["agent", "agent_session_id", "model", "started_at", "messages", "user_messages", "assistant_messages", "tools", "files"],
+            json!({
+                "agent": string(),
+                "agent_session_id": string(),
+                "model": nullable(string()),
+                "started_at": date_time(),
+                "messages": integer(),
+                "user_messages": integer(),
+                "assistant_messages": integer(),
+                "tools": { "type": "object", "additionalProperties": integer() },
+                "files": { "type": "array", "items": string() },
+            }),
+        ),
+        "SessionDiffMessage": object(
+            &["role", "excerpt"],
+            json!({ "role": string_enum(&["user", "assistant"]), "excerpt": string() }),
+        ),
+        "SessionDiff": object(
+            &["branch", "left", "right", "tools_only_left", "tools_only_right", "files_common", "files_only_left", "files_only_right", "divergence", "decisions"],
+            json!({
+                "branch": string(),
+                "left": schema_ref("SessionRunSummary"),
+                "right": schema_ref("SessionRunSummary"),
+                "tools_only_left": { "type": "array", "items": string() },
+                "tools_only_right": { "type": "array", "items": string() },
+                "files_common": { "type": "array", "items": string() },
+                "files_only_left": { "type": "array", "items": string() },
+                "files_only_right": { "type": "array", "items": string() },
+                "divergence": nullable(object(
+                    &["index", "left", "right"],
+                    json!({
+                        "index": integer(),
+                        "left": nullable(schema_ref("SessionDiffMessage")),
+                        "right": nullable(schema_ref("SessionDiffMessage")),
+                    }),
+                )),
+                "decisions": {
+                    "type": "array",
+                    "items": object(
+                        &["side", "files", "excerpt", "timestamp"],
+                        json!({
+                            "side": string_enum(&["left", "right"]),
+                            "files": { "type": "array", "items": string() },
+                            "excerpt": string(),
+                            "timestamp": nullable(string()),
+                        }),
+                    ),
+                },
+            }),
+        ),
+        "OperationRecord": object(
+            &["id", "kind", "project_root", "step", "percent", "state", "cancellable", "started_at", "updated_at"],
+            json!({
+                "id": string(),
+                "kind": string_enum(&["migration", "branch_cleanup"]),
+                "project_root": string(),
+                "step": string(),
+                "percent": integer(),
+                "state": string_enum(&["running", "completed", "failed", "cancelled", "interrupted"]),
+                "message": nullable(string()),
+                "cancellable": boolean(),
+                "started_at": date_time(),
+                "updated_at": date_time(),
+            }),
+        ),
+        "TaskEnqueueRequest": object(
+            &["repo", "branch", "prompt"],
+            json!({
+                "repo": { "type": "string", "description": "Repository id or path." },
+                "branch": string(),
+                "prompt": string(),
+                "agent": { "type": "string", "default": "claude" },
+            }),
+        ),
+        "TaskRecord": object(
+            &["id", "repo_path", "branch", "agent", "prompt", "state", "created_at"],
+            json!({
+                "id": string(),
+                "repo_path": string(),
+                "branch": string(),
+                "agent": string(),
+                "prompt": string(),
+                "state": string_enum(&["queued", "running", "completed", "failed", "interrupted"]),
+                "worktree": nullable(string()),
+                "exit_code": nullable(integer()),
+                "message": nullable(string()),
+                "created_at": date_time(),
+                "started_at": nullable(date_time()),
+                "finished_at": nullable(date_time()),
+                "attempts": integer(),
+                "retry_at": nullable(date_time()),
+            }),
+        ),
+        "BranchSuggestionsRequest": object(
+            &["description"],
+            json!({
+                "description": string(),
+                "stream": boolean(),
+            }),
+        ),
+        "BranchSuggestions": object(
+            &["suggestions"],
+            json!({ "suggestions": { "type": "array", "items": string() } }),
+        ),
+        "ExplainHunkRequest": object(
+            &["path", "hunk"],
+            json!({
+                "path": { "type": "string", "description": "Path relative to the repository root." },
+                "hunk": { "type": "string", "description": "One unified diff hunk from its `@@` header." },
+            }),
+        ),
+        "HunkExplanation": object(
+            &["hash", "explanation", "cached"],
+            json!({
+                "hash": string(),
+                "explanation": string(),
+                "cached": boolean(),
+            }),
+        ),
+    })
+}
+
+/// GET operation of a paginated list route.
+fn list_operation(summary: &str, item: &str, repo_scoped: bool) -> Value {
+    let mut parameters = Vec::new();
+    if repo_scoped {
+        parameters.push(component_param("RepoId"));
+    }
+    for name in ["Page", "PerPage", "Filter", "Sort"] {
+        parameters.push(component_param(name));
+    }
+    let mut responses = json!({
+        "200": {
+            "description": "One page of items.",
+            "headers": {
+                "X-Total-Count": { "$ref": "#/components/headers/TotalCount" },
+                "X-Page": { "$ref": "#/components/headers/Page" },
+                "X-Per-Page": { "$ref": "#/components/headers/PerPage" },
+            },
+            "content": { "application/json": { "schema": array_of(item) } },
+        },
+        "400": text_response("Invalid paging or sort field."),
+        "403": forbidden(),
+    });
+    if repo_scoped {
+        responses["404"] = not_found("Unknown repository.");
+        responses["502"] = text_response("git failed.");
+    }
+    json!({ "summary": summary, "parameters": parameters, "responses": responses })
+}
+
+fn required(mut param: Value) -> Value {
+    param["required"] = Value::Bool(true);
+    param
+}
+
+fn path_param(name: &str, description: &str) -> Value {
+    json!({
+        "name": name,
+        "in": "path",
+        "required": true,
+        "description": description,
+        "schema": string(),
+    })
+}
+
+fn query_param(name: &str, schema: Value, description: &str) -> Value {
+    json!({ "name": name, "in": "query", "description": description, "schema": schema })
+}
+
+fn component_param(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/parameters/{name}") })
+}
+
+fn header(description: &str) -> Value {
+    json!({ "description": description, "schema": integer() })
+}
+
+fn json_body(schema: Value) -> Value {
+    json!({ "required": true, "content": { "application/json": { "schema": schema } } })
+}
+
+fn json_response(description: &str, schema: Value) -> Value {
+    json!({ "description": description, "content": { "application/json": { "schema": schema } } })
+}
+
+fn text_response(description: &str) -> Value {
+    json!({ "description": description, "content": { "text/plain": { "schema": string() } } })
+}
+
+fn forbidden() -> Value {
+    json!({ "description": "Request from outside the dashboard origin, or read-only mode for writes." })
+}
+
+fn not_found(description: &str) -> Value {
+    json!({ "description": description })
+}
+
+fn schema_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{name}") })
+}
+
+fn array_of(item: &str) -> Value {
+    json!({ "type": "array", "items": schema_ref(item) })
+}
+
+fn object(required: &[&str], properties: Value) -> Value {
+    json!({ "type": "object", "required": required, "properties": properties })
+}
+
+fn nullable(schema: Value) -> Value {
+    json!({ "oneOf": [schema, { "type": "null" }] })
+}
+
+fn string() -> Value {
+    json!({ "type": "string" })
+}
+
+fn string_enum(values: &[&str]) -> Value {
+    json!({ "type": "string", "enum": values })
+}
+
+fn integer() -> Value {
+    json!({ "type": "integer" })
+}
+
+fn boolean() -> Value {
+    json!({ "type": "boolean" })
+}
+
+fn date_time() -> Value {
+    json!({ "type": "string", "format": "date-time" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect_refs(value: &Value, refs: &mut Vec<String>) {
+        match value {
+            Value::Object(map) => {
+                if let Some(Value::String(target)) = map.get("$ref") {
+                    refs.push(target.clone());
+                }
+                map.values().for_each(|child| collect_refs(child, refs));
+            }
+            Value::Array(items) => items.iter().for_each(|child| collect_refs(child, refs)),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn every_ref_resolves() {
+        let document = document();
+        let mut refs = Vec::new();
+        collect_refs(&document, &mut refs);
+        assert!(!refs.is_empty());
+        for target in refs {
+            let pointer = target.trim_start_matches('#');
+            assert!(document.pointer(pointer).is_some(), "dangling {target}");
+        }
+    }
+
+    #[test]
+    fn path_templates_declare_their_parameters() {
+        let document = document();
+        for (path, item) in document["paths"].as_object().unwrap() {
+            let templated: Vec<&str> = path
+                .split('/')
+                .filter_map(|segment| segment.strip_prefix('{')?.strip_suffix('}'))
+                .collect();
+            for operation in item.as_object().unwrap().values() {
+                let declared: Vec<String> = operation["parameters"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|param| match param.get("$ref").and_then(Value::as_str) {
+                        Some(target) => document.pointer(target.trim_start_matches('#')).unwrap()
+                            ["name"]
+                            .as_str()
+                            .unwrap()
+                            .to_string(),
+                        None => param["name"].as_str().unwrap().to_string(),
+                    })
+                    .collect();
+                for name in &templated {
+                    assert!(
+                        declared.iter().any(|declared| declared == name),
+                        "{path} does not declare {name}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,288 @@
+//! "Create PR" for a branch.
+//!
+//! Pushes the branch to `origin`, drafts the title and body with the
+//! configured AI from the branch's commits, diffstat and the closing summary
+//! of the agent session that worked on it, and opens the pull request
+//! through the GitHub REST client. Without AI (or when it fails) the draft
+//! falls back to the commit subjects. The new PR number is recorded in the
+//! per-repository branch → PR cache so other views can show it without a
+//! `gh` round trip.
+//!
+//! Shared by the Branches window `P` action and
+//! `POST /api/repos/{id}/branches/{branch}/pr`.
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use gwt_git::DEFAULT_UPSTREAM_REMOTE;
+use gwt_github::client::http::HttpIssueClient;
+use serde::{Deserialize, Serialize};
+
+/// Pull request opened by [`create_branch_pr`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CreatedBranchPr {
+    pub branch: String,
+    pub base: String,
+    pub number: u64,
+    pub url: String,
+    pub title: String,
+    /// Whether the title and body came from the AI.
+    pub ai_drafted: bool,
+}
+
+impl CreatedBranchPr {
+    pub fn summary(&self) -> String {
+        format!(
+            "Opened PR #{} for {} into {}: {}",
+            self.number, self.branch, self.base, self.url
+        )
+    }
+}
+
+/// Push `branch` and open a pull request into `base` (the default base
+/// branch when `None`).
+pub fn create_branch_pr(
+    repo_path: &Path,
+    branch: &str,
+    base: Option<&str>,
+    draft: bool,
+) -> Result<CreatedBranchPr, String> {
+    let main = gwt_git::worktree::main_worktree_root(repo_path)
+        .unwrap_or_else(|_| repo_path.to_path_buf());
+    if let Some(number) = gwt_git::pr_status::fetch_open_pr_number_for_branch(&main, branch) {
+        record_branch_pr(&main, branch, number);
+        return Err(format!(
+            "{branch} already has an open pull request (#{number})"
+        ));
+    }
+    let base = match base.map(str::trim).filter(|base| !base.is_empty()) {
+        Some(base) => base.to_string(),
+        None => crate::issue_monitor_worker::resolve_default_base_branch(&main),
+    };
+    if base == branch {
+        return Err(format!("{branch} cannot be its own base"));
+    }
+    let (owner, repo) = crate::issue_monitor_worker::github_remote_owner_and_repo(&main)
+        .map_err(|error| error.to_string())?;
+
+    git(&main, &["push", "-u", DEFAULT_UPSTREAM_REMOTE, branch])?;
+
+    let remote_base = format!("{DEFAULT_UPSTREAM_REMOTE}/{base}");
+    let base_ref = if git(&main, &["rev-parse", "--verify", "--quiet", &remote_base]).is_ok() {
+        remote_base
+    } else {
+        base.clone()
+    };
+    let commit_subjects: Vec<String> = git(
+        &main,
+        &[
+            "log",
+            "--reverse",
+            "--format=%s",
+            &format!("{base_ref}..{branch}"),
+        ],
+    )?
+    .lines()
+    .map(str::to_string)
+    .collect();
+    if commit_subjects.is_empty() {
+        return Err(format!("{branch} has no commits on top of {base}"));
+    }
+    let diffstat = git(
+        &main,
+        &["diff", "--stat", &format!("{base_ref}...{branch}")],
+    )
+    .unwrap_or_default();
+    let session_summary = latest_session_summary(&main, branch);
+
+    let context = gwt_ai::PullRequestContext {
+        branch,
+        base: &base,
+        commit_subjects: &commit_subjects,
+        diffstat: &diffstat,
+        session_summary: session_summary.as_deref(),
+    };
+    let (draft_text, ai_drafted) = match ai_draft(&context) {
+        Ok(text) => (text, true),
+        Err(error) => {
+            tracing::info!(branch, error = %error, "AI PR draft unavailable; using commit subjects");
+            (fallback_draft(&context), false)
+        }
+    };
+
+    let client = HttpIssueClient::from_gh_auth(&owner, &repo).map_err(|error| error.to_string())?;
+    let created = client
+        .create_pull_request(branch, &base, &draft_text.title, &draft_text.body, draft)
+        .map_err(|error| error.to_string())?;
+    record_branch_pr(&main, branch, created.number);
+    Ok(CreatedBranchPr {
+        branch: branch.to_string(),
+        base,
+        number: created.number,
+        url: created.url,
+        title: draft_text.title,
+        ai_drafted,
+    })
+}
+
+fn ai_draft(context: &gwt_ai::PullRequestContext<'_>) -> Result<gwt_ai::PullRequestDraft, String> {
+    let ai = gwt_config::Settings::load().unwrap_or_default().ai;
+    if !ai.is_enabled() {
+        return Err("AI is not configured".to_string());
+    }
+    let provider = gwt_ai::build_provider(
+        &ai.provider,
+        &ai.endpoint,
+        ai.api_key.as_deref().unwrap_or(""),
+        &ai.model,
+    )
+    .map_err(|error| error.to_string())?;
+    gwt_ai::run_ai_job(
+        "Pull request draft",
+        gwt_ai::AiJobPriority::Interactive,
+        || gwt_ai::draft_pull_request(provider.as_ref(), context),
+    )
+    .map_err(|error| gwt_ai::format_error_for_display(provider.kind(), &error))
+}
+
+/// Title from the only commit (or the branch name), body listing commits.
+fn fallback_draft(context: &gwt_ai::PullRequestContext<'_>) -> gwt_ai::PullRequestDraft {
+    let title = match context.commit_subjects {
+        [only] => only.clone(),
+        _ => context.branch.to_string(),
+    };
+    let mut body = String::from("## Changes\n\n");
+    for subject in context.commit_subjects {
+        body.push_str(&format!("- {subject}\n"));
+    }
+    if let Some(summary) = context
+        .session_summary
+        .map(str::trim)
+        .filter(|summary| !summary.is_empty())
+    {
+        body.push_str(&format!("\n## Agent summary\n\n{summary}\n"));
+    }
+    gwt_ai::PullRequestDraft { title, body }
+}
+
+/// Closing message of the most recent agent session on `branch`.
+fn latest_session_summary(repo_path: &Path, branch: &str) -> Option<String> {
+    let repo_hash =
+        crate::index_worker::detect_repo_hash(repo_path).map(|hash| hash.as_str().to_string());
+    let entries = std::fs::read_dir(gwt_core::paths::gwt_sessions_dir()).ok()?;
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("toml"))
+        .filter_map(|path| gwt_agent::Session::load(&path).ok())
+        .filter(|session| {
+            session.branch == branch && repo_hash.is_some() && session.repo_hash == repo_hash
+        })
+        .filter(|session| session.agent_session_id.is_some())
+        .max_by_key(|session| session.last_activity_at)
+        .and_then(|session| {
+            gwt_agent::comparison::read_session_insight(
+                &session.agent_id,
+                session.agent_session_id.as_deref()?,
+            )
+        })
+        .and_then(|insight| insight.closing_message)
+}
+
+/// `{"branches": {"<branch>": <pr number>}}` under
+/// `<cache>/branch-prs/<repo hash>.json`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BranchPrStore {
+    #[serde(default)]
+    branches: BTreeMap<String, u64>,
+}
+
+fn branch_pr_store_path(repo_path: &Path) -> Option<PathBuf> {
+    let repo_hash = crate::index_worker::detect_repo_hash(repo_path)?;
+    Some(
+        gwt_core::paths::gwt_cache_dir()
+            .join("branch-prs")
+            .join(format!("{}.json", repo_hash.as_str())),
+    )
+}
+
+/// PR number last recorded for `branch`, if any.
+pub fn cached_branch_pr(repo_path: &Path, branch: &str) -> Option<u64> {
+    let path = branch_pr_store_path(repo_path)?;
+    let store: BranchPrStore = serde_json::from_slice(&std::fs::read(path).ok()?).ok()?;
+    store.branches.get(branch).copied()
+}
+
+fn record_branch_pr(repo_path: &Path, branch: &str, number: u64) {
+    let Some(path) = branch_pr_store_path(repo_path) else {
+        return;
+    };
+    if let Err(error) = write_branch_pr(&path, branch, number) {
+        tracing::warn!(path = %path.display(), error = %error, "failed to record branch PR");
+    }
+}
+
+fn write_branch_pr(path: &Path, branch: &str, number: u64) -> std::io::Result<()> {
+    let mut store = match std::fs::read(path) {
+        Ok(bytes) => serde_json::from_slice::<BranchPrStore>(&bytes).unwrap_or_default(),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => BranchPrStore::default(),
+        Err(error) => return Err(error),
+    };
+    store.branches.insert(branch.to_string(), number);
+    let bytes = serde_json::to_vec_pretty(&store).map_err(std::io::Error::other)?;
+    gwt_github::cache::write_atomic(path, &bytes)
+}
+
+fn git(dir: &Path, args: &[&str]) -> Result<String, String> {
+    let output = gwt_core::process::run_git_logged(args, Some(dir))
+        .map_err(|error| format!("git {}: {error}", args[0]))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("git {}: {}", args[0], stderr.trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallback_draft_uses_single_commit_subject() {
+        let one = vec!["Add retry".to_string()];
+        let context = gwt_ai::PullRequestContext {
+            branch: "feature/retry",
+            base: "main",
+            commit_subjects: &one,
+            diffstat: "",
+            session_summary: Some("Retries with backoff."),
+        };
+        let draft = fallback_draft(&context);
+        assert_eq!(draft.title, "Add retry");
+        assert_eq!(
+            draft.body,
+            "## Changes\n\n- Add retry\n\n## Agent summary\n\nRetries with backoff.\n"
+        );
+
+        let two = vec!["a".to_string(), "b".to_string()];
+        let context = gwt_ai::PullRequestContext {
+            commit_subjects: &two,
+            session_summary: None,
+            ..context
+        };
+        assert_eq!(fallback_draft(&context).title, "feature/retry");
+    }
+
+    #[test]
+    fn branch_pr_store_keeps_other_branches() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("branch-prs").join("repo.json");
+        write_branch_pr(&path, "feature/a", 1).unwrap();
+        write_branch_pr(&path, "feature/b", 2).unwrap();
+        let store: BranchPrStore = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(store.branches.get("feature/a"), Some(&1));
+        assert_eq!(store.branches.get("feature/b"), Some(&2));
+    }
+}
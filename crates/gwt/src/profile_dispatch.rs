@@ -3,7 +3,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use gwt_config::{Profile, Settings};
+use gwt_config::{KeyringSecretStore, Profile, ProfileOrigin, SecretStore, Settings};
 
 use crate::protocol::{ProfileEntryView, ProfileEnvEntryView, ProfileSnapshotView};
 
@@ -38,15 +38,24 @@ pub fn load_snapshot(
     selected_profile: Option<&str>,
 ) -> Result<ProfileSnapshotView, ProfileServiceError> {
     let path = config_path()?;
-    load_snapshot_at(&path, selected_profile)
+    load_snapshot_at(&path, None, selected_profile)
 }
 
+/// Snapshot of the user's profiles merged with the shared profiles of the
+/// repository at `project_root` (`.gwt/profiles/`).
 pub fn load_snapshot_at(
     path: &Path,
+    project_root: Option<&Path>,
     selected_profile: Option<&str>,
 ) -> Result<ProfileSnapshotView, ProfileServiceError> {
     let mut settings = load_settings_or_default(path)?;
-    snapshot_from_settings(&mut settings, selected_profile, std::env::vars())
+    let (origins, repo_errors) = merge_repo_profiles(&mut settings, project_root);
+    let mut snapshot = snapshot_from_settings(&mut settings, selected_profile, std::env::vars())?;
+    for profile in &mut snapshot.profiles {
+        profile.origin = origins.get(&profile.name).copied().unwrap_or_default();
+    }
+    snapshot.repo_errors = repo_errors;
+    Ok(snapshot)
 }
 
 pub fn create_profile(name: &str) -> Result<(), ProfileServiceError> {
@@ -74,10 +83,14 @@ pub fn create_profile_at(path: &Path, name: &str) -> Result<(), ProfileServiceEr
 
 pub fn switch_active_profile(name: &str) -> Result<(), ProfileServiceError> {
     let path = config_path()?;
-    switch_active_profile_at(&path, name)
+    switch_active_profile_at(&path, None, name)
 }
 
-pub fn switch_active_profile_at(path: &Path, name: &str) -> Result<(), ProfileServiceError> {
+pub fn switch_active_profile_at(
+    path: &Path,
+    project_root: Option<&Path>,
+    name: &str,
+) -> Result<(), ProfileServiceError> {
     let mut settings = load_settings_or_default(path)?;
     let name = require_non_empty("profile name", name)?;
     if settings
@@ -86,7 +99,13 @@ pub fn switch_active_profile_at(path: &Path, name: &str) -> Result<(), ProfileSe
         .iter()
         .all(|profile| profile.name != name)
     {
-        return Err(ProfileServiceError::NotFound(name.to_string()));
+        // A shared repository profile becomes active by name only; it is
+        // not copied into the user config.
+        if repo_profile(project_root, name).is_none() {
+            return Err(ProfileServiceError::NotFound(name.to_string()));
+        }
+        settings.profiles.active = Some(name.to_string());
+        return settings.save(path).map_err(ProfileServiceError::from);
     }
     settings
         .profiles
@@ -101,26 +120,76 @@ pub fn save_profile(
     description: &str,
     env_vars: &[ProfileEnvEntryView],
     disabled_env: &[String],
+    secret_env: &[String],
 ) -> Result<(), ProfileServiceError> {
     let path = config_path()?;
     save_profile_at(
         &path,
+        None,
         current_name,
         name,
         description,
         env_vars,
         disabled_env,
+        secret_env,
     )
 }
 
+/// Save edits to a profile. Editing a shared repository profile saves a
+/// user copy that overrides it from then on.
+///
+/// Values of the `secret_env` keys go to the OS keychain instead of the
+/// config file. An empty value for a key that already was secret keeps the
+/// stored value, since snapshots never carry it.
+#[allow(clippy::too_many_arguments)]
 pub fn save_profile_at(
     path: &Path,
+    project_root: Option<&Path>,
     current_name: &str,
     name: &str,
     description: &str,
     env_vars: &[ProfileEnvEntryView],
     disabled_env: &[String],
+    secret_env: &[String],
+) -> Result<(), ProfileServiceError> {
+    save_profile_with_store(
+        path,
+        project_root,
+        ProfileEdit {
+            current_name,
+            name,
+            description,
+            env_vars,
+            disabled_env,
+            secret_env,
+        },
+        &KeyringSecretStore,
+    )
+}
+
+struct ProfileEdit<'a> {
+    current_name: &'a str,
+    name: &'a str,
+    description: &'a str,
+    env_vars: &'a [ProfileEnvEntryView],
+    disabled_env: &'a [String],
+    secret_env: &'a [String],
+}
+
+fn save_profile_with_store(
+    path: &Path,
+    project_root: Option<&Path>,
+    edit: ProfileEdit<'_>,
+    store: &dyn SecretStore,
 ) -> Result<(), ProfileServiceError> {
+    let ProfileEdit {
+        current_name,
+        name,
+        description,
+        env_vars,
+        disabled_env,
+        secret_env,
+    } = edit;
     let mut settings = load_settings_or_default(path)?;
     let current_name = require_non_empty("profile name", current_name)?;
     if settings
@@ -129,7 +198,10 @@ pub fn save_profile_at(
         .iter()
         .all(|profile| profile.name != current_name)
     {
-        return Err(ProfileServiceError::NotFound(current_name.to_string()));
+        let Some(shared) = repo_profile(project_root, current_name) else {
+            return Err(ProfileServiceError::NotFound(current_name.to_string()));
+        };
+        settings.profiles.profiles.push(shared);
     }
 
     let name = require_non_empty("profile name", name)?;
@@ -148,8 +220,28 @@ pub fn save_profile_at(
         return Err(ProfileServiceError::Duplicate(name.to_string()));
     }
 
-    let env_vars = normalize_env_vars(env_vars)?;
+    let mut env_vars = normalize_env_vars(env_vars)?;
     let disabled_env = normalize_disabled_env(disabled_env)?;
+    let mut secret_env = normalize_disabled_env(secret_env)?;
+    secret_env.retain(|key| env_vars.contains_key(key));
+    let previous_secrets = settings
+        .profiles
+        .get(current_name)
+        .map(|profile| profile.secret_env.clone())
+        .unwrap_or_default();
+    for key in &previous_secrets {
+        let kept = secret_env.contains(key);
+        if kept && env_vars.get(key).is_some_and(String::is_empty) {
+            env_vars.remove(key);
+            if current_name != name {
+                move_secret(store, current_name, name, key)?;
+            }
+        } else if !kept || current_name != name {
+            store
+                .delete(current_name, key)
+                .map_err(ProfileServiceError::from)?;
+        }
+    }
 
     settings
         .profiles
@@ -166,16 +258,36 @@ pub fn save_profile_at(
     };
     profile.env_vars = env_vars;
     profile.disabled_env = disabled_env;
+    profile.secret_env = secret_env;
+    profile.store_secrets(store)?;
 
     settings.save(path).map_err(ProfileServiceError::from)
 }
 
+fn move_secret(
+    store: &dyn SecretStore,
+    from: &str,
+    to: &str,
+    key: &str,
+) -> Result<(), ProfileServiceError> {
+    if let Some(value) = store.get(from, key)? {
+        store.set(to, key, &value)?;
+    }
+    store.delete(from, key).map_err(ProfileServiceError::from)
+}
+
 pub fn delete_profile(name: &str) -> Result<(), ProfileServiceError> {
     let path = config_path()?;
-    delete_profile_at(&path, name)
+    delete_profile_at(&path, None, name)
 }
 
-pub fn delete_profile_at(path: &Path, name: &str) -> Result<(), ProfileServiceError> {
+/// Delete a user profile. Shared repository profiles are removed from the
+/// repository instead; deleting a user override reveals the shared one.
+pub fn delete_profile_at(
+    path: &Path,
+    project_root: Option<&Path>,
+    name: &str,
+) -> Result<(), ProfileServiceError> {
     let mut settings = load_settings_or_default(path)?;
     let name = require_non_empty("profile name", name)?;
     if name == "default" {
@@ -189,13 +301,30 @@ pub fn delete_profile_at(path: &Path, name: &str) -> Result<(), ProfileServiceEr
         .iter()
         .all(|profile| profile.name != name)
     {
+        if repo_profile(project_root, name).is_some() {
+            return Err(ProfileServiceError::InvalidInput(format!(
+                "'{name}' is shared by the repository; remove it from {}/ instead",
+                gwt_config::PROJECT_PROFILES_DIR
+            )));
+        }
         return Err(ProfileServiceError::NotFound(name.to_string()));
     }
+    let secret_env = settings
+        .profiles
+        .get(name)
+        .map(|profile| profile.secret_env.clone())
+        .unwrap_or_default();
     settings
         .profiles
         .delete_profile(name)
         .map_err(ProfileServiceError::InvalidInput)?;
-    settings.save(path).map_err(ProfileServiceError::from)
+    settings.save(path).map_err(ProfileServiceError::from)?;
+    for key in &secret_env {
+        if let Err(error) = KeyringSecretStore.delete(name, key) {
+            tracing::warn!(profile = name, key = %key, %error, "failed to delete profile secret");
+        }
+    }
+    Ok(())
 }
 
 fn load_settings_or_default(path: &Path) -> Result<Settings, ProfileServiceError> {
@@ -206,6 +335,25 @@ fn load_settings_or_default(path: &Path) -> Result<Settings, ProfileServiceError
     }
 }
 
+fn merge_repo_profiles(
+    settings: &mut Settings,
+    project_root: Option<&Path>,
+) -> (BTreeMap<String, ProfileOrigin>, Vec<String>) {
+    let Some(project_root) = project_root else {
+        return (BTreeMap::new(), Vec::new());
+    };
+    let shared = gwt_config::load_repo_profiles(project_root);
+    let origins = settings.profiles.merge_repo_profiles(&shared.profiles);
+    (origins, shared.errors)
+}
+
+fn repo_profile(project_root: Option<&Path>, name: &str) -> Option<Profile> {
+    gwt_config::load_repo_profiles(project_root?)
+        .profiles
+        .into_iter()
+        .find(|profile| profile.name == name)
+}
+
 fn require_non_empty<'a>(field: &str, value: &'a str) -> Result<&'a str, ProfileServiceError> {
     let trimmed = value.trim();
     if trimmed.is_empty() {
@@ -270,23 +418,28 @@ where
         .map(|profile| ProfileEntryView {
             name: profile.name.clone(),
             description: profile.description.clone(),
-            env_vars: sorted_env_entries(&profile.env_vars),
+            env_vars: sorted_env_entries(profile),
             disabled_env: sorted_disabled_env(&profile.disabled_env),
+            secret_env: sorted_disabled_env(&profile.secret_env),
             is_default: profile.name == "default",
             is_active: profile.name == active_profile,
+            origin: ProfileOrigin::User,
         })
         .collect();
 
     let os_env = sorted_env_pairs(base_env);
-    let merged_preview = selected
+    let mut merged: BTreeMap<String, String> = selected
         .merged_env_pairs(
             os_env
                 .iter()
                 .map(|entry| (entry.key.clone(), entry.value.clone())),
         )
         .into_iter()
-        .map(mask_preview_entry)
         .collect();
+    for key in &selected.secret_env {
+        merged.insert(key.clone(), SECRET_PREVIEW.to_string());
+    }
+    let merged_preview = merged.into_iter().map(mask_preview_entry).collect();
 
     Ok(ProfileSnapshotView {
         active_profile,
@@ -294,15 +447,22 @@ where
         profiles,
         os_env,
         merged_preview,
+        repo_errors: Vec::new(),
     })
 }
 
-fn sorted_env_entries(
-    env_vars: &std::collections::HashMap<String, String>,
-) -> Vec<ProfileEnvEntryView> {
-    env_vars
+/// The profile's overrides; secret keys are listed with an empty value.
+fn sorted_env_entries(profile: &Profile) -> Vec<ProfileEnvEntryView> {
+    profile
+        .env_vars
         .iter()
         .map(|(key, value)| (key.clone(), value.clone()))
+        .chain(
+            profile
+                .secret_env
+                .iter()
+                .map(|key| (key.clone(), String::new())),
+        )
         .collect::<BTreeMap<_, _>>()
         .into_iter()
         .map(|(key, value)| ProfileEnvEntryView { key, value })
@@ -327,8 +487,11 @@ fn sorted_disabled_env(disabled_env: &[String]) -> Vec<String> {
     values
 }
 
+/// Preview value of a variable whose value is in the keychain.
+const SECRET_PREVIEW: &str = "<keychain>";
+
 fn mask_preview_entry((key, value): (String, String)) -> ProfileEnvEntryView {
-    let value = if is_sensitive_env_key(&key) {
+    let value = if value != SECRET_PREVIEW && gwt_config::secrets::is_sensitive_env_key(&key) {
         "<redacted>".to_string()
     } else {
         value
@@ -336,24 +499,6 @@ fn mask_preview_entry((key, value): (String, String)) -> ProfileEnvEntryView {
     ProfileEnvEntryView { key, value }
 }
 
-fn is_sensitive_env_key(key: &str) -> bool {
-    let key = key.to_ascii_uppercase();
-    key.starts_with("AWS_")
-        || [
-            "TOKEN",
-            "SECRET",
-            "PASSWORD",
-            "PASS",
-            "API_KEY",
-            "APIKEY",
-            "CREDENTIAL",
-            "PRIVATE_KEY",
-            "ACCESS_KEY",
-        ]
-        .iter()
-        .any(|marker| key.contains(marker))
-}
-
 #[cfg(test)]
 mod tests {
     use tempfile::tempdir;
@@ -367,7 +512,7 @@ mod tests {
     #[test]
     fn load_snapshot_at_missing_file_returns_default_profile_snapshot() {
         let temp = tempdir().expect("tempdir");
-        let snapshot = load_snapshot_at(&config_path(&temp), None).expect("default snapshot");
+        let snapshot = load_snapshot_at(&config_path(&temp), None, None).expect("default snapshot");
 
         assert_eq!(snapshot.active_profile, "default");
         assert_eq!(snapshot.selected_profile, "default");
@@ -382,9 +527,10 @@ mod tests {
         let path = config_path(&temp);
 
         create_profile_at(&path, "dev").expect("create profile");
-        switch_active_profile_at(&path, "dev").expect("switch active profile");
+        switch_active_profile_at(&path, None, "dev").expect("switch active profile");
         save_profile_at(
             &path,
+            None,
             "dev",
             "dev",
             "Repo development",
@@ -399,6 +545,7 @@ mod tests {
                 },
             ],
             &["SECRET".to_string()],
+            &[],
         )
         .expect("save profile");
 
@@ -453,6 +600,7 @@ mod tests {
 
         let error = save_profile_at(
             &path,
+            None,
             "dev",
             "dev",
             "",
@@ -467,6 +615,7 @@ mod tests {
                 },
             ],
             &[],
+            &[],
         )
         .expect_err("duplicate env key should fail");
 
@@ -478,17 +627,17 @@ mod tests {
         let temp = tempdir().expect("tempdir");
         let path = config_path(&temp);
         create_profile_at(&path, "dev").expect("create profile");
-        switch_active_profile_at(&path, "dev").expect("switch active");
+        switch_active_profile_at(&path, None, "dev").expect("switch active");
 
-        let error =
-            delete_profile_at(&path, "default").expect_err("default profile delete should fail");
+        let error = delete_profile_at(&path, None, "default")
+            .expect_err("default profile delete should fail");
         assert_eq!(
             error,
             ProfileServiceError::InvalidInput("default profile cannot be deleted".to_string())
         );
 
-        delete_profile_at(&path, "dev").expect("delete profile");
-        let snapshot = load_snapshot_at(&path, Some("dev")).expect("load snapshot");
+        delete_profile_at(&path, None, "dev").expect("delete profile");
+        let snapshot = load_snapshot_at(&path, None, Some("dev")).expect("load snapshot");
         assert_eq!(snapshot.active_profile, "default");
         assert_eq!(snapshot.selected_profile, "default");
     }
@@ -498,12 +647,22 @@ mod tests {
         let temp = tempdir().expect("tempdir");
         let path = config_path(&temp);
         create_profile_at(&path, "dev").expect("create profile");
-        switch_active_profile_at(&path, "dev").expect("switch active");
+        switch_active_profile_at(&path, None, "dev").expect("switch active");
 
-        save_profile_at(&path, "dev", "review", "Review profile", &[], &[])
-            .expect("rename profile");
+        save_profile_at(
+            &path,
+            None,
+            "dev",
+            "review",
+            "Review profile",
+            &[],
+            &[],
+            &[],
+        )
+        .expect("rename profile");
 
-        let snapshot = load_snapshot_at(&path, Some("review")).expect("load renamed snapshot");
+        let snapshot =
+            load_snapshot_at(&path, None, Some("review")).expect("load renamed snapshot");
         assert_eq!(snapshot.active_profile, "review");
         assert_eq!(snapshot.selected_profile, "review");
         assert!(snapshot
@@ -550,6 +709,7 @@ mod tests {
 
         save_profile_at(
             &path,
+            None,
             "default",
             "default",
             "",
@@ -558,6 +718,7 @@ mod tests {
                 value: String::new(),
             }],
             &["SECRET".to_string()],
+            &[],
         )
         .expect("save empty override");
 
@@ -600,4 +761,135 @@ mod tests {
                 && profile.disabled_env == vec!["SECRET".to_string()]
         }));
     }
+
+    #[test]
+    fn repo_profiles_are_listed_activated_and_overridden_on_edit() {
+        let temp = tempdir().expect("tempdir");
+        let path = config_path(&temp);
+        let repo = tempdir().expect("repo");
+        let shared_dir = repo.path().join(gwt_config::PROJECT_PROFILES_DIR);
+        std::fs::create_dir_all(&shared_dir).expect("profiles dir");
+        std::fs::write(shared_dir.join("team.toml"), "[env_vars]\nTEAM = \"1\"\n")
+            .expect("write team profile");
+        std::fs::write(shared_dir.join("broken.toml"), "name = [\n").expect("write broken");
+        let repo_root = Some(repo.path());
+
+        let snapshot = load_snapshot_at(&path, repo_root, None).expect("snapshot");
+        let team = snapshot
+            .profiles
+            .iter()
+            .find(|profile| profile.name == "team")
+            .expect("team profile listed");
+        assert_eq!(team.origin, ProfileOrigin::Repo);
+        assert_eq!(snapshot.repo_errors.len(), 1);
+        assert!(load_snapshot_at(&path, None, Some("team"))
+            .expect("snapshot without repo")
+            .profiles
+            .iter()
+            .all(|profile| profile.name != "team"));
+
+        switch_active_profile_at(&path, repo_root, "team").expect("activate repo profile");
+        let snapshot = load_snapshot_at(&path, repo_root, None).expect("snapshot");
+        assert_eq!(snapshot.active_profile, "team");
+
+        let error = delete_profile_at(&path, repo_root, "team").expect_err("repo delete");
+        assert!(matches!(error, ProfileServiceError::InvalidInput(_)));
+
+        save_profile_at(
+            &path,
+            repo_root,
+            "team",
+            "team",
+            "Mine",
+            &[ProfileEnvEntryView {
+                key: "TEAM".to_string(),
+                value: "2".to_string(),
+            }],
+            &[],
+            &[],
+        )
+        .expect("save override");
+        let snapshot = load_snapshot_at(&path, repo_root, Some("team")).expect("snapshot");
+        let team = snapshot
+            .profiles
+            .iter()
+            .find(|profile| profile.name == "team")
+            .expect("team profile listed");
+        assert_eq!(team.origin, ProfileOrigin::UserOverridesRepo);
+        assert_eq!(team.env_vars[0].value, "2");
+
+        delete_profile_at(&path, repo_root, "team").expect("delete override");
+        let snapshot = load_snapshot_at(&path, repo_root, Some("team")).expect("snapshot");
+        assert_eq!(snapshot.selected_profile, "team");
+        assert_eq!(
+            snapshot.profiles.last().expect("team").origin,
+            ProfileOrigin::Repo
+        );
+    }
+
+    #[test]
+    fn secret_values_are_kept_out_of_the_config_and_follow_renames() {
+        let temp = tempdir().expect("tempdir");
+        let path = config_path(&temp);
+        let store = gwt_config::MemorySecretStore::default();
+        create_profile_at(&path, "dev").expect("create profile");
+        let entry = |key: &str, value: &str| ProfileEnvEntryView {
+            key: key.to_string(),
+            value: value.to_string(),
+        };
+        let secret_env = ["NPM_TOKEN".to_string()];
+        save_profile_with_store(
+            &path,
+            None,
+            ProfileEdit {
+                current_name: "dev",
+                name: "dev",
+                description: "",
+                env_vars: &[entry("NPM_TOKEN", "npm_1"), entry("CI", "1")],
+                disabled_env: &[],
+                secret_env: &secret_env,
+            },
+            &store,
+        )
+        .expect("save secret");
+
+        let saved = std::fs::read_to_string(&path).expect("config");
+        assert!(!saved.contains("npm_1"), "{saved}");
+        assert_eq!(
+            store.get("dev", "NPM_TOKEN").expect("get").as_deref(),
+            Some("npm_1")
+        );
+        let snapshot = load_snapshot_at(&path, None, Some("dev")).expect("snapshot");
+        let dev = snapshot
+            .profiles
+            .iter()
+            .find(|profile| profile.name == "dev")
+            .expect("dev");
+        assert_eq!(dev.secret_env, secret_env);
+        assert!(dev.env_vars.contains(&entry("NPM_TOKEN", "")));
+        assert!(snapshot
+            .merged_preview
+            .contains(&entry("NPM_TOKEN", SECRET_PREVIEW)));
+
+        // An unchanged (empty) secret keeps its stored value across a rename.
+        save_profile_with_store(
+            &path,
+            None,
+            ProfileEdit {
+                current_name: "dev",
+                name: "ops",
+                description: "",
+                env_vars: &[entry("NPM_TOKEN", ""), entry("CI", "1")],
+                disabled_env: &[],
+                secret_env: &secret_env,
+            },
+            &store,
+        )
+        .expect("rename");
+        assert_eq!(store.get("dev", "NPM_TOKEN").expect("get"), None);
+        assert_eq!(
+            store.get("ops", "NPM_TOKEN").expect("get").as_deref(),
+            Some("npm_1")
+        );
+    }
 }
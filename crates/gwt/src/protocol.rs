@@ -154,6 +154,13 @@ pub enum FocusCycleDirection {
 pub enum BranchEntriesPhase {
     Inventory,
     Hydrated,
+    /// Inventory of a list too long to hydrate in one pass
+    /// ([`crate::branch_list::LAZY_HYDRATION_THRESHOLD`]); no `Hydrated`
+    /// event follows. The window requests the rows it shows with
+    /// `HydrateBranches`.
+    Lazy,
+    /// Hydrated subset of a `Lazy` load, merged into the list by name.
+    Partial,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -203,6 +210,11 @@ pub enum FrontendEvent {
     },
     /// Request an immediate usage refresh (SPEC-2970 FR-022).
     RefreshUsage,
+    /// Cancel one job of the AI job queue from the status strip. A running
+    /// job finishes but its result is discarded.
+    CancelAiJob {
+        job_id: u64,
+    },
     StartupAutoResumeReady {
         bounds: WindowGeometry,
     },
@@ -218,6 +230,20 @@ pub enum FrontendEvent {
     ReopenRecentProject {
         path: String,
     },
+    /// Fold the duplicate project at `path` into the clone of the same
+    /// remote listed in [`AppStateView::duplicate_projects`]. Answered with
+    /// [`BackendEvent::DuplicateProjectConsolidated`] or
+    /// [`BackendEvent::DuplicateProjectConsolidateError`].
+    ConsolidateDuplicateProject {
+        path: String,
+    },
+    /// A folder was dropped onto the window. Git workspaces are opened
+    /// like Open Project, with `bounds` placing their Branches window;
+    /// other folders get [`BackendEvent::ProjectDropNeedsClone`].
+    DropProjectFolder {
+        path: String,
+        bounds: WindowGeometry,
+    },
     SelectProjectTab {
         tab_id: String,
     },
@@ -386,9 +412,53 @@ pub enum FrontendEvent {
         #[serde(default)]
         hex_byte: Option<u8>,
     },
+    /// File Tree Changes pane: list the selected worktree's changed files.
+    LoadGitChanges {
+        id: String,
+    },
+    /// Load the staged or unstaged diff of one changed file.
+    LoadFileDiff {
+        id: String,
+        path: String,
+        staged: bool,
+    },
+    /// Stage hunk `hunk_index` of the unstaged diff of `path`, or unstage it
+    /// from the staged diff when `staged`.
+    ToggleDiffHunk {
+        id: String,
+        path: String,
+        staged: bool,
+        hunk_index: usize,
+    },
+    /// Ask the configured AI provider for a commit message describing the
+    /// staged changes of the selected worktree.
+    SuggestCommitMessage {
+        id: String,
+    },
+    /// Stage `paths`, then commit the index with `message`.
+    CreateCommit {
+        id: String,
+        #[serde(default)]
+        paths: Vec<String>,
+        message: String,
+        #[serde(default)]
+        signoff: bool,
+        #[serde(default)]
+        gpg_sign: bool,
+    },
     LoadBranches {
         id: String,
     },
+    /// Branches list of a lazy load: hydrate the rows around the viewport.
+    /// A newer request for the same window cancels one still running.
+    /// Replies with `Partial` [`BackendEvent::BranchEntries`] chunks.
+    HydrateBranches {
+        id: String,
+        branches: Vec<String>,
+        /// `load_id` of the `Lazy` inventory the rows came from.
+        #[serde(default)]
+        load_id: u64,
+    },
     /// SPEC-2359 US-83: the Workspace surface requests the list of eligible
     /// existing remote branches a user can start work on (the "Open a branch…"
     /// toolbar action / picker). `id` is the requesting Workspace window id.
@@ -481,6 +551,140 @@ pub enum FrontendEvent {
         delete_remote: bool,
         #[serde(default)]
         force_filesystem_delete: bool,
+        /// Protected-pattern branches whose names the user typed to confirm.
+        #[serde(default)]
+        confirmed_protected: Vec<String>,
+    },
+    /// Branches list: open a worktree directory in the file manager or the
+    /// `[open]` editor. `path` must be a worktree of the window's project;
+    /// failures reply with [`BackendEvent::BranchError`].
+    OpenWorktreePath {
+        id: String,
+        path: String,
+        target: crate::worktree_open::WorktreeOpenTarget,
+    },
+    /// Branches list: stash or restore the uncommitted changes of a worktree
+    /// of the window's project. Replies with
+    /// [`BackendEvent::WorktreeStashResult`] or [`BackendEvent::BranchError`].
+    StashWorktree {
+        id: String,
+        path: String,
+        action: crate::worktree_stash::WorktreeStashAction,
+    },
+    /// Branches cleanup: archive the worktrees of `branches` instead of
+    /// deleting them. Replies with [`BackendEvent::BranchCleanupResult`].
+    ArchiveWorktrees {
+        id: String,
+        branches: Vec<String>,
+    },
+    /// Branches list: recreate the archived worktree of `branch`. Replies
+    /// with [`BackendEvent::WorktreeRestoreResult`] or
+    /// [`BackendEvent::BranchError`].
+    RestoreWorktree {
+        id: String,
+        branch: String,
+    },
+    /// Branches list: fix the integrity problems found in the worktree of
+    /// `branch`. Replies with [`BackendEvent::WorktreeRepairResult`] or
+    /// [`BackendEvent::BranchError`].
+    RepairWorktree {
+        id: String,
+        branch: String,
+    },
+    /// Branches list: create `new_branch` from `branch`'s HEAD and carry
+    /// `branch`'s uncommitted changes over. Replies with
+    /// [`BackendEvent::WorktreeTransplantResult`] or
+    /// [`BackendEvent::BranchError`].
+    TransplantWorktree {
+        id: String,
+        branch: String,
+        new_branch: String,
+    },
+    /// Branches list: download the artifacts of `branch`'s latest completed
+    /// CI runs into its worktree, only those matching `pattern` when given.
+    /// Replies with [`BackendEvent::CiArtifactsDownloadResult`] or
+    /// [`BackendEvent::BranchError`].
+    DownloadCiArtifacts {
+        id: String,
+        branch: String,
+        #[serde(default)]
+        pattern: Option<String>,
+    },
+    /// Branches list: fetch the default base and rebase `branch` onto it,
+    /// or onto `onto` when given. Replies with
+    /// [`BackendEvent::BranchRebaseResult`] or [`BackendEvent::BranchError`].
+    RebaseBranch {
+        id: String,
+        branch: String,
+        #[serde(default)]
+        onto: Option<String>,
+    },
+    /// Continue the stopped rebase of `branch` after its conflicts were
+    /// staged. Same replies as `RebaseBranch`.
+    ContinueRebase {
+        id: String,
+        branch: String,
+    },
+    /// Abort the stopped rebase of `branch`. Same replies as
+    /// `RebaseBranch`.
+    AbortRebase {
+        id: String,
+        branch: String,
+    },
+    /// Open the Launch Wizard on `branch` with a prompt asking the agent to
+    /// resolve the conflicts of its stopped rebase.
+    ResolveRebaseConflicts {
+        id: String,
+        branch: String,
+    },
+    /// Branches list: push `branch` and open a pull request into the default
+    /// base with an AI-drafted title and body. Replies with
+    /// [`BackendEvent::PullRequestCreateResult`] or
+    /// [`BackendEvent::BranchError`].
+    CreatePullRequest {
+        id: String,
+        branch: String,
+    },
+    /// Branches list: latest CI state per branch, from recent workflow runs.
+    /// Replies with [`BackendEvent::BranchCiStatuses`].
+    LoadBranchCiStatus {
+        id: String,
+    },
+    /// Branches window: list agent processes started outside gwt. Replies
+    /// with [`BackendEvent::ExternalAgents`].
+    DetectExternalAgents {
+        id: String,
+    },
+    /// Branches window: track the external agent `pid` as a session on
+    /// `branch`. Replies with [`BackendEvent::ExternalAgentAdopted`] or
+    /// [`BackendEvent::BranchError`].
+    AdoptExternalAgent {
+        id: String,
+        pid: u32,
+        branch: String,
+    },
+    /// Branches window "Stale remotes" view: list `origin` branches whose PRs
+    /// are all merged or closed and whose last commit is at least
+    /// `min_age_days` old.
+    LoadStaleRemoteBranches {
+        id: String,
+        min_age_days: u32,
+    },
+    /// Delete the confirmed stale branches from `origin`.
+    DeleteStaleRemoteBranches {
+        id: String,
+        branches: Vec<String>,
+    },
+    /// Branches window "Removed" view: list the worktrees branch cleanup
+    /// moved into the trash.
+    LoadRemovedWorktrees {
+        id: String,
+    },
+    /// Restore the most recently removed worktree of `branch`. Replies with
+    /// [`BackendEvent::WorktreeRestoreResult`] or [`BackendEvent::BranchError`].
+    UndoWorktreeRemoval {
+        id: String,
+        branch: String,
     },
     RunWorkspaceCleanup {
         branch: String,
@@ -552,6 +756,9 @@ pub enum FrontendEvent {
         description: String,
         env_vars: Vec<ProfileEnvEntryView>,
         disabled_env: Vec<String>,
+        /// `env_vars` keys whose values go to the OS keychain.
+        #[serde(default)]
+        secret_env: Vec<String>,
     },
     DeleteProfile {
         id: String,
@@ -760,6 +967,11 @@ pub enum FrontendEvent {
     StartMigration {
         tab_id: String,
     },
+    /// Migration confirmation modal opened for `tab_id`: backend replies with
+    /// a [`BackendEvent::MigrationReport`] dry run of the migration.
+    MigrationDryRun {
+        tab_id: String,
+    },
     /// SPEC-1934 US-6.7: user dismissed the migration modal for `tab_id`.
     /// Tab opens with the original Normal Git layout; the modal will appear
     /// again on the next launch.
@@ -846,9 +1058,22 @@ pub enum FrontendEvent {
         #[serde(default)]
         board_provider: Option<String>,
     },
+    /// Settings > System > AI preset: point `[ai]` at a local model server.
+    /// `preset` is `ollama`. Backend replies with
+    /// [`BackendEvent::AiPresetApplied`] on success or
+    /// [`BackendEvent::SystemSettingsError`] on failure.
+    ApplyAiPreset {
+        preset: String,
+    },
     /// SPEC #2920 Phase 11: Settings > System opened. Backend replies with
     /// the current OS autostart registration state for this user.
     GetAutostartStatus,
+    /// Status strip: a `profile` or `command` footer widget is due for a
+    /// refresh. `index` is [`gwt_config::FooterWidget::index`]. Backend
+    /// replies with [`BackendEvent::FooterWidgetValue`].
+    RefreshFooterWidget {
+        index: usize,
+    },
     /// SPEC #2920 Phase 11: Settings > System > Launch GWT at login changed.
     /// Backend installs or uninstalls the per-user autostart registration and
     /// replies with the authoritative status on success.
@@ -924,6 +1149,53 @@ pub enum FrontendEvent {
     },
 }
 
+impl FrontendEvent {
+    /// Whether the event only reads state, so it may be served while the
+    /// embedded server runs in read-only mode. This is an allowlist: new
+    /// variants are rejected in read-only mode until they are added here.
+    pub fn allowed_in_read_only(&self) -> bool {
+        matches!(
+            self,
+            FrontendEvent::FrontendReady
+                | FrontendEvent::RefreshUsage
+                | FrontendEvent::SelectProjectTab { .. }
+                | FrontendEvent::FocusWindow { .. }
+                | FrontendEvent::CycleFocus { .. }
+                | FrontendEvent::UpdateViewport { .. }
+                | FrontendEvent::ListWindows
+                | FrontendEvent::LoadFileTree { .. }
+                | FrontendEvent::ListFileTreeWorktrees { .. }
+                | FrontendEvent::SelectFileTreeWorktree { .. }
+                | FrontendEvent::LoadFileContent { .. }
+                | FrontendEvent::LoadGitChanges { .. }
+                | FrontendEvent::LoadFileDiff { .. }
+                | FrontendEvent::LoadBranches { .. }
+                | FrontendEvent::HydrateBranches { .. }
+                | FrontendEvent::LoadStaleRemoteBranches { .. }
+                | FrontendEvent::LoadRemovedWorktrees { .. }
+                | FrontendEvent::LoadBoard { .. }
+                | FrontendEvent::LoadBoardHistory { .. }
+                | FrontendEvent::LoadProfile { .. }
+                | FrontendEvent::LoadLogs { .. }
+                | FrontendEvent::LoadProcessConsole { .. }
+                | FrontendEvent::LoadKnowledgeBridge { .. }
+                | FrontendEvent::SearchKnowledgeBridge { .. }
+                | FrontendEvent::SearchProjectIndex { .. }
+                | FrontendEvent::SelectKnowledgeBridgeEntry { .. }
+                | FrontendEvent::RefreshIndexStatus { .. }
+                | FrontendEvent::ListResumableAgents { .. }
+                | FrontendEvent::ListIssueMonitor
+                | FrontendEvent::ListCustomAgents
+                | FrontendEvent::ListCustomAgentPresets
+                | FrontendEvent::ListAgentBackends { .. }
+                | FrontendEvent::GetSystemSettings
+                | FrontendEvent::GetBoardAuthStatus
+                | FrontendEvent::GetProjectBoardConfig { .. }
+                | FrontendEvent::GetAutostartStatus
+        )
+    }
+}
+
 /// Browser-side metadata-only UI trace payload sent by Diagnostics > Stop UI
 /// Trace. Top-level fields are typed so backend validation is explicit, while
 /// individual entries remain schema-flexible for low-friction diagnostics.
@@ -1028,6 +1300,15 @@ pub struct RecentProjectView {
     pub kind: ProjectKind,
 }
 
+/// A recent or registered project that is a second clone of the same
+/// origin remote as `canonical_path`.
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicateProjectView {
+    pub path: String,
+    pub canonical_path: String,
+    pub remote: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct GitHubRepositorySearchResultView {
     pub full_name: String,
@@ -1050,8 +1331,16 @@ pub struct ProfileEntryView {
     pub description: String,
     pub env_vars: Vec<ProfileEnvEntryView>,
     pub disabled_env: Vec<String>,
+    /// `env_vars` keys whose values are in the OS keychain; the snapshot
+    /// lists them with an empty value.
+    #[serde(default)]
+    pub secret_env: Vec<String>,
     pub is_default: bool,
     pub is_active: bool,
+    /// User config, the repository's `.gwt/profiles/`, or a user profile
+    /// overriding a repository one of the same name.
+    #[serde(default)]
+    pub origin: gwt_config::ProfileOrigin,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -1062,6 +1351,9 @@ pub struct ProfileSnapshotView {
     #[serde(default)]
     pub os_env: Vec<ProfileEnvEntryView>,
     pub merged_preview: Vec<ProfileEnvEntryView>,
+    /// `.gwt/profiles/` files that could not be loaded.
+    #[serde(default)]
+    pub repo_errors: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1070,6 +1362,10 @@ pub struct AppStateView {
     pub tabs: Vec<ProjectTabView>,
     pub active_tab_id: Option<String>,
     pub recent_projects: Vec<RecentProjectView>,
+    /// `repositories` from the global config; listed in the project
+    /// switcher even when they are not in the recent history.
+    pub registered_projects: Vec<RecentProjectView>,
+    pub duplicate_projects: Vec<DuplicateProjectView>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -1486,6 +1782,11 @@ pub enum BackendEvent {
     RuntimeHealth {
         snapshot: RuntimeHealthSnapshotView,
     },
+    /// AI job queue snapshot, broadcast on every change (status strip AI
+    /// cell).
+    AiQueueStatus {
+        status: gwt_ai::AiQueueStatus,
+    },
     TerminalOutput {
         id: String,
         data_base64: String,
@@ -1619,6 +1920,31 @@ pub enum BackendEvent {
         #[serde(default)]
         current_size: Option<u64>,
     },
+    GitChanges {
+        id: String,
+        entries: Vec<gwt_git::diff::FileEntry>,
+    },
+    FileDiff {
+        id: String,
+        diff: gwt_git::diff::FileDiff,
+    },
+    FileDiffError {
+        id: String,
+        path: String,
+        message: String,
+    },
+    CommitMessageSuggestion {
+        id: String,
+        message: String,
+    },
+    CommitCreated {
+        id: String,
+        commit: gwt_git::commit::CommitEntry,
+    },
+    CommitError {
+        id: String,
+        message: String,
+    },
     BranchEntries {
         id: String,
         phase: BranchEntriesPhase,
@@ -1741,6 +2067,77 @@ pub enum BackendEvent {
         id: String,
         results: Vec<BranchCleanupResultEntry>,
     },
+    WorktreeStashResult {
+        id: String,
+        message: String,
+    },
+    WorktreeRestoreResult {
+        id: String,
+        message: String,
+    },
+    WorktreeRepairResult {
+        id: String,
+        message: String,
+    },
+    WorktreeTransplantResult {
+        id: String,
+        message: String,
+    },
+    CiArtifactsDownloadResult {
+        id: String,
+        message: String,
+    },
+    /// Reply to the Branches list rebase actions. `outcome` is `None` after
+    /// an abort.
+    BranchRebaseResult {
+        id: String,
+        branch: String,
+        outcome: Option<gwt_git::rebase::RebaseOutcome>,
+        message: String,
+    },
+    /// Reply to `LoadBranchCiStatus`; branches without workflow runs are
+    /// absent.
+    BranchCiStatuses {
+        id: String,
+        statuses: std::collections::HashMap<String, gwt_git::pr_status::BranchCiStatus>,
+    },
+    /// A hook installed by `gwt hooks install` reported a commit or push, or
+    /// a background fetch (`hook` "fetch") moved remote refs, so open
+    /// Branches lists reload their safety and PR state.
+    GitRefsChanged {
+        hook: String,
+        branch: Option<String>,
+    },
+    PullRequestCreateResult {
+        id: String,
+        branch: String,
+        number: u64,
+        url: String,
+        message: String,
+    },
+    ExternalAgents {
+        id: String,
+        agents: Vec<crate::external_agents::ExternalAgent>,
+    },
+    ExternalAgentAdopted {
+        id: String,
+        pid: u32,
+        branch: String,
+        message: String,
+    },
+    StaleRemoteBranches {
+        id: String,
+        min_age_days: u32,
+        entries: Vec<crate::stale_remote_branches::StaleRemoteBranch>,
+    },
+    StaleRemoteBranchesDeleted {
+        id: String,
+        results: Vec<BranchCleanupResultEntry>,
+    },
+    RemovedWorktrees {
+        id: String,
+        entries: Vec<gwt_git::worktree_trash::TrashedWorktree>,
+    },
     BranchCleanupProgress {
         id: String,
         branch: String,
@@ -1779,6 +2176,11 @@ pub enum BackendEvent {
     CloneProjectParentSelected {
         path: String,
     },
+    /// The dropped folder is not a Git workspace; the frontend offers to
+    /// clone a repository into it.
+    ProjectDropNeedsClone {
+        path: String,
+    },
     GithubRepositorySearchResults {
         query: String,
         repositories: Vec<GitHubRepositorySearchResultView>,
@@ -1796,6 +2198,18 @@ pub enum BackendEvent {
     CloneProjectError {
         message: String,
     },
+    /// The duplicate at `path` was dropped from the project lists after its
+    /// missing branches were fetched into `canonical_path`.
+    DuplicateProjectConsolidated {
+        path: String,
+        canonical_path: String,
+        fetched_branches: Vec<String>,
+        skipped_branches: Vec<String>,
+    },
+    DuplicateProjectConsolidateError {
+        path: String,
+        message: String,
+    },
     LaunchWizardOpenError {
         title: String,
         message: String,
@@ -1840,6 +2254,10 @@ pub enum BackendEvent {
     RuntimeHookEvent {
         event: RuntimeHookEvent,
     },
+    /// One entry from `gwt_core::activity_bus`, relayed to every client.
+    ActivityEvent {
+        event: gwt_core::activity_bus::ActivityEnvelope,
+    },
     UpdateState(gwt_core::update::UpdateState),
     /// SPEC-2041 Phase 19 (FR-054): download progress for the current update.
     /// Emitted from `Backend` while a download is active; the `#update-modal`
@@ -1942,6 +2360,13 @@ pub enum BackendEvent {
         has_locked: bool,
         has_submodules: bool,
     },
+    /// Dry run requested by [`FrontendEvent::MigrationDryRun`]: what the
+    /// migration of `tab_id` would move, the disk space involved, and the
+    /// pre-flight problems that would stop it.
+    MigrationReport {
+        tab_id: String,
+        report: gwt_core::migration::MigrationReport,
+    },
     /// SPEC-1934 FR-029: incremental progress while
     /// `gwt::migration::execute_migration` runs. `phase` is the snake_case key
     /// from `MigrationPhase::as_str()`.
@@ -2040,6 +2465,15 @@ pub enum BackendEvent {
         #[serde(skip_serializing_if = "Option::is_none")]
         board_provider: Option<String>,
     },
+    /// [`FrontendEvent::ApplyAiPreset`] wrote the `[ai]` settings below.
+    /// `models` lists what the server offers so the user can see which
+    /// model was picked.
+    AiPresetApplied {
+        provider: String,
+        endpoint: String,
+        model: String,
+        models: Vec<String>,
+    },
     /// SPEC-1933 US-4: error reply for [`FrontendEvent::GetSystemSettings`]
     /// or [`FrontendEvent::UpdateSystemSettings`]. `message` is
     /// human-readable; the frontend surfaces it as an inline status row in
@@ -2047,6 +2481,49 @@ pub enum BackendEvent {
     SystemSettingsError {
         message: String,
     },
+    /// Effective global shortcuts from the `[keybindings]` config table,
+    /// sent after [`FrontendEvent::FrontendReady`]. `errors` lists the
+    /// entries that were ignored in favour of the defaults.
+    Keybindings {
+        bindings: Vec<gwt_config::ResolvedKeybinding>,
+        errors: Vec<String>,
+    },
+    /// User palettes from the `[theme.palettes]` config table, sent after
+    /// [`FrontendEvent::FrontendReady`] so Settings can offer them next to
+    /// the built-in palettes. `errors` lists the entries that were dropped.
+    ThemePalettes {
+        palettes: Vec<gwt_config::ResolvedThemePalette>,
+        errors: Vec<String>,
+    },
+    /// Status strip widgets from the `[[footer.widgets]]` config table, sent
+    /// after [`FrontendEvent::FrontendReady`]. `errors` lists the entries
+    /// that were dropped or adjusted.
+    FooterWidgets {
+        widgets: Vec<gwt_config::FooterWidget>,
+        errors: Vec<String>,
+    },
+    /// Problems in `~/.gwt/config.toml` found by strict validation, sent
+    /// with the System Settings snapshot. Empty when the file is valid or
+    /// missing.
+    ConfigDiagnostics {
+        path: String,
+        diagnostics: Vec<gwt_config::ConfigDiagnostic>,
+    },
+    /// Refresh budget from the `[refresh]` config table, sent after
+    /// [`FrontendEvent::FrontendReady`]. The GUI's periodic refreshes slow
+    /// down by `idle_backoff` once no input arrived for `idle_after_secs`.
+    RefreshBudget {
+        idle_after_secs: u64,
+        idle_backoff: u32,
+        pause_when_hidden: bool,
+    },
+    /// Response to [`FrontendEvent::RefreshFooterWidget`]. Exactly one of
+    /// `value` and `error` is set.
+    FooterWidgetValue {
+        index: usize,
+        value: Option<String>,
+        error: Option<String>,
+    },
     /// SPEC #2920 Phase 11: response to
     /// [`FrontendEvent::GetAutostartStatus`] or
     /// [`FrontendEvent::UpdateAutostart`]. Carries the authoritative
@@ -2109,6 +2586,12 @@ pub enum BackendEvent {
         id: String,
         message: String,
     },
+    /// Sent once per connection when the embedded server runs with
+    /// `--read-only` / `[server] read_only`; the UI shows a banner and the
+    /// server drops mutating frontend events.
+    ServerMode {
+        read_only: bool,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -2196,6 +2679,11 @@ pub const BACKEND_EVENT_POLICIES: &[BackendEventPolicy] = &[
         BackendEventDeliveryClass::IdempotentLatest,
         BackendEventBackpressurePolicy::LatestWins,
     ),
+    BackendEventPolicy::new(
+        "ai_queue_status",
+        BackendEventDeliveryClass::IdempotentLatest,
+        BackendEventBackpressurePolicy::LatestWins,
+    ),
     BackendEventPolicy::new(
         "terminal_output",
         BackendEventDeliveryClass::Streamed,
@@ -2291,6 +2779,36 @@ pub const BACKEND_EVENT_POLICIES: &[BackendEventPolicy] = &[
         BackendEventDeliveryClass::Error,
         BackendEventBackpressurePolicy::FailOpenError,
     ),
+    BackendEventPolicy::new(
+        "git_changes",
+        BackendEventDeliveryClass::Snapshot,
+        BackendEventBackpressurePolicy::ClientScopedSnapshot,
+    ),
+    BackendEventPolicy::new(
+        "file_diff",
+        BackendEventDeliveryClass::Snapshot,
+        BackendEventBackpressurePolicy::ClientScopedSnapshot,
+    ),
+    BackendEventPolicy::new(
+        "file_diff_error",
+        BackendEventDeliveryClass::Error,
+        BackendEventBackpressurePolicy::FailOpenError,
+    ),
+    BackendEventPolicy::new(
+        "commit_message_suggestion",
+        BackendEventDeliveryClass::Snapshot,
+        BackendEventBackpressurePolicy::ClientScopedSnapshot,
+    ),
+    BackendEventPolicy::new(
+        "commit_created",
+        BackendEventDeliveryClass::Snapshot,
+        BackendEventBackpressurePolicy::ClientScopedSnapshot,
+    ),
+    BackendEventPolicy::new(
+        "commit_error",
+        BackendEventDeliveryClass::Error,
+        BackendEventBackpressurePolicy::FailOpenError,
+    ),
     BackendEventPolicy::new(
         "branch_entries",
         BackendEventDeliveryClass::Snapshot,
@@ -2356,6 +2874,76 @@ pub const BACKEND_EVENT_POLICIES: &[BackendEventPolicy] = &[
         BackendEventDeliveryClass::EphemeralStatus,
         BackendEventBackpressurePolicy::BestEffort,
     ),
+    BackendEventPolicy::new(
+        "worktree_stash_result",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
+    BackendEventPolicy::new(
+        "worktree_restore_result",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
+    BackendEventPolicy::new(
+        "worktree_repair_result",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
+    BackendEventPolicy::new(
+        "worktree_transplant_result",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
+    BackendEventPolicy::new(
+        "ci_artifacts_download_result",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
+    BackendEventPolicy::new(
+        "branch_rebase_result",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
+    BackendEventPolicy::new(
+        "branch_ci_statuses",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
+    BackendEventPolicy::new(
+        "git_refs_changed",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
+    BackendEventPolicy::new(
+        "pull_request_create_result",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
+    BackendEventPolicy::new(
+        "external_agents",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
+    BackendEventPolicy::new(
+        "external_agent_adopted",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
+    BackendEventPolicy::new(
+        "stale_remote_branches",
+        BackendEventDeliveryClass::Snapshot,
+        BackendEventBackpressurePolicy::ClientScopedSnapshot,
+    ),
+    BackendEventPolicy::new(
+        "stale_remote_branches_deleted",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
+    BackendEventPolicy::new(
+        "removed_worktrees",
+        BackendEventDeliveryClass::Snapshot,
+        BackendEventBackpressurePolicy::ClientScopedSnapshot,
+    ),
     BackendEventPolicy::new(
         "branch_cleanup_progress",
         BackendEventDeliveryClass::Streamed,
@@ -2421,6 +3009,16 @@ pub const BACKEND_EVENT_POLICIES: &[BackendEventPolicy] = &[
         BackendEventDeliveryClass::Error,
         BackendEventBackpressurePolicy::FailOpenError,
     ),
+    BackendEventPolicy::new(
+        "duplicate_project_consolidated",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
+    BackendEventPolicy::new(
+        "duplicate_project_consolidate_error",
+        BackendEventDeliveryClass::Error,
+        BackendEventBackpressurePolicy::FailOpenError,
+    ),
     BackendEventPolicy::new(
         "launch_wizard_open_error",
         BackendEventDeliveryClass::Error,
@@ -2463,6 +3061,11 @@ pub const BACKEND_EVENT_POLICIES: &[BackendEventPolicy] = &[
         BackendEventDeliveryClass::BestEffortDaemon,
         BackendEventBackpressurePolicy::BestEffort,
     ),
+    BackendEventPolicy::new(
+        "activity_event",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
     BackendEventPolicy::new(
         "update_state",
         BackendEventDeliveryClass::IdempotentLatest,
@@ -2543,6 +3146,11 @@ pub const BACKEND_EVENT_POLICIES: &[BackendEventPolicy] = &[
         BackendEventDeliveryClass::EphemeralStatus,
         BackendEventBackpressurePolicy::BestEffort,
     ),
+    BackendEventPolicy::new(
+        "migration_report",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
     BackendEventPolicy::new(
         "migration_progress",
         BackendEventDeliveryClass::Streamed,
@@ -2568,11 +3176,46 @@ pub const BACKEND_EVENT_POLICIES: &[BackendEventPolicy] = &[
         BackendEventDeliveryClass::EphemeralStatus,
         BackendEventBackpressurePolicy::BestEffort,
     ),
+    BackendEventPolicy::new(
+        "ai_preset_applied",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
     BackendEventPolicy::new(
         "system_settings_error",
         BackendEventDeliveryClass::Error,
         BackendEventBackpressurePolicy::FailOpenError,
     ),
+    BackendEventPolicy::new(
+        "keybindings",
+        BackendEventDeliveryClass::Snapshot,
+        BackendEventBackpressurePolicy::ClientScopedSnapshot,
+    ),
+    BackendEventPolicy::new(
+        "theme_palettes",
+        BackendEventDeliveryClass::Snapshot,
+        BackendEventBackpressurePolicy::ClientScopedSnapshot,
+    ),
+    BackendEventPolicy::new(
+        "footer_widgets",
+        BackendEventDeliveryClass::Snapshot,
+        BackendEventBackpressurePolicy::ClientScopedSnapshot,
+    ),
+    BackendEventPolicy::new(
+        "config_diagnostics",
+        BackendEventDeliveryClass::Snapshot,
+        BackendEventBackpressurePolicy::ClientScopedSnapshot,
+    ),
+    BackendEventPolicy::new(
+        "refresh_budget",
+        BackendEventDeliveryClass::Snapshot,
+        BackendEventBackpressurePolicy::ClientScopedSnapshot,
+    ),
+    BackendEventPolicy::new(
+        "footer_widget_value",
+        BackendEventDeliveryClass::EphemeralStatus,
+        BackendEventBackpressurePolicy::BestEffort,
+    ),
     BackendEventPolicy::new(
         "autostart_status",
         BackendEventDeliveryClass::Snapshot,
@@ -2613,6 +3256,11 @@ pub const BACKEND_EVENT_POLICIES: &[BackendEventPolicy] = &[
         BackendEventDeliveryClass::Error,
         BackendEventBackpressurePolicy::FailOpenError,
     ),
+    BackendEventPolicy::new(
+        "server_mode",
+        BackendEventDeliveryClass::IdempotentLatest,
+        BackendEventBackpressurePolicy::LatestWins,
+    ),
 ];
 
 pub fn backend_event_policy(kind: &str) -> Option<BackendEventPolicy> {
@@ -2633,6 +3281,7 @@ impl BackendEvent {
             BackendEvent::ImprovementActionError { .. } => "improvement_action_error",
             BackendEvent::ProviderUsage { .. } => "provider_usage",
             BackendEvent::RuntimeHealth { .. } => "runtime_health",
+            BackendEvent::AiQueueStatus { .. } => "ai_queue_status",
             BackendEvent::TerminalOutput { .. } => "terminal_output",
             BackendEvent::TerminalSnapshot { .. } => "terminal_snapshot",
             BackendEvent::TerminalStatus { .. } => "terminal_status",
@@ -2653,6 +3302,12 @@ impl BackendEvent {
             BackendEvent::FileContentError { .. } => "file_content_error",
             BackendEvent::FileContentSaved { .. } => "file_content_saved",
             BackendEvent::FileContentSaveError { .. } => "file_content_save_error",
+            BackendEvent::GitChanges { .. } => "git_changes",
+            BackendEvent::FileDiff { .. } => "file_diff",
+            BackendEvent::FileDiffError { .. } => "file_diff_error",
+            BackendEvent::CommitMessageSuggestion { .. } => "commit_message_suggestion",
+            BackendEvent::CommitCreated { .. } => "commit_created",
+            BackendEvent::CommitError { .. } => "commit_error",
             BackendEvent::BranchEntries { .. } => "branch_entries",
             BackendEvent::RemoteStartWorkBranches { .. } => "remote_start_work_branches",
             BackendEvent::BoardEntries { .. } => "board_entries",
@@ -2670,6 +3325,20 @@ impl BackendEvent {
             BackendEvent::KnowledgeDetail { .. } => "knowledge_detail",
             BackendEvent::KnowledgeBridgePhaseUpdated { .. } => "knowledge_bridge_phase_updated",
             BackendEvent::BranchCleanupResult { .. } => "branch_cleanup_result",
+            BackendEvent::WorktreeStashResult { .. } => "worktree_stash_result",
+            BackendEvent::WorktreeRestoreResult { .. } => "worktree_restore_result",
+            BackendEvent::WorktreeRepairResult { .. } => "worktree_repair_result",
+            BackendEvent::WorktreeTransplantResult { .. } => "worktree_transplant_result",
+            BackendEvent::CiArtifactsDownloadResult { .. } => "ci_artifacts_download_result",
+            BackendEvent::BranchRebaseResult { .. } => "branch_rebase_result",
+            BackendEvent::BranchCiStatuses { .. } => "branch_ci_statuses",
+            BackendEvent::GitRefsChanged { .. } => "git_refs_changed",
+            BackendEvent::PullRequestCreateResult { .. } => "pull_request_create_result",
+            BackendEvent::ExternalAgents { .. } => "external_agents",
+            BackendEvent::ExternalAgentAdopted { .. } => "external_agent_adopted",
+            BackendEvent::StaleRemoteBranches { .. } => "stale_remote_branches",
+            BackendEvent::StaleRemoteBranchesDeleted { .. } => "stale_remote_branches_deleted",
+            BackendEvent::RemovedWorktrees { .. } => "removed_worktrees",
             BackendEvent::BranchCleanupProgress { .. } => "branch_cleanup_progress",
             BackendEvent::BranchError { .. } => "branch_error",
             BackendEvent::BoardError { .. } => "board_error",
@@ -2678,6 +3347,7 @@ impl BackendEvent {
             BackendEvent::KnowledgeError { .. } => "knowledge_error",
             BackendEvent::ProjectOpenError { .. } => "project_open_error",
             BackendEvent::CloneProjectParentSelected { .. } => "clone_project_parent_selected",
+            BackendEvent::ProjectDropNeedsClone { .. } => "project_drop_needs_clone",
             BackendEvent::GithubRepositorySearchResults { .. } => {
                 "github_repository_search_results"
             }
@@ -2685,6 +3355,10 @@ impl BackendEvent {
             BackendEvent::CloneProjectProgress { .. } => "clone_project_progress",
             BackendEvent::CloneProjectDone { .. } => "clone_project_done",
             BackendEvent::CloneProjectError { .. } => "clone_project_error",
+            BackendEvent::DuplicateProjectConsolidated { .. } => "duplicate_project_consolidated",
+            BackendEvent::DuplicateProjectConsolidateError { .. } => {
+                "duplicate_project_consolidate_error"
+            }
             BackendEvent::LaunchWizardOpenError { .. } => "launch_wizard_open_error",
             BackendEvent::LaunchWizardState { .. } => "launch_wizard_state",
             BackendEvent::WorkspaceResumableAgents { .. } => "workspace_resumable_agents",
@@ -2693,6 +3367,7 @@ impl BackendEvent {
             BackendEvent::LaunchProgress { .. } => "launch_progress",
             BackendEvent::ProjectIndexStatus { .. } => "project_index_status",
             BackendEvent::RuntimeHookEvent { .. } => "runtime_hook_event",
+            BackendEvent::ActivityEvent { .. } => "activity_event",
             BackendEvent::UpdateState(_) => "update_state",
             BackendEvent::UpdateProgress { .. } => "update_progress",
             BackendEvent::UpdateReady { .. } => "update_ready",
@@ -2709,6 +3384,7 @@ impl BackendEvent {
             BackendEvent::AgentBackendDeleted { .. } => "agent_backend_deleted",
             BackendEvent::AgentBackendError { .. } => "agent_backend_error",
             BackendEvent::MigrationDetected { .. } => "migration_detected",
+            BackendEvent::MigrationReport { .. } => "migration_report",
             BackendEvent::MigrationProgress { .. } => "migration_progress",
             BackendEvent::MigrationDone { .. } => "migration_done",
             BackendEvent::MigrationError { .. } => "migration_error",
@@ -2716,7 +3392,14 @@ impl BackendEvent {
             BackendEvent::BoardAuthStatus { .. } => "board_auth_status",
             BackendEvent::ProjectBoardConfig { .. } => "project_board_config",
             BackendEvent::SystemSettingsUpdated { .. } => "system_settings_updated",
+            BackendEvent::AiPresetApplied { .. } => "ai_preset_applied",
             BackendEvent::SystemSettingsError { .. } => "system_settings_error",
+            BackendEvent::Keybindings { .. } => "keybindings",
+            BackendEvent::ThemePalettes { .. } => "theme_palettes",
+            BackendEvent::FooterWidgets { .. } => "footer_widgets",
+            BackendEvent::ConfigDiagnostics { .. } => "config_diagnostics",
+            BackendEvent::RefreshBudget { .. } => "refresh_budget",
+            BackendEvent::FooterWidgetValue { .. } => "footer_widget_value",
             BackendEvent::AutostartStatus { .. } => "autostart_status",
             BackendEvent::AutostartError { .. } => "autostart_error",
             BackendEvent::WorkspaceProjectionPruneResult { .. } => {
@@ -2729,6 +3412,7 @@ impl BackendEvent {
             BackendEvent::UiTraceError { .. } => "ui_trace_error",
             BackendEvent::ReleaseNotesPayload { .. } => "release_notes_payload",
             BackendEvent::ReleaseNotesError { .. } => "release_notes_error",
+            BackendEvent::ServerMode { .. } => "server_mode",
         }
     }
 
@@ -2834,6 +3518,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn read_only_allowlist_admits_views_and_rejects_mutations() {
+        let view = serde_json::from_value::<FrontendEvent>(serde_json::json!({
+            "kind": "load_branches",
+            "id": "w-1"
+        }))
+        .expect("deserialize load_branches");
+        assert!(view.allowed_in_read_only());
+        assert!(FrontendEvent::FrontendReady.allowed_in_read_only());
+
+        let input = FrontendEvent::TerminalInput {
+            id: "w-1".to_string(),
+            data: "ls\n".to_string(),
+        };
+        assert!(!input.allowed_in_read_only());
+        let send = FrontendEvent::PaneSendInput {
+            session_id: "session-1".to_string(),
+            text: "hi".to_string(),
+        };
+        assert!(!send.allowed_in_read_only());
+    }
+
+    #[test]
+    fn server_mode_serializes_kind_and_has_delivery_policy() {
+        let event = BackendEvent::ServerMode { read_only: true };
+        let value = serde_json::to_value(&event).expect("serialize server_mode");
+        assert_eq!(value["kind"], "server_mode");
+        assert_eq!(value["read_only"], true);
+        assert_eq!(event.event_kind(), "server_mode");
+        assert!(backend_event_policy("server_mode").is_some());
+    }
+
     #[test]
     fn update_window_geometry_deserializes_base_geometry_revision_contract() {
         let legacy = serde_json::from_value::<FrontendEvent>(serde_json::json!({
@@ -2930,6 +3646,29 @@ mod tests {
         assert_eq!(value.get("load_id"), Some(&Value::from(7u64)));
     }
 
+    #[test]
+    fn hydrate_branches_request_and_lazy_phases_round_trip() {
+        let request = serde_json::from_value::<FrontendEvent>(serde_json::json!({
+            "kind": "hydrate_branches",
+            "id": "branches-1",
+            "branches": ["feature/a", "origin/feature/a"],
+            "load_id": 9
+        }))
+        .expect("deserialize hydrate_branches");
+        assert!(matches!(
+            &request,
+            FrontendEvent::HydrateBranches { branches, load_id: 9, .. } if branches.len() == 2
+        ));
+        assert!(request.allowed_in_read_only());
+
+        for (phase, wire) in [
+            (BranchEntriesPhase::Lazy, "lazy"),
+            (BranchEntriesPhase::Partial, "partial"),
+        ] {
+            assert_eq!(serde_json::to_value(phase).unwrap(), Value::from(wire));
+        }
+    }
+
     #[test]
     fn branch_entries_serializes_actual_merge_target_reference_contract() {
         let event = BackendEvent::BranchEntries {
@@ -2960,6 +3699,10 @@ mod tests {
                     reason: None,
                 },
                 start_work_eligibility: None,
+                worktree_path: None,
+                archived: false,
+                integrity_issues: Vec::new(),
+                linked_spec: None,
             }],
             load_id: 0,
         };
@@ -3027,6 +3770,10 @@ mod tests {
                     reason: None,
                 },
                 start_work_eligibility: None,
+                worktree_path: None,
+                archived: false,
+                integrity_issues: Vec::new(),
+                linked_spec: None,
             }],
             load_id: 0,
         };
@@ -4212,8 +4959,10 @@ mod tests {
                         value: "xterm-256color".to_string(),
                     }],
                     disabled_env: vec!["SECRET".to_string()],
+                    secret_env: Vec::new(),
                     is_default: true,
                     is_active: true,
+                    origin: gwt_config::ProfileOrigin::UserOverridesRepo,
                 }],
                 os_env: vec![ProfileEnvEntryView {
                     key: "PATH".to_string(),
@@ -4223,6 +4972,7 @@ mod tests {
                     key: "TERM".to_string(),
                     value: "xterm-256color".to_string(),
                 }],
+                repo_errors: Vec::new(),
             },
         };
 
@@ -4243,6 +4993,10 @@ mod tests {
             value["snapshot"]["os_env"][0]["value"],
             Value::String("/usr/bin".to_string())
         );
+        assert_eq!(
+            value["snapshot"]["profiles"][0]["origin"],
+            Value::String("user_overrides_repo".to_string())
+        );
     }
 
     #[test]
@@ -0,0 +1,153 @@
+//! Branches list "rebase onto base" action.
+//!
+//! `b` on a branch with a worktree fetches the base from `origin` and
+//! rebases the branch onto it with [`gwt_git::branch::Branch::rebase_onto`].
+//! A conflicted rebase stays in progress; the Branches window lists the
+//! conflicted files and offers to continue, abort, or open the Launch
+//! Wizard for the branch with [`conflict_resolution_prompt`] prefilled so
+//! the selected agent resolves them.
+
+use std::path::{Path, PathBuf};
+
+use gwt_git::{
+    rebase::{
+        abort_rebase, conflicted_files, continue_rebase, rebasing_branch, ConflictFile,
+        RebaseOutcome,
+    },
+    DEFAULT_UPSTREAM_REMOTE,
+};
+
+/// Rebase `branch` onto `onto`, or onto the updated default base branch
+/// when `onto` is `None`.
+pub fn rebase_project_branch(
+    project_root: &Path,
+    branch: &str,
+    onto: Option<&str>,
+) -> Result<RebaseOutcome, String> {
+    if gwt_git::is_protected_branch_in(project_root, branch) {
+        return Err(format!(
+            "{branch} is a protected branch and cannot be rebased"
+        ));
+    }
+    let worktree = branch_worktree(project_root, branch)?;
+    let onto = match onto.map(str::trim).filter(|onto| !onto.is_empty()) {
+        Some(onto) => onto.to_string(),
+        None => updated_base_ref(&worktree),
+    };
+    let target = gwt_git::branch::list_branches(&worktree)
+        .map_err(|error| error.to_string())?
+        .into_iter()
+        .find(|candidate| candidate.is_local && candidate.name == branch)
+        .ok_or_else(|| format!("{branch} is not a local branch"))?;
+    target
+        .rebase_onto(&worktree, &onto)
+        .map_err(|error| error.to_string())
+}
+
+/// Continue the stopped rebase of `branch`.
+pub fn continue_project_rebase(project_root: &Path, branch: &str) -> Result<RebaseOutcome, String> {
+    let worktree = branch_worktree(project_root, branch)?;
+    continue_rebase(&worktree).map_err(|error| error.to_string())
+}
+
+/// Abort the stopped rebase of `branch`.
+pub fn abort_project_rebase(project_root: &Path, branch: &str) -> Result<(), String> {
+    let worktree = branch_worktree(project_root, branch)?;
+    abort_rebase(&worktree).map_err(|error| error.to_string())
+}
+
+/// Prompt for an agent started in the worktree of `branch` while its rebase
+/// is stopped on conflicts. The conflicts are read fresh so files resolved
+/// by hand in the meantime are left out.
+pub fn project_conflict_resolution_prompt(
+    project_root: &Path,
+    branch: &str,
+) -> Result<String, String> {
+    let worktree = branch_worktree(project_root, branch)?;
+    let conflicts = conflicted_files(&worktree).map_err(|error| error.to_string())?;
+    if conflicts.is_empty() {
+        return Err(format!("{branch} has no unresolved conflicts"));
+    }
+    Ok(conflict_resolution_prompt(branch, &conflicts))
+}
+
+/// Instructions for resolving the conflicts of a stopped rebase.
+pub fn conflict_resolution_prompt(branch: &str, conflicts: &[ConflictFile]) -> String {
+    let mut prompt = format!(
+        "A `git rebase` of branch `{branch}` stopped on merge conflicts in this worktree.\n\n\
+         Conflicted files:\n"
+    );
+    for conflict in conflicts {
+        prompt.push_str(&format!(
+            "- {} ({})\n",
+            conflict.path,
+            conflict.kind.label()
+        ));
+    }
+    prompt.push_str(
+        "\nResolve each conflict so that both sides' intent is kept, remove the conflict \
+         markers, run the relevant tests, and `git add` the resolved files. Then run \
+         `git -c core.editor=true rebase --continue`, and repeat if the next commit \
+         conflicts too. Do not run `git rebase --abort` or `git rebase --skip`; if a \
+         conflict cannot be resolved safely, stop and explain why.",
+    );
+    prompt
+}
+
+/// `origin/<default base>` after fetching it, or the local base when the
+/// remote does not have it.
+fn updated_base_ref(worktree: &Path) -> String {
+    let base = crate::issue_monitor_worker::resolve_default_base_branch(worktree);
+    let remote_ref = format!("{DEFAULT_UPSTREAM_REMOTE}/{base}");
+    match gwt_core::process::run_git_logged(
+        &["fetch", DEFAULT_UPSTREAM_REMOTE, &base],
+        Some(worktree),
+    ) {
+        Ok(output) if output.status.success() => {}
+        _ => tracing::debug!(base = %base, "fetching the rebase base failed; using local refs"),
+    }
+    let qualified = format!("refs/remotes/{remote_ref}");
+    let remote_exists = gwt_git::list_existing_refs(worktree, &[qualified.as_str()])
+        .map(|refs| refs.contains(&qualified))
+        .unwrap_or(false);
+    if remote_exists {
+        remote_ref
+    } else {
+        base
+    }
+}
+
+/// Worktree that has `branch` checked out, including one whose HEAD is
+/// detached by a stopped rebase of `branch`.
+fn branch_worktree(project_root: &Path, branch: &str) -> Result<PathBuf, String> {
+    gwt_git::WorktreeManager::new(project_root)
+        .list()
+        .map_err(|error| error.to_string())?
+        .into_iter()
+        .find(|worktree| match worktree.branch.as_deref() {
+            Some(name) => name == branch,
+            None => rebasing_branch(&worktree.path).as_deref() == Some(branch),
+        })
+        .map(|worktree| worktree.path)
+        .ok_or_else(|| format!("{branch} has no worktree"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gwt_git::rebase::ConflictKind;
+
+    #[test]
+    fn prompt_lists_conflicts_and_forbids_abort() {
+        let prompt = conflict_resolution_prompt(
+            "feature/x",
+            &[ConflictFile {
+                path: "src/lib.rs".to_string(),
+                kind: ConflictKind::BothModified,
+            }],
+        );
+        assert!(prompt.contains("branch `feature/x`"));
+        assert!(prompt.contains("- src/lib.rs (both modified)\n"));
+        assert!(prompt.contains("Do not run `git rebase --abort`"));
+    }
+}
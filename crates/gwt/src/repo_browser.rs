@@ -1,16 +1,21 @@
 use std::{
-    collections::HashSet,
+    collections::{HashMap, HashSet},
     path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
     thread,
 };
 
 use crate::{AppEventProxy, OutboundEvent, UserEvent};
 use gwt::{
-    hydrate_branch_entries_with_active_sessions, list_branch_entries_with_active_sessions,
-    list_branch_inventory, BackendEvent, BranchEntriesPhase, BranchListEntry, BranchResumeInfo,
-    BranchScope,
+    hydrate_branch_entries_with_active_sessions, hydrate_branch_subset_with_active_sessions,
+    list_branch_entries_with_active_sessions, list_branch_inventory, wants_lazy_hydration,
+    BackendEvent, BranchEntriesPhase, BranchListEntry, BranchResumeInfo, BranchScope,
 };
 
+/// Rows hydrated per `Partial` event, so the first visible rows get their
+/// badges before the whole viewport is checked.
+const BRANCH_HYDRATION_CHUNK: usize = 16;
+
 pub fn spawn_branch_load_async(
     proxy: AppEventProxy,
     window_id: String,
@@ -32,6 +37,13 @@ pub fn spawn_branch_load_async(
         proxy.send(UserEvent::RefreshLaunchWizardSessions(
             resume_sessions.clone(),
         ));
+        // Agents adopted from outside gwt have no PTY in this process; their
+        // branches count as active while the adopted process lives.
+        let mut active_session_branches = active_session_branches;
+        active_session_branches.extend(gwt::external_agents::live_adopted_branches(
+            &project_root,
+            &sessions_dir,
+        ));
         dispatch_branch_load_progressive(
             &proxy,
             &window_id,
@@ -39,6 +51,91 @@ pub fn spawn_branch_load_async(
             &active_session_branches,
             &resume_sessions,
         );
+        // Picked up by the next load; `gh` must not hold up this one.
+        gwt::branch_protection::sync_protected_branches_if_stale(&project_root);
+    });
+}
+
+/// Newest `HydrateBranches` request per Branches window. A running
+/// hydration stops between branches once a newer request for its window
+/// arrives (the user scrolled on), so off-screen rows do not hold up the
+/// rows now in view.
+fn hydration_generations() -> &'static Mutex<HashMap<String, u64>> {
+    static GENERATIONS: OnceLock<Mutex<HashMap<String, u64>>> = OnceLock::new();
+    GENERATIONS.get_or_init(Default::default)
+}
+
+fn next_hydration_generation(window_id: &str) -> u64 {
+    let mut generations = hydration_generations()
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    let generation = generations.entry(window_id.to_string()).or_insert(0);
+    *generation += 1;
+    *generation
+}
+
+fn hydration_superseded(window_id: &str, generation: u64) -> bool {
+    hydration_generations()
+        .lock()
+        .map(|generations| generations.get(window_id).copied() != Some(generation))
+        .unwrap_or(false)
+}
+
+/// Hydrate the `branches` rows of a lazy Branches load off the UI thread,
+/// in chunks, each sent as a `Partial` branch_entries event. Supersedes any
+/// hydration still running for the same window.
+pub fn spawn_branch_hydration_async(
+    proxy: AppEventProxy,
+    window_id: String,
+    project_root: PathBuf,
+    branches: Vec<String>,
+    active_session_branches: HashSet<String>,
+    sessions_dir: PathBuf,
+    load_id: u64,
+) {
+    let generation = next_hydration_generation(&window_id);
+    thread::spawn(move || {
+        // Same active set as the full load: adopted external agents count.
+        let mut active_session_branches = active_session_branches;
+        active_session_branches.extend(gwt::external_agents::live_adopted_branches(
+            &project_root,
+            &sessions_dir,
+        ));
+        let is_cancelled = || hydration_superseded(&window_id, generation);
+        for chunk in branches.chunks(BRANCH_HYDRATION_CHUNK) {
+            let names: HashSet<String> = chunk.iter().cloned().collect();
+            let entries = match hydrate_branch_subset_with_active_sessions(
+                &project_root,
+                &names,
+                &active_session_branches,
+                &is_cancelled,
+            ) {
+                Ok(Some(entries)) => entries,
+                Ok(None) => return,
+                Err(error) => {
+                    dispatch_async_events(
+                        &proxy,
+                        vec![OutboundEvent::broadcast(BackendEvent::BranchError {
+                            id: window_id.clone(),
+                            message: error.to_string(),
+                        })],
+                    );
+                    return;
+                }
+            };
+            if is_cancelled() {
+                return;
+            }
+            dispatch_async_events(
+                &proxy,
+                vec![OutboundEvent::broadcast(BackendEvent::BranchEntries {
+                    id: window_id.clone(),
+                    phase: BranchEntriesPhase::Partial,
+                    entries,
+                    load_id,
+                })],
+            );
+        }
     });
 }
 
@@ -117,6 +214,21 @@ fn dispatch_branch_load_progressive(
     match list_branch_inventory(project_root) {
         Ok(mut entries) => {
             apply_branch_resume_availability(project_root, &mut entries, resume_sessions);
+            // Hydrating every row of a long list would keep the window on
+            // "Checking branch details" for minutes; the window hydrates
+            // the rows it shows instead.
+            if wants_lazy_hydration(&entries) {
+                dispatch_async_events(
+                    proxy,
+                    vec![OutboundEvent::broadcast(BackendEvent::BranchEntries {
+                        id: window_id.to_string(),
+                        phase: BranchEntriesPhase::Lazy,
+                        entries,
+                        load_id,
+                    })],
+                );
+                return;
+            }
             dispatch_async_events(
                 proxy,
                 vec![OutboundEvent::broadcast(BackendEvent::BranchEntries {
@@ -210,6 +322,10 @@ mod tests {
             cleanup: BranchCleanupInfo::default(),
             resume: BranchResumeInfo::unavailable(),
             start_work_eligibility: None,
+            worktree_path: None,
+            archived: false,
+            integrity_issues: Vec::new(),
+            linked_spec: None,
         }
     }
 
@@ -226,6 +342,10 @@ mod tests {
             cleanup: BranchCleanupInfo::default(),
             resume: BranchResumeInfo::unavailable(),
             start_work_eligibility: None,
+            worktree_path: None,
+            archived: false,
+            integrity_issues: Vec::new(),
+            linked_spec: None,
         }
     }
 
@@ -0,0 +1,677 @@
+//! Repositories served by one gwt instance.
+//!
+//! The dashboard already switches between project tabs and recent projects;
+//! `repositories = [...]` in `~/.gwt/config.toml` registers additional roots
+//! so every project is reachable from the switcher and from the embedded
+//! server's `/api/repos` and `/api/repos/{id}/worktrees` routes.
+//!
+//! A repo id is the worktree hash of its canonical path, so ids are stable
+//! across restarts and never expose the path in URLs.
+//!
+//! The list routes accept `?filter`, `?sort`, `?page` and `?per_page`
+//! ([`ListQuery`]); without `page`/`per_page` the whole filtered list is
+//! returned, so existing callers keep working.
+//!
+//! The same upstream repository can end up registered twice under different
+//! paths (an old clone next to a new bare layout). [`find_duplicate_projects`]
+//! pairs such entries by their origin remote ([`gwt_core::repo_hash`]) and
+//! [`consolidate_duplicate`] carries the duplicate's branches over so the
+//! duplicate entry can be dropped in favour of one canonical project.
+
+use std::{
+    cmp::Ordering,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::persistence::{ProjectKind, RecentProjectEntry};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RepoSummary {
+    pub id: String,
+    pub title: String,
+    pub path: String,
+    pub kind: ProjectKind,
+    /// Listed under `repositories` in the global config (as opposed to only
+    /// appearing in the recent-projects history).
+    pub registered: bool,
+    /// Id of the earlier entry with the same origin remote, when this entry
+    /// is a second clone of it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duplicate_of: Option<String>,
+}
+
+/// A project whose origin remote matches an earlier, separate clone.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DuplicateProject {
+    pub path: PathBuf,
+    /// The entry kept as the single project for that remote.
+    pub canonical_path: PathBuf,
+    /// Normalized origin, e.g. `github.com/akiojin/gwt`.
+    pub remote: String,
+}
+
+/// What [`consolidate_duplicate`] carried into the canonical clone.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ConsolidationOutcome {
+    /// Local branches of the duplicate that the canonical clone lacked.
+    pub fetched_branches: Vec<String>,
+    /// Branches both clones have; the canonical clone's copy is kept.
+    pub skipped_branches: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RepoWorktreeSummary {
+    pub path: String,
+    pub branch: Option<String>,
+    pub locked: bool,
+    pub prunable: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RepoBranchSummary {
+    pub name: String,
+    pub is_head: bool,
+    pub upstream: Option<String>,
+    pub ahead: u32,
+    pub behind: u32,
+    pub last_commit_date: Option<String>,
+    /// Worktree that has the branch checked out.
+    pub worktree_path: Option<String>,
+}
+
+/// Largest accepted `per_page`.
+pub const MAX_PER_PAGE: usize = 500;
+/// `per_page` used when only `page` is given.
+pub const DEFAULT_PER_PAGE: usize = 50;
+
+/// Query string of the list routes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ListQuery {
+    /// 1-based page number.
+    pub page: Option<usize>,
+    pub per_page: Option<usize>,
+    /// Case-insensitive substring matched against the item's text fields.
+    pub filter: Option<String>,
+    /// Field to sort by; a leading `-` sorts descending.
+    pub sort: Option<String>,
+}
+
+/// One page of a list route. `total` counts the items left after filtering.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListPage<T> {
+    pub items: Vec<T>,
+    pub total: usize,
+    pub page: usize,
+    pub per_page: usize,
+}
+
+/// Items that the list routes can filter and sort.
+pub trait ListItem {
+    /// Fields searched by `?filter`.
+    fn filter_fields(&self) -> Vec<&str>;
+    /// Value of the sortable `field`, or `None` when `field` is unknown.
+    /// Items without a value sort last.
+    fn sort_value(&self, field: &str) -> Option<Option<String>>;
+    /// Fields accepted by `?sort`.
+    fn sort_fields() -> &'static [&'static str];
+}
+
+impl ListItem for RepoSummary {
+    fn filter_fields(&self) -> Vec<&str> {
+        vec![&self.title, &self.path]
+    }
+
+    fn sort_value(&self, field: &str) -> Option<Option<String>> {
+        match field {
+            "title" => Some(Some(self.title.to_lowercase())),
+            "path" => Some(Some(self.path.clone())),
+            _ => None,
+        }
+    }
+
+    fn sort_fields() -> &'static [&'static str] {
+        &["title", "path"]
+    }
+}
+
+impl ListItem for RepoWorktreeSummary {
+    fn filter_fields(&self) -> Vec<&str> {
+        let mut fields = vec![self.path.as_str()];
+        fields.extend(self.branch.as_deref());
+        fields
+    }
+
+    fn sort_value(&self, field: &str) -> Option<Option<String>> {
+        match field {
+            "path" => Some(Some(self.path.clone())),
+            "branch" => Some(self.branch.clone()),
+            _ => None,
+        }
+    }
+
+    fn sort_fields() -> &'static [&'static str] {
+        &["path", "branch"]
+    }
+}
+
+impl ListItem for RepoBranchSummary {
+    fn filter_fields(&self) -> Vec<&str> {
+        let mut fields = vec![self.name.as_str()];
+        fields.extend(self.upstream.as_deref());
+        fields
+    }
+
+    fn sort_value(&self, field: &str) -> Option<Option<String>> {
+        match field {
+            "name" => Some(Some(self.name.clone())),
+            "updated" => Some(self.last_commit_date.clone()),
+            _ => None,
+        }
+    }
+
+    fn sort_fields() -> &'static [&'static str] {
+        &["name", "updated"]
+    }
+}
+
+/// Filter, sort and page `items` according to `query`. Errors name the
+/// offending parameter.
+pub fn paginate<T: ListItem>(mut items: Vec<T>, query: &ListQuery) -> Result<ListPage<T>, String> {
+    if let Some(filter) = query.filter.as_deref().map(str::trim) {
+        let needle = filter.to_lowercase();
+        if !needle.is_empty() {
+            items.retain(|item| {
+                item.filter_fields()
+                    .iter()
+                    .any(|field| field.to_lowercase().contains(&needle))
+            });
+        }
+    }
+    if let Some(sort) = query.sort.as_deref().filter(|sort| !sort.is_empty()) {
+        let (field, descending) = match sort.strip_prefix('-') {
+            Some(field) => (field, true),
+            None => (sort, false),
+        };
+        if !T::sort_fields().contains(&field) {
+            return Err(format!(
+                "sort must be one of {}",
+                T::sort_fields().join(", ")
+            ));
+        }
+        items.sort_by(|a, b| {
+            match (a.sort_value(field).flatten(), b.sort_value(field).flatten()) {
+                (Some(a), Some(b)) if descending => b.cmp(&a),
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        });
+    }
+
+    let total = items.len();
+    if query.page.is_none() && query.per_page.is_none() {
+        return Ok(ListPage {
+            items,
+            total,
+            page: 1,
+            per_page: total,
+        });
+    }
+    let page = query.page.unwrap_or(1);
+    if page == 0 {
+        return Err("page starts at 1".to_string());
+    }
+    let per_page = query.per_page.unwrap_or(DEFAULT_PER_PAGE);
+    if per_page == 0 || per_page > MAX_PER_PAGE {
+        return Err(format!("per_page must be between 1 and {MAX_PER_PAGE}"));
+    }
+    let items = items
+        .into_iter()
+        .skip((page - 1).saturating_mul(per_page))
+        .take(per_page)
+        .collect();
+    Ok(ListPage {
+        items,
+        total,
+        page,
+        per_page,
+    })
+}
+
+/// Stable id for the repository at `path`, or `None` when the path does not
+/// exist.
+pub fn repo_id(path: &Path) -> Option<String> {
+    gwt_core::worktree_hash::compute_worktree_hash(path)
+        .ok()
+        .map(|hash| hash.to_string())
+}
+
+/// Project entries for the configured repository roots, skipping paths that
+/// no longer exist.
+pub fn registered_project_entries(paths: &[PathBuf]) -> Vec<RecentProjectEntry> {
+    paths
+        .iter()
+        .filter(|path| path.is_dir())
+        .map(|path| RecentProjectEntry {
+            path: path.clone(),
+            title: path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string()),
+            kind: match gwt_git::detect_repo_type(path) {
+                gwt_git::RepoType::Normal { .. } => ProjectKind::Git,
+                gwt_git::RepoType::Bare { .. } => ProjectKind::Bare,
+                gwt_git::RepoType::NonRepo => ProjectKind::NonRepo,
+            },
+        })
+        .collect()
+}
+
+/// Registered repositories first, then recent projects, deduplicated by id.
+pub fn collect_repos(registered: &[PathBuf], recent: &[RecentProjectEntry]) -> Vec<RepoSummary> {
+    let registered = registered_project_entries(registered);
+    let mut repos: Vec<RepoSummary> = Vec::new();
+    let candidates = registered
+        .iter()
+        .map(|entry| (entry, true))
+        .chain(recent.iter().map(|entry| (entry, false)));
+    for (entry, is_registered) in candidates {
+        let Some(id) = repo_id(&entry.path) else {
+            continue;
+        };
+        if repos.iter().any(|repo| repo.id == id) {
+            continue;
+        }
+        repos.push(RepoSummary {
+            id,
+            title: entry.title.clone(),
+            path: entry.path.display().to_string(),
+            kind: entry.kind,
+            registered: is_registered,
+            duplicate_of: None,
+        });
+    }
+    let entries: Vec<RecentProjectEntry> = registered.into_iter().chain(recent.to_vec()).collect();
+    for duplicate in find_duplicate_projects(&entries) {
+        let canonical_id = repo_id(&duplicate.canonical_path);
+        let duplicate_id = repo_id(&duplicate.path);
+        if let Some(repo) = repos
+            .iter_mut()
+            .find(|repo| duplicate_id.as_deref() == Some(repo.id.as_str()))
+        {
+            repo.duplicate_of = canonical_id;
+        }
+    }
+    repos
+}
+
+/// Entries of `projects` that are separate clones of the same origin remote
+/// as an earlier entry. Worktrees of one clone share its git directory and
+/// are not duplicates of each other.
+pub fn find_duplicate_projects(projects: &[RecentProjectEntry]) -> Vec<DuplicateProject> {
+    // (repo hash, clone root, path) of the first entry seen for each remote.
+    let mut canonical: Vec<(String, PathBuf, PathBuf)> = Vec::new();
+    let mut duplicates: Vec<DuplicateProject> = Vec::new();
+    for project in projects {
+        let Some(hash) = gwt_core::repo_hash::detect_repo_hash(&project.path) else {
+            continue;
+        };
+        let hash = hash.as_str().to_string();
+        let root = clone_root(&project.path);
+        match canonical.iter().find(|(seen, _, _)| *seen == hash) {
+            None => canonical.push((hash, root, project.path.clone())),
+            Some((_, canonical_root, canonical_path)) => {
+                let known = duplicates.iter().any(|duplicate| {
+                    duplicate.path == project.path || clone_root(&duplicate.path) == root
+                });
+                if root != *canonical_root && !known {
+                    duplicates.push(DuplicateProject {
+                        path: project.path.clone(),
+                        canonical_path: canonical_path.clone(),
+                        remote: origin_remote(&project.path).unwrap_or_default(),
+                    });
+                }
+            }
+        }
+    }
+    duplicates
+}
+
+/// Fetch the local branches of the clone at `duplicate` that the clone at
+/// `canonical` lacks into `canonical`, so nothing is lost when the duplicate
+/// entry is dropped. Refuses while a worktree of the duplicate has
+/// uncommitted changes. Neither clone is deleted.
+pub fn consolidate_duplicate(
+    duplicate: &Path,
+    canonical: &Path,
+) -> Result<ConsolidationOutcome, String> {
+    let duplicate_root =
+        gwt_git::worktree::main_worktree_root(duplicate).map_err(|error| error.to_string())?;
+    let canonical_root =
+        gwt_git::worktree::main_worktree_root(canonical).map_err(|error| error.to_string())?;
+    if clone_root(&duplicate_root) == clone_root(&canonical_root) {
+        return Err(format!(
+            "{} and {} are the same clone",
+            duplicate.display(),
+            canonical.display()
+        ));
+    }
+
+    let dirty: Vec<String> = gwt_git::WorktreeManager::new(&duplicate_root)
+        .list()
+        .map_err(|error| error.to_string())?
+        .into_iter()
+        .filter(|worktree| !worktree.prunable && worktree.path.is_dir())
+        .filter(|worktree| {
+            gwt_git::diff::get_status(&worktree.path)
+                .map(|entries| !entries.is_empty())
+                .unwrap_or(false)
+        })
+        .map(|worktree| worktree.path.display().to_string())
+        .collect();
+    if !dirty.is_empty() {
+        return Err(format!(
+            "commit or stash the uncommitted changes in {} first",
+            dirty.join(", ")
+        ));
+    }
+
+    let local_names = |root: &Path| -> Result<Vec<String>, String> {
+        Ok(gwt_git::branch::list_branches(root)
+            .map_err(|error| error.to_string())?
+            .into_iter()
+            .filter(|branch| branch.is_local)
+            .map(|branch| branch.name)
+            .collect())
+    };
+    let existing = local_names(&canonical_root)?;
+    let (skipped_branches, fetched_branches): (Vec<String>, Vec<String>) =
+        local_names(&duplicate_root)?
+            .into_iter()
+            .partition(|name| existing.contains(name));
+    if !fetched_branches.is_empty() {
+        let source = duplicate_root.to_string_lossy().into_owned();
+        let refspecs: Vec<String> = fetched_branches
+            .iter()
+            .map(|name| format!("refs/heads/{name}:refs/heads/{name}"))
+            .collect();
+        let mut args = vec!["fetch", "--no-tags", source.as_str()];
+        args.extend(refspecs.iter().map(String::as_str));
+        let output = gwt_core::process::run_git_logged(&args, Some(&canonical_root))
+            .map_err(|error| format!("git fetch: {error}"))?;
+        if !output.status.success() {
+            return Err(format!(
+                "git fetch: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+    }
+    Ok(ConsolidationOutcome {
+        fetched_branches,
+        skipped_branches,
+    })
+}
+
+/// Canonicalized main worktree root of the clone containing `path`; falls
+/// back to `path` itself.
+fn clone_root(path: &Path) -> PathBuf {
+    let root = gwt_git::worktree::main_worktree_root(path).unwrap_or_else(|_| path.to_path_buf());
+    dunce::canonicalize(&root).unwrap_or(root)
+}
+
+fn origin_remote(path: &Path) -> Option<String> {
+    let output =
+        gwt_core::process::run_git_logged(&["remote", "get-url", "origin"], Some(path)).ok()?;
+    output.status.success().then(|| {
+        gwt_core::repo_hash::normalize_origin_url(String::from_utf8_lossy(&output.stdout).trim())
+    })
+}
+
+/// [`collect_repos`] over the global config and the persisted session's
+/// recent projects.
+pub fn load_repos() -> Vec<RepoSummary> {
+    let registered = gwt_config::Settings::load()
+        .map(|settings| settings.repositories)
+        .unwrap_or_default();
+    let recent = crate::persistence::load_session_state(&gwt_core::paths::gwt_session_state_path())
+        .map(|state| state.recent_projects)
+        .unwrap_or_default();
+    collect_repos(&registered, &recent)
+}
+
+/// Worktrees of the repository at `path`.
+pub fn repo_worktrees(path: &Path) -> Result<Vec<RepoWorktreeSummary>, String> {
+    let root = gwt_git::worktree::main_worktree_root(path).map_err(|error| error.to_string())?;
+    let worktrees = gwt_git::WorktreeManager::new(&root)
+        .list()
+        .map_err(|error| error.to_string())?;
+    Ok(worktrees
+        .into_iter()
+        .map(|worktree| RepoWorktreeSummary {
+            path: worktree.path.display().to_string(),
+            branch: worktree.branch,
+            locked: worktree.locked,
+            prunable: worktree.prunable,
+        })
+        .collect())
+}
+
+/// Local branches of the repository at `path`, with the worktree each one is
+/// checked out in.
+pub fn repo_branches(path: &Path) -> Result<Vec<RepoBranchSummary>, String> {
+    let root = gwt_git::worktree::main_worktree_root(path).map_err(|error| error.to_string())?;
+    let worktrees = gwt_git::WorktreeManager::new(&root)
+        .list()
+        .map_err(|error| error.to_string())?;
+    let branches = gwt_git::branch::list_branches(&root).map_err(|error| error.to_string())?;
+    Ok(branches
+        .into_iter()
+        .map(|branch| RepoBranchSummary {
+            worktree_path: worktrees
+                .iter()
+                .find(|worktree| worktree.branch.as_deref() == Some(branch.name.as_str()))
+                .map(|worktree| worktree.path.display().to_string()),
+            name: branch.name,
+            is_head: branch.is_head,
+            upstream: branch.upstream,
+            ahead: branch.ahead,
+            behind: branch.behind,
+            last_commit_date: branch.last_commit_date,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let output = gwt_core::process::hidden_command("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {args:?}");
+    }
+
+    #[test]
+    fn collect_repos_lists_registered_first_and_dedupes_recent() {
+        let tmp = tempfile::tempdir().unwrap();
+        let alpha = tmp.path().join("alpha");
+        let beta = tmp.path().join("beta");
+        std::fs::create_dir_all(&alpha).unwrap();
+        std::fs::create_dir_all(&beta).unwrap();
+        let recent = vec![
+            RecentProjectEntry {
+                path: alpha.clone(),
+                title: "Alpha (recent)".to_string(),
+                kind: ProjectKind::NonRepo,
+            },
+            RecentProjectEntry {
+                path: beta.clone(),
+                title: "Beta".to_string(),
+                kind: ProjectKind::NonRepo,
+            },
+        ];
+
+        let repos = collect_repos(&[alpha.clone(), tmp.path().join("missing")], &recent);
+
+        let titles: Vec<&str> = repos.iter().map(|repo| repo.title.as_str()).collect();
+        assert_eq!(titles, ["alpha", "Beta"]);
+        assert!(repos[0].registered);
+        assert!(!repos[1].registered);
+        assert_eq!(Some(repos[1].id.clone()), repo_id(&beta));
+    }
+
+    #[test]
+    fn repo_worktrees_lists_linked_worktrees() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--initial-branch=main"]);
+        git(&repo, &["config", "user.email", "t@example.com"]);
+        git(&repo, &["config", "user.name", "T"]);
+        git(&repo, &["commit", "--allow-empty", "-m", "init"]);
+        let feature = tmp.path().join("feature");
+        git(
+            &repo,
+            &[
+                "worktree",
+                "add",
+                "-b",
+                "feature/x",
+                feature.to_str().unwrap(),
+            ],
+        );
+
+        let worktrees = repo_worktrees(&repo).unwrap();
+        let branches: Vec<Option<&str>> = worktrees
+            .iter()
+            .map(|worktree| worktree.branch.as_deref())
+            .collect();
+        assert_eq!(branches, [Some("main"), Some("feature/x")]);
+        assert!(repo_worktrees(tmp.path()).is_err());
+
+        let branches = repo_branches(&feature).unwrap();
+        let feature_branch = branches
+            .iter()
+            .find(|branch| branch.name == "feature/x")
+            .unwrap();
+        assert_eq!(
+            feature_branch.worktree_path.as_deref().map(Path::new),
+            Some(feature.canonicalize().unwrap().as_path())
+        );
+    }
+
+    fn init_clone(dir: &Path, origin: &str) {
+        std::fs::create_dir_all(dir).unwrap();
+        git(dir, &["init", "--initial-branch=main"]);
+        git(dir, &["config", "user.email", "t@example.com"]);
+        git(dir, &["config", "user.name", "T"]);
+        git(dir, &["remote", "add", "origin", origin]);
+        git(dir, &["commit", "--allow-empty", "-m", "init"]);
+    }
+
+    fn project(path: &Path) -> RecentProjectEntry {
+        RecentProjectEntry {
+            path: path.to_path_buf(),
+            title: path.display().to_string(),
+            kind: ProjectKind::Git,
+        }
+    }
+
+    #[test]
+    fn duplicate_clones_are_found_and_consolidated() {
+        let tmp = tempfile::tempdir().unwrap();
+        let old = tmp.path().join("old");
+        let new = tmp.path().join("new");
+        let other = tmp.path().join("other");
+        init_clone(&new, "git@github.com:acme/app.git");
+        init_clone(&old, "https://github.com/acme/app");
+        init_clone(&other, "https://github.com/acme/other");
+        git(&old, &["branch", "feature/kept"]);
+        let linked = tmp.path().join("new-linked");
+        git(
+            &new,
+            &["worktree", "add", "-b", "wip", linked.to_str().unwrap()],
+        );
+
+        let duplicates = find_duplicate_projects(&[
+            project(&new),
+            project(&linked),
+            project(&other),
+            project(&old),
+        ]);
+        assert_eq!(
+            duplicates,
+            vec![DuplicateProject {
+                path: old.clone(),
+                canonical_path: new.clone(),
+                remote: "github.com/acme/app".to_string(),
+            }]
+        );
+
+        std::fs::write(old.join("notes.txt"), "draft").unwrap();
+        assert!(consolidate_duplicate(&old, &new).is_err());
+        std::fs::remove_file(old.join("notes.txt")).unwrap();
+
+        let outcome = consolidate_duplicate(&old, &new).unwrap();
+        assert_eq!(outcome.fetched_branches, ["feature/kept"]);
+        assert_eq!(outcome.skipped_branches, ["main"]);
+        assert!(gwt_git::branch::list_branches(&new)
+            .unwrap()
+            .iter()
+            .any(|branch| branch.name == "feature/kept"));
+        assert!(consolidate_duplicate(&linked, &new).is_err());
+    }
+
+    fn worktree(path: &str, branch: Option<&str>) -> RepoWorktreeSummary {
+        RepoWorktreeSummary {
+            path: path.to_string(),
+            branch: branch.map(str::to_string),
+            locked: false,
+            prunable: false,
+        }
+    }
+
+    #[test]
+    fn paginate_filters_sorts_and_pages() {
+        let items = vec![
+            worktree("/w/c", Some("feature/c")),
+            worktree("/w/a", Some("feature/a")),
+            worktree("/w/detached", None),
+            worktree("/w/b", Some("fix/b")),
+        ];
+        let query = |page, per_page, filter: &str, sort: &str| ListQuery {
+            page,
+            per_page,
+            filter: Some(filter.to_string()),
+            sort: Some(sort.to_string()),
+        };
+
+        let all = paginate(items.clone(), &ListQuery::default()).unwrap();
+        assert_eq!((all.total, all.items.len()), (4, 4));
+
+        let page = paginate(items.clone(), &query(Some(2), Some(1), "FEATURE", "branch")).unwrap();
+        assert_eq!(page.total, 2);
+        assert_eq!(page.items, vec![worktree("/w/c", Some("feature/c"))]);
+
+        let sorted = paginate(items.clone(), &query(None, None, "", "-branch")).unwrap();
+        let branches: Vec<Option<&str>> = sorted
+            .items
+            .iter()
+            .map(|worktree| worktree.branch.as_deref())
+            .collect();
+        assert_eq!(
+            branches,
+            [Some("fix/b"), Some("feature/c"), Some("feature/a"), None]
+        );
+
+        assert!(paginate(items.clone(), &query(None, None, "", "locked")).is_err());
+        assert!(paginate(items.clone(), &query(Some(0), None, "", "")).is_err());
+        assert!(paginate(items, &query(Some(1), Some(MAX_PER_PAGE + 1), "", "")).is_err());
+    }
+}
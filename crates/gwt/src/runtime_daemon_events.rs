@@ -9,6 +9,9 @@ pub const RUNTIME_STATUS_CHANNEL: &str = "runtime_status";
 pub const RUNTIME_HOOK_CHANNEL: &str = "runtime_hook";
 pub const ISSUE_MONITOR_CHANNEL: &str = "issue_monitor";
 pub const ISSUE_MONITOR_CONTROL_CHANNEL: &str = "issue_monitor_control";
+/// Commits, pushes and background fetches; the GUI turns it into
+/// `BackendEvent::GitRefsChanged`.
+pub const GIT_REFS_CHANNEL: &str = "git_refs";
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RuntimeDaemonEvent {
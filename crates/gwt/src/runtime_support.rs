@@ -132,6 +132,8 @@ pub fn app_state_view_from_parts(
                 kind: project.kind,
             })
             .collect(),
+        registered_projects: Vec::new(),
+        duplicate_projects: Vec::new(),
     }
 }
 
@@ -303,6 +305,10 @@ pub fn synthetic_branch_entry(branch_name: &str) -> BranchListEntry {
         cleanup: gwt::BranchCleanupInfo::default(),
         resume: gwt::BranchResumeInfo::unavailable(),
         start_work_eligibility: None,
+        worktree_path: None,
+        archived: false,
+        integrity_issues: Vec::new(),
+        linked_spec: None,
     }
 }
 
@@ -560,11 +566,23 @@ pub fn front_door_route(argv: &[String]) -> FrontDoorRoute {
                 "board"
                     | "index"
                     | "hook"
+                    | "hooks"
                     | "discuss"
                     | "plan"
                     | "build"
                     | "update"
                     | "__internal"
+                    | "insights"
+                    | "logs"
+                    | "locks"
+                    | "mcp"
+                    | "migrate"
+                    | "config"
+                    | "profile"
+                    | "session"
+                    | "deps"
+                    | "run"
+                    | "jobs"
                     | "memory"
                     | "lessons"
                     | "discussion"
@@ -574,6 +592,7 @@ pub fn front_door_route(argv: &[String]) -> FrontDoorRoute {
                     | "work"
                     | "pane"
                     | "open"
+                    | "--plain-ui"
             ));
             FrontDoorRoute::DetachedCli
         }
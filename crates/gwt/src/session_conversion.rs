@@ -0,0 +1,385 @@
+//! Cross-agent session conversion.
+//!
+//! Turns the transcript of a Claude Code or Codex session into another
+//! agent's history format with [`gwt_ai::convert_session_with_loss`] and
+//! writes it under `~/.gwt/sessions/conversions/`. Agents cannot adopt a
+//! foreign conversation as one of their own, so the target agent starts a
+//! new conversation whose first prompt points at the converted history, and
+//! the new gwt session records where it came from in
+//! [`gwt_agent::ConvertedFrom`].
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use gwt_agent::{session_export::read_session_transcript, AgentId, ConvertedFrom, Session};
+use gwt_ai::{session_converter::SessionFormat, LossInfo, Role, SessionMessage};
+use gwt_core::session_search::TranscriptEntry;
+
+/// Directory under the sessions dir that holds converted histories.
+pub const CONVERSIONS_DIR: &str = "conversions";
+
+/// A converted history written to disk, ready to seed the target agent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConvertedSession {
+    /// Id of the converted history; also its file stem.
+    pub id: String,
+    pub source_agent: AgentId,
+    pub source_session_id: String,
+    pub target_agent: AgentId,
+    pub history_path: PathBuf,
+    pub loss: LossInfo,
+}
+
+impl ConvertedSession {
+    /// First prompt of the target agent.
+    pub fn seed_prompt(&self) -> String {
+        format!(
+            "Continue the work of an earlier {} conversation. Its history, converted to your \
+             format, is in {}. Read it first, then say where it left off.",
+            self.source_agent.display_name(),
+            self.history_path.display()
+        )
+    }
+
+    /// Launch arguments that hand [`Self::seed_prompt`] to the target agent
+    /// and keep it interactive.
+    pub fn seed_args(&self) -> Vec<String> {
+        let prompt = self.seed_prompt();
+        match self.target_agent {
+            AgentId::Gemini => vec!["--prompt-interactive".to_string(), prompt],
+            AgentId::OpenCode => vec!["--prompt".to_string(), prompt],
+            _ => vec![prompt],
+        }
+    }
+
+    pub fn provenance(&self) -> ConvertedFrom {
+        ConvertedFrom {
+            agent_id: self.source_agent.clone(),
+            agent_session_id: self.source_session_id.clone(),
+            history_path: self.history_path.clone(),
+            loss: self.loss.summary(),
+        }
+    }
+}
+
+/// The history format gwt can write for `agent`, if any.
+pub fn conversion_format(agent: &AgentId) -> Option<SessionFormat> {
+    SessionFormat::parse(agent.command())
+}
+
+/// Convert the transcript of `source_session_id` to the format of `target`
+/// and write it under `sessions_dir`.
+pub fn convert_agent_session(
+    sessions_dir: &Path,
+    source: &AgentId,
+    source_session_id: &str,
+    target: &AgentId,
+) -> Result<ConvertedSession, String> {
+    let target_format = conversion_format(target)
+        .ok_or_else(|| format!("{} sessions cannot be converted to", target.display_name()))?;
+    let entries = read_session_transcript(source, source_session_id).ok_or_else(|| {
+        format!(
+            "No readable {} transcript for session {source_session_id}",
+            source.display_name()
+        )
+    })?;
+    let (history, dropped) = transcript_history(&entries);
+    let (encoded, loss) =
+        gwt_ai::convert_session_with_loss(source.command(), target_format.name(), &history)
+            .map_err(|error| error.to_string())?;
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let extension = match target_format {
+        SessionFormat::Claude => "jsonl",
+        _ => "json",
+    };
+    let dir = sessions_dir.join(CONVERSIONS_DIR);
+    fs::create_dir_all(&dir).map_err(|error| format!("{}: {error}", dir.display()))?;
+    let history_path = dir.join(format!("{id}.{extension}"));
+    fs::write(&history_path, encoded)
+        .map_err(|error| format!("{}: {error}", history_path.display()))?;
+
+    Ok(ConvertedSession {
+        id,
+        source_agent: source.clone(),
+        source_session_id: source_session_id.to_string(),
+        target_agent: target.clone(),
+        history_path,
+        loss: LossInfo {
+            system_as_user: loss.system_as_user,
+            ..dropped
+        },
+    })
+}
+
+/// Record on the gwt session `session_id` that it was seeded by `converted`.
+pub fn record_provenance(
+    sessions_dir: &Path,
+    session_id: &str,
+    converted: &ConvertedSession,
+) -> std::io::Result<()> {
+    gwt_agent::update_session(sessions_dir, session_id, |session| {
+        session.converted_from = Some(converted.provenance());
+        Ok(())
+    })
+    .map(|_| ())
+}
+
+/// One agent session in a conversion lineage, with the sessions seeded
+/// from it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineageNode {
+    pub agent: AgentId,
+    pub agent_session_id: String,
+    /// `None` for a source session gwt did not start.
+    pub started_at: Option<DateTime<Utc>>,
+    /// What the conversion that produced this session dropped; `None` for
+    /// the first session of a chain.
+    pub loss: Option<String>,
+    pub children: Vec<LineageNode>,
+}
+
+/// The conversion chains among the gwt sessions of `branch`, oldest first.
+/// A chain starts at a session that was converted but not itself seeded by
+/// a conversion; a source gwt no longer knows becomes a bare root.
+pub fn conversion_lineage(sessions: &[Session], branch: &str) -> Vec<LineageNode> {
+    let mut sessions: Vec<&Session> = sessions
+        .iter()
+        .filter(|session| session.branch == branch)
+        .collect();
+    sessions.sort_by_key(|session| session.created_at);
+    let key_of = |agent: &AgentId, id: &str| (agent.command().to_string(), id.to_string());
+    // Every agent conversation id a gwt session went through.
+    let mut owner: HashMap<(String, String), usize> = HashMap::new();
+    for (index, session) in sessions.iter().enumerate() {
+        let ids = session.agent_session_id.iter().chain(
+            session
+                .session_history
+                .iter()
+                .map(|entry| &entry.agent_session_id),
+        );
+        for id in ids {
+            owner.entry(key_of(&session.agent_id, id)).or_insert(index);
+        }
+    }
+
+    let mut children: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut external: Vec<(ConvertedFrom, Vec<usize>)> = Vec::new();
+    for (index, session) in sessions.iter().enumerate() {
+        let Some(from) = &session.converted_from else {
+            continue;
+        };
+        match owner.get(&key_of(&from.agent_id, &from.agent_session_id)) {
+            Some(&parent) if parent != index => children.entry(parent).or_default().push(index),
+            _ => match external.iter_mut().find(|(source, _)| {
+                source.agent_id == from.agent_id && source.agent_session_id == from.agent_session_id
+            }) {
+                Some((_, seeded)) => seeded.push(index),
+                None => external.push((from.clone(), vec![index])),
+            },
+        }
+    }
+
+    fn build(
+        index: usize,
+        sessions: &[&Session],
+        children: &HashMap<usize, Vec<usize>>,
+    ) -> LineageNode {
+        let session = sessions[index];
+        LineageNode {
+            agent: session.agent_id.clone(),
+            agent_session_id: session
+                .agent_session_id
+                .clone()
+                .unwrap_or_else(|| session.id.clone()),
+            started_at: Some(session.created_at),
+            loss: session
+                .converted_from
+                .as_ref()
+                .map(|from| from.loss.clone()),
+            children: children
+                .get(&index)
+                .into_iter()
+                .flatten()
+                .map(|&child| build(child, sessions, children))
+                .collect(),
+        }
+    }
+
+    let mut roots: Vec<LineageNode> = (0..sessions.len())
+        .filter(|index| children.contains_key(index) && sessions[*index].converted_from.is_none())
+        .map(|index| build(index, &sessions, &children))
+        .collect();
+    roots.extend(external.into_iter().map(|(source, seeded)| {
+        LineageNode {
+            agent: source.agent_id,
+            agent_session_id: source.agent_session_id,
+            started_at: None,
+            loss: None,
+            children: seeded
+                .into_iter()
+                .map(|child| build(child, &sessions, &children))
+                .collect(),
+        }
+    }));
+    roots.sort_by_key(|root| {
+        root.started_at
+            .or_else(|| root.children.first().and_then(|child| child.started_at))
+    });
+    roots
+}
+
+/// `lineage` as indented lines, e.g. `Claude Code session 1a2b3c4d
+/// (2026-10-01 10:00)` followed by `  -> converted (2 tool messages
+/// dropped) -> Codex session ...` for each session seeded from it.
+pub fn render_lineage(lineage: &[LineageNode]) -> Vec<String> {
+    fn describe(node: &LineageNode) -> String {
+        let short_id: String = node.agent_session_id.chars().take(8).collect();
+        let started = node
+            .started_at
+            .map(|at| {
+                at.with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_else(|| "not started by gwt".to_string());
+        format!(
+            "{} session {short_id} ({started})",
+            node.agent.display_name()
+        )
+    }
+    fn walk(node: &LineageNode, depth: usize, lines: &mut Vec<String>) {
+        for child in &node.children {
+            let loss = child.loss.as_deref().unwrap_or("nothing recorded");
+            lines.push(format!(
+                "{}-> converted ({loss}) -> {}",
+                "  ".repeat(depth + 1),
+                describe(child)
+            ));
+            walk(child, depth + 1, lines);
+        }
+    }
+    let mut lines = Vec::new();
+    for root in lineage {
+        lines.push(describe(root));
+        walk(root, 0, &mut lines);
+    }
+    lines
+}
+
+/// The text turns of a transcript, and the tool activity left out of them.
+pub fn transcript_history(entries: &[TranscriptEntry]) -> (Vec<SessionMessage>, LossInfo) {
+    let mut history = Vec::new();
+    let mut loss = LossInfo::default();
+    for entry in entries {
+        loss.dropped_tool_messages += entry.tools.len();
+        for file in &entry.files {
+            if !loss.files_touched.contains(file) {
+                loss.files_touched.push(file.clone());
+            }
+        }
+        let role = match entry.role.as_str() {
+            "user" => Role::User,
+            "assistant" => Role::Assistant,
+            _ => {
+                loss.dropped_tool_messages += 1;
+                continue;
+            }
+        };
+        if entry.text.trim().is_empty() {
+            continue;
+        }
+        history.push(SessionMessage {
+            role,
+            content: entry.text.clone(),
+        });
+    }
+    (history, loss)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(role: &str, text: &str, tools: &[&str], files: &[&str]) -> TranscriptEntry {
+        TranscriptEntry {
+            role: role.to_string(),
+            text: text.to_string(),
+            timestamp: None,
+            tools: tools.iter().map(|tool| tool.to_string()).collect(),
+            files: files.iter().map(|file| file.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn transcript_history_keeps_text_turns_and_counts_tool_activity() {
+        let (history, loss) = transcript_history(&[
+            entry("user", "Fix the parser", &[], &[]),
+            entry(
+                "assistant",
+                "Editing",
+                &["Edit", "Edit"],
+                &["src/a.rs", "src/a.rs"],
+            ),
+            entry("tool", "ok", &[], &[]),
+            entry("assistant", "", &["Bash"], &[]),
+            entry("assistant", "Done", &[], &[]),
+        ]);
+        let roles: Vec<Role> = history.iter().map(|message| message.role.clone()).collect();
+        assert_eq!(roles, [Role::User, Role::Assistant, Role::Assistant]);
+        assert_eq!(loss.dropped_tool_messages, 4);
+        assert_eq!(loss.files_touched, ["src/a.rs"]);
+    }
+
+    fn session(agent: AgentId, id: &str, minute: u32, from: Option<(AgentId, &str)>) -> Session {
+        let mut session = Session::new("/wt", "feature/x", agent);
+        session.agent_session_id = Some(id.to_string());
+        session.created_at =
+            chrono::TimeZone::with_ymd_and_hms(&Utc, 2026, 10, 1, 9, minute, 0).unwrap();
+        session.converted_from = from.map(|(agent_id, source)| ConvertedFrom {
+            agent_id,
+            agent_session_id: source.to_string(),
+            history_path: PathBuf::from("/conv.json"),
+            loss: format!("from {source}"),
+        });
+        session
+    }
+
+    #[test]
+    fn conversion_lineage_chains_sessions_of_the_branch() {
+        let mut other_branch = session(AgentId::ClaudeCode, "z", 0, None);
+        other_branch.branch = "main".to_string();
+        let sessions = vec![
+            session(AgentId::Gemini, "c", 30, Some((AgentId::Codex, "b"))),
+            session(AgentId::ClaudeCode, "a", 0, None),
+            session(AgentId::Codex, "b", 10, Some((AgentId::ClaudeCode, "a"))),
+            session(AgentId::ClaudeCode, "lone", 5, None),
+            session(AgentId::Codex, "e", 20, Some((AgentId::ClaudeCode, "gone"))),
+            other_branch,
+        ];
+
+        let lineage = conversion_lineage(&sessions, "feature/x");
+        let ids: Vec<&str> = lineage
+            .iter()
+            .map(|root| root.agent_session_id.as_str())
+            .collect();
+        assert_eq!(ids, ["a", "gone"]);
+        assert_eq!(lineage[0].children[0].agent_session_id, "b");
+        assert_eq!(
+            lineage[0].children[0].children[0].loss.as_deref(),
+            Some("from b")
+        );
+        assert_eq!(lineage[1].started_at, None);
+
+        let lines = render_lineage(&lineage);
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].starts_with("Claude Code session a ("));
+        assert!(lines[1].starts_with("  -> converted (from a) -> Codex session b ("));
+        assert!(lines[2].starts_with("    -> converted (from b) -> Gemini"));
+        assert_eq!(lines[3], "Claude Code session gone (not started by gwt)");
+    }
+}
@@ -0,0 +1,152 @@
+//! Agent session export for one branch of a repository.
+//!
+//! Picks the persisted gwt sessions that belong to the repository (same
+//! repo hash, or run inside one of its worktrees) and hands them to
+//! [`gwt_agent::session_export`]. Shared by `gwt session export` and
+//! `GET /api/repos/{id}/branches/{branch}/sessions/export`.
+//!
+//! [`bundle_branch_sessions`] adds the launch plans, environment and git
+//! metadata for `gwt session bundle` (see [`gwt_agent::session_bundle`]).
+
+use std::path::{Path, PathBuf};
+
+use gwt_agent::{
+    session_bundle::{bundle_environment, BundleGitInfo, SessionBundle},
+    session_export::{build_session_export, read_session_transcript, SessionExport},
+    Session,
+};
+
+/// Transcripts of the agent sessions that ran on `branch` of the
+/// repository at `repo_path`.
+pub fn export_branch_sessions(repo_path: &Path, branch: &str) -> SessionExport {
+    let main = main_root(repo_path);
+    build_session_export(branch, &repo_sessions(&main), read_session_transcript)
+}
+
+/// Anonymized bug-report bundle of the agent sessions that ran on `branch`
+/// of the repository at `repo_path`.
+pub fn bundle_branch_sessions(repo_path: &Path, branch: &str) -> std::io::Result<SessionBundle> {
+    let main = main_root(repo_path);
+    let sessions = repo_sessions(&main);
+    let export = build_session_export(branch, &sessions, read_session_transcript);
+    let worktree = sessions
+        .iter()
+        .filter(|session| session.branch == branch)
+        .map(|session| session.worktree_path.clone())
+        .find(|path| path.is_dir());
+    let git = branch_git_info(&main, worktree.as_deref(), branch);
+    let home = std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(PathBuf::from);
+    SessionBundle::new(
+        env!("CARGO_PKG_VERSION"),
+        git,
+        bundle_environment(std::env::vars()),
+        &sessions,
+        export,
+    )
+    .anonymize(&main, home.as_deref(), &gwt_core::logging::redact::global())
+}
+
+fn main_root(repo_path: &Path) -> PathBuf {
+    gwt_git::worktree::main_worktree_root(repo_path).unwrap_or_else(|_| repo_path.to_path_buf())
+}
+
+/// Persisted gwt sessions of the repository whose main worktree is `main`.
+fn repo_sessions(main: &Path) -> Vec<Session> {
+    let repo_hash =
+        gwt_core::repo_hash::detect_repo_hash(main).map(|hash| hash.as_str().to_string());
+    let worktrees: Vec<PathBuf> = gwt_git::WorktreeManager::new(main)
+        .list()
+        .map(|worktrees| {
+            worktrees
+                .into_iter()
+                .map(|worktree| worktree.path)
+                .collect()
+        })
+        .unwrap_or_default();
+    crate::launch_wizard::load_sessions(&gwt_core::paths::gwt_sessions_dir())
+        .into_iter()
+        .filter(|session| is_repo_session(session, repo_hash.as_deref(), &worktrees))
+        .collect()
+}
+
+/// Commit, git version and uncommitted paths of `branch`. Missing pieces
+/// stay empty: a bundle is still useful without them.
+fn branch_git_info(main: &Path, worktree: Option<&Path>, branch: &str) -> BundleGitInfo {
+    let git_stdout = |args: &[&str], dir: &Path| {
+        gwt_core::process::run_git_logged(args, Some(dir))
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+            .filter(|stdout| !stdout.is_empty())
+    };
+    let commit = format!("{branch}^{{commit}}");
+    BundleGitInfo {
+        branch: branch.to_string(),
+        head: git_stdout(&["rev-parse", "--verify", "--quiet", &commit], main),
+        git_version: git_stdout(&["--version"], main),
+        dirty_paths: worktree
+            .and_then(|worktree| gwt_git::diff::get_status(worktree).ok())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|entry| entry.path.to_string_lossy().into_owned())
+            .collect(),
+    }
+}
+
+fn is_repo_session(session: &Session, repo_hash: Option<&str>, worktrees: &[PathBuf]) -> bool {
+    if repo_hash.is_some() && session.repo_hash.as_deref() == repo_hash {
+        return true;
+    }
+    worktrees
+        .iter()
+        .any(|worktree| session.worktree_path.starts_with(worktree))
+}
+
+/// File name offered for a downloaded export, e.g.
+/// `feature-login-sessions.md`.
+pub fn export_file_name(branch: &str, extension: &str) -> String {
+    let stem: String = branch
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("{stem}-sessions.{extension}")
+}
+
+#[cfg(test)]
+mod tests {
+    use gwt_agent::AgentId;
+
+    use super::*;
+
+    #[test]
+    fn repo_sessions_match_by_hash_or_worktree() {
+        let mut hashed = Session::new("/elsewhere", "main", AgentId::Codex);
+        hashed.repo_hash = Some("abc".to_string());
+        let mut local = Session::new("/repo/wt/sub", "main", AgentId::Codex);
+        local.repo_hash = None;
+        let mut foreign = Session::new("/other", "main", AgentId::Codex);
+        foreign.repo_hash = Some("xyz".to_string());
+        let worktrees = vec![PathBuf::from("/repo/wt")];
+
+        assert!(is_repo_session(&hashed, Some("abc"), &worktrees));
+        assert!(is_repo_session(&local, Some("abc"), &worktrees));
+        assert!(!is_repo_session(&foreign, Some("abc"), &worktrees));
+        assert!(!is_repo_session(&hashed, None, &[]));
+    }
+
+    #[test]
+    fn export_file_name_is_filesystem_safe() {
+        assert_eq!(
+            export_file_name("feature/login form", "md"),
+            "feature-login-form-sessions.md"
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Posts a finished agent session's summary to its linked GitHub Issue.
+//!
+//! Turned on per repository with `[session_issue_comments]` in
+//! `~/.gwt/config.toml`. The comment carries the agent-authored session title
+//! and focus line, the branch diffstat against the default base, and the
+//! branch's open PR, so people following the Issue see progress without
+//! asking.
+
+use std::{fmt::Write as _, path::Path};
+
+use gwt_github::{IssueClient, IssueNumber};
+
+/// First line of every posted comment, so the comments can be recognised.
+pub const SESSION_COMMENT_MARKER: &str = "<!-- gwt:session-summary -->";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionIssueComment {
+    pub issue_number: u64,
+    pub agent_id: String,
+    pub branch: String,
+    /// Agent-authored session title.
+    pub summary: Option<String>,
+    /// Agent-authored current focus line.
+    pub detail: Option<String>,
+}
+
+/// Markdown body for `comment`, or `None` when there is nothing to report
+/// (no summary, no focus line and no changes).
+pub fn render_comment(
+    comment: &SessionIssueComment,
+    diffstat: Option<&str>,
+    pr_url: Option<&str>,
+) -> Option<String> {
+    let summary = non_empty(comment.summary.as_deref());
+    let detail = non_empty(comment.detail.as_deref());
+    let diffstat = non_empty(diffstat);
+    if summary.is_none() && detail.is_none() && diffstat.is_none() {
+        return None;
+    }
+    let mut body = String::new();
+    let _ = writeln!(body, "{SESSION_COMMENT_MARKER}");
+    let _ = writeln!(
+        body,
+        "**Agent session finished**: `{}` on `{}`",
+        comment.agent_id, comment.branch
+    );
+    for text in [summary, detail].into_iter().flatten() {
+        let _ = write!(body, "\n{text}\n");
+    }
+    body.push('\n');
+    if let Some(diffstat) = diffstat {
+        let _ = writeln!(body, "- Changes: {diffstat}");
+    }
+    if let Some(pr_url) = non_empty(pr_url) {
+        let _ = writeln!(body, "- Pull request: {pr_url}");
+    }
+    Some(body)
+}
+
+/// Post `comment` when the config enables it for `project_root`. Returns
+/// whether a comment was created.
+pub fn publish_session_issue_comment(
+    project_root: &Path,
+    worktree: &Path,
+    comment: &SessionIssueComment,
+) -> Result<bool, String> {
+    let enabled = gwt_config::Settings::load()
+        .map(|settings| settings.session_issue_comments.enabled_for(project_root))
+        .unwrap_or(false);
+    if !enabled {
+        return Ok(false);
+    }
+    let (owner, repo) = crate::issue_monitor_worker::github_remote_owner_and_repo(project_root)
+        .map_err(|error| error.to_string())?;
+    let base = crate::issue_monitor_worker::resolve_default_base_branch(project_root);
+    let diffstat = gwt_git::diff::branch_shortstat(worktree, &format!("origin/{base}"))
+        .ok()
+        .flatten();
+    let pr_url = gwt_git::pr_status::fetch_open_pr_number_for_branch(worktree, &comment.branch)
+        .map(|number| format!("https://github.com/{owner}/{repo}/pull/{number}"));
+    let Some(body) = render_comment(comment, diffstat.as_deref(), pr_url.as_deref()) else {
+        return Ok(false);
+    };
+    let client = gwt_github::client::http::HttpIssueClient::from_gh_auth(&owner, &repo)
+        .map_err(|error| error.to_string())?;
+    client
+        .create_comment(IssueNumber(comment.issue_number), &body)
+        .map_err(|error| error.to_string())?;
+    Ok(true)
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comment(summary: Option<&str>) -> SessionIssueComment {
+        SessionIssueComment {
+            issue_number: 42,
+            agent_id: "codex".to_string(),
+            branch: "work/login".to_string(),
+            summary: summary.map(str::to_string),
+            detail: None,
+        }
+    }
+
+    #[test]
+    fn render_comment_lists_summary_diffstat_and_pr() {
+        let body = render_comment(
+            &comment(Some("Fix login redirect")),
+            Some("2 files changed, 5 insertions(+)"),
+            Some("https://github.com/o/r/pull/7"),
+        )
+        .unwrap();
+
+        assert!(body.starts_with(SESSION_COMMENT_MARKER), "{body}");
+        assert!(body.contains("`codex` on `work/login`"), "{body}");
+        assert!(body.contains("\nFix login redirect\n"), "{body}");
+        assert!(body.contains("- Changes: 2 files changed, 5 insertions(+)"));
+        assert!(body.contains("- Pull request: https://github.com/o/r/pull/7"));
+    }
+
+    #[test]
+    fn render_comment_skips_sessions_with_nothing_to_report() {
+        assert_eq!(render_comment(&comment(Some("  ")), None, None), None);
+        assert!(render_comment(&comment(None), Some("1 file changed"), None).is_some());
+    }
+}
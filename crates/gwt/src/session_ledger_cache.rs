@@ -35,12 +35,15 @@ impl SessionLedgerCache {
 
     /// Load every session under `sessions_dir`, reusing parsed entries whose
     /// (mtime, size) are unchanged. Files that fail to stat or parse are
-    /// skipped, matching the previous eager loader's semantics.
+    /// skipped, matching the previous eager loader's semantics. On a
+    /// filesystem with second-precise mtimes every file is re-parsed, since
+    /// an unchanged (mtime, size) no longer proves an unchanged file.
     pub(crate) fn load(&mut self, sessions_dir: &Path) -> Vec<gwt_agent::Session> {
         let Ok(dir) = std::fs::read_dir(sessions_dir) else {
             self.entries.clear();
             return Vec::new();
         };
+        let reuse = gwt_core::fs_probe::capabilities(sessions_dir).subsecond_mtime;
         let mut seen: HashMap<PathBuf, CachedSession> = HashMap::new();
         let mut sessions = Vec::new();
         for entry in dir.flatten() {
@@ -56,7 +59,7 @@ impl SessionLedgerCache {
             };
             let size = meta.len();
             let cached = match self.entries.remove(&path) {
-                Some(hit) if hit.mtime == mtime && hit.size == size => hit,
+                Some(hit) if reuse && hit.mtime == mtime && hit.size == size => hit,
                 _ => {
                     let Ok(session) = gwt_agent::Session::load_and_migrate(&path) else {
                         continue;
@@ -0,0 +1,200 @@
+//! Stale remote branch advisor for the Branches window.
+//!
+//! A branch on `origin` is stale when every PR opened from it is merged or
+//! closed and its last commit is older than the requested age. Branches that
+//! never had a PR are left alone (they may be unpublished work), as are the
+//! protected base branches and branches protected on GitHub. Deletion goes through
+//! [`gwt_git::WorktreeManager::delete_remote_branch`], so the protected-branch
+//! guard and push timeout match local cleanup's "delete remote" option.
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use gwt_git::pr_status::{PrHead, PrState};
+use serde::{Deserialize, Serialize};
+
+use crate::branch_cleanup::{BranchCleanupResultEntry, BranchCleanupResultStatus};
+
+pub const STALE_REMOTE: &str = "origin";
+pub const DEFAULT_STALE_REMOTE_MIN_AGE_DAYS: u32 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StalePrState {
+    Merged,
+    Closed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaleRemoteBranch {
+    /// Branch name on the remote, without the `origin/` prefix.
+    pub branch: String,
+    pub last_commit_date: String,
+    pub age_days: u64,
+    /// Most recent PR from this branch.
+    pub pr_number: u64,
+    pub pr_state: StalePrState,
+}
+
+/// Stale `origin` branches of the repository at `repo_path`, oldest first.
+pub fn find_stale_remote_branches(
+    repo_path: &Path,
+    min_age_days: u32,
+) -> Result<Vec<StaleRemoteBranch>, String> {
+    let branches = gwt_git::branch::list_branches(repo_path).map_err(|error| error.to_string())?;
+    let prs = gwt_git::pr_status::fetch_pr_heads(repo_path).map_err(|error| error.to_string())?;
+    let mut stale = classify_stale_remote_branches(&branches, &prs, min_age_days, Utc::now());
+    stale.retain(|branch| !gwt_git::is_synced_protected_branch(repo_path, &branch.branch));
+    Ok(stale)
+}
+
+pub fn classify_stale_remote_branches(
+    branches: &[gwt_git::Branch],
+    prs: &[PrHead],
+    min_age_days: u32,
+    now: DateTime<Utc>,
+) -> Vec<StaleRemoteBranch> {
+    let mut stale: Vec<StaleRemoteBranch> = branches
+        .iter()
+        .filter(|branch| branch.is_remote && branch.remote_name.as_deref() == Some(STALE_REMOTE))
+        .filter_map(|branch| {
+            let name = branch.remote_branch_name.as_deref()?;
+            if gwt_git::is_protected_branch(name) {
+                return None;
+            }
+            let branch_prs: Vec<&PrHead> =
+                prs.iter().filter(|pr| pr.head_ref_name == name).collect();
+            if branch_prs.iter().any(|pr| pr.state == PrState::Open) {
+                return None;
+            }
+            let latest = branch_prs.iter().max_by_key(|pr| pr.number)?;
+            let last_commit_date = branch.last_commit_date.as_deref()?;
+            let committed_at = parse_commit_date(last_commit_date)?;
+            let age_days = u64::try_from((now - committed_at).num_days()).unwrap_or(0);
+            if age_days < u64::from(min_age_days) {
+                return None;
+            }
+            Some(StaleRemoteBranch {
+                branch: name.to_string(),
+                last_commit_date: last_commit_date.to_string(),
+                age_days,
+                pr_number: latest.number,
+                pr_state: if latest.state == PrState::Merged {
+                    StalePrState::Merged
+                } else {
+                    StalePrState::Closed
+                },
+            })
+        })
+        .collect();
+    stale.sort_by(|left, right| {
+        right
+            .age_days
+            .cmp(&left.age_days)
+            .then_with(|| left.branch.cmp(&right.branch))
+    });
+    stale
+}
+
+/// Delete each of `branches` from `origin`. Every branch gets a result entry;
+/// one failure does not stop the rest.
+pub fn delete_stale_remote_branches(
+    repo_path: &Path,
+    branches: &[String],
+) -> Vec<BranchCleanupResultEntry> {
+    let manager = gwt_git::WorktreeManager::new(repo_path)
+        .with_protection(crate::branch_protection::protection_policy());
+    branches
+        .iter()
+        .map(|branch| {
+            let upstream = format!("{STALE_REMOTE}/{branch}");
+            let (status, message) = match manager.delete_remote_branch(branch, Some(&upstream)) {
+                Ok(gwt_git::RemoteDeleteOutcome::Deleted) => (
+                    BranchCleanupResultStatus::Success,
+                    format!("Deleted {upstream}"),
+                ),
+                Ok(gwt_git::RemoteDeleteOutcome::SkippedMissing) => (
+                    BranchCleanupResultStatus::Success,
+                    format!("{upstream} was already gone"),
+                ),
+                Err(error) => (BranchCleanupResultStatus::Failed, error.to_string()),
+            };
+            BranchCleanupResultEntry {
+                branch: branch.clone(),
+                execution_branch: None,
+                status,
+                message,
+            }
+        })
+        .collect()
+}
+
+/// `git for-each-ref --format=%(creatordate:iso8601)` dates, e.g.
+/// `2026-01-02 03:04:05 +0900`.
+fn parse_commit_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_str(value.trim(), "%Y-%m-%d %H:%M:%S %z")
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn remote(name: &str, date: &str) -> gwt_git::Branch {
+        gwt_git::Branch {
+            name: format!("origin/{name}"),
+            remote_name: Some("origin".to_string()),
+            remote_branch_name: Some(name.to_string()),
+            is_local: false,
+            is_remote: true,
+            is_head: false,
+            upstream: None,
+            ahead: 0,
+            behind: 0,
+            last_commit_date: Some(date.to_string()),
+        }
+    }
+
+    fn pr(number: u64, head: &str, state: PrState) -> PrHead {
+        PrHead {
+            number,
+            head_ref_name: head.to_string(),
+            state,
+        }
+    }
+
+    #[test]
+    fn classify_lists_old_branches_whose_prs_are_all_finished() {
+        let now = DateTime::parse_from_rfc3339("2026-03-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let old = "2026-01-01 00:00:00 +0000";
+        let branches = vec![
+            remote("work/merged", old),
+            remote("work/closed", "2026-01-20 00:00:00 +0000"),
+            remote("work/reopened", old),
+            remote("work/no-pr", old),
+            remote("work/recent", "2026-02-20 00:00:00 +0000"),
+            remote("main", old),
+        ];
+        let prs = vec![
+            pr(1, "work/merged", PrState::Merged),
+            pr(2, "work/closed", PrState::Closed),
+            pr(3, "work/reopened", PrState::Merged),
+            pr(4, "work/reopened", PrState::Open),
+            pr(5, "work/recent", PrState::Merged),
+            pr(6, "main", PrState::Merged),
+        ];
+
+        let stale = classify_stale_remote_branches(&branches, &prs, 30, now);
+
+        let names: Vec<&str> = stale.iter().map(|entry| entry.branch.as_str()).collect();
+        assert_eq!(names, ["work/merged", "work/closed"]);
+        assert_eq!(stale[0].age_days, 59);
+        assert_eq!(stale[0].pr_state, StalePrState::Merged);
+        assert_eq!(stale[1].pr_number, 2);
+        assert_eq!(stale[1].pr_state, StalePrState::Closed);
+        assert!(classify_stale_remote_branches(&branches, &prs, 90, now).is_empty());
+    }
+}
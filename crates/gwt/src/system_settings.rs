@@ -9,6 +9,16 @@
 //!   [`crate::protocol::BackendEvent::SystemSettingsUpdated`] /
 //!   [`crate::protocol::BackendEvent::SystemSettingsError`]
 //!
+//! The tab's AI presets go through
+//! [`crate::protocol::FrontendEvent::ApplyAiPreset`] →
+//! [`crate::protocol::BackendEvent::AiPresetApplied`], which points `[ai]` at
+//! a local Ollama server so AI features work offline.
+//!
+//! Opening Settings also answers with
+//! [`crate::protocol::BackendEvent::ConfigDiagnostics`], the problems
+//! [`gwt_config::validate`] finds in the file, so a config that fell back to
+//! the defaults or has ignored keys is visible instead of silent.
+//!
 //! Validation: the only currently-accepted values for `language` are `auto`,
 //! `en`, and `ja`. Anything else is rejected with
 //! [`SystemSettingsError::InvalidLanguage`] so the frontend dropdown stays
@@ -28,6 +38,12 @@ pub enum SystemSettingsError {
     InvalidLanguage(String),
     #[error("invalid board provider `{0}`: expected `local`, `slack`, or `teams`")]
     InvalidBoardProvider(String),
+    #[error("unknown AI preset `{0}`: expected `ollama`")]
+    InvalidAiPreset(String),
+    #[error("Ollama is not reachable at {endpoint}: {reason}")]
+    OllamaUnreachable { endpoint: String, reason: String },
+    #[error("Ollama at {0} has no models installed; run `ollama pull <model>` first")]
+    NoOllamaModels(String),
     #[error("config storage error: {0}")]
     Storage(String),
 }
@@ -351,6 +367,82 @@ pub fn board_auth_status_event(message: Option<String>) -> BackendEvent {
     }
 }
 
+/// `[ai]` values written by an AI preset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AiPresetOutcome {
+    pub provider: String,
+    pub endpoint: String,
+    pub model: String,
+    /// Models available on the configured server.
+    pub models: Vec<String>,
+}
+
+/// Apply the AI preset `preset` to `path`. Only `ollama` exists: it asks the
+/// local Ollama server for its installed models, so call it off the UI
+/// thread.
+pub fn apply_ai_preset(path: &Path, preset: &str) -> Result<AiPresetOutcome, SystemSettingsError> {
+    if !preset.trim().eq_ignore_ascii_case("ollama") {
+        return Err(SystemSettingsError::InvalidAiPreset(preset.to_string()));
+    }
+    let endpoint = gwt_ai::DEFAULT_OLLAMA_ENDPOINT;
+    let models = gwt_ai::OllamaClient::installed_models(endpoint)
+        .map_err(|err| SystemSettingsError::OllamaUnreachable {
+            endpoint: endpoint.to_string(),
+            reason: err.to_string(),
+        })?
+        .into_iter()
+        .map(|model| model.id)
+        .collect();
+    write_ollama_preset(path, endpoint, models)
+}
+
+/// Point `[ai]` at the Ollama server at `endpoint`. The configured model is
+/// kept when it is one of `models`, otherwise the first installed model is
+/// used. Ollama needs no API key, so a stored key is dropped.
+pub fn write_ollama_preset(
+    path: &Path,
+    endpoint: &str,
+    models: Vec<String>,
+) -> Result<AiPresetOutcome, SystemSettingsError> {
+    let mut settings = load_settings_or_default(path)?;
+    let model = if models.contains(&settings.ai.model) {
+        settings.ai.model.clone()
+    } else {
+        models
+            .first()
+            .cloned()
+            .ok_or_else(|| SystemSettingsError::NoOllamaModels(endpoint.to_string()))?
+    };
+    settings.ai.provider = "ollama".to_string();
+    settings.ai.endpoint = endpoint.to_string();
+    settings.ai.api_key = None;
+    settings.ai.model = model.clone();
+    settings
+        .save(path)
+        .map_err(|err| SystemSettingsError::Storage(err.to_string()))?;
+    Ok(AiPresetOutcome {
+        provider: settings.ai.provider,
+        endpoint: settings.ai.endpoint,
+        model,
+        models,
+    })
+}
+
+/// Build the `BackendEvent` reply for `FrontendEvent::ApplyAiPreset`.
+pub fn ai_preset_event(path: &Path, preset: &str) -> BackendEvent {
+    match apply_ai_preset(path, preset) {
+        Ok(outcome) => BackendEvent::AiPresetApplied {
+            provider: outcome.provider,
+            endpoint: outcome.endpoint,
+            model: outcome.model,
+            models: outcome.models,
+        },
+        Err(err) => BackendEvent::SystemSettingsError {
+            message: err.to_string(),
+        },
+    }
+}
+
 /// Build the `BackendEvent` reply for `FrontendEvent::GetSystemSettings`.
 pub fn get_event(path: &Path) -> BackendEvent {
     match read_settings(path) {
@@ -365,6 +457,28 @@ pub fn get_event(path: &Path) -> BackendEvent {
     }
 }
 
+/// Build the [`BackendEvent::ConfigDiagnostics`] reply for the config file at
+/// `path`. A missing file has no diagnostics.
+pub fn config_diagnostics_event(path: &Path) -> BackendEvent {
+    let diagnostics = if path.exists() {
+        gwt_config::validate::validate_file(path).unwrap_or_else(|error| {
+            vec![gwt_config::ConfigDiagnostic {
+                severity: gwt_config::DiagnosticSeverity::Error,
+                key: None,
+                message: error.to_string(),
+                line: None,
+                column: None,
+            }]
+        })
+    } else {
+        Vec::new()
+    };
+    BackendEvent::ConfigDiagnostics {
+        path: path.display().to_string(),
+        diagnostics,
+    }
+}
+
 /// Build the `BackendEvent` reply for `FrontendEvent::UpdateSystemSettings`.
 pub fn update_event(
     path: &Path,
@@ -755,4 +869,34 @@ mod tests {
         assert_eq!(snapshot.slack_client_id.as_deref(), Some("C123"));
         assert_eq!(snapshot.slack_default_channel, None);
     }
+
+    #[test]
+    fn ollama_preset_keeps_an_installed_model_and_drops_the_api_key() {
+        let tmp = tempdir().unwrap();
+        let path = tmp.path().join("config.toml");
+        let mut settings = Settings::default();
+        settings.ai.api_key = Some("sk-remote".to_string());
+        settings.ai.model = "qwen2.5".to_string();
+        settings.save(&path).unwrap();
+
+        let models = vec!["llama3.2:latest".to_string(), "qwen2.5".to_string()];
+        let outcome = write_ollama_preset(&path, "http://localhost:11434", models).unwrap();
+        assert_eq!(outcome.model, "qwen2.5");
+        let saved = Settings::load_from_path(&path).unwrap().ai;
+        assert_eq!(saved.provider, "ollama");
+        assert_eq!(saved.endpoint, "http://localhost:11434");
+        assert_eq!(saved.api_key, None);
+
+        let outcome =
+            write_ollama_preset(&path, "http://localhost:11434", vec!["phi4".to_string()]).unwrap();
+        assert_eq!(outcome.model, "phi4");
+        assert!(matches!(
+            write_ollama_preset(&path, "http://localhost:11434", Vec::new()),
+            Err(SystemSettingsError::NoOllamaModels(_))
+        ));
+        assert!(matches!(
+            apply_ai_preset(&path, "lmstudio"),
+            Err(SystemSettingsError::InvalidAiPreset(_))
+        ));
+    }
 }
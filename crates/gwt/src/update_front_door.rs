@@ -119,7 +119,7 @@ pub fn spawn_startup_update_check(
             }
             let exe = current_exe.clone();
             let join = tokio::task::spawn_blocking(move || {
-                gwt_core::update::UpdateManager::new().check_for_executable(true, exe.as_deref())
+                channel_update_manager().check_for_executable(true, exe.as_deref())
             })
             .await;
             let Ok(update_state) = join else {
@@ -144,7 +144,7 @@ pub fn spawn_startup_update_check(
             tokio::time::sleep(state.current_interval()).await;
             let exe = current_exe.clone();
             let outcome = tokio::task::spawn_blocking(move || {
-                gwt_core::update::UpdateManager::new().check_for_executable(true, exe.as_deref())
+                channel_update_manager().check_for_executable(true, exe.as_deref())
             })
             .await;
             match outcome {
@@ -159,6 +159,12 @@ pub fn spawn_startup_update_check(
     });
 }
 
+/// Update manager following the `[update] channel` setting, re-read on every
+/// check so a changed channel applies without a restart.
+fn channel_update_manager() -> gwt_core::update::UpdateManager {
+    gwt_core::update::UpdateManager::new().with_channel(gwt::cli::update::configured_channel())
+}
+
 fn handle_poll_outcome(
     state: &mut PollState,
     outcome: gwt_core::update::UpdateState,
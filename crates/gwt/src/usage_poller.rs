@@ -263,6 +263,7 @@ fn is_active_status(status: AgentStatus) -> bool {
             | AgentStatus::Idle
             | AgentStatus::WaitingInput
             | AgentStatus::Interrupted
+            | AgentStatus::Stalled
     )
 }
 
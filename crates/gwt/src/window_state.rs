@@ -3,7 +3,9 @@ use crate::{
     persistence::WindowState,
     preset::WindowPreset,
 };
+use gwt_config::AgentExitPolicy;
 use gwt_terminal::PaneStatus;
+use std::time::Duration;
 
 pub fn compose_window_state(
     pty_state: WindowState,
@@ -64,7 +66,33 @@ pub fn window_state_from_pane_status(status: &PaneStatus) -> WindowState {
     match status {
         PaneStatus::Running => WindowState::Running,
         PaneStatus::Completed(0) => WindowState::Stopped,
-        PaneStatus::Completed(_) | PaneStatus::Error(_) => WindowState::Error,
+        PaneStatus::Completed(_) | PaneStatus::Signaled(_) | PaneStatus::Error(_) => {
+            WindowState::Error
+        }
+    }
+}
+
+/// [`window_state_from_pane_status`] for an agent process, applying the
+/// agent's configured exit policy. `uptime` is how long the process ran.
+/// Spawn and I/O errors stay errors regardless of the policy.
+pub fn agent_window_state_from_pane_status(
+    status: &PaneStatus,
+    uptime: Duration,
+    policy: &AgentExitPolicy,
+) -> WindowState {
+    let normal_exit = match status {
+        PaneStatus::Running => return WindowState::Running,
+        PaneStatus::Error(_) => return WindowState::Error,
+        PaneStatus::Completed(code) => policy.success_codes.contains(code),
+        PaneStatus::Signaled(_) => policy.signal_is_success,
+    };
+    let ran_past_fast_exit = policy
+        .fast_exit_secs
+        .is_some_and(|secs| uptime >= Duration::from_secs(secs));
+    if normal_exit || ran_past_fast_exit {
+        WindowState::Stopped
+    } else {
+        WindowState::Error
     }
 }
 
@@ -100,8 +128,9 @@ fn parse_runtime_status(status: &str) -> Option<WindowState> {
 #[cfg(test)]
 mod tests {
     use super::{
-        compose_window_state, compose_window_state_with_active_session, runtime_hook_window_state,
-        window_state_from_pane_status,
+        agent_window_state_from_pane_status, compose_window_state,
+        compose_window_state_with_active_session, runtime_hook_window_state,
+        window_state_from_pane_status, AgentExitPolicy, Duration,
     };
     use crate::{
         daemon_runtime::{RuntimeHookEvent, RuntimeHookEventKind},
@@ -311,4 +340,54 @@ mod tests {
             WindowState::Error
         );
     }
+
+    #[test]
+    fn agent_exit_policy_accepts_configured_codes_signals_and_long_runs() {
+        let default = AgentExitPolicy::default();
+        let quick = Duration::from_secs(1);
+        let long = Duration::from_secs(600);
+        let signaled = PaneStatus::Signaled("Terminated".to_string());
+        assert_eq!(
+            agent_window_state_from_pane_status(&PaneStatus::Completed(0), quick, &default),
+            WindowState::Stopped
+        );
+        assert_eq!(
+            agent_window_state_from_pane_status(&PaneStatus::Completed(1), long, &default),
+            WindowState::Error
+        );
+        assert_eq!(
+            agent_window_state_from_pane_status(&signaled, long, &default),
+            WindowState::Error
+        );
+
+        let lenient = AgentExitPolicy {
+            success_codes: vec![0, 1],
+            signal_is_success: true,
+            fast_exit_secs: Some(10),
+        };
+        assert_eq!(
+            agent_window_state_from_pane_status(&PaneStatus::Completed(1), quick, &lenient),
+            WindowState::Stopped
+        );
+        assert_eq!(
+            agent_window_state_from_pane_status(&signaled, quick, &lenient),
+            WindowState::Stopped
+        );
+        assert_eq!(
+            agent_window_state_from_pane_status(&PaneStatus::Completed(2), quick, &lenient),
+            WindowState::Error
+        );
+        assert_eq!(
+            agent_window_state_from_pane_status(&PaneStatus::Completed(2), long, &lenient),
+            WindowState::Stopped
+        );
+        assert_eq!(
+            agent_window_state_from_pane_status(
+                &PaneStatus::Error("boom".to_string()),
+                long,
+                &lenient
+            ),
+            WindowState::Error
+        );
+    }
 }
@@ -0,0 +1,87 @@
+//! Branches cleanup "Archive" alternative.
+//!
+//! Instead of deleting a branch, its worktree can be archived: the files,
+//! including uncommitted changes, are packed into the project's archive
+//! directory and the worktree is removed while the branch stays. `r` on an
+//! archived branch restores it. The archive format and index live in
+//! [`gwt_git::worktree_archive`], shared with `gwtd worktree.archive`.
+
+use std::{collections::HashSet, path::Path};
+
+use gwt_git::worktree_archive;
+
+use crate::branch_cleanup::{BranchCleanupResultEntry, BranchCleanupResultStatus};
+
+/// Archive the worktrees of `branches`. Branches with a running agent session
+/// are skipped. Every branch gets a result entry.
+pub fn archive_project_worktrees(
+    project_root: &Path,
+    branches: &[String],
+    active_session_branches: &HashSet<String>,
+) -> Vec<BranchCleanupResultEntry> {
+    let archive_dir = worktree_archive::default_archive_dir(project_root);
+    branches
+        .iter()
+        .map(|branch| {
+            let (status, message) = if active_session_branches.contains(branch) {
+                (
+                    BranchCleanupResultStatus::Failed,
+                    "An agent session is running in this worktree".to_string(),
+                )
+            } else {
+                match worktree_archive::archive_worktree(project_root, &archive_dir, branch) {
+                    Ok(archive) => (
+                        BranchCleanupResultStatus::Success,
+                        format!(
+                            "Archived {}; press r on the branch to restore it",
+                            archive.worktree_path.display()
+                        ),
+                    ),
+                    Err(error) => (BranchCleanupResultStatus::Failed, error.to_string()),
+                }
+            };
+            BranchCleanupResultEntry {
+                branch: branch.clone(),
+                execution_branch: None,
+                status,
+                message,
+            }
+        })
+        .collect()
+}
+
+/// Restore the archived worktree of `branch`. Returns a one-line summary for
+/// the Branches notice.
+pub fn restore_project_worktree(project_root: &Path, branch: &str) -> Result<String, String> {
+    let archive_dir = worktree_archive::default_archive_dir(project_root);
+    worktree_archive::restore_worktree(project_root, &archive_dir, branch)
+        .map(|path| format!("Restored {branch} at {}", path.display()))
+        .map_err(|error| error.to_string())
+}
+
+/// Branches of the repository at `repo_path` that have an archived worktree.
+pub fn archived_branches(repo_path: &Path) -> HashSet<String> {
+    worktree_archive::list_archives(&worktree_archive::default_archive_dir(repo_path))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|archive| archive.branch)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn archive_skips_branches_with_running_sessions() {
+        let active = HashSet::from(["work/busy".to_string()]);
+        let results = archive_project_worktrees(
+            Path::new("/nonexistent/repo"),
+            &["work/busy".to_string()],
+            &active,
+        );
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].status, BranchCleanupResultStatus::Failed);
+        assert!(results[0].message.contains("agent session"));
+    }
+}
@@ -0,0 +1,55 @@
+//! Worktree integrity checks after agent sessions.
+//!
+//! With `[worktree_integrity] verify_after_session = true` in
+//! `~/.gwt/config.toml`, the worktree of every agent session that stops is
+//! checked for the leftovers of an interrupted git command. Problems show up
+//! on the branch row, and `f` on the branch repairs what can be repaired.
+//! The checks themselves live in [`gwt_git::worktree_integrity`].
+
+use std::path::{Path, PathBuf};
+
+use gwt_git::worktree_integrity;
+
+/// Check `worktree` and save the report when the config enables it. Returns
+/// the problems found, or `None` when the check is off.
+pub fn verify_after_session(worktree: &Path) -> Result<Option<Vec<String>>, String> {
+    let enabled = gwt_config::Settings::load()
+        .map(|settings| settings.worktree_integrity.verify_after_session)
+        .unwrap_or(false);
+    if !enabled {
+        return Ok(None);
+    }
+    let report = worktree_integrity::check_worktree_integrity(worktree)
+        .map_err(|error| error.to_string())?;
+    worktree_integrity::save_report(worktree, &report).map_err(|error| error.to_string())?;
+    Ok(Some(
+        report.issues.iter().map(|issue| issue.summary()).collect(),
+    ))
+}
+
+/// Repair the worktree of `branch`. Returns a one-line summary for the
+/// Branches notice.
+pub fn repair_project_worktree(project_root: &Path, branch: &str) -> Result<String, String> {
+    let worktree =
+        branch_worktree(project_root, branch).ok_or_else(|| format!("{branch} has no worktree"))?;
+    let (notes, after) = worktree_integrity::repair_worktree_integrity(&worktree)
+        .map_err(|error| error.to_string())?;
+    let mut message = if notes.is_empty() {
+        format!("{branch}: no integrity problems found")
+    } else {
+        format!("{branch}: {}", notes.join("; "))
+    };
+    if after.is_healthy() && !notes.is_empty() {
+        message.push_str(" — worktree is healthy");
+    }
+    Ok(message)
+}
+
+pub(crate) fn branch_worktree(project_root: &Path, branch: &str) -> Option<PathBuf> {
+    gwt_git::WorktreeManager::new(project_root)
+        .list()
+        .ok()?
+        .into_iter()
+        .find(|worktree| worktree.branch.as_deref() == Some(branch))
+        .map(|worktree| worktree.path)
+}
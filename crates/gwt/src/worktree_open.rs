@@ -0,0 +1,172 @@
+//! Opens a worktree directory in the file manager or an editor for the
+//! Branches list "open" actions.
+//!
+//! The commands come from `[open]` in `~/.gwt/config.toml`; unset entries
+//! fall back to the platform opener and `$VISUAL` / `$EDITOR`. Only
+//! directories that `git worktree list` reports for the project are opened,
+//! so a client cannot use the action to launch programs on arbitrary paths.
+
+use std::path::{Path, PathBuf};
+
+use gwt_config::OpenCommandsConfig;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorktreeOpenTarget {
+    FileManager,
+    Editor,
+}
+
+/// `(program, args)` that opens `path` for `target`. `env` looks up
+/// environment variables (`VISUAL`, `EDITOR`).
+pub fn open_command(
+    target: WorktreeOpenTarget,
+    path: &Path,
+    config: &OpenCommandsConfig,
+    env: impl Fn(&str) -> Option<String>,
+) -> Result<(String, Vec<String>), String> {
+    let configured = match target {
+        WorktreeOpenTarget::FileManager => config.file_manager.clone(),
+        WorktreeOpenTarget::Editor => config
+            .editor
+            .clone()
+            .or_else(|| env("VISUAL"))
+            .or_else(|| env("EDITOR")),
+    };
+    let path = path.display().to_string();
+    if let Some(command) = configured.filter(|command| !command.trim().is_empty()) {
+        let mut words = command.split_whitespace().map(str::to_string);
+        let program = words.next().unwrap_or_default();
+        let mut args: Vec<String> = words.collect();
+        args.push(path);
+        return Ok((program, args));
+    }
+    match target {
+        WorktreeOpenTarget::FileManager => Ok((platform_opener().to_string(), vec![path])),
+        WorktreeOpenTarget::Editor => Err(
+            "No editor configured: set [open] editor in ~/.gwt/config.toml or $EDITOR".to_string(),
+        ),
+    }
+}
+
+fn platform_opener() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "open"
+    } else if cfg!(target_os = "windows") {
+        "explorer"
+    } else {
+        "xdg-open"
+    }
+}
+
+/// Resolve `path` to one of `project_root`'s worktrees.
+pub fn resolve_project_worktree(project_root: &Path, path: &Path) -> Result<PathBuf, String> {
+    let root =
+        gwt_git::worktree::main_worktree_root(project_root).map_err(|error| error.to_string())?;
+    let worktrees = gwt_git::WorktreeManager::new(&root)
+        .list()
+        .map_err(|error| error.to_string())?;
+    let wanted = dunce::canonicalize(path).map_err(|error| error.to_string())?;
+    worktrees
+        .into_iter()
+        .map(|worktree| worktree.path)
+        .find(|candidate| dunce::canonicalize(candidate).is_ok_and(|resolved| resolved == wanted))
+        .ok_or_else(|| format!("{} is not a worktree of this project", path.display()))
+}
+
+/// Open `path`, which must be a worktree of `project_root`, with the command
+/// configured for `target`.
+pub fn open_project_worktree(
+    project_root: &Path,
+    path: &Path,
+    target: WorktreeOpenTarget,
+) -> Result<(), String> {
+    let worktree = resolve_project_worktree(project_root, path)?;
+    let config = gwt_config::Settings::load()
+        .map(|settings| settings.open)
+        .unwrap_or_default();
+    let (program, args) = open_command(target, &worktree, &config, |key| std::env::var(key).ok())?;
+    let child = gwt_core::process::hidden_command(&program)
+        .args(&args)
+        .spawn()
+        .map_err(|error| format!("failed to run {program}: {error}"))?;
+    // Reap on a detached thread so repeated opens do not leave zombies.
+    std::thread::spawn(move || {
+        let mut child = child;
+        let _ = child.wait();
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_command_prefers_config_then_visual_then_editor() {
+        let path = Path::new("/src/work/x");
+        let env = |key: &str| match key {
+            "VISUAL" => Some("zed".to_string()),
+            "EDITOR" => Some("vim".to_string()),
+            _ => None,
+        };
+        let configured = OpenCommandsConfig {
+            file_manager: Some("nautilus --new-window".to_string()),
+            editor: Some("code -n".to_string()),
+        };
+
+        assert_eq!(
+            open_command(WorktreeOpenTarget::Editor, path, &configured, env).unwrap(),
+            (
+                "code".to_string(),
+                vec!["-n".to_string(), "/src/work/x".to_string()]
+            )
+        );
+        assert_eq!(
+            open_command(WorktreeOpenTarget::FileManager, path, &configured, env)
+                .unwrap()
+                .0,
+            "nautilus"
+        );
+        let defaults = OpenCommandsConfig::default();
+        assert_eq!(
+            open_command(WorktreeOpenTarget::Editor, path, &defaults, env)
+                .unwrap()
+                .0,
+            "zed"
+        );
+        assert_eq!(
+            open_command(WorktreeOpenTarget::FileManager, path, &defaults, env)
+                .unwrap()
+                .0,
+            platform_opener()
+        );
+        assert!(open_command(WorktreeOpenTarget::Editor, path, &defaults, |_| None).is_err());
+    }
+
+    #[test]
+    fn resolve_project_worktree_rejects_other_directories() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        for args in [
+            &["init", "--initial-branch=main"][..],
+            &["config", "user.email", "t@example.com"],
+            &["config", "user.name", "T"],
+            &["commit", "--allow-empty", "-m", "init"],
+        ] {
+            let status = gwt_core::process::hidden_command("git")
+                .args(args)
+                .current_dir(&repo)
+                .status()
+                .unwrap();
+            assert!(status.success(), "git {args:?}");
+        }
+
+        assert!(resolve_project_worktree(&repo, &repo).is_ok());
+        let outside = tmp.path().join("outside");
+        std::fs::create_dir_all(&outside).unwrap();
+        assert!(resolve_project_worktree(&repo, &outside).is_err());
+    }
+}
@@ -0,0 +1,96 @@
+//! Branches list stash actions.
+//!
+//! `s` stashes the uncommitted changes of a branch's worktree and `u`
+//! restores the newest stash taken on that branch, so a dirty worktree can be
+//! set aside before another agent is started in it.
+//! Paths are validated with [`crate::worktree_open::resolve_project_worktree`]
+//! like the open actions.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// Message recorded on stashes created from the Branches list.
+pub const BRANCHES_STASH_MESSAGE: &str = "gwt: stashed from Branches";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorktreeStashAction {
+    Stash,
+    Unstash,
+}
+
+/// Run `action` on `path`, which must be a worktree of `project_root`.
+/// Returns a one-line summary for the Branches notice.
+pub fn run_project_worktree_stash(
+    project_root: &Path,
+    path: &Path,
+    action: WorktreeStashAction,
+) -> Result<String, String> {
+    let worktree = crate::worktree_open::resolve_project_worktree(project_root, path)?;
+    run_worktree_stash(&worktree, action)
+}
+
+pub fn run_worktree_stash(worktree: &Path, action: WorktreeStashAction) -> Result<String, String> {
+    match action {
+        WorktreeStashAction::Stash => {
+            let stashed = gwt_git::stash::stash_push(worktree, BRANCHES_STASH_MESSAGE)
+                .map_err(|error| error.to_string())?;
+            Ok(if stashed {
+                format!("Stashed changes in {}", worktree.display())
+            } else {
+                format!("No changes to stash in {}", worktree.display())
+            })
+        }
+        WorktreeStashAction::Unstash => {
+            let entries =
+                gwt_git::stash::stash_list(worktree).map_err(|error| error.to_string())?;
+            let Some(latest) = entries.first() else {
+                return Err(format!("No stash for {}", worktree.display()));
+            };
+            gwt_git::stash::stash_pop(worktree, &latest.reference)
+                .map_err(|error| error.to_string())?;
+            Ok(format!(
+                "Restored \"{}\" in {}",
+                latest.message,
+                worktree.display()
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = gwt_core::process::hidden_command("git")
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {args:?}");
+    }
+
+    #[test]
+    fn stash_then_unstash_round_trips_worktree_changes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let repo = tmp.path().join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--initial-branch=main"]);
+        git(&repo, &["config", "user.email", "t@example.com"]);
+        git(&repo, &["config", "user.name", "T"]);
+        git(&repo, &["commit", "--allow-empty", "-m", "init"]);
+        std::fs::write(repo.join("notes.txt"), "draft\n").unwrap();
+
+        let stashed = run_project_worktree_stash(&repo, &repo, WorktreeStashAction::Stash).unwrap();
+        assert!(stashed.starts_with("Stashed changes"), "{stashed}");
+        assert!(!repo.join("notes.txt").exists());
+
+        let restored =
+            run_project_worktree_stash(&repo, &repo, WorktreeStashAction::Unstash).unwrap();
+        assert!(restored.contains(BRANCHES_STASH_MESSAGE), "{restored}");
+        assert!(repo.join("notes.txt").exists());
+        assert!(run_project_worktree_stash(&repo, &repo, WorktreeStashAction::Unstash).is_err());
+    }
+}
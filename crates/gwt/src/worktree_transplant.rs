@@ -0,0 +1,54 @@
+//! Branches list "start from uncommitted state" action.
+//!
+//! `t` on a branch with a worktree asks for a new branch name, creates that
+//! branch from the branch's HEAD in a sibling worktree and carries its
+//! staged, unstaged and untracked changes over, leaving the original branch
+//! and worktree untouched. The transplant itself lives in
+//! [`gwt_git::worktree_transplant`], shared with `gwtd worktree.transplant`.
+
+use std::path::Path;
+
+use gwt_git::worktree_transplant::{transplant_uncommitted, TransplantOutcome};
+
+/// Start `new_branch` from the uncommitted state of `source_branch` in the
+/// repository at `project_root`. The worktree goes where a launch would put
+/// it.
+pub fn transplant_project_worktree(
+    project_root: &Path,
+    source_branch: &str,
+    new_branch: &str,
+) -> Result<TransplantOutcome, String> {
+    let new_branch = new_branch.trim();
+    if new_branch.is_empty() {
+        return Err("new branch name cannot be empty".to_string());
+    }
+    let main_repo_path = gwt_git::worktree::main_worktree_root(project_root)
+        .unwrap_or_else(|_| project_root.to_path_buf());
+    let max_path_len = gwt_config::Settings::load()
+        .ok()
+        .and_then(|settings| settings.worktree_max_path_len);
+    let worktree_path = gwt_git::worktree::sibling_worktree_path_with_max_len(
+        &main_repo_path,
+        new_branch,
+        max_path_len,
+    );
+    let outcome =
+        transplant_uncommitted(&main_repo_path, source_branch, new_branch, &worktree_path)
+            .map_err(|error| error.to_string())?;
+    gwt_core::activity_bus::publish(gwt_core::activity_bus::ActivityEvent::WorktreeCreated {
+        repo_path: Some(main_repo_path.display().to_string()),
+        branch: Some(outcome.branch.clone()),
+        worktree_path: outcome.worktree_path.display().to_string(),
+        copied_files: Vec::new(),
+    });
+    crate::lifecycle_hooks::emit(
+        crate::lifecycle_hooks::LifecycleEvent::WorktreeCreated,
+        serde_json::json!({
+            "repo_path": main_repo_path.display().to_string(),
+            "branch": outcome.branch,
+            "worktree_path": outcome.worktree_path.display().to_string(),
+            "source_branch": outcome.source_branch,
+        }),
+    );
+    Ok(outcome)
+}
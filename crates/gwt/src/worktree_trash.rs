@@ -0,0 +1,43 @@
+//! Undo for worktree removal.
+//!
+//! Branch cleanup moves removed worktrees into the `.gwt-trash/` staging
+//! area while `[worktree_trash] retention_days` is above zero. The Branches
+//! "Removed" filter lists them and restores one on request; `gwtd
+//! worktree.undo` does the same from the command line. The trash layout and
+//! index live in [`gwt_git::worktree_trash`].
+
+use std::path::Path;
+
+use gwt_git::worktree_trash::{self, TrashedWorktree, WorktreeTrash};
+
+/// Trash for the repository at `repo_path`, or `None` when the retention is
+/// set to zero and removed worktrees are deleted right away.
+pub fn configured_trash(repo_path: &Path) -> Option<WorktreeTrash> {
+    let retention_days = gwt_config::Settings::load()
+        .map(|settings| settings.worktree_trash.retention_days)
+        .unwrap_or_else(|_| gwt_config::WorktreeTrashConfig::default().retention_days);
+    (retention_days > 0).then(|| WorktreeTrash::for_repo(repo_path, retention_days))
+}
+
+/// Removed worktrees of the repository at `repo_path`, most recent first.
+pub fn removed_worktrees(repo_path: &Path) -> Result<Vec<TrashedWorktree>, String> {
+    let mut entries = worktree_trash::list_trashed(&worktree_trash::default_trash_dir(repo_path))
+        .map_err(|error| error.to_string())?;
+    entries.reverse();
+    Ok(entries)
+}
+
+/// Restore the most recently removed worktree of `branch` (of any branch
+/// when `None`). Returns a one-line summary for the Branches notice.
+pub fn undo_worktree_removal(repo_path: &Path, branch: Option<&str>) -> Result<String, String> {
+    let trash_dir = worktree_trash::default_trash_dir(repo_path);
+    worktree_trash::restore_trashed(repo_path, &trash_dir, branch)
+        .map(|entry| {
+            format!(
+                "Restored {} at {}",
+                entry.branch,
+                entry.worktree_path.display()
+            )
+        })
+        .map_err(|error| error.to_string())
+}
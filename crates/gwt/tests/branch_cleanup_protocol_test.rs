@@ -17,11 +17,13 @@ fn run_branch_cleanup_defaults_force_filesystem_delete_to_false() {
             branches,
             delete_remote,
             force_filesystem_delete,
+            confirmed_protected,
         } => {
             assert_eq!(id, "branches-1");
             assert_eq!(branches, vec!["work/old"]);
             assert!(!delete_remote);
             assert!(!force_filesystem_delete);
+            assert!(confirmed_protected.is_empty());
         }
         other => panic!("expected RunBranchCleanup, got {other:?}"),
     }
@@ -5,7 +5,7 @@ use std::{
     time::{Duration, Instant},
 };
 
-use gwt::migration::execute_migration;
+use gwt::migration::{dry_run_migration, execute_migration};
 use gwt_core::config::BareProjectConfig;
 use gwt_core::migration::{MigrationOptions, MigrationPhase, RecoveryState};
 
@@ -110,6 +110,55 @@ fn t100_e2e_normal_to_nested_bare_worktree_layout() {
     assert_eq!(outcome.branch_worktree_path, worktree);
 }
 
+#[test]
+fn dry_run_reports_moves_and_dirty_state_without_touching_the_repo() {
+    let project = tempfile::tempdir().unwrap();
+    init_repo_with_commit(project.path());
+    let branch = current_branch(project.path());
+    let external = tempfile::tempdir().unwrap();
+    let linked = external.path().join("linked");
+    run_git(
+        project.path(),
+        &[
+            "worktree",
+            "add",
+            "-b",
+            "feature/x",
+            linked.to_str().unwrap(),
+        ],
+    );
+    std::fs::write(project.path().join("README.md"), "# edited\n").unwrap();
+
+    let report = dry_run_migration(project.path());
+
+    assert!(report.problems.is_empty(), "{:?}", report.problems);
+    assert!(report.can_migrate());
+    assert!(report.has_dirty());
+    assert!(report.required_bytes >= report.project_bytes);
+    assert!(report.estimated_seconds > 0);
+    let moves: Vec<_> = report
+        .worktrees
+        .iter()
+        .map(|worktree| {
+            (
+                worktree.branch.as_str(),
+                worktree.to.clone(),
+                worktree.is_dirty,
+            )
+        })
+        .collect();
+    assert_eq!(
+        moves,
+        vec![
+            (branch.as_str(), project.path().join(&branch), true),
+            ("feature/x", project.path().join("feature").join("x"), false),
+        ]
+    );
+    assert!(project.path().join(".git").is_dir());
+    assert!(!report.bare_repo_path.exists());
+    assert!(!project.path().join(".gwt-migration-backup").exists());
+}
+
 #[test]
 fn t143_e2e_remote_ahead_migration_preserves_local_head() {
     // A real copied Workbench smoke exposed that cloning origin during
@@ -0,0 +1,176 @@
+//! End-to-end tests for the reverse Bare+Worktree → Normal migration.
+
+use std::path::{Path, PathBuf};
+
+use gwt::migration::{execute_migration, execute_reverse_migration};
+use gwt_core::config::BareProjectConfig;
+use gwt_core::migration::{
+    MigrationOptions, MigrationPhase, RecoveryState, ReverseMigrationOptions,
+};
+
+fn run_git(dir: &Path, args: &[&str]) {
+    let output = gwt_core::process::hidden_command("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("git");
+    assert!(
+        output.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn git_stdout(dir: &Path, args: &[&str]) -> String {
+    let output = gwt_core::process::hidden_command("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("git");
+    assert!(
+        output.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// A migrated project on `develop` with a `feature/x` worktree next to it.
+/// Returns the bare repository path.
+fn bare_project(project: &Path) -> PathBuf {
+    run_git(project, &["init", "-b", "develop", "."]);
+    run_git(project, &["config", "user.email", "test@example.com"]);
+    run_git(project, &["config", "user.name", "Test"]);
+    std::fs::write(project.join("README.md"), "# sample\n").unwrap();
+    run_git(project, &["add", "README.md"]);
+    run_git(project, &["commit", "-m", "init"]);
+    run_git(project, &["branch", "feature/x"]);
+
+    let outcome = execute_migration(project, MigrationOptions::default(), |_, _| {})
+        .expect("execute_migration");
+    let feature = project.join("feature").join("x");
+    run_git(
+        &outcome.bare_repo_path,
+        &["worktree", "add", feature.to_str().unwrap(), "feature/x"],
+    );
+    outcome.bare_repo_path
+}
+
+#[test]
+fn reverse_migration_restores_a_normal_repo_with_primary_changes() {
+    let project = tempfile::tempdir().unwrap();
+    let bare = bare_project(project.path());
+    let develop = project.path().join("develop");
+    std::fs::write(develop.join("README.md"), "# edited\n").unwrap();
+    std::fs::write(develop.join("notes.txt"), "draft\n").unwrap();
+
+    let mut phases = Vec::new();
+    let outcome = execute_reverse_migration(
+        project.path(),
+        ReverseMigrationOptions::default(),
+        |phase, _| phases.push(phase),
+    )
+    .expect("execute_reverse_migration");
+
+    assert_eq!(outcome.primary_branch, "develop");
+    assert_eq!(outcome.removed_worktrees.len(), 1);
+    assert!(project.path().join(".git").is_dir());
+    assert!(!bare.exists());
+    assert!(!develop.exists());
+    assert!(!project.path().join("feature").exists());
+    assert!(BareProjectConfig::load(project.path()).unwrap().is_none());
+    assert_eq!(
+        std::fs::read_to_string(project.path().join("README.md")).unwrap(),
+        "# edited\n"
+    );
+    assert_eq!(
+        git_stdout(project.path(), &["rev-parse", "--is-bare-repository"]),
+        "false"
+    );
+    assert_eq!(
+        git_stdout(project.path(), &["symbolic-ref", "--short", "HEAD"]),
+        "develop"
+    );
+    assert_eq!(
+        git_stdout(project.path(), &["status", "--porcelain"]),
+        "M README.md\n?? notes.txt"
+    );
+    assert_eq!(
+        git_stdout(project.path(), &["worktree", "list", "--porcelain"])
+            .lines()
+            .filter(|line| line.starts_with("worktree "))
+            .count(),
+        1
+    );
+    git_stdout(project.path(), &["rev-parse", "--verify", "feature/x"]);
+    assert!(phases.contains(&MigrationPhase::Unbare));
+    assert_eq!(phases.last(), Some(&MigrationPhase::Done));
+}
+
+#[test]
+fn reverse_migration_checks_out_a_primary_branch_without_a_worktree() {
+    let project = tempfile::tempdir().unwrap();
+    let bare = bare_project(project.path());
+    let feature = project.path().join("feature").join("x");
+    run_git(&bare, &["worktree", "remove", feature.to_str().unwrap()]);
+    run_git(&project.path().join("develop"), &["branch", "release"]);
+
+    let outcome = execute_reverse_migration(
+        project.path(),
+        ReverseMigrationOptions {
+            primary_branch: Some("release".to_string()),
+            ..Default::default()
+        },
+        |_, _| {},
+    )
+    .expect("execute_reverse_migration");
+
+    assert_eq!(outcome.primary_branch, "release");
+    assert!(project.path().join("README.md").is_file());
+    assert_eq!(
+        git_stdout(project.path(), &["symbolic-ref", "--short", "HEAD"]),
+        "release"
+    );
+    assert_eq!(git_stdout(project.path(), &["status", "--porcelain"]), "");
+}
+
+#[test]
+fn reverse_migration_refuses_dirty_sibling_worktrees_untouched() {
+    let project = tempfile::tempdir().unwrap();
+    let bare = bare_project(project.path());
+    let feature = project.path().join("feature").join("x");
+    std::fs::write(feature.join("wip.txt"), "wip\n").unwrap();
+
+    let err = execute_reverse_migration(
+        project.path(),
+        ReverseMigrationOptions::default(),
+        |_, _| {},
+    )
+    .expect_err("dirty sibling worktree");
+
+    assert_eq!(err.phase, MigrationPhase::Validate);
+    assert_eq!(err.recovery, RecoveryState::Untouched);
+    assert!(
+        err.message.contains("uncommitted changes"),
+        "{}",
+        err.message
+    );
+    assert!(bare.is_dir());
+    assert!(feature.join("wip.txt").is_file());
+}
+
+#[test]
+fn reverse_migration_rejects_a_normal_repository() {
+    let project = tempfile::tempdir().unwrap();
+    run_git(project.path(), &["init", "."]);
+
+    let err = execute_reverse_migration(
+        project.path(),
+        ReverseMigrationOptions::default(),
+        |_, _| {},
+    )
+    .expect_err("normal repository");
+
+    assert_eq!(err.phase, MigrationPhase::Validate);
+    assert_eq!(err.recovery, RecoveryState::Untouched);
+}
@@ -524,3 +524,61 @@ test("Workspace-owned cleanup result re-renders the modal even without a Branche
   assert.match(dialogEl.textContent, /Deleted local branch/);
   assert.equal(workspaceRenderCount, 1);
 });
+
+test("Branches path column toggles and row keys copy or open the worktree", async () => {
+  const { createBranchesCleanupSurface } = await loadBranchesCleanupSurfaceForTest();
+  const { document, modalEl, dialogEl, createNode } = mount();
+  const sent = [];
+  const copied = [];
+  const windowId = "branches-window";
+  const windowEl = document.createElement("section");
+  const body = document.createElement("div");
+  windowEl.appendChild(body);
+  const surface = createBranchesCleanupSurface({
+    send: (message) => sent.push(message),
+    createNode,
+    windowMap: new Map([[windowId, windowEl]]),
+    focusWindowLocally() {},
+    sendWindowFocus() {},
+    branchCleanupModal: modalEl,
+    branchCleanupDialog: dialogEl,
+    launchPending: { settleWhere() {} },
+    visibleBounds: () => ({}),
+    getActiveWorkProjection: () => null,
+    renderWorkspaceWindows() {},
+    writeClipboardText: async (text) => {
+      copied.push(text);
+      return true;
+    },
+  });
+  surface.mountBranchesWindow({ id: windowId }, body);
+  const state = surface.ensureBranchListState(windowId);
+  state.loading = false;
+  state.entries = [
+    { name: "work/x", scope: "local", worktree_path: "/src/work/x", cleanup: {} },
+    { name: "main", scope: "local", worktree_path: null, cleanup: {} },
+  ];
+  surface.renderBranches(windowId);
+
+  const path = body.querySelector(".branch-row .branch-path");
+  assert.equal(path.hidden, true);
+  body.querySelector("[data-action='toggle-branch-path']").click();
+  assert.equal(path.hidden, false);
+  assert.equal(path.textContent, "/src/work/x");
+
+  const row = body.querySelector(".branch-row");
+  const press = (key) =>
+    row.dispatchEvent(new document.defaultView.KeyboardEvent("keydown", { key }));
+  press("o");
+  assert.deepEqual(sent.at(-1), {
+    kind: "open_worktree_path",
+    id: windowId,
+    path: "/src/work/x",
+    target: "file_manager",
+  });
+  press("e");
+  assert.equal(sent.at(-1).target, "editor");
+  press("c");
+  await new Promise((resolve) => setTimeout(resolve, 0));
+  assert.deepEqual(copied, ["/src/work/x"]);
+});
@@ -15,6 +15,8 @@ import {
   branchWindowNeedsResync,
   applyBranchEntriesEvent,
   branchLoadStatusSummary,
+  branchRenderWindow,
+  unhydratedBranchNames,
 } from "../branch-list-state.js";
 
 const hydratedEntry = (name, availability) => ({
@@ -157,3 +159,78 @@ test("branchLoadStatusSummary interrupted copy self-heals and never says 'Refres
   assert.doesNotMatch(`${summary.detail} ${summary.hint}`, /Refresh to verify/i);
   assert.match(`${summary.hint}`, /automatically|no refresh/i);
 });
+
+test("applyBranchEntriesEvent lazy phase shows rows without a loading state", () => {
+  const state = { entries: [], lastLoadId: 0, loading: true };
+  const res = applyBranchEntriesEvent(state, {
+    phase: "lazy",
+    load_id: 3,
+    entries: [inventoryEntry("work/a"), inventoryEntry("work/b")],
+  });
+  assert.equal(res.applied, true);
+  assert.equal(state.loading, false);
+  assert.equal(state.phase, "lazy");
+  assert.equal(branchLoadStatusSummary(state).kind, "lazy");
+});
+
+test("applyBranchEntriesEvent partial phase merges only the checked rows", () => {
+  const state = { entries: [], lastLoadId: 0 };
+  applyBranchEntriesEvent(state, {
+    phase: "lazy",
+    load_id: 4,
+    entries: [inventoryEntry("work/a"), inventoryEntry("work/b")],
+  });
+  const res = applyBranchEntriesEvent(state, {
+    phase: "partial",
+    load_id: 4,
+    entries: [hydratedEntry("work/b", "safe")],
+  });
+  assert.equal(res.applied, true);
+  assert.equal(state.phase, "lazy");
+  assert.equal(state.entries.length, 2);
+  assert.equal(state.entries[0].cleanup_ready, false);
+  assert.equal(state.entries[1].cleanup_ready, true);
+  assert.equal(state.entries[1].cleanup.availability, "safe");
+  assert.equal(state.lastHydratedByName.get("work/b").availability, "safe");
+
+  const stale = applyBranchEntriesEvent(state, {
+    phase: "partial",
+    load_id: 2,
+    entries: [hydratedEntry("work/a", "safe")],
+  });
+  assert.equal(stale.applied, false);
+  assert.equal(state.entries[0].cleanup_ready, false);
+});
+
+test("branchRenderWindow renders short lists whole and windows long ones", () => {
+  assert.deepEqual(branchRenderWindow({ total: 40, scrollTop: 500, viewportHeight: 400 }), {
+    start: 0,
+    end: 40,
+    virtualized: false,
+  });
+  const window = branchRenderWindow({
+    total: 1000,
+    scrollTop: 5000,
+    viewportHeight: 500,
+    rowHeight: 50,
+    overscan: 10,
+  });
+  assert.deepEqual(window, { start: 90, end: 120, virtualized: true });
+  const bottom = branchRenderWindow({
+    total: 1000,
+    scrollTop: 100000,
+    viewportHeight: 500,
+    rowHeight: 50,
+    overscan: 10,
+  });
+  assert.equal(bottom.end, 1000);
+  assert.ok(bottom.start < bottom.end);
+});
+
+test("unhydratedBranchNames covers the window plus prefetch rows", () => {
+  const entries = Array.from({ length: 10 }, (_, index) =>
+    index === 4 ? hydratedEntry(`work/${index}`, "safe") : inventoryEntry(`work/${index}`),
+  );
+  assert.deepEqual(unhydratedBranchNames(entries, 3, 6, 1), ["work/2", "work/3", "work/5", "work/6"]);
+  assert.deepEqual(unhydratedBranchNames(entries, 0, 2, 0), ["work/0", "work/1"]);
+});
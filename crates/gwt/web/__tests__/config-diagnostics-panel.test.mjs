@@ -0,0 +1,81 @@
+// Settings banner for `~/.gwt/config.toml` validation problems.
+
+import assert from "node:assert/strict";
+import { test } from "node:test";
+
+import {
+  configDiagnosticsSummary,
+  formatConfigDiagnostic,
+  renderConfigDiagnostics,
+} from "../config-diagnostics-panel.js";
+
+function fakeElement() {
+  return {
+    hidden: false,
+    dataset: {},
+    className: "",
+    textContent: "",
+    children: [],
+    get firstChild() {
+      return this.children[0] ?? null;
+    },
+    appendChild(node) {
+      this.children.push(node);
+      return node;
+    },
+    removeChild(node) {
+      this.children = this.children.filter((child) => child !== node);
+    },
+  };
+}
+
+const document = { createElement: () => fakeElement() };
+
+test("diagnostics format like gwt config validate", () => {
+  assert.equal(
+    formatConfigDiagnostic({
+      severity: "warning",
+      key: "agent.check_api_key",
+      message: "unknown key; it is ignored",
+      line: 5,
+      column: 1,
+    }),
+    "5:1: warning: agent.check_api_key: unknown key; it is ignored",
+  );
+  assert.equal(
+    formatConfigDiagnostic({ severity: "warning", key: "voice", message: "hotkey empty" }),
+    "warning: voice: hotkey empty",
+  );
+  assert.match(
+    configDiagnosticsSummary([{ severity: "error" }, { severity: "warning" }]),
+    /^1 error, 1 warning — gwt is using the default settings/,
+  );
+  assert.match(
+    configDiagnosticsSummary([{ severity: "warning" }, { severity: "warning" }]),
+    /^2 warnings — the listed keys are ignored/,
+  );
+});
+
+test("banner lists diagnostics and hides when the file is clean", () => {
+  const container = fakeElement();
+  renderConfigDiagnostics({
+    container,
+    document,
+    path: "/home/me/.gwt/config.toml",
+    diagnostics: [
+      { severity: "error", message: "invalid type", line: 1, column: 9 },
+    ],
+  });
+  assert.equal(container.hidden, false);
+  assert.equal(container.dataset.kind, "error");
+  const [summary, list] = container.children;
+  assert.match(summary.textContent, /^\/home\/me\/\.gwt\/config\.toml: 1 error/);
+  assert.deepEqual(
+    list.children.map((item) => item.textContent),
+    ["1:9: error: invalid type"],
+  );
+
+  renderConfigDiagnostics({ container, document, diagnostics: [] });
+  assert.equal(container.hidden, true);
+  assert.equal(container.children.length, 0);
+});
@@ -11,6 +11,8 @@ const tokensCss = readFileSync(tokensPath, "utf8");
 const appCss = readFileSync(resolve(here, "../styles/app.css"), "utf8");
 const dark = extractTokens(tokensCss, "dark");
 const light = extractTokens(tokensCss, "light");
+const solarizedDark = { ...dark, ...extractTokens(tokensCss, "dark", "solarized") };
+const solarizedLight = { ...light, ...extractTokens(tokensCss, "light", "solarized") };
 
 const NORMAL_AA = 4.5;
 const LARGE_AA = 3.0;
@@ -265,6 +267,43 @@ for (const themeName of ["dark", "light"]) {
   }
 }
 
+// Built-in palettes re-skin the flagship sets, so every palette variant must
+// clear the same text, state, strip, and focus-ring thresholds.
+const PALETTE_VARIANTS = [
+  ["solarized dark", solarizedDark],
+  ["solarized light", solarizedLight],
+];
+
+for (const [variantName, theme] of PALETTE_VARIANTS) {
+  const pairs = [
+    ...REQUIRED_PAIRS,
+    ...STATE_TEXT_TOKENS.flatMap((token) =>
+      SURFACE_BACKGROUNDS.map((bg) => [token, bg, NORMAL_AA, `${token} text on ${bg}`]),
+    ),
+    ...FOCUS_RING_BACKGROUNDS.map((bg) => [
+      "--color-focus-ring",
+      bg,
+      LARGE_AA,
+      `focus ring on ${bg}`,
+    ]),
+  ];
+  for (const [fgName, bgName, threshold, label] of pairs) {
+    test(`[${variantName}] contrast >= ${threshold}: ${label}`, () => {
+      const ratio = contrastRatio(theme[fgName], theme[bgName]);
+      assert.ok(
+        ratio >= threshold,
+        `${label}: contrast ${ratio.toFixed(2)} < ${threshold} (fg=${theme[fgName]} on bg=${theme[bgName]})`,
+      );
+    });
+  }
+  test(`[${variantName}] WCAG AA: status strip palette on strip bg`, () => {
+    for (const [stateName, color] of Object.entries(STRIP_PALETTE)) {
+      const ratio = contrastRatio(color, theme["--color-status-strip-bg"]);
+      assert.ok(ratio >= NORMAL_AA, `strip ${stateName}: ${ratio.toFixed(2)} < ${NORMAL_AA}`);
+    }
+  });
+}
+
 test("dark and light token sets define identical semantic keys", () => {
   const darkKeys = Object.keys(dark).sort();
   const lightKeys = Object.keys(light).sort();
@@ -360,12 +399,15 @@ function windowTabActiveBodies(css) {
   return bodies;
 }
 
-function extractTokens(css, themeName) {
-  const blockRegex = themeName === "dark"
-    ? /:root\[data-theme="dark"\]\s*\{([\s\S]*?)\}/
-    : /:root\[data-theme="light"\]\s*\{([\s\S]*?)\}/;
+function extractTokens(css, themeName, palette = null) {
+  const selector = palette
+    ? `:root[data-theme="${themeName}"][data-palette="${palette}"]`
+    : `:root[data-theme="${themeName}"]`;
+  const blockRegex = new RegExp(
+    `${selector.replace(/[[\]]/g, "\\$&")}\\s*\\{([\\s\\S]*?)\\}`,
+  );
   const match = css.match(blockRegex);
-  assert.ok(match, `tokens.css must contain a :root[data-theme="${themeName}"] block`);
+  assert.ok(match, `tokens.css must contain a ${selector} block`);
   const tokens = {};
   for (const line of match[1].split("\n")) {
     const m = line.match(/^\s*(--[a-z][a-z0-9-]*)\s*:\s*([^;]+);/);
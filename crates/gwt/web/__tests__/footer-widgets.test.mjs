@@ -0,0 +1,90 @@
+// `[[footer.widgets]]` Status Strip widgets: local rendering, backend
+// refresh requests, and per-widget timers.
+
+import assert from "node:assert/strict";
+import { test } from "node:test";
+
+import { createFooterWidgets, footerWidgetText } from "../footer-widgets.js";
+
+function fakeElement() {
+  return {
+    hidden: false,
+    title: "",
+    dataset: {},
+    children: [],
+    textContent: "",
+    append(...nodes) {
+      this.children.push(...nodes);
+    },
+    replaceChildren(...nodes) {
+      this.children = nodes;
+    },
+  };
+}
+
+function setup() {
+  const cell = fakeElement();
+  const doc = {
+    getElementById: (id) => (id === "op-strip-widgets" ? cell : null),
+    createElement: () => fakeElement(),
+  };
+  const sent = [];
+  const timers = [];
+  const cleared = [];
+  const widgets = createFooterWidgets({
+    doc,
+    send: (message) => sent.push(message),
+    now: () => new Date(2026, 0, 1, 9, 5),
+    setTimer: (fn, ms) => timers.push({ fn, ms }) - 1,
+    clearTimer: (id) => cleared.push(id),
+  });
+  const shown = () =>
+    cell.children.map((item) => item.children.map((part) => part.textContent).join(" "));
+  return { cell, sent, timers, cleared, widgets, shown };
+}
+
+const CONFIG = [
+  { index: 0, kind: "clock", label: "TIME", command: null, refresh_secs: 30 },
+  { index: 2, kind: "command", label: "K8S", command: "kubectl config current-context", refresh_secs: 60 },
+  { index: 3, kind: "running_agents", label: "AGENTS", command: null, refresh_secs: 5 },
+];
+
+test("clock and running agents render locally; others wait for the backend", () => {
+  assert.equal(footerWidgetText(CONFIG[0], { now: new Date(2026, 0, 1, 7, 3) }), "07:03");
+  assert.equal(footerWidgetText(CONFIG[2], { runningAgents: 4 }), "4");
+  assert.equal(footerWidgetText(CONFIG[1], { values: new Map() }), "…");
+  assert.equal(
+    footerWidgetText(CONFIG[1], { values: new Map([[2, { value: null, error: "exited" }]]) }),
+    "—",
+  );
+});
+
+test("widgets render in config order and poll remote kinds on their interval", () => {
+  const { cell, sent, timers, widgets, shown } = setup();
+  widgets.apply({ widgets: CONFIG });
+
+  assert.equal(cell.hidden, false);
+  assert.deepEqual(shown(), ["TIME 09:05", "K8S …", "AGENTS 0"]);
+  assert.deepEqual(sent, [{ kind: "refresh_footer_widget", index: 2 }]);
+  assert.deepEqual(timers.map((timer) => timer.ms), [30000, 60000, 5000]);
+
+  timers[1].fn();
+  assert.equal(sent.length, 2);
+
+  widgets.applyValue({ index: 2, value: "prod", error: null });
+  widgets.setRunningAgents(3);
+  assert.deepEqual(shown(), ["TIME 09:05", "K8S prod", "AGENTS 3"]);
+  assert.equal(cell.children[1].title, "kubectl config current-context");
+
+  widgets.applyValue({ index: 2, value: null, error: "timed out after 10s" });
+  assert.equal(cell.children[1].title, "K8S: timed out after 10s");
+});
+
+test("reapplying the config clears old timers and an empty list hides the cell", () => {
+  const { cell, cleared, widgets } = setup();
+  widgets.apply({ widgets: CONFIG });
+  widgets.apply({ widgets: [] });
+  assert.deepEqual(cleared, [0, 1, 2]);
+  assert.equal(cell.hidden, true);
+  assert.equal(cell.children.length, 0);
+});
@@ -1,6 +1,6 @@
 import { test } from "node:test";
 import assert from "node:assert/strict";
-import { createHotkeyManager, parseCombo, comboMatches } from "../hotkey.js";
+import { createHotkeyManager, parseCombo, comboMatches, formatCombo } from "../hotkey.js";
 
 test("parseCombo recognises platform modifiers", () => {
   assert.deepEqual(parseCombo("cmd+p"), { mod: true, alt: false, shift: false, key: "p" });
@@ -82,6 +82,43 @@ test("dispatch ignores events targeted at editable surfaces", () => {
   assert.equal(called, 1);
 });
 
+test("formatCombo renders display labels", () => {
+  assert.equal(formatCombo("cmd+k"), "⌘ K");
+  assert.equal(formatCombo("cmd+shift+/"), "⌘ ?");
+  assert.equal(formatCombo("shift+enter"), "⇧ Enter");
+  assert.equal(formatCombo("escape"), "Esc");
+});
+
+test("scoped bindings only fire and describe on their screen", () => {
+  let scope = "canvas";
+  const mgr = createHotkeyManager({ currentScope: () => scope });
+  let closed = 0;
+  mgr.register("cmd+k", () => true, { label: "Command palette", group: "Navigation" });
+  mgr.register("escape", () => { closed += 1; return true; }, { label: "Close", scope: "board" });
+  mgr.declare("shift+enter", { label: "Post entry", scope: "board" });
+  mgr.register("cmd+b", () => true);
+
+  assert.equal(mgr.dispatch(mockKey({ key: "Escape" })), false);
+  assert.deepEqual(mgr.bindingsFor().map((b) => b.label), ["Command palette"]);
+
+  scope = "board";
+  assert.equal(mgr.dispatch(mockKey({ key: "Escape" })), true);
+  assert.equal(closed, 1);
+  assert.equal(mgr.dispatch(mockKey({ shiftKey: true, key: "Enter" })), false);
+  assert.deepEqual(
+    mgr.bindingsFor().map((b) => [b.label, b.keys, b.scope]),
+    [
+      ["Command palette", "⌘ K", "global"],
+      ["Close", "Esc", "board"],
+      ["Post entry", "⇧ Enter", "board"],
+    ],
+  );
+  // The same combo may be bound on different screens.
+  mgr.register("escape", () => true, { scope: "logs" });
+  mgr.unregister("escape", { scope: "board" });
+  assert.equal(mgr.dispatch(mockKey({ key: "Escape" })), false);
+});
+
 function mockKey({ metaKey = false, ctrlKey = false, altKey = false, shiftKey = false, key, target = { tagName: "BODY" } } = {}) {
   const e = {
     metaKey, ctrlKey, altKey, shiftKey, key, target,
@@ -92,3 +129,28 @@ function mockKey({ metaKey = false, ctrlKey = false, altKey = false, shiftKey =
   };
   return e;
 }
+
+test("rebind moves action bindings and can swap or unbind them", () => {
+  const mgr = createHotkeyManager();
+  const hits = [];
+  mgr.register("cmd+k", () => { hits.push("palette"); return true; }, { label: "Palette", action: "command_palette" });
+  mgr.register("cmd+b", () => { hits.push("board"); return true; }, { label: "Board", action: "open_board" });
+  mgr.register("cmd+l", () => true, { label: "Logs", action: "open_logs" });
+
+  const errors = mgr.rebind([
+    { action: "command_palette", combo: "cmd+b" },
+    { action: "open_board", combo: "cmd+k" },
+    { action: "open_logs", combo: null },
+    { action: "missing", combo: "cmd+m" },
+  ]);
+
+  assert.deepEqual(errors, ["unknown hotkey action: missing"]);
+  mgr.dispatch({ metaKey: true, key: "b" });
+  assert.deepEqual(hits, ["palette"]);
+  assert.equal(mgr.comboFor("open_board"), "cmd+k");
+  assert.equal(mgr.comboFor("open_logs"), null);
+  assert.deepEqual(mgr.bindingsFor().map((b) => b.label).sort(), ["Board", "Palette"]);
+
+  assert.equal(mgr.rebind([{ action: "open_logs", combo: "cmd+b" }]).length, 1);
+  assert.equal(mgr.comboFor("open_logs"), null);
+});
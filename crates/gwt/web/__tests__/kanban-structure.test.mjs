@@ -72,12 +72,12 @@ test("Knowledge windows install periodic cache-first refresh for external phase
   );
   assert.match(
     appSource,
-    /setInterval[\s\S]{0,900}?requestKnowledgeBridge\(\s*windowId,\s*knowledgeKind,\s*false\s*\)/,
+    /function ensureKnowledgeAutoRefresh[\s\S]{0,900}?requestKnowledgeBridge\(\s*windowId,\s*knowledgeKind,\s*false\s*\)/,
     "expected auto-refresh to stay cache-first instead of forcing remote sync",
   );
   assert.doesNotMatch(
     appSource,
-    /setInterval[\s\S]{0,900}?requestKnowledgeBridge\(\s*windowId,\s*knowledgeKind,\s*true\s*\)/,
+    /function ensureKnowledgeAutoRefresh[\s\S]{0,900}?requestKnowledgeBridge\(\s*windowId,\s*knowledgeKind,\s*true\s*\)/,
     "auto-refresh must not surface GitHub auth failures while cached entries are visible",
   );
   assert.match(
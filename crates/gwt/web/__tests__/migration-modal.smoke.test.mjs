@@ -10,7 +10,11 @@ import { fileURLToPath } from "node:url";
 import { dirname, resolve } from "node:path";
 import { parseHTML } from "linkedom";
 
-import { renderMigrationModal } from "../migration-modal.js";
+import {
+  formatBytes,
+  formatDuration,
+  renderMigrationModal,
+} from "../migration-modal.js";
 
 const here = dirname(fileURLToPath(import.meta.url));
 const indexHtmlPath = resolve(here, "..", "index.html");
@@ -119,6 +123,68 @@ test("confirm stage disables Migrate when locked worktrees are present", () => {
   assert.equal(migrate.disabled, true);
 });
 
+test("confirm stage renders the dry-run report and blocks Migrate on problems", () => {
+  const { modalEl, dialogEl, createNode } = mount();
+  renderMigrationModal({
+    modalEl,
+    dialogEl,
+    state: makeState({
+      report: {
+        bare_repo_path: "/work/gwt/gwt.git",
+        remote_url: null,
+        worktrees: [
+          {
+            branch: "develop",
+            from: "/work/gwt",
+            to: "/work/gwt/develop",
+            is_main_repo: true,
+            is_dirty: true,
+            is_locked: false,
+          },
+          {
+            branch: "feature/x",
+            from: "/work/gwt-x",
+            to: "/work/gwt/feature/x",
+            is_main_repo: false,
+            is_dirty: false,
+            is_locked: false,
+          },
+        ],
+        project_bytes: 1024,
+        required_bytes: 2048,
+        available_bytes: 1024,
+        estimated_seconds: 5,
+        problems: ["insufficient disk space: required 2048 bytes, available 1024 bytes"],
+      },
+    }),
+    createNode,
+    onMigrate: noop,
+    onClose: noop,
+  });
+
+  const report = dialogEl.querySelector(".migration-modal-report");
+  assert.ok(report, "dry-run section must render");
+  const rows = report.querySelectorAll(".migration-modal-worktrees tr");
+  assert.equal(rows.length, 3, "header row plus one row per worktree");
+  assert.match(rows[1].textContent, /\/work\/gwt\/develop/);
+  assert.match(rows[1].textContent, /uncommitted changes/);
+  assert.match(report.textContent, /2\.0 KB needed, 1\.0 KB free/);
+  assert.match(report.textContent, /about 5 s/);
+  assert.equal(report.querySelectorAll(".migration-modal-problems li").length, 1);
+  const migrate = Array.from(dialogEl.querySelectorAll("button")).find(
+    (b) => b.textContent === "Migrate",
+  );
+  assert.equal(migrate.disabled, true);
+});
+
+test("report formatters render sizes and durations", () => {
+  assert.equal(formatBytes(512), "512 B");
+  assert.equal(formatBytes(3 * 1024 * 1024 * 1024), "3.0 GB");
+  assert.equal(formatDuration(0), "about 1 s");
+  assert.equal(formatDuration(150), "about 3 min");
+  assert.equal(formatDuration(undefined), "unknown");
+});
+
 test("running stage shows phase label and progress bar", () => {
   const { modalEl, dialogEl, createNode } = mount();
   renderMigrationModal({
@@ -5576,3 +5576,16 @@ test("app.css must not redeclare display for the Workspace overview shell", () =
   const shellBlock = componentsCss.match(/\.workspace-overview-shell\s*\{[^}]*\}/)?.[0] ?? "";
   assert.match(shellBlock, /display\s*:\s*grid/, "components.css owns the grid layout");
 });
+
+test("Profile rows mark repository-shared profiles and user overrides", () => {
+  assert.match(profileWindowSurfaceSource, /profile\.origin === "repo"/);
+  assert.match(profileWindowSurfaceSource, /profile\.origin === "user_overrides_repo"/);
+  assert.match(profileWindowSurfaceSource, /snapshot\.repo_errors/);
+  assert.match(inlineStyle, /\.profile-chip\.shared/);
+});
+
+test("Profile env rows can keep values in the OS keychain", () => {
+  assert.match(profileWindowSurfaceSource, /\["secret", "Secret"\]/);
+  assert.match(profileWindowSurfaceSource, /secret_env: payload\.secretEnv/);
+  assert.match(profileWindowSurfaceSource, /valueInput\.type = isSecret \? "password" : "text"/);
+});
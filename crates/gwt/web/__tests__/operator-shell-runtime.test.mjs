@@ -110,6 +110,39 @@ test("Operator shell keeps the Command Rail always visible with no reveal state
   }
 });
 
+test("Hotkey overlay and Status Strip hints follow the focused screen", async () => {
+  const { initOperatorShell, resolveHotkeyScope } = await importOperatorShell();
+  const { document, window } = parseHTML(html);
+  const shell = initOperatorShell({ document, window });
+  const overlay = document.getElementById("op-hotkey-overlay");
+
+  assert.equal(resolveHotkeyScope(document), "canvas");
+  const hints = document.getElementById("op-strip-hints");
+  assert.match(hints.textContent, /Command palette/);
+  assert.match(hints.textContent, /\? Help/);
+
+  const boardWindow = document.createElement("div");
+  boardWindow.className = "workspace-window focused";
+  boardWindow.dataset.preset = "board";
+  document.body.appendChild(boardWindow);
+  assert.equal(resolveHotkeyScope(document), "board");
+
+  const toggle = { key: "?", shiftKey: true, target: document.body, preventDefault() {}, stopPropagation() {} };
+  globalThis.Element ??= window.Element;
+  assert.equal(shell.hotkey.dispatch(toggle), true);
+  assert.equal(overlay.dataset.open, "true");
+  assert.equal(document.getElementById("op-hotkey-scope").textContent, "Board");
+  const rows = Array.from(overlay.querySelectorAll("#op-hotkey-bindings .op-hotkey-card__row span"))
+    .map((el) => el.textContent);
+  assert.ok(rows.includes("Post entry"), `board bindings missing: ${rows.join("/")}`);
+  assert.ok(rows.includes("Command palette"), "global bindings stay listed");
+  assert.ok(!rows.includes("Open result"), "other screens' bindings are hidden");
+  assert.equal(overlay.querySelector('[data-hotkey-scope="canvas"]').hidden, true);
+
+  assert.equal(shell.hotkey.dispatch(toggle), true);
+  assert.equal(overlay.dataset.open, undefined);
+});
+
 test("FR-047 (anshin): MISSION cell shows done/total and converges to complete", async () => {
   const { applyTelemetryCounts } = await importOperatorShell();
   assert.equal(typeof applyTelemetryCounts, "function");
@@ -729,3 +762,38 @@ async function importOperatorShell() {
   writeFileSync(tmpModule, source);
   return import(pathToFileURL(tmpModule).href);
 }
+
+test("AI queue cell counts jobs and cancels them from the detail list", async () => {
+  const { applyAiQueueStatus } = await importOperatorShell();
+  const { document, window } = parseHTML(html);
+  const cancelled = [];
+  const options = { cancelJob: (id) => cancelled.push(id) };
+
+  const cell = document.getElementById("op-strip-ai-queue");
+  applyAiQueueStatus(document, { running: 0, queued: 0, jobs: [] }, options);
+  assert.equal(cell?.hidden, true, "an empty queue hides the cell");
+
+  applyAiQueueStatus(
+    document,
+    {
+      running: 1,
+      queued: 1,
+      jobs: [
+        { id: 7, label: "Commit message suggestion", priority: "interactive", state: "running", cancelled: false },
+        { id: 8, label: "Work summaries (3 branches)", priority: "background", state: "queued", cancelled: false },
+      ],
+    },
+    options,
+  );
+  assert.equal(cell?.hidden, false);
+  assert.equal(document.getElementById("op-strip-ai-queue-value")?.textContent, "1 run 1 queued");
+
+  cell?.dispatchEvent(new window.Event("click", { bubbles: true }));
+  const detail = document.getElementById("op-ai-queue-detail");
+  assert.equal(detail?.hidden, false);
+  const rows = detail?.querySelectorAll(".op-ai-queue-detail__job") ?? [];
+  assert.equal(rows.length, 2);
+  assert.match(rows[1]?.textContent ?? "", /queued · background/);
+  rows[1]?.querySelector("button")?.dispatchEvent(new window.Event("click", { bubbles: true }));
+  assert.deepEqual(cancelled, [8]);
+});
@@ -0,0 +1,38 @@
+import assert from "node:assert/strict";
+import test from "node:test";
+
+import {
+  dataTransferMayCarryFolder,
+  droppedFolderPaths,
+  fileUriToPath,
+} from "../project-folder-drop.js";
+
+function uriListTransfer(text) {
+  return {
+    types: ["Files", "text/uri-list"],
+    getData: (type) => (type === "text/uri-list" ? text : ""),
+  };
+}
+
+test("fileUriToPath decodes local, drive-letter and UNC file URIs", () => {
+  assert.equal(fileUriToPath("file:///home/me/my%20repo"), "/home/me/my repo");
+  assert.equal(fileUriToPath("file://localhost/srv/repo"), "/srv/repo");
+  assert.equal(fileUriToPath("file:///C:/work/repo"), "C:/work/repo");
+  assert.equal(fileUriToPath("file://server/share/repo"), "//server/share/repo");
+  assert.equal(fileUriToPath("https://github.com/example/repo"), null);
+  assert.equal(fileUriToPath("not a uri"), null);
+});
+
+test("droppedFolderPaths reads file URIs from the uri-list and skips comments", () => {
+  const transfer = uriListTransfer(
+    "# dragged from the file manager\r\nfile:///home/me/repo\r\nhttps://example.com\r\nfile:///home/me/other\r\n",
+  );
+  assert.deepEqual(droppedFolderPaths(transfer), ["/home/me/repo", "/home/me/other"]);
+  assert.deepEqual(droppedFolderPaths(null), []);
+});
+
+test("dataTransferMayCarryFolder only looks at the drag types", () => {
+  assert.equal(dataTransferMayCarryFolder(uriListTransfer("")), true);
+  assert.equal(dataTransferMayCarryFolder({ types: ["Files"] }), false);
+  assert.equal(dataTransferMayCarryFolder(undefined), false);
+});
@@ -133,6 +133,28 @@ test("buildProjectSwitcherRows lists open projects first with runtime and unread
   assert.equal(rows[2].path, "/repo/three");
 });
 
+test("buildProjectSwitcherRows lists registered repositories between open and recent projects", () => {
+  const rows = buildProjectSwitcherRows({
+    tabs: makeTabs(),
+    registeredProjects: [
+      { title: "one", kind: "git", path: "/repo/one" },
+      { title: "four", kind: "bare", path: "/repo/four" },
+    ],
+    recentProjects: [
+      { title: "Repo Four", kind: "bare", path: "/repo/four" },
+      { title: "Repo Three", kind: "git", path: "/repo/three" },
+    ],
+    activeTabId: "tab-1",
+    runtimeStateForWindow: (windowData) => windowData.status,
+  });
+
+  assert.deepEqual(
+    rows.map((row) => `${row.section}:${row.title}`),
+    ["open:Repo One", "open:Repo Two", "registered:four", "recent:Repo Three"],
+  );
+  assert.equal(rows[2].type, "recent");
+});
+
 test("project switcher controller renders rows, clears unread on project select, and only requests notification permission from a click", async () => {
   const { document } = parseHTML(`
     <button id="project-switcher-button"></button>
@@ -194,6 +216,70 @@ test("project switcher controller renders rows, clears unread on project select,
   assert.deepEqual(cleared, ["tab-2"]);
 });
 
+test("project switcher flags duplicate clones and sends consolidate after confirmation", () => {
+  const { document } = parseHTML(`
+    <button id="project-switcher-button"></button>
+    <div id="project-switcher-panel"></div>
+  `);
+  const panelEl = document.getElementById("project-switcher-panel");
+  const sends = [];
+  const confirmed = [];
+  const createNode = (tagName, className, textContent) => {
+    const node = document.createElement(tagName);
+    if (className) node.className = className;
+    if (textContent !== undefined) node.textContent = textContent;
+    return node;
+  };
+  const state = {
+    tabs: [],
+    recent_projects: [
+      { title: "app", kind: "bare", path: "/src/app" },
+      { title: "app-old", kind: "git", path: "/old/app-old" },
+    ],
+    duplicate_projects: [
+      {
+        path: "/old/app-old",
+        canonical_path: "/src/app",
+        remote: "github.com/acme/app",
+      },
+    ],
+  };
+
+  const rows = buildProjectSwitcherRows({
+    recentProjects: state.recent_projects,
+    duplicateProjects: state.duplicate_projects,
+  });
+  assert.deepEqual(
+    rows.map((row) => row.duplicateOf),
+    [null, "/src/app"],
+  );
+
+  const controller = createProjectSwitcherController({
+    buttonEl: document.getElementById("project-switcher-button"),
+    panelEl,
+    getState: () => state,
+    send: (payload) => sends.push(payload),
+    createNode,
+    confirmConsolidate: (row) => {
+      confirmed.push(row.path);
+      return confirmed.length > 1;
+    },
+  });
+  controller.open();
+  assert.match(panelEl.textContent, /Duplicate/);
+  const action = () =>
+    panelEl.querySelector("[data-action='consolidate-duplicate-project']");
+  assert.match(action().textContent, /Consolidate into app/);
+
+  action().dispatchEvent(new document.defaultView.Event("click", { bubbles: true }));
+  assert.deepEqual(sends, [], "declined confirmation must not send");
+  action().dispatchEvent(new document.defaultView.Event("click", { bubbles: true }));
+  assert.deepEqual(sends, [
+    { kind: "consolidate_duplicate_project", path: "/old/app-old" },
+  ]);
+  assert.deepEqual(confirmed, ["/old/app-old", "/old/app-old"]);
+});
+
 // SPEC-2013 Phase 9: on narrow mobile viewports the Projects panel must stay
 // inside the visual viewport instead of right-aligning to a near-left button
 // and clipping the Open Projects list off-screen.
@@ -0,0 +1,90 @@
+// Shared refresh scheduler: one timer for all periodic work, idle backoff,
+// and pausing while the window is hidden.
+
+import assert from "node:assert/strict";
+import { test } from "node:test";
+
+import { createRefreshScheduler } from "../refresh-scheduler.js";
+
+function setup() {
+  const clock = { now: 0, hidden: false, timer: null, armed: 0 };
+  const scheduler = createRefreshScheduler({
+    now: () => clock.now,
+    setTimer: (fn, ms) => {
+      clock.armed += 1;
+      clock.timer = { fn, at: clock.now + ms };
+      return clock.timer;
+    },
+    clearTimer: (timer) => {
+      if (clock.timer === timer) clock.timer = null;
+    },
+    isHidden: () => clock.hidden,
+  });
+  // Advance time, firing the armed timer whenever it comes due.
+  const advance = (ms) => {
+    const end = clock.now + ms;
+    while (clock.timer && clock.timer.at <= end) {
+      const { fn, at } = clock.timer;
+      clock.now = at;
+      clock.timer = null;
+      fn();
+    }
+    clock.now = end;
+  };
+  return { clock, scheduler, advance };
+}
+
+test("tasks share one timer armed for the earliest due task", () => {
+  const { clock, scheduler, advance } = setup();
+  const runs = [];
+  scheduler.every(1000, () => runs.push("fast"));
+  const cancel = scheduler.every(3000, () => runs.push("slow"));
+  assert.equal(clock.timer.at, 1000);
+
+  advance(3000);
+  assert.deepEqual(runs, ["fast", "fast", "fast", "slow"]);
+
+  cancel();
+  advance(3000);
+  assert.equal(runs.filter((run) => run === "slow").length, 1);
+});
+
+test("idle tasks back off and input restores their pace", () => {
+  const { scheduler, advance } = setup();
+  scheduler.configure({ idle_after_secs: 10, idle_backoff: 5, pause_when_hidden: true });
+  let widget = 0;
+  let clockTicks = 0;
+  scheduler.every(1000, () => (widget += 1));
+  scheduler.every(1000, () => (clockTicks += 1), { backoff: false });
+
+  advance(10_000);
+  assert.equal(widget, 10);
+  assert.equal(scheduler.isIdle(), true);
+
+  advance(10_000);
+  assert.equal(clockTicks, 20);
+  assert.equal(widget, 12);
+
+  scheduler.noteActivity();
+  advance(1000);
+  assert.equal(widget, 13);
+});
+
+test("hidden windows pause periodic work until woken", () => {
+  const { clock, scheduler, advance } = setup();
+  let runs = 0;
+  scheduler.every(1000, () => (runs += 1));
+  advance(1000);
+  assert.equal(runs, 1);
+
+  clock.hidden = true;
+  scheduler.wake();
+  assert.equal(clock.timer, null);
+  advance(60_000);
+  assert.equal(runs, 1);
+
+  clock.hidden = false;
+  scheduler.wake();
+  assert.equal(runs, 2);
+  assert.equal(clock.timer.at, clock.now + 1000);
+});
@@ -440,6 +440,19 @@ test("System tab exposes Launch GWT at login autostart control", () => {
   );
 });
 
+test("System tab offers a local Ollama AI preset", () => {
+  assert.match(
+    settingsSource,
+    /send\(\{\s*kind:\s*"apply_ai_preset",\s*preset:\s*"ollama"\s*\}\)/,
+    "expected the Ollama preset button to send apply_ai_preset",
+  );
+  assert.match(
+    appSource,
+    /case "ai_preset_applied":[\s\S]*?systemSettingsState\.aiPresetPending = false;/,
+    "expected app.js to clear the pending preset when the backend confirms it",
+  );
+});
+
 test("renderSettingsWindow requests current settings via get_system_settings", () => {
   assert.match(
     settingsSource,
@@ -92,10 +92,76 @@ test("unsubscribe stops notifications", () => {
   assert.deepEqual(events, []);
 });
 
-function makeEnv({ stored = null, prefersDark = false } = {}) {
+test("palette defaults to the built-in default palette", () => {
+  const env = makeEnv({ stored: null, prefersDark: true });
+  const mgr = createThemeManager(env);
+  assert.equal(mgr.getPalette(), "default");
+  assert.equal(env.documentPalette, "default");
+  assert.deepEqual(
+    mgr.listPalettes().map(({ name }) => name),
+    ["default", "solarized"],
+  );
+});
+
+test("setPalette persists built-in palettes and notifies subscribers", () => {
+  const env = makeEnv({ stored: "light", prefersDark: false });
+  const mgr = createThemeManager(env);
+  const events = [];
+  mgr.subscribe((eff) => events.push(eff));
+
+  mgr.setPalette("solarized");
+  assert.equal(env.storage.get("gwt:ui:palette"), "solarized");
+  assert.equal(env.documentPalette, "solarized");
+  assert.deepEqual(events, ["light"]);
+
+  mgr.setPalette("default");
+  assert.equal(env.storage.get("gwt:ui:palette"), null);
+  assert.equal(env.documentPalette, "default");
+});
+
+test("user palettes apply the token overrides for the effective theme", () => {
+  const env = makeEnv({ stored: "dark", prefersDark: false, palette: "ocean" });
+  const mgr = createThemeManager(env);
+  assert.deepEqual(env.documentTokens, {}, "ocean is unknown until the backend sends it");
+
+  mgr.setCustomPalettes([
+    {
+      name: "ocean",
+      dark: { "color-canvas": "#001b2e" },
+      light: { "color-canvas": "#eef6fb" },
+    },
+  ]);
+  assert.equal(env.documentPalette, "default");
+  assert.deepEqual(env.documentTokens, { "color-canvas": "#001b2e" });
+  assert.deepEqual(mgr.listPalettes().at(-1), { name: "ocean", builtin: false });
+
+  mgr.setTheme("light");
+  assert.deepEqual(env.documentTokens, { "color-canvas": "#eef6fb" });
+});
+
+test("previewPalette renders without persisting and null restores the choice", () => {
+  const env = makeEnv({ stored: "dark", prefersDark: false });
+  const mgr = createThemeManager(env);
+  const events = [];
+  mgr.subscribe((eff) => events.push(eff));
+
+  mgr.previewPalette("solarized");
+  assert.equal(env.documentPalette, "solarized");
+  assert.equal(mgr.getPalette(), "default");
+  assert.equal(env.storage.get("gwt:ui:palette"), null);
+
+  mgr.previewPalette(null);
+  assert.equal(env.documentPalette, "default");
+  assert.deepEqual(events, [], "previews do not notify subscribers");
+});
+
+function makeEnv({ stored = null, prefersDark = false, palette = null } = {}) {
   const storage = new Map();
   if (stored) storage.set("gwt:ui:theme", stored);
+  if (palette) storage.set("gwt:ui:palette", palette);
   let documentTheme = null;
+  let documentPalette = null;
+  let documentTokens = null;
   let listener = null;
   let currentDark = prefersDark;
 
@@ -117,6 +183,10 @@ function makeEnv({ stored = null, prefersDark = false } = {}) {
     },
     setDocumentTheme: (t) => { documentTheme = t; },
     get documentTheme() { return documentTheme; },
+    setDocumentPalette: (p) => { documentPalette = p; },
+    get documentPalette() { return documentPalette; },
+    setDocumentTokens: (t) => { documentTokens = t; },
+    get documentTokens() { return documentTokens; },
     changePrefersDark: (next) => {
       currentDark = next;
       listener?.({ matches: next });
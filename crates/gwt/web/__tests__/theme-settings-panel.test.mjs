@@ -0,0 +1,66 @@
+import { test } from "node:test";
+import assert from "node:assert/strict";
+import { parseHTML } from "linkedom";
+import { renderThemeSettingsPanel } from "../theme-settings-panel.js";
+
+function fixture() {
+  const { document, window } = parseHTML(
+    `<!doctype html><body><section id="panel"></section></body>`,
+  );
+  const calls = [];
+  const themeManager = {
+    getPreference: () => "dark",
+    getPalette: () => "solarized",
+    listPalettes: () => [
+      { name: "default", builtin: true },
+      { name: "solarized", builtin: true },
+      { name: "ocean", builtin: false },
+    ],
+    setTheme: (value) => calls.push(["setTheme", value]),
+    setPalette: (name) => calls.push(["setPalette", name]),
+    previewPalette: (name) => calls.push(["previewPalette", name]),
+  };
+  return { document, window, panel: document.getElementById("panel"), themeManager, calls };
+}
+
+test("renderThemeSettingsPanel lists palettes and marks the chosen one", () => {
+  const ctx = fixture();
+  renderThemeSettingsPanel({ ...ctx, errors: [] });
+
+  const options = [...ctx.panel.querySelectorAll("[data-palette]")];
+  assert.deepEqual(options.map((o) => o.dataset.palette), ["default", "solarized", "ocean"]);
+  assert.deepEqual(
+    options.map((o) => o.getAttribute("aria-checked")),
+    ["false", "true", "false"],
+  );
+  assert.equal(ctx.panel.querySelector("#settings-appearance-theme").value, "dark");
+  assert.ok(ctx.panel.querySelector("[data-role='theme-preview']"));
+  assert.equal(ctx.panel.querySelector("[data-role='theme-palette-errors']"), null);
+});
+
+test("hovering previews a palette and clicking keeps it", () => {
+  const ctx = fixture();
+  renderThemeSettingsPanel(ctx);
+  const ocean = ctx.panel.querySelector("[data-palette='ocean']");
+
+  ocean.dispatchEvent(new ctx.window.Event("mouseenter"));
+  ocean.dispatchEvent(new ctx.window.Event("mouseleave"));
+  ocean.dispatchEvent(new ctx.window.Event("click"));
+
+  assert.deepEqual(ctx.calls, [
+    ["previewPalette", "ocean"],
+    ["previewPalette", null],
+    ["setPalette", "ocean"],
+  ]);
+});
+
+test("renderThemeSettingsPanel lists dropped config entries", () => {
+  const ctx = fixture();
+  renderThemeSettingsPanel({
+    ...ctx,
+    errors: ['theme.palettes.ocean.dark: unknown token "nope"'],
+  });
+  const errors = ctx.panel.querySelector("[data-role='theme-palette-errors']");
+  assert.ok(errors);
+  assert.match(errors.textContent, /unknown token/);
+});
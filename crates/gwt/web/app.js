@@ -9,8 +9,12 @@
         applyIssueMonitorStatus,
         applyProviderUsage,
         applyRuntimeHealth,
+        applyAiQueueStatus,
+        applyKeybindings,
       } from "/operator-shell.js";
       import { createFocusTrap } from "/focus-trap.js";
+      import { createFooterWidgets } from "/footer-widgets.js";
+      import { createRefreshScheduler } from "/refresh-scheduler.js";
       import {
         TITLEBAR_DOCK_HIT_HEIGHT,
         clientPointFromDragEvent,
@@ -146,9 +150,19 @@
       // module loads so the theme toggle, command palette, hotkey overlay,
       // status strip clock, and Mission Briefing intro are wired before the
       // rest of app.js continues bootstrapping the legacy surfaces.
+      // Periodic refreshes share one scheduler; `[refresh]` configures it on
+      // connect.
+      const refreshScheduler = createRefreshScheduler();
+      for (const type of ["keydown", "pointerdown", "wheel"]) {
+        window.addEventListener(type, () => refreshScheduler.noteActivity(), {
+          capture: true,
+          passive: true,
+        });
+      }
+      document.addEventListener("visibilitychange", () => refreshScheduler.wake());
       let __op;
       try {
-        __op = initOperatorShell();
+        __op = initOperatorShell({ refreshScheduler });
       } catch (error) {
         console.error("operator shell failed during startup", error);
         dismissOperatorBriefing();
@@ -156,12 +170,15 @@
           themeManager: null,
           hotkey: null,
           palette: null,
+          helpOverlay: null,
         };
       }
       window.__operatorShell = {
         themeManager: __op.themeManager,
         hotkey: __op.hotkey,
         palette: __op.palette,
+        helpOverlay: __op.helpOverlay,
+        applyKeybindings: (bindings) => applyKeybindings(document, __op, bindings),
         applyTelemetryCounts: (counts) => applyTelemetryCounts(document, counts),
         applyIssueMonitorStatus: (status) => applyIssueMonitorStatus(document, status),
         applyProviderUsage: (snapshot) => applyProviderUsage(document, snapshot),
@@ -169,8 +186,20 @@
           applyRuntimeHealth(document, snapshot, {
             focusWindow: (windowId) => focusWindowRemotely(windowId, { center: true }),
           }),
+        applyAiQueueStatus: (status) =>
+          applyAiQueueStatus(document, status, {
+            cancelJob: (jobId) => send({ kind: "cancel_ai_job", job_id: jobId }),
+          }),
       };
 
+      // `[[footer.widgets]]` cells in the Status Strip; configured on connect.
+      const footerWidgets = createFooterWidgets({
+        doc: document,
+        send,
+        setTimer: (fn, ms) => refreshScheduler.every(ms, fn),
+        clearTimer: (cancel) => cancel(),
+      });
+
       const uiTraceProfiler = createUiTraceProfiler();
 
       const canvas = document.getElementById("canvas");
@@ -2972,6 +3001,7 @@
             counts.agents = Math.max(counts.agents, activeAgents + blockedAgents);
           }
         }
+        footerWidgets.setRunningAgents(counts.running);
         applyOperatorTelemetryCounts(counts);
       }
 
@@ -3467,6 +3497,7 @@
       // applyStatus, mountWindowBody, receive(), and the boot-time bridge
       // installs keep their existing call sites wired through this factory.
       const {
+        writeClipboardText,
         updateTerminalOverlayCopyState,
         copyTerminalOverlayMessage,
         installTerminalCopyHandlers,
@@ -4069,6 +4100,7 @@
         openIssueLaunchWizard,
         visibleBounds,
         launchPending,
+        refreshScheduler,
       });
 
       // SPEC-3064 Phase 3 (E6c): the Board & Logs window surface (board/log
@@ -4196,6 +4228,21 @@
         });
       }
 
+      // `[worktree_files] copy_on_create`: say which local files a new
+      // worktree received, since they are not visible in git status.
+      function showWorktreeFilesCopiedToast(activity) {
+        const files = activity?.copied_files || [];
+        if (activity?.event !== "worktree.created" || files.length === 0) return;
+        alertsToasts.push({
+          id: `worktree-files-${activity.worktree_path}`,
+          level: "info",
+          title: `Copied ${files.length} file${files.length === 1 ? "" : "s"} into ${activity.branch || "new worktree"}`,
+          message: files.join(", "),
+          dismissible: true,
+          timeoutMs: 8_000,
+        });
+      }
+
       function createKnowledgeMarkdownBody(section, className = "knowledge-section-body") {
         const node = createNode("div", `${className} knowledge-markdown-body`);
         const html = typeof section?.body_html === "string" ? section.body_html.trim() : "";
@@ -4257,6 +4304,7 @@
         visibleBounds,
         getActiveWorkProjection: () => activeWorkProjection,
         renderWorkspaceWindows: () => workspaceOverviewSurface.renderWindows(),
+        writeClipboardText,
       });
 
       const workspaceOverviewSurface = createWorkspaceOverviewSurface({
@@ -4666,6 +4714,8 @@
         applyAutostartError,
         applyCustomAgentDeleted,
         applyCustomAgentError,
+        applyThemePalettes,
+        applyConfigDiagnostics,
         renderSettingsWindow,
         renderSettingsAgentList,
         renderAgentBackendsPanel,
@@ -4683,6 +4733,7 @@
         focusOrSpawnPreset,
         renderUsagePanel,
         indexStatusByProjectRoot,
+        getThemeManager: () => window.__operatorShell?.themeManager ?? null,
       });
 
       function ensureWindow(windowData) {
@@ -5352,6 +5403,38 @@
           case "runtime_health":
             window.__operatorShell?.applyRuntimeHealth?.(event.snapshot || {});
             break;
+          case "ai_queue_status":
+            window.__operatorShell?.applyAiQueueStatus?.(event.status || {});
+            break;
+          case "keybindings": {
+            const problems = [
+              ...(event.errors || []),
+              ...(window.__operatorShell?.applyKeybindings?.(event.bindings || []) || []),
+            ];
+            if (problems.length > 0) console.warn("keybindings ignored", problems);
+            break;
+          }
+          case "theme_palettes":
+            if ((event.errors || []).length > 0) {
+              console.warn("theme palettes ignored", event.errors);
+            }
+            applyThemePalettes(event);
+            break;
+          case "config_diagnostics":
+            applyConfigDiagnostics(event);
+            break;
+          case "footer_widgets":
+            if ((event.errors || []).length > 0) {
+              console.warn("footer widgets ignored", event.errors);
+            }
+            footerWidgets.apply(event);
+            break;
+          case "footer_widget_value":
+            footerWidgets.applyValue(event);
+            break;
+          case "refresh_budget":
+            refreshScheduler.configure(event);
+            break;
           case "issue_monitor_status":
             frontendUnits.issueMonitorSurface.applyStatus(event.status || {});
             window.__operatorShell?.applyIssueMonitorStatus?.(event.status || {});
@@ -5425,6 +5508,12 @@
           case "file_content_saved":
           case "file_content_save_error":
           case "file_content_error":
+          case "git_changes":
+          case "file_diff":
+          case "file_diff_error":
+          case "commit_message_suggestion":
+          case "commit_created":
+          case "commit_error":
             applyFileTreeReceiveEvent(event);
             break;
           case "branch_entries": {
@@ -5458,6 +5547,20 @@
           // optimistic queued state.
           case "branch_cleanup_result":
           case "branch_cleanup_progress":
+          case "worktree_stash_result":
+          case "worktree_restore_result":
+          case "worktree_repair_result":
+          case "worktree_transplant_result":
+          case "ci_artifacts_download_result":
+          case "branch_rebase_result":
+          case "pull_request_create_result":
+          case "external_agents":
+          case "external_agent_adopted":
+          case "branch_ci_statuses":
+          case "git_refs_changed":
+          case "stale_remote_branches":
+          case "stale_remote_branches_deleted":
+          case "removed_worktrees":
           case "branch_error":
             applyBranchCleanupReceiveEvent(event);
             break;
@@ -5520,6 +5623,14 @@
           case "knowledge_error":
             applyKnowledgeReceiveEvent(event);
             break;
+          case "server_mode": {
+            const banner = document.getElementById("read-only-banner");
+            if (banner) {
+              banner.hidden = !event.read_only;
+            }
+            document.body.classList.toggle("is-read-only", Boolean(event.read_only));
+            break;
+          }
           case "project_open_error":
             projectError = event.message;
             frontendUnits.projectWorkspaceShell.renderProjectPicker();
@@ -5530,6 +5641,7 @@
           // SPEC-3064 Phase 3 (E7): clone-project modal state and rendering
           // live in the project shell surface.
           case "clone_project_parent_selected":
+          case "project_drop_needs_clone":
           case "github_repository_search_results":
           case "github_repository_search_error":
           case "clone_project_progress":
@@ -5537,6 +5649,20 @@
           case "clone_project_error":
             applyCloneProjectReceiveEvent(event);
             break;
+          case "duplicate_project_consolidated": {
+            const fetched = event.fetched_branches || [];
+            window.alert(
+              `Consolidated ${event.path} into ${event.canonical_path}.\n` +
+                (fetched.length > 0
+                  ? `Fetched branches: ${fetched.join(", ")}`
+                  : "No branches were missing.") +
+                "\nThe folder was not deleted.",
+            );
+            break;
+          }
+          case "duplicate_project_consolidate_error":
+            window.alert(`Could not consolidate ${event.path}: ${event.message}`);
+            break;
           case "launch_wizard_open_error":
             // SPEC-3064 Phase 3 (E5): guard defer + wizard state mutation
             // live in the launch wizard surface.
@@ -5577,6 +5703,14 @@
           case "runtime_hook_event":
             frontendUnits.boardSurface.handleRuntimeHookEvent(event);
             break;
+          case "activity_event":
+            // Agent / worktree activity bus; surfaces subscribe to the DOM
+            // event instead of polling.
+            window.dispatchEvent(
+              new CustomEvent("gwt:activity", { detail: event.event }),
+            );
+            showWorktreeFilesCopiedToast(event.event);
+            break;
           case "update_state":
             if (event.state === "available") {
               updateCtaController.handleUpdateState(event);
@@ -5748,7 +5882,20 @@
             systemSettingsState.statusKind = "success";
             renderSystemPanelInAllSettingsWindows();
             break;
+          case "ai_preset_applied":
+            systemSettingsState.aiPreset = {
+              provider: event.provider,
+              endpoint: event.endpoint,
+              model: event.model,
+              models: event.models || [],
+            };
+            systemSettingsState.aiPresetPending = false;
+            systemSettingsState.statusMessage = `AI features now use ${event.model}.`;
+            systemSettingsState.statusKind = "success";
+            renderSystemPanelInAllSettingsWindows();
+            break;
           case "system_settings_error":
+            systemSettingsState.aiPresetPending = false;
             // Issue #2698 PR 4 — defer when user is mid-dropdown.
             if (
               systemSettingsInteractionGuard.defer({
@@ -5815,6 +5962,7 @@
           // SPEC-3064 Phase 3 (E7): migration modal state and rendering live
           // in the project shell surface.
           case "migration_detected":
+          case "migration_report":
           case "migration_progress":
           case "migration_done":
           case "migration_error":
@@ -6608,6 +6756,12 @@
             return;
           }
           case "open-help": {
+            // The shell's overlay renders the current screen's bindings;
+            // the raw toggle below only covers a degraded shell.
+            if (window.__operatorShell?.helpOverlay) {
+              window.__operatorShell.helpOverlay.toggle();
+              return;
+            }
             const overlay = document.getElementById("op-hotkey-overlay");
             if (overlay) {
               overlay.dataset.open = overlay.dataset.open === "true" ? "" : "true";
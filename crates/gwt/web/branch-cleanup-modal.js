@@ -39,8 +39,10 @@ export function renderBranchCleanupModal({
   riskLabels,
   onCancel,
   onSubmit,
+  onArchive,
   onDeleteRemoteToggle,
   onForceFilesystemDeleteToggle,
+  onConfirmationInput,
 }) {
   if (!windowId || !state || !state.cleanupModal.open) {
     const wasOpenBeforeClose = modalEl.classList.contains("open");
@@ -224,6 +226,17 @@ export function renderBranchCleanupModal({
       `Delete ${selectedEntries.length} selected branch${selectedEntries.length === 1 ? "" : "es"}.`,
     ),
   );
+  // Branches matching a `protected_branches` pattern run only after their
+  // name is typed; the backend refuses them otherwise.
+  const protectedBranches = selectedEntries
+    .filter((entry) =>
+      (entry.cleanup.risks || []).includes("protected_pattern"),
+    )
+    .map((entry) => entry.cleanup.execution_branch || entry.name);
+  const typed = { ...(state.cleanupModal.confirmations || {}) };
+  const allProtectedConfirmed = () =>
+    protectedBranches.every((branch) => typed[branch] === branch);
+  let submit = null;
   const list = createNode("div", "branch-cleanup-list");
   for (const entry of selectedEntries) {
     const item = createNode("div", "branch-cleanup-item");
@@ -257,6 +270,24 @@ export function renderBranchCleanupModal({
         createNode("div", "branch-cleanup-item-copy", risks.join(", ")),
       );
     }
+    const confirmBranch = entry.cleanup.execution_branch || entry.name;
+    if (protectedBranches.includes(confirmBranch)) {
+      const input = createNode("input", "branch-cleanup-confirm-input");
+      input.type = "text";
+      input.placeholder = `Type ${confirmBranch} to confirm`;
+      input.setAttribute("aria-label", `Type ${confirmBranch} to confirm`);
+      input.value = typed[confirmBranch] || "";
+      input.addEventListener("input", () => {
+        typed[confirmBranch] = input.value;
+        if (typeof onConfirmationInput === "function") {
+          onConfirmationInput(confirmBranch, input.value);
+        }
+        if (submit) {
+          submit.disabled = !allProtectedConfirmed();
+        }
+      });
+      item.appendChild(input);
+    }
     list.appendChild(item);
   }
   dialogEl.appendChild(list);
@@ -308,8 +339,21 @@ export function renderBranchCleanupModal({
   cancel.type = "button";
   cancel.addEventListener("click", onCancel);
   footer.appendChild(cancel);
-  const submit = createNode("button", "wizard-button primary", "Run cleanup");
+  // Archiving keeps the branch and packs the worktree, uncommitted changes
+  // included, so it can be restored later.
+  if (
+    typeof onArchive === "function" &&
+    selectedEntries.some((entry) => entry.worktree_path)
+  ) {
+    const archive = createNode("button", "wizard-button", "Archive worktrees");
+    archive.type = "button";
+    archive.title = "Keep the branches and archive their worktrees for later restore";
+    archive.addEventListener("click", onArchive);
+    footer.appendChild(archive);
+  }
+  submit = createNode("button", "wizard-button primary", "Run cleanup");
   submit.type = "button";
+  submit.disabled = !allProtectedConfirmed();
   submit.addEventListener("click", onSubmit);
   footer.appendChild(submit);
   dialogEl.appendChild(footer);
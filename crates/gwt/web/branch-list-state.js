@@ -16,6 +16,19 @@
 // the detail check now recovers automatically on reconnect.
 export const BRANCH_DETAIL_CHECK_INTERRUPTED_NOTICE = "Branch detail check interrupted";
 
+// Lists with more rows than this render only the rows around the viewport
+// (plus spacers). Matches LAZY_HYDRATION_THRESHOLD in branch_list.rs, above
+// which the backend also stops hydrating the whole list: a "lazy" inventory
+// is followed by "partial" events for the rows the window asks for.
+export const BRANCH_VIRTUALIZE_MIN_ROWS = 200;
+// Rows rendered above and below the viewport.
+export const BRANCH_VIRTUAL_OVERSCAN_ROWS = 10;
+// Rows beyond the rendered window whose safety check is requested ahead of
+// the scroll.
+export const BRANCH_HYDRATION_PREFETCH_ROWS = 30;
+// Row height used until a rendered row has been measured.
+export const BRANCH_ROW_HEIGHT_ESTIMATE = 56;
+
 // Build a name -> cleanup index from hydrated entries so a later inventory /
 // interruption can re-display the last verified badges.
 export function indexHydratedCleanup(entries) {
@@ -111,22 +124,105 @@ export function applyBranchEntriesEvent(state, event) {
   }
   const phase = String(event.phase || "hydrated").toLowerCase();
   let entries = Array.isArray(event.entries) ? event.entries : [];
+  if (phase === "partial") {
+    mergePartialBranchEntries(state, entries);
+    return { applied: true };
+  }
+  state.hydrationRequestKey = "";
   if (phase === "hydrated") {
     state.lastHydratedByName = indexHydratedCleanup(entries);
     state.detailCheckStale = false;
     state.needsResync = false;
   } else {
     entries = carryOverLastKnownCleanup(entries, state.lastHydratedByName);
+    if (phase === "lazy") {
+      state.detailCheckStale = false;
+      state.needsResync = false;
+    }
   }
   state.entries = entries;
   state.phase = phase;
-  state.loading = phase !== "hydrated";
+  // A lazy load is complete once its inventory lands; rows are checked as
+  // they scroll into view.
+  state.loading = phase !== "hydrated" && phase !== "lazy";
   state.receivedFreshEntries = true;
   state.error = "";
   state.notice = "";
   return { applied: true };
 }
 
+// Merge the hydrated rows of a "partial" event into the list by name. Only
+// the fields hydration computes are taken, so the inventory's resume and
+// worktree data stay as loaded.
+function mergePartialBranchEntries(state, hydrated) {
+  const byName = new Map();
+  for (const entry of hydrated) {
+    if (entry && entry.name) {
+      byName.set(entry.name, entry);
+    }
+  }
+  if (!(state.lastHydratedByName instanceof Map)) {
+    state.lastHydratedByName = new Map();
+  }
+  state.entries = (state.entries || []).map((entry) => {
+    const fresh = entry && byName.get(entry.name);
+    if (!fresh) {
+      return entry;
+    }
+    if (fresh.cleanup_ready && fresh.cleanup) {
+      state.lastHydratedByName.set(fresh.name, fresh.cleanup);
+    }
+    const { cleanup_stale: _stale, ...rest } = entry;
+    return {
+      ...rest,
+      cleanup: fresh.cleanup,
+      cleanup_ready: Boolean(fresh.cleanup_ready),
+      start_work_eligibility: fresh.start_work_eligibility ?? null,
+    };
+  });
+}
+
+// Rows [start, end) of a `total`-row list to render for the given scroll
+// position. Short lists render every row.
+export function branchRenderWindow({
+  total,
+  scrollTop = 0,
+  viewportHeight = 0,
+  rowHeight = BRANCH_ROW_HEIGHT_ESTIMATE,
+  overscan = BRANCH_VIRTUAL_OVERSCAN_ROWS,
+}) {
+  if (!(total > BRANCH_VIRTUALIZE_MIN_ROWS)) {
+    return { start: 0, end: Math.max(0, total || 0), virtualized: false };
+  }
+  const height = rowHeight > 0 ? rowHeight : BRANCH_ROW_HEIGHT_ESTIMATE;
+  const first = Math.floor(Math.max(0, scrollTop) / height);
+  const visible = Math.ceil(Math.max(viewportHeight, height) / height);
+  const start = Math.max(0, Math.min(total - 1, first - overscan));
+  const end = Math.min(total, first + visible + overscan);
+  return { start, end: Math.max(start + 1, end), virtualized: true };
+}
+
+// Names of the rows in [start - prefetch, end + prefetch) still waiting for
+// their cleanup safety check, in list order.
+export function unhydratedBranchNames(
+  entries,
+  start,
+  end,
+  prefetch = BRANCH_HYDRATION_PREFETCH_ROWS,
+) {
+  const rows = Array.isArray(entries) ? entries : [];
+  const from = Math.max(0, start - prefetch);
+  const to = Math.min(rows.length, end + prefetch);
+  const names = [];
+  for (let index = from; index < to; index += 1) {
+    const entry = rows[index];
+    if (entry && !entry.cleanup_ready) {
+      names.push(entry.name);
+    }
+  }
+  return names;
+}
+
 // Top-of-list status summary. FR-066: the interrupted state is now a small,
 // non-blocking, reassuring inline notice (it self-heals on reconnect) rather
 // than an alarming manual-refresh band.
@@ -166,5 +262,17 @@ export function branchLoadStatusSummary(state) {
       hint: "",
     };
   }
+  if (
+    state.phase === "lazy" &&
+    Array.isArray(state.entries) &&
+    state.entries.some((entry) => entry && !entry.cleanup_ready)
+  ) {
+    return {
+      kind: "lazy",
+      title: "Checking branches as they scroll into view",
+      detail: `${state.entries.length} branches — cleanup safety is verified for the rows on screen first.`,
+      hint: "Cleanup selection unlocks per row once it is verified.",
+    };
+  }
   return null;
 }
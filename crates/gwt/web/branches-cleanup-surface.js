@@ -27,10 +27,14 @@
 // - getActiveWorkProjection(): read accessor for the active Work
 //   projection (app.js owns the let).
 // - renderWorkspaceWindows(): late-bound Workspace Overview re-render.
+// - writeClipboardText(text): clipboard helper from terminal-attachments.
 import { renderBranchCleanupModal as renderBranchCleanupModalView } from "/branch-cleanup-modal.js";
 import {
   markBranchDetailInterrupted,
   branchLoadStatusSummary,
+  branchRenderWindow,
+  unhydratedBranchNames,
+  BRANCH_ROW_HEIGHT_ESTIMATE,
 } from "/branch-list-state.js";
 
 export function createBranchesCleanupSurface({
@@ -45,6 +49,7 @@ export function createBranchesCleanupSurface({
   visibleBounds,
   getActiveWorkProjection,
   renderWorkspaceWindows,
+  writeClipboardText,
 }) {
       const branchListStateMap = new Map();
       let branchCleanupWindowId = null;
@@ -101,6 +106,166 @@ export function createBranchesCleanupSurface({
         renderBranchCleanupModal();
       }
 
+      // The worktree path column is a per-browser preference shared by every
+      // Branches window.
+      function readBranchPathColumnPreference() {
+        try {
+          if (typeof localStorage === "undefined") return false;
+          return localStorage.getItem("branches-show-path") === "1";
+        } catch (_err) {
+          return false;
+        }
+      }
+
+      function writeBranchPathColumnPreference(value) {
+        try {
+          if (typeof localStorage === "undefined") return;
+          if (value) {
+            localStorage.setItem("branches-show-path", "1");
+          } else {
+            localStorage.removeItem("branches-show-path");
+          }
+        } catch (_err) {
+          // localStorage may be unavailable in private mode; ignore.
+        }
+      }
+
+      function toggleBranchPathColumn(windowId) {
+        const state = ensureBranchListState(windowId);
+        state.showPath = !state.showPath;
+        writeBranchPathColumnPreference(state.showPath);
+        renderBranches(windowId);
+      }
+
+      // Copy (`c`), open in the file manager (`o`), open in the editor
+      // (`e`), stash (`s`) or unstash (`u`) the worktree that has
+      // `branchName` checked out, start a new branch from its uncommitted
+      // state (`t`), rebase it onto the updated base (`b`), repair (`f`) the
+      // integrity problems found after an agent session, download the
+      // artifacts of its latest CI runs into it (`a`), or restore (`r`) its
+      // archived worktree. `P` pushes the branch and opens a pull request
+      // for it, worktree or not.
+      function runBranchPathAction(windowId, branchName, action) {
+        const state = ensureBranchListState(windowId);
+        const entry = state.entries.find((candidate) => candidate.name === branchName);
+        if (action === "pull_request") {
+          state.notice = `Opening a pull request for ${branchName}…`;
+          renderBranches(windowId);
+          send({ kind: "create_pull_request", id: windowId, branch: branchName });
+          return;
+        }
+        if (action === "restore") {
+          if (!entry?.archived) {
+            state.notice = `${branchName} has no archived worktree`;
+            renderBranches(windowId);
+            return;
+          }
+          send({ kind: "restore_worktree", id: windowId, branch: branchName });
+          return;
+        }
+        const path = entry?.worktree_path;
+        if (!path) {
+          state.notice = `${branchName} has no worktree`;
+          renderBranches(windowId);
+          return;
+        }
+        if (action === "copy") {
+          Promise.resolve(writeClipboardText?.(path)).then((copied) => {
+            state.notice = copied ? `Copied ${path}` : "Clipboard unavailable";
+            renderBranches(windowId);
+          });
+          return;
+        }
+        if (action === "transplant") {
+          const newBranch = window.prompt(
+            `New branch from ${branchName}'s uncommitted changes`,
+            `${branchName}-salvage`,
+          );
+          if (!newBranch || !newBranch.trim()) return;
+          send({
+            kind: "transplant_worktree",
+            id: windowId,
+            branch: branchName,
+            new_branch: newBranch.trim(),
+          });
+          return;
+        }
+        if (action === "rebase") {
+          const onto = window.prompt(
+            `Rebase ${branchName} onto (leave empty for the updated default base)`,
+            "",
+          );
+          if (onto === null) return;
+          state.notice = `Rebasing ${branchName}…`;
+          renderBranches(windowId);
+          send({
+            kind: "rebase_branch",
+            id: windowId,
+            branch: branchName,
+            onto: onto.trim() || null,
+          });
+          return;
+        }
+        if (action === "artifacts") {
+          const pattern = window.prompt(
+            `Download CI artifacts of ${branchName} matching (leave empty for all)`,
+            "",
+          );
+          if (pattern === null) return;
+          state.notice = `Downloading CI artifacts of ${branchName}…`;
+          renderBranches(windowId);
+          send({
+            kind: "download_ci_artifacts",
+            id: windowId,
+            branch: branchName,
+            pattern: pattern.trim() || null,
+          });
+          return;
+        }
+        if (action === "repair") {
+          state.notice = `Repairing ${branchName}'s worktree…`;
+          renderBranches(windowId);
+          send({ kind: "repair_worktree", id: windowId, branch: branchName });
+          return;
+        }
+        if (action === "stash" || action === "unstash") {
+          send({
+            kind: "stash_worktree",
+            id: windowId,
+            path,
+            action,
+          });
+          return;
+        }
+        send({
+          kind: "open_worktree_path",
+          id: windowId,
+          path,
+          target: action === "editor" ? "editor" : "file_manager",
+        });
+      }
+
+      const BRANCH_PATH_ACTION_KEYS = {
+        c: "copy",
+        o: "file_manager",
+        e: "editor",
+        s: "stash",
+        u: "unstash",
+        t: "transplant",
+        b: "rebase",
+        r: "restore",
+        f: "repair",
+        a: "artifacts",
+        P: "pull_request",
+      };
+      const BRANCH_CI_GLYPHS = {
+        passing: "✓",
+        failing: "✗",
+        pending: "●",
+      };
+      // Mirrors DEFAULT_STALE_REMOTE_MIN_AGE_DAYS in stale_remote_branches.rs.
+      const STALE_REMOTE_DEFAULT_MIN_AGE_DAYS = 30;
+
       function ensureBranchListState(windowId) {
         if (!branchListStateMap.has(windowId)) {
           branchListStateMap.set(windowId, {
@@ -111,18 +276,54 @@ export function createBranchesCleanupSurface({
             error: "",
             selectedBranchName: "",
             filter: "local",
+            showPath: readBranchPathColumnPreference(),
+            // "Stale" filter: origin branches whose PRs are all merged or
+            // closed, loaded on demand because it needs a `gh pr list` call.
+            stale: {
+              loading: false,
+              loaded: false,
+              deleting: false,
+              minAgeDays: STALE_REMOTE_DEFAULT_MIN_AGE_DAYS,
+              entries: [],
+              selected: new Set(),
+            },
+            // "Removed" filter: worktrees branch cleanup moved into
+            // .gwt-trash/, reloaded each time the filter is opened.
+            removed: {
+              loading: false,
+              loaded: false,
+              restoring: "",
+              entries: [],
+            },
             cleanupSelected: new Set(),
+            // Latest CI state per branch name ({ status, head_sha, url }),
+            // refreshed after each list load.
+            ciStatuses: {},
             notice: "",
+            // Rebase stopped on conflicts: { branch, onto, stoppedAt,
+            // conflicts }, cleared by a finished or aborted rebase.
+            rebase: null,
+            // Agents started outside gwt ({ pid, agent, command_line, cwd,
+            // branch }), listed by the "Adopt" action until dismissed.
+            externalAgents: null,
             // SPEC-2009 Phase 7 (FR-064..FR-067): detail-check reconnect state.
             lastHydratedByName: new Map(),
             lastLoadId: 0,
             detailCheckStale: false,
             needsResync: false,
+            // Lazy loads (long lists): rows of the pending hydrate_branches
+            // request, and the measured row height the render window uses.
+            hydrationRequestKey: "",
+            hydrationTimer: null,
+            rowHeight: 0,
+            scrollFrame: null,
             cleanupModal: {
               open: false,
               stage: "confirm",
               deleteRemote: false,
               forceFilesystemDelete: false,
+              // Protected-pattern branch -> the name typed to confirm it.
+              confirmations: {},
               progress: null,
               results: [],
             },
@@ -142,6 +343,206 @@ export function createBranchesCleanupSurface({
           kind: "load_branches",
           id: windowId,
         });
+        send({
+          kind: "load_branch_ci_status",
+          id: windowId,
+        });
+      }
+
+      function requestStaleRemoteBranches(windowId) {
+        const state = ensureBranchListState(windowId);
+        if (state.stale.loading) {
+          return;
+        }
+        state.stale.loading = true;
+        send({
+          kind: "load_stale_remote_branches",
+          id: windowId,
+          min_age_days: state.stale.minAgeDays,
+        });
+      }
+
+      function deleteSelectedStaleRemoteBranches(windowId) {
+        const state = ensureBranchListState(windowId);
+        const branches = state.stale.entries
+          .map((entry) => entry.branch)
+          .filter((branch) => state.stale.selected.has(branch));
+        if (branches.length === 0 || state.stale.deleting) {
+          return;
+        }
+        const confirmed = window.confirm(
+          `Delete ${branches.length} branch${branches.length === 1 ? "" : "es"} from origin?\n\n` +
+            `${branches.join("\n")}\n\nThis runs git push origin --delete and cannot be undone.`,
+        );
+        if (!confirmed) {
+          return;
+        }
+        state.stale.deleting = true;
+        state.notice = "";
+        send({
+          kind: "delete_stale_remote_branches",
+          id: windowId,
+          branches,
+        });
+        renderBranches(windowId);
+      }
+
+      function requestRemovedWorktrees(windowId) {
+        const state = ensureBranchListState(windowId);
+        if (state.removed.loading) {
+          return;
+        }
+        state.removed.loading = true;
+        send({
+          kind: "load_removed_worktrees",
+          id: windowId,
+        });
+      }
+
+      function undoWorktreeRemoval(windowId, branch) {
+        const state = ensureBranchListState(windowId);
+        if (state.removed.restoring) {
+          return;
+        }
+        state.removed.restoring = branch;
+        state.notice = "";
+        send({
+          kind: "undo_worktree_removal",
+          id: windowId,
+          branch,
+        });
+        renderBranches(windowId);
+      }
+
+      function renderRemovedWorktrees(windowId, list, state) {
+        const removed = state.removed;
+        list.replaceChildren();
+        if (removed.entries.length === 0) {
+          list.appendChild(
+            createNode(
+              "div",
+              "branch-empty workspace-empty-state",
+              removed.loading || !removed.loaded
+                ? "Loading removed worktrees"
+                : "No removed worktrees in .gwt-trash",
+            ),
+          );
+          return;
+        }
+
+        for (const entry of removed.entries) {
+          const row = createNode("div", "removed-worktree-row");
+          row.dataset.removedBranch = entry.branch;
+          row.appendChild(createNode("span", "branch-name-text", entry.branch));
+          const removedAt = new Date(entry.removed_at);
+          row.appendChild(
+            createNode(
+              "span",
+              "branch-summary",
+              `${entry.worktree_path} · removed ${
+                Number.isNaN(removedAt.getTime()) ? entry.removed_at : removedAt.toLocaleString()
+              }`,
+            ),
+          );
+          const restoreButton = createNode(
+            "button",
+            "wizard-button",
+            removed.restoring === entry.branch ? "Restoring…" : "Restore",
+          );
+          restoreButton.type = "button";
+          restoreButton.dataset.action = "undo-worktree-removal";
+          restoreButton.disabled = Boolean(removed.restoring);
+          restoreButton.addEventListener("click", (event) => {
+            event.stopPropagation();
+            undoWorktreeRemoval(windowId, entry.branch);
+          });
+          row.appendChild(restoreButton);
+          list.appendChild(row);
+        }
+      }
+
+      function renderStaleRemoteBranches(windowId, list, state) {
+        const stale = state.stale;
+        list.replaceChildren();
+
+        const toolbar = createNode("div", "stale-remote-toolbar");
+        const ageLabel = createNode("label", "stale-remote-age", "Last commit older than ");
+        const ageInput = document.createElement("input");
+        ageInput.type = "number";
+        ageInput.min = "1";
+        ageInput.value = String(stale.minAgeDays);
+        ageInput.addEventListener("change", () => {
+          const days = Number.parseInt(ageInput.value, 10);
+          if (!Number.isFinite(days) || days < 1) {
+            ageInput.value = String(stale.minAgeDays);
+            return;
+          }
+          stale.minAgeDays = days;
+          requestStaleRemoteBranches(windowId);
+          renderBranches(windowId);
+        });
+        ageLabel.appendChild(ageInput);
+        ageLabel.appendChild(document.createTextNode(" days"));
+        toolbar.appendChild(ageLabel);
+
+        const deleteButton = createNode(
+          "button",
+          "wizard-button",
+          stale.deleting
+            ? "Deleting…"
+            : stale.selected.size === 0
+              ? "Delete from origin"
+              : `Delete from origin (${stale.selected.size})`,
+        );
+        deleteButton.type = "button";
+        deleteButton.dataset.action = "delete-stale-remotes";
+        deleteButton.disabled = stale.deleting || stale.selected.size === 0;
+        deleteButton.addEventListener("click", (event) => {
+          event.stopPropagation();
+          deleteSelectedStaleRemoteBranches(windowId);
+        });
+        toolbar.appendChild(deleteButton);
+        list.appendChild(toolbar);
+
+        if (stale.entries.length === 0) {
+          list.appendChild(
+            createNode(
+              "div",
+              "branch-empty workspace-empty-state",
+              stale.loading || !stale.loaded
+                ? "Checking remote branches"
+                : `No merged or closed branches older than ${stale.minAgeDays} days`,
+            ),
+          );
+          return;
+        }
+
+        for (const entry of stale.entries) {
+          const row = createNode("label", "stale-remote-row");
+          row.dataset.staleBranch = entry.branch;
+          const checkbox = document.createElement("input");
+          checkbox.type = "checkbox";
+          checkbox.checked = stale.selected.has(entry.branch);
+          checkbox.disabled = stale.deleting;
+          checkbox.addEventListener("change", () => {
+            if (checkbox.checked) {
+              stale.selected.add(entry.branch);
+            } else {
+              stale.selected.delete(entry.branch);
+            }
+            renderBranches(windowId);
+          });
+          row.appendChild(checkbox);
+          row.appendChild(createNode("span", "branch-name-text", `origin/${entry.branch}`));
+          row.appendChild(
+            createNode(
+              "span",
+              "branch-summary",
+              `PR #${entry.pr_number} ${entry.pr_state} · ${entry.age_days} days`,
+            ),
+          );
+          list.appendChild(row);
+        }
       }
 
       function createBranchRow(windowId, branchName) {
@@ -183,10 +584,17 @@ export function createBranchesCleanupSurface({
         date.className = "branch-date";
         main.appendChild(date);
 
+        const path = document.createElement("div");
+        path.className = "branch-path";
+        main.appendChild(path);
+
         row.appendChild(main);
 
         const meta = document.createElement("div");
         meta.className = "branch-meta";
+        const ci = document.createElement("span");
+        ci.className = "branch-ci";
+        meta.appendChild(ci);
         const scope = document.createElement("span");
         scope.className = "branch-scope";
         meta.appendChild(scope);
@@ -225,9 +633,12 @@ export function createBranchesCleanupSurface({
           nameContainer,
           nameText,
           headBadge: null,
+          specBadge: null,
           upstream,
           date,
+          path,
           cleanupDetail: null,
+          ci,
           scope,
           cleanupBadge,
           summary,
@@ -282,6 +693,18 @@ export function createBranchesCleanupSurface({
         // (after the tabindex/role wiring above) but couldn't select
         // or open the launch wizard from it.
         row.addEventListener("keydown", (event) => {
+          const plainKey = !event.metaKey && !event.ctrlKey && !event.altKey;
+          if (plainKey && BRANCH_PATH_ACTION_KEYS[event.key]) {
+            event.preventDefault();
+            select();
+            runBranchPathAction(windowId, branchName, BRANCH_PATH_ACTION_KEYS[event.key]);
+            return;
+          }
+          if (plainKey && event.key === "p") {
+            event.preventDefault();
+            toggleBranchPathColumn(windowId);
+            return;
+          }
           if (event.key !== "Enter" && event.key !== " ") return;
           event.preventDefault();
           if (event.key === "Enter" && (event.metaKey || event.ctrlKey)) {
@@ -318,8 +741,45 @@ export function createBranchesCleanupSurface({
           fields.headBadge = null;
         }
 
+        if (entry.linked_spec) {
+          if (!fields.specBadge) {
+            const spec = document.createElement("span");
+            spec.className = "branch-spec";
+            fields.nameContainer.appendChild(spec);
+            fields.specBadge = spec;
+          }
+          fields.specBadge.textContent = `SPEC-${entry.linked_spec}`;
+          fields.specBadge.title = `Linked to spec issue #${entry.linked_spec}`;
+        } else if (fields.specBadge) {
+          fields.specBadge.remove();
+          fields.specBadge = null;
+        }
+
         fields.upstream.textContent = entry.upstream || "No upstream";
         fields.date.textContent = entry.last_commit_date || "No commit date";
+        fields.path.hidden = !state.showPath;
+        fields.path.textContent =
+          entry.worktree_path || (entry.archived ? "Archived" : "No worktree");
+        fields.path.classList.toggle("missing", !entry.worktree_path);
+        fields.path.title = entry.worktree_path
+          ? `${entry.worktree_path} — C copy, O open, E editor`
+          : entry.archived
+            ? "R restore the archived worktree"
+            : "";
+
+        const integrityIssues = entry.integrity_issues || [];
+        if (integrityIssues.length > 0) {
+          if (!fields.integrity) {
+            const integrity = document.createElement("div");
+            fields.main.appendChild(integrity);
+            fields.integrity = integrity;
+          }
+          fields.integrity.className = "branch-cleanup-detail blocked branch-integrity";
+          fields.integrity.textContent = `Integrity: ${integrityIssues.join(", ")} — F repair`;
+        } else if (fields.integrity) {
+          fields.integrity.remove();
+          fields.integrity = null;
+        }
 
         const cleanupDetail = cleanupDetailText(entry, state);
         if (cleanupDetail) {
@@ -337,6 +797,12 @@ export function createBranchesCleanupSurface({
           fields.cleanupDetail = null;
         }
 
+        const ci = state.ciStatuses[entry.name];
+        fields.ci.hidden = !ci;
+        fields.ci.className = `branch-ci ${ci?.status || ""}`.trim();
+        fields.ci.textContent = ci ? BRANCH_CI_GLYPHS[ci.status] || "" : "";
+        fields.ci.title = ci ? `CI ${ci.status}${ci.url ? ` — ${ci.url}` : ""}` : "";
+
         fields.scope.textContent = entry.scope;
         fields.cleanupBadge.className =
           `branch-cleanup-badge ${cleanupAvailabilityForRender(entry, state)}`;
@@ -394,6 +860,11 @@ export function createBranchesCleanupSurface({
         for (const button of element.querySelectorAll("[data-branch-filter]")) {
           button.classList.toggle("active", button.dataset.branchFilter === state.filter);
         }
+        const pathToggle = element.querySelector("[data-action='toggle-branch-path']");
+        if (pathToggle) {
+          pathToggle.classList.toggle("active", state.showPath);
+          pathToggle.setAttribute("aria-pressed", String(state.showPath));
+        }
         if (cleanupButton) {
           const selectedCount = selectedBranchCleanupEntries(windowId).length;
           cleanupButton.disabled = selectedCount === 0;
@@ -401,6 +872,20 @@ export function createBranchesCleanupSurface({
             selectedCount === 0 ? "Clean Up" : `Clean Up (${selectedCount})`;
         }
         renderBranchLoadStatusSummary(notice, branchLoadStatusSummary(state));
+        renderRebaseConflicts(windowId, notice, state);
+        renderExternalAgents(windowId, notice, state);
+
+        if (state.filter === "stale") {
+          renderStaleRemoteBranches(windowId, list, state);
+          renderBranchCleanupModal();
+          return;
+        }
+
+        if (state.filter === "removed") {
+          renderRemovedWorktrees(windowId, list, state);
+          renderBranchCleanupModal();
+          return;
+        }
 
         if (state.error) {
           setBranchListPlaceholder(list, state.error);
@@ -424,6 +909,17 @@ export function createBranchesCleanupSurface({
           return;
         }
 
+        const scroll = element.querySelector(".branch-scroll");
+        const renderWindow = branchRenderWindow({
+          total: visibleEntries.length,
+          scrollTop: scroll ? scroll.scrollTop : 0,
+          viewportHeight: scroll ? scroll.clientHeight : 0,
+          rowHeight: state.rowHeight || BRANCH_ROW_HEIGHT_ESTIMATE,
+        });
+        const windowEntries = renderWindow.virtualized
+          ? visibleEntries.slice(renderWindow.start, renderWindow.end)
+          : visibleEntries;
+
         const existingRows = new Map();
         for (const child of Array.from(list.children)) {
           if (child.classList.contains("branch-row") && child.dataset.branchName) {
@@ -435,7 +931,7 @@ export function createBranchesCleanupSurface({
 
         let prevSibling = null;
         const usedNames = new Set();
-        for (const entry of visibleEntries) {
+        for (const entry of windowEntries) {
           let row = existingRows.get(entry.name);
           if (!row) {
             row = createBranchRow(windowId, entry.name);
@@ -455,9 +951,77 @@ export function createBranchesCleanupSurface({
           }
         }
 
+        if (renderWindow.virtualized) {
+          renderVirtualSpacers(list, state, renderWindow, visibleEntries.length, windowEntries.length);
+        }
+        if (state.phase === "lazy") {
+          scheduleBranchHydration(
+            windowId,
+            state,
+            unhydratedBranchNames(visibleEntries, renderWindow.start, renderWindow.end),
+          );
+        }
+
         renderBranchCleanupModal();
       }
 
+      // Long lists render only the rows around the viewport; spacers above
+      // and below keep the scroll height (and position) of the full list.
+      // The row height estimate is refined from the rows just rendered.
+      function renderVirtualSpacers(list, state, renderWindow, total, renderedCount) {
+        const rows = list.querySelectorAll(".branch-row");
+        if (rows.length > 0 && renderedCount > 0) {
+          let height = 0;
+          for (const row of rows) height += row.offsetHeight;
+          if (height > 0) state.rowHeight = height / rows.length;
+        }
+        const rowHeight = state.rowHeight || BRANCH_ROW_HEIGHT_ESTIMATE;
+        const top = document.createElement("div");
+        top.className = "branch-virtual-spacer";
+        top.setAttribute("aria-hidden", "true");
+        top.style.height = `${Math.round(renderWindow.start * rowHeight)}px`;
+        list.insertBefore(top, list.firstChild);
+        const bottom = document.createElement("div");
+        bottom.className = "branch-virtual-spacer";
+        bottom.setAttribute("aria-hidden", "true");
+        bottom.style.height = `${Math.round((total - renderWindow.end) * rowHeight)}px`;
+        list.appendChild(bottom);
+      }
+
+      // Ask the backend to check the rows around the viewport of a lazy
+      // load. Debounced so a fast scroll only requests where it stops; the
+      // backend cancels a still-running request for the same window.
+      function scheduleBranchHydration(windowId, state, names) {
+        const key = names.join("\n");
+        if (!key || key === state.hydrationRequestKey) {
+          return;
+        }
+        if (state.hydrationTimer) {
+          clearTimeout(state.hydrationTimer);
+        }
+        state.hydrationTimer = setTimeout(() => {
+          state.hydrationTimer = null;
+          state.hydrationRequestKey = key;
+          send({
+            kind: "hydrate_branches",
+            id: windowId,
+            branches: names,
+            load_id: state.lastLoadId,
+          });
+        }, 150);
+      }
+
+      function scheduleBranchViewportRender(windowId) {
+        const state = ensureBranchListState(windowId);
+        if (state.scrollFrame !== null) {
+          return;
+        }
+        state.scrollFrame = requestAnimationFrame(() => {
+          state.scrollFrame = null;
+          renderBranches(windowId);
+        });
+      }
+
       function filteredBranchEntries(state) {
         if (state.filter === "all") {
           return state.entries;
@@ -643,6 +1207,10 @@ export function createBranchesCleanupSurface({
               return "unmerged";
             case "protected_base":
               return "protected base (remote kept)";
+            case "protected_pattern":
+              return "protected";
+            case "unreliable_filesystem":
+              return "network filesystem";
             default:
               return "warning";
           }
@@ -691,6 +1259,142 @@ export function createBranchesCleanupSurface({
         }
       }
 
+      // Conflict list of a stopped rebase, appended to the notice band with
+      // the actions that finish it.
+      function renderExternalAgents(windowId, notice, state) {
+        const agents = state.externalAgents;
+        if (!notice || !agents) {
+          return;
+        }
+        notice.hidden = false;
+        const panel = document.createElement("div");
+        panel.className = "branch-external-agents";
+
+        const title = document.createElement("div");
+        title.className = "branch-notice-title";
+        title.textContent =
+          agents.length === 0
+            ? "No agents running outside gwt"
+            : "Agents running outside gwt";
+        panel.appendChild(title);
+
+        const branchNames = state.entries
+          .filter((entry) => entry.scope === "local")
+          .map((entry) => entry.name);
+        const list = document.createElement("ul");
+        list.className = "branch-external-agent-list";
+        for (const agent of agents) {
+          const item = document.createElement("li");
+          const label = document.createElement("span");
+          label.className = "branch-external-agent-label";
+          label.textContent = `${agent.agent} · pid ${agent.pid}`;
+          label.title = `${agent.command_line}\n${agent.cwd}`;
+          item.appendChild(label);
+
+          const select = document.createElement("select");
+          select.setAttribute("aria-label", `Branch for pid ${agent.pid}`);
+          const placeholder = document.createElement("option");
+          placeholder.value = "";
+          placeholder.textContent = "Choose branch…";
+          select.appendChild(placeholder);
+          const names = new Set(branchNames);
+          if (agent.branch) {
+            names.add(agent.branch);
+          }
+          for (const name of names) {
+            const option = document.createElement("option");
+            option.value = name;
+            option.textContent = name;
+            select.appendChild(option);
+          }
+          select.value = agent.branch || "";
+          item.appendChild(select);
+
+          const adopt = document.createElement("button");
+          adopt.type = "button";
+          adopt.className = "wizard-button";
+          adopt.textContent = "Adopt";
+          adopt.disabled = !select.value;
+          select.addEventListener("change", () => {
+            adopt.disabled = !select.value;
+          });
+          adopt.addEventListener("click", () => {
+            adopt.disabled = true;
+            send({
+              kind: "adopt_external_agent",
+              id: windowId,
+              pid: agent.pid,
+              branch: select.value,
+            });
+          });
+          item.appendChild(adopt);
+          list.appendChild(item);
+        }
+        panel.appendChild(list);
+
+        const dismiss = document.createElement("button");
+        dismiss.type = "button";
+        dismiss.className = "wizard-button";
+        dismiss.textContent = "Close";
+        dismiss.addEventListener("click", () => {
+          state.externalAgents = null;
+          renderBranches(windowId);
+        });
+        panel.appendChild(dismiss);
+        notice.appendChild(panel);
+      }
+
+      function renderRebaseConflicts(windowId, notice, state) {
+        const rebase = state.rebase;
+        if (!notice || !rebase) {
+          return;
+        }
+        notice.hidden = false;
+        const panel = document.createElement("div");
+        panel.className = "branch-rebase-panel";
+
+        const title = document.createElement("div");
+        title.className = "branch-notice-title";
+        title.textContent = `Rebase of ${rebase.branch} onto ${rebase.onto} stopped`;
+        panel.appendChild(title);
+        if (rebase.stoppedAt) {
+          const stoppedAt = document.createElement("div");
+          stoppedAt.className = "branch-notice-detail";
+          stoppedAt.textContent = `While applying: ${rebase.stoppedAt}`;
+          panel.appendChild(stoppedAt);
+        }
+
+        const list = document.createElement("ul");
+        list.className = "branch-rebase-conflicts";
+        for (const conflict of rebase.conflicts) {
+          const item = document.createElement("li");
+          const path = document.createElement("code");
+          path.textContent = conflict.path;
+          item.appendChild(path);
+          item.append(` — ${String(conflict.kind).replaceAll("_", " ")}`);
+          list.appendChild(item);
+        }
+        panel.appendChild(list);
+
+        const actions = document.createElement("div");
+        actions.className = "branch-rebase-actions";
+        const action = (label, kind) => {
+          const button = document.createElement("button");
+          button.type = "button";
+          button.className = "wizard-button";
+          button.textContent = label;
+          button.addEventListener("click", () => {
+            send({ kind, id: windowId, branch: rebase.branch });
+          });
+          actions.appendChild(button);
+        };
+        action("Open agent to resolve", "resolve_rebase_conflicts");
+        action("Continue", "continue_rebase");
+        action("Abort", "abort_rebase");
+        panel.appendChild(actions);
+        notice.appendChild(panel);
+      }
+
       function failLoadingBranchesOnConnectionLoss(windowId, state) {
         // FR-064/FR-065: keep the rows + last-known cleanup badges, flag the
         // window for auto re-hydration on reconnect, and stop the spinner —
@@ -772,6 +1476,7 @@ export function createBranchesCleanupSurface({
         state.cleanupModal.stage = "confirm";
         state.cleanupModal.deleteRemote = false;
         state.cleanupModal.forceFilesystemDelete = false;
+        state.cleanupModal.confirmations = {};
         state.cleanupModal.progress = null;
         state.cleanupModal.results = [];
         branchCleanupWindowId = windowId;
@@ -828,9 +1533,34 @@ export function createBranchesCleanupSurface({
           branches,
           delete_remote: state.cleanupModal.deleteRemote,
           force_filesystem_delete: state.cleanupModal.forceFilesystemDelete,
+          confirmed_protected: Object.entries(
+            state.cleanupModal.confirmations || {},
+          )
+            .filter(([branch, typed]) => branch === typed)
+            .map(([branch]) => branch),
         });
       }
 
+      // Cleanup alternative: archive the selected branches' worktrees and
+      // keep the branches. Results arrive as `branch_cleanup_result`.
+      function archiveBranchWorktrees(windowId) {
+        const state = ensureBranchListState(windowId);
+        const branches = selectedBranchCleanupEntries(windowId)
+          .filter((entry) => entry.worktree_path)
+          .map((entry) => entry.name);
+        if (branches.length === 0) {
+          state.notice = "None of the selected branches has a worktree";
+          renderBranches(windowId);
+          return;
+        }
+        state.notice = "";
+        state.cleanupModal.stage = "running";
+        state.cleanupModal.progress = initialBranchCleanupProgress(branches);
+        state.cleanupModal.results = [];
+        renderBranchCleanupModal();
+        send({ kind: "archive_worktrees", id: windowId, branches });
+      }
+
       function branchCleanupResultSummary(results) {
         const counts = { success: 0, partial: 0, failed: 0 };
         for (const result of results || []) {
@@ -857,6 +1587,10 @@ export function createBranchesCleanupSurface({
           riskLabels: cleanupRiskLabels,
           onCancel: () => closeBranchCleanupModal(windowId),
           onSubmit: () => runBranchCleanup(windowId),
+          onArchive:
+            windowId === WORKSPACE_CLEANUP_WINDOW_ID
+              ? null
+              : () => archiveBranchWorktrees(windowId),
           onDeleteRemoteToggle: (checked) => {
             if (state) {
               state.cleanupModal.deleteRemote = checked;
@@ -867,6 +1601,14 @@ export function createBranchesCleanupSurface({
               state.cleanupModal.forceFilesystemDelete = checked;
             }
           },
+          onConfirmationInput: (branch, typed) => {
+            if (state) {
+              state.cleanupModal.confirmations = {
+                ...(state.cleanupModal.confirmations || {}),
+                [branch]: typed,
+              };
+            }
+          },
         });
       }
 
@@ -896,12 +1638,16 @@ export function createBranchesCleanupSurface({
                     <button class="branch-filter-button" type="button" data-branch-filter="local">Local</button>
                     <button class="branch-filter-button" type="button" data-branch-filter="remote">Remote</button>
                     <button class="branch-filter-button" type="button" data-branch-filter="all">All</button>
+                    <button class="branch-filter-button" type="button" data-branch-filter="stale" title="Remote branches whose PRs are merged or closed">Stale</button>
+                    <button class="branch-filter-button" type="button" data-branch-filter="removed" title="Worktrees removed by cleanup, kept in .gwt-trash">Removed</button>
                   </div>
                 </div>
                 <div class="branch-toolbar-actions workspace-toolbar-actions">
                   <div class="branch-selection-actions">
                     <button class="wizard-button branch-cleanup-trigger" type="button" data-action="open-branch-cleanup">Clean Up</button>
                   </div>
+                  <button class="branch-filter-button" type="button" data-action="find-external-agents" title="Agents started outside gwt">Adopt</button>
+                  <button class="branch-filter-button" type="button" data-action="toggle-branch-path" aria-pressed="false" title="Show worktree paths (P)">Path</button>
                   <button class="icon-button" data-action="refresh-branches" aria-label="Refresh branches">↻</button>
                 </div>
               </div>
@@ -924,9 +1670,43 @@ export function createBranchesCleanupSurface({
               );
               state.error = "";
               state.notice = "";
-              requestBranches(windowData.id);
+              if (state.filter === "stale") {
+                requestStaleRemoteBranches(windowData.id);
+              } else if (state.filter === "removed") {
+                requestRemovedWorktrees(windowData.id);
+              } else {
+                requestBranches(windowData.id);
+              }
               renderBranches(windowData.id);
             });
+          body
+            .querySelector(".branch-scroll")
+            .addEventListener(
+              "scroll",
+              () => {
+                const state = ensureBranchListState(windowData.id);
+                if (
+                  state.entries.length > 0 &&
+                  state.filter !== "stale" &&
+                  state.filter !== "removed"
+                ) {
+                  scheduleBranchViewportRender(windowData.id);
+                }
+              },
+              { passive: true },
+            );
+          body
+            .querySelector("[data-action='find-external-agents']")
+            .addEventListener("click", (event) => {
+              event.stopPropagation();
+              send({ kind: "detect_external_agents", id: windowData.id });
+            });
+          body
+            .querySelector("[data-action='toggle-branch-path']")
+            .addEventListener("click", (event) => {
+              event.stopPropagation();
+              toggleBranchPathColumn(windowData.id);
+            });
           for (const button of body.querySelectorAll("[data-branch-filter]")) {
             button.addEventListener("click", (event) => {
               event.stopPropagation();
@@ -934,6 +1714,12 @@ export function createBranchesCleanupSurface({
                 windowData.id,
               );
               state.filter = button.dataset.branchFilter;
+              if (state.filter === "stale" && !state.stale.loaded) {
+                requestStaleRemoteBranches(windowData.id);
+              }
+              if (state.filter === "removed") {
+                requestRemovedWorktrees(windowData.id);
+              }
               renderBranches(windowData.id);
             });
           }
@@ -991,6 +1777,137 @@ export function createBranchesCleanupSurface({
             renderBranchCleanupOwner(event.id);
             break;
           }
+          case "branch_rebase_result": {
+            const state = ensureBranchListState(
+              event.id,
+            );
+            const outcome = event.outcome;
+            state.rebase =
+              outcome?.status === "conflicted"
+                ? {
+                    branch: event.branch,
+                    onto: outcome.onto,
+                    stoppedAt: outcome.stopped_at || "",
+                    conflicts: outcome.conflicts || [],
+                  }
+                : null;
+            state.notice = event.message;
+            state.error = "";
+            requestBranches(event.id);
+            renderBranches(event.id);
+            break;
+          }
+          case "branch_ci_statuses": {
+            const state = ensureBranchListState(
+              event.id,
+            );
+            state.ciStatuses = event.statuses || {};
+            renderBranches(event.id);
+            break;
+          }
+          case "external_agents": {
+            const state = ensureBranchListState(
+              event.id,
+            );
+            state.externalAgents = event.agents || [];
+            renderBranches(event.id);
+            break;
+          }
+          case "external_agent_adopted": {
+            const state = ensureBranchListState(
+              event.id,
+            );
+            if (state.externalAgents) {
+              state.externalAgents = state.externalAgents.filter(
+                (agent) => agent.pid !== event.pid,
+              );
+            }
+            state.notice = event.message;
+            state.error = "";
+            requestBranches(event.id);
+            renderBranches(event.id);
+            break;
+          }
+          case "worktree_stash_result":
+          case "worktree_restore_result":
+          case "worktree_repair_result":
+          case "worktree_transplant_result":
+          case "ci_artifacts_download_result":
+          case "pull_request_create_result": {
+            const state = ensureBranchListState(
+              event.id,
+            );
+            state.notice = event.message;
+            state.error = "";
+            if (state.removed.restoring) {
+              state.removed.restoring = "";
+              requestRemovedWorktrees(event.id);
+            }
+            requestBranches(event.id);
+            renderBranches(event.id);
+            break;
+          }
+          case "git_refs_changed": {
+            // A gwt-installed git hook saw a commit or push, or a background
+            // fetch moved remote refs; reload every open Branches list so
+            // safety and PR state catch up.
+            for (const windowId of branchListStateMap.keys()) {
+              requestBranches(windowId);
+            }
+            break;
+          }
+          case "stale_remote_branches": {
+            const state = ensureBranchListState(
+              event.id,
+            );
+            const entries = event.entries || [];
+            const listed = new Set(entries.map((entry) => entry.branch));
+            state.stale.loading = false;
+            state.stale.loaded = true;
+            state.stale.entries = entries;
+            state.stale.selected = new Set(
+              Array.from(state.stale.selected).filter((branch) => listed.has(branch)),
+            );
+            renderBranches(event.id);
+            break;
+          }
+          case "removed_worktrees": {
+            const state = ensureBranchListState(
+              event.id,
+            );
+            state.removed.loading = false;
+            state.removed.loaded = true;
+            state.removed.entries = event.entries || [];
+            renderBranches(event.id);
+            break;
+          }
+          case "stale_remote_branches_deleted": {
+            const state = ensureBranchListState(
+              event.id,
+            );
+            const results = event.results || [];
+            const deleted = new Set(
+              results
+                .filter((result) => result.status === "success")
+                .map((result) => result.branch),
+            );
+            const failed = results.filter((result) => result.status !== "success");
+            state.stale.deleting = false;
+            state.stale.entries = state.stale.entries.filter(
+              (entry) => !deleted.has(entry.branch),
+            );
+            for (const branch of deleted) {
+              state.stale.selected.delete(branch);
+            }
+            state.notice =
+              failed.length === 0
+                ? `Deleted ${deleted.size} remote branch${deleted.size === 1 ? "" : "es"}`
+                : `Deleted ${deleted.size}, failed ${failed.length}: ` +
+                  failed.map((result) => `${result.branch} (${result.message})`).join("; ");
+            requestBranches(event.id);
+            renderBranches(event.id);
+            break;
+          }
           case "branch_error": {
             // SPEC-2359 W-17 (FR-398): a failed branch resume must re-enable
             // its pending Resume control immediately (not via timeout).
@@ -999,6 +1916,20 @@ export function createBranchesCleanupSurface({
               event.id,
             );
             state.loading = false;
+            if (state.stale.loading || state.stale.deleting) {
+              state.stale.loading = false;
+              state.stale.deleting = false;
+              state.notice = event.message;
+              renderBranches(event.id);
+              break;
+            }
+            if (state.removed.loading || state.removed.restoring) {
+              state.removed.loading = false;
+              state.removed.restoring = "";
+              state.notice = event.message;
+              renderBranches(event.id);
+              break;
+            }
             if (state.cleanupModal.stage === "running") {
               failRunningBranchCleanup(event.id, event.message);
               renderBranchCleanupOwner(event.id);
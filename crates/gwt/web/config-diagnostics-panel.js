@@ -0,0 +1,65 @@
+// Settings banner for problems in `~/.gwt/config.toml`.
+//
+// The backend validates the config file whenever a Settings window asks
+// for the System snapshot and replies with `config_diagnostics`. Errors mean
+// the file did not parse and gwt runs on the default settings; warnings are
+// ignored keys and dropped entries. The banner stays hidden while the file
+// is clean. Lines read like `gwt config validate` output.
+
+export function formatConfigDiagnostic(diagnostic = {}) {
+  const position =
+    diagnostic.line != null && diagnostic.column != null
+      ? `${diagnostic.line}:${diagnostic.column}: `
+      : "";
+  const key = diagnostic.key ? `${diagnostic.key}: ` : "";
+  return `${position}${diagnostic.severity || "error"}: ${key}${diagnostic.message || ""}`;
+}
+
+function plural(count, noun) {
+  return `${count} ${noun}${count === 1 ? "" : "s"}`;
+}
+
+export function configDiagnosticsSummary(diagnostics = []) {
+  const errors = diagnostics.filter((d) => d.severity === "error").length;
+  const warnings = diagnostics.length - errors;
+  const counts = [
+    errors > 0 ? plural(errors, "error") : "",
+    warnings > 0 ? plural(warnings, "warning") : "",
+  ]
+    .filter(Boolean)
+    .join(", ");
+  return errors > 0
+    ? `${counts} — gwt is using the default settings until the file is fixed.`
+    : `${counts} — the listed keys are ignored.`;
+}
+
+export function renderConfigDiagnostics({
+  container,
+  path = "",
+  diagnostics = [],
+  document: doc,
+}) {
+  if (!container) return;
+  const ownerDoc = doc || container.ownerDocument || globalThis.document;
+  while (container.firstChild) container.removeChild(container.firstChild);
+  container.hidden = diagnostics.length === 0;
+  if (diagnostics.length === 0) return;
+  container.dataset.kind = diagnostics.some((d) => d.severity === "error")
+    ? "error"
+    : "warning";
+
+  const title = ownerDoc.createElement("p");
+  title.className = "settings-config-diagnostics__summary";
+  title.textContent = `${path || "config.toml"}: ${configDiagnosticsSummary(diagnostics)}`;
+  container.appendChild(title);
+
+  const list = ownerDoc.createElement("ul");
+  list.className = "settings-config-diagnostics__list";
+  for (const diagnostic of diagnostics) {
+    const item = ownerDoc.createElement("li");
+    item.dataset.severity = diagnostic.severity || "error";
+    item.textContent = formatConfigDiagnostic(diagnostic);
+    list.appendChild(item);
+  }
+  container.appendChild(list);
+}
@@ -47,6 +47,27 @@ export function createFileTreeSurface({
             selectedWorktreeId: "",
             selectedWorktreeLabel: "",
             splitterRatio: 0.4,
+            // Changes pane: the left pane lists changed files instead of
+            // the tree, and the viewer shows their diff.
+            changes: {
+              open: false,
+              loading: false,
+              entries: [],
+              error: "",
+            },
+            // Commit form under the Changes list. `selected` holds the
+            // unstaged/untracked paths to stage before committing; whatever
+            // is already staged is committed as well.
+            commit: {
+              selected: new Set(),
+              message: "",
+              signoff: false,
+              gpgSign: false,
+              busy: "", // "" | suggest | commit
+              error: "",
+              notice: "",
+            },
+            diffLayout: "unified", // unified | split
             viewer: {
               path: "",
               mode: "empty", // empty | text | binary | hex | error | loading
@@ -454,6 +475,9 @@ export function createFileTreeSurface({
             // Re-emit the worktree selection that was queued behind the modal.
             selectFileTreeWorktree(windowId, pending.worktreeId);
             break;
+          case "open_diff":
+            beginViewerForDiff(windowId, pending.path, pending.staged);
+            break;
           case "close_window":
             // Forward to the backend close path so persistence stays in sync.
             send({ kind: "close_window", id: windowId });
@@ -493,6 +517,378 @@ export function createFileTreeSurface({
         requestFileContent(windowId, path, "text");
       }
 
+      function requestGitChanges(windowId) {
+        const state = ensureFileTreeState(windowId);
+        state.changes.loading = true;
+        state.changes.error = "";
+        send({ kind: "load_git_changes", id: windowId });
+      }
+
+      function beginViewerForDiff(windowId, path, staged) {
+        const state = ensureFileTreeState(windowId);
+        // `diff: null` renders as loading until the file_diff reply lands.
+        state.viewer = {
+          ...state.viewer,
+          path,
+          mode: "diff",
+          text: "",
+          totalSize: 0,
+          error: { kind: "", message: "", size: null, limit: null },
+          dirty: false,
+          diff: null,
+          diffStaged: Boolean(staged),
+          diffError: "",
+        };
+        renderFileTreeViewer(windowId);
+        send({ kind: "load_file_diff", id: windowId, path, staged: Boolean(staged) });
+      }
+
+      function changeEntryStatus(entry) {
+        return String((entry && entry.status) || "").toLowerCase();
+      }
+
+      // Open a Changes pane entry: untracked files have no diff yet, so they
+      // go to the text viewer; everything else opens its staged or unstaged
+      // diff.
+      function openChangeEntry(windowId, entry) {
+        const state = ensureFileTreeState(windowId);
+        const status = changeEntryStatus(entry);
+        const pending =
+          status === "untracked"
+            ? { kind: "switch_file", path: entry.path }
+            : { kind: "open_diff", path: entry.path, staged: status === "staged" };
+        state.selectedPath = entry.path;
+        if (!queueNavigationGuardedByDirty(windowId, pending)) {
+          runPendingNavigation(windowId, pending);
+        }
+        renderFileTree(windowId);
+      }
+
+      function isCurrentChangeEntry(viewer, entry) {
+        if (viewer.path !== entry.path) return false;
+        const status = changeEntryStatus(entry);
+        if (viewer.mode === "diff") {
+          return status !== "untracked" && viewer.diffStaged === (status === "staged");
+        }
+        return status === "untracked";
+      }
+
+      // Step to the previous (-1) or next (+1) changed file.
+      function stepChangeEntry(windowId, delta) {
+        const state = ensureFileTreeState(windowId);
+        const entries = state.changes.entries;
+        if (entries.length === 0) return;
+        const current = entries.findIndex((entry) => isCurrentChangeEntry(state.viewer, entry));
+        const next = current < 0 ? 0 : (current + delta + entries.length) % entries.length;
+        openChangeEntry(windowId, entries[next]);
+      }
+
+      function canCommit(state) {
+        const { commit } = state;
+        const hasStaged = state.changes.entries.some(
+          (entry) => changeEntryStatus(entry) === "staged",
+        );
+        return (
+          !commit.busy && commit.message.trim() !== "" && (hasStaged || commit.selected.size > 0)
+        );
+      }
+
+      function requestCommitMessageSuggestion(windowId) {
+        const state = ensureFileTreeState(windowId);
+        state.commit.busy = "suggest";
+        state.commit.error = "";
+        state.commit.notice = "";
+        send({ kind: "suggest_commit_message", id: windowId });
+        renderFileTree(windowId);
+      }
+
+      function submitCommit(windowId) {
+        const state = ensureFileTreeState(windowId);
+        if (!canCommit(state)) return;
+        const { commit } = state;
+        commit.busy = "commit";
+        commit.error = "";
+        commit.notice = "";
+        send({
+          kind: "create_commit",
+          id: windowId,
+          paths: [...commit.selected],
+          message: commit.message,
+          signoff: commit.signoff,
+          gpg_sign: commit.gpgSign,
+        });
+        renderFileTree(windowId);
+      }
+
+      function commitOption(label, checked, onChange) {
+        const input = makeEl("input", { attrs: { type: "checkbox" } });
+        input.checked = checked;
+        input.addEventListener("change", () => onChange(input.checked));
+        return makeEl("label", { className: "file-tree-commit-option" }, [input, label]);
+      }
+
+      function renderCommitForm(windowId, list) {
+        const state = ensureFileTreeState(windowId);
+        const { commit } = state;
+        const message = makeEl("textarea", {
+          className: "file-tree-commit-message",
+          attrs: { placeholder: "Commit message", rows: "4", "aria-label": "Commit message" },
+        });
+        message.value = commit.message;
+        message.disabled = Boolean(commit.busy);
+        const commitBtn = makeEl("button", {
+          className: "file-tree-commit-submit",
+          text: commit.busy === "commit" ? "Committing…" : "Commit",
+          attrs: { type: "button" },
+        });
+        commitBtn.disabled = !canCommit(state);
+        message.addEventListener("input", () => {
+          commit.message = message.value;
+          commitBtn.disabled = !canCommit(state);
+        });
+        message.addEventListener("keydown", (event) => {
+          if (event.key === "Enter" && (event.ctrlKey || event.metaKey)) {
+            event.preventDefault();
+            submitCommit(windowId);
+          }
+        });
+        commitBtn.addEventListener("click", () => submitCommit(windowId));
+        const suggestBtn = makeEl("button", {
+          className: "file-tree-commit-suggest",
+          text: commit.busy === "suggest" ? "Suggesting…" : "Suggest",
+          attrs: { type: "button", title: "Suggest a message for the staged changes with AI" },
+        });
+        suggestBtn.disabled = Boolean(commit.busy);
+        suggestBtn.addEventListener("click", () => requestCommitMessageSuggestion(windowId));
+        const status = commit.error || commit.notice;
+        list.appendChild(
+          makeEl("div", { className: "file-tree-commit-form" }, [
+            message,
+            makeEl("div", { className: "file-tree-commit-options" }, [
+              commitOption("Sign off", commit.signoff, (checked) => {
+                commit.signoff = checked;
+              }),
+              commitOption("GPG sign", commit.gpgSign, (checked) => {
+                commit.gpgSign = checked;
+              }),
+            ]),
+            makeEl("div", { className: "file-tree-commit-actions" }, [suggestBtn, commitBtn]),
+            status
+              ? makeEl("div", {
+                  className: "file-tree-commit-status",
+                  text: status,
+                  dataset: { kind: commit.error ? "error" : "notice" },
+                })
+              : null,
+          ]),
+        );
+      }
+
+      function renderChangesList(windowId, list, footer) {
+        const state = ensureFileTreeState(windowId);
+        const { changes } = state;
+        footer.textContent = changes.entries.length + " changed";
+        if (changes.error) {
+          list.appendChild(
+            makeEl("div", {
+              className: "file-tree-empty workspace-empty-state",
+              text: changes.error,
+            }),
+          );
+        }
+        if (changes.entries.length === 0) {
+          list.appendChild(
+            makeEl("div", {
+              className: "file-tree-empty workspace-empty-state",
+              text: changes.loading ? "Loading changes" : "No changes",
+            }),
+          );
+          return;
+        }
+        for (const entry of changes.entries) {
+          const status = changeEntryStatus(entry);
+          // Staged rows are committed regardless; the others opt in.
+          const pick = makeEl("input", {
+            className: "file-tree-change-pick",
+            attrs: {
+              type: "checkbox",
+              "aria-label": `Include ${entry.path} in the commit`,
+              title: status === "staged" ? "Already staged" : "Stage and include in the commit",
+            },
+          });
+          pick.checked = status === "staged" || state.commit.selected.has(entry.path);
+          pick.disabled = status === "staged";
+          pick.addEventListener("click", (event) => event.stopPropagation());
+          pick.addEventListener("change", () => {
+            if (pick.checked) {
+              state.commit.selected.add(entry.path);
+            } else {
+              state.commit.selected.delete(entry.path);
+            }
+            renderFileTree(windowId);
+          });
+          const row = makeEl(
+            "div",
+            {
+              className: "file-tree-row file-tree-change-row",
+              attrs: { tabindex: "0", role: "button" },
+            },
+            [
+              pick,
+              makeEl("span", {
+                className: "file-tree-change-status",
+                text: status,
+                dataset: { status },
+              }),
+              makeEl("span", { className: "tree-name", text: entry.path }),
+            ],
+          );
+          if (isCurrentChangeEntry(state.viewer, entry)) {
+            row.classList.add("selected");
+            row.setAttribute("aria-current", "true");
+          }
+          row.addEventListener("click", () => openChangeEntry(windowId, entry));
+          row.addEventListener("keydown", (event) => {
+            if (event.target !== row) return;
+            if (event.key === "Enter" || event.key === " ") {
+              event.preventDefault();
+              openChangeEntry(windowId, entry);
+            }
+          });
+          list.appendChild(row);
+        }
+        renderCommitForm(windowId, list);
+      }
+
+      // Highlight a single diff line. Lines are highlighted on their own,
+      // so constructs spanning lines (block comments, strings) may render
+      // plain; that is the trade-off for keeping per-line rows.
+      function highlightDiffLine(codeEl, text, language) {
+        codeEl.textContent = text;
+        if (!language || !text) return;
+        loadHighlightModule().then((hljs) => {
+          if (!hljs || !hljs.getLanguage || !hljs.getLanguage(language)) return;
+          try {
+            codeEl.innerHTML = hljs.highlight(text, { language, ignoreIllegals: true }).value;
+          } catch (e) {
+            codeEl.textContent = text;
+          }
+        });
+      }
+
+      const DIFF_MARKERS = { added: "+", removed: "-", context: " " };
+
+      // One diff line: the given line-number gutters, the +/- marker and
+      // the highlighted text. `line === null` is the blank filler opposite
+      // an unpaired line in the side-by-side layout.
+      function diffCell(line, numbers, language) {
+        if (!line) {
+          return makeEl("div", { className: "file-tree-diff-cell", dataset: { kind: "empty" } });
+        }
+        const code = makeEl("code", { className: "file-tree-diff-code hljs" });
+        highlightDiffLine(code, line.text || "", language);
+        return makeEl(
+          "div",
+          { className: "file-tree-diff-cell", dataset: { kind: line.kind } },
+          [
+            ...numbers.map((number) =>
+              makeEl("span", {
+                className: "file-tree-diff-lineno",
+                text: number == null ? "" : number,
+              }),
+            ),
+            makeEl("span", {
+              className: "file-tree-diff-marker",
+              text: DIFF_MARKERS[line.kind] || " ",
+            }),
+            code,
+          ],
+        );
+      }
+
+      // Side-by-side rows: context lines sit on both sides; a run of removed
+      // lines is paired with the added lines that follow it.
+      function splitDiffRows(lines) {
+        const rows = [];
+        let index = 0;
+        while (index < lines.length) {
+          const line = lines[index];
+          if (line.kind === "context") {
+            rows.push([line, line]);
+            index += 1;
+            continue;
+          }
+          const removed = [];
+          const added = [];
+          while (index < lines.length && lines[index].kind === "removed") {
+            removed.push(lines[index]);
+            index += 1;
+          }
+          while (index < lines.length && lines[index].kind === "added") {
+            added.push(lines[index]);
+            index += 1;
+          }
+          for (let i = 0; i < Math.max(removed.length, added.length); i += 1) {
+            rows.push([removed[i] || null, added[i] || null]);
+          }
+        }
+        return rows;
+      }
+
+      function renderDiffHunk(windowId, state, hunk, index, language) {
+        const v = state.viewer;
+        const action = makeEl("button", {
+          className: "wizard-button",
+          text: v.diffStaged ? "Unstage hunk" : "Stage hunk",
+          attrs: { type: "button" },
+          dataset: {
+            viewerAction: v.diffStaged ? "unstage-hunk" : "stage-hunk",
+            hunkIndex: index,
+          },
+        });
+        action.addEventListener("click", () => {
+          action.disabled = true;
+          send({
+            kind: "toggle_diff_hunk",
+            id: windowId,
+            path: v.path,
+            staged: Boolean(v.diffStaged),
+            hunk_index: index,
+          });
+        });
+        const section = makeEl("section", { className: "file-tree-diff-hunk" }, [
+          makeEl("div", { className: "file-tree-diff-hunk-header" }, [
+            makeEl("span", { className: "file-tree-diff-hunk-range", text: hunk.header }),
+            action,
+          ]),
+        ]);
+        const layout = state.diffLayout === "split" ? "split" : "unified";
+        const rows = makeEl("div", {
+          className: "file-tree-diff-rows",
+          dataset: { layout },
+        });
+        if (layout === "split") {
+          for (const [left, right] of splitDiffRows(hunk.lines || [])) {
+            rows.appendChild(
+              makeEl("div", { className: "file-tree-diff-row" }, [
+                diffCell(left, [left && left.old_line], language),
+                diffCell(right, [right && right.new_line], language),
+              ]),
+            );
+          }
+        } else {
+          for (const line of hunk.lines || []) {
+            rows.appendChild(
+              makeEl("div", { className: "file-tree-diff-row" }, [
+                diffCell(line, [line.old_line, line.new_line], language),
+              ]),
+            );
+          }
+        }
+        section.appendChild(rows);
+        return section;
+      }
+
       function queueNavigationGuardedByDirty(windowId, pendingAction) {
         const state = ensureFileTreeState(windowId);
         if (state.viewer.dirty) {
@@ -913,6 +1309,75 @@ export function createFileTreeSurface({
             body.appendChild(container);
             break;
           }
+          case "diff": {
+            header.appendChild(headerPath);
+            header.appendChild(
+              makeEl("span", {
+                className: "file-tree-viewer-meta",
+                text: v.diffStaged ? "staged" : "unstaged",
+              }),
+            );
+            const headerButton = (text, viewerAction, onClick) => {
+              const btn = makeEl("button", {
+                className: "wizard-button",
+                text,
+                attrs: { type: "button" },
+                dataset: { viewerAction },
+              });
+              btn.addEventListener("click", onClick);
+              header.appendChild(btn);
+            };
+            headerButton(v.diffStaged ? "Show unstaged" : "Show staged", "toggle-diff-side", () =>
+              beginViewerForDiff(windowId, v.path, !v.diffStaged),
+            );
+            headerButton(
+              state.diffLayout === "split" ? "Unified" : "Side by side",
+              "toggle-diff-layout",
+              () => {
+                state.diffLayout = state.diffLayout === "split" ? "unified" : "split";
+                renderFileTreeViewer(windowId);
+              },
+            );
+            if (state.changes.entries.length > 1) {
+              headerButton("‹", "previous-change", () => stepChangeEntry(windowId, -1));
+              headerButton("›", "next-change", () => stepChangeEntry(windowId, 1));
+            }
+            if (v.diffError) {
+              body.appendChild(
+                makeEl("div", { className: "file-tree-viewer-error", text: v.diffError }),
+              );
+            }
+            const diff = v.diff;
+            if (!diff) {
+              if (!v.diffError) {
+                body.appendChild(
+                  makeEl("div", { className: "file-tree-viewer-empty", text: "Loading…" }),
+                );
+              }
+            } else if (diff.binary) {
+              body.appendChild(
+                makeEl("div", {
+                  className: "file-tree-viewer-notice",
+                  text: "Binary file; there is no text diff to show.",
+                }),
+              );
+            } else if (!diff.hunks || diff.hunks.length === 0) {
+              body.appendChild(
+                makeEl("div", {
+                  className: "file-tree-viewer-empty",
+                  text: v.diffStaged
+                    ? "No staged changes in this file."
+                    : "No unstaged changes in this file.",
+                }),
+              );
+            } else {
+              const language = detectLanguageByExtension(v.path);
+              diff.hunks.forEach((hunk, index) => {
+                body.appendChild(renderDiffHunk(windowId, state, hunk, index, language));
+              });
+            }
+            break;
+          }
           case "error":
             header.appendChild(headerPath);
             body.appendChild(
@@ -978,6 +1443,10 @@ export function createFileTreeSurface({
           return;
         }
         list.innerHTML = "";
+        if (state.changes.open) {
+          renderChangesList(windowId, list, footer);
+          return;
+        }
         footer.textContent = state.selectedPath || ".";
 
         if (state.error) {
@@ -1120,7 +1589,14 @@ export function createFileTreeSurface({
             dataset: { action: "refresh-tree" },
             text: "↻",
           });
+          const changesBtn = makeEl("button", {
+            className: "icon-button file-tree-changes-toggle",
+            attrs: { "aria-label": "Show changes", "aria-pressed": "false", type: "button" },
+            dataset: { action: "toggle-changes" },
+            text: "±",
+          });
           toolbar.appendChild(pathLabel);
+          toolbar.appendChild(changesBtn);
           toolbar.appendChild(refreshBtn);
 
           const split = makeEl("div", { className: "file-tree-split" });
@@ -1175,6 +1651,11 @@ export function createFileTreeSurface({
               openWorktreePicker(windowData.id);
               return;
             }
+            if (state.changes.open) {
+              requestGitChanges(windowData.id);
+              renderFileTree(windowData.id);
+              return;
+            }
             state.loaded.clear();
             state.expanded.clear();
             state.loading.clear();
@@ -1183,6 +1664,21 @@ export function createFileTreeSurface({
             renderFileTree(windowData.id);
           });
 
+          changesBtn.addEventListener("click", (event) => {
+            event.stopPropagation();
+            const state = ensureFileTreeState(windowData.id);
+            if (!state.selectedWorktreeId) {
+              openWorktreePicker(windowData.id);
+              return;
+            }
+            state.changes.open = !state.changes.open;
+            changesBtn.setAttribute("aria-pressed", state.changes.open ? "true" : "false");
+            if (state.changes.open) {
+              requestGitChanges(windowData.id);
+            }
+            renderFileTree(windowData.id);
+          });
+
           // Splitter drag: pointer events keep the handler small and ignore
           // the canvas pan/zoom because the modal capture absorbs them.
           splitter.addEventListener("pointerdown", (event) => {
@@ -1288,6 +1784,17 @@ export function createFileTreeSurface({
             state.loading.clear();
             state.error = "";
             requestFileTree(event.id, "");
+            state.changes.entries = [];
+            state.commit = {
+              ...state.commit,
+              selected: new Set(),
+              busy: "",
+              error: "",
+              notice: "",
+            };
+            if (state.changes.open) {
+              requestGitChanges(event.id);
+            }
             renderFileTree(event.id);
             break;
           }
@@ -1419,6 +1926,95 @@ export function createFileTreeSurface({
             }
             break;
           }
+          case "git_changes": {
+            const state = ensureFileTreeState(event.id);
+            state.changes.entries = Array.isArray(event.entries) ? event.entries : [];
+            state.changes.loading = false;
+            state.changes.error = "";
+            const paths = new Set(state.changes.entries.map((entry) => entry.path));
+            for (const path of state.commit.selected) {
+              if (!paths.has(path)) state.commit.selected.delete(path);
+            }
+            renderFileTree(event.id);
+            break;
+          }
+          case "commit_message_suggestion": {
+            const state = ensureFileTreeState(event.id);
+            state.commit.busy = "";
+            state.commit.message = event.message || "";
+            renderFileTree(event.id);
+            break;
+          }
+          case "commit_created": {
+            const state = ensureFileTreeState(event.id);
+            const created = event.commit || {};
+            state.commit = {
+              ...state.commit,
+              selected: new Set(),
+              message: "",
+              busy: "",
+              error: "",
+              notice: `Committed ${created.hash || ""} ${created.subject || ""}`.trim(),
+            };
+            // The open diff no longer matches the index; reload it.
+            if (state.viewer.mode === "diff" && state.viewer.path) {
+              beginViewerForDiff(event.id, state.viewer.path, state.viewer.diffStaged);
+            }
+            renderFileTree(event.id);
+            break;
+          }
+          case "commit_error": {
+            const state = ensureFileTreeState(event.id);
+            state.commit.busy = "";
+            state.commit.error = event.message || "Commit failed";
+            state.commit.notice = "";
+            renderFileTree(event.id);
+            break;
+          }
+          case "file_diff": {
+            const state = ensureFileTreeState(event.id);
+            const diff = event.diff || {};
+            // Drop a diff that arrives after the user moved on.
+            if (
+              state.viewer.mode !== "diff" ||
+              state.viewer.path !== diff.path ||
+              state.viewer.diffStaged !== Boolean(diff.staged)
+            ) {
+              break;
+            }
+            state.viewer = {
+              ...state.viewer,
+              mode: "diff",
+              diff,
+              diffStaged: Boolean(diff.staged),
+              diffError: "",
+            };
+            renderFileTreeViewer(event.id);
+            renderFileTree(event.id);
+            break;
+          }
+          case "file_diff_error": {
+            const state = ensureFileTreeState(event.id);
+            if (!event.path) {
+              state.changes.loading = false;
+              state.changes.error = event.message || "Unable to list changes";
+              renderFileTree(event.id);
+              break;
+            }
+            if (state.viewer.path !== event.path) break;
+            if (state.viewer.mode === "diff") {
+              // Keep any diff on screen; a failed hunk action only adds a notice.
+              state.viewer.diffError = event.message || "";
+            } else {
+              state.viewer = {
+                ...state.viewer,
+                mode: "error",
+                error: { kind: "", message: event.message || "", size: null, limit: null },
+              };
+            }
+            renderFileTreeViewer(event.id);
+            break;
+          }
           case "file_content_error": {
             const state = ensureFileTreeState(
               event.id,
@@ -1477,6 +2073,8 @@ export function createFileTreeSurface({
         renderConflictModal,
         queueNavigationGuardedByDirty,
         beginViewerForFile,
+        beginViewerForDiff,
+        requestGitChanges,
         applyAfterSaveContinuation,
         mountFileTreeWindow,
         applyFileTreeReceiveEvent,
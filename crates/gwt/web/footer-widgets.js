@@ -0,0 +1,103 @@
+// Status Strip footer widgets from the `[[footer.widgets]]` config table.
+// The backend sends the resolved list on connect (`footer_widgets`); each
+// widget then refreshes on its own `refresh_secs`. Clock and running-agent
+// widgets render locally, profile and command widgets ask the backend with
+// `refresh_footer_widget` and fill in from `footer_widget_value`.
+
+const REMOTE_KINDS = new Set(["profile", "command"]);
+
+function pad2(value) {
+  return String(value).padStart(2, "0");
+}
+
+export function footerWidgetText(widget, state = {}) {
+  switch (widget.kind) {
+    case "clock": {
+      const now = state.now ?? new Date();
+      return `${pad2(now.getHours())}:${pad2(now.getMinutes())}`;
+    }
+    case "running_agents":
+      return String(state.runningAgents ?? 0);
+    default: {
+      const entry = state.values?.get(widget.index);
+      if (!entry) return "…";
+      return entry.error ? "—" : entry.value || "—";
+    }
+  }
+}
+
+export function createFooterWidgets({
+  doc,
+  send,
+  now = () => new Date(),
+  setTimer = (fn, ms) => setInterval(fn, ms),
+  clearTimer = (id) => clearInterval(id),
+}) {
+  let widgets = [];
+  let timers = [];
+  let runningAgents = 0;
+  const values = new Map();
+
+  const cell = () => doc.getElementById("op-strip-widgets");
+
+  function render() {
+    const target = cell();
+    if (!target) return;
+    target.hidden = widgets.length === 0;
+    target.replaceChildren(
+      ...widgets.map((widget) => {
+        const item = doc.createElement("span");
+        item.className = "op-status-strip__widget";
+        item.dataset.kind = widget.kind;
+        const label = doc.createElement("span");
+        label.className = "op-status-strip__label";
+        label.textContent = widget.label;
+        const value = doc.createElement("span");
+        value.className = "op-status-strip__value";
+        value.textContent = footerWidgetText(widget, {
+          now: now(),
+          runningAgents,
+          values,
+        });
+        const error = values.get(widget.index)?.error;
+        if (error) item.title = `${widget.label}: ${error}`;
+        else if (widget.command) item.title = widget.command;
+        item.append(label, value);
+        return item;
+      }),
+    );
+  }
+
+  function refresh(widget) {
+    if (REMOTE_KINDS.has(widget.kind)) {
+      send({ kind: "refresh_footer_widget", index: widget.index });
+    } else {
+      render();
+    }
+  }
+
+  function apply({ widgets: next = [] } = {}) {
+    for (const id of timers) clearTimer(id);
+    widgets = next;
+    values.clear();
+    timers = widgets.map((widget) => {
+      refresh(widget);
+      return setTimer(() => refresh(widget), Math.max(1, widget.refresh_secs) * 1000);
+    });
+    render();
+  }
+
+  function applyValue({ index, value = null, error = null } = {}) {
+    values.set(index, { value, error });
+    render();
+  }
+
+  function setRunningAgents(count) {
+    const next = Number(count) || 0;
+    if (next === runningAgents) return;
+    runningAgents = next;
+    if (widgets.some((widget) => widget.kind === "running_agents")) render();
+  }
+
+  return { apply, applyValue, setRunningAgents };
+}
@@ -1,6 +1,12 @@
 // SPEC-2356 — Operator Design System: Hotkey Manager.
 // Owns the `Hotkey Registry` entity. Combos use the form "cmd+p", "cmd+shift+?", etc.
 // "cmd" matches both metaKey (macOS Command) and ctrlKey (Windows/Linux Ctrl).
+//
+// Bindings may carry help metadata ({ label, group, scope }). Scoped bindings
+// only fire while `currentScope()` reports their screen, and `bindingsFor()`
+// feeds the contextual help overlay. `declare()` records keys a surface
+// handles itself so they still show up in help. Bindings tagged with an
+// `action` can be moved to user-configured combos with `rebind()`.
 
 const EDITABLE_TAGS = new Set(["INPUT", "TEXTAREA", "SELECT"]);
 
@@ -26,22 +32,128 @@ export function comboMatches(combo, event) {
   return eventKey === combo.key;
 }
 
-export function createHotkeyManager() {
+export const GLOBAL_SCOPE = "global";
+
+const KEY_LABELS = {
+  escape: "Esc",
+  enter: "Enter",
+  " ": "Space",
+  space: "Space",
+  arrowup: "↑",
+  arrowdown: "↓",
+  arrowleft: "←",
+  arrowright: "→",
+  "/": "?",
+};
+
+// "cmd+shift+/" -> "⌘ ?", "escape" -> "Esc". Shift is implied by "?" so it is
+// folded into the key label rather than shown as a separate ⇧.
+export function formatCombo(combo) {
+  const c = parseCombo(combo);
+  const segs = [];
+  if (c.mod) segs.push("⌘");
+  if (c.alt) segs.push("⌥");
+  const key = KEY_LABELS[c.key] ?? c.key.toUpperCase();
+  if (c.shift && key !== "?") segs.push("⇧");
+  segs.push(key);
+  return segs.join(" ");
+}
+
+export function createHotkeyManager({ currentScope = () => GLOBAL_SCOPE } = {}) {
   const bindings = new Map();
+  // action -> { id, source, handler, meta }; `id` is null while unbound.
+  const actions = new Map();
+
+  function add(combo, handler, meta) {
+    const key = canonical(combo);
+    const scope = meta.scope ?? GLOBAL_SCOPE;
+    const id = scope === GLOBAL_SCOPE ? key : `${scope}:${key}`;
+    if (bindings.has(id)) throw new Error(`hotkey "${combo}" already registered`);
+    if (meta.action) actions.set(meta.action, { id, source: combo, handler, meta });
+    bindings.set(id, {
+      combo: parseCombo(combo),
+      source: combo,
+      handler,
+      label: meta.label ?? "",
+      group: meta.group ?? "",
+      display: meta.display ?? "",
+      scope,
+      action: meta.action ?? "",
+    });
+  }
+
+  function register(combo, handler, meta = {}) {
+    add(combo, handler, meta);
+  }
 
-  function register(combo, handler) {
+  function declare(combo, meta = {}) {
+    add(combo, null, meta);
+  }
+
+  function unregister(combo, { scope = GLOBAL_SCOPE } = {}) {
     const key = canonical(combo);
-    if (bindings.has(key)) throw new Error(`hotkey "${combo}" already registered`);
-    bindings.set(key, { combo: parseCombo(combo), handler });
+    const id = scope === GLOBAL_SCOPE ? key : `${scope}:${key}`;
+    const action = bindings.get(id)?.action;
+    if (action) actions.delete(action);
+    bindings.delete(id);
+  }
+
+  // Move action bindings to new combos in one step, so swapping two
+  // actions never collides midway. `entries` is [{ action, combo }]; a
+  // null combo leaves the action unbound. A combo that is already taken
+  // keeps the action on its previous combo. Returns the skipped problems.
+  function rebind(entries) {
+    const errors = [];
+    const moves = [];
+    for (const { action, combo } of entries) {
+      const record = actions.get(action);
+      if (!record) {
+        errors.push(`unknown hotkey action: ${action}`);
+        continue;
+      }
+      const previous = record.id ? record.source : null;
+      if (record.id) bindings.delete(record.id);
+      record.id = null;
+      moves.push({ record, combo: combo || null, previous });
+    }
+    for (const { record, combo, previous } of moves) {
+      if (!combo) continue;
+      try {
+        add(combo, record.handler, record.meta);
+      } catch (error) {
+        errors.push(`${record.meta.action}: ${error.message ?? error}`);
+        if (previous) {
+          try { add(previous, record.handler, record.meta); } catch { /* stays unbound */ }
+        }
+      }
+    }
+    return errors;
+  }
+
+  // Current combo of an action, or null while it is unbound.
+  function comboFor(action) {
+    const record = actions.get(action);
+    return record?.id ? record.source : null;
   }
 
-  function unregister(combo) {
-    bindings.delete(canonical(combo));
+  function bindingsFor(scope = currentScope()) {
+    return Array.from(bindings.values())
+      .filter((b) => b.label && (b.scope === GLOBAL_SCOPE || b.scope === scope))
+      .map((b) => ({
+        combo: b.source,
+        keys: b.display || formatCombo(b.source),
+        label: b.label,
+        group: b.group,
+        scope: b.scope,
+      }));
   }
 
   function dispatch(event) {
     if (isEditableTarget(event.target)) return false;
-    for (const { combo, handler } of bindings.values()) {
+    const scope = currentScope();
+    for (const { combo, handler, scope: bindingScope } of bindings.values()) {
+      if (!handler) continue;
+      if (bindingScope !== GLOBAL_SCOPE && bindingScope !== scope) continue;
       if (comboMatches(combo, event)) {
         const consumed = handler(event);
         if (consumed) {
@@ -60,7 +172,18 @@ export function createHotkeyManager() {
     return () => target.removeEventListener("keydown", listener);
   }
 
-  return { register, unregister, dispatch, attach, list: () => Array.from(bindings.keys()) };
+  return {
+    register,
+    declare,
+    unregister,
+    rebind,
+    comboFor,
+    dispatch,
+    attach,
+    bindingsFor,
+    currentScope,
+    list: () => Array.from(bindings.keys()),
+  };
 }
 
 function canonical(combo) {
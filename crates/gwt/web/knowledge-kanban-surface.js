@@ -24,6 +24,8 @@
 // - openIssueLaunchWizard(windowId, issueNumber): launch wizard entry.
 // - visibleBounds(): current canvas bounds for resume placement.
 // - launchPending: shared Resume/Launch pending controller.
+// - refreshScheduler: shared periodic-work scheduler; the auto refresh
+//   backs off with it while the GUI is idle.
 import { createFocusTrap } from "/focus-trap.js";
 
 export function createKnowledgeKanbanSurface({
@@ -41,6 +43,7 @@ export function createKnowledgeKanbanSurface({
   openIssueLaunchWizard,
   visibleBounds,
   launchPending,
+  refreshScheduler,
 }) {
       const knowledgeBridgeStateMap = new Map();
       const KNOWLEDGE_AUTO_REFRESH_INTERVAL_MS = 60000;
@@ -243,9 +246,9 @@ export function createKnowledgeKanbanSurface({
         if (state.autoRefreshTimer) {
           return;
         }
-        state.autoRefreshTimer = setInterval(() => {
+        const tick = () => {
           if (!windowMap.get(windowId)) {
-            clearInterval(state.autoRefreshTimer);
+            state.autoRefreshTimer();
             state.autoRefreshTimer = null;
             return;
           }
@@ -253,7 +256,16 @@ export function createKnowledgeKanbanSurface({
             return;
           }
           requestKnowledgeBridge(windowId, knowledgeKind, false);
-        }, KNOWLEDGE_AUTO_REFRESH_INTERVAL_MS);
+        };
+        if (refreshScheduler) {
+          state.autoRefreshTimer = refreshScheduler.every(
+            KNOWLEDGE_AUTO_REFRESH_INTERVAL_MS,
+            tick,
+          );
+        } else {
+          const intervalId = setInterval(tick, KNOWLEDGE_AUTO_REFRESH_INTERVAL_MS);
+          state.autoRefreshTimer = () => clearInterval(intervalId);
+        }
       }
 
       function readKanbanHideDonePreference() {
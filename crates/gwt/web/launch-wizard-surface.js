@@ -1031,6 +1031,11 @@ export function createLaunchWizardSurface({
             summaryList.appendChild(card);
           }
           section.appendChild(summaryList);
+          if (launchWizard.api_key_warning) {
+            section.appendChild(
+              createNode("div", "launch-note launch-key-warning", launchWizard.api_key_warning),
+            );
+          }
           panel.appendChild(section);
         }
 
@@ -1162,6 +1167,79 @@ export function createLaunchWizardSurface({
           panel.appendChild(section);
         }
 
+        // Recent-session picker: resume a specific earlier conversation of
+        // this worktree instead of only the latest one. Each row dispatches
+        // ResumeRecentSession with its index.
+        const recentSessions = Array.isArray(launchWizard.recent_sessions)
+          ? launchWizard.recent_sessions
+          : [];
+        if (showStartMethods && !isIntakeWizard && recentSessions.length > 0) {
+          const recentSection = createLaunchSection(
+            "Recent sessions",
+            "Resume a specific conversation on this worktree.",
+          );
+          const recentList = createNode("div", "start-method-list");
+          for (const recent of recentSessions) {
+            const button = createNode("button", "start-method-button");
+            button.type = "button";
+            button.setAttribute("data-recent-session", recent.session_id);
+            const isRecentPending =
+              launchWizardPendingAction?.kind === "resume_recent_session"
+                && launchWizardPendingAction.index === recent.index;
+            button.classList.toggle("is-pending", isRecentPending);
+            button.disabled = isLaunchActionPending;
+            const head = createNode("div", "start-method-head");
+            head.appendChild(
+              createNode(
+                "div",
+                "start-method-title",
+                isRecentPending
+                  ? "Preparing..."
+                  : recent.preview || "(no readable transcript)",
+              ),
+            );
+            head.appendChild(
+              createNode("div", "start-method-badge", recent.agent_label),
+            );
+            button.appendChild(head);
+            const startedAt = new Date(recent.started_at);
+            const turns = `${recent.turns} ${recent.turns === 1 ? "turn" : "turns"}`;
+            button.appendChild(
+              createNode(
+                "div",
+                "start-method-summary",
+                Number.isNaN(startedAt.getTime())
+                  ? turns
+                  : `${startedAt.toLocaleString()} · ${turns}`,
+              ),
+            );
+            button.appendChild(
+              createNode("div", "start-method-detail", `Session ID · ${recent.session_id}`),
+            );
+            const resumeRecentSession = () => {
+              if (
+                !releaseWizardInteractionGuardForChromeAction()
+                || button.disabled
+                || launchWizardPendingAction
+              ) {
+                return;
+              }
+              setLaunchWizardPendingAction({
+                kind: "resume_recent_session",
+                index: recent.index,
+              });
+              sendWizardAction({
+                kind: "resume_recent_session",
+                index: recent.index,
+              });
+            };
+            button.addEventListener("click", resumeRecentSession);
+            recentList.appendChild(button);
+          }
+          recentSection.appendChild(recentList);
+          panel.appendChild(recentSection);
+        }
+
         // SPEC-2359 US-83 / FR-444: "open an existing branch" picker. Lets the
         // user continue on an eligible remote branch (no new work/* branch)
         // instead of starting a fresh work branch. Each candidate dispatches
@@ -1635,6 +1713,8 @@ export function createLaunchWizardSurface({
           launchWizard.show_runtime_target ||
           (launchWizard.show_docker_service &&
             (launchWizard.docker_service_options || []).length > 0) ||
+          (launchWizard.show_docker_profile &&
+            (launchWizard.docker_profile_options || []).length > 0) ||
           (launchWizard.show_docker_lifecycle &&
             (launchWizard.docker_lifecycle_options || []).length > 0);
         if (
@@ -1644,7 +1724,9 @@ export function createLaunchWizardSurface({
         ) {
           const section = createLaunchSection(
             "Runtime",
-            "Choose where the session runs and how Docker services are used.",
+            launchWizard.runtime_rule
+              ? `Pre-selected by launch rule: ${launchWizard.runtime_rule}.`
+              : "Choose where the session runs and how Docker services are used.",
           );
           const grid = createNode("div", "launch-form-grid");
           let appendedRuntimeControl = false;
@@ -1679,6 +1761,23 @@ export function createLaunchWizardSurface({
             );
             appendedRuntimeControl = true;
           }
+          if (
+            launchWizard.show_docker_profile &&
+            (launchWizard.docker_profile_options || []).length > 0
+          ) {
+            appendSelectField(
+              grid,
+              "Docker profile",
+              launchWizard.docker_profile_options || [],
+              launchWizard.selected_docker_profile || "",
+              (value) =>
+                sendWizardAction({
+                  kind: "set_docker_profile",
+                  profile: value,
+                }),
+            );
+            appendedRuntimeControl = true;
+          }
           if (
             launchWizard.show_docker_lifecycle &&
             (launchWizard.docker_lifecycle_options || []).length > 0
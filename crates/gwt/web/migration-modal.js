@@ -27,6 +27,96 @@ const PHASE_LABELS = {
   rolled_back: "Rolled back",
 };
 
+export function formatBytes(bytes) {
+  if (!Number.isFinite(bytes) || bytes < 0) return "unknown";
+  const units = ["B", "KB", "MB", "GB", "TB"];
+  let value = bytes;
+  let unit = 0;
+  while (value >= 1024 && unit < units.length - 1) {
+    value /= 1024;
+    unit += 1;
+  }
+  return unit === 0 ? `${value} B` : `${value.toFixed(1)} ${units[unit]}`;
+}
+
+export function formatDuration(seconds) {
+  if (!Number.isFinite(seconds) || seconds < 0) return "unknown";
+  if (seconds < 60) return `about ${Math.max(1, Math.round(seconds))} s`;
+  return `about ${Math.round(seconds / 60)} min`;
+}
+
+// Pre-flight section of the confirm stage: the dry-run report from
+// `migration_report`, scrollable so long worktree lists stay inside the
+// dialog.
+function renderReport(createNode, m) {
+  const section = createNode("section", "migration-modal-report");
+  section.setAttribute("aria-label", "Migration dry run");
+  if (!m.report) {
+    section.appendChild(
+      createNode(
+        "div",
+        "migration-modal-report-loading",
+        m.reportLoading ? "Checking what the migration will do…" : "",
+      ),
+    );
+    return section;
+  }
+  const report = m.report;
+
+  const problems = Array.isArray(report.problems) ? report.problems : [];
+  if (problems.length > 0) {
+    const list = createNode("ul", "migration-modal-problems");
+    for (const problem of problems) {
+      list.appendChild(createNode("li", "blocked", problem));
+    }
+    section.appendChild(list);
+  }
+
+  const summary = createNode("dl", "migration-modal-report-summary");
+  const addRow = (label, value) => {
+    summary.appendChild(createNode("dt", "", label));
+    summary.appendChild(createNode("dd", "", value));
+  };
+  addRow("Bare repository", report.bare_repo_path || "");
+  addRow(
+    "Disk space",
+    `${formatBytes(report.required_bytes)} needed, ${
+      report.available_bytes == null ? "unknown" : formatBytes(report.available_bytes)
+    } free (project ${formatBytes(report.project_bytes)})`,
+  );
+  addRow("Estimated duration", formatDuration(report.estimated_seconds));
+  if (report.remote_url) addRow("Cloned from", report.remote_url);
+  section.appendChild(summary);
+
+  const worktrees = Array.isArray(report.worktrees) ? report.worktrees : [];
+  const table = createNode("table", "migration-modal-worktrees");
+  const head = createNode("tr");
+  for (const label of ["Branch", "From", "To", "State"]) {
+    head.appendChild(createNode("th", "", label));
+  }
+  table.appendChild(head);
+  for (const worktree of worktrees) {
+    const row = createNode("tr");
+    row.appendChild(createNode("td", "", worktree.branch || ""));
+    row.appendChild(createNode("td", "", worktree.from || ""));
+    row.appendChild(createNode("td", "", worktree.to || ""));
+    const states = [];
+    if (worktree.is_main_repo) states.push("main checkout");
+    if (worktree.is_dirty) states.push("uncommitted changes");
+    if (worktree.is_locked) states.push("locked");
+    row.appendChild(
+      createNode(
+        "td",
+        worktree.is_locked ? "blocked" : worktree.is_dirty ? "dirty" : "",
+        states.join(", ") || "clean",
+      ),
+    );
+    table.appendChild(row);
+  }
+  section.appendChild(table);
+  return section;
+}
+
 function describePhase(phase) {
   if (!phase) return PHASE_LABELS.confirm;
   return PHASE_LABELS[phase] || phase;
@@ -229,6 +319,7 @@ export function renderMigrationModal({
   if (detailList.childNodes.length > 0) {
     dialogEl.appendChild(detailList);
   }
+  dialogEl.appendChild(renderReport(createNode, m));
 
   dialogEl.appendChild(
     createNode(
@@ -246,7 +337,9 @@ export function renderMigrationModal({
   const footer = createNode("div", "modal-footer");
   const migrate = createNode("button", "wizard-button primary", "Migrate");
   migrate.type = "button";
-  migrate.disabled = Boolean(m.hasLocked);
+  const reportProblems =
+    m.report && Array.isArray(m.report.problems) ? m.report.problems.length : 0;
+  migrate.disabled = Boolean(m.hasLocked) || reportProblems > 0;
   migrate.addEventListener("click", onMigrate);
   footer.appendChild(migrate);
 
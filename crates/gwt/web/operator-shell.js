@@ -4,7 +4,7 @@
 // status strip live clock, and Living Telemetry counter logic.
 
 import { createThemeManager, createBrowserEnv } from "/theme-manager.js";
-import { createHotkeyManager } from "/hotkey.js";
+import { createHotkeyManager, formatCombo } from "/hotkey.js";
 import { wireThemeToggle as wireSegmentedThemeToggle } from "/theme-toggle.js";
 
 const BRIEFING_KEY = "gwt:ui:briefing";
@@ -21,15 +21,25 @@ export function initOperatorShell(deps = {}) {
   };
 
   const themeManager = deps.themeManager ?? createThemeManagerSafe(doc, win, markDegraded);
-  const hotkey = deps.hotkey ?? createHotkeyManager();
+  const hotkey = deps.hotkey ?? createHotkeyManager({ currentScope: () => resolveHotkeyScope(doc) });
 
   safeWire("theme toggle", () => wireThemeToggle({ doc, themeManager }), markDegraded);
   safeWire("legacy chrome keys", () => removeLegacyChromeKeys(win), markDegraded);
   safeWire("rail commands", () => wireRailCommands({ doc }), markDegraded);
-  safeWire("status strip clock", () => wireStatusStripClock({ doc }), markDegraded);
+  safeWire(
+    "status strip clock",
+    () => wireStatusStripClock({ doc, scheduler: deps.refreshScheduler }),
+    markDegraded,
+  );
   if (shellDegraded) hideMissionBriefingImmediately(doc);
   else safeWire("mission briefing", () => wireMissionBriefing({ doc, win }), markDegraded);
-  safeWire("hotkey overlay", () => wireHotkeyOverlay({ doc, hotkey }), markDegraded);
+  safeWire("screen hotkeys", () => declareScreenHotkeys(hotkey), markDegraded);
+  const helpOverlay = safeWire(
+    "hotkey overlay",
+    () => wireHotkeyOverlay({ doc, hotkey }),
+    markDegraded,
+    null,
+  );
   const palette = safeWire(
     "command palette",
     () => wireCommandPalette({ doc, hotkey }),
@@ -41,8 +51,9 @@ export function initOperatorShell(deps = {}) {
     () => wireGlobalHotkeys({ doc, hotkey, palette }),
     markDegraded,
   );
+  safeWire("hotkey hints", () => wireHotkeyHints({ doc, hotkey }), markDegraded);
 
-  return { themeManager, hotkey, palette };
+  return { themeManager, hotkey, palette, helpOverlay };
 }
 
 function safeWire(label, fn, onError, fallback = undefined) {
@@ -69,6 +80,11 @@ function createFallbackThemeManager() {
     getPreference() { return "auto"; },
     getEffective() { return "dark"; },
     setTheme() {},
+    getPalette() { return "default"; },
+    listPalettes() { return [{ name: "default", builtin: true }]; },
+    setPalette() {},
+    previewPalette() {},
+    setCustomPalettes() {},
     subscribe() { return () => {}; },
   };
 }
@@ -131,7 +147,7 @@ function wireRailCommands({ doc }) {
 // Status Strip — live clock + counters
 // ------------------------------------------------------------
 
-function wireStatusStripClock({ doc }) {
+function wireStatusStripClock({ doc, scheduler }) {
   const clock = doc.getElementById("op-strip-clock");
   if (!clock) return;
 
@@ -139,7 +155,6 @@ function wireStatusStripClock({ doc }) {
   // mission-control "blink" effect can target only the separators while the
   // numbers stay rock-steady. Clears + rebuilds via DOM nodes (no innerHTML
   // because the security hook flags textContent-as-template-string).
-  const reduced = matchReduced(doc);
   const setClock = (h, m, s) => {
     while (clock.firstChild) clock.removeChild(clock.firstChild);
     const append = (text, isColon) => {
@@ -154,25 +169,20 @@ function wireStatusStripClock({ doc }) {
     append(":", true);
     append(pad2(s), false);
   };
+  let shown = "";
   const tick = () => {
     const now = new Date();
+    const text = now.toTimeString().slice(0, 8);
+    if (text === shown) return;
+    shown = text;
     setClock(now.getHours(), now.getMinutes(), now.getSeconds());
   };
   tick();
 
-  if (reduced.matches) {
-    setInterval(tick, 1000);
-  } else {
-    let last = 0;
-    const loop = (t) => {
-      if (t - last >= 1000) {
-        last = t;
-        tick();
-      }
-      requestAnimationFrame(loop);
-    };
-    requestAnimationFrame(loop);
-  }
+  // The shared refresh scheduler pauses the clock while the window is
+  // hidden; without it, fall back to a plain one-second interval.
+  if (scheduler) scheduler.every(1000, tick, { backoff: false });
+  else setInterval(tick, 1000);
 }
 
 export function applyTelemetryCounts(doc, counts = {}) {
@@ -285,6 +295,106 @@ function issueMonitorStatusStripView(status = {}) {
   };
 }
 
+// ------------------------------------------------------------
+// AI job queue cell
+// ------------------------------------------------------------
+
+let aiQueueLatest = { jobs: [], options: {} };
+
+// `AI 1 run 2 queued`, hidden while the queue is empty. Click lists the jobs
+// with a Cancel button each; `options.cancelJob(id)` sends the cancellation.
+export function applyAiQueueStatus(doc, status = {}, options = {}) {
+  const cell = doc.getElementById("op-strip-ai-queue");
+  const value = doc.getElementById("op-strip-ai-queue-value");
+  if (!cell || !value) return;
+
+  const jobs = Array.isArray(status.jobs) ? status.jobs : [];
+  const running = Math.max(0, Number(status.running || 0));
+  const queued = Math.max(0, Number(status.queued || 0));
+  const text = `${running} run ${queued} queued`;
+  value.textContent = text;
+  cell.hidden = jobs.length === 0;
+  cell.setAttribute("aria-label", `AI jobs: ${text}`);
+  cell.setAttribute("title", `AI jobs: ${text}`);
+  aiQueueLatest = { jobs, options };
+  if (cell.dataset.aiQueueBound !== "true") {
+    cell.dataset.aiQueueBound = "true";
+    cell.addEventListener("click", () => {
+      const detail = aiQueueDetail(doc);
+      if (detail.hidden) {
+        renderAiQueueDetail(doc, aiQueueLatest.jobs, aiQueueLatest.options);
+        positionAboveCell(doc, detail, cell);
+        detail.hidden = false;
+      } else {
+        detail.hidden = true;
+      }
+    });
+  }
+
+  const detail = doc.getElementById("op-ai-queue-detail");
+  if (!detail) return;
+  if (jobs.length === 0) {
+    detail.hidden = true;
+  } else if (!detail.hidden) {
+    renderAiQueueDetail(doc, jobs, options);
+  }
+}
+
+function aiQueueDetail(doc) {
+  let detail = doc.getElementById("op-ai-queue-detail");
+  if (detail) return detail;
+  detail = doc.createElement("div");
+  detail.className = "op-runtime-health-detail op-ai-queue-detail";
+  detail.id = "op-ai-queue-detail";
+  detail.hidden = true;
+  detail.setAttribute("role", "dialog");
+  detail.setAttribute("aria-label", "AI jobs");
+  doc.body.appendChild(detail);
+  return detail;
+}
+
+function renderAiQueueDetail(doc, jobs, options) {
+  const detail = aiQueueDetail(doc);
+  while (detail.firstChild) detail.removeChild(detail.firstChild);
+  for (const job of jobs) {
+    const row = doc.createElement("div");
+    row.className = "op-ai-queue-detail__job";
+    row.dataset.state = job.state;
+    const label = doc.createElement("span");
+    label.className = "op-ai-queue-detail__label";
+    label.textContent = job.label;
+    const state = doc.createElement("span");
+    state.className = "op-ai-queue-detail__state";
+    state.textContent = job.cancelled
+      ? "cancelling"
+      : `${job.state}${job.priority === "background" ? " · background" : ""}`;
+    row.appendChild(label);
+    row.appendChild(state);
+    if (!job.cancelled) {
+      const cancel = doc.createElement("button");
+      cancel.type = "button";
+      cancel.className = "op-ai-queue-detail__cancel";
+      cancel.textContent = "Cancel";
+      cancel.setAttribute("aria-label", `Cancel ${job.label}`);
+      cancel.addEventListener("click", () => options.cancelJob?.(job.id));
+      row.appendChild(cancel);
+    }
+    detail.appendChild(row);
+  }
+}
+
+function positionAboveCell(doc, detail, cell) {
+  const rect = cell.getBoundingClientRect?.();
+  if (!rect) return;
+  const viewport = doc.defaultView || {};
+  const width = Number(viewport.innerWidth) || 1024;
+  const height = Number(viewport.innerHeight) || 768;
+  const margin = 8;
+  const detailWidth = Math.min(420, Math.max(0, width - margin * 2));
+  detail.style.left = `${Math.max(margin, Math.min(rect.left, width - detailWidth - margin))}px`;
+  detail.style.bottom = `${Math.max(margin, height - rect.top + margin)}px`;
+}
+
 // ------------------------------------------------------------
 // Provider usage pill (SPEC-2970)
 // ------------------------------------------------------------
@@ -964,10 +1074,81 @@ function wireMissionBriefing({ doc, win }) {
 // Hotkey Overlay
 // ------------------------------------------------------------
 
+// Screen names are the focused window's preset ("file_tree", "board", …),
+// "modal" while any modal backdrop is open, or "canvas" when nothing is
+// focused. Scoped hotkeys and the contextual help overlay key off this.
+export function resolveHotkeyScope(doc) {
+  if (doc.querySelector?.(".modal-backdrop.open")) return "modal";
+  const focused = doc.querySelector?.(".workspace-window.focused");
+  return focused?.dataset?.preset || "canvas";
+}
+
+const SCREEN_LABELS = {
+  canvas: "Canvas",
+  modal: "Dialog",
+  shell: "Terminal",
+  claude: "Agent terminal",
+  codex: "Agent terminal",
+  agent: "Agent terminal",
+  file_tree: "File Tree",
+  board: "Board",
+  index: "Index search",
+  issue_monitor: "Issue Monitor",
+  branches: "Branches",
+};
+
+function hotkeyScopeLabel(scope) {
+  return SCREEN_LABELS[scope] ?? scope.replace(/_/g, " ").replace(/^\w/, (c) => c.toUpperCase());
+}
+
+// Keys the surfaces handle with their own listeners. Declaring them here
+// keeps the contextual help overlay in step with what each screen accepts.
+function declareScreenHotkeys(hotkey) {
+  const terminalCopy = { label: "Copy selection", group: "Terminal", display: "Ctrl ⇧ C" };
+  for (const scope of ["shell", "claude", "codex", "agent"]) {
+    hotkey.declare("ctrl+shift+c", { ...terminalCopy, scope });
+  }
+  hotkey.declare("enter", { label: "Open entry", group: "File Tree", scope: "file_tree" });
+  hotkey.declare("shift+enter", { label: "Post entry", group: "Board", scope: "board" });
+  hotkey.declare("arrowdown", { label: "Next result", group: "Index search", scope: "index" });
+  hotkey.declare("arrowup", { label: "Previous result", group: "Index search", scope: "index" });
+  hotkey.declare("enter", { label: "Open result", group: "Index search", scope: "index" });
+  hotkey.declare("escape", { label: "Close issue detail", group: "Issue Monitor", scope: "issue_monitor" });
+  hotkey.declare("c", { label: "Copy worktree path", group: "Branches", scope: "branches" });
+  hotkey.declare("o", { label: "Open worktree in file manager", group: "Branches", scope: "branches" });
+  hotkey.declare("e", { label: "Open worktree in editor", group: "Branches", scope: "branches" });
+  hotkey.declare("s", { label: "Stash worktree changes", group: "Branches", scope: "branches" });
+  hotkey.declare("u", { label: "Restore latest worktree stash", group: "Branches", scope: "branches" });
+  hotkey.declare("t", { label: "New branch from uncommitted changes", group: "Branches", scope: "branches" });
+  hotkey.declare("r", { label: "Restore archived worktree", group: "Branches", scope: "branches" });
+  hotkey.declare("f", { label: "Repair worktree integrity", group: "Branches", scope: "branches" });
+  hotkey.declare("a", { label: "Download CI artifacts", group: "Branches", scope: "branches" });
+  hotkey.declare("p", { label: "Toggle path column", group: "Branches", scope: "branches" });
+  hotkey.declare("escape", { label: "Close dialog", group: "Dialog", scope: "modal" });
+}
+
+// Group registry bindings for one scope: global groups first in registration
+// order, then the screen's own bindings.
+function hotkeyHelpGroups(bindings, scope) {
+  const groups = new Map();
+  const ordered = [
+    ...bindings.filter((b) => b.scope === scope && scope !== "global"),
+    ...bindings.filter((b) => b.scope === "global"),
+  ];
+  for (const binding of ordered) {
+    const title = binding.group || "General";
+    if (!groups.has(title)) groups.set(title, []);
+    groups.get(title).push(binding);
+  }
+  return Array.from(groups, ([title, rows]) => ({ title, rows }));
+}
+
 function wireHotkeyOverlay({ doc, hotkey }) {
   const overlay = doc.getElementById("op-hotkey-overlay");
-  if (!overlay) return;
+  if (!overlay) return null;
   const card = overlay.querySelector(".op-hotkey-card");
+  const bindingsHost = doc.getElementById("op-hotkey-bindings");
+  const scopeLabel = doc.getElementById("op-hotkey-scope");
 
   // SPEC-2356 — modal-dialog focus management: remember the trigger so we can
   // restore focus on close, and move focus into the dialog on open so screen
@@ -975,7 +1156,44 @@ function wireHotkeyOverlay({ doc, hotkey }) {
   // surface invoked ⌘?.
   let returnFocusTo = null;
 
+  // The static markup is the fallback; once open, the card only lists what
+  // the registry says is valid on the current screen.
+  const render = () => {
+    const scope = hotkey.currentScope?.() ?? "canvas";
+    if (scopeLabel) scopeLabel.textContent = hotkeyScopeLabel(scope);
+    for (const el of overlay.querySelectorAll("[data-hotkey-scope]")) {
+      el.hidden = el.dataset.hotkeyScope !== scope;
+    }
+    if (!bindingsHost || typeof hotkey.bindingsFor !== "function") return;
+    const groups = hotkeyHelpGroups(hotkey.bindingsFor(scope), scope);
+    if (groups.length === 0) return;
+    bindingsHost.replaceChildren(
+      ...groups.map(({ title, rows }) => {
+        const group = doc.createElement("div");
+        group.className = "op-hotkey-card__group";
+        const heading = doc.createElement("h3");
+        heading.className = "op-hotkey-card__group-title";
+        heading.textContent = title;
+        group.appendChild(heading);
+        for (const binding of rows) {
+          const row = doc.createElement("div");
+          row.className = "op-hotkey-card__row";
+          const label = doc.createElement("span");
+          label.textContent = binding.label;
+          const keys = doc.createElement("kbd");
+          keys.className = "op-kbd";
+          keys.textContent = binding.keys;
+          row.append(label, keys);
+          group.appendChild(row);
+        }
+        return group;
+      }),
+    );
+  };
+
   const open = () => {
+    // Capture the screen before focus moves into the card.
+    render();
     returnFocusTo = doc.activeElement instanceof Element ? doc.activeElement : null;
     overlay.dataset.open = "true";
     overlay.removeAttribute("aria-hidden");
@@ -991,6 +1209,11 @@ function wireHotkeyOverlay({ doc, hotkey }) {
     }
     returnFocusTo = null;
   };
+  const toggle = () => {
+    if (overlay.dataset.open === "true") close();
+    else open();
+    return true;
+  };
 
   overlay.addEventListener("click", (e) => {
     if (e.target === overlay) close();
@@ -1003,11 +1226,52 @@ function wireHotkeyOverlay({ doc, hotkey }) {
     }
   });
 
-  hotkey.register("cmd+shift+/", () => { // ⌘ ?
-    if (overlay.dataset.open === "true") close();
-    else open();
-    return true;
-  });
+  // The Help group stays static in the card, so these carry no help label.
+  hotkey.register("cmd+shift+/", toggle, { action: "help" }); // ⌘ ?
+  // One-key toggle. The manager already ignores editable targets (including
+  // the xterm input textarea), so typing "?" into a terminal is unaffected.
+  hotkey.register("shift+?", toggle);
+
+  return { open, close, toggle };
+}
+
+// Status Strip hint cell: the first few keys of the current screen plus the
+// help toggle. Refreshed whenever focus or the open modal changes.
+const HOTKEY_HINT_LIMIT = 3;
+
+function hotkeyHintsFor(bindings, scope) {
+  const own = bindings.filter((b) => b.scope === scope && scope !== "global");
+  const picked = (own.length ? own : bindings).slice(0, HOTKEY_HINT_LIMIT);
+  return [...picked, { keys: "?", label: "Help" }];
+}
+
+function wireHotkeyHints({ doc, hotkey }) {
+  const cell = doc.getElementById("op-strip-hints");
+  if (!cell || typeof hotkey.bindingsFor !== "function") return;
+  let lastScope = null;
+  const update = () => {
+    const scope = hotkey.currentScope();
+    if (scope === lastScope) return;
+    lastScope = scope;
+    cell.dataset.scope = scope;
+    cell.replaceChildren(
+      ...hotkeyHintsFor(hotkey.bindingsFor(scope), scope).map((hint) => {
+        const item = doc.createElement("span");
+        item.className = "op-status-strip__hint";
+        const keys = doc.createElement("kbd");
+        keys.className = "op-kbd";
+        keys.textContent = hint.keys;
+        item.append(keys, ` ${hint.label}`);
+        return item;
+      }),
+    );
+  };
+  // Defer so window focus / modal classes settle before the scope is read.
+  const schedule = () => setTimeout(update, 0);
+  doc.addEventListener("focusin", schedule);
+  doc.addEventListener("click", schedule);
+  doc.addEventListener("keyup", schedule);
+  update();
 }
 
 // ------------------------------------------------------------
@@ -1149,13 +1413,14 @@ function wireCommandPalette({ doc, hotkey }) {
 
   button?.addEventListener("click", open);
 
-  hotkey.register("cmd+k", () => { open(); return true; });
-  hotkey.register("cmd+p", () => { open(); return true; });
+  hotkey.register("cmd+k", () => { open(); return true; }, { label: "Command palette", group: "Navigation", action: "command_palette" });
+  hotkey.register("cmd+p", () => { open(); return true; }, { label: "Project switcher", group: "Navigation", action: "project_switcher" });
 
   return {
     open, close,
     register: (action) => actions.register(action),
     unregister: (id) => actions.unregister(id),
+    setHint: (id, hint) => actions.setHint(id, hint),
   };
 }
 
@@ -1188,6 +1453,10 @@ function createActionRegistry(doc) {
     unregister(id) {
       items.delete(id);
     },
+    setHint(id, hint) {
+      const item = items.get(id);
+      if (item) items.set(id, { ...item, hint });
+    },
     filter(query) {
       const all = Array.from(items.values());
       if (!query) return all;
@@ -1218,9 +1487,9 @@ function wireGlobalHotkeys({ doc, hotkey, palette }) {
     return true;
   };
 
-  hotkey.register("cmd+b", send("open-board"));
-  hotkey.register("cmd+g", send("open-git"));
-  hotkey.register("cmd+l", send("open-logs"));
+  hotkey.register("cmd+b", send("open-board"), { label: "Board surface", group: "Navigation", action: "open_board" });
+  hotkey.register("cmd+g", send("open-git"), { label: "Workspace surface", group: "Navigation", action: "open_work" });
+  hotkey.register("cmd+l", send("open-logs"), { label: "Logs surface", group: "Navigation", action: "open_logs" });
   // SPEC-2356 Phase 9: Cmd+\\ sidebar toggle hotkey is removed in favor of the
   // hover-reveal peek 帯. Chrome visibility is now driven entirely by pointer
   // hover / keyboard focus / pointer tap.
@@ -1234,6 +1503,35 @@ function wireGlobalHotkeys({ doc, hotkey, palette }) {
   hotkey.attach(doc);
 }
 
+// Palette items whose hint shows an action's shortcut.
+const PALETTE_HINT_ACTIONS = {
+  open_board: "open-board",
+  open_work: "open-git",
+  open_logs: "open-logs",
+  help: "open-help",
+};
+
+// Apply the `keybindings` event: move the global shortcuts to the
+// configured combos, then refresh the static key labels and palette hints
+// that do not read from the registry. Returns the problems to surface.
+export function applyKeybindings(doc, { hotkey, palette } = {}, bindings = []) {
+  if (!hotkey || typeof hotkey.rebind !== "function") return [];
+  const errors = hotkey.rebind(bindings);
+  for (const { action } of bindings) {
+    const combo = hotkey.comboFor(action);
+    const keys = combo ? formatCombo(combo) : "";
+    for (const el of doc.querySelectorAll(`[data-hotkey-action="${action}"]`)) {
+      el.textContent = keys;
+      el.hidden = !combo;
+    }
+    const paletteId = PALETTE_HINT_ACTIONS[action];
+    if (paletteId && typeof palette?.setHint === "function") {
+      palette.setHint(paletteId, keys.replaceAll(" ", ""));
+    }
+  }
+  return errors;
+}
+
 // ------------------------------------------------------------
 // helpers
 // ------------------------------------------------------------
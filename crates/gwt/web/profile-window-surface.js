@@ -22,6 +22,8 @@ export function createProfileWindowSurface({
   sendWindowFocus,
 }) {
       const profileStateMap = new Map();
+      // Result column of a variable whose value is in the OS keychain.
+      const SECRET_RESULT_LABEL = "Keychain";
 
       function ensureProfileState(windowId) {
         if (!profileStateMap.has(windowId)) {
@@ -72,6 +74,10 @@ export function createProfileWindowSurface({
             value: entry.value || "",
           })),
           disabledEnv: (profile.disabled_env || []).map((entry) => entry || ""),
+          // Keys whose values live in the OS keychain. The snapshot lists
+          // them with an empty value; saving an empty value keeps the stored
+          // one.
+          secretEnv: (profile.secret_env || []).map((entry) => entry || ""),
         };
       }
 
@@ -81,7 +87,7 @@ export function createProfileWindowSurface({
 
       function profileDraftPayload(draft) {
         if (!draft) {
-          return { envVars: [], disabledEnv: [] };
+          return { envVars: [], disabledEnv: [], secretEnv: [] };
         }
         const envByKey = new Map();
         for (const entry of draft.envVars || []) {
@@ -104,6 +110,13 @@ export function createProfileWindowSurface({
         for (const key of disabledSet) {
           envByKey.delete(key);
         }
+        const secretSet = new Set();
+        for (const entry of draft.secretEnv || []) {
+          const key = normalizeProfileEnvKey(entry);
+          if (envByKey.has(key)) {
+            secretSet.add(key);
+          }
+        }
         return {
           envVars: Array.from(envByKey.values()).sort((left, right) =>
             left.key.localeCompare(right.key),
@@ -111,6 +124,9 @@ export function createProfileWindowSurface({
           disabledEnv: Array.from(disabledSet).sort((left, right) =>
             left.localeCompare(right),
           ),
+          secretEnv: Array.from(secretSet).sort((left, right) =>
+            left.localeCompare(right),
+          ),
         };
       }
 
@@ -121,6 +137,16 @@ export function createProfileWindowSurface({
         );
       }
 
+      function setProfileSecretKey(draft, key, secret) {
+        const normalized = normalizeProfileEnvKey(key);
+        draft.secretEnv = (draft.secretEnv || []).filter(
+          (entry) => normalizeProfileEnvKey(entry) !== normalized,
+        );
+        if (secret && normalized) {
+          draft.secretEnv.push(normalized);
+        }
+      }
+
       function removeProfileDisabledKey(draft, key) {
         const normalized = normalizeProfileEnvKey(key);
         draft.disabledEnv = (draft.disabledEnv || []).filter(
@@ -152,6 +178,7 @@ export function createProfileWindowSurface({
         if (!normalized) {
           return;
         }
+        setProfileSecretKey(draft, normalized, mode === "secret");
         if (mode === "use_os") {
           removeProfileEnvOverride(draft, normalized);
           removeProfileDisabledKey(draft, normalized);
@@ -185,6 +212,10 @@ export function createProfileWindowSurface({
           .sort((left, right) => left.key.localeCompare(right.key));
         const overrides = new Map(payload.envVars.map((entry) => [entry.key, entry]));
         const disabled = new Set(payload.disabledEnv);
+        const secrets = new Set(payload.secretEnv);
+        const overrideMode = (key) => (secrets.has(key) ? "secret" : "override");
+        const overrideResult = (key, value) =>
+          secrets.has(key) ? SECRET_RESULT_LABEL : value;
         const osKeys = new Set();
         const rows = [];
 
@@ -193,7 +224,7 @@ export function createProfileWindowSurface({
           const mode = disabled.has(entry.key)
             ? "disabled"
             : overrides.has(entry.key)
-              ? "override"
+              ? overrideMode(entry.key)
               : "use_os";
           const profileValue = overrides.get(entry.key)?.value ?? "";
           rows.push({
@@ -205,9 +236,9 @@ export function createProfileWindowSurface({
             result:
               mode === "disabled"
                 ? "Disabled"
-                : mode === "override"
-                  ? profileValue
-                  : entry.value,
+                : mode === "use_os"
+                  ? entry.value
+                  : overrideResult(entry.key, profileValue),
           });
         }
 
@@ -221,9 +252,9 @@ export function createProfileWindowSurface({
             kind: "added",
             key: entry.key,
             osValue: "",
-            mode: "override",
+            mode: overrideMode(entry.key),
             profileValue: entry.value,
-            result: entry.value,
+            result: overrideResult(entry.key, entry.value),
           });
         }
         for (const key of payload.disabledEnv) {
@@ -289,6 +320,7 @@ export function createProfileWindowSurface({
           description: draft.description,
           envVars: payload.envVars,
           disabledEnv: payload.disabledEnv,
+          secretEnv: payload.secretEnv,
         });
       }
 
@@ -361,6 +393,7 @@ export function createProfileWindowSurface({
           description: state.draft.description,
           env_vars: payload.envVars,
           disabled_env: payload.disabledEnv,
+          secret_env: payload.secretEnv,
         });
       }
 
@@ -530,6 +563,17 @@ export function createProfileWindowSurface({
           if (profile.is_default) {
             chips.appendChild(createNode("span", "profile-chip", "Default"));
           }
+          // Shared profiles come from the repository's `.gwt/profiles/`; a
+          // user profile with the same name wins over them.
+          if (profile.origin === "repo") {
+            const chip = createNode("span", "profile-chip shared", "Repo");
+            chip.title = "Shared in this repository's .gwt/profiles/";
+            chips.appendChild(chip);
+          } else if (profile.origin === "user_overrides_repo") {
+            const chip = createNode("span", "profile-chip override", "Overrides repo");
+            chip.title = "Your copy is used instead of the repository's .gwt/profiles/ profile";
+            chips.appendChild(chip);
+          }
           header.appendChild(chips);
           row.appendChild(header);
           row.appendChild(
@@ -547,6 +591,9 @@ export function createProfileWindowSurface({
           row.appendChild(meta);
           list.appendChild(row);
         }
+        for (const message of snapshot.repo_errors || []) {
+          list.appendChild(createNode("div", "profile-repo-error", message));
+        }
 
         editor.innerHTML = "";
         const selected = selectedProfileEntry(state);
@@ -574,7 +621,11 @@ export function createProfileWindowSurface({
 
         const deleteButton = createNode("button", "wizard-button", "Delete");
         deleteButton.type = "button";
-        deleteButton.disabled = selected.is_default || state.loading;
+        deleteButton.disabled =
+          selected.is_default || selected.origin === "repo" || state.loading;
+        if (selected.origin === "repo") {
+          deleteButton.title = "Shared profiles are removed from .gwt/profiles/ in the repository";
+        }
         deleteButton.addEventListener("click", () => deleteProfile(windowId));
         actions.appendChild(deleteButton);
         editor.appendChild(actions);
@@ -679,10 +730,12 @@ export function createProfileWindowSurface({
               ? [
                   ["use_os", "Use OS"],
                   ["override", "Override"],
+                  ["secret", "Secret"],
                   ["disabled", "Disabled"],
                 ]
               : [
                   ["override", "Enabled"],
+                  ["secret", "Secret"],
                   ["disabled", "Disabled"],
                 ];
           for (const option of modeOptions) {
@@ -710,8 +763,10 @@ export function createProfileWindowSurface({
           row.appendChild(modeSelect);
 
           const valueInput = document.createElement("input");
-          valueInput.type = "text";
-          valueInput.placeholder = "Profile";
+          const isSecret = envRow.mode === "secret";
+          valueInput.type = isSecret ? "password" : "text";
+          valueInput.placeholder = isSecret ? "Stored in keychain" : "Profile";
+          valueInput.autocomplete = "off";
           valueInput.value = envRow.profileValue;
           valueInput.setAttribute("aria-label", `Profile value, row ${index + 1}`);
           const resultCell = createNode("div", "profile-env-result", envRow.result);
@@ -722,8 +777,10 @@ export function createProfileWindowSurface({
               resultCell.textContent = valueInput.value;
             } else {
               setProfileEnvOverride(state.draft, envRow.key, valueInput.value);
-              modeSelect.value = "override";
-              resultCell.textContent = valueInput.value;
+              if (!isSecret) {
+                modeSelect.value = "override";
+                resultCell.textContent = valueInput.value;
+              }
             }
             scheduleProfileSave(windowId);
           });
@@ -0,0 +1,121 @@
+// Drag-and-drop a folder onto the canvas to open it as a project.
+//
+// Drops that land on a workspace window stay with the terminal attachment
+// bridges (terminal-attachments.js); a drop anywhere else that carries a
+// local folder sends `drop_project_folder`. The backend runs the same
+// repo-type detection as startup: Git workspaces open as a project tab
+// (raising the migration modal for a normal checkout) with their Branches
+// window, anything else comes back as `project_drop_needs_clone` and the
+// clone modal opens with the folder as destination.
+//
+// Browsers do not expose the path of a dropped file, so the browser path
+// reads the `text/uri-list` entry file managers attach (`file:///...`).
+// Native WebView drops arrive with real paths via `gwt:native-file-drop`.
+
+const URI_LIST_TYPE = "text/uri-list";
+
+// Local path of a `file:` URI, or null for anything else.
+export function fileUriToPath(uri) {
+  let url;
+  try {
+    url = new URL(String(uri || "").trim());
+  } catch {
+    return null;
+  }
+  if (url.protocol !== "file:") {
+    return null;
+  }
+  const pathname = decodeURIComponent(url.pathname);
+  if (url.hostname && url.hostname !== "localhost") {
+    // UNC share: file://server/share/dir
+    return `//${url.hostname}${pathname}`;
+  }
+  // file:///C:/dir -> C:/dir
+  if (/^\/[A-Za-z]:\//.test(pathname)) {
+    return pathname.slice(1);
+  }
+  return pathname || null;
+}
+
+// Local paths listed in a drop's `text/uri-list`.
+export function droppedFolderPaths(dataTransfer) {
+  if (typeof dataTransfer?.getData !== "function") {
+    return [];
+  }
+  return String(dataTransfer.getData(URI_LIST_TYPE) || "")
+    .split(/\r?\n/)
+    .map((line) => line.trim())
+    .filter((line) => line && !line.startsWith("#"))
+    .map(fileUriToPath)
+    .filter(Boolean);
+}
+
+// Whether a drag may carry a local folder. Only the types are readable
+// before the drop.
+export function dataTransferMayCarryFolder(dataTransfer) {
+  return Array.from(dataTransfer?.types || []).includes(URI_LIST_TYPE);
+}
+
+function workspaceWindowAt(target, x, y) {
+  if (target?.closest?.(".workspace-window")) {
+    return true;
+  }
+  if (!Number.isFinite(x) || !Number.isFinite(y)) {
+    return false;
+  }
+  return Boolean(
+    document.elementFromPoint?.(x, y)?.closest?.(".workspace-window"),
+  );
+}
+
+// Whether a drop event should be handled as a project folder drop.
+export function isProjectFolderDropEvent(event) {
+  return (
+    dataTransferMayCarryFolder(event?.dataTransfer) &&
+    !workspaceWindowAt(event.target, Number(event.clientX), Number(event.clientY))
+  );
+}
+
+// deps:
+// - send(message): forward a frontend event over the WebSocket bridge.
+// - visibleBounds(): canvas bounds the Branches window is placed in.
+// - isBlocked(): true while a modal owns the screen.
+export function installProjectFolderDrop({ send, visibleBounds, isBlocked = () => false }) {
+  const sendDrop = (path) => {
+    send({ kind: "drop_project_folder", path, bounds: visibleBounds() });
+  };
+
+  window.addEventListener("dragover", (event) => {
+    if (isBlocked() || !isProjectFolderDropEvent(event)) {
+      return;
+    }
+    event.preventDefault();
+    event.dataTransfer.dropEffect = "link";
+  });
+
+  window.addEventListener("drop", (event) => {
+    if (isBlocked() || !isProjectFolderDropEvent(event)) {
+      return;
+    }
+    const [path] = droppedFolderPaths(event.dataTransfer);
+    if (!path) {
+      return;
+    }
+    event.preventDefault();
+    sendDrop(path);
+  });
+
+  window.addEventListener("gwt:native-file-drop", (event) => {
+    const detail = event.detail || {};
+    const path = Array.isArray(detail.paths)
+      ? detail.paths.find((entry) => typeof entry === "string" && entry.length > 0)
+      : null;
+    if (!path || isBlocked()) {
+      return;
+    }
+    if (workspaceWindowAt(null, Number(detail.x), Number(detail.y))) {
+      return;
+    }
+    sendDrop(path);
+  });
+}
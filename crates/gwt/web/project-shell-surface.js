@@ -63,6 +63,7 @@ import {
   shouldTriggerOpenFolderHotkey,
 } from "/project-switcher.js";
 import { windowRuntimeLabel } from "/window-runtime-state.js";
+import { installProjectFolderDrop } from "/project-folder-drop.js";
 import { groupProjectWindowList } from "/window-list-model.js";
 import {
   applyWindowLaneData,
@@ -207,6 +208,12 @@ export function createProjectShellSurface({
         requestNotificationPermission: requestDesktopNotificationPermission,
         onOpenFolder: sendOpenProjectDialog,
         onCloneFromGithub: openCloneProjectModal,
+        confirmConsolidate: (row) =>
+          window.confirm(
+            `${row.path} clones the same remote as ${row.duplicateOf}.\n\n` +
+              "Fetch its missing branches into that project and remove it from " +
+              "the project list? No files are deleted.",
+          ),
       });
 
       function windowListRenderKey() {
@@ -338,6 +345,8 @@ export function createProjectShellSurface({
         hasDirty: false,
         hasLocked: false,
         hasSubmodules: false,
+        report: null,
+        reportLoading: false,
         phase: "confirm",
         percent: 0,
         message: "",
@@ -861,6 +870,21 @@ export function createProjectShellSurface({
             };
             renderProjectCloneModal();
             break;
+          case "project_drop_needs_clone":
+            // The dropped folder is not a Git workspace: offer to clone
+            // into it.
+            if (cloneProjectModalState.cloning) {
+              break;
+            }
+            cloneProjectModalState = {
+              ...cloneProjectModalState,
+              open: true,
+              parentPath: event.path || "",
+              error: "",
+              progress: "",
+            };
+            renderProjectCloneModal();
+            break;
           case "github_repository_search_results":
             if (event.query !== cloneProjectModalState.query.trim()) {
               break;
@@ -934,14 +958,26 @@ export function createProjectShellSurface({
               hasDirty: Boolean(event.has_dirty),
               hasLocked: Boolean(event.has_locked),
               hasSubmodules: Boolean(event.has_submodules),
+              report: null,
+              reportLoading: true,
               phase: "confirm",
               percent: 0,
               message: "",
               recovery: "",
             };
+            send({ kind: "migration_dry_run", tab_id: event.tab_id });
             renderMigrationModal();
             break;
           }
+          case "migration_report": {
+            if (migrationModalState.tabId !== event.tab_id) break;
+            migrationModalState.report = event.report || null;
+            migrationModalState.reportLoading = false;
+            if (migrationModalState.open && migrationModalState.stage === "confirm") {
+              renderMigrationModal();
+            }
+            break;
+          }
           case "migration_progress": {
             // Phase tick from execute_migration. Ignore if the modal already
             // closed (e.g. user pressed Quit) so we never re-open it.
@@ -1102,6 +1138,11 @@ export function createProjectShellSurface({
         pickerOpenProjectButton.addEventListener("click", sendOpenProjectDialog);
         pickerCloneProjectButton.addEventListener("click", openCloneProjectModal);
         onboardingOpenProjectButton.addEventListener("click", sendOpenProjectDialog);
+        installProjectFolderDrop({
+          send,
+          visibleBounds,
+          isBlocked: projectShellModalOrDropdownOpen,
+        });
 
         // Escape closes the Projects switcher even when focus has left its panel
         // (e.g. returned to the trigger button or the document body).
@@ -91,6 +91,17 @@ function rowMetaForRecent(project) {
   return path || kind || "";
 }
 
+function duplicateFor(duplicateProjects, path) {
+  return (Array.isArray(duplicateProjects) ? duplicateProjects : []).find(
+    (duplicate) => String(duplicate?.path || "").trim() === path,
+  );
+}
+
+function pathBaseName(path) {
+  const parts = String(path || "").split(/[\\/]/).filter(Boolean);
+  return parts[parts.length - 1] || path;
+}
+
 export function nextProjectTabId(tabs, activeTabId, direction = "next") {
   const ids = (Array.isArray(tabs) ? tabs : [])
     .map((tab) => tab?.id)
@@ -155,9 +166,17 @@ export function shouldHandleProjectSwitcherShortcut(
   return String(event.key || "").toLowerCase() === "p";
 }
 
+const SECTION_LABELS = {
+  open: "Open Projects",
+  registered: "Repositories",
+  recent: "Recent",
+};
+
 export function buildProjectSwitcherRows({
   tabs,
   recentProjects,
+  registeredProjects = [],
+  duplicateProjects = [],
   activeTabId,
   unreadProjectIds = new Set(),
   runtimeStateForWindow,
@@ -191,6 +210,27 @@ export function buildProjectSwitcherRows({
     });
   }
 
+  // `repositories` from the global config: always switchable, even when
+  // they have dropped out of the recent history.
+  for (const project of Array.isArray(registeredProjects) ? registeredProjects : []) {
+    const path = String(project?.path || "").trim();
+    if (!path || openPaths.has(path)) {
+      continue;
+    }
+    openPaths.add(path);
+    rows.push({
+      section: "registered",
+      type: "recent",
+      title: project?.title || path,
+      path,
+      meta: rowMetaForRecent(project),
+      active: false,
+      unread: false,
+      runningCount: 0,
+      duplicateOf: duplicateFor(duplicateProjects, path)?.canonical_path || null,
+    });
+  }
+
   for (const project of Array.isArray(recentProjects) ? recentProjects : []) {
     const path = String(project?.path || "").trim();
     if (!path || openPaths.has(path)) {
@@ -205,6 +245,7 @@ export function buildProjectSwitcherRows({
       active: false,
       unread: false,
       runningCount: 0,
+      duplicateOf: duplicateFor(duplicateProjects, path)?.canonical_path || null,
     });
   }
 
@@ -224,6 +265,7 @@ export function createProjectSwitcherController({
   requestNotificationPermission = null,
   onOpenFolder = () => {},
   onCloneFromGithub = () => {},
+  confirmConsolidate = () => true,
 } = {}) {
   let open = false;
   let selectedIndex = 0;
@@ -271,6 +313,8 @@ export function createProjectSwitcherController({
     return buildProjectSwitcherRows({
       tabs: state.tabs || [],
       recentProjects: state.recent_projects || [],
+      registeredProjects: state.registered_projects || [],
+      duplicateProjects: state.duplicate_projects || [],
       activeTabId: state.active_tab_id || null,
       unreadProjectIds,
       runtimeStateForWindow,
@@ -332,7 +376,15 @@ export function createProjectSwitcherController({
     const meta = createNode("span", "project-switcher-row__meta", row.meta);
     item.append(title, meta);
 
-    if (row.unread) {
+    if (row.duplicateOf) {
+      const badge = createNode(
+        "span",
+        "project-switcher-row__badge project-switcher-row__badge--duplicate",
+        "Duplicate",
+      );
+      badge.title = `Same remote as ${row.duplicateOf}`;
+      item.appendChild(badge);
+    } else if (row.unread) {
       const badge = createNode("span", "project-switcher-row__badge", "New");
       item.appendChild(badge);
     } else if (row.runningCount > 0) {
@@ -346,6 +398,32 @@ export function createProjectSwitcherController({
     }
 
     fragment.appendChild(item);
+    if (row.duplicateOf) {
+      appendConsolidateAction(fragment, row);
+    }
+  }
+
+  // A second clone of the same remote as an earlier project: offer to fold
+  // it into that project. The backend fetches the duplicate's missing
+  // branches first and leaves both folders on disk.
+  function appendConsolidateAction(fragment, row) {
+    const action = createNode(
+      "button",
+      "project-switcher-consolidate",
+      `Consolidate into ${pathBaseName(row.duplicateOf)}`,
+    );
+    action.type = "button";
+    action.dataset.action = "consolidate-duplicate-project";
+    action.dataset.projectPath = row.path;
+    action.title = `${row.path} and ${row.duplicateOf} clone the same remote`;
+    action.addEventListener("click", () => {
+      if (!confirmConsolidate(row)) {
+        return;
+      }
+      send?.({ kind: "consolidate_duplicate_project", path: row.path });
+      close({ restoreFocus: true });
+    });
+    fragment.appendChild(action);
   }
 
   function appendActions(fragment) {
@@ -433,7 +511,7 @@ export function createProjectSwitcherController({
         if (row.section !== previousSection) {
           appendSectionLabel(
             fragment,
-            row.section === "open" ? "Open Projects" : "Recent",
+            SECTION_LABELS[row.section] || "Recent",
           );
           previousSection = row.section;
         }
@@ -0,0 +1,110 @@
+// One scheduler for the GUI's periodic work (status strip clock, footer
+// widgets, Knowledge auto refresh). A single timer is armed for the task
+// that is due first instead of one interval or animation-frame loop per
+// feature. The budget comes from the `[refresh]` config table
+// (`refresh_budget`): once no keyboard or pointer input arrived for
+// `idle_after_secs`, tasks run `idle_backoff` times less often, and while
+// the window is hidden nothing runs at all when `pause_when_hidden` is set.
+// Input after an idle stretch and becoming visible again bring overdue
+// tasks forward immediately.
+
+const DEFAULT_BUDGET = {
+  idleAfterMs: 120_000,
+  idleBackoff: 4,
+  pauseWhenHidden: true,
+};
+
+export function createRefreshScheduler({
+  now = () => Date.now(),
+  setTimer = (fn, ms) => setTimeout(fn, ms),
+  clearTimer = (id) => clearTimeout(id),
+  isHidden = () => typeof document !== "undefined" && document.hidden === true,
+} = {}) {
+  const tasks = new Set();
+  let budget = { ...DEFAULT_BUDGET };
+  let lastActivity = now();
+  let timer = null;
+
+  const idle = () => now() - lastActivity >= budget.idleAfterMs;
+  const paused = () => budget.pauseWhenHidden && isHidden();
+  const period = (task) =>
+    task.backoff && idle() ? task.intervalMs * budget.idleBackoff : task.intervalMs;
+
+  function arm() {
+    if (timer !== null) {
+      clearTimer(timer);
+      timer = null;
+    }
+    if (tasks.size === 0 || paused()) return;
+    let due = Infinity;
+    for (const task of tasks) due = Math.min(due, task.due);
+    timer = setTimer(run, Math.max(0, due - now()));
+  }
+
+  function run() {
+    timer = null;
+    if (paused()) return;
+    const at = now();
+    for (const task of [...tasks]) {
+      if (task.due > at) continue;
+      task.due = at + period(task);
+      try {
+        task.fn();
+      } catch (error) {
+        console.error("periodic refresh failed", error);
+      }
+    }
+    arm();
+  }
+
+  // Run `fn` every `intervalMs`. Tasks with `backoff: false` (the clock)
+  // keep their pace while idle. Returns a function that cancels the task.
+  function every(intervalMs, fn, { backoff = true } = {}) {
+    const task = {
+      intervalMs: Math.max(1, Number(intervalMs) || 1),
+      fn,
+      backoff,
+      due: 0,
+    };
+    task.due = now() + period(task);
+    tasks.add(task);
+    arm();
+    return () => {
+      if (tasks.delete(task)) arm();
+    };
+  }
+
+  // Keyboard or pointer input. Cheap while active; leaving an idle stretch
+  // pulls every backed-off task back to its normal pace.
+  function noteActivity() {
+    const wasIdle = idle();
+    lastActivity = now();
+    if (!wasIdle) return;
+    for (const task of tasks) {
+      task.due = Math.min(task.due, lastActivity + task.intervalMs);
+    }
+    arm();
+  }
+
+  // Visibility changed: stop while hidden, catch up on overdue tasks when
+  // shown again.
+  function wake() {
+    if (paused()) {
+      arm();
+      return;
+    }
+    run();
+  }
+
+  function configure({ idle_after_secs, idle_backoff, pause_when_hidden } = {}) {
+    budget = {
+      idleAfterMs:
+        Number(idle_after_secs) > 0 ? Number(idle_after_secs) * 1000 : DEFAULT_BUDGET.idleAfterMs,
+      idleBackoff: Math.max(1, Number(idle_backoff) || DEFAULT_BUDGET.idleBackoff),
+      pauseWhenHidden: pause_when_hidden ?? DEFAULT_BUDGET.pauseWhenHidden,
+    };
+    arm();
+  }
+
+  return { every, noteActivity, wake, configure, isIdle: idle };
+}
@@ -1,6 +1,6 @@
 // SPEC-3064 Phase 3 (E4) — Settings windows surface extracted from app.js.
 // Owns the Settings window body (tabbed System / Custom Agents / Agent
-// Backends / Usage & Limits surface), the customAgentsState /
+// Backends / Usage & Limits / Appearance surface), the customAgentsState /
 // agentBackendsState / systemSettingsState stores, the Teams channel link
 // converters, the add-from-preset flow, the autostart appliers, and the
 // systemSettingsInteractionGuard that defers destructive System panel
@@ -22,9 +22,13 @@
 //   imported as a sibling module.
 // - indexStatusByProjectRoot: per-project index status Map owned by the
 //   Project Index surface instance created in app.js (E3).
+// - getThemeManager(): operator shell theme manager accessor (null when the
+//   shell failed to boot).
 import { createInteractionGuard } from "/interaction-guard.js";
 import { renderIndexSettingsPanel } from "/index-settings-panel.js";
 import { renderCustomAgentEnvEditor } from "/custom-agent-env-editor.js";
+import { renderThemeSettingsPanel } from "/theme-settings-panel.js";
+import { renderConfigDiagnostics } from "/config-diagnostics-panel.js";
 
 export function createSettingsSurface({
   send,
@@ -34,6 +38,7 @@ export function createSettingsSurface({
   focusOrSpawnPreset,
   renderUsagePanel,
   indexStatusByProjectRoot,
+  getThemeManager = () => null,
 }) {
       // Issue #2698 PR 4 — same guard applied to the System Settings
       // Output Language `<select>`. Backend echoes `system_settings`
@@ -151,6 +156,9 @@ export function createSettingsSurface({
           teamsDefaultChannel: "",
           oauthRedirectPort: 8765,
         },
+        // `[ai]` written by the last AI preset (`ai_preset_applied`).
+        aiPreset: null,
+        aiPresetPending: false,
         autostartEnabled: false,
         autostartPreviousEnabled: false,
         autostartMechanism: "",
@@ -161,6 +169,11 @@ export function createSettingsSurface({
         statusMessage: "",
         statusKind: "",
       };
+      // `[theme.palettes]` entries the backend dropped, shown on the
+      // Appearance tab.
+      let themePaletteErrors = [];
+      // Problems in `~/.gwt/config.toml`, shown above the tabs.
+      let configDiagnostics = { path: "", diagnostics: [] };
       const settingsWindowBodies = new Set();
       let pendingAddFromPreset = null;
       let editingCustomAgentId = null;
@@ -207,10 +220,15 @@ export function createSettingsSurface({
         tabs.appendChild(buildSettingsTab("agent-backends", "Agent Backends", false));
         // SPEC-2970: provider usage display preferences (Claude opt-in).
         tabs.appendChild(buildSettingsTab("usage", "Usage & Limits", false));
+        tabs.appendChild(buildSettingsTab("appearance", "Appearance", false));
 
         toolbar.appendChild(heading);
         toolbar.appendChild(tabs);
 
+        const diagnosticsEl = createDiv("settings-config-diagnostics");
+        diagnosticsEl.dataset.role = "config-diagnostics";
+        renderConfigDiagnostics({ container: diagnosticsEl, ...configDiagnostics });
+
         const bodyEl = createDiv("settings-body");
 
         const panelSystem = document.createElement("section");
@@ -238,12 +256,20 @@ export function createSettingsSurface({
         panelUsage.dataset.settingsPanel = "usage";
         panelUsage.dataset.role = "settings-scroll";
 
+        const panelAppearance = document.createElement("section");
+        panelAppearance.className = "settings-panel hidden";
+        panelAppearance.setAttribute("role", "tabpanel");
+        panelAppearance.dataset.settingsPanel = "appearance";
+        panelAppearance.dataset.role = "settings-scroll";
+
         bodyEl.appendChild(panelSystem);
         bodyEl.appendChild(panelAgents);
         bodyEl.appendChild(panelBackends);
         bodyEl.appendChild(panelUsage);
+        bodyEl.appendChild(panelAppearance);
 
         root.appendChild(toolbar);
+        root.appendChild(diagnosticsEl);
         root.appendChild(bodyEl);
         body.appendChild(root);
 
@@ -268,6 +294,7 @@ export function createSettingsSurface({
 
         renderSystemPanel(panelSystem);
         renderUsagePanel(panelUsage);
+        renderAppearancePanel(panelAppearance);
         // Always request fresh system settings on open so the dropdown
         // reflects the on-disk config, even if the user changed it from a
         // different gwt instance.
@@ -301,6 +328,45 @@ export function createSettingsSurface({
 
       let pendingSettingsTabTarget = null;
 
+      function renderAppearancePanel(panel) {
+        renderThemeSettingsPanel({
+          panel,
+          themeManager: getThemeManager(),
+          errors: themePaletteErrors,
+        });
+      }
+
+      function renderAppearancePanelInAllSettingsWindows() {
+        purgeDetachedSettingsBodies();
+        for (const body of settingsWindowBodies) {
+          const panel = body.querySelector("[data-settings-panel='appearance']");
+          if (panel) renderAppearancePanel(panel);
+        }
+      }
+
+      // Theme or palette changes from any control (project bar toggle,
+      // command palette, another Settings window) keep every Appearance
+      // tab in sync.
+      getThemeManager()?.subscribe(() => renderAppearancePanelInAllSettingsWindows());
+
+      function applyThemePalettes(event) {
+        themePaletteErrors = event.errors || [];
+        getThemeManager()?.setCustomPalettes(event.palettes || []);
+        renderAppearancePanelInAllSettingsWindows();
+      }
+
+      function applyConfigDiagnostics(event) {
+        configDiagnostics = {
+          path: event.path || "",
+          diagnostics: event.diagnostics || [],
+        };
+        purgeDetachedSettingsBodies();
+        for (const body of settingsWindowBodies) {
+          const container = body.querySelector("[data-role='config-diagnostics']");
+          if (container) renderConfigDiagnostics({ container, ...configDiagnostics });
+        }
+      }
+
       function renderIndexPanel(panel) {
         const activeProjectRoot = activeProjectTab()?.project_root || "";
         const status =
@@ -550,6 +616,36 @@ export function createSettingsSurface({
           "Settings UI text and gwtd subcommands stay English.";
         section.appendChild(help);
 
+        // Local AI preset: points `[ai]` at Ollama so branch suggestions and
+        // summaries work offline. The backend picks an installed model.
+        const aiSection = createDiv("settings-section");
+        aiSection.appendChild(createNode("span", "settings-label", "AI provider"));
+        const ollamaBtn = createNode(
+          "button",
+          "wizard-button",
+          systemSettingsState.aiPresetPending ? "Connecting…" : "Use local Ollama",
+        );
+        ollamaBtn.type = "button";
+        ollamaBtn.dataset.role = "system-ai-preset-ollama";
+        ollamaBtn.disabled = systemSettingsState.aiPresetPending === true;
+        ollamaBtn.addEventListener("click", () => {
+          systemSettingsState.aiPresetPending = true;
+          systemSettingsState.statusMessage = "Looking for Ollama models…";
+          systemSettingsState.statusKind = "info";
+          renderSystemPanelInAllSettingsWindows();
+          send({ kind: "apply_ai_preset", preset: "ollama" });
+        });
+        aiSection.appendChild(ollamaBtn);
+        const aiHelp = document.createElement("p");
+        aiHelp.className = "settings-help";
+        const preset = systemSettingsState.aiPreset;
+        aiHelp.textContent = preset
+          ? `Using ${preset.model} via ${preset.provider} at ${preset.endpoint}` +
+            ` (${preset.models.length} model(s) installed).`
+          : "Runs AI features against an Ollama server on this machine " +
+            "(http://localhost:11434) with no API key.";
+        aiSection.appendChild(aiHelp);
+
         const trustSection = createDiv("settings-section");
         const trustLabel = document.createElement("label");
         trustLabel.className = "settings-checkbox-label";
@@ -946,6 +1042,7 @@ export function createSettingsSurface({
         autostartSection.appendChild(status);
 
         panel.appendChild(section);
+        panel.appendChild(aiSection);
         panel.appendChild(trustSection);
         panel.appendChild(boardSection);
         panel.appendChild(autostartSection);
@@ -1210,6 +1307,8 @@ export function createSettingsSurface({
         applyAutostartError,
         applyCustomAgentDeleted,
         applyCustomAgentError,
+        applyThemePalettes,
+        applyConfigDiagnostics,
         renderSettingsWindow,
         renderSettingsAgentList,
         renderAgentBackendsPanel,
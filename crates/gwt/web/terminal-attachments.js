@@ -8,6 +8,7 @@
 // its original app.js indentation.
 import { classifyTerminalCopyKeyEvent } from "/terminal-copy-shortcut.js";
 import { createTerminalContextMenuController } from "/terminal-context-menu.js";
+import { isProjectFolderDropEvent } from "/project-folder-drop.js";
 
 // deps:
 // - send(message): forward a frontend event over the WebSocket bridge.
@@ -771,8 +772,14 @@ export function createTerminalAttachments({
       }
 
       function installBrowserFileDropBridge() {
+        // Folder drops outside every window open a project
+        // (project-folder-drop.js).
         const handleDragOver = (event) => {
-          if (!dataTransferHasFiles(event.dataTransfer) || eventTargetsTerminalRoot(event)) {
+          if (
+            !dataTransferHasFiles(event.dataTransfer) ||
+            eventTargetsTerminalRoot(event) ||
+            isProjectFolderDropEvent(event)
+          ) {
             return;
           }
           event.preventDefault();
@@ -785,7 +792,11 @@ export function createTerminalAttachments({
         };
 
         const handleDrop = (event) => {
-          if (!dataTransferHasFiles(event.dataTransfer) || eventTargetsTerminalRoot(event)) {
+          if (
+            !dataTransferHasFiles(event.dataTransfer) ||
+            eventTargetsTerminalRoot(event) ||
+            isProjectFolderDropEvent(event)
+          ) {
             return;
           }
           const files = Array.from(event.dataTransfer?.files || []);
@@ -838,6 +849,7 @@ export function createTerminalAttachments({
       }
 
       return {
+        writeClipboardText,
         updateTerminalOverlayCopyState,
         copyTerminalOverlayMessage,
         installTerminalCopyHandlers,
@@ -3,14 +3,26 @@
 // Plain ESM module; no DOM access at top level so it stays unit-testable under Node.
 
 const STORAGE_KEY = "gwt:ui:theme";
+const PALETTE_STORAGE_KEY = "gwt:ui:palette";
 const VALID = new Set(["dark", "light", "auto"]);
+// Palettes defined in tokens.css. User palettes (the `[theme.palettes]`
+// config table) arrive from the backend and override tokens on top of
+// `default`.
+export const BUILTIN_PALETTES = ["default", "solarized"];
 
 export function createThemeManager(env) {
   const storage = env.storage;
   const matchMedia = env.matchMedia;
   const setDocumentTheme = env.setDocumentTheme;
+  const setDocumentPalette = env.setDocumentPalette;
+  const setDocumentTokens = env.setDocumentTokens;
 
   let preference = normalize(storage.get(STORAGE_KEY));
+  // The stored palette may name a user palette that has not arrived from
+  // the backend yet; it renders as `default` until setCustomPalettes().
+  let palette = storage.get(PALETTE_STORAGE_KEY) || "default";
+  let previewing = null;
+  let customPalettes = [];
   const subscribers = new Set();
   const mql = matchMedia("(prefers-color-scheme: dark)");
   let lastEffective = computeEffective(preference, mql);
@@ -29,6 +41,14 @@ export function createThemeManager(env) {
 
   function applyDocument(eff) {
     setDocumentTheme?.(eff);
+    applyPalette(eff);
+  }
+
+  function applyPalette(eff) {
+    const name = previewing ?? palette;
+    const custom = customPalettes.find((entry) => entry.name === name);
+    setDocumentPalette?.(BUILTIN_PALETTES.includes(name) ? name : "default");
+    setDocumentTokens?.(custom ? custom[eff] || {} : {});
   }
 
   function notify(eff) {
@@ -56,6 +76,35 @@ export function createThemeManager(env) {
         if (normalized !== previousPreference) notify(eff);
       }
     },
+    getPalette() { return palette; },
+    // Built-in palettes first, then user palettes in config order.
+    listPalettes() {
+      return [
+        ...BUILTIN_PALETTES.map((name) => ({ name, builtin: true })),
+        ...customPalettes.map(({ name }) => ({ name, builtin: false })),
+      ];
+    },
+    setPalette(next) {
+      const name = typeof next === "string" && next ? next : "default";
+      previewing = null;
+      palette = name;
+      if (name === "default") storage.delete(PALETTE_STORAGE_KEY);
+      else storage.set(PALETTE_STORAGE_KEY, name);
+      applyPalette(lastEffective);
+      notify(lastEffective);
+    },
+    // Render `name` without persisting it; `null` restores the chosen one.
+    previewPalette(name) {
+      previewing = typeof name === "string" && name ? name : null;
+      applyPalette(lastEffective);
+    },
+    setCustomPalettes(list) {
+      customPalettes = Array.isArray(list)
+        ? list.filter((entry) => entry && typeof entry.name === "string")
+        : [];
+      applyPalette(lastEffective);
+      notify(lastEffective);
+    },
     subscribe(fn) {
       subscribers.add(fn);
       return () => subscribers.delete(fn);
@@ -79,6 +128,7 @@ function computeEffective(preference, mql) {
 // using `createBrowserEnv()` below.
 export function createBrowserEnv(doc, win) {
   const root = doc.documentElement;
+  let appliedTokens = [];
   return {
     storage: {
       get(k) { try { return win.localStorage.getItem(k); } catch { return null; } },
@@ -87,5 +137,14 @@ export function createBrowserEnv(doc, win) {
     },
     matchMedia: (q) => win.matchMedia(q),
     setDocumentTheme: (t) => { root.setAttribute("data-theme", t); },
+    setDocumentPalette: (p) => {
+      if (p === "default") root.removeAttribute("data-palette");
+      else root.setAttribute("data-palette", p);
+    },
+    setDocumentTokens: (tokens) => {
+      for (const token of appliedTokens) root.style.removeProperty(`--${token}`);
+      appliedTokens = Object.keys(tokens);
+      for (const token of appliedTokens) root.style.setProperty(`--${token}`, tokens[token]);
+    },
   };
 }
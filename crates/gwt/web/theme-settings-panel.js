@@ -0,0 +1,160 @@
+// Settings > Appearance panel renderer.
+//
+// Pure rendering against an injected theme manager (see theme-manager.js):
+// the Theme select flips dark / light / auto, and the Palette list previews
+// a palette across the whole app while it is hovered or focused and keeps
+// it on click. The sample card below the list uses the same tokens as the
+// branch list, wizard, and modals, so it shows the live palette in one
+// place. `errors` lists `[theme.palettes]` entries the backend dropped.
+
+const THEME_OPTIONS = [
+  { value: "auto", text: "Auto (OS preference)" },
+  { value: "dark", text: "Dark" },
+  { value: "light", text: "Light" },
+];
+
+const SAMPLE_STATES = [
+  ["active", "Running"],
+  ["idle", "Idle"],
+  ["needs-input", "Needs input"],
+  ["blocked", "Blocked"],
+  ["done", "Done"],
+];
+
+const SAMPLE_AGENTS = ["claude", "codex", "gemini", "opencode", "copilot"];
+
+function clear(node) {
+  while (node.firstChild) node.removeChild(node.firstChild);
+}
+
+function element(doc, tag, className, text) {
+  const node = doc.createElement(tag);
+  if (className) node.className = className;
+  if (text !== undefined) node.textContent = text;
+  return node;
+}
+
+function renderThemeSection(doc, themeManager) {
+  const section = element(doc, "div", "settings-section");
+  const label = element(doc, "label", "settings-label", "Theme");
+  label.setAttribute("for", "settings-appearance-theme");
+  section.appendChild(label);
+
+  const select = element(doc, "select", "settings-select");
+  select.id = "settings-appearance-theme";
+  for (const opt of THEME_OPTIONS) {
+    const option = element(doc, "option", "", opt.text);
+    option.value = opt.value;
+    select.appendChild(option);
+  }
+  select.value = themeManager.getPreference();
+  select.addEventListener("change", (e) => themeManager.setTheme(e.target.value));
+  section.appendChild(select);
+  return section;
+}
+
+function renderPaletteSection(doc, themeManager, errors) {
+  const section = element(doc, "div", "settings-section");
+  const label = element(doc, "span", "settings-label", "Palette");
+  label.id = "settings-appearance-palette-label";
+  section.appendChild(label);
+
+  const list = element(doc, "div", "settings-palette-list");
+  list.setAttribute("role", "radiogroup");
+  list.setAttribute("aria-labelledby", label.id);
+  const current = themeManager.getPalette();
+  for (const { name, builtin } of themeManager.listPalettes()) {
+    const option = element(doc, "button", "settings-palette-option");
+    option.type = "button";
+    option.setAttribute("role", "radio");
+    option.setAttribute("aria-checked", String(name === current));
+    option.dataset.palette = name;
+    option.appendChild(element(doc, "span", "settings-palette-option__name", name));
+    option.appendChild(
+      element(doc, "span", "settings-palette-option__kind", builtin ? "built-in" : "config"),
+    );
+    option.addEventListener("mouseenter", () => themeManager.previewPalette(name));
+    option.addEventListener("focus", () => themeManager.previewPalette(name));
+    option.addEventListener("mouseleave", () => themeManager.previewPalette(null));
+    option.addEventListener("blur", () => themeManager.previewPalette(null));
+    option.addEventListener("click", () => themeManager.setPalette(name));
+    list.appendChild(option);
+  }
+  section.appendChild(list);
+
+  section.appendChild(
+    element(
+      doc,
+      "p",
+      "settings-help",
+      "Hover a palette to preview it. Add your own under [theme.palettes.<name>] " +
+        "in ~/.gwt/config.toml; each palette has dark and light token tables.",
+    ),
+  );
+  if (errors.length > 0) {
+    const problems = element(doc, "ul", "settings-palette-errors");
+    problems.dataset.role = "theme-palette-errors";
+    for (const message of errors) problems.appendChild(element(doc, "li", "", message));
+    section.appendChild(problems);
+  }
+  return section;
+}
+
+function renderPreviewSection(doc) {
+  const section = element(doc, "div", "settings-section settings-theme-preview");
+  section.dataset.role = "theme-preview";
+  section.appendChild(element(doc, "span", "settings-label", "Preview"));
+
+  const card = element(doc, "div", "settings-theme-preview__card");
+  card.appendChild(element(doc, "strong", "settings-theme-preview__title", "feature/login-form"));
+  card.appendChild(
+    element(doc, "p", "settings-theme-preview__text", "Worktree ready · 3 commits ahead of main"),
+  );
+  card.appendChild(
+    element(doc, "p", "settings-theme-preview__muted", "Last activity 4 minutes ago"),
+  );
+  const link = element(doc, "a", "settings-theme-preview__link", "Open pull request");
+  link.href = "#";
+  link.addEventListener("click", (e) => e.preventDefault());
+  card.appendChild(link);
+
+  const states = element(doc, "div", "settings-theme-preview__row");
+  for (const [state, text] of SAMPLE_STATES) {
+    const chip = element(doc, "span", "settings-theme-preview__state", text);
+    chip.dataset.state = state;
+    states.appendChild(chip);
+  }
+  card.appendChild(states);
+
+  const agents = element(doc, "div", "settings-theme-preview__row");
+  for (const agent of SAMPLE_AGENTS) {
+    const chip = element(doc, "span", "settings-theme-preview__agent", agent);
+    chip.dataset.agent = agent;
+    agents.appendChild(chip);
+  }
+  card.appendChild(agents);
+
+  const button = element(doc, "button", "settings-theme-preview__button", "Launch agent");
+  button.type = "button";
+  button.tabIndex = -1;
+  card.appendChild(button);
+
+  section.appendChild(card);
+  return section;
+}
+
+export function renderThemeSettingsPanel(options) {
+  const { panel, themeManager, errors = [], document: doc } = options;
+  const ownerDoc = doc || (panel && panel.ownerDocument) || globalThis.document;
+  if (!panel) return;
+  clear(panel);
+  if (!themeManager) {
+    panel.appendChild(
+      element(ownerDoc, "p", "settings-help", "Theme controls are unavailable."),
+    );
+    return;
+  }
+  panel.appendChild(renderThemeSection(ownerDoc, themeManager));
+  panel.appendChild(renderPaletteSection(ownerDoc, themeManager, errors));
+  panel.appendChild(renderPreviewSection(ownerDoc));
+}